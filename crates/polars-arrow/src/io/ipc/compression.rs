@@ -1,8 +1,101 @@
-use polars_error::PolarsResult;
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+#[cfg(feature = "io_ipc_compression")]
+use std::sync::OnceLock;
+
 #[cfg(feature = "io_ipc_compression")]
 use polars_error::to_compute_err;
+use polars_error::{PolarsResult, polars_err};
 use polars_utils::compression::ZstdLevel;
 
+/// The key of the schema metadata under which the [`ZstdDictionary`] of an IPC file or stream is
+/// stored.
+pub const ZSTD_DICTIONARY_METADATA_KEY: &str = "polars:zstd_dictionary";
+
+/// A zstd dictionary that is shared by the compressed buffers of an IPC file or stream.
+///
+/// A dictionary trained on the data improves the compression of small buffers with similar
+/// contents, such as short strings spread over many record batches. It is stored in the schema
+/// metadata under [`ZSTD_DICTIONARY_METADATA_KEY`], so only readers that know about that key can
+/// read buffers that were compressed with it.
+#[derive(Clone)]
+pub struct ZstdDictionary(Arc<ZstdDictionaryInner>);
+
+struct ZstdDictionaryInner {
+    bytes: Vec<u8>,
+    /// The dictionary prepared for compression, at the level of its first use.
+    #[cfg(feature = "io_ipc_compression")]
+    encoder: OnceLock<(i32, zstd::dict::EncoderDictionary<'static>)>,
+    #[cfg(feature = "io_ipc_compression")]
+    decoder: OnceLock<zstd::dict::DecoderDictionary<'static>>,
+}
+
+impl ZstdDictionary {
+    /// Create a dictionary from its serialized form, as produced by [`ZstdDictionary::train`] or
+    /// `zstd --train`.
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self(Arc::new(ZstdDictionaryInner {
+            bytes,
+            #[cfg(feature = "io_ipc_compression")]
+            encoder: OnceLock::new(),
+            #[cfg(feature = "io_ipc_compression")]
+            decoder: OnceLock::new(),
+        }))
+    }
+
+    /// Train a dictionary of at most `max_size` bytes on `samples`.
+    ///
+    /// Training fails if there are too few samples, in which case it is best to compress without
+    /// a dictionary.
+    #[cfg(feature = "io_ipc_compression")]
+    pub fn train<S: AsRef<[u8]>>(samples: &[S], max_size: usize) -> PolarsResult<Self> {
+        let bytes = zstd::dict::from_samples(samples, max_size).map_err(to_compute_err)?;
+        Ok(Self::new(bytes))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0.bytes
+    }
+
+    /// The dictionary as a value of the schema metadata.
+    pub fn to_metadata_value(&self) -> String {
+        use base64::Engine as _;
+        base64::engine::general_purpose::STANDARD.encode(&self.0.bytes)
+    }
+
+    /// Read the dictionary from a value of the schema metadata.
+    pub fn from_metadata_value(value: &str) -> PolarsResult<Self> {
+        use base64::Engine as _;
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(value)
+            .map_err(|err| {
+                polars_err!(ComputeError: "invalid zstd dictionary in IPC schema metadata: {err}")
+            })?;
+        Ok(Self::new(bytes))
+    }
+}
+
+impl Debug for ZstdDictionary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ZstdDictionary({} bytes)", self.0.bytes.len())
+    }
+}
+
+impl PartialEq for ZstdDictionary {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.bytes == other.0.bytes
+    }
+}
+
+impl Eq for ZstdDictionary {}
+
+impl Hash for ZstdDictionary {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.bytes.hash(state)
+    }
+}
+
 #[cfg(feature = "io_ipc_compression")]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_compression")))]
 pub fn decompress_lz4(input_buf: &[u8], output_buf: &mut [u8]) -> PolarsResult<()> {
@@ -13,10 +106,26 @@ pub fn decompress_lz4(input_buf: &[u8], output_buf: &mut [u8]) -> PolarsResult<(
 
 #[cfg(feature = "io_ipc_compression")]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_compression")))]
-pub fn decompress_zstd(input_buf: &[u8], output_buf: &mut [u8]) -> PolarsResult<()> {
+pub fn decompress_zstd(
+    input_buf: &[u8],
+    output_buf: &mut [u8],
+    dictionary: Option<&ZstdDictionary>,
+) -> PolarsResult<()> {
     use std::io::Read;
-    let mut decoder = zstd::Decoder::with_buffer(input_buf)?;
-    decoder.read_exact(output_buf).map_err(|e| e.into())
+    match dictionary {
+        None => {
+            let mut decoder = zstd::Decoder::with_buffer(input_buf)?;
+            decoder.read_exact(output_buf).map_err(|e| e.into())
+        },
+        Some(dictionary) => {
+            let inner = &dictionary.0;
+            let prepared = inner
+                .decoder
+                .get_or_init(|| zstd::dict::DecoderDictionary::copy(&inner.bytes));
+            let mut decoder = zstd::Decoder::with_prepared_dictionary(input_buf, prepared)?;
+            decoder.read_exact(output_buf).map_err(|e| e.into())
+        },
+    }
 }
 
 #[cfg(not(feature = "io_ipc_compression"))]
@@ -27,7 +136,11 @@ pub fn decompress_lz4(_input_buf: &[u8], _output_buf: &mut [u8]) -> PolarsResult
 }
 
 #[cfg(not(feature = "io_ipc_compression"))]
-pub fn decompress_zstd(_input_buf: &[u8], _output_buf: &mut [u8]) -> PolarsResult<()> {
+pub fn decompress_zstd(
+    _input_buf: &[u8],
+    _output_buf: &mut [u8],
+    _dictionary: Option<&ZstdDictionary>,
+) -> PolarsResult<()> {
     panic!(
         "The crate was compiled without IPC compression. Use `io_ipc_compression` to read compressed IPC."
     );
@@ -51,9 +164,26 @@ pub fn compress_zstd(
     input_buf: &[u8],
     output_buf: &mut Vec<u8>,
     level: ZstdLevel,
+    dictionary: Option<&ZstdDictionary>,
 ) -> PolarsResult<()> {
-    zstd::stream::copy_encode(input_buf, output_buf, level.compression_level())
-        .map_err(|e| e.into())
+    use std::io::Write;
+
+    let level = level.compression_level();
+    let Some(dictionary) = dictionary else {
+        return zstd::stream::copy_encode(input_buf, output_buf, level).map_err(|e| e.into());
+    };
+    let inner = &dictionary.0;
+    let (prepared_level, prepared) = inner
+        .encoder
+        .get_or_init(|| (level, zstd::dict::EncoderDictionary::copy(&inner.bytes, level)));
+    let mut encoder = if *prepared_level == level {
+        zstd::stream::Encoder::with_prepared_dictionary(output_buf, prepared)?
+    } else {
+        zstd::stream::Encoder::with_dictionary(output_buf, level, &inner.bytes)?
+    };
+    encoder.write_all(input_buf)?;
+    encoder.finish()?;
+    Ok(())
 }
 
 #[cfg(not(feature = "io_ipc_compression"))]
@@ -64,7 +194,12 @@ pub fn compress_lz4(_input_buf: &[u8], _output_buf: &[u8]) -> PolarsResult<()> {
 }
 
 #[cfg(not(feature = "io_ipc_compression"))]
-pub fn compress_zstd(_input_buf: &[u8], _output_buf: &[u8], _level: ZstdLevel) -> PolarsResult<()> {
+pub fn compress_zstd(
+    _input_buf: &[u8],
+    _output_buf: &[u8],
+    _level: ZstdLevel,
+    _dictionary: Option<&ZstdDictionary>,
+) -> PolarsResult<()> {
     panic!(
         "The crate was compiled without IPC compression. Use `io_ipc_compression` to write compressed IPC."
     )
@@ -80,13 +215,33 @@ mod tests {
     fn round_trip_zstd() {
         let data: Vec<u8> = (0..200u8).map(|x| x % 10).collect();
         let mut buffer = vec![];
-        compress_zstd(&data, &mut buffer, Default::default()).unwrap();
+        compress_zstd(&data, &mut buffer, Default::default(), None).unwrap();
 
         let mut result = vec![0; 200];
-        decompress_zstd(&buffer, &mut result).unwrap();
+        decompress_zstd(&buffer, &mut result, None).unwrap();
         assert_eq!(data, result);
     }
 
+    #[cfg(feature = "io_ipc_compression")]
+    #[test]
+    #[cfg_attr(miri, ignore)] // ZSTD uses foreign calls that miri does not support
+    fn round_trip_zstd_dictionary() {
+        let samples = (0..1000)
+            .map(|i| format!("customer-{:04}-region-{}", i, i % 7).into_bytes())
+            .collect::<Vec<_>>();
+        let dictionary = ZstdDictionary::train(&samples, 1024).unwrap();
+        let dictionary =
+            ZstdDictionary::from_metadata_value(&dictionary.to_metadata_value()).unwrap();
+
+        let data = b"customer-1234-region-5";
+        let mut buffer = vec![];
+        compress_zstd(data, &mut buffer, Default::default(), Some(&dictionary)).unwrap();
+
+        let mut result = vec![0; data.len()];
+        decompress_zstd(&buffer, &mut result, Some(&dictionary)).unwrap();
+        assert_eq!(data.as_slice(), result);
+    }
+
     #[cfg(feature = "io_ipc_compression")]
     #[test]
     #[cfg_attr(miri, ignore)] // LZ4 uses foreign calls that miri does not support
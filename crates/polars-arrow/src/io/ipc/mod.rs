@@ -32,6 +32,7 @@ pub mod append;
 pub mod read;
 pub mod write;
 pub use arrow_format as format;
+pub use compression::{ZSTD_DICTIONARY_METADATA_KEY, ZstdDictionary};
 
 const ARROW_MAGIC_V1: [u8; 4] = [b'F', b'E', b'A', b'1'];
 const ARROW_MAGIC_V2: [u8; 6] = [b'A', b'R', b'R', b'O', b'W', b'1'];
@@ -61,4 +62,6 @@ pub struct IpcSchema {
     pub fields: Vec<IpcField>,
     /// Endianness of the file
     pub is_little_endian: bool,
+    /// The zstd dictionary the buffers were compressed with, if any
+    pub zstd_dictionary: Option<ZstdDictionary>,
 }
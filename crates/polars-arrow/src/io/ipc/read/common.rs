@@ -6,7 +6,7 @@ use polars_error::{PolarsResult, polars_bail, polars_err};
 use polars_utils::aliases::PlHashMap;
 use polars_utils::pl_str::PlSmallStr;
 
-use super::Dictionaries;
+use super::{Compression, Dictionaries};
 use super::deserialize::{read, skip};
 use crate::array::*;
 use crate::datatypes::{ArrowDataType, ArrowSchema, Field};
@@ -105,6 +105,10 @@ pub fn read_record_batch<R: Read + Seek>(
         .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferNodes(err)))?
         .ok_or_else(|| polars_err!(oos = OutOfSpecKind::MissingMessageNodes))?;
     let mut field_nodes = field_nodes.iter().collect::<VecDeque<_>>();
+    let compression = batch
+        .compression()
+        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferCompression(err)))?
+        .map(|codec| Compression::new(codec, ipc_schema.zstd_dictionary.as_ref()));
 
     let columns = if let Some(projection) = projection {
         let projection = ProjectionIter::new(
@@ -124,9 +128,7 @@ pub fn read_record_batch<R: Read + Seek>(
                     dictionaries,
                     block_offset,
                     ipc_schema.is_little_endian,
-                    batch.compression().map_err(|err| {
-                        polars_err!(oos = OutOfSpecKind::InvalidFlatbufferCompression(err))
-                    })?,
+                    compression,
                     limit,
                     version,
                     scratch,
@@ -158,9 +160,7 @@ pub fn read_record_batch<R: Read + Seek>(
                     dictionaries,
                     block_offset,
                     ipc_schema.is_little_endian,
-                    batch.compression().map_err(|err| {
-                        polars_err!(oos = OutOfSpecKind::InvalidFlatbufferCompression(err))
-                    })?,
+                    compression,
                     limit,
                     version,
                     scratch,
@@ -289,6 +289,7 @@ pub fn read_dictionary<R: Read + Seek>(
     let ipc_schema = IpcSchema {
         fields: vec![first_ipc_field.clone()],
         is_little_endian: ipc_schema.is_little_endian,
+        zstd_dictionary: ipc_schema.zstd_dictionary.clone(),
     };
     let chunk = read_record_batch(
         batch,
@@ -1,11 +1,11 @@
 use std::collections::VecDeque;
 use std::io::{Read, Seek};
 
-use arrow_format::ipc::{BodyCompressionRef, MetadataVersion};
+use arrow_format::ipc::MetadataVersion;
 use polars_error::PolarsResult;
 
 use super::array::*;
-use super::{Dictionaries, IpcBuffer, Node};
+use super::{Compression, Dictionaries, IpcBuffer, Node};
 use crate::array::*;
 use crate::datatypes::{ArrowDataType, Field, PhysicalType};
 use crate::io::ipc::IpcField;
@@ -23,7 +23,7 @@ pub fn read<R: Read + Seek>(
     dictionaries: &Dictionaries,
     block_offset: u64,
     is_little_endian: bool,
-    compression: Option<BodyCompressionRef>,
+    compression: Option<Compression>,
     limit: Option<usize>,
     version: MetadataVersion,
     scratch: &mut Vec<u8>,
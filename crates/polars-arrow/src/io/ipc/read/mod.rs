@@ -6,6 +6,7 @@
 //! data in the order it was written in.
 //! In addition, there is a Block-based struct [`BlockReader`](reader::BlockReader), which
 //! enabled random access to a standalone IPC Block.
+use super::ZstdDictionary;
 use crate::array::Array;
 
 mod array;
@@ -37,9 +38,37 @@ pub type Dictionaries = PlHashMap<i64, Box<dyn Array>>;
 
 pub(crate) type Node<'a> = arrow_format::ipc::FieldNodeRef<'a>;
 pub(crate) type IpcBuffer<'a> = arrow_format::ipc::BufferRef<'a>;
-pub(crate) type Compression<'a> = arrow_format::ipc::BodyCompressionRef<'a>;
 pub(crate) type Version = arrow_format::ipc::MetadataVersion;
 
+/// The compression of the buffers of a record batch.
+#[derive(Clone, Copy)]
+pub(crate) struct Compression<'a> {
+    codec: arrow_format::ipc::BodyCompressionRef<'a>,
+    zstd_dictionary: Option<&'a ZstdDictionary>,
+}
+
+impl<'a> Compression<'a> {
+    pub(crate) fn new(
+        codec: arrow_format::ipc::BodyCompressionRef<'a>,
+        zstd_dictionary: Option<&'a ZstdDictionary>,
+    ) -> Self {
+        Self {
+            codec,
+            zstd_dictionary,
+        }
+    }
+
+    pub(crate) fn codec(
+        &self,
+    ) -> arrow_format::ipc::planus::Result<arrow_format::ipc::CompressionType> {
+        self.codec.codec()
+    }
+
+    pub(crate) fn zstd_dictionary(&self) -> Option<&'a ZstdDictionary> {
+        self.zstd_dictionary
+    }
+}
+
 #[cfg(feature = "io_flight")]
 pub use flight::*;
 
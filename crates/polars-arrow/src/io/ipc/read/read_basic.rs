@@ -150,16 +150,17 @@ fn read_compressed_buffer<T: NativeType, R: Read + Seek>(
     let mut buffer = vec![T::default(); real_output_len];
     let out_slice = bytemuck::cast_slice_mut(&mut buffer);
 
-    let compression = compression
+    let codec = compression
         .codec()
         .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferCompression(err)))?;
 
-    match compression {
+    match codec {
         arrow_format::ipc::CompressionType::Lz4Frame => {
             compression::decompress_lz4(&scratch[8..], out_slice)?;
         },
         arrow_format::ipc::CompressionType::Zstd => {
-            compression::decompress_zstd(&scratch[8..], out_slice)?;
+            let dictionary = compression.zstd_dictionary();
+            compression::decompress_zstd(&scratch[8..], out_slice, dictionary)?;
         },
     }
     Ok(buffer)
@@ -319,16 +320,17 @@ fn read_compressed_bitmap<R: Read + Seek>(
     let mut buffer = Vec::new();
     buffer.resize(decompressed_bytes, 0);
 
-    let compression = compression
+    let codec = compression
         .codec()
         .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferCompression(err)))?;
 
-    match compression {
+    match codec {
         arrow_format::ipc::CompressionType::Lz4Frame => {
             compression::decompress_lz4(&scratch[8..], &mut buffer)?;
         },
         arrow_format::ipc::CompressionType::Zstd => {
-            compression::decompress_zstd(&scratch[8..], &mut buffer)?;
+            let dictionary = compression.zstd_dictionary();
+            compression::decompress_zstd(&scratch[8..], &mut buffer, dictionary)?;
         },
     }
     Ok(buffer)
@@ -5,7 +5,7 @@ use arrow_format::ipc::{FieldRef, FixedSizeListRef, MapRef, TimeRef, TimestampRe
 use polars_error::{PolarsResult, polars_bail, polars_err};
 use polars_utils::pl_str::PlSmallStr;
 
-use super::super::{IpcField, IpcSchema};
+use super::super::{IpcField, IpcSchema, ZSTD_DICTIONARY_METADATA_KEY, ZstdDictionary};
 use super::{OutOfSpecKind, StreamMetadata};
 use crate::datatypes::{
     ArrowDataType, ArrowSchema, Extension, ExtensionType, Field, IntegerType, IntervalUnit,
@@ -408,10 +408,11 @@ pub(super) fn fb_to_schema(
         arrow_format::ipc::Endianness::Big => false,
     };
 
+    let mut zstd_dictionary = None;
     let custom_schema_metadata = match schema.custom_metadata()? {
         None => None,
         Some(metadata) => {
-            let metadata: Metadata = metadata
+            let mut metadata: Metadata = metadata
                 .into_iter()
                 .filter_map(|kv_result| {
                     // FIXME: silently hiding errors here
@@ -419,6 +420,10 @@ pub(super) fn fb_to_schema(
                     Some((kv_ref.key().ok()??.into(), kv_ref.value().ok()??.into()))
                 })
                 .collect();
+            // The dictionary is part of the encoding of the buffers, not of the user metadata.
+            if let Some(value) = metadata.remove(ZSTD_DICTIONARY_METADATA_KEY) {
+                zstd_dictionary = Some(ZstdDictionary::from_metadata_value(&value)?);
+            }
 
             if metadata.is_empty() {
                 None
@@ -433,6 +438,7 @@ pub(super) fn fb_to_schema(
         IpcSchema {
             fields: ipc_fields,
             is_little_endian,
+            zstd_dictionary,
         },
         custom_schema_metadata,
     ))
@@ -5,7 +5,7 @@ use arrow_format::ipc::planus::Builder;
 use polars_error::{PolarsResult, polars_bail, polars_err};
 use polars_utils::compression::ZstdLevel;
 
-use super::super::IpcField;
+use super::super::{IpcField, ZSTD_DICTIONARY_METADATA_KEY, ZstdDictionary};
 use super::write;
 use crate::array::*;
 use crate::datatypes::*;
@@ -26,11 +26,51 @@ pub enum Compression {
 }
 
 /// Options declaring the behaviour of writing to IPC
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 pub struct WriteOptions {
     /// Whether the buffers should be compressed and which codec to use.
     /// Note: to use compression the crate must be compiled with feature `io_ipc_compression`.
     pub compression: Option<Compression>,
+    /// The dictionary to compress the buffers with if the codec is [`Compression::ZSTD`].
+    /// The writers store it in the schema metadata, see [`ZstdDictionary`].
+    pub zstd_dictionary: Option<ZstdDictionary>,
+}
+
+impl WriteOptions {
+    fn buffer_compression(&self) -> Option<BufferCompression<'_>> {
+        self.compression.map(|codec| BufferCompression {
+            codec,
+            zstd_dictionary: match codec {
+                Compression::ZSTD(_) => self.zstd_dictionary.as_ref(),
+                Compression::LZ4 => None,
+            },
+        })
+    }
+
+    /// Add the zstd dictionary, if it is used, to the custom schema metadata.
+    pub(crate) fn schema_metadata<'a>(
+        &self,
+        metadata: Option<&'a Metadata>,
+    ) -> Option<Cow<'a, Metadata>> {
+        match (self.compression, &self.zstd_dictionary) {
+            (Some(Compression::ZSTD(_)), Some(dictionary)) => {
+                let mut metadata = metadata.cloned().unwrap_or_default();
+                metadata.insert(
+                    ZSTD_DICTIONARY_METADATA_KEY.into(),
+                    dictionary.to_metadata_value().into(),
+                );
+                Some(Cow::Owned(metadata))
+            },
+            _ => metadata.map(Cow::Borrowed),
+        }
+    }
+}
+
+/// The compression of the buffers of a record batch.
+#[derive(Clone, Copy)]
+pub struct BufferCompression<'a> {
+    pub codec: Compression,
+    pub zstd_dictionary: Option<&'a ZstdDictionary>,
 }
 
 /// Find the dictionary that are new and need to be encoded.
@@ -323,10 +363,45 @@ pub fn encode_array(
         nodes,
         offset,
         is_native_little_endian(),
-        options.compression,
+        options.buffer_compression(),
     )
 }
 
+/// Train a [`ZstdDictionary`] of at most `max_size` bytes on the buffers of `arrays`, to compress
+/// the record batches of a file that are similar to them.
+#[cfg(feature = "io_ipc_compression")]
+pub fn train_zstd_dictionary(
+    arrays: &[Box<dyn Array>],
+    max_size: usize,
+) -> PolarsResult<ZstdDictionary> {
+    // Large buffers are split, as training needs many samples.
+    const SAMPLE_SIZE: usize = 1 << 14;
+
+    let mut buffers = vec![];
+    let mut arrow_data = vec![];
+    let mut nodes = vec![];
+    let mut offset = 0;
+    for array in arrays {
+        write(
+            array.as_ref(),
+            &mut buffers,
+            &mut arrow_data,
+            &mut nodes,
+            &mut offset,
+            is_native_little_endian(),
+            None,
+        );
+    }
+    let samples = buffers
+        .iter()
+        .flat_map(|buffer| {
+            let start = buffer.offset as usize;
+            arrow_data[start..start + buffer.length as usize].chunks(SAMPLE_SIZE)
+        })
+        .collect::<Vec<_>>();
+    ZstdDictionary::train(&samples, max_size)
+}
+
 /// Write [`RecordBatchT`] into two sets of bytes, one for the header (ipc::Schema::Message) and the
 /// other for the batch's data
 pub fn encode_record_batch(
@@ -422,7 +497,7 @@ pub fn encode_dictionary_values(
         &mut nodes,
         &mut 0,
         is_native_little_endian(),
-        options.compression,
+        options.buffer_compression(),
     );
 
     let compression = serialize_compression(options.compression);
@@ -5,6 +5,8 @@ mod serialize;
 mod stream;
 pub(crate) mod writer;
 
+#[cfg(feature = "io_ipc_compression")]
+pub use common::train_zstd_dictionary;
 pub use common::{
     Compression, DictionaryTracker, EncodedData, Record, WriteOptions, commit_encoded_arrays,
     dictionaries_to_encode, encode_array, encode_dictionary_values, encode_new_dictionaries,
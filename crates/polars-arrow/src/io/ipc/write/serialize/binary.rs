@@ -9,7 +9,7 @@ fn write_generic_binary<O: Offset>(
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<BufferCompression<'_>>,
 ) {
     let offsets = offsets.buffer();
     write_bitmap(
@@ -58,7 +58,7 @@ pub(super) fn write_binary<O: Offset>(
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<BufferCompression<'_>>,
 ) {
     write_generic_binary(
         array.validity(),
@@ -78,7 +78,7 @@ pub(super) fn write_utf8<O: Offset>(
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<BufferCompression<'_>>,
 ) {
     write_generic_binary(
         array.validity(),
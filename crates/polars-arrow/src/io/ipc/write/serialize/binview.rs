@@ -8,7 +8,7 @@ pub(super) fn write_binview<T: ViewType + ?Sized>(
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<BufferCompression<'_>>,
 ) {
     write_bitmap(
         array.validity(),
@@ -7,7 +7,7 @@ pub(super) fn write_fixed_size_list(
     nodes: &mut Vec<ipc::FieldNode>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<BufferCompression<'_>>,
 ) {
     write_bitmap(
         array.validity(),
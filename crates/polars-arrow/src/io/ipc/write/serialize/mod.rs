@@ -3,7 +3,7 @@ use arrow_format::ipc;
 
 use super::super::compression;
 use super::super::endianness::is_native_little_endian;
-use super::common::{Compression, pad_to_64};
+use super::common::{BufferCompression, Compression, pad_to_64};
 use crate::array::*;
 use crate::bitmap::Bitmap;
 use crate::datatypes::PhysicalType;
@@ -41,7 +41,7 @@ pub fn write(
     nodes: &mut Vec<ipc::FieldNode>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<BufferCompression<'_>>,
 ) {
     nodes.push(ipc::FieldNode {
         length: array.len() as i64,
@@ -206,17 +206,18 @@ fn write_bytes(
     buffers: &mut Vec<ipc::Buffer>,
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
-    compression: Option<Compression>,
+    compression: Option<BufferCompression<'_>>,
 ) {
     let start = arrow_data.len();
     if let Some(compression) = compression {
         arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
-        match compression {
+        match compression.codec {
             Compression::LZ4 => {
                 compression::compress_lz4(bytes, arrow_data).unwrap();
             },
             Compression::ZSTD(level) => {
-                compression::compress_zstd(bytes, arrow_data, level).unwrap();
+                compression::compress_zstd(bytes, arrow_data, level, compression.zstd_dictionary)
+                    .unwrap();
             },
         }
     } else {
@@ -232,7 +233,7 @@ fn write_bitmap(
     buffers: &mut Vec<ipc::Buffer>,
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
-    compression: Option<Compression>,
+    compression: Option<BufferCompression<'_>>,
 ) {
     match bitmap {
         Some(bitmap) => {
@@ -263,7 +264,7 @@ fn write_buffer<T: NativeType>(
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<BufferCompression<'_>>,
 ) {
     let start = arrow_data.len();
     if let Some(compression) = compression {
@@ -299,7 +300,7 @@ fn _write_compressed_buffer_from_iter<T: NativeType, I: TrustedLen<Item = T>>(
     buffer: I,
     arrow_data: &mut Vec<u8>,
     is_little_endian: bool,
-    compression: Compression,
+    compression: BufferCompression<'_>,
 ) {
     let len = buffer.size_hint().0;
     let mut swapped = Vec::with_capacity(len * size_of::<T>());
@@ -313,12 +314,13 @@ fn _write_compressed_buffer_from_iter<T: NativeType, I: TrustedLen<Item = T>>(
             .for_each(|x| swapped.extend_from_slice(x.as_ref()))
     };
     arrow_data.extend_from_slice(&(swapped.len() as i64).to_le_bytes());
-    match compression {
+    match compression.codec {
         Compression::LZ4 => {
             compression::compress_lz4(&swapped, arrow_data).unwrap();
         },
         Compression::ZSTD(level) => {
-            compression::compress_zstd(&swapped, arrow_data, level).unwrap();
+            compression::compress_zstd(&swapped, arrow_data, level, compression.zstd_dictionary)
+                .unwrap();
         },
     }
 }
@@ -337,17 +339,18 @@ fn _write_compressed_buffer<T: NativeType>(
     buffer: &[T],
     arrow_data: &mut Vec<u8>,
     is_little_endian: bool,
-    compression: Compression,
+    compression: BufferCompression<'_>,
 ) {
     if is_little_endian == is_native_little_endian() {
         let bytes = bytemuck::cast_slice(buffer);
         arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
-        match compression {
+        match compression.codec {
             Compression::LZ4 => {
                 compression::compress_lz4(bytes, arrow_data).unwrap();
             },
             Compression::ZSTD(level) => {
-                compression::compress_zstd(bytes, arrow_data, level).unwrap();
+                compression::compress_zstd(bytes, arrow_data, level, compression.zstd_dictionary)
+                    .unwrap();
             },
         }
     } else {
@@ -363,7 +366,7 @@ fn write_buffer_from_iter<T: NativeType, I: TrustedLen<Item = T>>(
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<BufferCompression<'_>>,
 ) {
     let start = arrow_data.len();
 
@@ -71,11 +71,14 @@ impl<W: Write> StreamWriter<W> {
             default_ipc_fields(schema.iter_values())
         });
 
+        let metadata = self
+            .write_options
+            .schema_metadata(self.custom_schema_metadata.as_deref());
         let encoded_message = EncodedData {
             ipc_message: schema_to_bytes(
                 schema,
                 self.ipc_fields.as_ref().unwrap(),
-                self.custom_schema_metadata.as_deref(),
+                metadata.as_deref(),
             ),
             arrow_data: vec![],
         };
@@ -4,7 +4,7 @@ use std::sync::Arc;
 use arrow_format::ipc::planus::Builder;
 use polars_error::{PolarsResult, polars_bail};
 
-use super::super::{ARROW_MAGIC_V2, IpcField};
+use super::super::{ARROW_MAGIC_V2, IpcField, ZstdDictionary};
 use super::common::{DictionaryTracker, EncodedData, WriteOptions};
 use super::common_sync::{write_continuation, write_message};
 use super::{default_ipc_fields, schema, schema_to_bytes};
@@ -232,11 +232,11 @@ impl<W: Write> FileWriter<W> {
         // write EOS
         write_continuation(&mut self.writer, 0)?;
 
-        let schema = schema::serialize_schema(
-            &self.schema,
-            &self.ipc_fields,
-            self.custom_schema_metadata.as_deref(),
-        );
+        let metadata = self
+            .options
+            .schema_metadata(self.custom_schema_metadata.as_deref());
+        let schema =
+            schema::serialize_schema(&self.schema, &self.ipc_fields, metadata.as_deref());
 
         let root = arrow_format::ipc::Footer {
             version: arrow_format::ipc::MetadataVersion::V5,
@@ -261,4 +261,10 @@ impl<W: Write> FileWriter<W> {
     pub fn set_custom_schema_metadata(&mut self, custom_metadata: Arc<Metadata>) {
         self.custom_schema_metadata = Some(custom_metadata);
     }
+
+    /// Sets the dictionary to compress the next record batches with. It is stored in the footer,
+    /// so it must be set before `finish` is called.
+    pub fn set_zstd_dictionary(&mut self, zstd_dictionary: Option<ZstdDictionary>) {
+        self.options.zstd_dictionary = zstd_dictionary;
+    }
 }
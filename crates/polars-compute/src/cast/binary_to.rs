@@ -16,10 +16,90 @@ pub(super) trait Parse {
         Self: Sized;
 }
 
+/// Parses up to 8 ASCII digits without a sign at once, which is the common case for integers
+/// read from text formats such as CSV.
+#[inline]
+fn parse_short_digits(val: &[u8]) -> Option<u64> {
+    if val.is_empty() || val.len() > 8 {
+        return None;
+    }
+    // Left-pad with zeros, so the first digit is the most significant one.
+    let mut buf = [b'0'; 8];
+    buf[8 - val.len()..].copy_from_slice(val);
+    let v = u64::from_le_bytes(buf);
+
+    // Every byte must be in b'0'..=b'9'.
+    let high = v & 0xF0F0_F0F0_F0F0_F0F0;
+    let carried = v.wrapping_add(0x0606_0606_0606_0606) & 0xF0F0_F0F0_F0F0_F0F0;
+    if high | (carried >> 4) != 0x3333_3333_3333_3333 {
+        return None;
+    }
+
+    // Combine pairs of digits, then pairs of those, then the two halves.
+    const MASK: u64 = 0x0000_00FF_0000_00FF;
+    const MUL1: u64 = 100 + (1_000_000 << 32);
+    const MUL2: u64 = 1 + (10_000 << 32);
+    let v = v - 0x3030_3030_3030_3030;
+    let v = v.wrapping_mul(10).wrapping_add(v >> 8);
+    let v = (v & MASK)
+        .wrapping_mul(MUL1)
+        .wrapping_add(((v >> 16) & MASK).wrapping_mul(MUL2))
+        >> 32;
+    Some(v)
+}
+
+/// Parses a decimal such as `-123.45` with up to 8 digits before and after the point into its
+/// sign, its digits as an integer and the number of digits after the point.
+#[inline]
+fn parse_short_decimal(val: &[u8]) -> Option<(bool, u64, usize)> {
+    let (negative, val) = match val.split_first() {
+        Some((b'-', rest)) => (true, rest),
+        Some((b'+', rest)) => (false, rest),
+        _ => (false, val),
+    };
+    let (int, frac) = match val.iter().position(|&b| b == b'.') {
+        Some(idx) => (&val[..idx], &val[idx + 1..]),
+        None => (val, &val[..0]),
+    };
+    let int = parse_short_digits(int)?;
+    let frac_digits = frac.len();
+    let frac = if frac.is_empty() {
+        0
+    } else {
+        parse_short_digits(frac)?
+    };
+    Some((negative, int * 10u64.pow(frac_digits as u32) + frac, frac_digits))
+}
+
+/// Parses short decimals with [`parse_short_decimal`] if the result is exact, which is the case if
+/// the digits and the power of ten are exactly representable, as the division is then correctly
+/// rounded (Clinger's fast path). Other values fall back to `fast_float2`.
+macro_rules! impl_parse_float {
+    ($float:ident, $max_mantissa:expr, $powers:expr) => {
+        impl Parse for $float {
+            fn parse(val: &[u8]) -> Option<Self> {
+                if let Some((negative, mantissa, frac_digits)) = parse_short_decimal(val) {
+                    const POWERS: [$float; 9] = $powers;
+                    if mantissa <= $max_mantissa && frac_digits < POWERS.len() {
+                        let v = mantissa as $float / POWERS[frac_digits];
+                        return Some(if negative { -v } else { v });
+                    }
+                }
+                fast_float2::parse(val).ok()
+            }
+        }
+    };
+}
+impl_parse_float!(f32, 1 << 24, [1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8]);
+impl_parse_float!(f64, 1 << 53, [1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8]);
+
 macro_rules! impl_parse {
     ($primitive_type:ident) => {
         impl Parse for $primitive_type {
             fn parse(val: &[u8]) -> Option<Self> {
+                if let Some(v) = parse_short_digits(val) {
+                    return Self::try_from(v).ok();
+                }
                 atoi_simd::parse_skipped(val).ok()
             }
         }
@@ -45,24 +125,7 @@ impl Parse for pf16 {
     where
         Self: Sized,
     {
-        fast_float2::parse(val).ok().map(|f: f32| f.as_())
-    }
-}
-
-impl Parse for f32 {
-    fn parse(val: &[u8]) -> Option<Self>
-    where
-        Self: Sized,
-    {
-        fast_float2::parse(val).ok()
-    }
-}
-impl Parse for f64 {
-    fn parse(val: &[u8]) -> Option<Self>
-    where
-        Self: Sized,
-    {
-        fast_float2::parse(val).ok()
+        <f32 as Parse>::parse(val).map(|f| f.as_())
     }
 }
 
@@ -76,11 +76,23 @@ pub(super) fn utf8view_to_primitive<T>(
 where
     T: NativeType + Parse,
 {
-    let iter = from
-        .iter()
-        .map(|x| x.and_then::<T, _>(|x| T::parse(x.as_bytes())));
+    // Parse straight from the views, so that short strings are read from the view itself
+    // without touching the data buffers.
+    let buffers = from.data_buffers();
+    let input_validity = from.validity();
+    let mut values = Vec::with_capacity(from.len());
+    let mut validity = MutableBitmap::with_capacity(from.len());
+    for (i, view) in from.views().iter().enumerate() {
+        // SAFETY: `i` is in bounds and the views of a valid array point into its data buffers.
+        let is_valid = input_validity.is_none_or(|v| unsafe { v.get_bit_unchecked(i) });
+        let value = is_valid
+            .then(|| T::parse(unsafe { view.get_slice_unchecked(buffers) }))
+            .flatten();
+        validity.push(value.is_some());
+        values.push(value.unwrap_or_default());
+    }
 
-    PrimitiveArray::<T>::from_trusted_len_iter(iter).to(to.clone())
+    PrimitiveArray::new(to.clone(), values.into(), validity.into())
 }
 
 /// Parses a `&dyn` [`Array`] of UTF-8 encoded string representations of numbers
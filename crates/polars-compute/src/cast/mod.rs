@@ -1237,4 +1237,71 @@ mod tests {
             vec![vec![10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10],]
         );
     }
+
+    #[test]
+    fn cast_utf8view_to_integer() {
+        let array = Utf8ViewArray::from_slice([
+            Some("12345678"),
+            Some("007"),
+            Some("-42"),
+            Some("123456789012"),
+            Some("300"),
+            Some("1a"),
+            Some(""),
+            None,
+        ]);
+        let out = cast(&array, &ArrowDataType::Int64, CastOptionsImpl::default()).unwrap();
+        assert_eq!(
+            out.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap(),
+            &PrimitiveArray::from([
+                Some(12345678),
+                Some(7),
+                Some(-42),
+                Some(123456789012),
+                Some(300),
+                None,
+                None,
+                None,
+            ])
+        );
+
+        let out = cast(&array, &ArrowDataType::UInt8, CastOptionsImpl::default()).unwrap();
+        assert_eq!(
+            out.as_any().downcast_ref::<PrimitiveArray<u8>>().unwrap(),
+            &PrimitiveArray::from([None, Some(7), None, None, None, None, None, None])
+        );
+    }
+
+    #[test]
+    fn cast_utf8view_to_float() {
+        let values = [
+            "0.1",
+            "-0.0",
+            "+123.456",
+            "12345678.12345678",
+            "99999999.99999999",
+            "0.30000001",
+            "1.",
+            ".5",
+            "1e5",
+            "-inf",
+        ];
+        let array = Utf8ViewArray::from_slice(values.map(Some));
+        let out = cast(&array, &ArrowDataType::Float64, CastOptionsImpl::default()).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        for (v, parsed) in values.iter().zip(out.iter()) {
+            let expected = v.parse::<f64>().unwrap();
+            assert_eq!(parsed.unwrap().to_bits(), expected.to_bits(), "{v}");
+        }
+        let out = cast(&array, &ArrowDataType::Float32, CastOptionsImpl::default()).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f32>>().unwrap();
+        for (v, parsed) in values.iter().zip(out.iter()) {
+            let expected = v.parse::<f32>().unwrap();
+            assert_eq!(parsed.unwrap().to_bits(), expected.to_bits(), "{v}");
+        }
+
+        let array = Utf8ViewArray::from_slice([Some("1.2.3"), Some("-"), Some("."), Some("1a")]);
+        let out = cast(&array, &ArrowDataType::Float64, CastOptionsImpl::default()).unwrap();
+        assert_eq!(out.null_count(), 4);
+    }
 }
@@ -35,6 +35,9 @@ use polars_error::{PolarsResult, polars_ensure};
 /// The maximum precision of a Decimal128.
 pub const DEC128_MAX_PREC: usize = 38;
 
+/// The minimum scale of the mean of Decimal128s.
+pub const DEC128_MEAN_MIN_SCALE: usize = 6;
+
 pub fn dec128_verify_prec_scale(p: usize, s: usize) -> PolarsResult<()> {
     polars_ensure!((1..=DEC128_MAX_PREC).contains(&p), InvalidOperation: "precision must be between 1 and 38");
     polars_ensure!(s <= p, InvalidOperation: "scale must be less than or equal to precision");
@@ -599,6 +602,83 @@ pub fn dec128_div(l: i128, r: i128, p: usize, s: usize) -> Option<i128> {
     }
 }
 
+/// Computes the remainder of the floored division of two Decimal128s, assuming
+/// they have the same scale. The result has the sign of the divisor.
+#[inline]
+pub fn dec128_rem(l: i128, r: i128) -> Option<i128> {
+    if r == 0 {
+        return None;
+    }
+
+    // Can't overflow, both fit in DEC128_MAX_PREC.
+    let m = l % r;
+    if m != 0 && (m < 0) != (r < 0) {
+        Some(m + r)
+    } else {
+        Some(m)
+    }
+}
+
+/// The scale of the mean of Decimal128s with precision p and scale s.
+///
+/// The scale is raised to DEC128_MEAN_MIN_SCALE as long as the integer digits
+/// still fit in the maximum precision, so the mean can't overflow.
+#[inline]
+pub fn dec128_mean_scale(p: usize, s: usize) -> usize {
+    s.max(DEC128_MEAN_MIN_SCALE).min(DEC128_MAX_PREC - (p - s))
+}
+
+/// Computes the mean of count Decimal128s with scale s that sum to sum, with
+/// scale new_s >= s, rounding to nearest even. Returns None if count is zero.
+#[inline]
+pub fn dec128_mean(sum: i128, count: usize, s: usize, new_s: usize) -> Option<i128> {
+    if count == 0 {
+        return None;
+    }
+
+    let count = i128::try_from(count).ok()?;
+    if new_s == s {
+        return dec128_div(sum, count, DEC128_MAX_PREC, 0);
+    }
+
+    // Split off the integer part first, so the rescale can't overflow the
+    // intermediate result. As the multiplier is even, rounding the fractional
+    // part to nearest even also rounds the result to nearest even.
+    let m = POW10_I128[new_s - s];
+    let (q, r) = (sum / count, sum % count);
+    let frac = dec128_div(r.checked_mul(m)?, count, DEC128_MAX_PREC, 0)?;
+    q.checked_mul(m)?.checked_add(frac)
+}
+
+/// Computes the median of Decimal128s with scale s, with scale new_s >= s. The
+/// mean of the two middle values is rounded to nearest even. Returns None if
+/// there are no values.
+#[inline]
+pub fn dec128_median(values: &mut [i128], s: usize, new_s: usize) -> Option<i128> {
+    let n = values.len();
+    if n == 0 {
+        return None;
+    }
+
+    let m = POW10_I128[new_s - s];
+    let (lower, hi, _) = values.select_nth_unstable(n / 2);
+    let hi = *hi;
+    if n % 2 == 1 {
+        return hi.checked_mul(m);
+    }
+
+    // Halve before adding, the sum of two Decimal128s can overflow an i128.
+    let lo = *lower.iter().max().unwrap();
+    let q = lo.div_euclid(2) + hi.div_euclid(2);
+    let r = lo.rem_euclid(2) + hi.rem_euclid(2);
+    if new_s > s {
+        // The multiplier is even, so the half is exact.
+        q.checked_mul(m)?.checked_add(r * (m / 2))
+    } else {
+        Some(q + r / 2 + (r == 1 && q % 2 != 0) as i128)
+    }
+}
+
 /// Checks if two Decimal128s are equal in value.
 #[inline]
 pub fn dec128_eq(mut lv: i128, ls: usize, mut rv: i128, rs: usize) -> bool {
@@ -1172,4 +1252,49 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_rem() {
+        assert_eq!(dec128_rem(75, 20), Some(15));
+        assert_eq!(dec128_rem(-75, 20), Some(5));
+        assert_eq!(dec128_rem(75, -20), Some(-5));
+        assert_eq!(dec128_rem(-75, -20), Some(-15));
+        assert_eq!(dec128_rem(80, -20), Some(0));
+        assert_eq!(dec128_rem(75, 0), None);
+    }
+
+    #[test]
+    fn test_mean() {
+        assert_eq!(dec128_mean_scale(10, 2), 6);
+        assert_eq!(dec128_mean_scale(10, 8), 8);
+        assert_eq!(dec128_mean_scale(38, 2), 2);
+        assert_eq!(dec128_mean_scale(36, 2), 4);
+
+        // 1.01 + 2.02 + 3.04 = 6.07
+        assert_eq!(dec128_mean(607, 3, 2, 6), Some(2023333));
+        assert_eq!(dec128_mean(-607, 3, 2, 6), Some(-2023333));
+        // Ties round to nearest even.
+        assert_eq!(dec128_mean(5, 2, 0, 0), Some(2));
+        assert_eq!(dec128_mean(7, 2, 0, 0), Some(4));
+        assert_eq!(dec128_mean(-5, 2, 0, 0), Some(-2));
+        assert_eq!(dec128_mean(1, 16, 0, 3), Some(62));
+        assert_eq!(dec128_mean(3, 16, 0, 3), Some(188));
+        assert_eq!(dec128_mean(607, 0, 2, 6), None);
+    }
+
+    #[test]
+    fn test_median() {
+        assert_eq!(dec128_median(&mut [303, 101, 202], 2, 2), Some(202));
+        assert_eq!(dec128_median(&mut [303, 101, 202], 2, 6), Some(2020000));
+        assert_eq!(dec128_median(&mut [4, 1, 2, 3], 0, 1), Some(25));
+        // Ties round to nearest even.
+        assert_eq!(dec128_median(&mut [1, 2, 3, 4], 0, 0), Some(2));
+        assert_eq!(dec128_median(&mut [3, 4], 0, 0), Some(4));
+        assert_eq!(dec128_median(&mut [-3, -4], 0, 0), Some(-4));
+        assert_eq!(dec128_median(&mut [-5, 2], 0, 0), Some(-2));
+        // The sum of the middle values doesn't fit in an i128.
+        let max = 10i128.pow(38) - 1;
+        assert_eq!(dec128_median(&mut [max, max - 2], 0, 0), Some(max - 1));
+        assert_eq!(dec128_median(&mut [], 2, 6), None);
+    }
 }
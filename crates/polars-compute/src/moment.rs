@@ -96,6 +96,14 @@ impl VarState {
         self.clear_zero_weight_nan();
     }
 
+    pub fn insert_weighted(&mut self, x: f64, weight: f64) {
+        self.combine(&Self {
+            weight,
+            mean: x,
+            dp: 0.0,
+        })
+    }
+
     pub fn remove_one(&mut self, x: f64) {
         // Just a specialized version of
         // self.combine(&Self { weight: -1.0, mean: x, dp: 0.0 })
@@ -123,6 +131,11 @@ impl VarState {
         self.clear_zero_weight_nan();
     }
 
+    /// The weighted mean, `None` if the total weight is zero.
+    pub fn mean(&self) -> Option<f64> {
+        (self.weight != 0.0).then_some(self.mean)
+    }
+
     pub fn finalize(&self, ddof: u8) -> Option<f64> {
         if self.weight <= ddof as f64 {
             None
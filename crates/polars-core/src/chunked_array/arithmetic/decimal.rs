@@ -1,5 +1,5 @@
 use polars_compute::decimal::{
-    DEC128_MAX_PREC, dec128_add, dec128_div, dec128_mul, dec128_rescale, dec128_sub,
+    DEC128_MAX_PREC, dec128_add, dec128_div, dec128_mul, dec128_rem, dec128_rescale, dec128_sub,
 };
 
 use super::*;
@@ -131,3 +131,34 @@ impl Div for &DecimalChunked {
         Ok(phys?.into_decimal_unchecked(prec, scale))
     }
 }
+
+impl Rem for &DecimalChunked {
+    type Output = PolarsResult<DecimalChunked>;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        let left_s = self.scale();
+        let right_s = rhs.scale();
+        let scale = left_s.max(right_s);
+        let prec = DEC128_MAX_PREC;
+        let phys = broadcast_try_binary_elementwise(
+            self.physical(),
+            rhs.physical(),
+            |opt_l, opt_r| {
+                let (Some(l), Some(r)) = (opt_l, opt_r) else {
+                    return PolarsResult::Ok(None);
+                };
+                if r == 0 {
+                    polars_bail!(ComputeError: "modulo by zero Decimal");
+                }
+                let ls = dec128_rescale(l, left_s, prec, scale).ok_or_else(|| {
+                    polars_err!(ComputeError: "overflow in Decimal cast for {l} from scale {left_s} to {scale}")
+                })?;
+                let rs = dec128_rescale(r, right_s, prec, scale).ok_or_else(|| {
+                    polars_err!(ComputeError: "overflow in Decimal cast for {r} from scale {right_s} to {scale}")
+                })?;
+                Ok(dec128_rem(ls, rs))
+            },
+        );
+        Ok(phys?.into_decimal_unchecked(prec, scale))
+    }
+}
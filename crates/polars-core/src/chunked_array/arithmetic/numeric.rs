@@ -1,10 +1,36 @@
 use polars_compute::arithmetic::ArithmeticKernel;
+use polars_utils::float::IsFloat;
 
 use super::*;
 use crate::chunked_array::arity::{
     apply_binary_kernel_broadcast, apply_binary_kernel_broadcast_owned, unary_kernel,
     unary_kernel_owned,
 };
+use crate::series::IsSorted;
+
+/// The sorted flag of `ca` after adding or subtracting `shift` to all its values. Shifting floats
+/// by a finite value keeps their order as rounding is monotone, integers may wrap around.
+fn shifted_sorted_flag<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    shift: Option<T::Native>,
+) -> IsSorted {
+    match shift {
+        Some(shift) if T::Native::is_float() && shift.is_finite() => ca.is_sorted_flag(),
+        _ => IsSorted::Not,
+    }
+}
+
+/// The sorted flag of `lhs` shifted by `rhs` if `rhs` is broadcast.
+fn broadcast_shifted_sorted_flag<T: PolarsNumericType>(
+    lhs: &ChunkedArray<T>,
+    rhs: &ChunkedArray<T>,
+) -> IsSorted {
+    if rhs.len() == 1 && lhs.len() != 1 {
+        shifted_sorted_flag(lhs, rhs.get(0))
+    } else {
+        IsSorted::Not
+    }
+}
 
 macro_rules! impl_op_overload {
     ($op: ident, $trait_method: ident, $ca_method: ident, $ca_method_scalar: ident) => {
@@ -103,23 +129,32 @@ impl<T: PolarsNumericType> ArithmeticChunked for ChunkedArray<T> {
     }
 
     fn wrapping_add(self, rhs: Self) -> Self::Out {
-        apply_binary_kernel_broadcast_owned(
+        let sorted = match broadcast_shifted_sorted_flag(&self, &rhs) {
+            IsSorted::Not => broadcast_shifted_sorted_flag(&rhs, &self),
+            sorted => sorted,
+        };
+        let mut out = apply_binary_kernel_broadcast_owned(
             self,
             rhs,
             ArithmeticKernel::wrapping_add,
             |l, r| ArithmeticKernel::wrapping_add_scalar(r, l),
             ArithmeticKernel::wrapping_add_scalar,
-        )
+        );
+        out.set_sorted_flag(sorted);
+        out
     }
 
     fn wrapping_sub(self, rhs: Self) -> Self::Out {
-        apply_binary_kernel_broadcast_owned(
+        let sorted = broadcast_shifted_sorted_flag(&self, &rhs);
+        let mut out = apply_binary_kernel_broadcast_owned(
             self,
             rhs,
             ArithmeticKernel::wrapping_sub,
             ArithmeticKernel::wrapping_sub_scalar_lhs,
             ArithmeticKernel::wrapping_sub_scalar,
-        )
+        );
+        out.set_sorted_flag(sorted);
+        out
     }
 
     fn wrapping_mul(self, rhs: Self) -> Self::Out {
@@ -163,11 +198,17 @@ impl<T: PolarsNumericType> ArithmeticChunked for ChunkedArray<T> {
     }
 
     fn wrapping_add_scalar(self, rhs: Self::Scalar) -> Self::Out {
-        unary_kernel_owned(self, |a| ArithmeticKernel::wrapping_add_scalar(a, rhs))
+        let sorted = shifted_sorted_flag(&self, Some(rhs));
+        let mut out = unary_kernel_owned(self, |a| ArithmeticKernel::wrapping_add_scalar(a, rhs));
+        out.set_sorted_flag(sorted);
+        out
     }
 
     fn wrapping_sub_scalar(self, rhs: Self::Scalar) -> Self::Out {
-        unary_kernel_owned(self, |a| ArithmeticKernel::wrapping_sub_scalar(a, rhs))
+        let sorted = shifted_sorted_flag(&self, Some(rhs));
+        let mut out = unary_kernel_owned(self, |a| ArithmeticKernel::wrapping_sub_scalar(a, rhs));
+        out.set_sorted_flag(sorted);
+        out
     }
 
     fn wrapping_sub_scalar_lhs(lhs: Self::Scalar, rhs: Self) -> Self::Out {
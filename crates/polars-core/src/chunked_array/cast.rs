@@ -3,14 +3,19 @@
 use std::borrow::Cow;
 
 use polars_compute::cast::CastOptionsImpl;
+use rayon::prelude::*;
 #[cfg(feature = "serde-lazy")]
 use serde::{Deserialize, Serialize};
 
 use super::flags::StatisticsFlags;
 #[cfg(feature = "dtype-datetime")]
 use crate::prelude::DataType::Datetime;
+use crate::POOL;
 use crate::prelude::*;
-use crate::utils::handle_casting_failures;
+use crate::utils::{handle_casting_failures, split_and_flatten};
+
+/// Minimum length from which parsing strings as numbers is split over the thread pool.
+const PAR_PARSE_THRESHOLD: usize = 1 << 16;
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, Hash, Eq)]
 #[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
@@ -327,6 +332,26 @@ impl ChunkCast for StringChunked {
                     Series::try_from((self.name().clone(), result))
                 },
             },
+            // Parsing numbers is expensive enough to be worth splitting large arrays.
+            dt if dt.is_primitive_numeric()
+                && self.len() >= PAR_PARSE_THRESHOLD
+                && POOL.current_num_threads() > 1 =>
+            {
+                let splits = split_and_flatten(self, POOL.current_num_threads());
+                let mut parts = POOL
+                    .install(|| {
+                        splits
+                            .par_iter()
+                            .map(|ca| cast_impl(self.name().clone(), &ca.chunks, dtype, options))
+                            .collect::<PolarsResult<Vec<_>>>()
+                    })?
+                    .into_iter();
+                let mut out = parts.next().unwrap();
+                for part in parts {
+                    out.append_owned(part)?;
+                }
+                Ok(out)
+            },
             _ => cast_impl(self.name().clone(), &self.chunks, dtype, options),
         }
     }
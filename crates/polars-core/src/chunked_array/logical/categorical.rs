@@ -5,6 +5,7 @@ use num_traits::Zero;
 
 use crate::chunked_array::cast::CastOptions;
 use crate::chunked_array::flags::StatisticsFlags;
+use crate::chunked_array::ops::arity::{try_unary_elementwise, unary_elementwise};
 use crate::chunked_array::ops::ChunkFullNull;
 use crate::prelude::*;
 use crate::series::IsSorted;
@@ -297,3 +298,288 @@ impl<T: PolarsCategoricalType> LogicalType for CategoricalChunked<T> {
         }
     }
 }
+
+/// Re-encoding of categorical columns into new mappings.
+///
+/// These create a new [`Categories`] with a deterministic category order rather than relying on
+/// a shared (global) mapping, which makes combining categoricals from different sources fast and
+/// reproducible. They are only supported for [`DataType::Categorical`], as the physical type of
+/// a [`DataType::Enum`] depends on its number of categories.
+impl<T: PolarsCategoricalType> CategoricalChunked<T> {
+    fn categories(&self, op: &str) -> PolarsResult<&Arc<Categories>> {
+        match self.dtype() {
+            DataType::Categorical(cats, _) => Ok(cats),
+            dt => polars_bail!(InvalidOperation: "`{op}` is not supported for dtype {dt}"),
+        }
+    }
+
+    /// The categories of the mapping, in order of their category id.
+    fn mapping_iter_str(&self) -> impl Iterator<Item = &str> {
+        let mapping = self.get_mapping();
+        (0..mapping.num_cats_upper_bound()).filter_map(|cat| mapping.cat_to_str(cat as CatSize))
+    }
+
+    /// Create new [`Categories`] like `cats` where the nth string of `categories` gets category
+    /// id n.
+    fn new_categories<'a>(
+        cats: &Categories,
+        categories: impl IntoIterator<Item = &'a str>,
+    ) -> PolarsResult<Arc<Categories>> {
+        let new_cats = Categories::random(cats.namespace().clone(), cats.physical());
+        let new_mapping = new_cats.mapping();
+        for s in categories {
+            let cat = new_mapping.insert_cat(s)?;
+            polars_ensure!(
+                cat as usize + 1 == new_mapping.num_cats_upper_bound(),
+                Duplicate: "category '{s}' occurs more than once"
+            );
+        }
+        Ok(new_cats)
+    }
+
+    /// Re-encode the category ids into the mapping of `new_cats`.
+    ///
+    /// Errors if a category in use is missing from `new_cats`.
+    fn remap_into(&self, new_cats: Arc<Categories>) -> PolarsResult<Self> {
+        let new_mapping = new_cats.mapping();
+        let mapping = self.get_mapping();
+        let remap: Vec<Option<T::Native>> = (0..mapping.num_cats_upper_bound())
+            .map(|cat| {
+                let s = mapping.cat_to_str(cat as CatSize)?;
+                new_mapping.get_cat(s).map(T::Native::from_cat)
+            })
+            .collect();
+
+        let phys = try_unary_elementwise(self.physical(), |opt_cat| {
+            let Some(cat) = opt_cat else {
+                return Ok(None);
+            };
+            match remap.get(cat.as_cat() as usize).copied().flatten() {
+                Some(new_cat) => Ok(Some(new_cat)),
+                None => polars_bail!(
+                    InvalidOperation: "category '{}' is missing from the new categories",
+                    mapping.cat_to_str(cat.as_cat()).unwrap_or_default()
+                ),
+            }
+        })?;
+        let dtype = DataType::from_categories(new_cats);
+        Ok(unsafe { Self::from_cats_and_dtype_unchecked(phys, dtype) })
+    }
+
+    /// Re-encode `self` and `other` into a single new mapping.
+    ///
+    /// The new mapping contains the categories of `self` followed by the categories of `other`
+    /// not in `self`, both in order of their category id, so the result does not depend on any
+    /// other columns using the same mappings. If both already share their categories they are
+    /// returned as-is.
+    pub fn merge_mappings(&self, other: &Self) -> PolarsResult<(Self, Self)> {
+        let cats = self.categories("merge_mappings")?;
+        let other_cats = other.categories("merge_mappings")?;
+        if Arc::ptr_eq(cats, other_cats) {
+            return Ok((self.clone(), other.clone()));
+        }
+        polars_ensure!(
+            cats.physical() == other_cats.physical(),
+            SchemaMismatch: "cannot merge categorical mappings with different physical types, \
+            left: {}, right: {}", cats.physical().as_str(), other_cats.physical().as_str()
+        );
+
+        let mut seen = PlHashSet::new();
+        let categories: Vec<&str> = self
+            .mapping_iter_str()
+            .chain(other.mapping_iter_str())
+            .filter(|s| seen.insert(*s))
+            .collect();
+
+        let new_cats = Self::new_categories(cats, categories)?;
+        Ok((self.remap_into(new_cats.clone())?, other.remap_into(new_cats)?))
+    }
+
+    /// Re-encode into a new mapping where the nth string of `categories` gets category id n.
+    ///
+    /// Errors if `categories` contains duplicates or misses a category that is in use.
+    pub fn remap_categories<'a>(
+        &self,
+        categories: impl IntoIterator<Item = &'a str>,
+    ) -> PolarsResult<Self> {
+        let cats = self.categories("remap_categories")?;
+        self.remap_into(Self::new_categories(cats, categories)?)
+    }
+
+    /// Re-encode into a new mapping only containing the categories in use, e.g. after filtering.
+    ///
+    /// The categories keep their relative order.
+    pub fn compact_categories(&self) -> PolarsResult<Self> {
+        let cats = self.categories("compact_categories")?;
+        let mapping = self.get_mapping();
+        let mut used = vec![false; mapping.num_cats_upper_bound()];
+        for cat in self.physical().iter().flatten() {
+            used[cat.as_cat() as usize] = true;
+        }
+        let categories = used
+            .iter()
+            .enumerate()
+            .filter(|(_, used)| **used)
+            .filter_map(|(cat, _)| mapping.cat_to_str(cat as CatSize));
+        self.remap_into(Self::new_categories(cats, categories)?)
+    }
+}
+
+/// Category evolution of Enums.
+///
+/// These rewrite the [`DataType::Enum`] and remap the category ids directly instead of casting
+/// through strings. As the physical type of an Enum depends on its number of categories, they
+/// return a [`Series`].
+impl<T: PolarsCategoricalType> CategoricalChunked<T> {
+    fn frozen_categories(&self, op: &str) -> PolarsResult<&Arc<FrozenCategories>> {
+        match self.dtype() {
+            DataType::Enum(fcats, _) => Ok(fcats),
+            dt => polars_bail!(InvalidOperation: "`{op}` is only supported for Enum, got {dt}"),
+        }
+    }
+
+    /// Re-encode the category ids into `fcats`, which must contain all categories in use.
+    fn remap_into_enum(&self, fcats: Arc<FrozenCategories>) -> Series {
+        let mapping = self.get_mapping();
+        let remap: Vec<Option<CatSize>> = (0..mapping.num_cats_upper_bound())
+            .map(|cat| fcats.mapping().get_cat(mapping.cat_to_str(cat as CatSize)?))
+            .collect();
+
+        with_match_categorical_physical_type!(fcats.physical(), |$C| {
+            let phys = unary_elementwise(self.physical(), |opt_cat: Option<T::Native>| {
+                let cat = remap[opt_cat?.as_cat() as usize].unwrap();
+                Some(<$C as PolarsCategoricalType>::Native::from_cat(cat))
+            });
+            let dtype = DataType::from_frozen_categories(fcats);
+            unsafe { CategoricalChunked::<$C>::from_cats_and_dtype_unchecked(phys, dtype) }
+                .into_series()
+        })
+    }
+
+    /// Append the strings of `categories` which are not a category yet to the Enum.
+    ///
+    /// The existing category ids are kept.
+    pub fn extend_categories<'a>(
+        &self,
+        categories: impl IntoIterator<Item = &'a str>,
+    ) -> PolarsResult<Series> {
+        let fcats = self.frozen_categories("extend_categories")?;
+        let new_fcats = fcats.with_added_categories(categories)?;
+        if new_fcats.physical() == T::physical() {
+            // The category ids are unchanged, only the dtype has to be replaced.
+            let dtype = DataType::from_frozen_categories(new_fcats);
+            let phys = self.physical().clone();
+            return Ok(unsafe { Self::from_cats_and_dtype_unchecked(phys, dtype) }.into_series());
+        }
+        Ok(self.remap_into_enum(new_fcats))
+    }
+
+    /// Cast to the Enum of `categories`, appending the categories of `self` which are missing
+    /// from it instead of turning their values into nulls.
+    pub fn cast_with_added_categories<'a>(
+        &self,
+        categories: impl IntoIterator<Item = &'a str>,
+    ) -> PolarsResult<Series> {
+        self.frozen_categories("cast_with_added_categories")?;
+        let target = FrozenCategories::new(categories)?;
+        let new_fcats = target.with_added_categories(self.mapping_iter_str())?;
+        Ok(self.remap_into_enum(new_fcats))
+    }
+
+    /// Remove the categories which are not in use from the Enum, e.g. after filtering.
+    ///
+    /// The remaining categories keep their relative order.
+    pub fn remove_unused_categories(&self) -> PolarsResult<Series> {
+        self.frozen_categories("remove_unused_categories")?;
+        let mapping = self.get_mapping();
+        let mut used = vec![false; mapping.num_cats_upper_bound()];
+        for cat in self.physical().iter().flatten() {
+            used[cat.as_cat() as usize] = true;
+        }
+        let new_fcats = FrozenCategories::new(
+            used.iter()
+                .enumerate()
+                .filter(|(_, used)| **used)
+                .filter_map(|(cat, _)| mapping.cat_to_str(cat as CatSize)),
+        )?;
+        Ok(self.remap_into_enum(new_fcats))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    fn categorical(values: &[Option<&str>]) -> PolarsResult<Categorical8Chunked> {
+        let cats = Categories::random(PlSmallStr::EMPTY, CategoricalPhysical::U8);
+        let dtype = DataType::from_categories(cats);
+        Categorical8Chunked::from_str_iter(PlSmallStr::EMPTY, dtype, values.iter().copied())
+    }
+
+    fn cat_ids(ca: &Categorical8Chunked) -> Vec<Option<u8>> {
+        ca.physical().iter().collect()
+    }
+
+    #[test]
+    fn test_merge_mappings() -> PolarsResult<()> {
+        let a = categorical(&[Some("b"), None, Some("a")])?;
+        let b = categorical(&[Some("c"), Some("a")])?;
+
+        let (a, b) = a.merge_mappings(&b)?;
+        assert_eq!(a.dtype(), b.dtype());
+        assert_eq!(cat_ids(&a), &[Some(0), None, Some(1)]);
+        assert_eq!(cat_ids(&b), &[Some(2), Some(1)]);
+        assert_eq!(b.iter_str().collect::<Vec<_>>(), &[Some("c"), Some("a")]);
+
+        let (c, d) = a.merge_mappings(&b)?;
+        assert_eq!(c.dtype(), a.dtype());
+        assert_eq!(d.dtype(), b.dtype());
+        Ok(())
+    }
+
+    #[test]
+    fn test_remap_and_compact_categories() -> PolarsResult<()> {
+        let ca = categorical(&[Some("x"), Some("y"), None, Some("z")])?;
+
+        let remapped = ca.remap_categories(["z", "x", "y", "w"])?;
+        assert_eq!(cat_ids(&remapped), &[Some(1), Some(2), None, Some(0)]);
+        assert!(ca.remap_categories(["x", "y"]).is_err());
+        assert!(ca.remap_categories(["x", "y", "z", "x"]).is_err());
+
+        let mask = BooleanChunked::from_slice(PlSmallStr::EMPTY, &[false, true, true, true]);
+        let filtered = remapped.into_series().filter(&mask)?;
+        let compacted = filtered.cat8()?.compact_categories()?;
+        assert_eq!(cat_ids(&compacted), &[Some(1), None, Some(0)]);
+        assert_eq!(compacted.get_mapping().num_cats_upper_bound(), 2);
+        Ok(())
+    }
+
+    fn enum_categories(s: &Series) -> Vec<&str> {
+        let DataType::Enum(fcats, _) = s.dtype() else {
+            panic!("expected an Enum")
+        };
+        fcats.categories().values_iter().collect()
+    }
+
+    #[test]
+    fn test_enum_category_evolution() -> PolarsResult<()> {
+        let dtype = DataType::from_frozen_categories(FrozenCategories::new(["a", "b", "c"])?);
+        let s = Series::new(PlSmallStr::EMPTY, [Some("c"), None, Some("a")]).cast(&dtype)?;
+        let ca = s.cat8()?;
+
+        let extended = ca.extend_categories(["d", "a", "d"])?;
+        assert_eq!(enum_categories(&extended), &["a", "b", "c", "d"]);
+        assert_eq!(cat_ids(extended.cat8()?), &[Some(2), None, Some(0)]);
+
+        let cast = ca.cast_with_added_categories(["c", "x"])?;
+        assert_eq!(enum_categories(&cast), &["c", "x", "a", "b"]);
+        assert_eq!(cat_ids(cast.cat8()?), &[Some(0), None, Some(2)]);
+
+        let compacted = ca.remove_unused_categories()?;
+        assert_eq!(enum_categories(&compacted), &["a", "c"]);
+        assert_eq!(cat_ids(compacted.cat8()?), &[Some(1), None, Some(0)]);
+
+        assert!(categorical(&[Some("a")])?.remove_unused_categories().is_err());
+        Ok(())
+    }
+}
@@ -67,8 +67,23 @@ impl ExtensionChunked {
     pub fn cast_with_options(
         &self,
         dtype: &DataType,
-        _options: CastOptions,
+        options: CastOptions,
     ) -> PolarsResult<Series> {
-        polars_bail!(ComputeError: "cannot cast extension types to {dtype:?}")
+        let typ = self.extension_type();
+        if let DataType::Extension(to_typ, to_storage) = dtype {
+            polars_ensure!(
+                typ.can_cast_to(dtype) || to_typ.can_cast_from(&self.dtype),
+                InvalidOperation: "cannot cast extension type {} to {}", typ, to_typ
+            );
+            let storage = self.storage.cast_with_options(to_storage, options)?;
+            to_typ.validate(&storage)?;
+            return Ok(storage.into_extension(to_typ.clone()));
+        }
+
+        polars_ensure!(
+            dtype == self.storage.dtype() || typ.can_cast_to(dtype),
+            InvalidOperation: "cannot cast extension type {} to {}", typ, dtype
+        );
+        self.storage.cast_with_options(dtype, options)
     }
 }
@@ -48,12 +48,15 @@ mod struct_;
 pub mod temporal;
 mod to_vec;
 mod trusted_len;
+mod zone_map;
 
 use arrow::legacy::prelude::*;
 #[cfg(feature = "dtype-struct")]
 pub use struct_::StructChunked;
+pub use zone_map::ChunkZoneMap;
 
 use self::flags::{StatisticsFlags, StatisticsFlagsIM};
+use self::zone_map::ZoneMapCache;
 use crate::series::IsSorted;
 use crate::utils::{first_non_null, first_null, last_non_null};
 
@@ -141,6 +144,7 @@ pub struct ChunkedArray<T: PolarsDataType> {
     pub(crate) chunks: Vec<ArrayRef>,
 
     pub(crate) flags: StatisticsFlagsIM,
+    zone_maps: ZoneMapCache,
 
     length: usize,
     null_count: usize,
@@ -205,6 +209,7 @@ impl<T: PolarsDataType> ChunkedArray<T> {
             field,
             chunks,
             flags: StatisticsFlagsIM::empty(),
+            zone_maps: ZoneMapCache::default(),
 
             _pd: Default::default(),
             length,
@@ -471,6 +476,7 @@ impl<T: PolarsDataType> ChunkedArray<T> {
     /// And the `null_count` remains correct.
     #[inline]
     pub unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.zone_maps.reset();
         &mut self.chunks
     }
 
@@ -965,6 +971,7 @@ impl<T: PolarsDataType> Clone for ChunkedArray<T> {
             field: self.field.clone(),
             chunks: self.chunks.clone(),
             flags: self.flags.clone(),
+            zone_maps: self.zone_maps.clone(),
 
             _pd: Default::default(),
             length: self.length,
@@ -1038,6 +1045,7 @@ impl<T: PolarsDataType> Default for ChunkedArray<T> {
             // Invariant: always has 1 chunk.
             chunks: vec![new_empty_array(arrow_dtype)],
             flags: StatisticsFlagsIM::empty(),
+            zone_maps: ZoneMapCache::default(),
 
             _pd: Default::default(),
             length: 0,
@@ -147,6 +147,7 @@ impl<T: PolarsDataType> ChunkedArray<T> {
             }
         }
         let len = inner(&self.chunks);
+        self.zone_maps.reset();
         // Length limit is `IdxSize::MAX - 1`. We use `IdxSize::MAX` to indicate `NULL` in indexing.
         if len >= (IdxSize::MAX as usize) && CHECK_LENGTH.get() {
             panic!("{}", LENGTH_LIMIT_MSG);
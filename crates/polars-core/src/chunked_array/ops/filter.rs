@@ -1,5 +1,6 @@
 use polars_compute::filter::filter as filter_fn;
 
+use crate::chunked_array::flags::StatisticsFlags;
 #[cfg(feature = "object")]
 use crate::chunked_array::object::builder::ObjectChunkedBuilder;
 use crate::prelude::*;
@@ -27,7 +28,7 @@ where
             };
         }
         check_filter_len!(self, filter);
-        Ok(unsafe {
+        let mut out = unsafe {
             arity::binary_unchecked_same_type(
                 self,
                 filter,
@@ -35,7 +36,10 @@ where
                 true,
                 true,
             )
-        })
+        };
+        // Dropping elements keeps the order of the remaining ones.
+        out.retain_flags_from(self, StatisticsFlags::IS_SORTED_ANY);
+        Ok(out)
     }
 }
 
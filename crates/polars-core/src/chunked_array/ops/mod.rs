@@ -91,6 +91,20 @@ pub struct ExplodeOptions {
     pub keep_nulls: bool,
 }
 
+/// What to do when the lists of the columns exploded together differ in length in a row.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ExplodeLengthMismatch {
+    /// Raise an error.
+    #[default]
+    Error,
+    /// Pad the shorter lists with nulls to the length of the longest list.
+    PadWithNull,
+    /// Truncate the longer lists to the length of the shortest list.
+    Truncate,
+}
+
 /// Explode/flatten a List or String Series
 pub trait ChunkExplode {
     fn explode(&self, options: ExplodeOptions) -> PolarsResult<Series> {
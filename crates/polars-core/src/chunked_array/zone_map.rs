@@ -0,0 +1,233 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow::array::{BinaryViewArrayGeneric, PrimitiveArray, ViewType};
+use arrow::bitmap::Bitmap;
+use polars_compute::min_max::MinMaxKernel;
+
+use crate::prelude::*;
+
+/// The minimum, maximum and null count of the values of a single chunk.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChunkZoneMap {
+    /// The smallest value, or null if the chunk only has nulls.
+    pub min: AnyValue<'static>,
+    /// The largest value, or null if the chunk only has nulls. NaN is larger than all other values.
+    pub max: AnyValue<'static>,
+    pub null_count: usize,
+}
+
+/// Identifies the buffers of a chunk by their address and length.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct ChunkFingerprint {
+    values: usize,
+    len: usize,
+    validity: Option<(usize, usize, usize)>,
+}
+
+impl ChunkFingerprint {
+    fn from_parts(values: usize, len: usize, validity: Option<&Bitmap>) -> Self {
+        let validity = validity.map(|validity| {
+            let (bytes, offset, len) = validity.as_slice();
+            (bytes.as_ptr() as usize, offset, len)
+        });
+        Self {
+            values,
+            len,
+            validity,
+        }
+    }
+
+    fn new<T: NumericNative>(arr: &PrimitiveArray<T>) -> Self {
+        Self::from_parts(arr.values().as_ptr() as usize, arr.len(), arr.validity())
+    }
+
+    fn of_views<V: ViewType + ?Sized>(arr: &BinaryViewArrayGeneric<V>) -> Self {
+        Self::from_parts(arr.views().as_ptr() as usize, arr.len(), arr.validity())
+    }
+}
+
+struct CachedZoneMaps {
+    /// The fingerprints of the chunks the zone maps were computed for.
+    fingerprints: Vec<ChunkFingerprint>,
+    zone_maps: Arc<[ChunkZoneMap]>,
+}
+
+struct CachedDistinctCount {
+    /// The fingerprints of the chunks the estimate was computed for.
+    fingerprints: Vec<ChunkFingerprint>,
+    estimate: usize,
+}
+
+/// The lazily computed zone maps and distinct count estimate of a [`ChunkedArray`], shared with
+/// its clones.
+///
+/// Nothing is allocated until the statistics are requested. The cache only refers to the chunks by
+/// their fingerprints, so it doesn't keep their buffers alive or prevent in-place mutation. It is
+/// reset on mutable access to the chunks, as their values may change in place.
+#[derive(Clone, Default)]
+pub(crate) struct ZoneMapCache {
+    zone_maps: OnceLock<Arc<CachedZoneMaps>>,
+    n_distinct: OnceLock<Arc<CachedDistinctCount>>,
+}
+
+impl ZoneMapCache {
+    pub(crate) fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// The number of values of a chunk that are sampled to estimate its number of distinct values.
+const DISTINCT_SAMPLE_SIZE: usize = 1024;
+
+/// Estimate the number of distinct values of a chunk, including null, from an evenly spaced
+/// sample of its values. If the sample has few distinct values, the chunk is unlikely to have any
+/// others, otherwise the number of distinct values is extrapolated from the sample.
+fn estimate_n_distinct<V: ViewType + ?Sized>(arr: &BinaryViewArrayGeneric<V>) -> usize {
+    let value = |i| arr.is_valid(i).then(|| arr.value(i).to_bytes());
+    if arr.len() <= DISTINCT_SAMPLE_SIZE {
+        return (0..arr.len()).map(value).collect::<PlHashSet<_>>().len();
+    }
+    let step = arr.len() / DISTINCT_SAMPLE_SIZE;
+    let sample = (0..arr.len()).step_by(step).take(DISTINCT_SAMPLE_SIZE);
+    let n_distinct = sample.map(value).collect::<PlHashSet<_>>().len();
+    if n_distinct * 16 <= DISTINCT_SAMPLE_SIZE {
+        n_distinct
+    } else {
+        n_distinct * arr.len() / DISTINCT_SAMPLE_SIZE
+    }
+}
+
+fn zone_map<T>(arr: &PrimitiveArray<T>) -> ChunkZoneMap
+where
+    T: NumericNative,
+    PrimitiveArray<T>: for<'a> MinMaxKernel<Scalar<'a> = T>,
+{
+    // A NaN is the largest value, but it doesn't make for a useful minimum.
+    let min = arr.min_ignore_nan_kernel();
+    let max = arr.max_propagate_nan_kernel();
+    ChunkZoneMap {
+        min: min.or(max).map_or(AnyValue::Null, Into::into),
+        max: max.map_or(AnyValue::Null, Into::into),
+        null_count: arr.null_count(),
+    }
+}
+
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    PrimitiveArray<T::Native>: for<'a> MinMaxKernel<Scalar<'a> = T::Native>,
+{
+    /// The zone maps of the chunks of this array, to skip chunks that can't match a predicate.
+    ///
+    /// The zone maps are computed on first use and cached until the chunks change.
+    pub fn chunk_zone_maps(&self) -> Arc<[ChunkZoneMap]> {
+        let fingerprints = self
+            .downcast_iter()
+            .map(ChunkFingerprint::new)
+            .collect::<Vec<_>>();
+        let compute = || {
+            Arc::new(CachedZoneMaps {
+                zone_maps: self.downcast_iter().map(zone_map).collect(),
+                fingerprints: fingerprints.clone(),
+            })
+        };
+        let cached = self.zone_maps.zone_maps.get_or_init(compute);
+        if cached.fingerprints == fingerprints {
+            cached.zone_maps.clone()
+        } else {
+            // The chunks were replaced without resetting the cache.
+            compute().zone_maps.clone()
+        }
+    }
+}
+
+impl StringChunked {
+    /// An estimate of the number of distinct values, including null, from a sample of the values
+    /// of every chunk. As the chunks are sampled separately, values that occur in several chunks
+    /// are counted once per chunk.
+    ///
+    /// The estimate is computed on first use and cached until the chunks change.
+    pub fn n_distinct_estimate(&self) -> usize {
+        let fingerprints = self
+            .downcast_iter()
+            .map(ChunkFingerprint::of_views)
+            .collect::<Vec<_>>();
+        let compute = || {
+            let estimate = self.downcast_iter().map(estimate_n_distinct).sum::<usize>();
+            Arc::new(CachedDistinctCount {
+                estimate: estimate.min(self.len()),
+                fingerprints: fingerprints.clone(),
+            })
+        };
+        let cached = self.zone_maps.n_distinct.get_or_init(compute);
+        if cached.fingerprints == fingerprints {
+            cached.estimate
+        } else {
+            // The chunks were replaced without resetting the cache.
+            compute().estimate
+        }
+    }
+}
+
+impl Series {
+    /// The zone maps of the chunks of a numeric or temporal [`Series`], with the values of the
+    /// physical type. See [`ChunkedArray::chunk_zone_maps`].
+    pub fn chunk_zone_maps(&self) -> Option<Arc<[ChunkZoneMap]>> {
+        let physical = self.to_physical_repr();
+        if !physical.dtype().is_primitive_numeric() {
+            return None;
+        }
+        Some(with_match_physical_numeric_polars_type!(physical.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = physical.as_ref().as_ref().as_ref();
+            ca.chunk_zone_maps()
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_chunk_zone_maps() -> PolarsResult<()> {
+        let mut ca = Float64Chunked::new("a".into(), [Some(3.0), None, Some(1.0)]);
+        ca.append(&Float64Chunked::new("a".into(), [f64::NAN, 2.0]))?;
+
+        let zone_maps = ca.chunk_zone_maps();
+        assert_eq!(
+            zone_maps[0],
+            ChunkZoneMap {
+                min: AnyValue::Float64(1.0),
+                max: AnyValue::Float64(3.0),
+                null_count: 1,
+            }
+        );
+        assert_eq!(zone_maps[1].min, AnyValue::Float64(2.0));
+        assert!(matches!(zone_maps[1].max, AnyValue::Float64(v) if v.is_nan()));
+        // The clones share the cache.
+        assert!(Arc::ptr_eq(&zone_maps, &ca.clone().chunk_zone_maps()));
+
+        // The cache doesn't prevent mutating the values in place.
+        let values = ca.downcast_iter().next().unwrap().values().as_ptr();
+        ca.apply_mut(|v| v + 10.0);
+        assert_eq!(ca.downcast_iter().next().unwrap().values().as_ptr(), values);
+        assert_eq!(ca.chunk_zone_maps()[0].max, AnyValue::Float64(13.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_n_distinct_estimate() {
+        let few = (0..10_000)
+            .map(|i| (i % 7 != 0).then(|| format!("key{}", i % 5)))
+            .collect::<StringChunked>();
+        assert_eq!(few.n_distinct_estimate(), 6);
+
+        let many = (0..10_000)
+            .map(|i| Some(format!("key{i}")))
+            .collect::<StringChunked>();
+        assert_eq!(many.n_distinct_estimate(), 10_000);
+
+        let small = StringChunked::new("a".into(), [Some("a"), None, Some("a"), Some("b")]);
+        assert_eq!(small.n_distinct_estimate(), 3);
+    }
+}
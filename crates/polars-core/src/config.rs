@@ -31,6 +31,31 @@ pub fn verbose() -> bool {
     std::env::var("POLARS_VERBOSE").as_deref().unwrap_or("") == "1"
 }
 
+/// Whether filters report the rows they drop because their predicate evaluated to null. The report
+/// is printed to stderr, so this also requires verbose mode.
+pub fn audit_null_filters() -> bool {
+    std::env::var("POLARS_AUDIT_NULL_FILTERS").as_deref() == Ok("1") && verbose()
+}
+
+/// Frames with fewer rows than this are filtered, projected, grouped and sorted by the in-memory
+/// engine on the calling thread, without splitting them or dispatching work to the thread pool.
+/// Joins and the streaming engine are not affected. Disabled (0) by default.
+pub fn small_batch_threshold() -> usize {
+    std::env::var("POLARS_SMALL_BATCH_THRESHOLD")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0)
+}
+
+/// The number of times the files of a scan may be retried in total after a transient (I/O) error,
+/// before the scan fails. Disabled (0) by default.
+pub fn scan_retry_budget() -> usize {
+    std::env::var("POLARS_SCAN_RETRY_BUDGET")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0)
+}
+
 pub fn get_engine_affinity() -> String {
     std::env::var("POLARS_ENGINE_AFFINITY").unwrap_or_else(|_| "auto".to_string())
 }
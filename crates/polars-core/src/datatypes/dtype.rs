@@ -4,6 +4,7 @@ use arrow::datatypes::{
     DTYPE_CATEGORICAL_NEW, DTYPE_ENUM_VALUES_LEGACY, DTYPE_ENUM_VALUES_NEW, MAINTAIN_PL_TYPE,
     Metadata, PL_KEY,
 };
+use polars_error::ErrorDetail;
 #[cfg(feature = "dtype-array")]
 use polars_utils::format_tuple;
 use polars_utils::itertools::Itertools;
@@ -463,6 +464,63 @@ impl DataType {
         })
     }
 
+    /// Return whether every value of this type can be cast to `to` without losing information,
+    /// e.g. `Int32` to `Int64`, but not `Int64` to `Float64`.
+    pub fn is_lossless_cast_to(&self, to: &DataType) -> bool {
+        use DataType as D;
+
+        // The number of bits of the values, excluding the sign.
+        fn precision(dtype: &DataType) -> Option<u32> {
+            Some(match dtype {
+                D::Int8 => 7,
+                D::Int16 => 15,
+                D::Int32 => 31,
+                D::Int64 => 63,
+                D::Int128 => 127,
+                D::UInt8 => 8,
+                D::UInt16 => 16,
+                D::UInt32 => 32,
+                D::UInt64 => 64,
+                D::UInt128 => 128,
+                D::Float16 => 11,
+                D::Float32 => 24,
+                D::Float64 => 53,
+                _ => return None,
+            })
+        }
+
+        if self == to || self.is_null() {
+            return true;
+        }
+        match (self, to) {
+            (D::Boolean, to) => precision(to).is_some(),
+            (from, to) if from.is_float() => to.is_float() && precision(to) >= precision(from),
+            (from, to) if from.is_integer() => {
+                let signs_compatible = !from.is_signed_integer() || !to.is_unsigned_integer();
+                precision(from).is_some_and(|p| Some(p) <= precision(to)) && signs_compatible
+            },
+            #[cfg(feature = "dtype-decimal")]
+            (D::Decimal(p1, s1), D::Decimal(p2, s2)) => s2 >= s1 && p2 - s2 >= p1 - s1,
+            #[cfg(all(feature = "dtype-date", feature = "dtype-datetime"))]
+            (D::Date, D::Datetime(tu, _)) => *tu != TimeUnit::Nanoseconds,
+            #[cfg(feature = "dtype-categorical")]
+            (D::Categorical(_, _) | D::Enum(_, _), D::String) => true,
+            (D::List(from), D::List(to)) => from.is_lossless_cast_to(to),
+            #[cfg(feature = "dtype-array")]
+            (D::Array(from, l_width), D::Array(to, r_width)) => {
+                l_width == r_width && from.is_lossless_cast_to(to)
+            },
+            #[cfg(feature = "dtype-struct")]
+            (D::Struct(l_fields), D::Struct(r_fields)) => {
+                l_fields.len() == r_fields.len()
+                    && l_fields.iter().zip(r_fields).all(|(l, r)| {
+                        l.name() == r.name() && l.dtype().is_lossless_cast_to(r.dtype())
+                    })
+            },
+            _ => false,
+        }
+    }
+
     pub fn implode(self) -> DataType {
         DataType::List(Box::new(self))
     }
@@ -1063,9 +1121,14 @@ impl DataType {
             },
 
             (l, r) if l == r => Ok(false),
-            (l, r) => {
-                polars_bail!(SchemaMismatch: "type {:?} is incompatible with expected type {:?}", l, r)
-            },
+            (l, r) => Err(polars_err!(
+                SchemaMismatch: "type {:?} is incompatible with expected type {:?}", l, r
+            )
+            .with_detail(ErrorDetail::SchemaMismatch {
+                column: None,
+                expected: r.to_string(),
+                found: l.to_string(),
+            })),
         }
     }
 
@@ -0,0 +1,89 @@
+use std::any::Any;
+use std::borrow::Cow;
+use std::hash::{BuildHasher, Hash, Hasher};
+
+use polars_utils::aliases::PlFixedStateQuality;
+
+use super::{ExtensionTypeFactory, ExtensionTypeImpl, ExtensionTypeInstance};
+use crate::datatypes::DataType;
+
+/// Name of the geometry extension type, compatible with the GeoArrow WKB extension type.
+pub const GEOMETRY_EXTENSION_NAME: &str = "geoarrow.wkb";
+
+/// Geometries stored as WKB (well-known binary) in a binary column.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct GeometryType {
+    /// GeoArrow metadata, e.g. the coordinate reference system, serialized as JSON.
+    metadata: Option<String>,
+}
+
+impl GeometryType {
+    pub fn new(metadata: Option<String>) -> Self {
+        Self { metadata }
+    }
+}
+
+impl ExtensionTypeImpl for GeometryType {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(GEOMETRY_EXTENSION_NAME)
+    }
+
+    fn serialize_metadata(&self) -> Option<Cow<'_, str>> {
+        self.metadata.as_deref().map(Cow::Borrowed)
+    }
+
+    fn dyn_clone(&self) -> Box<dyn ExtensionTypeImpl> {
+        Box::new(self.clone())
+    }
+
+    fn dyn_eq(&self, other: &dyn ExtensionTypeImpl) -> bool {
+        (other as &dyn Any)
+            .downcast_ref::<GeometryType>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn dyn_hash(&self) -> u64 {
+        let mut hasher = PlFixedStateQuality::default().build_hasher();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn dyn_display(&self) -> Cow<'_, str> {
+        Cow::Borrowed("geometry")
+    }
+
+    fn dyn_debug(&self) -> Cow<'_, str> {
+        match &self.metadata {
+            Some(md) => Cow::Owned(format!("Geometry(metadata='{md}')")),
+            None => Cow::Borrowed("Geometry"),
+        }
+    }
+}
+
+pub(super) struct GeometryTypeFactory;
+
+impl ExtensionTypeFactory for GeometryTypeFactory {
+    fn create_type_instance(
+        &self,
+        _name: &str,
+        _storage: &DataType,
+        metadata: Option<&str>,
+    ) -> Box<dyn ExtensionTypeImpl> {
+        Box::new(GeometryType::new(metadata.map(|s| s.to_string())))
+    }
+}
+
+impl DataType {
+    /// The geometry extension type without metadata.
+    pub fn geometry() -> Self {
+        DataType::Extension(
+            ExtensionTypeInstance(Box::new(GeometryType::default())),
+            Box::new(DataType::Binary),
+        )
+    }
+
+    /// Check if this is the geometry extension type.
+    pub fn is_geometry(&self) -> bool {
+        matches!(self, DataType::Extension(typ, _) if typ.name() == GEOMETRY_EXTENSION_NAME)
+    }
+}
@@ -0,0 +1,149 @@
+use std::sync::{Arc, LazyLock, RwLock};
+
+use hashbrown::hash_map::Entry;
+use polars_error::{PolarsResult, polars_bail, polars_err};
+use polars_utils::aliases::{InitHashMaps, PlHashMap};
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::datatypes::DataType;
+use crate::series::Series;
+
+/// A binary operation that can be implemented for extension types.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExtensionBinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Eq,
+    EqMissing,
+    NotEq,
+    NotEqMissing,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+/// The implementation of an [`ExtensionBinaryOp`] for an extension type.
+pub trait ExtensionBinaryKernel: 'static + Send + Sync {
+    /// The dtype of the result of the operation on operands of the given dtypes, at least one of
+    /// which is the extension type the kernel is registered for.
+    ///
+    /// Comparisons must return `Boolean`.
+    fn output_dtype(&self, lhs: &DataType, rhs: &DataType) -> PolarsResult<DataType>;
+
+    /// Apply the operation. The operands have the same length, or one of them has length 1 and
+    /// is broadcast.
+    fn call(&self, lhs: &Series, rhs: &Series) -> PolarsResult<Series>;
+}
+
+#[allow(clippy::type_complexity)]
+static KERNELS: LazyLock<
+    RwLock<PlHashMap<(PlSmallStr, ExtensionBinaryOp), Arc<dyn ExtensionBinaryKernel>>>,
+> = LazyLock::new(|| RwLock::new(PlHashMap::new()));
+
+/// Register the kernel of the operation `op` for the extension type with name `name`.
+///
+/// The kernel is used whenever the left operand is of this extension type, or the right operand
+/// is while the left operand has no kernel of its own for `op`.
+pub fn register_extension_binary_kernel(
+    name: &str,
+    op: ExtensionBinaryOp,
+    kernel: Arc<dyn ExtensionBinaryKernel>,
+) -> PolarsResult<()> {
+    match KERNELS.write().unwrap().entry((name.into(), op)) {
+        Entry::Occupied(_) => {
+            polars_bail!(ComputeError: "attempted to register duplicate {op:?} kernel for extension type with name '{name}'")
+        },
+        Entry::Vacant(v) => {
+            v.insert(kernel);
+            Ok(())
+        },
+    }
+}
+
+pub fn unregister_extension_binary_kernel(
+    name: &str,
+    op: ExtensionBinaryOp,
+) -> PolarsResult<Arc<dyn ExtensionBinaryKernel>> {
+    KERNELS.write().unwrap().remove(&(name.into(), op)).ok_or_else(||
+        polars_err!(ComputeError: "attempted to unregister unknown {op:?} kernel for extension type with name '{name}'")
+    )
+}
+
+/// Returns the kernel of `op` for operands of the given dtypes, preferring the kernel of the
+/// left operand's extension type.
+pub fn get_extension_binary_kernel(
+    lhs: &DataType,
+    rhs: &DataType,
+    op: ExtensionBinaryOp,
+) -> Option<Arc<dyn ExtensionBinaryKernel>> {
+    let names = [lhs, rhs].map(|dtype| match dtype {
+        DataType::Extension(typ, _) => Some(typ.name()),
+        _ => None,
+    });
+    if names.iter().all(Option::is_none) {
+        return None;
+    }
+
+    let kernels = KERNELS.read().unwrap();
+    names
+        .into_iter()
+        .flatten()
+        .find_map(|name| kernels.get(&(PlSmallStr::from_str(&name), op)).cloned())
+}
+
+/// Apply the registered kernel of `op`, or returns `None` if there is none for these operands.
+pub(crate) fn apply_extension_binary_kernel(
+    lhs: &Series,
+    rhs: &Series,
+    op: ExtensionBinaryOp,
+) -> Option<PolarsResult<Series>> {
+    let kernel = get_extension_binary_kernel(lhs.dtype(), rhs.dtype(), op)?;
+    Some(kernel.call(lhs, rhs).map(|out| out.with_name(lhs.name().clone())))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::extension::get_extension_type_or_generic;
+    use crate::prelude::*;
+
+    /// Adds the storages and keeps the extension type of the left operand.
+    struct AddStorage;
+
+    impl ExtensionBinaryKernel for AddStorage {
+        fn output_dtype(&self, lhs: &DataType, _rhs: &DataType) -> PolarsResult<DataType> {
+            Ok(lhs.clone())
+        }
+
+        fn call(&self, lhs: &Series, rhs: &Series) -> PolarsResult<Series> {
+            let DataType::Extension(typ, _) = lhs.dtype() else {
+                unreachable!()
+            };
+            let out = (lhs.to_storage() + rhs.to_storage())?;
+            Ok(out.into_extension(typ.clone()))
+        }
+    }
+
+    #[test]
+    fn test_extension_binary_kernel() -> PolarsResult<()> {
+        let typ = get_extension_type_or_generic("test.kernel", &DataType::Int64, None);
+        let s = Series::new("a".into(), [1i64, 2]).into_extension(typ);
+        assert!((&s + &s).is_err());
+
+        let op = ExtensionBinaryOp::Add;
+        register_extension_binary_kernel("test.kernel", op, Arc::new(AddStorage))?;
+        assert!(register_extension_binary_kernel("test.kernel", op, Arc::new(AddStorage)).is_err());
+        let out = (&s + &s)?;
+        assert_eq!(out.dtype(), s.dtype());
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(Vec::from(out.to_storage().i64()?), &[Some(2), Some(4)]);
+
+        unregister_extension_binary_kernel("test.kernel", op)?;
+        assert!((&s + &s).is_err());
+        Ok(())
+    }
+}
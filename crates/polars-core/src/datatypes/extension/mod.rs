@@ -3,12 +3,25 @@ use std::borrow::Cow;
 use std::fmt::{Debug, Display};
 use std::hash::{Hash, Hasher};
 
+use polars_error::PolarsResult;
+
 use crate::datatypes::DataType;
+use crate::series::Series;
 
 mod generic;
+#[cfg(feature = "geo")]
+mod geometry;
+mod kernels;
 mod registry;
 
 use generic::GenericExtensionType;
+#[cfg(feature = "geo")]
+pub use geometry::{GEOMETRY_EXTENSION_NAME, GeometryType};
+pub(crate) use kernels::apply_extension_binary_kernel;
+pub use kernels::{
+    ExtensionBinaryKernel, ExtensionBinaryOp, get_extension_binary_kernel,
+    register_extension_binary_kernel, unregister_extension_binary_kernel,
+};
 pub use registry::{
     UnknownExtensionTypeBehavior, get_extension_type_or_generic, get_extension_type_or_storage,
     register_extension_type, set_unknown_extension_type_behavior, unregister_extension_type,
@@ -44,6 +57,23 @@ pub trait ExtensionTypeImpl: 'static + Send + Sync + Any {
     /// Should be a more verbose string representation, useful for debugging, in TitleCase,
     /// for example: String, Decimal(10, 2).
     fn dyn_debug(&self) -> Cow<'_, str>;
+
+    /// Validate the storage values of a Series that is converted to this extension type.
+    fn validate(&self, _storage: &Series) -> PolarsResult<()> {
+        Ok(())
+    }
+
+    /// Whether a Series of `dtype` can be cast to this extension type. The values are cast to
+    /// the storage type and validated. Casting from the storage type is always allowed.
+    fn can_cast_from(&self, _dtype: &DataType) -> bool {
+        false
+    }
+
+    /// Whether this extension type can be cast to `dtype` by casting its storage. Casting to the
+    /// storage type is always allowed.
+    fn can_cast_to(&self, _dtype: &DataType) -> bool {
+        false
+    }
 }
 
 #[repr(transparent)]
@@ -90,4 +120,16 @@ impl ExtensionTypeInstance {
     pub fn serialize_metadata(&self) -> Option<Cow<'_, str>> {
         self.0.serialize_metadata()
     }
+
+    pub fn validate(&self, storage: &Series) -> PolarsResult<()> {
+        self.0.validate(storage)
+    }
+
+    pub fn can_cast_from(&self, dtype: &DataType) -> bool {
+        self.0.can_cast_from(dtype)
+    }
+
+    pub fn can_cast_to(&self, dtype: &DataType) -> bool {
+        self.0.can_cast_to(dtype)
+    }
 }
@@ -89,6 +89,11 @@ static REGISTRY: LazyLock<RwLock<PlHashMap<PlSmallStr, Option<Arc<dyn ExtensionT
     LazyLock::new(|| {
         let mut m = PlHashMap::new();
         m.insert(PlSmallStr::from_static(POLARS_OBJECT_EXTENSION_NAME), None);
+        #[cfg(feature = "geo")]
+        m.insert(
+            PlSmallStr::from_static(super::GEOMETRY_EXTENSION_NAME),
+            Some(Arc::new(super::geometry::GeometryTypeFactory) as _),
+        );
         RwLock::new(m)
     });
 
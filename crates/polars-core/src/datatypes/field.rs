@@ -163,8 +163,18 @@ impl DataType {
         Self::from_arrow(dt, None)
     }
 
+    /// Convert an arrow data type, panicking if it is not supported.
     pub fn from_arrow(dt: &ArrowDataType, md: Option<&Metadata>) -> DataType {
-        match dt {
+        Self::try_from_arrow(dt, md).unwrap()
+    }
+
+    pub fn try_from_arrow_field(field: &ArrowField) -> PolarsResult<DataType> {
+        Self::try_from_arrow(&field.dtype, field.metadata.as_deref())
+    }
+
+    /// Convert an arrow data type, erroring if it is not supported.
+    pub fn try_from_arrow(dt: &ArrowDataType, md: Option<&Metadata>) -> PolarsResult<DataType> {
+        Ok(match dt {
             ArrowDataType::Null => DataType::Null,
             ArrowDataType::UInt8 => DataType::UInt8,
             ArrowDataType::UInt16 => DataType::UInt16,
@@ -185,10 +195,10 @@ impl DataType {
             ArrowDataType::Float64 => DataType::Float64,
             #[cfg(feature = "dtype-array")]
             ArrowDataType::FixedSizeList(f, size) => {
-                DataType::Array(DataType::from_arrow_field(f).boxed(), *size)
+                DataType::Array(DataType::try_from_arrow_field(f)?.boxed(), *size)
             },
             ArrowDataType::LargeList(f) | ArrowDataType::List(f) => {
-                DataType::List(DataType::from_arrow_field(f).boxed())
+                DataType::List(DataType::try_from_arrow_field(f)?.boxed())
             },
             ArrowDataType::Date32 => DataType::Date,
             ArrowDataType::Timestamp(tu, tz) => {
@@ -245,13 +255,19 @@ impl DataType {
                 ) {
                     DataType::from_categories(Categories::global())
                 } else {
-                    Self::from_arrow(value_type, None)
+                    Self::try_from_arrow(value_type, None)?
                 }
             },
 
             #[cfg(feature = "dtype-struct")]
             ArrowDataType::Struct(fields) => {
-                DataType::Struct(fields.iter().map(|fld| fld.into()).collect())
+                let fields = fields
+                    .iter()
+                    .map(|fld| {
+                        Ok(Field::new(fld.name.clone(), Self::try_from_arrow_field(fld)?))
+                    })
+                    .collect::<PolarsResult<_>>()?;
+                DataType::Struct(fields)
             },
             #[cfg(not(feature = "dtype-struct"))]
             ArrowDataType::Struct(_) => {
@@ -270,7 +286,7 @@ impl DataType {
             #[cfg(feature = "dtype-extension")]
             ArrowDataType::Extension(ext) => {
                 use crate::prelude::extension::get_extension_type_or_storage;
-                let storage = DataType::from_arrow(&ext.inner, md);
+                let storage = DataType::try_from_arrow(&ext.inner, md)?;
                 match get_extension_type_or_storage(&ext.name, &storage, ext.metadata.as_deref()) {
                     Some(typ) => DataType::Extension(typ, Box::new(storage)),
                     None => storage,
@@ -278,6 +294,21 @@ impl DataType {
             },
             #[cfg(feature = "dtype-decimal")]
             ArrowDataType::Decimal(precision, scale) => DataType::Decimal(*precision, *scale),
+            // Decimals are backed by i128, so only a Decimal256 of at most 38 digits can be loaded.
+            #[cfg(feature = "dtype-decimal")]
+            ArrowDataType::Decimal256(precision, scale)
+                if *precision <= polars_compute::decimal::DEC128_MAX_PREC =>
+            {
+                DataType::Decimal(*precision, *scale)
+            },
+            #[cfg(feature = "dtype-decimal")]
+            ArrowDataType::Decimal256(precision, _) => {
+                use polars_compute::decimal::DEC128_MAX_PREC;
+                polars_bail!(
+                    InvalidOperation: "Decimal256 with precision {precision} is not supported \
+                    by Polars, decimals have at most {DEC128_MAX_PREC} digits"
+                )
+            },
             ArrowDataType::Utf8View | ArrowDataType::LargeUtf8 | ArrowDataType::Utf8 => {
                 DataType::String
             },
@@ -293,21 +324,21 @@ impl DataType {
             ArrowDataType::LargeBinary | ArrowDataType::Binary => DataType::Binary,
             ArrowDataType::FixedSizeBinary(_) => DataType::Binary,
             ArrowDataType::Map(inner, _is_sorted) => {
-                DataType::List(Self::from_arrow_field(inner).boxed())
+                DataType::List(Self::try_from_arrow_field(inner)?.boxed())
             },
             ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
-                check_allow_importing_interval_as_struct("month_day_nano_interval").unwrap();
+                check_allow_importing_interval_as_struct("month_day_nano_interval")?;
                 feature_gated!("dtype-struct", DataType::_month_days_ns_struct_type())
             },
             ArrowDataType::Interval(IntervalUnit::MonthDayMillis) => {
-                check_allow_importing_interval_as_struct("month_day_millisecond_interval").unwrap();
+                check_allow_importing_interval_as_struct("month_day_millisecond_interval")?;
                 feature_gated!("dtype-struct", DataType::_month_days_ns_struct_type())
             },
-            dt => panic!(
-                "Arrow datatype {dt:?} not supported by Polars. \
+            dt => polars_bail!(
+                InvalidOperation: "Arrow datatype {dt:?} not supported by Polars. \
                 You probably need to activate that data-type feature."
             ),
-        }
+        })
     }
 }
 
@@ -135,7 +135,7 @@ fn get_ellipsis() -> &'static str {
     "…"
 }
 
-fn estimate_string_width(s: &str) -> usize {
+pub(crate) fn estimate_string_width(s: &str) -> usize {
     // get a slightly more accurate estimate of a string's screen
     // width, accounting (very roughly) for multibyte characters
     let n_chars = s.chars().count();
@@ -840,7 +840,7 @@ impl Display for DataFrame {
     }
 }
 
-fn fmt_int_string_custom(num: &str, group_size: u8, group_separator: &str) -> String {
+pub(crate) fn fmt_int_string_custom(num: &str, group_size: u8, group_separator: &str) -> String {
     if group_size == 0 || num.len() <= 1 {
         num.to_string()
     } else {
@@ -867,7 +867,7 @@ fn fmt_int_string(num: &str) -> String {
     fmt_int_string_custom(num, 3, &get_thousands_separator())
 }
 
-fn fmt_float_string_custom(
+pub(crate) fn fmt_float_string_custom(
     num: &str,
     group_size: u8,
     group_separator: &str,
@@ -0,0 +1,163 @@
+use arrow::array::StructArray;
+use arrow::ffi::{ArrowArrayStream, export_iterator};
+
+use crate::prelude::*;
+
+/// Options for [`DataFrame::into_arrow_c_stream`].
+#[derive(Copy, Clone, Debug)]
+pub struct ArrowStreamOptions {
+    pub compat_level: CompatLevel,
+    /// Split the batches at the chunk boundaries of all columns, such that columns with
+    /// unaligned chunks are exported by slicing their chunks. If `false`, unaligned chunks are an
+    /// error.
+    pub align_chunks: bool,
+}
+
+impl Default for ArrowStreamOptions {
+    fn default() -> Self {
+        Self {
+            compat_level: CompatLevel::newest(),
+            align_chunks: true,
+        }
+    }
+}
+
+/// Produces the batches of the stream on request.
+struct BatchIter {
+    columns: Vec<Column>,
+    dtype: ArrowDataType,
+    compat_level: CompatLevel,
+    /// The row offsets at which the batches end.
+    ends: std::vec::IntoIter<usize>,
+    offset: usize,
+}
+
+impl Iterator for BatchIter {
+    type Item = PolarsResult<Box<dyn Array>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let end = self.ends.next()?;
+        let length = end - self.offset;
+        // The batch lies within a single chunk of every column, so slicing doesn't copy.
+        let arrays = self
+            .columns
+            .iter()
+            .map(|c| {
+                c.slice(self.offset as i64, length)
+                    .as_materialized_series()
+                    .to_arrow(0, self.compat_level)
+            })
+            .collect();
+        self.offset = end;
+        let array = StructArray::new(self.dtype.clone(), length, arrays, None);
+        Some(Ok(Box::new(array)))
+    }
+}
+
+impl DataFrame {
+    /// Export the [`DataFrame`] through the [Arrow C stream interface], one batch per chunk.
+    ///
+    /// The chunks are exported as they are produced by the consumer of the stream, without
+    /// rechunking. With the newest [`CompatLevel`], most types are exported without copying.
+    ///
+    /// [Arrow C stream interface]: https://arrow.apache.org/docs/format/CStreamInterface.html
+    pub fn into_arrow_c_stream(
+        self,
+        options: ArrowStreamOptions,
+    ) -> PolarsResult<ArrowArrayStream> {
+        let chunk_ends = |s: &Series| {
+            let mut ends = s
+                .chunk_lengths()
+                .scan(0, |end, len| {
+                    *end += len;
+                    Some(*end)
+                })
+                .collect::<Vec<_>>();
+            // Empty chunks don't need a batch.
+            ends.dedup();
+            ends.retain(|end| *end > 0);
+            ends
+        };
+
+        let mut ends: Option<Vec<usize>> = None;
+        let mut aligned = true;
+        for s in self.columns().iter().filter_map(Column::as_series) {
+            let column_ends = chunk_ends(s);
+            match &mut ends {
+                None => ends = Some(column_ends),
+                Some(ends) if *ends != column_ends => {
+                    aligned = false;
+                    ends.extend(column_ends);
+                    ends.sort_unstable();
+                    ends.dedup();
+                },
+                Some(_) => {},
+            }
+        }
+        polars_ensure!(
+            aligned || options.align_chunks,
+            ComputeError: "the chunks of the columns are not aligned, set `align_chunks` to \
+            export them"
+        );
+        // Frames of only scalar columns are exported as a single batch.
+        let ends = match ends {
+            Some(ends) => ends,
+            None if self.height() > 0 => vec![self.height()],
+            None => vec![],
+        };
+
+        let dtype = ArrowDataType::Struct(
+            self.schema()
+                .to_arrow(options.compat_level)
+                .into_iter_values()
+                .collect(),
+        );
+        let field = ArrowField::new(PlSmallStr::EMPTY, dtype.clone(), false);
+        let iter = BatchIter {
+            columns: self.into_columns(),
+            dtype,
+            compat_level: options.compat_level,
+            ends: ends.into_iter(),
+            offset: 0,
+        };
+        Ok(export_iterator(Box::new(iter), field))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::ffi::ArrowArrayStreamReader;
+
+    use super::*;
+
+    #[test]
+    fn test_into_arrow_c_stream() -> PolarsResult<()> {
+        let mut a = Series::new("a".into(), [1, 2]);
+        a.append(&Series::new("a".into(), [3]))?;
+        let mut b = Series::new("b".into(), ["x"]);
+        b.append(&Series::new("b".into(), ["y", "z"]))?;
+        let c = Column::new_scalar("c".into(), Scalar::from(1.5), 3);
+        let df = DataFrame::new(3, vec![a.into(), b.into(), c])?;
+
+        assert!(
+            df.clone()
+                .into_arrow_c_stream(ArrowStreamOptions {
+                    align_chunks: false,
+                    ..Default::default()
+                })
+                .is_err()
+        );
+
+        let stream = Box::new(df.into_arrow_c_stream(Default::default())?);
+        let mut reader = unsafe { ArrowArrayStreamReader::try_new(stream)? };
+        let mut lengths = vec![];
+        while let Some(array) = unsafe { reader.next() } {
+            let array = array?;
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            assert_eq!(array.values().len(), 3);
+            lengths.push(array.len());
+        }
+        assert_eq!(lengths, [1, 1, 1]);
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use polars_utils::format_pl_smallstr;
+
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+/// How [`DataFrame::cast_with_policy`] handles casts that may lose information.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CastPolicy {
+    /// Only allow casts that never lose information, such as `Int32` to `Int64`. Other casts
+    /// raise an error, regardless of the values.
+    Upcast,
+    /// Raise an error if any value cannot be cast.
+    #[default]
+    Strict,
+    /// Replace values that cannot be cast with null.
+    NonStrict,
+    /// Replace values that cannot be cast with null, and count them per column in a report.
+    Report,
+}
+
+impl CastPolicy {
+    /// Check that the cast of `name` from `from` to `to` is allowed by the policy.
+    pub fn check(&self, name: &str, from: &DataType, to: &DataType) -> PolarsResult<()> {
+        polars_ensure!(
+            *self != CastPolicy::Upcast || from.is_lossless_cast_to(to),
+            InvalidOperation: "casting column '{}' from {} to {} may lose information, which is \
+            not allowed with the `Upcast` policy", name, from, to
+        );
+        Ok(())
+    }
+
+    /// The [`CastOptions`] of the casts of the policy.
+    pub fn cast_options(&self) -> CastOptions {
+        match self {
+            CastPolicy::Upcast | CastPolicy::Strict => CastOptions::Strict,
+            CastPolicy::NonStrict | CastPolicy::Report => CastOptions::NonStrict,
+        }
+    }
+}
+
+impl DataFrame {
+    /// Cast the columns to the types of the columns with the same name in `schema`, handling
+    /// values that cannot be cast according to `policy`.
+    ///
+    /// Columns that are not in `schema` are left as is. With [`CastPolicy::Report`], a report
+    /// is returned as well, with a row per column that changed type: its name, the `from` and
+    /// `to` types, and the number of `lossy_rows` that became null.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("a" => ["1", "2", "x"])?;
+    /// let schema = Schema::from_iter([Field::new("a".into(), DataType::Int64)]);
+    /// let (out, report) = df.cast_with_policy(&schema, CastPolicy::Report)?;
+    /// assert_eq!(out.column("a")?.null_count(), 1);
+    /// assert_eq!(report.unwrap().column("lossy_rows")?.get(0)?, AnyValue::from(1 as IdxSize));
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn cast_with_policy(
+        &self,
+        schema: &Schema,
+        policy: CastPolicy,
+    ) -> PolarsResult<(DataFrame, Option<DataFrame>)> {
+        let mut df = self.clone();
+        let mut names = vec![];
+        let mut from = vec![];
+        let mut to = vec![];
+        let mut lossy_rows = vec![];
+        for (name, dtype) in schema.iter() {
+            let idx = self.try_get_column_index(name)?;
+            let c = &self.columns()[idx];
+            if c.dtype() == dtype {
+                continue;
+            }
+            policy.check(name, c.dtype(), dtype)?;
+            let out = c.cast_with_options(dtype, policy.cast_options())?;
+
+            names.push(name.clone());
+            from.push(format_pl_smallstr!("{}", c.dtype()));
+            to.push(format_pl_smallstr!("{}", dtype));
+            lossy_rows.push((out.null_count() - c.null_count()) as IdxSize);
+            df.replace_column(idx, out)?;
+        }
+
+        let report = (policy == CastPolicy::Report)
+            .then(|| {
+                DataFrame::new(
+                    names.len(),
+                    vec![
+                        StringChunked::from_iter_values("column".into(), names.iter())
+                            .into_column(),
+                        StringChunked::from_iter_values("from".into(), from.iter()).into_column(),
+                        StringChunked::from_iter_values("to".into(), to.iter()).into_column(),
+                        IdxCa::from_vec("lossy_rows".into(), lossy_rows).into_column(),
+                    ],
+                )
+            })
+            .transpose()?;
+        Ok((df, report))
+    }
+}
@@ -0,0 +1,116 @@
+use polars_utils::format_pl_smallstr;
+
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+/// The names of the statistics computed by [`DataFrame::describe`], in order.
+pub fn describe_statistics(percentiles: &[f64]) -> PolarsResult<Vec<PlSmallStr>> {
+    for p in percentiles {
+        polars_ensure!(
+            (0.0..=1.0).contains(p),
+            InvalidOperation: "percentiles must be between 0 and 1, got {}", p
+        );
+    }
+    let mut statistics = ["count", "null_count", "mean", "std", "min"]
+        .into_iter()
+        .map(PlSmallStr::from_static)
+        .collect::<Vec<_>>();
+    // Round away floating point noise, such that 0.1 is shown as `10%`.
+    statistics.extend(
+        percentiles
+            .iter()
+            .map(|p| format_pl_smallstr!("{}%", (p * 1e8).round() / 1e6)),
+    );
+    statistics.push(PlSmallStr::from_static("max"));
+    Ok(statistics)
+}
+
+/// The type of the statistics of a column of type `dtype` in the output of
+/// [`DataFrame::describe`].
+pub fn describe_dtype(dtype: &DataType) -> DataType {
+    if dtype.is_numeric() || dtype.is_nested() || dtype.is_null() || dtype.is_bool() {
+        DataType::Float64
+    } else {
+        DataType::String
+    }
+}
+
+fn describe_column(c: &Column, percentiles: &[f64]) -> PolarsResult<Column> {
+    let s = c.as_materialized_series();
+    let dtype = s.dtype();
+    let is_numeric = dtype.is_numeric();
+    let is_temporal = dtype.is_temporal();
+    let skip_min_max = dtype.is_nested()
+        || dtype.is_categorical()
+        || dtype.is_enum()
+        || dtype.is_null()
+        || dtype.is_object();
+    let null = || Scalar::null(DataType::Null);
+
+    let mut stats = vec![
+        Scalar::new_idxsize((s.len() - s.null_count()) as IdxSize),
+        Scalar::new_idxsize(s.null_count() as IdxSize),
+        if is_numeric || is_temporal || dtype.is_bool() {
+            s.mean_reduce()?
+        } else {
+            null()
+        },
+        if is_numeric { s.std_reduce(1)? } else { null() },
+        if skip_min_max { null() } else { s.min_reduce()? },
+    ];
+    for &p in percentiles {
+        stats.push(if is_numeric {
+            s.quantile_reduce(p, QuantileMethod::Nearest)?
+        } else if is_temporal {
+            s.to_physical_repr()
+                .quantile_reduce(p, QuantileMethod::Nearest)?
+                .cast_with_options(dtype, CastOptions::NonStrict)?
+        } else {
+            null()
+        });
+    }
+    stats.push(if skip_min_max { null() } else { s.max_reduce()? });
+
+    let out_dtype = describe_dtype(dtype);
+    let values = stats
+        .into_iter()
+        .map(|stat| Ok(stat.cast_with_options(&out_dtype, CastOptions::NonStrict)?.into_value()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(Series::from_any_values_and_dtype(s.name().clone(), &values, &out_dtype, true)?.into())
+}
+
+impl DataFrame {
+    /// Summary statistics of the columns: the count, null count, mean, standard deviation,
+    /// minimum, the given `percentiles` and maximum.
+    ///
+    /// Statistics that do not apply to the type of a column are null. The statistics of numeric,
+    /// boolean, nested and null columns are returned as `Float64`, those of other columns as
+    /// `String`. Percentiles are computed with [`QuantileMethod::Nearest`] and should be between
+    /// 0 and 1, e.g. `&[0.25, 0.5, 0.75]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("a" => [1.0, 2.0, 3.0], "b" => ["x", "y", "z"])?;
+    /// let out = df.describe(&[0.5])?;
+    /// assert_eq!(out.column("a")?.get(5)?, AnyValue::Float64(2.0));
+    /// assert_eq!(out.column("b")?.get(6)?, AnyValue::String("z"));
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn describe(&self, percentiles: &[f64]) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            self.width() > 0,
+            InvalidOperation: "cannot describe a DataFrame that has no columns"
+        );
+        let statistics = describe_statistics(percentiles)?;
+        let mut columns = Vec::with_capacity(self.width() + 1);
+        columns.push(
+            StringChunked::from_iter_values("statistic".into(), statistics.iter()).into_column(),
+        );
+        for c in self.columns() {
+            columns.push(describe_column(c, percentiles)?);
+        }
+        DataFrame::new(statistics.len(), columns)
+    }
+}
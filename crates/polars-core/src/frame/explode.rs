@@ -1,4 +1,5 @@
-use arrow::offset::OffsetsBuffer;
+use arrow::bitmap::Bitmap;
+use arrow::offset::{Offsets, OffsetsBuffer};
 use polars_utils::pl_str::PlSmallStr;
 use rayon::prelude::*;
 #[cfg(feature = "serde")]
@@ -21,6 +22,83 @@ fn get_exploded(
     }
 }
 
+/// Resize the lists in every row of `columns` to the longest or shortest list of that row.
+///
+/// A row is only null if it is null in all columns, otherwise null lists count as empty.
+fn align_list_lengths(
+    columns: &[Column],
+    mismatch: ExplodeLengthMismatch,
+) -> PolarsResult<Vec<Column>> {
+    let lists = columns
+        .iter()
+        .map(|c| {
+            let s = c.as_materialized_series();
+            let s = match s.dtype() {
+                #[cfg(feature = "dtype-array")]
+                DataType::Array(inner, _) => s.cast(&DataType::List(inner.clone()))?,
+                _ => s.clone(),
+            };
+            Ok(s.list()?.rechunk().into_owned())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let arrays = lists
+        .iter()
+        .map(|ca| ca.downcast_as_array())
+        .collect::<Vec<_>>();
+
+    let height = lists[0].len();
+    let row_len = |arr: &LargeListArray, i: usize| {
+        if arr.is_valid(i) {
+            arr.offsets().length_at(i)
+        } else {
+            0
+        }
+    };
+    let lengths = (0..height)
+        .map(|i| {
+            let row_lengths = arrays.iter().map(|arr| row_len(arr, i));
+            match mismatch {
+                ExplodeLengthMismatch::PadWithNull => row_lengths.max(),
+                _ => row_lengths.min(),
+            }
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let offsets: OffsetsBuffer<i64> = Offsets::try_from_lengths(lengths.iter().copied())?.into();
+    let validity = (0..height)
+        .map(|i| arrays.iter().any(|arr| arr.is_valid(i)))
+        .collect::<Bitmap>();
+    let validity = (validity.unset_bits() > 0).then_some(validity);
+
+    lists
+        .iter()
+        .zip(&arrays)
+        .map(|(ca, arr)| {
+            let idx = (0..height)
+                .flat_map(|i| {
+                    let (start, _) = arr.offsets().start_end(i);
+                    let len = row_len(arr, i);
+                    (0..lengths[i]).map(move |j| (j < len).then_some((start + j) as IdxSize))
+                })
+                .collect::<IdxCa>();
+            let values = ca.get_inner().take(&idx)?.rechunk();
+
+            let dtype = LargeListArray::default_datatype(
+                values.dtype().to_physical().to_arrow(CompatLevel::newest()),
+            );
+            let arr = LargeListArray::new(
+                dtype,
+                offsets.clone(),
+                values.chunks()[0].clone(),
+                validity.clone(),
+            );
+            let mut out = ListChunked::with_chunk(ca.name().clone(), arr);
+            out.set_inner_dtype(ca.inner_dtype().clone());
+            Ok(out.into_series().into_column())
+        })
+        .collect()
+}
+
 /// Arguments for `LazyFrame::unpivot` function
 #[derive(Clone, Default, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -62,6 +140,7 @@ impl DataFrame {
         &self,
         mut columns: Vec<Column>,
         options: ExplodeOptions,
+        mismatch: ExplodeLengthMismatch,
     ) -> PolarsResult<DataFrame> {
         polars_ensure!(!columns.is_empty(), InvalidOperation: "no columns provided in explode");
         let mut df = self.clone();
@@ -73,6 +152,9 @@ impl DataFrame {
         }
 
         columns.sort_by_key(|c| self.try_get_column_index(c.name()).unwrap());
+        if mismatch != ExplodeLengthMismatch::Error && columns.len() > 1 {
+            columns = align_list_lengths(&columns, mismatch)?;
+        }
 
         // first remove all the exploded columns
         for s in &columns {
@@ -224,7 +306,23 @@ impl DataFrame {
         // We need to sort the column by order of original occurrence. Otherwise the insert by index
         // below will panic
         let columns = self.select_to_vec(columns)?;
-        self.explode_impl(columns, options)
+        self.explode_impl(columns, options, ExplodeLengthMismatch::Error)
+    }
+
+    /// Explode `DataFrame` to long format like [`DataFrame::explode`], with `mismatch` deciding
+    /// what happens to rows in which the lists of the exploded columns differ in length.
+    pub fn explode_with_mismatch<I, S>(
+        &self,
+        columns: I,
+        options: ExplodeOptions,
+        mismatch: ExplodeLengthMismatch,
+    ) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let columns = self.select_to_vec(columns)?;
+        self.explode_impl(columns, options, mismatch)
     }
 }
 
@@ -365,4 +463,48 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_explode_length_mismatch() -> PolarsResult<()> {
+        let a: ListChunked = [
+            Some(Series::new(PlSmallStr::EMPTY, &[1i32, 2])),
+            Some(Series::new(PlSmallStr::EMPTY, &[3i32])),
+            None,
+        ]
+        .into_iter()
+        .collect();
+        let b = Column::new(
+            PlSmallStr::from_static("b"),
+            &[
+                Series::new(PlSmallStr::EMPTY, &[10i32]),
+                Series::new(PlSmallStr::EMPTY, &[20i32, 30]),
+                Series::new(PlSmallStr::EMPTY, &[40i32]),
+            ],
+        );
+        let a = a.with_name(PlSmallStr::from_static("a")).into_column();
+        let df = DataFrame::new_infer_height(vec![a, b])?;
+        let options = ExplodeOptions {
+            empty_as_null: true,
+            keep_nulls: true,
+        };
+
+        assert!(df.explode(["a", "b"], options).is_err());
+
+        let out =
+            df.explode_with_mismatch(["a", "b"], options, ExplodeLengthMismatch::PadWithNull)?;
+        let expected = df![
+            "a" => [Some(1i32), Some(2), Some(3), None, None],
+            "b" => [Some(10i32), None, Some(20), Some(30), Some(40)],
+        ]?;
+        assert!(out.equals_missing(&expected));
+
+        let out = df.explode_with_mismatch(["a", "b"], options, ExplodeLengthMismatch::Truncate)?;
+        let expected = df![
+            "a" => [Some(1i32), Some(3), None],
+            "b" => [Some(10i32), Some(20), None],
+        ]?;
+        assert!(out.equals_missing(&expected));
+        Ok(())
+    }
 }
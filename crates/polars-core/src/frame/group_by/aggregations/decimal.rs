@@ -0,0 +1,118 @@
+use polars_compute::decimal::{
+    DEC128_MAX_PREC, dec128_fits, dec128_mean, dec128_mean_scale, dec128_median,
+};
+
+use super::*;
+
+/// Applies `f` to the values of every group in parallel.
+///
+/// # Safety
+/// No bounds checks on `groups`.
+unsafe fn apply_groups<O, F>(ca: &Int128Chunked, groups: &GroupsType, f: F) -> Vec<O>
+where
+    O: Send,
+    F: Fn(&mut dyn Iterator<Item = Option<i128>>) -> O + Send + Sync,
+{
+    let ca = ca.rechunk();
+    let arr = ca.downcast_as_array();
+    POOL.install(|| match groups {
+        GroupsType::Idx(groups) => groups
+            .into_par_iter()
+            .map(|(_, idx)| {
+                f(&mut idx.iter().map(|i| unsafe { arr.get_unchecked(*i as usize) }))
+            })
+            .collect(),
+        GroupsType::Slice { groups, .. } => groups
+            .par_iter()
+            .map(|[first, len]| {
+                let (first, len) = (*first as usize, *len as usize);
+                f(&mut (first..first + len).map(|i| unsafe { arr.get_unchecked(i) }))
+            })
+            .collect(),
+    })
+}
+
+/// The sum and the number of valid values, or `None` if the sum overflows the maximum precision.
+fn checked_sum(values: &mut dyn Iterator<Item = Option<i128>>) -> Option<(i128, usize)> {
+    let (sum, count) = values
+        .flatten()
+        .try_fold((0i128, 0usize), |(sum, count), v| {
+            Some((sum.checked_add(v)?, count + 1))
+        })?;
+    dec128_fits(sum, DEC128_MAX_PREC).then_some((sum, count))
+}
+
+/// The sums and counts of the groups, erroring on overflow if `strict`.
+///
+/// # Safety
+/// No bounds checks on `groups`.
+unsafe fn sums_and_counts(
+    ca: &DecimalChunked,
+    groups: &GroupsType,
+    strict: bool,
+) -> PolarsResult<Vec<Option<(i128, usize)>>> {
+    let sums = unsafe { apply_groups(ca.physical(), groups, checked_sum) };
+    polars_ensure!(
+        !strict || sums.iter().all(Option::is_some),
+        ComputeError: "overflow in decimal sum"
+    );
+    Ok(sums)
+}
+
+impl DecimalChunked {
+    /// Sums the groups exactly, with the maximum precision. If `strict`, errors if a sum
+    /// overflows it, otherwise the overflowing sums are null.
+    ///
+    /// # Safety
+    /// No bounds checks on `groups`.
+    pub unsafe fn agg_sum_checked(
+        &self,
+        groups: &GroupsType,
+        strict: bool,
+    ) -> PolarsResult<DecimalChunked> {
+        let sums = unsafe { sums_and_counts(self, groups, strict)? };
+        let ca: Int128Chunked = sums
+            .into_iter()
+            .map(|s| s.map(|(sum, _)| sum))
+            .collect_ca(self.name().clone());
+        Ok(ca.into_decimal_unchecked(DEC128_MAX_PREC, self.scale()))
+    }
+
+    /// The means of the groups, with the scale of [`dec128_mean_scale`]. If `strict`, errors if
+    /// a sum overflows the maximum precision, otherwise the means of those groups are null.
+    ///
+    /// # Safety
+    /// No bounds checks on `groups`.
+    pub unsafe fn agg_mean_checked(
+        &self,
+        groups: &GroupsType,
+        strict: bool,
+    ) -> PolarsResult<DecimalChunked> {
+        let scale = self.scale();
+        let mean_scale = dec128_mean_scale(self.precision(), scale);
+        let sums = unsafe { sums_and_counts(self, groups, strict)? };
+        let ca: Int128Chunked = sums
+            .into_iter()
+            .map(|s| s.and_then(|(sum, count)| dec128_mean(sum, count, scale, mean_scale)))
+            .collect_ca(self.name().clone());
+        Ok(ca.into_decimal_unchecked(DEC128_MAX_PREC, mean_scale))
+    }
+
+    /// The medians of the groups, computed on the integers with the scale of
+    /// [`dec128_mean_scale`].
+    ///
+    /// # Safety
+    /// No bounds checks on `groups`.
+    pub unsafe fn agg_median_exact(&self, groups: &GroupsType) -> DecimalChunked {
+        let scale = self.scale();
+        let median_scale = dec128_mean_scale(self.precision(), scale);
+        let medians = unsafe {
+            apply_groups(self.physical(), groups, |values| {
+                let mut values = values.flatten().collect::<Vec<_>>();
+                dec128_median(&mut values, scale, median_scale)
+            })
+        };
+        let ca: Int128Chunked = medians.into_iter().collect_ca(self.name().clone());
+        ca.into_decimal_unchecked(DEC128_MAX_PREC, median_scale)
+    }
+}
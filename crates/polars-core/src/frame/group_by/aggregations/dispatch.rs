@@ -215,7 +215,13 @@ impl Series {
             Float64 => SeriesWrap(s.f64().unwrap().clone()).agg_mean(groups),
             dt if dt.is_primitive_numeric() => apply_method_physical_integer!(s, agg_mean, groups),
             #[cfg(feature = "dtype-decimal")]
-            Decimal(_, _) => self.cast(&Float64).unwrap().agg_mean(groups),
+            // This can't error, so the means of which the sum overflows are null.
+            Decimal(_, _) => s
+                .decimal()
+                .unwrap()
+                .agg_mean_checked(groups, false)
+                .unwrap()
+                .into_series(),
             #[cfg(feature = "dtype-datetime")]
             dt @ Datetime(_, _) => self
                 .to_physical_repr()
@@ -271,7 +277,11 @@ impl Series {
                 apply_method_physical_integer!(s, agg_median, groups)
             },
             #[cfg(feature = "dtype-decimal")]
-            Decimal(_, _) => self.cast(&Float64).unwrap().agg_median(groups),
+            Decimal(_, _) => s
+                .decimal()
+                .unwrap()
+                .agg_median_exact(groups)
+                .into_series(),
             #[cfg(feature = "dtype-datetime")]
             dt @ Datetime(_, _) => self
                 .to_physical_repr()
@@ -215,10 +215,41 @@ impl IntoGroupsType for BooleanChunked {
 impl IntoGroupsType for StringChunked {
     #[allow(clippy::needless_lifetimes)]
     fn group_tuples<'a>(&'a self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
+        #[cfg(feature = "dtype-categorical")]
+        if !self.is_sorted_any() && has_low_cardinality(self) {
+            if verbose() {
+                eprintln!("group_by keys have few distinct values; grouping on categorical codes");
+            }
+            return group_tuples_categorical(self, multithreaded, sorted);
+        }
         self.as_binary().group_tuples(multithreaded, sorted)
     }
 }
 
+/// Fewer keys than this are grouped directly, as building a dictionary doesn't pay off.
+#[cfg(feature = "dtype-categorical")]
+const MIN_DICTIONARY_GROUP_BY_LEN: usize = 4096;
+
+/// Whether the keys have few distinct values compared to their length, going by the cached
+/// distinct count estimate of the column.
+#[cfg(feature = "dtype-categorical")]
+pub(super) fn has_low_cardinality(ca: &StringChunked) -> bool {
+    ca.len() >= MIN_DICTIONARY_GROUP_BY_LEN && ca.n_distinct_estimate() * 64 <= ca.len()
+}
+
+/// Group the keys on their codes in a categorical with a new mapping, which only hashes every
+/// key into a table with an entry per distinct key.
+#[cfg(feature = "dtype-categorical")]
+pub(super) fn group_tuples_categorical(
+    ca: &StringChunked,
+    multithreaded: bool,
+    sorted: bool,
+) -> PolarsResult<GroupsType> {
+    let cats = Categories::random(PlSmallStr::EMPTY, CategoricalPhysical::U32);
+    let cat = ca.cast(&DataType::from_categories(cats))?;
+    cat.cat32()?.physical().group_tuples(multithreaded, sorted)
+}
+
 impl IntoGroupsType for BinaryChunked {
     #[allow(clippy::needless_lifetimes)]
     fn group_tuples<'a>(
@@ -1236,4 +1236,27 @@ mod test {
         let _ = df.group_by(["g"])?.sum()?;
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_group_by_low_cardinality_strings() -> PolarsResult<()> {
+        let keys = (0..10_000)
+            .map(|i| (i % 7 != 0).then(|| format!("key{}", i % 5)))
+            .collect::<StringChunked>();
+
+        assert!(super::into_groups::has_low_cardinality(&keys));
+        for multithreaded in [false, true] {
+            let groups = keys.group_tuples(multithreaded, true)?;
+            let groups = groups.unwrap_idx();
+            assert_eq!(groups.first(), &[0, 1, 2, 3, 4, 5]);
+            let mut len = 0;
+            for (first, all) in groups.iter() {
+                assert!(all.iter().all(|i| keys.get(*i as usize) == keys.get(first as usize)));
+                len += all.len();
+            }
+            assert_eq!(len, keys.len());
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,470 @@
+use std::ops::Bound;
+
+use arrow::bitmap::Bitmap;
+use polars_row::RowsEncoded;
+use polars_utils::{UnitVec, format_pl_smallstr};
+use rayon::prelude::*;
+
+use crate::POOL;
+use crate::chunked_array::ops::row_encode::{_get_rows_encoded, _get_rows_encoded_unordered};
+use crate::prelude::*;
+
+/// How [`DataFrameIndex::join`] handles rows that have no match in the index.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum IndexJoinType {
+    /// Only keep the rows that have a match.
+    #[default]
+    Inner,
+    /// Keep all rows, with nulls in the columns of the indexed frame if there is no match.
+    Left,
+}
+
+/// A hash index on the key columns of a [`DataFrame`], created with [`DataFrame::create_index`].
+///
+/// The index maps every key to the indices of the rows with that key, such that repeated lookups
+/// against a frame that does not change are answered without scanning it. Rows with a null in
+/// any of the key columns are not indexed.
+#[derive(Clone)]
+pub struct DataFrameIndex {
+    df: DataFrame,
+    keys: Vec<PlSmallStr>,
+    key_schema: Schema,
+    map: PlHashMap<Box<[u8]>, UnitVec<IdxSize>>,
+}
+
+/// A sorted index on the key columns of a [`DataFrame`], created with
+/// [`DataFrame::create_sorted_index`].
+///
+/// This complements [`DataFrameIndex`] with lookups of the rows whose keys are in a range, which
+/// are answered with a binary search over the row encoding of the keys. Rows with a null in any of
+/// the key columns are not indexed.
+#[derive(Clone)]
+pub struct SortedDataFrameIndex {
+    df: DataFrame,
+    keys: Vec<PlSmallStr>,
+    key_schema: Schema,
+    rows: RowsEncoded,
+    /// The indexed rows, sorted by key, with equal keys in the order of the frame.
+    idx: Vec<IdxSize>,
+}
+
+/// The rows without nulls in any of the `keys`, or `None` if there are no nulls.
+fn valid_keys(keys: &[Column]) -> Option<Bitmap> {
+    keys.iter()
+        .filter(|c| c.has_nulls())
+        .map(|c| c.is_not_null())
+        .reduce(|acc, v| &acc & &v)
+        .map(|v| v.rechunk().downcast_as_array().values().clone())
+}
+
+/// Single-row columns with the values of `key` for the first key columns of `key_schema`.
+fn key_to_columns(key_schema: &Schema, key: &[AnyValue]) -> PolarsResult<Vec<Column>> {
+    key.iter()
+        .zip(key_schema.iter())
+        .map(|(av, (name, dtype))| {
+            let s = Series::from_any_values_and_dtype(
+                name.clone(),
+                std::slice::from_ref(av),
+                dtype,
+                true,
+            )?;
+            Ok(s.into_column())
+        })
+        .collect()
+}
+
+/// The ordered row encoding of `columns`, with nulls first.
+fn encode_sorted(columns: &[Column]) -> PolarsResult<RowsEncoded> {
+    let options = vec![false; columns.len()];
+    _get_rows_encoded(columns, &options, &options)
+}
+
+impl DataFrameIndex {
+    /// The indexed frame.
+    pub fn frame(&self) -> &DataFrame {
+        &self.df
+    }
+
+    /// The names of the key columns.
+    pub fn keys(&self) -> &[PlSmallStr] {
+        &self.keys
+    }
+
+    /// The number of distinct keys in the index.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// The indices of the rows with the given `key`, which has a value for every key column.
+    pub fn lookup_idx(&self, key: &[AnyValue]) -> PolarsResult<&[IdxSize]> {
+        polars_ensure!(
+            key.len() == self.keys.len(),
+            ShapeMismatch: "expected a key with {} values, got {}", self.keys.len(), key.len()
+        );
+        let columns = key_to_columns(&self.key_schema, key)?;
+        if key.iter().any(|av| av.is_null()) {
+            return Ok(&[]);
+        }
+
+        let rows = _get_rows_encoded_unordered(&columns)?;
+        Ok(self
+            .map
+            .get(rows.get(0))
+            .map(|idx| idx.as_slice())
+            .unwrap_or_default())
+    }
+
+    /// The rows with the given `key`, which has a value for every key column.
+    pub fn lookup(&self, key: &[AnyValue]) -> PolarsResult<DataFrame> {
+        let idx = IdxCa::from_slice(PlSmallStr::EMPTY, self.lookup_idx(key)?);
+        // SAFETY: the index only holds indices of rows of the frame.
+        Ok(unsafe { self.df.take_unchecked(&idx) })
+    }
+
+    /// Join `other` with the indexed frame, matching the `on` columns of `other` with the keys of
+    /// the index.
+    ///
+    /// The output has the columns of `other`, followed by the columns of the indexed frame that
+    /// are not keys. Names that are already taken by `other` get the suffix `_right`. The rows
+    /// are in the order of `other`, with a row for every match.
+    pub fn join<I, S>(
+        &self,
+        other: &DataFrame,
+        on: I,
+        how: IndexJoinType,
+    ) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let probe = other.select_to_vec(on)?;
+        polars_ensure!(
+            probe.len() == self.keys.len(),
+            InvalidOperation: "expected {} join columns, got {}", self.keys.len(), probe.len()
+        );
+        for (c, (name, dtype)) in probe.iter().zip(self.key_schema.iter()) {
+            polars_ensure!(
+                c.dtype() == dtype,
+                SchemaMismatch: "datatypes of join keys don't match - `{}`: {} on left does not \
+                match `{}`: {} on right", c.name(), c.dtype(), name, dtype
+            );
+        }
+
+        let rows = _get_rows_encoded_unordered(&probe)?;
+        let valid = valid_keys(&probe);
+        let mut left_idx = Vec::with_capacity(other.height());
+        let mut right_idx = Vec::with_capacity(other.height());
+        for (i, row) in rows.iter().enumerate() {
+            let matches = if valid.as_ref().is_some_and(|v| !v.get_bit(i)) {
+                None
+            } else {
+                self.map.get(row)
+            };
+            match matches {
+                Some(idx) => {
+                    left_idx.extend(std::iter::repeat_n(i as IdxSize, idx.len()));
+                    right_idx.extend(idx.iter().map(|&j| Some(j)));
+                },
+                None if how == IndexJoinType::Left => {
+                    left_idx.push(i as IdxSize);
+                    right_idx.push(None);
+                },
+                None => {},
+            }
+        }
+
+        let left_idx = IdxCa::from_vec(PlSmallStr::EMPTY, left_idx);
+        let right_idx = IdxCa::from_iter_options(PlSmallStr::EMPTY, right_idx.into_iter());
+        // SAFETY: the indices are rows of `other`.
+        let left = unsafe { other.take_unchecked(&left_idx) };
+        let right = self.df.drop_many(self.keys.iter().cloned()).take(&right_idx)?;
+
+        let height = left.height();
+        let left_schema = left.schema().clone();
+        let mut columns = left.into_columns();
+        for mut c in right.into_columns() {
+            if left_schema.contains(c.name()) {
+                let name = format_pl_smallstr!("{}_right", c.name());
+                c.rename(name);
+            }
+            columns.push(c);
+        }
+        DataFrame::new(height, columns)
+    }
+}
+
+impl SortedDataFrameIndex {
+    /// The indexed frame.
+    pub fn frame(&self) -> &DataFrame {
+        &self.df
+    }
+
+    /// The names of the key columns.
+    pub fn keys(&self) -> &[PlSmallStr] {
+        &self.keys
+    }
+
+    /// The number of indexed rows.
+    pub fn len(&self) -> usize {
+        self.idx.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.idx.is_empty()
+    }
+
+    /// Encode a bound, or return `None` if it has a null and matches no rows.
+    fn encode_bound(&self, bound: Bound<&[AnyValue]>) -> PolarsResult<Option<Bound<Vec<u8>>>> {
+        let key = match bound {
+            Bound::Included(key) | Bound::Excluded(key) => key,
+            Bound::Unbounded => return Ok(Some(Bound::Unbounded)),
+        };
+        polars_ensure!(
+            !key.is_empty() && key.len() <= self.keys.len(),
+            ShapeMismatch: "expected a bound with 1 to {} values, got {}",
+            self.keys.len(), key.len()
+        );
+        let columns = key_to_columns(&self.key_schema, key)?;
+        if key.iter().any(|av| av.is_null()) {
+            return Ok(None);
+        }
+        let rows = encode_sorted(&columns)?;
+        Ok(Some(bound.map(|_| rows.get(0).to_vec())))
+    }
+
+    /// The indices of the rows with keys between `low` and `high`, in the order of their keys.
+    ///
+    /// A bound has values for the first (one or more) key columns, and is only compared with
+    /// those columns. A bound with a null matches no rows.
+    pub fn range_lookup(
+        &self,
+        low: Bound<&[AnyValue]>,
+        high: Bound<&[AnyValue]>,
+    ) -> PolarsResult<&[IdxSize]> {
+        let (Some(low), Some(high)) = (self.encode_bound(low)?, self.encode_bound(high)?) else {
+            return Ok(&[]);
+        };
+        // Truncating the rows to the length of the bound compares only the bound's columns.
+        let cmp = |i: &IdxSize, bound: &[u8]| {
+            let row = self.rows.get(*i as usize);
+            row[..row.len().min(bound.len())].cmp(bound)
+        };
+        let start = match &low {
+            Bound::Included(low) => self.idx.partition_point(|i| cmp(i, low).is_lt()),
+            Bound::Excluded(low) => self.idx.partition_point(|i| cmp(i, low).is_le()),
+            Bound::Unbounded => 0,
+        };
+        let end = match &high {
+            Bound::Included(high) => self.idx.partition_point(|i| cmp(i, high).is_le()),
+            Bound::Excluded(high) => self.idx.partition_point(|i| cmp(i, high).is_lt()),
+            Bound::Unbounded => self.idx.len(),
+        };
+        Ok(&self.idx[start..end.max(start)])
+    }
+
+    /// The rows with keys between `low` and `high`, in the order of the frame, see
+    /// [`SortedDataFrameIndex::range_lookup`].
+    pub fn range_filter(
+        &self,
+        low: Bound<&[AnyValue]>,
+        high: Bound<&[AnyValue]>,
+    ) -> PolarsResult<DataFrame> {
+        let mut idx = self.range_lookup(low, high)?.to_vec();
+        idx.sort_unstable();
+        let idx = IdxCa::from_vec(PlSmallStr::EMPTY, idx);
+        // SAFETY: the index only holds indices of rows of the frame.
+        Ok(unsafe { self.df.take_unchecked(&idx) })
+    }
+}
+
+impl DataFrame {
+    /// Select the `keys` columns of an index.
+    fn index_keys<I, S>(&self, keys: I) -> PolarsResult<(Vec<PlSmallStr>, Vec<Column>, Schema)>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        let keys = keys.into_iter().map(Into::into).collect::<Vec<PlSmallStr>>();
+        polars_ensure!(
+            !keys.is_empty(),
+            InvalidOperation: "an index needs at least one key column"
+        );
+        let key_columns = self.select_to_vec(&keys)?;
+        let key_schema = key_columns
+            .iter()
+            .map(|c| c.field().into_owned())
+            .collect::<Schema>();
+        Ok((keys, key_columns, key_schema))
+    }
+
+    /// Create a hash index on the `keys` columns, for repeated lookups of rows by key.
+    ///
+    /// The index holds a (cheap) clone of the frame, so later changes to the frame are not
+    /// reflected in the index.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("id" => [3, 1, 3], "value" => ["a", "b", "c"])?;
+    /// let index = df.create_index(["id"])?;
+    /// assert_eq!(index.lookup_idx(&[AnyValue::Int32(3)])?, &[0, 2]);
+    /// let rows = index.lookup(&[AnyValue::Int32(1)])?;
+    /// assert_eq!(rows.column("value")?.get(0)?, AnyValue::String("b"));
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn create_index<I, S>(&self, keys: I) -> PolarsResult<DataFrameIndex>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        let (keys, key_columns, key_schema) = self.index_keys(keys)?;
+        let rows = _get_rows_encoded_unordered(&key_columns)?;
+        let valid = valid_keys(&key_columns);
+        let mut map = PlHashMap::<Box<[u8]>, UnitVec<IdxSize>>::new();
+        for (i, row) in rows.iter().enumerate() {
+            if valid.as_ref().is_some_and(|v| !v.get_bit(i)) {
+                continue;
+            }
+            match map.get_mut(row) {
+                Some(idx) => idx.push(i as IdxSize),
+                None => {
+                    let mut idx = UnitVec::new();
+                    idx.push(i as IdxSize);
+                    map.insert(row.into(), idx);
+                },
+            }
+        }
+
+        Ok(DataFrameIndex {
+            df: self.clone(),
+            keys,
+            key_schema,
+            map,
+        })
+    }
+
+    /// Create a sorted index on the `keys` columns, for repeated lookups of the rows whose keys
+    /// are in a range.
+    ///
+    /// The index holds a (cheap) clone of the frame, so later changes to the frame are not
+    /// reflected in the index.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::ops::Bound;
+    /// # use polars_core::prelude::*;
+    /// let df = df!("ts" => [30, 10, 20, 40], "value" => ["a", "b", "c", "d"])?;
+    /// let index = df.create_sorted_index(["ts"])?;
+    /// let low = [AnyValue::Int32(15)];
+    /// let high = [AnyValue::Int32(30)];
+    /// let idx = index.range_lookup(Bound::Included(&low[..]), Bound::Excluded(&high[..]))?;
+    /// assert_eq!(idx, &[2]);
+    /// let rows = index.range_filter(Bound::Included(&low[..]), Bound::Included(&high[..]))?;
+    /// assert_eq!(rows.column("value")?.get(0)?, AnyValue::String("a"));
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn create_sorted_index<I, S>(&self, keys: I) -> PolarsResult<SortedDataFrameIndex>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        let (keys, key_columns, key_schema) = self.index_keys(keys)?;
+        let rows = encode_sorted(&key_columns)?;
+        let valid = valid_keys(&key_columns);
+        let mut idx = (0..self.height() as IdxSize)
+            .filter(|&i| valid.as_ref().is_none_or(|v| v.get_bit(i as usize)))
+            .collect::<Vec<_>>();
+        POOL.install(|| idx.par_sort_by(|&a, &b| rows.get(a as usize).cmp(rows.get(b as usize))));
+
+        Ok(SortedDataFrameIndex {
+            df: self.clone(),
+            keys,
+            key_schema,
+            rows,
+            idx,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_index_lookup_and_join() -> PolarsResult<()> {
+        let df = df! {
+            "k1" => [Some(1), Some(2), Some(1), None],
+            "k2" => ["a", "b", "a", "a"],
+            "v" => [10, 20, 30, 40],
+        }?;
+        let index = df.create_index(["k1", "k2"])?;
+        assert_eq!(index.len(), 2);
+        let key = [AnyValue::Int32(1), AnyValue::String("a")];
+        assert_eq!(index.lookup_idx(&key)?, &[0, 2]);
+        let key = [AnyValue::Int32(2), AnyValue::String("a")];
+        assert!(index.lookup_idx(&key)?.is_empty());
+        let key = [AnyValue::Null, AnyValue::String("a")];
+        assert!(index.lookup_idx(&key)?.is_empty());
+        assert!(index.lookup_idx(&[AnyValue::Int32(1)]).is_err());
+
+        let other = df! {
+            "k1" => [Some(2), None, Some(1), Some(5)],
+            "k2" => ["b", "a", "a", "b"],
+            "v" => [1, 2, 3, 4],
+        }?;
+        let out = index.join(&other, ["k1", "k2"], IndexJoinType::Inner)?;
+        let expected = df! {
+            "k1" => [2, 1, 1],
+            "k2" => ["b", "a", "a"],
+            "v" => [1, 3, 3],
+            "v_right" => [20, 10, 30],
+        }?;
+        assert!(out.equals(&expected));
+
+        let out = index.join(&other, ["k1", "k2"], IndexJoinType::Left)?;
+        let expected = df! {
+            "k1" => [Some(2), None, Some(1), Some(1), Some(5)],
+            "k2" => ["b", "a", "a", "a", "b"],
+            "v" => [1, 2, 3, 3, 4],
+            "v_right" => [Some(20), None, Some(10), Some(30), None],
+        }?;
+        assert!(out.equals_missing(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sorted_index_range_lookup() -> PolarsResult<()> {
+        let df = df! {
+            "k1" => [Some(2), Some(1), None, Some(1), Some(3)],
+            "k2" => ["b", "c", "a", "a", "a"],
+        }?;
+        let index = df.create_sorted_index(["k1", "k2"])?;
+        assert_eq!(index.len(), 4);
+        let low = [AnyValue::Int32(1)];
+        let high = [AnyValue::Int32(3)];
+        let idx = index.range_lookup(Bound::Included(&low), Bound::Excluded(&high))?;
+        assert_eq!(idx, &[3, 1, 0]);
+        let low = [AnyValue::Int32(1), AnyValue::String("a")];
+        let idx = index.range_lookup(Bound::Excluded(&low[..]), Bound::Unbounded)?;
+        assert_eq!(idx, &[1, 0, 4]);
+        let idx = index.range_lookup(Bound::Included(&[AnyValue::Null][..]), Bound::Unbounded)?;
+        assert!(idx.is_empty());
+        let key = [AnyValue::Int32(1), AnyValue::String("a"), AnyValue::Int32(0)];
+        assert!(index.range_lookup(Bound::Included(&key[..]), Bound::Unbounded).is_err());
+
+        let low = [AnyValue::Int32(2)];
+        let out = index.range_filter(Bound::Included(&low[..]), Bound::Unbounded)?;
+        let expected = df! {
+            "k1" => [2, 3],
+            "k2" => ["b", "a"],
+        }?;
+        assert!(out.equals(&expected));
+        Ok(())
+    }
+}
@@ -18,8 +18,20 @@ use crate::{HEAD_DEFAULT_LENGTH, TAIL_DEFAULT_LENGTH};
 
 #[cfg(feature = "dataframe_arithmetic")]
 mod arithmetic;
+mod arrow_stream;
 pub mod builder;
+mod cast;
 mod chunks;
+pub use arrow_stream::ArrowStreamOptions;
+pub use cast::CastPolicy;
+#[cfg(feature = "describe")]
+pub mod describe;
+#[cfg(feature = "dataframe_index")]
+mod index;
+#[cfg(feature = "dataframe_index")]
+pub use index::{DataFrameIndex, IndexJoinType, SortedDataFrameIndex};
+mod render;
+pub use render::{TableFormat, TableFormatOptions};
 pub use chunks::chunk_df_for_writing;
 mod broadcast;
 pub mod column;
@@ -1067,8 +1079,10 @@ impl DataFrame {
 
     /// Get column index of a [`Series`] by name.
     pub fn try_get_column_index(&self, name: &str) -> PolarsResult<usize> {
-        self.get_column_index(name)
-            .ok_or_else(|| polars_err!(col_not_found = name))
+        self.get_column_index(name).ok_or_else(|| {
+            let candidates = self.schema().iter_names();
+            polars_err!(col_not_found = name, candidates = candidates)
+        })
     }
 
     /// Select a single column by name.
@@ -1336,10 +1350,8 @@ impl DataFrame {
             Duplicate: "column rename attempted with already existing name \"{name}\""
         );
 
-        self.get_column_index(column)
-            .and_then(|idx| unsafe { self.columns_mut() }.get_mut(idx))
-            .ok_or_else(|| polars_err!(col_not_found = column))
-            .map(|c| c.rename(name))?;
+        let idx = self.try_get_column_index(column)?;
+        unsafe { self.columns_mut() }[idx].rename(name);
 
         Ok(self)
     }
@@ -1362,7 +1374,7 @@ impl DataFrame {
             );
 
             match schema.get_full(from) {
-                None => polars_bail!(col_not_found = from),
+                None => polars_bail!(col_not_found = from, candidates = schema.iter_names()),
                 Some((idx, _, _)) => {
                     let (n, _) = schema.get_at_index_mut(idx).unwrap();
                     *n = to.clone();
@@ -2582,31 +2594,34 @@ impl DataFrame {
         cols: impl IntoIterator<Item = impl Into<PlSmallStr>>,
         separator: Option<&str>,
     ) -> PolarsResult<DataFrame> {
-        self.unnest_impl(cols.into_iter().map(Into::into).collect(), separator)
+        self.unnest_with_names(cols, |name, field| {
+            Ok(match separator {
+                None => field.clone(),
+                Some(separator) => polars_utils::format_pl_smallstr!("{name}{separator}{field}"),
+            })
+        })
     }
 
+    /// Unnest the given `Struct` columns, naming the new columns with `column_name`.
+    ///
+    /// `column_name` is called with the name of the `Struct` column and the name of the field.
     #[cfg(feature = "dtype-struct")]
-    fn unnest_impl(
+    pub fn unnest_with_names(
         &self,
-        cols: PlHashSet<PlSmallStr>,
-        separator: Option<&str>,
+        cols: impl IntoIterator<Item = impl Into<PlSmallStr>>,
+        mut column_name: impl FnMut(&PlSmallStr, &PlSmallStr) -> PolarsResult<PlSmallStr>,
     ) -> PolarsResult<DataFrame> {
+        let cols: PlHashSet<PlSmallStr> = cols.into_iter().map(Into::into).collect();
         let mut new_cols = Vec::with_capacity(std::cmp::min(self.width() * 2, self.width() + 128));
         let mut count = 0;
         for s in self.columns() {
             if cols.contains(s.name()) {
                 let ca = s.struct_()?.clone();
-                new_cols.extend(ca.fields_as_series().into_iter().map(|mut f| {
-                    if let Some(separator) = &separator {
-                        f.rename(polars_utils::format_pl_smallstr!(
-                            "{}{}{}",
-                            s.name(),
-                            separator,
-                            f.name()
-                        ));
-                    }
-                    Column::from(f)
-                }));
+                for mut f in ca.fields_as_series() {
+                    let name = column_name(s.name(), f.name())?;
+                    f.rename(name);
+                    new_cols.push(Column::from(f));
+                }
                 count += 1;
             } else {
                 new_cols.push(s.clone())
@@ -2626,6 +2641,44 @@ impl DataFrame {
         DataFrame::new_infer_height(new_cols)
     }
 
+    /// Recursively unnest all `Struct` columns.
+    ///
+    /// The fields are inserted as columns named `{parent}{separator}{field}`. Nested structs are
+    /// flattened up to `max_depth` levels, or completely if `max_depth` is `None`.
+    #[cfg(feature = "dtype-struct")]
+    pub fn unnest_all(&self, separator: &str, max_depth: Option<usize>) -> PolarsResult<DataFrame> {
+        fn flatten(
+            s: Series,
+            name: PlSmallStr,
+            separator: &str,
+            depth: usize,
+            max_depth: Option<usize>,
+            out: &mut Vec<Column>,
+        ) -> PolarsResult<()> {
+            if s.dtype().is_struct() && max_depth.is_none_or(|max| depth < max) {
+                for f in s.struct_()?.fields_as_series() {
+                    let name = polars_utils::format_pl_smallstr!("{name}{separator}{}", f.name());
+                    flatten(f, name, separator, depth + 1, max_depth, out)?;
+                }
+            } else {
+                out.push(Column::from(s.with_name(name)));
+            }
+            Ok(())
+        }
+
+        let mut new_cols = Vec::with_capacity(std::cmp::min(self.width() * 2, self.width() + 128));
+        for c in self.columns() {
+            if c.dtype().is_struct() {
+                let s = c.as_materialized_series().clone();
+                flatten(s, c.name().clone(), separator, 0, max_depth, &mut new_cols)?;
+            } else {
+                new_cols.push(c.clone())
+            }
+        }
+
+        DataFrame::new_infer_height(new_cols)
+    }
+
     pub fn append_record_batch(&mut self, rb: RecordBatchT<ArrayRef>) -> PolarsResult<()> {
         // @Optimize: this does a lot of unnecessary allocations. We should probably have a
         // append_chunk or something like this. It is just quite difficult to make that safe.
@@ -2934,4 +2987,66 @@ mod test {
         df.apply("x", |f| f.cast(&DataType::Int8).unwrap()).unwrap();
         assert_ne!(&schema_before, df.schema());
     }
+
+    #[test]
+    fn test_cast_with_policy() {
+        let df = df! {
+            "a" => [1i32, 2, 3],
+            "b" => [Some(1.5), None, Some(1e20)],
+        }
+        .unwrap();
+        let schema = Schema::from_iter([
+            Field::new("a".into(), DataType::Int64),
+            Field::new("b".into(), DataType::Int32),
+        ]);
+
+        let err = df.cast_with_policy(&schema, CastPolicy::Upcast).unwrap_err();
+        assert!(err.to_string().contains("column 'b' from f64 to i32"));
+        assert!(df.cast_with_policy(&schema, CastPolicy::Strict).is_err());
+
+        let (out, report) = df.cast_with_policy(&schema, CastPolicy::NonStrict).unwrap();
+        assert!(report.is_none());
+        let expected = df! {
+            "a" => [1i64, 2, 3],
+            "b" => [Some(1i32), None, None],
+        }
+        .unwrap();
+        assert!(out.equals_missing(&expected));
+
+        let (out, report) = df.cast_with_policy(&schema, CastPolicy::Report).unwrap();
+        assert!(out.equals_missing(&expected));
+        let expected = df! {
+            "column" => ["a", "b"],
+            "from" => ["i32", "f64"],
+            "to" => ["i64", "i32"],
+            "lossy_rows" => [0 as IdxSize, 1],
+        }
+        .unwrap();
+        assert!(report.unwrap().equals(&expected));
+
+        let schema = Schema::from_iter([Field::new("a".into(), DataType::Int64)]);
+        let (out, _) = df.cast_with_policy(&schema, CastPolicy::Upcast).unwrap();
+        assert_eq!(out.column("a").unwrap().dtype(), &DataType::Int64);
+    }
+
+    #[test]
+    #[cfg(feature = "describe")]
+    fn test_describe() {
+        let df = df! {
+            "a" => [Some(1.0), None, Some(3.0), Some(2.0)],
+            "b" => [true, false, true, true],
+            "c" => ["zz", "xx", "yy", "xx"],
+        }
+        .unwrap();
+        let out = df.describe(&[0.5]).unwrap();
+        let expected = df! {
+            "statistic" => ["count", "null_count", "mean", "std", "min", "50%", "max"],
+            "a" => [3.0, 1.0, 2.0, 1.0, 1.0, 2.0, 3.0],
+            "b" => [Some(4.0), Some(0.0), Some(0.75), None, Some(0.0), None, Some(1.0)],
+            "c" => [Some("4"), Some("0"), None, None, Some("xx"), None, Some("zz")],
+        }
+        .unwrap();
+        assert!(out.equals_missing(&expected));
+        assert!(df.describe(&[1.5]).is_err());
+    }
 }
@@ -38,9 +38,10 @@ impl<'a> AmortizedColumnSelector<'a> {
         match self {
             Self::Direct { df } => df.column(name),
             Self::NameToIdxMapping { df, name_to_idx } => {
-                let i = *name_to_idx
-                    .get(name)
-                    .ok_or_else(|| polars_err!(col_not_found = name))?;
+                let i = *name_to_idx.get(name).ok_or_else(|| {
+                    let candidates = df.columns().iter().map(|c| c.name());
+                    polars_err!(col_not_found = name, candidates = candidates)
+                })?;
 
                 Ok(df.select_at_idx(i).unwrap())
             },
@@ -0,0 +1,339 @@
+use std::fmt::Write;
+
+use crate::fmt::{estimate_string_width, fmt_float_string_custom, fmt_int_string_custom};
+use crate::prelude::*;
+
+/// The output format of [`DataFrame::to_table_string`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TableFormat {
+    /// Plain text, with the columns padded to a fixed width.
+    #[default]
+    Text,
+    /// A Markdown table.
+    Markdown,
+    /// An HTML `<table>`.
+    Html,
+}
+
+/// Options for rendering a [`DataFrame`] with [`DataFrame::to_table_string`].
+///
+/// Unlike the [`Display`](std::fmt::Display) implementation of [`DataFrame`], the rendering
+/// does not depend on the global formatting configuration.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TableFormatOptions {
+    pub format: TableFormat,
+    /// The number of decimals of floats. By default, floats are shown with as many decimals as
+    /// needed to represent them exactly.
+    pub float_precision: Option<usize>,
+    /// Separator between groups of thousands of integers and floats.
+    pub thousands_separator: Option<char>,
+    pub decimal_separator: char,
+    /// The maximum number of rows to render. If there are more, the first and last rows are
+    /// rendered around a row of ellipses.
+    pub max_rows: Option<usize>,
+    /// Render the data types of the columns below their names.
+    pub include_dtypes: bool,
+}
+
+impl Default for TableFormatOptions {
+    fn default() -> Self {
+        Self {
+            format: TableFormat::default(),
+            float_precision: None,
+            thousands_separator: None,
+            decimal_separator: '.',
+            max_rows: None,
+            include_dtypes: true,
+        }
+    }
+}
+
+impl TableFormatOptions {
+    fn format_float(&self, v: f64) -> String {
+        let s = match self.float_precision {
+            Some(precision) => format!("{v:.precision$}"),
+            None if v != 0.0 && v.is_finite() && !(1e-6..1e16).contains(&v.abs()) => {
+                // Scientific notation does not combine with digit grouping.
+                return format!("{v:e}").replace('.', &self.decimal_separator.to_string());
+            },
+            None if v.is_finite() && v.fract() == 0.0 => format!("{v:.1}"),
+            None => format!("{v}"),
+        };
+        if !v.is_finite() {
+            return s;
+        }
+        let group_size = if self.thousands_separator.is_some() { 3 } else { 0 };
+        let separator = self.thousands_separator.map(String::from).unwrap_or_default();
+        fmt_float_string_custom(&s, group_size, &separator, self.decimal_separator)
+    }
+
+    fn format_value(&self, av: AnyValue) -> String {
+        match av {
+            AnyValue::Null => "null".to_string(),
+            AnyValue::Float32(v) => self.format_float(v as f64),
+            AnyValue::Float64(v) => self.format_float(v),
+            av if av.dtype().is_integer() => {
+                let s = match av.extract::<i128>() {
+                    Some(v) => v.to_string(),
+                    None => av.str_value().into_owned(),
+                };
+                match self.thousands_separator {
+                    Some(separator) => fmt_int_string_custom(&s, 3, &separator.to_string()),
+                    None => s,
+                }
+            },
+            av => av.str_value().into_owned(),
+        }
+    }
+}
+
+fn escape_html(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn escape_markdown(s: &str) -> String {
+    s.replace('|', "\\|").replace('\n', "<br>")
+}
+
+/// Pad `s` to `width`, on the left if `right_align`.
+fn pad(out: &mut String, s: &str, width: usize, right_align: bool) {
+    let padding = width.saturating_sub(estimate_string_width(s));
+    if right_align {
+        out.extend(std::iter::repeat_n(' ', padding));
+        out.push_str(s);
+    } else {
+        out.push_str(s);
+        out.extend(std::iter::repeat_n(' ', padding));
+    }
+}
+
+impl DataFrame {
+    /// Render the [`DataFrame`] as a table in the format of the `options`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("a" => [1, 1000], "b" => [0.5, 2.25])?;
+    /// let options = TableFormatOptions {
+    ///     format: TableFormat::Markdown,
+    ///     thousands_separator: Some(','),
+    ///     include_dtypes: false,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     df.to_table_string(&options)?,
+    ///     "|     a |    b |\n| ----: | ---: |\n|     1 |  0.5 |\n| 1,000 | 2.25 |\n"
+    /// );
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn to_table_string(&self, options: &TableFormatOptions) -> PolarsResult<String> {
+        let height = self.height();
+        let rows = match options.max_rows {
+            Some(max_rows) if height > max_rows => {
+                let n_last = max_rows / 2;
+                let mut rows = (0..max_rows - n_last).map(Some).collect::<Vec<_>>();
+                rows.push(None);
+                rows.extend((height - n_last..height).map(Some));
+                rows
+            },
+            _ => (0..height).map(Some).collect(),
+        };
+
+        let columns = self.columns();
+        let header = columns.iter().map(|c| c.name().to_string()).collect::<Vec<_>>();
+        let dtypes = columns.iter().map(|c| c.dtype().to_string()).collect::<Vec<_>>();
+        let right_align = columns
+            .iter()
+            .map(|c| c.dtype().is_numeric())
+            .collect::<Vec<_>>();
+        let cells = rows
+            .iter()
+            .map(|row| {
+                columns
+                    .iter()
+                    .map(|c| match row {
+                        Some(i) => Ok(options.format_value(c.get(*i)?)),
+                        None => Ok("...".to_string()),
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut out = String::new();
+        match options.format {
+            TableFormat::Html => {
+                let write_row = |out: &mut String, row: &[String], tag: &str| {
+                    out.push_str("<tr>");
+                    for cell in row {
+                        write!(out, "<{tag}>{}</{tag}>", escape_html(cell)).unwrap();
+                    }
+                    out.push_str("</tr>\n");
+                };
+                out.push_str("<table>\n<thead>\n");
+                write_row(&mut out, &header, "th");
+                if options.include_dtypes {
+                    write_row(&mut out, &dtypes, "td");
+                }
+                out.push_str("</thead>\n<tbody>\n");
+                for row in &cells {
+                    write_row(&mut out, row, "td");
+                }
+                out.push_str("</tbody>\n</table>\n");
+            },
+            TableFormat::Text | TableFormat::Markdown => {
+                let is_markdown = options.format == TableFormat::Markdown;
+                let escape = |s: &String| {
+                    if is_markdown {
+                        escape_markdown(s)
+                    } else {
+                        s.clone()
+                    }
+                };
+                let mut lines = vec![header.iter().map(escape).collect::<Vec<_>>()];
+                if options.include_dtypes {
+                    lines.push(dtypes.iter().map(escape).collect());
+                }
+                let n_header_lines = lines.len();
+                lines.extend(cells.iter().map(|row| row.iter().map(escape).collect()));
+
+                let mut widths = vec![if is_markdown { 3 } else { 0 }; columns.len()];
+                for line in &lines {
+                    for (width, cell) in widths.iter_mut().zip(line) {
+                        *width = (*width).max(estimate_string_width(cell));
+                    }
+                }
+
+                let (start, separator, end) = if is_markdown {
+                    ("| ", " | ", " |")
+                } else {
+                    ("", "  ", "")
+                };
+                for (i, line) in lines.iter().enumerate() {
+                    if i == n_header_lines {
+                        out.push_str(start);
+                        for (j, width) in widths.iter().enumerate() {
+                            if j > 0 {
+                                out.push_str(separator);
+                            }
+                            if is_markdown && right_align[j] {
+                                out.extend(std::iter::repeat_n('-', width - 1));
+                                out.push(':');
+                            } else {
+                                out.extend(std::iter::repeat_n('-', *width));
+                            }
+                        }
+                        out.push_str(end);
+                        out.push('\n');
+                    }
+                    out.push_str(start);
+                    for (j, cell) in line.iter().enumerate() {
+                        if j > 0 {
+                            out.push_str(separator);
+                        }
+                        pad(&mut out, cell, widths[j], right_align[j]);
+                    }
+                    if is_markdown {
+                        out.push_str(end);
+                    } else {
+                        out.truncate(out.trim_end_matches(' ').len());
+                    }
+                    out.push('\n');
+                }
+            },
+        }
+        Ok(out)
+    }
+
+    /// Render the [`DataFrame`] as a Markdown table, with the default [`TableFormatOptions`].
+    pub fn to_markdown(&self) -> PolarsResult<String> {
+        self.to_table_string(&TableFormatOptions {
+            format: TableFormat::Markdown,
+            ..Default::default()
+        })
+    }
+
+    /// Render the [`DataFrame`] as an HTML table, with the default [`TableFormatOptions`].
+    pub fn to_html(&self) -> PolarsResult<String> {
+        self.to_table_string(&TableFormatOptions {
+            format: TableFormat::Html,
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_to_table_string() {
+        let df = df! {
+            "int" => [Some(1), None, Some(1234567)],
+            "float" => [1.0, -0.125, 1e20],
+            "str" => ["a|b", "<c>", "d"],
+        }
+        .unwrap();
+
+        let options = TableFormatOptions {
+            thousands_separator: Some('_'),
+            ..Default::default()
+        };
+        let expected = "\
+      int   float  str
+      i32     f64  str
+---------  ------  ---
+        1     1.0  a|b
+     null  -0.125  <c>
+1_234_567    1e20  d
+";
+        assert_eq!(df.to_table_string(&options).unwrap(), expected);
+
+        let expected = "\
+<table>
+<thead>
+<tr><th>int</th><th>float</th><th>str</th></tr>
+<tr><td>i32</td><td>f64</td><td>str</td></tr>
+</thead>
+<tbody>
+<tr><td>1</td><td>1.0</td><td>a|b</td></tr>
+<tr><td>null</td><td>-0.125</td><td>&lt;c&gt;</td></tr>
+<tr><td>1234567</td><td>1e20</td><td>d</td></tr>
+</tbody>
+</table>
+";
+        assert_eq!(df.to_html().unwrap(), expected);
+
+        let df = df! {
+            "a" => [1.5, 2.0, 3.25],
+            "b" => ["x|y", "z", "w"],
+        }
+        .unwrap();
+        let options = TableFormatOptions {
+            format: TableFormat::Markdown,
+            float_precision: Some(2),
+            decimal_separator: ',',
+            max_rows: Some(2),
+            include_dtypes: false,
+            ..Default::default()
+        };
+        let expected = "\
+|    a | b    |
+| ---: | ---- |
+| 1,50 | x\\|y |
+|  ... | ...  |
+| 3,25 | w    |
+";
+        assert_eq!(df.to_table_string(&options).unwrap(), expected);
+    }
+}
@@ -0,0 +1,588 @@
+//! Construction of [`DataFrame`]s from arbitrary serde sources.
+//!
+//! Records are converted to struct [`AnyValue`]s, from which the schema is inferred in the same
+//! way as for JSON: fields missing from a record are null, and fields of different types are cast
+//! to their supertype.
+use std::fmt::{self, Display};
+
+use polars_utils::format_pl_smallstr;
+use serde::de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{self, Serialize, Serializer};
+
+use crate::prelude::*;
+
+impl DataFrame {
+    /// Create a [`DataFrame`] from records that implement [`Serialize`].
+    ///
+    /// Every record must serialize to a struct or a map, of which the fields become the columns.
+    /// Sequences are stored as lists and nested structs and maps as structs.
+    pub fn from_serde_iter<T: Serialize>(
+        records: impl IntoIterator<Item = T>,
+    ) -> PolarsResult<Self> {
+        let rows = records
+            .into_iter()
+            .map(|record| record.serialize(ValueSerializer).map_err(|e| e.0))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        rows_to_df(rows)
+    }
+
+    /// Create a [`DataFrame`] from a serde [`Deserializer`] of a sequence of records.
+    ///
+    /// This ingests any self-describing serde format, e.g. CBOR, MessagePack or TOML, without
+    /// defining Rust types for the records first. Every record must be a struct or a map.
+    pub fn from_serde_deserializer<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> PolarsResult<Self> {
+        let rows = deserializer
+            .deserialize_seq(RowsVisitor)
+            .map_err(|e| polars_err!(ComputeError: "failed to deserialize records: {}", e))?;
+        rows_to_df(rows)
+    }
+}
+
+fn rows_to_df(rows: Vec<AnyValue<'static>>) -> PolarsResult<DataFrame> {
+    if let Some(row) = rows
+        .iter()
+        .find(|row| !matches!(row, AnyValue::Null | AnyValue::StructOwned(_)))
+    {
+        polars_bail!(ComputeError: "expected records to be structs or maps, got {}", row.dtype());
+    }
+    if rows.is_empty() {
+        return Ok(DataFrame::empty());
+    }
+    let s = Series::from_any_values(PlSmallStr::EMPTY, &rows, false)?;
+    match s.dtype() {
+        DataType::Struct(_) => Ok(s.struct_()?.clone().unnest()),
+        // All records are null.
+        _ => Ok(DataFrame::empty_with_height(s.len())),
+    }
+}
+
+fn list_value(values: Vec<AnyValue<'static>>) -> PolarsResult<AnyValue<'static>> {
+    Series::from_any_values(PlSmallStr::EMPTY, &values, false).map(AnyValue::List)
+}
+
+fn struct_value(entries: PlIndexMap<PlSmallStr, AnyValue<'static>>) -> AnyValue<'static> {
+    let (fields, values) = entries
+        .into_iter()
+        .map(|(name, value)| (Field::new(name, value.dtype()), value))
+        .unzip();
+    AnyValue::StructOwned(Box::new((values, fields)))
+}
+
+/// An externally tagged enum variant, stored as a struct with a single field.
+fn variant_value(variant: &'static str, value: AnyValue<'static>) -> AnyValue<'static> {
+    struct_value(PlIndexMap::from_iter([(PlSmallStr::from_static(variant), value)]))
+}
+
+fn key_to_name(key: AnyValue<'static>) -> PolarsResult<PlSmallStr> {
+    match key {
+        AnyValue::StringOwned(name) => Ok(name),
+        AnyValue::Null | AnyValue::List(_) | AnyValue::StructOwned(_) => {
+            polars_bail!(ComputeError: "map keys must be strings or numbers, got {}", key.dtype())
+        },
+        key => Ok(format_pl_smallstr!("{}", key)),
+    }
+}
+
+#[derive(Debug)]
+struct SerdeError(PolarsError);
+
+impl Display for SerdeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl std::error::Error for SerdeError {}
+
+impl ser::Error for SerdeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self(polars_err!(ComputeError: "{}", msg))
+    }
+}
+
+impl From<PolarsError> for SerdeError {
+    fn from(err: PolarsError) -> Self {
+        Self(err)
+    }
+}
+
+type SerResult = Result<AnyValue<'static>, SerdeError>;
+
+/// Serializes a value to an [`AnyValue`].
+struct ValueSerializer;
+
+impl Serializer for ValueSerializer {
+    type Ok = AnyValue<'static>;
+    type Error = SerdeError;
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = SeqSerializer;
+    type SerializeTupleStruct = SeqSerializer;
+    type SerializeTupleVariant = VariantSerializer<SeqSerializer>;
+    type SerializeMap = MapSerializer;
+    type SerializeStruct = MapSerializer;
+    type SerializeStructVariant = VariantSerializer<MapSerializer>;
+
+    fn serialize_bool(self, v: bool) -> SerResult {
+        Ok(AnyValue::Boolean(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> SerResult {
+        Ok(AnyValue::Int8(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> SerResult {
+        Ok(AnyValue::Int16(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> SerResult {
+        Ok(AnyValue::Int32(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> SerResult {
+        Ok(AnyValue::Int64(v))
+    }
+
+    fn serialize_i128(self, v: i128) -> SerResult {
+        Ok(AnyValue::Int128(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> SerResult {
+        Ok(AnyValue::UInt8(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> SerResult {
+        Ok(AnyValue::UInt16(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> SerResult {
+        Ok(AnyValue::UInt32(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> SerResult {
+        Ok(AnyValue::UInt64(v))
+    }
+
+    fn serialize_u128(self, v: u128) -> SerResult {
+        Ok(AnyValue::UInt128(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> SerResult {
+        Ok(AnyValue::Float32(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> SerResult {
+        Ok(AnyValue::Float64(v))
+    }
+
+    fn serialize_char(self, v: char) -> SerResult {
+        Ok(AnyValue::StringOwned(format_pl_smallstr!("{}", v)))
+    }
+
+    fn serialize_str(self, v: &str) -> SerResult {
+        Ok(AnyValue::StringOwned(v.into()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> SerResult {
+        Ok(AnyValue::BinaryOwned(v.to_vec()))
+    }
+
+    fn serialize_none(self) -> SerResult {
+        Ok(AnyValue::Null)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> SerResult {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> SerResult {
+        Ok(AnyValue::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> SerResult {
+        Ok(AnyValue::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> SerResult {
+        Ok(AnyValue::StringOwned(PlSmallStr::from_static(variant)))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> SerResult {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> SerResult {
+        Ok(variant_value(variant, value.serialize(self)?))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqSerializer, SerdeError> {
+        Ok(SeqSerializer(Vec::with_capacity(len.unwrap_or_default())))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqSerializer, SerdeError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer, SerdeError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<VariantSerializer<SeqSerializer>, SerdeError> {
+        Ok(VariantSerializer {
+            variant,
+            inner: self.serialize_seq(Some(len))?,
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<MapSerializer, SerdeError> {
+        Ok(MapSerializer::default())
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<MapSerializer, SerdeError> {
+        Ok(MapSerializer::default())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<VariantSerializer<MapSerializer>, SerdeError> {
+        Ok(VariantSerializer {
+            variant,
+            inner: MapSerializer::default(),
+        })
+    }
+}
+
+struct SeqSerializer(Vec<AnyValue<'static>>);
+
+impl ser::SerializeSeq for SeqSerializer {
+    type Ok = AnyValue<'static>;
+    type Error = SerdeError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SerdeError> {
+        self.0.push(value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> SerResult {
+        Ok(list_value(self.0)?)
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer {
+    type Ok = AnyValue<'static>;
+    type Error = SerdeError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SerdeError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> SerResult {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer {
+    type Ok = AnyValue<'static>;
+    type Error = SerdeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SerdeError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> SerResult {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+#[derive(Default)]
+struct MapSerializer {
+    entries: PlIndexMap<PlSmallStr, AnyValue<'static>>,
+    next_key: Option<PlSmallStr>,
+}
+
+impl ser::SerializeMap for MapSerializer {
+    type Ok = AnyValue<'static>;
+    type Error = SerdeError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), SerdeError> {
+        self.next_key = Some(key_to_name(key.serialize(ValueSerializer)?)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SerdeError> {
+        let key = self.next_key.take().unwrap();
+        self.entries.insert(key, value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> SerResult {
+        Ok(struct_value(self.entries))
+    }
+}
+
+impl ser::SerializeStruct for MapSerializer {
+    type Ok = AnyValue<'static>;
+    type Error = SerdeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SerdeError> {
+        let value = value.serialize(ValueSerializer)?;
+        self.entries.insert(PlSmallStr::from_static(key), value);
+        Ok(())
+    }
+
+    fn end(self) -> SerResult {
+        ser::SerializeMap::end(self)
+    }
+}
+
+struct VariantSerializer<S> {
+    variant: &'static str,
+    inner: S,
+}
+
+impl ser::SerializeTupleVariant for VariantSerializer<SeqSerializer> {
+    type Ok = AnyValue<'static>;
+    type Error = SerdeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SerdeError> {
+        ser::SerializeSeq::serialize_element(&mut self.inner, value)
+    }
+
+    fn end(self) -> SerResult {
+        let value = ser::SerializeSeq::end(self.inner)?;
+        Ok(variant_value(self.variant, value))
+    }
+}
+
+impl ser::SerializeStructVariant for VariantSerializer<MapSerializer> {
+    type Ok = AnyValue<'static>;
+    type Error = SerdeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SerdeError> {
+        ser::SerializeStruct::serialize_field(&mut self.inner, key, value)
+    }
+
+    fn end(self) -> SerResult {
+        let value = ser::SerializeMap::end(self.inner)?;
+        Ok(variant_value(self.variant, value))
+    }
+}
+
+/// Deserializes a value of any self-describing format to an [`AnyValue`].
+struct ValueVisitor;
+
+impl<'de> DeserializeSeed<'de> for ValueVisitor {
+    type Value = AnyValue<'static>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = AnyValue<'static>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("any value")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(AnyValue::Boolean(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(AnyValue::Int64(v))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        Ok(AnyValue::Int128(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(AnyValue::UInt64(v))
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        Ok(AnyValue::UInt128(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(AnyValue::Float64(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(AnyValue::StringOwned(v.into()))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(AnyValue::BinaryOwned(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(AnyValue::BinaryOwned(v))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(AnyValue::Null)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(AnyValue::Null)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(value) = seq.next_element_seed(ValueVisitor)? {
+            values.push(value);
+        }
+        list_value(values).map_err(de::Error::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut entries = PlIndexMap::with_capacity(map.size_hint().unwrap_or_default());
+        while let Some(key) = map.next_key_seed(ValueVisitor)? {
+            let name = key_to_name(key).map_err(de::Error::custom)?;
+            entries.insert(name, map.next_value_seed(ValueVisitor)?);
+        }
+        Ok(struct_value(entries))
+    }
+}
+
+/// Deserializes a sequence of records.
+struct RowsVisitor;
+
+impl<'de> Visitor<'de> for RowsVisitor {
+    type Value = Vec<AnyValue<'static>>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a sequence of records")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut rows = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(row) = seq.next_element_seed(ValueVisitor)? {
+            rows.push(row);
+        }
+        Ok(rows)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Serialize;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    enum Status {
+        Active,
+        Blocked,
+    }
+
+    #[derive(Serialize)]
+    struct Record {
+        id: u32,
+        name: Option<String>,
+        tags: Vec<String>,
+        status: Status,
+    }
+
+    #[test]
+    fn test_from_serde_iter() -> PolarsResult<()> {
+        let records = [
+            Record {
+                id: 1,
+                name: None,
+                tags: vec![],
+                status: Status::Active,
+            },
+            Record {
+                id: 2,
+                name: Some("b".into()),
+                tags: vec!["x".into(), "y".into()],
+                status: Status::Blocked,
+            },
+        ];
+        let df = DataFrame::from_serde_iter(&records)?;
+        assert_eq!(df.shape(), (2, 4));
+        assert_eq!(df.column("id")?.dtype(), &DataType::UInt32);
+        assert_eq!(df.column("name")?.dtype(), &DataType::String);
+        assert_eq!(df.column("name")?.null_count(), 1);
+        assert_eq!(
+            df.column("tags")?.dtype(),
+            &DataType::List(Box::new(DataType::String))
+        );
+        assert_eq!(df.column("status")?.dtype(), &DataType::String);
+
+        assert!(DataFrame::from_serde_iter([1, 2]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_serde_deserializer() -> PolarsResult<()> {
+        let json = r#"[{"a": 1, "b": [1.5]}, {"a": null, "c": {"d": "x"}}]"#;
+        let mut deserializer = serde_json::Deserializer::from_str(json);
+        let df = DataFrame::from_serde_deserializer(&mut deserializer)?;
+        assert_eq!(df.get_column_names(), &["a", "b", "c"]);
+        assert_eq!(df.column("a")?.null_count(), 1);
+        assert_eq!(
+            df.column("b")?.dtype(),
+            &DataType::List(Box::new(DataType::Float64))
+        );
+        assert!(matches!(df.column("c")?.dtype(), DataType::Struct(_)));
+        Ok(())
+    }
+}
@@ -1,6 +1,10 @@
 mod av_buffer;
 mod dataframe;
+#[cfg(all(feature = "serde", feature = "dtype-struct"))]
+mod from_serde;
 mod transpose;
+#[cfg(feature = "rows")]
+pub mod typed;
 
 use std::borrow::Borrow;
 use std::fmt::Debug;
@@ -0,0 +1,360 @@
+//! Conversion between [`DataFrame`]s and rows stored as Rust structs.
+//!
+//! [`IntoDataFrame`] and [`FromDataFrame`] are implemented with the derive macros of the
+//! `polars-derive` crate. Every field of the struct becomes a column of the same name; the types
+//! of the fields must implement [`IntoColumnValue`] or [`FromColumnValue`].
+#[cfg(feature = "dtype-date")]
+use arrow::temporal_conversions::{EPOCH_DAYS_FROM_CE, date32_to_date};
+#[cfg(feature = "dtype-duration")]
+use arrow::temporal_conversions::{
+    duration_ms_to_duration, duration_ns_to_duration, duration_us_to_duration,
+};
+#[cfg(feature = "dtype-time")]
+use arrow::temporal_conversions::time64ns_to_time;
+#[cfg(feature = "dtype-datetime")]
+use arrow::temporal_conversions::{
+    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
+};
+#[cfg(feature = "dtype-date")]
+use chrono::Datelike;
+
+#[cfg(feature = "dtype-time")]
+use crate::chunked_array::temporal::time_to_time64ns;
+use crate::prelude::*;
+
+/// A Rust value that can be stored in a column.
+pub trait IntoColumnValue {
+    /// The data type of the column.
+    fn dtype() -> DataType;
+
+    fn into_column_value(self) -> PolarsResult<AnyValue<'static>>;
+}
+
+/// A Rust value that can be read from a column.
+pub trait FromColumnValue: Sized {
+    /// The data type the column is cast to before reading the values.
+    fn dtype() -> DataType;
+
+    fn from_column_value(value: AnyValue<'_>) -> PolarsResult<Self>;
+}
+
+/// Rows that can be converted into a [`DataFrame`].
+pub trait IntoDataFrame: Sized {
+    fn into_df(rows: impl IntoIterator<Item = Self>) -> PolarsResult<DataFrame>;
+}
+
+/// Rows that can be read from a [`DataFrame`].
+pub trait FromDataFrame: Sized {
+    fn from_df(df: &DataFrame) -> PolarsResult<Vec<Self>>;
+}
+
+fn mismatch<T>(value: &AnyValue) -> PolarsError {
+    match value {
+        AnyValue::Null => polars_err!(
+            SchemaMismatch: "unexpected null value for `{}`; use an `Option` for nullable columns",
+            std::any::type_name::<T>()
+        ),
+        value => polars_err!(
+            SchemaMismatch: "cannot read a value of type {} as `{}`",
+            value.dtype(), std::any::type_name::<T>()
+        ),
+    }
+}
+
+#[doc(hidden)]
+pub fn values_to_column<T: IntoColumnValue>(name: &str, values: Vec<T>) -> PolarsResult<Column> {
+    let values = values
+        .into_iter()
+        .map(T::into_column_value)
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let dtype = <T as IntoColumnValue>::dtype();
+    Series::from_any_values_and_dtype(name.into(), &values, &dtype, true).map(Column::from)
+}
+
+#[doc(hidden)]
+pub fn column_to_values<T: FromColumnValue>(df: &DataFrame, name: &str) -> PolarsResult<Vec<T>> {
+    let s = df
+        .column(name)?
+        .as_materialized_series()
+        .strict_cast(&<T as FromColumnValue>::dtype())?;
+    s.iter().map(T::from_column_value).collect()
+}
+
+#[doc(hidden)]
+#[cfg(feature = "dtype-struct")]
+pub fn struct_field<T: IntoColumnValue>(name: &str) -> Field {
+    Field::new(name.into(), <T as IntoColumnValue>::dtype())
+}
+
+#[doc(hidden)]
+#[cfg(feature = "dtype-struct")]
+pub fn struct_value(values: Vec<AnyValue<'static>>, dtype: DataType) -> AnyValue<'static> {
+    let DataType::Struct(fields) = dtype else {
+        unreachable!()
+    };
+    AnyValue::StructOwned(Box::new((values, fields)))
+}
+
+/// The fields of a struct value, taken by name when building the Rust struct.
+#[doc(hidden)]
+#[cfg(feature = "dtype-struct")]
+pub struct StructFields {
+    fields: Vec<(PlSmallStr, AnyValue<'static>)>,
+}
+
+#[cfg(feature = "dtype-struct")]
+impl StructFields {
+    pub fn new<T>(value: AnyValue<'_>) -> PolarsResult<Self> {
+        let fields = match &value {
+            AnyValue::Struct(_, _, fields) => fields
+                .iter()
+                .map(|fld| fld.name.clone())
+                .zip(value._iter_struct_av().map(AnyValue::into_static))
+                .collect(),
+            AnyValue::StructOwned(payload) => payload
+                .1
+                .iter()
+                .map(|fld| fld.name.clone())
+                .zip(payload.0.iter().cloned().map(AnyValue::into_static))
+                .collect(),
+            value => return Err(mismatch::<T>(value)),
+        };
+        Ok(Self { fields })
+    }
+
+    pub fn take<T: FromColumnValue>(&mut self, name: &str) -> PolarsResult<T> {
+        let Some((_, value)) = self.fields.iter_mut().find(|(fld, _)| fld == name) else {
+            polars_bail!(SchemaFieldNotFound: "{}", name);
+        };
+        T::from_column_value(std::mem::take(value))
+    }
+}
+
+macro_rules! impl_column_value {
+    ($($ty:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl IntoColumnValue for $ty {
+                fn dtype() -> DataType {
+                    DataType::$variant
+                }
+
+                fn into_column_value(self) -> PolarsResult<AnyValue<'static>> {
+                    Ok(AnyValue::$variant(self))
+                }
+            }
+
+            impl FromColumnValue for $ty {
+                fn dtype() -> DataType {
+                    DataType::$variant
+                }
+
+                fn from_column_value(value: AnyValue<'_>) -> PolarsResult<Self> {
+                    match value {
+                        AnyValue::$variant(v) => Ok(v),
+                        value => Err(mismatch::<Self>(&value)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_column_value!(
+    bool => Boolean,
+    u8 => UInt8,
+    u16 => UInt16,
+    u32 => UInt32,
+    u64 => UInt64,
+    i8 => Int8,
+    i16 => Int16,
+    i32 => Int32,
+    i64 => Int64,
+    f32 => Float32,
+    f64 => Float64,
+);
+
+impl IntoColumnValue for String {
+    fn dtype() -> DataType {
+        DataType::String
+    }
+
+    fn into_column_value(self) -> PolarsResult<AnyValue<'static>> {
+        Ok(AnyValue::StringOwned(self.into()))
+    }
+}
+
+impl FromColumnValue for String {
+    fn dtype() -> DataType {
+        DataType::String
+    }
+
+    fn from_column_value(value: AnyValue<'_>) -> PolarsResult<Self> {
+        match value {
+            AnyValue::String(v) => Ok(v.to_string()),
+            AnyValue::StringOwned(v) => Ok(v.into_string()),
+            value => Err(mismatch::<Self>(&value)),
+        }
+    }
+}
+
+impl<T: IntoColumnValue> IntoColumnValue for Option<T> {
+    fn dtype() -> DataType {
+        T::dtype()
+    }
+
+    fn into_column_value(self) -> PolarsResult<AnyValue<'static>> {
+        match self {
+            Some(v) => v.into_column_value(),
+            None => Ok(AnyValue::Null),
+        }
+    }
+}
+
+impl<T: FromColumnValue> FromColumnValue for Option<T> {
+    fn dtype() -> DataType {
+        T::dtype()
+    }
+
+    fn from_column_value(value: AnyValue<'_>) -> PolarsResult<Self> {
+        match value {
+            AnyValue::Null => Ok(None),
+            value => T::from_column_value(value).map(Some),
+        }
+    }
+}
+
+impl<T: IntoColumnValue> IntoColumnValue for Vec<T> {
+    fn dtype() -> DataType {
+        DataType::List(Box::new(<T as IntoColumnValue>::dtype()))
+    }
+
+    fn into_column_value(self) -> PolarsResult<AnyValue<'static>> {
+        let s = values_to_column(PlSmallStr::EMPTY.as_str(), self)?;
+        Ok(AnyValue::List(s.take_materialized_series()))
+    }
+}
+
+impl<T: FromColumnValue> FromColumnValue for Vec<T> {
+    fn dtype() -> DataType {
+        DataType::List(Box::new(<T as FromColumnValue>::dtype()))
+    }
+
+    fn from_column_value(value: AnyValue<'_>) -> PolarsResult<Self> {
+        match value {
+            AnyValue::List(s) => s.iter().map(T::from_column_value).collect(),
+            value => Err(mismatch::<Self>(&value)),
+        }
+    }
+}
+
+#[cfg(feature = "dtype-date")]
+impl IntoColumnValue for chrono::NaiveDate {
+    fn dtype() -> DataType {
+        DataType::Date
+    }
+
+    fn into_column_value(self) -> PolarsResult<AnyValue<'static>> {
+        Ok(AnyValue::Date(self.num_days_from_ce() - EPOCH_DAYS_FROM_CE))
+    }
+}
+
+#[cfg(feature = "dtype-date")]
+impl FromColumnValue for chrono::NaiveDate {
+    fn dtype() -> DataType {
+        DataType::Date
+    }
+
+    fn from_column_value(value: AnyValue<'_>) -> PolarsResult<Self> {
+        match value {
+            AnyValue::Date(v) => Ok(date32_to_date(v)),
+            value => Err(mismatch::<Self>(&value)),
+        }
+    }
+}
+
+#[cfg(feature = "dtype-datetime")]
+impl IntoColumnValue for chrono::NaiveDateTime {
+    fn dtype() -> DataType {
+        DataType::Datetime(TimeUnit::Microseconds, None)
+    }
+
+    fn into_column_value(self) -> PolarsResult<AnyValue<'static>> {
+        let v = datetime_to_timestamp_us(self);
+        Ok(AnyValue::Datetime(v, TimeUnit::Microseconds, None))
+    }
+}
+
+#[cfg(feature = "dtype-datetime")]
+impl FromColumnValue for chrono::NaiveDateTime {
+    fn dtype() -> DataType {
+        DataType::Datetime(TimeUnit::Microseconds, None)
+    }
+
+    fn from_column_value(value: AnyValue<'_>) -> PolarsResult<Self> {
+        match value {
+            AnyValue::Datetime(v, tu, _) | AnyValue::DatetimeOwned(v, tu, _) => Ok(match tu {
+                TimeUnit::Nanoseconds => timestamp_ns_to_datetime(v),
+                TimeUnit::Microseconds => timestamp_us_to_datetime(v),
+                TimeUnit::Milliseconds => timestamp_ms_to_datetime(v),
+            }),
+            value => Err(mismatch::<Self>(&value)),
+        }
+    }
+}
+
+#[cfg(feature = "dtype-time")]
+impl IntoColumnValue for chrono::NaiveTime {
+    fn dtype() -> DataType {
+        DataType::Time
+    }
+
+    fn into_column_value(self) -> PolarsResult<AnyValue<'static>> {
+        Ok(AnyValue::Time(time_to_time64ns(&self)))
+    }
+}
+
+#[cfg(feature = "dtype-time")]
+impl FromColumnValue for chrono::NaiveTime {
+    fn dtype() -> DataType {
+        DataType::Time
+    }
+
+    fn from_column_value(value: AnyValue<'_>) -> PolarsResult<Self> {
+        match value {
+            AnyValue::Time(v) => Ok(time64ns_to_time(v)),
+            value => Err(mismatch::<Self>(&value)),
+        }
+    }
+}
+
+#[cfg(feature = "dtype-duration")]
+impl IntoColumnValue for chrono::TimeDelta {
+    fn dtype() -> DataType {
+        DataType::Duration(TimeUnit::Microseconds)
+    }
+
+    fn into_column_value(self) -> PolarsResult<AnyValue<'static>> {
+        let v = self.num_microseconds().ok_or_else(
+            || polars_err!(ComputeError: "duration {} does not fit in microseconds", self),
+        )?;
+        Ok(AnyValue::Duration(v, TimeUnit::Microseconds))
+    }
+}
+
+#[cfg(feature = "dtype-duration")]
+impl FromColumnValue for chrono::TimeDelta {
+    fn dtype() -> DataType {
+        DataType::Duration(TimeUnit::Microseconds)
+    }
+
+    fn from_column_value(value: AnyValue<'_>) -> PolarsResult<Self> {
+        match value {
+            AnyValue::Duration(v, tu) => Ok(match tu {
+                TimeUnit::Nanoseconds => duration_ns_to_duration(v),
+                TimeUnit::Microseconds => duration_us_to_duration(v),
+                TimeUnit::Milliseconds => duration_ms_to_duration(v),
+            }),
+            value => Err(mismatch::<Self>(&value)),
+        }
+    }
+}
@@ -33,10 +33,12 @@ pub mod testing;
 mod tests;
 
 use std::cell::{Cell, RefCell};
-use std::sync::{LazyLock, Mutex};
+use std::num::NonZeroUsize;
+use std::sync::{Arc, LazyLock, Mutex, RwLock};
 
 pub use datatypes::SchemaExtPl;
 pub use hashing::IdBuildHasher;
+use polars_error::{PolarsResult, polars_err};
 use rayon::{ThreadPool, ThreadPoolBuilder};
 
 // A secret ID used to limit deserialization of raw pointers to those
@@ -62,6 +64,28 @@ thread_local! {
     );
 }
 
+/// Pools that run work instead of [`THREAD_POOL`], see [`POOL::install_scoped`].
+#[cfg(any(target_os = "emscripten", not(target_family = "wasm")))]
+static SCOPED_POOLS: RwLock<Vec<Arc<ThreadPool>>> = RwLock::new(Vec::new());
+
+/// The pools created by [`POOL::sized`], at most one per number of threads.
+static SIZED_POOLS: Mutex<Vec<Arc<ThreadPool>>> = Mutex::new(Vec::new());
+
+/// The scoped pool of which the current thread is a worker.
+#[cfg(any(target_os = "emscripten", not(target_family = "wasm")))]
+fn current_scoped_pool() -> Option<Arc<ThreadPool>> {
+    // Only workers of pools other than the global pool can be part of a scoped pool.
+    rayon::current_thread_index()?;
+    if THREAD_POOL.current_thread_index().is_some() {
+        return None;
+    }
+    let pools = SCOPED_POOLS.read().unwrap();
+    pools
+        .iter()
+        .find(|p| p.current_thread_index().is_some())
+        .cloned()
+}
+
 impl POOL {
     pub fn install<OP, R>(&self, op: OP) -> R
     where
@@ -173,13 +197,82 @@ impl POOL {
         }
     }
 
+    /// Run `op` on `pool` instead of the global thread pool.
+    ///
+    /// All parallel work that polars starts from `op` also runs on `pool`, which isolates it from
+    /// other work on the global thread pool and limits it to the threads of `pool`.
+    pub fn install_scoped<OP, R>(&self, pool: &Arc<ThreadPool>, op: OP) -> R
+    where
+        OP: FnOnce() -> R + Send,
+        R: Send,
+    {
+        #[cfg(not(any(target_os = "emscripten", not(target_family = "wasm"))))]
+        {
+            let _ = pool;
+            op()
+        }
+
+        #[cfg(any(target_os = "emscripten", not(target_family = "wasm")))]
+        {
+            struct Unregister<'a>(&'a Arc<ThreadPool>);
+
+            impl Drop for Unregister<'_> {
+                fn drop(&mut self) {
+                    let mut pools = SCOPED_POOLS.write().unwrap();
+                    if let Some(i) = pools.iter().position(|p| Arc::ptr_eq(p, self.0)) {
+                        pools.swap_remove(i);
+                    }
+                }
+            }
+
+            // A pool is registered once per call, so concurrent calls can share a pool.
+            SCOPED_POOLS.write().unwrap().push(pool.clone());
+            let _unregister = Unregister(pool);
+            pool.install(op)
+        }
+    }
+
+    /// A pool of `num_threads` threads to use with [`POOL::install_scoped`].
+    ///
+    /// The pools are created once per number of threads and shared by all callers.
+    pub fn sized(&self, num_threads: NonZeroUsize) -> PolarsResult<Arc<ThreadPool>> {
+        let mut pools = SIZED_POOLS.lock().unwrap();
+        let num_threads = num_threads.get();
+        if let Some(pool) = pools.iter().find(|p| p.current_num_threads() == num_threads) {
+            return Ok(pool.clone());
+        }
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .thread_name(move |i| format!("polars-{num_threads}-{i}"))
+            .build()
+            .map_err(|e| polars_err!(ComputeError: "could not spawn threads: {}", e))?;
+        let pool = Arc::new(pool);
+        pools.push(pool.clone());
+        Ok(pool)
+    }
+
+    /// Whether the current thread runs work of a pool installed with [`POOL::install_scoped`].
+    pub fn is_scoped(&self) -> bool {
+        #[cfg(not(any(target_os = "emscripten", not(target_family = "wasm"))))]
+        {
+            false
+        }
+
+        #[cfg(any(target_os = "emscripten", not(target_family = "wasm")))]
+        {
+            current_scoped_pool().is_some()
+        }
+    }
+
     #[cfg(any(target_os = "emscripten", not(target_family = "wasm")))]
     pub fn with<OP, R>(&self, op: OP) -> R
     where
         OP: FnOnce(&ThreadPool) -> R + Send,
         R: Send,
     {
-        if ALLOW_RAYON_THREADS.get() || THREAD_POOL.current_thread_index().is_some() {
+        if let Some(pool) = current_scoped_pool() {
+            op(&pool)
+        } else if ALLOW_RAYON_THREADS.get() || THREAD_POOL.current_thread_index().is_some() {
             op(&THREAD_POOL)
         } else {
             NOOP_POOL.with(|v| op(&v.borrow()))
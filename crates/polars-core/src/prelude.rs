@@ -50,10 +50,14 @@ pub use crate::frame::explode::UnpivotArgsIR;
 pub(crate) use crate::frame::group_by::aggregations::*;
 #[cfg(feature = "algorithm_group_by")]
 pub use crate::frame::group_by::*;
-pub use crate::frame::{DataFrame, UniqueKeepStrategy};
+pub use crate::frame::{
+    ArrowStreamOptions, CastPolicy, DataFrame, TableFormat, TableFormatOptions, UniqueKeepStrategy,
+};
+#[cfg(feature = "dataframe_index")]
+pub use crate::frame::{DataFrameIndex, IndexJoinType, SortedDataFrameIndex};
 pub use crate::hashing::VecHash;
 pub use crate::named_from::{NamedFrom, NamedFromOwned};
-pub use crate::scalar::Scalar;
+pub use crate::scalar::{Scalar, TypedScalar};
 pub use crate::schema::*;
 #[cfg(feature = "checked_arithmetic")]
 pub use crate::series::arithmetic::checked::NumOpsDispatchChecked;
@@ -2,6 +2,9 @@ mod from;
 mod new;
 #[cfg(any(feature = "serde", feature = "dsl-schema"))]
 mod serde;
+mod typed;
+
+pub use typed::TypedScalar;
 
 use std::hash::Hash;
 
@@ -0,0 +1,167 @@
+use polars_error::{PolarsError, PolarsResult, polars_ensure};
+use polars_utils::pl_str::PlSmallStr;
+
+use super::Scalar;
+use crate::prelude::*;
+
+/// A single, possibly null, value of the physical type `T`.
+///
+/// Unlike [`Scalar`], the value is stored as its native type instead of an [`AnyValue`], so it
+/// can be read and written without matching on the value. Converting into a [`Scalar`], e.g. to
+/// build a literal, is cheap for numeric types.
+pub struct TypedScalar<T: PolarsDataType> {
+    value: Option<T::OwnedPhysical>,
+}
+
+// Implemented by hand, as deriving would require these traits of `T`.
+impl<T: PolarsDataType> Clone for TypedScalar<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<T: PolarsDataType> std::fmt::Debug for TypedScalar<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypedScalar")
+            .field("dtype", &T::get_static_dtype())
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl<T: PolarsDataType> PartialEq for TypedScalar<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: PolarsDataType> TypedScalar<T> {
+    #[inline(always)]
+    pub const fn new(value: Option<T::OwnedPhysical>) -> Self {
+        Self { value }
+    }
+
+    #[inline(always)]
+    pub const fn null() -> Self {
+        Self::new(None)
+    }
+
+    #[inline(always)]
+    pub fn is_null(&self) -> bool {
+        self.value.is_none()
+    }
+
+    #[inline(always)]
+    pub fn value(&self) -> Option<&T::OwnedPhysical> {
+        self.value.as_ref()
+    }
+
+    #[inline(always)]
+    pub fn into_value(self) -> Option<T::OwnedPhysical> {
+        self.value
+    }
+
+    #[inline(always)]
+    pub fn update(&mut self, value: Option<T::OwnedPhysical>) {
+        self.value = value;
+    }
+
+    pub fn dtype(&self) -> DataType {
+        T::get_static_dtype()
+    }
+}
+
+impl<T: PolarsDataType> Default for TypedScalar<T> {
+    fn default() -> Self {
+        Self::null()
+    }
+}
+
+impl<T: PolarsNumericType> From<TypedScalar<T>> for Scalar {
+    #[inline]
+    fn from(value: TypedScalar<T>) -> Self {
+        let value = value.into_value().map_or(AnyValue::Null, AnyValue::from);
+        Scalar::new(T::get_static_dtype(), value)
+    }
+}
+
+impl From<TypedScalar<BooleanType>> for Scalar {
+    #[inline]
+    fn from(value: TypedScalar<BooleanType>) -> Self {
+        let value = value.into_value().map_or(AnyValue::Null, AnyValue::Boolean);
+        Scalar::new(DataType::Boolean, value)
+    }
+}
+
+impl From<TypedScalar<StringType>> for Scalar {
+    #[inline]
+    fn from(value: TypedScalar<StringType>) -> Self {
+        let value = value
+            .into_value()
+            .map_or(AnyValue::Null, |v| AnyValue::StringOwned(PlSmallStr::from_string(v)));
+        Scalar::new(DataType::String, value)
+    }
+}
+
+impl<T: PolarsNumericType> TryFrom<Scalar> for TypedScalar<T> {
+    type Error = PolarsError;
+
+    fn try_from(value: Scalar) -> PolarsResult<Self> {
+        polars_ensure!(
+            value.dtype() == &T::get_static_dtype(),
+            SchemaMismatch: "expected a scalar of type {}, got {}",
+            T::get_static_dtype(), value.dtype()
+        );
+        Ok(Self::new(value.value().extract()))
+    }
+}
+
+impl<T: PolarsDataType> ChunkedArray<T>
+where
+    for<'a> T::Physical<'a>: Into<T::OwnedPhysical>,
+{
+    /// Get the value at `index` as a [`TypedScalar`].
+    pub fn get_scalar(&self, index: usize) -> PolarsResult<TypedScalar<T>> {
+        polars_ensure!(index < self.len(), oob = index, self.len());
+        Ok(TypedScalar::new(self.get(index).map(Into::into)))
+    }
+}
+
+impl Series {
+    /// Get the value at `index` as a [`TypedScalar`] of the physical type `T`, without going
+    /// through [`AnyValue`]. Errors if `T` is not the type of the [`Series`].
+    pub fn get_typed<T>(&self, index: usize) -> PolarsResult<TypedScalar<T>>
+    where
+        T: PolarsPhysicalType,
+        for<'a> T::Physical<'a>: Into<T::OwnedPhysical>,
+    {
+        self.unpack::<T>()?.get_scalar(index)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_typed_scalar() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [Some(1i64), None]);
+        assert_eq!(s.get_typed::<Int64Type>(0)?.value(), Some(&1));
+        assert!(s.get_typed::<Int64Type>(1)?.is_null());
+        assert!(s.get_typed::<Int64Type>(2).is_err());
+        assert!(s.get_typed::<Int32Type>(0).is_err());
+
+        let s = Series::new("a".into(), ["x"]);
+        assert_eq!(
+            s.get_typed::<StringType>(0)?.into_value().as_deref(),
+            Some("x")
+        );
+
+        let scalar = Scalar::from(TypedScalar::<Float64Type>::new(Some(1.5)));
+        assert_eq!(scalar, Scalar::from(1.5));
+        let typed = TypedScalar::<Float64Type>::try_from(scalar)?;
+        assert_eq!(typed.into_value(), Some(1.5));
+        assert!(TypedScalar::<Int64Type>::try_from(Scalar::from(1.5)).is_err());
+        Ok(())
+    }
+}
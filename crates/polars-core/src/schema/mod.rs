@@ -4,7 +4,7 @@ use arrow::bitmap::Bitmap;
 use polars_utils::pl_str::PlSmallStr;
 
 use crate::prelude::*;
-use crate::utils::try_get_supertype;
+use crate::utils::PromotionRules;
 
 pub mod iceberg;
 
@@ -24,6 +24,13 @@ pub trait SchemaExt {
 
     fn to_supertype(&mut self, other: &Schema) -> PolarsResult<bool>;
 
+    /// Like [`to_supertype`][Self::to_supertype], but with configurable [`PromotionRules`].
+    fn to_supertype_with_rules(
+        &mut self,
+        other: &Schema,
+        rules: PromotionRules,
+    ) -> PolarsResult<bool>;
+
     /// Select fields using a bitmap.
     fn project_select(&self, select: &Bitmap) -> Self;
 }
@@ -82,13 +89,21 @@ impl SchemaExt for Schema {
 
     /// Take another [`Schema`] and try to find the supertypes between them.
     fn to_supertype(&mut self, other: &Schema) -> PolarsResult<bool> {
+        self.to_supertype_with_rules(other, PromotionRules::default())
+    }
+
+    fn to_supertype_with_rules(
+        &mut self,
+        other: &Schema,
+        rules: PromotionRules,
+    ) -> PolarsResult<bool> {
         polars_ensure!(self.len() == other.len(), ComputeError: "schema lengths differ");
 
         let mut changed = false;
         for ((k, dt), (other_k, other_dt)) in self.iter_mut().zip(other.iter()) {
             polars_ensure!(k == other_k, ComputeError: "schema names differ: got {}, expected {}", k, other_k);
 
-            let st = try_get_supertype(dt, other_dt)?;
+            let st = rules.try_get_supertype(dt, other_dt)?;
             changed |= (&st != dt) || (&st != other_dt);
             *dt = st
         }
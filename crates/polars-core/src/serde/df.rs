@@ -32,7 +32,7 @@ impl DataFrame {
         }
 
         let mut ipc_writer =
-            arrow::io::ipc::write::StreamWriter::new(writer, WriteOptions { compression: None });
+            arrow::io::ipc::write::StreamWriter::new(writer, WriteOptions::default());
 
         ipc_writer.set_custom_schema_metadata(Arc::new(Metadata::from_iter(
             self.columns().iter().map(|c| {
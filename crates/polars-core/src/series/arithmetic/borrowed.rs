@@ -1,4 +1,6 @@
 use super::*;
+#[cfg(feature = "dtype-extension")]
+use crate::datatypes::extension::{ExtensionBinaryOp, apply_extension_binary_kernel};
 use crate::utils::align_chunks_binary;
 
 pub trait NumOpsDispatchInner: PolarsDataType + Sized {
@@ -502,6 +504,10 @@ impl Add for &Series {
 
     fn add(self, rhs: Self) -> Self::Output {
         check_lengths(self, rhs)?;
+        #[cfg(feature = "dtype-extension")]
+        if let Some(out) = apply_extension_binary_kernel(self, rhs, ExtensionBinaryOp::Add) {
+            return out;
+        }
         match (self.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-struct")]
             (DataType::Struct(_), DataType::Struct(_)) => {
@@ -527,6 +533,10 @@ impl Sub for &Series {
 
     fn sub(self, rhs: Self) -> Self::Output {
         check_lengths(self, rhs)?;
+        #[cfg(feature = "dtype-extension")]
+        if let Some(out) = apply_extension_binary_kernel(self, rhs, ExtensionBinaryOp::Sub) {
+            return out;
+        }
         match (self.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-struct")]
             (DataType::Struct(_), DataType::Struct(_)) => {
@@ -557,6 +567,10 @@ impl Mul for &Series {
     /// ```
     fn mul(self, rhs: Self) -> Self::Output {
         check_lengths(self, rhs)?;
+        #[cfg(feature = "dtype-extension")]
+        if let Some(out) = apply_extension_binary_kernel(self, rhs, ExtensionBinaryOp::Mul) {
+            return out;
+        }
 
         use DataType::*;
         match (self.dtype(), rhs.dtype()) {
@@ -598,6 +612,10 @@ impl Div for &Series {
     /// ```
     fn div(self, rhs: Self) -> Self::Output {
         check_lengths(self, rhs)?;
+        #[cfg(feature = "dtype-extension")]
+        if let Some(out) = apply_extension_binary_kernel(self, rhs, ExtensionBinaryOp::Div) {
+            return out;
+        }
         use DataType::*;
         match (self.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-struct")]
@@ -635,6 +653,10 @@ impl Rem for &Series {
     /// ```
     fn rem(self, rhs: Self) -> Self::Output {
         check_lengths(self, rhs)?;
+        #[cfg(feature = "dtype-extension")]
+        if let Some(out) = apply_extension_binary_kernel(self, rhs, ExtensionBinaryOp::Rem) {
+            return out;
+        }
         match (self.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-struct")]
             (DataType::Struct(_), DataType::Struct(_)) => {
@@ -2,12 +2,14 @@
 
 use polars_error::feature_gated;
 
+#[cfg(feature = "dtype-extension")]
+use crate::datatypes::extension::{ExtensionBinaryOp, apply_extension_binary_kernel};
 use crate::prelude::*;
 use crate::series::arithmetic::coerce_lhs_rhs;
 use crate::series::nulls::replace_non_null;
 
 macro_rules! impl_eq_compare {
-    ($self:expr, $rhs:expr, $method:ident) => {{
+    ($self:expr, $rhs:expr, $method:ident, $ext_op:ident) => {{
         use DataType::*;
         let (lhs, rhs) = ($self, $rhs);
         validate_types(lhs.dtype(), rhs.dtype())?;
@@ -23,6 +25,11 @@ macro_rules! impl_eq_compare {
             rhs.len()
         );
 
+        #[cfg(feature = "dtype-extension")]
+        if let Some(out) = apply_extension_binary_kernel(lhs, rhs, ExtensionBinaryOp::$ext_op) {
+            return out?.bool().cloned();
+        }
+
         match (lhs.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-categorical")]
             (Categorical(lcats, _), Categorical(rcats, _)) => {
@@ -123,7 +130,7 @@ macro_rules! bail_invalid_ineq {
 }
 
 macro_rules! impl_ineq_compare {
-    ($self:expr, $rhs:expr, $method:ident, $op:literal, $rev_method:ident) => {{
+    ($self:expr, $rhs:expr, $method:ident, $op:literal, $rev_method:ident, $ext_op:ident) => {{
         use DataType::*;
         let (lhs, rhs) = ($self, $rhs);
         validate_types(lhs.dtype(), rhs.dtype())?;
@@ -141,6 +148,11 @@ macro_rules! impl_ineq_compare {
             rhs.name(), rhs.len()
         );
 
+        #[cfg(feature = "dtype-extension")]
+        if let Some(out) = apply_extension_binary_kernel(lhs, rhs, ExtensionBinaryOp::$ext_op) {
+            return out?.bool().cloned();
+        }
+
         match (lhs.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-categorical")]
             (Categorical(lcats, _), Categorical(rcats, _)) => {
@@ -259,22 +271,22 @@ impl ChunkCompareEq<&Series> for Series {
 
     /// Create a boolean mask by checking for equality.
     fn equal(&self, rhs: &Series) -> Self::Item {
-        impl_eq_compare!(self, rhs, equal)
+        impl_eq_compare!(self, rhs, equal, Eq)
     }
 
     /// Create a boolean mask by checking for equality.
     fn equal_missing(&self, rhs: &Series) -> Self::Item {
-        impl_eq_compare!(self, rhs, equal_missing)
+        impl_eq_compare!(self, rhs, equal_missing, EqMissing)
     }
 
     /// Create a boolean mask by checking for inequality.
     fn not_equal(&self, rhs: &Series) -> Self::Item {
-        impl_eq_compare!(self, rhs, not_equal)
+        impl_eq_compare!(self, rhs, not_equal, NotEq)
     }
 
     /// Create a boolean mask by checking for inequality.
     fn not_equal_missing(&self, rhs: &Series) -> Self::Item {
-        impl_eq_compare!(self, rhs, not_equal_missing)
+        impl_eq_compare!(self, rhs, not_equal_missing, NotEqMissing)
     }
 }
 
@@ -283,22 +295,22 @@ impl ChunkCompareIneq<&Series> for Series {
 
     /// Create a boolean mask by checking if self > rhs.
     fn gt(&self, rhs: &Series) -> Self::Item {
-        impl_ineq_compare!(self, rhs, gt, ">", lt)
+        impl_ineq_compare!(self, rhs, gt, ">", lt, Gt)
     }
 
     /// Create a boolean mask by checking if self >= rhs.
     fn gt_eq(&self, rhs: &Series) -> Self::Item {
-        impl_ineq_compare!(self, rhs, gt_eq, ">=", lt_eq)
+        impl_ineq_compare!(self, rhs, gt_eq, ">=", lt_eq, GtEq)
     }
 
     /// Create a boolean mask by checking if self < rhs.
     fn lt(&self, rhs: &Series) -> Self::Item {
-        impl_ineq_compare!(self, rhs, lt, "<", gt)
+        impl_ineq_compare!(self, rhs, lt, "<", gt, Lt)
     }
 
     /// Create a boolean mask by checking if self <= rhs.
     fn lt_eq(&self, rhs: &Series) -> Self::Item {
-        impl_ineq_compare!(self, rhs, lt_eq, "<=", gt_eq)
+        impl_ineq_compare!(self, rhs, lt_eq, "<=", gt_eq, LtEq)
     }
 }
 
@@ -391,7 +391,16 @@ impl Series {
             ArrowDataType::Decimal256(precision, scale) => {
                 feature_gated!("dtype-decimal", {
                     use arrow::types::i256;
-
+                    use polars_compute::decimal::DEC128_MAX_PREC;
+
+                    // Decimals are backed by i128, so only a Decimal256 of at most 38 digits can
+                    // be loaded.
+                    polars_ensure!(
+                        *precision <= DEC128_MAX_PREC,
+                        InvalidOperation: "Decimal256 with precision {} is not supported by Polars, \
+                        decimals have at most {DEC128_MAX_PREC} digits",
+                        precision
+                    );
                     polars_compute::decimal::dec128_verify_prec_scale(*precision, *scale)?;
 
                     let mut chunks = chunks;
@@ -430,7 +439,7 @@ impl Series {
             },
             #[cfg(feature = "dtype-categorical")]
             ArrowDataType::Dictionary(key_type, _, _) => {
-                let polars_dtype = DataType::from_arrow(chunks[0].dtype(), md);
+                let polars_dtype = DataType::try_from_arrow(chunks[0].dtype(), md)?;
 
                 let mut series_iter = chunks.into_iter().map(|arr| {
                     import_arrow_dictionary_array(name.clone(), arr, key_type, &polars_dtype)
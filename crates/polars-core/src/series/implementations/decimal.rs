@@ -1,3 +1,6 @@
+use polars_compute::decimal::{
+    DEC128_MAX_PREC, dec128_fits, dec128_mean, dec128_mean_scale, dec128_median,
+};
 use polars_compute::rolling::QuantileMethod;
 
 use super::*;
@@ -44,6 +47,22 @@ impl SeriesWrap<DecimalChunked> {
         scalar
     }
 
+    /// The exact sum and the number of valid values. Errors if the sum doesn't fit in the maximum
+    /// precision.
+    fn checked_sum(&self) -> PolarsResult<(i128, usize)> {
+        let sum = self
+            .0
+            .physical()
+            .iter()
+            .flatten()
+            .try_fold(0i128, i128::checked_add)
+            .filter(|sum| dec128_fits(*sum, DEC128_MAX_PREC));
+        let Some(sum) = sum else {
+            polars_bail!(ComputeError: "overflow in decimal sum")
+        };
+        Ok((sum, self.0.len() - self.0.null_count()))
+    }
+
     fn agg_helper<F: Fn(&Int128Chunked) -> Series>(&self, f: F) -> Series {
         let agg_s = f(self.0.physical());
         match agg_s.dtype() {
@@ -151,7 +170,9 @@ impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
 
     #[cfg(feature = "algorithm_group_by")]
     unsafe fn agg_sum(&self, groups: &GroupsType) -> Series {
-        self.agg_helper(|ca| ca.agg_sum(groups))
+        // Sums are promoted to the maximum precision, like in `sum_reduce`. This can't error, so
+        // sums that overflow it are null.
+        self.0.agg_sum_checked(groups, false).unwrap().into_series()
     }
 
     #[cfg(feature = "algorithm_group_by")]
@@ -201,6 +222,10 @@ impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
         let rhs = rhs.decimal()?;
         ((&self.0) / rhs).map(|ca| ca.into_series())
     }
+    fn remainder(&self, rhs: &Series) -> PolarsResult<Series> {
+        let rhs = rhs.decimal()?;
+        ((&self.0) % rhs).map(|ca| ca.into_series())
+    }
     #[cfg(feature = "algorithm_group_by")]
     fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
         self.0.physical().group_tuples(multithreaded, sorted)
@@ -419,14 +444,10 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
     }
 
     fn sum_reduce(&self) -> PolarsResult<Scalar> {
-        Ok(self.apply_physical(|ca| {
-            let sum = ca.sum();
-            let DataType::Decimal(prec, scale) = self.dtype() else {
-                unreachable!()
-            };
-            let av = AnyValue::Decimal(sum.unwrap(), *prec, *scale);
-            Scalar::new(self.dtype().clone(), av)
-        }))
+        let (sum, _) = self.checked_sum()?;
+        let scale = self.0.scale();
+        let av = AnyValue::Decimal(sum, DEC128_MAX_PREC, scale);
+        Ok(Scalar::new(DataType::Decimal(DEC128_MAX_PREC, scale), av))
     }
 
     fn min_reduce(&self) -> PolarsResult<Scalar> {
@@ -470,7 +491,11 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
             .map(|v| v / self.scale_factor() as f64)
     }
     fn mean_reduce(&self) -> PolarsResult<Scalar> {
-        Ok(Scalar::new(DataType::Float64, self.mean().into()))
+        let mean_scale = dec128_mean_scale(self.0.precision(), self.0.scale());
+        let (sum, count) = self.checked_sum()?;
+        let mean = dec128_mean(sum, count, self.0.scale(), mean_scale);
+        let av = mean.map_or(AnyValue::Null, |v| AnyValue::Decimal(v, DEC128_MAX_PREC, mean_scale));
+        Ok(Scalar::new(DataType::Decimal(DEC128_MAX_PREC, mean_scale), av))
     }
 
     fn median(&self) -> Option<f64> {
@@ -481,7 +506,13 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
     }
 
     fn median_reduce(&self) -> PolarsResult<Scalar> {
-        Ok(self.apply_scale(self.0.physical().median_reduce()))
+        let median_scale = dec128_mean_scale(self.0.precision(), self.0.scale());
+        let mut values = self.0.physical().iter().flatten().collect::<Vec<_>>();
+        let median = dec128_median(&mut values, self.0.scale(), median_scale);
+        let av = median.map_or(AnyValue::Null, |v| {
+            AnyValue::Decimal(v, DEC128_MAX_PREC, median_scale)
+        });
+        Ok(Scalar::new(DataType::Decimal(DEC128_MAX_PREC, median_scale), av))
     }
 
     fn std(&self, ddof: u8) -> Option<f64> {
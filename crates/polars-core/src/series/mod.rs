@@ -468,6 +468,19 @@ impl Series {
             opt => opt,
         };
 
+        #[cfg(feature = "dtype-extension")]
+        if let D::Extension(typ, storage) = dtype
+            && !slf.dtype().is_extension()
+        {
+            polars_ensure!(
+                slf.dtype() == storage.as_ref() || typ.can_cast_from(slf.dtype()),
+                InvalidOperation: "cannot cast {} to extension type {}", slf.dtype(), typ
+            );
+            let storage = slf.cast_with_options(storage, options)?;
+            typ.validate(&storage)?;
+            return Ok(storage.into_extension(typ.clone()));
+        }
+
         let out = slf.0.cast(dtype, new_options)?;
         if options.is_strict() {
             handle_casting_failures(slf.as_ref(), &out)?;
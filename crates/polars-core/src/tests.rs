@@ -1,3 +1,6 @@
+use rayon::ThreadPoolBuilder;
+
+use crate::POOL;
 use crate::prelude::*;
 
 #[test]
@@ -15,3 +18,24 @@ fn test_initial_empty_sort() -> PolarsResult<()> {
     series.f64()?.sort(false);
     Ok(())
 }
+
+#[test]
+fn test_install_scoped() {
+    let pool = Arc::new(ThreadPoolBuilder::new().num_threads(2).build().unwrap());
+    let (num_threads, nested_num_threads) = POOL.install_scoped(&pool, || {
+        let nested = POOL.join(|| POOL.current_num_threads(), || ()).0;
+        (POOL.current_num_threads(), nested)
+    });
+    assert_eq!((num_threads, nested_num_threads), (2, 2));
+    assert!(pool.install(|| POOL.current_thread_index()).is_none());
+    assert!(POOL.install_scoped(&pool, || POOL.is_scoped()));
+    assert!(!POOL.is_scoped());
+}
+
+#[test]
+fn test_sized_pools_are_shared() {
+    let num_threads = std::num::NonZeroUsize::new(3).unwrap();
+    let pool = POOL.sized(num_threads).unwrap();
+    assert_eq!(pool.current_num_threads(), 3);
+    assert!(Arc::ptr_eq(&pool, &POOL.sized(num_threads).unwrap()));
+}
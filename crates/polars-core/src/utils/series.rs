@@ -1,5 +1,7 @@
 use std::rc::Rc;
 
+use polars_error::ErrorDetail;
+
 use crate::prelude::*;
 use crate::series::amortized_iter::AmortSeries;
 
@@ -78,7 +80,7 @@ pub fn handle_casting_failures(input: &Series, output: &Series) -> PolarsResult<
         _ => "",
     };
 
-    polars_bail!(
+    let err = polars_err!(
         InvalidOperation:
         "conversion from `{}` to `{}` failed in column '{}' for {} out of {} values: {}{}",
         input.dtype(),
@@ -88,5 +90,17 @@ pub fn handle_casting_failures(input: &Series, output: &Series) -> PolarsResult<
         input.len(),
         failures.fmt_list(),
         additional_info,
-    )
+    );
+    Err(err.with_detail(ErrorDetail::CastFailed {
+        column: output.name().to_string(),
+        from: input.dtype().to_string(),
+        to: output.dtype().to_string(),
+        rows: idxs.into_iter().map(|idx| idx as usize).collect(),
+        values: (0..failures.len())
+            .map(|i| {
+                let value = failures.get(i).unwrap();
+                value.get_str().map_or_else(|| value.to_string(), str::to_string)
+            })
+            .collect(),
+    }))
 }
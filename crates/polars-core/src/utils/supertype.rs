@@ -2,6 +2,8 @@ use bitflags::bitflags;
 use num_traits::Signed;
 #[cfg(feature = "dtype-decimal")]
 use polars_compute::decimal::{DEC128_MAX_PREC, i128_to_dec128};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use super::*;
 
@@ -542,6 +544,107 @@ pub fn get_supertype_with_options(
     inner(l, r, options).or_else(|| inner(r, l, options))
 }
 
+/// How an integer and a float are promoted by [`PromotionRules`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum IntFloatPromotion {
+    /// Widen the float if it cannot represent all values of the integer, e.g. `Int64` and
+    /// `Float32` promote to `Float64`.
+    #[default]
+    Widen,
+    /// Always promote to the float, e.g. `Int64` and `Float32` promote to `Float32`.
+    Float,
+}
+
+/// How `Null` is promoted by [`PromotionRules`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum NullPromotion {
+    /// `Null` promotes to any other data type.
+    #[default]
+    Promote,
+    /// `Null` has no supertype with other data types.
+    Raise,
+}
+
+/// How two decimals are promoted by [`PromotionRules`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum DecimalPromotion {
+    /// Take the largest precision and the largest scale, e.g. `Decimal(10, 2)` and
+    /// `Decimal(5, 4)` promote to `Decimal(10, 4)`.
+    #[default]
+    Max,
+    /// Take the largest scale and widen the precision so that no integer digits are lost, e.g.
+    /// `Decimal(10, 2)` and `Decimal(5, 4)` promote to `Decimal(12, 4)`.
+    PreserveIntegerDigits,
+}
+
+/// Configurable rules to determine the supertype of two data types.
+///
+/// The default rules are those of [`get_supertype`]. The configured rules also apply to the
+/// inner data types of lists and arrays; all other cases fall back to [`get_supertype`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct PromotionRules {
+    pub int_float: IntFloatPromotion,
+    pub null: NullPromotion,
+    pub decimal: DecimalPromotion,
+}
+
+impl PromotionRules {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Determine the supertype of `l` and `r` under these rules.
+    ///
+    /// Returns [`None`] if no such data type exists.
+    pub fn get_supertype(&self, l: &DataType, r: &DataType) -> Option<DataType> {
+        use DataType::*;
+        if self.is_default() || l == r {
+            return get_supertype(l, r);
+        }
+        match (l, r) {
+            (Null, _) | (_, Null) if self.null == NullPromotion::Raise => None,
+            (List(l), List(r)) => Some(List(Box::new(self.get_supertype(l, r)?))),
+            #[cfg(feature = "dtype-array")]
+            (Array(l, l_width), Array(r, r_width)) if l_width == r_width => {
+                Some(Array(Box::new(self.get_supertype(l, r)?), *l_width))
+            },
+            (int, float) | (float, int)
+                if self.int_float == IntFloatPromotion::Float
+                    && int.is_integer()
+                    && float.is_float() =>
+            {
+                Some(float.clone())
+            },
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal(p1, s1), Decimal(p2, s2))
+                if self.decimal == DecimalPromotion::PreserveIntegerDigits =>
+            {
+                let scale = (*s1).max(*s2);
+                let integer_digits = (p1 - s1).max(p2 - s2);
+                Some(Decimal((integer_digits + scale).min(DEC128_MAX_PREC), scale))
+            },
+            _ => get_supertype(l, r),
+        }
+    }
+
+    /// Determine the supertype of `l` and `r` under these rules.
+    ///
+    /// Returns a [`PolarsError::SchemaMismatch`] if no such data type exists.
+    pub fn try_get_supertype(&self, l: &DataType, r: &DataType) -> PolarsResult<DataType> {
+        self.get_supertype(l, r).ok_or_else(
+            || polars_err!(SchemaMismatch: "failed to determine supertype of {} and {}", l, r),
+        )
+    }
+}
+
 /// Given multiple data types, determine the data type that all types can safely be cast to.
 ///
 /// Returns [`DataType::Null`] if no data types were passed.
@@ -0,0 +1,167 @@
+//! Derive macros to convert between `Vec<T>` and `DataFrame` for plain Rust structs.
+//!
+//! Every field of the struct becomes a column of the same name. Fields can be of any type that
+//! implements `IntoColumnValue`/`FromColumnValue`: primitives, `String`, `Option<T>` for
+//! nullable columns, `Vec<T>` for lists, chrono types and other structs deriving these macros,
+//! which are stored as struct columns.
+//!
+//! The generated code refers to the `polars` crate, which needs the `derive` feature.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, Ident, Type, parse_macro_input};
+
+fn named_fields(input: &DeriveInput) -> syn::Result<Vec<(&Ident, &Type)>> {
+    if let Data::Struct(data) = &input.data
+        && let Fields::Named(fields) = &data.fields
+    {
+        return Ok(fields
+            .named
+            .iter()
+            .map(|field| (field.ident.as_ref().unwrap(), &field.ty))
+            .collect());
+    }
+    Err(syn::Error::new_spanned(
+        &input.ident,
+        "DataFrame conversions can only be derived for structs with named fields",
+    ))
+}
+
+fn column_name(ident: &Ident) -> String {
+    ident.to_string().trim_start_matches("r#").to_string()
+}
+
+/// Local variables holding the values of each field, named so they cannot shadow the
+/// variables of the generated code.
+fn value_vars(idents: &[&Ident]) -> Vec<Ident> {
+    idents
+        .iter()
+        .map(|ident| format_ident!("__{}_values", ident))
+        .collect()
+}
+
+/// Derive `IntoDataFrame` and `IntoColumnValue`, converting rows to a `DataFrame`.
+#[proc_macro_derive(IntoDataFrame)]
+pub fn derive_into_dataframe(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    into_dataframe_impl(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `FromDataFrame` and `FromColumnValue`, reading rows from a `DataFrame`.
+#[proc_macro_derive(FromDataFrame)]
+pub fn derive_from_dataframe(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_dataframe_impl(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn into_dataframe_impl(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = named_fields(input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let idents = fields.iter().map(|(ident, _)| *ident).collect::<Vec<_>>();
+    let types = fields.iter().map(|(_, ty)| *ty).collect::<Vec<_>>();
+    let names = idents.iter().map(|ident| column_name(ident)).collect::<Vec<_>>();
+    let vars = value_vars(&idents);
+
+    Ok(quote! {
+        impl #impl_generics ::polars::frame::row::typed::IntoDataFrame
+            for #name #ty_generics #where_clause
+        {
+            fn into_df(
+                rows: impl ::std::iter::IntoIterator<Item = Self>,
+            ) -> ::polars::error::PolarsResult<::polars::frame::DataFrame> {
+                let mut height = 0usize;
+                #(let mut #vars = ::std::vec::Vec::<#types>::new();)*
+                for row in rows {
+                    height += 1;
+                    #(#vars.push(row.#idents);)*
+                }
+                let columns = ::std::vec![
+                    #(::polars::frame::row::typed::values_to_column(#names, #vars)?,)*
+                ];
+                ::polars::frame::DataFrame::new(height, columns)
+            }
+        }
+
+        impl #impl_generics ::polars::frame::row::typed::IntoColumnValue
+            for #name #ty_generics #where_clause
+        {
+            fn dtype() -> ::polars::datatypes::DataType {
+                ::polars::datatypes::DataType::Struct(::std::vec![
+                    #(::polars::frame::row::typed::struct_field::<#types>(#names),)*
+                ])
+            }
+
+            fn into_column_value(
+                self,
+            ) -> ::polars::error::PolarsResult<::polars::datatypes::AnyValue<'static>> {
+                let values = ::std::vec![
+                    #(::polars::frame::row::typed::IntoColumnValue::into_column_value(
+                        self.#idents
+                    )?,)*
+                ];
+                let dtype = <Self as ::polars::frame::row::typed::IntoColumnValue>::dtype();
+                ::std::result::Result::Ok(
+                    ::polars::frame::row::typed::struct_value(values, dtype)
+                )
+            }
+        }
+    })
+}
+
+fn from_dataframe_impl(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = named_fields(input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let idents = fields.iter().map(|(ident, _)| *ident).collect::<Vec<_>>();
+    let types = fields.iter().map(|(_, ty)| *ty).collect::<Vec<_>>();
+    let names = idents.iter().map(|ident| column_name(ident)).collect::<Vec<_>>();
+    let vars = value_vars(&idents);
+
+    Ok(quote! {
+        impl #impl_generics ::polars::frame::row::typed::FromDataFrame
+            for #name #ty_generics #where_clause
+        {
+            fn from_df(
+                df: &::polars::frame::DataFrame,
+            ) -> ::polars::error::PolarsResult<::std::vec::Vec<Self>> {
+                #(
+                    let mut #vars = ::polars::frame::row::typed::column_to_values::<#types>(
+                        df, #names
+                    )?.into_iter();
+                )*
+                ::std::result::Result::Ok(
+                    (0..df.height())
+                        .map(|_| Self { #(#idents: #vars.next().unwrap(),)* })
+                        .collect()
+                )
+            }
+        }
+
+        impl #impl_generics ::polars::frame::row::typed::FromColumnValue
+            for #name #ty_generics #where_clause
+        {
+            fn dtype() -> ::polars::datatypes::DataType {
+                ::polars::datatypes::DataType::Struct(::std::vec![
+                    #(::polars::datatypes::Field::new(
+                        #names.into(),
+                        <#types as ::polars::frame::row::typed::FromColumnValue>::dtype(),
+                    ),)*
+                ])
+            }
+
+            fn from_column_value(
+                value: ::polars::datatypes::AnyValue<'_>,
+            ) -> ::polars::error::PolarsResult<Self> {
+                let mut fields = ::polars::frame::row::typed::StructFields::new::<Self>(value)?;
+                ::std::result::Result::Ok(Self { #(#idents: fields.take(#names)?,)* })
+            }
+        }
+    })
+}
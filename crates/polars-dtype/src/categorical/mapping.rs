@@ -1,17 +1,22 @@
 use std::fmt;
 use std::hash::BuildHasher;
+use std::io::Write;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 use arrow::array::builder::StaticArrayBuilder;
 use arrow::array::{Array, MutableUtf8Array, Utf8Array, Utf8ViewArrayBuilder};
 use arrow::datatypes::ArrowDataType;
-use polars_error::{PolarsResult, polars_bail};
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
 use polars_utils::aliases::PlSeedableRandomStateQuality;
 use polars_utils::parma::raw::RawTable;
 
 use super::CatSize;
+use super::shared::{SharedCategories, SharedMappingBytes, shared_hasher, write_shared};
 
 pub struct CategoricalMapping {
+    /// Read-only categories with the ids `0..num_shared`, which are not copied into this mapping.
+    shared: Option<SharedCategories>,
+    num_shared: usize,
     str_to_cat: RawTable<str, CatSize>,
     cat_to_str: boxcar::Vec<&'static str>,
     max_categories: usize,
@@ -26,6 +31,8 @@ impl CategoricalMapping {
 
     pub fn with_hasher(max_categories: usize, hasher: PlSeedableRandomStateQuality) -> Self {
         Self {
+            shared: None,
+            num_shared: 0,
             str_to_cat: RawTable::default(),
             cat_to_str: boxcar::Vec::default(),
             max_categories,
@@ -34,6 +41,46 @@ impl CategoricalMapping {
         }
     }
 
+    /// Creates a mapping whose first categories are backed by `bytes` in the format written by
+    /// [`CategoricalMapping::write_shared`], typically a memory-mapped file.
+    ///
+    /// The shared categories are never copied, such that many processes can attach to the same
+    /// file while holding its strings in memory only once. Categories that are inserted later are
+    /// local to this mapping.
+    pub fn from_shared(bytes: SharedMappingBytes, max_categories: usize) -> PolarsResult<Self> {
+        let shared = SharedCategories::try_new(bytes)?;
+        let num_shared = shared.len();
+        polars_ensure!(
+            num_shared <= max_categories,
+            ComputeError: "shared categorical mapping has {} categories, but at most {} are \
+            allowed", num_shared, max_categories
+        );
+        let mut slf = Self::with_hasher(max_categories, shared_hasher());
+        slf.shared = Some(shared);
+        slf.num_shared = num_shared;
+        *slf.upper_bound.get_mut() = num_shared;
+        Ok(slf)
+    }
+
+    /// Write the categories in the format of [`CategoricalMapping::from_shared`], with the same
+    /// category ids.
+    pub fn write_shared<W: Write>(&self, writer: &mut W) -> PolarsResult<()> {
+        let strings = (0..self.num_cats_upper_bound())
+            .map(|cat| {
+                self.cat_to_str(cat as CatSize).ok_or_else(|| {
+                    polars_err!(ComputeError: "cannot share a mapping while inserting into it")
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        write_shared(writer, strings.into_iter())
+    }
+
+    /// The number of categories that are backed by shared bytes, see
+    /// [`CategoricalMapping::from_shared`].
+    pub fn num_shared_cats(&self) -> usize {
+        self.num_shared
+    }
+
     #[inline(always)]
     pub fn hasher(&self) -> &PlSeedableRandomStateQuality {
         &self.hasher
@@ -58,6 +105,11 @@ impl CategoricalMapping {
     /// Same as get_cat, but with the hash pre-computed.
     #[inline(always)]
     pub fn get_cat_with_hash(&self, s: &str, hash: u64) -> Option<CatSize> {
+        if let Some(shared) = &self.shared
+            && let Some(cat) = shared.find(s, hash)
+        {
+            return Some(cat);
+        }
         self.str_to_cat.get(hash, |k| k == s).copied()
     }
 
@@ -71,6 +123,11 @@ impl CategoricalMapping {
     /// Same as to_cat, but with the hash pre-computed.
     #[inline(always)]
     pub fn insert_cat_with_hash(&self, s: &str, hash: u64) -> PolarsResult<CatSize> {
+        if let Some(shared) = &self.shared
+            && let Some(cat) = shared.find(s, hash)
+        {
+            return Ok(cat);
+        }
         self.str_to_cat
             .try_get_or_insert_with(
                 hash,
@@ -85,7 +142,7 @@ impl CategoricalMapping {
                     let idx = self
                         .cat_to_str
                         .push(unsafe { core::mem::transmute::<&str, &'static str>(k) });
-                    Ok(idx as CatSize)
+                    Ok((self.num_shared + idx) as CatSize)
                 },
             )
             .copied()
@@ -95,7 +152,12 @@ impl CategoricalMapping {
     /// None if the string is not in the data structure.
     #[inline(always)]
     pub fn cat_to_str(&self, cat: CatSize) -> Option<&str> {
-        self.cat_to_str.get(cat as usize).copied()
+        let cat = cat as usize;
+        if cat < self.num_shared {
+            // SAFETY: the shared categories have the ids `0..num_shared`.
+            return self.shared.as_ref().map(|s| unsafe { s.get_unchecked(cat) });
+        }
+        self.cat_to_str.get(cat - self.num_shared).copied()
     }
 
     /// Get the string corresponding to a categorical id.
@@ -105,7 +167,11 @@ impl CategoricalMapping {
     /// have synchronized with the call which inserted it.
     #[inline(always)]
     pub unsafe fn cat_to_str_unchecked(&self, cat: CatSize) -> &str {
-        unsafe { self.cat_to_str.get_unchecked(cat as usize) }
+        let cat = cat as usize;
+        if cat < self.num_shared {
+            return unsafe { self.shared.as_ref().unwrap_unchecked().get_unchecked(cat) };
+        }
+        unsafe { self.cat_to_str.get_unchecked(cat - self.num_shared) }
     }
 
     /// Returns an upper bound such that all strings inserted into the CategoricalMapping
@@ -160,6 +226,7 @@ impl fmt::Debug for CategoricalMapping {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CategoricalMapping")
             .field("max_categories", &self.max_categories)
+            .field("num_shared", &self.num_shared)
             .field("upper_bound", &self.upper_bound.load(Ordering::Relaxed))
             .finish()
     }
@@ -1,7 +1,8 @@
 use std::fmt;
 use std::hash::{BuildHasher, Hash, Hasher};
+use std::io::Write;
 use std::str::FromStr;
-use std::sync::{Arc, LazyLock, Mutex, Weak};
+use std::sync::{Arc, LazyLock, Mutex, OnceLock, Weak};
 
 use arrow::array::builder::StaticArrayBuilder;
 use arrow::array::{Utf8ViewArray, Utf8ViewArrayBuilder};
@@ -14,9 +15,11 @@ use polars_utils::pl_str::PlSmallStr;
 
 mod catsize;
 mod mapping;
+mod shared;
 
 pub use catsize::{CatNative, CatSize};
 pub use mapping::CategoricalMapping;
+pub use shared::SharedMappingBytes;
 
 /// The physical datatype backing a categorical / enum.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -115,6 +118,7 @@ static GLOBAL_CATEGORIES: LazyLock<Arc<Categories>> = LazyLock::new(|| {
     let global = Arc::new(Categories {
         id: CategoricalId::global(),
         mapping: Mutex::new(Weak::new()),
+        shared_mapping: OnceLock::new(),
     });
     registry.insert(global_id, Arc::downgrade(&global));
     global
@@ -124,6 +128,8 @@ static GLOBAL_CATEGORIES: LazyLock<Arc<Categories>> = LazyLock::new(|| {
 pub struct Categories {
     id: CategoricalId,
     mapping: Mutex<Weak<CategoricalMapping>>,
+    // Keeps an attached shared mapping alive, as it can't be recreated from scratch.
+    shared_mapping: OnceLock<Arc<CategoricalMapping>>,
 }
 
 impl Categories {
@@ -149,6 +155,7 @@ impl Categories {
         let slf = Arc::new(Self {
             id: id.clone(),
             mapping,
+            shared_mapping: OnceLock::new(),
         });
         registry.insert(id, Arc::downgrade(&slf));
         slf
@@ -201,6 +208,35 @@ impl Categories {
         arc
     }
 
+    /// Back the mapping of this Categories object by `bytes`, as written by
+    /// [`Categories::write_shared_mapping`], see [`CategoricalMapping::from_shared`].
+    ///
+    /// This must be done before the mapping is first used. The attached mapping is kept alive
+    /// for as long as this Categories object.
+    pub fn attach_shared_mapping(
+        &self,
+        bytes: SharedMappingBytes,
+    ) -> PolarsResult<Arc<CategoricalMapping>> {
+        let mut guard = self.mapping.lock().unwrap();
+        polars_ensure!(
+            guard.upgrade().is_none(),
+            InvalidOperation: "cannot attach a shared mapping to Categories '{}', its mapping is \
+            already in use", self.id.name
+        );
+        let max_categories = self.id.physical.max_categories();
+        let mapping = Arc::new(CategoricalMapping::from_shared(bytes, max_categories)?);
+        *guard = Arc::downgrade(&mapping);
+        // An earlier attached mapping would still be alive, so this was not set yet.
+        let _ = self.shared_mapping.set(mapping.clone());
+        Ok(mapping)
+    }
+
+    /// Write the categories of this Categories object, such that other processes can attach
+    /// to them with [`Categories::attach_shared_mapping`].
+    pub fn write_shared_mapping<W: Write>(&self, writer: &mut W) -> PolarsResult<()> {
+        self.mapping().write_shared(writer)
+    }
+
     pub fn freeze(&self) -> Arc<FrozenCategories> {
         let mapping = self.mapping();
         let n = mapping.num_cats_upper_bound();
@@ -309,6 +345,20 @@ impl FrozenCategories {
     pub fn hash(&self) -> u64 {
         self.combined_hash
     }
+
+    /// Creates a FrozenCategories object with these categories followed by the strings of
+    /// `added` which are not yet a category, in order of first occurrence. The existing
+    /// categories keep their category ids.
+    pub fn with_added_categories<'a, I: IntoIterator<Item = &'a str>>(
+        &'a self,
+        added: I,
+    ) -> PolarsResult<Arc<Self>> {
+        let mut seen = PlHashSet::new();
+        let added = added
+            .into_iter()
+            .filter(|s| self.mapping.get_cat(s).is_none() && seen.insert(*s));
+        Self::new(self.categories.values_iter().chain(added))
+    }
 }
 
 impl fmt::Debug for FrozenCategories {
@@ -0,0 +1,190 @@
+//! A file format for the categories of a [`CategoricalMapping`](super::CategoricalMapping) which
+//! is memory-mapped by many processes, such that the strings and their hash index are only held
+//! in memory once.
+//!
+//! The layout is as follows, with all integers little-endian:
+//! - the magic bytes `PLCATMAP`,
+//! - the format version, a check hash, the number of categories `n` and the number of slots `m`
+//!   of the hash index, a power of two, each as `u64`,
+//! - `n + 1` offsets of the strings in the string data, as `u64`,
+//! - the `m` slots of the hash index, as `u32`, which hold 0 or a category id plus one,
+//! - the string data.
+use std::hash::BuildHasher;
+use std::io::Write;
+use std::sync::Arc;
+
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
+use polars_utils::aliases::{PlSeedableRandomStateQuality, SeedableFromU64SeedExt};
+
+use super::CatSize;
+
+const MAGIC: &[u8; 8] = b"PLCATMAP";
+const VERSION: u64 = 1;
+const HEADER_LEN: usize = 40;
+
+/// Bytes that back a shared categorical mapping, e.g. a memory-mapped file.
+pub type SharedMappingBytes = Arc<dyn AsRef<[u8]> + Send + Sync>;
+
+/// The hasher of mappings that are backed by shared bytes, which must be the same in every
+/// process.
+pub(super) fn shared_hasher() -> PlSeedableRandomStateQuality {
+    PlSeedableRandomStateQuality::seed_from_u64(0)
+}
+
+/// The hash of a fixed string, to detect files that were written with another hash function.
+fn check_hash(hasher: &PlSeedableRandomStateQuality) -> u64 {
+    hasher.hash_one("polars shared categorical mapping")
+}
+
+#[inline(always)]
+fn read_u64(bytes: &[u8], pos: usize) -> u64 {
+    u64::from_le_bytes(bytes[pos..pos + 8].try_into().unwrap())
+}
+
+#[inline(always)]
+fn read_u32(bytes: &[u8], pos: usize) -> u32 {
+    u32::from_le_bytes(bytes[pos..pos + 4].try_into().unwrap())
+}
+
+/// Write `strings`, which must be unique, in the shared mapping format.
+pub(super) fn write_shared<'a, W, I>(writer: &mut W, strings: I) -> PolarsResult<()>
+where
+    W: Write,
+    I: ExactSizeIterator<Item = &'a str> + Clone,
+{
+    let len = strings.len();
+    polars_ensure!(
+        len < u32::MAX as usize,
+        ComputeError: "too many categories for a shared categorical mapping"
+    );
+    let num_slots = (2 * len).max(1).next_power_of_two();
+    let hasher = shared_hasher();
+    let mut slots = vec![0u32; num_slots];
+    for (cat, s) in strings.clone().enumerate() {
+        let mut slot = hasher.hash_one(s) as usize & (num_slots - 1);
+        while slots[slot] != 0 {
+            slot = (slot + 1) & (num_slots - 1);
+        }
+        slots[slot] = cat as u32 + 1;
+    }
+
+    writer.write_all(MAGIC)?;
+    for v in [VERSION, check_hash(&hasher), len as u64, num_slots as u64] {
+        writer.write_all(&v.to_le_bytes())?;
+    }
+    let mut offset = 0u64;
+    writer.write_all(&offset.to_le_bytes())?;
+    for s in strings.clone() {
+        offset += s.len() as u64;
+        writer.write_all(&offset.to_le_bytes())?;
+    }
+    for slot in slots {
+        writer.write_all(&slot.to_le_bytes())?;
+    }
+    for s in strings {
+        writer.write_all(s.as_bytes())?;
+    }
+    Ok(())
+}
+
+/// The read-only categories of a shared mapping, which have the ids `0..len`.
+pub(super) struct SharedCategories {
+    bytes: SharedMappingBytes,
+    len: usize,
+    num_slots: usize,
+    slots_start: usize,
+    data_start: usize,
+}
+
+impl SharedCategories {
+    pub(super) fn try_new(bytes: SharedMappingBytes) -> PolarsResult<Self> {
+        let b = (*bytes).as_ref();
+        polars_ensure!(
+            b.len() >= HEADER_LEN && &b[..8] == MAGIC,
+            ComputeError: "bytes are not a shared categorical mapping"
+        );
+        let version = read_u64(b, 8);
+        polars_ensure!(
+            version == VERSION,
+            ComputeError: "unsupported shared categorical mapping version {}", version
+        );
+        polars_ensure!(
+            read_u64(b, 16) == check_hash(&shared_hasher()),
+            ComputeError: "shared categorical mapping was written by an incompatible version of \
+            Polars"
+        );
+
+        let len = read_u64(b, 24) as usize;
+        let num_slots = read_u64(b, 32) as usize;
+        let starts = len
+            .checked_add(1)
+            .and_then(|n| n.checked_mul(8))
+            .and_then(|n| n.checked_add(HEADER_LEN))
+            .and_then(|slots_start| {
+                let data_start = num_slots.checked_mul(4)?.checked_add(slots_start)?;
+                Some((slots_start, data_start))
+            });
+        let Some((slots_start, data_start)) = starts.filter(|(slots_start, data_start)| {
+            num_slots.is_power_of_two()
+                && num_slots > len
+                && *data_start <= b.len()
+                && read_u64(b, slots_start - 8) == (b.len() - data_start) as u64
+        }) else {
+            polars_bail!(ComputeError: "shared categorical mapping is truncated or corrupt");
+        };
+
+        // Validate once, such that the strings can be read without checks.
+        let Ok(data) = std::str::from_utf8(&b[data_start..]) else {
+            polars_bail!(ComputeError: "shared categorical mapping contains invalid UTF-8");
+        };
+        let mut prev = 0;
+        for i in 0..=len {
+            let offset = read_u64(b, HEADER_LEN + i * 8) as usize;
+            polars_ensure!(
+                offset >= prev && data.is_char_boundary(offset),
+                ComputeError: "shared categorical mapping is truncated or corrupt"
+            );
+            prev = offset;
+        }
+
+        Ok(Self {
+            bytes,
+            len,
+            num_slots,
+            slots_start,
+            data_start,
+        })
+    }
+
+    #[inline(always)]
+    pub(super) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// # Safety
+    /// `cat` must be less than `len`.
+    #[inline(always)]
+    pub(super) unsafe fn get_unchecked(&self, cat: usize) -> &str {
+        let b = (*self.bytes).as_ref();
+        let start = self.data_start + read_u64(b, HEADER_LEN + cat * 8) as usize;
+        let end = self.data_start + read_u64(b, HEADER_LEN + (cat + 1) * 8) as usize;
+        // SAFETY: the strings were validated on creation.
+        unsafe { std::str::from_utf8_unchecked(b.get_unchecked(start..end)) }
+    }
+
+    /// The category of `s`, whose `hash` was computed with [`shared_hasher`].
+    #[inline(always)]
+    pub(super) fn find(&self, s: &str, hash: u64) -> Option<CatSize> {
+        let b = (*self.bytes).as_ref();
+        let mut slot = hash as usize & (self.num_slots - 1);
+        // The bounds guard against corrupt indices, which are not validated on creation.
+        for _ in 0..self.num_slots {
+            let cat = read_u32(b, self.slots_start + slot * 4).checked_sub(1)? as usize;
+            if cat < self.len && unsafe { self.get_unchecked(cat) } == s {
+                return Some(cat as CatSize);
+            }
+            slot = (slot + 1) & (self.num_slots - 1);
+        }
+        None
+    }
+}
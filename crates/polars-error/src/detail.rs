@@ -0,0 +1,140 @@
+use std::fmt::{self, Display, Formatter};
+
+/// The kind of a [`PolarsError`](crate::PolarsError) as a stable code, which doesn't change when
+/// the message of the error is reworded.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    AssertionError,
+    ColumnNotFound,
+    ComputeError,
+    Duplicate,
+    InvalidOperation,
+    IO,
+    NoData,
+    OutOfBounds,
+    SchemaFieldNotFound,
+    SchemaMismatch,
+    ShapeMismatch,
+    SQLInterface,
+    SQLSyntax,
+    StringCacheMismatch,
+    StructFieldNotFound,
+    Python,
+}
+
+impl ErrorCode {
+    pub const fn as_str(self) -> &'static str {
+        use ErrorCode::*;
+        match self {
+            AssertionError => "assertion_error",
+            ColumnNotFound => "column_not_found",
+            ComputeError => "compute_error",
+            Duplicate => "duplicate",
+            InvalidOperation => "invalid_operation",
+            IO => "io",
+            NoData => "no_data",
+            OutOfBounds => "out_of_bounds",
+            SchemaFieldNotFound => "schema_field_not_found",
+            SchemaMismatch => "schema_mismatch",
+            ShapeMismatch => "shape_mismatch",
+            SQLInterface => "sql_interface",
+            SQLSyntax => "sql_syntax",
+            StringCacheMismatch => "string_cache_mismatch",
+            StructFieldNotFound => "struct_field_not_found",
+            Python => "python",
+        }
+    }
+}
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The structured fields of an error, such that they can be inspected without parsing the
+/// message of the error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ErrorDetail {
+    /// A data type that isn't the expected one, optionally of a column.
+    SchemaMismatch {
+        column: Option<String>,
+        expected: String,
+        found: String,
+    },
+    /// A column that doesn't exist, with the existing columns whose names are similar to it,
+    /// most similar first.
+    ColumnNotFound { name: String, similar: Vec<String> },
+    /// A column name that occurs more than once.
+    DuplicateColumn { name: String },
+    /// An index that is out of bounds of a sequence of length `len`.
+    OutOfBounds { index: i64, len: usize },
+    /// Values of a column that failed a strict cast.
+    CastFailed {
+        column: String,
+        from: String,
+        to: String,
+        /// The rows of all values that failed, relative to the frame if it is known, otherwise
+        /// relative to the column.
+        rows: Vec<usize>,
+        /// The first values that failed, formatted.
+        values: Vec<String>,
+    },
+}
+
+impl ErrorDetail {
+    /// The detail of the column `name` not being found among the `columns`.
+    pub fn column_not_found<I, S>(name: &str, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        ErrorDetail::ColumnNotFound {
+            name: name.to_string(),
+            similar: similar_names(name, columns),
+        }
+    }
+}
+
+/// The at most 3 `candidates` whose names differ from `name` in casing or by a few edits, most
+/// similar first.
+pub fn similar_names<I, S>(name: &str, candidates: I) -> Vec<String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let name = name.to_lowercase().chars().collect::<Vec<_>>();
+    let max_distance = (name.len() / 3).max(1);
+
+    let mut similar = candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let candidate = candidate.as_ref();
+            let chars = candidate.to_lowercase().chars().collect::<Vec<_>>();
+            if chars.len().abs_diff(name.len()) > max_distance {
+                return None;
+            }
+            let distance = edit_distance(&name, &chars);
+            (distance <= max_distance).then(|| (distance, candidate.to_string()))
+        })
+        .collect::<Vec<_>>();
+    // Stable, such that candidates that are as similar keep their order.
+    similar.sort_by_key(|(distance, _)| *distance);
+    similar.truncate(3);
+    similar.into_iter().map(|(_, name)| name).collect()
+}
+
+/// The Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    let mut curr = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != cb);
+            curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
@@ -1,4 +1,5 @@
 pub mod constants;
+mod detail;
 mod warning;
 
 use std::borrow::Cow;
@@ -11,6 +12,7 @@ use std::sync::{Arc, LazyLock};
 use std::{env, io};
 pub mod signals;
 
+pub use detail::*;
 pub use warning::*;
 
 #[cfg(feature = "python")]
@@ -32,12 +34,29 @@ static ERROR_STRATEGY: LazyLock<ErrorStrategy> = LazyLock::new(|| {
     }
 });
 
-#[derive(Debug, Clone)]
-pub struct ErrString(Cow<'static, str>);
+#[derive(Clone)]
+pub struct ErrString {
+    msg: Cow<'static, str>,
+    detail: Option<Box<ErrorDetail>>,
+}
 
 impl ErrString {
     pub const fn new_static(s: &'static str) -> Self {
-        Self(Cow::Borrowed(s))
+        Self {
+            msg: Cow::Borrowed(s),
+            detail: None,
+        }
+    }
+
+    pub fn detail(&self) -> Option<&ErrorDetail> {
+        self.detail.as_deref()
+    }
+
+    /// Replace the message, keeping the detail.
+    fn map<F: FnOnce(&str) -> String>(&self, func: F) -> Self {
+        let mut out = ErrString::from(func(&self.msg));
+        out.detail = self.detail.clone();
+        out
     }
 }
 
@@ -48,19 +67,36 @@ where
     fn from(msg: T) -> Self {
         match &*ERROR_STRATEGY {
             ErrorStrategy::Panic => panic!("{}", msg.into()),
-            ErrorStrategy::WithBacktrace => ErrString(Cow::Owned(format!(
-                "{}\n\nRust backtrace:\n{}",
-                msg.into(),
-                std::backtrace::Backtrace::force_capture()
-            ))),
-            ErrorStrategy::Normal => ErrString(msg.into()),
+            ErrorStrategy::WithBacktrace => ErrString {
+                msg: Cow::Owned(format!(
+                    "{}\n\nRust backtrace:\n{}",
+                    msg.into(),
+                    std::backtrace::Backtrace::force_capture()
+                )),
+                detail: None,
+            },
+            ErrorStrategy::Normal => ErrString {
+                msg: msg.into(),
+                detail: None,
+            },
+        }
+    }
+}
+
+impl fmt::Debug for ErrString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut tuple = f.debug_tuple("ErrString");
+        tuple.field(&self.msg);
+        if let Some(detail) = &self.detail {
+            tuple.field(detail);
         }
+        tuple.finish()
     }
 }
 
 impl AsRef<str> for ErrString {
     fn as_ref(&self) -> &str {
-        &self.0
+        &self.msg
     }
 }
 
@@ -68,13 +104,13 @@ impl Deref for ErrString {
     type Target = str;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.msg
     }
 }
 
 impl Display for ErrString {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.msg)
     }
 }
 
@@ -239,11 +275,11 @@ impl PolarsError {
     pub fn wrap_msg<F: FnOnce(&str) -> String>(&self, func: F) -> Self {
         use PolarsError::*;
         match self {
-            AssertionError(msg) => AssertionError(func(msg).into()),
-            ColumnNotFound(msg) => ColumnNotFound(func(msg).into()),
-            ComputeError(msg) => ComputeError(func(msg).into()),
-            Duplicate(msg) => Duplicate(func(msg).into()),
-            InvalidOperation(msg) => InvalidOperation(func(msg).into()),
+            AssertionError(msg) => AssertionError(msg.map(func)),
+            ColumnNotFound(msg) => ColumnNotFound(msg.map(func)),
+            ComputeError(msg) => ComputeError(msg.map(func)),
+            Duplicate(msg) => Duplicate(msg.map(func)),
+            InvalidOperation(msg) => InvalidOperation(msg.map(func)),
             IO { error, msg } => {
                 let msg = match msg {
                     Some(msg) => func(msg),
@@ -254,15 +290,15 @@ impl PolarsError {
                     msg: Some(msg.into()),
                 }
             },
-            NoData(msg) => NoData(func(msg).into()),
-            OutOfBounds(msg) => OutOfBounds(func(msg).into()),
-            SchemaFieldNotFound(msg) => SchemaFieldNotFound(func(msg).into()),
-            SchemaMismatch(msg) => SchemaMismatch(func(msg).into()),
-            ShapeMismatch(msg) => ShapeMismatch(func(msg).into()),
-            StringCacheMismatch(msg) => StringCacheMismatch(func(msg).into()),
-            StructFieldNotFound(msg) => StructFieldNotFound(func(msg).into()),
-            SQLInterface(msg) => SQLInterface(func(msg).into()),
-            SQLSyntax(msg) => SQLSyntax(func(msg).into()),
+            NoData(msg) => NoData(msg.map(func)),
+            OutOfBounds(msg) => OutOfBounds(msg.map(func)),
+            SchemaFieldNotFound(msg) => SchemaFieldNotFound(msg.map(func)),
+            SchemaMismatch(msg) => SchemaMismatch(msg.map(func)),
+            ShapeMismatch(msg) => ShapeMismatch(msg.map(func)),
+            StringCacheMismatch(msg) => StringCacheMismatch(msg.map(func)),
+            StructFieldNotFound(msg) => StructFieldNotFound(msg.map(func)),
+            SQLInterface(msg) => SQLInterface(msg.map(func)),
+            SQLSyntax(msg) => SQLSyntax(msg.map(func)),
             Context { error, .. } => error.wrap_msg(func),
             #[cfg(feature = "python")]
             Python { error } => pyo3::Python::attach(|py| {
@@ -321,6 +357,99 @@ impl PolarsError {
         }
         self
     }
+
+    /// The stable code of this error, which is that of the underlying error if context was
+    /// added to it.
+    pub fn code(&self) -> ErrorCode {
+        use PolarsError::*;
+        match self.get_err() {
+            AssertionError(_) => ErrorCode::AssertionError,
+            ColumnNotFound(_) => ErrorCode::ColumnNotFound,
+            ComputeError(_) => ErrorCode::ComputeError,
+            Duplicate(_) => ErrorCode::Duplicate,
+            InvalidOperation(_) => ErrorCode::InvalidOperation,
+            IO { .. } => ErrorCode::IO,
+            NoData(_) => ErrorCode::NoData,
+            OutOfBounds(_) => ErrorCode::OutOfBounds,
+            SchemaFieldNotFound(_) => ErrorCode::SchemaFieldNotFound,
+            SchemaMismatch(_) => ErrorCode::SchemaMismatch,
+            ShapeMismatch(_) => ErrorCode::ShapeMismatch,
+            SQLInterface(_) => ErrorCode::SQLInterface,
+            SQLSyntax(_) => ErrorCode::SQLSyntax,
+            StringCacheMismatch(_) => ErrorCode::StringCacheMismatch,
+            StructFieldNotFound(_) => ErrorCode::StructFieldNotFound,
+            Context { .. } => unreachable!(),
+            #[cfg(feature = "python")]
+            Python { .. } => ErrorCode::Python,
+        }
+    }
+
+    /// The structured fields of the underlying error, if they are known.
+    pub fn detail(&self) -> Option<&ErrorDetail> {
+        use PolarsError::*;
+        match self.get_err() {
+            AssertionError(msg)
+            | ColumnNotFound(msg)
+            | ComputeError(msg)
+            | Duplicate(msg)
+            | InvalidOperation(msg)
+            | NoData(msg)
+            | OutOfBounds(msg)
+            | SchemaFieldNotFound(msg)
+            | SchemaMismatch(msg)
+            | ShapeMismatch(msg)
+            | SQLInterface(msg)
+            | SQLSyntax(msg)
+            | StringCacheMismatch(msg)
+            | StructFieldNotFound(msg) => msg.detail(),
+            _ => None,
+        }
+    }
+
+    /// Attach the structured fields of the underlying error. Errors without a message, such as
+    /// I/O errors, don't keep them.
+    #[must_use]
+    pub fn with_detail(mut self, detail: ErrorDetail) -> Self {
+        if let Some(msg) = self.msg_mut() {
+            msg.detail = Some(Box::new(detail));
+        }
+        self
+    }
+
+    /// Shift the rows of the structured fields by `offset`, e.g. to make the rows of a chunk
+    /// relative to the frame it is part of.
+    #[must_use]
+    pub fn with_row_offset(mut self, offset: usize) -> Self {
+        if let Some(ErrorDetail::CastFailed { rows, .. }) = self
+            .msg_mut()
+            .and_then(|msg| msg.detail.as_deref_mut())
+        {
+            rows.iter_mut().for_each(|row| *row += offset);
+        }
+        self
+    }
+
+    fn msg_mut(&mut self) -> Option<&mut ErrString> {
+        use PolarsError::*;
+        match self {
+            AssertionError(msg)
+            | ColumnNotFound(msg)
+            | ComputeError(msg)
+            | Duplicate(msg)
+            | InvalidOperation(msg)
+            | NoData(msg)
+            | OutOfBounds(msg)
+            | SchemaFieldNotFound(msg)
+            | SchemaMismatch(msg)
+            | ShapeMismatch(msg)
+            | SQLInterface(msg)
+            | SQLSyntax(msg)
+            | StringCacheMismatch(msg)
+            | StructFieldNotFound(msg) => Some(msg),
+            Context { error, .. } => error.msg_mut(),
+            _ => None,
+        }
+    }
 }
 
 pub fn map_err<E: Error>(error: E) -> PolarsError {
@@ -445,26 +574,43 @@ Alternatively, if the performance cost is acceptable, you could just set:
 
 on startup."#.trim_start())
     };
-    (duplicate = $name:expr) => {
-        $crate::polars_err!(Duplicate: "column with name '{}' has more than one occurrence", $name)
-    };
+    (duplicate = $name:expr) => {{
+        let name = &$name;
+        $crate::polars_err!(Duplicate: "column with name '{}' has more than one occurrence", name)
+            .with_detail($crate::ErrorDetail::DuplicateColumn { name: name.to_string() })
+    }};
     (duplicate_field = $name:expr) => {
         $crate::polars_err!(Duplicate: "multiple fields with name '{}' found", $name)
     };
     (col_not_found = $name:expr) => {
-        $crate::polars_err!(ColumnNotFound: "{:?} not found", $name)
+        $crate::polars_err!(col_not_found = $name, candidates = std::iter::empty::<&str>())
     };
-    (mismatch, col=$name:expr, expected=$expected:expr, found=$found:expr) => {
+    (col_not_found = $name:expr, candidates = $candidates:expr) => {{
+        let name = &$name;
+        $crate::polars_err!(ColumnNotFound: "{:?} not found", name)
+            .with_detail($crate::ErrorDetail::column_not_found(&name.to_string(), $candidates))
+    }};
+    (mismatch, col=$name:expr, expected=$expected:expr, found=$found:expr) => {{
+        let (name, expected, found) = (&$name, &$expected, &$found);
         $crate::polars_err!(
             SchemaMismatch: "data type mismatch for column {}: expected: {}, found: {}",
-            $name,
-            $expected,
-            $found,
+            name,
+            expected,
+            found,
         )
-    };
-    (oob = $idx:expr, $len:expr) => {
-        polars_err!(OutOfBounds: "index {} is out of bounds for sequence of length {}", $idx, $len)
-    };
+        .with_detail($crate::ErrorDetail::SchemaMismatch {
+            column: Some(name.to_string()),
+            expected: expected.to_string(),
+            found: found.to_string(),
+        })
+    }};
+    (oob = $idx:expr, $len:expr) => {{
+        let (index, len) = ($idx, $len);
+        $crate::polars_err!(
+            OutOfBounds: "index {} is out of bounds for sequence of length {}", index, len
+        )
+        .with_detail($crate::ErrorDetail::OutOfBounds { index: index as i64, len: len as usize })
+    }};
     (agg_len = $agg_len:expr, $groups_len:expr) => {
         polars_err!(
             ComputeError:
@@ -43,6 +43,14 @@ pub fn function_expr_to_udf(func: IRArrayFunction) -> SpecialEq<Arc<dyn ColumnsU
         Slice(offset, length) => map!(slice, offset, length),
         #[cfg(feature = "array_to_struct")]
         ToStruct(ng) => map!(arr_to_struct, ng.clone()),
+        #[cfg(feature = "array_linalg")]
+        Dot => map_as_slice!(dot),
+        #[cfg(feature = "array_linalg")]
+        MatVec => map_as_slice!(matvec),
+        #[cfg(feature = "array_linalg")]
+        Norm(kind) => map!(norm, kind),
+        #[cfg(feature = "array_linalg")]
+        CosineSimilarity => map_as_slice!(cosine_similarity),
     }
 }
 
@@ -188,6 +196,31 @@ pub(super) fn count_matches(args: &[Column]) -> PolarsResult<Column> {
         .map(Column::from)
 }
 
+#[cfg(feature = "array_linalg")]
+pub(super) fn dot(s: &[Column]) -> PolarsResult<Column> {
+    polars_ops::chunked_array::array::array_dot(s[0].array()?, s[1].array()?).map(Column::from)
+}
+
+#[cfg(feature = "array_linalg")]
+pub(super) fn matvec(s: &[Column]) -> PolarsResult<Column> {
+    polars_ops::chunked_array::array::array_matvec(s[0].array()?, s[1].array()?)
+        .map(IntoColumn::into_column)
+}
+
+#[cfg(feature = "array_linalg")]
+pub(super) fn norm(
+    s: &Column,
+    kind: polars_ops::chunked_array::array::VectorNorm,
+) -> PolarsResult<Column> {
+    polars_ops::chunked_array::array::array_norm(s.array()?, kind).map(Column::from)
+}
+
+#[cfg(feature = "array_linalg")]
+pub(super) fn cosine_similarity(s: &[Column]) -> PolarsResult<Column> {
+    polars_ops::chunked_array::array::array_cosine_similarity(s[0].array()?, s[1].array()?)
+        .map(Column::from)
+}
+
 pub(super) fn shift(s: &[Column]) -> PolarsResult<Column> {
     let ca = s[0].array()?;
     let n = &s[1];
@@ -2,6 +2,8 @@ use std::sync::Arc;
 
 use polars_core::error::PolarsResult;
 use polars_core::prelude::{Column, DataType, IntoColumn};
+#[cfg(feature = "crypto")]
+use polars_ops::chunked_array::crypto::CryptoKey;
 use polars_ops::prelude::BinaryNameSpaceImpl;
 use polars_plan::dsl::{ColumnsUdf, SpecialEq};
 use polars_plan::plans::IRBinaryFunction;
@@ -38,6 +40,10 @@ pub fn function_expr_to_udf(func: IRBinaryFunction) -> SpecialEq<Arc<dyn Columns
         Tail => {
             map_as_slice!(bin_tail)
         },
+        #[cfg(feature = "crypto")]
+        Encrypt { key } => map!(encrypt, &key),
+        #[cfg(feature = "crypto")]
+        Decrypt { key } => map!(decrypt, &key),
     }
 }
 
@@ -133,3 +139,15 @@ pub(super) fn bin_tail(s: &mut [Column]) -> PolarsResult<Column> {
         .with_name(ca.name().clone())
         .into_column())
 }
+
+#[cfg(feature = "crypto")]
+pub(super) fn encrypt(s: &Column, key: &CryptoKey) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    polars_ops::chunked_array::crypto::encrypt_deterministic(ca, key).map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "crypto")]
+pub(super) fn decrypt(s: &Column, key: &CryptoKey) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    polars_ops::chunked_array::crypto::decrypt_deterministic(ca, key).map(|ca| ca.into_column())
+}
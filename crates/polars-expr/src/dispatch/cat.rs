@@ -2,10 +2,12 @@ use std::sync::Arc;
 
 use polars_core::error::PolarsResult;
 use polars_core::prelude::{
-    ChunkTakeUnchecked, ChunkedArray, Column, FalseT, IDX_DTYPE, IntoColumn, PolarsPhysicalType,
-    StringChunked,
+    Categorical8Type, Categorical16Type, Categorical32Type, CategoricalPhysical,
+    ChunkTakeUnchecked, ChunkedArray, Column, FalseT, IDX_DTYPE, IntoColumn, PlSmallStr,
+    PolarsPhysicalType, StringChunked,
 };
 use polars_core::series::Series;
+use polars_core::with_match_categorical_physical_type;
 use polars_ops::prelude::BinaryNameSpaceImpl;
 #[cfg(feature = "strings")]
 use polars_ops::prelude::StringNameSpaceImpl;
@@ -16,6 +18,8 @@ pub fn function_expr_to_udf(func: IRCategoricalFunction) -> SpecialEq<Arc<dyn Co
     use IRCategoricalFunction::*;
     match func {
         GetCategories => map!(get_categories),
+        ExtendCategories(categories) => map!(extend_categories, &categories),
+        CastWithAddedCategories(categories) => map!(cast_with_added_categories, &categories),
         #[cfg(feature = "strings")]
         LenBytes => map!(len_bytes),
         #[cfg(feature = "strings")]
@@ -35,6 +39,22 @@ fn get_categories(s: &Column) -> PolarsResult<Column> {
     Ok(ca.into_column())
 }
 
+fn extend_categories(c: &Column, categories: &[PlSmallStr]) -> PolarsResult<Column> {
+    let categories = categories.iter().map(PlSmallStr::as_str);
+    let out = with_match_categorical_physical_type!(c.dtype().cat_physical()?, |$C| {
+        c.cat::<$C>()?.extend_categories(categories)?
+    });
+    Ok(out.into_column())
+}
+
+fn cast_with_added_categories(c: &Column, categories: &[PlSmallStr]) -> PolarsResult<Column> {
+    let categories = categories.iter().map(PlSmallStr::as_str);
+    let out = with_match_categorical_physical_type!(c.dtype().cat_physical()?, |$C| {
+        c.cat::<$C>()?.cast_with_added_categories(categories)?
+    });
+    Ok(out.into_column())
+}
+
 // Determine mapping between categories and underlying physical. For local, this is just 0..n.
 // For global, this is the global indexes.
 fn _get_cat_phys_map(col: &Column) -> (StringChunked, Series) {
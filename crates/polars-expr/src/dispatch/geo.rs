@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::prelude::*;
+use polars_ops::chunked_array::geo;
+use polars_plan::dsl::{ColumnsUdf, SpecialEq};
+use polars_plan::plans::IRGeoFunction;
+
+pub fn function_expr_to_udf(func: IRGeoFunction) -> SpecialEq<Arc<dyn ColumnsUdf>> {
+    use IRGeoFunction::*;
+    match func {
+        Point => map_as_slice!(point),
+        X => map!(x),
+        Y => map!(y),
+        Distance => map_as_slice!(distance),
+        Contains => map_as_slice!(contains),
+        Within => map_as_slice!(within),
+        IntersectsBbox => map_as_slice!(intersects_bbox),
+    }
+}
+
+fn point(s: &[Column]) -> PolarsResult<Column> {
+    geo::st_point(s[0].as_materialized_series(), s[1].as_materialized_series())
+        .map(Column::from)
+}
+
+fn x(c: &Column) -> PolarsResult<Column> {
+    c.try_apply_unary_elementwise(geo::st_x)
+}
+
+fn y(c: &Column) -> PolarsResult<Column> {
+    c.try_apply_unary_elementwise(geo::st_y)
+}
+
+fn distance(s: &[Column]) -> PolarsResult<Column> {
+    geo::st_distance(s[0].as_materialized_series(), s[1].as_materialized_series())
+        .map(Column::from)
+}
+
+fn contains(s: &[Column]) -> PolarsResult<Column> {
+    geo::st_contains(s[0].as_materialized_series(), s[1].as_materialized_series())
+        .map(Column::from)
+}
+
+fn within(s: &[Column]) -> PolarsResult<Column> {
+    geo::st_within(s[0].as_materialized_series(), s[1].as_materialized_series())
+        .map(Column::from)
+}
+
+fn intersects_bbox(s: &[Column]) -> PolarsResult<Column> {
+    let mut bbox = [0.0; 4];
+    for (bound, c) in bbox.iter_mut().zip(&s[1..]) {
+        polars_ensure!(
+            c.len() == 1,
+            ComputeError: "bounds in `st.intersects_bbox` must be scalars, got {} elements",
+            c.len()
+        );
+        let value = c.cast(&DataType::Float64)?.f64()?.get(0);
+        *bound = value.ok_or_else(
+            || polars_err!(ComputeError: "bounds in `st.intersects_bbox` cannot be null"),
+        )?;
+    }
+    s[0].try_apply_unary_elementwise(|s| geo::st_intersects_bbox(s, bbox))
+}
@@ -71,6 +71,61 @@ pub fn reverse<'a>(
     Ok(ac)
 }
 
+pub fn gather_every<'a>(
+    inputs: &[Arc<dyn PhysicalExpr>],
+    df: &DataFrame,
+    groups: &'a GroupPositions,
+    state: &ExecutionState,
+    n: usize,
+    offset: usize,
+) -> PolarsResult<AggregationContext<'a>> {
+    assert_eq!(inputs.len(), 1);
+    polars_ensure!(n > 0, InvalidOperation: "gather_every(n): n should be positive");
+
+    let mut ac = inputs[0].evaluate_on_groups(df, groups, state)?;
+    ac.groups();
+
+    // Scalars are groups of a single value.
+    if let AggState::AggregatedScalar(c) | AggState::LiteralScalar(c) = &mut ac.state {
+        *c = c.as_list().into_column();
+        if c.len() == 1 && ac.groups.len() != 1 {
+            *c = c.new_from_index(0, ac.groups.len());
+        }
+        ac.state = AggState::AggregatedList(std::mem::take(c));
+        ac.update_groups = UpdateGroups::WithSeriesLen;
+    }
+
+    // Only rewrite the groups, the values are gathered when the groups are aggregated.
+    POOL.install(|| {
+        let positions = GroupsType::Idx(match &**ac.groups().as_ref() {
+            GroupsType::Idx(idx) => idx
+                .into_par_iter()
+                .map(|(first, idx)| {
+                    let idx: UnitVec<IdxSize> =
+                        idx.iter().copied().skip(offset).step_by(n).collect();
+                    (idx.first().copied().unwrap_or(first), idx)
+                })
+                .collect(),
+            GroupsType::Slice {
+                groups,
+                overlapping: _,
+                monotonic: _,
+            } => groups
+                .into_par_iter()
+                .map(|[start, len]| {
+                    let idx: UnitVec<IdxSize> =
+                        (*start..*start + *len).skip(offset).step_by(n).collect();
+                    (idx.first().copied().unwrap_or(*start), idx)
+                })
+                .collect(),
+        })
+        .into_sliceable();
+        ac.with_groups(positions);
+    });
+
+    Ok(ac)
+}
+
 pub fn null_count<'a>(
     inputs: &[Arc<dyn PhysicalExpr>],
     df: &DataFrame,
@@ -52,6 +52,9 @@ pub fn function_expr_to_udf(func: IRListFunction) -> SpecialEq<Arc<dyn ColumnsUd
         ArgMax => map!(arg_max),
         #[cfg(feature = "diff")]
         Diff { n, null_behavior } => map!(diff, n, null_behavior),
+        RollingSum(window_size) => map!(rolling_sum, window_size),
+        RollingMean(window_size) => map!(rolling_mean, window_size),
+        ZipWith(op) => map_as_slice!(zip_with, op),
         Sort(options) => map!(sort, options),
         Reverse => map!(reverse),
         Unique(is_stable) => map!(unique, is_stable),
@@ -345,6 +348,20 @@ pub(super) fn diff(
     Ok(s.list()?.lst_diff(n, null_behavior)?.into_column())
 }
 
+pub(super) fn rolling_sum(s: &Column, window_size: usize) -> PolarsResult<Column> {
+    Ok(s.list()?.lst_rolling_sum(window_size)?.into_column())
+}
+
+pub(super) fn rolling_mean(s: &Column, window_size: usize) -> PolarsResult<Column> {
+    Ok(s.list()?.lst_rolling_mean(window_size)?.into_column())
+}
+
+pub(super) fn zip_with(s: &[Column], op: polars_ops::prelude::ListZipOp) -> PolarsResult<Column> {
+    let list = s[0].list()?;
+    let other = s[1].list()?;
+    list.lst_zip_with(other, op).map(|ok| ok.into_column())
+}
+
 pub(super) fn sort(s: &Column, options: SortOptions) -> PolarsResult<Column> {
     Ok(s.list()?.lst_sort(options)?.into_column())
 }
@@ -9,6 +9,10 @@ use polars_core::utils::try_get_supertype;
 use polars_ops::series::InterpolationMethod;
 #[cfg(feature = "rank")]
 use polars_ops::series::RankOptions;
+#[cfg(feature = "rule_failures")]
+use polars_ops::series::RuleFailuresOutput;
+#[cfg(feature = "weighted")]
+use polars_ops::series::WeightedAgg;
 use polars_ops::series::{ArgAgg, NullStrategy, SeriesMethods};
 #[cfg(feature = "dtype-array")]
 use polars_plan::dsl::ReshapeDimension;
@@ -58,6 +62,53 @@ pub(super) fn pct_change(s: &[Column]) -> PolarsResult<Column> {
         .map(Column::from)
 }
 
+#[cfg(feature = "pct_change_by")]
+pub(super) fn pct_change_by(s: &[Column], offset: polars_time::Duration) -> PolarsResult<Column> {
+    use arrow::legacy::time_zone::Tz;
+    use polars_ops::series::SeriesMethods;
+
+    let values = s[0].as_materialized_series();
+    let by = s[1].as_materialized_series();
+    polars_ensure!(!offset.negative(), InvalidOperation: "offset cannot be negative");
+    polars_time::prelude::ensure_duration_matches_dtype(offset, by.dtype(), "offset")?;
+
+    // Physical values of `by` and the values `offset` earlier, in the same unit.
+    let (by, time_unit, time_zone) = match by.dtype() {
+        DataType::Datetime(tu, tz) => (by.cast(&DataType::Int64)?, Some(*tu), tz.clone()),
+        DataType::Date => {
+            let by = by.cast(&DataType::Datetime(TimeUnit::Microseconds, None))?;
+            (by.cast(&DataType::Int64)?, Some(TimeUnit::Microseconds), None)
+        },
+        _ => (by.cast(&DataType::Int64)?, None, None),
+    };
+    let by_is_sorted = by.is_sorted(Default::default())?;
+    let by = by.i64()?;
+
+    let targets = match time_unit {
+        Some(tu) if !offset.is_constant_duration(time_zone.as_ref()) => {
+            #[cfg(feature = "timezones")]
+            let tz = time_zone.as_ref().and_then(|tz| tz.parse::<Tz>().ok());
+            #[cfg(not(feature = "timezones"))]
+            let tz: Option<Tz> = None;
+            let add = match tu {
+                TimeUnit::Nanoseconds => polars_time::Duration::add_ns,
+                TimeUnit::Microseconds => polars_time::Duration::add_us,
+                TimeUnit::Milliseconds => polars_time::Duration::add_ms,
+            };
+            let offset = -offset;
+            by.try_apply_nonnull_values_generic(|t| add(&offset, t, tz.as_ref()))?
+        },
+        Some(tu) => by.wrapping_add_scalar(-match tu {
+            TimeUnit::Nanoseconds => offset.duration_ns(),
+            TimeUnit::Microseconds => offset.duration_us(),
+            TimeUnit::Milliseconds => offset.duration_ms(),
+        }),
+        None => by.wrapping_add_scalar(-offset.nanoseconds()),
+    };
+
+    polars_ops::prelude::pct_change_by(values, by, &targets, by_is_sorted).map(Column::from)
+}
+
 #[cfg(feature = "interpolate")]
 pub(super) fn interpolate(s: &Column, method: InterpolationMethod) -> PolarsResult<Column> {
     Ok(polars_ops::prelude::interpolate(s.as_materialized_series(), method).into())
@@ -154,6 +205,21 @@ pub(super) fn mean_horizontal(s: &mut [Column], ignore_nulls: bool) -> PolarsRes
     polars_ops::prelude::mean_horizontal(s, null_strategy).map(Option::unwrap)
 }
 
+#[cfg(feature = "rule_failures")]
+pub(super) fn rule_failures(s: &mut [Column], output: RuleFailuresOutput) -> PolarsResult<Column> {
+    polars_ops::prelude::rule_failures(s, output)
+}
+
+#[cfg(feature = "weighted")]
+pub(super) fn weighted_agg(s: &[Column], agg: WeightedAgg) -> PolarsResult<Column> {
+    polars_ops::prelude::weighted_agg(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        agg,
+    )
+    .map(Column::from)
+}
+
 pub(super) fn drop_nulls(s: &Column) -> PolarsResult<Column> {
     Ok(s.drop_nulls())
 }
@@ -674,9 +740,13 @@ pub(super) fn unique(s: &Column, stable: bool) -> PolarsResult<Column> {
 }
 
 #[cfg(feature = "fused")]
-pub(super) fn fused(input: &[Column], op: FusedOperator) -> PolarsResult<Column> {
+pub(super) fn fused(input: &[Column], op: &FusedOperator) -> PolarsResult<Column> {
     use polars_plan::plans::FusedOperator;
 
+    if let FusedOperator::Elementwise(expr) = op {
+        return polars_ops::series::fused_elementwise_column(&input[0], expr);
+    }
+
     let s0 = &input[0];
     let s1 = &input[1];
     let s2 = &input[2];
@@ -684,6 +754,7 @@ pub(super) fn fused(input: &[Column], op: FusedOperator) -> PolarsResult<Column>
         FusedOperator::MultiplyAdd => Ok(polars_ops::series::fma_columns(s0, s1, s2)),
         FusedOperator::SubMultiply => Ok(polars_ops::series::fsm_columns(s0, s1, s2)),
         FusedOperator::MultiplySub => Ok(polars_ops::series::fms_columns(s0, s1, s2)),
+        FusedOperator::Elementwise(_) => unreachable!(),
     }
 }
 
@@ -883,6 +954,22 @@ pub(super) fn qcut(
     .map(Column::from)
 }
 
+#[cfg(feature = "to_dummies")]
+pub(super) fn to_dummies(
+    s: &Column,
+    categories: &[PlSmallStr],
+    separator: &str,
+    drop_first: bool,
+) -> PolarsResult<Column> {
+    polars_ops::series::to_dummies_with_categories(
+        s.as_materialized_series(),
+        categories,
+        separator,
+        drop_first,
+    )
+    .map(IntoColumn::into_column)
+}
+
 #[cfg(feature = "ewma")]
 pub(super) fn ewm_mean(
     s: &Column,
@@ -108,11 +108,15 @@ mod cum;
 mod datetime;
 #[cfg(feature = "dtype-extension")]
 mod extension;
+#[cfg(feature = "geo")]
+mod geo;
 mod groups_dispatch;
 mod horizontal;
 mod list;
 mod misc;
 mod pow;
+#[cfg(feature = "quant")]
+mod quant;
 #[cfg(feature = "random")]
 mod random;
 #[cfg(feature = "range")]
@@ -146,7 +150,11 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::Categorical(func) => cat::function_expr_to_udf(func),
         #[cfg(feature = "dtype-extension")]
         F::Extension(func) => extension::function_expr_to_udf(func),
+        #[cfg(feature = "geo")]
+        F::Geo(func) => geo::function_expr_to_udf(func),
         F::ListExpr(func) => list::function_expr_to_udf(func),
+        #[cfg(feature = "quant")]
+        F::Quant(func) => quant::function_expr_to_udf(func),
         #[cfg(feature = "strings")]
         F::StringExpr(func) => strings::function_expr_to_udf(func),
         #[cfg(feature = "dtype-struct")]
@@ -227,6 +235,8 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
                 Skew => map!(rolling::rolling_skew, options.clone()),
                 #[cfg(feature = "moment")]
                 Kurtosis => map!(rolling::rolling_kurtosis, options.clone()),
+                #[cfg(feature = "outliers")]
+                Mad => map!(rolling::rolling_mad, options.clone()),
                 #[cfg(feature = "cov")]
                 CorrCov {
                     corr_cov_options,
@@ -340,6 +350,8 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::Diff(null_behavior) => map_as_slice!(misc::diff, null_behavior),
         #[cfg(feature = "pct_change")]
         F::PctChange => map_as_slice!(misc::pct_change),
+        #[cfg(feature = "pct_change_by")]
+        F::PctChangeBy { offset } => map_as_slice!(misc::pct_change_by, offset),
         #[cfg(feature = "interpolate")]
         F::Interpolate(method) => {
             map!(misc::interpolate, method)
@@ -366,7 +378,7 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         #[cfg(feature = "round_series")]
         F::Ceil => map!(round::ceil),
         #[cfg(feature = "fused")]
-        F::Fused(op) => map_as_slice!(misc::fused, op),
+        F::Fused(op) => map_as_slice!(misc::fused, &op),
         F::ConcatExpr(rechunk) => map_as_slice!(misc::concat_expr, rechunk),
         #[cfg(feature = "cov")]
         F::Correlation { method } => map_as_slice!(misc::corr, method),
@@ -410,6 +422,12 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::RLE => map!(polars_ops::series::rle),
         #[cfg(feature = "rle")]
         F::RLEID => map!(polars_ops::series::rle_id),
+        #[cfg(feature = "to_dummies")]
+        F::ToDummies {
+            categories,
+            separator,
+            drop_first,
+        } => map!(misc::to_dummies, &categories, &separator, drop_first),
         F::ToPhysical => map!(misc::to_physical),
         #[cfg(feature = "random")]
         F::Random { method, seed } => {
@@ -495,6 +513,10 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::MinHorizontal => wrap!(misc::min_horizontal),
         F::SumHorizontal { ignore_nulls } => wrap!(misc::sum_horizontal, ignore_nulls),
         F::MeanHorizontal { ignore_nulls } => wrap!(misc::mean_horizontal, ignore_nulls),
+        #[cfg(feature = "rule_failures")]
+        F::RuleFailures { output } => wrap!(misc::rule_failures, output),
+        #[cfg(feature = "weighted")]
+        F::Weighted(agg) => map_as_slice!(misc::weighted_agg, agg),
         #[cfg(feature = "ewma")]
         F::EwmMean { options } => map!(misc::ewm_mean, options),
         #[cfg(feature = "ewma_by")]
@@ -562,6 +584,9 @@ pub fn function_expr_to_groups_udf(func: &IRFunctionExpr) -> Option<SpecialEq<Ar
     Some(match func {
         F::NullCount => wrap_groups!(groups_dispatch::null_count),
         F::Reverse => wrap_groups!(groups_dispatch::reverse),
+        F::GatherEvery { n, offset } => {
+            wrap_groups!(groups_dispatch::gather_every, (*n, v1: usize), (*offset, v2: usize))
+        },
         F::Boolean(IRBooleanFunction::Any { ignore_nulls }) => {
             let ignore_nulls = *ignore_nulls;
             wrap_groups!(groups_dispatch::any, (ignore_nulls, v: bool))
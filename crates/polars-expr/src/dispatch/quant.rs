@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::prelude::*;
+use polars_ops::series;
+use polars_plan::dsl::{ColumnsUdf, SpecialEq};
+use polars_plan::plans::IRQuantFunction;
+
+pub fn function_expr_to_udf(func: IRQuantFunction) -> SpecialEq<Arc<dyn ColumnsUdf>> {
+    use IRQuantFunction::*;
+    match func {
+        Drawdown => map!(drawdown),
+        MaxDrawdown => map!(max_drawdown),
+        HitRatio => map!(hit_ratio),
+        Sharpe { annualization } => map!(sharpe, annualization),
+    }
+}
+
+fn drawdown(c: &Column) -> PolarsResult<Column> {
+    series::drawdown(c.as_materialized_series()).map(Column::from)
+}
+
+fn max_drawdown(c: &Column) -> PolarsResult<Column> {
+    series::max_drawdown(c.as_materialized_series()).map(Column::from)
+}
+
+fn hit_ratio(c: &Column) -> PolarsResult<Column> {
+    series::hit_ratio(c.as_materialized_series()).map(Column::from)
+}
+
+fn sharpe(c: &Column, annualization: f64) -> PolarsResult<Column> {
+    series::sharpe(c.as_materialized_series(), annualization).map(Column::from)
+}
@@ -110,6 +110,13 @@ pub(super) fn rolling_kurtosis(
     polars_ops::series::rolling_kurtosis(s, options).map(Column::from)
 }
 
+#[cfg(feature = "outliers")]
+pub(super) fn rolling_mad(s: &Column, options: RollingOptionsFixedWindow) -> PolarsResult<Column> {
+    // @scalar-opt
+    let s = s.as_materialized_series();
+    polars_ops::series::rolling_mad(s, options).map(Column::from)
+}
+
 #[cfg(feature = "cov")]
 fn det_count_x_y(window_size: usize, len: usize, dtype: &DataType) -> Series {
     match dtype {
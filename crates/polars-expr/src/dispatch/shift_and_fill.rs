@@ -14,33 +14,24 @@ where
     ca.shift_and_fill(n, fill_value)
 }
 
-#[cfg(any(
-    feature = "object",
-    feature = "dtype-struct",
-    feature = "dtype-categorical"
-))]
+/// Shift `s` and fill the vacated slots with the values of `fill_value` at the same positions,
+/// which is either a single value or a column of the same length as `s`.
 fn shift_and_fill_with_mask(s: &Column, n: i64, fill_value: &Column) -> PolarsResult<Column> {
     use arrow::array::BooleanArray;
     use arrow::bitmap::BitmapBuilder;
     use polars_core::prelude::BooleanChunked;
 
-    let mask: BooleanChunked = if n > 0 {
-        let len = s.len();
-        let mut bits = BitmapBuilder::with_capacity(s.len());
-        bits.extend_constant(n as usize, false);
-        bits.extend_constant(len.saturating_sub(n as usize), true);
-        let mask = BooleanArray::from_data_default(bits.freeze(), None);
-        mask.into()
+    let len = s.len();
+    let shifted = (n.unsigned_abs() as usize).min(len);
+    let mut bits = BitmapBuilder::with_capacity(len);
+    if n > 0 {
+        bits.extend_constant(shifted, false);
+        bits.extend_constant(len - shifted, true);
     } else {
-        let length = s.len() as i64;
-        // n is negative, so subtraction.
-        let tipping_point = std::cmp::max(length + n, 0);
-        let mut bits = BitmapBuilder::with_capacity(s.len());
-        bits.extend_constant(tipping_point as usize, true);
-        bits.extend_constant(-n as usize, false);
-        let mask = BooleanArray::from_data_default(bits.freeze(), None);
-        mask.into()
-    };
+        bits.extend_constant(len - shifted, true);
+        bits.extend_constant(shifted, false);
+    }
+    let mask: BooleanChunked = BooleanArray::from_data_default(bits.freeze(), None).into();
     s.shift(n).zip_with_same_type(&mask, fill_value)
 }
 
@@ -53,6 +44,14 @@ pub(super) fn shift_and_fill(args: &[Column]) -> PolarsResult<Column> {
         let logical = s.dtype();
         let physical = s.to_physical_repr();
         let fill_value_s = &args[2];
+        if fill_value_s.len() != 1 {
+            polars_ensure!(
+                fill_value_s.len() == s.len(),
+                ShapeMismatch: "fill value of 'shift' has length {}, expected 1 or {}",
+                fill_value_s.len(), s.len()
+            );
+            return shift_and_fill_with_mask(s, n, fill_value_s);
+        }
         let fill_value = fill_value_s.get(0).unwrap();
 
         use DataType::*;
@@ -3,6 +3,8 @@ use std::sync::Arc;
 
 use polars_core::prelude::*;
 use polars_core::utils::{CustomIterTools, handle_casting_failures};
+#[cfg(feature = "crypto")]
+use polars_ops::chunked_array::crypto::CryptoKey;
 use polars_ops::prelude::{BinaryNameSpaceImpl, StringNameSpaceImpl};
 #[cfg(feature = "temporal")]
 use polars_plan::dsl::StrptimeOptions;
@@ -139,6 +141,24 @@ pub fn function_expr_to_udf(func: IRStringFunction) -> SpecialEq<Arc<dyn Columns
         },
         #[cfg(feature = "regex")]
         EscapeRegex => map!(escape_regex),
+        #[cfg(feature = "string_mask")]
+        MaskPattern {
+            pat,
+            replacement,
+            keep_last_n,
+        } => map!(mask_pattern, &pat, &replacement, keep_last_n),
+        #[cfg(feature = "string_mask")]
+        MaskPii {
+            pattern,
+            replacement,
+            keep_last_n,
+        } => map!(mask_pii, pattern, &replacement, keep_last_n),
+        #[cfg(feature = "crypto")]
+        Tokenize { key } => map!(tokenize, &key),
+        #[cfg(feature = "crypto")]
+        Encrypt { key } => map!(encrypt, &key),
+        #[cfg(feature = "crypto")]
+        MaskFormatPreserving { key } => map!(mask_format_preserving, &key),
     }
 }
 
@@ -860,3 +880,47 @@ pub(super) fn escape_regex(s: &Column) -> PolarsResult<Column> {
     let ca = s.str()?;
     Ok(ca.str_escape_regex().into_column())
 }
+
+#[cfg(feature = "string_mask")]
+pub(super) fn mask_pattern(
+    s: &Column,
+    pat: &str,
+    replacement: &str,
+    keep_last_n: usize,
+) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    polars_ops::chunked_array::strings::mask_pattern(ca, pat, replacement, keep_last_n)
+        .map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "string_mask")]
+pub(super) fn mask_pii(
+    s: &Column,
+    pattern: polars_ops::prelude::PiiPattern,
+    replacement: &str,
+    keep_last_n: usize,
+) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    polars_ops::chunked_array::strings::mask_pii(ca, pattern, replacement, keep_last_n)
+        .map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "crypto")]
+pub(super) fn tokenize(s: &Column, key: &CryptoKey) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    polars_ops::chunked_array::crypto::tokenize(ca, key).map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "crypto")]
+pub(super) fn encrypt(s: &Column, key: &CryptoKey) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    polars_ops::chunked_array::crypto::encrypt_deterministic(&ca.as_binary(), key)
+        .map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "crypto")]
+pub(super) fn mask_format_preserving(s: &Column, key: &CryptoKey) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    polars_ops::chunked_array::crypto::mask_format_preserving(ca, key)
+        .map(|ca| ca.into_column())
+}
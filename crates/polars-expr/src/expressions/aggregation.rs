@@ -204,12 +204,26 @@ impl PhysicalExpr for AggregationExpr {
                 },
                 GroupByMethod::Mean => {
                     let (c, groups) = ac.get_final_aggregation();
-                    let agg_c = c.agg_mean(&groups);
+                    let agg_c = match c.dtype() {
+                        // Error if the sum of a group overflows, instead of returning null.
+                        #[cfg(feature = "dtype-decimal")]
+                        DataType::Decimal(_, _) => {
+                            c.decimal()?.agg_mean_checked(&groups, true)?.into_column()
+                        },
+                        _ => c.agg_mean(&groups),
+                    };
                     AggregatedScalar(agg_c.with_name(keep_name))
                 },
                 GroupByMethod::Sum => {
                     let (c, groups) = ac.get_final_aggregation();
-                    let agg_c = c.agg_sum(&groups);
+                    let agg_c = match c.dtype() {
+                        // Error if the sum of a group overflows, instead of returning null.
+                        #[cfg(feature = "dtype-decimal")]
+                        DataType::Decimal(_, _) => {
+                            c.decimal()?.agg_sum_checked(&groups, true)?.into_column()
+                        },
+                        _ => c.agg_sum(&groups),
+                    };
                     AggregatedScalar(agg_c.with_name(keep_name))
                 },
                 GroupByMethod::Count { include_nulls } => {
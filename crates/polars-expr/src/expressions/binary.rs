@@ -82,6 +82,10 @@ pub fn apply_operator(left: &Column, right: &Column, op: Operator) -> PolarsResu
             _ if right.dtype().is_array() => left / right,
             List(_) => left / right,
             _ if right.dtype().is_list() => left / right,
+            #[cfg(feature = "dtype-extension")]
+            Extension(..) => left / right,
+            #[cfg(feature = "dtype-extension")]
+            _ if right.dtype().is_extension() => left / right,
             _ if left.dtype().is_string() || right.dtype().is_string() => {
                 polars_bail!(InvalidOperation: "cannot divide using strings")
             },
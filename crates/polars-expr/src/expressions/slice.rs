@@ -2,6 +2,7 @@ use AnyValue::Null;
 use polars_core::POOL;
 use polars_core::prelude::*;
 use polars_core::utils::{CustomIterTools, slice_offsets};
+use polars_ops::prelude::ListNameSpaceImpl;
 use polars_utils::idx_vec::IdxVec;
 use rayon::prelude::*;
 
@@ -61,6 +62,19 @@ fn check_argument(arg: &Column, groups: &GroupsType, name: &str, expr: &Expr) ->
     Ok(())
 }
 
+/// Get the value of a slice argument for every group.
+///
+/// Arguments that are not aggregated, e.g. a column holding the number of rows to take of every
+/// group, use their first value in each group.
+fn group_argument(ac: &mut AggregationContext) -> PolarsResult<Column> {
+    if matches!(ac.agg_state(), AggState::NotAggregated(_) | AggState::AggregatedList(_)) {
+        let lists = ac.aggregated_as_list();
+        Ok(lists.lst_get(0, true)?.into_column())
+    } else {
+        Ok(ac.aggregated())
+    }
+}
+
 fn slice_groups_idx(offset: i64, length: usize, mut first: IdxSize, idx: &[IdxSize]) -> IdxItem {
     let (offset, len) = slice_offsets(offset, length, idx.len());
 
@@ -169,7 +183,7 @@ impl PhysicalExpr for SliceExpr {
                 }
                 let groups = ac.groups();
                 let offset = extract_offset(offset, &self.expr)?;
-                let length = ac_length.aggregated();
+                let length = group_argument(&mut ac_length)?;
                 check_argument(&length, groups, "length", &self.expr)?;
 
                 let length = length.cast(&IDX_DTYPE)?;
@@ -211,7 +225,7 @@ impl PhysicalExpr for SliceExpr {
                 }
                 let groups = ac.groups();
                 let length = extract_length(length, &self.expr)?;
-                let offset = ac_offset.aggregated();
+                let offset = group_argument(&mut ac_offset)?;
                 check_argument(&offset, groups, "offset", &self.expr)?;
 
                 let offset = offset.cast(&DataType::Int64)?;
@@ -253,8 +267,8 @@ impl PhysicalExpr for SliceExpr {
                 }
 
                 let groups = ac.groups();
-                let length = ac_length.aggregated();
-                let offset = ac_offset.aggregated();
+                let length = group_argument(&mut ac_length)?;
+                let offset = group_argument(&mut ac_offset)?;
                 check_argument(&length, groups, "length", &self.expr)?;
                 check_argument(&offset, groups, "offset", &self.expr)?;
 
@@ -851,16 +851,19 @@ impl PhysicalExpr for WindowExpr {
                                         return Ordering::Equal;
                                     }
 
-                                    let mut cmp = order_a.cmp(&order_b);
-                                    if !is_valid_a {
-                                        cmp = Ordering::Less;
-                                    }
-                                    if !is_valid_b {
-                                        cmp = Ordering::Greater;
+                                    // Nulls are ordered by `nulls_last` only, regardless of
+                                    // the direction of the other values.
+                                    if !is_valid_a | !is_valid_b {
+                                        let cmp = if is_valid_a {
+                                            Ordering::Greater
+                                        } else {
+                                            Ordering::Less
+                                        };
+                                        return if options.nulls_last { cmp.reverse() } else { cmp };
                                     }
-                                    if options.descending
-                                        | ((!is_valid_a | !is_valid_b) & options.nulls_last)
-                                    {
+
+                                    let mut cmp = order_a.cmp(&order_b);
+                                    if options.descending {
                                         cmp = cmp.reverse();
                                     }
                                     cmp
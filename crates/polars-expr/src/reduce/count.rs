@@ -118,6 +118,15 @@ impl GroupedReduction for CountReduce {
         Ok(ca.into_series())
     }
 
+    fn checkpoint_state(&self) -> Option<Vec<Series>> {
+        Some(vec![native_to_series(self.counts.clone())])
+    }
+
+    fn restore_state(&mut self, state: &[Series]) -> PolarsResult<()> {
+        self.counts = series_to_native(&state[0])?;
+        Ok(())
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
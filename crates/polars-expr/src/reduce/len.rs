@@ -89,6 +89,15 @@ impl GroupedReduction for LenReduce {
         Ok(ca.into_series())
     }
 
+    fn checkpoint_state(&self) -> Option<Vec<Series>> {
+        Some(vec![native_to_series(self.groups.clone())])
+    }
+
+    fn restore_state(&mut self, state: &[Series]) -> PolarsResult<()> {
+        self.groups = series_to_native(&state[0])?;
+        Ok(())
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -2,6 +2,8 @@ use std::marker::PhantomData;
 
 use arrow::temporal_conversions::MICROSECONDS_IN_DAY;
 use num_traits::{AsPrimitive, Zero};
+#[cfg(feature = "dtype-decimal")]
+use polars_compute::decimal::{DEC128_MAX_PREC, dec128_fits, dec128_mean, dec128_mean_scale};
 use polars_core::with_match_physical_numeric_polars_type;
 
 use super::*;
@@ -18,7 +20,7 @@ pub fn new_mean_reduction(dtype: DataType) -> PolarsResult<Box<dyn GroupedReduct
             })
         },
         #[cfg(feature = "dtype-decimal")]
-        Decimal(_, _) => Box::new(VGR::new(dtype, NumMeanReducer::<Int128Type>(PhantomData))),
+        Decimal(_, _) => Box::new(VGR::new(dtype, DecimalMeanReducer)),
         Null => Box::new(super::NullGroupedReduction::new(Scalar::null(
             DataType::Null,
         ))),
@@ -50,15 +52,6 @@ fn finish_output(values: Vec<(f64, usize)>, dtype: &DataType) -> Series {
                 .collect_ca(PlSmallStr::EMPTY);
             ca.into_series()
         },
-        #[cfg(feature = "dtype-decimal")]
-        DataType::Decimal(_prec, scale) => {
-            let inv_scale_factor = 1.0 / 10u128.pow(*scale as u32) as f64;
-            let ca: Float64Chunked = values
-                .into_iter()
-                .map(|(s, c)| (c != 0).then(|| s / c as f64 * inv_scale_factor))
-                .collect_ca(PlSmallStr::EMPTY);
-            ca.into_series()
-        },
         #[cfg(feature = "dtype-datetime")]
         DataType::Date => {
             const US_IN_DAY: f64 = MICROSECONDS_IN_DAY as f64;
@@ -129,6 +122,85 @@ where
         assert!(m.is_none());
         Ok(finish_output(v, dtype))
     }
+
+    fn checkpoint_values(&self, v: &[Self::Value]) -> Option<Vec<Series>> {
+        let sums = v.iter().map(|(sum, _)| *sum).collect();
+        let counts = v.iter().map(|(_, count)| *count as u64).collect();
+        Some(vec![native_to_series::<f64>(sums), native_to_series::<u64>(counts)])
+    }
+
+    fn restore_values(&self, state: &[Series]) -> PolarsResult<Vec<Self::Value>> {
+        let sums = series_to_native::<f64>(&state[0])?;
+        let counts = series_to_native::<u64>(&state[1])?;
+        Ok(sums.into_iter().zip(counts).map(|(s, c)| (s, c as usize)).collect())
+    }
+}
+
+/// Sums the decimals exactly with checked additions, so the mean stays a decimal. An
+/// overflowing sum is `None`.
+#[cfg(feature = "dtype-decimal")]
+#[derive(Clone)]
+struct DecimalMeanReducer;
+
+#[cfg(feature = "dtype-decimal")]
+impl Reducer for DecimalMeanReducer {
+    type Dtype = Int128Type;
+    type Value = (Option<i128>, usize);
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        (Some(0), 0)
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        s.to_physical_repr()
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.0 = a.0.zip(b.0).and_then(|(a, b)| a.checked_add(b));
+        a.1 += b.1;
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<i128>, _seq_id: u64) {
+        if let Some(b) = b {
+            a.0 = a.0.and_then(|a| a.checked_add(b));
+            a.1 += 1;
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        for x in ca.iter().flatten() {
+            v.0 = v.0.and_then(|v| v.checked_add(x));
+        }
+        v.1 += ca.len() - ca.null_count();
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let DataType::Decimal(prec, scale) = dtype else {
+            unreachable!()
+        };
+        let mean_scale = dec128_mean_scale(*prec, *scale);
+        let sums = v
+            .into_iter()
+            .map(|(s, c)| Some((s.filter(|s| dec128_fits(*s, DEC128_MAX_PREC))?, c)))
+            .collect::<Option<Vec<_>>>();
+        let Some(sums) = sums else {
+            polars_bail!(ComputeError: "overflow in decimal sum")
+        };
+        let ca: Int128Chunked = sums
+            .into_iter()
+            .map(|(s, c)| dec128_mean(s, c, *scale, mean_scale))
+            .collect_ca(PlSmallStr::EMPTY);
+        Ok(ca.into_decimal_unchecked(DEC128_MAX_PREC, mean_scale).into_series())
+    }
 }
 
 #[derive(Clone)]
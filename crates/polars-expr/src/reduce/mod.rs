@@ -21,6 +21,7 @@ use std::marker::PhantomData;
 
 use arrow::array::{Array, PrimitiveArray, StaticArray};
 use arrow::bitmap::{Bitmap, BitmapBuilder, MutableBitmap};
+use arrow::types::NativeType;
 pub use convert::into_reduction;
 pub use min_max::{new_max_reduction, new_min_reduction};
 use polars_core::prelude::*;
@@ -111,6 +112,19 @@ pub trait GroupedReduction: Any + Send + Sync {
     /// After this operation the number of groups is reset to 0.
     fn finalize(&mut self) -> PolarsResult<Series>;
 
+    /// Returns the state of the groups as columns with one row per group, to store it in a
+    /// checkpoint, or `None` if this reduction doesn't support checkpointing.
+    ///
+    /// The evicted groups are not part of the state.
+    fn checkpoint_state(&self) -> Option<Vec<Series>> {
+        None
+    }
+
+    /// Replaces the groups by the state returned by [`GroupedReduction::checkpoint_state`].
+    fn restore_state(&mut self, _state: &[Series]) -> PolarsResult<()> {
+        polars_bail!(InvalidOperation: "this reduction can't be restored from a checkpoint")
+    }
+
     /// Returns this GroupedReduction as a dyn Any.
     fn as_any(&self) -> &dyn Any;
 }
@@ -139,6 +153,32 @@ pub trait Reducer: Send + Sync + Clone + 'static {
         m: Option<Bitmap>,
         dtype: &DataType,
     ) -> PolarsResult<Series>;
+
+    /// Stores the values of the groups in columns, see [`GroupedReduction::checkpoint_state`].
+    fn checkpoint_values(&self, _v: &[Self::Value]) -> Option<Vec<Series>> {
+        None
+    }
+
+    /// Restores the values stored by [`Reducer::checkpoint_values`].
+    fn restore_values(&self, _state: &[Series]) -> PolarsResult<Vec<Self::Value>> {
+        polars_bail!(InvalidOperation: "this reduction can't be restored from a checkpoint")
+    }
+}
+
+/// Stores native values in a column, for [`Reducer::checkpoint_values`].
+fn native_to_series<T: NativeType>(values: Vec<T>) -> Series {
+    Series::from_arrow(PlSmallStr::EMPTY, Box::new(PrimitiveArray::from_vec(values))).unwrap()
+}
+
+/// Reads the values of a column created by [`native_to_series`].
+fn series_to_native<T: NativeType>(s: &Series) -> PolarsResult<Vec<T>> {
+    let s = s.rechunk();
+    let Some(arr) = s.chunks().first() else {
+        return Ok(Vec::new());
+    };
+    let arr = arr.as_any().downcast_ref::<PrimitiveArray<T>>();
+    let arr = arr.ok_or_else(|| polars_err!(ComputeError: "invalid checkpoint of a reduction"))?;
+    Ok(arr.values().to_vec())
 }
 
 pub trait NumericReduction: Send + Sync + 'static {
@@ -212,6 +252,14 @@ impl<R: NumericReduction> Reducer for NumReducer<R> {
         let arr = Box::new(PrimitiveArray::<Self::Value>::from_vec(v).with_validity(m));
         Ok(unsafe { Series::from_chunks_and_dtype_unchecked(PlSmallStr::EMPTY, vec![arr], dtype) })
     }
+
+    fn checkpoint_values(&self, v: &[Self::Value]) -> Option<Vec<Series>> {
+        Some(vec![native_to_series(v.to_vec())])
+    }
+
+    fn restore_values(&self, state: &[Series]) -> PolarsResult<Vec<Self::Value>> {
+        series_to_native(&state[0])
+    }
 }
 
 pub struct VecGroupedReduction<R: Reducer> {
@@ -347,6 +395,15 @@ where
         self.reducer.finish(v, None, &self.in_dtype)
     }
 
+    fn checkpoint_state(&self) -> Option<Vec<Series>> {
+        self.reducer.checkpoint_values(&self.values)
+    }
+
+    fn restore_state(&mut self, state: &[Series]) -> PolarsResult<()> {
+        self.values = self.reducer.restore_values(state)?;
+        Ok(())
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -488,6 +545,22 @@ where
         self.reducer.finish(v, Some(m.freeze()), &self.in_dtype)
     }
 
+    fn checkpoint_state(&self) -> Option<Vec<Series>> {
+        let mut state = self.reducer.checkpoint_values(&self.values)?;
+        let mask = BooleanChunked::from_iter_values(PlSmallStr::EMPTY, self.mask.iter());
+        state.push(mask.into_series());
+        Some(state)
+    }
+
+    fn restore_state(&mut self, state: &[Series]) -> PolarsResult<()> {
+        let (mask, values) = state
+            .split_last()
+            .ok_or_else(|| polars_err!(ComputeError: "invalid checkpoint of a reduction"))?;
+        self.values = self.reducer.restore_values(values)?;
+        self.mask = mask.bool()?.into_no_null_iter().collect();
+        Ok(())
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -2,6 +2,8 @@ use std::borrow::Cow;
 
 use arrow::array::PrimitiveArray;
 use num_traits::Zero;
+#[cfg(feature = "dtype-decimal")]
+use polars_compute::decimal::{DEC128_MAX_PREC, dec128_fits};
 use polars_core::with_match_physical_numeric_polars_type;
 use polars_utils::float::IsFloat;
 #[cfg(feature = "dtype-f16")]
@@ -42,6 +44,8 @@ fn out_dtype(in_dtype: &DataType) -> DataType {
     match in_dtype {
         Boolean => IDX_DTYPE,
         Int8 | UInt8 | Int16 | UInt16 => Int64,
+        #[cfg(feature = "dtype-decimal")]
+        Decimal(_, scale) => Decimal(DEC128_MAX_PREC, *scale),
         dt => dt.clone(),
     }
 }
@@ -58,7 +62,7 @@ pub fn new_sum_reduction(dtype: DataType) -> PolarsResult<Box<dyn GroupedReducti
             })
         },
         #[cfg(feature = "dtype-decimal")]
-        Decimal(_, _) => Box::new(VGR::new(dtype, NumSumReducer::<Int128Type>(PhantomData))),
+        Decimal(_, _) => Box::new(VGR::new(dtype, DecimalSumReducer)),
         Duration(_) => Box::new(VGR::new(dtype, NumSumReducer::<Int64Type>(PhantomData))),
         Null => Box::new(super::NullGroupedReduction::new(Scalar::null(
             DataType::Null,
@@ -139,6 +143,73 @@ where
             Series::from_chunks_and_dtype_unchecked(PlSmallStr::EMPTY, vec![arr], &out_dtype(dtype))
         })
     }
+
+    fn checkpoint_values(&self, v: &[Self::Value]) -> Option<Vec<Series>> {
+        Some(vec![native_to_series(v.to_vec())])
+    }
+
+    fn restore_values(&self, state: &[Series]) -> PolarsResult<Vec<Self::Value>> {
+        series_to_native(&state[0])
+    }
+}
+
+/// Sums the decimals with checked additions, an overflowing sum is `None`.
+#[cfg(feature = "dtype-decimal")]
+#[derive(Clone)]
+struct DecimalSumReducer;
+
+#[cfg(feature = "dtype-decimal")]
+impl Reducer for DecimalSumReducer {
+    type Dtype = Int128Type;
+    type Value = Option<i128>;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        Some(0)
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        s.to_physical_repr()
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        *a = a.zip(*b).and_then(|(a, b)| a.checked_add(b));
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<i128>, _seq_id: u64) {
+        if let Some(b) = b {
+            *a = a.and_then(|a| a.checked_add(b));
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        for x in ca.iter().flatten() {
+            *v = v.and_then(|v| v.checked_add(x));
+        }
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let DataType::Decimal(_, scale) = dtype else {
+            unreachable!()
+        };
+        let sums = v
+            .into_iter()
+            .map(|sum| sum.filter(|sum| dec128_fits(*sum, DEC128_MAX_PREC)))
+            .collect::<Option<Vec<_>>>();
+        let Some(sums) = sums else {
+            polars_bail!(ComputeError: "overflow in decimal sum")
+        };
+        let ca = Int128Chunked::from_vec(PlSmallStr::EMPTY, sums);
+        Ok(ca.into_decimal_unchecked(DEC128_MAX_PREC, *scale).into_series())
+    }
 }
 
 #[derive(Clone)]
@@ -177,4 +248,12 @@ impl Reducer for BoolSumReducer {
         assert!(dtype == &DataType::Boolean);
         Ok(IdxCa::from_vec(PlSmallStr::EMPTY, v).into_series())
     }
+
+    fn checkpoint_values(&self, v: &[Self::Value]) -> Option<Vec<Series>> {
+        Some(vec![native_to_series(v.to_vec())])
+    }
+
+    fn restore_values(&self, state: &[Series]) -> PolarsResult<Vec<Self::Value>> {
+        series_to_native(&state[0])
+    }
 }
@@ -5,7 +5,7 @@ use std::time::Duration;
 
 use arrow::bitmap::Bitmap;
 use bitflags::bitflags;
-use polars_core::config::verbose;
+use polars_core::config::{audit_null_filters, small_batch_threshold, verbose};
 use polars_core::prelude::*;
 use polars_ops::prelude::ChunkJoinOptIds;
 use polars_utils::relaxed_cell::RelaxedCell;
@@ -75,6 +75,8 @@ bitflags! {
         const CACHE_WINDOW_EXPR = 0x02;
         /// Indicates the expression has a window function
         const HAS_WINDOW = 0x04;
+        /// Report the rows that filters drop because their predicate evaluated to null.
+        const AUDIT_NULL_FILTERS = 0x08;
     }
 }
 
@@ -91,6 +93,9 @@ impl StateFlags {
         if verbose {
             flags |= StateFlags::VERBOSE;
         }
+        if audit_null_filters() {
+            flags |= StateFlags::AUDIT_NULL_FILTERS;
+        }
         flags
     }
     fn as_u8(self) -> u8 {
@@ -126,6 +131,7 @@ pub struct ExecutionState {
     pub element: Arc<Option<(Column, Option<Bitmap>)>>,
     node_timer: Option<NodeTimer>,
     stop: Arc<RelaxedCell<bool>>,
+    small_batch_threshold: usize,
 }
 
 impl ExecutionState {
@@ -144,6 +150,7 @@ impl ExecutionState {
             element: Default::default(),
             node_timer: None,
             stop: Arc::new(RelaxedCell::from(false)),
+            small_batch_threshold: small_batch_threshold(),
         }
     }
 
@@ -210,6 +217,7 @@ impl ExecutionState {
             element: self.element.clone(),
             node_timer: self.node_timer.clone(),
             stop: self.stop.clone(),
+            small_batch_threshold: self.small_batch_threshold,
         }
     }
 
@@ -295,6 +303,18 @@ impl ExecutionState {
         flags.contains(StateFlags::VERBOSE)
     }
 
+    /// Report the rows that filters drop because their predicate evaluated to null.
+    pub fn audit_null_filters(&self) -> bool {
+        let flags: StateFlags = self.flags.load().into();
+        flags.contains(StateFlags::AUDIT_NULL_FILTERS)
+    }
+
+    /// Whether a frame of `height` rows should be filtered, projected, grouped or sorted on the
+    /// calling thread, without splitting it or dispatching work to the thread pool.
+    pub fn is_small_batch(&self, height: usize) -> bool {
+        height < self.small_batch_threshold
+    }
+
     pub fn remove_cache_window_flag(&mut self) {
         self.set_flags(&|mut flags| {
             flags.remove(StateFlags::CACHE_WINDOW_EXPR);
@@ -1,5 +1,7 @@
 mod execution_state;
 mod node_timer;
+mod null_filter_audit;
 
 pub use execution_state::*;
+pub use null_filter_audit::NullFilterAudit;
 use node_timer::*;
@@ -0,0 +1,77 @@
+use std::sync::Mutex;
+
+use polars_core::prelude::*;
+
+/// The maximum number of dropped rows that is printed per filter.
+const SAMPLE_SIZE: usize = 10;
+
+/// Traces the rows a filter drops because its predicate evaluated to null rather than to
+/// `false`, e.g. because Kleene logic propagated a null through `&` or `|`.
+///
+/// Enabled by setting `POLARS_AUDIT_NULL_FILTERS=1` in verbose mode.
+pub struct NullFilterAudit {
+    predicate: String,
+    state: Mutex<AuditState>,
+}
+
+#[derive(Default)]
+struct AuditState {
+    height: usize,
+    null_count: usize,
+    sample: Option<DataFrame>,
+}
+
+impl NullFilterAudit {
+    pub fn new(predicate: String) -> Self {
+        Self {
+            predicate,
+            state: Default::default(),
+        }
+    }
+
+    /// Record the rows of `df` that are dropped because `mask` is null.
+    pub fn record(&self, df: &DataFrame, mask: &BooleanChunked) -> PolarsResult<()> {
+        // A unit length mask is broadcast over the whole frame.
+        let null_count = if mask.len() == 1 {
+            mask.null_count() * df.height()
+        } else {
+            mask.null_count()
+        };
+
+        let mut state = self.state.lock().unwrap();
+        state.height += df.height();
+        if null_count == 0 {
+            return Ok(());
+        }
+        state.null_count += null_count;
+
+        let sampled = state.sample.as_ref().map_or(0, |sample| sample.height());
+        if sampled < SAMPLE_SIZE {
+            let rows = df
+                .filter(&mask.is_null())?
+                .head(Some(SAMPLE_SIZE - sampled));
+            match &mut state.sample {
+                Some(sample) => {
+                    sample.vstack_mut_owned(rows)?;
+                },
+                None => state.sample = Some(rows),
+            }
+        }
+        Ok(())
+    }
+
+    /// Print the rows that were dropped by a null predicate since the last report, if any, in
+    /// verbose mode.
+    pub fn report(&self) {
+        let state = std::mem::take(&mut *self.state.lock().unwrap());
+        if let Some(sample) = state.sample
+            && polars_core::config::verbose()
+        {
+            eprintln!(
+                "[null filter audit] predicate `{}` evaluated to null for {} of {} rows, \
+                which were dropped; sample of the dropped rows:\n{}",
+                self.predicate, state.null_count, state.height, sample
+            );
+        }
+    }
+}
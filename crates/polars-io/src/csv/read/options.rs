@@ -32,6 +32,8 @@ pub struct CsvReadOptions {
     // CSV-specific options
     pub parse_options: Arc<CsvParseOptions>,
     pub has_header: bool,
+    pub column_name_policy: ColumnNamePolicy,
+    pub normalize_column_names: bool,
     pub chunk_size: usize,
     /// Skip rows according to the CSV spec.
     pub skip_rows: usize,
@@ -80,6 +82,8 @@ impl Default for CsvReadOptions {
 
             parse_options: Default::default(),
             has_header: true,
+            column_name_policy: ColumnNamePolicy::default(),
+            normalize_column_names: false,
             chunk_size: 1 << 18,
             skip_rows: 0,
             skip_lines: 0,
@@ -198,6 +202,21 @@ impl CsvReadOptions {
         self
     }
 
+    /// Sets how to handle duplicate or empty column names in the header. Unless the policy is
+    /// [`ColumnNamePolicy::Raise`], empty names are replaced by `column_{i}`, with `i` the
+    /// position of the column starting at 1.
+    pub fn with_column_name_policy(mut self, column_name_policy: ColumnNamePolicy) -> Self {
+        self.column_name_policy = column_name_policy;
+        self
+    }
+
+    /// Normalize the column names in the header to the Unicode NFC form, such that names that
+    /// only differ in their representation are the same.
+    pub fn with_normalize_column_names(mut self, normalize_column_names: bool) -> Self {
+        self.normalize_column_names = normalize_column_names;
+        self
+    }
+
     /// Sets the chunk size used by the parser. This influences performance.
     pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
         self.chunk_size = chunk_size;
@@ -330,6 +349,20 @@ impl CsvParseOptions {
     }
 }
 
+/// How to handle the columns of a CSV header that have a duplicate or empty name.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ColumnNamePolicy {
+    /// Raise an error on a duplicate or empty name.
+    Raise,
+    /// Add a `_duplicated_{n}` suffix to the names of the later columns with the same name.
+    #[default]
+    Suffix,
+    /// Only read the last of the columns with the same name.
+    TakeLast,
+}
+
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
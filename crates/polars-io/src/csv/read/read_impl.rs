@@ -12,7 +12,7 @@ use rayon::prelude::*;
 
 use super::CsvParseOptions;
 use super::buffer::init_buffers;
-use super::options::{CsvEncoding, NullValuesCompiled};
+use super::options::{ColumnNamePolicy, CsvEncoding, NullValuesCompiled};
 use super::parser::{CountLines, is_comment_line, parse_lines};
 use super::reader::prepare_csv_schema;
 #[cfg(feature = "decompress")]
@@ -142,6 +142,8 @@ impl<'a> CoreReader<'a> {
         mut projection: Option<Vec<usize>>,
         max_records: Option<usize>,
         has_header: bool,
+        column_name_policy: ColumnNamePolicy,
+        normalize_column_names: bool,
         ignore_errors: bool,
         schema: Option<SchemaRef>,
         columns: Option<Arc<[PlSmallStr]>>,
@@ -201,6 +203,8 @@ impl<'a> CoreReader<'a> {
             skip_lines,
             projection: projection.clone().map(Arc::new),
             has_header,
+            column_name_policy,
+            normalize_column_names,
             ignore_errors,
             schema: schema.clone(),
             columns: columns.clone(),
@@ -221,7 +225,12 @@ impl<'a> CoreReader<'a> {
 
         let mut schema = match schema {
             Some(schema) => schema,
-            None => Arc::new(inferred_schema),
+            None => {
+                if projection.is_none() && !inferred_schema.dropped_columns.is_empty() {
+                    projection = Some(inferred_schema.read_columns().collect());
+                }
+                Arc::new(inferred_schema.schema)
+            },
         };
         if let Some(dtypes) = dtype_overwrite {
             polars_ensure!(
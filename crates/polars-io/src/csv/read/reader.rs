@@ -111,6 +111,8 @@ impl<R: MmapBytesReader> CsvReader<R> {
             self.options.projection.clone().map(|x| x.as_ref().clone()),
             self.options.infer_schema_length,
             self.options.has_header,
+            self.options.column_name_policy,
+            self.options.normalize_column_names,
             self.options.ignore_errors,
             self.options.schema.clone(),
             self.options.columns.clone(),
@@ -7,9 +7,10 @@ use polars_time::chunkedarray::string::infer as date_infer;
 use polars_time::prelude::string::Pattern;
 use polars_utils::format_pl_smallstr;
 use polars_utils::mmap::MemSlice;
+use unicode_normalization::UnicodeNormalization;
 
 use super::splitfields::SplitFields;
-use super::{CsvEncoding, CsvParseOptions, NullValues};
+use super::{ColumnNamePolicy, CsvEncoding, CsvParseOptions, NullValues};
 use crate::utils::{BOOLEAN_RE, FLOAT_RE, FLOAT_RE_DECIMAL, INTEGER_RE};
 
 /// Low-level CSV schema inference function.
@@ -21,13 +22,22 @@ pub(super) fn infer_file_schema_impl(
     content_lines: &[MemSlice],
     infer_all_as_str: bool,
     parse_options: &CsvParseOptions,
+    column_name_policy: ColumnNamePolicy,
+    normalize_column_names: bool,
     schema_overwrite: Option<&Schema>,
-) -> PolarsResult<Schema> {
-    let mut headers = header_line
+) -> PolarsResult<InferredSchema> {
+    let (mut headers, dropped_columns) = header_line
         .as_ref()
-        .map(|line| infer_headers(line, parse_options))
+        .map(|line| {
+            infer_headers(
+                line,
+                parse_options,
+                column_name_policy,
+                normalize_column_names,
+            )
+        })
         .transpose()?
-        .unwrap_or_else(|| Vec::with_capacity(8));
+        .unwrap_or_else(|| (Vec::with_capacity(8), Vec::new()));
 
     let extend_header_with_unknown_column = header_line.is_none();
 
@@ -46,17 +56,53 @@ pub(super) fn infer_file_schema_impl(
         )?;
     }
 
-    Ok(build_schema(&headers, &column_types, schema_overwrite))
+    Ok(InferredSchema {
+        schema: build_schema(&headers, &column_types, schema_overwrite),
+        dropped_columns,
+    })
+}
+
+/// The schema inferred from the header and the first rows of a CSV file.
+#[derive(Clone, Debug, Default)]
+pub struct InferredSchema {
+    /// The columns of the file by position, including the dropped columns.
+    pub schema: Schema,
+    /// The positions of the columns that aren't read due to [`ColumnNamePolicy::TakeLast`], in
+    /// ascending order.
+    pub dropped_columns: Vec<usize>,
+}
+
+impl InferredSchema {
+    /// The positions of the columns that are read.
+    pub fn read_columns(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.schema.len()).filter(|i| self.dropped_columns.binary_search(i).is_err())
+    }
+
+    /// The schema of the columns that are read.
+    pub fn read_schema(&self) -> Schema {
+        if self.dropped_columns.is_empty() {
+            return self.schema.clone();
+        }
+        self.read_columns()
+            .map(|i| {
+                let (name, dtype) = self.schema.get_at_index(i).unwrap();
+                (name.clone(), dtype.clone())
+            })
+            .collect()
+    }
 }
 
 // We use lossy utf8 here because we don't want the schema inference to fail on utf8.
 // It may later.
 const INFER_ENCODING: CsvEncoding = CsvEncoding::LossyUtf8;
 
+/// The column names of the header and the positions of the columns that aren't read.
 fn infer_headers(
     mut header_line: &[u8],
     parse_options: &CsvParseOptions,
-) -> PolarsResult<Vec<PlSmallStr>> {
+    column_name_policy: ColumnNamePolicy,
+    normalize_column_names: bool,
+) -> PolarsResult<(Vec<PlSmallStr>, Vec<usize>)> {
     let len = header_line.len();
 
     if header_line.last().copied() == Some(b'\r') {
@@ -70,7 +116,7 @@ fn infer_headers(
         parse_options.eol_char,
     );
 
-    let headers = byterecord
+    let mut headers = byterecord
         .map(|(slice, needs_escaping)| {
             let slice_escaped = if needs_escaping && (slice.len() >= 2) {
                 &slice[1..(slice.len() - 1)]
@@ -82,20 +128,84 @@ fn infer_headers(
         })
         .collect::<PolarsResult<Vec<_>>>()?;
 
-    let mut deduplicated_headers = Vec::with_capacity(headers.len());
-    let mut header_names = PlHashMap::with_capacity(headers.len());
+    // Normalize before deduplicating, such that names that only differ in their representation
+    // are duplicates.
+    if normalize_column_names {
+        for name in headers.iter_mut() {
+            *name = Cow::Owned(name.nfc().collect());
+        }
+    }
 
-    for name in &headers {
-        let count = header_names.entry(name.as_ref()).or_insert(0usize);
-        if *count != 0 {
-            deduplicated_headers.push(format_pl_smallstr!("{}_duplicated_{}", name, *count - 1))
-        } else {
-            deduplicated_headers.push(PlSmallStr::from_str(name))
+    // Empty names are only allowed if they can be replaced.
+    if column_name_policy != ColumnNamePolicy::Raise {
+        for (i, name) in headers.iter_mut().enumerate() {
+            if name.is_empty() {
+                *name = Cow::Owned(column_name(i).into_string());
+            }
         }
-        *count += 1;
     }
 
-    Ok(deduplicated_headers)
+    match column_name_policy {
+        ColumnNamePolicy::Raise => {
+            let mut header_names = PlHashSet::with_capacity(headers.len());
+            for (i, name) in headers.iter().enumerate() {
+                polars_ensure!(
+                    !name.is_empty(),
+                    ComputeError: "column {} of the CSV header has an empty name", i + 1
+                );
+                polars_ensure!(header_names.insert(name.as_ref()), duplicate = name);
+            }
+            let headers = headers.iter().map(|name| PlSmallStr::from_str(name)).collect();
+            Ok((headers, Vec::new()))
+        },
+        ColumnNamePolicy::Suffix => {
+            let mut deduplicated_headers = Vec::with_capacity(headers.len());
+            let mut header_names = PlHashMap::with_capacity(headers.len());
+
+            for name in &headers {
+                let count = header_names.entry(name.as_ref()).or_insert(0usize);
+                if *count != 0 {
+                    deduplicated_headers
+                        .push(format_pl_smallstr!("{}_duplicated_{}", name, *count - 1))
+                } else {
+                    deduplicated_headers.push(PlSmallStr::from_str(name))
+                }
+                *count += 1;
+            }
+
+            Ok((deduplicated_headers, Vec::new()))
+        },
+        ColumnNamePolicy::TakeLast => {
+            let last_index = headers
+                .iter()
+                .enumerate()
+                .map(|(i, name)| (name.as_ref(), i))
+                .collect::<PlHashMap<_, _>>();
+            let dropped_columns = (0..headers.len())
+                .filter(|&i| last_index[headers[i].as_ref()] != i)
+                .collect::<Vec<_>>();
+
+            // The columns that aren't read keep their position under a name that doesn't clash,
+            // as the columns are parsed by position.
+            let mut names = last_index
+                .keys()
+                .map(|name| PlSmallStr::from_str(name))
+                .collect::<PlHashSet<_>>();
+            let mut deduplicated_headers = headers
+                .iter()
+                .map(|name| PlSmallStr::from_str(name))
+                .collect::<Vec<_>>();
+            for &i in &dropped_columns {
+                let mut name = format_pl_smallstr!("{}_{}", headers[i], i);
+                while !names.insert(name.clone()) {
+                    name = format_pl_smallstr!("_{}", name);
+                }
+                deduplicated_headers[i] = name;
+            }
+
+            Ok((deduplicated_headers, dropped_columns))
+        },
+    }
 }
 
 fn infer_types_from_line(
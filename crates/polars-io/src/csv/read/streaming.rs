@@ -2,12 +2,11 @@ use std::cmp;
 use std::iter::Iterator;
 use std::sync::Arc;
 
-use polars_core::prelude::Schema;
 use polars_core::schema::SchemaRef;
 use polars_error::{PolarsResult, polars_bail, polars_ensure};
 use polars_utils::mmap::MemSlice;
 
-use crate::csv::read::schema_inference::infer_file_schema_impl;
+use crate::csv::read::schema_inference::{InferredSchema, infer_file_schema_impl};
 use crate::prelude::_csv_read_internal::{SplitLines, is_comment_line};
 use crate::prelude::{CsvParseOptions, CsvReadOptions};
 use crate::utils::compression::CompressedReader;
@@ -32,7 +31,7 @@ pub fn read_until_start_and_infer_schema(
     projected_schema: Option<SchemaRef>,
     mut inspect_first_content_row_fn: Option<InspectContentFn<'_>>,
     reader: &mut CompressedReader,
-) -> PolarsResult<(Schema, MemSlice)> {
+) -> PolarsResult<(InferredSchema, MemSlice)> {
     #[derive(Copy, Clone)]
     enum State {
         // Ordered so that all states only happen after the ones before it.
@@ -333,7 +332,7 @@ fn infer_schema(
     infer_all_as_str: bool,
     options: &CsvReadOptions,
     projected_schema: Option<SchemaRef>,
-) -> PolarsResult<Schema> {
+) -> PolarsResult<InferredSchema> {
     let has_no_inference_data = if options.has_header {
         header_line.is_none()
     } else {
@@ -344,14 +343,19 @@ fn infer_schema(
         polars_bail!(NoData: "empty CSV");
     }
 
-    let mut inferred_schema = if has_no_inference_data {
-        Schema::default()
+    let InferredSchema {
+        schema: mut inferred_schema,
+        mut dropped_columns,
+    } = if has_no_inference_data {
+        InferredSchema::default()
     } else {
         infer_file_schema_impl(
             header_line,
             content_lines,
             infer_all_as_str,
             &options.parse_options,
+            options.column_name_policy,
+            options.normalize_column_names,
             options.schema_overwrite.as_deref(),
         )?
     };
@@ -369,6 +373,9 @@ fn infer_schema(
             );
         }
 
+        // The provided schema names the columns by position, so all columns are read.
+        dropped_columns.clear();
+
         if options.parse_options.truncate_ragged_lines {
             inferred_schema = Arc::unwrap_or_clone(schema.clone());
         } else {
@@ -396,5 +403,8 @@ fn infer_schema(
         }
     }
 
-    Ok(inferred_schema)
+    Ok(InferredSchema {
+        schema: inferred_schema,
+        dropped_columns,
+    })
 }
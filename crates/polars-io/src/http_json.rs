@@ -0,0 +1,338 @@
+//! Reading the records of a paginated JSON API over HTTP(S) as a stream of [`DataFrame`]s.
+//!
+//! Every page is requested when the previous batch has been consumed, so a query only
+//! requests the pages it needs, e.g. when it is sliced.
+use std::io::Cursor;
+use std::num::NonZeroUsize;
+
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use reqwest::Url;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, LINK};
+use serde_json::Value;
+
+use crate::cloud::USER_AGENT;
+use crate::pl_async;
+use crate::prelude::*;
+
+/// How the pages after the first page of an API are requested.
+#[derive(Clone, Debug, Default)]
+pub enum HttpPagination {
+    /// The API responds with a single page.
+    #[default]
+    None,
+    /// The URL of the next page is in the response, at a JSON pointer such as `/links/next`.
+    /// The read ends at a response without that URL.
+    NextUrl { pointer: String },
+    /// The URL of the next page is in the `Link` header with `rel="next"`. The read ends at a
+    /// response without such a link.
+    LinkHeader,
+    /// The pages are numbered by a query parameter, starting at `start`. The read ends at the
+    /// first empty page.
+    PageNumber { param: String, start: u64 },
+    /// The records are selected by an offset and a limit query parameter. The read ends at the
+    /// first page with fewer than `limit` records.
+    Offset {
+        offset_param: String,
+        limit_param: String,
+        limit: u64,
+    },
+}
+
+#[derive(Clone, Debug)]
+pub struct HttpJsonScanOptions {
+    /// The URL of the first page.
+    pub url: String,
+    pub pagination: HttpPagination,
+    /// JSON pointer to the array of records in a response, e.g. `/data`. By default, the
+    /// response is the array of records.
+    pub records_pointer: Option<String>,
+    /// Headers to send with every request, e.g. for authentication.
+    pub headers: Vec<(String, String)>,
+    /// The schema of the records. By default, it is inferred from the first page.
+    pub schema: Option<SchemaRef>,
+    pub infer_schema_length: Option<NonZeroUsize>,
+    /// The maximum number of pages to request.
+    pub max_pages: Option<usize>,
+}
+
+impl HttpJsonScanOptions {
+    pub fn new(url: &str, pagination: HttpPagination) -> Self {
+        Self {
+            url: url.to_string(),
+            pagination,
+            records_pointer: None,
+            headers: Vec::new(),
+            schema: None,
+            infer_schema_length: NonZeroUsize::new(100),
+            max_pages: None,
+        }
+    }
+}
+
+/// Requests the pages of a JSON API, producing the records of every page as a batch.
+pub struct HttpJsonSource {
+    client: reqwest::Client,
+    base_url: String,
+    pagination: HttpPagination,
+    records_pointer: Option<String>,
+    schema: SchemaRef,
+    /// The URL of the next page, `None` once the last page has been read.
+    next_url: Option<String>,
+    /// The number of pages read for [`HttpPagination::PageNumber`], or the number of records
+    /// read for [`HttpPagination::Offset`].
+    position: u64,
+    pages_left: Option<usize>,
+    /// The first page, which is requested up front if the schema is inferred.
+    first_page: Option<DataFrame>,
+}
+
+impl HttpJsonSource {
+    pub fn try_new(options: HttpJsonScanOptions) -> PolarsResult<Self> {
+        let mut headers = HeaderMap::with_capacity(options.headers.len());
+        for (name, value) in &options.headers {
+            headers.insert(
+                HeaderName::from_bytes(name.as_bytes()).map_err(to_compute_err)?,
+                HeaderValue::from_str(value).map_err(to_compute_err)?,
+            );
+        }
+        let client = reqwest::Client::builder()
+            .user_agent(USER_AGENT)
+            .default_headers(headers)
+            .build()
+            .map_err(to_compute_err)?;
+
+        let mut source = Self {
+            client,
+            next_url: Some(page_url(&options.url, &options.pagination, 0)?),
+            base_url: options.url,
+            pagination: options.pagination,
+            records_pointer: options.records_pointer,
+            schema: options.schema.clone().unwrap_or_default(),
+            position: 0,
+            pages_left: options.max_pages,
+            first_page: None,
+        };
+
+        if options.schema.is_none() {
+            let records = source.next_page()?.unwrap_or_default();
+            polars_ensure!(
+                !records.is_empty(),
+                ComputeError: "cannot infer the schema from the empty first page of {}",
+                source.base_url
+            );
+            let df = parse_records(&records, None, options.infer_schema_length)?;
+            source.schema = df.schema().clone();
+            source.first_page = Some(df);
+        }
+        Ok(source)
+    }
+
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Get the records of the next page, or `None` once all pages have been read.
+    pub fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        if let Some(df) = self.first_page.take() {
+            return Ok(Some(df));
+        }
+        let Some(records) = self.next_page()? else {
+            return Ok(None);
+        };
+        parse_records(&records, Some(&self.schema), None).map(Some)
+    }
+
+    fn next_page(&mut self) -> PolarsResult<Option<Vec<Value>>> {
+        if self.pages_left == Some(0) {
+            return Ok(None);
+        }
+        let Some(url) = self.next_url.take() else {
+            return Ok(None);
+        };
+        if let Some(pages_left) = &mut self.pages_left {
+            *pages_left -= 1;
+        }
+
+        let (mut response, headers) = self.request(&url)?;
+        let records = match &self.records_pointer {
+            Some(pointer) => match response.pointer_mut(pointer).map(Value::take) {
+                Some(Value::Array(records)) => records,
+                Some(Value::Null) => Vec::new(),
+                _ => polars_bail!(
+                    ComputeError: "the response of {} has no array of records at `{}`", url, pointer
+                ),
+            },
+            None => match response.take() {
+                Value::Array(records) => records,
+                _ => polars_bail!(
+                    ComputeError: "the response of {} is not an array of records", url
+                ),
+            },
+        };
+
+        self.next_url = match &self.pagination {
+            HttpPagination::None => None,
+            HttpPagination::NextUrl { pointer } => match response.pointer(pointer) {
+                Some(Value::String(next)) => Some(join_url(&url, next)?),
+                _ => None,
+            },
+            HttpPagination::LinkHeader => next_link(&headers)
+                .map(|next| join_url(&url, next))
+                .transpose()?,
+            HttpPagination::PageNumber { .. } => {
+                self.position += 1;
+                (!records.is_empty())
+                    .then(|| page_url(&self.base_url, &self.pagination, self.position))
+                    .transpose()?
+            },
+            HttpPagination::Offset { limit, .. } => {
+                self.position += records.len() as u64;
+                (records.len() as u64 >= *limit)
+                    .then(|| page_url(&self.base_url, &self.pagination, self.position))
+                    .transpose()?
+            },
+        };
+        Ok(Some(records))
+    }
+
+    fn request(&self, url: &str) -> PolarsResult<(Value, HeaderMap)> {
+        pl_async::get_runtime().block_in_place_on(async {
+            let response = self
+                .client
+                .get(url)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status())
+                .map_err(to_compute_err)?;
+            let headers = response.headers().clone();
+            let bytes = response.bytes().await.map_err(to_compute_err)?;
+            let value = serde_json::from_slice(&bytes).map_err(to_compute_err)?;
+            Ok((value, headers))
+        })
+    }
+}
+
+/// Get the URL of the page at `position`, for the paginations that select the page with query
+/// parameters.
+fn page_url(base_url: &str, pagination: &HttpPagination, position: u64) -> PolarsResult<String> {
+    let params = match pagination {
+        HttpPagination::PageNumber { param, start } => {
+            vec![(param, (start + position).to_string())]
+        },
+        HttpPagination::Offset {
+            offset_param,
+            limit_param,
+            limit,
+        } => vec![
+            (offset_param, position.to_string()),
+            (limit_param, limit.to_string()),
+        ],
+        _ => return Ok(base_url.to_string()),
+    };
+
+    let mut url = Url::parse(base_url).map_err(to_compute_err)?;
+    let other_params = url
+        .query_pairs()
+        .filter(|(name, _)| params.iter().all(|(param, _)| param.as_str() != *name))
+        .map(|(name, value)| (name.into_owned(), value.into_owned()))
+        .collect::<Vec<_>>();
+    url.query_pairs_mut()
+        .clear()
+        .extend_pairs(other_params)
+        .extend_pairs(params);
+    Ok(url.into())
+}
+
+/// Resolve a link to the next page, which may be relative to the URL of the current page.
+fn join_url(url: &str, next: &str) -> PolarsResult<String> {
+    let url = Url::parse(url).and_then(|url| url.join(next));
+    Ok(url.map_err(to_compute_err)?.into())
+}
+
+/// Get the target of the link with `rel="next"` in the `Link` headers.
+fn next_link(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get_all(LINK)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .find_map(|link| {
+            let (target, params) = link.split_once(';')?;
+            let target = target.trim().strip_prefix('<')?.strip_suffix('>')?;
+            params
+                .split(';')
+                .filter_map(|param| param.trim().strip_prefix("rel="))
+                .any(|rel| rel.trim_matches('"').split_whitespace().any(|rel| rel == "next"))
+                .then_some(target)
+        })
+}
+
+fn parse_records(
+    records: &[Value],
+    schema: Option<&SchemaRef>,
+    infer_schema_length: Option<NonZeroUsize>,
+) -> PolarsResult<DataFrame> {
+    if let Some(schema) = schema
+        && records.is_empty()
+    {
+        return Ok(DataFrame::empty_with_arc_schema(schema.clone()));
+    }
+
+    let mut buf = Vec::new();
+    for record in records {
+        serde_json::to_writer(&mut buf, record).map_err(to_compute_err)?;
+        buf.push(b'\n');
+    }
+    let reader = JsonLineReader::new(Cursor::new(buf));
+    match schema {
+        Some(schema) => reader.with_schema(schema.clone()),
+        None => reader.infer_schema_len(infer_schema_length),
+    }
+    .finish()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pagination_urls() -> PolarsResult<()> {
+        let pagination = HttpPagination::PageNumber {
+            param: "page".into(),
+            start: 1,
+        };
+        assert_eq!(
+            page_url("https://api.test/items?page=7&q=a", &pagination, 2)?,
+            "https://api.test/items?q=a&page=3"
+        );
+
+        let pagination = HttpPagination::Offset {
+            offset_param: "offset".into(),
+            limit_param: "limit".into(),
+            limit: 50,
+        };
+        assert_eq!(
+            page_url("https://api.test/items", &pagination, 100)?,
+            "https://api.test/items?offset=100&limit=50"
+        );
+
+        assert_eq!(
+            join_url("https://api.test/items?page=1", "/items?cursor=x")?,
+            "https://api.test/items?cursor=x"
+        );
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            LINK,
+            HeaderValue::from_static(concat!(
+                r#"<https://api.test/items?page=1>; rel="prev", "#,
+                r#"<https://api.test/items?page=3>; rel="next""#,
+            )),
+        );
+        assert_eq!(next_link(&headers), Some("https://api.test/items?page=3"));
+        headers.insert(LINK, HeaderValue::from_static(r#"<https://api.test/items>; rel="first""#));
+        assert_eq!(next_link(&headers), None);
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+//! LZ4-compressed in-memory storage of a single [`Column`].
+//!
+//! The column is stored as an Arrow IPC file in memory with one record batch per chunk, so that
+//! decompressing it restores the original chunking.
+use std::io::Cursor;
+
+use polars_core::prelude::*;
+
+use super::{IpcCompression, IpcReader, IpcWriter};
+use crate::prelude::*;
+
+/// A [`Column`] compressed in memory with LZ4.
+#[derive(Clone)]
+pub struct CompressedColumn {
+    name: PlSmallStr,
+    dtype: DataType,
+    len: usize,
+    bytes: Arc<[u8]>,
+}
+
+impl CompressedColumn {
+    pub fn compress(column: &Column) -> PolarsResult<Self> {
+        let mut df = column.clone().into_frame();
+        let mut bytes = Vec::new();
+        IpcWriter::new(&mut bytes)
+            .with_compression(Some(IpcCompression::LZ4))
+            .with_compat_level(CompatLevel::newest())
+            .finish(&mut df)?;
+
+        Ok(Self {
+            name: column.name().clone(),
+            dtype: column.dtype().clone(),
+            len: column.len(),
+            bytes: bytes.into(),
+        })
+    }
+
+    pub fn decompress(&self) -> PolarsResult<Column> {
+        let df = IpcReader::new(Cursor::new(&*self.bytes))
+            .set_rechunk(false)
+            .finish()?;
+        let column = df.into_columns().pop().unwrap();
+        debug_assert_eq!(column.dtype(), &self.dtype);
+        Ok(column)
+    }
+
+    pub fn name(&self) -> &PlSmallStr {
+        &self.name
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The size of the compressed column in bytes.
+    pub fn compressed_size(&self) -> usize {
+        self.bytes.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_compressed_column_roundtrip() -> PolarsResult<()> {
+        let mut s = Series::new("a".into(), (0..1000).map(|i| i % 7).collect::<Vec<i64>>());
+        s.append(&Series::new("a".into(), [None, Some(1i64)]))?;
+        let column = Column::from(s);
+
+        let compressed = CompressedColumn::compress(&column)?;
+        assert!(compressed.compressed_size() < column.as_materialized_series().estimated_size());
+
+        let out = compressed.decompress()?;
+        assert_eq!(out.n_chunks(), 2);
+        assert!(out.equals_missing(&column));
+        Ok(())
+    }
+}
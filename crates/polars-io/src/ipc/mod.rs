@@ -1,4 +1,6 @@
 #[cfg(feature = "ipc")]
+mod compressed;
+#[cfg(feature = "ipc")]
 mod ipc_file;
 #[cfg(feature = "cloud")]
 mod ipc_reader_async;
@@ -6,9 +8,15 @@ mod ipc_reader_async;
 mod ipc_stream;
 #[cfg(feature = "ipc")]
 mod mmap;
+#[cfg(feature = "ipc")]
+mod shared_memory;
 mod write;
 #[cfg(feature = "ipc")]
+pub use compressed::CompressedColumn;
+#[cfg(feature = "ipc")]
 pub use ipc_file::{IpcReader, IpcScanOptions};
+#[cfg(feature = "ipc")]
+pub use shared_memory::{SharedMemoryFrame, shared_memory_path};
 #[cfg(feature = "cloud")]
 pub use ipc_reader_async::*;
 #[cfg(feature = "ipc_streaming")]
@@ -0,0 +1,115 @@
+//! Share a [`DataFrame`] between processes through a named shared-memory segment.
+//!
+//! The segment is an uncompressed Arrow IPC file in the shared-memory directory (`/dev/shm` when
+//! available, the temporary directory otherwise). Readers memory-map the segment, so opening it
+//! does not copy or deserialize the data.
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use polars_core::prelude::*;
+
+use super::{IpcReader, IpcWriter};
+use crate::prelude::*;
+
+const SEGMENT_EXTENSION: &str = "arrow";
+
+/// Makes the temporary files of concurrent publishers in this process unique.
+static TMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+fn shared_memory_dir() -> PathBuf {
+    let shm = Path::new("/dev/shm");
+    if shm.is_dir() {
+        shm.to_path_buf()
+    } else {
+        std::env::temp_dir()
+    }
+}
+
+/// Get the path of the segment `name`.
+pub fn shared_memory_path(name: &str) -> PolarsResult<PathBuf> {
+    polars_ensure!(
+        !name.is_empty() && !name.starts_with('.') && !name.contains(['/', '\\']),
+        InvalidOperation: "invalid shared memory segment name '{}'", name
+    );
+    Ok(shared_memory_dir().join(format!("polars-{name}.{SEGMENT_EXTENSION}")))
+}
+
+fn write_segment(
+    name: &str,
+    tmp_path: &Path,
+    path: &Path,
+    df: &mut DataFrame,
+) -> PolarsResult<()> {
+    let file = File::create_new(tmp_path)?;
+    IpcWriter::new(file)
+        .with_compression(None)
+        .with_compat_level(CompatLevel::newest())
+        .finish(df)?;
+    // Linking fails if the segment exists, so readers never see a partially written segment and
+    // we never replace the segment of another publisher.
+    std::fs::hard_link(tmp_path, path).map_err(|err| match err.kind() {
+        std::io::ErrorKind::AlreadyExists => {
+            polars_err!(Duplicate: "shared memory segment '{}' already exists", name)
+        },
+        _ => err.into(),
+    })
+}
+
+/// A [`DataFrame`] published into a named shared-memory segment.
+///
+/// The segment is removed when this handle is dropped. Processes that have already opened the
+/// segment can keep using their `DataFrame`.
+#[derive(Debug)]
+pub struct SharedMemoryFrame {
+    name: PlSmallStr,
+    path: PathBuf,
+}
+
+impl SharedMemoryFrame {
+    /// Publish `df` under `name`. Fails if a segment with that name already exists.
+    pub fn publish(name: &str, df: &mut DataFrame) -> PolarsResult<Self> {
+        let path = shared_memory_path(name)?;
+        let pid = std::process::id();
+        let counter = TMP_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let tmp_path = path.with_extension(format!("{SEGMENT_EXTENSION}.{pid}.{counter}"));
+
+        let result = write_segment(name, &tmp_path, &path, df);
+        let _ = std::fs::remove_file(&tmp_path);
+        result?;
+
+        Ok(Self {
+            name: name.into(),
+            path,
+        })
+    }
+
+    /// Open the segment `name` published by another process without copying the data.
+    pub fn open(name: &str) -> PolarsResult<DataFrame> {
+        let path = shared_memory_path(name)?;
+        let file = File::open(&path).map_err(|err| match err.kind() {
+            std::io::ErrorKind::NotFound => {
+                polars_err!(ComputeError: "shared memory segment '{}' does not exist", name)
+            },
+            _ => err.into(),
+        })?;
+        IpcReader::new(file)
+            .set_rechunk(false)
+            .memory_mapped(Some(path))
+            .finish()
+    }
+
+    pub fn name(&self) -> &PlSmallStr {
+        &self.name
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for SharedMemoryFrame {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
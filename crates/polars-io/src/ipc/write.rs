@@ -1,7 +1,7 @@
 use std::io::Write;
 
 use arrow::datatypes::Metadata;
-use arrow::io::ipc::IpcField;
+use arrow::io::ipc::{IpcField, ZstdDictionary};
 use arrow::io::ipc::write::{self, EncodedData, WriteOptions};
 use polars_core::prelude::*;
 #[cfg(feature = "serde")]
@@ -22,6 +22,9 @@ pub struct IpcWriterOptions {
     pub record_batch_size: Option<usize>,
     /// Size of each written chunk.
     pub chunk_size: IdxSize,
+    /// Maximum size in bytes of a zstd dictionary to train on the first record batch, see
+    /// [`IpcWriter::with_zstd_dictionary_size`].
+    pub zstd_dictionary_size: Option<usize>,
 }
 
 impl Default for IpcWriterOptions {
@@ -31,6 +34,7 @@ impl Default for IpcWriterOptions {
             compat_level: CompatLevel::newest(),
             record_batch_size: None,
             chunk_size: 1 << 18,
+            zstd_dictionary_size: None,
         }
     }
 }
@@ -40,6 +44,7 @@ impl IpcWriterOptions {
         IpcWriter::new(writer)
             .with_compression(self.compression)
             .with_record_batch_size(self.record_batch_size)
+            .with_zstd_dictionary_size(self.zstd_dictionary_size)
     }
 }
 
@@ -78,6 +83,7 @@ pub struct IpcWriter<W> {
     pub(super) record_batch_size: Option<usize>,
     pub(super) parallel: bool,
     pub(super) custom_schema_metadata: Option<Arc<Metadata>>,
+    pub(super) zstd_dictionary_size: Option<usize>,
 }
 
 impl<W: Write> IpcWriter<W> {
@@ -102,6 +108,16 @@ impl<W: Write> IpcWriter<W> {
         self
     }
 
+    /// Compress with a zstd dictionary of at most this many bytes, trained on the first record
+    /// batch, which helps with many short strings. Only applies to [`SerWriter::finish`] with
+    /// [`IpcCompression::ZSTD`]; see [`BatchedWriter::set_zstd_dictionary`] for batched writes.
+    ///
+    /// Such files can only be read by Polars, as the dictionary is stored in the schema metadata.
+    pub fn with_zstd_dictionary_size(mut self, zstd_dictionary_size: Option<usize>) -> Self {
+        self.zstd_dictionary_size = zstd_dictionary_size;
+        self
+    }
+
     pub fn batched(
         self,
         schema: &Schema,
@@ -114,6 +130,7 @@ impl<W: Write> IpcWriter<W> {
             Some(ipc_fields),
             WriteOptions {
                 compression: self.compression.map(|c| c.into()),
+                zstd_dictionary: None,
             },
         );
         writer.start()?;
@@ -142,30 +159,39 @@ where
             record_batch_size: None,
             parallel: true,
             custom_schema_metadata: None,
+            zstd_dictionary_size: None,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
         let schema = schema_to_arrow_checked(df.schema(), self.compat_level, "ipc")?;
+        if self.parallel {
+            df.align_chunks_par();
+        } else {
+            df.align_chunks();
+        }
+        let mut iter = df.iter_chunks(self.compat_level, true).peekable();
+
+        let zstd_dictionary = match (self.compression, self.zstd_dictionary_size) {
+            (Some(IpcCompression::ZSTD(_)), Some(max_size)) => iter
+                .peek()
+                // Fall back to plain zstd if there is too little data to train on.
+                .and_then(|batch| write::train_zstd_dictionary(batch.arrays(), max_size).ok()),
+            _ => None,
+        };
         let mut ipc_writer = write::FileWriter::try_new(
             &mut self.writer,
             Arc::new(schema),
             None,
             WriteOptions {
                 compression: self.compression.map(|c| c.into()),
+                zstd_dictionary,
             },
         )?;
         if let Some(custom_metadata) = &self.custom_schema_metadata {
             ipc_writer.set_custom_schema_metadata(Arc::clone(custom_metadata));
         }
 
-        if self.parallel {
-            df.align_chunks_par();
-        } else {
-            df.align_chunks();
-        }
-        let iter = df.iter_chunks(self.compat_level, true);
-
         for batch in iter {
             ipc_writer.write(&batch, None)?
         }
@@ -211,6 +237,12 @@ impl<W: Write> BatchedWriter<W> {
         self.writer.write_encoded_dictionaries(encoded_dictionaries)
     }
 
+    /// Set the dictionary to compress the next batches with, e.g. one trained with
+    /// [`write::train_zstd_dictionary`]. Must be called before `finish`.
+    pub fn set_zstd_dictionary(&mut self, zstd_dictionary: Option<ZstdDictionary>) {
+        self.writer.set_zstd_dictionary(zstd_dictionary);
+    }
+
     /// Writes the footer of the IPC file.
     pub fn finish(&mut self) -> PolarsResult<()> {
         self.writer.finish()?;
@@ -0,0 +1,208 @@
+//! Deserialization of the payloads of Kafka messages.
+use std::io::Cursor;
+
+use arrow::datatypes::ArrowSchema;
+use arrow::io::avro::avro_schema::file::Block;
+use arrow::io::avro::avro_schema::schema::{Field as AvroField, Record, Schema as AvroSchema};
+use arrow::io::avro::read;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_error::to_compute_err;
+
+use super::KafkaFormat;
+use crate::pl_async;
+use crate::prelude::*;
+
+/// Magic byte in front of the schema id of messages in the Confluent wire format.
+const AVRO_MAGIC_BYTE: u8 = 0;
+
+pub(super) enum Decoder {
+    Json,
+    Avro(AvroDecoder),
+}
+
+impl Decoder {
+    pub(super) fn new(format: &KafkaFormat) -> Self {
+        match format {
+            KafkaFormat::Json => Self::Json,
+            KafkaFormat::Avro { schema_registry_url } => Self::Avro(AvroDecoder {
+                registry_url: schema_registry_url.trim_end_matches('/').to_string(),
+                writer_schemas: Default::default(),
+            }),
+        }
+    }
+
+    /// Deserialize the payloads into a [`DataFrame`] with the given `schema`.
+    pub(super) fn decode(
+        &mut self,
+        payloads: &[Vec<u8>],
+        schema: &SchemaRef,
+    ) -> PolarsResult<DataFrame> {
+        if payloads.is_empty() {
+            return Ok(DataFrame::empty_with_arc_schema(schema.clone()));
+        }
+        match self {
+            Self::Json => {
+                let mut buf = Vec::with_capacity(payloads.iter().map(|p| p.len() + 1).sum());
+                for payload in payloads {
+                    buf.extend_from_slice(payload);
+                    buf.push(b'\n');
+                }
+                JsonLineReader::new(Cursor::new(buf))
+                    .with_schema(schema.clone())
+                    .finish()
+            },
+            Self::Avro(decoder) => decoder.decode(payloads, schema),
+        }
+    }
+}
+
+/// Decodes Avro messages in the Confluent wire format, of which the writer schemas are
+/// fetched from a schema registry.
+pub(super) struct AvroDecoder {
+    registry_url: String,
+    writer_schemas: PlHashMap<u32, (ArrowSchema, Vec<AvroField>)>,
+}
+
+impl AvroDecoder {
+    fn decode(&mut self, payloads: &[Vec<u8>], schema: &SchemaRef) -> PolarsResult<DataFrame> {
+        let mut dfs = Vec::new();
+        let mut start = 0;
+        // Messages with the same writer schema are decoded together.
+        while start < payloads.len() {
+            let schema_id = wire_schema_id(&payloads[start])?;
+            let mut end = start + 1;
+            while end < payloads.len() && wire_schema_id(&payloads[end])? == schema_id {
+                end += 1;
+            }
+
+            let data = payloads[start..end]
+                .iter()
+                .flat_map(|payload| payload[5..].iter().copied())
+                .collect::<Vec<_>>();
+            let block = Block::new(end - start, data);
+            let (arrow_schema, avro_fields) = self.writer_schema(schema_id)?;
+            let projection = vec![true; avro_fields.len()];
+            let batch = read::deserialize(&block, arrow_schema, avro_fields, &projection)?;
+            dfs.push(conform_to_schema(DataFrame::from(batch), schema)?);
+            start = end;
+        }
+        Ok(accumulate_dataframes_vertical_unchecked(dfs))
+    }
+
+    fn writer_schema(&mut self, schema_id: u32) -> PolarsResult<&(ArrowSchema, Vec<AvroField>)> {
+        if !self.writer_schemas.contains_key(&schema_id) {
+            let url = format!("{}/schemas/ids/{schema_id}", self.registry_url);
+            let record = fetch_registry_schema(&url)?;
+            let arrow_schema = read::infer_schema(&record)?;
+            self.writer_schemas.insert(schema_id, (arrow_schema, record.fields));
+        }
+        Ok(&self.writer_schemas[&schema_id])
+    }
+}
+
+fn wire_schema_id(payload: &[u8]) -> PolarsResult<u32> {
+    polars_ensure!(
+        payload.len() >= 5 && payload[0] == AVRO_MAGIC_BYTE,
+        ComputeError: "Kafka message is not encoded in the Avro wire format of the schema registry"
+    );
+    Ok(u32::from_be_bytes(payload[1..5].try_into().unwrap()))
+}
+
+/// Get the schema of the latest version of the values of `topic` from the schema registry.
+pub(super) fn latest_value_schema(registry_url: &str, topic: &str) -> PolarsResult<Schema> {
+    let url = format!(
+        "{}/subjects/{topic}-value/versions/latest",
+        registry_url.trim_end_matches('/')
+    );
+    let record = fetch_registry_schema(&url)?;
+    let arrow_schema = read::infer_schema(&record)?;
+    Ok(Schema::from_arrow_schema(&arrow_schema))
+}
+
+fn fetch_registry_schema(url: &str) -> PolarsResult<Record> {
+    let bytes = pl_async::get_runtime().block_in_place_on(async {
+        let response = reqwest::get(url)
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(to_compute_err)?;
+        response.bytes().await.map_err(to_compute_err)
+    })?;
+
+    let value: serde_json::Value = serde_json::from_slice(&bytes).map_err(to_compute_err)?;
+    let Some(schema) = value.get("schema").and_then(|schema| schema.as_str()) else {
+        polars_bail!(ComputeError: "unexpected response of the schema registry for {}", url);
+    };
+    match serde_json::from_str(schema).map_err(to_compute_err)? {
+        AvroSchema::Record(record) => Ok(record),
+        _ => polars_bail!(ComputeError: "the Avro schema of {} is not a record", url),
+    }
+}
+
+/// Select the columns of `schema`, filling the columns that are missing from an older writer
+/// schema with nulls.
+fn conform_to_schema(df: DataFrame, schema: &Schema) -> PolarsResult<DataFrame> {
+    let height = df.height();
+    let columns = schema
+        .iter()
+        .map(|(name, dtype)| match df.column(name) {
+            Ok(column) => column.cast(dtype),
+            Err(_) => Ok(Column::full_null(name.clone(), height, dtype)),
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    DataFrame::new(height, columns)
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+
+    use super::*;
+
+    #[test]
+    fn test_decode_messages() -> PolarsResult<()> {
+        let schema = Arc::new(Schema::from_iter([
+            Field::new("a".into(), DataType::Int64),
+            Field::new("b".into(), DataType::String),
+            Field::new("c".into(), DataType::Float64),
+        ]));
+        let expected = df!(
+            "a" => [1i64, -2],
+            "b" => ["x", "yz"],
+            "c" => [None::<f64>, None],
+        )?;
+
+        let payloads = [br#"{"a": 1, "b": "x"}"#.to_vec(), br#"{"a": -2, "b": "yz"}"#.to_vec()];
+        let out = Decoder::new(&KafkaFormat::Json).decode(&payloads, &schema)?;
+        assert!(out.equals_missing(&expected));
+
+        // Writer schema without `c`, registered under id 7.
+        let record: Record = match serde_json::from_str(
+            r#"{"type": "record", "name": "r", "fields": [
+                {"name": "a", "type": "long"}, {"name": "b", "type": "string"}
+            ]}"#,
+        )
+        .map_err(to_compute_err)?
+        {
+            AvroSchema::Record(record) => record,
+            _ => unreachable!(),
+        };
+        let mut decoder = AvroDecoder {
+            registry_url: String::new(),
+            writer_schemas: Default::default(),
+        };
+        decoder
+            .writer_schemas
+            .insert(7, (read::infer_schema(&record)?, record.fields));
+
+        let payloads = [
+            vec![AVRO_MAGIC_BYTE, 0, 0, 0, 7, 0x02, 0x02, b'x'],
+            vec![AVRO_MAGIC_BYTE, 0, 0, 0, 7, 0x03, 0x04, b'y', b'z'],
+        ];
+        let out = decoder.decode(&payloads, &schema)?;
+        assert!(out.equals_missing(&expected));
+
+        assert!(decoder.decode(&[vec![1, 2]], &schema).is_err());
+        Ok(())
+    }
+}
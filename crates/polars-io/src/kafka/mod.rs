@@ -0,0 +1,398 @@
+//! Reading the messages of a Kafka topic as a stream of [`DataFrame`]s.
+//!
+//! The messages are consumed with a consumer group, of which the offsets are committed as the
+//! batches are produced. Bounded reads therefore continue where the previous read of the same
+//! group stopped, which allows running a query as a micro-batch job; unbounded reads keep
+//! producing batches as long as messages arrive.
+mod decode;
+
+use std::time::{Duration, Instant};
+
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use rdkafka::config::ClientConfig;
+use rdkafka::consumer::{BaseConsumer, CommitMode, Consumer};
+use rdkafka::message::Message;
+use rdkafka::{Offset, TopicPartitionList};
+
+use self::decode::Decoder;
+
+const METADATA_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The encoding of the values of the messages.
+#[derive(Clone, Debug)]
+pub enum KafkaFormat {
+    /// One JSON object per message.
+    Json,
+    /// Avro in the wire format of the Confluent schema registry: the writer schema of every
+    /// message is fetched from the registry by the id in front of the message.
+    Avro { schema_registry_url: String },
+}
+
+/// Which messages of the topic are read.
+#[derive(Clone, Debug)]
+pub enum KafkaReadMode {
+    /// Read up to the end of the partitions at the start of the scan.
+    BoundedByOffset,
+    /// Read up to the first message with a timestamp at or after `end`, in milliseconds since
+    /// the epoch.
+    BoundedByTime { end: i64 },
+    /// Keep reading the messages that arrive in the topic.
+    Unbounded,
+}
+
+/// Drop messages that arrive too late according to an event-time column.
+#[derive(Clone, Debug)]
+pub struct KafkaWatermark {
+    /// The `Datetime` column with the event time of the messages.
+    pub column: PlSmallStr,
+    /// How far the event time of a message may lag behind the latest event time seen so far.
+    pub allowed_lateness: Duration,
+}
+
+#[derive(Clone, Debug)]
+pub struct KafkaScanOptions {
+    /// Comma-separated list of the brokers to connect to.
+    pub brokers: String,
+    pub topic: String,
+    /// The consumer group of which the committed offsets are the start of the read.
+    pub group_id: String,
+    pub format: KafkaFormat,
+    /// The schema of the messages. Required for JSON; for Avro it defaults to the latest
+    /// schema of the values of the topic in the schema registry.
+    pub schema: Option<SchemaRef>,
+    pub read_mode: KafkaReadMode,
+    pub watermark: Option<KafkaWatermark>,
+    /// The maximum number of messages in a batch.
+    pub batch_size: usize,
+    /// How long to wait for more messages before producing a smaller batch.
+    pub batch_timeout: Duration,
+    /// Additional librdkafka configuration of the consumer, e.g. for authentication.
+    pub consumer_config: Vec<(String, String)>,
+}
+
+impl KafkaScanOptions {
+    pub fn new(brokers: &str, topic: &str, group_id: &str, format: KafkaFormat) -> Self {
+        Self {
+            brokers: brokers.to_string(),
+            topic: topic.to_string(),
+            group_id: group_id.to_string(),
+            format,
+            schema: None,
+            read_mode: KafkaReadMode::BoundedByOffset,
+            watermark: None,
+            batch_size: 10_000,
+            batch_timeout: Duration::from_millis(500),
+            consumer_config: Vec::new(),
+        }
+    }
+
+    /// The schema of the messages: the given schema, or for Avro the latest schema of the
+    /// values of the topic in the schema registry.
+    pub fn resolve_schema(&self) -> PolarsResult<SchemaRef> {
+        Ok(match (&self.schema, &self.format) {
+            (Some(schema), _) => schema.clone(),
+            (None, KafkaFormat::Avro { schema_registry_url }) => Arc::new(
+                decode::latest_value_schema(schema_registry_url, &self.topic)?,
+            ),
+            (None, KafkaFormat::Json) => {
+                polars_bail!(InvalidOperation: "reading JSON messages from Kafka requires a schema")
+            },
+        })
+    }
+
+    /// Whether the read keeps consuming the messages that arrive in the topic.
+    pub fn is_unbounded(&self) -> bool {
+        matches!(self.read_mode, KafkaReadMode::Unbounded)
+    }
+}
+
+struct EventTime {
+    column: PlSmallStr,
+    /// The allowed lateness in the time unit of the column.
+    lateness: i64,
+    watermark: Option<i64>,
+}
+
+/// Consumes a Kafka topic, producing the decoded messages in batches.
+pub struct KafkaSource {
+    consumer: BaseConsumer,
+    topic: String,
+    schema: SchemaRef,
+    decoder: Decoder,
+    batch_size: usize,
+    batch_timeout: Duration,
+    /// The offset at which the read of every partition that is not yet finished ends, for the
+    /// bounded read modes.
+    end_offsets: Option<PlHashMap<i32, i64>>,
+    event_time: Option<EventTime>,
+    committed: bool,
+}
+
+impl KafkaSource {
+    pub fn try_new(options: KafkaScanOptions) -> PolarsResult<Self> {
+        let schema = options.resolve_schema()?;
+
+        let event_time = match &options.watermark {
+            Some(watermark) => {
+                let lateness = watermark.allowed_lateness;
+                let lateness = match schema.try_get(&watermark.column)? {
+                    DataType::Datetime(TimeUnit::Nanoseconds, _) => lateness.as_nanos(),
+                    DataType::Datetime(TimeUnit::Microseconds, _) => lateness.as_micros(),
+                    DataType::Datetime(TimeUnit::Milliseconds, _) => lateness.as_millis(),
+                    dt => polars_bail!(
+                        InvalidOperation:
+                        "the event-time column of a watermark must be a Datetime, got {}", dt
+                    ),
+                };
+                Some(EventTime {
+                    column: watermark.column.clone(),
+                    lateness: lateness as i64,
+                    watermark: None,
+                })
+            },
+            None => None,
+        };
+
+        let mut config = ClientConfig::new();
+        config
+            .set("bootstrap.servers", &options.brokers)
+            .set("group.id", &options.group_id)
+            .set("enable.auto.offset.store", "false");
+        for (key, value) in &options.consumer_config {
+            config.set(key, value);
+        }
+        let consumer: BaseConsumer = config.create().map_err(to_compute_err)?;
+
+        let end_offsets = assign_partitions(&consumer, &options.topic, &options.read_mode)?;
+        Ok(Self {
+            consumer,
+            topic: options.topic,
+            schema,
+            decoder: Decoder::new(&options.format),
+            batch_size: options.batch_size,
+            batch_timeout: options.batch_timeout,
+            end_offsets,
+            event_time,
+            committed: false,
+        })
+    }
+
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Whether the source keeps reading new messages.
+    pub fn is_unbounded(&self) -> bool {
+        self.end_offsets.is_none()
+    }
+
+    /// The current watermark: the latest event time seen minus the allowed lateness.
+    pub fn watermark(&self) -> Option<i64> {
+        self.event_time.as_ref().and_then(|event_time| event_time.watermark)
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.end_offsets
+            .as_ref()
+            .is_some_and(|end_offsets| end_offsets.is_empty())
+    }
+
+    /// Read the next batch of messages, or `None` once a bounded read is finished.
+    ///
+    /// Waits for at least one message, and then for up to the batch timeout for more messages.
+    pub fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        let mut payloads = Vec::new();
+        let mut next_offsets = PlHashMap::new();
+        let mut n_messages = 0;
+        let deadline = Instant::now() + self.batch_timeout;
+
+        while n_messages < self.batch_size && !self.is_exhausted() {
+            if n_messages > 0 && Instant::now() >= deadline {
+                break;
+            }
+            let Some(message) = self.consumer.poll(self.batch_timeout) else {
+                self.finish_partitions_at_end()?;
+                continue;
+            };
+            let message = message.map_err(to_compute_err)?;
+            let (partition, offset) = (message.partition(), message.offset());
+
+            if let Some(end_offsets) = &self.end_offsets {
+                let Some(&end) = end_offsets.get(&partition) else {
+                    continue;
+                };
+                if offset + 1 >= end {
+                    self.finish_partition(partition)?;
+                }
+                if offset >= end {
+                    continue;
+                }
+            }
+            n_messages += 1;
+            next_offsets.insert(partition, offset + 1);
+            // Tombstones have no value.
+            if let Some(payload) = message.payload() {
+                payloads.push(payload.to_vec());
+            }
+        }
+
+        if n_messages == 0 {
+            self.commit()?;
+            return Ok(None);
+        }
+
+        let mut df = self.decoder.decode(&payloads, &self.schema)?;
+        if let Some(event_time) = &mut self.event_time {
+            df = event_time.drop_late(df)?;
+        }
+
+        // The messages are only marked as consumed once they are decoded.
+        let mut offsets = TopicPartitionList::new();
+        for (partition, offset) in next_offsets {
+            offsets
+                .add_partition_offset(&self.topic, partition, Offset::Offset(offset))
+                .map_err(to_compute_err)?;
+        }
+        self.consumer.store_offsets(&offsets).map_err(to_compute_err)?;
+        if self.is_exhausted() {
+            self.commit()?;
+        }
+        Ok(Some(df))
+    }
+
+    fn finish_partition(&mut self, partition: i32) -> PolarsResult<()> {
+        if let Some(end_offsets) = &mut self.end_offsets {
+            end_offsets.remove(&partition);
+        }
+        let mut tpl = TopicPartitionList::new();
+        tpl.add_partition(&self.topic, partition);
+        self.consumer.pause(&tpl).map_err(to_compute_err)
+    }
+
+    /// Finish the partitions of which the position reached the end offset without receiving
+    /// the message before it, which happens if the offsets have gaps, e.g. due to compaction or
+    /// transaction markers.
+    fn finish_partitions_at_end(&mut self) -> PolarsResult<()> {
+        let Some(end_offsets) = &self.end_offsets else {
+            return Ok(());
+        };
+        let position = self.consumer.position().map_err(to_compute_err)?;
+        let finished = position
+            .elements_for_topic(&self.topic)
+            .into_iter()
+            .filter_map(|elem| match (elem.offset(), end_offsets.get(&elem.partition())) {
+                (Offset::Offset(offset), Some(&end)) if offset >= end => Some(elem.partition()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        for partition in finished {
+            self.finish_partition(partition)?;
+        }
+        Ok(())
+    }
+
+    fn commit(&mut self) -> PolarsResult<()> {
+        if !self.committed {
+            self.consumer
+                .commit_consumer_state(CommitMode::Sync)
+                .map_err(to_compute_err)?;
+            self.committed = true;
+        }
+        Ok(())
+    }
+}
+
+impl EventTime {
+    /// Drop the rows of which the event time is before the watermark of the previous batches,
+    /// and advance the watermark. Rows without an event time are kept.
+    fn drop_late(&mut self, df: DataFrame) -> PolarsResult<DataFrame> {
+        let event_time = df.column(&self.column)?.datetime()?.physical();
+        let mask = self
+            .watermark
+            .map(|watermark| &event_time.gt_eq(watermark) | &event_time.is_null());
+        if let Some(latest) = event_time.max() {
+            let watermark = latest - self.lateness;
+            self.watermark = Some(self.watermark.map_or(watermark, |w| w.max(watermark)));
+        }
+        match mask {
+            Some(mask) => df.filter(&mask),
+            None => Ok(df),
+        }
+    }
+}
+
+/// Assign the partitions of `topic` to the consumer, starting at the committed offsets of the
+/// group or at the earliest offsets. Returns the end offsets of the partitions that have
+/// messages to read for the bounded read modes.
+fn assign_partitions(
+    consumer: &BaseConsumer,
+    topic: &str,
+    read_mode: &KafkaReadMode,
+) -> PolarsResult<Option<PlHashMap<i32, i64>>> {
+    let metadata = consumer
+        .fetch_metadata(Some(topic), METADATA_TIMEOUT)
+        .map_err(to_compute_err)?;
+    let Some(topic_metadata) = metadata.topics().iter().find(|t| t.name() == topic) else {
+        polars_bail!(ComputeError: "Kafka topic '{}' does not exist", topic);
+    };
+
+    let mut partitions = TopicPartitionList::new();
+    for partition in topic_metadata.partitions() {
+        partitions.add_partition(topic, partition.id());
+    }
+    let committed = consumer
+        .committed_offsets(partitions.clone(), METADATA_TIMEOUT)
+        .map_err(to_compute_err)?;
+    let end_times = match read_mode {
+        KafkaReadMode::BoundedByTime { end } => {
+            let mut times = partitions.clone();
+            times.set_all_offsets(Offset::Offset(*end)).map_err(to_compute_err)?;
+            Some(
+                consumer
+                    .offsets_for_times(times, METADATA_TIMEOUT)
+                    .map_err(to_compute_err)?,
+            )
+        },
+        _ => None,
+    };
+
+    let mut assignment = TopicPartitionList::new();
+    let mut end_offsets = PlHashMap::new();
+    for elem in committed.elements_for_topic(topic) {
+        let partition = elem.partition();
+        let (low, high) = consumer
+            .fetch_watermarks(topic, partition, METADATA_TIMEOUT)
+            .map_err(to_compute_err)?;
+        let start = match elem.offset() {
+            Offset::Offset(offset) => offset,
+            _ => low,
+        };
+        let end = match (read_mode, &end_times) {
+            (KafkaReadMode::Unbounded, _) => None,
+            (_, Some(end_times)) => match end_times.find_partition(topic, partition) {
+                Some(elem) => match elem.offset() {
+                    Offset::Offset(offset) => Some(offset),
+                    _ => Some(high),
+                },
+                None => Some(high),
+            },
+            _ => Some(high),
+        };
+        if let Some(end) = end {
+            if start >= end {
+                continue;
+            }
+            end_offsets.insert(partition, end);
+        }
+        assignment
+            .add_partition_offset(topic, partition, Offset::Offset(start))
+            .map_err(to_compute_err)?;
+    }
+    consumer.assign(&assignment).map_err(to_compute_err)?;
+
+    Ok(match read_mode {
+        KafkaReadMode::Unbounded => None,
+        _ => Some(end_offsets),
+    })
+}
@@ -16,10 +16,14 @@ pub mod cloud;
 pub mod csv;
 #[cfg(feature = "file_cache")]
 pub mod file_cache;
+#[cfg(feature = "http_json")]
+pub mod http_json;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub mod ipc;
 #[cfg(feature = "json")]
 pub mod json;
+#[cfg(feature = "kafka")]
+pub mod kafka;
 pub mod mmap;
 #[cfg(feature = "json")]
 pub mod ndjson;
@@ -36,6 +40,7 @@ pub mod prelude;
 #[cfg(feature = "scan_lines")]
 pub mod scan_lines;
 mod shared;
+pub mod upload;
 pub mod utils;
 
 #[cfg(feature = "cloud")]
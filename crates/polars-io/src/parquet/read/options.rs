@@ -7,6 +7,8 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 pub struct ParquetOptions {
     pub schema: Option<SchemaRef>,
+    /// Overrides the dtypes of a subset of the columns. The columns are cast while decoding.
+    pub schema_overwrite: Option<SchemaRef>,
     pub parallel: ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
@@ -16,6 +18,7 @@ impl Default for ParquetOptions {
     fn default() -> Self {
         Self {
             schema: None,
+            schema_overwrite: None,
             parallel: ParallelStrategy::default(),
             low_memory: false,
             use_statistics: true,
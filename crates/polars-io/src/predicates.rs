@@ -341,6 +341,51 @@ fn use_min_max(dtype: &DataType) -> bool {
         )
 }
 
+/// A value nested in a column for which file formats may keep statistics.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NestedStatisticsPath {
+    /// A field of a struct column, given as the path of field names from the column.
+    StructField {
+        column: PlSmallStr,
+        fields: Vec<PlSmallStr>,
+    },
+    /// The lengths of the lists in a list column.
+    ListLength { column: PlSmallStr },
+}
+
+impl NestedStatisticsPath {
+    /// The column that contains the nested value.
+    pub fn column(&self) -> &PlSmallStr {
+        match self {
+            Self::StructField { column, .. } | Self::ListLength { column } => column,
+        }
+    }
+
+    /// The name the skip batch predicate uses for the nested value, its statistics are named
+    /// `{name}_min`, `{name}_max` and `{name}_nc`.
+    pub fn name(&self) -> PlSmallStr {
+        match self {
+            Self::StructField { column, fields } => {
+                format_pl_smallstr!("{}.{}", column, fields.join("."))
+            },
+            Self::ListLength { column } => format_pl_smallstr!("{column}.len()"),
+        }
+    }
+}
+
+/// Get the constant columns that mark the statistics of `path` as unknown.
+fn unknown_nested_statistics(
+    path: &NestedStatisticsPath,
+    dtype: &DataType,
+) -> [(PlSmallStr, Scalar); 3] {
+    let name = path.name();
+    [
+        (format_pl_smallstr!("{name}_min"), Scalar::null(dtype.clone())),
+        (format_pl_smallstr!("{name}_max"), Scalar::null(dtype.clone())),
+        (format_pl_smallstr!("{name}_nc"), Scalar::null(IDX_DTYPE)),
+    ]
+}
+
 pub struct ColumnStatistics {
     pub dtype: DataType,
     pub min: AnyValue<'static>,
@@ -464,6 +509,9 @@ pub struct ScanIOPredicate {
     /// A predicate that gets given statistics and evaluates whether a batch can be skipped.
     pub skip_batch_predicate: Option<Arc<dyn SkipBatchPredicate>>,
 
+    /// Nested values the skip batch predicate needs statistics for, with their types.
+    pub nested_statistics: Arc<[(NestedStatisticsPath, DataType)]>,
+
     /// A predicate that gets given statistics and evaluates whether a batch can be skipped.
     pub column_predicates: Arc<ColumnPredicates>,
 
@@ -485,8 +533,20 @@ impl ScanIOPredicate {
         }
         self.live_columns = Arc::new(live_columns);
 
+        let (constant_nested_statistics, nested_statistics): (Vec<_>, Vec<_>) = self
+            .nested_statistics
+            .iter()
+            .cloned()
+            .partition(|(path, _)| constant_columns.iter().any(|(c, _)| c == path.column()));
+        self.nested_statistics = nested_statistics.into();
+
         if let Some(skip_batch_predicate) = self.skip_batch_predicate.take() {
             let mut sbp_constant_columns = Vec::with_capacity(constant_columns.len() * 3);
+            // The nested values of constant columns are not computed, so their statistics are
+            // unknown.
+            for (path, dtype) in constant_nested_statistics.iter() {
+                sbp_constant_columns.extend(unknown_nested_statistics(path, dtype));
+            }
             for (c, v) in constant_columns.iter() {
                 sbp_constant_columns.push((format_pl_smallstr!("{c}_min"), v.clone()));
                 sbp_constant_columns.push((format_pl_smallstr!("{c}_max"), v.clone()));
@@ -515,6 +575,19 @@ impl ScanIOPredicate {
             child: self.predicate.clone(),
         });
     }
+
+    /// Add unknown statistics for the nested values that are missing from `statistics_df`.
+    pub fn add_unknown_nested_statistics(&self, statistics_df: &mut DataFrame) -> PolarsResult<()> {
+        let height = statistics_df.height();
+        for (path, dtype) in self.nested_statistics.iter() {
+            for (name, scalar) in unknown_nested_statistics(path, dtype) {
+                if !statistics_df.schema().contains(&name) {
+                    statistics_df.with_column(Column::new_scalar(name, scalar, height))?;
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 impl fmt::Debug for ScanIOPredicate {
@@ -0,0 +1,231 @@
+//! Upload a [`DataFrame`] over several record batch streams in parallel.
+//!
+//! Bulk loading through a single Arrow Flight `DoPut` call or ADBC ingestion statement is
+//! usually bound by a single connection. The [`ParallelUploader`] slices the frame into record
+//! batches without copying and hands them to a number of streams, each driven by its own thread
+//! and connection.
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, sync_channel};
+use std::sync::{Arc, Mutex};
+
+use arrow::datatypes::ArrowSchemaRef;
+use arrow::record_batch::RecordBatch;
+use polars_core::prelude::*;
+
+use crate::shared::schema_to_arrow_checked;
+
+/// A single stream of record batches, e.g. one Flight `DoPut` call or ADBC ingestion statement.
+pub trait UploadStream: Send {
+    /// Send a record batch. This may block, which throttles the producer once all streams are
+    /// busy.
+    fn send(&mut self, batch: RecordBatch) -> PolarsResult<()>;
+
+    /// Called after the last batch was sent, e.g. to complete the call or commit the ingestion.
+    fn finish(&mut self) -> PolarsResult<()>;
+}
+
+/// The destination of an upload, which opens one [`UploadStream`] per connection.
+pub trait UploadTarget: Sync {
+    type Stream: UploadStream;
+
+    /// Open stream `index` for batches of the given schema.
+    fn open_stream(&self, index: usize, schema: &ArrowSchemaRef) -> PolarsResult<Self::Stream>;
+}
+
+/// Uploads a [`DataFrame`] over several [`UploadStream`]s in parallel.
+///
+/// Batches are handed to whichever stream is ready first, so the rows are not uploaded in
+/// order and faster connections receive more batches.
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct ParallelUploader {
+    n_streams: usize,
+    batch_size: usize,
+    max_in_flight: Option<usize>,
+    compat_level: CompatLevel,
+}
+
+impl ParallelUploader {
+    /// Upload over `n_streams` streams.
+    pub fn new(n_streams: usize) -> Self {
+        Self {
+            n_streams: n_streams.max(1),
+            batch_size: 64 * 1024,
+            max_in_flight: None,
+            compat_level: CompatLevel::newest(),
+        }
+    }
+
+    /// Set the maximum number of rows per record batch.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Set the number of batches that may be queued for the streams before slicing the frame
+    /// blocks. Defaults to twice the number of streams.
+    pub fn with_max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.max_in_flight = Some(max_in_flight);
+        self
+    }
+
+    pub fn with_compat_level(mut self, compat_level: CompatLevel) -> Self {
+        self.compat_level = compat_level;
+        self
+    }
+
+    /// Upload `df` to `target`. Returns the number of rows sent over every stream.
+    ///
+    /// If any stream fails, no further batches are queued and the error is returned. Streams that
+    /// did not fail are still finished.
+    pub fn upload<T: UploadTarget>(&self, target: &T, df: &DataFrame) -> PolarsResult<Vec<usize>> {
+        let schema = Arc::new(schema_to_arrow_checked(df.schema(), self.compat_level, "upload")?);
+        let max_in_flight = self.max_in_flight.unwrap_or(2 * self.n_streams);
+        let (sender, receiver) = sync_channel::<RecordBatch>(max_in_flight);
+        let receiver = Arc::new(Mutex::new(receiver));
+        let aborted = AtomicBool::new(false);
+
+        std::thread::scope(|scope| {
+            let handles = (0..self.n_streams)
+                .map(|index| {
+                    let receiver = receiver.clone();
+                    let schema = &schema;
+                    let aborted = &aborted;
+                    scope.spawn(move || {
+                        let result = run_stream(target, index, schema, &receiver);
+                        if result.is_err() {
+                            aborted.store(true, Ordering::Relaxed);
+                        }
+                        result
+                    })
+                })
+                .collect::<Vec<_>>();
+            // The receiver is dropped once all streams exit, which unblocks the sender.
+            drop(receiver);
+
+            'slices: for offset in (0..df.height()).step_by(self.batch_size) {
+                let mut slice = df.slice(offset as i64, self.batch_size);
+                slice.align_chunks();
+                for batch in slice.iter_chunks(self.compat_level, false) {
+                    if aborted.load(Ordering::Relaxed) || sender.send(batch).is_err() {
+                        break 'slices;
+                    }
+                }
+            }
+            drop(sender);
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect()
+        })
+    }
+}
+
+fn run_stream<T: UploadTarget>(
+    target: &T,
+    index: usize,
+    schema: &ArrowSchemaRef,
+    receiver: &Mutex<Receiver<RecordBatch>>,
+) -> PolarsResult<usize> {
+    let mut stream = target.open_stream(index, schema)?;
+    let mut n_rows = 0;
+    loop {
+        // Release the lock before sending, so the other streams can receive in the meantime.
+        let batch = receiver.lock().unwrap().recv();
+        let Ok(batch) = batch else {
+            break;
+        };
+        n_rows += batch.height();
+        stream.send(batch)?;
+    }
+    stream.finish()?;
+    Ok(n_rows)
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::AtomicUsize;
+
+    use polars_core::df;
+    use polars_core::utils::accumulate_dataframes_vertical;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct CollectTarget {
+        batches: Arc<Mutex<Vec<RecordBatch>>>,
+        finished: Arc<AtomicUsize>,
+        fail_stream: Option<usize>,
+    }
+
+    struct CollectStream {
+        batches: Arc<Mutex<Vec<RecordBatch>>>,
+        finished: Arc<AtomicUsize>,
+    }
+
+    impl UploadStream for CollectStream {
+        fn send(&mut self, batch: RecordBatch) -> PolarsResult<()> {
+            self.batches.lock().unwrap().push(batch);
+            Ok(())
+        }
+
+        fn finish(&mut self) -> PolarsResult<()> {
+            self.finished.fetch_add(1, Ordering::Relaxed);
+            Ok(())
+        }
+    }
+
+    impl UploadTarget for CollectTarget {
+        type Stream = CollectStream;
+
+        fn open_stream(
+            &self,
+            index: usize,
+            _schema: &ArrowSchemaRef,
+        ) -> PolarsResult<Self::Stream> {
+            polars_ensure!(
+                self.fail_stream != Some(index),
+                ComputeError: "cannot open stream {}", index
+            );
+            Ok(CollectStream {
+                batches: self.batches.clone(),
+                finished: self.finished.clone(),
+            })
+        }
+    }
+
+    #[test]
+    fn test_parallel_upload() -> PolarsResult<()> {
+        let df = df![
+            "a" => (0..1000).collect::<Vec<i32>>(),
+            "b" => (0..1000).map(|i| i.to_string()).collect::<Vec<_>>(),
+        ]?;
+
+        let target = CollectTarget::default();
+        let rows = ParallelUploader::new(4)
+            .with_batch_size(64)
+            .upload(&target, &df)?;
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows.iter().sum::<usize>(), 1000);
+        assert_eq!(target.finished.load(Ordering::Relaxed), 4);
+
+        let batches = std::mem::take(&mut *target.batches.lock().unwrap());
+        assert_eq!(batches.len(), 16);
+        assert!(batches.iter().all(|batch| batch.height() <= 64));
+        let mut out = accumulate_dataframes_vertical(batches.into_iter().map(DataFrame::from))?
+            .sort(["a"], Default::default())?;
+        out.rechunk_mut();
+        assert!(out.equals(&df));
+
+        let target = CollectTarget {
+            fail_stream: Some(1),
+            ..Default::default()
+        };
+        let result = ParallelUploader::new(2)
+            .with_batch_size(64)
+            .upload(&target, &df);
+        assert!(matches!(result, Err(PolarsError::ComputeError(_))));
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+use polars_core::prelude::*;
+use polars_expr::prelude::*;
+use polars_expr::{ExpressionConversionState, create_physical_expr};
+use polars_plan::plans::IR;
+
+use crate::frame::IntoLazy;
+
+/// Expressions that are planned once and can then be evaluated on many batches of columns.
+///
+/// This skips the query planning that is done for every [`LazyFrame`](crate::frame::LazyFrame)
+/// and is meant for evaluating the same expressions on many small batches, where that planning
+/// would dominate the run time.
+///
+/// # Example
+///
+/// ```rust
+/// # use polars_core::prelude::*;
+/// # use polars_lazy::prelude::*;
+/// let schema = Schema::from_iter([Field::new("x".into(), DataType::Float64)]);
+/// let evaluator = ExprEvaluator::compile(&[(col("x") * lit(2.0)).alias("y")], &schema)?;
+/// let out = evaluator.evaluate(vec![Column::new("x".into(), [1.0, 2.0])])?;
+/// assert_eq!(out[0], Column::new("y".into(), [2.0, 4.0]));
+/// # Ok::<(), PolarsError>(())
+/// ```
+pub struct ExprEvaluator {
+    input_schema: SchemaRef,
+    output_schema: SchemaRef,
+    exprs: Vec<Arc<dyn PhysicalExpr>>,
+    has_windows: bool,
+    state: ExecutionState,
+}
+
+impl ExprEvaluator {
+    /// Plan `exprs` for batches with the columns of `schema`, in that order.
+    pub fn compile<E: AsRef<[Expr]>>(exprs: E, schema: &Schema) -> PolarsResult<Self> {
+        let input_schema = Arc::new(schema.clone());
+        let plan = DataFrame::empty_with_schema(schema)
+            .lazy()
+            .select(exprs.as_ref())
+            .to_alp()?;
+        let mut expr_arena = plan.expr_arena;
+        let IR::Select { expr, schema, .. } = plan.lp_arena.get(plan.lp_top) else {
+            polars_bail!(InvalidOperation: "`ExprEvaluator` needs at least one expression");
+        };
+
+        let mut conversion_state = ExpressionConversionState::new(false);
+        let exprs = expr
+            .iter()
+            .map(|e| create_physical_expr(e, &mut expr_arena, &input_schema, &mut conversion_state))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(Self {
+            input_schema,
+            output_schema: schema.clone(),
+            exprs,
+            has_windows: conversion_state.has_windows,
+            state: ExecutionState::new(),
+        })
+    }
+
+    /// The schema of the evaluated columns.
+    pub fn schema(&self) -> &SchemaRef {
+        &self.output_schema
+    }
+
+    /// Evaluate the expressions on a batch of columns, which must match the schema the
+    /// expressions were compiled for.
+    ///
+    /// Scalar results are broadcast to the length of the batch.
+    pub fn evaluate(&self, columns: Vec<Column>) -> PolarsResult<Vec<Column>> {
+        polars_ensure!(
+            columns.len() == self.input_schema.len()
+                && columns
+                    .iter()
+                    .zip(self.input_schema.iter())
+                    .all(|(c, (name, dtype))| c.name() == name && c.dtype() == dtype),
+            SchemaMismatch: "batch does not match the schema of the `ExprEvaluator`: {:?}",
+            self.input_schema
+        );
+        let height = columns.first().map_or(0, |c| c.len());
+        polars_ensure!(
+            columns.iter().all(|c| c.len() == height),
+            ShapeMismatch: "all columns of a batch must have the same length"
+        );
+        // SAFETY: the names are unique as they match the schema and the lengths were checked.
+        let df = unsafe { DataFrame::new_unchecked(height, columns) };
+
+        let out = self
+            .exprs
+            .iter()
+            .map(|e| {
+                let c = e.evaluate(&df, &self.state)?;
+                if c.len() == height {
+                    Ok(c)
+                } else if c.len() == 1 {
+                    Ok(c.new_from_index(0, height))
+                } else {
+                    polars_bail!(
+                        ShapeMismatch: "expression '{}' produced {} values for a batch of {} rows",
+                        c.name(), c.len(), height
+                    )
+                }
+            })
+            .collect();
+        if self.has_windows {
+            self.state.clear_window_expr_cache();
+        }
+        out
+    }
+}
@@ -22,7 +22,7 @@ pub(crate) fn concat_impl<L: AsRef<[LazyFrame]>>(
             .ok_or_else(|| polars_err!(NoData: "empty container given"))?,
     );
 
-    let opt_state = lf.opt_state;
+    let state = lf.get_state();
     let cached_arenas = lf.cached_arena.clone();
 
     let mut lps = Vec::with_capacity(inputs.len());
@@ -34,7 +34,7 @@ pub(crate) fn concat_impl<L: AsRef<[LazyFrame]>>(
     }
 
     let lp = DslPlan::Union { inputs: lps, args };
-    Ok(LazyFrame::from_inner(lp, opt_state, cached_arenas))
+    Ok(LazyFrame::from_inner(lp, state, cached_arenas))
 }
 
 #[cfg(feature = "diagonal_concat")]
@@ -54,9 +54,9 @@ pub fn concat_lf_horizontal<L: AsRef<[LazyFrame]>>(
     args: UnionArgs,
 ) -> PolarsResult<LazyFrame> {
     let lfs = inputs.as_ref();
-    let (opt_state, cached_arena) = lfs
+    let (state, cached_arena) = lfs
         .first()
-        .map(|lf| (lf.opt_state, lf.cached_arena.clone()))
+        .map(|lf| (lf.get_state(), lf.cached_arena.clone()))
         .ok_or_else(
             || polars_err!(NoData: "Require at least one LazyFrame for horizontal concatenation"),
         )?;
@@ -69,7 +69,7 @@ pub fn concat_lf_horizontal<L: AsRef<[LazyFrame]>>(
         inputs: lfs.iter().map(|lf| lf.logical_plan.clone()).collect(),
         options,
     };
-    Ok(LazyFrame::from_inner(lp, opt_state, cached_arena))
+    Ok(LazyFrame::from_inner(lp, state, cached_arena))
 }
 
 /// Concat multiple [`LazyFrame`]s vertically.
@@ -133,4 +133,37 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_concat_lf_by_name() -> PolarsResult<()> {
+        let a = df![
+            "a" => [1i32],
+            "b" => ["x"]
+        ]?;
+        let b = df![
+            "b" => ["y"],
+            "a" => [2i64]
+        ]?;
+        let c = df![
+            "b" => ["z"],
+            "c" => [3i64]
+        ]?;
+
+        let args = UnionArgs {
+            by_name: true,
+            to_supertypes: true,
+            ..Default::default()
+        };
+        let out = concat([a.clone().lazy(), b.lazy()], args)?.collect()?;
+        let expected = df![
+            "a" => [1i64, 2],
+            "b" => ["x", "y"]
+        ]?;
+        assert!(out.equals(&expected));
+
+        let err = concat([a.lazy(), c.lazy()], args)?.collect().unwrap_err();
+        let msg = r#"input 1: missing columns ["a"]; unexpected columns ["c"]"#;
+        assert!(err.to_string().contains(msg));
+        Ok(())
+    }
 }
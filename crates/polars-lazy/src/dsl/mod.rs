@@ -32,8 +32,10 @@
 //! These kinds of invalid operations will only yield an error at runtime, when
 //! [`collect`](crate::frame::LazyFrame::collect) is called on the [`LazyFrame`].
 
+mod evaluator;
 pub mod functions;
 
+pub use evaluator::ExprEvaluator;
 pub use functions::*;
 pub use polars_plan::dsl::*;
 pub use polars_plan::plans::UdfSchema;
@@ -0,0 +1,111 @@
+use super::*;
+
+/// Options for [`LazyFrame::apply_changes`].
+#[derive(Clone, Debug)]
+pub struct ApplyChangesOptions {
+    /// The column of the changes that orders them. If `None`, the changes are applied in the
+    /// order of the rows.
+    pub sequence_column: Option<PlSmallStr>,
+    /// The value of the operation column that marks a delete. All other changes insert or update
+    /// the row of their key.
+    pub delete_op: PlSmallStr,
+}
+
+impl Default for ApplyChangesOptions {
+    fn default() -> Self {
+        Self {
+            sequence_column: None,
+            delete_op: PlSmallStr::from_static("delete"),
+        }
+    }
+}
+
+impl LazyFrame {
+    /// Apply a frame of change data capture (CDC) records to this snapshot.
+    ///
+    /// Every key is set to its last change, in the order of the sequence column: a delete
+    /// removes the row of the key, an insert or update replaces it or adds it if the key
+    /// doesn't exist. The operation and sequence columns are not part of the output, and columns
+    /// that are missing from the changes are null in the changed rows.
+    ///
+    /// The unchanged rows keep their order and are followed by the changed rows, in the order of
+    /// their last change.
+    pub fn apply_changes<I, S>(
+        self,
+        changes: LazyFrame,
+        key: I,
+        op_column: &str,
+        options: ApplyChangesOptions,
+    ) -> PolarsResult<LazyFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        let key = key.into_iter().map(Into::into).collect::<Vec<PlSmallStr>>();
+        polars_ensure!(!key.is_empty(), InvalidOperation: "`apply_changes` requires a key");
+        let key_exprs = key.iter().cloned().map(col).collect::<Vec<_>>();
+
+        let changes = match &options.sequence_column {
+            Some(sequence) => changes.sort(
+                [sequence.clone()],
+                SortMultipleOptions::default().with_maintain_order(true),
+            ),
+            None => changes,
+        };
+        let last_changes = changes
+            .unique_stable_generic(Some(key_exprs.clone()), UniqueKeepStrategy::Last)
+            .cache();
+
+        let unchanged = self.join(
+            last_changes.clone().select(key_exprs.clone()),
+            key_exprs.clone(),
+            key_exprs,
+            JoinArgs {
+                maintain_order: MaintainOrderJoin::Left,
+                ..JoinArgs::new(JoinType::Anti)
+            },
+        );
+        let bookkeeping = std::iter::once(PlSmallStr::from_str(op_column))
+            .chain(options.sequence_column)
+            .collect::<Vec<_>>();
+        let upserts = last_changes
+            .filter(col(op_column).neq_missing(lit(options.delete_op)))
+            .drop(by_name(bookkeeping, true));
+
+        concat_lf_diagonal([unchanged, upserts], UnionArgs::default())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_apply_changes() -> PolarsResult<()> {
+        let base = df![
+            "id" => [1, 2, 3, 5],
+            "value" => ["a", "b", "c", "e"],
+        ]?;
+        let changes = df![
+            "id" => [4, 2, 1, 2, 3],
+            "value" => [Some("d"), Some("x"), None, Some("y"), Some("z")],
+            "op" => ["insert", "update", "delete", "update", "update"],
+            "seq" => [1, 3, 2, 2, 5],
+        ]?;
+        let options = ApplyChangesOptions {
+            sequence_column: Some("seq".into()),
+            ..Default::default()
+        };
+        let out = base
+            .lazy()
+            .apply_changes(changes.lazy(), ["id"], "op", options)?
+            .collect()?;
+
+        let expected = df![
+            "id" => [5, 4, 2, 3],
+            "value" => ["e", "d", "x", "z"],
+        ]?;
+        assert!(out.equals(&expected));
+        Ok(())
+    }
+}
@@ -0,0 +1,225 @@
+//! Transparent in-memory compression of the cold columns of cached frames.
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use polars_core::prelude::*;
+use polars_io::ipc::CompressedColumn;
+
+use crate::prelude::*;
+
+/// When the columns of a [`CompressibleFrame`] are compressed.
+#[derive(Clone, Debug)]
+pub struct ColdCompressionOptions {
+    /// Columns with a smaller estimated size in bytes are never compressed.
+    pub min_size: usize,
+    /// Columns that have not been accessed for this long are compressed.
+    pub cold_after: Duration,
+}
+
+impl Default for ColdCompressionOptions {
+    fn default() -> Self {
+        Self {
+            min_size: 1 << 20,
+            cold_after: Duration::from_secs(300),
+        }
+    }
+}
+
+enum ColumnState {
+    Resident { column: Column, last_access: Instant },
+    Compressed(CompressedColumn),
+}
+
+impl ColumnState {
+    fn size(&self) -> usize {
+        match self {
+            Self::Resident { column, .. } => column.as_materialized_series().estimated_size(),
+            Self::Compressed(compressed) => compressed.compressed_size(),
+        }
+    }
+}
+
+/// A cached [`DataFrame`] of which the cold columns are kept LZ4-compressed in memory.
+///
+/// Compressed columns are decompressed when accessed and stay resident until they turn cold
+/// again, which is checked by [`CompressibleFrame::compress_cold`]. Scans created with
+/// [`CompressibleFrame::lazy`] only decompress the projected columns.
+pub struct CompressibleFrame {
+    schema: SchemaRef,
+    height: usize,
+    columns: Vec<Mutex<ColumnState>>,
+    options: ColdCompressionOptions,
+}
+
+impl CompressibleFrame {
+    pub fn new(df: DataFrame, options: ColdCompressionOptions) -> Self {
+        let schema = df.schema().clone();
+        let height = df.height();
+        let now = Instant::now();
+        let columns = df
+            .into_columns()
+            .into_iter()
+            .map(|column| {
+                Mutex::new(ColumnState::Resident {
+                    column,
+                    last_access: now,
+                })
+            })
+            .collect();
+        Self {
+            schema,
+            height,
+            columns,
+            options,
+        }
+    }
+
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn column_at(&self, idx: usize) -> PolarsResult<Column> {
+        let mut state = self.columns[idx].lock().unwrap();
+        let column = match &*state {
+            ColumnState::Resident { column, .. } => column.clone(),
+            ColumnState::Compressed(compressed) => compressed.decompress()?,
+        };
+        *state = ColumnState::Resident {
+            column: column.clone(),
+            last_access: Instant::now(),
+        };
+        Ok(column)
+    }
+
+    /// Get a column, decompressing it if it is compressed.
+    pub fn column(&self, name: &str) -> PolarsResult<Column> {
+        let idx = self.schema.try_index_of(name)?;
+        self.column_at(idx)
+    }
+
+    /// Get the frame with all columns decompressed.
+    pub fn to_df(&self) -> PolarsResult<DataFrame> {
+        let columns = (0..self.columns.len())
+            .map(|idx| self.column_at(idx))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        DataFrame::new(self.height, columns)
+    }
+
+    /// Compress the resident columns that are cold according to the [`ColdCompressionOptions`],
+    /// returning the number of compressed columns.
+    pub fn compress_cold(&self) -> PolarsResult<usize> {
+        let mut n_compressed = 0;
+        for state in &self.columns {
+            let mut state = state.lock().unwrap();
+            let ColumnState::Resident {
+                column,
+                last_access,
+            } = &*state
+            else {
+                continue;
+            };
+            if last_access.elapsed() < self.options.cold_after
+                || state.size() < self.options.min_size
+            {
+                continue;
+            }
+            let compressed = CompressedColumn::compress(column)?;
+            *state = ColumnState::Compressed(compressed);
+            n_compressed += 1;
+        }
+        Ok(n_compressed)
+    }
+
+    /// The number of columns that are currently compressed.
+    pub fn n_compressed(&self) -> usize {
+        self.columns
+            .iter()
+            .filter(|state| matches!(&*state.lock().unwrap(), ColumnState::Compressed(_)))
+            .count()
+    }
+
+    /// The estimated size in bytes of the frame, counting compressed columns at their
+    /// compressed size.
+    pub fn estimated_size(&self) -> usize {
+        self.columns
+            .iter()
+            .map(|state| state.lock().unwrap().size())
+            .sum()
+    }
+
+    /// Scan the frame, decompressing only the columns used by the query.
+    pub fn lazy(self: &Arc<Self>) -> PolarsResult<LazyFrame> {
+        let args = ScanArgsAnonymous {
+            schema: Some(self.schema.clone()),
+            name: "COMPRESSIBLE FRAME",
+            ..Default::default()
+        };
+        LazyFrame::anonymous_scan(self.clone(), args)
+    }
+}
+
+impl AnonymousScan for CompressibleFrame {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let df = match &scan_opts.with_columns {
+            Some(names) => {
+                let columns = names
+                    .iter()
+                    .map(|name| self.column(name))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                DataFrame::new(self.height, columns)?
+            },
+            None => self.to_df()?,
+        };
+        Ok(match scan_opts.n_rows {
+            Some(n_rows) => df.slice(0, n_rows),
+            None => df,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_compress_cold_columns() -> PolarsResult<()> {
+        let df = df![
+            "a" => (0..1000).map(|i| i % 10).collect::<Vec<i64>>(),
+            "b" => [1i8].repeat(1000),
+        ]?;
+        let options = ColdCompressionOptions {
+            min_size: 4096,
+            cold_after: Duration::ZERO,
+        };
+        let frame = Arc::new(CompressibleFrame::new(df.clone(), options));
+        let size = frame.estimated_size();
+
+        // Only `a` is large enough to be compressed.
+        assert_eq!(frame.compress_cold()?, 1);
+        assert_eq!(frame.n_compressed(), 1);
+        assert!(frame.estimated_size() < size);
+
+        // Projecting only `b` leaves `a` compressed.
+        let out = frame.lazy()?.select([col("b")]).collect()?;
+        assert_eq!(out.shape(), (1000, 1));
+        assert_eq!(frame.n_compressed(), 1);
+
+        let out = frame.lazy()?.collect()?;
+        assert!(out.equals_missing(&df));
+        assert_eq!(frame.n_compressed(), 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,464 @@
+//! Materialized aggregates of a frame that answer matching group-by queries.
+//!
+//! An [`AggregateCube`] groups a frame by its dimension columns once and stores partial
+//! aggregates per group. Registered as an [`Accelerator`], it answers group-bys of
+//! [`AggregateCube::lazy`] by a subset of the dimensions from the (much smaller) cube, as long as
+//! their aggregations can be derived from the partial aggregates.
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use arrow::record_batch::RecordBatch;
+use polars_core::prelude::*;
+use polars_plan::plans::expr_ir::ExprIR;
+use polars_utils::format_pl_smallstr;
+
+use crate::prelude::*;
+
+/// An aggregate from which the aggregates of coarser groups can be computed.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Partial {
+    Len,
+    Count(PlSmallStr),
+    Sum(PlSmallStr),
+    Min(PlSmallStr),
+    Max(PlSmallStr),
+}
+
+impl Partial {
+    fn expr(&self) -> Expr {
+        match self {
+            Partial::Len => len(),
+            Partial::Count(c) => col(c.clone()).count(),
+            Partial::Sum(c) => col(c.clone()).sum(),
+            Partial::Min(c) => col(c.clone()).min(),
+            Partial::Max(c) => col(c.clone()).max(),
+        }
+    }
+}
+
+/// An aggregation that a cube can answer.
+enum CubeAgg {
+    Len,
+    Count(PlSmallStr),
+    Sum(PlSmallStr),
+    Min(PlSmallStr),
+    Max(PlSmallStr),
+    Mean(PlSmallStr),
+}
+
+impl CubeAgg {
+    fn from_expr(e: &Expr) -> Option<Self> {
+        let column = |e: &Expr| match e {
+            Expr::Column(name) => Some(name.clone()),
+            _ => None,
+        };
+        Some(match e {
+            Expr::Alias(e, _) => return Self::from_expr(e),
+            Expr::Len => Self::Len,
+            Expr::Agg(agg) => match agg {
+                AggExpr::Count {
+                    input,
+                    include_nulls: false,
+                } => Self::Count(column(input)?),
+                AggExpr::Count {
+                    input,
+                    include_nulls: true,
+                } => {
+                    column(input)?;
+                    Self::Len
+                },
+                AggExpr::Sum(input) => Self::Sum(column(input)?),
+                AggExpr::Min {
+                    input,
+                    propagate_nans: false,
+                } => Self::Min(column(input)?),
+                AggExpr::Max {
+                    input,
+                    propagate_nans: false,
+                } => Self::Max(column(input)?),
+                AggExpr::Mean(input) => Self::Mean(column(input)?),
+                _ => return None,
+            },
+            _ => return None,
+        })
+    }
+
+    fn from_aexpr(node: Node, expr_arena: &Arena<AExpr>) -> Option<Self> {
+        let column = |node: &Node| match expr_arena.get(*node) {
+            AExpr::Column(name) => Some(name.clone()),
+            _ => None,
+        };
+        Some(match expr_arena.get(node) {
+            AExpr::Len => Self::Len,
+            AExpr::Agg(agg) => match agg {
+                IRAggExpr::Count {
+                    input,
+                    include_nulls: false,
+                } => Self::Count(column(input)?),
+                IRAggExpr::Count {
+                    input,
+                    include_nulls: true,
+                } => {
+                    column(input)?;
+                    Self::Len
+                },
+                IRAggExpr::Sum(input) => Self::Sum(column(input)?),
+                IRAggExpr::Min {
+                    input,
+                    propagate_nans: false,
+                } => Self::Min(column(input)?),
+                IRAggExpr::Max {
+                    input,
+                    propagate_nans: false,
+                } => Self::Max(column(input)?),
+                IRAggExpr::Mean(input) => Self::Mean(column(input)?),
+                _ => return None,
+            },
+            _ => return None,
+        })
+    }
+
+    fn partials(&self) -> Vec<Partial> {
+        match self {
+            CubeAgg::Len => vec![Partial::Len],
+            CubeAgg::Count(c) => vec![Partial::Count(c.clone())],
+            CubeAgg::Sum(c) => vec![Partial::Sum(c.clone())],
+            CubeAgg::Min(c) => vec![Partial::Min(c.clone())],
+            CubeAgg::Max(c) => vec![Partial::Max(c.clone())],
+            CubeAgg::Mean(c) => vec![Partial::Sum(c.clone()), Partial::Count(c.clone())],
+        }
+    }
+
+    /// The aggregation over the cube, where `partial` selects the column of a partial aggregate.
+    fn reaggregate(&self, partial: impl Fn(Partial) -> Expr) -> Expr {
+        match self {
+            CubeAgg::Len => partial(Partial::Len).sum(),
+            CubeAgg::Count(c) => partial(Partial::Count(c.clone())).sum(),
+            CubeAgg::Sum(c) => partial(Partial::Sum(c.clone())).sum(),
+            CubeAgg::Min(c) => partial(Partial::Min(c.clone())).min(),
+            CubeAgg::Max(c) => partial(Partial::Max(c.clone())).max(),
+            CubeAgg::Mean(c) => {
+                let sum = partial(Partial::Sum(c.clone())).sum();
+                let count = partial(Partial::Count(c.clone())).sum();
+                when(count.clone().gt(lit(0)))
+                    .then(sum.cast(DataType::Float64) / count.cast(DataType::Float64))
+                    .otherwise(lit(NULL))
+            },
+        }
+    }
+}
+
+fn partial_name(idx: usize) -> PlSmallStr {
+    format_pl_smallstr!("__POLARS_CUBE_{idx}")
+}
+
+/// Precomputed aggregates of a frame, grouped by its dimension columns.
+///
+/// Once registered with [`register_accelerator`], group-bys of [`AggregateCube::lazy`] are
+/// answered from the cube if:
+/// - they are keyed by dimension columns,
+/// - they don't maintain the order of the groups,
+/// - their aggregations are `len`, or `count`, `sum`, `min`, `max` or `mean` of a column, which
+///   can be computed from the aggregations the cube was created with. For example, a cube with
+///   the `sum` and `count` of a column answers its `mean`.
+///
+/// Other queries are executed on the frame as usual.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # use polars_core::prelude::*;
+/// # use polars_lazy::prelude::*;
+/// let df = df!("region" => ["a", "b", "a"], "sales" => [1, 2, 3])?;
+/// let cube = Arc::new(AggregateCube::new(
+///     "sales_by_region",
+///     df,
+///     ["region"],
+///     [col("sales").mean()],
+/// )?);
+/// register_accelerator(cube.clone());
+/// let out = cube
+///     .lazy()
+///     .group_by([col("region")])
+///     .agg([col("sales").sum()])
+///     .sort(["region"], Default::default())
+///     .collect()?;
+/// unregister_accelerator("sales_by_region");
+/// assert_eq!(out.column("sales")?.get(0)?, AnyValue::Int32(4));
+/// assert_eq!(cube.routed_queries(), 1);
+/// # Ok::<(), PolarsError>(())
+/// ```
+pub struct AggregateCube {
+    name: PlSmallStr,
+    source: Arc<DataFrame>,
+    dimensions: Vec<PlSmallStr>,
+    partials: PlIndexSet<Partial>,
+    cube: DataFrame,
+    routed_queries: Arc<AtomicUsize>,
+}
+
+impl AggregateCube {
+    /// Group `df` by the `dimensions` columns and compute the partial aggregates that are needed
+    /// for the `aggs`, which are `len`, or `count`, `sum`, `min`, `max` or `mean` of a column.
+    pub fn new<I, S, E>(
+        name: impl Into<PlSmallStr>,
+        df: DataFrame,
+        dimensions: I,
+        aggs: E,
+    ) -> PolarsResult<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+        E: AsRef<[Expr]>,
+    {
+        let dimensions = dimensions.into_iter().map(Into::into).collect::<Vec<_>>();
+        polars_ensure!(
+            !dimensions.is_empty(),
+            InvalidOperation: "an aggregate cube needs at least one dimension"
+        );
+        let schema = df.schema();
+        for d in &dimensions {
+            schema.try_get(d)?;
+        }
+
+        let mut partials = PlIndexSet::default();
+        for e in aggs.as_ref() {
+            let Some(agg) = CubeAgg::from_expr(e) else {
+                polars_bail!(InvalidOperation: "cannot precompute aggregation {} in a cube", e);
+            };
+            for partial in agg.partials() {
+                if let Partial::Sum(c) = &partial {
+                    let dtype = schema.try_get(c)?;
+                    polars_ensure!(
+                        dtype.is_primitive_numeric(),
+                        InvalidOperation: "cannot precompute the sum of column '{}' of type {}",
+                        c, dtype
+                    );
+                }
+                partials.insert(partial);
+            }
+        }
+
+        let source = Arc::new(df);
+        let partial_exprs = partials
+            .iter()
+            .enumerate()
+            .map(|(i, partial)| partial.expr().alias(partial_name(i)))
+            .collect::<Vec<_>>();
+        let cube = LazyFrame::from(DslPlan::DataFrameScan {
+            df: source.clone(),
+            schema: source.schema().clone(),
+        })
+        .group_by(dimensions.iter().cloned().map(col).collect::<Vec<_>>())
+        .agg(partial_exprs)
+        .collect()?;
+
+        Ok(Self {
+            name: name.into(),
+            source,
+            dimensions,
+            partials,
+            cube,
+            routed_queries: Default::default(),
+        })
+    }
+
+    /// A [`LazyFrame`] of the frame, of which group-bys are answered from the cube.
+    pub fn lazy(&self) -> LazyFrame {
+        LazyFrame::from(DslPlan::DataFrameScan {
+            df: self.source.clone(),
+            schema: self.source.schema().clone(),
+        })
+    }
+
+    /// The names of the dimension columns.
+    pub fn dimensions(&self) -> &[PlSmallStr] {
+        &self.dimensions
+    }
+
+    /// The number of groups in the cube.
+    pub fn len(&self) -> usize {
+        self.cube.height()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cube.height() == 0
+    }
+
+    /// The number of queries that were answered from the cube.
+    pub fn routed_queries(&self) -> usize {
+        self.routed_queries.load(Ordering::Relaxed)
+    }
+
+    /// The plan answering a group-by from the cube, if it can.
+    fn route(
+        &self,
+        keys: &[ExprIR],
+        aggs: &[ExprIR],
+        schema: &SchemaRef,
+        expr_arena: &Arena<AExpr>,
+    ) -> Option<CubeQuery> {
+        let keys = keys
+            .iter()
+            .map(|key| match expr_arena.get(key.node()) {
+                AExpr::Column(name) if self.dimensions.contains(name) => {
+                    Some(col(name.clone()).alias(key.output_name().clone()))
+                },
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let aggs = aggs
+            .iter()
+            .map(|e| {
+                let agg = CubeAgg::from_aexpr(e.node(), expr_arena)?;
+                if !agg.partials().iter().all(|p| self.partials.contains(p)) {
+                    return None;
+                }
+                let expr = agg.reaggregate(|p| {
+                    col(partial_name(self.partials.get_index_of(&p).unwrap()))
+                });
+                Some(expr.alias(e.output_name().clone()))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(CubeQuery {
+            cube: self.cube.clone(),
+            keys,
+            aggs,
+            schema: schema.clone(),
+            routed_queries: self.routed_queries.clone(),
+        })
+    }
+}
+
+impl Accelerator for AggregateCube {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn capabilities(&self) -> AcceleratorCapabilities {
+        AcceleratorCapabilities {
+            nodes: ["df", "aggregate"].into_iter().collect(),
+            udfs: false,
+        }
+    }
+
+    fn claim(
+        &self,
+        root: Node,
+        lp_arena: &Arena<IR>,
+        expr_arena: &Arena<AExpr>,
+    ) -> PolarsResult<Option<Arc<dyn AcceleratedPlan>>> {
+        let IR::GroupBy {
+            input,
+            keys,
+            aggs,
+            schema,
+            maintain_order,
+            options,
+            apply,
+        } = lp_arena.get(root)
+        else {
+            return Ok(None);
+        };
+        if *maintain_order
+            || apply.is_some()
+            || options.slice.is_some()
+            || options.is_rolling()
+            || options.is_dynamic()
+        {
+            return Ok(None);
+        }
+        // Only queries on the frame of the cube are answered, not those on copies of it.
+        let IR::DataFrameScan { df, .. } = lp_arena.get(*input) else {
+            return Ok(None);
+        };
+        if !Arc::ptr_eq(df, &self.source) {
+            return Ok(None);
+        }
+        Ok(self
+            .route(keys, aggs, schema, expr_arena)
+            .map(|query| Arc::new(query) as Arc<dyn AcceleratedPlan>))
+    }
+}
+
+/// A group-by answered from the cube.
+struct CubeQuery {
+    cube: DataFrame,
+    keys: Vec<Expr>,
+    aggs: Vec<Expr>,
+    schema: SchemaRef,
+    routed_queries: Arc<AtomicUsize>,
+}
+
+impl AcceleratedPlan for CubeQuery {
+    fn execute(&self) -> PolarsResult<Vec<RecordBatch>> {
+        // The aggregations of the partial aggregates may have other types than those of the
+        // query, e.g. the mean of a `Float32` column.
+        let casts = self
+            .schema
+            .iter()
+            .map(|(name, dtype)| col(name.clone()).cast(dtype.clone()))
+            .collect::<Vec<_>>();
+        let mut df = self
+            .cube
+            .clone()
+            .lazy()
+            .group_by(self.keys.clone())
+            .agg(self.aggs.clone())
+            .select(casts)
+            .collect()?;
+        df.rechunk_mut_par();
+        self.routed_queries.fetch_add(1, Ordering::Relaxed);
+        Ok(df.iter_chunks(CompatLevel::newest(), false).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_aggregate_cube_routing() -> PolarsResult<()> {
+        let df = df! {
+            "region" => ["a", "b", "a", "b", "a"],
+            "product" => ["x", "x", "y", "y", "x"],
+            "sales" => [Some(1), Some(2), None, Some(4), Some(5)],
+        }?;
+        let cube = Arc::new(AggregateCube::new(
+            "test-aggregate-cube",
+            df.clone(),
+            ["region", "product"],
+            [col("sales").mean(), col("sales").max(), len()],
+        )?);
+        assert_eq!(cube.len(), 4);
+        assert!(AggregateCube::new("c", df.clone(), ["region"], [col("sales").std(1)]).is_err());
+        assert!(AggregateCube::new("c", df.clone(), ["region"], [col("region").sum()]).is_err());
+
+        let query = |lf: LazyFrame| {
+            lf.group_by([col("region")])
+                .agg([
+                    col("sales").sum(),
+                    col("sales").mean().alias("mean"),
+                    col("sales").count().alias("count"),
+                    len(),
+                ])
+                .sort(["region"], Default::default())
+        };
+        let unroutable = |lf: LazyFrame| {
+            lf.group_by([col("region")])
+                .agg([col("sales").min()])
+                .sort(["region"], Default::default())
+        };
+
+        register_accelerator(cube.clone());
+        let routed = query(cube.lazy()).collect();
+        let not_routed = unroutable(cube.lazy()).collect();
+        assert!(unregister_accelerator("test-aggregate-cube"));
+
+        assert_eq!(routed?, query(df.clone().lazy()).collect()?);
+        assert_eq!(not_routed?, unroutable(df.lazy()).collect()?);
+        assert_eq!(cube.routed_queries(), 1);
+        Ok(())
+    }
+}
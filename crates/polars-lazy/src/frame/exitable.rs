@@ -8,7 +8,7 @@ use super::*;
 
 impl LazyFrame {
     pub fn collect_concurrently(self) -> PolarsResult<InProcessQuery> {
-        let (mut state, mut physical_plan, _) = self.prepare_collect(false, None)?;
+        let (mut state, mut physical_plan, _, audit) = self.prepare_collect(false, None)?;
 
         let (tx, rx) = channel();
         let token = state.cancel_token();
@@ -17,20 +17,20 @@ impl LazyFrame {
             #[cfg(feature = "async")]
             {
                 polars_io::pl_async::get_runtime().spawn_blocking(move || {
-                    let result = physical_plan.execute(&mut state);
+                    let result = audited(audit, || physical_plan.execute(&mut state));
                     tx.send(result).unwrap();
                 });
             }
             #[cfg(not(feature = "async"))]
             {
                 std::thread::spawn(move || {
-                    let result = physical_plan.execute(&mut state);
+                    let result = audited(audit, || physical_plan.execute(&mut state));
                     tx.send(result).unwrap();
                 });
             }
         } else {
             POOL.spawn_fifo(move || {
-                let result = physical_plan.execute(&mut state);
+                let result = audited(audit, || physical_plan.execute(&mut state));
                 tx.send(result).unwrap();
             });
         }
@@ -0,0 +1,80 @@
+//! Filters with expressions on indexed frames.
+use std::ops::Bound;
+
+use polars_core::prelude::*;
+#[cfg(feature = "is_between")]
+use polars_ops::prelude::ClosedInterval;
+
+use crate::prelude::*;
+
+type KeyRange = (Bound<AnyValue<'static>>, Bound<AnyValue<'static>>);
+
+fn literal_value(e: &Expr) -> Option<AnyValue<'static>> {
+    match e {
+        Expr::Literal(lv) if lv.is_scalar() => Some(lv.to_any_value()?.into_static()),
+        _ => None,
+    }
+}
+
+/// The range of `key` that `predicate` selects, if it is a range of literals.
+fn key_range(predicate: &Expr, key: &str) -> Option<KeyRange> {
+    let is_key = |e: &Expr| matches!(e, Expr::Column(name) if name == key);
+    match predicate {
+        Expr::BinaryExpr { left, op, right } if is_key(left) => {
+            let v = literal_value(right)?;
+            match op {
+                Operator::Eq => Some((Bound::Included(v.clone()), Bound::Included(v))),
+                Operator::Gt => Some((Bound::Excluded(v), Bound::Unbounded)),
+                Operator::GtEq => Some((Bound::Included(v), Bound::Unbounded)),
+                Operator::Lt => Some((Bound::Unbounded, Bound::Excluded(v))),
+                Operator::LtEq => Some((Bound::Unbounded, Bound::Included(v))),
+                _ => None,
+            }
+        },
+        #[cfg(feature = "is_between")]
+        Expr::Function {
+            input,
+            function: FunctionExpr::Boolean(BooleanFunction::IsBetween { closed }),
+        } if input.len() == 3 && is_key(&input[0]) => {
+            let low = literal_value(&input[1])?;
+            let high = literal_value(&input[2])?;
+            Some(match closed {
+                ClosedInterval::Both => (Bound::Included(low), Bound::Included(high)),
+                ClosedInterval::Left => (Bound::Included(low), Bound::Excluded(high)),
+                ClosedInterval::Right => (Bound::Excluded(low), Bound::Included(high)),
+                ClosedInterval::None => (Bound::Excluded(low), Bound::Excluded(high)),
+            })
+        },
+        _ => None,
+    }
+}
+
+fn as_key(bound: &Bound<AnyValue<'static>>) -> Bound<&[AnyValue<'static>]> {
+    match bound {
+        Bound::Included(v) => Bound::Included(std::slice::from_ref(v)),
+        Bound::Excluded(v) => Bound::Excluded(std::slice::from_ref(v)),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+/// Filters on the frame of a [`SortedDataFrameIndex`] that use the index where possible.
+pub trait SortedIndexFilter {
+    /// Filter the indexed frame with `predicate`.
+    ///
+    /// Comparisons of the first key column with a literal, such as `col(key).gt(lit(1))`, and
+    /// `col(key).is_between(low, high, closed)` with literal bounds are answered with a binary
+    /// search of the index. Other predicates are evaluated on the frame.
+    fn filter(&self, predicate: Expr) -> PolarsResult<DataFrame>;
+}
+
+impl SortedIndexFilter for SortedDataFrameIndex {
+    fn filter(&self, predicate: Expr) -> PolarsResult<DataFrame> {
+        if let Some((low, high)) = key_range(&predicate, &self.keys()[0])
+            // Bounds that can't be cast to the key type are left to the expression engine.
+            && let Ok(df) = self.range_filter(as_key(&low), as_key(&high))
+        {
+            return Ok(df);
+        }
+        self.frame().clone().lazy().filter(predicate).collect()
+    }
+}
@@ -0,0 +1,157 @@
+//! Listeners that are notified of every executed query, e.g. to persist audit records.
+use std::sync::{Arc, LazyLock, RwLock};
+use std::time::{Duration, Instant};
+
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// Description of an executed query that is passed to the [`ExecutionListener`]s.
+#[derive(Debug, Clone)]
+pub struct ExecutionRecord {
+    /// The optimized plan that was executed.
+    pub plan: String,
+    /// The engine that executed the plan.
+    pub engine: Engine,
+    /// The optimizations that were enabled.
+    pub opt_flags: OptFlags,
+    /// The sources scanned by the query. In-memory `DataFrame`s are not included.
+    pub sources: Vec<ScanSources>,
+    /// Time spent executing the plan.
+    pub duration: Duration,
+    /// The number of rows of every output, or the error if the query failed.
+    pub row_counts: PolarsResult<Vec<usize>>,
+}
+
+/// Receives an [`ExecutionRecord`] after every `collect`.
+pub trait ExecutionListener: Send + Sync {
+    /// Called after the query finished. Returning an error fails the query, even if its
+    /// execution succeeded.
+    fn on_execution(&self, record: &ExecutionRecord) -> PolarsResult<()>;
+}
+
+static EXECUTION_LISTENERS: LazyLock<RwLock<Vec<Arc<dyn ExecutionListener>>>> =
+    LazyLock::new(Default::default);
+
+/// Register a listener that is notified of every collected query.
+pub fn register_execution_listener(listener: Arc<dyn ExecutionListener>) {
+    EXECUTION_LISTENERS.write().unwrap().push(listener);
+}
+
+/// Remove a listener that was registered with [`register_execution_listener`].
+pub fn unregister_execution_listener(listener: &Arc<dyn ExecutionListener>) {
+    EXECUTION_LISTENERS
+        .write()
+        .unwrap()
+        .retain(|registered| !Arc::ptr_eq(registered, listener));
+}
+
+/// Remove all registered execution listeners.
+pub fn clear_execution_listeners() {
+    EXECUTION_LISTENERS.write().unwrap().clear();
+}
+
+/// Collects the information about a query before it is executed, the plan can be consumed by
+/// the execution.
+pub(crate) struct ExecutionAudit {
+    listeners: Vec<Arc<dyn ExecutionListener>>,
+    plan: String,
+    engine: Engine,
+    opt_flags: OptFlags,
+    sources: Vec<ScanSources>,
+}
+
+impl ExecutionAudit {
+    /// Returns `None` if there are no listeners.
+    pub(crate) fn new(plan: IRPlanRef<'_>, engine: Engine, opt_flags: OptFlags) -> Option<Self> {
+        let listeners = EXECUTION_LISTENERS.read().unwrap().clone();
+        if listeners.is_empty() {
+            return None;
+        }
+
+        let sources = plan
+            .lp_arena
+            .iter(plan.lp_top)
+            .filter_map(|(_, ir)| match ir {
+                IR::Scan { sources, .. } => Some(sources.clone()),
+                _ => None,
+            })
+            .collect();
+
+        Some(Self {
+            listeners,
+            plan: plan.describe(),
+            engine,
+            opt_flags,
+            sources,
+        })
+    }
+
+    fn finish(
+        self,
+        duration: Duration,
+        row_counts: Result<Vec<usize>, &PolarsError>,
+    ) -> PolarsResult<()> {
+        let record = ExecutionRecord {
+            plan: self.plan,
+            engine: self.engine,
+            opt_flags: self.opt_flags,
+            sources: self.sources,
+            duration,
+            row_counts: row_counts.map_err(Clone::clone),
+        };
+        for listener in &self.listeners {
+            listener.on_execution(&record)?;
+        }
+        Ok(())
+    }
+}
+
+/// The output of an executed query, of which the row counts are passed to the listeners.
+pub(crate) trait ExecutionOutput {
+    fn row_counts(&self) -> Vec<usize>;
+}
+
+impl ExecutionOutput for DataFrame {
+    fn row_counts(&self) -> Vec<usize> {
+        vec![self.height()]
+    }
+}
+
+impl ExecutionOutput for Vec<DataFrame> {
+    fn row_counts(&self) -> Vec<usize> {
+        self.iter().map(|df| df.height()).collect()
+    }
+}
+
+/// The output of a profiled query and its timings.
+impl ExecutionOutput for (DataFrame, DataFrame) {
+    fn row_counts(&self) -> Vec<usize> {
+        self.0.row_counts()
+    }
+}
+
+#[cfg(feature = "new_streaming")]
+impl ExecutionOutput for polars_stream::QueryResult {
+    fn row_counts(&self) -> Vec<usize> {
+        match self {
+            Self::Single(df) => df.row_counts(),
+            Self::Multiple(dfs) => dfs.row_counts(),
+        }
+    }
+}
+
+/// Execute a query and notify the listeners of `audit`. Every engine executes its queries
+/// through this function.
+pub(crate) fn audited<T: ExecutionOutput>(
+    audit: Option<ExecutionAudit>,
+    execute: impl FnOnce() -> PolarsResult<T>,
+) -> PolarsResult<T> {
+    let Some(audit) = audit else {
+        return execute();
+    };
+    let start = Instant::now();
+    let result = execute();
+    audit.finish(start.elapsed(), result.as_ref().map(T::row_counts))?;
+    result
+}
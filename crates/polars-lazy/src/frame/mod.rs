@@ -3,39 +3,71 @@
 mod python;
 
 mod cached_arenas;
+#[cfg(feature = "apply_changes")]
+mod changes;
+#[cfg(feature = "ipc")]
+mod compressed;
+mod cube;
 mod err;
+#[cfg(feature = "dataframe_index")]
+mod index;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+mod listener;
+mod partitions;
+mod registry;
+mod tee;
 
 use std::num::NonZeroUsize;
 use std::sync::mpsc::{Receiver, sync_channel};
 use std::sync::{Arc, Mutex};
 
 pub use anonymous_scan::*;
+#[cfg(feature = "apply_changes")]
+pub use changes::ApplyChangesOptions;
+#[cfg(feature = "ipc")]
+pub use compressed::{ColdCompressionOptions, CompressibleFrame};
 #[cfg(feature = "csv")]
 pub use csv::*;
+pub use cube::AggregateCube;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
 pub use file_list_reader::*;
+#[cfg(feature = "dataframe_index")]
+pub use index::SortedIndexFilter;
+#[cfg(any(feature = "csv", feature = "json"))]
+pub use follow::FollowOptions;
+pub use listener::{
+    ExecutionListener, ExecutionRecord, clear_execution_listeners, register_execution_listener,
+    unregister_execution_listener,
+};
 #[cfg(feature = "json")]
 pub use ndjson::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
+pub use partitions::{PartitionInfo, PartitionResults};
 use polars_compute::rolling::QuantileMethod;
 use polars_core::POOL;
 use polars_core::error::feature_gated;
 use polars_core::prelude::*;
+use polars_core::utils::PromotionRules;
 use polars_io::RowIndex;
 use polars_mem_engine::scan_predicate::functions::apply_scan_predicate_to_scan_ir;
 use polars_mem_engine::{Executor, create_multiple_physical_plans, create_physical_plan};
+#[cfg(feature = "asof_join")]
+use polars_ops::frame::AsOfOptions;
 use polars_ops::frame::{JoinCoalesce, MaintainOrderJoin};
 #[cfg(feature = "is_between")]
 use polars_ops::prelude::ClosedInterval;
 pub use polars_plan::frame::{AllowedOptimizations, OptFlags};
 use polars_utils::pl_str::PlSmallStr;
+pub use registry::{RegistrySnapshot, TableRegistry, TableSnapshot};
+pub use tee::TeeTarget;
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use rayon::ThreadPool;
 
 use crate::frame::cached_arenas::CachedArena;
+use crate::frame::listener::{ExecutionAudit, audited};
 use crate::prelude::*;
 
 pub trait IntoLazy {
@@ -50,6 +82,7 @@ impl IntoLazy for DataFrame {
             logical_plan: lp,
             opt_state: Default::default(),
             cached_arena: Default::default(),
+            thread_pool: None,
         }
     }
 }
@@ -60,6 +93,13 @@ impl IntoLazy for LazyFrame {
     }
 }
 
+/// The settings of a [`LazyFrame`] that carry over to the frames created from it.
+#[derive(Clone, Default)]
+pub(crate) struct FrameState {
+    opt_state: OptFlags,
+    thread_pool: Option<Arc<ThreadPool>>,
+}
+
 /// Lazy abstraction over an eager `DataFrame`.
 ///
 /// It really is an abstraction over a logical plan. The methods of this struct will incrementally
@@ -70,6 +110,8 @@ pub struct LazyFrame {
     pub logical_plan: DslPlan,
     pub(crate) opt_state: OptFlags,
     pub(crate) cached_arena: Arc<Mutex<Option<CachedArena>>>,
+    /// The pool the query is executed on, instead of the global thread pool.
+    pub(crate) thread_pool: Option<Arc<ThreadPool>>,
 }
 
 impl From<DslPlan> for LazyFrame {
@@ -78,6 +120,7 @@ impl From<DslPlan> for LazyFrame {
             logical_plan: plan,
             opt_state: OptFlags::default(),
             cached_arena: Default::default(),
+            thread_pool: None,
         }
     }
 }
@@ -85,13 +128,14 @@ impl From<DslPlan> for LazyFrame {
 impl LazyFrame {
     pub(crate) fn from_inner(
         logical_plan: DslPlan,
-        opt_state: OptFlags,
+        state: FrameState,
         cached_arena: Arc<Mutex<Option<CachedArena>>>,
     ) -> Self {
         Self {
             logical_plan,
-            opt_state,
+            opt_state: state.opt_state,
             cached_arena,
+            thread_pool: state.thread_pool,
         }
     }
 
@@ -99,15 +143,19 @@ impl LazyFrame {
         DslBuilder::from(self.logical_plan)
     }
 
-    fn get_opt_state(&self) -> OptFlags {
-        self.opt_state
+    pub(crate) fn get_state(&self) -> FrameState {
+        FrameState {
+            opt_state: self.opt_state,
+            thread_pool: self.thread_pool.clone(),
+        }
     }
 
-    fn from_logical_plan(logical_plan: DslPlan, opt_state: OptFlags) -> Self {
+    fn from_logical_plan(logical_plan: DslPlan, state: FrameState) -> Self {
         LazyFrame {
             logical_plan,
-            opt_state,
+            opt_state: state.opt_state,
             cached_arena: Default::default(),
+            thread_pool: state.thread_pool,
         }
     }
 
@@ -208,6 +256,29 @@ impl LazyFrame {
         self
     }
 
+    /// Execute the query on `pool` instead of the global thread pool, which isolates it from
+    /// other queries running concurrently.
+    ///
+    /// The pool is kept by the [`LazyFrame`]s created from this one, and is used by every way of
+    /// executing the query, e.g. collecting, sinking and profiling it. The streaming engine runs
+    /// the query on threads of its own, as many as `pool` has.
+    pub fn with_thread_pool(mut self, pool: Arc<ThreadPool>) -> Self {
+        self.thread_pool = Some(pool);
+        self
+    }
+
+    /// Execute the query on a pool of `n` threads, see [`LazyFrame::with_thread_pool`].
+    ///
+    /// The queries limited to the same number of threads share a pool.
+    pub fn with_max_threads(self, n: NonZeroUsize) -> PolarsResult<Self> {
+        Ok(self.with_thread_pool(POOL.sized(n)?))
+    }
+
+    /// The pool set with [`LazyFrame::with_thread_pool`].
+    pub fn thread_pool(&self) -> Option<&Arc<ThreadPool>> {
+        self.thread_pool.as_ref()
+    }
+
     /// Return a String describing the naive (un-optimized) logical plan.
     pub fn describe_plan(&self) -> PolarsResult<String> {
         Ok(self.clone().to_alp()?.describe())
@@ -244,6 +315,48 @@ impl LazyFrame {
         }
     }
 
+    /// Estimate the number of output rows and the bytes to scan of the optimized plan.
+    ///
+    /// Only the metadata of the sources is used; the query is not executed. This allows
+    /// schedulers to budget a query before running it.
+    pub fn estimate(self) -> PolarsResult<PlanEstimate> {
+        let plan = self.to_alp_optimized()?;
+        Ok(estimate_plan(plan.lp_top, &plan.lp_arena))
+    }
+
+    /// Return the source columns every output column of the optimized plan is derived from.
+    ///
+    /// The source columns are identified by the column name and the scanned sources they are
+    /// read from. See [`column_lineage`] for what is part of the lineage of a column.
+    pub fn column_lineage(self) -> PolarsResult<ColumnLineage> {
+        let plan = self.to_alp_optimized()?;
+        Ok(column_lineage(plan.lp_top, &plan.lp_arena, &plan.expr_arena))
+    }
+
+    /// Return the columns every scan of the optimized plan reads, and the scans whose columns
+    /// don't reach the output, such that unused inputs can be removed from the query.
+    pub fn pruning_report(self) -> PolarsResult<PruningReport> {
+        let plan = self.to_alp_optimized()?;
+        Ok(pruning_report(plan.lp_top, &plan.lp_arena, &plan.expr_arena))
+    }
+
+    /// Return a String describing the optimized logical plan followed by its [`PruningReport`].
+    ///
+    /// The plan is optimized once for both, so the report describes the plan that is shown.
+    pub fn describe_optimized_plan_with_pruning_report(
+        &self,
+        tree_format: bool,
+    ) -> PolarsResult<String> {
+        let plan = self.clone().to_alp_optimized()?;
+        let report = pruning_report(plan.lp_top, &plan.lp_arena, &plan.expr_arena);
+        let plan = if tree_format {
+            plan.describe_tree_format()
+        } else {
+            plan.describe()
+        };
+        Ok(format!("{plan}\n\n{report}"))
+    }
+
     /// Add a sort operation to the logical plan.
     ///
     /// Sorts the LazyFrame by the column name specified using the provided options.
@@ -284,12 +397,12 @@ impl LazyFrame {
     /// ```
     /// See [`SortMultipleOptions`] for more options.
     pub fn sort(self, by: impl IntoVec<PlSmallStr>, sort_options: SortMultipleOptions) -> Self {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self
             .get_plan_builder()
             .sort(by.into_vec().into_iter().map(col).collect(), sort_options)
             .build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     /// Add a sort operation to the logical plan.
@@ -320,9 +433,9 @@ impl LazyFrame {
         if by_exprs.is_empty() {
             self
         } else {
-            let opt_state = self.get_opt_state();
+            let state = self.get_state();
             let lp = self.get_plan_builder().sort(by_exprs, sort_options).build();
-            Self::from_logical_plan(lp, opt_state)
+            Self::from_logical_plan(lp, state)
         }
     }
 
@@ -407,6 +520,39 @@ impl LazyFrame {
         })
     }
 
+    /// Rename the columns selected by `columns` with `function`, e.g. to add a prefix or to map
+    /// the names with a closure through [`RenameAliasFn::Map`].
+    ///
+    /// The new names are computed from the schema when the plan is built, so this is a plain
+    /// [`LazyFrame::rename`] and no expression is evaluated per column. Select the columns by
+    /// data type to only rename columns of that type.
+    pub fn rename_with(self, columns: Selector, function: RenameAliasFn) -> Self {
+        self.map_private(DslFunction::RenameWith { columns, function })
+    }
+
+    /// Move the columns selected by `columns` to `position`, in the order in which they
+    /// are selected.
+    ///
+    /// This only reorders the schema, so the rest of the columns don't have to be listed in a
+    /// [`LazyFrame::select`].
+    pub fn move_columns(self, columns: Selector, position: ColumnPosition) -> Self {
+        self.map_private(DslFunction::MoveColumns { columns, position })
+    }
+
+    /// Add `column` at `index`, or replace the column of the same name and move it to `index`.
+    ///
+    /// Negative indices count from the end, as in [`ColumnPosition::Index`].
+    pub fn insert_column_at(self, index: i64, column: Expr) -> PolarsResult<Self> {
+        let name = expr_output_name(&column)?;
+        let columns = Selector::ByName {
+            names: [name].into(),
+            strict: true,
+        };
+        Ok(self
+            .with_column(column)
+            .move_columns(columns, ColumnPosition::Index(index)))
+    }
+
     /// Removes columns from the DataFrame.
     /// Note that it's better to only select the columns you need
     /// and let the projection pushdown optimize away the unneeded columns.
@@ -414,9 +560,9 @@ impl LazyFrame {
     /// Any given columns that are not in the schema will give a [`PolarsError::ColumnNotFound`]
     /// error while materializing the [`LazyFrame`].
     pub fn drop(self, columns: Selector) -> Self {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self.get_plan_builder().drop(columns).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     /// Shift the values by a given period and fill the parts that will be empty due to this operation
@@ -439,25 +585,25 @@ impl LazyFrame {
 
     /// Fill None values in the DataFrame with an expression.
     pub fn fill_null<E: Into<Expr>>(self, fill_value: E) -> LazyFrame {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self.get_plan_builder().fill_null(fill_value.into()).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     /// Fill NaN values in the DataFrame with an expression.
     pub fn fill_nan<E: Into<Expr>>(self, fill_value: E) -> LazyFrame {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self.get_plan_builder().fill_nan(fill_value.into()).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     /// Caches the result into a new LazyFrame.
     ///
     /// This should be used to prevent computations running multiple times.
     pub fn cache(self) -> Self {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self.get_plan_builder().cache().build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     /// Cast named frame columns, resulting in a new LazyFrame with updated dtypes
@@ -491,6 +637,134 @@ impl LazyFrame {
         }])
     }
 
+    /// Cast the columns to the types of the columns with the same name in `schema`, handling
+    /// values that cannot be cast according to `policy`.
+    ///
+    /// See [`DataFrame::cast_with_policy`]. With [`CastPolicy::Report`], the report is returned
+    /// as a second [`LazyFrame`] that is computed from the same input.
+    pub fn cast_with_policy(
+        mut self,
+        schema: &Schema,
+        policy: CastPolicy,
+    ) -> PolarsResult<(LazyFrame, Option<LazyFrame>)> {
+        let input_schema = self.collect_schema()?;
+        let mut cast_cols = vec![];
+        let mut reports = vec![];
+        for (name, dtype) in schema.iter() {
+            let from = input_schema.try_get(name)?;
+            if from == dtype {
+                continue;
+            }
+            policy.check(name, from, dtype)?;
+            let cast = col(name.clone()).cast_with_options(dtype.clone(), policy.cast_options());
+
+            if policy == CastPolicy::Report {
+                let lossy_rows = col(name.clone())
+                    .is_not_null()
+                    .and(cast.clone().is_null())
+                    .sum()
+                    .cast(IDX_DTYPE);
+                reports.push(self.clone().select([
+                    lit(name.as_str()).alias("column"),
+                    lit(from.to_string()).alias("from"),
+                    lit(dtype.to_string()).alias("to"),
+                    lossy_rows.alias("lossy_rows"),
+                ]));
+            }
+            cast_cols.push(cast);
+        }
+
+        let report = if policy != CastPolicy::Report {
+            None
+        } else if reports.is_empty() {
+            let schema = Schema::from_iter([
+                Field::new("column".into(), DataType::String),
+                Field::new("from".into(), DataType::String),
+                Field::new("to".into(), DataType::String),
+                Field::new("lossy_rows".into(), IDX_DTYPE),
+            ]);
+            Some(DataFrame::empty_with_schema(&schema).lazy())
+        } else {
+            Some(concat(reports, UnionArgs::default())?)
+        };
+        let lf = if cast_cols.is_empty() {
+            self
+        } else {
+            self.with_columns(cast_cols)
+        };
+        Ok((lf, report))
+    }
+
+    /// Summary statistics of the columns, computed in a single pass over the data.
+    ///
+    /// The output is the same as that of [`DataFrame::describe`]: a `statistic` column with the
+    /// names of the statistics, followed by a column with the statistics of every column.
+    #[cfg(feature = "describe")]
+    pub fn summary(mut self, percentiles: &[f64]) -> PolarsResult<LazyFrame> {
+        use polars_core::frame::describe::{describe_dtype, describe_statistics};
+
+        let schema = self.collect_schema()?;
+        polars_ensure!(
+            !schema.is_empty(),
+            InvalidOperation: "cannot describe a LazyFrame that has no columns"
+        );
+        let statistics = describe_statistics(percentiles)?;
+        let statistics = StringChunked::from_iter_values("statistic".into(), statistics.iter());
+        let mut exprs = Vec::with_capacity(schema.len() + 1);
+        exprs.push(lit(statistics.into_series()).implode());
+
+        // Every statistic is a scalar, so the statistics of a column can be collected in a list
+        // of a single row, which is exploded afterwards.
+        for (name, dtype) in schema.iter() {
+            let c = || col(name.clone());
+            let null = || lit(NULL);
+            let is_numeric = dtype.is_numeric();
+            let is_temporal = dtype.is_temporal();
+            let skip_min_max = dtype.is_nested()
+                || dtype.is_categorical()
+                || dtype.is_enum()
+                || dtype.is_null()
+                || dtype.is_object();
+
+            let mut stats = vec![
+                c().count(),
+                c().null_count(),
+                if is_numeric || is_temporal || dtype.is_bool() {
+                    c().mean()
+                } else {
+                    null()
+                },
+                if is_numeric { c().std(1) } else { null() },
+                if skip_min_max { null() } else { c().min() },
+            ];
+            for &p in percentiles {
+                stats.push(if is_numeric {
+                    c().quantile(lit(p), QuantileMethod::Nearest)
+                } else if is_temporal {
+                    c().to_physical()
+                        .quantile(lit(p), QuantileMethod::Nearest)
+                        .cast(dtype.clone())
+                } else {
+                    null()
+                });
+            }
+            stats.push(if skip_min_max { null() } else { c().max() });
+
+            let out_dtype = describe_dtype(dtype);
+            let stats = stats
+                .into_iter()
+                .map(|e| e.cast(out_dtype.clone()))
+                .collect::<Vec<_>>();
+            exprs.push(concat_list(stats)?.alias(name.clone()));
+        }
+
+        let options = ExplodeOptions {
+            empty_as_null: true,
+            keep_nulls: true,
+        };
+        Ok(self.select(exprs).explode(all(), options))
+    }
+
     pub fn optimize(
         self,
         lp_arena: &mut Arena<IR>,
@@ -552,7 +826,7 @@ impl LazyFrame {
         check_sink: bool,
         query_start: Option<std::time::Instant>,
         post_opt: P,
-    ) -> PolarsResult<(ExecutionState, Box<dyn Executor>, bool)>
+    ) -> PolarsResult<(ExecutionState, Box<dyn Executor>, bool, Option<ExecutionAudit>)>
     where
         P: FnOnce(
             Node,
@@ -562,6 +836,7 @@ impl LazyFrame {
         ) -> PolarsResult<()>,
     {
         let (mut lp_arena, mut expr_arena) = self.get_arenas();
+        let opt_state = self.opt_state;
 
         let mut scratch = vec![];
         let lp_top = self.optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut scratch)?;
@@ -587,6 +862,12 @@ impl LazyFrame {
         } else {
             true
         };
+        let plan = IRPlanRef {
+            lp_top,
+            lp_arena: &lp_arena,
+            expr_arena: &expr_arena,
+        };
+        let audit = ExecutionAudit::new(plan, Engine::InMemory, opt_state);
         let physical_plan = create_physical_plan(
             lp_top,
             &mut lp_arena,
@@ -595,22 +876,27 @@ impl LazyFrame {
         )?;
 
         let state = ExecutionState::new();
-        Ok((state, physical_plan, no_file_sink))
+        Ok((state, physical_plan, no_file_sink, audit))
     }
 
     // post_opt: A function that is called after optimization. This can be used to modify the IR jit.
-    pub fn _collect_post_opt<P>(self, post_opt: P) -> PolarsResult<DataFrame>
+    pub fn _collect_post_opt<P>(mut self, post_opt: P) -> PolarsResult<DataFrame>
     where
         P: FnOnce(
-            Node,
-            &mut Arena<IR>,
-            &mut Arena<AExpr>,
-            Option<std::time::Duration>,
-        ) -> PolarsResult<()>,
+                Node,
+                &mut Arena<IR>,
+                &mut Arena<AExpr>,
+                Option<std::time::Duration>,
+            ) -> PolarsResult<()>
+            + Send,
     {
-        let (mut state, mut physical_plan, _) =
+        if let Some(pool) = self.thread_pool.take() {
+            return POOL.install_scoped(&pool, || self._collect_post_opt(post_opt));
+        }
+
+        let (mut state, mut physical_plan, _, audit) =
             self.prepare_collect_post_opt(false, None, post_opt)?;
-        physical_plan.execute(&mut state)
+        audited(audit, || physical_plan.execute(&mut state))
     }
 
     #[allow(unused_mut)]
@@ -618,7 +904,7 @@ impl LazyFrame {
         self,
         check_sink: bool,
         query_start: Option<std::time::Instant>,
-    ) -> PolarsResult<(ExecutionState, Box<dyn Executor>, bool)> {
+    ) -> PolarsResult<(ExecutionState, Box<dyn Executor>, bool, Option<ExecutionAudit>)> {
         self.prepare_collect_post_opt(check_sink, query_start, |_, _, _, _| Ok(()))
     }
 
@@ -627,6 +913,10 @@ impl LazyFrame {
     ///
     /// The query is optimized prior to execution.
     pub fn collect_with_engine(mut self, mut engine: Engine) -> PolarsResult<DataFrame> {
+        if let Some(pool) = self.thread_pool.take() {
+            return POOL.install_scoped(&pool, || self.collect_with_engine(engine));
+        }
+
         let payload = if let DslPlan::Sink { payload, .. } = &self.logical_plan {
             payload.clone()
         } else {
@@ -664,9 +954,11 @@ impl LazyFrame {
             },
             _ => {},
         }
+        let opt_state = self.opt_state;
         let mut alp_plan = self.clone().to_alp_optimized()?;
+        let audit = ExecutionAudit::new(alp_plan.as_ref(), engine, opt_state);
 
-        match engine {
+        audited(audit, || match engine {
             Engine::Auto | Engine::Streaming => feature_gated!("new_streaming", {
                 let result = polars_stream::run_query(
                     alp_plan.lp_top,
@@ -678,17 +970,69 @@ impl LazyFrame {
             Engine::Gpu => {
                 Err(polars_err!(InvalidOperation: "sink is not supported for the gpu engine"))
             },
-            Engine::InMemory => {
-                let mut physical_plan = create_physical_plan(
-                    alp_plan.lp_top,
-                    &mut alp_plan.lp_arena,
-                    &mut alp_plan.expr_arena,
-                    BUILD_STREAMING_EXECUTOR,
-                )?;
+            Engine::InMemory => create_physical_plan(
+                alp_plan.lp_top,
+                &mut alp_plan.lp_arena,
+                &mut alp_plan.expr_arena,
+                BUILD_STREAMING_EXECUTOR,
+            )
+            .and_then(|mut physical_plan| {
                 let mut state = ExecutionState::new();
                 physical_plan.execute(&mut state)
-            },
+            }),
+        })
+    }
+
+    /// Execute the query with the streaming engine, writing a checkpoint to `options.dir` at
+    /// most every `options.interval`. If the process is interrupted, the query can continue from
+    /// the last checkpoint with [`LazyFrame::resume_from_checkpoint`].
+    ///
+    /// The checkpoint is removed once the query completes. Only queries made of scans,
+    /// projections, filters, aggregations, in-memory joins and sinks support checkpointing.
+    #[cfg(feature = "checkpoint")]
+    pub fn collect_with_checkpoint(self, options: CheckpointOptions) -> PolarsResult<DataFrame> {
+        self.run_with_checkpoint(options, false)
+    }
+
+    /// Continue executing a query from the last checkpoint written by
+    /// [`LazyFrame::collect_with_checkpoint`] or by a previous call to this function.
+    ///
+    /// The query must be the same as the query that wrote the checkpoint.
+    #[cfg(feature = "checkpoint")]
+    pub fn resume_from_checkpoint(self, options: CheckpointOptions) -> PolarsResult<DataFrame> {
+        self.run_with_checkpoint(options, true)
+    }
+
+    #[cfg(feature = "checkpoint")]
+    fn run_with_checkpoint(
+        mut self,
+        options: CheckpointOptions,
+        resume: bool,
+    ) -> PolarsResult<DataFrame> {
+        if let Some(pool) = self.thread_pool.take() {
+            return POOL.install_scoped(&pool, || self.run_with_checkpoint(options, resume));
+        }
+
+        if !matches!(self.logical_plan, DslPlan::Sink { .. }) {
+            self.logical_plan = DslPlan::Sink {
+                input: Arc::new(self.logical_plan),
+                payload: SinkType::Memory,
+            };
         }
+        let lf = self.with_new_streaming(true);
+        let opt_state = lf.opt_state;
+        let mut alp_plan = lf.to_alp_optimized()?;
+        let audit = ExecutionAudit::new(alp_plan.as_ref(), Engine::Streaming, opt_state);
+        audited(audit, || {
+            polars_stream::run_query_with_checkpoint(
+                alp_plan.lp_top,
+                &mut alp_plan.lp_arena,
+                &mut alp_plan.expr_arena,
+                options,
+                resume,
+            )
+        })
+        .map(|v| v.unwrap_single())
     }
 
     pub fn explain_all(plans: Vec<DslPlan>, opt_state: OptFlags) -> PolarsResult<String> {
@@ -696,6 +1040,7 @@ impl LazyFrame {
             logical_plan: DslPlan::SinkMultiple { inputs: plans },
             opt_state,
             cached_arena: Default::default(),
+            thread_pool: None,
         };
         sink_multiple.explain(true)
     }
@@ -722,6 +1067,7 @@ impl LazyFrame {
             logical_plan: DslPlan::SinkMultiple { inputs: plans },
             opt_state,
             cached_arena: Default::default(),
+            thread_pool: None,
         };
 
         #[cfg(feature = "new_streaming")]
@@ -741,8 +1087,27 @@ impl LazyFrame {
             },
             _ => {},
         }
-        let mut alp_plan = sink_multiple.to_alp_optimized()?;
+        let alp_plan = sink_multiple.to_alp_optimized()?;
+        let audit = ExecutionAudit::new(alp_plan.as_ref(), engine, opt_state);
+        audited(audit, || Self::execute_multiple(alp_plan, engine))
+    }
+
+    /// Collect the `plans` in one go, scanning the sources they have in common only once.
+    ///
+    /// Like [`LazyFrame::collect_all_with_engine`], but a scan shared by several plans stays
+    /// shared when they filter it differently: the scan applies the union of their filters and
+    /// every plan filters the shared output. This suits many small queries over the same files,
+    /// at the cost of reading more rows than each query needs on its own.
+    pub fn collect_multiplexed(
+        plans: Vec<DslPlan>,
+        engine: Engine,
+        opt_state: OptFlags,
+    ) -> PolarsResult<Vec<DataFrame>> {
+        let opt_state = opt_state | OptFlags::COMM_SUBPLAN_ELIM | OptFlags::SHARE_SCANS;
+        Self::collect_all_with_engine(plans, engine, opt_state)
+    }
 
+    fn execute_multiple(mut alp_plan: IRPlan, engine: Engine) -> PolarsResult<Vec<DataFrame>> {
         if engine == Engine::Streaming {
             feature_gated!("new_streaming", {
                 let result = polars_stream::run_query(
@@ -869,29 +1234,37 @@ impl LazyFrame {
 
     // post_opt: A function that is called after optimization. This can be used to modify the IR jit.
     // This version does profiling of the node execution.
-    pub fn _profile_post_opt<P>(self, post_opt: P) -> PolarsResult<(DataFrame, DataFrame)>
+    pub fn _profile_post_opt<P>(mut self, post_opt: P) -> PolarsResult<(DataFrame, DataFrame)>
     where
         P: FnOnce(
-            Node,
-            &mut Arena<IR>,
-            &mut Arena<AExpr>,
-            Option<std::time::Duration>,
-        ) -> PolarsResult<()>,
+                Node,
+                &mut Arena<IR>,
+                &mut Arena<AExpr>,
+                Option<std::time::Duration>,
+            ) -> PolarsResult<()>
+            + Send,
     {
+        if let Some(pool) = self.thread_pool.take() {
+            return POOL.install_scoped(&pool, || self._profile_post_opt(post_opt));
+        }
+
         let query_start = std::time::Instant::now();
-        let (mut state, mut physical_plan, _) =
+        let (mut state, mut physical_plan, _, audit) =
             self.prepare_collect_post_opt(false, Some(query_start), post_opt)?;
         state.time_nodes(query_start);
-        let out = physical_plan.execute(&mut state)?;
-        let timer_df = state.finish_timer()?;
-        Ok((out, timer_df))
+        audited(audit, || {
+            let out = physical_plan.execute(&mut state)?;
+            let timer_df = state.finish_timer()?;
+            Ok((out, timer_df))
+        })
     }
 
     /// Profile a LazyFrame.
     ///
     /// This will run the query and return a tuple
     /// containing the materialized DataFrame and a DataFrame that contains profiling information
-    /// of each node that is executed.
+    /// of each node that is executed. The expressions of projections and group-bys are also
+    /// timed individually, as `expr(<output name>)`.
     ///
     /// The units of the timings are microseconds.
     pub fn profile(self) -> PolarsResult<(DataFrame, DataFrame)> {
@@ -935,17 +1308,21 @@ impl LazyFrame {
             // if it fails in a todo!() error if auto_new_streaming is set.
             let mut new_stream_lazy = self.clone();
             new_stream_lazy.opt_state |= OptFlags::NEW_STREAMING;
+            let opt_state = new_stream_lazy.opt_state;
             let mut alp_plan = match new_stream_lazy.to_alp_optimized() {
                 Ok(v) => v,
                 Err(e) => return Some(Err(e)),
             };
+            let audit = ExecutionAudit::new(alp_plan.as_ref(), Engine::Streaming, opt_state);
 
             let f = || {
-                polars_stream::run_query(
-                    alp_plan.lp_top,
-                    &mut alp_plan.lp_arena,
-                    &mut alp_plan.expr_arena,
-                )
+                audited(audit, || {
+                    polars_stream::run_query(
+                        alp_plan.lp_top,
+                        &mut alp_plan.lp_arena,
+                        &mut alp_plan.expr_arena,
+                    )
+                })
             };
 
             match std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)) {
@@ -1033,9 +1410,9 @@ impl LazyFrame {
     /// }
     /// ```
     pub fn filter(self, predicate: Expr) -> Self {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self.get_plan_builder().filter(predicate).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     /// Remove frame rows that match a predicate expression.
@@ -1059,6 +1436,49 @@ impl LazyFrame {
         self.filter(predicate.neq_missing(lit(true)))
     }
 
+    /// Partition the frame rows into the rows that match a predicate expression and the rows
+    /// that are dropped by it.
+    ///
+    /// Rows where the predicate resolves to `null` are dropped, as with [`LazyFrame::filter`].
+    /// If `reason` is given, the dropped rows get a column of that name with the first
+    /// conjunct of the predicate (the operands of its top-level `&`s and `all_horizontal`s)
+    /// that was not `true`.
+    ///
+    /// The input is cached, so it is computed once if both frames are collected together with
+    /// [`LazyFrame::collect_all_with_engine`].
+    pub fn filter_partition(
+        self,
+        predicate: Expr,
+        reason: Option<PlSmallStr>,
+    ) -> (LazyFrame, LazyFrame) {
+        let Some(reason) = reason else {
+            let lf = self.cache();
+            return (lf.clone().filter(predicate.clone()), lf.remove(predicate));
+        };
+
+        let mut conjuncts = Vec::new();
+        split_conjuncts(predicate, &mut conjuncts);
+        let failed_conjunct = conjuncts.into_iter().rev().fold(
+            lit(NULL).cast(DataType::String),
+            |otherwise, conjunct| {
+                let name = lit(conjunct.to_string());
+                when(conjunct.neq_missing(lit(true)))
+                    .then(name)
+                    .otherwise(otherwise)
+            },
+        );
+
+        let lf = self
+            .with_column(failed_conjunct.alias(reason.clone()))
+            .cache();
+        let kept = lf
+            .clone()
+            .filter(col(reason.clone()).is_null())
+            .drop(cols([reason.clone()]));
+        let dropped = lf.filter(col(reason).is_not_null());
+        (kept, dropped)
+    }
+
     /// Select (and optionally rename, with [`alias`](crate::dsl::Expr::alias)) columns from the query.
     ///
     /// Columns can be selected with [`col`];
@@ -1109,9 +1529,9 @@ impl LazyFrame {
     }
 
     fn select_impl(self, exprs: Vec<Expr>, options: ProjectionOptions) -> Self {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self.get_plan_builder().project(exprs, options).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     /// Performs a "group-by" on a `LazyFrame`, producing a [`LazyGroupBy`], which can subsequently be aggregated.
@@ -1140,13 +1560,13 @@ impl LazyFrame {
             .iter()
             .map(|e| e.clone().into())
             .collect::<Vec<_>>();
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
 
         #[cfg(feature = "dynamic_group_by")]
         {
             LazyGroupBy {
                 logical_plan: self.logical_plan,
-                opt_state,
+                state,
                 keys,
                 predicates: vec![],
                 maintain_order: false,
@@ -1159,7 +1579,7 @@ impl LazyFrame {
         {
             LazyGroupBy {
                 logical_plan: self.logical_plan,
-                opt_state,
+                state,
                 keys,
                 predicates: vec![],
                 maintain_order: false,
@@ -1193,10 +1613,10 @@ impl LazyFrame {
                 options,
             );
         }
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         LazyGroupBy {
             logical_plan: self.logical_plan,
-            opt_state,
+            state,
             predicates: vec![],
             keys: group_by.as_ref().to_vec(),
             maintain_order: true,
@@ -1239,10 +1659,10 @@ impl LazyFrame {
                 options,
             );
         }
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         LazyGroupBy {
             logical_plan: self.logical_plan,
-            opt_state,
+            state,
             predicates: vec![],
             keys: group_by.as_ref().to_vec(),
             maintain_order: true,
@@ -1258,13 +1678,13 @@ impl LazyFrame {
             .iter()
             .map(|e| e.clone().into())
             .collect::<Vec<_>>();
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
 
         #[cfg(feature = "dynamic_group_by")]
         {
             LazyGroupBy {
                 logical_plan: self.logical_plan,
-                opt_state,
+                state,
                 keys,
                 predicates: vec![],
                 maintain_order: true,
@@ -1277,7 +1697,7 @@ impl LazyFrame {
         {
             LazyGroupBy {
                 logical_plan: self.logical_plan,
-                opt_state,
+                state,
                 keys,
                 predicates: vec![],
                 maintain_order: true,
@@ -1423,6 +1843,46 @@ impl LazyFrame {
         )
     }
 
+    /// Join every row of this query with the row of `other` that was valid at its `on` value,
+    /// e.g. to look up the version of a slowly changing dimension (SCD type 2) at a timestamp.
+    ///
+    /// A row of `other` with the same `by` keys matches if `valid_from <= on < valid_to`, where
+    /// a null `valid_to` is valid indefinitely. If the ranges of several rows with the same keys
+    /// contain the `on` value, the one with the latest `valid_from` is used. Rows without a match
+    /// are kept with nulls, like in a left join.
+    ///
+    /// Both inputs are sorted on their time column and matched in a single sweep, so the output
+    /// is ordered by `on`.
+    #[cfg(feature = "asof_join")]
+    pub fn join_validity_range<S: Into<PlSmallStr>>(
+        self,
+        other: LazyFrame,
+        by: impl IntoVec<PlSmallStr>,
+        on: S,
+        valid_from: S,
+        valid_to: S,
+    ) -> LazyFrame {
+        let by = by.into_vec();
+        let by = (!by.is_empty()).then_some(by);
+        let (on, valid_from) = (on.into(), valid_from.into());
+        let sort_options = SortMultipleOptions::default().with_maintain_order(true);
+
+        self.sort([on.clone()], sort_options.clone())
+            .join_builder()
+            .with(other.sort([valid_from.clone()], sort_options))
+            .left_on([col(on)])
+            .right_on([col(valid_from)])
+            .how(JoinType::AsOf(Box::new(AsOfOptions {
+                end_column: Some(valid_to.into()),
+                left_by: by.clone(),
+                right_by: by,
+                allow_eq: true,
+                check_sortedness: true,
+                ..Default::default()
+            })))
+            .finish()
+    }
+
     /// Generic function to join two LazyFrames.
     ///
     /// `join` can join on multiple columns, given as two list of expressions, and with a
@@ -1472,6 +1932,7 @@ impl LazyFrame {
             nulls_equal,
             coalesce,
             maintain_order,
+            promotion_rules,
         } = args;
 
         if slice.is_some() {
@@ -1487,7 +1948,8 @@ impl LazyFrame {
             .validate(validation)
             .join_nulls(nulls_equal)
             .coalesce(coalesce)
-            .maintain_order(maintain_order);
+            .maintain_order(maintain_order)
+            .promotion_rules(promotion_rules);
 
         if let Some(suffix) = suffix {
             builder = builder.suffix(suffix);
@@ -1524,7 +1986,7 @@ impl LazyFrame {
     /// }
     /// ```
     pub fn with_column(self, expr: Expr) -> LazyFrame {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self
             .get_plan_builder()
             .with_columns(
@@ -1536,7 +1998,7 @@ impl LazyFrame {
                 },
             )
             .build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     /// Add or replace multiple columns, given as expressions, to a DataFrame.
@@ -1585,24 +2047,24 @@ impl LazyFrame {
         per_column: Arc<[MatchToSchemaPerColumn]>,
         extra_columns: ExtraColumnsPolicy,
     ) -> LazyFrame {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self
             .get_plan_builder()
             .match_to_schema(schema, per_column, extra_columns)
             .build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     pub fn pipe_with_schema(
         self,
         callback: PlanCallback<(Vec<DslPlan>, Vec<SchemaRef>), DslPlan>,
     ) -> Self {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self
             .get_plan_builder()
             .pipe_with_schema(vec![], callback)
             .build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     pub fn pipe_with_schemas(
@@ -1610,7 +2072,7 @@ impl LazyFrame {
         others: Vec<LazyFrame>,
         callback: PlanCallback<(Vec<DslPlan>, Vec<SchemaRef>), DslPlan>,
     ) -> Self {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self
             .get_plan_builder()
             .pipe_with_schema(
@@ -1618,13 +2080,13 @@ impl LazyFrame {
                 callback,
             )
             .build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     fn with_columns_impl(self, exprs: Vec<Expr>, options: ProjectionOptions) -> LazyFrame {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self.get_plan_builder().with_columns(exprs, options).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     pub fn with_context<C: AsRef<[LazyFrame]>>(self, contexts: C) -> LazyFrame {
@@ -1633,9 +2095,9 @@ impl LazyFrame {
             .iter()
             .map(|lf| lf.logical_plan.clone())
             .collect();
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self.get_plan_builder().with_context(contexts).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     /// Aggregate all the columns as their maximum values.
@@ -1721,7 +2183,19 @@ impl LazyFrame {
 
     /// Apply explode operation. [See eager explode](polars_core::frame::DataFrame::explode).
     pub fn explode(self, columns: Selector, options: ExplodeOptions) -> LazyFrame {
-        self.explode_impl(columns, options, false)
+        self.explode_impl(columns, options, ExplodeLengthMismatch::Error, false)
+    }
+
+    /// Apply explode operation, with `mismatch` deciding what happens to rows in which the lists
+    /// of the exploded columns differ in length.
+    /// [See eager explode](polars_core::frame::DataFrame::explode_with_mismatch).
+    pub fn explode_with_mismatch(
+        self,
+        columns: Selector,
+        options: ExplodeOptions,
+        mismatch: ExplodeLengthMismatch,
+    ) -> LazyFrame {
+        self.explode_impl(columns, options, mismatch, false)
     }
 
     /// Apply explode operation. [See eager explode](polars_core::frame::DataFrame::explode).
@@ -1729,14 +2203,15 @@ impl LazyFrame {
         self,
         columns: Selector,
         options: ExplodeOptions,
+        mismatch: ExplodeLengthMismatch,
         allow_empty: bool,
     ) -> LazyFrame {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self
             .get_plan_builder()
-            .explode(columns, options, allow_empty)
+            .explode(columns, options, mismatch, allow_empty)
             .build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     /// Aggregate all the columns as the sum of their null value count.
@@ -1762,14 +2237,14 @@ impl LazyFrame {
         subset: Option<Vec<Expr>>,
         keep_strategy: UniqueKeepStrategy,
     ) -> LazyFrame {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let options = DistinctOptionsDSL {
             subset,
             maintain_order: true,
             keep_strategy,
         };
         let lp = self.get_plan_builder().distinct(options).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     /// Drop non-unique rows without maintaining the order of kept rows.
@@ -1789,14 +2264,29 @@ impl LazyFrame {
         subset: Option<Vec<Expr>>,
         keep_strategy: UniqueKeepStrategy,
     ) -> LazyFrame {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let options = DistinctOptionsDSL {
             subset,
             maintain_order: false,
             keep_strategy,
         };
         let lp = self.get_plan_builder().distinct(options).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
+    }
+
+    /// Keep a single row per unique combination of the `keys`: the row with the lowest value of
+    /// `by`, or the highest value if `descending`.
+    ///
+    /// This gives the same rows as sorting by `by` and keeping the first row per key, but groups
+    /// the rows in a single hash pass instead of sorting the whole frame. Ties are resolved in
+    /// favor of the first row, and a row where `by` is null is only kept if `by` is null for all
+    /// rows of its key. The order of the kept rows is maintained.
+    #[cfg(feature = "range")]
+    pub fn unique_by<E: AsRef<[Expr]>>(self, keys: E, by: Expr, descending: bool) -> LazyFrame {
+        let keys = keys.as_ref();
+        let best = if descending { by.arg_max() } else { by.arg_min() };
+        let idx_in_group = int_range(lit(0), len(), 1, IDX_DTYPE).over(keys);
+        self.filter(idx_in_group.eq(best.fill_null(lit(0 as IdxSize)).over(keys)))
     }
 
     /// Drop rows containing one or more NaN values.
@@ -1804,9 +2294,9 @@ impl LazyFrame {
     /// `subset` is an optional `Vec` of column names to consider for NaNs; if None, all
     /// floating point columns are considered.
     pub fn drop_nans(self, subset: Option<Selector>) -> LazyFrame {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self.get_plan_builder().drop_nans(subset).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     /// Drop rows containing one or more None values.
@@ -1814,9 +2304,9 @@ impl LazyFrame {
     /// `subset` is an optional `Vec` of column names to consider for nulls; if None, all
     /// columns are considered.
     pub fn drop_nulls(self, subset: Option<Selector>) -> LazyFrame {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self.get_plan_builder().drop_nulls(subset).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     /// Slice the DataFrame using an offset (starting row) and a length.
@@ -1829,9 +2319,9 @@ impl LazyFrame {
     /// DataFrame, the portion between `offset` and the end will be returned. In this
     /// case, the number of rows in the returned DataFrame will be less than `len`.
     pub fn slice(self, offset: i64, len: IdxSize) -> LazyFrame {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self.get_plan_builder().slice(offset, len).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     /// Get the first row.
@@ -1856,6 +2346,24 @@ impl LazyFrame {
         self.slice(neg_tail, n)
     }
 
+    /// Take every `n`th row, starting at row `offset`.
+    ///
+    /// The streaming engine gathers the rows of all columns in a single operator, so this is a
+    /// cheap way to downsample large streams.
+    pub fn gather_every(self, n: usize, offset: usize) -> LazyFrame {
+        self.select([all().as_expr().gather_every(n, offset)])
+    }
+
+    /// Pivot the DataFrame from long to wide format.
+    ///
+    /// `on_columns` holds the categories of the `on` columns, one column per `on` column and one
+    /// row per output column. Because the categories are known up front the output schema does
+    /// not depend on the data, so the pivot can be part of a lazy query and predicates on and
+    /// projections of the `index` columns are pushed down to the input. Values of `on` that are
+    /// not in `on_columns` are ignored.
+    ///
+    /// For every value column and category, `agg` is evaluated on the values of that category
+    /// within every `index` group, where `element()` refers to those values.
     #[cfg(feature = "pivot")]
     #[expect(clippy::too_many_arguments)]
     pub fn pivot(
@@ -1868,7 +2376,7 @@ impl LazyFrame {
         maintain_order: bool,
         separator: PlSmallStr,
     ) -> LazyFrame {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self
             .get_plan_builder()
             .pivot(
@@ -1881,7 +2389,7 @@ impl LazyFrame {
                 separator,
             )
             .build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     /// Unpivot the DataFrame from wide to long format.
@@ -1889,9 +2397,9 @@ impl LazyFrame {
     /// See [`UnpivotArgsIR`] for information on how to unpivot a DataFrame.
     #[cfg(feature = "pivot")]
     pub fn unpivot(self, args: UnpivotArgsDSL) -> LazyFrame {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self.get_plan_builder().unpivot(args).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     /// Limit the DataFrame to the first `n` rows.
@@ -1922,7 +2430,7 @@ impl LazyFrame {
     where
         F: 'static + Fn(DataFrame) -> PolarsResult<DataFrame> + Send + Sync,
     {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self
             .get_plan_builder()
             .map(
@@ -1932,7 +2440,7 @@ impl LazyFrame {
                 PlSmallStr::from_static(name.unwrap_or("ANONYMOUS UDF")),
             )
             .build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     #[cfg(feature = "python")]
@@ -1943,18 +2451,18 @@ impl LazyFrame {
         schema: Option<SchemaRef>,
         validate_output: bool,
     ) -> LazyFrame {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self
             .get_plan_builder()
             .map_python(function, optimizations, schema, validate_output)
             .build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     pub(crate) fn map_private(self, function: DslFunction) -> LazyFrame {
-        let opt_state = self.get_opt_state();
+        let state = self.get_state();
         let lp = self.get_plan_builder().map_private(function).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, state)
     }
 
     /// Add a new column at index 0 that counts the rows.
@@ -2015,9 +2523,27 @@ impl LazyFrame {
     /// inserted as columns.
     #[cfg(feature = "dtype-struct")]
     pub fn unnest(self, cols: Selector, separator: Option<PlSmallStr>) -> Self {
+        self.unnest_with_naming(cols, UnnestNaming::from_separator(separator))
+    }
+
+    /// Unnest the given `Struct` columns, naming the new columns according to `naming`.
+    ///
+    /// Use this to avoid name collisions between the fields and the existing columns.
+    #[cfg(feature = "dtype-struct")]
+    pub fn unnest_with_naming(self, cols: Selector, naming: UnnestNaming) -> Self {
         self.map_private(DslFunction::Unnest {
             columns: cols,
+            naming,
+        })
+    }
+
+    /// Recursively unnest all `Struct` columns. The fields are inserted as columns named
+    /// `{parent}{separator}{field}`, flattening at most `max_depth` levels.
+    #[cfg(feature = "dtype-struct")]
+    pub fn unnest_all(self, separator: PlSmallStr, max_depth: Option<usize>) -> Self {
+        self.map_private(DslFunction::UnnestAll {
             separator,
+            max_depth,
         })
     }
 
@@ -2027,21 +2553,46 @@ impl LazyFrame {
         S: Into<PlSmallStr>,
     {
         let key = key.into();
+        let state = self.get_state();
 
         let lp = DslPlan::MergeSorted {
             input_left: Arc::new(self.logical_plan),
             input_right: Arc::new(other.logical_plan),
             key,
         };
-        Ok(LazyFrame::from_logical_plan(lp, self.opt_state))
+        Ok(LazyFrame::from_logical_plan(lp, state))
     }
 
     pub fn hint(self, hint: HintIR) -> PolarsResult<LazyFrame> {
+        let state = self.get_state();
         let lp = DslPlan::MapFunction {
             input: Arc::new(self.logical_plan),
             function: DslFunction::Hint(hint),
         };
-        Ok(LazyFrame::from_logical_plan(lp, self.opt_state))
+        Ok(LazyFrame::from_logical_plan(lp, state))
+    }
+}
+
+/// Split a predicate into the operands of its top-level `&`s and `all_horizontal`s.
+fn split_conjuncts(predicate: Expr, conjuncts: &mut Vec<Expr>) {
+    match predicate {
+        Expr::BinaryExpr {
+            left,
+            op: Operator::And | Operator::LogicalAnd,
+            right,
+        } => {
+            split_conjuncts(Arc::unwrap_or_clone(left), conjuncts);
+            split_conjuncts(Arc::unwrap_or_clone(right), conjuncts);
+        },
+        Expr::Function {
+            input,
+            function: FunctionExpr::Boolean(BooleanFunction::AllHorizontal),
+        } => {
+            for predicate in input {
+                split_conjuncts(predicate, conjuncts);
+            }
+        },
+        predicate => conjuncts.push(predicate),
     }
 }
 
@@ -2049,7 +2600,7 @@ impl LazyFrame {
 #[derive(Clone)]
 pub struct LazyGroupBy {
     pub logical_plan: DslPlan,
-    opt_state: OptFlags,
+    state: FrameState,
     keys: Vec<Expr>,
     predicates: Vec<Expr>,
     maintain_order: bool,
@@ -2063,8 +2614,9 @@ impl From<LazyGroupBy> for LazyFrame {
     fn from(lgb: LazyGroupBy) -> Self {
         Self {
             logical_plan: lgb.logical_plan,
-            opt_state: lgb.opt_state,
+            opt_state: lgb.state.opt_state,
             cached_arena: Default::default(),
+            thread_pool: lgb.state.thread_pool,
         }
     }
 }
@@ -2134,7 +2686,7 @@ impl LazyGroupBy {
         let lp = DslBuilder::from(self.logical_plan)
             .group_by(self.keys, self.predicates, aggs, None, self.maintain_order)
             .build();
-        LazyFrame::from_logical_plan(lp, self.opt_state)
+        LazyFrame::from_logical_plan(lp, self.state)
     }
 
     /// Return first n rows of each group
@@ -2151,6 +2703,7 @@ impl LazyGroupBy {
                 empty_as_null: true,
                 keep_nulls: true,
             },
+            ExplodeLengthMismatch::Error,
             true,
         )
     }
@@ -2169,6 +2722,7 @@ impl LazyGroupBy {
                 empty_as_null: true,
                 keep_nulls: true,
             },
+            ExplodeLengthMismatch::Error,
             true,
         )
     }
@@ -2201,7 +2755,7 @@ impl LazyGroupBy {
             maintain_order: self.maintain_order,
             options: Arc::new(options),
         };
-        LazyFrame::from_logical_plan(lp, self.opt_state)
+        LazyFrame::from_logical_plan(lp, self.state)
     }
 }
 
@@ -2219,6 +2773,9 @@ pub struct JoinBuilder {
     nulls_equal: bool,
     coalesce: JoinCoalesce,
     maintain_order: MaintainOrderJoin,
+    promotion_rules: PromotionRules,
+    #[cfg(feature = "asof_join")]
+    asof_tolerance: Option<Expr>,
 }
 impl JoinBuilder {
     /// Create the `JoinBuilder` with the provided `LazyFrame` as the left table.
@@ -2236,6 +2793,9 @@ impl JoinBuilder {
             nulls_equal: false,
             coalesce: Default::default(),
             maintain_order: Default::default(),
+            promotion_rules: Default::default(),
+            #[cfg(feature = "asof_join")]
+            asof_tolerance: None,
         }
     }
 
@@ -2322,23 +2882,61 @@ impl JoinBuilder {
         self
     }
 
+    /// The rules to determine the supertype of join keys with different data types.
+    pub fn promotion_rules(mut self, promotion_rules: PromotionRules) -> Self {
+        self.promotion_rules = promotion_rules;
+        self
+    }
+
+    /// The tolerance of an asof join per row of the left table.
+    ///
+    /// The expression is evaluated on the left table, e.g. `col("tick_size") * lit(2)` or a
+    /// tolerance per group with `over`. Rows with a null tolerance are matched without a
+    /// tolerance.
+    #[cfg(feature = "asof_join")]
+    pub fn asof_tolerance(mut self, tolerance: Expr) -> Self {
+        self.asof_tolerance = Some(tolerance);
+        self
+    }
+
     /// Finish builder
     pub fn finish(self) -> LazyFrame {
-        let opt_state = self.lf.opt_state;
+        let state = self.lf.get_state();
         let other = self.other.expect("'with' not set in join builder");
+        #[allow(unused_mut)]
+        let (mut lf, mut how) = (self.lf, self.how);
+
+        // A tolerance expression is evaluated into a temporary column of the left table.
+        #[cfg(feature = "asof_join")]
+        let tolerance_column = match (self.asof_tolerance, &mut how) {
+            (Some(tolerance), JoinType::AsOf(options)) => {
+                let name = PlSmallStr::from_static("__POLARS_ASOF_TOLERANCE");
+                lf = lf.with_column(tolerance.alias(name.clone()));
+                options.tolerance_column = Some(name.clone());
+                Some(name)
+            },
+            (Some(_), _) => {
+                return lf.pipe_with_schema(PlanCallback::new(|_| {
+                    polars_bail!(
+                        InvalidOperation: "a tolerance expression is only supported for asof joins"
+                    )
+                }));
+            },
+            (None, _) => None,
+        };
 
         let args = JoinArgs {
-            how: self.how,
+            how,
             validation: self.validation,
             suffix: self.suffix,
             slice: None,
             nulls_equal: self.nulls_equal,
             coalesce: self.coalesce,
             maintain_order: self.maintain_order,
+            promotion_rules: self.promotion_rules,
         };
 
-        let lp = self
-            .lf
+        let lp = lf
             .get_plan_builder()
             .join(
                 other.logical_plan,
@@ -2352,12 +2950,17 @@ impl JoinBuilder {
                 .into(),
             )
             .build();
-        LazyFrame::from_logical_plan(lp, opt_state)
+        let out = LazyFrame::from_logical_plan(lp, state);
+        #[cfg(feature = "asof_join")]
+        if let Some(name) = tolerance_column {
+            return out.drop(by_name([name], true));
+        }
+        out
     }
 
     // Finish with join predicates
     pub fn join_where(self, predicates: Vec<Expr>) -> LazyFrame {
-        let opt_state = self.lf.opt_state;
+        let state = self.lf.get_state();
         let other = self.other.expect("with not set");
 
         // Decompose `And` conjunctions into their component expressions
@@ -2426,6 +3029,7 @@ impl JoinBuilder {
             nulls_equal: self.nulls_equal,
             coalesce: self.coalesce,
             maintain_order: self.maintain_order,
+            promotion_rules: self.promotion_rules,
         };
         let options = JoinOptions {
             allow_parallel: self.allow_parallel,
@@ -2442,7 +3046,7 @@ impl JoinBuilder {
             options: Arc::from(options),
         };
 
-        LazyFrame::from_logical_plan(lp, opt_state)
+        LazyFrame::from_logical_plan(lp, state)
     }
 }
 
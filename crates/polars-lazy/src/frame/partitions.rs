@@ -0,0 +1,238 @@
+use polars_utils::plpath::PlPath;
+
+use super::*;
+
+/// The source of a partition passed to the function of [`LazyFrame::map_partitions`].
+#[derive(Debug, Clone)]
+pub struct PartitionInfo {
+    /// Position of the source in the expanded sources of the scan.
+    pub index: usize,
+    /// Number of sources of the scan.
+    pub count: usize,
+    /// Path of the source, or `None` for opened files and in-memory buffers.
+    pub path: Option<PlPath>,
+}
+
+/// The results of [`LazyFrame::execute_per_partition`], in the order in which the partitions
+/// finish.
+///
+/// Every result comes with a single-row frame holding the values of the partition columns.
+/// Dropping the iterator stops starting new partitions.
+pub struct PartitionResults {
+    recv: Receiver<(DataFrame, PolarsResult<DataFrame>)>,
+}
+
+impl Iterator for PartitionResults {
+    type Item = (DataFrame, PolarsResult<DataFrame>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.recv.recv().ok()
+    }
+}
+
+impl LazyFrame {
+    /// Split a file scan into one scan per source, after expanding directories and globs.
+    ///
+    /// This must be called directly on a scan, e.g. the result of [`LazyFrame::scan_parquet`].
+    /// Every scan infers its schema and hive partitions from its own source.
+    pub fn split_sources(self) -> PolarsResult<Vec<(LazyFrame, PartitionInfo)>> {
+        let state = self.get_state();
+        let DslPlan::Scan {
+            sources,
+            unified_scan_args,
+            scan_type,
+            ..
+        } = self.logical_plan
+        else {
+            polars_bail!(
+                InvalidOperation:
+                "can only split the sources of a file scan; call this directly on `scan_*`"
+            )
+        };
+        let mut args = *unified_scan_args;
+        polars_ensure!(
+            args.row_index.is_none() && args.pre_slice.is_none() && args.deletion_files.is_none(),
+            InvalidOperation:
+            "cannot split a scan with a row index, slice or deletion files into its sources"
+        );
+
+        let sources = match &*scan_type {
+            #[cfg(feature = "parquet")]
+            FileScanDsl::Parquet { .. } => sources.expand_paths_with_hive_update(&mut args)?,
+            #[cfg(feature = "ipc")]
+            FileScanDsl::Ipc { .. } => sources.expand_paths_with_hive_update(&mut args)?,
+            #[cfg(feature = "python")]
+            FileScanDsl::PythonDataset { .. } => {
+                polars_bail!(InvalidOperation: "cannot split the sources of a dataset scan")
+            },
+            FileScanDsl::Anonymous { .. } => {
+                polars_bail!(InvalidOperation: "cannot split the sources of an anonymous scan")
+            },
+            _ => sources.expand_paths(&mut args)?,
+        };
+        // The statistics and row counts describe all sources together.
+        args.glob = false;
+        args.table_statistics = None;
+        args.row_count = None;
+
+        let count = sources.len();
+        (0..count)
+            .map(|index| {
+                let source = sources.at(index).into_owned()?;
+                let path = match &source {
+                    ScanSource::Path(path) => Some(path.clone()),
+                    ScanSource::File(_) | ScanSource::Buffer(_) => None,
+                };
+                let lp = DslPlan::Scan {
+                    sources: source.into_sources(),
+                    unified_scan_args: Box::new(args.clone()),
+                    scan_type: scan_type.clone(),
+                    cached_ir: Default::default(),
+                };
+                let info = PartitionInfo { index, count, path };
+                Ok((LazyFrame::from_logical_plan(lp, state.clone()), info))
+            })
+            .collect()
+    }
+
+    /// Apply `function` to the frame of every source of a file scan separately.
+    ///
+    /// The function is called once per source with its frame and a [`PartitionInfo`]. The
+    /// sources are scanned and mapped in parallel and the results are concatenated in the order
+    /// of the sources. Projections and predicates are pushed into every scan as far as
+    /// `optimizations` allow.
+    pub fn map_partitions<F>(
+        self,
+        function: F,
+        optimizations: AllowedOptimizations,
+        schema: Option<Arc<dyn UdfSchema>>,
+    ) -> PolarsResult<LazyFrame>
+    where
+        F: 'static + Fn(DataFrame, &PartitionInfo) -> PolarsResult<DataFrame> + Send + Sync,
+    {
+        let function = Arc::new(function);
+        let partitions = self
+            .split_sources()?
+            .into_iter()
+            .map(|(lf, info)| {
+                let function = function.clone();
+                lf.map(
+                    move |df| function(df, &info),
+                    optimizations,
+                    schema.clone(),
+                    Some("map_partitions"),
+                )
+            })
+            .collect::<Vec<_>>();
+        polars_ensure!(
+            !partitions.is_empty(),
+            ComputeError: "cannot map the partitions of a scan without sources"
+        );
+        concat(partitions, UnionArgs::default())
+    }
+
+    /// Run the query once per hive partition of its scan, with at most `concurrency` partitions
+    /// running at the same time.
+    ///
+    /// The query must contain exactly one scan whose hive partitions include `partition_cols`.
+    /// Every run only scans the files of its partition, so all operations of the query, e.g.
+    /// aggregations and joins, only see the rows of that partition.
+    pub fn execute_per_partition(
+        mut self,
+        partition_cols: impl IntoVec<PlSmallStr>,
+        concurrency: NonZeroUsize,
+    ) -> PolarsResult<PartitionResults> {
+        let partition_cols = partition_cols.into_vec();
+        polars_ensure!(
+            !partition_cols.is_empty(),
+            InvalidOperation: "executing per partition requires at least one partition column"
+        );
+        let mut state = self.get_state();
+        let (mut lp_arena, mut expr_arena) = self.get_arenas();
+        let root = to_alp(
+            self.logical_plan.clone(),
+            &mut expr_arena,
+            &mut lp_arena,
+            &mut state.opt_state,
+        )?;
+
+        let scans = lp_arena
+            .iter(root)
+            .filter(|(_, ir)| match ir {
+                IR::Scan {
+                    hive_parts: Some(hive_parts),
+                    ..
+                } => partition_cols
+                    .iter()
+                    .all(|name| hive_parts.schema().contains(name)),
+                _ => false,
+            })
+            .map(|(node, _)| node)
+            .collect::<Vec<_>>();
+        polars_ensure!(
+            scans.len() == 1,
+            InvalidOperation: "executing per partition requires exactly one scan that is hive \
+            partitioned by {:?}, found {}", partition_cols, scans.len()
+        );
+        let scan_node = scans[0];
+        let scan = lp_arena.get(scan_node).clone();
+        let IR::Scan {
+            sources,
+            hive_parts: Some(hive_parts),
+            unified_scan_args,
+            ..
+        } = &scan
+        else {
+            unreachable!()
+        };
+        // The row index and slice of a scan are computed over all of its files.
+        polars_ensure!(
+            sources.is_paths()
+                && sources.len() == hive_parts.df().height()
+                && !unified_scan_args.has_row_index_or_slice(),
+            InvalidOperation: "cannot execute a scan with a row index or slice per partition"
+        );
+        let (keys, partitions) = hive_parts.group_sources_by(partition_cols)?;
+
+        let dsl = Arc::new(self.logical_plan);
+        let runs = partitions
+            .into_iter()
+            .enumerate()
+            .map(|(i, files)| {
+                let mut scan = scan.clone();
+                filter_scan_ir(&mut scan, files.into_iter());
+                let mut lp_arena = lp_arena.clone();
+                lp_arena.replace(scan_node, scan);
+                // The arenas keep their version, so the plan is not converted again.
+                let lp = DslPlan::IR {
+                    dsl: dsl.clone(),
+                    version: lp_arena.version(),
+                    node: Some(root),
+                };
+                let lf = LazyFrame::from_logical_plan(lp, state.clone());
+                lf.set_cached_arena(lp_arena, expr_arena.clone());
+                (keys.slice(i as i64, 1), lf)
+            })
+            .collect::<Vec<_>>();
+
+        let n_workers = concurrency.get().min(runs.len());
+        let runs = Arc::new(Mutex::new(runs.into_iter()));
+        let (send, recv) = sync_channel(n_workers);
+        for _ in 0..n_workers {
+            let runs = runs.clone();
+            let send = send.clone();
+            std::thread::spawn(move || {
+                loop {
+                    let Some((partition, lf)) = runs.lock().unwrap().next() else {
+                        break;
+                    };
+                    // Stop if the receiver has closed.
+                    if send.send((partition, lf.collect())).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        Ok(PartitionResults { recv })
+    }
+}
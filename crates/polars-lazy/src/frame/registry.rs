@@ -0,0 +1,180 @@
+//! A thread-safe registry of named, versioned tables for long-running services.
+use std::sync::{Arc, RwLock};
+
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// A version of a table in a [`TableRegistry`].
+///
+/// Snapshots are immutable; refreshing a table registers a new snapshot and queries holding on
+/// to an older one keep seeing the old data.
+#[derive(Clone)]
+pub struct TableSnapshot {
+    name: PlSmallStr,
+    version: u64,
+    frame: LazyFrame,
+}
+
+impl TableSnapshot {
+    pub fn name(&self) -> &PlSmallStr {
+        &self.name
+    }
+
+    /// The version of the table, unique within its registry and increasing with every change.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// A [`LazyFrame`] of the table to build queries on.
+    pub fn lazy(&self) -> LazyFrame {
+        self.frame.clone()
+    }
+}
+
+/// A consistent view of all tables of a [`TableRegistry`] at one point in time.
+#[derive(Clone, Default)]
+pub struct RegistrySnapshot {
+    tables: PlIndexMap<PlSmallStr, Arc<TableSnapshot>>,
+}
+
+impl RegistrySnapshot {
+    pub fn get(&self, name: &str) -> Option<&Arc<TableSnapshot>> {
+        self.tables.get(name)
+    }
+
+    /// The names of the tables, in order of registration.
+    pub fn names(&self) -> impl Iterator<Item = &PlSmallStr> {
+        self.tables.keys()
+    }
+
+    pub fn len(&self) -> usize {
+        self.tables.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty()
+    }
+}
+
+#[derive(Default)]
+struct RegistryState {
+    tables: RegistrySnapshot,
+    last_version: u64,
+}
+
+impl RegistryState {
+    fn insert(&mut self, name: PlSmallStr, frame: LazyFrame) -> Option<Arc<TableSnapshot>> {
+        self.last_version += 1;
+        let snapshot = Arc::new(TableSnapshot {
+            name: name.clone(),
+            version: self.last_version,
+            frame,
+        });
+        self.tables.tables.insert(name, snapshot)
+    }
+}
+
+/// A thread-safe registry of named tables.
+///
+/// Services register their [`DataFrame`]s (via [`IntoLazy::lazy`]) or [`LazyFrame`]s under a
+/// name and atomically swap them on refresh. Concurrent queries take cheap snapshots, which are
+/// not affected by later changes to the registry.
+#[derive(Default)]
+pub struct TableRegistry {
+    state: RwLock<RegistryState>,
+}
+
+impl TableRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a new table, returning its version.
+    ///
+    /// Errors if a table with this name is already registered.
+    pub fn register(&self, name: impl Into<PlSmallStr>, frame: LazyFrame) -> PolarsResult<u64> {
+        let name = name.into();
+        let mut state = self.state.write().unwrap();
+        polars_ensure!(
+            !state.tables.tables.contains_key(&name),
+            Duplicate: "table '{name}' is already registered"
+        );
+        state.insert(name, frame);
+        Ok(state.last_version)
+    }
+
+    /// Atomically replace a registered table, returning the replaced snapshot.
+    ///
+    /// If `expected_version` is given, the table is only replaced if its current version
+    /// matches, so that concurrent refreshes do not overwrite each other.
+    pub fn swap(
+        &self,
+        name: &str,
+        frame: LazyFrame,
+        expected_version: Option<u64>,
+    ) -> PolarsResult<Arc<TableSnapshot>> {
+        let mut state = self.state.write().unwrap();
+        let Some(current) = state.tables.get(name) else {
+            polars_bail!(ComputeError: "table '{name}' is not registered");
+        };
+        if let Some(expected) = expected_version
+            && current.version != expected
+        {
+            polars_bail!(
+                ComputeError: "table '{name}' has version {}, expected version {expected}",
+                current.version
+            );
+        }
+        let name = current.name.clone();
+        Ok(state.insert(name, frame).unwrap())
+    }
+
+    /// Remove a table from the registry, returning its last snapshot.
+    ///
+    /// Queries holding on to a snapshot of the table are not affected.
+    pub fn remove(&self, name: &str) -> Option<Arc<TableSnapshot>> {
+        let mut state = self.state.write().unwrap();
+        state.tables.tables.shift_remove(name)
+    }
+
+    /// The current snapshot of a table.
+    pub fn get(&self, name: &str) -> Option<Arc<TableSnapshot>> {
+        self.state.read().unwrap().tables.get(name).cloned()
+    }
+
+    /// A consistent snapshot of all registered tables.
+    pub fn snapshot(&self) -> RegistrySnapshot {
+        self.state.read().unwrap().tables.clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_table_registry() -> PolarsResult<()> {
+        let registry = TableRegistry::new();
+        let v1 = registry.register("t", df!["a" => [1, 2]]?.lazy())?;
+        assert!(registry.register("t", df!["a" => [3]]?.lazy()).is_err());
+
+        let snapshot = registry.snapshot();
+        let t = registry.get("t").unwrap();
+
+        assert!(registry.swap("t", df!["a" => [3]]?.lazy(), Some(v1 + 1)).is_err());
+        let old = registry.swap("t", df!["a" => [3]]?.lazy(), Some(v1))?;
+        assert_eq!(old.version(), v1);
+        assert!(registry.get("t").unwrap().version() > v1);
+
+        // Snapshots taken before the swap still see the old table.
+        assert_eq!(t.lazy().collect()?.height(), 2);
+        assert_eq!(snapshot.get("t").unwrap().lazy().collect()?.height(), 2);
+        assert_eq!(registry.get("t").unwrap().lazy().collect()?.height(), 1);
+
+        assert!(registry.remove("t").is_some());
+        assert!(registry.snapshot().is_empty());
+        assert!(registry.swap("t", df!["a" => [3]]?.lazy(), None).is_err());
+        Ok(())
+    }
+}
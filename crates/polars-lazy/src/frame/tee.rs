@@ -0,0 +1,80 @@
+use super::*;
+
+type TeeQuery = Box<dyn FnOnce(LazyFrame) -> LazyFrame + Send>;
+
+/// A sink of [`LazyFrame::sink_multiple`].
+pub struct TeeTarget {
+    query: Option<TeeQuery>,
+    destination: SinkDestination,
+    file_format: Arc<FileType>,
+    unified_sink_args: UnifiedSinkArgs,
+}
+
+impl TeeTarget {
+    pub fn new(
+        destination: SinkDestination,
+        file_format: impl Into<Arc<FileType>>,
+        unified_sink_args: UnifiedSinkArgs,
+    ) -> Self {
+        Self {
+            query: None,
+            destination,
+            file_format: file_format.into(),
+            unified_sink_args,
+        }
+    }
+
+    /// Apply `query` to the shared output before writing it, e.g. to aggregate it.
+    pub fn with_query<F>(mut self, query: F) -> Self
+    where
+        F: FnOnce(LazyFrame) -> LazyFrame + Send + 'static,
+    {
+        self.query = Some(Box::new(query));
+        self
+    }
+}
+
+impl LazyFrame {
+    /// Execute the query once and write its output to all `targets`.
+    ///
+    /// The part of the plan that the targets share is executed only once, and its output is
+    /// fanned out to the sinks. [`Engine::Auto`] selects the streaming engine.
+    pub fn sink_multiple(self, targets: Vec<TeeTarget>, engine: Engine) -> PolarsResult<()> {
+        polars_ensure!(
+            !targets.is_empty(),
+            InvalidOperation: "`sink_multiple` requires at least one target"
+        );
+        polars_ensure!(
+            !matches!(self.logical_plan, DslPlan::Sink { .. }),
+            InvalidOperation: "cannot create a sink on top of another sink"
+        );
+
+        let opt_state = self.opt_state | OptFlags::COMM_SUBPLAN_ELIM;
+        let plans = targets
+            .into_iter()
+            .map(|target| {
+                let lf = match target.query {
+                    Some(query) => query(self.clone()),
+                    None => self.clone(),
+                };
+                let lf = lf.sink(
+                    target.destination,
+                    target.file_format,
+                    target.unified_sink_args,
+                )?;
+                Ok(lf.logical_plan)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let engine = match engine {
+            Engine::Auto => Engine::Streaming,
+            engine => engine,
+        };
+        let collect = || Self::collect_all_with_engine(plans, engine, opt_state);
+        match &self.thread_pool {
+            Some(pool) => POOL.install_scoped(pool, collect)?,
+            None => collect()?,
+        };
+        Ok(())
+    }
+}
@@ -7,15 +7,26 @@ pub use polars_io::ipc::IpcWriterOptions;
 pub use polars_io::json::JsonWriterOptions;
 #[cfg(feature = "parquet")]
 pub use polars_io::parquet::write::ParquetWriteOptions;
+#[cfg(feature = "array_linalg")]
+pub use polars_ops::chunked_array::array::VectorNorm;
 pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
 #[cfg(feature = "rank")]
 pub use polars_ops::prelude::{RankMethod, RankOptions};
 #[cfg(feature = "polars_cloud_client")]
 pub use polars_plan::client::prepare_cloud_plan;
 pub use polars_plan::dsl::AnonymousScanOptions;
-pub use polars_plan::plans::{AnonymousScan, AnonymousScanArgs, Literal, LiteralValue, NULL, Null};
+pub use polars_plan::plans::accelerator::{
+    AcceleratedPlan, Accelerator, AcceleratorCapabilities, register_accelerator,
+    unregister_accelerator,
+};
+pub use polars_plan::plans::{
+    AnonymousScan, AnonymousScanArgs, ColumnLineage, ColumnPosition, Literal, LiteralValue, NULL,
+    Null, PlanEstimate, PruningReport, SourceColumn, SourceEstimate, SourcePruning,
+};
 pub(crate) use polars_plan::prelude::*;
 pub use polars_plan::prelude::{PlanCallback, UnionArgs};
+#[cfg(feature = "checkpoint")]
+pub use polars_stream::CheckpointOptions;
 #[cfg(feature = "rolling_window_by")]
 pub use polars_time::Duration;
 #[cfg(feature = "dynamic_group_by")]
@@ -58,6 +58,7 @@ impl LazyFrame {
                 cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
                 missing_columns_policy: MissingColumnsPolicy::Raise,
                 extra_columns_policy: ExtraColumnsPolicy::Raise,
+                schema_unification_policy: SchemaUnificationPolicy::FirstFile,
                 include_file_paths: None,
                 deletion_files: None,
                 table_statistics: None,
@@ -3,7 +3,7 @@ use arrow::buffer::Buffer;
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::csv::read::{
-    CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues,
+    ColumnNamePolicy, CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues,
     read_until_start_and_infer_schema,
 };
 use polars_io::path_utils::expand_paths;
@@ -14,6 +14,7 @@ use polars_utils::plpath::PlPath;
 use polars_utils::slice_enum::Slice;
 
 use crate::prelude::*;
+use crate::scan::follow::{FollowFormat, FollowScan};
 
 #[derive(Clone)]
 #[cfg(feature = "csv")]
@@ -24,6 +25,7 @@ pub struct LazyCsvReader {
     read_options: CsvReadOptions,
     cloud_options: Option<CloudOptions>,
     include_file_paths: Option<PlSmallStr>,
+    follow: Option<FollowOptions>,
 }
 
 #[cfg(feature = "csv")]
@@ -49,6 +51,7 @@ impl LazyCsvReader {
             read_options: Default::default(),
             cloud_options: Default::default(),
             include_file_paths: None,
+            follow: None,
         }
     }
 
@@ -139,6 +142,20 @@ impl LazyCsvReader {
         self
     }
 
+    /// Set how duplicate and empty column names in the header are handled.
+    #[must_use]
+    pub fn with_column_name_policy(mut self, column_name_policy: ColumnNamePolicy) -> Self {
+        self.read_options.column_name_policy = column_name_policy;
+        self
+    }
+
+    /// Normalize the column names in the header to the Unicode NFC form.
+    #[must_use]
+    pub fn with_normalize_column_names(mut self, normalize_column_names: bool) -> Self {
+        self.read_options.normalize_column_names = normalize_column_names;
+        self
+    }
+
     /// Sets the chunk size used by the parser. This influences performance.
     /// This can be used as a way to reduce memory usage during the parsing at the cost of performance.
     pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
@@ -261,7 +278,7 @@ impl LazyCsvReader {
             let (inferred_schema, _) =
                 read_until_start_and_infer_schema(&self.read_options, None, None, &mut reader)?;
 
-            PolarsResult::Ok(inferred_schema)
+            PolarsResult::Ok(inferred_schema.schema)
         };
 
         let schema = match self.sources.clone() {
@@ -316,11 +333,40 @@ impl LazyCsvReader {
         self.include_file_paths = include_file_paths;
         self
     }
+
+    /// Keep the query running and read the rows that are appended to the file, like `tail -f`.
+    ///
+    /// The file is read from the start, after which the scan waits for complete lines to be
+    /// appended. The scan is unbounded and must be collected with the streaming engine; set
+    /// [`Self::with_n_rows`] to stop after a number of rows. Requires a single local file.
+    #[must_use]
+    pub fn with_follow(mut self, follow: Option<FollowOptions>) -> Self {
+        self.follow = follow;
+        self
+    }
+
+    fn finish_follow(mut self, follow: FollowOptions) -> PolarsResult<LazyFrame> {
+        let path = FollowScan::path(&self.sources)?;
+        let row_index = self.read_options.row_index.take();
+        let n_rows = self.read_options.n_rows.take();
+        let include_file_paths = self.include_file_paths.take();
+
+        let schema = match self.read_options.schema.clone() {
+            Some(schema) => schema,
+            None => self.clone().finish()?.collect_schema()?,
+        };
+        let format = FollowFormat::Csv(self.read_options);
+        FollowScan::new(path, format, schema, follow).finish(row_index, n_rows, include_file_paths)
+    }
 }
 
 impl LazyFileListReader for LazyCsvReader {
     /// Get the final [LazyFrame].
-    fn finish(self) -> PolarsResult<LazyFrame> {
+    fn finish(mut self) -> PolarsResult<LazyFrame> {
+        if let Some(follow) = self.follow.take() {
+            return self.finish_follow(follow);
+        }
+
         let rechunk = self.rechunk();
         let row_index = self.row_index().cloned();
         let pre_slice = self.n_rows().map(|len| Slice::Positive { offset: 0, len });
@@ -344,6 +390,7 @@ impl LazyFileListReader for LazyCsvReader {
                 cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
                 missing_columns_policy: MissingColumnsPolicy::Raise,
                 extra_columns_policy: ExtraColumnsPolicy::Raise,
+                schema_unification_policy: SchemaUnificationPolicy::FirstFile,
                 include_file_paths: self.include_file_paths,
                 deletion_files: None,
                 table_statistics: None,
@@ -0,0 +1,261 @@
+// The batches of the scan are only read by the streaming engine.
+#![cfg_attr(not(feature = "new_streaming"), allow(unused))]
+use std::any::Any;
+use std::fs::{File, Metadata};
+use std::io::{Cursor, ErrorKind, Read};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use polars_core::prelude::*;
+#[cfg(feature = "csv")]
+use polars_io::csv::read::CsvReadOptions;
+#[cfg(feature = "json")]
+use polars_io::ndjson::core::JsonLineReader;
+#[cfg(feature = "new_streaming")]
+use polars_io::pl_async;
+use polars_io::{RowIndex, SerReader};
+
+use crate::prelude::*;
+
+/// Options to keep reading a file as it is appended to, like `tail -f`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FollowOptions {
+    /// How long to wait before checking the file for new data again.
+    pub poll_interval: Duration,
+    /// Start reading from the beginning of the file at the path again when it is replaced,
+    /// e.g. by log rotation, or truncated.
+    pub detect_rotation: bool,
+}
+
+impl Default for FollowOptions {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_secs(1),
+            detect_rotation: true,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(super) enum FollowFormat {
+    #[cfg(feature = "csv")]
+    Csv(CsvReadOptions),
+    #[cfg(feature = "json")]
+    NDJson { ignore_errors: bool },
+}
+
+impl FollowFormat {
+    /// Parse complete lines, of which the first is the first line of the file if `at_start`.
+    fn parse(&self, lines: Vec<u8>, at_start: bool, schema: &SchemaRef) -> PolarsResult<DataFrame> {
+        match self {
+            #[cfg(feature = "csv")]
+            Self::Csv(options) => {
+                let mut options = options
+                    .clone()
+                    .with_schema(Some(schema.clone()))
+                    .with_raise_if_empty(false);
+                if !at_start {
+                    options = options
+                        .with_has_header(false)
+                        .with_skip_rows(0)
+                        .with_skip_lines(0)
+                        .with_skip_rows_after_header(0);
+                }
+                options
+                    .into_reader_with_file_handle(Cursor::new(lines))
+                    .finish()
+            },
+            #[cfg(feature = "json")]
+            Self::NDJson { ignore_errors } => JsonLineReader::new(Cursor::new(lines))
+                .with_schema(schema.clone())
+                .with_ignore_errors(*ignore_errors)
+                .finish(),
+        }
+    }
+}
+
+/// The position of an execution of a [`FollowScan`] in the followed file.
+#[derive(Default)]
+struct FollowState {
+    file: Option<File>,
+    id: Option<(u64, u64)>,
+    offset: u64,
+    /// The bytes after the last newline, which are held back until the line is complete.
+    partial: Vec<u8>,
+    at_start: bool,
+}
+
+#[cfg(unix)]
+fn file_id(metadata: &Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+fn file_id(_metadata: &Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+impl FollowState {
+    /// Read the complete lines that were appended since the previous call, returning whether
+    /// they start at the beginning of the file.
+    fn poll(&mut self, path: &Path, detect_rotation: bool) -> PolarsResult<(Vec<u8>, bool)> {
+        let mut bytes = Vec::new();
+        if let Some(file) = &mut self.file {
+            self.offset += file.read_to_end(&mut bytes)? as u64;
+            if !bytes.is_empty() || !detect_rotation || !self.is_rotated(path)? {
+                return Ok(self.complete_lines(bytes));
+            }
+
+            // The file was replaced or truncated, the line it ended with is complete now.
+            self.file = None;
+            if !self.partial.is_empty() {
+                let mut line = std::mem::take(&mut self.partial);
+                line.push(b'\n');
+                return Ok((line, std::mem::take(&mut self.at_start)));
+            }
+        }
+
+        let mut file = match File::open(path) {
+            Ok(file) => file,
+            // The new file may not have been created yet while the file is rotated.
+            Err(err) if err.kind() == ErrorKind::NotFound && self.id.is_some() => {
+                return Ok((bytes, false));
+            },
+            Err(err) => return Err(err.into()),
+        };
+        self.id = file_id(&file.metadata()?);
+        self.offset = file.read_to_end(&mut bytes)? as u64;
+        self.file = Some(file);
+        self.at_start = true;
+        Ok(self.complete_lines(bytes))
+    }
+
+    fn is_rotated(&self, path: &Path) -> PolarsResult<bool> {
+        match std::fs::metadata(path) {
+            Ok(metadata) => Ok(file_id(&metadata) != self.id || metadata.len() < self.offset),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn complete_lines(&mut self, bytes: Vec<u8>) -> (Vec<u8>, bool) {
+        let mut lines = std::mem::take(&mut self.partial);
+        lines.extend_from_slice(&bytes);
+        match memchr::memrchr(b'\n', &lines) {
+            Some(end) => {
+                self.partial = lines.split_off(end + 1);
+                (lines, std::mem::take(&mut self.at_start))
+            },
+            None => {
+                self.partial = lines;
+                (Vec::new(), false)
+            },
+        }
+    }
+}
+
+/// Reads the lines that are appended to a file, as an unbounded source. Every execution follows
+/// the file from its start.
+pub(super) struct FollowScan {
+    path: PathBuf,
+    format: FollowFormat,
+    schema: SchemaRef,
+    options: FollowOptions,
+}
+
+impl FollowScan {
+    pub(super) fn new(
+        path: PathBuf,
+        format: FollowFormat,
+        schema: SchemaRef,
+        options: FollowOptions,
+    ) -> Self {
+        Self {
+            path,
+            format,
+            schema,
+            options,
+        }
+    }
+
+    /// Get the path of a scan in follow mode, which must be a single local file.
+    pub(super) fn path(sources: &ScanSources) -> PolarsResult<PathBuf> {
+        if let ScanSources::Paths(paths) = sources
+            && let [path] = &paths[..]
+            && let Some(path) = path.as_ref().as_local_path()
+        {
+            return Ok(path.to_path_buf());
+        }
+        polars_bail!(InvalidOperation: "follow mode requires the source to be a single local file")
+    }
+
+    /// Create the [`LazyFrame`], applying the options of the reader that the scan does not.
+    pub(super) fn finish(
+        self,
+        row_index: Option<RowIndex>,
+        n_rows: Option<usize>,
+        include_file_paths: Option<PlSmallStr>,
+    ) -> PolarsResult<LazyFrame> {
+        let path = self.path.to_string_lossy().into_owned();
+        let schema = self.schema.clone();
+        let mut lf = LazyFrame::anonymous_scan(
+            Arc::new(self),
+            ScanArgsAnonymous {
+                schema: Some(schema),
+                name: "FOLLOW SCAN",
+                ..Default::default()
+            },
+        )?;
+
+        if let Some(name) = include_file_paths {
+            lf = lf.with_column(lit(path).alias(name));
+        }
+        if let Some(row_index) = row_index {
+            lf = lf.with_row_index(row_index.name, Some(row_index.offset));
+        }
+        if let Some(n_rows) = n_rows {
+            lf = lf.slice(0, n_rows as IdxSize);
+        }
+        Ok(lf)
+    }
+}
+
+impl AnonymousScan for FollowScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, _scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        polars_bail!(
+            InvalidOperation:
+            "a scan in follow mode is unbounded and can only be collected with the streaming engine"
+        )
+    }
+
+    #[cfg(feature = "new_streaming")]
+    fn allows_batches(&self) -> bool {
+        true
+    }
+
+    #[cfg(feature = "new_streaming")]
+    fn batch_reader(&self) -> PolarsResult<Option<AnonymousBatchReader>> {
+        let path = self.path.clone();
+        let format = self.format.clone();
+        let schema = self.schema.clone();
+        let options = self.options.clone();
+        let mut state = FollowState::default();
+
+        Ok(Some(Box::new(move || {
+            let (lines, at_start) = state.poll(&path, options.detect_rotation)?;
+            if lines.is_empty() {
+                // Produce an empty batch rather than to block until data arrives, so that the
+                // query can stop in between polls, e.g. once a slice is satisfied.
+                let sleep = tokio::time::sleep(options.poll_interval);
+                pl_async::get_runtime().block_in_place_on(sleep);
+                return Ok(Some(DataFrame::empty_with_arc_schema(schema.clone())));
+            }
+            format.parse(lines, at_start, &schema).map(Some)
+        })))
+    }
+}
@@ -0,0 +1,62 @@
+use std::any::Any;
+use std::sync::Mutex;
+
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_io::http_json::{HttpJsonScanOptions, HttpJsonSource};
+
+use crate::prelude::*;
+
+struct HttpJsonScan {
+    source: Mutex<HttpJsonSource>,
+}
+
+impl AnonymousScan for HttpJsonScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let mut dfs = Vec::new();
+        while let Some(df) = self.next_batch()? {
+            dfs.push(df);
+        }
+
+        if dfs.is_empty() {
+            return Ok(DataFrame::empty_with_schema(&scan_opts.schema));
+        }
+        Ok(accumulate_dataframes_vertical_unchecked(dfs))
+    }
+
+    fn allows_batches(&self) -> bool {
+        true
+    }
+
+    fn next_batch(&self) -> PolarsResult<Option<DataFrame>> {
+        self.source.lock().unwrap().next_batch()
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame that reads the records of a paginated JSON API over HTTP(S).
+    ///
+    /// Unless a schema is given, the first page is requested when the LazyFrame is created, to
+    /// infer the schema. The other pages are requested one at a time by the first execution of
+    /// the query.
+    pub fn scan_http_json(options: HttpJsonScanOptions) -> PolarsResult<Self> {
+        let source = HttpJsonSource::try_new(options)?;
+        let schema = source.schema().clone();
+        let function = Arc::new(HttpJsonScan {
+            source: Mutex::new(source),
+        });
+
+        Self::anonymous_scan(
+            function,
+            ScanArgsAnonymous {
+                schema: Some(schema),
+                name: "HTTP JSON SCAN",
+                ..Default::default()
+            },
+        )
+    }
+}
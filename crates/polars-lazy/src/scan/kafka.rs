@@ -0,0 +1,77 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_io::kafka::{KafkaScanOptions, KafkaSource};
+
+use crate::prelude::*;
+
+/// Creates a new consumer for every execution, so that the consumer and its assigned partitions
+/// don't outlive the query.
+struct KafkaScan {
+    options: KafkaScanOptions,
+}
+
+impl AnonymousScan for KafkaScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            !self.options.is_unbounded(),
+            InvalidOperation:
+            "an unbounded Kafka scan can only be collected with the streaming engine"
+        );
+
+        let mut source = KafkaSource::try_new(self.options.clone())?;
+        let mut dfs = Vec::new();
+        while let Some(df) = source.next_batch()? {
+            dfs.push(df);
+        }
+
+        if dfs.is_empty() {
+            return Ok(DataFrame::empty_with_schema(&scan_opts.schema));
+        }
+        Ok(accumulate_dataframes_vertical_unchecked(dfs))
+    }
+
+    fn allows_batches(&self) -> bool {
+        true
+    }
+
+    fn batch_reader(&self) -> PolarsResult<Option<AnonymousBatchReader>> {
+        let mut source = KafkaSource::try_new(self.options.clone())?;
+        Ok(Some(Box::new(move || source.next_batch())))
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame that reads the messages of a Kafka topic.
+    ///
+    /// The topic is read from the committed offsets of the consumer group, which are advanced
+    /// as the batches are read. With a bounded [`KafkaReadMode`], every collect of a query on
+    /// the same group reads the messages that arrived since the previous collect; an unbounded
+    /// scan keeps producing batches and requires the streaming engine, e.g. to sink the
+    /// results.
+    ///
+    /// The schema is resolved when the LazyFrame is created. The consumer is created, and the
+    /// partitions are assigned, by every execution of the query.
+    ///
+    /// [`KafkaReadMode`]: polars_io::kafka::KafkaReadMode
+    pub fn scan_kafka(mut options: KafkaScanOptions) -> PolarsResult<Self> {
+        // Every execution decodes the messages with the schema of the plan.
+        let schema = options.resolve_schema()?;
+        options.schema = Some(schema.clone());
+        let function = Arc::new(KafkaScan { options });
+
+        Self::anonymous_scan(
+            function,
+            ScanArgsAnonymous {
+                schema: Some(schema),
+                name: "KAFKA SCAN",
+                ..Default::default()
+            },
+        )
+    }
+}
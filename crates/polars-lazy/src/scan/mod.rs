@@ -2,12 +2,20 @@ pub(super) mod anonymous_scan;
 #[cfg(feature = "csv")]
 pub(super) mod csv;
 pub(super) mod file_list_reader;
+#[cfg(any(feature = "csv", feature = "json"))]
+pub(super) mod follow;
+#[cfg(feature = "http_json")]
+pub(super) mod http_json;
 #[cfg(feature = "ipc")]
 pub(super) mod ipc;
+#[cfg(feature = "kafka")]
+pub(super) mod kafka;
 #[cfg(feature = "json")]
 pub(super) mod ndjson;
 #[cfg(feature = "parquet")]
 pub(super) mod parquet;
+#[cfg(feature = "new_streaming")]
+pub(super) mod stream;
 
 #[cfg(feature = "catalog")]
 mod catalog;
@@ -11,8 +11,9 @@ use polars_plan::prelude::{NDJsonReadOptions, UnifiedScanArgs};
 use polars_utils::plpath::PlPath;
 use polars_utils::slice_enum::Slice;
 
-use crate::prelude::LazyFrame;
+use crate::prelude::{FollowOptions, LazyFrame};
 use crate::scan::file_list_reader::LazyFileListReader;
+use crate::scan::follow::{FollowFormat, FollowScan};
 
 #[derive(Clone)]
 pub struct LazyJsonLineReader {
@@ -28,6 +29,7 @@ pub struct LazyJsonLineReader {
     pub(crate) ignore_errors: bool,
     pub(crate) include_file_paths: Option<PlSmallStr>,
     pub(crate) cloud_options: Option<CloudOptions>,
+    pub(crate) follow: Option<FollowOptions>,
 }
 
 impl LazyJsonLineReader {
@@ -49,6 +51,7 @@ impl LazyJsonLineReader {
             n_rows: None,
             include_file_paths: None,
             cloud_options: None,
+            follow: None,
         }
     }
 
@@ -121,10 +124,41 @@ impl LazyJsonLineReader {
         self.include_file_paths = include_file_paths;
         self
     }
+
+    /// Keep the query running and read the lines that are appended to the file, like `tail -f`.
+    ///
+    /// The file is read from the start, after which the scan waits for complete lines to be
+    /// appended. The scan is unbounded and must be collected with the streaming engine; set
+    /// [`Self::with_n_rows`] to stop after a number of rows. Requires a single local file.
+    #[must_use]
+    pub fn with_follow(mut self, follow: Option<FollowOptions>) -> Self {
+        self.follow = follow;
+        self
+    }
+
+    fn finish_follow(mut self, follow: FollowOptions) -> PolarsResult<LazyFrame> {
+        let path = FollowScan::path(&self.sources)?;
+        let row_index = self.row_index.take();
+        let n_rows = self.n_rows.take();
+        let include_file_paths = self.include_file_paths.take();
+
+        let schema = match self.schema.clone() {
+            Some(schema) => schema,
+            None => self.clone().finish()?.collect_schema()?,
+        };
+        let format = FollowFormat::NDJson {
+            ignore_errors: self.ignore_errors,
+        };
+        FollowScan::new(path, format, schema, follow).finish(row_index, n_rows, include_file_paths)
+    }
 }
 
 impl LazyFileListReader for LazyJsonLineReader {
-    fn finish(self) -> PolarsResult<LazyFrame> {
+    fn finish(mut self) -> PolarsResult<LazyFrame> {
+        if let Some(follow) = self.follow.take() {
+            return self.finish_follow(follow);
+        }
+
         let unified_scan_args = UnifiedScanArgs {
             schema: None,
             cloud_options: self.cloud_options,
@@ -141,6 +175,7 @@ impl LazyFileListReader for LazyJsonLineReader {
             cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
             missing_columns_policy: MissingColumnsPolicy::Raise,
             extra_columns_policy: ExtraColumnsPolicy::Raise,
+            schema_unification_policy: SchemaUnificationPolicy::FirstFile,
             include_file_paths: self.include_file_paths,
             deletion_files: None,
             table_statistics: None,
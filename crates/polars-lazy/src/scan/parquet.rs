@@ -18,6 +18,8 @@ pub struct ScanArgsParquet {
     pub hive_options: HiveOptions,
     pub use_statistics: bool,
     pub schema: Option<SchemaRef>,
+    /// Overrides the dtypes of a subset of the columns.
+    pub schema_overwrite: Option<SchemaRef>,
     pub low_memory: bool,
     pub rechunk: bool,
     pub cache: bool,
@@ -25,6 +27,7 @@ pub struct ScanArgsParquet {
     pub glob: bool,
     pub include_file_paths: Option<PlSmallStr>,
     pub allow_missing_columns: bool,
+    pub schema_unification_policy: SchemaUnificationPolicy,
 }
 
 impl Default for ScanArgsParquet {
@@ -37,12 +40,14 @@ impl Default for ScanArgsParquet {
             hive_options: Default::default(),
             use_statistics: true,
             schema: None,
+            schema_overwrite: None,
             rechunk: false,
             low_memory: false,
             cache: true,
             glob: true,
             include_file_paths: None,
             allow_missing_columns: false,
+            schema_unification_policy: SchemaUnificationPolicy::FirstFile,
         }
     }
 }
@@ -67,6 +72,7 @@ impl LazyFileListReader for LazyParquetReader {
     fn finish(self) -> PolarsResult<LazyFrame> {
         let parquet_options = ParquetOptions {
             schema: self.args.schema,
+            schema_overwrite: self.args.schema_overwrite,
             parallel: self.args.parallel,
             low_memory: self.args.low_memory,
             use_statistics: self.args.use_statistics,
@@ -96,6 +102,7 @@ impl LazyFileListReader for LazyParquetReader {
                 MissingColumnsPolicy::Raise
             },
             extra_columns_policy: ExtraColumnsPolicy::Raise,
+            schema_unification_policy: self.args.schema_unification_policy,
             include_file_paths: self.args.include_file_paths,
             deletion_files: None,
             table_statistics: None,
@@ -0,0 +1,71 @@
+use std::any::Any;
+use std::sync::Mutex;
+
+use futures::StreamExt;
+use futures::stream::BoxStream;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_io::pl_async;
+
+use crate::prelude::*;
+
+/// Reads the [`DataFrame`]s produced by an async stream.
+struct StreamScan {
+    stream: Mutex<BoxStream<'static, DataFrame>>,
+}
+
+impl AnonymousScan for StreamScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let mut dfs = Vec::new();
+        while let Some(df) = self.next_batch()? {
+            dfs.push(df);
+        }
+
+        if dfs.is_empty() {
+            return Ok(DataFrame::empty_with_schema(&scan_opts.schema));
+        }
+        Ok(accumulate_dataframes_vertical_unchecked(dfs))
+    }
+
+    fn allows_batches(&self) -> bool {
+        true
+    }
+
+    fn next_batch(&self) -> PolarsResult<Option<DataFrame>> {
+        let mut stream = self.stream.lock().unwrap();
+        // Poll on the async runtime, the stream may depend on its reactor (e.g. for IO or timers).
+        Ok(pl_async::get_runtime().block_in_place_on(stream.next()))
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame that reads the [`DataFrame`]s produced by an async `stream`.
+    ///
+    /// With the streaming engine, the next batch is only polled once the previous batch has
+    /// been taken up by the query, which applies backpressure to the producer of the stream.
+    /// The in-memory engine collects the whole stream before executing the query.
+    ///
+    /// The stream is consumed by the first execution of the query; the batches must all have
+    /// the given `schema`.
+    pub fn scan_stream<S>(stream: S, schema: SchemaRef) -> PolarsResult<Self>
+    where
+        S: futures::Stream<Item = DataFrame> + Send + 'static,
+    {
+        let function = Arc::new(StreamScan {
+            stream: Mutex::new(stream.boxed()),
+        });
+
+        Self::anonymous_scan(
+            function,
+            ScanArgsAnonymous {
+                schema: Some(schema),
+                name: "STREAM SCAN",
+                ..Default::default()
+            },
+        )
+    }
+}
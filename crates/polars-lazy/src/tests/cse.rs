@@ -345,3 +345,36 @@ fn test_cse_prune_scan_filter_difference() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_cse_share_scans_filter_difference() -> PolarsResult<()> {
+    let lf = scan_foods_ipc().with_column(col("category").str().to_uppercase());
+
+    let q = lf
+        .clone()
+        .filter(col("fats_g").gt(2.0))
+        .left_join(
+            lf.clone().filter(col("fats_g").lt(1.0)),
+            col("fats_g"),
+            col("fats_g"),
+        )
+        .with_comm_subplan_elim(true);
+    let shared = q
+        .clone()
+        .with_optimizations(q.get_current_optimizations() | OptFlags::SHARE_SCANS);
+
+    // The caches are kept and the union of the predicates is pushed down.
+    assert_eq!(count_caches(shared.clone()), 2);
+    assert!(predicate_at_scan(shared.clone()));
+    assert!(shared.collect()?.equals_missing(&q.collect()?));
+
+    let plans = vec![
+        lf.clone().filter(col("fats_g").gt(2.0)).logical_plan,
+        lf.clone().filter(col("fats_g").lt(1.0)).logical_plan,
+    ];
+    let out = LazyFrame::collect_multiplexed(plans, Engine::InMemory, OptFlags::default())?;
+    assert!(out[0].equals(&lf.clone().filter(col("fats_g").gt(2.0)).collect()?));
+    assert!(out[1].equals(&lf.filter(col("fats_g").lt(1.0)).collect()?));
+
+    Ok(())
+}
@@ -411,6 +411,87 @@ fn test_scan_parquet_limit_9001() {
     });
 }
 
+#[test]
+fn test_parquet_estimate() -> PolarsResult<()> {
+    let q = scan_foods_parquet(false);
+    let file_size = std::fs::metadata(FOODS_PARQUET)?.len();
+
+    let estimate = q.clone().estimate()?;
+    assert_eq!(estimate.rows, Some(27));
+    assert_eq!(estimate.bytes_to_scan, Some(file_size));
+    assert_eq!(estimate.sources.len(), 1);
+    assert_eq!(estimate.sources[0].n_files, 1);
+
+    let estimate = q
+        .filter(col("calories").gt(lit(100)))
+        .select([col("category")])
+        .limit(5)
+        .estimate()?;
+    assert_eq!(estimate.rows, Some(5));
+    assert!(estimate.bytes_to_scan.unwrap() < file_size);
+
+    Ok(())
+}
+
+#[test]
+fn test_parquet_column_lineage() -> PolarsResult<()> {
+    let other = df![
+        "category" => ["fruit"],
+        "rating" => [5],
+    ]?;
+    let lineage = scan_foods_parquet(false)
+        .select([
+            col("category"),
+            (col("calories") + col("fats_g")).alias("energy"),
+            lit(1).alias("one"),
+        ])
+        .left_join(other.lazy(), col("category"), col("category"))
+        .column_lineage()?;
+
+    let columns = |name: &str| {
+        lineage[name]
+            .iter()
+            .map(|c| (c.source.is_some(), c.column.as_str()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        lineage.keys().map(|k| k.as_str()).collect::<Vec<_>>(),
+        ["category", "energy", "one", "rating"]
+    );
+    assert_eq!(columns("category"), [(true, "category"), (false, "category")]);
+    assert_eq!(columns("energy"), [(true, "calories"), (true, "fats_g")]);
+    assert!(columns("one").is_empty());
+    assert_eq!(columns("rating"), [(false, "rating")]);
+
+    Ok(())
+}
+
+#[test]
+fn test_parquet_pruning_report() -> PolarsResult<()> {
+    let other = df![
+        "category" => ["fruit"],
+        "rating" => [5],
+    ]?;
+    let report = scan_foods_parquet(false)
+        .left_join(other.lazy(), col("category"), col("category"))
+        .select([col("calories"), col("fats_g")])
+        .pruning_report()?;
+
+    let columns =
+        |source: &SourcePruning| source.columns.iter().map(|c| c.as_str()).collect::<Vec<_>>();
+    assert_eq!(report.sources.len(), 2);
+    let foods = report.sources.iter().find(|s| s.source.is_some()).unwrap();
+    assert_eq!(foods.n_columns, 4);
+    assert_eq!(columns(foods), ["category", "calories", "fats_g"]);
+    assert!(!foods.unused);
+    let other = report.sources.iter().find(|s| s.source.is_none()).unwrap();
+    assert_eq!(other.n_columns, 2);
+    assert_eq!(columns(other), ["category"]);
+    assert!(other.unused);
+
+    Ok(())
+}
+
 #[test]
 #[cfg(not(target_os = "windows"))]
 fn test_ipc_globbing() -> PolarsResult<()> {
@@ -806,3 +887,47 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_scan_csv_follow() -> PolarsResult<()> {
+    use std::io::Write;
+    use std::time::Duration;
+
+    let dir = std::env::temp_dir().join(format!("polars-follow-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join("data.csv");
+    // The last line is incomplete.
+    std::fs::write(&path, "a,b\n1,x\n2,y\n3")?;
+
+    let writer = {
+        let path = path.clone();
+        std::thread::spawn(move || -> std::io::Result<()> {
+            std::thread::sleep(Duration::from_millis(50));
+            let mut file = std::fs::OpenOptions::new().append(true).open(&path)?;
+            file.write_all(b",z\n")?;
+            std::thread::sleep(Duration::from_millis(50));
+            // Rotate the file.
+            std::fs::rename(&path, path.with_extension("csv.1"))?;
+            std::fs::write(&path, "a,b\n4,w\n")
+        })
+    };
+
+    let out = LazyCsvReader::new(PlPath::new(path.to_str().unwrap()))
+        .with_follow(Some(FollowOptions {
+            poll_interval: Duration::from_millis(10),
+            detect_rotation: true,
+        }))
+        .with_n_rows(Some(4))
+        .finish()?
+        .collect_with_engine(Engine::Streaming)?;
+    writer.join().unwrap()?;
+    std::fs::remove_dir_all(&dir)?;
+
+    let expected = df!(
+        "a" => [1i64, 2, 3, 4],
+        "b" => ["x", "y", "z", "w"],
+    )?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
@@ -2044,3 +2044,352 @@ fn test_named_udfs() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_execution_listener() -> PolarsResult<()> {
+    struct Listener(std::sync::Mutex<Vec<ExecutionRecord>>);
+
+    impl ExecutionListener for Listener {
+        fn on_execution(&self, record: &ExecutionRecord) -> PolarsResult<()> {
+            // Other tests run concurrently, only record our own query.
+            if record.plan.contains("listener_marker") {
+                self.0.lock().unwrap().push(record.clone());
+            }
+            Ok(())
+        }
+    }
+
+    /// Unregisters the listener when the test ends, also if it fails.
+    struct Registered(Arc<dyn ExecutionListener>);
+
+    impl Drop for Registered {
+        fn drop(&mut self) {
+            unregister_execution_listener(&self.0);
+        }
+    }
+
+    let listener = Arc::new(Listener(Default::default()));
+    let _registered = Registered(listener.clone());
+    register_execution_listener(listener.clone());
+
+    let q = scan_foods_csv()
+        .filter(col("calories").gt(lit(100)))
+        .select([col("category").alias("listener_marker")]);
+    q.clone().collect()?;
+    q.profile()?;
+
+    let records = listener.0.lock().unwrap();
+    assert_eq!(records.len(), 2);
+    for record in records.iter() {
+        assert_eq!(record.engine, Engine::InMemory);
+        assert_eq!(record.sources.len(), 1);
+        assert!(record.sources[0].first_path().unwrap().to_str().ends_with("foods1.csv"));
+        assert_eq!(record.row_counts.as_ref().unwrap(), &[11]);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_rename_with() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1],
+        "b" => ["x"],
+        "c" => [2],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .rename_with(
+            DataTypeSelector::Integer.as_selector(),
+            RenameAliasFn::Prefix("int_".into()),
+        )
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["int_a", "b", "int_c"]);
+
+    let upper = PlanCallback::new(|name: PlSmallStr| Ok(name.to_uppercase().into()));
+    let out = df
+        .lazy()
+        .rename_with(Selector::Wildcard, RenameAliasFn::Map(upper))
+        .select([col("C")])
+        .collect()?;
+    assert_eq!(out, df!["C" => [2]]?);
+    Ok(())
+}
+
+#[test]
+fn test_move_columns() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1],
+        "b" => ["x"],
+        "c" => [2],
+        "d" => ["y"],
+    ]?;
+    let by_name = |names: &[&str]| Selector::ByName {
+        names: names.iter().map(|n| PlSmallStr::from(*n)).collect(),
+        strict: true,
+    };
+
+    let out = df
+        .clone()
+        .lazy()
+        .move_columns(by_name(&["d", "b"]), ColumnPosition::First)
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["d", "b", "a", "c"]);
+
+    let out = df
+        .clone()
+        .lazy()
+        .move_columns(
+            DataTypeSelector::Integer.as_selector(),
+            ColumnPosition::After("d".into()),
+        )
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["b", "d", "a", "c"]);
+
+    let out = df
+        .clone()
+        .lazy()
+        .move_columns(by_name(&["a"]), ColumnPosition::Index(-1))
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["b", "c", "a", "d"]);
+
+    let out = df
+        .clone()
+        .lazy()
+        .insert_column_at(1, lit(3).alias("e"))?
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["a", "e", "b", "c", "d"]);
+
+    let moved_relative_to_itself = df
+        .lazy()
+        .move_columns(by_name(&["a"]), ColumnPosition::Before("a".into()))
+        .collect();
+    assert!(moved_relative_to_itself.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_accelerator_claims_subplan() -> PolarsResult<()> {
+    use arrow::record_batch::RecordBatch;
+
+    struct Output;
+
+    impl AcceleratedPlan for Output {
+        fn execute(&self) -> PolarsResult<Vec<RecordBatch>> {
+            let df = df!["accelerator_marker" => [42]]?;
+            Ok(df.iter_chunks(CompatLevel::newest(), false).collect())
+        }
+    }
+
+    struct TestAccelerator;
+
+    impl Accelerator for TestAccelerator {
+        fn name(&self) -> &str {
+            "test-accelerator"
+        }
+
+        fn capabilities(&self) -> AcceleratorCapabilities {
+            AcceleratorCapabilities {
+                nodes: ["df", "filter", "projection", "simple_projection"]
+                    .into_iter()
+                    .collect(),
+                udfs: false,
+            }
+        }
+
+        fn claim(
+            &self,
+            root: Node,
+            lp_arena: &Arena<IR>,
+            _expr_arena: &Arena<AExpr>,
+        ) -> PolarsResult<Option<Arc<dyn AcceleratedPlan>>> {
+            // Other tests run concurrently, only claim our own query.
+            let schema = lp_arena.get(root).schema(lp_arena);
+            if !schema.contains("accelerator_marker") {
+                return Ok(None);
+            }
+            Ok(Some(Arc::new(Output)))
+        }
+    }
+
+    let q = df!["accelerator_marker" => [1, 2, 3]]?
+        .lazy()
+        .filter(col("accelerator_marker").gt(lit(1)))
+        .select([col("accelerator_marker") * lit(2)]);
+
+    register_accelerator(Arc::new(TestAccelerator));
+    let out = q.clone().collect();
+    assert!(unregister_accelerator("test-accelerator"));
+    assert_eq!(out?, df!["accelerator_marker" => [42]]?);
+
+    assert_eq!(q.collect()?, df!["accelerator_marker" => [4, 6]]?);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "pivot")]
+fn test_lazy_pivot_pushdown() -> PolarsResult<()> {
+    let df = df![
+        "id" => [1, 1, 2, 2, 3],
+        "kind" => ["a", "b", "a", "c", "a"],
+        "value" => [1, 2, 3, 4, 5],
+        "unused" => [0, 0, 0, 0, 0],
+    ]?;
+    let categories = df!["kind" => ["a", "b"]]?;
+
+    let q = df
+        .lazy()
+        .pivot(
+            by_name(["kind"], true),
+            Arc::new(categories),
+            by_name(["id"], true),
+            by_name(["value"], true),
+            element().sum(),
+            true,
+            "_".into(),
+        )
+        .filter(col("id").lt(lit(3)));
+
+    // The schema is known without looking at the data.
+    let schema = q.clone().collect_schema()?;
+    assert_eq!(schema.iter_names().collect::<Vec<_>>(), &["id", "a", "b"]);
+    assert!(predicate_at_scan(q.clone()));
+
+    let out = q.collect()?;
+    let expected = df![
+        "id" => [1, 2],
+        "a" => [1, 3],
+        "b" => [2, 0],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_scan_stream() -> PolarsResult<()> {
+    let batches = (0..4)
+        .map(|i| df!("a" => [i, i + 10]).unwrap())
+        .collect::<Vec<_>>();
+    let schema = batches[0].schema().clone();
+
+    for engine in [Engine::InMemory, Engine::Streaming] {
+        let stream = futures::stream::iter(batches.clone());
+        let out = LazyFrame::scan_stream(stream, schema.clone())?
+            .filter(col("a").gt(lit(1)))
+            .collect_with_engine(engine)?;
+
+        let a = out.column("a")?.i32()?.into_no_null_iter().collect::<Vec<_>>();
+        assert_eq!(a, [10, 11, 2, 12, 3, 13]);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_lazy_cast_with_policy() -> PolarsResult<()> {
+    let lf = df! {
+        "a" => ["1", "x", "3"],
+        "b" => [1i32, 2, 3],
+        "c" => [1.0, 2.0, 3.0],
+    }?
+    .lazy();
+    let schema = Schema::from_iter([
+        Field::new("a".into(), DataType::Int64),
+        Field::new("b".into(), DataType::Int64),
+    ]);
+
+    let (out, report) = lf.clone().cast_with_policy(&schema, CastPolicy::Report)?;
+    let expected = df! {
+        "a" => [Some(1i64), None, Some(3)],
+        "b" => [1i64, 2, 3],
+        "c" => [1.0, 2.0, 3.0],
+    }?;
+    assert!(out.collect()?.equals_missing(&expected));
+    let expected = df! {
+        "column" => ["a", "b"],
+        "from" => ["str", "i32"],
+        "to" => ["i64", "i64"],
+        "lossy_rows" => [1 as IdxSize, 0],
+    }?;
+    assert!(report.unwrap().collect()?.equals(&expected));
+
+    assert!(lf.clone().cast_with_policy(&schema, CastPolicy::Upcast).is_err());
+    let (out, report) = lf.cast_with_policy(&schema, CastPolicy::Strict)?;
+    assert!(report.is_none());
+    assert!(out.collect().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_expr_evaluator() -> PolarsResult<()> {
+    let schema = Schema::from_iter([
+        Field::new("a".into(), DataType::Int32),
+        Field::new("b".into(), DataType::Float64),
+    ]);
+    let evaluator = ExprEvaluator::compile(
+        [
+            (col("a") + col("b")).alias("sum"),
+            col("a").max().alias("max"),
+            lit(1).alias("one"),
+        ],
+        &schema,
+    )?;
+    assert_eq!(
+        evaluator.schema().iter_names().collect::<Vec<_>>(),
+        ["sum", "max", "one"]
+    );
+
+    for n in [1, 3] {
+        let a = Column::new("a".into(), (0..n).collect::<Vec<i32>>());
+        let b = Column::new("b".into(), vec![0.5; n as usize]);
+        let out = evaluator.evaluate(vec![a, b])?;
+        let sum = (0..n).map(|i| i as f64 + 0.5).collect::<Vec<_>>();
+        assert_eq!(out[0], Column::new("sum".into(), sum));
+        assert_eq!(out[1], Column::new("max".into(), vec![n - 1; n as usize]));
+        assert_eq!(out[2], Column::new("one".into(), vec![1i32; n as usize]));
+    }
+
+    let a = Column::new("a".into(), [1.0]);
+    let b = Column::new("b".into(), [1.0]);
+    assert!(evaluator.evaluate(vec![a, b]).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "describe")]
+fn test_lazy_summary() -> PolarsResult<()> {
+    let df = df! {
+        "a" => [Some(1.0), None, Some(3.0), Some(2.0)],
+        "b" => [true, false, true, true],
+        "c" => ["zz", "xx", "yy", "xx"],
+        "d" => [Some(4i64), Some(8), None, Some(6)],
+    }?;
+    let percentiles = [0.25, 0.5, 0.75];
+    let out = df.clone().lazy().summary(&percentiles)?.collect()?;
+    assert_eq!(out.column("statistic")?.str()?.get(6), Some("75%"));
+    assert!(out.equals_missing(&df.describe(&percentiles)?));
+    Ok(())
+}
+
+#[test]
+fn test_with_max_threads() -> PolarsResult<()> {
+    let num_threads = lit(1)
+        .map(
+            |_| Ok(Column::new("n".into(), [polars_core::POOL.current_num_threads() as u32])),
+            |_, field| Ok(Field::new(field.name().clone(), DataType::UInt32)),
+        )
+        .alias("n");
+    let out = df![
+        "a" => [1, 2, 3],
+    ]?
+    .lazy()
+    .with_max_threads(std::num::NonZeroUsize::new(2).unwrap())?
+    // The pool is kept by the frames created from this one.
+    .select([num_threads])
+    .collect()?;
+    assert_eq!(out.column("n")?.u32()?.get(0), Some(2));
+    Ok(())
+}
@@ -1,3 +1,5 @@
+use polars_core::utils::{IntFloatPromotion, NullPromotion, PromotionRules};
+
 use super::*;
 
 #[test]
@@ -46,3 +48,39 @@ fn test_schema_update_after_projection_pd() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_promotion_rules() -> PolarsResult<()> {
+    let rules = PromotionRules {
+        int_float: IntFloatPromotion::Float,
+        null: NullPromotion::Raise,
+        ..Default::default()
+    };
+    let args = UnionArgs {
+        to_supertypes: true,
+        promotion_rules: rules,
+        ..Default::default()
+    };
+    let ints = df!["x" => [1i64, 2]]?.lazy();
+    let floats = df!["x" => [2.0f32]]?.lazy();
+
+    let out = concat([ints.clone(), floats.clone()], args)?.collect()?;
+    assert_eq!(out.column("x")?.dtype(), &DataType::Float32);
+
+    let out = ints
+        .clone()
+        .join(
+            floats,
+            [col("x")],
+            [col("x")],
+            JoinArgs::new(JoinType::Inner).with_promotion_rules(rules),
+        )
+        .collect()?;
+    assert_eq!(out.height(), 1);
+
+    let nulls = Column::full_null("x".into(), 1, &DataType::Null)
+        .into_frame()
+        .lazy();
+    assert!(concat([ints, nulls], args)?.collect().is_err());
+    Ok(())
+}
@@ -1,13 +1,119 @@
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_expr::state::NullFilterAudit;
+use polars_expr::{ExpressionConversionState, create_physical_expr};
+use polars_plan::plans::expr_ir::ExprIR;
+use polars_plan::plans::predicates::aexpr_to_skip_batch_predicate;
+use polars_utils::format_pl_smallstr;
 
 use super::*;
 
+/// Skips the chunks of the input of which the zone maps show that none of their rows match the
+/// predicate.
+pub struct ZoneMapPredicate {
+    /// Evaluates to `true` on the statistics of the chunks that can be skipped.
+    skip_batch_predicate: Arc<dyn PhysicalExpr>,
+    live_columns: Vec<PlSmallStr>,
+}
+
+impl ZoneMapPredicate {
+    pub fn new(
+        predicate: &ExprIR,
+        expr_arena: &mut Arena<AExpr>,
+        schema: &Schema,
+    ) -> PolarsResult<Option<Self>> {
+        let live_columns = aexpr_to_leaf_names_iter(predicate.node(), expr_arena)
+            .cloned()
+            .collect::<PlIndexSet<_>>();
+        // Only numeric and temporal columns have zone maps.
+        let has_zone_maps = |name: &PlSmallStr| {
+            schema
+                .get(name)
+                .is_some_and(|dtype| dtype.to_physical().is_primitive_numeric())
+        };
+        if live_columns.is_empty() || !live_columns.iter().all(has_zone_maps) {
+            return Ok(None);
+        }
+        let Some(node) = aexpr_to_skip_batch_predicate(predicate.node(), expr_arena, schema) else {
+            return Ok(None);
+        };
+
+        let mut skip_batch_schema = Schema::with_capacity(1 + 3 * live_columns.len());
+        skip_batch_schema.insert(PlSmallStr::from_static("len"), IDX_DTYPE);
+        for col in &live_columns {
+            let dtype = schema.get(col).unwrap();
+            skip_batch_schema.insert(format_pl_smallstr!("{col}_min"), dtype.clone());
+            skip_batch_schema.insert(format_pl_smallstr!("{col}_max"), dtype.clone());
+            skip_batch_schema.insert(format_pl_smallstr!("{col}_nc"), IDX_DTYPE);
+        }
+        let expr = ExprIR::new(node, predicate.output_name_inner().clone());
+        let skip_batch_predicate = create_physical_expr(
+            &expr,
+            expr_arena,
+            &Arc::new(skip_batch_schema),
+            &mut ExpressionConversionState::new(true),
+        )?;
+        Ok(Some(Self {
+            skip_batch_predicate,
+            live_columns: live_columns.into_iter().collect(),
+        }))
+    }
+
+    /// Which of the aligned chunks of `df` can be skipped, or `None` if some of the live columns
+    /// have no zone maps.
+    fn skipped_chunks(
+        &self,
+        df: &DataFrame,
+        state: &ExecutionState,
+    ) -> PolarsResult<Option<Vec<bool>>> {
+        let num_chunks = df.first_col_n_chunks();
+        let mut columns = Vec::with_capacity(1 + 3 * self.live_columns.len());
+        for name in &self.live_columns {
+            let Some(s) = df.column(name)?.as_series() else {
+                return Ok(None);
+            };
+            let Some(zone_maps) = s.chunk_zone_maps() else {
+                return Ok(None);
+            };
+            if columns.is_empty() {
+                let len = s.chunk_lengths().map(|len| len as IdxSize).collect::<Vec<_>>();
+                columns.push(Column::new(PlSmallStr::from_static("len"), len));
+            }
+
+            let physical = s.dtype().to_physical();
+            let stat = |suffix: &str, values: Vec<AnyValue<'static>>| -> PolarsResult<Column> {
+                let name = format_pl_smallstr!("{name}_{suffix}");
+                let stat = Series::from_any_values_and_dtype(name, &values, &physical, true)?;
+                // SAFETY: the values are of the physical type of the column.
+                Ok(unsafe { stat.from_physical_unchecked(s.dtype())? }.into_column())
+            };
+            columns.push(stat("min", zone_maps.iter().map(|z| z.min.clone()).collect())?);
+            columns.push(stat("max", zone_maps.iter().map(|z| z.max.clone()).collect())?);
+            let null_counts = zone_maps
+                .iter()
+                .map(|z| z.null_count as IdxSize)
+                .collect::<Vec<_>>();
+            columns.push(Column::new(format_pl_smallstr!("{name}_nc"), null_counts));
+        }
+
+        let statistics = DataFrame::new(num_chunks, columns)?;
+        let skip = self.skip_batch_predicate.evaluate(&statistics, state)?;
+        let skip = skip.bool()?;
+        let skip = if skip.len() == 1 {
+            vec![skip.get(0) == Some(true); num_chunks]
+        } else {
+            skip.iter().map(|skip| skip == Some(true)).collect()
+        };
+        Ok(Some(skip))
+    }
+}
+
 pub struct FilterExec {
     pub(crate) predicate: Arc<dyn PhysicalExpr>,
     pub(crate) input: Box<dyn Executor>,
     // if the predicate contains a window function
     has_window: bool,
     streamable: bool,
+    zone_map_predicate: Option<ZoneMapPredicate>,
 }
 
 fn column_to_mask(c: &Column) -> PolarsResult<&BooleanChunked> {
@@ -24,12 +130,14 @@ impl FilterExec {
         input: Box<dyn Executor>,
         has_window: bool,
         streamable: bool,
+        zone_map_predicate: Option<ZoneMapPredicate>,
     ) -> Self {
         Self {
             predicate,
             input,
             has_window,
             streamable,
+            zone_map_predicate,
         }
     }
 
@@ -37,6 +145,7 @@ impl FilterExec {
         &mut self,
         df: DataFrame,
         state: &mut ExecutionState,
+        audit: Option<&NullFilterAudit>,
     ) -> PolarsResult<DataFrame> {
         if self.has_window {
             state.insert_has_window_function_flag()
@@ -45,23 +154,36 @@ impl FilterExec {
         if self.has_window {
             state.clear_window_expr_cache()
         }
+        let mask = column_to_mask(&c)?;
+        if let Some(audit) = audit {
+            audit.record(&df, mask)?;
+        }
 
         // @scalar-opt
         // @partition-opt
-        df.filter(column_to_mask(&c)?)
+        if state.is_small_batch(df.height()) {
+            df.filter_seq(mask)
+        } else {
+            df.filter(mask)
+        }
     }
 
     fn execute_chunks(
         &mut self,
         chunks: Vec<DataFrame>,
         state: &ExecutionState,
+        audit: Option<&NullFilterAudit>,
     ) -> PolarsResult<DataFrame> {
         let iter = chunks.into_par_iter().map(|df| {
             let c = self.predicate.evaluate(&df, state)?;
+            let mask = column_to_mask(&c)?;
+            if let Some(audit) = audit {
+                audit.record(&df, mask)?;
+            }
 
             // @scalar-opt
             // @partition-opt
-            df.filter(column_to_mask(&c)?)
+            df.filter(mask)
         });
         let df = POOL.install(|| iter.collect::<PolarsResult<Vec<_>>>())?;
         Ok(accumulate_dataframes_vertical_unchecked(df))
@@ -72,21 +194,61 @@ impl FilterExec {
         mut df: DataFrame,
         state: &mut ExecutionState,
     ) -> PolarsResult<DataFrame> {
+        let audit = state
+            .audit_null_filters()
+            .then(|| NullFilterAudit::new(format!("{}", self.predicate.as_ref())));
+        let audit = audit.as_ref();
+
+        // Skip the chunks of which the zone maps show that none of their rows match, which
+        // requires evaluating the (elementwise) predicate per chunk.
+        if let Some(zone_map_predicate) = &self.zone_map_predicate
+            && audit.is_none()
+            && df.first_col_n_chunks() > 1
+        {
+            df.align_chunks_par();
+            if let Some(skipped) = zone_map_predicate.skipped_chunks(&df, state)?
+                && skipped.contains(&true)
+            {
+                if state.verbose() {
+                    let num_skipped = skipped.iter().filter(|skip| **skip).count();
+                    eprintln!(
+                        "FilterExec: zone maps skipped {num_skipped} of {} chunks",
+                        skipped.len()
+                    );
+                }
+                let chunks = df
+                    .split_chunks()
+                    .zip(skipped)
+                    .filter_map(|(chunk, skip)| (!skip).then_some(chunk))
+                    .collect::<Vec<_>>();
+                return if chunks.is_empty() {
+                    Ok(df.clear())
+                } else {
+                    self.execute_chunks(chunks, state, None)
+                };
+            }
+        }
+
         let n_partitions = POOL.current_num_threads();
         // Vertical parallelism.
-        if self.streamable && df.height() > 0 {
+        let out = if self.streamable && df.height() > 0 && !state.is_small_batch(df.height()) {
             if df.first_col_n_chunks() > 1 {
                 let chunks = df.split_chunks().collect::<Vec<_>>();
-                self.execute_chunks(chunks, state)
+                self.execute_chunks(chunks, state, audit)
             } else if df.width() < n_partitions {
-                self.execute_hor(df, state)
+                self.execute_hor(df, state, audit)
             } else {
                 let chunks = df.split_chunks_by_n(n_partitions, true);
-                self.execute_chunks(chunks, state)
+                self.execute_chunks(chunks, state, audit)
             }
         } else {
-            self.execute_hor(df, state)
+            self.execute_hor(df, state, audit)
+        };
+
+        if let Some(audit) = audit {
+            audit.report();
         }
+        out
     }
 }
 
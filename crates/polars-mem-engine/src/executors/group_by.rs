@@ -10,15 +10,24 @@ pub(super) fn evaluate_aggs(
 ) -> PolarsResult<Vec<Column>> {
     POOL.install(|| {
         aggs.par_iter()
-            .map(|expr| {
-                let agg = expr.evaluate_on_groups(df, groups, state)?.finalize();
-                polars_ensure!(agg.len() == groups.len(), agg_len = agg.len(), groups.len());
-                Ok(agg)
-            })
+            .map(|expr| evaluate_agg(expr.as_ref(), df, groups, state))
             .collect::<PolarsResult<Vec<_>>>()
     })
 }
 
+fn evaluate_agg(
+    expr: &dyn PhysicalExpr,
+    df: &DataFrame,
+    groups: &GroupPositions,
+    state: &ExecutionState,
+) -> PolarsResult<Column> {
+    let agg = evaluate_timed(expr, df, state, || {
+        Ok(expr.evaluate_on_groups(df, groups, state)?.finalize())
+    })?;
+    polars_ensure!(agg.len() == groups.len(), agg_len = agg.len(), groups.len());
+    Ok(agg)
+}
+
 /// Take an input Executor and a multiple expressions
 pub struct GroupByExec {
     input: Box<dyn Executor>,
@@ -63,8 +72,9 @@ pub(super) fn group_by_helper(
     maintain_order: bool,
     slice: Option<(i64, usize)>,
 ) -> PolarsResult<DataFrame> {
-    df.rechunk_mut_par();
-    let gb = df.group_by_with_series(keys, true, maintain_order)?;
+    rechunk_mut(&mut df, state);
+    let small_batch = state.is_small_batch(df.height());
+    let gb = df.group_by_with_series(keys, !small_batch, maintain_order)?;
 
     if let Some(f) = apply {
         return gb.apply_sliced(slice, move |df| f.call(df));
@@ -81,13 +91,20 @@ pub(super) fn group_by_helper(
         groups = sliced_groups.as_ref().unwrap();
     }
 
-    let (mut columns, agg_columns) = POOL.install(|| {
-        let get_columns = || gb.keys_sliced(slice);
-
-        let get_agg = || evaluate_aggs(&df, aggs, groups, state);
-
-        rayon::join(get_columns, get_agg)
-    });
+    let get_columns = || gb.keys_sliced(slice);
+    let (mut columns, agg_columns) = if small_batch {
+        let agg_columns = aggs
+            .iter()
+            .map(|expr| evaluate_agg(expr.as_ref(), &df, groups, state))
+            .collect::<PolarsResult<Vec<_>>>();
+        (get_columns(), agg_columns)
+    } else {
+        POOL.install(|| {
+            let get_agg = || evaluate_aggs(&df, aggs, groups, state);
+
+            rayon::join(get_columns, get_agg)
+        })
+    };
 
     columns.extend(agg_columns?);
     DataFrame::new_infer_height(columns)
@@ -23,7 +23,7 @@ impl GroupByDynamicExec {
     ) -> PolarsResult<DataFrame> {
         use crate::executors::group_by_rolling::sort_and_groups;
 
-        df.rechunk_mut_par();
+        rechunk_mut(&mut df, state);
 
         let mut keys = self
             .keys
@@ -65,7 +65,7 @@ impl GroupByRollingExec {
         state: &ExecutionState,
         mut df: DataFrame,
     ) -> PolarsResult<DataFrame> {
-        df.rechunk_mut_par();
+        rechunk_mut(&mut df, state);
 
         let mut keys = self
             .keys
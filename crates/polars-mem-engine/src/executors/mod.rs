@@ -52,3 +52,12 @@ pub(super) use self::udf::*;
 pub(super) use self::union::*;
 pub(super) use self::unique::*;
 use crate::prelude::*;
+
+/// Rechunk `df` in place, on the thread pool unless it is a small batch.
+pub(super) fn rechunk_mut(df: &mut DataFrame, state: &ExecutionState) {
+    if state.is_small_batch(df.height()) {
+        df.rechunk_mut();
+    } else {
+        df.rechunk_mut_par();
+    }
+}
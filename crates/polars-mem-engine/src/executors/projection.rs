@@ -22,11 +22,12 @@ impl ProjectionExec {
         state: &ExecutionState,
         mut df: DataFrame,
     ) -> PolarsResult<DataFrame> {
+        let run_parallel = self.options.run_parallel && !state.is_small_batch(df.height());
         // Vertical and horizontal parallelism.
         let df = if self.allow_vertical_parallelism
             && df.first_col_n_chunks() > 1
             && df.height() > POOL.current_num_threads() * 2
-            && self.options.run_parallel
+            && run_parallel
         {
             let chunks = df.split_chunks().collect::<Vec<_>>();
             let iter = chunks.into_par_iter().map(|mut df| {
@@ -35,7 +36,7 @@ impl ProjectionExec {
                     &self.expr,
                     state,
                     self.has_windows,
-                    self.options.run_parallel,
+                    run_parallel,
                 )?;
                 check_expand_literals(
                     &df,
@@ -57,7 +58,7 @@ impl ProjectionExec {
                 &self.expr,
                 state,
                 self.has_windows,
-                self.options.run_parallel,
+                run_parallel,
             )?;
             check_expand_literals(
                 &df,
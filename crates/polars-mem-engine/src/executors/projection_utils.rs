@@ -13,6 +13,29 @@ pub(super) fn profile_name(
     }
 }
 
+/// Evaluate `expr` and, when profiling, record its execution time under its output name.
+pub(super) fn evaluate_timed<T>(
+    expr: &dyn PhysicalExpr,
+    df: &DataFrame,
+    state: &ExecutionState,
+    evaluate: impl FnOnce() -> PolarsResult<T>,
+) -> PolarsResult<T> {
+    if state.has_node_timer() {
+        let name = profile_name(expr, df.schema())?;
+        state.record(evaluate, Cow::Owned(format!("expr({name})")))
+    } else {
+        evaluate()
+    }
+}
+
+fn evaluate_expr(
+    expr: &Arc<dyn PhysicalExpr>,
+    df: &DataFrame,
+    state: &ExecutionState,
+) -> PolarsResult<Column> {
+    evaluate_timed(expr.as_ref(), df, state, || expr.evaluate(df, state))
+}
+
 type IdAndExpression = (u32, Arc<dyn PhysicalExpr>);
 
 #[cfg(feature = "dynamic_group_by")]
@@ -37,7 +60,7 @@ fn rolling_evaluate(
                 state.window_cache.insert_groups(groups_key, groups);
                 partition
                     .par_iter()
-                    .map(|(idx, expr)| expr.evaluate(df, &state).map(|s| (*idx, s)))
+                    .map(|(idx, expr)| evaluate_expr(expr, df, &state).map(|s| (*idx, s)))
                     .collect::<PolarsResult<Vec<_>>>()
             })
             .collect()
@@ -94,15 +117,16 @@ fn window_evaluate(
         //  rayon threads should not be blocked.
         if cache {
             let first = &partition[0];
-            let c = first.1.evaluate(df, &state)?;
+            let c = evaluate_expr(&first.1, df, &state)?;
             first_result = Some((first.0, c));
             state.insert_cache_window_flag();
         } else {
             state.remove_cache_window_flag();
         }
 
-        let apply =
-            |index: &u32, e: &Arc<dyn PhysicalExpr>| e.evaluate(df, &state).map(|c| (*index, c));
+        let apply = |index: &u32, e: &Arc<dyn PhysicalExpr>| {
+            evaluate_expr(e, df, &state).map(|c| (*index, c))
+        };
 
         let slice = &partition[first_result.is_some() as usize..];
         let mut results = if par_horizontal {
@@ -214,7 +238,7 @@ fn execute_projection_cached_window_fns(
     let mut selected_columns = POOL.install(|| {
         other
             .par_iter()
-            .map(|(idx, expr)| expr.evaluate(df, state).map(|s| (*idx, s)))
+            .map(|(idx, expr)| evaluate_expr(expr, df, state).map(|s| (*idx, s)))
             .collect::<PolarsResult<Vec<_>>>()
     })?;
 
@@ -258,7 +282,7 @@ fn run_exprs_par(
     POOL.install(|| {
         exprs
             .par_iter()
-            .map(|expr| expr.evaluate(df, state))
+            .map(|expr| evaluate_expr(expr, df, state))
             .collect()
     })
 }
@@ -268,7 +292,10 @@ fn run_exprs_seq(
     exprs: &[Arc<dyn PhysicalExpr>],
     state: &ExecutionState,
 ) -> PolarsResult<Vec<Column>> {
-    exprs.iter().map(|expr| expr.evaluate(df, state)).collect()
+    exprs
+        .iter()
+        .map(|expr| evaluate_expr(expr, df, state))
+        .collect()
 }
 
 pub(super) fn evaluate_physical_expressions(
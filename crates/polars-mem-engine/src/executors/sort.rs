@@ -16,7 +16,7 @@ impl SortExec {
         mut df: DataFrame,
     ) -> PolarsResult<DataFrame> {
         state.should_stop()?;
-        df.rechunk_mut_par();
+        rechunk_mut(&mut df, state);
 
         let height = df.height();
 
@@ -43,7 +43,9 @@ impl SortExec {
             })
             .collect::<PolarsResult<Vec<_>>>()?;
 
-        df.sort_impl(by_columns, self.sort_options.clone(), self.slice)
+        let mut sort_options = self.sort_options.clone();
+        sort_options.multithreaded &= !state.is_small_batch(height);
+        df.sort_impl(by_columns, sort_options, self.slice)
     }
 }
 
@@ -22,11 +22,12 @@ impl StackExec {
     ) -> PolarsResult<DataFrame> {
         let schema = &*self.output_schema;
 
+        let run_parallel = self.options.run_parallel && !state.is_small_batch(df.height());
         // Vertical and horizontal parallelism.
         let df = if self.allow_vertical_parallelism
             && df.first_col_n_chunks() > 1
             && df.height() > 0
-            && self.options.run_parallel
+            && run_parallel
         {
             let chunks = df.split_chunks().collect::<Vec<_>>();
             let iter = chunks.into_par_iter().map(|mut df| {
@@ -35,7 +36,7 @@ impl StackExec {
                     &self.exprs,
                     state,
                     self.has_windows,
-                    self.options.run_parallel,
+                    run_parallel,
                 )?;
                 // We don't have to do a broadcast check as cse is not allowed to hit this.
                 df.with_columns_mut(res, schema)?;
@@ -52,7 +53,7 @@ impl StackExec {
                 &self.exprs,
                 state,
                 self.has_windows,
-                self.options.run_parallel,
+                run_parallel,
             )?;
             if !self.options.should_broadcast {
                 debug_assert!(
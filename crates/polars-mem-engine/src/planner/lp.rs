@@ -328,13 +328,19 @@ fn create_physical_plan_impl(
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
             let input = recurse!(input, state)?;
             let mut state = ExpressionConversionState::new(true);
-            let predicate =
+            let phys_predicate =
                 create_physical_expr(&predicate, expr_arena, &input_schema, &mut state)?;
+            let zone_map_predicate = if streamable && !state.has_windows {
+                executors::ZoneMapPredicate::new(&predicate, expr_arena, &input_schema)?
+            } else {
+                None
+            };
             Ok(Box::new(executors::FilterExec::new(
-                predicate,
+                phys_predicate,
                 input,
                 state.has_windows,
                 streamable,
+                zone_map_predicate,
             )))
         },
         #[allow(unused_variables)]
@@ -1,28 +1,24 @@
-use std::cell::LazyCell;
+use std::borrow::Cow;
 use std::sync::Arc;
 
 use polars_core::config;
 use polars_core::error::PolarsResult;
-use polars_core::prelude::{IDX_DTYPE, IdxCa, InitHashMaps, PlHashMap, PlIndexMap, PlIndexSet};
+use polars_core::prelude::{IDX_DTYPE, PlHashMap, PlIndexSet};
 use polars_core::schema::Schema;
 use polars_error::polars_warn;
 use polars_expr::{ExpressionConversionState, create_physical_expr};
 use polars_io::predicates::ScanIOPredicate;
-use polars_plan::dsl::default_values::{
-    DefaultFieldValues, IcebergIdentityTransformedPartitionFields,
-};
-use polars_plan::dsl::deletion::DeletionFilesList;
-use polars_plan::dsl::{
-    FileScanIR, Operator, PredicateFileSkip, ScanSources, TableStatistics, UnifiedScanArgs,
-};
+use polars_plan::dsl::{Operator, PredicateFileSkip, ScanSources, TableStatistics};
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
 use polars_plan::plans::hive::HivePartitionsDf;
-use polars_plan::plans::predicates::{aexpr_to_column_predicates, aexpr_to_skip_batch_predicate};
-use polars_plan::plans::{AExpr, ExprIRDisplay, FileInfo, IR, MintermIter};
+use polars_plan::plans::predicates::{
+    aexpr_to_column_predicates, aexpr_to_skip_batch_predicate, replace_nested_statistics_paths,
+};
+use polars_plan::plans::{AExpr, ExprIRDisplay, IR, MintermIter, filter_scan_ir};
 use polars_plan::utils::aexpr_to_leaf_names_iter;
 use polars_utils::arena::{Arena, Node};
 use polars_utils::pl_str::PlSmallStr;
-use polars_utils::{IdxSize, format_pl_smallstr};
+use polars_utils::format_pl_smallstr;
 
 use crate::scan_predicate::skip_files_mask::SkipFilesMask;
 use crate::scan_predicate::{PhysicalColumnPredicates, ScanPredicate};
@@ -118,9 +114,20 @@ pub fn create_scan_predicate(
     ));
 
     let mut skip_batch_predicate = None;
+    let mut nested_statistics = vec![];
 
     if create_skip_batch_predicate {
-        if let Some(node) = aexpr_to_skip_batch_predicate(predicate.node(), expr_arena, schema) {
+        // Struct fields and list lengths are given their own statistics.
+        let (node, nested) = replace_nested_statistics_paths(predicate.node(), expr_arena, schema);
+        let sbp_input_schema = if nested.is_empty() {
+            Cow::Borrowed(schema.as_ref())
+        } else {
+            let mut schema = schema.as_ref().clone();
+            schema.extend(nested.iter().map(|(path, dtype)| (path.name(), dtype.clone())));
+            Cow::Owned(schema)
+        };
+
+        if let Some(node) = aexpr_to_skip_batch_predicate(node, expr_arena, &sbp_input_schema) {
             let expr = ExprIR::new(node, predicate.output_name_inner().clone());
 
             if std::env::var("POLARS_OUTPUT_SKIP_BATCH_PRED").as_deref() == Ok("1") {
@@ -140,6 +147,12 @@ pub fn create_scan_predicate(
                 skip_batch_schema.insert(format_pl_smallstr!("{col}_max"), dtype.clone());
                 skip_batch_schema.insert(format_pl_smallstr!("{col}_nc"), IDX_DTYPE);
             }
+            for (path, dtype) in nested.iter() {
+                let name = path.name();
+                skip_batch_schema.insert(format_pl_smallstr!("{name}_min"), dtype.clone());
+                skip_batch_schema.insert(format_pl_smallstr!("{name}_max"), dtype.clone());
+                skip_batch_schema.insert(format_pl_smallstr!("{name}_nc"), IDX_DTYPE);
+            }
 
             skip_batch_predicate = Some(create_physical_expr(
                 &expr,
@@ -147,6 +160,7 @@ pub fn create_scan_predicate(
                 &Arc::new(skip_batch_schema),
                 state,
             )?);
+            nested_statistics = nested;
         }
     }
 
@@ -200,6 +214,7 @@ pub fn create_scan_predicate(
         predicate: phys_predicate,
         live_columns,
         skip_batch_predicate,
+        nested_statistics: nested_statistics.into(),
         column_predicates,
         hive_predicate,
         hive_predicate_is_full_predicate,
@@ -259,7 +274,13 @@ pub fn initialize_scan_predicate<'a>(
 
             expected_mask_len = table_statsitics.0.height();
 
-            let exclusion_mask = skip_batch_predicate.evaluate_with_stat_df(&table_statsitics.0)?;
+            let exclusion_mask = if predicate.nested_statistics.is_empty() {
+                skip_batch_predicate.evaluate_with_stat_df(&table_statsitics.0)?
+            } else {
+                let mut statistics_df = table_statsitics.0.as_ref().clone();
+                predicate.add_unknown_nested_statistics(&mut statistics_df)?;
+                skip_batch_predicate.evaluate_with_stat_df(&statistics_df)?
+            };
 
             (SkipFilesMask::Exclusion(exclusion_mask), true)
         } else {
@@ -384,204 +405,3 @@ pub fn apply_scan_predicate_to_scan_ir(
 
     Ok(())
 }
-
-/// Filters the paths for a scan IR. This also involves performing selections on
-/// e.g. hive partitions, deletion files.
-///
-/// Note: `selected_path_indices` should be cheaply cloneable.
-///
-/// # Panics
-/// Panics if `scan_ir` is not `IR::Scan`.
-pub fn filter_scan_ir<I>(scan_ir: &mut IR, selected_path_indices: I)
-where
-    I: Iterator<Item = usize> + Clone,
-{
-    let IR::Scan {
-        sources,
-        file_info:
-            FileInfo {
-                schema: _,
-                reader_schema,
-                row_estimation,
-            },
-        hive_parts,
-        predicate: _,
-        predicate_file_skip_applied: _,
-        output_schema: _,
-        scan_type,
-        unified_scan_args,
-    } = scan_ir
-    else {
-        panic!("{:?}", scan_ir);
-    };
-
-    let size_hint = selected_path_indices.size_hint();
-
-    if size_hint.0 == sources.len()
-        && size_hint.1 == Some(sources.len())
-        && selected_path_indices
-            .clone()
-            .enumerate()
-            .all(|(i, x)| i == x)
-    {
-        return;
-    }
-
-    let UnifiedScanArgs {
-        schema: _,
-        cloud_options: _,
-        hive_options: _,
-        rechunk: _,
-        cache: _,
-        glob: _,
-        hidden_file_prefix: _,
-        projection: _,
-        column_mapping: _,
-        default_values,
-        // Ensure these are None.
-        row_index: None,
-        pre_slice: None,
-        cast_columns_policy: _,
-        missing_columns_policy: _,
-        extra_columns_policy: _,
-        include_file_paths: _,
-        table_statistics,
-        deletion_files,
-        row_count,
-    } = unified_scan_args.as_mut()
-    else {
-        panic!("{unified_scan_args:?}")
-    };
-
-    *row_count = None;
-
-    if selected_path_indices.clone().next() != Some(0) {
-        *reader_schema = None;
-
-        // Ensure the metadata is unset, otherwise it may incorrectly be used at
-        // scan. This is especially important for Parquet as it requires the
-        // correct `is_nullable` in the arrow field.
-        match scan_type.as_mut() {
-            #[cfg(feature = "parquet")]
-            FileScanIR::Parquet {
-                options: _,
-                metadata,
-            } => *metadata = None,
-
-            #[cfg(feature = "ipc")]
-            FileScanIR::Ipc {
-                options: _,
-                metadata,
-            } => *metadata = None,
-
-            #[cfg(feature = "csv")]
-            FileScanIR::Csv { options: _ } => {},
-
-            #[cfg(feature = "json")]
-            FileScanIR::NDJson { options: _ } => {},
-
-            #[cfg(feature = "python")]
-            FileScanIR::PythonDataset {
-                dataset_object: _,
-                cached_ir,
-            } => *cached_ir.lock().unwrap() = None,
-
-            #[cfg(feature = "scan_lines")]
-            FileScanIR::Lines { name: _ } => {},
-
-            FileScanIR::Anonymous {
-                options: _,
-                function: _,
-            } => {},
-        }
-    }
-
-    let selected_path_indices_idxsize = LazyCell::new(|| {
-        selected_path_indices
-            .clone()
-            .map(|i| IdxSize::try_from(i).unwrap())
-            .collect::<Vec<_>>()
-    });
-
-    *deletion_files = deletion_files.as_ref().and_then(|x| match x {
-        DeletionFilesList::IcebergPositionDelete(deletions) => {
-            let mut out = None;
-
-            for (out_idx, source_idx) in selected_path_indices.clone().enumerate() {
-                if let Some(v) = deletions.get(&source_idx) {
-                    out.get_or_insert_with(|| {
-                        PlIndexMap::with_capacity(selected_path_indices.size_hint().0 - out_idx)
-                    })
-                    .insert(out_idx, v.clone());
-                }
-            }
-
-            out.map(|x| DeletionFilesList::IcebergPositionDelete(Arc::new(x)))
-        },
-    });
-
-    *table_statistics = table_statistics.as_ref().map(|x| {
-        let df_height = IdxSize::try_from(x.0.height()).unwrap();
-
-        assert!(selected_path_indices_idxsize.iter().all(|x| *x < df_height));
-
-        TableStatistics(Arc::new(unsafe {
-            x.0.take_slice_unchecked(&selected_path_indices_idxsize)
-        }))
-    });
-
-    let original_sources_len = sources.len();
-    *sources = sources.gather(selected_path_indices.clone()).unwrap();
-    *row_estimation = (
-        None,
-        row_estimation
-            .1
-            .div_ceil(original_sources_len)
-            .saturating_mul(sources.len()),
-    );
-
-    *hive_parts = hive_parts.as_ref().map(|hp| {
-        let df = hp.df();
-        let df_height = IdxSize::try_from(df.height()).unwrap();
-
-        assert!(selected_path_indices_idxsize.iter().all(|x| *x < df_height));
-
-        // Safety: Asserted all < df.height() above.
-        unsafe { df.take_slice_unchecked(&selected_path_indices_idxsize) }.into()
-    });
-
-    *default_values = default_values.as_ref().map(|x| match x {
-        DefaultFieldValues::Iceberg(v) => {
-            let mut out = PlIndexMap::with_capacity(v.len());
-            let mut gather_indices = PlHashMap::with_capacity(v.len());
-
-            for (k, v) in v.iter() {
-                out.insert(
-                    *k,
-                    v.as_ref().map_err(Clone::clone).map(|partition_values| {
-                        if !gather_indices.contains_key(&partition_values.len()) {
-                            gather_indices.insert(
-                                partition_values.len(),
-                                selected_path_indices
-                                    .clone()
-                                    .map(|i| {
-                                        (i < partition_values.len())
-                                            .then(|| IdxSize::try_from(i).unwrap())
-                                    })
-                                    .collect::<IdxCa>(),
-                            );
-                        }
-
-                        unsafe {
-                            partition_values.take_unchecked(
-                                gather_indices.get(&partition_values.len()).unwrap(),
-                            )
-                        }
-                    }),
-                );
-            }
-
-            DefaultFieldValues::Iceberg(Arc::new(IcebergIdentityTransformedPartitionFields(out)))
-        },
-    });
-}
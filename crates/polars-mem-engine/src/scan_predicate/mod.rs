@@ -6,14 +6,17 @@ use std::sync::Arc;
 use arrow::bitmap::Bitmap;
 pub use functions::{create_scan_predicate, initialize_scan_predicate};
 use polars_core::frame::DataFrame;
-use polars_core::prelude::{AnyValue, Column, Field, GroupPositions, PlHashMap, PlIndexSet};
+use polars_core::prelude::{
+    AnyValue, Column, DataType, Field, GroupPositions, IDX_DTYPE, PlHashMap, PlIndexSet,
+};
 use polars_core::scalar::Scalar;
 use polars_core::schema::{Schema, SchemaRef};
 use polars_error::PolarsResult;
 use polars_expr::prelude::{AggregationContext, PhysicalExpr, phys_expr_to_io_expr};
 use polars_expr::state::ExecutionState;
 use polars_io::predicates::{
-    ColumnPredicates, ScanIOPredicate, SkipBatchPredicate, SpecializedColumnPredicate,
+    ColumnPredicates, NestedStatisticsPath, ScanIOPredicate, SkipBatchPredicate,
+    SpecializedColumnPredicate,
 };
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::{IdxSize, format_pl_smallstr};
@@ -34,6 +37,10 @@ pub struct ScanPredicate {
     /// `false` even when the batch could theoretically be skipped.
     pub skip_batch_predicate: Option<Arc<dyn PhysicalExpr>>,
 
+    /// Struct fields and list lengths the skip batch predicate needs statistics for, these are
+    /// given as additional columns named after the path.
+    pub nested_statistics: Arc<[(NestedStatisticsPath, DataType)]>,
+
     /// Partial predicates for each column for filter when loading columnar formats.
     pub column_predicates: PhysicalColumnPredicates,
 
@@ -122,6 +129,7 @@ impl ScanPredicate {
                 Default::default()
             });
 
+        let mut nested_statistics = self.nested_statistics.to_vec();
         let predicate_constants = constant_columns
             .filter_map(|(name, scalar): (PlSmallStr, Scalar)| {
                 if !live_columns.swap_remove(&name) {
@@ -129,6 +137,27 @@ impl ScanPredicate {
                 }
 
                 if self.skip_batch_predicate.is_some() {
+                    // The nested values of constant columns are not computed, so their statistics
+                    // are unknown.
+                    nested_statistics.retain(|(path, dtype)| {
+                        if path.column() != &name {
+                            return true;
+                        }
+                        let path_name = path.name();
+                        skip_batch_predicate_constants.extend([
+                            (
+                                format_pl_smallstr!("{path_name}_min"),
+                                Scalar::null(dtype.clone()),
+                            ),
+                            (
+                                format_pl_smallstr!("{path_name}_max"),
+                                Scalar::null(dtype.clone()),
+                            ),
+                            (format_pl_smallstr!("{path_name}_nc"), Scalar::null(IDX_DTYPE)),
+                        ]);
+                        false
+                    });
+
                     let mut null_count: Scalar = (0 as IdxSize).into();
 
                     // If the constant value is Null, we don't know how many nulls there are
@@ -163,6 +192,7 @@ impl ScanPredicate {
             predicate,
             live_columns: Arc::new(live_columns),
             skip_batch_predicate,
+            nested_statistics: nested_statistics.into(),
             column_predicates: self.column_predicates.clone(), // Q? Maybe this should cull
             // predicates.
             hive_predicate: None,
@@ -193,6 +223,7 @@ impl ScanPredicate {
             skip_batch_predicate: skip_batch_predicate
                 .cloned()
                 .or_else(|| self.to_dyn_skip_batch_predicate(schema)),
+            nested_statistics: self.nested_statistics.clone(),
             column_predicates: Arc::new(ColumnPredicates {
                 predicates: self
                     .column_predicates
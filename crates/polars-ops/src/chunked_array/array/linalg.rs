@@ -0,0 +1,361 @@
+use arrow::array::{Array, FixedSizeListArray, PrimitiveArray};
+use arrow::bitmap::{Bitmap, BitmapBuilder};
+use arrow::types::NativeType;
+use num_traits::Float;
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The vector norm computed by [`array_norm`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum VectorNorm {
+    /// Sum of the absolute values.
+    L1,
+    /// Euclidean norm.
+    L2,
+    /// Maximum of the absolute values.
+    Max,
+}
+
+/// Get the float type that linear algebra over arrays with inner type `dtype` is computed in.
+pub fn linalg_float_dtype(dtype: &DataType) -> PolarsResult<DataType> {
+    match dtype {
+        DataType::Float32 => Ok(DataType::Float32),
+        dt if dt.is_primitive_numeric() => Ok(DataType::Float64),
+        dt => polars_bail!(
+            InvalidOperation: "linear algebra is not supported for arrays of dtype {}", dt
+        ),
+    }
+}
+
+fn common_float_dtype(lhs: &DataType, rhs: &DataType) -> PolarsResult<DataType> {
+    match (linalg_float_dtype(lhs)?, linalg_float_dtype(rhs)?) {
+        (DataType::Float32, DataType::Float32) => Ok(DataType::Float32),
+        _ => Ok(DataType::Float64),
+    }
+}
+
+/// Get the output type of [`array_dot`] and [`array_cosine_similarity`].
+pub fn dot_output_dtype(lhs: &DataType, rhs: &DataType) -> PolarsResult<DataType> {
+    let (DataType::Array(lhs_inner, lhs_width), DataType::Array(rhs_inner, rhs_width)) = (lhs, rhs)
+    else {
+        polars_bail!(InvalidOperation: "expected arrays, got {} and {}", lhs, rhs)
+    };
+    polars_ensure!(
+        lhs_width == rhs_width,
+        ShapeMismatch: "expected arrays of the same width, got {} and {}", lhs_width, rhs_width
+    );
+    common_float_dtype(lhs_inner, rhs_inner)
+}
+
+fn inner_dtype(ca: &ArrayChunked) -> &DataType {
+    let DataType::Array(inner, _) = ca.dtype() else {
+        unreachable!()
+    };
+    inner
+}
+
+/// Cast the inner values to `float` and rechunk, so the values of a row are contiguous.
+fn to_float_array(ca: &ArrayChunked, float: &DataType) -> PolarsResult<ArrayChunked> {
+    let dtype = DataType::Array(Box::new(float.clone()), ca.width());
+    Ok(ca.cast(&dtype)?.array()?.rechunk().into_owned())
+}
+
+/// Get the length of the output of a binary operation, arrays of length 1 are broadcast.
+fn broadcast_len(op: &str, lhs: usize, rhs: usize) -> PolarsResult<usize> {
+    match (lhs, rhs) {
+        (l, r) if l == r => Ok(l),
+        (1, r) => Ok(r),
+        (l, 1) => Ok(l),
+        (l, r) => polars_bail!(length_mismatch = op, l, r),
+    }
+}
+
+fn any_null(validity: Option<&Bitmap>, range: std::ops::Range<usize>) -> bool {
+    validity.is_some_and(|v| range.into_iter().any(|i| !v.get_bit(i)))
+}
+
+/// Access to the rows of a rechunked array with a float inner type.
+struct Rows<'a, T> {
+    values: &'a [T],
+    width: usize,
+    len: usize,
+    outer_validity: Option<&'a Bitmap>,
+    inner_validity: Option<&'a Bitmap>,
+}
+
+impl<'a, T: NativeType> Rows<'a, T> {
+    fn new(arr: &'a FixedSizeListArray) -> Self {
+        let values = arr
+            .values()
+            .as_any()
+            .downcast_ref::<PrimitiveArray<T>>()
+            .unwrap();
+        Self {
+            values: values.values().as_slice(),
+            width: arr.size(),
+            len: arr.len(),
+            outer_validity: arr.validity(),
+            inner_validity: values.validity(),
+        }
+    }
+
+    /// Get row `i`, or `None` if the row or any of its values is null. An array of length 1 is
+    /// broadcast.
+    fn get(&self, i: usize) -> Option<&'a [T]> {
+        let i = if self.len == 1 { 0 } else { i };
+        let range = i * self.width..(i + 1) * self.width;
+        if any_null(self.outer_validity, i..i + 1)
+            || any_null(self.inner_validity, range.clone())
+        {
+            return None;
+        }
+        Some(&self.values[range])
+    }
+}
+
+fn dot<T: Float>(lhs: &[T], rhs: &[T]) -> T {
+    lhs.iter()
+        .zip(rhs)
+        .fold(T::zero(), |acc, (l, r)| acc + *l * *r)
+}
+
+fn norm<T: Float>(values: &[T], norm: VectorNorm) -> T {
+    match norm {
+        VectorNorm::L1 => values.iter().fold(T::zero(), |acc, v| acc + v.abs()),
+        VectorNorm::L2 => dot(values, values).sqrt(),
+        VectorNorm::Max => values.iter().fold(T::zero(), |acc, v| acc.max(v.abs())),
+    }
+}
+
+fn cosine_similarity<T: Float>(lhs: &[T], rhs: &[T]) -> T {
+    dot(lhs, rhs) / (norm(lhs, VectorNorm::L2) * norm(rhs, VectorNorm::L2))
+}
+
+fn binary_rows<T, F>(lhs: &ArrayChunked, rhs: &ArrayChunked, len: usize, f: F) -> Series
+where
+    T: PolarsFloatType,
+    T::Native: Float,
+    F: Fn(&[T::Native], &[T::Native]) -> T::Native,
+{
+    let lhs_rows = Rows::<T::Native>::new(lhs.downcast_as_array());
+    let rhs_rows = Rows::<T::Native>::new(rhs.downcast_as_array());
+    let out: ChunkedArray<T> = (0..len)
+        .map(|i| Some(f(lhs_rows.get(i)?, rhs_rows.get(i)?)))
+        .collect();
+    out.with_name(lhs.name().clone()).into_series()
+}
+
+fn prepare_binary(
+    op: &str,
+    lhs: &ArrayChunked,
+    rhs: &ArrayChunked,
+) -> PolarsResult<(ArrayChunked, ArrayChunked, usize)> {
+    let float = dot_output_dtype(lhs.dtype(), rhs.dtype())?;
+    let len = broadcast_len(op, lhs.len(), rhs.len())?;
+    Ok((to_float_array(lhs, &float)?, to_float_array(rhs, &float)?, len))
+}
+
+/// Compute the dot product of every pair of rows.
+pub fn array_dot(lhs: &ArrayChunked, rhs: &ArrayChunked) -> PolarsResult<Series> {
+    let (lhs, rhs, len) = prepare_binary("arr.linalg.dot", lhs, rhs)?;
+    Ok(match inner_dtype(&lhs) {
+        DataType::Float32 => binary_rows::<Float32Type, _>(&lhs, &rhs, len, dot),
+        _ => binary_rows::<Float64Type, _>(&lhs, &rhs, len, dot),
+    })
+}
+
+/// Compute the cosine similarity of every pair of rows.
+pub fn array_cosine_similarity(lhs: &ArrayChunked, rhs: &ArrayChunked) -> PolarsResult<Series> {
+    let (lhs, rhs, len) = prepare_binary("arr.linalg.cosine_similarity", lhs, rhs)?;
+    Ok(match inner_dtype(&lhs) {
+        DataType::Float32 => binary_rows::<Float32Type, _>(&lhs, &rhs, len, cosine_similarity),
+        _ => binary_rows::<Float64Type, _>(&lhs, &rhs, len, cosine_similarity),
+    })
+}
+
+fn norm_rows<T>(ca: &ArrayChunked, kind: VectorNorm) -> Series
+where
+    T: PolarsFloatType,
+    T::Native: Float,
+{
+    let rows = Rows::<T::Native>::new(ca.downcast_as_array());
+    let out: ChunkedArray<T> = (0..ca.len())
+        .map(|i| Some(norm(rows.get(i)?, kind)))
+        .collect();
+    out.with_name(ca.name().clone()).into_series()
+}
+
+/// Compute the norm of every row.
+pub fn array_norm(ca: &ArrayChunked, kind: VectorNorm) -> PolarsResult<Series> {
+    let float = linalg_float_dtype(inner_dtype(ca))?;
+    let ca = to_float_array(ca, &float)?;
+    Ok(match float {
+        DataType::Float32 => norm_rows::<Float32Type>(&ca, kind),
+        _ => norm_rows::<Float64Type>(&ca, kind),
+    })
+}
+
+/// Get the output type of [`array_matvec`] for a matrix of type `matrix` and a vector of type
+/// `vector`.
+pub fn matvec_output_dtype(matrix: &DataType, vector: &DataType) -> PolarsResult<DataType> {
+    let (DataType::Array(row, n_rows), DataType::Array(vector_inner, n_cols)) = (matrix, vector)
+    else {
+        polars_bail!(
+            InvalidOperation: "`arr.linalg.matvec` expects arrays, got {} and {}", matrix, vector
+        )
+    };
+    let DataType::Array(matrix_inner, width) = row.as_ref() else {
+        polars_bail!(
+            InvalidOperation: "`arr.linalg.matvec` expects a matrix of type Array(Array), got {}",
+            matrix
+        )
+    };
+    polars_ensure!(
+        width == n_cols,
+        ShapeMismatch: "cannot multiply a matrix with {} columns by a vector of length {}",
+        width, n_cols
+    );
+    let float = common_float_dtype(matrix_inner, vector_inner)?;
+    Ok(DataType::Array(Box::new(float), *n_rows))
+}
+
+fn matvec_rows<T>(
+    matrix: &ArrayChunked,
+    vector: &ArrayChunked,
+    len: usize,
+    dtype: &DataType,
+) -> ArrayChunked
+where
+    T: PolarsFloatType,
+    T::Native: Float,
+{
+    let matrix_arr = matrix.downcast_as_array();
+    let matrix_rows = matrix_arr
+        .values()
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()
+        .unwrap();
+    let n_rows = matrix.width();
+    let n_cols = matrix_rows.size();
+    let row_validity = matrix_rows.validity();
+    let matrix_values = Rows::<T::Native>::new(matrix_rows);
+    let vector_rows = Rows::<T::Native>::new(vector.downcast_as_array());
+
+    // A matrix is null if it has null values or null rows.
+    let get_matrix = |i: usize| {
+        let i = if matrix.len() == 1 { 0 } else { i };
+        let range = i * n_rows * n_cols..(i + 1) * n_rows * n_cols;
+        if any_null(matrix_arr.validity(), i..i + 1)
+            || any_null(row_validity, i * n_rows..(i + 1) * n_rows)
+            || any_null(matrix_values.inner_validity, range.clone())
+        {
+            return None;
+        }
+        Some(&matrix_values.values[range])
+    };
+
+    let mut values = Vec::with_capacity(len * n_rows);
+    let mut validity = BitmapBuilder::with_capacity(len);
+    for i in 0..len {
+        match (get_matrix(i), vector_rows.get(i)) {
+            (Some(m), Some(v)) => {
+                values.extend((0..n_rows).map(|r| dot(&m[r * n_cols..(r + 1) * n_cols], v)));
+                validity.push(true);
+            },
+            _ => {
+                values.extend(std::iter::repeat_n(T::Native::zero(), n_rows));
+                validity.push(false);
+            },
+        }
+    }
+
+    let arr = FixedSizeListArray::new(
+        dtype.to_arrow(CompatLevel::newest()),
+        len,
+        PrimitiveArray::from_vec(values).boxed(),
+        validity.into_opt_validity(),
+    );
+    ArrayChunked::with_chunk(matrix.name().clone(), arr)
+}
+
+/// Multiply every matrix in `matrix`, an array of rows, with the vector in the same row of
+/// `vector`.
+pub fn array_matvec(matrix: &ArrayChunked, vector: &ArrayChunked) -> PolarsResult<ArrayChunked> {
+    let dtype = matvec_output_dtype(matrix.dtype(), vector.dtype())?;
+    let len = broadcast_len("arr.linalg.matvec", matrix.len(), vector.len())?;
+    let DataType::Array(float, _) = &dtype else {
+        unreachable!()
+    };
+    let matrix_dtype = DataType::Array(
+        Box::new(DataType::Array(float.clone(), vector.width())),
+        matrix.width(),
+    );
+    let matrix = matrix.cast(&matrix_dtype)?.array()?.rechunk().into_owned();
+    let vector = to_float_array(vector, float)?;
+
+    Ok(match float.as_ref() {
+        DataType::Float32 => matvec_rows::<Float32Type>(&matrix, &vector, len, &dtype),
+        _ => matvec_rows::<Float64Type>(&matrix, &vector, len, &dtype),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn array(values: &[Option<[f64; 2]>]) -> ArrayChunked {
+        let s = Series::new(
+            "a".into(),
+            values
+                .iter()
+                .map(|v| v.map(|v| Series::new("".into(), v)))
+                .collect::<Vec<_>>(),
+        );
+        s.cast(&DataType::Array(Box::new(DataType::Float64), 2))
+            .unwrap()
+            .array()
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn test_array_linalg() -> PolarsResult<()> {
+        let a = array(&[Some([3.0, 4.0]), Some([1.0, 0.0]), None]);
+        let b = array(&[Some([1.0, 2.0])]);
+
+        let out = array_dot(&a, &b)?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(11.0), Some(1.0), None]);
+
+        let out = array_norm(&a, VectorNorm::L2)?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(5.0), Some(1.0), None]);
+        let out = array_norm(&a, VectorNorm::L1)?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(7.0), Some(1.0), None]);
+        let out = array_norm(&a, VectorNorm::Max)?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(4.0), Some(1.0), None]);
+
+        let out = array_cosine_similarity(&a, &a)?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(1.0), Some(1.0), None]);
+
+        assert!(array_dot(&a, &array(&[Some([1.0, 2.0]), None])).is_err());
+
+        let rows = Series::new(
+            "".into(),
+            [
+                Series::new("".into(), [1.0, 2.0]),
+                Series::new("".into(), [3.0, 4.0]),
+            ],
+        );
+        let matrix = Series::new("m".into(), [rows]).cast(&DataType::Array(
+            Box::new(DataType::Array(Box::new(DataType::Float64), 2)),
+            2,
+        ))?;
+        let out = array_matvec(matrix.array()?, &a)?;
+        assert_eq!(out.get_as_series(0).unwrap().f64()?.to_vec(), &[Some(11.0), Some(25.0)]);
+        assert_eq!(out.get_as_series(1).unwrap().f64()?.to_vec(), &[Some(1.0), Some(3.0)]);
+        assert!(out.get_as_series(2).is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,268 @@
+//! Keyed tokenization, deterministic encryption and keyed masking.
+//!
+//! Keys never travel with the query plan; expressions carry a [`CryptoKey`], which pairs a key
+//! identifier with the [`KeyProvider`] that resolves it when the query runs. Expressions holding
+//! a [`CryptoKey`] can't be serialized.
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use aes_siv::KeyInit;
+use aes_siv::siv::Aes128Siv;
+use polars_core::prelude::*;
+
+pub const KEY_LEN: usize = 32;
+const TAG_LEN: usize = 16;
+
+const TOKENIZE_CONTEXT: &str = "polars 2025-06 column tokenization";
+const ENCRYPT_CONTEXT: &str = "polars 2025-06 deterministic encryption aes-siv";
+const MASK_CONTEXT: &str = "polars 2025-06 keyed mask";
+
+/// Resolves key identifiers to key material.
+pub trait KeyProvider: Send + Sync {
+    /// Get the 32 byte key registered under `key_id`.
+    fn get_key(&self, key_id: &str) -> PolarsResult<[u8; KEY_LEN]>;
+}
+
+/// A [`KeyProvider`] backed by an in-memory map.
+#[derive(Default, Clone)]
+pub struct StaticKeyProvider {
+    keys: PlHashMap<PlSmallStr, [u8; KEY_LEN]>,
+}
+
+impl StaticKeyProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_key(mut self, key_id: impl Into<PlSmallStr>, key: [u8; KEY_LEN]) -> Self {
+        self.keys.insert(key_id.into(), key);
+        self
+    }
+}
+
+impl KeyProvider for StaticKeyProvider {
+    fn get_key(&self, key_id: &str) -> PolarsResult<[u8; KEY_LEN]> {
+        self.keys
+            .get(key_id)
+            .copied()
+            .ok_or_else(|| polars_err!(ComputeError: "no key registered under id '{key_id}'"))
+    }
+}
+
+/// A key identifier together with the [`KeyProvider`] that resolves it.
+///
+/// Two keys are equal if they have the same identifier and share the same provider.
+#[derive(Clone)]
+pub struct CryptoKey {
+    key_id: PlSmallStr,
+    provider: Arc<dyn KeyProvider>,
+}
+
+impl CryptoKey {
+    pub fn new(key_id: impl Into<PlSmallStr>, provider: Arc<dyn KeyProvider>) -> Self {
+        Self {
+            key_id: key_id.into(),
+            provider,
+        }
+    }
+
+    pub fn key_id(&self) -> &PlSmallStr {
+        &self.key_id
+    }
+
+    fn derive_key(&self, context: &str) -> PolarsResult<[u8; KEY_LEN]> {
+        let key = self.provider.get_key(&self.key_id)?;
+        Ok(blake3::derive_key(context, &key))
+    }
+}
+
+impl PartialEq for CryptoKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.key_id == other.key_id && Arc::ptr_eq(&self.provider, &other.provider)
+    }
+}
+
+impl Eq for CryptoKey {}
+
+impl Hash for CryptoKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key_id.hash(state);
+        (Arc::as_ptr(&self.provider) as *const ()).hash(state);
+    }
+}
+
+impl Debug for CryptoKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CryptoKey({})", self.key_id)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CryptoKey {
+    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::Error;
+        Err(S::Error::custom("cannot serialize an expression that holds a key provider"))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CryptoKey {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+        Err(D::Error::custom("cannot deserialize an expression that holds a key provider"))
+    }
+}
+
+#[cfg(feature = "dsl-schema")]
+impl schemars::JsonSchema for CryptoKey {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "CryptoKey".into()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed(concat!(module_path!(), "::", "CryptoKey"))
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        PlSmallStr::json_schema(generator)
+    }
+}
+
+fn siv_cipher(key: &CryptoKey) -> PolarsResult<Aes128Siv> {
+    Ok(Aes128Siv::new(&key.derive_key(ENCRYPT_CONTEXT)?.into()))
+}
+
+/// Replace every value with a keyed, irreversible token (hex encoded).
+///
+/// Equal inputs under the same key map to the same token, so tokenized columns can still be
+/// joined and grouped on.
+pub fn tokenize(ca: &StringChunked, key: &CryptoKey) -> PolarsResult<StringChunked> {
+    let key = key.derive_key(TOKENIZE_CONTEXT)?;
+    Ok(ca.apply_into_string_amortized(|s, buf| {
+        let hash = blake3::keyed_hash(&key, s.as_bytes());
+        buf.push_str(hash.to_hex().as_str());
+    }))
+}
+
+/// Deterministically encrypt every value with AES-SIV (RFC 5297).
+///
+/// Equal plaintexts give equal ciphertexts and tampering is detected on decryption.
+pub fn encrypt_deterministic(ca: &BinaryChunked, key: &CryptoKey) -> PolarsResult<BinaryChunked> {
+    let mut cipher = siv_cipher(key)?;
+    ca.try_apply_nonnull_values_generic::<BinaryType, _, _, _>(|plaintext| {
+        cipher
+            .encrypt([&[] as &[u8]; 0], plaintext)
+            .map_err(|_| polars_err!(ComputeError: "encryption failed"))
+    })
+}
+
+/// Decrypt values produced by [`encrypt_deterministic`].
+pub fn decrypt_deterministic(ca: &BinaryChunked, key: &CryptoKey) -> PolarsResult<BinaryChunked> {
+    let mut cipher = siv_cipher(key)?;
+    ca.try_apply_nonnull_values_generic::<BinaryType, _, _, _>(|ciphertext| {
+        polars_ensure!(
+            ciphertext.len() >= TAG_LEN,
+            ComputeError: "cannot decrypt value of {} bytes; it is shorter than the {TAG_LEN} byte tag",
+            ciphertext.len()
+        );
+        cipher.decrypt([&[] as &[u8]; 0], ciphertext).map_err(|_| {
+            polars_err!(ComputeError: "decryption failed; wrong key or modified value")
+        })
+    })
+}
+
+/// Mask values while keeping their format.
+///
+/// ASCII digits, lowercase and uppercase letters are replaced by uniformly drawn characters of
+/// the same class; all other characters (separators, whitespace, punctuation) are kept. The
+/// mask is keyed and deterministic, but it is not format-preserving encryption: values can't be
+/// recovered and distinct values may collide.
+pub fn mask_format_preserving(ca: &StringChunked, key: &CryptoKey) -> PolarsResult<StringChunked> {
+    let key = key.derive_key(MASK_CONTEXT)?;
+    Ok(ca.apply_into_string_amortized(|s, buf| {
+        let mut reader = blake3::Hasher::new_keyed(&key)
+            .update(s.as_bytes())
+            .finalize_xof();
+        // Rejection sampling, so that every character of a class is equally likely.
+        let mut draw = |n: u8| loop {
+            let mut b = [0u8; 1];
+            reader.fill(&mut b);
+            if b[0] < u8::MAX - u8::MAX % n {
+                break b[0] % n;
+            }
+        };
+        for c in s.chars() {
+            let masked = match c {
+                '0'..='9' => (b'0' + draw(10)) as char,
+                'a'..='z' => (b'a' + draw(26)) as char,
+                'A'..='Z' => (b'A' + draw(26)) as char,
+                c => c,
+            };
+            buf.push(masked);
+        }
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn keys() -> (CryptoKey, CryptoKey, CryptoKey) {
+        let provider: Arc<dyn KeyProvider> = Arc::new(
+            StaticKeyProvider::new()
+                .with_key("a", [1; KEY_LEN])
+                .with_key("b", [2; KEY_LEN]),
+        );
+        (
+            CryptoKey::new("a", provider.clone()),
+            CryptoKey::new("b", provider.clone()),
+            CryptoKey::new("missing", provider),
+        )
+    }
+
+    #[test]
+    fn test_encrypt_roundtrip() -> PolarsResult<()> {
+        let (a, b, _) = keys();
+        let ca = StringChunked::new("".into(), [Some("foo"), None, Some("foo"), Some("")]);
+        let encrypted = encrypt_deterministic(&ca.as_binary(), &a)?;
+        assert_eq!(encrypted.get(0), encrypted.get(2));
+        assert_ne!(encrypted.get(0), Some("foo".as_bytes()));
+        assert_eq!(encrypted.get(1), None);
+
+        let decrypted = decrypt_deterministic(&encrypted, &a)?;
+        assert_eq!(decrypted.get(0), Some("foo".as_bytes()));
+        assert_eq!(decrypted.get(3), Some("".as_bytes()));
+        assert!(decrypt_deterministic(&encrypted, &b).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_tokenize_and_mask() -> PolarsResult<()> {
+        let (a, b, missing) = keys();
+        let ca = StringChunked::new("".into(), [Some("4111-1111"), Some("Ab c")]);
+        let token_a = tokenize(&ca, &a)?;
+        let token_b = tokenize(&ca, &b)?;
+        assert_ne!(token_a.get(0), token_b.get(0));
+        assert_eq!(token_a.get(0).unwrap().len(), 64);
+
+        let masked = mask_format_preserving(&ca, &a)?;
+        let v = masked.get(0).unwrap();
+        assert_eq!(v.len(), 9);
+        assert_eq!(&v[4..5], "-");
+        assert!(v.chars().filter(|c| *c != '-').all(|c| c.is_ascii_digit()));
+        let v = masked.get(1).unwrap();
+        assert!(v.as_bytes()[0].is_ascii_uppercase());
+        assert!(v.as_bytes()[1].is_ascii_lowercase());
+        assert_eq!(&v[2..3], " ");
+
+        assert!(tokenize(&ca, &missing).is_err());
+        Ok(())
+    }
+}
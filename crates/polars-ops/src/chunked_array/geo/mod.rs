@@ -0,0 +1,201 @@
+//! Spatial operations on geometries stored as WKB in binary columns.
+//!
+//! All operations are planar; coordinates are treated as cartesian regardless of the
+//! coordinate reference system of the geometries.
+mod wkb;
+
+use polars_core::datatypes::extension::GeometryType;
+use polars_core::prelude::arity::{
+    broadcast_binary_elementwise, broadcast_try_binary_elementwise, try_unary_elementwise,
+};
+use polars_core::prelude::*;
+pub use wkb::{Coord, Geometry, parse_wkb, write_point};
+
+fn geometry_storage<'a>(s: &'a Series, op: &str) -> PolarsResult<&'a BinaryChunked> {
+    polars_ensure!(
+        s.dtype().is_geometry() || s.dtype() == &DataType::Binary,
+        InvalidOperation: "`{op}` expects a geometry or binary column, got {}", s.dtype()
+    );
+    s.to_storage().binary()
+}
+
+fn check_lengths(a: &Series, b: &Series, op: &str) -> PolarsResult<()> {
+    polars_ensure!(
+        a.len() == b.len() || a.len() == 1 || b.len() == 1,
+        length_mismatch = op, a.len(), b.len()
+    );
+    Ok(())
+}
+
+fn into_geometry_series(ca: BinaryChunked) -> Series {
+    ca.into_series()
+        .into_extension(ExtensionTypeInstance(Box::new(GeometryType::default())))
+}
+
+/// Create point geometries from `x` and `y` coordinates.
+pub fn st_point(x: &Series, y: &Series) -> PolarsResult<Series> {
+    check_lengths(x, y, "st.point")?;
+    let x = x.cast(&DataType::Float64)?;
+    let y = y.cast(&DataType::Float64)?;
+
+    let out: BinaryChunked = broadcast_binary_elementwise(x.f64()?, y.f64()?, |x, y| {
+        let (x, y) = (x?, y?);
+        let mut out = Vec::with_capacity(21);
+        write_point(x, y, &mut out);
+        Some(out)
+    });
+    Ok(into_geometry_series(out))
+}
+
+fn point_coord(s: &Series, op: &str, get: fn(Coord) -> f64) -> PolarsResult<Series> {
+    let ca = geometry_storage(s, op)?;
+    let out: Float64Chunked = try_unary_elementwise(ca, |wkb| {
+        let Some(wkb) = wkb else {
+            return Ok(None);
+        };
+        match parse_wkb(wkb)? {
+            Geometry::Point(p) => Ok(p.map(get)),
+            _ => polars_bail!(InvalidOperation: "`{op}` is only defined for point geometries"),
+        }
+    })?;
+    Ok(out.into_series())
+}
+
+/// Get the x coordinate of point geometries.
+pub fn st_x(s: &Series) -> PolarsResult<Series> {
+    point_coord(s, "st.x", |c| c.x)
+}
+
+/// Get the y coordinate of point geometries.
+pub fn st_y(s: &Series) -> PolarsResult<Series> {
+    point_coord(s, "st.y", |c| c.y)
+}
+
+/// The minimum planar distance between two geometries.
+pub fn st_distance(a: &Series, b: &Series) -> PolarsResult<Series> {
+    check_lengths(a, b, "st.distance")?;
+    let lhs = geometry_storage(a, "st.distance")?;
+    let rhs = geometry_storage(b, "st.distance")?;
+    let out: Float64Chunked = broadcast_try_binary_elementwise(lhs, rhs, |a, b| match (a, b) {
+        (Some(a), Some(b)) => Ok(Some(parse_wkb(a)?.distance(&parse_wkb(b)?))),
+        _ => Ok::<_, PolarsError>(None),
+    })?;
+    Ok(out.into_series())
+}
+
+/// Whether the geometries of `a` contain those of `b`.
+pub fn st_contains(a: &Series, b: &Series) -> PolarsResult<Series> {
+    check_lengths(a, b, "st.contains")?;
+    let lhs = geometry_storage(a, "st.contains")?;
+    let rhs = geometry_storage(b, "st.contains")?;
+    let out: BooleanChunked = broadcast_try_binary_elementwise(lhs, rhs, |a, b| match (a, b) {
+        (Some(a), Some(b)) => Ok(Some(parse_wkb(a)?.contains(&parse_wkb(b)?))),
+        _ => Ok::<_, PolarsError>(None),
+    })?;
+    Ok(out.into_series())
+}
+
+/// Whether the geometries of `a` lie within those of `b`.
+pub fn st_within(a: &Series, b: &Series) -> PolarsResult<Series> {
+    let mut out = st_contains(b, a)?;
+    out.rename(a.name().clone());
+    Ok(out)
+}
+
+/// Whether the bounding boxes of the geometries intersect the box spanned by
+/// `[xmin, ymin, xmax, ymax]`.
+///
+/// Empty geometries never intersect the box.
+pub fn st_intersects_bbox(s: &Series, bbox: [f64; 4]) -> PolarsResult<Series> {
+    let [xmin, ymin, xmax, ymax] = bbox;
+    let ca = geometry_storage(s, "st.intersects_bbox")?;
+    let out: BooleanChunked = try_unary_elementwise(ca, |wkb| {
+        let Some(wkb) = wkb else {
+            return Ok(None);
+        };
+        let intersects = parse_wkb(wkb)?
+            .bounds()
+            .is_some_and(|[x0, y0, x1, y1]| x0 <= xmax && x1 >= xmin && y0 <= ymax && y1 >= ymin);
+        Ok::<_, PolarsError>(Some(intersects))
+    })?;
+    Ok(out.into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn polygon(coords: &[(f64, f64)]) -> Vec<u8> {
+        let mut out = vec![1];
+        out.extend_from_slice(&3u32.to_le_bytes());
+        out.extend_from_slice(&1u32.to_le_bytes());
+        out.extend_from_slice(&(coords.len() as u32).to_le_bytes());
+        for (x, y) in coords {
+            out.extend_from_slice(&x.to_le_bytes());
+            out.extend_from_slice(&y.to_le_bytes());
+        }
+        out
+    }
+
+    #[test]
+    fn test_point_roundtrip() -> PolarsResult<()> {
+        let x = Series::new("x".into(), [Some(1.0), None, Some(3.5)]);
+        let y = Series::new("y".into(), [2i32]);
+        let points = st_point(&x, &y)?;
+        assert!(points.dtype().is_geometry());
+        assert_eq!(points.name().as_str(), "x");
+
+        let x = st_x(&points)?;
+        let y = st_y(&points)?;
+        assert_eq!(Vec::from(x.f64()?), &[Some(1.0), None, Some(3.5)]);
+        assert_eq!(Vec::from(y.f64()?), &[Some(2.0), None, Some(2.0)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_contains_and_distance() -> PolarsResult<()> {
+        let square = polygon(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0), (0.0, 0.0)]);
+        let square = BinaryChunked::from_slice("square".into(), &[square.as_slice()]);
+        let square = into_geometry_series(square);
+
+        let x = Series::new("x".into(), [1.0, 4.0, 7.0]);
+        let y = Series::new("y".into(), [1.0, 2.0, 8.0]);
+        let points = st_point(&x, &y)?;
+
+        let contains = st_contains(&square, &points)?;
+        assert_eq!(Vec::from(contains.bool()?), &[Some(true), Some(true), Some(false)]);
+        let within = st_within(&points, &square)?;
+        assert!(within.equals(&contains));
+
+        let distance = st_distance(&points, &square)?;
+        assert_eq!(Vec::from(distance.f64()?), &[Some(0.0), Some(0.0), Some(5.0)]);
+
+        let in_bbox = st_intersects_bbox(&points, [0.0, 0.0, 5.0, 5.0])?;
+        assert_eq!(Vec::from(in_bbox.bool()?), &[Some(true), Some(true), Some(false)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_wkb() {
+        let ca = BinaryChunked::from_slice("".into(), &[&[1u8, 1, 0][..]]);
+        assert!(st_x(&ca.into_series()).is_err());
+    }
+
+    #[test]
+    fn test_nested_collections() {
+        // Geometry collections that each contain the next one.
+        let collections = |depth: usize| {
+            let mut out = vec![];
+            for _ in 0..depth {
+                out.push(1);
+                out.extend_from_slice(&7u32.to_le_bytes());
+                out.extend_from_slice(&1u32.to_le_bytes());
+            }
+            write_point(1.0, 2.0, &mut out);
+            out
+        };
+        assert!(parse_wkb(&collections(10)).is_ok());
+        let err = parse_wkb(&collections(100_000)).unwrap_err();
+        assert!(err.to_string().contains("nested"));
+    }
+}
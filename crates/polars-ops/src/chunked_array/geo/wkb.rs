@@ -0,0 +1,406 @@
+//! Minimal reader and writer of WKB (well-known binary) geometries and planar algorithms on
+//! them. Z and M coordinates are read but ignored.
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Coord {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Geometry {
+    /// `None` for an empty point.
+    Point(Option<Coord>),
+    LineString(Vec<Coord>),
+    /// The exterior ring followed by the interior rings (holes).
+    Polygon(Vec<Vec<Coord>>),
+    MultiPoint(Vec<Coord>),
+    MultiLineString(Vec<Vec<Coord>>),
+    MultiPolygon(Vec<Vec<Vec<Coord>>>),
+    GeometryCollection(Vec<Geometry>),
+}
+
+const WKB_POINT: u32 = 1;
+const WKB_LINESTRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTIPOINT: u32 = 4;
+const WKB_MULTILINESTRING: u32 = 5;
+const WKB_MULTIPOLYGON: u32 = 6;
+const WKB_GEOMETRYCOLLECTION: u32 = 7;
+
+/// The maximum nesting depth of geometry collections, such that corrupt data cannot overflow the
+/// stack.
+const MAX_NESTING_DEPTH: usize = 64;
+
+// Flags of the extended WKB format of PostGIS.
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+/// Encode a point as little-endian WKB.
+pub fn write_point(x: f64, y: f64, out: &mut Vec<u8>) {
+    out.push(1);
+    out.extend_from_slice(&WKB_POINT.to_le_bytes());
+    out.extend_from_slice(&x.to_le_bytes());
+    out.extend_from_slice(&y.to_le_bytes());
+}
+
+pub fn parse_wkb(buf: &[u8]) -> PolarsResult<Geometry> {
+    let mut reader = WkbReader {
+        buf,
+        offset: 0,
+        little_endian: true,
+        n_dims: 2,
+        depth: 0,
+    };
+    let geometry = reader.geometry()?;
+    polars_ensure!(
+        reader.offset == buf.len(),
+        ComputeError: "invalid WKB: {} trailing bytes", buf.len() - reader.offset
+    );
+    Ok(geometry)
+}
+
+struct WkbReader<'a> {
+    buf: &'a [u8],
+    offset: usize,
+    little_endian: bool,
+    n_dims: usize,
+    /// The number of collections the reader is in.
+    depth: usize,
+}
+
+impl WkbReader<'_> {
+    fn take<const N: usize>(&mut self) -> PolarsResult<[u8; N]> {
+        let bytes = self
+            .buf
+            .get(self.offset..self.offset + N)
+            .ok_or_else(|| polars_err!(ComputeError: "invalid WKB: unexpected end of data"))?;
+        self.offset += N;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn u32(&mut self) -> PolarsResult<u32> {
+        let bytes = self.take::<4>()?;
+        Ok(if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn f64(&mut self) -> PolarsResult<f64> {
+        let bytes = self.take::<8>()?;
+        Ok(if self.little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    /// Read a length prefix, checking it against the remaining bytes so that corrupt data cannot
+    /// trigger huge allocations.
+    fn read_len(&mut self, min_item_size: usize) -> PolarsResult<usize> {
+        let len = self.u32()? as usize;
+        polars_ensure!(
+            len.saturating_mul(min_item_size) <= self.buf.len() - self.offset,
+            ComputeError: "invalid WKB: length {len} exceeds the size of the data"
+        );
+        Ok(len)
+    }
+
+    fn coord(&mut self) -> PolarsResult<Coord> {
+        let x = self.f64()?;
+        let y = self.f64()?;
+        for _ in 2..self.n_dims {
+            self.f64()?;
+        }
+        Ok(Coord { x, y })
+    }
+
+    fn coords(&mut self) -> PolarsResult<Vec<Coord>> {
+        let len = self.read_len(self.n_dims * 8)?;
+        (0..len).map(|_| self.coord()).collect()
+    }
+
+    fn rings(&mut self) -> PolarsResult<Vec<Vec<Coord>>> {
+        let len = self.read_len(4)?;
+        (0..len).map(|_| self.coords()).collect()
+    }
+
+    fn geometries(&mut self) -> PolarsResult<Vec<Geometry>> {
+        polars_ensure!(
+            self.depth < MAX_NESTING_DEPTH,
+            ComputeError: "invalid WKB: nested more than {MAX_NESTING_DEPTH} levels deep"
+        );
+        let len = self.read_len(5)?;
+        self.depth += 1;
+        let geometries = (0..len).map(|_| self.geometry()).collect();
+        self.depth -= 1;
+        geometries
+    }
+
+    fn geometry(&mut self) -> PolarsResult<Geometry> {
+        self.little_endian = match self.take::<1>()?[0] {
+            0 => false,
+            1 => true,
+            b => polars_bail!(ComputeError: "invalid WKB: unknown byte order {b}"),
+        };
+
+        let type_code = self.u32()?;
+        if type_code & EWKB_SRID != 0 {
+            self.u32()?;
+        }
+        let ewkb_dims = (type_code & EWKB_Z != 0) as usize + (type_code & EWKB_M != 0) as usize;
+        let type_code = type_code & 0x0FFF_FFFF;
+        // ISO WKB adds 1000 for Z, 2000 for M and 3000 for ZM coordinates.
+        let iso_dims = match type_code / 1000 {
+            0 => 0,
+            1 | 2 => 1,
+            3 => 2,
+            _ => polars_bail!(ComputeError: "invalid WKB: unknown geometry type {type_code}"),
+        };
+        self.n_dims = 2 + ewkb_dims + iso_dims;
+
+        let geometry = match type_code % 1000 {
+            WKB_POINT => {
+                let coord = self.coord()?;
+                Geometry::Point((!(coord.x.is_nan() && coord.y.is_nan())).then_some(coord))
+            },
+            WKB_LINESTRING => Geometry::LineString(self.coords()?),
+            WKB_POLYGON => Geometry::Polygon(self.rings()?),
+            WKB_MULTIPOINT => Geometry::MultiPoint(
+                self.geometries()?
+                    .into_iter()
+                    .filter_map(|g| match g {
+                        Geometry::Point(p) => Ok(p).transpose(),
+                        _ => Some(Err(invalid_part("MultiPoint"))),
+                    })
+                    .collect::<PolarsResult<_>>()?,
+            ),
+            WKB_MULTILINESTRING => Geometry::MultiLineString(
+                self.geometries()?
+                    .into_iter()
+                    .map(|g| match g {
+                        Geometry::LineString(ls) => Ok(ls),
+                        _ => Err(invalid_part("MultiLineString")),
+                    })
+                    .collect::<PolarsResult<_>>()?,
+            ),
+            WKB_MULTIPOLYGON => Geometry::MultiPolygon(
+                self.geometries()?
+                    .into_iter()
+                    .map(|g| match g {
+                        Geometry::Polygon(p) => Ok(p),
+                        _ => Err(invalid_part("MultiPolygon")),
+                    })
+                    .collect::<PolarsResult<_>>()?,
+            ),
+            WKB_GEOMETRYCOLLECTION => Geometry::GeometryCollection(self.geometries()?),
+            t => polars_bail!(ComputeError: "invalid WKB: unknown geometry type {t}"),
+        };
+        Ok(geometry)
+    }
+}
+
+fn invalid_part(name: &str) -> polars_error::PolarsError {
+    polars_err!(ComputeError: "invalid WKB: {name} contains a geometry of another type")
+}
+
+impl Geometry {
+    /// Call `f` with every line segment of the geometry. Points are passed as segments of length
+    /// zero.
+    fn for_each_segment(&self, f: &mut impl FnMut(Coord, Coord)) {
+        let mut path = |coords: &[Coord]| {
+            if let [c] = coords {
+                f(*c, *c);
+            }
+            for w in coords.windows(2) {
+                f(w[0], w[1]);
+            }
+        };
+
+        match self {
+            Geometry::Point(p) => p.iter().for_each(|c| f(*c, *c)),
+            Geometry::MultiPoint(ps) => ps.iter().for_each(|c| f(*c, *c)),
+            Geometry::LineString(ls) => path(ls),
+            Geometry::Polygon(rings) | Geometry::MultiLineString(rings) => {
+                rings.iter().for_each(|r| path(r))
+            },
+            Geometry::MultiPolygon(polygons) => polygons.iter().flatten().for_each(|r| path(r)),
+            Geometry::GeometryCollection(gs) => {
+                gs.iter().for_each(|g| g.for_each_segment(&mut *f))
+            },
+        }
+    }
+
+    fn for_each_coord(&self, f: &mut impl FnMut(Coord)) {
+        match self {
+            Geometry::Point(p) => p.iter().for_each(|c| f(*c)),
+            Geometry::LineString(cs) | Geometry::MultiPoint(cs) => cs.iter().for_each(|c| f(*c)),
+            Geometry::Polygon(rings) | Geometry::MultiLineString(rings) => {
+                rings.iter().flatten().for_each(|c| f(*c))
+            },
+            Geometry::MultiPolygon(polygons) => {
+                polygons.iter().flatten().flatten().for_each(|c| f(*c))
+            },
+            Geometry::GeometryCollection(gs) => gs.iter().for_each(|g| g.for_each_coord(&mut *f)),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        let mut empty = true;
+        self.for_each_coord(&mut |_| empty = false);
+        empty
+    }
+
+    /// The bounding box `[xmin, ymin, xmax, ymax]`, `None` for empty geometries.
+    pub fn bounds(&self) -> Option<[f64; 4]> {
+        let mut bounds: Option<[f64; 4]> = None;
+        self.for_each_coord(&mut |c| {
+            let b = bounds.get_or_insert([c.x, c.y, c.x, c.y]);
+            *b = [b[0].min(c.x), b[1].min(c.y), b[2].max(c.x), b[3].max(c.y)];
+        });
+        bounds
+    }
+
+    /// Whether the point lies in the interior or on the boundary of the geometry.
+    pub fn contains_point(&self, p: Coord) -> bool {
+        match self {
+            Geometry::Polygon(rings) => polygon_contains_point(rings, p),
+            Geometry::MultiPolygon(polygons) => {
+                polygons.iter().any(|rings| polygon_contains_point(rings, p))
+            },
+            Geometry::GeometryCollection(gs) => gs.iter().any(|g| g.contains_point(p)),
+            _ => {
+                let mut contains = false;
+                self.for_each_segment(&mut |a, b| contains |= segment_distance(p, a, b) == 0.0);
+                contains
+            },
+        }
+    }
+
+    /// Whether `other` lies in the interior or on the boundary of the geometry.
+    pub fn contains(&self, other: &Geometry) -> bool {
+        if other.is_empty() {
+            return false;
+        }
+
+        let mut all_inside = true;
+        other.for_each_coord(&mut |c| all_inside &= self.contains_point(c));
+        if !all_inside || !self.is_areal() {
+            return all_inside;
+        }
+
+        // The boundaries of an areal geometry and the other geometry may not cross.
+        let mut crosses = false;
+        self.for_each_segment(&mut |a, b| {
+            other.for_each_segment(&mut |c, d| crosses |= segments_cross(a, b, c, d));
+        });
+        !crosses
+    }
+
+    /// The minimum planar distance between the geometries, NaN if either is empty.
+    pub fn distance(&self, other: &Geometry) -> f64 {
+        if self.is_empty() || other.is_empty() {
+            return f64::NAN;
+        }
+
+        // One geometry may lie inside the other without their boundaries intersecting.
+        if self.is_areal() && self.contains_point(other.first_coord().unwrap())
+            || other.is_areal() && other.contains_point(self.first_coord().unwrap())
+        {
+            return 0.0;
+        }
+
+        let mut distance = f64::INFINITY;
+        self.for_each_segment(&mut |a, b| {
+            other.for_each_segment(&mut |c, d| {
+                let d = if segments_intersect(a, b, c, d) {
+                    0.0
+                } else {
+                    segment_distance(a, c, d)
+                        .min(segment_distance(b, c, d))
+                        .min(segment_distance(c, a, b))
+                        .min(segment_distance(d, a, b))
+                };
+                distance = distance.min(d);
+            })
+        });
+        distance
+    }
+
+    fn first_coord(&self) -> Option<Coord> {
+        let mut first = None;
+        self.for_each_coord(&mut |c| {
+            first.get_or_insert(c);
+        });
+        first
+    }
+
+    fn is_areal(&self) -> bool {
+        match self {
+            Geometry::Polygon(_) | Geometry::MultiPolygon(_) => true,
+            Geometry::GeometryCollection(gs) => gs.iter().any(|g| g.is_areal()),
+            _ => false,
+        }
+    }
+}
+
+fn polygon_contains_point(rings: &[Vec<Coord>], p: Coord) -> bool {
+    let Some((exterior, holes)) = rings.split_first() else {
+        return false;
+    };
+    let on_boundary =
+        |ring: &[Coord]| ring.windows(2).any(|w| segment_distance(p, w[0], w[1]) == 0.0);
+
+    if on_boundary(exterior) || holes.iter().any(|h| on_boundary(h)) {
+        return true;
+    }
+    ring_contains_point(exterior, p) && !holes.iter().any(|h| ring_contains_point(h, p))
+}
+
+/// Ray casting test of a point against a closed ring.
+fn ring_contains_point(ring: &[Coord], p: Coord) -> bool {
+    let mut inside = false;
+    for w in ring.windows(2) {
+        let (a, b) = (w[0], w[1]);
+        if (a.y > p.y) != (b.y > p.y) && p.x < (b.x - a.x) * (p.y - a.y) / (b.y - a.y) + a.x {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Distance of point `p` to the segment from `a` to `b`.
+fn segment_distance(p: Coord, a: Coord, b: Coord) -> f64 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let len2 = dx * dx + dy * dy;
+    let t = if len2 == 0.0 {
+        0.0
+    } else {
+        (((p.x - a.x) * dx + (p.y - a.y) * dy) / len2).clamp(0.0, 1.0)
+    };
+    (p.x - (a.x + t * dx)).hypot(p.y - (a.y + t * dy))
+}
+
+fn orientation(a: Coord, b: Coord, c: Coord) -> f64 {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+/// Whether the segments intersect in a single point that is interior to both.
+fn segments_cross(a: Coord, b: Coord, c: Coord, d: Coord) -> bool {
+    let (o1, o2) = (orientation(a, b, c), orientation(a, b, d));
+    let (o3, o4) = (orientation(c, d, a), orientation(c, d, b));
+    o1 * o2 < 0.0 && o3 * o4 < 0.0
+}
+
+/// Whether the segments share at least one point.
+fn segments_intersect(a: Coord, b: Coord, c: Coord, d: Coord) -> bool {
+    segments_cross(a, b, c, d)
+        || segment_distance(a, c, d) == 0.0
+        || segment_distance(b, c, d) == 0.0
+        || segment_distance(c, a, b) == 0.0
+        || segment_distance(d, a, b) == 0.0
+}
@@ -7,11 +7,13 @@ mod dispersion;
 mod get;
 mod min_max;
 mod namespace;
+mod rolling;
 #[cfg(feature = "list_sets")]
 mod sets;
 mod sum_mean;
 #[cfg(feature = "list_to_struct")]
 mod to_struct;
+mod zip_with;
 
 #[cfg(feature = "list_count")]
 pub use count::*;
@@ -23,6 +25,7 @@ pub use namespace::*;
 pub use sets::*;
 #[cfg(feature = "list_to_struct")]
 pub use to_struct::*;
+pub use zip_with::ListZipOp;
 
 pub trait AsList {
     fn as_list(&self) -> &ListChunked;
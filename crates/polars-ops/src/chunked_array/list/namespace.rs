@@ -300,6 +300,27 @@ pub trait ListNameSpaceImpl: AsList {
         ca.try_apply_amortized(|s| diff(s.as_ref(), n, null_behavior))
     }
 
+    /// Compute the sum of every window of `window_size` consecutive values of every list. The
+    /// first `window_size - 1` values of the lists are null.
+    fn lst_rolling_sum(&self, window_size: usize) -> PolarsResult<ListChunked> {
+        let ca = self.as_list();
+        rolling::rolling(ca, window_size, false)
+    }
+
+    /// Compute the mean of every window of `window_size` consecutive values of every list. The
+    /// first `window_size - 1` values of the lists are null.
+    fn lst_rolling_mean(&self, window_size: usize) -> PolarsResult<ListChunked> {
+        let ca = self.as_list();
+        rolling::rolling(ca, window_size, true)
+    }
+
+    /// Apply `op` to the elements at the same position of the lists of `self` and `other`, which
+    /// must have the same length.
+    fn lst_zip_with(&self, other: &ListChunked, op: ListZipOp) -> PolarsResult<ListChunked> {
+        let ca = self.as_list();
+        zip_with::zip_with(ca, other, op)
+    }
+
     fn lst_shift(&self, periods: &Column) -> PolarsResult<ListChunked> {
         let ca = self.as_list();
         let periods_s = periods.cast(&DataType::Int64)?;
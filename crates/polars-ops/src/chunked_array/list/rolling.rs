@@ -0,0 +1,74 @@
+use num_traits::NumCast;
+use polars_core::with_match_physical_numeric_polars_type;
+
+use super::*;
+
+/// The sum or mean of the window of `window_size` values that ends at every value of the lists
+/// with the `offsets`. Values that don't have a full window, or whose window has a null, are null.
+fn rolling_between_offsets<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    offsets: &[i64],
+    window_size: usize,
+    mean: bool,
+) -> ChunkedArray<T> {
+    let ca = ca.rechunk();
+    let arr = ca.downcast_as_array();
+    let values = arr.values().as_slice();
+    let divisor: T::Native = NumCast::from(window_size).unwrap();
+
+    let mut out = vec![None; values.len()];
+    for w in offsets.windows(2) {
+        let (start, end) = (w[0] as usize, w[1] as usize);
+        for i in start + window_size - 1..end {
+            let window_start = i + 1 - window_size;
+            if arr
+                .validity()
+                .is_some_and(|v| v.null_count_range(window_start, window_size) > 0)
+            {
+                continue;
+            }
+            let sum: T::Native = values[window_start..=i].iter().copied().sum();
+            out[i] = Some(if mean { sum / divisor } else { sum });
+        }
+    }
+    out.into_iter()
+        .collect::<ChunkedArray<T>>()
+        .with_name(ca.name().clone())
+}
+
+pub(super) fn rolling(
+    ca: &ListChunked,
+    window_size: usize,
+    mean: bool,
+) -> PolarsResult<ListChunked> {
+    let name = if mean { "rolling_mean" } else { "rolling_sum" };
+    polars_ensure!(
+        window_size > 0,
+        InvalidOperation: "`window_size` of list.{} must be positive", name
+    );
+    let inner_dtype = ca.inner_dtype();
+    polars_ensure!(
+        inner_dtype.is_primitive_numeric(),
+        InvalidOperation: "list.{} is only supported for numeric lists, got {}", name, inner_dtype
+    );
+    let dtype = match inner_dtype {
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 if mean => DataType::Float16,
+        DataType::Float32 if mean => DataType::Float32,
+        _ if mean => DataType::Float64,
+        DataType::Int8 | DataType::UInt8 | DataType::Int16 | DataType::UInt16 => DataType::Int64,
+        dt => dt.clone(),
+    };
+
+    // Trim the lists such that the values that were sliced out aren't computed.
+    let trimmed = ca.trim_lists_to_normalized_offsets();
+    let ca = trimmed.as_ref().unwrap_or(ca).rechunk();
+    let offsets = ca.downcast_as_array().offsets().clone();
+    ca.apply_to_inner(&|s| {
+        let s = s.cast(&dtype)?;
+        Ok(with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let values: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_between_offsets(values, offsets.as_slice(), window_size, mean).into_series()
+        }))
+    })
+}
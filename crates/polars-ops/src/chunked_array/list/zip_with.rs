@@ -0,0 +1,139 @@
+use std::fmt::{Display, Formatter};
+
+use arrow::array::{Array, IntoBoxedArray, ListArray};
+use arrow::compute::utils::combine_validities_and;
+use arrow::offset::Offsets;
+use polars_core::utils::try_get_supertype;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+use crate::series::min_max_binary_columns;
+
+/// An operation between the elements at the same position of two lists.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ListZipOp {
+    Add,
+    Sub,
+    Mul,
+    Min,
+    Max,
+}
+
+impl Display for ListZipOp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            ListZipOp::Add => "add",
+            ListZipOp::Sub => "sub",
+            ListZipOp::Mul => "mul",
+            ListZipOp::Min => "min",
+            ListZipOp::Max => "max",
+        };
+        write!(f, "{s}")
+    }
+}
+
+pub(super) fn zip_with(
+    lhs: &ListChunked,
+    rhs: &ListChunked,
+    op: ListZipOp,
+) -> PolarsResult<ListChunked> {
+    polars_ensure!(
+        lhs.len() == rhs.len() || lhs.len() == 1 || rhs.len() == 1,
+        length_mismatch = "list.zip_with",
+        lhs.len(),
+        rhs.len()
+    );
+    let dtype = try_get_supertype(lhs.inner_dtype(), rhs.inner_dtype())?;
+    polars_ensure!(
+        dtype.is_primitive_numeric(),
+        InvalidOperation: "list.zip_with is only supported for numeric lists, got {} and {}",
+        lhs.dtype(), rhs.dtype()
+    );
+
+    // Broadcast a single list, and trim the lists such that the values that were sliced out
+    // aren't computed.
+    let prepare = |ca: &ListChunked, len: usize| {
+        let ca = if ca.len() != len {
+            ca.new_from_index(0, len)
+        } else {
+            ca.clone()
+        };
+        let ca = ca.trim_lists_to_normalized_offsets().unwrap_or(ca);
+        ca.rechunk().into_owned()
+    };
+    let len = if lhs.len() == 1 { rhs.len() } else { lhs.len() };
+    let lhs = prepare(lhs, len);
+    let rhs = prepare(rhs, len);
+    let lhs_arr = lhs.downcast_as_array();
+    let rhs_arr = rhs.downcast_as_array();
+    let validity = combine_validities_and(lhs_arr.validity(), rhs_arr.validity());
+    let is_valid = |i: usize| validity.as_ref().is_none_or(|v| v.get_bit(i));
+
+    for i in (0..len).filter(|i| is_valid(*i)) {
+        let (lhs_len, rhs_len) = (lhs_arr.offsets().length_at(i), rhs_arr.offsets().length_at(i));
+        polars_ensure!(
+            lhs_len == rhs_len,
+            ShapeMismatch: "list.zip_with requires lists of equal length, got lengths {} and {} \
+            in row {}", lhs_len, rhs_len, i
+        );
+    }
+
+    let (offsets, lhs_values, rhs_values) = if lhs_arr.offsets() == rhs_arr.offsets() {
+        (lhs_arr.offsets().clone(), lhs.get_inner(), rhs.get_inner())
+    } else {
+        // The lists only differ in the null rows, gather the values of the other rows.
+        let mut offsets = Offsets::<i64>::with_capacity(len);
+        let mut lhs_idx = Vec::with_capacity(lhs_arr.values().len());
+        let mut rhs_idx = Vec::with_capacity(rhs_arr.values().len());
+        for i in 0..len {
+            if !is_valid(i) {
+                offsets.try_push(0)?;
+                continue;
+            }
+            let (start, end) = lhs_arr.offsets().start_end(i);
+            let rhs_start = rhs_arr.offsets().start_end(i).0;
+            lhs_idx.extend(start as IdxSize..end as IdxSize);
+            rhs_idx.extend((rhs_start..rhs_start + end - start).map(|i| i as IdxSize));
+            offsets.try_push(end - start)?;
+        }
+        (
+            offsets.into(),
+            lhs.get_inner().take_slice(&lhs_idx)?,
+            rhs.get_inner().take_slice(&rhs_idx)?,
+        )
+    };
+
+    let lhs_values = lhs_values.cast(&dtype)?;
+    let rhs_values = rhs_values.cast(&dtype)?;
+    let values = match op {
+        ListZipOp::Add => (&lhs_values + &rhs_values)?,
+        ListZipOp::Sub => (&lhs_values - &rhs_values)?,
+        ListZipOp::Mul => (&lhs_values * &rhs_values)?,
+        ListZipOp::Min | ListZipOp::Max => min_max_binary_columns(
+            &lhs_values.into_column(),
+            &rhs_values.into_column(),
+            op == ListZipOp::Min,
+        )?
+        .take_materialized_series(),
+    };
+    let values = values.rechunk();
+    let values = values.chunks()[0].clone();
+
+    let arr = ListArray::<i64>::new(
+        ListArray::<i64>::default_datatype(values.dtype().clone()),
+        offsets,
+        values,
+        validity,
+    );
+    // SAFETY: the inner dtype is the dtype of the values.
+    Ok(unsafe {
+        ListChunked::from_chunks_and_dtype_unchecked(
+            lhs.name().clone(),
+            vec![arr.into_boxed()],
+            DataType::List(Box::new(dtype)),
+        )
+    })
+}
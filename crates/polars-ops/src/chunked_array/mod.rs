@@ -1,8 +1,12 @@
 #[cfg(feature = "dtype-array")]
 pub mod array;
 mod binary;
+#[cfg(feature = "crypto")]
+pub mod crypto;
 #[cfg(feature = "timezones")]
 pub mod datetime;
+#[cfg(feature = "geo")]
+pub mod geo;
 pub mod list;
 #[cfg(feature = "propagate_nans")]
 pub mod nan_propagating_aggregate;
@@ -0,0 +1,165 @@
+use polars_core::prelude::*;
+use polars_utils::regex_cache::compile_regex;
+use regex::Regex;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Built-in matchers for common kinds of personal data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum PiiPattern {
+    /// Email addresses.
+    Email,
+    /// Phone numbers with 7 to 15 digits, optionally with a country code.
+    Phone,
+    /// Payment card numbers of 13 to 19 digits that pass the Luhn check.
+    CreditCard,
+}
+
+impl PiiPattern {
+    pub fn pattern(self) -> &'static str {
+        match self {
+            Self::Email => r"[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}",
+            Self::Phone => r"(?:\+\d{1,3}[-. ]?)?(?:\(\d{1,4}\)[-. ]?)?\d{2,4}(?:[-. ]?\d{2,5}){1,4}",
+            Self::CreditCard => r"\b\d(?:[- ]?\d){12,18}\b",
+        }
+    }
+
+    /// Additional validation of a match that cannot be expressed in the regex.
+    fn is_valid(self, m: &str) -> bool {
+        match self {
+            Self::Email => true,
+            Self::Phone => (7..=15).contains(&m.bytes().filter(u8::is_ascii_digit).count()),
+            Self::CreditCard => luhn_check(m),
+        }
+    }
+}
+
+/// Check whether the digits in `s` form a number of 13 to 19 digits with a valid Luhn check
+/// digit. Spaces and dashes are ignored; any other character fails the check.
+pub fn luhn_check(s: &str) -> bool {
+    let mut sum = 0;
+    let mut n_digits = 0;
+    for b in s.bytes().rev() {
+        match b {
+            b'0'..=b'9' => {
+                let mut d = (b - b'0') as u32;
+                if n_digits % 2 == 1 {
+                    d *= 2;
+                    if d > 9 {
+                        d -= 9;
+                    }
+                }
+                sum += d;
+                n_digits += 1;
+            },
+            b' ' | b'-' => {},
+            _ => return false,
+        }
+    }
+    (13..=19).contains(&n_digits) && sum % 10 == 0
+}
+
+/// Write `m` to `buf`, replacing every character except the last `keep_last_n` with
+/// `replacement`.
+fn write_masked(buf: &mut String, m: &str, replacement: &str, keep_last_n: usize) {
+    let n_chars = m.chars().count();
+    let n_masked = n_chars.saturating_sub(keep_last_n);
+    let split = m.char_indices().nth(n_masked).map_or(m.len(), |(i, _)| i);
+    for _ in 0..n_masked {
+        buf.push_str(replacement);
+    }
+    buf.push_str(&m[split..]);
+}
+
+fn mask_matches(
+    ca: &StringChunked,
+    reg: &Regex,
+    replacement: &str,
+    keep_last_n: usize,
+    is_valid: impl Fn(&str) -> bool,
+) -> StringChunked {
+    ca.apply_into_string_amortized(|s, buf| {
+        let mut last = 0;
+        for m in reg.find_iter(s) {
+            if !is_valid(m.as_str()) {
+                continue;
+            }
+            buf.push_str(&s[last..m.start()]);
+            write_masked(buf, m.as_str(), replacement, keep_last_n);
+            last = m.end();
+        }
+        buf.push_str(&s[last..]);
+    })
+}
+
+/// Mask every match of the regex `pat`.
+///
+/// Every character of a match is replaced with `replacement`, except for the last
+/// `keep_last_n` characters which are kept as is.
+pub fn mask_pattern(
+    ca: &StringChunked,
+    pat: &str,
+    replacement: &str,
+    keep_last_n: usize,
+) -> PolarsResult<StringChunked> {
+    let reg = compile_regex(pat)?;
+    Ok(mask_matches(ca, &reg, replacement, keep_last_n, |_| true))
+}
+
+/// Mask all occurrences of the built-in `pattern`. See [`mask_pattern`].
+pub fn mask_pii(
+    ca: &StringChunked,
+    pattern: PiiPattern,
+    replacement: &str,
+    keep_last_n: usize,
+) -> PolarsResult<StringChunked> {
+    let reg = compile_regex(pattern.pattern())?;
+    Ok(mask_matches(ca, &reg, replacement, keep_last_n, |m| pattern.is_valid(m)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_luhn_check() {
+        assert!(luhn_check("4111 1111 1111 1111"));
+        assert!(luhn_check("5500-0000-0000-0004"));
+        assert!(!luhn_check("4111 1111 1111 1112"));
+        assert!(!luhn_check("411111"));
+        assert!(!luhn_check("4111a1111 1111 1111"));
+    }
+
+    #[test]
+    fn test_mask_pii() -> PolarsResult<()> {
+        let ca = StringChunked::new(
+            "".into(),
+            [
+                Some("card 4111 1111 1111 1111 and 4111 1111 1111 1112"),
+                Some("mail john.doe@example.com now"),
+                Some("call +31 (020) 555-1234"),
+                None,
+            ],
+        );
+
+        let out = mask_pii(&ca, PiiPattern::CreditCard, "*", 4)?;
+        assert_eq!(
+            out.get(0),
+            Some("card ***************1111 and 4111 1111 1111 1112")
+        );
+        assert_eq!(out.get(1), ca.get(1));
+        assert_eq!(out.get(3), None);
+
+        let out = mask_pii(&ca, PiiPattern::Email, "#", 0)?;
+        assert_eq!(out.get(1), Some("mail #################### now"));
+
+        let out = mask_pii(&ca, PiiPattern::Phone, "x", 2)?;
+        assert_eq!(out.get(2), Some("call xxxxxxxxxxxxxxxx34"));
+
+        let out = mask_pattern(&ca, r"\d{4}", "", 1)?;
+        assert_eq!(out.get(2), Some("call +31 (020) 555-4"));
+        Ok(())
+    }
+}
@@ -10,6 +10,8 @@ mod extract;
 mod find_many;
 #[cfg(feature = "extract_jsonpath")]
 mod json_path;
+#[cfg(feature = "string_mask")]
+mod mask;
 #[cfg(feature = "strings")]
 mod namespace;
 #[cfg(feature = "string_normalize")]
@@ -35,6 +37,8 @@ pub use escape_regex::*;
 pub use find_many::*;
 #[cfg(feature = "extract_jsonpath")]
 pub use json_path::*;
+#[cfg(feature = "string_mask")]
+pub use mask::*;
 #[cfg(feature = "strings")]
 pub use namespace::*;
 #[cfg(feature = "string_normalize")]
@@ -0,0 +1,359 @@
+//! Approximate nearest neighbor search over embedding columns of type `Array`.
+//!
+//! The index is an inverted file (IVF): the vectors are clustered with k-means and a query only
+//! scans the vectors in the clusters with the nearest centroids.
+use arrow::array::{Array, PrimitiveArray};
+use polars_core::POOL;
+use polars_core::prelude::*;
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The distance used to rank the neighbors in an [`AnnIndex`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AnnMetric {
+    /// Euclidean distance.
+    #[default]
+    L2,
+    /// One minus the cosine similarity.
+    Cosine,
+    /// The negated inner product, so vectors with a larger inner product are nearer.
+    InnerProduct,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AnnIndexOptions {
+    pub metric: AnnMetric,
+    /// Number of clusters, defaults to the square root of the number of vectors.
+    pub n_lists: Option<usize>,
+    /// Number of clusters scanned by a query. Scanning all clusters gives exact results.
+    pub n_probe: usize,
+    /// Maximum number of k-means iterations.
+    pub max_iter: usize,
+}
+
+impl Default for AnnIndexOptions {
+    fn default() -> Self {
+        Self {
+            metric: AnnMetric::default(),
+            n_lists: None,
+            n_probe: 8,
+            max_iter: 10,
+        }
+    }
+}
+
+/// An approximate nearest neighbor index over an `Array` column.
+///
+/// Rows that are null or contain nulls are not indexed. The neighbors are returned as the row
+/// indices of the indexed column.
+#[derive(Clone, Debug)]
+pub struct AnnIndex {
+    metric: AnnMetric,
+    width: usize,
+    n_probe: usize,
+    vectors: Vec<f32>,
+    centroids: Vec<f32>,
+    lists: Vec<Vec<IdxSize>>,
+}
+
+/// Get the rows of `ca` as `f32`, together with whether every row is valid.
+fn to_vectors(ca: &ArrayChunked, metric: AnnMetric) -> PolarsResult<(Vec<f32>, Vec<bool>)> {
+    let DataType::Array(inner, width) = ca.dtype() else {
+        unreachable!()
+    };
+    polars_ensure!(
+        inner.is_primitive_numeric(),
+        InvalidOperation: "nearest neighbor search is not supported for arrays of dtype {}", inner
+    );
+    let width = *width;
+    let ca = ca.cast(&DataType::Array(Box::new(DataType::Float32), width))?;
+    let ca = ca.array()?.rechunk();
+    let arr = ca.downcast_as_array();
+    let values = arr
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f32>>()
+        .unwrap();
+
+    let mut vectors = values.values().to_vec();
+    let valid = (0..arr.len())
+        .map(|i| arr.is_valid(i) && (i * width..(i + 1) * width).all(|j| values.is_valid(j)))
+        .collect::<Vec<_>>();
+
+    if metric == AnnMetric::Cosine {
+        for vector in vectors.chunks_exact_mut(width) {
+            let norm = dot(vector, vector).sqrt();
+            if norm > 0.0 {
+                vector.iter_mut().for_each(|v| *v /= norm);
+            }
+        }
+    }
+    Ok((vectors, valid))
+}
+
+fn dot(lhs: &[f32], rhs: &[f32]) -> f32 {
+    lhs.iter().zip(rhs).map(|(l, r)| l * r).sum()
+}
+
+fn squared_l2(lhs: &[f32], rhs: &[f32]) -> f32 {
+    lhs.iter().zip(rhs).map(|(l, r)| (l - r) * (l - r)).sum()
+}
+
+impl AnnIndex {
+    /// Build an index over the vectors in `ca`.
+    pub fn build(ca: &ArrayChunked, options: &AnnIndexOptions) -> PolarsResult<Self> {
+        polars_ensure!(
+            options.n_lists != Some(0) && options.n_probe > 0,
+            InvalidOperation: "`n_lists` and `n_probe` must be positive"
+        );
+        let width = ca.width();
+        polars_ensure!(
+            width > 0,
+            InvalidOperation: "cannot build a nearest neighbor index over empty arrays"
+        );
+        let (vectors, valid) = to_vectors(ca, options.metric)?;
+        let rows = (0..valid.len())
+            .filter(|i| valid[*i])
+            .map(|i| i as IdxSize)
+            .collect::<Vec<_>>();
+
+        let mut index = Self {
+            metric: options.metric,
+            width,
+            n_probe: options.n_probe,
+            vectors,
+            centroids: vec![],
+            lists: vec![],
+        };
+        if rows.is_empty() {
+            return Ok(index);
+        }
+
+        let n_lists = options
+            .n_lists
+            .unwrap_or_else(|| (rows.len() as f64).sqrt().ceil() as usize)
+            .min(rows.len());
+        // Initialize the centroids with evenly spaced vectors, so the index is deterministic.
+        index.centroids = (0..n_lists)
+            .flat_map(|i| index.vector(rows[i * rows.len() / n_lists]))
+            .copied()
+            .collect();
+
+        let mut assignments = vec![usize::MAX; rows.len()];
+        for iteration in 0.. {
+            let new_assignments: Vec<usize> = POOL.install(|| {
+                rows.par_iter()
+                    .map(|row| index.nearest_centroids(index.vector(*row), 1)[0])
+                    .collect()
+            });
+            let converged = new_assignments == assignments;
+            assignments = new_assignments;
+            if converged || iteration == options.max_iter {
+                break;
+            }
+
+            let mut sums = vec![0.0f32; n_lists * width];
+            let mut counts = vec![0usize; n_lists];
+            for (row, list) in rows.iter().zip(&assignments) {
+                let sum = &mut sums[list * width..(list + 1) * width];
+                sum.iter_mut()
+                    .zip(index.vector(*row))
+                    .for_each(|(s, v)| *s += v);
+                counts[*list] += 1;
+            }
+            // Clusters without vectors keep their centroid.
+            for (list, count) in counts.into_iter().enumerate() {
+                if count > 0 {
+                    let range = list * width..(list + 1) * width;
+                    index.centroids[range.clone()]
+                        .iter_mut()
+                        .zip(&sums[range])
+                        .for_each(|(c, s)| *c = s / count as f32);
+                }
+            }
+        }
+
+        index.lists = vec![vec![]; n_lists];
+        for (row, list) in rows.into_iter().zip(assignments) {
+            index.lists[list].push(row);
+        }
+        Ok(index)
+    }
+
+    /// Set the number of clusters scanned by a query.
+    pub fn with_n_probe(mut self, n_probe: usize) -> Self {
+        self.n_probe = n_probe.max(1);
+        self
+    }
+
+    /// The number of indexed vectors.
+    pub fn len(&self) -> usize {
+        self.lists.iter().map(|list| list.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn metric(&self) -> AnnMetric {
+        self.metric
+    }
+
+    fn vector(&self, row: IdxSize) -> &[f32] {
+        let row = row as usize;
+        &self.vectors[row * self.width..(row + 1) * self.width]
+    }
+
+    /// Get the `n` clusters with the centroids nearest to `vector`.
+    fn nearest_centroids(&self, vector: &[f32], n: usize) -> Vec<usize> {
+        let mut distances = self
+            .centroids
+            .chunks_exact(self.width)
+            .map(|centroid| squared_l2(centroid, vector))
+            .enumerate()
+            .collect::<Vec<_>>();
+        distances.sort_unstable_by(|l, r| l.1.total_cmp(&r.1).then(l.0.cmp(&r.0)));
+        distances.into_iter().take(n).map(|(i, _)| i).collect()
+    }
+
+    fn distance(&self, query: &[f32], vector: &[f32]) -> f32 {
+        match self.metric {
+            AnnMetric::L2 => squared_l2(query, vector).sqrt(),
+            AnnMetric::Cosine => 1.0 - dot(query, vector),
+            AnnMetric::InnerProduct => -dot(query, vector),
+        }
+    }
+
+    fn search_vector(&self, query: &[f32], k: usize) -> Vec<(f32, IdxSize)> {
+        let mut neighbors = self
+            .nearest_centroids(query, self.n_probe)
+            .into_iter()
+            .flat_map(|list| &self.lists[list])
+            .map(|row| (self.distance(query, self.vector(*row)), *row))
+            .collect::<Vec<_>>();
+        neighbors.sort_unstable_by(|l, r| l.0.total_cmp(&r.0).then(l.1.cmp(&r.1)));
+        neighbors.truncate(k);
+        neighbors
+    }
+
+    /// Find the `k` nearest neighbors of every query vector.
+    ///
+    /// Returns a [`DataFrame`] with the list columns `indices`, the row indices of the
+    /// neighbors, and `distances`, both ordered from nearest to farthest. The lists are null for
+    /// queries that are null or contain nulls.
+    pub fn search(&self, queries: &ArrayChunked, k: usize) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            queries.width() == self.width,
+            ShapeMismatch: "query vectors have width {}, but the index has width {}",
+            queries.width(), self.width
+        );
+        let (vectors, valid) = to_vectors(queries, self.metric)?;
+        let neighbors: Vec<Option<Vec<(f32, IdxSize)>>> = POOL.install(|| {
+            valid
+                .par_iter()
+                .enumerate()
+                .map(|(i, valid)| {
+                    valid.then(|| {
+                        self.search_vector(&vectors[i * self.width..(i + 1) * self.width], k)
+                    })
+                })
+                .collect()
+        });
+
+        let height = neighbors.len();
+        let n_values = neighbors.iter().flatten().map(|n| n.len()).sum();
+        let mut indices = ListPrimitiveChunkedBuilder::<IdxType>::new(
+            PlSmallStr::from_static("indices"),
+            neighbors.len(),
+            n_values,
+            IDX_DTYPE,
+        );
+        let mut distances = ListPrimitiveChunkedBuilder::<Float32Type>::new(
+            PlSmallStr::from_static("distances"),
+            neighbors.len(),
+            n_values,
+            DataType::Float32,
+        );
+        for neighbors in neighbors {
+            match neighbors {
+                Some(neighbors) => {
+                    let (d, i): (Vec<_>, Vec<_>) = neighbors.into_iter().unzip();
+                    indices.append_slice(&i);
+                    distances.append_slice(&d);
+                },
+                None => {
+                    indices.append_null();
+                    distances.append_null();
+                },
+            }
+        }
+        DataFrame::new(
+            height,
+            vec![
+                indices.finish().into_column(),
+                distances.finish().into_column(),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::frame::DataFrameOps;
+
+    fn embeddings(values: &[&[f32]]) -> Series {
+        let width = values[0].len();
+        Series::new(
+            "embedding".into(),
+            values
+                .iter()
+                .map(|v| Series::new("".into(), *v))
+                .collect::<Vec<_>>(),
+        )
+        .cast(&DataType::Array(Box::new(DataType::Float32), width))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_ann_index() -> PolarsResult<()> {
+        let embedding = embeddings(&[
+            &[0.0, 0.0],
+            &[10.0, 10.0],
+            &[0.1, 0.0],
+            &[10.0, 9.0],
+            &[5.0, 5.0],
+        ]);
+        let df = DataFrame::new(embedding.len(), vec![embedding.into_column()])?;
+        let options = AnnIndexOptions {
+            n_lists: Some(2),
+            ..Default::default()
+        };
+        let index = df.build_ann_index("embedding", &options)?;
+        assert_eq!(index.len(), 5);
+
+        let queries = embeddings(&[&[0.0, 0.1], &[10.0, 10.0]]);
+        let out = index.search(queries.array()?, 2)?;
+        let indices = out.column("indices")?.list()?;
+        let neighbors = |i| {
+            indices
+                .get_as_series(i)
+                .unwrap()
+                .idx()
+                .unwrap()
+                .into_no_null_iter()
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(neighbors(0), &[0, 2]);
+        assert_eq!(neighbors(1), &[1, 3]);
+        let distances = out.column("distances")?.list()?.get_as_series(1).unwrap();
+        assert_eq!(distances.f32()?.get(0), Some(0.0));
+
+        let queries = embeddings(&[&[1.0, 1.0, 1.0]]);
+        assert!(index.search(queries.array()?, 1).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+
+/// Options for [`connected_components`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ConnectedComponentsOptions {
+    /// Add a `degree` column with the number of edges of every node, counting self-loops twice.
+    pub degree: bool,
+}
+
+/// Disjoint sets of the nodes `0..n`, every set represented by its smallest node.
+struct UnionFind {
+    parent: Vec<IdxSize>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n as IdxSize).collect(),
+        }
+    }
+
+    fn find(&mut self, mut node: IdxSize) -> IdxSize {
+        while self.parent[node as usize] != node {
+            // Path halving keeps the trees shallow without recursion.
+            let grandparent = self.parent[self.parent[node as usize] as usize];
+            self.parent[node as usize] = grandparent;
+            node = grandparent;
+        }
+        node
+    }
+
+    fn union(&mut self, a: IdxSize, b: IdxSize) {
+        let (a, b) = (self.find(a), self.find(b));
+        self.parent[a.max(b) as usize] = a.min(b);
+    }
+}
+
+/// Label the nodes of the undirected graph given by the edges `source` to `target` with their
+/// connected component.
+///
+/// The output has a `node` column with every distinct value of `source` and `target` in order of
+/// first appearance and a `component` column numbering the components in the same order. Null
+/// endpoints are not nodes, an edge with a single null endpoint only adds its other endpoint.
+pub fn connected_components(
+    edges: &DataFrame,
+    source: &str,
+    target: &str,
+    options: &ConnectedComponentsOptions,
+) -> PolarsResult<DataFrame> {
+    let source = edges.column(source)?.as_materialized_series();
+    let target = edges.column(target)?.as_materialized_series();
+    let dtype = try_get_supertype(source.dtype(), target.dtype())?;
+    let mut endpoints = source.cast(&dtype)?;
+    endpoints.append(&target.cast(&dtype)?)?;
+    let num_edges = edges.height();
+
+    // Hash the endpoints to node ids, numbered in order of first appearance.
+    let groups = endpoints.group_tuples(true, true)?;
+    let is_null = endpoints.is_null();
+    let mut node_ids = vec![IdxSize::MAX; endpoints.len()];
+    let mut first_rows = Vec::with_capacity(groups.len());
+    for group in groups.iter() {
+        let (first, rows) = match group {
+            GroupsIndicator::Idx((first, idx)) => (first, idx.to_vec()),
+            GroupsIndicator::Slice([first, len]) => (first, (first..first + len).collect()),
+        };
+        if is_null.get(first as usize) == Some(true) {
+            continue;
+        }
+        let id = first_rows.len() as IdxSize;
+        first_rows.push(first);
+        for row in rows {
+            node_ids[row as usize] = id;
+        }
+    }
+
+    let num_nodes = first_rows.len();
+    let mut sets = UnionFind::new(num_nodes);
+    let mut degree = vec![0 as IdxSize; num_nodes];
+    for (&a, &b) in node_ids[..num_edges].iter().zip(&node_ids[num_edges..]) {
+        for id in [a, b] {
+            if id != IdxSize::MAX {
+                degree[id as usize] += 1;
+            }
+        }
+        if a != IdxSize::MAX && b != IdxSize::MAX {
+            sets.union(a, b);
+        }
+    }
+
+    // Every root is the smallest node of its set, so it is labeled before its other nodes.
+    let mut labels = vec![IdxSize::MAX; num_nodes];
+    let mut num_components = 0;
+    let component = (0..num_nodes as IdxSize)
+        .map(|node| {
+            let root = sets.find(node) as usize;
+            if labels[root] == IdxSize::MAX {
+                labels[root] = num_components;
+                num_components += 1;
+            }
+            labels[root]
+        })
+        .collect::<Vec<_>>();
+
+    let first_rows = IdxCa::from_vec(PlSmallStr::EMPTY, first_rows);
+    let mut columns = vec![
+        endpoints
+            .take(&first_rows)?
+            .with_name(PlSmallStr::from_static("node"))
+            .into_column(),
+        Column::new(PlSmallStr::from_static("component"), component),
+    ];
+    if options.degree {
+        columns.push(Column::new(PlSmallStr::from_static("degree"), degree));
+    }
+    DataFrame::new(num_nodes, columns)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_connected_components() -> PolarsResult<()> {
+        let edges = df![
+            "src" => [Some("a"), Some("b"), Some("d"), Some("e"), None],
+            "dst" => ["b", "c", "d", "f", "g"],
+        ]?;
+        let options = ConnectedComponentsOptions { degree: true };
+        let out = connected_components(&edges, "src", "dst", &options)?;
+
+        let expected = df![
+            "node" => ["a", "b", "d", "e", "c", "f", "g"],
+            "component" => [0 as IdxSize, 0, 1, 2, 0, 2, 3],
+            "degree" => [1 as IdxSize, 2, 2, 1, 1, 1, 1],
+        ]?;
+        assert!(out.equals(&expected));
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+use polars_core::prelude::*;
+use polars_utils::format_pl_smallstr;
+
+use super::join::{JoinValidation, SeriesJoin, prepare_keys_multiple};
+
+/// Compare the rows of `left` and `right` that have the same values in `key_columns`.
+///
+/// The output has the key columns, a `change` column and the `{name}_old` and `{name}_new`
+/// values of every other column. The change is `"removed"` for rows that are only in `left`,
+/// `"added"` for rows that are only in `right` and `"modified"` for rows of which any of the
+/// other columns differ, where nulls compare equal to each other. Unchanged rows are left out.
+///
+/// The rows of `left` come first, in their original order, followed by the added rows in the
+/// order of `right`.
+pub fn diff_frames(
+    left: &DataFrame,
+    right: &DataFrame,
+    key_columns: &[PlSmallStr],
+) -> PolarsResult<DataFrame> {
+    polars_ensure!(
+        !key_columns.is_empty(),
+        InvalidOperation: "`diff_frames` needs at least one key column"
+    );
+    let schema = left.schema();
+    let right_schema = right.schema();
+    polars_ensure!(
+        schema.len() == right_schema.len()
+            && schema.iter().all(|(name, dtype)| right_schema.get(name) == Some(dtype)),
+        SchemaMismatch: "`diff_frames` requires both frames to have the same columns and types, \
+        got {:?} and {:?}", schema, right_schema
+    );
+    let value_columns = schema
+        .iter_names()
+        .filter(|name| !key_columns.contains(name))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    // Keys must be unique, otherwise it is ambiguous which rows should be compared.
+    let (idx_left, idx_right) = join_keys(left, key_columns)?.hash_join_outer(
+        &join_keys(right, key_columns)?,
+        JoinValidation::OneToOne,
+        true,
+    )?;
+    let mut left_rows = Vec::with_capacity(left.height());
+    let mut added = vec![];
+    for (l, r) in idx_left.iter().zip(idx_right.iter()) {
+        match (l, r) {
+            (Some(l), r) => left_rows.push((*l, r.copied())),
+            (None, Some(r)) => added.push(*r),
+            (None, None) => unreachable!(),
+        }
+    }
+    left_rows.sort_unstable();
+    added.sort_unstable();
+
+    let (matched_left, matched_right): (Vec<_>, Vec<_>) = left_rows
+        .iter()
+        .filter_map(|(l, r)| Some((*l, (*r)?)))
+        .unzip();
+    let matched_left = IdxCa::from_vec(PlSmallStr::EMPTY, matched_left);
+    let matched_right = IdxCa::from_vec(PlSmallStr::EMPTY, matched_right);
+    let mut modified = BooleanChunked::full(PlSmallStr::EMPTY, false, matched_left.len());
+    for name in &value_columns {
+        let old = left.column(name)?.take(&matched_left)?;
+        let new = right.column(name)?.take(&matched_right)?;
+        modified = &modified | &old.not_equal_missing(&new)?;
+    }
+
+    let mut modified = modified.into_no_null_iter();
+    let mut kept_left = Vec::with_capacity(left_rows.len());
+    let mut idx_new = Vec::with_capacity(left_rows.len() + added.len());
+    let mut change = Vec::with_capacity(left_rows.len() + added.len());
+    for (l, r) in left_rows {
+        match r {
+            None => change.push("removed"),
+            Some(_) if modified.next().unwrap() => change.push("modified"),
+            Some(_) => continue,
+        }
+        kept_left.push(l);
+        idx_new.push(r);
+    }
+    let mut idx_old = kept_left.iter().copied().map(Some).collect::<Vec<_>>();
+    idx_old.resize(kept_left.len() + added.len(), None);
+    idx_new.extend(added.iter().copied().map(Some));
+    change.resize(idx_old.len(), "added");
+
+    let mut keys = left
+        .select(key_columns)?
+        .take(&IdxCa::from_vec(PlSmallStr::EMPTY, kept_left))?;
+    keys.vstack_mut(
+        &right
+            .select(key_columns)?
+            .take(&IdxCa::from_vec(PlSmallStr::EMPTY, added))?,
+    )?;
+    let height = keys.height();
+    let mut columns = keys.into_columns();
+    columns.push(StringChunked::from_slice("change".into(), &change).into_column());
+
+    let idx_old = IdxCa::from_iter_options(PlSmallStr::EMPTY, idx_old.into_iter());
+    let idx_new = IdxCa::from_iter_options(PlSmallStr::EMPTY, idx_new.into_iter());
+    for name in &value_columns {
+        let old = left.column(name)?.take(&idx_old)?;
+        let new = right.column(name)?.take(&idx_new)?;
+        columns.push(old.with_name(format_pl_smallstr!("{name}_old")));
+        columns.push(new.with_name(format_pl_smallstr!("{name}_new")));
+    }
+    DataFrame::new(height, columns)
+}
+
+/// The key columns as a single column that can be hash joined on.
+fn join_keys(df: &DataFrame, key_columns: &[PlSmallStr]) -> PolarsResult<Series> {
+    let mut keys = df
+        .select_to_vec(key_columns)?
+        .into_iter()
+        .map(Column::take_materialized_series)
+        .collect::<Vec<_>>();
+    if keys.len() == 1 {
+        return Ok(keys.pop().unwrap());
+    }
+    Ok(prepare_keys_multiple(&keys, true)?.into_series())
+}
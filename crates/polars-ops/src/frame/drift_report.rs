@@ -0,0 +1,194 @@
+use polars_core::POOL;
+use polars_core::prelude::*;
+use rayon::prelude::*;
+
+/// The number of quantile bins of the baseline that the values of numeric columns are counted in
+/// to compute their population stability index.
+const N_BINS: usize = 10;
+/// The proportion that is used for empty bins and categories, such that the population stability
+/// index stays finite.
+const MIN_PROPORTION: f64 = 1e-4;
+
+/// Compare the distribution of every column of `current` with the same column of `baseline`.
+///
+/// The output has a row per column of `baseline`, followed by the columns that are only in
+/// `current`, with:
+/// - `column`: the name of the column.
+/// - `status`: `"removed"` or `"added"` if the column is only in `baseline` or `current`,
+///   `"dtype_changed"` if its data type differs and `"unchanged"` otherwise.
+/// - `null_rate_delta`: the change of the fraction of null values.
+/// - `mean_delta` and `std_delta`: the change of the mean and standard deviation of numeric
+///   columns.
+/// - `psi`: the population stability index of the values over the deciles of `baseline` for
+///   numeric columns, and over the categories for string, categorical and boolean columns.
+///
+/// The statistics are null if they don't apply to the data types of a column. The columns are
+/// compared in parallel.
+pub fn drift_report(baseline: &DataFrame, current: &DataFrame) -> PolarsResult<DataFrame> {
+    let baseline_schema = baseline.schema();
+    let mut names = baseline.get_column_names_owned();
+    names.extend(
+        current
+            .get_column_names()
+            .into_iter()
+            .filter(|name| !baseline_schema.contains(name.as_str()))
+            .cloned(),
+    );
+
+    let drifts = POOL.install(|| {
+        names
+            .par_iter()
+            .map(|name| column_drift(baseline.column(name).ok(), current.column(name).ok()))
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+
+    let f64_column = |name: &str, f: fn(&ColumnDrift) -> Option<f64>| {
+        Float64Chunked::from_iter_options(name.into(), drifts.iter().map(f)).into_column()
+    };
+    let status = drifts.iter().map(|drift| drift.status).collect::<Vec<_>>();
+    let columns = vec![
+        StringChunked::from_iter_values("column".into(), names.iter().map(|name| name.as_str()))
+            .into_column(),
+        StringChunked::from_slice("status".into(), &status).into_column(),
+        f64_column("null_rate_delta", |drift| drift.null_rate_delta),
+        f64_column("mean_delta", |drift| drift.mean_delta),
+        f64_column("std_delta", |drift| drift.std_delta),
+        f64_column("psi", |drift| drift.psi),
+    ];
+    DataFrame::new(names.len(), columns)
+}
+
+#[derive(Default)]
+struct ColumnDrift {
+    status: &'static str,
+    null_rate_delta: Option<f64>,
+    mean_delta: Option<f64>,
+    std_delta: Option<f64>,
+    psi: Option<f64>,
+}
+
+fn column_drift(baseline: Option<&Column>, current: Option<&Column>) -> PolarsResult<ColumnDrift> {
+    let (Some(baseline), Some(current)) = (baseline, current) else {
+        let status = if baseline.is_some() { "removed" } else { "added" };
+        return Ok(ColumnDrift {
+            status,
+            ..Default::default()
+        });
+    };
+
+    let mut drift = ColumnDrift {
+        status: if baseline.dtype() == current.dtype() {
+            "unchanged"
+        } else {
+            "dtype_changed"
+        },
+        null_rate_delta: delta(null_rate(baseline), null_rate(current)),
+        ..Default::default()
+    };
+    if is_numeric(baseline.dtype()) && is_numeric(current.dtype()) {
+        let baseline = sorted_values(baseline)?;
+        let current = sorted_values(current)?;
+        drift.mean_delta = delta(mean(&baseline), mean(&current));
+        drift.std_delta = delta(std(&baseline), std(&current));
+        drift.psi = binned_psi(&baseline, &current);
+    } else if is_categorical(baseline.dtype()) && is_categorical(current.dtype()) {
+        drift.psi = categorical_psi(baseline, current)?;
+    }
+    Ok(drift)
+}
+
+fn is_numeric(dtype: &DataType) -> bool {
+    dtype.is_primitive_numeric() || dtype.is_decimal()
+}
+
+fn is_categorical(dtype: &DataType) -> bool {
+    dtype.is_string() || dtype.is_bool() || dtype.is_categorical() || dtype.is_enum()
+}
+
+fn delta(baseline: Option<f64>, current: Option<f64>) -> Option<f64> {
+    Some(current? - baseline?)
+}
+
+fn null_rate(c: &Column) -> Option<f64> {
+    (!c.is_empty()).then(|| c.null_count() as f64 / c.len() as f64)
+}
+
+/// The non-null values of a numeric column, sorted.
+fn sorted_values(c: &Column) -> PolarsResult<Vec<f64>> {
+    let c = c.cast(&DataType::Float64)?;
+    let mut values = c.f64()?.iter().flatten().collect::<Vec<_>>();
+    values.sort_unstable_by(f64::total_cmp);
+    Ok(values)
+}
+
+fn mean(values: &[f64]) -> Option<f64> {
+    (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+}
+
+/// The sample standard deviation.
+fn std(values: &[f64]) -> Option<f64> {
+    if values.len() < 2 {
+        return None;
+    }
+    let mean = mean(values)?;
+    let sum_sq = values.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>();
+    Some((sum_sq / (values.len() - 1) as f64).sqrt())
+}
+
+/// The population stability index of the values, counted in bins between the quantiles of the
+/// baseline values.
+fn binned_psi(baseline: &[f64], current: &[f64]) -> Option<f64> {
+    if baseline.is_empty() || current.is_empty() {
+        return None;
+    }
+    let edges = (1..N_BINS)
+        .map(|i| baseline[i * baseline.len() / N_BINS])
+        .collect::<Vec<_>>();
+    let counts = |values: &[f64]| {
+        let mut counts = [0usize; N_BINS];
+        for v in values {
+            counts[edges.partition_point(|edge| edge.total_cmp(v).is_le())] += 1;
+        }
+        counts
+    };
+    let (baseline_counts, current_counts) = (counts(baseline), counts(current));
+    Some(psi(
+        baseline_counts
+            .into_iter()
+            .zip(current_counts)
+            .map(|(b, c)| (b as f64 / baseline.len() as f64, c as f64 / current.len() as f64)),
+    ))
+}
+
+/// The population stability index of the categories of the values.
+fn categorical_psi(baseline: &Column, current: &Column) -> PolarsResult<Option<f64>> {
+    let baseline = baseline.cast(&DataType::String)?;
+    let current = current.cast(&DataType::String)?;
+    let (baseline, current) = (baseline.str()?, current.str()?);
+
+    let mut counts = PlHashMap::<&str, [usize; 2]>::new();
+    for (i, ca) in [baseline, current].into_iter().enumerate() {
+        for v in ca.iter().flatten() {
+            counts.entry(v).or_default()[i] += 1;
+        }
+    }
+    let n_baseline = baseline.len() - baseline.null_count();
+    let n_current = current.len() - current.null_count();
+    if n_baseline == 0 || n_current == 0 {
+        return Ok(None);
+    }
+    Ok(Some(psi(counts.into_values().map(|[b, c]| {
+        (b as f64 / n_baseline as f64, c as f64 / n_current as f64)
+    }))))
+}
+
+/// The population stability index of the pairs of the proportions of the baseline and current
+/// values in every bin.
+fn psi(proportions: impl Iterator<Item = (f64, f64)>) -> f64 {
+    proportions
+        .map(|(b, c)| {
+            let (b, c) = (b.max(MIN_PROPORTION), c.max(MIN_PROPORTION));
+            (c - b) * (c / b).ln()
+        })
+        .sum()
+}
@@ -0,0 +1,208 @@
+use polars_core::prelude::*;
+
+/// Options for [`match_funnel`].
+#[derive(Clone, Debug, Default)]
+pub struct FunnelOptions {
+    /// The maximum time from the first to the last step of a match, as a duration for temporal
+    /// time columns and in the unit of the time column otherwise.
+    pub within: Option<Scalar>,
+}
+
+/// The value of `within` in the physical unit of a time column of type `time_dtype`.
+fn within_to_physical(within: &Scalar, time_dtype: &DataType) -> PolarsResult<i64> {
+    let within = within.clone().into_series(PlSmallStr::EMPTY);
+    let within = match (time_dtype, within.dtype()) {
+        #[cfg(all(feature = "dtype-datetime", feature = "dtype-duration"))]
+        (DataType::Datetime(tu, _), _) => within.cast(&DataType::Duration(*tu))?,
+        #[cfg(all(feature = "dtype-date", feature = "dtype-duration"))]
+        (DataType::Date, DataType::Duration(_)) => {
+            use polars_core::utils::arrow::temporal_conversions::MILLISECONDS_IN_DAY;
+
+            let ms = within.cast(&DataType::Duration(TimeUnit::Milliseconds))?;
+            ms.to_physical_repr().as_ref() / MILLISECONDS_IN_DAY
+        },
+        _ => within,
+    };
+    let within = within.to_physical_repr().strict_cast(&DataType::Int64)?;
+    within
+        .i64()?
+        .get(0)
+        .ok_or_else(|| polars_err!(InvalidOperation: "`within` of a funnel must not be null"))
+}
+
+/// Find the ordered sequences of events that match `steps` within every group of `group_by`.
+///
+/// Every step is a boolean column that marks the events of that step. A match is a sequence of
+/// events, one per step in order, where the last step happens at most `within` after the first
+/// when `within` is set. The match that completes first is taken, with the latest first step
+/// among those that complete at the same event, and the next match starts after it. The events
+/// must be sorted by `time_column` within every group, events with a null time are skipped.
+///
+/// The output has the group columns, a `match_id` column numbering the matches and a column per
+/// step with the time of its event, with the groups in order of appearance.
+pub fn match_funnel(
+    df: &DataFrame,
+    group_by: &[PlSmallStr],
+    time_column: &str,
+    steps: &[PlSmallStr],
+    options: &FunnelOptions,
+) -> PolarsResult<DataFrame> {
+    polars_ensure!(!steps.is_empty(), InvalidOperation: "a funnel needs at least one step");
+    let time = df.column(time_column)?;
+    polars_ensure!(
+        time.dtype().to_physical().is_integer(),
+        InvalidOperation: "the time column of a funnel must be temporal or an integer, got {}",
+        time.dtype()
+    );
+    let within = options
+        .within
+        .as_ref()
+        .map(|within| within_to_physical(within, time.dtype()))
+        .transpose()?;
+    let times = time.to_physical_repr().cast(&DataType::Int64)?;
+    let times = times.i64()?.iter().collect::<Vec<_>>();
+    let is_step = steps
+        .iter()
+        .map(|name| {
+            let ca = df.column(name)?.bool()?;
+            Ok(ca.iter().map(|v| v.unwrap_or(false)).collect::<Vec<_>>())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let groups = if group_by.is_empty() {
+        vec![(0..df.height() as IdxSize).collect::<Vec<_>>()]
+    } else {
+        let group_by = df.group_by_stable(group_by.iter().cloned())?;
+        group_by
+            .get_groups()
+            .iter()
+            .map(|g| match g {
+                GroupsIndicator::Idx((_, idx)) => idx.to_vec(),
+                GroupsIndicator::Slice([first, len]) => (first..first + len).collect(),
+            })
+            .collect()
+    };
+
+    let mut step_rows = vec![vec![]; steps.len()];
+    // The partial match up to every step with the latest start, as its start time and rows. A
+    // later start leaves more time for the remaining steps, so the others are never needed.
+    let mut partial: Vec<Option<(i64, Vec<IdxSize>)>> = vec![None; steps.len()];
+    for rows in groups {
+        partial.fill(None);
+        let mut prev = None;
+        for row in rows {
+            let Some(t) = times[row as usize] else {
+                continue;
+            };
+            polars_ensure!(
+                prev.is_none_or(|prev| prev <= t),
+                InvalidOperation: "the events of a funnel must be sorted by time within every group"
+            );
+            prev = Some(t);
+
+            // Extend the later steps first, so that an event is used for at most one step.
+            for step in (0..steps.len()).rev() {
+                if !is_step[step][row as usize] {
+                    continue;
+                }
+                let extended = if step == 0 {
+                    Some((t, vec![row]))
+                } else {
+                    partial[step - 1]
+                        .as_ref()
+                        .filter(|(start, _)| within.is_none_or(|within| t - start <= within))
+                        .map(|(start, match_rows)| {
+                            let mut match_rows = match_rows.clone();
+                            match_rows.push(row);
+                            (*start, match_rows)
+                        })
+                };
+                let Some((start, match_rows)) = extended else {
+                    continue;
+                };
+                if step + 1 == steps.len() {
+                    for (rows, row) in step_rows.iter_mut().zip(match_rows) {
+                        rows.push(row);
+                    }
+                    partial.fill(None);
+                    break;
+                }
+                if partial[step].as_ref().is_none_or(|(prev_start, _)| *prev_start <= start) {
+                    partial[step] = Some((start, match_rows));
+                }
+            }
+        }
+    }
+
+    let num_matches = step_rows[0].len();
+    let first_rows = IdxCa::from_vec(PlSmallStr::EMPTY, step_rows[0].clone());
+    let mut columns = df.select_to_vec(group_by)?;
+    for c in &mut columns {
+        *c = c.take(&first_rows)?;
+    }
+    columns.push(Column::new(
+        PlSmallStr::from_static("match_id"),
+        (0..num_matches as IdxSize).collect::<Vec<_>>(),
+    ));
+    for (name, rows) in steps.iter().zip(step_rows) {
+        let rows = IdxCa::from_vec(PlSmallStr::EMPTY, rows);
+        columns.push(time.take(&rows)?.with_name(name.clone()));
+    }
+    DataFrame::new(num_matches, columns)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_match_funnel() -> PolarsResult<()> {
+        let df = df![
+            "user" => [1, 2, 1, 1, 2, 1, 1, 1, 1],
+            "t" => [0i64, 1, 2, 3, 4, 10, 20, 21, 40],
+            "view" => [true, true, true, false, false, false, true, false, false],
+            "cart" => [false, false, false, true, true, false, false, true, false],
+            "buy" => [false, false, false, false, false, true, false, false, true],
+        ]?;
+        let steps = ["view".into(), "cart".into(), "buy".into()];
+        let options = FunnelOptions {
+            within: Some(Scalar::from(10i64)),
+        };
+
+        let out = match_funnel(&df, &["user".into()], "t", &steps, &options)?;
+        let expected = df![
+            "user" => [1],
+            "match_id" => [0 as IdxSize],
+            "view" => [2i64],
+            "cart" => [3i64],
+            "buy" => [10i64],
+        ]?;
+        assert!(out.equals(&expected));
+
+        let out = match_funnel(&df, &["user".into()], "t", &steps, &Default::default())?;
+        assert_eq!(Vec::from(out.column("view")?.i64()?), &[Some(2), Some(20)]);
+        assert_eq!(Vec::from(out.column("buy")?.i64()?), &[Some(10), Some(40)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_match_funnel_later_start() -> PolarsResult<()> {
+        // The first view and cart are too early for the buy, but the second ones are not.
+        let df = df![
+            "t" => [0i64, 1, 5, 6, 12],
+            "view" => [true, false, true, false, false],
+            "cart" => [false, true, false, true, false],
+            "buy" => [false, false, false, false, true],
+        ]?;
+        let steps = ["view".into(), "cart".into(), "buy".into()];
+        let options = FunnelOptions {
+            within: Some(Scalar::from(10i64)),
+        };
+
+        let out = match_funnel(&df, &[], "t", &steps, &options)?;
+        assert_eq!(Vec::from(out.column("view")?.i64()?), &[Some(5)]);
+        assert_eq!(Vec::from(out.column("cart")?.i64()?), &[Some(6)]);
+        assert_eq!(Vec::from(out.column("buy")?.i64()?), &[Some(12)]);
+        Ok(())
+    }
+}
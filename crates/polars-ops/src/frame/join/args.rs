@@ -15,6 +15,7 @@ pub type ChunkJoinOptIds = Vec<NullableIdxSize>;
 #[cfg(not(feature = "chunked_ids"))]
 pub type ChunkJoinIds = Vec<IdxSize>;
 
+use polars_core::utils::PromotionRules;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use strum_macros::IntoStaticStr;
@@ -30,6 +31,10 @@ pub struct JoinArgs {
     pub nulls_equal: bool,
     pub coalesce: JoinCoalesce,
     pub maintain_order: MaintainOrderJoin,
+    /// The rules to determine the supertype of join keys with different data types.
+    ///
+    /// With the default rules, only numeric keys are cast to a lossless supertype.
+    pub promotion_rules: PromotionRules,
 }
 
 impl JoinArgs {
@@ -128,6 +133,7 @@ impl JoinArgs {
             nulls_equal: false,
             coalesce: Default::default(),
             maintain_order: Default::default(),
+            promotion_rules: Default::default(),
         }
     }
 
@@ -141,6 +147,11 @@ impl JoinArgs {
         self
     }
 
+    pub fn with_promotion_rules(mut self, promotion_rules: PromotionRules) -> Self {
+        self.promotion_rules = promotion_rules;
+        self
+    }
+
     pub fn suffix(&self) -> &PlSmallStr {
         const DEFAULT: &PlSmallStr = &PlSmallStr::from_static("_right");
         self.suffix.as_ref().unwrap_or(DEFAULT)
@@ -2,6 +2,7 @@ use std::hash::Hash;
 
 use num_traits::Zero;
 use polars_core::hashing::_HASHMAP_INIT_SIZE;
+use polars_core::chunked_array::ops::row_encode::_get_rows_encoded_ca_unordered;
 use polars_core::prelude::*;
 use polars_core::series::BitRepr;
 use polars_core::utils::flatten::flatten_nullable;
@@ -505,6 +506,31 @@ fn dispatch_join_type(
     }
 }
 
+/// Split the row indices of both sides into the groups of equal `by` keys.
+fn split_by_groups(
+    left_by: &DataFrame,
+    right_by: &DataFrame,
+) -> PolarsResult<Vec<(Vec<IdxSize>, Vec<IdxSize>)>> {
+    let left_by = _get_rows_encoded_ca_unordered(PlSmallStr::EMPTY, left_by.columns())?;
+    let right_by = _get_rows_encoded_ca_unordered(PlSmallStr::EMPTY, right_by.columns())?;
+
+    let mut group_idx = PlHashMap::new();
+    let mut groups: Vec<(Vec<IdxSize>, Vec<IdxSize>)> = Vec::new();
+    for (idx, key) in right_by.iter().enumerate() {
+        let group = *group_idx.entry(key).or_insert_with(|| {
+            groups.push(Default::default());
+            groups.len() - 1
+        });
+        groups[group].1.push(idx as IdxSize);
+    }
+    for (idx, key) in left_by.iter().enumerate() {
+        if let Some(&group) = group_idx.get(&key) {
+            groups[group].0.push(idx as IdxSize);
+        }
+    }
+    Ok(groups)
+}
+
 pub trait AsofJoinBy: IntoDf {
     #[allow(clippy::too_many_arguments)]
     #[doc(hidden)]
@@ -517,17 +543,28 @@ pub trait AsofJoinBy: IntoDf {
         right_by: Vec<PlSmallStr>,
         strategy: AsofStrategy,
         tolerance: Option<AnyValue<'static>>,
+        tolerance_column: Option<&Series>,
+        end_column: Option<&Series>,
         suffix: Option<PlSmallStr>,
         slice: Option<(i64, usize)>,
         coalesce: bool,
         allow_eq: bool,
         check_sortedness: bool,
     ) -> PolarsResult<DataFrame> {
-        let (self_sliced_slot, left_slice_s); // Keeps temporaries alive.
+        polars_ensure!(
+            end_column.is_none() || strategy == AsofStrategy::Backward,
+            InvalidOperation: "asof join with an end column requires the backward strategy"
+        );
+        let (self_sliced_slot, left_slice_s, tolerance_slice_s); // Keeps temporaries alive.
         let (self_df, other_df, left_key, right_key);
+        let mut tolerance_column = tolerance_column;
         if let Some((offset, len)) = slice {
             self_sliced_slot = self.to_df().slice(offset, len);
             left_slice_s = left_on.slice(offset, len);
+            if let Some(tolerance) = tolerance_column {
+                tolerance_slice_s = tolerance.slice(offset, len);
+                tolerance_column = Some(&tolerance_slice_s);
+            }
             left_key = &left_slice_s;
             right_key = right_on;
             self_df = &self_sliced_slot;
@@ -546,7 +583,7 @@ pub trait AsofJoinBy: IntoDf {
         check_asof_columns(
             &left_asof,
             &right_asof,
-            tolerance.is_some(),
+            tolerance.is_some() || tolerance_column.is_some(),
             check_sortedness,
             !(left_by.is_empty() && right_by.is_empty()),
         )?;
@@ -562,15 +599,21 @@ pub trait AsofJoinBy: IntoDf {
             *r = r.to_physical_repr();
         }
 
-        let right_join_tuples = dispatch_join_type(
-            &left_asof,
-            &right_asof,
-            &mut left_by,
-            &mut right_by,
-            strategy,
-            tolerance,
-            allow_eq,
-        )?;
+        let mut take_idx = if let Some(end) = end_column {
+            let groups = split_by_groups(&left_by, &right_by)?;
+            join_end_column(left_key, right_key, end, &groups, tolerance, allow_eq)?
+        } else {
+            let right_join_tuples = dispatch_join_type(
+                &left_asof,
+                &right_asof,
+                &mut left_by,
+                &mut right_by,
+                strategy,
+                tolerance,
+                allow_eq,
+            )?;
+            IdxCa::with_chunk(PlSmallStr::EMPTY, right_join_tuples)
+        };
 
         let mut drop_these = right_by.get_column_names();
         if coalesce && left_asof_name == right_asof_name {
@@ -587,10 +630,11 @@ pub trait AsofJoinBy: IntoDf {
 
         let left = self_df.clone();
 
+        if let Some(tolerance) = tolerance_column {
+            take_idx = apply_tolerance_column(take_idx, left_key, right_key, tolerance)?;
+        }
         // SAFETY: join tuples are in bounds.
-        let right_df = unsafe {
-            proj_other_df.take_unchecked(&IdxCa::with_chunk(PlSmallStr::EMPTY, right_join_tuples))
-        };
+        let right_df = unsafe { proj_other_df.take_unchecked(&take_idx) };
 
         _finish_join(left, right_df, suffix)
     }
@@ -631,6 +675,8 @@ pub trait AsofJoinBy: IntoDf {
             tolerance,
             None,
             None,
+            None,
+            None,
             true,
             allow_eq,
             check_sortedness,
@@ -738,6 +784,75 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_asof_by_end_column() -> PolarsResult<()> {
+        let left = df![
+            "id" => ["a", "a", "a", "a", "b"],
+            "ts" => [5i64, 9, 10, 20, 3],
+        ]?;
+        let right = df![
+            "id" => ["a", "a", "b"],
+            "from" => [0i64, 10, 5],
+            "to" => [Some(8i64), None, Some(9)],
+            "v" => [1, 2, 3],
+        ]?;
+
+        let out = left._join_asof_by(
+            &right,
+            left.column("ts")?.as_materialized_series(),
+            right.column("from")?.as_materialized_series(),
+            vec!["id".into()],
+            vec!["id".into()],
+            AsofStrategy::Backward,
+            None,
+            None,
+            Some(right.column("to")?.as_materialized_series()),
+            None,
+            None,
+            true,
+            true,
+            true,
+        )?;
+        let out = out.column("v")?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), None, Some(2), Some(2), None]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_asof_by_end_column_overlapping() -> PolarsResult<()> {
+        let left = df![
+            "id" => ["a", "a", "a", "a", "b"],
+            "ts" => [1i64, 5, 7, 12, 3],
+        ]?;
+        // The range of "v" = 1 contains the ones of 2 and 3.
+        let right = df![
+            "id" => ["a", "a", "a", "b"],
+            "from" => [0i64, 2, 4, 0],
+            "to" => [Some(10i64), Some(4), Some(6), Some(2)],
+            "v" => [1, 2, 3, 4],
+        ]?;
+
+        let out = left._join_asof_by(
+            &right,
+            left.column("ts")?.as_materialized_series(),
+            right.column("from")?.as_materialized_series(),
+            vec!["id".into()],
+            vec!["id".into()],
+            AsofStrategy::Backward,
+            None,
+            None,
+            Some(right.column("to")?.as_materialized_series()),
+            None,
+            None,
+            true,
+            true,
+            true,
+        )?;
+        let out = out.column("v")?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), Some(3), Some(1), None, None]);
+        Ok(())
+    }
+
     #[test]
     fn test_asof_by3() -> PolarsResult<()> {
         let a = df![
@@ -2,10 +2,14 @@ mod default;
 mod groups;
 use std::borrow::Cow;
 use std::cmp::Ordering;
+use std::collections::BinaryHeap;
 
 use default::*;
 pub use groups::AsofJoinBy;
+use num_traits::Zero;
 use polars_core::prelude::*;
+use polars_core::with_match_physical_numeric_polars_type;
+use polars_utils::abs_diff::AbsDiff;
 use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -210,6 +214,14 @@ pub struct AsOfOptions {
     /// - "2h15m"
     /// - "1d6h"
     pub tolerance_str: Option<PlSmallStr>,
+    /// A column of the left frame with a tolerance per row, in the same unit as the asof column
+    /// or as a duration for temporal asof columns. Rows with a null tolerance are matched
+    /// without a tolerance.
+    pub tolerance_column: Option<PlSmallStr>,
+    /// A column of the right frame with the exclusive end of the range in which each right row is
+    /// valid, starting at its asof value. Left rows at or after the end are not matched. Rows with
+    /// a null end are valid indefinitely. Only supported with the backward strategy.
+    pub end_column: Option<PlSmallStr>,
     pub left_by: Option<Vec<PlSmallStr>>,
     pub right_by: Option<Vec<PlSmallStr>>,
     /// Allow equal matches
@@ -255,6 +267,144 @@ fn check_asof_columns(
     Ok(())
 }
 
+/// Cast a per-row `tolerance` to the physical type of an asof key of type `key_dtype`.
+fn tolerance_to_physical(tolerance: &Series, key_dtype: &DataType) -> PolarsResult<Series> {
+    use DataType::*;
+    let tolerance = match (key_dtype, tolerance.dtype()) {
+        #[cfg(all(feature = "dtype-datetime", feature = "dtype-duration"))]
+        (Datetime(tu, _), _) => tolerance.cast(&Duration(*tu))?,
+        #[cfg(feature = "dtype-duration")]
+        (Duration(tu), _) => tolerance.cast(&Duration(*tu))?,
+        #[cfg(all(feature = "dtype-time", feature = "dtype-duration"))]
+        (Time, _) => tolerance.cast(&Duration(TimeUnit::Nanoseconds))?,
+        #[cfg(all(feature = "dtype-date", feature = "dtype-duration"))]
+        (Date, Duration(_)) => {
+            use polars_core::utils::arrow::temporal_conversions::MILLISECONDS_IN_DAY;
+
+            let ms = tolerance.cast(&Duration(TimeUnit::Milliseconds))?;
+            ms.to_physical_repr().as_ref() / MILLISECONDS_IN_DAY
+        },
+        _ => tolerance.clone(),
+    };
+    tolerance.to_physical_repr().cast(&key_dtype.to_physical())
+}
+
+/// Remove the matches of `take_idx` of which the keys are further apart than the `tolerance` of
+/// their left row.
+fn apply_tolerance_column(
+    take_idx: IdxCa,
+    left_key: &Series,
+    right_key: &Series,
+    tolerance: &Series,
+) -> PolarsResult<IdxCa> {
+    polars_ensure!(
+        tolerance.len() == left_key.len(),
+        ShapeMismatch: "asof join tolerance has length {}, expected {}",
+        tolerance.len(), left_key.len()
+    );
+    polars_ensure!(
+        left_key.dtype().to_physical().is_primitive_numeric(),
+        InvalidOperation: "asof join tolerance is not supported for keys of type {}",
+        left_key.dtype()
+    );
+    let tolerance = tolerance_to_physical(tolerance, left_key.dtype())?;
+    let left_key = left_key.to_physical_repr();
+    // SAFETY: join tuples are in bounds.
+    let right_key = unsafe { right_key.to_physical_repr().take_unchecked(&take_idx) };
+    let within_tolerance = with_match_physical_numeric_polars_type!(left_key.dtype(), |$T| {
+        let l: &ChunkedArray<$T> = left_key.as_ref().as_ref();
+        let r: &ChunkedArray<$T> = right_key.as_ref().as_ref();
+        let t: &ChunkedArray<$T> = tolerance.as_ref().as_ref();
+        l.iter()
+            .zip(r.iter())
+            .zip(t.iter())
+            .map(|((l, r), t)| match (l, r, t) {
+                (Some(l), Some(r), Some(t)) => l.abs_diff(r) <= t.abs_diff(Zero::zero()),
+                _ => true,
+            })
+            .collect::<Vec<_>>()
+    });
+    Ok(take_idx
+        .iter()
+        .zip(within_tolerance)
+        .map(|(idx, within)| idx.filter(|_| within))
+        .collect())
+}
+
+/// Match every left row with the last right row of the same group of which the range
+/// `[right_key, end)` contains the left key, where a null `end` never ends.
+///
+/// The keys must be sorted within every group. As the ranges may overlap, the right rows of
+/// which the range has started are kept in a heap on their index, and the ranges that have
+/// ended are removed once they are at the top.
+fn join_end_column(
+    left_key: &Series,
+    right_key: &Series,
+    end: &Series,
+    groups: &[(Vec<IdxSize>, Vec<IdxSize>)],
+    tolerance: Option<AnyValue<'static>>,
+    allow_eq: bool,
+) -> PolarsResult<IdxCa> {
+    polars_ensure!(
+        end.len() == right_key.len(),
+        ShapeMismatch: "asof join end column has length {}, expected {}",
+        end.len(), right_key.len()
+    );
+    polars_ensure!(
+        left_key.dtype().to_physical().is_primitive_numeric(),
+        InvalidOperation: "asof join with an end column is not supported for keys of type {}",
+        left_key.dtype()
+    );
+    let end = end.cast(left_key.dtype())?;
+    let end = end.to_physical_repr();
+    let left_key = left_key.to_physical_repr();
+    let right_key = right_key.to_physical_repr();
+
+    let mut out = vec![None; left_key.len()];
+    with_match_physical_numeric_polars_type!(left_key.dtype(), |$T| {
+        let l: &ChunkedArray<$T> = left_key.as_ref().as_ref();
+        let r: &ChunkedArray<$T> = right_key.as_ref().as_ref();
+        let e: &ChunkedArray<$T> = end.as_ref().as_ref();
+        let tolerance = tolerance
+            .map(|t| t.try_extract::<<$T as PolarsNumericType>::Native>())
+            .transpose()?
+            .map(|t| t.abs_diff(Zero::zero()));
+
+        let mut started = BinaryHeap::new();
+        for (left_rows, right_rows) in groups {
+            started.clear();
+            let mut right_rows = right_rows.iter().copied().peekable();
+            for &left_idx in left_rows {
+                let Some(key) = l.get(left_idx as usize) else {
+                    continue;
+                };
+                while let Some(right_idx) = right_rows.next_if(|&right_idx| {
+                    r.get(right_idx as usize)
+                        .is_none_or(|start| ge_allow_eq(&key, &start, allow_eq))
+                }) {
+                    if r.get(right_idx as usize).is_some() {
+                        started.push(right_idx);
+                    }
+                }
+                // The left keys are sorted, so a range that has ended stays ended.
+                while let Some(&right_idx) = started.peek() {
+                    match e.get(right_idx as usize) {
+                        Some(end) if end <= key => {
+                            started.pop();
+                        },
+                        _ => break,
+                    }
+                }
+                out[left_idx as usize] = started.peek().copied().filter(|&right_idx| {
+                    let start = r.get(right_idx as usize).unwrap();
+                    tolerance.is_none_or(|t| key.abs_diff(start) <= t)
+                });
+            }
+        }
+    });
+    Ok(out.into_iter().collect())
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
@@ -278,6 +428,8 @@ pub trait AsofJoin: IntoDf {
         right_key: &Series,
         strategy: AsofStrategy,
         tolerance: Option<AnyValue<'static>>,
+        tolerance_column: Option<&Series>,
+        end_column: Option<&Series>,
         suffix: Option<PlSmallStr>,
         slice: Option<(i64, usize)>,
         coalesce: bool,
@@ -285,78 +437,96 @@ pub trait AsofJoin: IntoDf {
         check_sortedness: bool,
     ) -> PolarsResult<DataFrame> {
         let self_df = self.to_df();
+        polars_ensure!(
+            end_column.is_none() || strategy == AsofStrategy::Backward,
+            InvalidOperation: "asof join with an end column requires the backward strategy"
+        );
 
         check_asof_columns(
             left_key,
             right_key,
-            tolerance.is_some(),
+            tolerance.is_some() || tolerance_column.is_some(),
             check_sortedness,
             false,
         )?;
+        let (logical_left_key, logical_right_key) = (left_key, right_key);
         let left_key = left_key.to_physical_repr();
         let right_key = right_key.to_physical_repr();
 
-        let mut take_idx = match left_key.dtype() {
-            #[cfg(feature = "dtype-i128")]
-            DataType::Int128 => {
-                let ca = left_key.i128().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
-            },
-            DataType::Int64 => {
-                let ca = left_key.i64().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
-            },
-            DataType::Int32 => {
-                let ca = left_key.i32().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
-            },
-            #[cfg(feature = "dtype-u128")]
-            DataType::UInt128 => {
-                let ca = left_key.u128().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
-            },
-            DataType::UInt64 => {
-                let ca = left_key.u64().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
-            },
-            DataType::UInt32 => {
-                let ca = left_key.u32().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
-            },
-            #[cfg(feature = "dtype-f16")]
-            DataType::Float16 => {
-                let ca = left_key.f16().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
-            },
-            DataType::Float32 => {
-                let ca = left_key.f32().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
-            },
-            DataType::Float64 => {
-                let ca = left_key.f64().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
-            },
-            DataType::Boolean => {
-                let ca = left_key.bool().unwrap();
-                join_asof::<BooleanType>(ca, &right_key, strategy, allow_eq)
-            },
-            DataType::Binary => {
-                let ca = left_key.binary().unwrap();
-                join_asof::<BinaryType>(ca, &right_key, strategy, allow_eq)
-            },
-            DataType::String => {
-                let ca = left_key.str().unwrap();
-                let right_binary = right_key.cast(&DataType::Binary).unwrap();
-                join_asof::<BinaryType>(&ca.as_binary(), &right_binary, strategy, allow_eq)
-            },
-            DataType::Int8 | DataType::UInt8 | DataType::Int16 | DataType::UInt16 => {
-                let left_key = left_key.cast(&DataType::Int32).unwrap();
-                let right_key = right_key.cast(&DataType::Int32).unwrap();
-                let ca = left_key.i32().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
-            },
-            dt => polars_bail!(opq = asof_join, dt),
-        }?;
+        let mut take_idx = if let Some(end) = end_column {
+            let groups = [(
+                (0..left_key.len() as IdxSize).collect(),
+                (0..right_key.len() as IdxSize).collect(),
+            )];
+            let (left_key, right_key) = (logical_left_key, logical_right_key);
+            join_end_column(left_key, right_key, end, &groups, tolerance, allow_eq)?
+        } else {
+            match left_key.dtype() {
+                #[cfg(feature = "dtype-i128")]
+                DataType::Int128 => {
+                    let ca = left_key.i128().unwrap();
+                    join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
+                },
+                DataType::Int64 => {
+                    let ca = left_key.i64().unwrap();
+                    join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
+                },
+                DataType::Int32 => {
+                    let ca = left_key.i32().unwrap();
+                    join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
+                },
+                #[cfg(feature = "dtype-u128")]
+                DataType::UInt128 => {
+                    let ca = left_key.u128().unwrap();
+                    join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
+                },
+                DataType::UInt64 => {
+                    let ca = left_key.u64().unwrap();
+                    join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
+                },
+                DataType::UInt32 => {
+                    let ca = left_key.u32().unwrap();
+                    join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
+                },
+                #[cfg(feature = "dtype-f16")]
+                DataType::Float16 => {
+                    let ca = left_key.f16().unwrap();
+                    join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
+                },
+                DataType::Float32 => {
+                    let ca = left_key.f32().unwrap();
+                    join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
+                },
+                DataType::Float64 => {
+                    let ca = left_key.f64().unwrap();
+                    join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
+                },
+                DataType::Boolean => {
+                    let ca = left_key.bool().unwrap();
+                    join_asof::<BooleanType>(ca, &right_key, strategy, allow_eq)
+                },
+                DataType::Binary => {
+                    let ca = left_key.binary().unwrap();
+                    join_asof::<BinaryType>(ca, &right_key, strategy, allow_eq)
+                },
+                DataType::String => {
+                    let ca = left_key.str().unwrap();
+                    let right_binary = right_key.cast(&DataType::Binary).unwrap();
+                    join_asof::<BinaryType>(&ca.as_binary(), &right_binary, strategy, allow_eq)
+                },
+                DataType::Int8 | DataType::UInt8 | DataType::Int16 | DataType::UInt16 => {
+                    let left_key = left_key.cast(&DataType::Int32).unwrap();
+                    let right_key = right_key.cast(&DataType::Int32).unwrap();
+                    let ca = left_key.i32().unwrap();
+                    join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
+                },
+                dt => polars_bail!(opq = asof_join, dt),
+            }?
+        };
+        if let Some(tolerance) = tolerance_column {
+            take_idx =
+                apply_tolerance_column(take_idx, logical_left_key, logical_right_key, tolerance)?;
+        }
         try_raise_keyboard_interrupt();
 
         // Drop right join column.
@@ -294,36 +294,52 @@ pub trait DataFrameJoinOps: IntoDf {
                     args.nulls_equal,
                 ),
                 #[cfg(feature = "asof_join")]
-                JoinType::AsOf(options) => match (options.left_by, options.right_by) {
-                    (Some(left_by), Some(right_by)) => left_df._join_asof_by(
-                        other,
-                        s_left,
-                        s_right,
-                        left_by,
-                        right_by,
-                        options.strategy,
-                        options.tolerance.map(|v| v.into_value()),
-                        args.suffix.clone(),
-                        args.slice,
-                        should_coalesce,
-                        options.allow_eq,
-                        options.check_sortedness,
-                    ),
-                    (None, None) => left_df._join_asof(
-                        other,
-                        s_left,
-                        s_right,
-                        options.strategy,
-                        options.tolerance.map(|v| v.into_value()),
-                        args.suffix,
-                        args.slice,
-                        should_coalesce,
-                        options.allow_eq,
-                        options.check_sortedness,
-                    ),
-                    _ => {
-                        panic!("expected by arguments on both sides")
-                    },
+                JoinType::AsOf(options) => {
+                    let tolerance_column = options
+                        .tolerance_column
+                        .as_ref()
+                        .map(|name| left_df.column(name).map(|c| c.as_materialized_series()))
+                        .transpose()?;
+                    let end_column = options
+                        .end_column
+                        .as_ref()
+                        .map(|name| other.column(name).map(|c| c.as_materialized_series()))
+                        .transpose()?;
+                    match (options.left_by, options.right_by) {
+                        (Some(left_by), Some(right_by)) => left_df._join_asof_by(
+                            other,
+                            s_left,
+                            s_right,
+                            left_by,
+                            right_by,
+                            options.strategy,
+                            options.tolerance.map(|v| v.into_value()),
+                            tolerance_column,
+                            end_column,
+                            args.suffix.clone(),
+                            args.slice,
+                            should_coalesce,
+                            options.allow_eq,
+                            options.check_sortedness,
+                        ),
+                        (None, None) => left_df._join_asof(
+                            other,
+                            s_left,
+                            s_right,
+                            options.strategy,
+                            options.tolerance.map(|v| v.into_value()),
+                            tolerance_column,
+                            end_column,
+                            args.suffix,
+                            args.slice,
+                            should_coalesce,
+                            options.allow_eq,
+                            options.check_sortedness,
+                        ),
+                        _ => {
+                            panic!("expected by arguments on both sides")
+                        },
+                    }
                 },
                 #[cfg(feature = "iejoin")]
                 JoinType::IEJoin => {
@@ -629,7 +645,10 @@ trait DataFrameJoinOpsPrivate: IntoDf {
 impl DataFrameJoinOps for DataFrame {}
 impl DataFrameJoinOpsPrivate for DataFrame {}
 
-fn prepare_keys_multiple(s: &[Series], nulls_equal: bool) -> PolarsResult<BinaryOffsetChunked> {
+pub(crate) fn prepare_keys_multiple(
+    s: &[Series],
+    nulls_equal: bool,
+) -> PolarsResult<BinaryOffsetChunked> {
     let keys = s
         .iter()
         .map(|s| {
@@ -1,7 +1,31 @@
+#[cfg(feature = "ann")]
+pub mod ann;
+#[cfg(feature = "connected_components")]
+mod connected_components;
+#[cfg(feature = "diff_frames")]
+mod diff_frames;
+#[cfg(feature = "drift_report")]
+mod drift_report;
+#[cfg(feature = "funnel")]
+mod funnel;
+#[cfg(feature = "neighbor_agg")]
+mod neighbors;
 pub mod join;
 #[cfg(feature = "pivot")]
 pub mod unpivot;
 
+#[cfg(feature = "ann")]
+pub use ann::*;
+#[cfg(feature = "connected_components")]
+pub use connected_components::{ConnectedComponentsOptions, connected_components};
+#[cfg(feature = "diff_frames")]
+pub use diff_frames::diff_frames;
+#[cfg(feature = "drift_report")]
+pub use drift_report::drift_report;
+#[cfg(feature = "funnel")]
+pub use funnel::{FunnelOptions, match_funnel};
+#[cfg(feature = "neighbor_agg")]
+pub use neighbors::{NeighborAggregation, aggregate_neighbors};
 pub use join::*;
 #[cfg(feature = "to_dummies")]
 use polars_core::POOL;
@@ -24,6 +48,79 @@ impl IntoDf for DataFrame {
 impl<T: IntoDf> DataFrameOps for T {}
 
 pub trait DataFrameOps: IntoDf {
+    /// Build an approximate nearest neighbor index over the embeddings in the `Array` column
+    /// `column`, which can be queried with [`AnnIndex::search`].
+    #[cfg(feature = "ann")]
+    fn build_ann_index(&self, column: &str, options: &AnnIndexOptions) -> PolarsResult<AnnIndex> {
+        AnnIndex::build(self.to_df().column(column)?.array()?, options)
+    }
+
+    /// Compare the rows of two frames by the values of `key_columns`, returning the rows that
+    /// were removed, added or modified, with the old and new value of every other column.
+    ///
+    /// See [`diff_frames`] for the layout of the output.
+    #[cfg(feature = "diff_frames")]
+    fn diff_frames(
+        &self,
+        other: &DataFrame,
+        key_columns: &[PlSmallStr],
+    ) -> PolarsResult<DataFrame> {
+        diff_frames(self.to_df(), other, key_columns)
+    }
+
+    /// Compare the distribution of every column of `other` with this frame as the baseline,
+    /// such as a daily feed with a reference period.
+    ///
+    /// See [`drift_report`] for the statistics in the output.
+    #[cfg(feature = "drift_report")]
+    fn drift_report(&self, other: &DataFrame) -> PolarsResult<DataFrame> {
+        drift_report(self.to_df(), other)
+    }
+
+    /// Find the ordered sequences of events that match `steps` within every group, such as the
+    /// views, carts and purchases of every user.
+    ///
+    /// See [`match_funnel`] for how events are matched and the layout of the output.
+    #[cfg(feature = "funnel")]
+    fn match_funnel(
+        &self,
+        group_by: &[PlSmallStr],
+        time_column: &str,
+        steps: &[PlSmallStr],
+        options: &FunnelOptions,
+    ) -> PolarsResult<DataFrame> {
+        match_funnel(self.to_df(), group_by, time_column, steps, options)
+    }
+
+    /// Label the nodes of the graph with the edges `source` to `target` of this [`DataFrame`]
+    /// with their connected component, such as the clusters of matching records.
+    ///
+    /// See [`connected_components`] for the layout of the output.
+    #[cfg(feature = "connected_components")]
+    fn connected_components(
+        &self,
+        source: &str,
+        target: &str,
+        options: &ConnectedComponentsOptions,
+    ) -> PolarsResult<DataFrame> {
+        connected_components(self.to_df(), source, target, options)
+    }
+
+    /// Aggregate the `values` of the neighbors of every node of this [`DataFrame`], given by the
+    /// keys in the list column `neighbors`, such as the mean rating of the friends of a user.
+    ///
+    /// See [`aggregate_neighbors`] for the layout of the output.
+    #[cfg(feature = "neighbor_agg")]
+    fn aggregate_neighbors(
+        &self,
+        key: &str,
+        neighbors: &str,
+        values: &[PlSmallStr],
+        aggregation: NeighborAggregation,
+    ) -> PolarsResult<DataFrame> {
+        aggregate_neighbors(self.to_df(), key, neighbors, values, aggregation)
+    }
+
     /// Create dummy variables.
     ///
     /// # Example
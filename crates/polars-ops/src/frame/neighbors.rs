@@ -0,0 +1,221 @@
+use arrow::bitmap::Bitmap;
+use arrow::offset::OffsetsBuffer;
+use num_traits::AsPrimitive;
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+use polars_core::with_match_physical_numeric_polars_type;
+use polars_utils::total_ord::TotalOrd;
+
+/// How [`aggregate_neighbors`] combines the values of the neighbors of a node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NeighborAggregation {
+    /// The sum of the values, with 8 and 16-bit integers summed as `Int64`.
+    Sum,
+    /// The mean of the values as `Float64`, or null without values.
+    Mean,
+    /// The largest `k` values as a list, in descending order.
+    TopK(usize),
+}
+
+/// The neighbors of every node, as rows of the node frame.
+struct Adjacency {
+    offsets: OffsetsBuffer<i64>,
+    validity: Option<Bitmap>,
+    /// The row of every entry of the adjacency lists, or `IdxSize::MAX` if it isn't a node.
+    rows: Vec<IdxSize>,
+}
+
+impl Adjacency {
+    fn new(nodes: &DataFrame, key: &str, neighbors: &str) -> PolarsResult<Self> {
+        let key = nodes.column(key)?.as_materialized_series();
+        let lists = nodes.column(neighbors)?.list()?.rechunk();
+        let arr = lists.downcast_as_array();
+        let inner = lists.get_inner();
+        let dtype = try_get_supertype(key.dtype(), inner.dtype())?;
+        let mut endpoints = key.cast(&dtype)?;
+        endpoints.append(&inner.cast(&dtype)?)?;
+
+        // Hash the keys and the entries of the lists together, such that every group has the
+        // row of its node and the entries that refer to it.
+        let height = nodes.height() as IdxSize;
+        let groups = endpoints.group_tuples(true, false)?;
+        let is_null = endpoints.is_null();
+        let mut rows = vec![IdxSize::MAX; inner.len()];
+        for group in groups.iter() {
+            let (first, idx) = match group {
+                GroupsIndicator::Idx((first, idx)) => (first, idx.to_vec()),
+                GroupsIndicator::Slice([first, len]) => (first, (first..first + len).collect()),
+            };
+            if is_null.get(first as usize) == Some(true) {
+                continue;
+            }
+            let (nodes, entries): (Vec<_>, Vec<_>) = idx.into_iter().partition(|i| *i < height);
+            polars_ensure!(
+                nodes.len() <= 1,
+                InvalidOperation: "the keys of the nodes must be unique, found a duplicate key at \
+                rows {} and {}", nodes[0], nodes[1]
+            );
+            if let Some(node) = nodes.first() {
+                for entry in entries {
+                    rows[(entry - height) as usize] = *node;
+                }
+            }
+        }
+
+        Ok(Self {
+            offsets: arr.offsets().clone(),
+            validity: arr.validity().cloned(),
+            rows,
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.offsets.len_proxy()
+    }
+
+    /// The rows of the neighbors of `node`, or `None` if its adjacency list is null.
+    fn neighbors(&self, node: usize) -> Option<impl Iterator<Item = usize> + '_> {
+        if self.validity.as_ref().is_some_and(|v| !v.get_bit(node)) {
+            return None;
+        }
+        let (start, end) = self.offsets.start_end(node);
+        let rows = self.rows[start..end].iter().filter(|row| **row != IdxSize::MAX);
+        Some(rows.map(|row| *row as usize))
+    }
+}
+
+fn aggregate<T: PolarsNumericType>(
+    values: &ChunkedArray<T>,
+    adjacency: &Adjacency,
+    aggregation: NeighborAggregation,
+) -> Series {
+    let values = values.rechunk();
+    let arr = values.downcast_as_array();
+    let name = values.name().clone();
+    // Null values and unknown neighbors don't contribute.
+    let neighbor_values = |node| {
+        let rows = adjacency.neighbors(node)?;
+        Some(rows.filter_map(|row| arr.get(row)))
+    };
+    match aggregation {
+        NeighborAggregation::Sum => (0..adjacency.len())
+            .map(|node| neighbor_values(node).map(|v| v.sum::<T::Native>()))
+            .collect::<ChunkedArray<T>>()
+            .with_name(name)
+            .into_series(),
+        NeighborAggregation::Mean => (0..adjacency.len())
+            .map(|node| {
+                let (sum, count) = neighbor_values(node)?
+                    .fold((0.0, 0usize), |(sum, count), v| {
+                        (sum + AsPrimitive::<f64>::as_(v), count + 1)
+                    });
+                (count > 0).then(|| sum / count as f64)
+            })
+            .collect::<Float64Chunked>()
+            .with_name(name)
+            .into_series(),
+        NeighborAggregation::TopK(k) => {
+            let mut builder = ListPrimitiveChunkedBuilder::<T>::new(
+                name,
+                adjacency.len(),
+                adjacency.len() * k,
+                T::get_static_dtype(),
+            );
+            let mut top = Vec::new();
+            for node in 0..adjacency.len() {
+                match neighbor_values(node) {
+                    Some(v) => {
+                        top.clear();
+                        top.extend(v);
+                        // Stable, so ties keep the order of the neighbors.
+                        top.sort_by(|a, b| b.tot_cmp(a));
+                        top.truncate(k);
+                        builder.append_slice(&top);
+                    },
+                    None => builder.append_null(),
+                }
+            }
+            builder.finish().into_series()
+        },
+    }
+}
+
+/// Aggregate the `values` of the neighbors of every node of the graph with the adjacency lists
+/// `neighbors`.
+///
+/// Every row of `nodes` is a node with the key `key`, and the entries of its list in `neighbors`
+/// are the keys of its neighbors. The values of the neighbors are aggregated in a single pass
+/// over the adjacency lists, without gathering them per edge. Entries that are not the key of a
+/// node are skipped, a null adjacency list gives a null aggregate.
+///
+/// The output has the `key` column and a column with the aggregate of every column of `values`.
+pub fn aggregate_neighbors(
+    nodes: &DataFrame,
+    key: &str,
+    neighbors: &str,
+    values: &[PlSmallStr],
+    aggregation: NeighborAggregation,
+) -> PolarsResult<DataFrame> {
+    let adjacency = Adjacency::new(nodes, key, neighbors)?;
+    let mut columns = vec![nodes.column(key)?.clone()];
+    for name in values {
+        let mut s = nodes.column(name)?.as_materialized_series().clone();
+        polars_ensure!(
+            s.dtype().is_primitive_numeric(),
+            InvalidOperation: "neighbor aggregation is only supported for numeric columns, got \
+            {} for column '{}'", s.dtype(), name
+        );
+        let small_int = matches!(
+            s.dtype(),
+            DataType::Int8 | DataType::UInt8 | DataType::Int16 | DataType::UInt16
+        );
+        if aggregation == NeighborAggregation::Sum && small_int {
+            s = s.cast(&DataType::Int64)?;
+        }
+        let out = with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            aggregate(ca, &adjacency, aggregation)
+        });
+        columns.push(out.into_column());
+    }
+    DataFrame::new(nodes.height(), columns)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_aggregate_neighbors() -> PolarsResult<()> {
+        let neighbors = [Some(vec![2i64, 3]), Some(vec![1, 9]), Some(vec![]), None]
+            .into_iter()
+            .map(|list| list.map(|list| Series::new(PlSmallStr::EMPTY, list)))
+            .collect::<ListChunked>()
+            .with_name("neighbors".into());
+        let nodes = DataFrame::new(
+            4,
+            vec![
+                Column::new("id".into(), [1i64, 2, 3, 4]),
+                neighbors.into_column(),
+                Column::new("x".into(), [Some(10i16), None, Some(30), Some(40)]),
+            ],
+        )?;
+        let values = ["x".into()];
+
+        let out =
+            aggregate_neighbors(&nodes, "id", "neighbors", &values, NeighborAggregation::Sum)?;
+        assert_eq!(out.column("x")?.dtype(), &DataType::Int64);
+        assert_eq!(
+            Vec::from(out.column("x")?.i64()?),
+            &[Some(30), Some(10), Some(0), None]
+        );
+
+        let out =
+            aggregate_neighbors(&nodes, "id", "neighbors", &values, NeighborAggregation::Mean)?;
+        assert_eq!(
+            Vec::from(out.column("x")?.f64()?),
+            &[Some(30.0), Some(10.0), None, None]
+        );
+        Ok(())
+    }
+}
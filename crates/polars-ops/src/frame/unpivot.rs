@@ -3,9 +3,9 @@ use arrow::compute::concatenate::concatenate_unchecked;
 use polars_core::datatypes::{DataType, PlSmallStr};
 use polars_core::frame::DataFrame;
 use polars_core::frame::column::Column;
-use polars_core::prelude::{IntoVec, Series, UnpivotArgsIR};
+use polars_core::prelude::{IntoVec, PlHashMap, PlIndexSet, Series, UnpivotArgsIR};
 use polars_core::utils::merge_dtypes_many;
-use polars_error::{PolarsResult, polars_err};
+use polars_error::{PolarsResult, polars_ensure, polars_err};
 
 use crate::frame::IntoDf;
 
@@ -180,6 +180,145 @@ pub trait UnpivotDF: IntoDf {
 
         Ok(ids)
     }
+
+    /// Unpivot multiple groups of value columns at once.
+    ///
+    /// Every stubname selects the columns named `{stub}{separator}{suffix}`. The output has
+    /// the `index` columns, a String column `suffix_name` holding the suffix and one value column
+    /// per stubname, so that the values of all groups that share a suffix end up in the same row.
+    /// If a group has no column for a suffix that is present in another group its values are
+    /// null.
+    ///
+    /// ```ignore
+    /// # use polars_core::prelude::*;
+    /// let df = df!("id" => &[1, 2],
+    ///              "px_a" => &[1.0, 2.0],
+    ///              "px_b" => &[3.0, 4.0],
+    ///              "qty_a" => &[5, 6],
+    ///              "qty_b" => &[7, 8]
+    ///     )?;
+    ///
+    /// let long = df.wide_to_long(&["px".into(), "qty".into()], &["id".into()], "key".into(), "_")?;
+    /// println!("{:?}", long);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    /// Outputs:
+    /// ```text
+    ///  +-----+-----+-----+-----+
+    ///  | id  | key | px  | qty |
+    ///  | --- | --- | --- | --- |
+    ///  | i32 | str | f64 | i32 |
+    ///  +=====+=====+=====+=====+
+    ///  | 1   | "a" | 1.0 | 5   |
+    ///  +-----+-----+-----+-----+
+    ///  | 2   | "a" | 2.0 | 6   |
+    ///  +-----+-----+-----+-----+
+    ///  | 1   | "b" | 3.0 | 7   |
+    ///  +-----+-----+-----+-----+
+    ///  | 2   | "b" | 4.0 | 8   |
+    ///  +-----+-----+-----+-----+
+    /// ```
+    fn wide_to_long(
+        &self,
+        stubnames: &[PlSmallStr],
+        index: &[PlSmallStr],
+        suffix_name: PlSmallStr,
+        separator: &str,
+    ) -> PolarsResult<DataFrame> {
+        let self_ = self.to_df();
+        let schema = self_.schema();
+
+        // The suffixes in order of first occurrence and per stub the position of the column for
+        // every suffix.
+        let mut suffixes = PlIndexSet::<&str>::default();
+        let mut positions = vec![PlHashMap::<usize, usize>::default(); stubnames.len()];
+        for (pos, name) in schema.iter_names().enumerate() {
+            for (stub, positions) in stubnames.iter().zip(positions.iter_mut()) {
+                if let Some(suffix) = name
+                    .strip_prefix(stub.as_str())
+                    .and_then(|rest| rest.strip_prefix(separator))
+                {
+                    let (idx, _) = suffixes.insert_full(suffix);
+                    polars_ensure!(
+                        positions.insert(idx, pos).is_none(),
+                        Duplicate: "column '{}' matches stubname '{}' more than once", name, stub
+                    );
+                }
+            }
+        }
+
+        let len = self_.height();
+        let n_rows = len * suffixes.len();
+
+        let ids_ = self_.select(index.iter().cloned())?;
+        let mut ids = ids_.clear();
+        if ids.width() > 0 {
+            for _ in 0..suffixes.len() {
+                ids.vstack_mut_unchecked(&ids_);
+            }
+        }
+        ids.rechunk_mut_par();
+        drop(ids_);
+
+        let mut suffix_col = MutablePlString::with_capacity(n_rows);
+        for suffix in &suffixes {
+            suffix_col.extend_constant(len, Some(*suffix));
+        }
+        // SAFETY:
+        // The given dtype is correct
+        let suffix_col = unsafe {
+            Series::from_chunks_and_dtype_unchecked(
+                suffix_name,
+                vec![suffix_col.as_box()],
+                &DataType::String,
+            )
+        }
+        .into();
+
+        let columns = self_.columns();
+        let mut out = Vec::with_capacity(stubnames.len() + 1);
+        out.push(suffix_col);
+        for (stub, positions) in stubnames.iter().zip(&positions) {
+            // Values of one stub are placed in a single column, so we must find their supertype
+            let st = if positions.is_empty() {
+                DataType::Null
+            } else {
+                merge_dtypes_many(positions.values().map(|pos| columns[*pos].dtype()))?
+            };
+
+            let mut values = Vec::with_capacity(suffixes.len());
+            for idx in 0..suffixes.len() {
+                match positions.get(&idx) {
+                    Some(pos) => {
+                        let col = &columns[*pos];
+                        let value_col = col.cast(&st).map_err(
+                            |_| polars_err!(InvalidOperation: "'wide_to_long' not supported for dtype: {}\n\nConsider casting to String.", col.dtype()),
+                        )?;
+                        values.extend_from_slice(value_col.as_materialized_series().chunks())
+                    },
+                    None => values.extend_from_slice(
+                        Series::full_null(PlSmallStr::EMPTY, len, &st).chunks(),
+                    ),
+                }
+            }
+            let values_col = if values.is_empty() {
+                Column::new_empty(stub.clone(), &st)
+            } else {
+                let values_arr = concatenate_unchecked(&values)?;
+                // SAFETY:
+                // The given dtype is correct
+                unsafe {
+                    Series::from_chunks_and_dtype_unchecked(stub.clone(), vec![values_arr], &st)
+                }
+                .into()
+            };
+            out.push(values_col);
+        }
+
+        ids.hstack_mut(&out)?;
+
+        Ok(ids)
+    }
 }
 
 impl UnpivotDF for DataFrame {}
@@ -212,4 +351,42 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_wide_to_long() -> PolarsResult<()> {
+        let df = df!("id" => &[1, 2],
+         "px_a" => &[1.0, 2.0],
+         "qty_a" => &[5, 6],
+         "px_b" => &[3.0, 4.0],
+         "qty_c" => &[7, 8],
+         "other" => &["x", "y"]
+        )
+        .unwrap();
+
+        let long = df.wide_to_long(
+            &["px".into(), "qty".into()],
+            &["id".into()],
+            "key".into(),
+            "_",
+        )?;
+        assert_eq!(long.get_column_names(), &["id", "key", "px", "qty"]);
+        assert_eq!(
+            Vec::from(long.column("key")?.str()?),
+            &[Some("a"), Some("a"), Some("b"), Some("b"), Some("c"), Some("c")]
+        );
+        assert_eq!(
+            Vec::from(long.column("px")?.f64()?),
+            &[Some(1.0), Some(2.0), Some(3.0), Some(4.0), None, None]
+        );
+        assert_eq!(
+            Vec::from(long.column("qty")?.i32()?),
+            &[Some(5), Some(6), None, None, Some(7), Some(8)]
+        );
+        assert_eq!(
+            Vec::from(long.column("id")?.i32()?),
+            &[Some(1), Some(2), Some(1), Some(2), Some(1), Some(2)]
+        );
+
+        Ok(())
+    }
 }
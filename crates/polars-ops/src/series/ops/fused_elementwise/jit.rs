@@ -0,0 +1,389 @@
+//! Compilation of [`FusedElementwise`] chains to native code with Cranelift.
+//!
+//! A compiled kernel evaluates the whole chain per row in a single loop, keeping all the
+//! intermediate values in registers. Kernels are cached, so that a chain is compiled once and
+//! then reused for every batch it is evaluated on.
+use std::sync::{Arc, LazyLock, Mutex};
+
+use arrow::array::PrimitiveArray;
+use arrow::bitmap::Bitmap;
+use arrow::types::PrimitiveType;
+use cranelift_codegen::ir::condcodes::{FloatCC, IntCC};
+use cranelift_codegen::ir::{AbiParam, InstBuilder, MemFlags, Type, Value, types};
+use cranelift_codegen::settings::{self, Configurable};
+use cranelift_frontend::{FunctionBuilder, FunctionBuilderContext, Variable};
+use cranelift_jit::{JITBuilder, JITModule};
+use cranelift_module::{Linkage, Module};
+use num_traits::NumCast;
+use polars_core::config::verbose;
+use polars_core::prelude::*;
+
+use super::{FusedArithmeticOp, FusedComparisonOp, FusedElementwise};
+
+/// Chunks shorter than this are interpreted rather than compiled, as compiling takes longer
+/// than evaluating them. Kernels that are already compiled are always used.
+const MIN_COMPILE_LEN: usize = 1 << 14;
+
+/// Maximum number of kernels that are kept in the cache.
+const CACHE_CAPACITY: usize = 64;
+
+/// `fn(values, validity, out, out_validity, len)`, where the validities are one byte per row.
+type KernelFn<T> = unsafe extern "C" fn(*const T, *const u8, *mut T, *mut u8, i64);
+
+struct Kernel {
+    // Owns the memory of the function, which is freed when the kernel is dropped.
+    module: Option<JITModule>,
+    function: *const u8,
+}
+
+// SAFETY: the module is not used after the function is finalized, other than to free its memory
+// once the kernel is no longer referenced.
+unsafe impl Send for Kernel {}
+unsafe impl Sync for Kernel {}
+
+impl Drop for Kernel {
+    fn drop(&mut self) {
+        if let Some(module) = self.module.take() {
+            // SAFETY: the function can no longer be called.
+            unsafe { module.free_memory() };
+        }
+    }
+}
+
+/// The compiled kernels in insertion order. Literals are part of the key by their bits, see the
+/// `PartialEq` implementation of [`FusedElementwise`].
+static KERNELS: LazyLock<Mutex<PlIndexMap<(FusedElementwise, PrimitiveType), Arc<Kernel>>>> =
+    LazyLock::new(Default::default);
+
+fn get_kernel(
+    expr: &FusedElementwise,
+    primitive: PrimitiveType,
+    len: usize,
+) -> Option<Arc<Kernel>> {
+    let key = (expr.clone(), primitive);
+    if let Some(kernel) = KERNELS.lock().unwrap().get(&key) {
+        return Some(kernel.clone());
+    }
+    if len < MIN_COMPILE_LEN {
+        return None;
+    }
+
+    let float = match primitive {
+        PrimitiveType::Float32 => types::F32,
+        PrimitiveType::Float64 => types::F64,
+        _ => return None,
+    };
+    // Compile without holding the lock, so that other chains can be looked up in the meantime.
+    // If several threads compile the same chain, the kernel that is inserted first is kept.
+    match compile(expr, float) {
+        Ok(kernel) => {
+            let mut kernels = KERNELS.lock().unwrap();
+            if let Some(kernel) = kernels.get(&key) {
+                return Some(kernel.clone());
+            }
+            if kernels.len() == CACHE_CAPACITY {
+                kernels.shift_remove_index(0);
+            }
+            let kernel = Arc::new(kernel);
+            kernels.insert(key, kernel.clone());
+            Some(kernel)
+        },
+        Err(err) => {
+            if verbose() {
+                eprintln!("could not compile fused elementwise expression: {err}");
+            }
+            None
+        },
+    }
+}
+
+/// Evaluate `expr` on `arr` with a compiled kernel, returning `None` if there is none.
+pub(super) fn fused_elementwise_jit<T>(
+    arr: &PrimitiveArray<T>,
+    expr: &FusedElementwise,
+) -> Option<PrimitiveArray<T>>
+where
+    T: NumericNative,
+{
+    let kernel = get_kernel(expr, T::PRIMITIVE, arr.len())?;
+    // SAFETY: the kernel was compiled for `T`, as its primitive type is part of the cache key.
+    Some(unsafe { evaluate(&kernel, arr) })
+}
+
+/// # Safety
+/// The kernel must have been compiled for `T`.
+unsafe fn evaluate<T>(kernel: &Kernel, arr: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NumericNative,
+{
+    let function: KernelFn<T> = unsafe { std::mem::transmute(kernel.function) };
+
+    let len = arr.len();
+    let validity: Vec<u8> = match arr.validity() {
+        Some(validity) => validity.iter().map(u8::from).collect(),
+        None => vec![1; len],
+    };
+    let mut out = Vec::<T>::with_capacity(len);
+    let mut out_validity = vec![0u8; len];
+    // SAFETY: all buffers have `len` elements, all of which are written by the kernel.
+    unsafe {
+        function(
+            arr.values().as_ptr(),
+            validity.as_ptr(),
+            out.as_mut_ptr(),
+            out_validity.as_mut_ptr(),
+            len as i64,
+        );
+        out.set_len(len);
+    }
+
+    let validity: Bitmap = out_validity.iter().map(|v| *v != 0).collect();
+    let validity = (validity.unset_bits() > 0).then_some(validity);
+    PrimitiveArray::from_data_default(out.into(), validity)
+}
+
+fn compile(expr: &FusedElementwise, float: Type) -> PolarsResult<Kernel> {
+    let to_err = |err: &dyn std::fmt::Display| polars_err!(ComputeError: "{}", err);
+
+    let mut flags = settings::builder();
+    flags.set("opt_level", "speed").map_err(|e| to_err(&e))?;
+    let isa = cranelift_native::builder()
+        .map_err(|e| to_err(&e))?
+        .finish(settings::Flags::new(flags))
+        .map_err(|e| to_err(&e))?;
+    let mut module = JITModule::new(JITBuilder::with_isa(
+        isa,
+        cranelift_module::default_libcall_names(),
+    ));
+    let ptr = module.target_config().pointer_type();
+    polars_ensure!(ptr == types::I64, ComputeError: "only 64-bit targets are supported");
+
+    let mut ctx = module.make_context();
+    let params = &mut ctx.func.signature.params;
+    params.extend([AbiParam::new(ptr); 4]);
+    params.push(AbiParam::new(types::I64));
+
+    let mut fn_ctx = FunctionBuilderContext::new();
+    let mut b = FunctionBuilder::new(&mut ctx.func, &mut fn_ctx);
+    let entry = b.create_block();
+    let header = b.create_block();
+    let body = b.create_block();
+    let exit = b.create_block();
+
+    b.append_block_params_for_function_params(entry);
+    b.switch_to_block(entry);
+    let &[values, validity, out, out_validity, len] = b.block_params(entry) else {
+        unreachable!()
+    };
+    let i = Variable::new(0);
+    b.declare_var(i, types::I64);
+    let zero = b.ins().iconst(types::I64, 0);
+    b.def_var(i, zero);
+    b.ins().jump(header, &[]);
+
+    // while i < len
+    b.switch_to_block(header);
+    let idx = b.use_var(i);
+    let cond = b.ins().icmp(IntCC::SignedLessThan, idx, len);
+    b.ins().brif(cond, body, &[], exit, &[]);
+
+    b.switch_to_block(body);
+    let flags = MemFlags::trusted();
+    let offset = b.ins().imul_imm(idx, float.bytes() as i64);
+    let addr = b.ins().iadd(values, offset);
+    let value = b.ins().load(float, flags, addr, 0);
+    let addr = b.ins().iadd(validity, idx);
+    let valid = b.ins().load(types::I8, flags, addr, 0);
+
+    let mut emitter = Emitter {
+        b: &mut b,
+        float,
+        column: (value, valid),
+    };
+    let (result, result_valid) = emitter.emit(expr);
+
+    let addr = b.ins().iadd(out, offset);
+    b.ins().store(flags, result, addr, 0);
+    let addr = b.ins().iadd(out_validity, idx);
+    b.ins().store(flags, result_valid, addr, 0);
+    let next = b.ins().iadd_imm(idx, 1);
+    b.def_var(i, next);
+    b.ins().jump(header, &[]);
+
+    b.switch_to_block(exit);
+    b.ins().return_(&[]);
+    b.seal_all_blocks();
+    b.finalize();
+
+    let id = module
+        .declare_function("fused_elementwise", Linkage::Local, &ctx.func.signature)
+        .map_err(|e| to_err(&e))?;
+    module
+        .define_function(id, &mut ctx)
+        .map_err(|e| to_err(&e))?;
+    module.clear_context(&mut ctx);
+    module.finalize_definitions().map_err(|e| to_err(&e))?;
+    let function = module.get_finalized_function(id);
+    Ok(Kernel {
+        module: Some(module),
+        function,
+    })
+}
+
+/// Emits the instructions of a chain for a single row, producing its value and validity.
+///
+/// Validities are `i8` values that are either 0 or 1.
+struct Emitter<'a, 'b> {
+    b: &'a mut FunctionBuilder<'b>,
+    float: Type,
+    column: (Value, Value),
+}
+
+impl Emitter<'_, '_> {
+    fn emit(&mut self, expr: &FusedElementwise) -> (Value, Value) {
+        match expr {
+            FusedElementwise::Column => self.column,
+            FusedElementwise::Literal(v) => {
+                // Convert the literal in the same way as the interpreter.
+                let (value, is_valid) = if self.float == types::F32 {
+                    let v = v.and_then(<f32 as NumCast>::from);
+                    (self.b.ins().f32const(v.unwrap_or(0.0)), v.is_some())
+                } else {
+                    (self.b.ins().f64const(v.unwrap_or(0.0)), v.is_some())
+                };
+                let valid = self.b.ins().iconst(types::I8, is_valid as i64);
+                (value, valid)
+            },
+            FusedElementwise::Arithmetic { left, op, right } => {
+                let (l, l_valid) = self.emit(left);
+                let (r, r_valid) = self.emit(right);
+                let ins = self.b.ins();
+                let value = match op {
+                    FusedArithmeticOp::Add => ins.fadd(l, r),
+                    FusedArithmeticOp::Sub => ins.fsub(l, r),
+                    FusedArithmeticOp::Mul => ins.fmul(l, r),
+                    FusedArithmeticOp::Div => ins.fdiv(l, r),
+                };
+                (value, self.b.ins().band(l_valid, r_valid))
+            },
+            FusedElementwise::Ternary {
+                left,
+                op,
+                right,
+                truthy,
+                falsy,
+            } => {
+                let (l, l_valid) = self.emit(left);
+                let (r, r_valid) = self.emit(right);
+                // A null predicate selects the `falsy` branch.
+                let cmp = self.compare(*op, l, r);
+                let valid = self.b.ins().band(l_valid, r_valid);
+                let mask = self.b.ins().band(valid, cmp);
+
+                let (t, t_valid) = self.emit(truthy);
+                let (f, f_valid) = self.emit(falsy);
+                let value = self.b.ins().select(mask, t, f);
+                (value, self.b.ins().select(mask, t_valid, f_valid))
+            },
+        }
+    }
+
+    /// The comparisons of [`TotalOrd`](polars_utils::total_ord::TotalOrd), in which NaN is
+    /// equal to itself and larger than any other value.
+    fn compare(&mut self, op: FusedComparisonOp, l: Value, r: Value) -> Value {
+        match op {
+            FusedComparisonOp::Eq => self.tot_eq(l, r),
+            FusedComparisonOp::NotEq => {
+                let eq = self.tot_eq(l, r);
+                self.b.ins().bxor_imm(eq, 1)
+            },
+            FusedComparisonOp::Lt => self.tot_lt(l, r),
+            FusedComparisonOp::LtEq => {
+                let gt = self.tot_lt(r, l);
+                self.b.ins().bxor_imm(gt, 1)
+            },
+            FusedComparisonOp::Gt => self.tot_lt(r, l),
+            FusedComparisonOp::GtEq => {
+                let lt = self.tot_lt(l, r);
+                self.b.ins().bxor_imm(lt, 1)
+            },
+        }
+    }
+
+    fn is_nan(&mut self, v: Value) -> Value {
+        self.b.ins().fcmp(FloatCC::Unordered, v, v)
+    }
+
+    fn tot_eq(&mut self, l: Value, r: Value) -> Value {
+        let eq = self.b.ins().fcmp(FloatCC::Equal, l, r);
+        let l_nan = self.is_nan(l);
+        let r_nan = self.is_nan(r);
+        let both_nan = self.b.ins().band(l_nan, r_nan);
+        self.b.ins().bor(eq, both_nan)
+    }
+
+    fn tot_lt(&mut self, l: Value, r: Value) -> Value {
+        let lt = self.b.ins().fcmp(FloatCC::LessThan, l, r);
+        let l_not_nan = self.b.ins().fcmp(FloatCC::Ordered, l, l);
+        let r_nan = self.is_nan(r);
+        let only_r_nan = self.b.ins().band(l_not_nan, r_nan);
+        self.b.ins().bor(lt, only_r_nan)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::series::ops::fused_elementwise::interpret_arr;
+
+    #[test]
+    fn test_compiled_matches_interpreted() {
+        use {FusedArithmeticOp as A, FusedComparisonOp as C, FusedElementwise as E};
+
+        let lit = |v| Box::new(E::Literal(v));
+        let arith = |left, op, right| Box::new(E::Arithmetic { left, op, right });
+        let col = || Box::new(E::Column);
+
+        let arr = PrimitiveArray::<f64>::from([
+            Some(1.0),
+            None,
+            Some(f64::NAN),
+            Some(-0.0),
+            Some(1.5),
+            Some(f64::INFINITY),
+        ]);
+        for op in [C::Lt, C::NotEq, C::GtEq] {
+            let expr = E::Ternary {
+                left: arith(col(), A::Mul, lit(Some(2.0))),
+                op,
+                right: lit(Some(3.0)),
+                truthy: arith(col(), A::Div, lit(Some(0.0))),
+                falsy: arith(lit(None), A::Sub, col()),
+            };
+            let kernel = compile(&expr, types::F64).unwrap();
+            let compiled = unsafe { evaluate(&kernel, &arr) };
+            let interpreted = interpret_arr(&arr, &expr);
+            assert_eq!(compiled.validity(), interpreted.validity());
+            let compiled = compiled.values().iter().map(|v| v.to_bits());
+            let interpreted = interpreted.values().iter().map(|v| v.to_bits());
+            assert!(compiled.eq(interpreted));
+        }
+    }
+
+    #[test]
+    fn test_compiled_f32_literal() {
+        // A literal that doesn't fit in a `f32` saturates, as in the interpreter.
+        let expr = FusedElementwise::Arithmetic {
+            left: Box::new(FusedElementwise::Column),
+            op: FusedArithmeticOp::Add,
+            right: Box::new(FusedElementwise::Literal(Some(1e300))),
+        };
+        let arr = PrimitiveArray::<f32>::from([Some(1.0), None]);
+        let kernel = compile(&expr, types::F32).unwrap();
+        let compiled = unsafe { evaluate(&kernel, &arr) };
+        let interpreted = interpret_arr(&arr, &expr);
+        assert_eq!(compiled.validity(), interpreted.validity());
+        assert_eq!(compiled.value(0), f32::INFINITY);
+        assert_eq!(interpreted.value(0), f32::INFINITY);
+    }
+}
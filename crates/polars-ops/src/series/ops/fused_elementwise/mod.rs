@@ -0,0 +1,333 @@
+use std::hash::{Hash, Hasher};
+
+use arrow::array::PrimitiveArray;
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use num_traits::{Float, NumCast};
+use polars_core::prelude::*;
+use polars_utils::total_ord::TotalOrd;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "jit")]
+mod jit;
+
+/// Number of rows that are evaluated at once. The intermediate results of a block stay in the
+/// cache.
+const BLOCK_SIZE: usize = 1024;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FusedArithmeticOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FusedComparisonOp {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+/// A chain of elementwise operations on a single float column.
+///
+/// The chain is evaluated in a single pass over the column, block by block, so that none of the
+/// intermediate results are materialized for the whole column.
+///
+/// Literals are compared and hashed by their bits, so that chains with a `NaN` literal are equal
+/// to themselves and `0.0` and `-0.0` are told apart.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FusedElementwise {
+    /// The input column.
+    Column,
+    /// A scalar that is cast to the type of the column, `None` being null.
+    Literal(Option<f64>),
+    Arithmetic {
+        left: Box<FusedElementwise>,
+        op: FusedArithmeticOp,
+        right: Box<FusedElementwise>,
+    },
+    /// `when(left <op> right).then(truthy).otherwise(falsy)`.
+    Ternary {
+        left: Box<FusedElementwise>,
+        op: FusedComparisonOp,
+        right: Box<FusedElementwise>,
+        truthy: Box<FusedElementwise>,
+        falsy: Box<FusedElementwise>,
+    },
+}
+
+impl PartialEq for FusedElementwise {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Column, Self::Column) => true,
+            (Self::Literal(l), Self::Literal(r)) => l.map(f64::to_bits) == r.map(f64::to_bits),
+            (
+                Self::Arithmetic { left, op, right },
+                Self::Arithmetic {
+                    left: other_left,
+                    op: other_op,
+                    right: other_right,
+                },
+            ) => op == other_op && left == other_left && right == other_right,
+            (
+                Self::Ternary {
+                    left,
+                    op,
+                    right,
+                    truthy,
+                    falsy,
+                },
+                Self::Ternary {
+                    left: other_left,
+                    op: other_op,
+                    right: other_right,
+                    truthy: other_truthy,
+                    falsy: other_falsy,
+                },
+            ) => {
+                op == other_op
+                    && left == other_left
+                    && right == other_right
+                    && truthy == other_truthy
+                    && falsy == other_falsy
+            },
+            _ => false,
+        }
+    }
+}
+
+impl Eq for FusedElementwise {}
+
+impl Hash for FusedElementwise {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Column => {},
+            Self::Literal(v) => v.map(f64::to_bits).hash(state),
+            Self::Arithmetic { left, op, right } => {
+                left.hash(state);
+                op.hash(state);
+                right.hash(state);
+            },
+            Self::Ternary {
+                left,
+                op,
+                right,
+                truthy,
+                falsy,
+            } => {
+                left.hash(state);
+                op.hash(state);
+                right.hash(state);
+                truthy.hash(state);
+                falsy.hash(state);
+            },
+        }
+    }
+}
+
+impl FusedElementwise {
+    /// The number of operations in the chain.
+    pub fn num_operations(&self) -> usize {
+        match self {
+            Self::Column | Self::Literal(_) => 0,
+            Self::Arithmetic { left, right, .. } => {
+                1 + left.num_operations() + right.num_operations()
+            },
+            Self::Ternary {
+                left,
+                right,
+                truthy,
+                falsy,
+                ..
+            } => {
+                2 + left.num_operations()
+                    + right.num_operations()
+                    + truthy.num_operations()
+                    + falsy.num_operations()
+            },
+        }
+    }
+}
+
+/// Buffers for the values and validities of a block.
+struct BlockEvaluator<T> {
+    free_values: Vec<Vec<T>>,
+    free_masks: Vec<Vec<bool>>,
+}
+
+impl<T: Float + TotalOrd> BlockEvaluator<T> {
+    fn values(&mut self, len: usize) -> Vec<T> {
+        let mut buf = self.free_values.pop().unwrap_or_default();
+        buf.clear();
+        buf.resize(len, T::zero());
+        buf
+    }
+
+    fn mask(&mut self, len: usize) -> Vec<bool> {
+        let mut buf = self.free_masks.pop().unwrap_or_default();
+        buf.clear();
+        buf.resize(len, false);
+        buf
+    }
+
+    fn release(&mut self, (values, validity): (Vec<T>, Vec<bool>)) {
+        self.free_values.push(values);
+        self.free_masks.push(validity);
+    }
+
+    /// Evaluate `expr` on the block of `values`, returning the values and validity.
+    fn eval(
+        &mut self,
+        expr: &FusedElementwise,
+        values: &[T],
+        validity: Option<&Bitmap>,
+        offset: usize,
+    ) -> (Vec<T>, Vec<bool>) {
+        let len = values.len();
+        match expr {
+            FusedElementwise::Column => {
+                let mut out = self.values(len);
+                out.copy_from_slice(values);
+                let mut out_validity = self.mask(len);
+                match validity {
+                    None => out_validity.fill(true),
+                    Some(validity) => {
+                        for (i, v) in out_validity.iter_mut().enumerate() {
+                            *v = unsafe { validity.get_bit_unchecked(offset + i) };
+                        }
+                    },
+                }
+                (out, out_validity)
+            },
+            FusedElementwise::Literal(v) => {
+                let mut out = self.values(len);
+                let mut out_validity = self.mask(len);
+                if let Some(v) = v.and_then(<T as NumCast>::from) {
+                    out.fill(v);
+                    out_validity.fill(true);
+                }
+                (out, out_validity)
+            },
+            FusedElementwise::Arithmetic { left, op, right } => {
+                let (mut l, mut l_validity) = self.eval(left, values, validity, offset);
+                let (r, r_validity) = self.eval(right, values, validity, offset);
+                let l_r = l.iter_mut().zip(&r);
+                match op {
+                    FusedArithmeticOp::Add => l_r.for_each(|(l, r)| *l = *l + *r),
+                    FusedArithmeticOp::Sub => l_r.for_each(|(l, r)| *l = *l - *r),
+                    FusedArithmeticOp::Mul => l_r.for_each(|(l, r)| *l = *l * *r),
+                    FusedArithmeticOp::Div => l_r.for_each(|(l, r)| *l = *l / *r),
+                }
+                l_validity
+                    .iter_mut()
+                    .zip(&r_validity)
+                    .for_each(|(l, r)| *l &= *r);
+                self.release((r, r_validity));
+                (l, l_validity)
+            },
+            FusedElementwise::Ternary {
+                left,
+                op,
+                right,
+                truthy,
+                falsy,
+            } => {
+                let (l, mut mask) = self.eval(left, values, validity, offset);
+                let (r, r_validity) = self.eval(right, values, validity, offset);
+                // A null predicate selects the `falsy` branch.
+                let cmp: fn(&T, &T) -> bool = match op {
+                    FusedComparisonOp::Eq => T::tot_eq,
+                    FusedComparisonOp::NotEq => T::tot_ne,
+                    FusedComparisonOp::Lt => T::tot_lt,
+                    FusedComparisonOp::LtEq => T::tot_le,
+                    FusedComparisonOp::Gt => T::tot_gt,
+                    FusedComparisonOp::GtEq => T::tot_ge,
+                };
+                let values_iter = l.iter().zip(&r);
+                for ((m, r_valid), (l, r)) in mask.iter_mut().zip(&r_validity).zip(values_iter) {
+                    *m &= *r_valid && cmp(l, r);
+                }
+                self.release((l, r_validity));
+                self.free_values.push(r);
+
+                let (t, t_validity) = self.eval(truthy, values, validity, offset);
+                let (mut f, mut f_validity) = self.eval(falsy, values, validity, offset);
+                let f_iter = f.iter_mut().zip(f_validity.iter_mut());
+                let t_iter = t.iter().zip(&t_validity);
+                for ((f, f_valid), ((t, t_valid), m)) in f_iter.zip(t_iter.zip(&mask)) {
+                    if *m {
+                        *f = *t;
+                        *f_valid = *t_valid;
+                    }
+                }
+                self.release((t, t_validity));
+                self.free_masks.push(mask);
+                (f, f_validity)
+            },
+        }
+    }
+}
+
+fn fused_elementwise_arr<T>(arr: &PrimitiveArray<T>, expr: &FusedElementwise) -> PrimitiveArray<T>
+where
+    T: NumericNative + Float + TotalOrd,
+{
+    #[cfg(feature = "jit")]
+    if let Some(out) = jit::fused_elementwise_jit(arr, expr) {
+        return out;
+    }
+    interpret_arr(arr, expr)
+}
+
+fn interpret_arr<T>(arr: &PrimitiveArray<T>, expr: &FusedElementwise) -> PrimitiveArray<T>
+where
+    T: NumericNative + Float + TotalOrd,
+{
+    let mut evaluator = BlockEvaluator {
+        free_values: vec![],
+        free_masks: vec![],
+    };
+    let mut out = Vec::with_capacity(arr.len());
+    let mut out_validity = MutableBitmap::with_capacity(arr.len());
+    for (i, block) in arr.values().chunks(BLOCK_SIZE).enumerate() {
+        let offset = i * BLOCK_SIZE;
+        let (values, validity) = evaluator.eval(expr, block, arr.validity(), offset);
+        out.extend_from_slice(&values);
+        validity.iter().for_each(|v| out_validity.push(*v));
+        evaluator.release((values, validity));
+    }
+    let validity: Bitmap = out_validity.into();
+    let validity = (validity.unset_bits() > 0).then_some(validity);
+    PrimitiveArray::from_data_default(out.into(), validity)
+}
+
+fn fused_elementwise_ca<T>(ca: &ChunkedArray<T>, expr: &FusedElementwise) -> ChunkedArray<T>
+where
+    T: PolarsFloatType,
+    T::Native: Float + TotalOrd,
+{
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| fused_elementwise_arr(arr, expr));
+    ChunkedArray::from_chunk_iter(ca.name().clone(), chunks)
+}
+
+/// Evaluate a [`FusedElementwise`] chain on a float column.
+pub fn fused_elementwise_column(c: &Column, expr: &FusedElementwise) -> PolarsResult<Column> {
+    let s = c.as_materialized_series();
+    let out = match s.dtype() {
+        DataType::Float32 => fused_elementwise_ca(s.f32().unwrap(), expr).into_series(),
+        DataType::Float64 => fused_elementwise_ca(s.f64().unwrap(), expr).into_series(),
+        dt => polars_bail!(opq = fused_elementwise, dt),
+    };
+    Ok(out.into_column())
+}
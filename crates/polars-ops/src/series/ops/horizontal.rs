@@ -83,7 +83,11 @@ where
     arity::binary_elementwise_values(left, right, op)
 }
 
-fn min_max_binary_columns(left: &Column, right: &Column, min: bool) -> PolarsResult<Column> {
+pub(crate) fn min_max_binary_columns(
+    left: &Column,
+    right: &Column,
+    min: bool,
+) -> PolarsResult<Column> {
     if left.dtype().to_physical().is_primitive_numeric()
         && right.dtype().to_physical().is_primitive_numeric()
         && left.null_count() == 0
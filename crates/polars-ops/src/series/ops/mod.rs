@@ -20,6 +20,8 @@ mod ewm_by;
 mod floor_divide;
 #[cfg(feature = "fused")]
 mod fused;
+#[cfg(feature = "fused")]
+mod fused_elementwise;
 mod horizontal;
 mod index;
 #[cfg(feature = "index_of")]
@@ -47,6 +49,8 @@ mod moment;
 mod negate;
 #[cfg(feature = "pct_change")]
 mod pct_change;
+#[cfg(feature = "quant")]
+mod quant;
 #[cfg(feature = "rank")]
 mod rank;
 #[cfg(feature = "reinterpret")]
@@ -55,6 +59,8 @@ mod reinterpret;
 mod replace;
 #[cfg(feature = "rle")]
 mod rle;
+#[cfg(feature = "rule_failures")]
+mod rule_failures;
 #[cfg(feature = "rolling_window")]
 mod rolling;
 #[cfg(feature = "round_series")]
@@ -67,6 +73,8 @@ mod to_dummies;
 #[cfg(feature = "unique_counts")]
 mod unique;
 mod various;
+#[cfg(feature = "weighted")]
+mod weighted;
 
 #[cfg(feature = "abs")]
 pub use abs::*;
@@ -90,6 +98,8 @@ pub use ewm_by::*;
 pub use floor_divide::*;
 #[cfg(feature = "fused")]
 pub use fused::*;
+#[cfg(feature = "fused")]
+pub use fused_elementwise::*;
 pub use horizontal::*;
 pub use index::*;
 #[cfg(feature = "index_of")]
@@ -123,6 +133,8 @@ pub use negate::*;
 pub use pct_change::*;
 pub use polars_core::chunked_array::ops::search_sorted::SearchSortedSide;
 use polars_core::prelude::*;
+#[cfg(feature = "quant")]
+pub use quant::*;
 #[cfg(feature = "rank")]
 pub use rank::*;
 #[cfg(feature = "reinterpret")]
@@ -131,6 +143,8 @@ pub use reinterpret::*;
 pub use replace::*;
 #[cfg(feature = "rle")]
 pub use rle::*;
+#[cfg(feature = "rule_failures")]
+pub use rule_failures::*;
 #[cfg(feature = "rolling_window")]
 pub use rolling::*;
 #[cfg(feature = "round_series")]
@@ -143,6 +157,8 @@ pub use to_dummies::*;
 #[cfg(feature = "unique_counts")]
 pub use unique::*;
 pub use various::*;
+#[cfg(feature = "weighted")]
+pub use weighted::*;
 mod not;
 
 #[cfg(feature = "dtype-array")]
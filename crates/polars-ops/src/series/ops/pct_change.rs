@@ -23,3 +23,60 @@ pub fn pct_change(s: &Series, n: &Series) -> PolarsResult<Series> {
         Ok(Series::full_null(s.name().clone(), s.len(), s.dtype()))
     }
 }
+
+/// Index of the last row of which `by` is at most the target, for every target.
+#[cfg(feature = "pct_change_by")]
+fn lookback_idx(by: &Int64Chunked, targets: &Int64Chunked, by_is_sorted: bool) -> IdxCa {
+    // Positions of the rows ordered by `by`, with the null values of `by` left out.
+    let order = if by_is_sorted {
+        (0..by.len() as IdxSize).collect::<Vec<_>>()
+    } else {
+        by.arg_sort(SortOptions::default().with_maintain_order(true))
+            .into_no_null_iter()
+            .collect()
+    };
+    let values = by.iter().collect::<Vec<_>>();
+    let order = order
+        .into_iter()
+        .filter_map(|idx| Some((values[idx as usize]?, idx)))
+        .collect::<Vec<_>>();
+
+    targets
+        .iter()
+        .map(|target| {
+            let target = target?;
+            let pos = order.partition_point(|(v, _)| *v <= target);
+            (pos > 0).then(|| order[pos - 1].1)
+        })
+        .collect_ca(targets.name().clone())
+}
+
+/// Percentage change between every value and the value of the last row of which `by` is at
+/// most the target of that row.
+///
+/// `targets` are the values of `by` shifted back by the offset, so that the change is computed
+/// versus the value at `by - offset`.
+#[cfg(feature = "pct_change_by")]
+pub fn pct_change_by(
+    s: &Series,
+    by: &Int64Chunked,
+    targets: &Int64Chunked,
+    by_is_sorted: bool,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        s.len() == by.len(),
+        length_mismatch = "pct_change_by",
+        s.len(),
+        by.len()
+    );
+
+    match s.dtype() {
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => {},
+        DataType::Float64 | DataType::Float32 => {},
+        _ => return pct_change_by(&s.cast(&DataType::Float64)?, by, targets, by_is_sorted),
+    }
+
+    let prev = s.take(&lookback_idx(by, targets, by_is_sorted))?;
+    (s - &prev)?.divide(&prev)
+}
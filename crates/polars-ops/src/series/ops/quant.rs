@@ -0,0 +1,72 @@
+//! Single-pass performance statistics of prices and returns.
+use polars_core::prelude::*;
+
+fn to_f64(s: &Series) -> PolarsResult<Float64Chunked> {
+    polars_ensure!(
+        s.dtype().is_primitive_numeric() || s.dtype().is_decimal(),
+        InvalidOperation: "expected a numeric column, got {}", s.dtype()
+    );
+    Ok(s.cast(&DataType::Float64)?.f64()?.clone())
+}
+
+/// Relative decline of every value from the running maximum of the values up to it.
+///
+/// The values are expected to be positive, e.g. prices or the value of a portfolio.
+pub fn drawdown(s: &Series) -> PolarsResult<Series> {
+    let ca = to_f64(s)?;
+    let mut peak = f64::NEG_INFINITY;
+    let out: Float64Chunked = ca
+        .iter()
+        .map(|v| {
+            let v = v?;
+            peak = peak.max(v);
+            Some(v / peak - 1.0)
+        })
+        .collect_trusted();
+    Ok(out.with_name(s.name().clone()).into_series())
+}
+
+/// The largest [`drawdown`], as a (negative) fraction of the running maximum.
+pub fn max_drawdown(s: &Series) -> PolarsResult<Series> {
+    let ca = to_f64(s)?;
+    let mut peak = f64::NEG_INFINITY;
+    let mut max_drawdown = None;
+    for v in ca.iter().flatten() {
+        peak = peak.max(v);
+        let drawdown = v / peak - 1.0;
+        max_drawdown = Some(max_drawdown.map_or(drawdown, |dd: f64| dd.min(drawdown)));
+    }
+    Ok(Series::new(s.name().clone(), [max_drawdown]))
+}
+
+/// The fraction of the non-null values that is positive.
+pub fn hit_ratio(s: &Series) -> PolarsResult<Series> {
+    let ca = to_f64(s)?;
+    let (hits, count) = ca
+        .iter()
+        .flatten()
+        .fold((0usize, 0usize), |(hits, count), v| {
+            (hits + (v > 0.0) as usize, count + 1)
+        });
+    let ratio = (count > 0).then(|| hits as f64 / count as f64);
+    Ok(Series::new(s.name().clone(), [ratio]))
+}
+
+/// The Sharpe ratio of returns: their mean divided by their sample standard deviation,
+/// scaled by the square root of the number of periods per year.
+pub fn sharpe(s: &Series, annualization: f64) -> PolarsResult<Series> {
+    let ca = to_f64(s)?;
+    // Welford's algorithm, so the mean and variance are computed in a single pass.
+    let (mut count, mut mean, mut m2) = (0usize, 0.0, 0.0);
+    for v in ca.iter().flatten() {
+        count += 1;
+        let delta = v - mean;
+        mean += delta / count as f64;
+        m2 += delta * (v - mean);
+    }
+    let sharpe = (count > 1).then(|| {
+        let std = (m2 / (count - 1) as f64).sqrt();
+        mean / std * annualization.sqrt()
+    });
+    Ok(Series::new(s.name().clone(), [sharpe]))
+}
@@ -133,3 +133,62 @@ pub fn rolling_kurtosis(s: &Series, options: RollingOptionsFixedWindow) -> Polar
         dt => polars_bail!(opq = rolling_kurtosis, dt),
     }
 }
+
+/// Rolling median absolute deviation: the median of the absolute deviations of the values in
+/// the window from their median. Null values are ignored.
+#[cfg(feature = "outliers")]
+pub fn rolling_mad(s: &Series, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+    polars_ensure!(
+        options.weights.is_none(),
+        InvalidOperation: "rolling_mad does not support weights"
+    );
+    polars_ensure!(s.dtype().is_primitive_numeric(), opq = rolling_mad, s.dtype());
+    let s = s.cast(&DataType::Float64)?;
+    let values = s.f64()?.iter().collect::<Vec<_>>();
+
+    let len = values.len();
+    let window_size = options.window_size;
+    let right = if options.center {
+        window_size.div_ceil(2)
+    } else {
+        1
+    };
+    let position = |window: &[f64], v: f64| window.partition_point(|x| x.total_cmp(&v).is_lt());
+
+    // The non-null values of the current window, sorted.
+    let mut window = Vec::with_capacity(window_size);
+    let mut deviations = Vec::with_capacity(window_size);
+    let (mut start, mut end) = (0, 0);
+    let out: Float64Chunked = (0..len)
+        .map(|i| {
+            let (new_start, new_end) = (i.saturating_sub(window_size - right), len.min(i + right));
+            for &v in values[end..new_end].iter().flatten() {
+                window.insert(position(&window, v), v);
+            }
+            for &v in values[start..new_start].iter().flatten() {
+                window.remove(position(&window, v));
+            }
+            (start, end) = (new_start, new_end);
+
+            if window.is_empty() || window.len() < options.min_periods {
+                return None;
+            }
+            let median = sorted_median(&window);
+            deviations.clear();
+            deviations.extend(window.iter().map(|v| (v - median).abs()));
+            deviations.sort_unstable_by(f64::total_cmp);
+            Some(sorted_median(&deviations))
+        })
+        .collect_trusted();
+    Ok(out.with_name(s.name().clone()).into_series())
+}
+
+#[cfg(feature = "outliers")]
+fn sorted_median(values: &[f64]) -> f64 {
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    }
+}
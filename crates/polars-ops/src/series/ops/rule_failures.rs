@@ -0,0 +1,103 @@
+//! Evaluate many validation rules at once into a compact per-row report.
+use arrow::array::PrimitiveArray;
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How [`rule_failures`] reports the rules a row fails.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum RuleFailuresOutput {
+    /// A bitmask in which bit `i` is set if the row fails rule `i`.
+    ///
+    /// Up to 64 rules fit in a `UInt64`. With more rules the mask is an `Array` of `UInt64`
+    /// words, where word `i / 64` holds bit `i % 64`.
+    #[default]
+    Bitmask,
+    /// A list with the names of the rules the row fails.
+    Names,
+}
+
+/// The output dtype of [`rule_failures`] for `n_rules` rules.
+pub fn rule_failures_dtype(n_rules: usize, output: RuleFailuresOutput) -> DataType {
+    match output {
+        RuleFailuresOutput::Bitmask if n_rules <= 64 => DataType::UInt64,
+        RuleFailuresOutput::Bitmask => {
+            DataType::Array(Box::new(DataType::UInt64), n_rules.div_ceil(64))
+        },
+        RuleFailuresOutput::Names => DataType::List(Box::new(DataType::String)),
+    }
+}
+
+/// Report for every row which of the boolean `rules` it fails.
+///
+/// A rule fails if it is `false` or null. The rules are evaluated in a single pass and the
+/// output is never null.
+pub fn rule_failures(rules: &[Column], output: RuleFailuresOutput) -> PolarsResult<Column> {
+    polars_ensure!(
+        !rules.is_empty(),
+        ComputeError: "cannot compute rule failures without any rules"
+    );
+    let len = rules.iter().map(|c| c.len()).max().unwrap();
+    for c in rules {
+        polars_ensure!(
+            c.dtype() == &DataType::Boolean,
+            InvalidOperation: "rule '{}' must evaluate to Boolean, got {}", c.name(), c.dtype()
+        );
+        polars_ensure!(
+            c.len() == len || c.len() == 1,
+            ShapeMismatch: "cannot evaluate two Series of different lengths ({} and {len})", c.len()
+        );
+    }
+    if output == RuleFailuresOutput::Names {
+        let mut seen = PlHashSet::with_capacity(rules.len());
+        for c in rules {
+            polars_ensure!(
+                seen.insert(c.name()),
+                Duplicate: "rule name '{}' is not unique; name every rule with `alias`", c.name()
+            );
+        }
+    }
+
+    // Row-major: the words of a row are contiguous.
+    let n_words = rules.len().div_ceil(64);
+    let mut words = vec![0u64; len * n_words];
+    for (i, c) in rules.iter().enumerate() {
+        let (word, bit) = (i / 64, 1u64 << (i % 64));
+        let ca = c.bool()?;
+        if ca.len() == 1 && len != 1 {
+            if ca.get(0) != Some(true) {
+                words[word..].iter_mut().step_by(n_words).for_each(|w| *w |= bit);
+            }
+            continue;
+        }
+        for (row, v) in ca.iter().enumerate() {
+            if v != Some(true) {
+                words[row * n_words + word] |= bit;
+            }
+        }
+    }
+
+    let name = rules[0].name().clone();
+    let out = match output {
+        RuleFailuresOutput::Bitmask if n_words == 1 => {
+            UInt64Chunked::from_vec(name, words).into_series()
+        },
+        RuleFailuresOutput::Bitmask => {
+            let values = PrimitiveArray::from_vec(words).boxed();
+            ArrayChunked::from_aligned_values(name, &DataType::UInt64, n_words, vec![values], len)
+                .into_series()
+        },
+        RuleFailuresOutput::Names => {
+            let names = rules.iter().map(|c| c.name().as_str()).collect::<Vec<_>>();
+            let mut builder = ListStringChunkedBuilder::new(name, len, len);
+            for row in words.chunks_exact(n_words) {
+                let failed = (0..names.len()).filter(|i| row[i / 64] & (1u64 << (i % 64)) != 0);
+                builder.append_values_iter(failed.map(|i| names[i]));
+            }
+            builder.finish().into_series()
+        },
+    };
+    Ok(out.into())
+}
@@ -6,11 +6,17 @@ use super::*;
 type DummyType = u8;
 #[cfg(feature = "dtype-u8")]
 type DummyCa = UInt8Chunked;
+/// The data type of the indicator columns.
+#[cfg(feature = "dtype-u8")]
+pub const DUMMY_DTYPE: DataType = DataType::UInt8;
 
 #[cfg(not(feature = "dtype-u8"))]
 type DummyType = i32;
 #[cfg(not(feature = "dtype-u8"))]
 type DummyCa = Int32Chunked;
+/// The data type of the indicator columns.
+#[cfg(not(feature = "dtype-u8"))]
+pub const DUMMY_DTYPE: DataType = DataType::Int32;
 
 pub trait ToDummies {
     fn to_dummies(
@@ -71,6 +77,48 @@ impl ToDummies for Series {
     }
 }
 
+/// One-hot encode `s` into a struct with one indicator field per category.
+///
+/// The fields are named `{name}{separator}{category}` and follow the order of `categories`, so
+/// the output type only depends on the arguments. Values are compared to the categories by their
+/// string representation; values that are not in `categories` and nulls are all zeros.
+#[cfg(feature = "dtype-struct")]
+pub fn to_dummies_with_categories(
+    s: &Series,
+    categories: &[PlSmallStr],
+    separator: &str,
+    drop_first: bool,
+) -> PolarsResult<StructChunked> {
+    let col_name = s.name();
+    let categories = &categories[usize::from(drop_first).min(categories.len())..];
+
+    let mut index = PlHashMap::with_capacity(categories.len());
+    for (i, category) in categories.iter().enumerate() {
+        polars_ensure!(
+            index.insert(category.as_str(), i).is_none(),
+            Duplicate: "category '{}' is given more than once in `to_dummies`", category
+        );
+    }
+
+    let mut values = vec![vec![0 as DummyType; s.len()]; categories.len()];
+    let s = s.cast(&DataType::String)?;
+    for (row, v) in s.str()?.iter().enumerate() {
+        if let Some(i) = v.and_then(|v| index.get(v)) {
+            values[*i][row] = 1;
+        }
+    }
+
+    let fields = categories
+        .iter()
+        .zip(values)
+        .map(|(category, values)| {
+            let name = format_pl_smallstr!("{col_name}{separator}{category}");
+            DummyCa::from_vec(name, values).into_series()
+        })
+        .collect::<Vec<_>>();
+    StructChunked::from_series(col_name.clone(), s.len(), fields.iter())
+}
+
 fn dummies_helper_idx(groups: &[IdxSize], len: usize, name: PlSmallStr) -> DummyCa {
     let mut av = vec![0 as DummyType; len];
 
@@ -0,0 +1,121 @@
+//! Aggregations of values that are weighted by a second column.
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+use polars_compute::moment::VarState;
+use polars_core::prelude::*;
+use polars_utils::total_ord::TotalOrd;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum WeightedAgg {
+    Mean,
+    /// The variance with `ddof` delta degrees of freedom, treating the weights as frequencies.
+    Var(u8),
+    /// The smallest value for which the weights of the values up to it are at least the given
+    /// fraction of the total weight.
+    Quantile(f64),
+}
+
+impl Hash for WeightedAgg {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            WeightedAgg::Mean => {},
+            WeightedAgg::Var(ddof) => ddof.hash(state),
+            WeightedAgg::Quantile(quantile) => quantile.to_bits().hash(state),
+        }
+    }
+}
+
+impl Display for WeightedAgg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            WeightedAgg::Mean => "mean",
+            WeightedAgg::Var(_) => "var",
+            WeightedAgg::Quantile(_) => "quantile",
+        };
+        write!(f, "weighted_{s}")
+    }
+}
+
+fn to_f64(s: &Series, agg: WeightedAgg) -> PolarsResult<Float64Chunked> {
+    polars_ensure!(
+        s.dtype().is_primitive_numeric() || s.dtype().is_decimal() || s.dtype().is_null(),
+        InvalidOperation: "`{}` expects numeric columns, got {}", agg, s.dtype()
+    );
+    Ok(s.cast(&DataType::Float64)?.f64()?.rechunk().into_owned())
+}
+
+/// Aggregate the `values` weighted by the `weights`, ignoring the values whose value or weight is
+/// null. The result is null if the values that remain have no weight.
+///
+/// A single weight is broadcast to all values.
+pub fn weighted_agg(values: &Series, weights: &Series, agg: WeightedAgg) -> PolarsResult<Series> {
+    polars_ensure!(
+        values.len() == weights.len() || weights.len() == 1,
+        length_mismatch = agg,
+        values.len(),
+        weights.len()
+    );
+    let name = values.name().clone();
+    let values = to_f64(values, agg)?;
+    let weights = to_f64(weights, agg)?;
+    let weights = if weights.len() != values.len() {
+        weights.new_from_index(0, values.len())
+    } else {
+        weights
+    };
+
+    let pairs = values
+        .iter()
+        .zip(weights.iter())
+        .filter_map(|(v, w)| Some((v?, w?)))
+        .map(|(v, w)| {
+            polars_ensure!(
+                w >= 0.0,
+                InvalidOperation: "`{}` expects non-negative weights, got {}", agg, w
+            );
+            Ok((v, w))
+        });
+
+    let out = match agg {
+        WeightedAgg::Mean | WeightedAgg::Var(_) => {
+            // Welford's algorithm generalized to weights, which is stable in a single pass.
+            let mut state = VarState::default();
+            for pair in pairs {
+                let (v, w) = pair?;
+                state.insert_weighted(v, w);
+            }
+            match agg {
+                WeightedAgg::Var(ddof) => state.finalize(ddof),
+                _ => state.mean(),
+            }
+        },
+        WeightedAgg::Quantile(quantile) => {
+            polars_ensure!(
+                (0.0..=1.0).contains(&quantile),
+                ComputeError: "`quantile` should be between 0.0 and 1.0, got {}", quantile
+            );
+            let mut pairs = pairs
+                .filter(|pair| !matches!(pair, Ok((_, w)) if *w == 0.0))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            pairs.sort_unstable_by(|a, b| a.0.tot_cmp(&b.0));
+            let total: f64 = pairs.iter().map(|(_, w)| w).sum();
+            let target = quantile * total;
+            let mut cum_weight = 0.0;
+            pairs
+                .iter()
+                .find(|(_, w)| {
+                    cum_weight += w;
+                    cum_weight >= target
+                })
+                .or(pairs.last())
+                .map(|(v, _)| *v)
+        },
+    };
+    Ok(Series::new(name, [out]))
+}
@@ -0,0 +1,98 @@
+//! GeoParquet metadata of WKB-encoded geometry columns.
+//!
+//! Columns of the `geoarrow.wkb` extension type are listed in the `"geo"` key of the file
+//! metadata when writing. When reading, binary columns listed there are read as
+//! `geoarrow.wkb`, so that files written by other GeoParquet writers keep their geometries.
+use arrow::datatypes::{ArrowDataType, ArrowSchema, ExtensionType, Metadata};
+use polars_utils::pl_str::PlSmallStr;
+use serde_json::{Map, Value, json};
+
+use crate::parquet::metadata::KeyValue;
+
+const GEO_META_KEY: &str = "geo";
+const GEO_EXTENSION_NAME: &str = "geoarrow.wkb";
+const GEOPARQUET_VERSION: &str = "1.1.0";
+
+fn is_binary(dtype: &ArrowDataType) -> bool {
+    matches!(
+        dtype,
+        ArrowDataType::Binary | ArrowDataType::LargeBinary | ArrowDataType::BinaryView
+    )
+}
+
+/// The GeoParquet metadata of the geometry columns in `schema`, `None` if there are none.
+pub(super) fn geo_metadata_key(schema: &ArrowSchema) -> Option<KeyValue> {
+    let mut columns = Map::new();
+    let mut primary_column = None;
+
+    for field in schema.iter_values() {
+        let ArrowDataType::Extension(ext) = &field.dtype else {
+            continue;
+        };
+        if ext.name != GEO_EXTENSION_NAME {
+            continue;
+        }
+
+        let mut column = json!({"encoding": "WKB", "geometry_types": []});
+        // The GeoArrow metadata carries the coordinate reference system, which GeoParquet
+        // stores in the same format.
+        let crs = ext
+            .metadata
+            .as_deref()
+            .and_then(|md| serde_json::from_str::<Value>(md).ok())
+            .and_then(|mut md| md.get_mut("crs").map(Value::take));
+        if let Some(crs) = crs {
+            column["crs"] = crs;
+        }
+
+        primary_column.get_or_insert_with(|| field.name.to_string());
+        columns.insert(field.name.to_string(), column);
+    }
+
+    let metadata = json!({
+        "version": GEOPARQUET_VERSION,
+        "primary_column": primary_column?,
+        "columns": columns,
+    });
+    Some(KeyValue {
+        key: GEO_META_KEY.to_string(),
+        value: Some(metadata.to_string()),
+    })
+}
+
+/// Read the binary columns listed in the GeoParquet metadata as `geoarrow.wkb`.
+///
+/// Missing or malformed GeoParquet metadata is ignored.
+pub(super) fn apply_geo_metadata(schema: &mut ArrowSchema, metadata: &Metadata) {
+    let Some(geo) = metadata
+        .get(GEO_META_KEY)
+        .and_then(|geo| serde_json::from_str::<Value>(geo).ok())
+    else {
+        return;
+    };
+    let Some(columns) = geo.get("columns").and_then(Value::as_object) else {
+        return;
+    };
+
+    for (name, column) in columns {
+        let is_wkb = column
+            .get("encoding")
+            .and_then(Value::as_str)
+            .is_some_and(|encoding| encoding.eq_ignore_ascii_case("WKB"));
+        let Some(field) = schema.get_mut(name.as_str()) else {
+            continue;
+        };
+        if !is_wkb || !is_binary(&field.dtype) {
+            continue;
+        }
+
+        let metadata = column
+            .get("crs")
+            .map(|crs| PlSmallStr::from_string(json!({ "crs": crs }).to_string()));
+        field.dtype = ArrowDataType::Extension(Box::new(ExtensionType {
+            name: PlSmallStr::from_static(GEO_EXTENSION_NAME),
+            inner: std::mem::take(&mut field.dtype),
+            metadata,
+        }));
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "geo")]
+mod geo;
 pub mod read;
 pub mod write;
 
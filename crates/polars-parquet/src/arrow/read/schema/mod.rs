@@ -53,7 +53,11 @@ pub fn infer_schema_with_options(
     let mut metadata = parse_key_value_metadata(file_metadata.key_value_metadata());
 
     let schema = read_schema_from_metadata(&mut metadata)?;
-    Ok(schema.unwrap_or_else(|| {
+    #[allow(unused_mut)]
+    let mut schema = schema.unwrap_or_else(|| {
         parquet_to_arrow_schema_with_options(file_metadata.schema().fields(), options)
-    }))
+    });
+    #[cfg(feature = "geo")]
+    crate::arrow::geo::apply_geo_metadata(&mut schema, &metadata);
+    Ok(schema)
 }
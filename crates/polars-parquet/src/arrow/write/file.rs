@@ -86,13 +86,22 @@ impl<W: Write> FileWriter<W> {
     /// Writes the footer of the parquet file. Returns the total size of the file.
     /// If `key_value_metadata` is provided, the value is taken as-is. If it is not provided,
     /// the Arrow schema is added to the metadata.
+    ///
+    /// GeoParquet metadata is added for geometry columns unless it is already provided.
     pub fn end(
         &mut self,
         key_value_metadata: Option<Vec<KeyValue>>,
         column_options: &[ColumnWriteOptions],
     ) -> PolarsResult<u64> {
-        let key_value_metadata = key_value_metadata
+        #[allow(unused_mut)]
+        let mut key_value_metadata = key_value_metadata
             .unwrap_or_else(|| vec![schema_to_metadata_key(&self.schema, column_options)]);
+        #[cfg(feature = "geo")]
+        if let Some(geo) = crate::arrow::geo::geo_metadata_key(&self.schema)
+            && !key_value_metadata.iter().any(|kv| kv.key == geo.key)
+        {
+            key_value_metadata.push(geo);
+        }
         Ok(self.writer.end(Some(key_value_metadata))?)
     }
 
@@ -1,4 +1,6 @@
 use polars_core::prelude::*;
+#[cfg(feature = "array_linalg")]
+use polars_ops::chunked_array::array::VectorNorm;
 
 use crate::dsl::function_expr::ArrayFunction;
 use crate::prelude::*;
@@ -211,4 +213,43 @@ impl ArrayNameSpace {
             variant: EvalVariant::ArrayAgg,
         }
     }
+
+    /// Get the linear algebra namespace.
+    #[cfg(feature = "array_linalg")]
+    pub fn linalg(self) -> ArrayLinalgNameSpace {
+        ArrayLinalgNameSpace(self.0)
+    }
+}
+
+/// Linear algebra on [`Series`] of [`DataType::Array`], where every subarray is a vector.
+#[cfg(feature = "array_linalg")]
+pub struct ArrayLinalgNameSpace(pub Expr);
+
+#[cfg(feature = "array_linalg")]
+impl ArrayLinalgNameSpace {
+    /// Compute the dot product of every subarray with the subarray of `other`.
+    pub fn dot<E: Into<Expr>>(self, other: E) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::ArrayExpr(ArrayFunction::Dot), other.into())
+    }
+
+    /// Multiply every matrix, an array of row arrays, with the vector in `vector`.
+    pub fn matvec<E: Into<Expr>>(self, vector: E) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::ArrayExpr(ArrayFunction::MatVec), vector.into())
+    }
+
+    /// Compute the norm of every subarray.
+    pub fn norm(self, kind: VectorNorm) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::Norm(kind)))
+    }
+
+    /// Compute the cosine similarity of every subarray with the subarray of `other`.
+    pub fn cosine_similarity<E: Into<Expr>>(self, other: E) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::ArrayExpr(ArrayFunction::CosineSimilarity),
+            other.into(),
+        )
+    }
 }
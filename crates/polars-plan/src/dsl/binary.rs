@@ -79,4 +79,20 @@ impl BinaryNameSpace {
         self.0
             .map_binary(FunctionExpr::BinaryExpr(BinaryFunction::Tail), n)
     }
+
+    /// Deterministically encrypt the binary values.
+    ///
+    /// The key material is resolved through the provider of `key` when the query runs.
+    #[cfg(feature = "crypto")]
+    pub fn encrypt(self, key: CryptoKey) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Encrypt { key }))
+    }
+
+    /// Decrypt values that were encrypted with `encrypt`.
+    #[cfg(feature = "crypto")]
+    pub fn decrypt(self, key: CryptoKey) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Decrypt { key }))
+    }
 }
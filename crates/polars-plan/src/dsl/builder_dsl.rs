@@ -304,12 +304,19 @@ impl DslBuilder {
         .into()
     }
 
-    pub fn explode(self, columns: Selector, options: ExplodeOptions, allow_empty: bool) -> Self {
+    pub fn explode(
+        self,
+        columns: Selector,
+        options: ExplodeOptions,
+        mismatch: ExplodeLengthMismatch,
+        allow_empty: bool,
+    ) -> Self {
         DslPlan::MapFunction {
             input: Arc::new(self.0),
             function: DslFunction::Explode {
                 columns,
                 options,
+                mismatch,
                 allow_empty,
             },
         }
@@ -8,6 +8,20 @@ impl CategoricalNameSpace {
         self.0.map_unary(CategoricalFunction::GetCategories)
     }
 
+    /// Append the `categories` which are not a category yet to an Enum.
+    ///
+    /// The existing values keep their category ids.
+    pub fn extend_categories(self, categories: Vec<PlSmallStr>) -> Expr {
+        self.0
+            .map_unary(CategoricalFunction::ExtendCategories(categories))
+    }
+
+    /// Cast an Enum to the Enum of `categories`, appending the categories missing from it.
+    pub fn cast_with_added_categories(self, categories: Vec<PlSmallStr>) -> Expr {
+        self.0
+            .map_unary(CategoricalFunction::CastWithAddedCategories(categories))
+    }
+
     #[cfg(feature = "strings")]
     pub fn len_bytes(self) -> Expr {
         self.0.map_unary(CategoricalFunction::LenBytes)
@@ -1,18 +1,33 @@
 use std::sync::{Arc, LazyLock, RwLock};
 
-use polars_error::PolarsResult;
+use bytes::Bytes;
+use polars_error::{PolarsResult, polars_err};
 
 use super::AnonymousColumnsUdf;
 use super::agg::AnonymousStreamingAgg;
+use crate::dsl::{Expr, LazySerde};
+use crate::prelude::FunctionOptions;
 
 // Can be used to have named anonymous functions.
 // The receiver must have implemented this registry and map the names to the proper UDFs.
+//
+// The registry hands out trait objects, so the functions must be compiled together with polars.
+// Plugins that are loaded from a shared library should use the FFI plugins instead.
 pub trait ExprRegistry: Sync + Send {
     #[allow(unused)]
     fn get_function(&self, name: &str, payload: &[u8]) -> Option<Arc<dyn AnonymousColumnsUdf>> {
         None
     }
 
+    /// The options of the function registered under `name`, e.g. whether it is elementwise or
+    /// an aggregation.
+    ///
+    /// Defaults to elementwise.
+    #[allow(unused)]
+    fn get_function_options(&self, name: &str, payload: &[u8]) -> FunctionOptions {
+        FunctionOptions::elementwise()
+    }
+
     #[allow(unused)]
     fn get_agg(
         &self,
@@ -30,3 +45,88 @@ pub fn set_named_serde_registry(reg: Arc<dyn ExprRegistry>) {
     let mut lock = NAMED_SERDE_REGISTRY_EXPR.write().unwrap();
     *lock = Some(reg);
 }
+
+/// Call the function that the named serde registry has under `name` on `inputs`.
+///
+/// The `payload` is opaque to polars and passed to the registry, e.g. to hold the keyword
+/// arguments of the function. The call is serialized as the name and the payload, so
+/// deserializing it requires a registry with the same function.
+pub fn call_named_function(
+    name: &str,
+    inputs: Vec<Expr>,
+    payload: impl Into<Bytes>,
+) -> PolarsResult<Expr> {
+    let payload = payload.into();
+    let registry = NAMED_SERDE_REGISTRY_EXPR
+        .read()
+        .unwrap()
+        .clone()
+        .ok_or_else(|| polars_err!(ComputeError: "named serde registry not set"))?;
+    let function = registry.get_function(name, &payload).ok_or_else(|| {
+        polars_err!(ComputeError: "function '{}' not found in named serde registry", name)
+    })?;
+    let options = registry.get_function_options(name, &payload);
+
+    Ok(Expr::AnonymousFunction {
+        input: inputs,
+        function: LazySerde::Named {
+            name: name.to_string(),
+            payload: Some(payload),
+            value: Some(super::SpecialEq::new(function)),
+        },
+        options,
+        fmt_str: Box::new(name.into()),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+    use crate::dsl::{BaseColumnUdf, col};
+
+    struct AddN;
+
+    impl ExprRegistry for AddN {
+        fn get_function(&self, name: &str, payload: &[u8]) -> Option<Arc<dyn AnonymousColumnsUdf>> {
+            let n = payload[0] as i64;
+            (name == "add_n").then(|| {
+                Arc::new(BaseColumnUdf::new(
+                    move |c: &mut [Column]| Ok(&c[0] + n),
+                    |_: &Schema, f: &[Field]| Ok(f[0].clone()),
+                )) as _
+            })
+        }
+    }
+
+    #[test]
+    fn test_call_named_function_roundtrip() -> PolarsResult<()> {
+        set_named_serde_registry(Arc::new(AddN));
+        assert!(call_named_function("unknown", vec![col("a")], vec![2]).is_err());
+
+        let expr = call_named_function("add_n", vec![col("a")], vec![2])?;
+        let Expr::AnonymousFunction { options, .. } = &expr else {
+            unreachable!()
+        };
+        assert!(options.is_elementwise());
+
+        let mut buf = vec![];
+        polars_utils::pl_serialize::serialize_into_writer::<_, _, true>(&mut buf, &expr)?;
+        let expr: Expr =
+            polars_utils::pl_serialize::deserialize_from_reader::<_, _, true>(buf.as_slice())?;
+        let Expr::AnonymousFunction { function, .. } = expr else {
+            unreachable!()
+        };
+        let udf = function.materialize()?;
+
+        let field = Field::new("a".into(), DataType::Int64);
+        let schema = Schema::from_iter([field.clone()]);
+        assert_eq!(udf.get_field(&schema, &[field])?.dtype(), &DataType::Int64);
+
+        let mut inputs = [Column::new("a".into(), [1i64, 2])];
+        let out = udf.call_udf(&mut inputs)?;
+        assert_eq!(Vec::from(out.i64()?), &[Some(3), Some(4)]);
+        Ok(())
+    }
+}
@@ -203,6 +203,14 @@ pub struct CastColumnsPolicy {
 
     pub missing_struct_fields: MissingColumnsPolicy,
     pub extra_struct_fields: ExtraColumnsPolicy,
+
+    /// Struct columns whose unused fields were pruned from the projection by the optimizer.
+    /// Extra struct fields in the file are ignored for these columns.
+    pub pruned_struct_columns: Option<Arc<[PlSmallStr]>>,
+
+    /// Columns whose dtype was overridden by the user or unified across files. These are cast to
+    /// the target dtype regardless of the options above.
+    pub overridden_columns: Option<Arc<[PlSmallStr]>>,
 }
 
 impl CastColumnsPolicy {
@@ -218,7 +226,23 @@ impl CastColumnsPolicy {
         categorical_to_string: false,
         missing_struct_fields: MissingColumnsPolicy::Raise,
         extra_struct_fields: ExtraColumnsPolicy::Raise,
+        pruned_struct_columns: None,
+        overridden_columns: None,
     };
+
+    /// Whether the unused struct fields of `column_name` were pruned by the optimizer.
+    pub fn is_pruned_struct_column(&self, column_name: &str) -> bool {
+        self.pruned_struct_columns
+            .as_deref()
+            .is_some_and(|columns| columns.iter().any(|c| c == column_name))
+    }
+
+    /// Whether the dtype of `column_name` was overridden by the user or unified across files.
+    pub fn is_overridden_column(&self, column_name: &str) -> bool {
+        self.overridden_columns
+            .as_deref()
+            .is_some_and(|columns| columns.iter().any(|c| c == column_name))
+    }
 }
 
 impl Default for CastColumnsPolicy {
@@ -237,6 +261,23 @@ pub enum ExtraColumnsPolicy {
     Ignore,
 }
 
+/// How the schemas of the files of a multi-file scan are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum SchemaUnificationPolicy {
+    /// Use the schema of the first file. The other files are matched against it according to the
+    /// cast, missing and extra columns policies.
+    #[default]
+    FirstFile,
+    /// Use the union of the columns of all files. Every column takes the supertype of its dtypes
+    /// across the files, and columns missing from a file are filled with nulls.
+    Union,
+    /// Require all files to have the same columns and dtypes. Errors listing every file that
+    /// differs from the first file.
+    Strict,
+}
+
 #[derive(Debug, Clone, Eq, Hash, PartialEq, strum_macros::IntoStaticStr)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
@@ -299,6 +340,7 @@ pub struct UnifiedScanArgs {
     pub cast_columns_policy: CastColumnsPolicy,
     pub missing_columns_policy: MissingColumnsPolicy,
     pub extra_columns_policy: ExtraColumnsPolicy,
+    pub schema_unification_policy: SchemaUnificationPolicy,
     pub include_file_paths: Option<PlSmallStr>,
 
     pub deletion_files: Option<DeletionFilesList>,
@@ -345,6 +387,7 @@ impl Default for UnifiedScanArgs {
             cast_columns_policy: CastColumnsPolicy::default(),
             missing_columns_policy: MissingColumnsPolicy::default(),
             extra_columns_policy: ExtraColumnsPolicy::default(),
+            schema_unification_policy: SchemaUnificationPolicy::default(),
             include_file_paths: None,
             deletion_files: None,
             table_statistics: None,
@@ -1,6 +1,8 @@
 use std::fmt;
 
 use polars_core::prelude::{ExplodeOptions, SortOptions};
+#[cfg(feature = "array_linalg")]
+use polars_ops::chunked_array::array::VectorNorm;
 
 use super::FunctionExpr;
 
@@ -41,6 +43,14 @@ pub enum ArrayFunction {
     Concat,
     #[cfg(feature = "array_to_struct")]
     ToStruct(Option<super::DslNameGenerator>),
+    #[cfg(feature = "array_linalg")]
+    Dot,
+    #[cfg(feature = "array_linalg")]
+    MatVec,
+    #[cfg(feature = "array_linalg")]
+    Norm(VectorNorm),
+    #[cfg(feature = "array_linalg")]
+    CosineSimilarity,
 }
 
 impl fmt::Display for ArrayFunction {
@@ -78,6 +88,14 @@ impl fmt::Display for ArrayFunction {
             Explode { .. } => "explode",
             #[cfg(feature = "array_to_struct")]
             ToStruct(_) => "to_struct",
+            #[cfg(feature = "array_linalg")]
+            Dot => "linalg.dot",
+            #[cfg(feature = "array_linalg")]
+            MatVec => "linalg.matvec",
+            #[cfg(feature = "array_linalg")]
+            Norm(_) => "linalg.norm",
+            #[cfg(feature = "array_linalg")]
+            CosineSimilarity => "linalg.cosine_similarity",
         };
         write!(f, "arr.{name}")
     }
@@ -26,6 +26,14 @@ pub enum BinaryFunction {
     Slice,
     Head,
     Tail,
+    #[cfg(feature = "crypto")]
+    Encrypt {
+        key: CryptoKey,
+    },
+    #[cfg(feature = "crypto")]
+    Decrypt {
+        key: CryptoKey,
+    },
 }
 
 impl Display for BinaryFunction {
@@ -49,6 +57,10 @@ impl Display for BinaryFunction {
             Slice => "slice",
             Head => "head",
             Tail => "tail",
+            #[cfg(feature = "crypto")]
+            Encrypt { .. } => "encrypt",
+            #[cfg(feature = "crypto")]
+            Decrypt { .. } => "decrypt",
         };
         write!(f, "bin.{s}")
     }
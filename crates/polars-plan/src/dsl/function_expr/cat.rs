@@ -5,6 +5,8 @@ use super::*;
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub enum CategoricalFunction {
     GetCategories,
+    ExtendCategories(Vec<PlSmallStr>),
+    CastWithAddedCategories(Vec<PlSmallStr>),
     #[cfg(feature = "strings")]
     LenBytes,
     #[cfg(feature = "strings")]
@@ -22,6 +24,8 @@ impl Display for CategoricalFunction {
         use CategoricalFunction::*;
         let s = match self {
             GetCategories => "get_categories",
+            ExtendCategories(_) => "extend_categories",
+            CastWithAddedCategories(_) => "cast_with_added_categories",
             #[cfg(feature = "strings")]
             LenBytes => "len_bytes",
             #[cfg(feature = "strings")]
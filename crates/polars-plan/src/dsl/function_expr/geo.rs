@@ -0,0 +1,37 @@
+use super::*;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum GeoFunction {
+    Point,
+    X,
+    Y,
+    Distance,
+    Contains,
+    Within,
+    /// Takes the bounds `xmin`, `ymin`, `xmax` and `ymax` as additional inputs.
+    IntersectsBbox,
+}
+
+impl Display for GeoFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use GeoFunction::*;
+        let s = match self {
+            Point => "point",
+            X => "x",
+            Y => "y",
+            Distance => "distance",
+            Contains => "contains",
+            Within => "within",
+            IntersectsBbox => "intersects_bbox",
+        };
+        write!(f, "st.{s}")
+    }
+}
+
+impl From<GeoFunction> for FunctionExpr {
+    fn from(func: GeoFunction) -> Self {
+        FunctionExpr::Geo(func)
+    }
+}
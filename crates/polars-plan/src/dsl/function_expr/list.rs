@@ -42,6 +42,9 @@ pub enum ListFunction {
         n: i64,
         null_behavior: NullBehavior,
     },
+    RollingSum(usize),
+    RollingMean(usize),
+    ZipWith(ListZipOp),
     Sort(SortOptions),
     Reverse,
     Unique(bool),
@@ -97,6 +100,9 @@ impl Display for ListFunction {
             ArgMax => "arg_max",
             #[cfg(feature = "diff")]
             Diff { .. } => "diff",
+            RollingSum(_) => "rolling_sum",
+            RollingMean(_) => "rolling_mean",
+            ZipWith(op) => return write!(f, "list.zip_with_{op}"),
             Length => "length",
             Sort(_) => "sort",
             Reverse => "reverse",
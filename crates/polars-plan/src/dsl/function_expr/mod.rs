@@ -14,8 +14,12 @@ mod correlation;
 mod datetime;
 #[cfg(feature = "dtype-extension")]
 mod extension;
+#[cfg(feature = "geo")]
+mod geo;
 mod list;
 mod pow;
+#[cfg(feature = "quant")]
+mod quant;
 #[cfg(feature = "random")]
 mod random;
 #[cfg(feature = "range")]
@@ -58,7 +62,11 @@ pub use self::cat::CategoricalFunction;
 pub use self::datetime::TemporalFunction;
 #[cfg(feature = "dtype-extension")]
 pub use self::extension::ExtensionFunction;
+#[cfg(feature = "geo")]
+pub use self::geo::GeoFunction;
 pub use self::pow::PowFunction;
+#[cfg(feature = "quant")]
+pub use self::quant::QuantFunction;
 #[cfg(feature = "range")]
 pub use self::range::{DateRangeArgs, RangeFunction};
 #[cfg(feature = "rolling_window")]
@@ -85,7 +93,11 @@ pub enum FunctionExpr {
     Categorical(CategoricalFunction),
     #[cfg(feature = "dtype-extension")]
     Extension(ExtensionFunction),
+    #[cfg(feature = "geo")]
+    Geo(GeoFunction),
     ListExpr(ListFunction),
+    #[cfg(feature = "quant")]
+    Quant(QuantFunction),
     #[cfg(feature = "strings")]
     StringExpr(StringFunction),
     #[cfg(feature = "dtype-struct")]
@@ -227,6 +239,10 @@ pub enum FunctionExpr {
     Diff(NullBehavior),
     #[cfg(feature = "pct_change")]
     PctChange,
+    #[cfg(feature = "pct_change_by")]
+    PctChangeBy {
+        offset: Duration,
+    },
     #[cfg(feature = "interpolate")]
     Interpolate(InterpolationMethod),
     #[cfg(feature = "interpolate_by")]
@@ -286,6 +302,12 @@ pub enum FunctionExpr {
     RLE,
     #[cfg(feature = "rle")]
     RLEID,
+    #[cfg(feature = "to_dummies")]
+    ToDummies {
+        categories: Vec<PlSmallStr>,
+        separator: PlSmallStr,
+        drop_first: bool,
+    },
     ToPhysical,
     #[cfg(feature = "random")]
     Random {
@@ -338,6 +360,12 @@ pub enum FunctionExpr {
     MeanHorizontal {
         ignore_nulls: bool,
     },
+    #[cfg(feature = "rule_failures")]
+    RuleFailures {
+        output: RuleFailuresOutput,
+    },
+    #[cfg(feature = "weighted")]
+    Weighted(WeightedAgg),
     #[cfg(feature = "ewma")]
     EwmMean {
         options: EWMOptions,
@@ -386,7 +414,11 @@ impl Hash for FunctionExpr {
             Categorical(f) => f.hash(state),
             #[cfg(feature = "dtype-extension")]
             Extension(f) => f.hash(state),
+            #[cfg(feature = "geo")]
+            Geo(f) => f.hash(state),
             ListExpr(f) => f.hash(state),
+            #[cfg(feature = "quant")]
+            Quant(f) => f.hash(state),
             #[cfg(feature = "strings")]
             StringExpr(f) => f.hash(state),
             #[cfg(feature = "dtype-struct")]
@@ -473,6 +505,10 @@ impl Hash for FunctionExpr {
             SumHorizontal { ignore_nulls } | MeanHorizontal { ignore_nulls } => {
                 ignore_nulls.hash(state)
             },
+            #[cfg(feature = "rule_failures")]
+            RuleFailures { output } => output.hash(state),
+            #[cfg(feature = "weighted")]
+            Weighted(agg) => agg.hash(state),
             MaxHorizontal | MinHorizontal | DropNans | DropNulls | Reverse | ArgUnique | ArgMin
             | ArgMax | Product | Shift | ShiftAndFill | Rechunk => {},
             Append { upcast } => upcast.hash(state),
@@ -562,6 +598,8 @@ impl Hash for FunctionExpr {
             Coalesce => {},
             #[cfg(feature = "pct_change")]
             PctChange => {},
+            #[cfg(feature = "pct_change_by")]
+            PctChangeBy { offset } => offset.hash(state),
             #[cfg(feature = "log")]
             Entropy { base, normalize } => {
                 base.to_bits().hash(state);
@@ -628,6 +666,16 @@ impl Hash for FunctionExpr {
             RLE => {},
             #[cfg(feature = "rle")]
             RLEID => {},
+            #[cfg(feature = "to_dummies")]
+            ToDummies {
+                categories,
+                separator,
+                drop_first,
+            } => {
+                categories.hash(state);
+                separator.hash(state);
+                drop_first.hash(state);
+            },
             ToPhysical => {},
             SetSortedFlag(is_sorted) => is_sorted.hash(state),
             #[cfg(feature = "ewma")]
@@ -682,7 +730,11 @@ impl Display for FunctionExpr {
             Categorical(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-extension")]
             Extension(func) => return write!(f, "{func}"),
+            #[cfg(feature = "geo")]
+            Geo(func) => return write!(f, "{func}"),
             ListExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "quant")]
+            Quant(func) => return write!(f, "{func}"),
             #[cfg(feature = "strings")]
             StringExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-struct")]
@@ -789,6 +841,8 @@ impl Display for FunctionExpr {
             Diff(_) => "diff",
             #[cfg(feature = "pct_change")]
             PctChange => "pct_change",
+            #[cfg(feature = "pct_change_by")]
+            PctChangeBy { .. } => "pct_change_by",
             #[cfg(feature = "interpolate")]
             Interpolate(_) => "interpolate",
             #[cfg(feature = "interpolate_by")]
@@ -837,6 +891,8 @@ impl Display for FunctionExpr {
             RLE => "rle",
             #[cfg(feature = "rle")]
             RLEID => "rle_id",
+            #[cfg(feature = "to_dummies")]
+            ToDummies { .. } => "to_dummies",
             ToPhysical => "to_physical",
             #[cfg(feature = "random")]
             Random { method, .. } => method.into(),
@@ -853,6 +909,10 @@ impl Display for FunctionExpr {
             MinHorizontal => "min_horizontal",
             SumHorizontal { .. } => "sum_horizontal",
             MeanHorizontal { .. } => "mean_horizontal",
+            #[cfg(feature = "rule_failures")]
+            RuleFailures { .. } => "rule_failures",
+            #[cfg(feature = "weighted")]
+            Weighted(agg) => return Display::fmt(agg, f),
             #[cfg(feature = "ewma")]
             EwmMean { .. } => "ewm_mean",
             #[cfg(feature = "ewma_by")]
@@ -0,0 +1,39 @@
+use super::*;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum QuantFunction {
+    Drawdown,
+    MaxDrawdown,
+    HitRatio,
+    Sharpe { annualization: f64 },
+}
+
+impl Hash for QuantFunction {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        if let Self::Sharpe { annualization } = self {
+            annualization.to_bits().hash(state);
+        }
+    }
+}
+
+impl Display for QuantFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use QuantFunction::*;
+        let s = match self {
+            Drawdown => "drawdown",
+            MaxDrawdown => "max_drawdown",
+            HitRatio => "hit_ratio",
+            Sharpe { .. } => "sharpe",
+        };
+        write!(f, "quant.{s}")
+    }
+}
+
+impl From<QuantFunction> for FunctionExpr {
+    fn from(func: QuantFunction) -> Self {
+        FunctionExpr::Quant(func)
+    }
+}
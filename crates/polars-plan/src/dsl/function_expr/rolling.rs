@@ -16,6 +16,8 @@ pub enum RollingFunction {
     Skew,
     #[cfg(feature = "moment")]
     Kurtosis,
+    #[cfg(feature = "outliers")]
+    Mad,
     #[cfg(feature = "cov")]
     CorrCov {
         corr_cov_options: RollingCovOptions,
@@ -42,6 +44,8 @@ impl Display for RollingFunction {
             Skew => "skew",
             #[cfg(feature = "moment")]
             Kurtosis => "kurtosis",
+            #[cfg(feature = "outliers")]
+            Mad => "mad",
             #[cfg(feature = "cov")]
             CorrCov { is_corr, .. } => {
                 if *is_corr {
@@ -132,6 +132,30 @@ pub enum StringFunction {
     },
     #[cfg(feature = "regex")]
     EscapeRegex,
+    #[cfg(feature = "string_mask")]
+    MaskPattern {
+        pat: PlSmallStr,
+        replacement: PlSmallStr,
+        keep_last_n: usize,
+    },
+    #[cfg(feature = "string_mask")]
+    MaskPii {
+        pattern: PiiPattern,
+        replacement: PlSmallStr,
+        keep_last_n: usize,
+    },
+    #[cfg(feature = "crypto")]
+    Tokenize {
+        key: CryptoKey,
+    },
+    #[cfg(feature = "crypto")]
+    Encrypt {
+        key: CryptoKey,
+    },
+    #[cfg(feature = "crypto")]
+    MaskFormatPreserving {
+        key: CryptoKey,
+    },
 }
 
 impl Display for StringFunction {
@@ -225,6 +249,16 @@ impl Display for StringFunction {
             FindMany { .. } => "extract_many",
             #[cfg(feature = "regex")]
             EscapeRegex => "escape_regex",
+            #[cfg(feature = "string_mask")]
+            MaskPattern { .. } => "mask_pattern",
+            #[cfg(feature = "string_mask")]
+            MaskPii { .. } => "mask_pii",
+            #[cfg(feature = "crypto")]
+            Tokenize { .. } => "tokenize",
+            #[cfg(feature = "crypto")]
+            Encrypt { .. } => "encrypt",
+            #[cfg(feature = "crypto")]
+            MaskFormatPreserving { .. } => "mask_format_preserving",
         };
         write!(f, "str.{s}")
     }
@@ -12,6 +12,7 @@ pub enum StructFunction {
     JsonEncode,
     WithFields,
     SelectFields(Selector),
+    SelectPath(NestedPath),
     MapFieldNames(PlanCallback<PlSmallStr, PlSmallStr>),
 }
 
@@ -27,6 +28,7 @@ impl Display for StructFunction {
             JsonEncode => write!(f, "struct.to_json"),
             WithFields => write!(f, "struct.with_fields"),
             SelectFields(_) => write!(f, "struct.field"),
+            SelectPath(path) => write!(f, "struct.path({path})"),
             MapFieldNames(_) => write!(f, "map_field_names"),
         }
     }
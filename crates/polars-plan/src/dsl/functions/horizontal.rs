@@ -168,6 +168,20 @@ pub fn mean_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> Polars
     ))
 }
 
+/// Evaluate the boolean `rules` and report for every row which of them it fails.
+///
+/// A rule fails if it evaluates to `false` or null. With [`RuleFailuresOutput::Names`] the
+/// output names of the rules are reported, so give every rule a unique name.
+///
+/// The name of the resulting column will be `"rule_failures"`; use [`alias`](Expr::alias) to
+/// choose a different name.
+#[cfg(feature = "rule_failures")]
+pub fn rule_failures<E: AsRef<[Expr]>>(rules: E, output: RuleFailuresOutput) -> PolarsResult<Expr> {
+    let rules = rules.as_ref().to_vec();
+    polars_ensure!(!rules.is_empty(), ComputeError: "cannot compute rule failures without any rules");
+    Ok(Expr::n_ary(FunctionExpr::RuleFailures { output }, rules).alias("rule_failures"))
+}
+
 /// Folds the expressions from left to right keeping the first non-null values.
 ///
 /// It is an error to provide an empty `exprs`.
@@ -18,6 +18,8 @@ mod selectors;
 mod syntactic_sugar;
 #[cfg(feature = "temporal")]
 mod temporal;
+#[cfg(feature = "weighted")]
+mod weighted;
 
 pub use arity::*;
 #[cfg(all(feature = "business", feature = "dtype-date"))]
@@ -44,6 +46,8 @@ pub use selectors::*;
 pub use syntactic_sugar::*;
 #[cfg(feature = "temporal")]
 pub use temporal::*;
+#[cfg(feature = "weighted")]
+pub use weighted::*;
 
 #[cfg(feature = "arg_where")]
 use crate::dsl::function_expr::FunctionExpr;
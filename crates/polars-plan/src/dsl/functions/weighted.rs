@@ -0,0 +1,20 @@
+use super::*;
+
+/// Compute the mean of `values` weighted by `weights`.
+///
+/// Pairs where the value or the weight is null are ignored.
+pub fn weighted_mean(values: Expr, weights: Expr) -> Expr {
+    values.map_binary(FunctionExpr::Weighted(WeightedAgg::Mean), weights)
+}
+
+/// Compute the variance of `values` weighted by `weights`, where the weights are treated as
+/// frequencies: the divisor is the sum of the weights minus `ddof`.
+pub fn weighted_var(values: Expr, weights: Expr, ddof: u8) -> Expr {
+    values.map_binary(FunctionExpr::Weighted(WeightedAgg::Var(ddof)), weights)
+}
+
+/// Compute the quantile of `values` weighted by `weights`: the smallest value for which the
+/// weights of the values up to it are at least `quantile` times the total weight.
+pub fn weighted_quantile(values: Expr, weights: Expr, quantile: f64) -> Expr {
+    values.map_binary(FunctionExpr::Weighted(WeightedAgg::Quantile(quantile)), weights)
+}
@@ -0,0 +1,46 @@
+use super::*;
+
+/// Spatial expressions on geometry columns.
+///
+/// Geometries are stored as WKB; all operations are planar.
+pub struct GeoNameSpace(pub(crate) Expr);
+
+impl GeoNameSpace {
+    /// Get the x coordinate of point geometries.
+    pub fn x(self) -> Expr {
+        self.0.map_unary(GeoFunction::X)
+    }
+
+    /// Get the y coordinate of point geometries.
+    pub fn y(self) -> Expr {
+        self.0.map_unary(GeoFunction::Y)
+    }
+
+    /// Compute the minimum planar distance to the geometries of `other`.
+    pub fn distance(self, other: Expr) -> Expr {
+        self.0.map_binary(GeoFunction::Distance, other)
+    }
+
+    /// Check whether the geometries contain those of `other`.
+    pub fn contains(self, other: Expr) -> Expr {
+        self.0.map_binary(GeoFunction::Contains, other)
+    }
+
+    /// Check whether the geometries lie within those of `other`.
+    pub fn within(self, other: Expr) -> Expr {
+        self.0.map_binary(GeoFunction::Within, other)
+    }
+
+    /// Check whether the bounding boxes of the geometries intersect the given box.
+    ///
+    /// The bounds must be scalar expressions.
+    pub fn intersects_bbox(self, xmin: Expr, ymin: Expr, xmax: Expr, ymax: Expr) -> Expr {
+        self.0
+            .map_n_ary(GeoFunction::IntersectsBbox, [xmin, ymin, xmax, ymax])
+    }
+}
+
+/// Create point geometries from `x` and `y` coordinates.
+pub fn st_point(x: Expr, y: Expr) -> Expr {
+    x.map_binary(GeoFunction::Point, y)
+}
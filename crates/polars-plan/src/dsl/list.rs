@@ -203,6 +203,24 @@ impl ListNameSpace {
         }))
     }
 
+    /// Compute the sum of every window of `window_size` consecutive values of every sublist.
+    pub fn rolling_sum(self, window_size: usize) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ListExpr(ListFunction::RollingSum(window_size)))
+    }
+
+    /// Compute the mean of every window of `window_size` consecutive values of every sublist.
+    pub fn rolling_mean(self, window_size: usize) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ListExpr(ListFunction::RollingMean(window_size)))
+    }
+
+    /// Apply `op` to the elements at the same position of the sublists of `self` and `other`.
+    pub fn zip_with(self, other: Expr, op: ListZipOp) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::ListExpr(ListFunction::ZipWith(op)), other)
+    }
+
     /// Shift every sublist.
     pub fn shift(self, periods: Expr) -> Expr {
         self.0
@@ -57,7 +57,9 @@ impl MetaNameSpace {
                 e,
                 Expr::Selector(_)
                     | Expr::Function {
-                        function: FunctionExpr::StructExpr(StructFunction::SelectFields(_)),
+                        function: FunctionExpr::StructExpr(
+                            StructFunction::SelectFields(_) | StructFunction::SelectPath(_)
+                        ),
                         ..
                     }
             )
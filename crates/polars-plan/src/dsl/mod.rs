@@ -26,14 +26,20 @@ mod format;
 mod from;
 pub mod function_expr;
 pub mod functions;
+#[cfg(feature = "geo")]
+mod geo;
 mod list;
 mod match_to_schema;
 #[cfg(feature = "meta")]
 mod meta;
 mod name;
+#[cfg(feature = "dtype-struct")]
+mod nested;
 mod options;
 #[cfg(feature = "python")]
 pub mod python_dsl;
+#[cfg(feature = "quant")]
+mod quant;
 #[cfg(feature = "random")]
 mod random;
 mod scan_sources;
@@ -60,13 +66,19 @@ pub use expr::*;
 pub use extension::*;
 pub use function_expr::*;
 pub use functions::*;
+#[cfg(feature = "geo")]
+pub use geo::*;
 pub use list::*;
 pub use match_to_schema::*;
 #[cfg(feature = "meta")]
 pub use meta::*;
 pub use name::*;
+#[cfg(feature = "dtype-struct")]
+pub use nested::*;
 pub use options::*;
 pub use plan::*;
+#[cfg(feature = "quant")]
+pub use quant::*;
 use polars_compute::rolling::QuantileMethod;
 use polars_core::chunked_array::cast::CastOptions;
 use polars_core::error::feature_gated;
@@ -84,6 +96,9 @@ mod file_scan;
 pub use file_scan::*;
 pub use scan_sources::{ScanSource, ScanSourceIter, ScanSourceRef, ScanSources};
 
+#[cfg(feature = "crypto")]
+pub use polars_ops::chunked_array::crypto::{CryptoKey, KeyProvider, StaticKeyProvider};
+
 pub use crate::plans::lit;
 use crate::prelude::*;
 
@@ -352,6 +367,18 @@ impl Expr {
         }
     }
 
+    /// Cast expression to another data type without failing on values that cannot be converted.
+    ///
+    /// Returns a struct with the converted `value`, which is null where the conversion failed, and
+    /// whether the conversion succeeded in `ok`. Null values convert successfully.
+    pub fn try_cast(self, dtype: impl Into<DataTypeExpr>) -> Self {
+        let value = self.clone().cast(dtype);
+        let ok = value.clone().is_not_null().or(self.is_null());
+        as_struct(vec![value.alias("value"), ok.alias("ok")])
+            .name()
+            .keep()
+    }
+
     /// Take the values by idx.
     pub fn gather<E: Into<Expr>>(self, idx: E) -> Self {
         Expr::Gather {
@@ -648,6 +675,9 @@ impl Expr {
     }
 
     /// Shift the values in the array by some period and fill the resulting empty values.
+    ///
+    /// The `fill_value` is either a scalar or an expression of the same length, of which the
+    /// values at the vacated positions are used, e.g. to fill with the value of another column.
     pub fn shift_and_fill<E: Into<Expr>, IE: Into<Expr>>(self, n: E, fill_value: IE) -> Self {
         self.map_ternary(FunctionExpr::ShiftAndFill, n.into(), fill_value.into())
     }
@@ -863,6 +893,61 @@ impl Expr {
         })
     }
 
+    /// Like [`Expr::over_with_options`], but every `order_by` expression has its own
+    /// `descending` and `nulls_last` flag.
+    ///
+    /// If the flags differ between the expressions, the groups are ordered by the row encoding
+    /// of the expressions.
+    pub fn over_with_multi_options<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        partition_by: Option<E>,
+        order_by: Option<(E, SortMultipleOptions)>,
+        mapping: WindowMapping,
+    ) -> PolarsResult<Self> {
+        let to_exprs = |e: E| e.as_ref().iter().map(|e| e.clone().into()).collect::<Vec<Expr>>();
+        let partition_by = partition_by.map(to_exprs);
+        let Some((order_by, options)) = order_by else {
+            return self.over_with_options(partition_by, None, mapping);
+        };
+        let order_by = to_exprs(order_by);
+
+        let n = order_by.len();
+        let mut descending = options.descending;
+        let mut nulls_last = options.nulls_last;
+        polars_ensure!(
+            descending.len() == n || descending.len() == 1,
+            ComputeError: "the length of `descending` ({}) does not match the length of \
+            `order_by` ({})", descending.len(), n
+        );
+        polars_ensure!(
+            nulls_last.len() == n || nulls_last.len() == 1,
+            ComputeError: "the length of `nulls_last` ({}) does not match the length of \
+            `order_by` ({})", nulls_last.len(), n
+        );
+
+        let is_uniform = |flags: &[bool]| flags.iter().all(|f| *f == flags[0]);
+        let (order_by, sort_options) = if is_uniform(&descending) && is_uniform(&nulls_last) {
+            let sort_options = SortOptions::default()
+                .with_order_descending(descending.first().copied().unwrap_or_default())
+                .with_nulls_last(nulls_last.first().copied().unwrap_or_default())
+                .with_maintain_order(options.maintain_order);
+            (order_by, sort_options)
+        } else {
+            descending.resize(n, descending[0]);
+            nulls_last.resize(n, nulls_last[0]);
+            let encoded = Expr::n_ary(
+                FunctionExpr::RowEncode(RowEncodingVariant::Ordered {
+                    descending: Some(descending),
+                    nulls_last: Some(nulls_last),
+                }),
+                order_by,
+            );
+            let sort_options = SortOptions::default().with_maintain_order(options.maintain_order);
+            (vec![encoded], sort_options)
+        };
+        self.over_with_options(partition_by, Some((order_by, sort_options)), mapping)
+    }
+
     #[cfg(feature = "dynamic_group_by")]
     pub fn rolling(
         self,
@@ -1307,6 +1392,54 @@ impl Expr {
         self.finish_rolling(options, RollingFunction::Map(f))
     }
 
+    /// Flag the values of which the modified z-score `|x - median| / (1.4826 * MAD)` exceeds
+    /// `threshold`, with MAD the median absolute deviation from the median.
+    ///
+    /// Use in a window context to screen per group.
+    #[cfg(feature = "outliers")]
+    pub fn is_outlier_mad(self, threshold: f64) -> Expr {
+        let median = self.clone().median();
+        let mad = (self.clone() - median.clone()).abs().median();
+        outlier_mad(self, median, mad, threshold)
+    }
+
+    /// Flag the values outside of `[q1 - k * iqr, q3 + k * iqr]`, with `q1` and `q3` the first
+    /// and third quartile and `iqr` the interquartile range `q3 - q1`.
+    ///
+    /// Use in a window context to screen per group.
+    #[cfg(feature = "outliers")]
+    pub fn is_outlier_iqr(self, k: f64) -> Expr {
+        let q1 = self.clone().quantile(lit(0.25), QuantileMethod::Linear);
+        let q3 = self.clone().quantile(lit(0.75), QuantileMethod::Linear);
+        outlier_iqr(self, q1, q3, k)
+    }
+
+    /// Flag the values of which the modified z-score with respect to the median and the median
+    /// absolute deviation of their rolling window exceeds `threshold`.
+    ///
+    /// See [`Expr::is_outlier_mad`].
+    #[cfg(all(feature = "outliers", feature = "rolling_window"))]
+    pub fn rolling_is_outlier_mad(
+        self,
+        threshold: f64,
+        options: RollingOptionsFixedWindow,
+    ) -> Expr {
+        let median = self.clone().rolling_median(options.clone());
+        let mad = self.clone().finish_rolling(options, RollingFunction::Mad);
+        outlier_mad(self, median, mad, threshold)
+    }
+
+    /// Flag the values outside of the range spanned by the quartiles of their rolling window,
+    /// extended by `k` times the interquartile range.
+    ///
+    /// See [`Expr::is_outlier_iqr`].
+    #[cfg(all(feature = "outliers", feature = "rolling_window"))]
+    pub fn rolling_is_outlier_iqr(self, k: f64, options: RollingOptionsFixedWindow) -> Expr {
+        let q1 = self.clone().rolling_quantile(QuantileMethod::Linear, 0.25, options.clone());
+        let q3 = self.clone().rolling_quantile(QuantileMethod::Linear, 0.75, options);
+        outlier_iqr(self, q1, q3, k)
+    }
+
     #[cfg(feature = "peaks")]
     pub fn peak_min(self) -> Expr {
         self.map_unary(FunctionExpr::PeakMin)
@@ -1352,6 +1485,25 @@ impl Expr {
         )
     }
 
+    #[cfg(feature = "to_dummies")]
+    /// One-hot encode the values into a struct with an indicator field per category.
+    ///
+    /// The fields are named `{name}{separator}{category}`. As the categories are given up front,
+    /// the output type does not depend on the data. Values that are not in `categories` are
+    /// encoded as all zeros.
+    pub fn to_dummies(
+        self,
+        categories: impl IntoVec<PlSmallStr>,
+        separator: Option<PlSmallStr>,
+        drop_first: bool,
+    ) -> Expr {
+        self.map_unary(FunctionExpr::ToDummies {
+            categories: categories.into_vec(),
+            separator: separator.unwrap_or_else(|| PlSmallStr::from_static("_")),
+            drop_first,
+        })
+    }
+
     #[cfg(feature = "cutqcut")]
     /// Bin continuous values into discrete categories.
     pub fn cut(
@@ -1432,6 +1584,26 @@ impl Expr {
         self.map_binary(FunctionExpr::PctChange, n)
     }
 
+    #[cfg(feature = "pct_change_by")]
+    /// Computes percentage change between values and the values `offset` earlier in `by`.
+    ///
+    /// The change is computed versus the last value of which `by` is at most `by - offset`.
+    pub fn pct_change_by(self, by: Expr, offset: Duration) -> Expr {
+        self.map_binary(FunctionExpr::PctChangeBy { offset }, by)
+    }
+
+    #[cfg(all(feature = "pct_change", feature = "log"))]
+    /// Computes the logarithmic return between values, i.e. `ln(x_t / x_{t-n})`.
+    pub fn log_return(self, n: Expr) -> Expr {
+        self.pct_change(n).log1p()
+    }
+
+    #[cfg(all(feature = "pct_change_by", feature = "log"))]
+    /// Computes the logarithmic return between values and the values `offset` earlier in `by`.
+    pub fn log_return_by(self, by: Expr, offset: Duration) -> Expr {
+        self.pct_change_by(by, offset).log1p()
+    }
+
     #[cfg(feature = "moment")]
     /// Compute the sample skewness of a data set.
     ///
@@ -1651,6 +1823,18 @@ impl Expr {
         extension::ExtensionNameSpace(self)
     }
 
+    /// Get the [`geo::GeoNameSpace`].
+    #[cfg(feature = "geo")]
+    pub fn st(self) -> geo::GeoNameSpace {
+        geo::GeoNameSpace(self)
+    }
+
+    /// Get the [`quant::QuantNameSpace`].
+    #[cfg(feature = "quant")]
+    pub fn quant(self) -> quant::QuantNameSpace {
+        quant::QuantNameSpace(self)
+    }
+
     /// Get the [`struct_::StructNameSpace`].
     #[cfg(feature = "dtype-struct")]
     pub fn struct_(self) -> struct_::StructNameSpace {
@@ -1664,6 +1848,25 @@ impl Expr {
     }
 }
 
+/// Scale that makes the median absolute deviation a consistent estimator of the standard
+/// deviation of normally distributed data.
+#[cfg(feature = "outliers")]
+const MAD_SCALE: f64 = 1.4826;
+
+#[cfg(feature = "outliers")]
+fn outlier_mad(expr: Expr, median: Expr, mad: Expr, threshold: f64) -> Expr {
+    // Multiply instead of dividing by the MAD, so a MAD of zero flags every deviation.
+    (expr - median).abs().gt(lit(threshold * MAD_SCALE) * mad)
+}
+
+#[cfg(feature = "outliers")]
+fn outlier_iqr(expr: Expr, q1: Expr, q3: Expr, k: f64) -> Expr {
+    let iqr = q3.clone() - q1.clone();
+    let lower = q1 - lit(k) * iqr.clone();
+    let upper = q3 + lit(k) * iqr;
+    expr.clone().lt(lower).or(expr.gt(upper))
+}
+
 /// Apply a function/closure over multiple columns once the logical plan get executed.
 ///
 /// This function is very similar to [`apply_multiple`], but differs in how it handles aggregations.
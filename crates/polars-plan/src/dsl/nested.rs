@@ -0,0 +1,328 @@
+use std::fmt::{Display, Formatter};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// A single step in a [`NestedPath`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum PathSegment {
+    /// Select a field of a struct by name.
+    Field(PlSmallStr),
+    /// Select all fields of a struct.
+    Wildcard,
+    /// Step into the elements of a list (or array).
+    Elements,
+}
+
+/// A path into nested data, e.g. `a.b[].c`.
+///
+/// The first segment is always the name of a column. The remainder of the path is resolved
+/// against the schema during expression expansion and turns into `struct.field` and
+/// `list.eval` chains.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct NestedPath {
+    segments: Arc<[PathSegment]>,
+}
+
+impl NestedPath {
+    pub fn new(segments: Arc<[PathSegment]>) -> Self {
+        Self { segments }
+    }
+
+    /// Parse a path of the form `a.b[].c`.
+    ///
+    /// * `.` separates struct fields.
+    /// * `[]` steps into the elements of a list or array.
+    /// * `*` selects all fields of a struct at that level.
+    /// * `\` escapes the next character, e.g. `a\.b` is the column `a.b`.
+    pub fn parse(path: &str) -> PolarsResult<Self> {
+        let mut segments = Vec::new();
+        let mut current = String::new();
+        // Whether `current` holds a name that still has to be pushed. This differs from
+        // `!current.is_empty()` for escaped empty names and names following `[]`.
+        let mut pending = false;
+        // Whether the last character was a `.`, which must be followed by a name.
+        let mut after_dot = false;
+        let mut chars = path.chars().peekable();
+
+        let push_name = |segments: &mut Vec<PathSegment>, current: &mut String| {
+            let name = std::mem::take(current);
+            if name == "*" {
+                segments.push(PathSegment::Wildcard)
+            } else {
+                segments.push(PathSegment::Field(name.into()))
+            }
+        };
+
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => {
+                    let Some(escaped) = chars.next() else {
+                        polars_bail!(InvalidOperation: "nested path '{path}' ends with an escape character");
+                    };
+                    current.push(escaped);
+                    pending = true;
+                    after_dot = false;
+                },
+                '.' => {
+                    if pending {
+                        push_name(&mut segments, &mut current);
+                        pending = false;
+                    } else {
+                        polars_ensure!(
+                            !after_dot && matches!(segments.last(), Some(PathSegment::Elements)),
+                            InvalidOperation: "nested path '{path}' contains an empty field name"
+                        );
+                    }
+                    after_dot = true;
+                },
+                '[' => {
+                    polars_ensure!(
+                        !after_dot,
+                        InvalidOperation: "nested path '{path}' contains an empty field name"
+                    );
+                    polars_ensure!(
+                        chars.next() == Some(']'),
+                        InvalidOperation: "expected '[]' in nested path '{path}'"
+                    );
+                    if pending {
+                        push_name(&mut segments, &mut current);
+                        pending = false;
+                    }
+                    polars_ensure!(
+                        !segments.is_empty(),
+                        InvalidOperation: "nested path '{path}' must start with a column name"
+                    );
+                    segments.push(PathSegment::Elements);
+                },
+                c => {
+                    current.push(c);
+                    pending = true;
+                    after_dot = false;
+                },
+            }
+        }
+        if pending {
+            push_name(&mut segments, &mut current);
+        } else {
+            polars_ensure!(
+                !after_dot && matches!(segments.last(), Some(PathSegment::Elements)),
+                InvalidOperation: "nested path '{path}' must not be empty or end with '.'"
+            );
+        }
+        Self::try_from_segments(segments)
+    }
+
+    fn try_from_segments(segments: Vec<PathSegment>) -> PolarsResult<Self> {
+        polars_ensure!(
+            matches!(segments.first(), Some(PathSegment::Field(_))),
+            InvalidOperation: "nested path must start with a column name"
+        );
+        Ok(Self::new(segments.into()))
+    }
+
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    /// Build the expression selecting this path.
+    pub fn into_expr(self) -> Expr {
+        let PathSegment::Field(root) = &self.segments[0] else {
+            unreachable!()
+        };
+        let root = Expr::Column(root.clone());
+        if self.segments.len() == 1 {
+            return root;
+        }
+        root.map_unary(FunctionExpr::StructExpr(StructFunction::SelectPath(
+            Self::new(self.segments[1..].into()),
+        )))
+    }
+}
+
+impl Display for NestedPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut first = true;
+        for segment in self.segments.iter() {
+            match segment {
+                PathSegment::Field(name) => {
+                    if !first {
+                        f.write_str(".")?;
+                    }
+                    for c in name.chars() {
+                        if matches!(c, '.' | '[' | '\\') {
+                            f.write_str("\\")?;
+                        }
+                        write!(f, "{c}")?;
+                    }
+                },
+                PathSegment::Wildcard => {
+                    if !first {
+                        f.write_str(".")?;
+                    }
+                    f.write_str("*")?;
+                },
+                PathSegment::Elements => f.write_str("[]")?,
+            }
+            first = false;
+        }
+        Ok(())
+    }
+}
+
+/// Select a nested field by walking through struct fields.
+///
+/// Every name is a struct field, except for the first one which is a column. A name of `"*"`
+/// selects all fields at that level and `"[]"` steps into the elements of a list or array.
+/// Errors if there are no names or the first name isn't a column.
+///
+/// ```ignore
+/// // Equivalent to `col("a").struct_().field_by_name("b").list().eval(element().struct_().field_by_name("c"))`
+/// // when `b` is a list of structs.
+/// nested(["a", "b", "[]", "c"])?
+/// ```
+pub fn nested<I, S>(names: I) -> PolarsResult<Expr>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<PlSmallStr>,
+{
+    let segments = names
+        .into_iter()
+        .map(|name| {
+            let name = name.into();
+            match name.as_str() {
+                "*" => PathSegment::Wildcard,
+                "[]" => PathSegment::Elements,
+                _ => PathSegment::Field(name),
+            }
+        })
+        .collect::<Vec<_>>();
+    Ok(NestedPath::try_from_segments(segments)?.into_expr())
+}
+
+/// Select a nested field using path syntax, e.g. `"a.b[].c"`.
+///
+/// See [`NestedPath::parse`] for the syntax.
+pub fn nested_path(path: &str) -> PolarsResult<Expr> {
+    Ok(NestedPath::parse(path)?.into_expr())
+}
+
+/// Resolve `segments` against `dtype`, starting from `expr` and pushing every resulting
+/// expression to `out`.
+pub(crate) fn expand_nested_path(
+    expr: Expr,
+    dtype: &DataType,
+    segments: &[PathSegment],
+    out: &mut Vec<Expr>,
+) -> PolarsResult<()> {
+    let Some((segment, rest)) = segments.split_first() else {
+        out.push(expr);
+        return Ok(());
+    };
+
+    match segment {
+        PathSegment::Field(name) => {
+            let DataType::Struct(fields) = dtype else {
+                polars_bail!(InvalidOperation: "cannot select field '{name}' of non-struct dtype '{dtype}'");
+            };
+            let Some(field) = fields.iter().find(|f| f.name() == name) else {
+                polars_bail!(StructFieldNotFound: "{name}");
+            };
+            let expr = expr.map_unary(FunctionExpr::StructExpr(StructFunction::FieldByName(
+                name.clone(),
+            )));
+            expand_nested_path(expr, field.dtype(), rest, out)
+        },
+        PathSegment::Wildcard => {
+            let DataType::Struct(fields) = dtype else {
+                polars_bail!(InvalidOperation: "cannot select all fields of non-struct dtype '{dtype}'");
+            };
+            for field in fields {
+                let expr = expr.clone().map_unary(FunctionExpr::StructExpr(
+                    StructFunction::FieldByName(field.name().clone()),
+                ));
+                expand_nested_path(expr, field.dtype(), rest, out)?;
+            }
+            Ok(())
+        },
+        PathSegment::Elements => {
+            let (inner, variant) = match dtype {
+                DataType::List(inner) => (inner.as_ref(), EvalVariant::List),
+                #[cfg(feature = "dtype-array")]
+                DataType::Array(inner, _) => {
+                    (inner.as_ref(), EvalVariant::Array { as_list: false })
+                },
+                dt => polars_bail!(InvalidOperation: "cannot step into the elements of non-list dtype '{dt}'"),
+            };
+
+            let start = out.len();
+            expand_nested_path(Expr::Element, inner, rest, out)?;
+            let expr = Arc::new(expr);
+            for e in out[start..].iter_mut() {
+                *e = Expr::Eval {
+                    expr: expr.clone(),
+                    evaluation: Arc::new(std::mem::take(e)),
+                    variant,
+                };
+            }
+            Ok(())
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_nested_path() -> PolarsResult<()> {
+        let path = NestedPath::parse("a.b[].c")?;
+        assert_eq!(
+            path.segments(),
+            &[
+                PathSegment::Field("a".into()),
+                PathSegment::Field("b".into()),
+                PathSegment::Elements,
+                PathSegment::Field("c".into()),
+            ]
+        );
+        assert_eq!(path.to_string(), "a.b[].c");
+
+        let path = NestedPath::parse("a[][].*")?;
+        assert_eq!(
+            path.segments(),
+            &[
+                PathSegment::Field("a".into()),
+                PathSegment::Elements,
+                PathSegment::Elements,
+                PathSegment::Wildcard,
+            ]
+        );
+
+        let path = NestedPath::parse(r"a\.b.c")?;
+        assert_eq!(
+            path.segments(),
+            &[
+                PathSegment::Field("a.b".into()),
+                PathSegment::Field("c".into()),
+            ]
+        );
+        assert_eq!(path.to_string(), r"a\.b.c");
+
+        assert!(NestedPath::parse("").is_err());
+        assert!(NestedPath::parse("a..b").is_err());
+        assert!(NestedPath::parse("a.").is_err());
+        assert!(NestedPath::parse("a[].").is_err());
+        assert!(NestedPath::parse("a.[]").is_err());
+        assert!(NestedPath::parse("a[]..b").is_err());
+        assert!(NestedPath::parse("[].a").is_err());
+        assert!(NestedPath::parse("a[x]").is_err());
+        Ok(())
+    }
+}
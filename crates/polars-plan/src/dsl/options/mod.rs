@@ -8,6 +8,7 @@ pub mod sink;
 pub mod sink2;
 use polars_core::error::PolarsResult;
 use polars_core::prelude::*;
+use polars_core::utils::PromotionRules;
 #[cfg(feature = "csv")]
 use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
@@ -39,6 +40,7 @@ use strum_macros::IntoStaticStr;
 
 use super::{Expr, ExprIR};
 use crate::dsl::Selector;
+use crate::prelude::PlanCallback;
 
 #[derive(Copy, Clone, PartialEq, Debug, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -194,6 +196,44 @@ pub struct UnpivotArgsDSL {
     pub value_name: Option<PlSmallStr>,
 }
 
+/// How the columns created by an `unnest` are named.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum UnnestNaming {
+    /// Use the field names.
+    #[default]
+    Keep,
+    /// Prefix the field names with the struct column name and a separator.
+    Prefix(PlSmallStr),
+    /// Derive the name from the struct column name and the field name.
+    Custom(PlanCallback<(PlSmallStr, PlSmallStr), PlSmallStr>),
+}
+
+impl UnnestNaming {
+    pub fn from_separator(separator: Option<PlSmallStr>) -> Self {
+        match separator {
+            None => Self::Keep,
+            Some(separator) => Self::Prefix(separator),
+        }
+    }
+
+    /// Name of the column created for `field` of the struct column `column`.
+    pub fn column_name(
+        &self,
+        column: &PlSmallStr,
+        field: &PlSmallStr,
+    ) -> PolarsResult<PlSmallStr> {
+        match self {
+            Self::Keep => Ok(field.clone()),
+            Self::Prefix(separator) => Ok(polars_utils::format_pl_smallstr!(
+                "{column}{separator}{field}"
+            )),
+            Self::Custom(function) => function.call((column.clone(), field.clone())),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Copy, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Engine {
@@ -377,7 +417,12 @@ pub struct UnionArgs {
     pub parallel: bool,
     pub rechunk: bool,
     pub to_supertypes: bool,
+    // The rules to determine the supertypes with if `to_supertypes` is set.
+    pub promotion_rules: PromotionRules,
     pub diagonal: bool,
+    // Align the columns of the inputs by name instead of by position. All inputs must have the
+    // same columns, which are ordered as in the first input.
+    pub by_name: bool,
     pub strict: bool,
     // If it is a union from a scan over multiple files.
     pub from_partitioned_ds: bool,
@@ -390,7 +435,9 @@ impl Default for UnionArgs {
             parallel: true,
             rechunk: false,
             to_supertypes: false,
+            promotion_rules: Default::default(),
             diagonal: false,
+            by_name: false,
             // By default, strict should be true in v2.0.0
             strict: false,
             from_partitioned_ds: false,
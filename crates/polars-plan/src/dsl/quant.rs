@@ -0,0 +1,27 @@
+use super::*;
+
+/// Performance statistics of prices and returns.
+pub struct QuantNameSpace(pub(crate) Expr);
+
+impl QuantNameSpace {
+    /// Compute the relative decline of every price from the running maximum of the prices.
+    pub fn drawdown(self) -> Expr {
+        self.0.map_unary(QuantFunction::Drawdown)
+    }
+
+    /// Compute the largest drawdown of the prices.
+    pub fn max_drawdown(self) -> Expr {
+        self.0.map_unary(QuantFunction::MaxDrawdown)
+    }
+
+    /// Compute the fraction of the returns that is positive.
+    pub fn hit_ratio(self) -> Expr {
+        self.0.map_unary(QuantFunction::HitRatio)
+    }
+
+    /// Compute the Sharpe ratio of the returns, annualized with the number of periods per
+    /// year.
+    pub fn sharpe(self, annualization: f64) -> Expr {
+        self.0.map_unary(QuantFunction::Sharpe { annualization })
+    }
+}
@@ -361,6 +361,16 @@ impl ScanSourceRef<'_> {
         self.as_path().is_some_and(|x| x.is_cloud_url())
     }
 
+    /// Size of the source in bytes, if it can be retrieved without a request to a cloud
+    /// storage provider.
+    pub fn byte_size(&self) -> Option<u64> {
+        match self {
+            Self::Path(path) => std::fs::metadata(path.as_local_path()?).ok().map(|m| m.len()),
+            Self::File(file) => file.metadata().ok().map(|m| m.len()),
+            Self::Buffer(buff) => Some(buff.len() as u64),
+        }
+    }
+
     /// Turn the scan source into a memory slice
     pub fn to_memslice(&self) -> PolarsResult<MemSlice> {
         self.to_memslice_possibly_async(false, None, 0)
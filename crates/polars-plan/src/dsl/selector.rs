@@ -233,7 +233,7 @@ impl Selector {
                     if schema.contains(name) {
                         out.insert(name.clone());
                     } else if *strict {
-                        polars_bail!(col_not_found = name);
+                        polars_bail!(col_not_found = name, candidates = schema.iter_names());
                     }
                 }
                 out
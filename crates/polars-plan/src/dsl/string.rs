@@ -496,4 +496,62 @@ impl StringNameSpace {
     pub fn escape_regex(self) -> Expr {
         self.0.map_unary(StringFunction::EscapeRegex)
     }
+
+    /// Mask every match of the regex `pat`.
+    ///
+    /// Every character of a match is replaced with `replacement`, except for the last
+    /// `keep_last_n` characters.
+    #[cfg(feature = "string_mask")]
+    pub fn mask_pattern(
+        self,
+        pat: impl Into<PlSmallStr>,
+        replacement: impl Into<PlSmallStr>,
+        keep_last_n: usize,
+    ) -> Expr {
+        self.0.map_unary(StringFunction::MaskPattern {
+            pat: pat.into(),
+            replacement: replacement.into(),
+            keep_last_n,
+        })
+    }
+
+    /// Mask emails, phone numbers or payment card numbers.
+    ///
+    /// Card numbers are only masked if they pass the Luhn check. See [`Self::mask_pattern`].
+    #[cfg(feature = "string_mask")]
+    pub fn mask_pii(
+        self,
+        pattern: PiiPattern,
+        replacement: impl Into<PlSmallStr>,
+        keep_last_n: usize,
+    ) -> Expr {
+        self.0.map_unary(StringFunction::MaskPii {
+            pattern,
+            replacement: replacement.into(),
+            keep_last_n,
+        })
+    }
+
+    /// Replace every value with a keyed, irreversible token.
+    ///
+    /// The key material is resolved through the provider of `key` when the query runs.
+    #[cfg(feature = "crypto")]
+    pub fn tokenize(self, key: CryptoKey) -> Expr {
+        self.0.map_unary(StringFunction::Tokenize { key })
+    }
+
+    /// Deterministically encrypt the string values to `Binary`.
+    ///
+    /// Use `bin().decrypt(key)` to recover the original bytes.
+    #[cfg(feature = "crypto")]
+    pub fn encrypt(self, key: CryptoKey) -> Expr {
+        self.0.map_unary(StringFunction::Encrypt { key })
+    }
+
+    /// Mask the string values with keyed random characters of the same class (digit, lowercase,
+    /// uppercase), keeping all other characters in place. The mask can't be reversed.
+    #[cfg(feature = "crypto")]
+    pub fn mask_format_preserving(self, key: CryptoKey) -> Expr {
+        self.0.map_unary(StringFunction::MaskFormatPreserving { key })
+    }
 }
@@ -37,6 +37,19 @@ bitflags! {
         /// Check if operations are order dependent and unset maintaining_order if
         /// the order would not be observed.
         const CHECK_ORDER_OBSERVE = 1 << 15;
+        /// Keep sharing the scans found by common-subplan-elimination if the queries apply
+        /// different filters to them. The scan then applies the union of the filters.
+        const SHARE_SCANS = 1 << 16;
+        /// Run group-bys over hive partitioned scans per partition if the keys contain
+        /// partition columns.
+        const PARTITION_GROUP_BY = 1 << 17;
+        /// Cast the integer columns of scans of a single parquet file to the smallest data types
+        /// that hold their values, based on the statistics of the file. Computed columns are not
+        /// shrunk.
+        const SHRINK_DTYPES = 1 << 18;
+        /// Order floats by the IEEE 754 totalOrder predicate in sorts, comparisons, `min`/`max`,
+        /// group-bys and joins. This changes the results of the query.
+        const FLOAT_TOTAL_ORDER = 1 << 19;
     }
 }
 
@@ -72,11 +85,29 @@ impl OptFlags {
     pub fn fast_projection(&self) -> bool {
         self.contains(OptFlags::FAST_PROJECTION)
     }
+    pub fn share_scans(&self) -> bool {
+        self.contains(OptFlags::SHARE_SCANS)
+    }
+    pub fn partition_group_by(&self) -> bool {
+        self.contains(OptFlags::PARTITION_GROUP_BY)
+    }
+    pub fn shrink_dtypes(&self) -> bool {
+        self.contains(OptFlags::SHRINK_DTYPES)
+    }
+    pub fn float_total_order(&self) -> bool {
+        self.contains(OptFlags::FLOAT_TOTAL_ORDER)
+    }
 }
 
 impl Default for OptFlags {
     fn default() -> Self {
-        Self::from_bits_truncate(u32::MAX) & !Self::NEW_STREAMING & !Self::EAGER
+        Self::from_bits_truncate(u32::MAX)
+            & !Self::NEW_STREAMING
+            & !Self::EAGER
+            & !Self::SHARE_SCANS
+            & !Self::PARTITION_GROUP_BY
+            & !Self::SHRINK_DTYPES
+            & !Self::FLOAT_TOTAL_ORDER
     }
 }
 
@@ -0,0 +1,199 @@
+//! Accelerators that execute parts of an optimized plan outside of the polars engines, e.g. on a
+//! GPU.
+//!
+//! After optimization, the registered [`Accelerator`]s are offered the largest subplans they
+//! support according to their [`AcceleratorCapabilities`]. A claimed subplan is replaced by a
+//! scan that executes the [`AcceleratedPlan`] and hands its Arrow output back to the engine that
+//! executes the rest of the query.
+use std::any::Any;
+use std::sync::{Arc, LazyLock, RwLock};
+
+use arrow::record_batch::RecordBatch;
+use polars_core::prelude::*;
+use polars_utils::arena::{Arena, Node};
+
+use crate::dsl::{AnonymousScanOptions, FileScanIR, ScanSources, UnifiedScanArgs};
+use crate::plans::{
+    AExpr, AnonymousScan, AnonymousScanArgs, ArenaExprIter, FileInfo, FunctionIR, IR,
+};
+
+/// The parts of a plan that an [`Accelerator`] can execute.
+#[derive(Debug, Clone, Default)]
+pub struct AcceleratorCapabilities {
+    /// The plan nodes that can be executed, by their [`IR::name`].
+    pub nodes: PlHashSet<&'static str>,
+    /// Whether subplans calling user-defined functions, e.g. of `map_batches`, can be executed.
+    pub udfs: bool,
+}
+
+/// An executor for subplans of optimized queries.
+pub trait Accelerator: Send + Sync {
+    /// The name under which the accelerator is registered.
+    fn name(&self) -> &str;
+
+    /// The parts of a plan the accelerator can execute. Only subplans of which all nodes are
+    /// supported are offered to [`Accelerator::claim`].
+    fn capabilities(&self) -> AcceleratorCapabilities;
+
+    /// Try to take over the execution of the subplan at `root`.
+    ///
+    /// Returns `None` to decline, after which the inputs of `root` are offered instead. The
+    /// returned plan is only executed when the query is executed.
+    fn claim(
+        &self,
+        root: Node,
+        lp_arena: &Arena<IR>,
+        expr_arena: &Arena<AExpr>,
+    ) -> PolarsResult<Option<Arc<dyn AcceleratedPlan>>>;
+}
+
+/// A subplan claimed by an [`Accelerator`].
+pub trait AcceleratedPlan: Send + Sync {
+    /// Execute the subplan. The batches must have the schema of the claimed subplan.
+    fn execute(&self) -> PolarsResult<Vec<RecordBatch>>;
+}
+
+static ACCELERATORS: LazyLock<RwLock<Vec<Arc<dyn Accelerator>>>> =
+    LazyLock::new(Default::default);
+
+/// Register an accelerator. Accelerators are offered subplans in the order of registration.
+pub fn register_accelerator(accelerator: Arc<dyn Accelerator>) {
+    ACCELERATORS.write().unwrap().push(accelerator);
+}
+
+/// Remove the accelerators registered under `name`, returning whether there were any.
+pub fn unregister_accelerator(name: &str) -> bool {
+    let mut accelerators = ACCELERATORS.write().unwrap();
+    let len = accelerators.len();
+    accelerators.retain(|accelerator| accelerator.name() != name);
+    accelerators.len() != len
+}
+
+/// Offer the plan to the registered accelerators from the root down, and replace the claimed
+/// subplans by scans that execute them.
+pub(crate) fn offload_to_accelerators(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<()> {
+    let accelerators = ACCELERATORS.read().unwrap().clone();
+    if accelerators.is_empty() {
+        return Ok(());
+    }
+
+    let capabilities = accelerators
+        .iter()
+        .map(|accelerator| accelerator.capabilities())
+        .collect::<Vec<_>>();
+    let mut supported = vec![PlHashMap::default(); accelerators.len()];
+    let mut visited = PlHashSet::default();
+    let mut stack = vec![root];
+    'nodes: while let Some(node) = stack.pop() {
+        if !visited.insert(node) {
+            continue;
+        }
+
+        // The sinks are always executed by the engine.
+        if !matches!(lp_arena.get(node), IR::Sink { .. } | IR::SinkMultiple { .. }) {
+            for (i, accelerator) in accelerators.iter().enumerate() {
+                if !is_supported(node, &capabilities[i], lp_arena, expr_arena, &mut supported[i]) {
+                    continue;
+                }
+                if let Some(plan) = accelerator.claim(node, lp_arena, expr_arena)? {
+                    let schema = lp_arena.get(node).schema(lp_arena).into_owned();
+                    let scan = Arc::new(AcceleratedScan {
+                        plan,
+                        schema: schema.clone(),
+                    });
+                    lp_arena.replace(node, scan.into_ir_node(schema));
+                    continue 'nodes;
+                }
+            }
+        }
+        stack.extend(lp_arena.get(node).inputs());
+    }
+    Ok(())
+}
+
+#[recursive::recursive]
+fn is_supported(
+    node: Node,
+    capabilities: &AcceleratorCapabilities,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    supported: &mut PlHashMap<Node, bool>,
+) -> bool {
+    if let Some(is_supported) = supported.get(&node) {
+        return *is_supported;
+    }
+
+    let ir = lp_arena.get(node);
+    let is_supported = capabilities.nodes.contains(ir.name())
+        && (capabilities.udfs || !has_udf(ir, expr_arena))
+        && ir
+            .inputs()
+            .all(|input| is_supported(input, capabilities, lp_arena, expr_arena, supported));
+    supported.insert(node, is_supported);
+    is_supported
+}
+
+fn has_udf(ir: &IR, expr_arena: &Arena<AExpr>) -> bool {
+    match ir {
+        IR::MapFunction { function, .. } => match function {
+            FunctionIR::Opaque { .. } => true,
+            #[cfg(feature = "python")]
+            FunctionIR::OpaquePython(_) => true,
+            _ => false,
+        },
+        IR::Scan { scan_type, .. } => matches!(&**scan_type, FileScanIR::Anonymous { .. }),
+        #[cfg(feature = "python")]
+        IR::PythonScan { .. } => true,
+        _ => ir.exprs().any(|e| {
+            expr_arena
+                .iter(e.node())
+                .any(|(_, ae)| matches!(ae, AExpr::AnonymousFunction { .. }))
+        }),
+    }
+}
+
+/// Scan of the output of an [`AcceleratedPlan`].
+struct AcceleratedScan {
+    plan: Arc<dyn AcceleratedPlan>,
+    schema: SchemaRef,
+}
+
+impl AcceleratedScan {
+    fn into_ir_node(self: Arc<Self>, schema: SchemaRef) -> IR {
+        let options = Arc::new(AnonymousScanOptions {
+            skip_rows: None,
+            fmt_str: "ACCELERATED",
+        });
+        IR::Scan {
+            sources: ScanSources::Paths(Default::default()),
+            file_info: FileInfo::new(schema, None, (None, usize::MAX)),
+            hive_parts: None,
+            predicate: None,
+            predicate_file_skip_applied: None,
+            output_schema: None,
+            scan_type: Box::new(FileScanIR::Anonymous {
+                options,
+                function: self,
+            }),
+            unified_scan_args: Box::new(UnifiedScanArgs::default()),
+        }
+    }
+}
+
+impl AnonymousScan for AcceleratedScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, _scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let mut df = DataFrame::empty_with_arc_schema(self.schema.clone());
+        for batch in self.plan.execute()? {
+            df.append_record_batch(batch)?;
+        }
+        Ok(df)
+    }
+}
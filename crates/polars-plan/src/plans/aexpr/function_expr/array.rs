@@ -39,6 +39,14 @@ pub enum IRArrayFunction {
     Slice(i64, i64),
     #[cfg(feature = "array_to_struct")]
     ToStruct(Option<DslNameGenerator>),
+    #[cfg(feature = "array_linalg")]
+    Dot,
+    #[cfg(feature = "array_linalg")]
+    MatVec,
+    #[cfg(feature = "array_linalg")]
+    Norm(VectorNorm),
+    #[cfg(feature = "array_linalg")]
+    CosineSimilarity,
 }
 
 impl<'a> FieldsMapper<'a> {
@@ -118,6 +126,20 @@ impl IRArrayFunction {
                     .collect::<PolarsResult<Vec<Field>>>()
                     .map(DataType::Struct)
             }),
+            #[cfg(feature = "array_linalg")]
+            Dot | CosineSimilarity => {
+                let args = mapper.args();
+                mapper.with_dtype(dot_output_dtype(args[0].dtype(), args[1].dtype())?)
+            },
+            #[cfg(feature = "array_linalg")]
+            MatVec => {
+                let args = mapper.args();
+                mapper.with_dtype(matvec_output_dtype(args[0].dtype(), args[1].dtype())?)
+            },
+            #[cfg(feature = "array_linalg")]
+            Norm(_) => mapper
+                .ensure_is_array()?
+                .try_map_dtype(|dt| linalg_float_dtype(dt.inner_dtype().unwrap())),
         }
     }
 
@@ -154,6 +176,10 @@ impl IRArrayFunction {
             A::Explode { .. } => FunctionOptions::row_separable(),
             #[cfg(feature = "array_to_struct")]
             A::ToStruct(_) => FunctionOptions::elementwise(),
+            #[cfg(feature = "array_linalg")]
+            A::Dot | A::MatVec | A::Norm(_) | A::CosineSimilarity => {
+                FunctionOptions::elementwise()
+            },
         }
     }
 }
@@ -222,6 +248,14 @@ impl Display for IRArrayFunction {
             Explode { .. } => "explode",
             #[cfg(feature = "array_to_struct")]
             ToStruct(_) => "to_struct",
+            #[cfg(feature = "array_linalg")]
+            Dot => "linalg.dot",
+            #[cfg(feature = "array_linalg")]
+            MatVec => "linalg.matvec",
+            #[cfg(feature = "array_linalg")]
+            Norm(_) => "linalg.norm",
+            #[cfg(feature = "array_linalg")]
+            CosineSimilarity => "linalg.cosine_similarity",
         };
         write!(f, "arr.{name}")
     }
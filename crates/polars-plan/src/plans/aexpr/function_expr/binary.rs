@@ -1,3 +1,6 @@
+#[cfg(feature = "crypto")]
+use polars_ops::chunked_array::crypto::CryptoKey;
+
 use super::*;
 
 #[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
@@ -20,6 +23,14 @@ pub enum IRBinaryFunction {
     Slice,
     Head,
     Tail,
+    #[cfg(feature = "crypto")]
+    Encrypt {
+        key: CryptoKey,
+    },
+    #[cfg(feature = "crypto")]
+    Decrypt {
+        key: CryptoKey,
+    },
 }
 
 impl IRBinaryFunction {
@@ -36,6 +47,8 @@ impl IRBinaryFunction {
             #[cfg(feature = "binary_encoding")]
             Reinterpret(dtype, _) => mapper.with_dtype(dtype.clone()),
             Slice | Head | Tail => mapper.with_same_dtype(),
+            #[cfg(feature = "crypto")]
+            Encrypt { .. } | Decrypt { .. } => mapper.with_dtype(DataType::Binary),
         }
     }
 
@@ -53,6 +66,8 @@ impl IRBinaryFunction {
             | B::Base64Encode
             | B::Reinterpret(_, _) => FunctionOptions::elementwise(),
             B::Slice | B::Head | B::Tail => FunctionOptions::elementwise(),
+            #[cfg(feature = "crypto")]
+            B::Encrypt { .. } | B::Decrypt { .. } => FunctionOptions::elementwise(),
         }
     }
 }
@@ -78,6 +93,10 @@ impl Display for IRBinaryFunction {
             Slice => "slice",
             Head => "head",
             Tail => "tail",
+            #[cfg(feature = "crypto")]
+            Encrypt { .. } => "encrypt",
+            #[cfg(feature = "crypto")]
+            Decrypt { .. } => "decrypt",
         };
         write!(f, "bin.{s}")
     }
@@ -4,6 +4,8 @@ use super::*;
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub enum IRCategoricalFunction {
     GetCategories,
+    ExtendCategories(Vec<PlSmallStr>),
+    CastWithAddedCategories(Vec<PlSmallStr>),
     #[cfg(feature = "strings")]
     LenBytes,
     #[cfg(feature = "strings")]
@@ -21,6 +23,18 @@ impl IRCategoricalFunction {
         use IRCategoricalFunction::*;
         match self {
             GetCategories => mapper.with_dtype(DataType::String),
+            ExtendCategories(categories) => mapper.try_map_dtype(|dt| {
+                let fcats = enum_categories(dt, "extend_categories")?;
+                let categories = categories.iter().map(PlSmallStr::as_str);
+                Ok(DataType::from_frozen_categories(fcats.with_added_categories(categories)?))
+            }),
+            CastWithAddedCategories(categories) => mapper.try_map_dtype(|dt| {
+                let fcats = enum_categories(dt, "cast_with_added_categories")?;
+                let target = FrozenCategories::new(categories.iter().map(PlSmallStr::as_str))?;
+                Ok(DataType::from_frozen_categories(
+                    target.with_added_categories(fcats.categories().values_iter())?,
+                ))
+            }),
             #[cfg(feature = "strings")]
             LenBytes => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "strings")]
@@ -38,6 +52,9 @@ impl IRCategoricalFunction {
         use IRCategoricalFunction as C;
         match self {
             C::GetCategories => FunctionOptions::groupwise(),
+            C::ExtendCategories(_) | C::CastWithAddedCategories(_) => {
+                FunctionOptions::elementwise()
+            },
             #[cfg(feature = "strings")]
             C::LenBytes | C::LenChars | C::StartsWith(_) | C::EndsWith(_) | C::Slice(_, _) => {
                 FunctionOptions::elementwise()
@@ -46,11 +63,20 @@ impl IRCategoricalFunction {
     }
 }
 
+fn enum_categories<'a>(dtype: &'a DataType, op: &str) -> PolarsResult<&'a Arc<FrozenCategories>> {
+    match dtype {
+        DataType::Enum(fcats, _) => Ok(fcats),
+        dt => polars_bail!(InvalidOperation: "`cat.{op}` is only supported for Enum, got {dt}"),
+    }
+}
+
 impl Display for IRCategoricalFunction {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         use IRCategoricalFunction::*;
         let s = match self {
             GetCategories => "get_categories",
+            ExtendCategories(_) => "extend_categories",
+            CastWithAddedCategories(_) => "cast_with_added_categories",
             #[cfg(feature = "strings")]
             LenBytes => "len_bytes",
             #[cfg(feature = "strings")]
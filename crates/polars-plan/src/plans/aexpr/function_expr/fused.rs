@@ -1,11 +1,15 @@
+use polars_ops::series::FusedElementwise;
+
 use super::*;
 
 #[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Copy, Clone, PartialEq, Debug, Hash)]
+#[derive(Clone, PartialEq, Debug, Hash)]
 pub enum FusedOperator {
     MultiplyAdd,
     SubMultiply,
     MultiplySub,
+    /// A chain of elementwise operations on a single float column, evaluated in one pass.
+    Elementwise(Arc<FusedElementwise>),
 }
 
 impl Display for FusedOperator {
@@ -14,6 +18,7 @@ impl Display for FusedOperator {
             FusedOperator::MultiplyAdd => "fma",
             FusedOperator::SubMultiply => "fsm",
             FusedOperator::MultiplySub => "fms",
+            FusedOperator::Elementwise(_) => "fused_elementwise",
         };
         write!(f, "{s}")
     }
@@ -0,0 +1,51 @@
+use super::*;
+
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum IRGeoFunction {
+    Point,
+    X,
+    Y,
+    Distance,
+    Contains,
+    Within,
+    /// Takes the bounds `xmin`, `ymin`, `xmax` and `ymax` as additional inputs.
+    IntersectsBbox,
+}
+
+impl IRGeoFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IRGeoFunction::*;
+        match self {
+            Point => mapper.with_dtype(DataType::geometry()),
+            X | Y | Distance => mapper.with_dtype(DataType::Float64),
+            Contains | Within | IntersectsBbox => mapper.with_dtype(DataType::Boolean),
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        FunctionOptions::elementwise()
+    }
+}
+
+impl Display for IRGeoFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRGeoFunction::*;
+        let s = match self {
+            Point => "point",
+            X => "x",
+            Y => "y",
+            Distance => "distance",
+            Contains => "contains",
+            Within => "within",
+            IntersectsBbox => "intersects_bbox",
+        };
+        write!(f, "st.{s}")
+    }
+}
+
+impl From<IRGeoFunction> for IRFunctionExpr {
+    fn from(func: IRGeoFunction) -> Self {
+        IRFunctionExpr::Geo(func)
+    }
+}
@@ -43,6 +43,9 @@ pub enum IRListFunction {
         n: i64,
         null_behavior: NullBehavior,
     },
+    RollingSum(usize),
+    RollingMean(usize),
+    ZipWith(ListZipOp),
     Sort(SortOptions),
     Reverse,
     Unique(bool),
@@ -122,6 +125,31 @@ impl IRListFunction {
 
                 Ok(DataType::List(Box::new(inner_dt)))
             }),
+            RollingSum(_) => mapper.try_map_dtype(|dt| {
+                let DataType::List(inner) = dt else {
+                    polars_bail!(op = "list.rolling_sum", dt);
+                };
+                let inner_dt = match inner.as_ref() {
+                    DataType::Int8 | DataType::UInt8 | DataType::Int16 | DataType::UInt16 => {
+                        DataType::Int64
+                    },
+                    inner_dt => inner_dt.clone(),
+                };
+                Ok(DataType::List(Box::new(inner_dt)))
+            }),
+            RollingMean(_) => mapper.try_map_dtype(|dt| {
+                let DataType::List(inner) = dt else {
+                    polars_bail!(op = "list.rolling_mean", dt);
+                };
+                let inner_dt = match inner.as_ref() {
+                    #[cfg(feature = "dtype-f16")]
+                    DataType::Float16 => DataType::Float16,
+                    DataType::Float32 => DataType::Float32,
+                    _ => DataType::Float64,
+                };
+                Ok(DataType::List(Box::new(inner_dt)))
+            }),
+            ZipWith(_) => mapper.ensure_is_list()?.map_to_list_supertype(),
             Sort(_) => mapper.ensure_is_list()?.with_same_dtype(),
             Reverse => mapper.ensure_is_list()?.with_same_dtype(),
             Unique(_) => mapper.ensure_is_list()?.with_same_dtype(),
@@ -207,6 +235,9 @@ impl IRListFunction {
             | L::Var(_)
             | L::ArgMin
             | L::ArgMax
+            | L::RollingSum(_)
+            | L::RollingMean(_)
+            | L::ZipWith(_)
             | L::Sort(_)
             | L::Reverse
             | L::Unique(_)
@@ -269,6 +300,9 @@ impl Display for IRListFunction {
             ArgMax => "arg_max",
             #[cfg(feature = "diff")]
             Diff { .. } => "diff",
+            RollingSum(_) => "rolling_sum",
+            RollingMean(_) => "rolling_mean",
+            ZipWith(op) => return write!(f, "list.zip_with_{op}"),
             Length => "length",
             Sort(_) => "sort",
             Reverse => "reverse",
@@ -18,10 +18,14 @@ mod datetime;
 mod extension;
 #[cfg(feature = "fused")]
 mod fused;
+#[cfg(feature = "geo")]
+mod geo;
 mod list;
 #[cfg(feature = "ffi_plugin")]
 pub mod plugin;
 mod pow;
+#[cfg(feature = "quant")]
+mod quant;
 #[cfg(feature = "random")]
 mod random;
 #[cfg(feature = "range")]
@@ -70,7 +74,11 @@ pub use self::cat::IRCategoricalFunction;
 pub use self::datetime::IRTemporalFunction;
 #[cfg(feature = "dtype-extension")]
 pub use self::extension::IRExtensionFunction;
+#[cfg(feature = "geo")]
+pub use self::geo::IRGeoFunction;
 pub use self::pow::IRPowFunction;
+#[cfg(feature = "quant")]
+pub use self::quant::IRQuantFunction;
 #[cfg(feature = "range")]
 pub use self::range::IRRangeFunction;
 #[cfg(feature = "rolling_window")]
@@ -99,7 +107,11 @@ pub enum IRFunctionExpr {
     Categorical(IRCategoricalFunction),
     #[cfg(feature = "dtype-extension")]
     Extension(IRExtensionFunction),
+    #[cfg(feature = "geo")]
+    Geo(IRGeoFunction),
     ListExpr(IRListFunction),
+    #[cfg(feature = "quant")]
+    Quant(IRQuantFunction),
     #[cfg(feature = "strings")]
     StringExpr(IRStringFunction),
     #[cfg(feature = "dtype-struct")]
@@ -241,6 +253,10 @@ pub enum IRFunctionExpr {
     Diff(NullBehavior),
     #[cfg(feature = "pct_change")]
     PctChange,
+    #[cfg(feature = "pct_change_by")]
+    PctChangeBy {
+        offset: Duration,
+    },
     #[cfg(feature = "interpolate")]
     Interpolate(InterpolationMethod),
     #[cfg(feature = "interpolate_by")]
@@ -300,6 +316,12 @@ pub enum IRFunctionExpr {
     RLE,
     #[cfg(feature = "rle")]
     RLEID,
+    #[cfg(feature = "to_dummies")]
+    ToDummies {
+        categories: Vec<PlSmallStr>,
+        separator: PlSmallStr,
+        drop_first: bool,
+    },
     ToPhysical,
     #[cfg(feature = "random")]
     Random {
@@ -352,6 +374,12 @@ pub enum IRFunctionExpr {
     MeanHorizontal {
         ignore_nulls: bool,
     },
+    #[cfg(feature = "rule_failures")]
+    RuleFailures {
+        output: RuleFailuresOutput,
+    },
+    #[cfg(feature = "weighted")]
+    Weighted(WeightedAgg),
     #[cfg(feature = "ewma")]
     EwmMean {
         options: EWMOptions,
@@ -400,7 +428,11 @@ impl Hash for IRFunctionExpr {
             Categorical(f) => f.hash(state),
             #[cfg(feature = "dtype-extension")]
             Extension(f) => f.hash(state),
+            #[cfg(feature = "geo")]
+            Geo(f) => f.hash(state),
             ListExpr(f) => f.hash(state),
+            #[cfg(feature = "quant")]
+            Quant(f) => f.hash(state),
             #[cfg(feature = "strings")]
             StringExpr(f) => f.hash(state),
             #[cfg(feature = "dtype-struct")]
@@ -490,6 +522,10 @@ impl Hash for IRFunctionExpr {
             SumHorizontal { ignore_nulls } | MeanHorizontal { ignore_nulls } => {
                 ignore_nulls.hash(state)
             },
+            #[cfg(feature = "rule_failures")]
+            RuleFailures { output } => output.hash(state),
+            #[cfg(feature = "weighted")]
+            Weighted(agg) => agg.hash(state),
             MaxHorizontal | MinHorizontal | DropNans | DropNulls | Reverse | ArgUnique | ArgMin
             | ArgMax | Product | Shift | ShiftAndFill | Rechunk => {},
             Append { upcast } => {
@@ -583,6 +619,8 @@ impl Hash for IRFunctionExpr {
             Coalesce => {},
             #[cfg(feature = "pct_change")]
             PctChange => {},
+            #[cfg(feature = "pct_change_by")]
+            PctChangeBy { offset } => offset.hash(state),
             #[cfg(feature = "log")]
             Entropy { base, normalize } => {
                 base.to_bits().hash(state);
@@ -647,6 +685,16 @@ impl Hash for IRFunctionExpr {
             RLE => {},
             #[cfg(feature = "rle")]
             RLEID => {},
+            #[cfg(feature = "to_dummies")]
+            ToDummies {
+                categories,
+                separator,
+                drop_first,
+            } => {
+                categories.hash(state);
+                separator.hash(state);
+                drop_first.hash(state);
+            },
             ToPhysical => {},
             SetSortedFlag(is_sorted) => is_sorted.hash(state),
             #[cfg(feature = "ewma")]
@@ -704,7 +752,11 @@ impl Display for IRFunctionExpr {
             Categorical(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-extension")]
             Extension(func) => return write!(f, "{func}"),
+            #[cfg(feature = "geo")]
+            Geo(func) => return write!(f, "{func}"),
             ListExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "quant")]
+            Quant(func) => return write!(f, "{func}"),
             #[cfg(feature = "strings")]
             StringExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-struct")]
@@ -811,6 +863,8 @@ impl Display for IRFunctionExpr {
             Diff(_) => "diff",
             #[cfg(feature = "pct_change")]
             PctChange => "pct_change",
+            #[cfg(feature = "pct_change_by")]
+            PctChangeBy { .. } => "pct_change_by",
             #[cfg(feature = "interpolate")]
             Interpolate(_) => "interpolate",
             #[cfg(feature = "interpolate_by")]
@@ -859,6 +913,8 @@ impl Display for IRFunctionExpr {
             RLE => "rle",
             #[cfg(feature = "rle")]
             RLEID => "rle_id",
+            #[cfg(feature = "to_dummies")]
+            ToDummies { .. } => "to_dummies",
             ToPhysical => "to_physical",
             #[cfg(feature = "random")]
             Random { method, .. } => method.into(),
@@ -877,6 +933,10 @@ impl Display for IRFunctionExpr {
             MinHorizontal => "min_horizontal",
             SumHorizontal { .. } => "sum_horizontal",
             MeanHorizontal { .. } => "mean_horizontal",
+            #[cfg(feature = "rule_failures")]
+            RuleFailures { .. } => "rule_failures",
+            #[cfg(feature = "weighted")]
+            Weighted(agg) => return Display::fmt(agg, f),
             #[cfg(feature = "ewma")]
             EwmMean { .. } => "ewm_mean",
             #[cfg(feature = "ewma_by")]
@@ -998,7 +1058,11 @@ impl IRFunctionExpr {
             F::Categorical(e) => e.function_options(),
             #[cfg(feature = "dtype-extension")]
             F::Extension(e) => e.function_options(),
+            #[cfg(feature = "geo")]
+            F::Geo(e) => e.function_options(),
             F::ListExpr(e) => e.function_options(),
+            #[cfg(feature = "quant")]
+            F::Quant(e) => e.function_options(),
             #[cfg(feature = "strings")]
             F::StringExpr(e) => e.function_options(),
             #[cfg(feature = "dtype-struct")]
@@ -1130,6 +1194,8 @@ impl IRFunctionExpr {
             F::Diff(NullBehavior::Ignore) => FunctionOptions::length_preserving(),
             #[cfg(feature = "pct_change")]
             F::PctChange => FunctionOptions::length_preserving(),
+            #[cfg(feature = "pct_change_by")]
+            F::PctChangeBy { .. } => FunctionOptions::length_preserving(),
             #[cfg(feature = "interpolate")]
             F::Interpolate(_) => FunctionOptions::length_preserving(),
             #[cfg(feature = "interpolate_by")]
@@ -1171,6 +1237,9 @@ impl IRFunctionExpr {
             F::RLE => FunctionOptions::groupwise(),
             #[cfg(feature = "rle")]
             F::RLEID => FunctionOptions::length_preserving(),
+            #[cfg(feature = "to_dummies")]
+            F::ToDummies { .. } => FunctionOptions::elementwise()
+                .with_flags(|f| f | FunctionFlags::PASS_NAME_TO_APPLY),
             F::ToPhysical => FunctionOptions::elementwise(),
             #[cfg(feature = "random")]
             F::Random {
@@ -1190,6 +1259,11 @@ impl IRFunctionExpr {
             }),
             F::MeanHorizontal { .. } | F::SumHorizontal { .. } => FunctionOptions::elementwise()
                 .with_flags(|f| f | FunctionFlags::INPUT_WILDCARD_EXPANSION),
+            #[cfg(feature = "rule_failures")]
+            F::RuleFailures { .. } => FunctionOptions::elementwise()
+                .with_flags(|f| f | FunctionFlags::INPUT_WILDCARD_EXPANSION),
+            #[cfg(feature = "weighted")]
+            F::Weighted(_) => FunctionOptions::aggregation(),
 
             F::FoldHorizontal { returns_scalar, .. }
             | F::ReduceHorizontal { returns_scalar, .. } => FunctionOptions::groupwise()
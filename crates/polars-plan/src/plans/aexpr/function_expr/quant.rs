@@ -0,0 +1,55 @@
+use super::*;
+
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum IRQuantFunction {
+    Drawdown,
+    MaxDrawdown,
+    HitRatio,
+    Sharpe { annualization: f64 },
+}
+
+impl Hash for IRQuantFunction {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        if let Self::Sharpe { annualization } = self {
+            annualization.to_bits().hash(state);
+        }
+    }
+}
+
+impl IRQuantFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        mapper.with_dtype(DataType::Float64)
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        use IRQuantFunction::*;
+        match self {
+            Drawdown => FunctionOptions::length_preserving(),
+            MaxDrawdown => FunctionOptions::aggregation(),
+            HitRatio | Sharpe { .. } => {
+                FunctionOptions::aggregation().flag(FunctionFlags::NON_ORDER_OBSERVING)
+            },
+        }
+    }
+}
+
+impl Display for IRQuantFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRQuantFunction::*;
+        let s = match self {
+            Drawdown => "drawdown",
+            MaxDrawdown => "max_drawdown",
+            HitRatio => "hit_ratio",
+            Sharpe { .. } => "sharpe",
+        };
+        write!(f, "quant.{s}")
+    }
+}
+
+impl From<IRQuantFunction> for IRFunctionExpr {
+    fn from(func: IRQuantFunction) -> Self {
+        IRFunctionExpr::Quant(func)
+    }
+}
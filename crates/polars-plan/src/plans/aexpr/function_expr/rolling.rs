@@ -15,6 +15,8 @@ pub enum IRRollingFunction {
     Skew,
     #[cfg(feature = "moment")]
     Kurtosis,
+    #[cfg(feature = "outliers")]
+    Mad,
     #[cfg(feature = "cov")]
     CorrCov {
         corr_cov_options: RollingCovOptions,
@@ -41,6 +43,8 @@ impl Display for IRRollingFunction {
             Skew => "skew",
             #[cfg(feature = "moment")]
             Kurtosis => "kurtosis",
+            #[cfg(feature = "outliers")]
+            Mad => "mad",
             #[cfg(feature = "cov")]
             CorrCov { is_corr, .. } => {
                 if *is_corr {
@@ -20,7 +20,11 @@ impl IRFunctionExpr {
             Categorical(func) => func.get_field(mapper),
             #[cfg(feature = "dtype-extension")]
             Extension(func) => func.get_field(mapper),
+            #[cfg(feature = "geo")]
+            Geo(func) => func.get_field(mapper),
             ListExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "quant")]
+            Quant(func) => func.get_field(mapper),
             #[cfg(feature = "strings")]
             StringExpr(s) => s.get_field(mapper),
             #[cfg(feature = "dtype-struct")]
@@ -82,6 +86,8 @@ impl IRFunctionExpr {
                     CorrCov { .. } => mapper.map_to_float_dtype(),
                     #[cfg(feature = "moment")]
                     Skew | Kurtosis => mapper.map_to_float_dtype(),
+                    #[cfg(feature = "outliers")]
+                    Mad => mapper.with_dtype(DataType::Float64),
                     Map(_) => mapper.try_map_field(|field| {
                         if options.weights.is_some() {
                             let dtype = match field.dtype() {
@@ -261,6 +267,8 @@ impl IRFunctionExpr {
                 DataType::Float32 => dt.clone(),
                 _ => DataType::Float64,
             }),
+            #[cfg(feature = "pct_change_by")]
+            PctChangeBy { .. } => mapper.map_numeric_to_float_dtype(true),
             #[cfg(feature = "interpolate")]
             Interpolate(method) => match method {
                 InterpolationMethod::Linear => mapper.map_numeric_to_float_dtype(false),
@@ -342,6 +350,25 @@ impl IRFunctionExpr {
             }),
             #[cfg(feature = "rle")]
             RLEID => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "to_dummies")]
+            ToDummies {
+                categories,
+                separator,
+                drop_first,
+            } => mapper.try_map_field(|field| {
+                let name = field.name();
+                let fields = categories
+                    .iter()
+                    .skip(usize::from(*drop_first))
+                    .map(|category| {
+                        Field::new(
+                            polars_utils::format_pl_smallstr!("{name}{separator}{category}"),
+                            polars_ops::series::DUMMY_DTYPE,
+                        )
+                    })
+                    .collect();
+                Ok(Field::new(name.clone(), DataType::Struct(fields)))
+            }),
             ToPhysical => mapper.to_physical_type(),
             #[cfg(feature = "random")]
             Random { .. } => mapper.with_same_dtype(),
@@ -413,6 +440,12 @@ impl IRFunctionExpr {
                 }
                 f
             }),
+            #[cfg(feature = "rule_failures")]
+            RuleFailures { output } => {
+                mapper.with_dtype(rule_failures_dtype(mapper.args().len(), *output))
+            },
+            #[cfg(feature = "weighted")]
+            Weighted(_) => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "ewma")]
             EwmMean { .. } => mapper.map_numeric_to_float_dtype(true),
             #[cfg(feature = "ewma_by")]
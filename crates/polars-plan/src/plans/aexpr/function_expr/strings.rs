@@ -1,5 +1,7 @@
 #[cfg(feature = "dtype-decimal")]
 use polars_compute::decimal::DEC128_MAX_PREC;
+#[cfg(feature = "crypto")]
+use polars_ops::chunked_array::crypto::CryptoKey;
 #[cfg(feature = "dtype-struct")]
 use polars_utils::format_pl_smallstr;
 
@@ -139,6 +141,30 @@ pub enum IRStringFunction {
     },
     #[cfg(feature = "regex")]
     EscapeRegex,
+    #[cfg(feature = "string_mask")]
+    MaskPattern {
+        pat: PlSmallStr,
+        replacement: PlSmallStr,
+        keep_last_n: usize,
+    },
+    #[cfg(feature = "string_mask")]
+    MaskPii {
+        pattern: PiiPattern,
+        replacement: PlSmallStr,
+        keep_last_n: usize,
+    },
+    #[cfg(feature = "crypto")]
+    Tokenize {
+        key: CryptoKey,
+    },
+    #[cfg(feature = "crypto")]
+    Encrypt {
+        key: CryptoKey,
+    },
+    #[cfg(feature = "crypto")]
+    MaskFormatPreserving {
+        key: CryptoKey,
+    },
 }
 
 impl IRStringFunction {
@@ -229,6 +255,12 @@ impl IRStringFunction {
             FindMany { .. } => mapper.with_dtype(DataType::List(Box::new(DataType::UInt32))),
             #[cfg(feature = "regex")]
             EscapeRegex => mapper.with_same_dtype(),
+            #[cfg(feature = "string_mask")]
+            MaskPattern { .. } | MaskPii { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "crypto")]
+            Tokenize { .. } | MaskFormatPreserving { .. } => mapper.with_dtype(DataType::String),
+            #[cfg(feature = "crypto")]
+            Encrypt { .. } => mapper.with_dtype(DataType::Binary),
         }
     }
 
@@ -307,6 +339,12 @@ impl IRStringFunction {
             S::FindMany { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "regex")]
             S::EscapeRegex => FunctionOptions::elementwise(),
+            #[cfg(feature = "string_mask")]
+            S::MaskPattern { .. } | S::MaskPii { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "crypto")]
+            S::Tokenize { .. } | S::Encrypt { .. } | S::MaskFormatPreserving { .. } => {
+                FunctionOptions::elementwise()
+            },
         }
     }
 }
@@ -402,6 +440,16 @@ impl Display for IRStringFunction {
             FindMany { .. } => "extract_many",
             #[cfg(feature = "regex")]
             EscapeRegex => "escape_regex",
+            #[cfg(feature = "string_mask")]
+            MaskPattern { .. } => "mask_pattern",
+            #[cfg(feature = "string_mask")]
+            MaskPii { .. } => "mask_pii",
+            #[cfg(feature = "crypto")]
+            Tokenize { .. } => "tokenize",
+            #[cfg(feature = "crypto")]
+            Encrypt { .. } => "encrypt",
+            #[cfg(feature = "crypto")]
+            MaskFormatPreserving { .. } => "mask_format_preserving",
         };
         write!(f, "str.{s}")
     }
@@ -1,17 +1,22 @@
 //! This module creates predicates that can skip record batches of rows based on statistics about
 //! that record batch.
 
-use polars_core::prelude::{AnyValue, DataType, Scalar};
+use polars_core::error::PolarsResult;
+use polars_core::prelude::{AnyValue, DataType, IDX_DTYPE, Scalar};
 use polars_core::schema::Schema;
+use polars_io::predicates::NestedStatisticsPath;
 use polars_utils::aliases::PlIndexMap;
 use polars_utils::arena::{Arena, Node};
 use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
 
 use super::super::evaluate::{constant_evaluate, into_column};
-use super::super::{AExpr, IRBooleanFunction, IRFunctionExpr, Operator};
+#[cfg(feature = "dtype-struct")]
+use super::super::IRStructFunction;
+use super::super::{AExpr, IRBooleanFunction, IRFunctionExpr, IRListFunction, Operator};
 use crate::plans::aexpr::builder::IntoAExprBuilder;
 use crate::plans::predicates::get_binary_expr_col_and_lv;
+use crate::plans::visitor::{AexprNode, RewritingVisitor, TreeWalker};
 use crate::plans::{AExprBuilder, aexpr_to_leaf_names_iter, is_scalar_ae, rename_columns};
 
 /// Return a new boolean expression determines whether a batch can be skipped based on min, max and
@@ -30,6 +35,117 @@ pub fn aexpr_to_skip_batch_predicate(
     aexpr_to_skip_batch_predicate_rec(e, expr_arena, schema, 0)
 }
 
+/// Replace the struct fields and list lengths of columns in `e` by columns named after their
+/// [`NestedStatisticsPath`], so that [`aexpr_to_skip_batch_predicate`] can use the statistics of
+/// the nested values.
+///
+/// Returns the new expression and the replaced paths with their types. The schema given to
+/// [`aexpr_to_skip_batch_predicate`] should be extended with the replaced paths.
+pub fn replace_nested_statistics_paths(
+    e: Node,
+    expr_arena: &mut Arena<AExpr>,
+    schema: &Schema,
+) -> (Node, Vec<(NestedStatisticsPath, DataType)>) {
+    struct ReplaceNestedPaths<'a> {
+        schema: &'a Schema,
+        paths: PlIndexMap<PlSmallStr, (NestedStatisticsPath, DataType)>,
+    }
+
+    impl RewritingVisitor for ReplaceNestedPaths<'_> {
+        type Node = AexprNode;
+        type Arena = Arena<AExpr>;
+
+        fn mutate(
+            &mut self,
+            node: Self::Node,
+            arena: &mut Self::Arena,
+        ) -> PolarsResult<Self::Node> {
+            let AExpr::Function {
+                input, function, ..
+            } = arena.get(node.node())
+            else {
+                return Ok(node);
+            };
+            let [input] = input.as_slice() else {
+                return Ok(node);
+            };
+            let AExpr::Column(name) = arena.get(input.node()) else {
+                return Ok(node);
+            };
+
+            // The input may already be a replaced struct field.
+            let (parent, dtype) = match self.paths.get(name) {
+                Some((path, dtype)) => (Some(path), dtype),
+                None => match self.schema.get(name) {
+                    Some(dtype) => (None, dtype),
+                    None => return Ok(node),
+                },
+            };
+
+            let (path, dtype) = match (function, parent, dtype) {
+                #[cfg(feature = "dtype-struct")]
+                (
+                    IRFunctionExpr::StructExpr(IRStructFunction::FieldByName(field)),
+                    None | Some(NestedStatisticsPath::StructField { .. }),
+                    DataType::Struct(struct_fields),
+                ) => {
+                    let Some(field_dtype) = struct_fields
+                        .iter()
+                        .find(|f| f.name() == field)
+                        .map(|f| f.dtype().clone())
+                    else {
+                        return Ok(node);
+                    };
+                    let path = match parent {
+                        Some(NestedStatisticsPath::StructField { column, fields }) => {
+                            let mut fields = fields.clone();
+                            fields.push(field.clone());
+                            NestedStatisticsPath::StructField {
+                                column: column.clone(),
+                                fields,
+                            }
+                        },
+                        _ => NestedStatisticsPath::StructField {
+                            column: name.clone(),
+                            fields: vec![field.clone()],
+                        },
+                    };
+                    (path, field_dtype)
+                },
+                (IRFunctionExpr::ListExpr(IRListFunction::Length), None, DataType::List(_)) => (
+                    NestedStatisticsPath::ListLength {
+                        column: name.clone(),
+                    },
+                    IDX_DTYPE,
+                ),
+                _ => return Ok(node),
+            };
+
+            let name = path.name();
+            if self.schema.contains(&name) {
+                return Ok(node);
+            }
+            self.paths.insert(name.clone(), (path, dtype));
+            Ok(AexprNode::new(arena.add(AExpr::Column(name))))
+        }
+    }
+
+    let mut visitor = ReplaceNestedPaths {
+        schema,
+        paths: PlIndexMap::default(),
+    };
+    let node = AexprNode::new(e)
+        .rewrite(&mut visitor, expr_arena)
+        .unwrap()
+        .node();
+
+    // Struct fields that are only used to access deeper fields are not needed.
+    let mut paths = visitor.paths;
+    let used = aexpr_to_leaf_names_iter(node, expr_arena).collect::<Vec<_>>();
+    paths.retain(|name, _| used.contains(&name));
+    (node, paths.into_values().collect())
+}
+
 fn does_dtype_have_sufficient_order(dtype: &DataType) -> bool {
     // Rules surrounding floats are really complicated. I should get around to that.
     !dtype.is_nested() && !dtype.is_float() && !dtype.is_null() && !dtype.is_categorical()
@@ -503,3 +619,69 @@ fn aexpr_to_skip_batch_predicate_rec(
     }
     Some(expr.node())
 }
+
+#[cfg(test)]
+#[cfg(feature = "dtype-struct")]
+mod tests {
+    use polars_core::prelude::Field;
+
+    use super::*;
+    use crate::dsl::{col, lit};
+    use crate::plans::{ExprToIRContext, to_expr_ir};
+
+    #[test]
+    fn test_replace_nested_statistics_paths() -> PolarsResult<()> {
+        let inner = DataType::Struct(vec![Field::new("b".into(), DataType::Int64)]);
+        let schema = Schema::from_iter([
+            (
+                "s".into(),
+                DataType::Struct(vec![
+                    Field::new("a".into(), DataType::Int64),
+                    Field::new("inner".into(), inner),
+                ]),
+            ),
+            ("l".into(), DataType::List(Box::new(DataType::Int64))),
+        ]);
+
+        let expr = col("s")
+            .struct_()
+            .field_by_name("a")
+            .gt(lit(5))
+            .and(
+                col("s")
+                    .struct_()
+                    .field_by_name("inner")
+                    .struct_()
+                    .field_by_name("b")
+                    .eq(lit(1)),
+            )
+            .and(col("l").list().len().lt(lit(3)));
+
+        let mut arena = Arena::new();
+        let mut ctx = ExprToIRContext::new(&mut arena, &schema);
+        let node = to_expr_ir(expr, &mut ctx)?.node();
+        let (node, paths) = replace_nested_statistics_paths(node, &mut arena, &schema);
+
+        let mut names = paths
+            .iter()
+            .map(|(path, dtype)| (path.name(), dtype.clone()))
+            .collect::<Vec<_>>();
+        names.sort_by(|l, r| l.0.cmp(&r.0));
+        assert_eq!(
+            names,
+            [
+                ("l.len()".into(), IDX_DTYPE),
+                ("s.a".into(), DataType::Int64),
+                ("s.inner.b".into(), DataType::Int64),
+            ]
+        );
+        assert!(
+            aexpr_to_leaf_names_iter(node, &arena).all(|name| names.iter().any(|(n, _)| n == name))
+        );
+
+        let mut schema = schema;
+        schema.extend(names);
+        assert!(aexpr_to_skip_batch_predicate(node, &mut arena, &schema).is_some());
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 #[cfg(feature = "dtype-decimal")]
-use polars_compute::decimal::DEC128_MAX_PREC;
+use polars_compute::decimal::{DEC128_MAX_PREC, dec128_mean_scale};
 use polars_core::series::arithmetic::NumericListOp;
 use polars_utils::format_pl_smallstr;
 use recursive::recursive;
@@ -173,6 +173,8 @@ impl AExpr {
                             },
                             Boolean => Some(IDX_DTYPE),
                             UInt8 | Int8 | Int16 | UInt16 => Some(Int64),
+                            #[cfg(feature = "dtype-decimal")]
+                            Decimal(_, scale) => Some(Decimal(DEC128_MAX_PREC, *scale)),
                             _ => None,
                         };
                         if let Some(dt) = dt {
@@ -180,13 +182,15 @@ impl AExpr {
                         }
                         Ok(field)
                     },
-                    Median(expr) => {
-                        let field = [ctx.arena.get(*expr).to_field_impl(ctx)?];
-                        let mapper = FieldsMapper::new(&field);
-                        mapper.moment_dtype()
-                    },
-                    Mean(expr) => {
+                    Median(expr) | Mean(expr) => {
                         let field = [ctx.arena.get(*expr).to_field_impl(ctx)?];
+                        #[cfg(feature = "dtype-decimal")]
+                        if let Decimal(prec, scale) = field[0].dtype() {
+                            let dtype = Decimal(DEC128_MAX_PREC, dec128_mean_scale(*prec, *scale));
+                            let [mut field] = field;
+                            field.coerce(dtype);
+                            return Ok(field);
+                        }
                         let mapper = FieldsMapper::new(&field);
                         mapper.moment_dtype()
                     },
@@ -430,13 +434,17 @@ fn get_arithmetic_field(
     // take the left field as a whole.
     // don't take dtype and name separate as that splits the tree every node
     // leading to quadratic behavior. # 4736
-    //
-    // further right_type is only determined when needed.
     let mut left_field = left_ae.to_field_impl(ctx)?;
+    let right_type = right_ae.to_field_impl(ctx)?.dtype;
+
+    #[cfg(feature = "dtype-extension")]
+    if let Some(dtype) = get_extension_kernel_dtype(&left_field.dtype, &right_type, op)? {
+        left_field.coerce(dtype);
+        return Ok(left_field);
+    }
 
     let super_type = match op {
         Operator::Minus => {
-            let right_type = right_ae.to_field_impl(ctx)?.dtype;
             match (&left_field.dtype, &right_type) {
                 #[cfg(feature = "dtype-struct")]
                 (Struct(_), Struct(_)) => {
@@ -508,7 +516,6 @@ fn get_arithmetic_field(
             }
         },
         Operator::Plus => {
-            let right_type = right_ae.to_field_impl(ctx)?.dtype;
             match (&left_field.dtype, &right_type) {
                 #[cfg(feature = "dtype-struct")]
                 (Struct(_), Struct(_)) => {
@@ -570,8 +577,6 @@ fn get_arithmetic_field(
             }
         },
         _ => {
-            let right_type = right_ae.to_field_impl(ctx)?.dtype;
-
             match (&left_field.dtype, &right_type) {
                 #[cfg(feature = "dtype-struct")]
                 (Struct(_), Struct(_)) => {
@@ -682,11 +687,41 @@ fn get_arithmetic_field(
 fn get_truediv_field(left: Node, right: Node, ctx: &ToFieldContext) -> PolarsResult<Field> {
     let mut left_field = ctx.arena.get(left).to_field_impl(ctx)?;
     let right_field = ctx.arena.get(right).to_field_impl(ctx)?;
+    #[cfg(feature = "dtype-extension")]
+    if let Some(dtype) =
+        get_extension_kernel_dtype(left_field.dtype(), right_field.dtype(), Operator::TrueDivide)?
+    {
+        left_field.coerce(dtype);
+        return Ok(left_field);
+    }
     let out_type = get_truediv_dtype(left_field.dtype(), right_field.dtype())?;
     left_field.coerce(out_type);
     Ok(left_field)
 }
 
+/// The output dtype of an arithmetic operation that is implemented by a kernel registered for an
+/// extension type of one of the operands.
+#[cfg(feature = "dtype-extension")]
+fn get_extension_kernel_dtype(
+    left: &DataType,
+    right: &DataType,
+    op: Operator,
+) -> PolarsResult<Option<DataType>> {
+    use polars_core::datatypes::extension::{ExtensionBinaryOp, get_extension_binary_kernel};
+
+    let op = match op {
+        Operator::Plus => ExtensionBinaryOp::Add,
+        Operator::Minus => ExtensionBinaryOp::Sub,
+        Operator::Multiply => ExtensionBinaryOp::Mul,
+        Operator::Divide | Operator::TrueDivide => ExtensionBinaryOp::Div,
+        Operator::Modulus => ExtensionBinaryOp::Rem,
+        _ => return Ok(None),
+    };
+    get_extension_binary_kernel(left, right, op)
+        .map(|kernel| kernel.output_dtype(left, right))
+        .transpose()
+}
+
 fn get_truediv_dtype(left_dtype: &DataType, right_dtype: &DataType) -> PolarsResult<DataType> {
     use DataType::*;
 
@@ -13,6 +13,9 @@ pub struct AnonymousScanArgs {
     pub predicate: Option<Expr>,
 }
 
+/// Produces the batches of a single execution of an [`AnonymousScan`].
+pub type AnonymousBatchReader = Box<dyn FnMut() -> PolarsResult<Option<DataFrame>> + Send>;
+
 pub trait AnonymousScan: Send + Sync {
     fn as_any(&self) -> &dyn Any;
     /// Creates a DataFrame from the supplied function & scan options.
@@ -35,6 +38,28 @@ pub trait AnonymousScan: Send + Sync {
     fn allows_projection_pushdown(&self) -> bool {
         false
     }
+    /// Specify if the scan provider can produce its output in batches with
+    /// [`AnonymousScan::next_batch`]. This is used by the streaming engine.
+    ///
+    /// Defaults to `false`
+    fn allows_batches(&self) -> bool {
+        false
+    }
+    /// Creates the next batch of the output, or `None` if the scan is exhausted.
+    ///
+    /// Only called if [`AnonymousScan::allows_batches`] returns `true`.
+    fn next_batch(&self) -> PolarsResult<Option<DataFrame>> {
+        polars_bail!(ComputeError: "anonymous scan does not support reading in batches");
+    }
+    /// Creates the reader of the batches of an execution of the scan. The reader holds the state
+    /// of the execution, e.g. its position in the source, so that every execution starts anew.
+    /// It is created when the first batch is requested.
+    ///
+    /// Only called if [`AnonymousScan::allows_batches`] returns `true`. Defaults to `None`, which
+    /// reads the batches with [`AnonymousScan::next_batch`].
+    fn batch_reader(&self) -> PolarsResult<Option<AnonymousBatchReader>> {
+        Ok(None)
+    }
 }
 
 impl Debug for dyn AnonymousScan {
@@ -258,12 +258,18 @@ impl<'a> IRBuilder<'a> {
     }
 
     // call this if the schema needs to be updated
-    pub fn explode(self, columns: Arc<[PlSmallStr]>, options: ExplodeOptions) -> Self {
+    pub fn explode(
+        self,
+        columns: Arc<[PlSmallStr]>,
+        options: ExplodeOptions,
+        mismatch: ExplodeLengthMismatch,
+    ) -> Self {
         let lp = IR::MapFunction {
             input: self.root,
             function: FunctionIR::Explode {
                 columns,
                 options,
+                mismatch,
                 schema: Default::default(),
             },
         };
@@ -1,3 +1,5 @@
+use polars_core::utils::PromotionRules;
+
 use super::*;
 
 fn nodes_to_schemas(inputs: &[Node], lp_arena: &mut Arena<IR>) -> Vec<SchemaRef> {
@@ -67,8 +69,74 @@ pub(super) fn convert_diagonal_concat(
     }
 }
 
+/// Reorder the columns of the inputs to the order of the first input.
+///
+/// Errors with the mismatches of all inputs if they don't have the same columns as the first
+/// input, or, with `check_dtypes`, if the columns have different data types.
+pub(super) fn convert_by_name_union(
+    inputs: &mut [Node],
+    check_dtypes: bool,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let Some((first, others)) = inputs.split_first_mut() else {
+        return Ok(());
+    };
+    let schema = lp_arena.get(*first).schema(lp_arena).into_owned();
+
+    let mut mismatches = vec![];
+    for (i, input) in others.iter_mut().enumerate() {
+        let input_schema = lp_arena.get(*input).schema(lp_arena).into_owned();
+
+        let mut input_mismatches = vec![];
+        let missing = schema
+            .iter_names()
+            .filter(|name| !input_schema.contains(name))
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            input_mismatches.push(format!("missing columns {missing:?}"));
+        }
+        let extra = input_schema
+            .iter_names()
+            .filter(|name| !schema.contains(name))
+            .collect::<Vec<_>>();
+        if !extra.is_empty() {
+            input_mismatches.push(format!("unexpected columns {extra:?}"));
+        }
+        if check_dtypes {
+            for (name, dtype) in schema.iter() {
+                if let Some(input_dtype) = input_schema.get(name) {
+                    if input_dtype != dtype {
+                        input_mismatches.push(format!(
+                            "column {name:?} has dtype {input_dtype}, expected {dtype}"
+                        ));
+                    }
+                }
+            }
+        }
+        if !input_mismatches.is_empty() {
+            mismatches.push(format!("input {}: {}", i + 1, input_mismatches.join("; ")));
+            continue;
+        }
+
+        if !input_schema.iter_names().eq(schema.iter_names()) {
+            *input = IRBuilder::new(*input, expr_arena, lp_arena)
+                .project_simple(schema.iter_names().map(|v| v.as_str()))?
+                .node();
+        }
+    }
+
+    polars_ensure!(
+        mismatches.is_empty(),
+        SchemaMismatch: "'concat' by name requires all inputs to have the columns of input 0\n{}",
+        mismatches.join("\n")
+    );
+    Ok(())
+}
+
 pub(super) fn convert_st_union(
     inputs: &mut [Node],
+    promotion_rules: PromotionRules,
     lp_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
     opt_flags: &OptFlags,
@@ -78,7 +146,7 @@ pub(super) fn convert_st_union(
     let mut changed = false;
     for input in inputs[1..].iter() {
         let schema_other = lp_arena.get(*input).schema(lp_arena);
-        changed |= schema.to_supertype(schema_other.as_ref())?;
+        changed |= schema.to_supertype_with_rules(schema_other.as_ref(), promotion_rules)?;
     }
 
     if changed {
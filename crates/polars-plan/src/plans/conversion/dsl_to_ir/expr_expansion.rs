@@ -96,6 +96,10 @@ fn function_input_wildcard_expansion(function: &FunctionExpr) -> FunctionExpansi
     {
         expand_into_inputs |= matches!(function, F::ArrayExpr(ArrayFunction::Concat));
     }
+    #[cfg(feature = "rule_failures")]
+    {
+        expand_into_inputs |= matches!(function, F::RuleFailures { .. });
+    }
     #[cfg(feature = "dtype-struct")]
     {
         expand_into_inputs |= matches!(function, F::AsStruct);
@@ -242,7 +246,9 @@ fn needs_expansion(expr: &Expr) -> bool {
                     e,
                     Expr::Function {
                         function: FunctionExpr::StructExpr(
-                            StructFunction::SelectFields(_) | StructFunction::FieldByName(_)
+                            StructFunction::SelectFields(_)
+                                | StructFunction::SelectPath(_)
+                                | StructFunction::FieldByName(_)
                         ),
                         ..
                     }
@@ -674,7 +680,9 @@ fn expand_expression_rec(
                 if matches!(
                     function,
                     FunctionExpr::StructExpr(
-                        StructFunction::FieldByName(_) | StructFunction::SelectFields(_)
+                        StructFunction::FieldByName(_)
+                            | StructFunction::SelectFields(_)
+                            | StructFunction::SelectPath(_)
                     )
                 ) {
                     toggle_cse_for_structs(opt_flags);
@@ -707,6 +715,22 @@ fn expand_expression_rec(
                             );
                         }
                     },
+                    #[cfg(feature = "dtype-struct")]
+                    FunctionExpr::StructExpr(StructFunction::SelectPath(path)) => {
+                        let mut tmp_out = Vec::new();
+                        expand_single(
+                            &input[0],
+                            ignored_selector_columns,
+                            schema,
+                            &mut tmp_out,
+                            opt_flags,
+                            |e| e,
+                        )?;
+                        for e in tmp_out {
+                            let dtype = e.to_field(schema)?.dtype;
+                            expand_nested_path(e, &dtype, path.segments(), out)?;
+                        }
+                    },
                     _ => {
                         _ = expand_expression_by_combination(
                             input,
@@ -96,6 +96,14 @@ pub(super) fn convert_functions(
                 A::Slice(offset, length) => IA::Slice(offset, length),
                 #[cfg(feature = "array_to_struct")]
                 A::ToStruct(ng) => IA::ToStruct(ng),
+                #[cfg(feature = "array_linalg")]
+                A::Dot => IA::Dot,
+                #[cfg(feature = "array_linalg")]
+                A::MatVec => IA::MatVec,
+                #[cfg(feature = "array_linalg")]
+                A::Norm(norm) => IA::Norm(norm),
+                #[cfg(feature = "array_linalg")]
+                A::CosineSimilarity => IA::CosineSimilarity,
             })
         },
         F::BinaryExpr(binary_function) => {
@@ -131,6 +139,10 @@ pub(super) fn convert_functions(
                 B::Slice => IB::Slice,
                 B::Head => IB::Head,
                 B::Tail => IB::Tail,
+                #[cfg(feature = "crypto")]
+                B::Encrypt { key } => IB::Encrypt { key },
+                #[cfg(feature = "crypto")]
+                B::Decrypt { key } => IB::Decrypt { key },
             })
         },
         #[cfg(feature = "dtype-categorical")]
@@ -138,6 +150,8 @@ pub(super) fn convert_functions(
             use {CategoricalFunction as C, IRCategoricalFunction as IC};
             I::Categorical(match categorical_function {
                 C::GetCategories => IC::GetCategories,
+                C::ExtendCategories(v) => IC::ExtendCategories(v),
+                C::CastWithAddedCategories(v) => IC::CastWithAddedCategories(v),
                 #[cfg(feature = "strings")]
                 C::LenBytes => IC::LenBytes,
                 #[cfg(feature = "strings")]
@@ -164,6 +178,23 @@ pub(super) fn convert_functions(
                 E::Storage => IE::Storage,
             })
         },
+        #[cfg(feature = "geo")]
+        F::Geo(geo_function) => I::Geo(match geo_function {
+            GeoFunction::Point => IRGeoFunction::Point,
+            GeoFunction::X => IRGeoFunction::X,
+            GeoFunction::Y => IRGeoFunction::Y,
+            GeoFunction::Distance => IRGeoFunction::Distance,
+            GeoFunction::Contains => IRGeoFunction::Contains,
+            GeoFunction::Within => IRGeoFunction::Within,
+            GeoFunction::IntersectsBbox => IRGeoFunction::IntersectsBbox,
+        }),
+        #[cfg(feature = "quant")]
+        F::Quant(quant_function) => I::Quant(match quant_function {
+            QuantFunction::Drawdown => IRQuantFunction::Drawdown,
+            QuantFunction::MaxDrawdown => IRQuantFunction::MaxDrawdown,
+            QuantFunction::HitRatio => IRQuantFunction::HitRatio,
+            QuantFunction::Sharpe { annualization } => IRQuantFunction::Sharpe { annualization },
+        }),
         F::ListExpr(list_function) => {
             use {IRListFunction as IL, ListFunction as L};
             I::ListExpr(match list_function {
@@ -205,6 +236,9 @@ pub(super) fn convert_functions(
                 L::ArgMax => IL::ArgMax,
                 #[cfg(feature = "diff")]
                 L::Diff { n, null_behavior } => IL::Diff { n, null_behavior },
+                L::RollingSum(window_size) => IL::RollingSum(window_size),
+                L::RollingMean(window_size) => IL::RollingMean(window_size),
+                L::ZipWith(op) => IL::ZipWith(op),
                 L::Sort(sort_options) => IL::Sort(sort_options),
                 L::Reverse => IL::Reverse,
                 L::Unique(v) => IL::Unique(v),
@@ -368,6 +402,32 @@ pub(super) fn convert_functions(
                 },
                 #[cfg(feature = "regex")]
                 S::EscapeRegex => IS::EscapeRegex,
+                #[cfg(feature = "string_mask")]
+                S::MaskPattern {
+                    pat,
+                    replacement,
+                    keep_last_n,
+                } => IS::MaskPattern {
+                    pat,
+                    replacement,
+                    keep_last_n,
+                },
+                #[cfg(feature = "string_mask")]
+                S::MaskPii {
+                    pattern,
+                    replacement,
+                    keep_last_n,
+                } => IS::MaskPii {
+                    pattern,
+                    replacement,
+                    keep_last_n,
+                },
+                #[cfg(feature = "crypto")]
+                S::Tokenize { key } => IS::Tokenize { key },
+                #[cfg(feature = "crypto")]
+                S::Encrypt { key } => IS::Encrypt { key },
+                #[cfg(feature = "crypto")]
+                S::MaskFormatPreserving { key } => IS::MaskFormatPreserving { key },
             })
         },
         #[cfg(feature = "dtype-struct")]
@@ -378,7 +438,9 @@ pub(super) fn convert_functions(
                 S::RenameFields(pl_small_strs) => IS::RenameFields(pl_small_strs),
                 S::PrefixFields(pl_small_str) => IS::PrefixFields(pl_small_str),
                 S::SuffixFields(pl_small_str) => IS::SuffixFields(pl_small_str),
-                S::SelectFields(_) => unreachable!("handled by expression expansion"),
+                S::SelectFields(_) | S::SelectPath(_) => {
+                    unreachable!("handled by expression expansion")
+                },
                 #[cfg(feature = "json")]
                 S::JsonEncode => IS::JsonEncode,
                 S::WithFields => unreachable!("handled before"),
@@ -775,6 +837,8 @@ pub(super) fn convert_functions(
                     R::Skew => IR::Skew,
                     #[cfg(feature = "moment")]
                     R::Kurtosis => IR::Kurtosis,
+                    #[cfg(feature = "outliers")]
+                    R::Mad => IR::Mad,
                     #[cfg(feature = "cov")]
                     R::CorrCov {
                         corr_cov_options,
@@ -814,7 +878,6 @@ pub(super) fn convert_functions(
         F::Append { upcast } => I::Append { upcast },
         F::ShiftAndFill => {
             polars_ensure!(&e[1].is_scalar(ctx.arena), ShapeMismatch: "'n' must be a scalar value");
-            polars_ensure!(&e[2].is_scalar(ctx.arena), ShapeMismatch: "'fill_value' must be a scalar value");
             I::ShiftAndFill
         },
         F::Shift => {
@@ -894,6 +957,8 @@ pub(super) fn convert_functions(
         },
         #[cfg(feature = "pct_change")]
         F::PctChange => I::PctChange,
+        #[cfg(feature = "pct_change_by")]
+        F::PctChangeBy { offset } => I::PctChangeBy { offset },
         #[cfg(feature = "interpolate")]
         F::Interpolate(interpolation_method) => I::Interpolate(interpolation_method),
         #[cfg(feature = "interpolate_by")]
@@ -978,6 +1043,16 @@ pub(super) fn convert_functions(
         F::RLE => I::RLE,
         #[cfg(feature = "rle")]
         F::RLEID => I::RLEID,
+        #[cfg(feature = "to_dummies")]
+        F::ToDummies {
+            categories,
+            separator,
+            drop_first,
+        } => I::ToDummies {
+            categories,
+            separator,
+            drop_first,
+        },
         F::ToPhysical => I::ToPhysical,
         #[cfg(feature = "random")]
         F::Random { method, seed } => {
@@ -1057,6 +1132,10 @@ pub(super) fn convert_functions(
         F::MinHorizontal => I::MinHorizontal,
         F::SumHorizontal { ignore_nulls } => I::SumHorizontal { ignore_nulls },
         F::MeanHorizontal { ignore_nulls } => I::MeanHorizontal { ignore_nulls },
+        #[cfg(feature = "rule_failures")]
+        F::RuleFailures { output } => I::RuleFailures { output },
+        #[cfg(feature = "weighted")]
+        F::Weighted(agg) => I::Weighted(agg),
         #[cfg(feature = "ewma")]
         F::EwmMean { options } => I::EwmMean { options },
         #[cfg(feature = "ewma_by")]
@@ -94,6 +94,16 @@ pub fn resolve_join(
                     polars_bail!(InvalidOperation: "expected both 'by_left' and 'by_right' to be set in 'asof_join'")
                 },
             }
+            if let Some(tolerance_column) = &options.tolerance_column {
+                validate_columns_in_input([tolerance_column], &schema_left, "asof_join")?;
+            }
+            if let Some(end_column) = &options.end_column {
+                polars_ensure!(
+                    options.strategy == polars_ops::frame::AsofStrategy::Backward,
+                    InvalidOperation: "asof join with an end column requires the backward strategy"
+                );
+                validate_columns_in_input([end_column], &schema_right, "asof_join")?;
+            }
         }
 
         polars_ensure!(
@@ -271,21 +281,30 @@ pub fn resolve_join(
         let ltype = get_dtype!(lnode, &schema_left)?;
         let rtype = get_dtype!(rnode, &schema_right)?;
 
-        if let Some(dtype) = get_numeric_upcast_supertype_lossless(&ltype, &rtype) {
+        let rules = options.args.promotion_rules;
+        let supertype = if ltype == rtype || rules.is_default() {
             // We use overflowing cast to allow better optimization as we are casting to a known
             // lossless supertype.
-            //
+            get_numeric_upcast_supertype_lossless(&ltype, &rtype)
+                .map(|dtype| (dtype, CastOptions::Overflowing))
+        } else {
+            rules
+                .get_supertype(&ltype, &rtype)
+                .map(|dtype| (dtype, CastOptions::Strict))
+        };
+
+        if let Some((dtype, cast_options)) = supertype {
             // We have unique references to these nodes (they are created by this function),
             // so we can mutate in-place without causing side effects somewhere else.
             let casted_l = ctxt.expr_arena.add(AExpr::Cast {
                 expr: lnode.node(),
                 dtype: dtype.clone(),
-                options: CastOptions::Overflowing,
+                options: cast_options,
             });
             let casted_r = ctxt.expr_arena.add(AExpr::Cast {
                 expr: rnode.node(),
                 dtype,
-                options: CastOptions::Overflowing,
+                options: cast_options,
             });
 
             if key_cols_coalesced {
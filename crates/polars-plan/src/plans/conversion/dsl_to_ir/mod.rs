@@ -150,11 +150,19 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
 
             if args.diagonal {
                 inputs = concat::convert_diagonal_concat(inputs, ctxt.lp_arena, ctxt.expr_arena)?;
+            } else if args.by_name {
+                concat::convert_by_name_union(
+                    &mut inputs,
+                    !args.to_supertypes,
+                    ctxt.lp_arena,
+                    ctxt.expr_arena,
+                )?;
             }
 
             if args.to_supertypes {
                 concat::convert_st_union(
                     &mut inputs,
+                    args.promotion_rules,
                     ctxt.lp_arena,
                     ctxt.expr_arena,
                     ctxt.opt_flags,
@@ -1004,10 +1012,33 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                 .map_err(|e| e.context(failed_here!(format!("{}", function).to_lowercase())))?;
             let input_schema = ctxt.lp_arena.get(input).schema(ctxt.lp_arena);
 
+            // The new names only depend on the schema, so this is just a rename.
+            let function = match function {
+                DslFunction::RenameWith { columns, function } => {
+                    let columns = columns.into_columns(&input_schema, &Default::default())?;
+                    let mut existing = Vec::with_capacity(columns.len());
+                    let mut new = Vec::with_capacity(columns.len());
+                    for name in columns {
+                        let new_name = function.call(&name)?;
+                        if new_name != name {
+                            existing.push(name);
+                            new.push(new_name);
+                        }
+                    }
+                    DslFunction::Rename {
+                        existing: existing.into(),
+                        new: new.into(),
+                        strict: true,
+                    }
+                },
+                function => function,
+            };
+
             match function {
                 DslFunction::Explode {
                     columns,
                     options,
+                    mismatch,
                     allow_empty,
                 } => {
                     let columns = columns.into_columns(&input_schema, &Default::default())?;
@@ -1018,11 +1049,66 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     let function = FunctionIR::Explode {
                         columns: columns.into_iter().collect(),
                         options,
+                        mismatch,
                         schema: Default::default(),
                     };
                     let ir = IR::MapFunction { input, function };
                     return Ok(ctxt.lp_arena.add(ir));
                 },
+                DslFunction::MoveColumns { columns, position } => {
+                    let moved = columns.into_columns(&input_schema, &Default::default())?;
+                    let mut names = input_schema
+                        .iter_names()
+                        .filter(|name| !moved.contains(*name))
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    let anchor_idx = |anchor: &PlSmallStr| {
+                        polars_ensure!(
+                            !moved.contains(anchor),
+                            InvalidOperation: "cannot move column '{}' relative to itself", anchor
+                        );
+                        names
+                            .iter()
+                            .position(|name| name == anchor)
+                            .ok_or_else(|| polars_err!(col_not_found = anchor))
+                    };
+                    let idx = match position {
+                        ColumnPosition::First => 0,
+                        ColumnPosition::Last => names.len(),
+                        ColumnPosition::Index(index) => {
+                            let idx = if index < 0 {
+                                index + names.len() as i64
+                            } else {
+                                index
+                            };
+                            polars_ensure!(
+                                (0..=names.len() as i64).contains(&idx),
+                                OutOfBounds: "column index {} is out of bounds for {} columns",
+                                index, names.len()
+                            );
+                            idx as usize
+                        },
+                        ColumnPosition::Before(anchor) => anchor_idx(&anchor)?,
+                        ColumnPosition::After(anchor) => anchor_idx(&anchor)? + 1,
+                    };
+                    names.splice(idx..idx, moved);
+
+                    if names.iter().eq(input_schema.iter_names()) {
+                        return Ok(input);
+                    }
+                    let columns = names
+                        .into_iter()
+                        .map(|name| {
+                            let dtype = input_schema.get(&name).unwrap().clone();
+                            (name, dtype)
+                        })
+                        .collect();
+                    let ir = IR::SimpleProjection {
+                        input,
+                        columns: Arc::new(columns),
+                    };
+                    return Ok(ctxt.lp_arena.add(ir));
+                },
                 DslFunction::FillNan(fill_value) => {
                     let exprs = input_schema
                         .iter()
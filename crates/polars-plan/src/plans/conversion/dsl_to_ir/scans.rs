@@ -74,6 +74,40 @@ pub(super) fn dsl_to_ir(
             ctxt.verbose,
         )?;
 
+        if unified_scan_args.schema_unification_policy != SchemaUnificationPolicy::FirstFile {
+            unify_file_schemas(
+                &sources,
+                &scan_type_ir,
+                &mut file_info,
+                unified_scan_args,
+            )?;
+        }
+
+        #[cfg(feature = "parquet")]
+        if let FileScanDsl::Parquet { options } = &*scan_type
+            && let Some(schema_overwrite) = &options.schema_overwrite
+        {
+            let schema = Arc::make_mut(&mut file_info.schema);
+            for (name, dtype) in schema_overwrite.iter() {
+                let Some(file_dtype) = schema.get_mut(name) else {
+                    polars_bail!(
+                        ColumnNotFound:
+                        "dtype override given for column '{}', which is not in the file", name
+                    );
+                };
+                *file_dtype = dtype.clone();
+            }
+            // The readers cast these columns while decoding.
+            let overridden_columns = &mut unified_scan_args.cast_columns_policy.overridden_columns;
+            *overridden_columns = Some(
+                overridden_columns
+                    .iter()
+                    .flat_map(|columns| columns.iter().cloned())
+                    .chain(schema_overwrite.iter_names_cloned())
+                    .collect(),
+            );
+        }
+
         if unified_scan_args.hive_options.enabled.is_none() {
             // We expect this to be `Some(_)` after this point. If it hasn't been auto-enabled
             // we explicitly set it to disabled.
@@ -173,6 +207,155 @@ pub(super) fn dsl_to_ir(
     Ok(cached_ir.clone().unwrap())
 }
 
+/// Combine the schemas of all files of the scan according to the schema unification policy.
+fn unify_file_schemas(
+    sources: &ScanSources,
+    scan_type: &FileScanIR,
+    file_info: &mut FileInfo,
+    unified_scan_args: &mut UnifiedScanArgs,
+) -> PolarsResult<()> {
+    use polars_core::POOL;
+    use polars_core::utils::try_get_supertype;
+    use rayon::prelude::*;
+
+    let policy = unified_scan_args.schema_unification_policy;
+
+    #[allow(unused_mut)]
+    let mut is_parquet = false;
+    #[allow(unused_mut)]
+    let mut is_ipc = false;
+    #[cfg(feature = "parquet")]
+    if let FileScanIR::Parquet { options, .. } = scan_type {
+        // A user-provided schema takes precedence over the schemas of the files.
+        if options.schema.is_some() {
+            return Ok(());
+        }
+        is_parquet = true;
+    }
+    #[cfg(feature = "ipc")]
+    {
+        is_ipc = matches!(scan_type, FileScanIR::Ipc { .. });
+    }
+    polars_ensure!(
+        is_parquet || is_ipc,
+        InvalidOperation:
+        "schema unification policy {:?} is only supported for parquet and ipc scans", policy
+    );
+
+    if sources.len() <= 1 {
+        return Ok(());
+    }
+
+    let cloud_options = unified_scan_args.cloud_options.as_ref();
+    let read_file_schema = |i: usize| -> PolarsResult<SchemaRef> {
+        let source = sources.at(i);
+        #[cfg(feature = "parquet")]
+        if is_parquet {
+            return parquet_file_info(source, None, cloud_options, 1).map(|(info, _)| info.schema);
+        }
+        #[cfg(feature = "ipc")]
+        if is_ipc {
+            return ipc_file_info(source, None, cloud_options).map(|(info, _)| info.schema);
+        }
+        let _ = (source, cloud_options);
+        unreachable!()
+    };
+
+    let first_schema = match file_info.reader_schema.as_ref() {
+        Some(Either::Left(schema)) => Schema::from_arrow_schema(schema.as_ref()),
+        Some(Either::Right(schema)) => schema.as_ref().clone(),
+        None => return Ok(()),
+    };
+    let file_schemas = POOL.install(|| {
+        (1..sources.len())
+            .into_par_iter()
+            .map(read_file_schema)
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+    let file_name = |i: usize| sources.at(i).to_include_path_name().to_string();
+
+    match policy {
+        SchemaUnificationPolicy::FirstFile => {},
+        SchemaUnificationPolicy::Strict => {
+            let conflicts = file_schemas
+                .iter()
+                .enumerate()
+                .filter_map(|(i, schema)| {
+                    let mismatches = schema_mismatches(&first_schema, schema);
+                    (!mismatches.is_empty())
+                        .then(|| format!("'{}': {}", file_name(i + 1), mismatches.join(", ")))
+                })
+                .collect::<Vec<_>>();
+
+            polars_ensure!(
+                conflicts.is_empty(),
+                SchemaMismatch:
+                "schemas of {} files differ from the schema of the first file '{}':\n{}",
+                conflicts.len(),
+                file_name(0),
+                conflicts.join("\n")
+            );
+        },
+        SchemaUnificationPolicy::Union => {
+            let mut unified = first_schema;
+            let mut promoted_columns = PlIndexSet::new();
+
+            for (i, schema) in file_schemas.iter().enumerate() {
+                for (name, dtype) in schema.iter() {
+                    let Some(unified_dtype) = unified.get_mut(name) else {
+                        unified.with_column(name.clone(), dtype.clone());
+                        continue;
+                    };
+                    if unified_dtype != dtype {
+                        *unified_dtype = try_get_supertype(unified_dtype, dtype).map_err(|_| {
+                            polars_err!(
+                                SchemaMismatch:
+                                "cannot unify dtype {} of column '{}' in file '{}' with {}",
+                                dtype, name, file_name(i + 1), unified_dtype
+                            )
+                        })?;
+                        promoted_columns.insert(name.clone());
+                    }
+                }
+            }
+
+            let schema = Arc::make_mut(&mut file_info.schema);
+            for (name, dtype) in unified.iter() {
+                schema.with_column(name.clone(), dtype.clone());
+            }
+            file_info.reader_schema = Some(Either::Right(Arc::new(unified)));
+
+            unified_scan_args.missing_columns_policy = MissingColumnsPolicy::Insert;
+            if !promoted_columns.is_empty() {
+                unified_scan_args.cast_columns_policy.overridden_columns =
+                    Some(promoted_columns.into_iter().collect());
+            }
+        },
+    }
+
+    Ok(())
+}
+
+/// Describe how `schema` differs from `expected`, ignoring the order of the columns.
+fn schema_mismatches(expected: &Schema, schema: &Schema) -> Vec<String> {
+    let mut mismatches = vec![];
+    for (name, expected_dtype) in expected.iter() {
+        match schema.get(name) {
+            None => mismatches.push(format!("missing column '{name}'")),
+            Some(dtype) if dtype != expected_dtype => mismatches.push(format!(
+                "column '{name}' has dtype {dtype}, expected {expected_dtype}"
+            )),
+            Some(_) => {},
+        }
+    }
+    for name in schema.iter_names() {
+        if !expected.contains(name) {
+            mismatches.push(format!("extra column '{name}'"));
+        }
+    }
+    mismatches
+}
+
 pub(super) fn insert_row_index_to_schema(
     schema: &mut Schema,
     name: PlSmallStr,
@@ -371,7 +554,7 @@ pub fn csv_file_info(
         let mut reader = CompressedReader::try_new(mem_slice)?;
 
         let mut first_row_len = 0;
-        let (schema, _) = read_until_start_and_infer_schema(
+        let (inferred_schema, _) = read_until_start_and_infer_schema(
             csv_options,
             None,
             Some(Box::new(|line| {
@@ -383,7 +566,7 @@ pub fn csv_file_info(
         let estimated_rows =
             (reader.total_len_estimate() as f64 / first_row_len as f64).round() as usize;
 
-        Ok((schema, estimated_rows))
+        Ok((inferred_schema.read_schema(), estimated_rows))
     };
 
     let merge_func =
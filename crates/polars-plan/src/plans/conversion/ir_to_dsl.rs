@@ -340,6 +340,14 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IA::Explode(options) => A::Explode(options),
                 #[cfg(feature = "array_to_struct")]
                 IA::ToStruct(ng) => A::ToStruct(ng),
+                #[cfg(feature = "array_linalg")]
+                IA::Dot => A::Dot,
+                #[cfg(feature = "array_linalg")]
+                IA::MatVec => A::MatVec,
+                #[cfg(feature = "array_linalg")]
+                IA::Norm(norm) => A::Norm(norm),
+                #[cfg(feature = "array_linalg")]
+                IA::CosineSimilarity => A::CosineSimilarity,
             })
         },
         IF::BinaryExpr(f) => {
@@ -362,6 +370,10 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::Slice => B::Slice,
                 IB::Head => B::Head,
                 IB::Tail => B::Tail,
+                #[cfg(feature = "crypto")]
+                IB::Encrypt { key } => B::Encrypt { key },
+                #[cfg(feature = "crypto")]
+                IB::Decrypt { key } => B::Decrypt { key },
             })
         },
         #[cfg(feature = "dtype-categorical")]
@@ -369,6 +381,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
             use {CategoricalFunction as C, IRCategoricalFunction as IC};
             F::Categorical(match f {
                 IC::GetCategories => C::GetCategories,
+                IC::ExtendCategories(v) => C::ExtendCategories(v),
+                IC::CastWithAddedCategories(v) => C::CastWithAddedCategories(v),
                 #[cfg(feature = "strings")]
                 IC::LenBytes => C::LenBytes,
                 #[cfg(feature = "strings")]
@@ -389,6 +403,29 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IE::Storage => E::Storage,
             })
         },
+        #[cfg(feature = "geo")]
+        IF::Geo(f) => {
+            use {GeoFunction as G, IRGeoFunction as IG};
+            F::Geo(match f {
+                IG::Point => G::Point,
+                IG::X => G::X,
+                IG::Y => G::Y,
+                IG::Distance => G::Distance,
+                IG::Contains => G::Contains,
+                IG::Within => G::Within,
+                IG::IntersectsBbox => G::IntersectsBbox,
+            })
+        },
+        #[cfg(feature = "quant")]
+        IF::Quant(f) => {
+            use {IRQuantFunction as IQ, QuantFunction as Q};
+            F::Quant(match f {
+                IQ::Drawdown => Q::Drawdown,
+                IQ::MaxDrawdown => Q::MaxDrawdown,
+                IQ::HitRatio => Q::HitRatio,
+                IQ::Sharpe { annualization } => Q::Sharpe { annualization },
+            })
+        },
         IF::ListExpr(f) => {
             use {IRListFunction as IL, ListFunction as L};
             F::ListExpr(match f {
@@ -430,6 +467,9 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IL::ArgMax => L::ArgMax,
                 #[cfg(feature = "diff")]
                 IL::Diff { n, null_behavior } => L::Diff { n, null_behavior },
+                IL::RollingSum(window_size) => L::RollingSum(window_size),
+                IL::RollingMean(window_size) => L::RollingMean(window_size),
+                IL::ZipWith(op) => L::ZipWith(op),
                 IL::Sort(sort_options) => L::Sort(sort_options),
                 IL::Reverse => L::Reverse,
                 IL::Unique(v) => L::Unique(v),
@@ -566,6 +606,32 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 },
                 #[cfg(feature = "regex")]
                 IB::EscapeRegex => B::EscapeRegex,
+                #[cfg(feature = "string_mask")]
+                IB::MaskPattern {
+                    pat,
+                    replacement,
+                    keep_last_n,
+                } => B::MaskPattern {
+                    pat,
+                    replacement,
+                    keep_last_n,
+                },
+                #[cfg(feature = "string_mask")]
+                IB::MaskPii {
+                    pattern,
+                    replacement,
+                    keep_last_n,
+                } => B::MaskPii {
+                    pattern,
+                    replacement,
+                    keep_last_n,
+                },
+                #[cfg(feature = "crypto")]
+                IB::Tokenize { key } => B::Tokenize { key },
+                #[cfg(feature = "crypto")]
+                IB::Encrypt { key } => B::Encrypt { key },
+                #[cfg(feature = "crypto")]
+                IB::MaskFormatPreserving { key } => B::MaskFormatPreserving { key },
             })
         },
         #[cfg(feature = "dtype-struct")]
@@ -885,6 +951,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                     IR::Skew => R::Skew,
                     #[cfg(feature = "moment")]
                     IR::Kurtosis => R::Kurtosis,
+                    #[cfg(feature = "outliers")]
+                    IR::Mad => R::Mad,
                     #[cfg(feature = "cov")]
                     IR::CorrCov {
                         corr_cov_options,
@@ -988,6 +1056,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::Diff(nb) => F::Diff(nb),
         #[cfg(feature = "pct_change")]
         IF::PctChange => F::PctChange,
+        #[cfg(feature = "pct_change_by")]
+        IF::PctChangeBy { offset } => F::PctChangeBy { offset },
         #[cfg(feature = "interpolate")]
         IF::Interpolate(m) => F::Interpolate(m),
         #[cfg(feature = "interpolate_by")]
@@ -1011,6 +1081,10 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::Ceil => F::Ceil,
         #[cfg(feature = "fused")]
         IF::Fused(f) => {
+            if let FusedOperator::Elementwise(expr) = &f {
+                assert_eq!(input.len(), 1);
+                return fused_elementwise_to_expr(expr, &input[0]);
+            }
             assert_eq!(input.len(), 3);
             let mut input = input.into_iter();
             let fst = input.next().unwrap();
@@ -1020,6 +1094,7 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 FusedOperator::MultiplyAdd => (fst * snd) + trd,
                 FusedOperator::SubMultiply => fst - (snd * trd),
                 FusedOperator::MultiplySub => (fst * snd) - trd,
+                FusedOperator::Elementwise(_) => unreachable!(),
             };
         },
         IF::ConcatExpr(v) => F::ConcatExpr(v),
@@ -1069,6 +1144,16 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::RLE => F::RLE,
         #[cfg(feature = "rle")]
         IF::RLEID => F::RLEID,
+        #[cfg(feature = "to_dummies")]
+        IF::ToDummies {
+            categories,
+            separator,
+            drop_first,
+        } => F::ToDummies {
+            categories,
+            separator,
+            drop_first,
+        },
         IF::ToPhysical => F::ToPhysical,
         #[cfg(feature = "random")]
         IF::Random { method, seed } => {
@@ -1148,6 +1233,10 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::MinHorizontal => F::MinHorizontal,
         IF::SumHorizontal { ignore_nulls } => F::SumHorizontal { ignore_nulls },
         IF::MeanHorizontal { ignore_nulls } => F::MeanHorizontal { ignore_nulls },
+        #[cfg(feature = "rule_failures")]
+        IF::RuleFailures { output } => F::RuleFailures { output },
+        #[cfg(feature = "weighted")]
+        IF::Weighted(agg) => F::Weighted(agg),
         #[cfg(feature = "ewma")]
         IF::EwmMean { options } => F::EwmMean { options },
         #[cfg(feature = "ewma_by")]
@@ -1177,3 +1266,53 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
 
     Expr::Function { input, function }
 }
+
+#[cfg(feature = "fused")]
+fn fused_elementwise_to_expr(expr: &polars_ops::series::FusedElementwise, input: &Expr) -> Expr {
+    use polars_ops::series::{FusedArithmeticOp as A, FusedComparisonOp as C, FusedElementwise as E};
+
+    let rec = |e: &E| Arc::new(fused_elementwise_to_expr(e, input));
+    match expr {
+        E::Column => input.clone(),
+        E::Literal(Some(v)) => Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Float(*v))),
+        E::Literal(None) => Expr::Literal(LiteralValue::untyped_null()),
+        E::Arithmetic { left, op, right } => {
+            let op = match op {
+                A::Add => Operator::Plus,
+                A::Sub => Operator::Minus,
+                A::Mul => Operator::Multiply,
+                A::Div => Operator::TrueDivide,
+            };
+            Expr::BinaryExpr {
+                left: rec(left),
+                op,
+                right: rec(right),
+            }
+        },
+        E::Ternary {
+            left,
+            op,
+            right,
+            truthy,
+            falsy,
+        } => {
+            let op = match op {
+                C::Eq => Operator::Eq,
+                C::NotEq => Operator::NotEq,
+                C::Lt => Operator::Lt,
+                C::LtEq => Operator::LtEq,
+                C::Gt => Operator::Gt,
+                C::GtEq => Operator::GtEq,
+            };
+            Expr::Ternary {
+                predicate: Arc::new(Expr::BinaryExpr {
+                    left: rec(left),
+                    op,
+                    right: rec(right),
+                }),
+                truthy: rec(truthy),
+                falsy: rec(falsy),
+            }
+        },
+    }
+}
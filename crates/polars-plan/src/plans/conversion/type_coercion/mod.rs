@@ -145,6 +145,8 @@ impl OptimizationRule for TypeCoercionRule {
                             categorical_to_string: true,
                             missing_struct_fields: MissingColumnsPolicy::Insert,
                             extra_struct_fields: ExtraColumnsPolicy::Ignore,
+                            pruned_struct_columns: None,
+                            overridden_columns: None,
                         }
                         .should_cast_column("", cast_to, &cast_from);
 
@@ -34,6 +34,7 @@ pub enum DslFunction {
     Explode {
         columns: Selector,
         options: ExplodeOptions,
+        mismatch: ExplodeLengthMismatch,
         allow_empty: bool,
     },
     #[cfg(feature = "pivot")]
@@ -45,9 +46,23 @@ pub enum DslFunction {
         new: Arc<[PlSmallStr]>,
         strict: bool,
     },
+    /// Rename the selected columns with a function of their name, resolved against the schema.
+    RenameWith {
+        columns: Selector,
+        function: RenameAliasFn,
+    },
+    /// Move the selected columns to a position, without evaluating any expression.
+    MoveColumns {
+        columns: Selector,
+        position: ColumnPosition,
+    },
     Unnest {
         columns: Selector,
-        separator: Option<PlSmallStr>,
+        naming: UnnestNaming,
+    },
+    UnnestAll {
+        separator: PlSmallStr,
+        max_depth: Option<usize>,
     },
     Stats(StatsFunction),
     /// FillValue
@@ -79,6 +94,20 @@ pub enum StatsFunction {
     Max,
 }
 
+/// The position that [`DslFunction::MoveColumns`] moves columns to, relative to the columns
+/// that are not moved.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ColumnPosition {
+    First,
+    Last,
+    /// Index among the columns that are not moved, negative indices count from the end.
+    Index(i64),
+    Before(PlSmallStr),
+    After(PlSmallStr),
+}
+
 pub(crate) fn validate_columns_in_input<S: AsRef<str>, I: IntoIterator<Item = S>>(
     columns: I,
     input_schema: &Schema,
@@ -129,7 +158,7 @@ impl DslFunction {
                 offset,
                 schema: Default::default(),
             },
-            DslFunction::Unnest { columns, separator } => {
+            DslFunction::Unnest { columns, naming } => {
                 let columns = columns.into_columns(input_schema, &Default::default())?;
                 let columns: Arc<[PlSmallStr]> = columns.into_iter().collect();
                 for col in columns.iter() {
@@ -139,7 +168,22 @@ impl DslFunction {
                         InvalidOperation: "invalid dtype: expected 'Struct', got '{:?}' for '{}'", dtype, col
                     );
                 }
-                FunctionIR::Unnest { columns, separator }
+                FunctionIR::Unnest { columns, naming }
+            },
+            DslFunction::UnnestAll {
+                separator,
+                max_depth,
+            } => {
+                let columns = input_schema
+                    .iter()
+                    .filter(|(_, dtype)| dtype.is_struct())
+                    .map(|(name, _)| name.clone())
+                    .collect();
+                FunctionIR::UnnestAll {
+                    columns,
+                    separator,
+                    max_depth,
+                }
             },
             DslFunction::Hint(h) => FunctionIR::Hint(h),
             #[cfg(feature = "python")]
@@ -147,6 +191,8 @@ impl DslFunction {
             DslFunction::Stats(_)
             | DslFunction::FillNan(_)
             | DslFunction::Rename { .. }
+            | DslFunction::RenameWith { .. }
+            | DslFunction::MoveColumns { .. }
             | DslFunction::Explode { .. } => {
                 // We should not reach this.
                 panic!("impl error")
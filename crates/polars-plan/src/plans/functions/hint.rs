@@ -1,7 +1,10 @@
 use std::fmt;
 use std::sync::Arc;
 
-use polars_core::prelude::PlHashSet;
+use polars_core::chunked_array::ops::row_encode::_get_rows_encoded_ca;
+use polars_core::prelude::*;
+use polars_ops::series::SeriesMethods;
+use polars_utils::format_list_truncated;
 use polars_utils::pl_str::PlSmallStr;
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -24,36 +27,97 @@ pub struct Sorted {
 #[derive(Clone, Hash, strum_macros::IntoStaticStr)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 pub enum HintIR {
-    Sorted(Arc<[Sorted]>),
+    Sorted {
+        sorted: Arc<[Sorted]>,
+        /// Check that the data is sorted when executing instead of trusting the hint. Only the
+        /// columns that remain after projection pushdown are checked.
+        verify: bool,
+    },
+    /// The `keys` together uniquely identify every row.
+    Unique {
+        keys: Arc<[PlSmallStr]>,
+        /// Check that the keys are unique when executing instead of trusting the hint.
+        verify: bool,
+    },
 }
 
 impl HintIR {
     pub fn project(&self, projected_names: &PlHashSet<PlSmallStr>) -> Option<HintIR> {
         match self {
-            Self::Sorted(s) => {
+            Self::Sorted { sorted: s, verify } => {
+                // The sortedness of a later column only holds within the groups of the earlier
+                // columns, so we can only keep the leading projected columns.
                 let num_matches = s
                     .iter()
-                    .filter(|i| projected_names.contains(&i.column))
+                    .take_while(|i| projected_names.contains(&i.column))
                     .count();
 
                 if num_matches == s.len() {
-                    return Some(Self::Sorted(s.clone()));
+                    Some(self.clone())
                 } else if num_matches == 0 {
-                    return None;
+                    None
+                } else {
+                    Some(Self::Sorted {
+                        sorted: s[..num_matches].into(),
+                        verify: *verify,
+                    })
                 }
-
-                let mut sorted = Vec::with_capacity(num_matches);
-                sorted.extend(
-                    s.iter()
-                        .filter(|i| projected_names.contains(&i.column))
-                        .cloned(),
-                );
-                Some(Self::Sorted(sorted.into()))
             },
+            // A subset of a unique key is not necessarily unique.
+            Self::Unique { keys, .. } => keys
+                .iter()
+                .all(|k| projected_names.contains(k))
+                .then(|| self.clone()),
         }
     }
 }
 
+/// Check that `df` is sorted by the `sorted` columns together. An unknown order is checked as
+/// ascending with the nulls first.
+pub(super) fn verify_sorted(df: &DataFrame, sorted: &[Sorted]) -> PolarsResult<()> {
+    let columns = sorted
+        .iter()
+        .map(|s| df.column(&s.column).cloned())
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let descending = sorted
+        .iter()
+        .map(|s| s.descending.unwrap_or(false))
+        .collect::<Vec<_>>();
+    let nulls_last = sorted
+        .iter()
+        .map(|s| s.nulls_last.unwrap_or(false))
+        .collect::<Vec<_>>();
+
+    let is_sorted = if let [column] = columns.as_slice() {
+        column.as_materialized_series().is_sorted(SortOptions {
+            descending: descending[0],
+            nulls_last: nulls_last[0],
+            ..Default::default()
+        })?
+    } else {
+        _get_rows_encoded_ca(PlSmallStr::EMPTY, &columns, &descending, &nulls_last)?
+            .into_series()
+            .is_sorted(SortOptions::default())?
+    };
+    polars_ensure!(
+        is_sorted,
+        ComputeError: "data is not sorted by {} as declared with `set_sorted`",
+        format_list_truncated!(sorted.iter().map(|s| &s.column), 4, '"')
+    );
+    Ok(())
+}
+
+/// Check that the `keys` of `df` are unique.
+pub(super) fn verify_unique(df: &DataFrame, keys: &[PlSmallStr]) -> PolarsResult<()> {
+    let num_groups = df.group_by(keys.iter())?.get_groups().len();
+    polars_ensure!(
+        num_groups == df.height(),
+        ComputeError: "key {} is not unique as declared with `set_unique_key`",
+        format_list_truncated!(keys.iter(), 4, '"')
+    );
+    Ok(())
+}
+
 impl fmt::Display for Sorted {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let descending = match self.descending {
@@ -84,7 +148,7 @@ impl fmt::Debug for HintIR {
 impl fmt::Display for HintIR {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            HintIR::Sorted(s) => {
+            HintIR::Sorted { sorted: s, verify } => {
                 write!(f, "sorted(")?;
                 if let Some(fst) = s.first() {
                     fst.fmt(f)?;
@@ -93,6 +157,22 @@ impl fmt::Display for HintIR {
                         si.fmt(f)?;
                     }
                 }
+                if *verify {
+                    write!(f, ", verify")?;
+                }
+                write!(f, ")")
+            },
+            HintIR::Unique { keys, verify } => {
+                write!(f, "unique(")?;
+                for (i, key) in keys.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "'{key}'")?;
+                }
+                if *verify {
+                    write!(f, ", verify")?;
+                }
                 write!(f, ")")
             },
         }
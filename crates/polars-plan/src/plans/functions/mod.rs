@@ -49,12 +49,20 @@ pub enum FunctionIR {
 
     Unnest {
         columns: Arc<[PlSmallStr]>,
-        separator: Option<PlSmallStr>,
+        naming: UnnestNaming,
+    },
+    /// Recursively unnest all struct columns.
+    UnnestAll {
+        /// The struct columns of the input, only used by the optimizers.
+        columns: Arc<[PlSmallStr]>,
+        separator: PlSmallStr,
+        max_depth: Option<usize>,
     },
     Rechunk,
     Explode {
         columns: Arc<[PlSmallStr]>,
         options: ExplodeOptions,
+        mismatch: ExplodeLengthMismatch,
         #[cfg_attr(feature = "ir_serde", serde(skip))]
         schema: CachedSchema,
     },
@@ -98,14 +106,16 @@ impl PartialEq for FunctionIR {
                 Explode {
                     columns: l,
                     options: l_options,
+                    mismatch: l_mismatch,
                     ..
                 },
                 Explode {
                     columns: r,
                     options: r_options,
+                    mismatch: r_mismatch,
                     ..
                 },
-            ) => l == r && l_options == r_options,
+            ) => l == r && l_options == r_options && l_mismatch == r_mismatch,
             #[cfg(feature = "pivot")]
             (Unpivot { args: l, .. }, Unpivot { args: r, .. }) => l == r,
             (RowIndex { name: l, .. }, RowIndex { name: r, .. }) => l == r,
@@ -132,18 +142,29 @@ impl Hash for FunctionIR {
                 cloud_options.hash(state);
                 alias.hash(state);
             },
-            FunctionIR::Unnest { columns, separator } => {
+            FunctionIR::Unnest { columns, naming } => {
+                columns.hash(state);
+                naming.hash(state);
+            },
+            FunctionIR::UnnestAll {
+                columns,
+                separator,
+                max_depth,
+            } => {
                 columns.hash(state);
                 separator.hash(state);
+                max_depth.hash(state);
             },
             FunctionIR::Rechunk => {},
             FunctionIR::Explode {
                 columns,
                 options,
+                mismatch,
                 schema: _,
             } => {
                 columns.hash(state);
                 options.hash(state);
+                mismatch.hash(state);
             },
             #[cfg(feature = "pivot")]
             FunctionIR::Unpivot { args, schema: _ } => args.hash(state),
@@ -166,14 +187,15 @@ impl FunctionIR {
         use FunctionIR::*;
         match self {
             Rechunk => false,
-            FastCount { .. } | Unnest { .. } | Explode { .. } => true,
+            FastCount { .. } | Unnest { .. } | UnnestAll { .. } | Explode { .. } => true,
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
             Opaque { streamable, .. } => *streamable,
             #[cfg(feature = "python")]
             OpaquePython(OpaquePythonUdf { streamable, .. }) => *streamable,
             RowIndex { .. } => false,
-            Hint(_) => true,
+            // Verifying needs to see all the data at once.
+            Hint(HintIR::Sorted { verify, .. } | HintIR::Unique { verify, .. }) => !*verify,
         }
     }
 
@@ -196,7 +218,7 @@ impl FunctionIR {
             OpaquePython(OpaquePythonUdf { predicate_pd, .. }) => *predicate_pd,
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
-            Rechunk | Unnest { .. } | Explode { .. } | Hint(_) => true,
+            Rechunk | Unnest { .. } | UnnestAll { .. } | Explode { .. } | Hint(_) => true,
             RowIndex { .. } | FastCount { .. } => false,
         }
     }
@@ -207,7 +229,12 @@ impl FunctionIR {
             Opaque { projection_pd, .. } => *projection_pd,
             #[cfg(feature = "python")]
             OpaquePython(OpaquePythonUdf { projection_pd, .. }) => *projection_pd,
-            Rechunk | FastCount { .. } | Unnest { .. } | Explode { .. } | Hint(_) => true,
+            Rechunk
+            | FastCount { .. }
+            | Unnest { .. }
+            | UnnestAll { .. }
+            | Explode { .. }
+            | Hint(_) => true,
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
             RowIndex { .. } => true,
@@ -217,7 +244,7 @@ impl FunctionIR {
     pub(crate) fn additional_projection_pd_columns(&self) -> Cow<'_, [PlSmallStr]> {
         use FunctionIR::*;
         match self {
-            Unnest { columns, .. } => Cow::Borrowed(columns.as_ref()),
+            Unnest { columns, .. } | UnnestAll { columns, .. } => Cow::Borrowed(columns.as_ref()),
             Explode { columns, .. } => Cow::Borrowed(columns.as_ref()),
             _ => Cow::Borrowed(&[]),
         }
@@ -244,15 +271,26 @@ impl FunctionIR {
                 df.rechunk_mut_par();
                 Ok(df)
             },
-            Unnest { columns, separator } => {
-                feature_gated!(
-                    "dtype-struct",
-                    df.unnest(columns.iter().cloned(), separator.as_deref())
-                )
+            Unnest { columns, naming } => {
+                feature_gated!("dtype-struct", {
+                    df.unnest_with_names(columns.iter().cloned(), |column, field| {
+                        naming.column_name(column, field)
+                    })
+                })
+            },
+            UnnestAll {
+                separator,
+                max_depth,
+                ..
+            } => {
+                feature_gated!("dtype-struct", df.unnest_all(separator, *max_depth))
             },
             Explode {
-                columns, options, ..
-            } => df.explode(columns.iter().cloned(), *options),
+                columns,
+                options,
+                mismatch,
+                ..
+            } => df.explode_with_mismatch(columns.iter().cloned(), *options, *mismatch),
             #[cfg(feature = "pivot")]
             Unpivot { args, .. } => {
                 use polars_ops::unpivot::UnpivotDF;
@@ -260,11 +298,19 @@ impl FunctionIR {
                 df.unpivot2(args)
             },
             RowIndex { name, offset, .. } => df.with_row_index(name.clone(), *offset),
+            Hint(HintIR::Unique { keys, verify }) => {
+                if *verify {
+                    hint::verify_unique(&df, keys)?;
+                }
+                Ok(df)
+            },
             Hint(hint) => {
-                #[expect(irrefutable_let_patterns)]
-                if let HintIR::Sorted(s) = &hint
-                    && let Some(s) = s.first()
+                if let HintIR::Sorted { sorted, verify } = &hint
+                    && let Some(s) = sorted.first()
                 {
+                    if *verify {
+                        hint::verify_sorted(&df, sorted)?;
+                    }
                     let idx = df.try_get_column_index(&s.column)?;
                     let col = &mut unsafe { df.columns_mut_retain_schema() }[idx];
                     if let Some(d) = s.descending {
@@ -286,7 +332,7 @@ impl FunctionIR {
         match self {
             FunctionIR::RowIndex { .. } => true,
             FunctionIR::FastCount { .. } => false,
-            FunctionIR::Unnest { .. } => is_input_ordered,
+            FunctionIR::Unnest { .. } | FunctionIR::UnnestAll { .. } => is_input_ordered,
             FunctionIR::Rechunk => is_input_ordered,
             #[cfg(feature = "python")]
             FunctionIR::OpaquePython(..) => true,
@@ -300,7 +346,7 @@ impl FunctionIR {
 
     pub fn is_elementwise(&self) -> bool {
         match self {
-            Self::Unnest { .. } | Self::Hint(_) => true,
+            Self::Unnest { .. } | Self::UnnestAll { .. } | Self::Hint(_) => true,
             #[cfg(feature = "python")]
             Self::OpaquePython(..) => false,
             #[cfg(feature = "pivot")]
@@ -320,7 +366,7 @@ impl FunctionIR {
     /// Is the input ordering always the same as the output ordering.
     pub fn has_equal_order(&self) -> bool {
         match self {
-            Self::Unnest { .. } | Self::Rechunk | Self::Hint(_) => true,
+            Self::Unnest { .. } | Self::UnnestAll { .. } | Self::Rechunk | Self::Hint(_) => true,
             #[cfg(feature = "python")]
             Self::OpaquePython(..) => false,
             #[cfg(feature = "pivot")]
@@ -347,12 +393,25 @@ impl Display for FunctionIR {
                 write!(f, "hint.{hint}")
             },
             Opaque { fmt_str, .. } => write!(f, "{fmt_str}"),
-            Unnest { columns, separator } => {
+            Unnest { columns, naming } => {
                 write!(f, "UNNEST by:")?;
                 let columns = columns.as_ref();
                 fmt_column_delimited(f, columns, "[", "]")?;
-                if let Some(separator) = separator {
-                    write!(f, ", separator: {separator}")?;
+                match naming {
+                    UnnestNaming::Keep => {},
+                    UnnestNaming::Prefix(separator) => write!(f, ", separator: {separator}")?,
+                    UnnestNaming::Custom(_) => write!(f, ", naming: custom")?,
+                }
+                Ok(())
+            },
+            UnnestAll {
+                columns: _,
+                separator,
+                max_depth,
+            } => {
+                write!(f, "UNNEST ALL separator: {separator}")?;
+                if let Some(max_depth) = max_depth {
+                    write!(f, ", max_depth: {max_depth}")?;
                 }
                 Ok(())
             },
@@ -387,6 +446,7 @@ impl Display for FunctionIR {
             Explode {
                 columns,
                 options,
+                mismatch,
                 schema: _,
             } => {
                 f.write_str("EXPLODE ")?;
@@ -397,6 +457,9 @@ impl Display for FunctionIR {
                 if !options.keep_nulls {
                     f.write_str(", keep_nulls: false")?;
                 }
+                if *mismatch != ExplodeLengthMismatch::Error {
+                    write!(f, ", length_mismatch: {mismatch:?}")?;
+                }
                 Ok(())
             },
             #[cfg(feature = "pivot")]
@@ -48,7 +48,7 @@ impl FunctionIR {
                 Ok(Cow::Owned(Arc::new(schema)))
             },
             Rechunk => Ok(Cow::Borrowed(input_schema)),
-            Unnest { columns, separator } => {
+            Unnest { columns, naming } => {
                 #[cfg(feature = "dtype-struct")]
                 {
                     let mut new_schema = Schema::with_capacity(input_schema.len() * 2);
@@ -57,16 +57,13 @@ impl FunctionIR {
                             match dtype {
                                 DataType::Struct(flds) => {
                                     for fld in flds {
-                                        let fld_name = match separator {
-                                            None => fld.name().clone(),
-                                            Some(sep) => {
-                                                polars_utils::format_pl_smallstr!(
-                                                    "{name}{sep}{}",
-                                                    fld.name()
-                                                )
-                                            },
-                                        };
-                                        new_schema.with_column(fld_name, fld.dtype().clone());
+                                        let fld_name = naming.column_name(name, fld.name())?;
+                                        if new_schema
+                                            .with_column(fld_name.clone(), fld.dtype().clone())
+                                            .is_some()
+                                        {
+                                            return Err(unnest_duplicate_err(&fld_name));
+                                        }
                                     }
                                 },
                                 DataType::Unknown(_) => {
@@ -78,8 +75,11 @@ impl FunctionIR {
                                     );
                                 },
                             }
-                        } else {
-                            new_schema.with_column(name.clone(), dtype.clone());
+                        } else if new_schema
+                            .with_column(name.clone(), dtype.clone())
+                            .is_some()
+                        {
+                            return Err(unnest_duplicate_err(name));
                         }
                     }
 
@@ -90,6 +90,31 @@ impl FunctionIR {
                     panic!("activate feature 'dtype-struct'")
                 }
             },
+            UnnestAll {
+                columns: _,
+                separator,
+                max_depth,
+            } => {
+                #[cfg(feature = "dtype-struct")]
+                {
+                    let mut new_schema = Schema::with_capacity(input_schema.len() * 2);
+                    for (name, dtype) in input_schema.iter() {
+                        unnest_all_schema(
+                            name.clone(),
+                            dtype,
+                            separator,
+                            0,
+                            *max_depth,
+                            &mut new_schema,
+                        );
+                    }
+                    Ok(Cow::Owned(Arc::new(new_schema)))
+                }
+                #[cfg(not(feature = "dtype-struct"))]
+                {
+                    panic!("activate feature 'dtype-struct'")
+                }
+            },
             RowIndex { schema, name, .. } => Ok(Cow::Owned(row_index_schema(
                 schema,
                 input_schema,
@@ -98,6 +123,7 @@ impl FunctionIR {
             Explode {
                 schema,
                 options: _,
+                mismatch: _,
                 columns,
             } => explode_schema(schema, input_schema, columns),
             #[cfg(feature = "pivot")]
@@ -107,6 +133,43 @@ impl FunctionIR {
     }
 }
 
+#[cfg(feature = "dtype-struct")]
+fn unnest_duplicate_err(name: &str) -> PolarsError {
+    polars_err!(
+        Duplicate: "unnest produces duplicate column name '{name}'\n\nHint: use a naming strategy that prefixes the field names with the struct column name."
+    )
+}
+
+#[cfg(feature = "dtype-struct")]
+fn unnest_all_schema(
+    name: PlSmallStr,
+    dtype: &DataType,
+    separator: &str,
+    depth: usize,
+    max_depth: Option<usize>,
+    schema: &mut Schema,
+) {
+    match dtype {
+        DataType::Struct(fields) if max_depth.is_none_or(|max| depth < max) => {
+            for fld in fields {
+                let fld_name =
+                    polars_utils::format_pl_smallstr!("{name}{separator}{}", fld.name());
+                unnest_all_schema(
+                    fld_name,
+                    fld.dtype(),
+                    separator,
+                    depth + 1,
+                    max_depth,
+                    schema,
+                );
+            }
+        },
+        _ => {
+            schema.with_column(name, dtype.clone());
+        },
+    }
+}
+
 fn row_index_schema(
     cached_schema: &CachedSchema,
     input_schema: &SchemaRef,
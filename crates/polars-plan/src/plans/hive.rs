@@ -1,5 +1,6 @@
 use std::path::{Component, Path};
 
+use polars_core::frame::group_by::GroupsIndicator;
 use polars_core::prelude::*;
 use polars_io::prelude::schema_inference::{finish_infer_field_schema, infer_field_schema};
 use polars_utils::plpath::PlPath;
@@ -32,6 +33,29 @@ impl HivePartitionsDf {
     pub fn schema(&self) -> &SchemaRef {
         self.0.schema()
     }
+
+    /// Group the sources by their values of the partition `columns`.
+    ///
+    /// Returns the distinct values in order of first occurrence, and the indices of the sources
+    /// of every group.
+    pub fn group_sources_by(
+        &self,
+        columns: Vec<PlSmallStr>,
+    ) -> PolarsResult<(DataFrame, Vec<Vec<usize>>)> {
+        let gb = self.df().group_by_stable(columns)?;
+        let groups = gb
+            .get_groups()
+            .iter()
+            .map(|g| match g {
+                GroupsIndicator::Idx((_, idx)) => idx.iter().map(|i| *i as usize).collect(),
+                GroupsIndicator::Slice([first, len]) => {
+                    (first as usize..(first + len) as usize).collect::<Vec<_>>()
+                },
+            })
+            .collect::<Vec<_>>();
+        let keys = DataFrame::new(groups.len(), gb.keys())?;
+        Ok((keys, groups))
+    }
 }
 
 impl From<DataFrame> for HivePartitionsDf {
@@ -0,0 +1,217 @@
+use polars_core::prelude::*;
+use polars_utils::slice_enum::Slice;
+use recursive::recursive;
+
+use crate::prelude::*;
+
+/// Size estimates of a query plan, derived from metadata only.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PlanEstimate {
+    /// Estimated number of output rows, `None` if unknown.
+    ///
+    /// Filters, aggregations and inner joins are assumed to keep all their input rows, so this
+    /// is an upper bound for plans containing them.
+    pub rows: Option<usize>,
+    /// Number of bytes read by all scans in the plan, `None` if unknown for any of them.
+    pub bytes_to_scan: Option<u64>,
+    /// Estimates of every scan in the plan.
+    pub sources: Vec<SourceEstimate>,
+}
+
+/// Size estimates of a single scan in a query plan.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceEstimate {
+    /// Identifier of the scanned sources, e.g. the path of the first file.
+    pub name: PlSmallStr,
+    pub n_files: usize,
+    /// Estimated number of rows read from the files, `None` if unknown.
+    pub rows: Option<usize>,
+    /// Number of bytes read from the files, `None` if unknown.
+    ///
+    /// This is the total file size, which is scaled down by the size of the projected columns
+    /// for parquet files if their metadata is available.
+    pub bytes_to_scan: Option<u64>,
+}
+
+/// Estimate the size of the plan at `root` without executing it.
+pub fn estimate_plan(root: Node, lp_arena: &Arena<IR>) -> PlanEstimate {
+    let mut visited = PlHashSet::new();
+    let mut sources = Vec::new();
+    let rows = estimate_rows(root, lp_arena, &mut visited, &mut sources);
+
+    let bytes_to_scan = sources
+        .iter()
+        .map(|s| s.bytes_to_scan)
+        .sum::<Option<u64>>();
+
+    PlanEstimate {
+        rows,
+        bytes_to_scan,
+        sources,
+    }
+}
+
+#[recursive]
+fn estimate_rows(
+    node: Node,
+    lp_arena: &Arena<IR>,
+    visited: &mut PlHashSet<Node>,
+    sources: &mut Vec<SourceEstimate>,
+) -> Option<usize> {
+    let mut rec = |node| estimate_rows(node, lp_arena, visited, sources);
+
+    match lp_arena.get(node) {
+        #[cfg(feature = "python")]
+        IR::PythonScan { .. } => None,
+        IR::Scan {
+            sources: scan_sources,
+            file_info,
+            output_schema,
+            scan_type,
+            unified_scan_args,
+            ..
+        } => {
+            let rows = (file_info.row_estimation.1 != usize::MAX)
+                .then_some(file_info.row_estimation.1);
+            let rows = match &unified_scan_args.pre_slice {
+                Some(slice) => slice_rows(slice.clone(), rows),
+                None => rows,
+            };
+
+            // Scans can be shared by multiple cache nodes, only count them once.
+            if visited.insert(node) {
+                let bytes_to_scan = scan_sources
+                    .iter()
+                    .map(|source| source.byte_size())
+                    .sum::<Option<u64>>()
+                    .map(|bytes| {
+                        let fraction = projected_fraction(scan_type, output_schema.as_deref());
+                        (bytes as f64 * fraction.unwrap_or(1.0)) as u64
+                    });
+
+                sources.push(SourceEstimate {
+                    name: scan_sources.id(),
+                    n_files: scan_sources.len(),
+                    rows,
+                    bytes_to_scan,
+                });
+            }
+
+            rows
+        },
+        IR::DataFrameScan { df, .. } => Some(df.height()),
+        IR::Slice { input, offset, len } => {
+            slice_rows(Slice::from((*offset, *len as usize)), rec(*input))
+        },
+        IR::Sort {
+            input,
+            slice: Some(slice),
+            ..
+        } => slice_rows(Slice::from(*slice), rec(*input)),
+        IR::Filter { input, .. }
+        | IR::SimpleProjection { input, .. }
+        | IR::Select { input, .. }
+        | IR::Sort { input, .. }
+        | IR::Cache { input, .. }
+        | IR::GroupBy { input, .. }
+        | IR::HStack { input, .. }
+        | IR::Distinct { input, .. }
+        | IR::Sink { input, .. } => rec(*input),
+        IR::ExtContext {
+            input, contexts, ..
+        } => {
+            for context in contexts {
+                rec(*context);
+            }
+            rec(*input)
+        },
+        IR::MapFunction { input, function } => {
+            let rows = rec(*input);
+            match function {
+                FunctionIR::FastCount { .. } => Some(1),
+                FunctionIR::Explode { .. } => None,
+                #[cfg(feature = "pivot")]
+                FunctionIR::Unpivot { .. } => None,
+                FunctionIR::Opaque { .. } => None,
+                #[cfg(feature = "python")]
+                FunctionIR::OpaquePython(_) => None,
+                _ => rows,
+            }
+        },
+        IR::Join {
+            input_left,
+            input_right,
+            options,
+            ..
+        } => {
+            let left = rec(*input_left);
+            let right = rec(*input_right);
+            match options.args.how {
+                JoinType::Cross => left?.checked_mul(right?),
+                JoinType::Full => left?.checked_add(right?),
+                JoinType::Right => right,
+                _ => left,
+            }
+        },
+        IR::Union { inputs, options } => {
+            // Visit all inputs to collect their scans, even if the rows of one are unknown.
+            let rows = inputs.iter().map(|input| rec(*input)).collect::<Vec<_>>();
+            let rows = rows.into_iter().sum::<Option<usize>>();
+            match options.slice {
+                Some(slice) => slice_rows(Slice::from(slice), rows),
+                None => rows,
+            }
+        },
+        IR::HConcat { inputs, .. } | IR::SinkMultiple { inputs } => {
+            let rows = inputs.iter().map(|input| rec(*input)).collect::<Vec<_>>();
+            rows.into_iter().collect::<Option<Vec<_>>>()?.into_iter().max()
+        },
+        #[cfg(feature = "merge_sorted")]
+        IR::MergeSorted {
+            input_left,
+            input_right,
+            ..
+        } => {
+            let left = rec(*input_left);
+            let right = rec(*input_right);
+            left?.checked_add(right?)
+        },
+        IR::Invalid => unreachable!(),
+    }
+}
+
+fn slice_rows(slice: Slice, input_rows: Option<usize>) -> Option<usize> {
+    match input_rows {
+        Some(n_rows) => Some(slice.restrict_to_bounds(n_rows).len()),
+        // The slice length is an upper bound, unless the slice is open-ended.
+        None => (slice.len() < IdxSize::MAX as usize).then_some(slice.len()),
+    }
+}
+
+/// Fraction of the file bytes that are occupied by the projected columns.
+#[allow(unused_variables)]
+fn projected_fraction(scan_type: &FileScanIR, projection: Option<&Schema>) -> Option<f64> {
+    match scan_type {
+        #[cfg(feature = "parquet")]
+        FileScanIR::Parquet {
+            metadata: Some(metadata),
+            ..
+        } => {
+            let projection = projection?;
+            let mut total = 0;
+            let mut projected = 0;
+
+            for rg in &metadata.row_groups {
+                total += rg.compressed_size();
+                for name in projection.iter_names() {
+                    if let Some(columns) = rg.columns_under_root_iter(name) {
+                        projected += columns.map(|c| c.compressed_size() as usize).sum::<usize>();
+                    }
+                }
+            }
+
+            (total > 0).then(|| projected as f64 / total as f64)
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,241 @@
+use polars_core::prelude::*;
+use recursive::recursive;
+
+use crate::prelude::*;
+
+/// A column of a source of a query plan.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SourceColumn {
+    /// Identifier of the scanned sources, e.g. the path of the first file. `None` for in-memory
+    /// and Python sources.
+    pub source: Option<PlSmallStr>,
+    pub column: PlSmallStr,
+}
+
+/// The source columns every output column of a plan is derived from, in order of the output
+/// schema.
+pub type ColumnLineage = PlIndexMap<PlSmallStr, PlIndexSet<SourceColumn>>;
+
+/// Compute the source columns that every output column of the plan at `root` is derived from.
+///
+/// Only the columns an output column is computed from are part of its lineage, the columns
+/// that filter, sort or join the rows are not. Columns created from literals or row indices have
+/// no source columns, and the outputs of opaque functions are derived from all their inputs.
+pub fn column_lineage(
+    root: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> ColumnLineage {
+    let mut cache = PlHashMap::new();
+    lineage(root, lp_arena, expr_arena, &mut cache)
+}
+
+/// The union of the lineage of the columns the expression `e` reads from `input`.
+fn expr_lineage(
+    e: &ExprIR,
+    input: &ColumnLineage,
+    expr_arena: &Arena<AExpr>,
+) -> PlIndexSet<SourceColumn> {
+    let mut sources = PlIndexSet::new();
+    for name in aexpr_to_leaf_names_iter(e.node(), expr_arena) {
+        if let Some(columns) = input.get(name) {
+            sources.extend(columns.iter().cloned());
+        }
+    }
+    sources
+}
+
+/// The lineage of the columns of `schema`, the union of the columns with the same name of all
+/// `inputs`.
+fn union_by_name<'a>(
+    schema: &Schema,
+    inputs: impl IntoIterator<Item = &'a ColumnLineage>,
+) -> ColumnLineage {
+    let mut out: ColumnLineage = schema
+        .iter_names()
+        .map(|name| (name.clone(), PlIndexSet::new()))
+        .collect();
+    for input in inputs {
+        for (name, columns) in input {
+            if let Some(sources) = out.get_mut(name) {
+                sources.extend(columns.iter().cloned());
+            }
+        }
+    }
+    out
+}
+
+#[recursive]
+fn lineage(
+    node: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    cache: &mut PlHashMap<Node, ColumnLineage>,
+) -> ColumnLineage {
+    if let Some(lineage) = cache.get(&node) {
+        return lineage.clone();
+    }
+    let mut rec = |node| lineage(node, lp_arena, expr_arena, cache);
+    let ir = lp_arena.get(node);
+    let schema = ir.schema(lp_arena).into_owned();
+
+    let out = match ir {
+        IR::Scan {
+            sources,
+            unified_scan_args,
+            ..
+        } => {
+            let source = Some(sources.id());
+            let row_index = unified_scan_args.row_index.as_ref().map(|ri| &ri.name);
+            schema
+                .iter_names()
+                .map(|name| {
+                    let mut columns = PlIndexSet::new();
+                    if row_index != Some(name) {
+                        columns.insert(SourceColumn {
+                            source: source.clone(),
+                            column: name.clone(),
+                        });
+                    }
+                    (name.clone(), columns)
+                })
+                .collect()
+        },
+        #[cfg(feature = "python")]
+        IR::PythonScan { .. } => source_columns(&schema),
+        IR::DataFrameScan { .. } => source_columns(&schema),
+        IR::Filter { input, .. }
+        | IR::Slice { input, .. }
+        | IR::Sort { input, .. }
+        | IR::Cache { input, .. }
+        | IR::Distinct { input, .. }
+        | IR::Sink { input, .. } => rec(*input),
+        IR::SimpleProjection { input, columns } => {
+            let input = rec(*input);
+            union_by_name(columns, [&input])
+        },
+        IR::Select { input, expr, .. } => {
+            let input = rec(*input);
+            expr.iter()
+                .map(|e| (e.output_name().clone(), expr_lineage(e, &input, expr_arena)))
+                .collect()
+        },
+        IR::HStack { input, exprs, .. } => {
+            let mut out = rec(*input);
+            for e in exprs {
+                let sources = expr_lineage(e, &out, expr_arena);
+                out.insert(e.output_name().clone(), sources);
+            }
+            out
+        },
+        IR::GroupBy {
+            input, keys, aggs, ..
+        } => {
+            let input = rec(*input);
+            let mut out = union_by_name(&schema, [&input]);
+            for e in keys.iter().chain(aggs) {
+                out.insert(e.output_name().clone(), expr_lineage(e, &input, expr_arena));
+            }
+            out
+        },
+        IR::Join {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            options,
+            ..
+        } => {
+            let left = rec(*input_left);
+            let right = rec(*input_right);
+            let suffix = options.args.suffix();
+            let coalesce = options.args.should_coalesce();
+            schema
+                .iter_names()
+                .map(|name| {
+                    let mut sources = left
+                        .get(name)
+                        .or_else(|| right.get(name))
+                        .or_else(|| {
+                            // Columns of both sides with the same name get a suffix.
+                            let name = name.strip_suffix(suffix.as_str())?;
+                            right.get(name).or_else(|| left.get(name))
+                        })
+                        .cloned()
+                        .unwrap_or_default();
+                    if coalesce {
+                        for (l, r) in left_on.iter().zip(right_on) {
+                            if l.output_name() == name || r.output_name() == name {
+                                sources.extend(expr_lineage(l, &left, expr_arena));
+                                sources.extend(expr_lineage(r, &right, expr_arena));
+                            }
+                        }
+                    }
+                    (name.clone(), sources)
+                })
+                .collect()
+        },
+        IR::MapFunction { input, function } => {
+            let input = rec(*input);
+            let derived_from = |columns: &[PlSmallStr]| {
+                let mut sources = PlIndexSet::new();
+                for name in columns {
+                    sources.extend(input.get(name).into_iter().flatten().cloned());
+                }
+                sources
+            };
+            let new_columns = match function {
+                FunctionIR::RowIndex { .. } | FunctionIR::FastCount { .. } => PlIndexSet::new(),
+                FunctionIR::Unnest { columns, .. } | FunctionIR::UnnestAll { columns, .. } => {
+                    derived_from(columns)
+                },
+                _ => input.values().flatten().cloned().collect(),
+            };
+            schema
+                .iter_names()
+                .map(|name| {
+                    let sources = input.get(name).unwrap_or(&new_columns).clone();
+                    (name.clone(), sources)
+                })
+                .collect()
+        },
+        IR::Union { inputs, .. } | IR::HConcat { inputs, .. } | IR::SinkMultiple { inputs } => {
+            let inputs = inputs.iter().map(|input| rec(*input)).collect::<Vec<_>>();
+            union_by_name(&schema, &inputs)
+        },
+        IR::ExtContext {
+            input, contexts, ..
+        } => {
+            let mut inputs = vec![rec(*input)];
+            inputs.extend(contexts.iter().map(|context| rec(*context)));
+            union_by_name(&schema, &inputs)
+        },
+        #[cfg(feature = "merge_sorted")]
+        IR::MergeSorted {
+            input_left,
+            input_right,
+            ..
+        } => {
+            let inputs = [rec(*input_left), rec(*input_right)];
+            union_by_name(&schema, &inputs)
+        },
+        IR::Invalid => unreachable!(),
+    };
+
+    cache.insert(node, out.clone());
+    out
+}
+
+/// The lineage of a source without an identifier, every column is its own source column.
+fn source_columns(schema: &Schema) -> ColumnLineage {
+    schema
+        .iter_names()
+        .map(|name| {
+            let column = SourceColumn {
+                source: None,
+                column: name.clone(),
+            };
+            (name.clone(), PlIndexSet::from_iter([column]))
+        })
+        .collect()
+}
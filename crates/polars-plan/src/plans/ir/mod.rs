@@ -1,5 +1,9 @@
 mod dot;
+mod estimate;
+mod filter_scan;
 mod format;
+mod lineage;
+mod pruning;
 pub mod inputs;
 mod schema;
 pub(crate) mod tree_format;
@@ -10,7 +14,11 @@ use std::borrow::Cow;
 use std::fmt;
 
 pub use dot::{EscapeLabel, IRDotDisplay, PathsDisplay, ScanSourcesDisplay};
+pub use estimate::{PlanEstimate, SourceEstimate, estimate_plan};
+pub use filter_scan::filter_scan_ir;
 pub use format::{ExprIRDisplay, IRDisplay, write_group_by, write_ir_non_recursive};
+pub use lineage::{ColumnLineage, SourceColumn, column_lineage};
+pub use pruning::{PruningReport, SourcePruning, pruning_report};
 use polars_core::prelude::*;
 use polars_utils::idx_vec::UnitVec;
 use polars_utils::unique_id::UniqueId;
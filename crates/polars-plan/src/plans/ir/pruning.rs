@@ -0,0 +1,108 @@
+use std::fmt;
+
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// The columns that every scan of a query plan reads after projection pushdown.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PruningReport {
+    /// The scans in the order they are visited from the root of the plan.
+    pub sources: Vec<SourcePruning>,
+}
+
+/// The columns a single scan of a query plan reads.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourcePruning {
+    /// Identifier of the scanned sources, e.g. the path of the first file. `None` for in-memory
+    /// and Python sources.
+    pub source: Option<PlSmallStr>,
+    /// Number of columns of the source.
+    pub n_columns: usize,
+    /// The columns that are read from the source, in the order of the source.
+    pub columns: Vec<PlSmallStr>,
+    /// Whether none of the columns that are read are part of the lineage of an output column.
+    ///
+    /// The rows of an unused source may still affect the output, e.g. if it is only used to
+    /// filter the rows of another source with a join.
+    pub unused: bool,
+}
+
+/// Report the columns that every scan of the plan at `root` reads, and the scans whose columns
+/// don't reach the output. See [`column_lineage`] for what makes a column reach the output.
+///
+/// Sources without an identifier are matched by column name, so an in-memory source is only
+/// reported as unused if no other in-memory source with the same columns is used.
+pub fn pruning_report(
+    root: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PruningReport {
+    // The outputs of the queries of a `SinkMultiple` are not part of its schema.
+    let outputs = match lp_arena.get(root) {
+        IR::SinkMultiple { inputs } => inputs.clone(),
+        _ => vec![root],
+    };
+    let used = outputs
+        .into_iter()
+        .flat_map(|node| column_lineage(node, lp_arena, expr_arena).into_values())
+        .flatten()
+        .collect::<PlHashSet<_>>();
+
+    // Scans that are shared by several queries are visited once per query.
+    let mut visited = PlHashSet::new();
+    let sources = lp_arena
+        .iter(root)
+        .filter_map(|(node, ir)| {
+            if !visited.insert(node) {
+                return None;
+            }
+            let (source, source_schema) = match ir {
+                IR::Scan {
+                    sources, file_info, ..
+                } => (Some(sources.id()), &file_info.schema),
+                IR::DataFrameScan { schema, .. } => (None, schema),
+                #[cfg(feature = "python")]
+                IR::PythonScan { options } => (None, &options.schema),
+                _ => return None,
+            };
+            let mut columns = ir.schema(lp_arena).iter_names().cloned().collect::<Vec<_>>();
+            columns.sort_by_key(|name| source_schema.index_of(name).unwrap_or(usize::MAX));
+            let unused = columns.iter().all(|column| {
+                !used.contains(&SourceColumn {
+                    source: source.clone(),
+                    column: column.clone(),
+                })
+            });
+            Some(SourcePruning {
+                source,
+                n_columns: source_schema.len(),
+                columns,
+                unused,
+            })
+        })
+        .collect();
+    PruningReport { sources }
+}
+
+impl fmt::Display for PruningReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "COLUMNS READ PER SOURCE")?;
+        for source in &self.sources {
+            let name = source.source.as_deref().unwrap_or("IN_MEMORY");
+            let (n_read, n_columns) = (source.columns.len(), source.n_columns);
+            write!(f, "\n  {name}: {n_read}/{n_columns} columns [")?;
+            for (i, column) in source.columns.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "\"{column}\"")?;
+            }
+            write!(f, "]")?;
+            if source.unused {
+                write!(f, " UNUSED")?;
+            }
+        }
+        Ok(())
+    }
+}
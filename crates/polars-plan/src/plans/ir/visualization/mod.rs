@@ -329,6 +329,7 @@ impl IRVisualizationDataGenerator<'_> {
                             nulls_equal,
                             coalesce,
                             maintain_order,
+                            promotion_rules: _,
                         },
                     options,
                 } = options.as_ref();
@@ -343,6 +344,8 @@ impl IRVisualizationDataGenerator<'_> {
                             strategy,
                             tolerance,
                             tolerance_str,
+                            tolerance_column,
+                            end_column,
                             left_by,
                             right_by,
                             allow_eq,
@@ -477,6 +480,7 @@ impl IRVisualizationDataGenerator<'_> {
                     cast_columns_policy: _,
                     missing_columns_policy: _,
                     extra_columns_policy: _,
+                    schema_unification_policy: _,
                     include_file_paths,
                     deletion_files,
                     table_statistics,
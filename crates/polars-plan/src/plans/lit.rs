@@ -607,6 +607,15 @@ impl Literal for Scalar {
     }
 }
 
+impl<T: PolarsDataType> Literal for TypedScalar<T>
+where
+    Scalar: From<TypedScalar<T>>,
+{
+    fn lit(self) -> Expr {
+        Scalar::from(self).lit()
+    }
+}
+
 /// Create a Literal Expression from `L`. A literal expression behaves like a column that contains a single distinct
 /// value.
 ///
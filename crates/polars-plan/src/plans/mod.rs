@@ -8,6 +8,7 @@ pub(crate) mod aexpr;
 pub(crate) mod anonymous_scan;
 pub(crate) mod ir;
 
+pub mod accelerator;
 mod apply;
 mod builder_ir;
 pub(crate) mod conversion;
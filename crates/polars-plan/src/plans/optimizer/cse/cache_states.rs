@@ -118,6 +118,11 @@ type TwoParents = [Option<Node>; 2];
 // - Above the filters the caches are the same -> run predicate pd from the filter node -> finish
 // - There is a cache without predicates above the cache node -> run predicate form the cache nodes -> finish
 // - The predicates above the cache nodes are all different -> remove the cache nodes -> finish
+//
+// If `share_scans` is set, caches with different predicates above them are kept. If all of them
+// have a predicate, their union is pushed down from the cache nodes and the predicates above the
+// caches remain to select the rows of every branch.
+#[allow(clippy::too_many_arguments)]
 pub(super) fn set_cache_states(
     root: Node,
     lp_arena: &mut Arena<IR>,
@@ -126,6 +131,7 @@ pub(super) fn set_cache_states(
     verbose: bool,
     pushdown_maintain_errors: bool,
     new_streaming: bool,
+    share_scans: bool,
 ) -> PolarsResult<()> {
     let mut stack = Vec::with_capacity(4);
     let mut names_scratch = vec![];
@@ -261,7 +267,7 @@ pub(super) fn set_cache_states(
             // # CHECK IF WE NEED TO REMOVE CACHES
             // If we encounter multiple predicates we remove the cache nodes completely as we don't
             // want to loose predicate pushdown in favor of scan sharing.
-            if v.predicate_union.len() > 1 {
+            if v.predicate_union.len() > 1 && !share_scans {
                 if verbose {
                     eprintln!("cache nodes will be removed because predicates don't match")
                 }
@@ -362,6 +368,9 @@ pub(super) fn set_cache_states(
                 let (_pred, count) = v.predicate_union.iter().next().unwrap();
                 *count == v.children.len() as u32
             };
+            let push_predicate_union = share_scans
+                && v.predicate_union.len() > 1
+                && v.predicate_union.values().sum::<u32>() == v.children.len() as u32;
 
             if allow_parent_predicate_pushdown {
                 let parents = *v.parents.first().unwrap();
@@ -378,6 +387,43 @@ pub(super) fn set_cache_states(
                         .expect("expected filter; this is an optimizer bug");
                     lp_arena.replace(node, lp.clone());
                 }
+            } else if push_predicate_union {
+                if verbose {
+                    eprintln!("pushing down the union of the predicates of a shared scan")
+                }
+                // Every cache has a filter above it, take the union of their distinct predicates.
+                let mut seen = PlHashSet::new();
+                let mut predicates = vec![];
+                for &parents in &v.parents {
+                    let node = get_filter_node(parents, lp_arena)
+                        .expect("expected filter; this is an optimizer bug");
+                    let IR::Filter { predicate, .. } = lp_arena.get(node) else {
+                        unreachable!()
+                    };
+                    if seen.insert(predicate.to_expr(expr_arena)) {
+                        predicates.push(predicate.node());
+                    }
+                }
+                let predicate = predicates
+                    .into_iter()
+                    .reduce(|left, right| {
+                        expr_arena.add(AExpr::BinaryExpr {
+                            left,
+                            op: Operator::Or,
+                            right,
+                        })
+                    })
+                    .unwrap();
+                let predicate = ExprIR::from_node(predicate, expr_arena);
+
+                let child = *v.children.first().unwrap();
+                let child_lp = lp_arena.take(child);
+                let input = lp_arena.add(child_lp);
+                let lp = pred_pd.optimize(IR::Filter { input, predicate }, lp_arena, expr_arena)?;
+                lp_arena.replace(child, lp.clone());
+                for &child in &v.children[1..] {
+                    lp_arena.replace(child, lp.clone());
+                }
             } else {
                 let child = *v.children.first().unwrap();
                 let child_lp = lp_arena.take(child);
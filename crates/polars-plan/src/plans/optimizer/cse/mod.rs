@@ -56,6 +56,7 @@ impl CommonSubPlanOptimizer {
                 verbose,
                 pushdown_maintain_errors,
                 opt_flags.new_streaming(),
+                opt_flags.share_scans(),
             )?;
         }
 
@@ -206,6 +206,7 @@ pub(super) fn expand_datasets(
                             cast_columns_policy,
                             missing_columns_policy,
                             extra_columns_policy,
+                            schema_unification_policy,
                             include_file_paths: _include_file_paths @ None,
                             deletion_files,
                             table_statistics,
@@ -224,6 +225,7 @@ pub(super) fn expand_datasets(
                         unified_scan_args.cast_columns_policy = cast_columns_policy.clone();
                         unified_scan_args.missing_columns_policy = *missing_columns_policy;
                         unified_scan_args.extra_columns_policy = *extra_columns_policy;
+                        unified_scan_args.schema_unification_policy = *schema_unification_policy;
                         unified_scan_args.column_mapping = column_mapping.clone();
                         unified_scan_args.default_values = default_values.clone();
                         unified_scan_args.deletion_files = deletion_files.clone();
@@ -0,0 +1,473 @@
+use polars_core::prelude::arity::unary_elementwise_values;
+use polars_core::prelude::*;
+use polars_utils::arena::{Arena, Node};
+use recursive::recursive;
+
+use crate::dsl::{BaseColumnUdf, new_column_udf};
+use crate::plans::{
+    AExpr, ExprIR, IR, IRAggExpr, OutputName, ToFieldContext, det_join_schema, into_column,
+};
+use crate::prelude::{FunctionOptions, ProjectionOptions};
+
+/// Order the floats of the query by the IEEE 754 totalOrder predicate.
+///
+/// The floats are mapped to integer keys of the same width that have the same order as the
+/// predicate, and the sorts, comparisons, `min`/`max` aggregations, group-by and unique keys and
+/// the column keys of equi-joins operate on the keys instead. The keys are mapped back to floats
+/// where they reach the output, so the kernels of both engines don't need to know about the
+/// order of the floats.
+///
+/// The keys of asof joins and the expression keys of joins keep the default order of floats.
+pub fn float_total_order(
+    root: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let nodes = ir_arena.iter(root).map(|(node, _)| node).collect::<Vec<_>>();
+    for node in nodes {
+        if !matches!(ir_arena.get(node), IR::Join { .. }) {
+            let schema = match ir_arena.get(node).input_schema(ir_arena) {
+                Some(schema) => schema.into_owned(),
+                None => continue,
+            };
+            let exprs = ir_arena
+                .get(node)
+                .exprs()
+                .map(|e| e.node())
+                .collect::<Vec<_>>();
+            for e in exprs {
+                rewrite_expr(e, &schema, expr_arena);
+            }
+        }
+        match ir_arena.get(node) {
+            IR::Sort { .. } => rewrite_sort(node, ir_arena, expr_arena)?,
+            IR::GroupBy { .. } => rewrite_group_by(node, ir_arena, expr_arena)?,
+            IR::Join { .. } => rewrite_join(node, ir_arena, expr_arena)?,
+            IR::Distinct { .. } => rewrite_distinct(node, ir_arena, expr_arena),
+            _ => {},
+        }
+    }
+    Ok(())
+}
+
+fn is_float(dtype: &DataType) -> bool {
+    matches!(dtype, DataType::Float16 | DataType::Float32 | DataType::Float64)
+}
+
+fn key_dtype(dtype: &DataType) -> DataType {
+    match dtype {
+        DataType::Float64 => DataType::Int64,
+        _ => DataType::Int32,
+    }
+}
+
+/// Flip all bits but the sign bit of negative numbers. This reverses the order of the negative
+/// floats, such that the bits of the floats ordered as signed integers follow the totalOrder
+/// predicate. The mapping is its own inverse.
+macro_rules! flip {
+    ($bits:expr, $signed:ty, $unsigned:ty) => {{
+        let bits: $signed = $bits;
+        bits ^ ((bits >> (<$signed>::BITS - 1)) as $unsigned >> 1) as $signed
+    }};
+}
+
+fn to_key(c: &Column) -> PolarsResult<Column> {
+    let out = match c.dtype() {
+        DataType::Float64 => {
+            let ca: Int64Chunked = unary_elementwise_values(c.f64()?, |v: f64| {
+                flip!(v.to_bits() as i64, i64, u64)
+            });
+            ca.into_column()
+        },
+        DataType::Float32 => {
+            let ca: Int32Chunked = unary_elementwise_values(c.f32()?, |v: f32| {
+                flip!(v.to_bits() as i32, i32, u32)
+            });
+            ca.into_column()
+        },
+        // Widening to `Float32` keeps the order, signs and payloads of all values.
+        DataType::Float16 => return to_key(&c.cast(&DataType::Float32)?),
+        dt => polars_bail!(opq = total_order_key, dt),
+    };
+    Ok(out.with_name(c.name().clone()))
+}
+
+fn from_key(c: &Column, dtype: &DataType) -> PolarsResult<Column> {
+    let out = match dtype {
+        DataType::Float64 => {
+            let ca: Float64Chunked = unary_elementwise_values(c.i64()?, |k: i64| {
+                f64::from_bits(flip!(k, i64, u64) as u64)
+            });
+            ca.into_column()
+        },
+        DataType::Float32 => {
+            let ca: Float32Chunked = unary_elementwise_values(c.i32()?, |k: i32| {
+                f32::from_bits(flip!(k, i32, u32) as u32)
+            });
+            ca.into_column()
+        },
+        DataType::Float16 => return from_key(c, &DataType::Float32)?.cast(dtype),
+        dt => polars_bail!(opq = from_total_order_key, dt),
+    };
+    Ok(out.with_name(c.name().clone()))
+}
+
+/// The key of the floats of `input`.
+fn key_expr(input: Node, expr_arena: &mut Arena<AExpr>) -> Node {
+    let function = BaseColumnUdf::new(
+        |c: &mut [Column]| to_key(&c[0]),
+        |_: &Schema, fields: &[Field]| {
+            let dtype = key_dtype(fields[0].dtype());
+            Ok(Field::new(fields[0].name().clone(), dtype))
+        },
+    );
+    let input = vec![ExprIR::from_node(input, expr_arena)];
+    expr_arena.add(AExpr::AnonymousFunction {
+        input,
+        function: new_column_udf(function),
+        options: FunctionOptions::elementwise(),
+        fmt_str: Box::new(PlSmallStr::from_static("total_order_key")),
+    })
+}
+
+/// The floats of `dtype` of the keys of `input`.
+fn float_expr(input: Node, dtype: &DataType, expr_arena: &Arena<AExpr>) -> AExpr {
+    let (out_dtype, field_dtype) = (dtype.clone(), dtype.clone());
+    let function = BaseColumnUdf::new(
+        move |c: &mut [Column]| from_key(&c[0], &out_dtype),
+        move |_: &Schema, fields: &[Field]| {
+            Ok(Field::new(fields[0].name().clone(), field_dtype.clone()))
+        },
+    );
+    AExpr::AnonymousFunction {
+        input: vec![ExprIR::from_node(input, expr_arena)],
+        function: new_column_udf(function),
+        options: FunctionOptions::elementwise(),
+        fmt_str: Box::new(PlSmallStr::from_static("from_total_order_key")),
+    }
+}
+
+fn float_dtype(node: Node, schema: &Schema, expr_arena: &Arena<AExpr>) -> Option<DataType> {
+    let ctx = ToFieldContext::new(expr_arena, schema);
+    let dtype = expr_arena.get(node).to_dtype(&ctx).ok()?;
+    is_float(&dtype).then_some(dtype)
+}
+
+/// Rewrite the float comparisons, sorts and `min`/`max` aggregations of the expression at `node`
+/// to operate on the keys of the floats.
+///
+/// Nodes that are shared by several expressions may be visited more than once, so the rewrites
+/// don't apply to their own output, which is either a key or not a float.
+#[recursive]
+fn rewrite_expr(node: Node, schema: &Schema, expr_arena: &mut Arena<AExpr>) {
+    let mut inputs = vec![];
+    expr_arena.get(node).inputs_rev(&mut inputs);
+    for input in inputs {
+        rewrite_expr(input, schema, expr_arena);
+    }
+
+    match expr_arena.get(node).clone() {
+        AExpr::BinaryExpr { left, op, right } if op.is_comparison() => {
+            let (Some(left_dtype), Some(right_dtype)) = (
+                float_dtype(left, schema, expr_arena),
+                float_dtype(right, schema, expr_arena),
+            ) else {
+                return;
+            };
+            if left_dtype != right_dtype {
+                return;
+            }
+            let left = key_expr(left, expr_arena);
+            let right = key_expr(right, expr_arena);
+            expr_arena.replace(node, AExpr::BinaryExpr { left, op, right });
+        },
+        AExpr::Agg(IRAggExpr::Min {
+            input,
+            propagate_nans,
+        }) => {
+            let Some(dtype) = float_dtype(input, schema, expr_arena) else {
+                return;
+            };
+            let input = key_expr(input, expr_arena);
+            let min = expr_arena.add(AExpr::Agg(IRAggExpr::Min {
+                input,
+                propagate_nans,
+            }));
+            expr_arena.replace(node, float_expr(min, &dtype, expr_arena));
+        },
+        AExpr::Agg(IRAggExpr::Max {
+            input,
+            propagate_nans,
+        }) => {
+            let Some(dtype) = float_dtype(input, schema, expr_arena) else {
+                return;
+            };
+            let input = key_expr(input, expr_arena);
+            let max = expr_arena.add(AExpr::Agg(IRAggExpr::Max {
+                input,
+                propagate_nans,
+            }));
+            expr_arena.replace(node, float_expr(max, &dtype, expr_arena));
+        },
+        AExpr::Sort { expr, options } => {
+            let Some(dtype) = float_dtype(expr, schema, expr_arena) else {
+                return;
+            };
+            let expr = key_expr(expr, expr_arena);
+            let sort = expr_arena.add(AExpr::Sort { expr, options });
+            expr_arena.replace(node, float_expr(sort, &dtype, expr_arena));
+        },
+        AExpr::SortBy {
+            expr,
+            mut by,
+            sort_options,
+        } => {
+            let mut changed = false;
+            for by in &mut by {
+                if float_dtype(*by, schema, expr_arena).is_some() {
+                    *by = key_expr(*by, expr_arena);
+                    changed = true;
+                }
+            }
+            if changed {
+                expr_arena.replace(
+                    node,
+                    AExpr::SortBy {
+                        expr,
+                        by,
+                        sort_options,
+                    },
+                );
+            }
+        },
+        _ => {},
+    }
+}
+
+fn rewrite_sort(
+    node: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let IR::Sort { input, .. } = ir_arena.get(node) else {
+        unreachable!()
+    };
+    let schema = ir_arena.get(*input).schema(ir_arena).into_owned();
+    let IR::Sort { by_column, .. } = ir_arena.get_mut(node) else {
+        unreachable!()
+    };
+    for e in by_column {
+        if is_float(e.dtype(&schema, expr_arena)?) {
+            *e = ExprIR::new(key_expr(e.node(), expr_arena), e.output_name_inner().clone());
+        }
+    }
+    Ok(())
+}
+
+fn rewrite_group_by(
+    node: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let IR::GroupBy {
+        input,
+        options,
+        apply: None,
+        ..
+    } = ir_arena.get(node)
+    else {
+        return Ok(());
+    };
+    if options.is_rolling() || options.is_dynamic() {
+        return Ok(());
+    }
+    let input_schema = ir_arena.get(*input).schema(ir_arena).into_owned();
+    let output_schema = ir_arena.get(node).schema(ir_arena).into_owned();
+
+    let mut group_by = ir_arena.get(node).clone();
+    let IR::GroupBy { keys, schema, .. } = &mut group_by else {
+        unreachable!()
+    };
+    let mut floats = vec![];
+    for e in keys {
+        let dtype = e.dtype(&input_schema, expr_arena)?.clone();
+        if is_float(&dtype) {
+            *e = ExprIR::new(key_expr(e.node(), expr_arena), e.output_name_inner().clone());
+            Arc::make_mut(schema).set_dtype(e.output_name(), key_dtype(&dtype));
+            floats.push((e.output_name().clone(), dtype));
+        }
+    }
+    if floats.is_empty() {
+        return Ok(());
+    }
+    let group_by = ir_arena.add(group_by);
+    restore_floats(node, group_by, &floats, output_schema, ir_arena, expr_arena);
+    Ok(())
+}
+
+fn rewrite_join(
+    node: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let IR::Join {
+        input_left,
+        input_right,
+        schema: output_schema,
+        left_on,
+        right_on,
+        options,
+    } = ir_arena.get(node)
+    else {
+        unreachable!()
+    };
+    let how = &options.args.how;
+    if !(how.is_equi() || how.is_semi_anti()) || options.options.is_some() {
+        return Ok(());
+    }
+    let (input_left, input_right) = (*input_left, *input_right);
+    let left_schema = ir_arena.get(input_left).schema(ir_arena).into_owned();
+    let right_schema = ir_arena.get(input_right).schema(ir_arena).into_owned();
+
+    let (mut left_floats, mut right_floats) = (vec![], vec![]);
+    for (l, r) in left_on.iter().zip(right_on) {
+        let (Some(l), Some(r)) = (
+            into_column(l.node(), expr_arena),
+            into_column(r.node(), expr_arena),
+        ) else {
+            continue;
+        };
+        let (Some(l_dtype), Some(r_dtype)) = (left_schema.get(l), right_schema.get(r)) else {
+            continue;
+        };
+        if is_float(l_dtype) && l_dtype == r_dtype {
+            left_floats.push((l.clone(), l_dtype.clone()));
+            right_floats.push((r.clone(), r_dtype.clone()));
+        }
+    }
+    if left_floats.is_empty() {
+        return Ok(());
+    }
+    let output_schema = output_schema.clone();
+
+    let mut join = ir_arena.get(node).clone();
+    let IR::Join {
+        input_left,
+        input_right,
+        schema,
+        left_on,
+        right_on,
+        options,
+    } = &mut join
+    else {
+        unreachable!()
+    };
+    *input_left = add_keys(*input_left, &left_floats, ir_arena, expr_arena);
+    *input_right = add_keys(*input_right, &right_floats, ir_arena, expr_arena);
+    // Clear the cached data types of the keys.
+    for e in left_on.iter_mut().chain(right_on.iter_mut()) {
+        *e = ExprIR::new(e.node(), e.output_name_inner().clone());
+    }
+    *schema = det_join_schema(
+        &ir_arena.get(*input_left).schema(ir_arena).into_owned(),
+        &ir_arena.get(*input_right).schema(ir_arena).into_owned(),
+        left_on,
+        right_on,
+        options,
+        expr_arena,
+    )?;
+    // The key columns in the output are the columns whose data type changed.
+    let floats = output_schema
+        .iter()
+        .filter(|(name, dtype)| schema.get(name) != Some(*dtype))
+        .map(|(name, dtype)| (name.clone(), dtype.clone()))
+        .collect::<Vec<_>>();
+
+    let join = ir_arena.add(join);
+    restore_floats(node, join, &floats, output_schema, ir_arena, expr_arena);
+    Ok(())
+}
+
+fn rewrite_distinct(node: Node, ir_arena: &mut Arena<IR>, expr_arena: &mut Arena<AExpr>) {
+    let IR::Distinct { input, options } = ir_arena.get(node) else {
+        unreachable!()
+    };
+    let input = *input;
+    let schema = ir_arena.get(input).schema(ir_arena).into_owned();
+    let floats = schema
+        .iter()
+        .filter(|(name, dtype)| {
+            is_float(dtype)
+                && options
+                    .subset
+                    .as_ref()
+                    .is_none_or(|subset| subset.contains(*name))
+        })
+        .map(|(name, dtype)| (name.clone(), dtype.clone()))
+        .collect::<Vec<_>>();
+    if floats.is_empty() {
+        return;
+    }
+
+    let mut distinct = ir_arena.get(node).clone();
+    let IR::Distinct { input: keys, .. } = &mut distinct else {
+        unreachable!()
+    };
+    *keys = add_keys(input, &floats, ir_arena, expr_arena);
+    let distinct = ir_arena.add(distinct);
+    restore_floats(node, distinct, &floats, schema, ir_arena, expr_arena);
+}
+
+/// Replace the `floats` columns of the output of `input` with their keys.
+fn add_keys(
+    input: Node,
+    floats: &[(PlSmallStr, DataType)],
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> Node {
+    let mut schema = ir_arena.get(input).schema(ir_arena).into_owned();
+    let exprs = floats
+        .iter()
+        .map(|(name, dtype)| {
+            Arc::make_mut(&mut schema).set_dtype(name, key_dtype(dtype));
+            let column = expr_arena.add(AExpr::Column(name.clone()));
+            let key = key_expr(column, expr_arena);
+            ExprIR::new(key, OutputName::ColumnLhs(name.clone()))
+        })
+        .collect();
+    ir_arena.add(IR::HStack {
+        input,
+        exprs,
+        schema,
+        options: ProjectionOptions::default(),
+    })
+}
+
+/// Replace `node` with `input`, of which the `floats` columns are mapped back from their keys to
+/// get the `schema` of `node`.
+fn restore_floats(
+    node: Node,
+    input: Node,
+    floats: &[(PlSmallStr, DataType)],
+    schema: SchemaRef,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) {
+    let exprs = floats
+        .iter()
+        .map(|(name, dtype)| {
+            let column = expr_arena.add(AExpr::Column(name.clone()));
+            let float = expr_arena.add(float_expr(column, dtype, expr_arena));
+            ExprIR::new(float, OutputName::ColumnLhs(name.clone()))
+        })
+        .collect();
+    ir_arena.replace(
+        node,
+        IR::HStack {
+            input,
+            exprs,
+            schema,
+            options: ProjectionOptions::default(),
+        },
+    );
+}
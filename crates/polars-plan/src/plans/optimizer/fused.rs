@@ -1,6 +1,11 @@
+use polars_ops::series::{FusedArithmeticOp, FusedComparisonOp, FusedElementwise as FE};
+
 use super::stack_opt::OptimizeExprContext;
 use super::*;
 
+/// The minimum number of operations of an elementwise chain that is fused into a single pass.
+const MIN_FUSED_OPERATIONS: usize = 2;
+
 pub struct FusedArithmetic {}
 
 fn get_expr(input: &[Node], op: FusedOperator, expr_arena: &Arena<AExpr>) -> AExpr {
@@ -49,6 +54,154 @@ fn check_eligible(
     }
 }
 
+/// Builds a [`FusedElementwise`](FE) chain of the operations on a single float column.
+struct ElementwiseChainBuilder<'a> {
+    name: &'a PlSmallStr,
+    dtype: &'a DataType,
+    expr_arena: &'a Arena<AExpr>,
+    schema: &'a Schema,
+    column: Option<Node>,
+}
+
+impl ElementwiseChainBuilder<'_> {
+    fn has_dtype(&self, node: Node) -> PolarsResult<bool> {
+        let dtype = self
+            .expr_arena
+            .get(node)
+            .to_dtype(&ToFieldContext::new(self.expr_arena, self.schema))?;
+        Ok(&dtype == self.dtype)
+    }
+
+    fn literal(&self, lv: &LiteralValue) -> Option<Option<f64>> {
+        match lv {
+            LiteralValue::Dyn(DynLiteralValue::Float(v)) => Some(Some(*v)),
+            // Only integers that are exactly representable, so that they are rounded only once.
+            LiteralValue::Dyn(DynLiteralValue::Int(v)) => {
+                let f = *v as f64;
+                (f as i128 == *v).then_some(Some(f))
+            },
+            LiteralValue::Scalar(s) if s.value().is_null() => Some(None),
+            LiteralValue::Scalar(s) if s.dtype() == self.dtype => s.value().extract().map(Some),
+            _ => None,
+        }
+    }
+
+    fn build(&mut self, node: Node) -> PolarsResult<Option<FE>> {
+        let out = match self.expr_arena.get(node) {
+            AExpr::Column(name) if name == self.name => {
+                self.column = Some(node);
+                FE::Column
+            },
+            AExpr::Literal(lv) => match self.literal(lv) {
+                Some(v) => FE::Literal(v),
+                None => return Ok(None),
+            },
+            AExpr::BinaryExpr { left, op, right } => {
+                let op = match op {
+                    Operator::Plus => FusedArithmeticOp::Add,
+                    Operator::Minus => FusedArithmeticOp::Sub,
+                    Operator::Multiply => FusedArithmeticOp::Mul,
+                    Operator::Divide | Operator::TrueDivide => FusedArithmeticOp::Div,
+                    _ => return Ok(None),
+                };
+                if !self.has_dtype(node)? {
+                    return Ok(None);
+                }
+                let (Some(left), Some(right)) = (self.build(*left)?, self.build(*right)?) else {
+                    return Ok(None);
+                };
+                FE::Arithmetic {
+                    left: Box::new(left),
+                    op,
+                    right: Box::new(right),
+                }
+            },
+            AExpr::Ternary {
+                predicate,
+                truthy,
+                falsy,
+            } => {
+                let AExpr::BinaryExpr { left, op, right } = self.expr_arena.get(*predicate) else {
+                    return Ok(None);
+                };
+                let op = match op {
+                    Operator::Eq => FusedComparisonOp::Eq,
+                    Operator::NotEq => FusedComparisonOp::NotEq,
+                    Operator::Lt => FusedComparisonOp::Lt,
+                    Operator::LtEq => FusedComparisonOp::LtEq,
+                    Operator::Gt => FusedComparisonOp::Gt,
+                    Operator::GtEq => FusedComparisonOp::GtEq,
+                    _ => return Ok(None),
+                };
+                if !self.has_dtype(node)? {
+                    return Ok(None);
+                }
+                let (Some(left), Some(right), Some(truthy), Some(falsy)) = (
+                    self.build(*left)?,
+                    self.build(*right)?,
+                    self.build(*truthy)?,
+                    self.build(*falsy)?,
+                ) else {
+                    return Ok(None);
+                };
+                FE::Ternary {
+                    left: Box::new(left),
+                    op,
+                    right: Box::new(right),
+                    truthy: Box::new(truthy),
+                    falsy: Box::new(falsy),
+                }
+            },
+            _ => return Ok(None),
+        };
+        Ok(Some(out))
+    }
+}
+
+/// Fuse a chain of arithmetic and `when/then/otherwise` on a single float column into a single
+/// pass over that column, so that the intermediate results are not materialized.
+fn fuse_elementwise(
+    node: Node,
+    expr_arena: &Arena<AExpr>,
+    schema: &Schema,
+) -> PolarsResult<Option<AExpr>> {
+    if !matches!(expr_arena.get(node), AExpr::BinaryExpr { .. } | AExpr::Ternary { .. }) {
+        return Ok(None);
+    }
+    let field = expr_arena
+        .get(node)
+        .to_field(&ToFieldContext::new(expr_arena, schema))?;
+    // The output must keep the name and type of the column.
+    if !matches!(field.dtype, DataType::Float32 | DataType::Float64)
+        || schema.get(&field.name) != Some(&field.dtype)
+    {
+        return Ok(None);
+    }
+
+    let mut builder = ElementwiseChainBuilder {
+        name: &field.name,
+        dtype: &field.dtype,
+        expr_arena,
+        schema,
+        column: None,
+    };
+    let Some(chain) = builder.build(node)? else {
+        return Ok(None);
+    };
+    let Some(column) = builder.column else {
+        return Ok(None);
+    };
+    if chain.num_operations() < MIN_FUSED_OPERATIONS {
+        return Ok(None);
+    }
+
+    Ok(Some(AExpr::Function {
+        input: vec![ExprIR::from_node(column, expr_arena)],
+        function: IRFunctionExpr::Fused(FusedOperator::Elementwise(Arc::new(chain))),
+        options: FunctionOptions::elementwise(),
+    }))
+}
+
 impl OptimizationRule for FusedArithmetic {
     #[allow(clippy::float_cmp)]
     fn optimize_expr(
@@ -63,6 +216,10 @@ impl OptimizationRule for FusedArithmetic {
             return Ok(None);
         }
 
+        if let Some(fused) = fuse_elementwise(expr_node, expr_arena, schema)? {
+            return Ok(Some(fused));
+        }
+
         let expr = expr_arena.get(expr_node);
 
         use AExpr::*;
@@ -12,21 +12,27 @@ mod count_star;
 #[cfg(feature = "cse")]
 mod cse;
 mod flatten_union;
+mod float_total_order;
 #[cfg(feature = "fused")]
 mod fused;
 mod join_utils;
 pub(crate) use join_utils::ExprOrigin;
+mod partition_group_by;
 mod expand_datasets;
 #[cfg(feature = "python")]
 pub use expand_datasets::ExpandedPythonScan;
 mod predicate_pushdown;
 mod projection_pushdown;
+mod shrink_dtypes;
+#[cfg(feature = "dtype-struct")]
+mod prune_struct_fields;
 pub mod set_order;
 mod simplify_expr;
 mod slice_pushdown_expr;
 mod slice_pushdown_lp;
 mod sortedness;
 mod stack_opt;
+mod uniqueness;
 
 use collapse_and_project::SimpleProjectionAndCollapse;
 #[cfg(feature = "cse")]
@@ -94,6 +100,12 @@ pub(super) fn run_projection_predicate_pushdown(
         ir_arena.replace(root, ir);
     }
 
+    // Should be run after predicate pushdown, as it also looks at the scan predicates.
+    #[cfg(feature = "dtype-struct")]
+    if opt_flags.projection_pushdown() {
+        prune_struct_fields::prune_struct_fields(root, ir_arena, expr_arena);
+    }
+
     Ok(())
 }
 
@@ -247,6 +259,11 @@ pub fn optimize(
 
     root = opt.optimize_loop(&mut rules, expr_arena, ir_arena, root)?;
 
+    if get_or_init_members!().has_hint {
+        sortedness::use_known_sortedness(root, ir_arena, expr_arena);
+        uniqueness::use_unique_keys(root, ir_arena, expr_arena);
+    }
+
     if opt_flags.cluster_with_columns() && get_or_init_members!().with_columns_count > 1 {
         cluster_with_columns::optimize(root, ir_arena, expr_arena)
     }
@@ -299,6 +316,24 @@ pub fn optimize(
 
     expand_datasets::expand_datasets(root, ir_arena, expr_arena, apply_scan_predicate_to_scan_ir)?;
 
+    // Runs after the other optimizations, which don't know the keys of the floats.
+    if opt_flags.float_total_order() {
+        float_total_order::float_total_order(root, ir_arena, expr_arena)?;
+    }
+
+    // Runs after the other optimizations, as the partitions share the expressions of the group-by.
+    if opt_flags.partition_group_by() && get_or_init_members!().has_group_by {
+        partition_group_by::partition_group_by(root, ir_arena, expr_arena);
+    }
+
+    // Runs last, such that the pushed down projections and predicates of the scans apply to the
+    // data types of the files.
+    if opt_flags.shrink_dtypes() {
+        shrink_dtypes::shrink_dtypes(root, ir_arena, expr_arena)?;
+    }
+
+    crate::plans::accelerator::offload_to_accelerators(root, ir_arena, expr_arena)?;
+
     // During debug we check if the optimizations have not modified the final schema.
     #[cfg(debug_assertions)]
     {
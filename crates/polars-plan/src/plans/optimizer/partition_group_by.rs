@@ -0,0 +1,88 @@
+use polars_utils::arena::{Arena, Node};
+
+use crate::dsl::UnionOptions;
+use crate::plans::{AExpr, IR, filter_scan_ir, into_column};
+
+/// Above this number of partitions the plan would grow too large, so the group-by is kept as is.
+const MAX_PARTITIONS: usize = 256;
+
+/// Run the group-bys over hive partitioned scans once per partition if the keys contain
+/// partition columns.
+///
+/// All rows of a group then come from the files of a single partition, so the partitions can be
+/// grouped independently and their results concatenated, without merging the groups of
+/// different partitions. The results of a partition are available as soon as it is grouped.
+///
+/// Scans with more than [`MAX_PARTITIONS`] partitions are grouped at once.
+pub fn partition_group_by(root: Node, ir_arena: &mut Arena<IR>, expr_arena: &Arena<AExpr>) {
+    let nodes = ir_arena.iter(root).map(|(node, _)| node).collect::<Vec<_>>();
+    for node in nodes {
+        let IR::GroupBy {
+            input,
+            keys,
+            maintain_order: false,
+            options,
+            apply: None,
+            ..
+        } = ir_arena.get(node)
+        else {
+            continue;
+        };
+        if options.slice.is_some() || options.is_rolling() || options.is_dynamic() {
+            continue;
+        }
+        let input = *input;
+        let IR::Scan {
+            sources,
+            hive_parts: Some(hive_parts),
+            unified_scan_args,
+            ..
+        } = ir_arena.get(input)
+        else {
+            continue;
+        };
+        // The row index and slice of a scan are computed over all of its files.
+        if !sources.is_paths()
+            || sources.len() != hive_parts.df().height()
+            || unified_scan_args.has_row_index_or_slice()
+        {
+            continue;
+        }
+
+        let partition_columns = keys
+            .iter()
+            .filter_map(|e| into_column(e.node(), expr_arena))
+            .filter(|name| hive_parts.schema().contains(name))
+            .collect::<Vec<_>>();
+        if partition_columns.is_empty() {
+            continue;
+        }
+        let Ok((_, partitions)) = hive_parts.group_sources_by(partition_columns) else {
+            continue;
+        };
+        if partitions.len() < 2 || partitions.len() > MAX_PARTITIONS {
+            continue;
+        }
+
+        let scan = ir_arena.get(input).clone();
+        let inputs = partitions
+            .into_iter()
+            .map(|files| {
+                let mut scan = scan.clone();
+                filter_scan_ir(&mut scan, files.into_iter());
+                let scan = ir_arena.add(scan);
+                let mut group_by = ir_arena.get(node).clone();
+                let IR::GroupBy { input, .. } = &mut group_by else {
+                    unreachable!()
+                };
+                *input = scan;
+                ir_arena.add(group_by)
+            })
+            .collect();
+        let options = UnionOptions {
+            from_partitioned_ds: true,
+            ..Default::default()
+        };
+        ir_arena.replace(node, IR::Union { inputs, options });
+    }
+}
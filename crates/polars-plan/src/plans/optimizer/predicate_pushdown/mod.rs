@@ -516,10 +516,8 @@ impl PredicatePushDown {
                                 expr_arena,
                             ))
                         },
-                        FunctionIR::Unnest {
-                            columns,
-                            separator: _,
-                        } => {
+                        FunctionIR::Unnest { columns, .. }
+                        | FunctionIR::UnnestAll { columns, .. } => {
                             let exclude = columns.iter().cloned().collect::<PlHashSet<_>>();
 
                             let local_predicates =
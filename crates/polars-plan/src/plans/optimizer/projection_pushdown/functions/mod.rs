@@ -18,14 +18,17 @@ pub(super) fn process_functions(
     use FunctionIR::*;
     match function {
         Explode {
-            columns, options, ..
+            columns,
+            options,
+            mismatch,
+            ..
         } => {
             columns
                 .iter()
                 .for_each(|name| add_str_to_accumulated(name.clone(), &mut ctx, expr_arena));
             proj_pd.pushdown_and_assign(input, ctx, lp_arena, expr_arena)?;
             Ok(IRBuilder::new(input, expr_arena, lp_arena)
-                .explode(columns, options)
+                .explode(columns, options, mismatch)
                 .build())
         },
         #[cfg(feature = "pivot")]
@@ -56,7 +59,10 @@ pub(super) fn process_functions(
                         expr_arena,
                     )
                 }
-                let expands_schema = matches!(function, FunctionIR::Unnest { .. });
+                let expands_schema = matches!(
+                    function,
+                    FunctionIR::Unnest { .. } | FunctionIR::UnnestAll { .. }
+                );
 
                 let local_projections = proj_pd.pushdown_and_assign_check_schema(
                     input,
@@ -133,6 +133,13 @@ pub(super) fn process_join(
                 project_right.insert(name.clone());
             }
         }
+
+        if let Some(name) = &asof_options.tolerance_column {
+            project_left.insert(name.clone());
+        }
+        if let Some(name) = &asof_options.end_column {
+            project_right.insert(name.clone());
+        }
     }
 
     // Turn on coalesce if non-coalesced keys are not included in projection. Reduces materialization.
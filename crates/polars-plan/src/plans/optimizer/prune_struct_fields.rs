@@ -0,0 +1,160 @@
+//! Prune the fields of struct columns that a scan does not need to read.
+//!
+//! If a struct column of a file scan is only used through `struct.field()`, the output schema of
+//! the scan is narrowed to the accessed fields, so the readers can skip decoding the others.
+use polars_utils::idx_vec::UnitVec;
+use polars_utils::unitvec;
+
+use super::*;
+
+/// The fields used of a struct column, `None` if the column itself is used.
+type UsedFields = PlHashMap<PlSmallStr, Option<PlHashSet<PlSmallStr>>>;
+
+pub(super) fn prune_struct_fields(
+    root: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) {
+    let mut n_parents: PlHashMap<Node, usize> = PlHashMap::new();
+    let mut candidates = vec![];
+    for (node, ir) in ir_arena.iter(root) {
+        for input in ir.inputs() {
+            *n_parents.entry(input).or_default() += 1;
+        }
+        if let IR::Select { input, .. } = ir
+            && is_prunable_scan(ir_arena.get(*input))
+        {
+            candidates.push((node, *input));
+        }
+    }
+
+    for (select, scan) in candidates {
+        if n_parents.get(&scan) != Some(&1) {
+            continue;
+        }
+        let IR::Select { expr, .. } = ir_arena.get(select) else {
+            unreachable!()
+        };
+        let IR::Scan {
+            file_info,
+            predicate,
+            output_schema,
+            ..
+        } = ir_arena.get(scan)
+        else {
+            unreachable!()
+        };
+        let schema = output_schema.as_ref().unwrap_or(&file_info.schema);
+
+        let mut used = UsedFields::new();
+        for e in expr.iter().chain(predicate) {
+            collect_used_fields(e.node(), expr_arena, schema, &mut used);
+        }
+
+        let mut pruned_columns = vec![];
+        let new_schema = schema
+            .iter()
+            .map(|(name, dtype)| {
+                let dtype = match (dtype, used.get(name)) {
+                    (DataType::Struct(fields), Some(Some(used_fields)))
+                        if used_fields.len() < fields.len() =>
+                    {
+                        pruned_columns.push(name.clone());
+                        DataType::Struct(
+                            fields
+                                .iter()
+                                .filter(|f| used_fields.contains(f.name()))
+                                .cloned()
+                                .collect(),
+                        )
+                    },
+                    _ => dtype.clone(),
+                };
+                (name.clone(), dtype)
+            })
+            .collect::<Schema>();
+
+        if pruned_columns.is_empty() {
+            continue;
+        }
+
+        let IR::Scan {
+            output_schema,
+            unified_scan_args,
+            ..
+        } = ir_arena.get_mut(scan)
+        else {
+            unreachable!()
+        };
+        *output_schema = Some(Arc::new(new_schema));
+        unified_scan_args.cast_columns_policy.pruned_struct_columns = Some(pruned_columns.into());
+    }
+}
+
+fn is_prunable_scan(ir: &IR) -> bool {
+    let IR::Scan {
+        scan_type,
+        unified_scan_args,
+        ..
+    } = ir
+    else {
+        return false;
+    };
+
+    #[allow(unused_mut)]
+    let mut is_supported = false;
+    #[cfg(feature = "parquet")]
+    {
+        is_supported |= matches!(scan_type.as_ref(), FileScanIR::Parquet { .. });
+    }
+    #[cfg(feature = "ipc")]
+    {
+        is_supported |= matches!(scan_type.as_ref(), FileScanIR::Ipc { .. });
+    }
+    #[cfg(not(any(feature = "parquet", feature = "ipc")))]
+    let _ = scan_type;
+
+    // Columns of Iceberg scans are selected by their physical ID, not by their name.
+    is_supported
+        && unified_scan_args.column_mapping.is_none()
+        && unified_scan_args
+            .cast_columns_policy
+            .pruned_struct_columns
+            .is_none()
+}
+
+fn collect_used_fields(
+    root: Node,
+    expr_arena: &Arena<AExpr>,
+    schema: &Schema,
+    used: &mut UsedFields,
+) {
+    let mut stack: UnitVec<Node> = unitvec![root];
+    while let Some(node) = stack.pop() {
+        let ae = expr_arena.get(node);
+        match ae {
+            AExpr::Function {
+                input,
+                function: IRFunctionExpr::StructExpr(IRStructFunction::FieldByName(field)),
+                ..
+            } => {
+                if let AExpr::Column(name) = expr_arena.get(input[0].node())
+                    && matches!(schema.get(name), Some(DataType::Struct(_)))
+                {
+                    if let Some(fields) = used
+                        .entry(name.clone())
+                        .or_insert_with(|| Some(PlHashSet::new()))
+                    {
+                        fields.insert(field.clone());
+                    }
+                    continue;
+                }
+            },
+            AExpr::Column(name) => {
+                used.insert(name.clone(), None);
+            },
+            _ => {},
+        }
+        ae.children_rev(&mut stack);
+    }
+}
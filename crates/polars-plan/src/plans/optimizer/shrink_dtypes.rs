@@ -0,0 +1,352 @@
+use polars_core::prelude::*;
+use polars_utils::arena::{Arena, Node};
+use recursive::recursive;
+
+use crate::plans::{AExpr, ExprIR, IR, IRAggExpr};
+use crate::utils::aexpr_to_leaf_names_iter;
+
+type ShrunkColumns = PlIndexMap<PlSmallStr, DataType>;
+
+/// Cast the integer columns of scans of a single parquet file to the smallest data types that hold
+/// all of their values, keeping their signedness.
+///
+/// The bounds of the values are taken from the statistics of the file, so that optimizing the
+/// query (e.g. for `explain`) doesn't read the data. This limits the pass to:
+/// - scans of a single parquet file, as only the metadata of the first file of a scan is known
+///   when optimizing, and every row group must have statistics of the column;
+/// - integer columns, as floats can't be shrunk without losing precision;
+/// - columns of the scan. Computed columns, such as the `sum` or `len` of a group-by, are not
+///   shrunk, as their bounds would have to be derived from the expressions.
+///
+/// In-memory frames are not shrunk, as that would require a pass over the data and keep a cast
+/// copy next to the frame.
+///
+/// Only the columns that are passed on unchanged to the output of the query are shrunk, through
+/// filters, slices, sorts, projections and the `min`, `max`, `first` and `last` aggregations of
+/// group-bys, such that no expression is evaluated on the smaller data types. The output of sinks
+/// is not shrunk, as the files they write should have the data types of the query.
+pub fn shrink_dtypes(
+    root: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let allowed = output_names(root, ir_arena);
+    shrink_node(root, allowed, ir_arena, expr_arena)?;
+    Ok(())
+}
+
+fn output_names(node: Node, ir_arena: &Arena<IR>) -> PlHashSet<PlSmallStr> {
+    ir_arena.get(node).schema(ir_arena).iter_names().cloned().collect()
+}
+
+/// Shrink the `allowed` columns of the output of `node` where possible, and return the columns
+/// that were shrunk with their new data types.
+#[recursive]
+fn shrink_node(
+    node: Node,
+    mut allowed: PlHashSet<PlSmallStr>,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<ShrunkColumns> {
+    if allowed.is_empty() {
+        return Ok(Default::default());
+    }
+
+    match ir_arena.get(node) {
+        #[cfg(feature = "parquet")]
+        IR::Scan { .. } => {
+            let shrunk = shrink_parquet_columns(ir_arena.get(node), &allowed)?;
+            cast_scan_columns(node, &shrunk, ir_arena, expr_arena);
+            Ok(shrunk)
+        },
+        IR::Filter { input, predicate } => {
+            let input = *input;
+            for name in aexpr_to_leaf_names_iter(predicate.node(), expr_arena) {
+                allowed.remove(name);
+            }
+            shrink_node(input, allowed, ir_arena, expr_arena)
+        },
+        IR::Slice { input, .. } | IR::Distinct { input, .. } => {
+            let input = *input;
+            shrink_node(input, allowed, ir_arena, expr_arena)
+        },
+        IR::SimpleProjection { input, .. } => {
+            let input = *input;
+            let shrunk = shrink_node(input, allowed, ir_arena, expr_arena)?;
+            let IR::SimpleProjection { columns, .. } = ir_arena.get_mut(node) else {
+                unreachable!()
+            };
+            set_dtypes(columns, &shrunk);
+            Ok(shrunk)
+        },
+        IR::Sort {
+            input, by_column, ..
+        } => {
+            let input = *input;
+            let allowed = passed_on_columns(by_column, allowed, true, expr_arena);
+            let shrunk = shrink_node(input, allowed, ir_arena, expr_arena)?;
+            let IR::Sort { by_column, .. } = ir_arena.get_mut(node) else {
+                unreachable!()
+            };
+            for e in by_column {
+                reset_dtype(e, &shrunk, expr_arena);
+            }
+            Ok(shrunk)
+        },
+        IR::Select { input, expr, .. } => {
+            let input = *input;
+            let allowed = passed_on_columns(expr, allowed, false, expr_arena);
+            shrink_projection(node, input, allowed, ir_arena, expr_arena)
+        },
+        IR::HStack { input, exprs, .. } => {
+            let input = *input;
+            let allowed = passed_on_columns(exprs, allowed, true, expr_arena);
+            shrink_projection(node, input, allowed, ir_arena, expr_arena)
+        },
+        IR::GroupBy {
+            input,
+            keys,
+            aggs,
+            options,
+            apply: None,
+            ..
+        } if !options.is_rolling() && !options.is_dynamic() => {
+            let input = *input;
+            let exprs = keys.iter().chain(aggs).cloned().collect::<Vec<_>>();
+            let allowed = passed_on_columns(&exprs, allowed, false, expr_arena);
+            shrink_projection(node, input, allowed, ir_arena, expr_arena)
+        },
+        // The files written by sinks keep the data types of the query.
+        _ => Ok(Default::default()),
+    }
+}
+
+/// Cast the `shrunk` columns of the scan at `node` with a with-columns node on top of it.
+#[cfg(feature = "parquet")]
+fn cast_scan_columns(
+    node: Node,
+    shrunk: &ShrunkColumns,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) {
+    use polars_core::chunked_array::cast::CastOptions;
+
+    use crate::plans::OutputName;
+    use crate::prelude::ProjectionOptions;
+
+    if shrunk.is_empty() {
+        return;
+    }
+
+    let mut schema = ir_arena.get(node).schema(ir_arena).into_owned();
+    set_dtypes(&mut schema, &shrunk);
+    let exprs = shrunk
+        .iter()
+        .map(|(name, dtype)| {
+            let cast = AExpr::Cast {
+                expr: expr_arena.add(AExpr::Column(name.clone())),
+                dtype: dtype.clone(),
+                options: CastOptions::Strict,
+            };
+            ExprIR::new(expr_arena.add(cast), OutputName::ColumnLhs(name.clone()))
+        })
+        .collect();
+    let scan = ir_arena.take(node);
+    let input = ir_arena.add(scan);
+    ir_arena.replace(
+        node,
+        IR::HStack {
+            input,
+            exprs,
+            schema,
+            options: ProjectionOptions::default(),
+        },
+    );
+}
+
+/// Shrink the input of a select, with-columns or group-by node and update its expressions and
+/// schema to the shrunk columns that it passes on.
+fn shrink_projection(
+    node: Node,
+    input: Node,
+    allowed: PlHashSet<PlSmallStr>,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<ShrunkColumns> {
+    let shrunk = shrink_node(input, allowed, ir_arena, expr_arena)?;
+    let (exprs, schema) = match ir_arena.get_mut(node) {
+        IR::Select { expr, schema, .. } => (expr.iter_mut().collect::<Vec<_>>(), schema),
+        IR::HStack { exprs, schema, .. } => (exprs.iter_mut().collect(), schema),
+        IR::GroupBy {
+            keys, aggs, schema, ..
+        } => (keys.iter_mut().chain(aggs.iter_mut()).collect(), schema),
+        _ => unreachable!(),
+    };
+    for e in exprs {
+        reset_dtype(e, &shrunk, expr_arena);
+    }
+    set_dtypes(schema, &shrunk);
+    Ok(shrunk)
+}
+
+/// The column that `e` passes on with the same name and data type, if any.
+fn passed_on_column<'a>(e: &ExprIR, expr_arena: &'a Arena<AExpr>) -> Option<&'a PlSmallStr> {
+    let mut node = e.node();
+    if let AExpr::Agg(
+        IRAggExpr::Min { input, .. }
+        | IRAggExpr::Max { input, .. }
+        | IRAggExpr::First(input)
+        | IRAggExpr::FirstNonNull(input)
+        | IRAggExpr::Last(input)
+        | IRAggExpr::LastNonNull(input),
+    ) = expr_arena.get(node)
+    {
+        node = *input;
+    }
+    match expr_arena.get(node) {
+        AExpr::Column(name) if name == e.output_name() => Some(name),
+        _ => None,
+    }
+}
+
+/// The columns of the input of `exprs` that may be shrunk, given the `allowed` columns of the
+/// output. These are the columns that are passed on by `exprs`, or also the columns that aren't
+/// overwritten if the node `keeps_input_columns`, which no other expression depends on.
+fn passed_on_columns(
+    exprs: &[ExprIR],
+    mut allowed: PlHashSet<PlSmallStr>,
+    keeps_input_columns: bool,
+    expr_arena: &Arena<AExpr>,
+) -> PlHashSet<PlSmallStr> {
+    if !keeps_input_columns {
+        allowed.retain(|name| exprs.iter().any(|e| e.output_name() == name));
+    }
+    let mut used = PlHashSet::new();
+    for e in exprs {
+        if passed_on_column(e, expr_arena).is_none() {
+            allowed.remove(e.output_name());
+            used.extend(aexpr_to_leaf_names_iter(e.node(), expr_arena).cloned());
+        }
+    }
+    allowed.retain(|name| !used.contains(name));
+    allowed
+}
+
+/// Clear the cached data type of `e` if it passes on a shrunk column.
+fn reset_dtype(e: &mut ExprIR, shrunk: &ShrunkColumns, expr_arena: &Arena<AExpr>) {
+    if passed_on_column(e, expr_arena).is_some_and(|name| shrunk.contains_key(name)) {
+        *e = ExprIR::new(e.node(), e.output_name_inner().clone());
+    }
+}
+
+fn set_dtypes(schema: &mut SchemaRef, shrunk: &ShrunkColumns) {
+    let schema = Arc::make_mut(schema);
+    for (name, dtype) in shrunk {
+        schema.set_dtype(name, dtype.clone());
+    }
+}
+
+/// Shrink the integer columns of a scan of a single parquet file using the statistics of its row
+/// groups, if all row groups have a minimum and maximum of the column.
+#[cfg(feature = "parquet")]
+fn shrink_parquet_columns(
+    scan: &IR,
+    allowed: &PlHashSet<PlSmallStr>,
+) -> PolarsResult<ShrunkColumns> {
+    use either::Either;
+    use polars_parquet::read::statistics::deserialize_all;
+
+    use crate::dsl::FileScanIR;
+
+    let IR::Scan {
+        sources,
+        file_info,
+        output_schema,
+        scan_type,
+        unified_scan_args,
+        ..
+    } = scan
+    else {
+        unreachable!()
+    };
+    let FileScanIR::Parquet {
+        metadata: Some(metadata),
+        ..
+    } = scan_type.as_ref()
+    else {
+        return Ok(Default::default());
+    };
+    let Some(Either::Left(reader_schema)) = &file_info.reader_schema else {
+        return Ok(Default::default());
+    };
+    let row_groups = &metadata.row_groups;
+    if sources.len() != 1 || row_groups.is_empty() || unified_scan_args.column_mapping.is_some() {
+        return Ok(Default::default());
+    }
+
+    let mut shrunk = ShrunkColumns::default();
+    let schema = output_schema.as_ref().unwrap_or(&file_info.schema);
+    for (name, dtype) in schema.iter() {
+        if !dtype.is_integer() || !allowed.contains(name) {
+            continue;
+        }
+        let Some(field) = reader_schema.get(name) else {
+            continue;
+        };
+        let Some(&[idx]) = row_groups[0].columns_idxs_under_root_iter(name) else {
+            continue;
+        };
+        let Some(statistics) = deserialize_all(field, row_groups, idx)? else {
+            continue;
+        };
+        // Missing statistics of a row group are null.
+        if statistics.min_value.null_count() > 0 || statistics.max_value.null_count() > 0 {
+            continue;
+        }
+        let min = Series::from_arrow(name.clone(), statistics.min_value)?.min_reduce()?;
+        let max = Series::from_arrow(name.clone(), statistics.max_value)?.max_reduce()?;
+        let (Some(min), Some(max)) = (int_bound(&min)?, int_bound(&max)?) else {
+            continue;
+        };
+        if let Some(dtype) = shrunk_integer_dtype(dtype, min, max) {
+            shrunk.insert(name.clone(), dtype);
+        }
+    }
+    Ok(shrunk)
+}
+
+#[cfg(feature = "parquet")]
+fn int_bound(bound: &Scalar) -> PolarsResult<Option<i128>> {
+    if bound.is_null() {
+        return Ok(None);
+    }
+    let value = bound.value();
+    value
+        .extract::<i128>()
+        .map(Some)
+        .ok_or_else(|| polars_err!(ComputeError: "could not extract {} as an integer", value))
+}
+
+/// The smallest integer data type with the signedness of `dtype` that holds `min` and `max`, if
+/// it is smaller than `dtype`.
+#[cfg(feature = "parquet")]
+fn shrunk_integer_dtype(dtype: &DataType, min: i128, max: i128) -> Option<DataType> {
+    let candidates = if dtype.is_unsigned_integer() {
+        [
+            (cfg!(feature = "dtype-u8"), DataType::UInt8, 0, u8::MAX as i128),
+            (cfg!(feature = "dtype-u16"), DataType::UInt16, 0, u16::MAX as i128),
+            (true, DataType::UInt32, 0, u32::MAX as i128),
+        ]
+    } else {
+        [
+            (cfg!(feature = "dtype-i8"), DataType::Int8, i8::MIN as i128, i8::MAX as i128),
+            (cfg!(feature = "dtype-i16"), DataType::Int16, i16::MIN as i128, i16::MAX as i128),
+            (true, DataType::Int32, i32::MIN as i128, i32::MAX as i128),
+        ]
+    };
+    candidates
+        .into_iter()
+        .take_while(|(_, candidate, _, _)| candidate != dtype)
+        .find(|(enabled, _, lower, upper)| *enabled && *lower <= min && max <= *upper)
+        .map(|(_, candidate, _, _)| candidate)
+}
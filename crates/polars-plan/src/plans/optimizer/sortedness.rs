@@ -4,10 +4,12 @@ use polars_core::chunked_array::cast::CastOptions;
 use polars_core::prelude::{FillNullStrategy, PlHashMap, PlHashSet};
 use polars_core::schema::Schema;
 use polars_core::series::IsSorted;
+use polars_ops::frame::{JoinArgs, JoinType, MaintainOrderJoin};
 use polars_utils::arena::{Arena, Node};
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::unique_id::UniqueId;
 
+use crate::dsl::Operator;
 use crate::plans::{
     AExpr, ExprIR, FunctionIR, HintIR, IR, IRFunctionExpr, Sorted, ToFieldContext,
     constant_evaluate, into_column,
@@ -63,6 +65,122 @@ pub fn is_sorted(root: Node, ir_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) ->
     )
 }
 
+/// Use the known sortedness of the plan, e.g. from `set_sorted` hints, to remove sorts that don't
+/// change the order and to skip the sortedness checks of asof joins.
+///
+/// Other nodes are not rewritten. The streaming engine checks the sortedness of group-by keys
+/// with [`is_sorted`] when lowering, while the in-memory group-bys, joins and window expressions
+/// only see the sorted flag that the hint sets on its first column at runtime.
+pub fn use_known_sortedness(root: Node, ir_arena: &mut Arena<IR>, expr_arena: &Arena<AExpr>) {
+    let mut sortedness = PlHashMap::default();
+    let mut cache_proxy = PlHashMap::default();
+    let mut amort_passed_columns = PlHashSet::default();
+
+    let nodes = ir_arena.iter(root).map(|(node, _)| node).collect::<Vec<_>>();
+    for node in nodes {
+        let mut node_sortedness = |node: Node, ir_arena: &Arena<IR>| {
+            is_sorted_rec(
+                node,
+                ir_arena,
+                expr_arena,
+                &mut sortedness,
+                &mut cache_proxy,
+                &mut amort_passed_columns,
+            )
+        };
+
+        match ir_arena.get(node) {
+            IR::Sort {
+                input,
+                by_column,
+                slice: None,
+                sort_options,
+            } if sort_options.limit.is_none() => {
+                let input = *input;
+                let Some(input_sorted) = node_sortedness(input, ir_arena) else {
+                    continue;
+                };
+                let is_sorted_by = by_column.len() <= input_sorted.0.len()
+                    && by_column.iter().zip(input_sorted.0.iter()).enumerate().all(
+                        |(i, (e, s))| {
+                            // A single value applies to all columns.
+                            let descending = sort_options
+                                .descending
+                                .get(i)
+                                .unwrap_or(&sort_options.descending[0]);
+                            let nulls_last = sort_options
+                                .nulls_last
+                                .get(i)
+                                .unwrap_or(&sort_options.nulls_last[0]);
+                            into_column(e.node(), expr_arena) == Some(&s.column)
+                                && s.descending == Some(*descending)
+                                && s.nulls_last == Some(*nulls_last)
+                        },
+                    );
+
+                // A (stable) sort of sorted data returns it as is.
+                if is_sorted_by {
+                    let ir = ir_arena.take(input);
+                    ir_arena.replace(node, ir);
+                }
+            },
+            #[cfg(feature = "asof_join")]
+            IR::Join {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                options,
+                ..
+            } if matches!(
+                &options.args.how,
+                JoinType::AsOf(asof_options) if asof_options.check_sortedness
+            ) =>
+            {
+                let (input_left, input_right) = (*input_left, *input_right);
+                let is_key_sorted = |key: &ExprIR, sorted: Option<IRSorted>| {
+                    sorted.as_ref().and_then(|s| s.0.first()).is_some_and(|s| {
+                        into_column(key.node(), expr_arena) == Some(&s.column)
+                            && s.descending == Some(false)
+                    })
+                };
+                let left_sorted = node_sortedness(input_left, ir_arena);
+                let right_sorted = node_sortedness(input_right, ir_arena);
+                if !(is_key_sorted(&left_on[0], left_sorted)
+                    && is_key_sorted(&right_on[0], right_sorted))
+                {
+                    continue;
+                }
+
+                let IR::Join { options, .. } = ir_arena.get_mut(node) else {
+                    unreachable!()
+                };
+                if let JoinType::AsOf(asof_options) = &mut Arc::make_mut(options).args.how {
+                    asof_options.check_sortedness = false;
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+/// Does the join output the rows of its left input in their order, filtered or repeated? The left
+/// columns then keep their sortedness.
+fn join_keeps_left_order(args: &JoinArgs) -> bool {
+    let maintains_left_order = matches!(
+        args.maintain_order,
+        MaintainOrderJoin::Left | MaintainOrderJoin::LeftRight
+    );
+    match &args.how {
+        #[cfg(feature = "asof_join")]
+        JoinType::AsOf(_) => true,
+        JoinType::Inner | JoinType::Left => maintains_left_order,
+        #[cfg(feature = "semi_anti_join")]
+        JoinType::Semi | JoinType::Anti => maintains_left_order,
+        _ => false,
+    }
+}
+
 #[recursive::recursive]
 fn is_sorted_rec(
     root: Node,
@@ -319,11 +437,15 @@ fn is_sorted_rec(
         },
 
         IR::GroupBy { .. } => None,
+        IR::Join {
+            input_left,
+            options,
+            ..
+        } if join_keeps_left_order(&options.args) => rec!(*input_left),
         IR::Join { .. } => None,
         IR::MapFunction { input, function } => match function {
             FunctionIR::Hint(hint) => match hint {
-                HintIR::Sorted(v) => Some(IRSorted(v.clone())),
-                #[expect(unreachable_patterns)]
+                HintIR::Sorted { sorted, .. } => Some(IRSorted(sorted.clone())),
                 _ => rec!(*input),
             },
             _ => None,
@@ -417,6 +539,16 @@ pub fn aexpr_sortedness(
             }
             Some(expr_sortedness)
         },
+        // Casting numbers to floats never fails and rounds monotonically.
+        AExpr::Cast { expr, dtype, .. } if dtype.is_float() => {
+            let expr = arena.get(*expr);
+            let expr_sortedness = aexpr_sortedness(expr, arena, schema, input_sorted)?;
+            let input_dtype = expr.to_dtype(&ToFieldContext::new(arena, schema)).ok()?;
+            if !input_dtype.is_primitive_numeric() {
+                return None;
+            }
+            Some(expr_sortedness)
+        },
         AExpr::Cast { .. } => None, // @TODO: More casts are allowed are allowed
         AExpr::Sort { expr: _, options } => Some(AExprSorted {
             descending: Some(options.descending),
@@ -434,6 +566,31 @@ pub fn aexpr_sortedness(
             length: _,
         } => aexpr_sortedness(arena.get(*input), arena, schema, input_sorted),
 
+        // Shifting floats by a finite scalar keeps their order, integers may wrap around.
+        AExpr::BinaryExpr {
+            left,
+            op: op @ (Operator::Plus | Operator::Minus),
+            right,
+        } => {
+            let is_finite_scalar = |node: Node| match arena.get(node) {
+                AExpr::Literal(lv) if lv.is_scalar() => lv
+                    .to_any_value()
+                    .and_then(|v| v.extract::<f64>())
+                    .is_some_and(f64::is_finite),
+                _ => false,
+            };
+            let dtype = aexpr.to_dtype(&ToFieldContext::new(arena, schema)).ok()?;
+            if !dtype.is_float() {
+                return None;
+            }
+            if is_finite_scalar(*right) {
+                aexpr_sortedness(arena.get(*left), arena, schema, input_sorted)
+            } else if *op == Operator::Plus && is_finite_scalar(*left) {
+                aexpr_sortedness(arena.get(*right), arena, schema, input_sorted)
+            } else {
+                None
+            }
+        },
         AExpr::BinaryExpr { .. }
         | AExpr::Gather { .. }
         | AExpr::SortBy { .. }
@@ -488,6 +645,10 @@ pub fn function_expr_sortedness(
         IRFunctionExpr::Mode {
             maintain_order: true,
         } => first_input!(),
+        #[cfg(feature = "round_series")]
+        IRFunctionExpr::Round { .. } | IRFunctionExpr::Floor | IRFunctionExpr::Ceil => {
+            first_input!()
+        },
 
         #[cfg(feature = "range")]
         IRFunctionExpr::Range(range) => {
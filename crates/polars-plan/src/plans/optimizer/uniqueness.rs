@@ -0,0 +1,174 @@
+use std::sync::Arc;
+
+use polars_core::prelude::{PlHashMap, PlHashSet};
+use polars_ops::frame::{JoinType, JoinValidation};
+use polars_utils::arena::{Arena, Node};
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::plans::{AExpr, ExprIR, FunctionIR, HintIR, IR, into_column};
+
+/// Sets of columns that each uniquely identify the rows of a plan.
+type UniqueKeys = Arc<[Arc<[PlSmallStr]>]>;
+
+/// Use the unique keys of the plan, e.g. from `set_unique_key` hints, to remove distincts that
+/// don't remove any rows and left joins that only look up rows without adding any columns.
+pub fn use_unique_keys(root: Node, ir_arena: &mut Arena<IR>, expr_arena: &Arena<AExpr>) {
+    let mut unique_keys = PlHashMap::default();
+
+    let nodes = ir_arena.iter(root).map(|(node, _)| node).collect::<Vec<_>>();
+    for node in nodes {
+        match ir_arena.get(node) {
+            IR::Distinct { input, options } => {
+                let input = *input;
+                let input_keys = unique_keys_rec(input, ir_arena, expr_arena, &mut unique_keys);
+                let is_redundant = match &options.subset {
+                    None => !input_keys.is_empty(),
+                    Some(subset) => input_keys
+                        .iter()
+                        .any(|key| key.iter().all(|k| subset.contains(k))),
+                };
+                if !is_redundant {
+                    continue;
+                }
+
+                let slice = options.slice;
+                let ir = match slice {
+                    None => ir_arena.take(input),
+                    Some((offset, len)) => IR::Slice {
+                        input,
+                        offset,
+                        len: len as _,
+                    },
+                };
+                ir_arena.replace(node, ir);
+            },
+            IR::Join {
+                input_left,
+                input_right,
+                schema,
+                right_on,
+                options,
+                ..
+            } if matches!(options.args.how, JoinType::Left)
+                && options.args.should_coalesce()
+                && options.args.slice.is_none()
+                && matches!(
+                    options.args.validation,
+                    JoinValidation::ManyToMany | JoinValidation::ManyToOne
+                ) =>
+            {
+                let (input_left, input_right) = (*input_left, *input_right);
+                // The right side must not add any columns to the output.
+                if schema.len() != ir_arena.get(input_left).schema(ir_arena).len() {
+                    continue;
+                }
+                let Some(right_on) = right_on
+                    .iter()
+                    .map(|e| into_column(e.node(), expr_arena).cloned())
+                    .collect::<Option<PlHashSet<_>>>()
+                else {
+                    continue;
+                };
+                // Every left row matches at most one right row, so the left join returns the left
+                // input as is.
+                let right_keys =
+                    unique_keys_rec(input_right, ir_arena, expr_arena, &mut unique_keys);
+                if right_keys
+                    .iter()
+                    .any(|key| key.iter().all(|k| right_on.contains(k)))
+                {
+                    let ir = ir_arena.take(input_left);
+                    ir_arena.replace(node, ir);
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+#[recursive::recursive]
+fn unique_keys_rec(
+    root: Node,
+    ir_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    unique_keys: &mut PlHashMap<Node, UniqueKeys>,
+) -> UniqueKeys {
+    if let Some(keys) = unique_keys.get(&root) {
+        return keys.clone();
+    }
+
+    macro_rules! rec {
+        ($node:expr) => {{ unique_keys_rec($node, ir_arena, expr_arena, unique_keys) }};
+    }
+
+    // Keep the keys of which all columns pass through unchanged, under their (new) names.
+    let rename = |keys: UniqueKeys, exprs: &[ExprIR]| -> UniqueKeys {
+        let renamed = exprs
+            .iter()
+            .filter_map(|e| Some((into_column(e.node(), expr_arena)?, e.output_name())))
+            .collect::<PlHashMap<_, _>>();
+        keys.iter()
+            .filter_map(|key| {
+                key.iter()
+                    .map(|k| renamed.get(k).map(|&name| name.clone()))
+                    .collect::<Option<Arc<[_]>>>()
+            })
+            .collect()
+    };
+
+    // @NOTE: Most of the below implementations are very conservative.
+    let keys: UniqueKeys = match ir_arena.get(root) {
+        IR::Filter { input, .. }
+        | IR::Slice { input, .. }
+        | IR::Sort { input, .. }
+        | IR::Cache { input, .. } => rec!(*input),
+        IR::SimpleProjection { input, columns } => rec!(*input)
+            .iter()
+            .filter(|key| key.iter().all(|k| columns.contains(k)))
+            .cloned()
+            .collect(),
+        IR::Select { input, expr, .. } => rename(rec!(*input), expr),
+        IR::HStack { input, exprs, .. } => rec!(*input)
+            .iter()
+            .filter(|key| {
+                key.iter()
+                    .all(|k| exprs.iter().all(|e| e.output_name() != k))
+            })
+            .cloned()
+            .collect(),
+        IR::GroupBy {
+            keys,
+            options,
+            apply: None,
+            ..
+        } if !keys.is_empty() && !options.is_rolling() && !options.is_dynamic() => {
+            [keys
+                .iter()
+                .map(|k| k.output_name().clone())
+                .collect::<Arc<[_]>>()]
+            .into()
+        },
+        IR::Distinct { input, options } => {
+            let subset = match &options.subset {
+                Some(subset) => subset.clone(),
+                None => ir_arena
+                    .get(*input)
+                    .schema(ir_arena)
+                    .iter_names_cloned()
+                    .collect(),
+            };
+            rec!(*input).iter().cloned().chain([subset]).collect()
+        },
+        IR::MapFunction { input, function } => match function {
+            FunctionIR::Hint(HintIR::Unique { keys, .. }) => {
+                rec!(*input).iter().cloned().chain([keys.clone()]).collect()
+            },
+            FunctionIR::Hint(_) => rec!(*input),
+            _ => Default::default(),
+        },
+        _ => Default::default(),
+    };
+
+    unique_keys.insert(root, keys.clone());
+    keys
+}
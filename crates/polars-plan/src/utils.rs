@@ -145,7 +145,10 @@ pub fn expr_output_name(expr: &Expr) -> PolarsResult<PlSmallStr> {
 
             #[cfg(feature = "dtype-struct")]
             Expr::Function {
-                function: FunctionExpr::StructExpr(StructFunction::SelectFields(_)),
+                function:
+                    FunctionExpr::StructExpr(
+                        StructFunction::SelectFields(_) | StructFunction::SelectPath(_),
+                    ),
                 ..
             } => break,
 
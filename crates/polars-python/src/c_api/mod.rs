@@ -179,6 +179,9 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::mean_horizontal))
         .unwrap();
+    #[cfg(feature = "rule_failures")]
+    m.add_wrapped(wrap_pyfunction!(functions::rule_failures))
+        .unwrap();
 
     // Functions - lazy
     m.add_wrapped(wrap_pyfunction!(functions::arg_sort_by))
@@ -208,12 +211,24 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::len)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::cov)).unwrap();
+    #[cfg(feature = "weighted")]
+    m.add_wrapped(wrap_pyfunction!(functions::weighted_mean))
+        .unwrap();
+    #[cfg(feature = "weighted")]
+    m.add_wrapped(wrap_pyfunction!(functions::weighted_var))
+        .unwrap();
+    #[cfg(feature = "weighted")]
+    m.add_wrapped(wrap_pyfunction!(functions::weighted_quantile))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::cum_fold))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::cum_reduce))
         .unwrap();
     #[cfg(feature = "trigonometry")]
     m.add_wrapped(wrap_pyfunction!(functions::arctan2)).unwrap();
+    #[cfg(feature = "geo")]
+    m.add_wrapped(wrap_pyfunction!(functions::st_point))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::datetime))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::concat_expr))
@@ -348,6 +363,8 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(testing::assert_dataframe_equal_py))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(testing::assert_dataframe_equal_by_key_py))
+        .unwrap();
 
     // Exceptions - Errors
     m.add("PolarsError", py.get_type::<exceptions::PolarsError>())
@@ -1,8 +1,16 @@
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use polars_dtype::categorical::{CatSize, Categories};
+use polars_error::PolarsResult;
+use polars_utils::mmap::MMapSemaphore;
+use polars_utils::{create_file, open_file};
+use pyo3::prelude::*;
 use pyo3::{pyclass, pymethods};
 
+use crate::utils::EnterPolarsExt;
+
 #[pyclass(frozen)]
 #[repr(transparent)]
 #[derive(Clone)]
@@ -70,6 +78,23 @@ impl PyCategories {
     pub fn is_global(&self) -> bool {
         self.categories.is_global()
     }
+
+    pub fn write_shared_mapping(&self, py: Python<'_>, path: PathBuf) -> PyResult<()> {
+        py.enter_polars(|| {
+            let mut writer = BufWriter::new(create_file(&path)?);
+            self.categories.write_shared_mapping(&mut writer)?;
+            writer.flush()?;
+            PolarsResult::Ok(())
+        })
+    }
+
+    pub fn attach_shared_mapping(&self, py: Python<'_>, path: PathBuf) -> PyResult<()> {
+        py.enter_polars(|| {
+            let mmap = MMapSemaphore::new_from_file(&open_file(&path)?)?;
+            self.categories.attach_shared_mapping(Arc::new(mmap))?;
+            PolarsResult::Ok(())
+        })
+    }
 }
 
 impl From<Arc<Categories>> for PyCategories {
@@ -884,6 +884,24 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<InterpolationMethod> {
     }
 }
 
+#[cfg(feature = "rule_failures")]
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<RuleFailuresOutput> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*(ob.extract::<PyBackedStr>()?) {
+            "bitmask" => RuleFailuresOutput::Bitmask,
+            "names" => RuleFailuresOutput::Names,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`output` must be one of {{'bitmask', 'names'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "avro")]
 impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<Option<AvroCompression>> {
     type Error = PyErr;
@@ -981,6 +999,25 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<CsvEncoding> {
     }
 }
 
+#[cfg(feature = "csv")]
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<ColumnNamePolicy> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "raise" => ColumnNamePolicy::Raise,
+            "suffix" => ColumnNamePolicy::Suffix,
+            "take_last" => ColumnNamePolicy::TakeLast,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`column_name_policy` must be one of {{'raise', 'suffix', 'take_last'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "ipc")]
 impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<Option<IpcCompression>> {
     type Error = PyErr;
@@ -1264,6 +1301,24 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<UniqueKeepStrategy> {
     }
 }
 
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<ExplodeLengthMismatch> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "raise" => ExplodeLengthMismatch::Error,
+            "pad" => ExplodeLengthMismatch::PadWithNull,
+            "truncate" => ExplodeLengthMismatch::Truncate,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`length_mismatch` must be one of {{'raise', 'pad', 'truncate'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "search_sorted")]
 impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<SearchSortedSide> {
     type Error = PyErr;
@@ -1389,6 +1444,26 @@ pub(crate) fn parse_cloud_options(
     Ok(out)
 }
 
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<ListZipOp> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "add" => ListZipOp::Add,
+            "sub" => ListZipOp::Sub,
+            "mul" => ListZipOp::Mul,
+            "min" => ListZipOp::Min,
+            "max" => ListZipOp::Max,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "list operation must be one of {{'add', 'sub', 'mul', 'min', 'max'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "list_sets")]
 impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<SetOperation> {
     type Error = PyErr;
@@ -1542,6 +1617,8 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<CastColumnsPolicy> {
             categorical_to_string,
             missing_struct_fields,
             extra_struct_fields,
+            pruned_struct_columns: None,
+            overridden_columns: None,
         }));
 
         fn parse_multiple_options(
@@ -1587,6 +1664,22 @@ pub(crate) fn parse_fill_null_strategy(
     Ok(parsed)
 }
 
+#[cfg(feature = "ipc")]
+pub(crate) fn parse_ipc_compression(
+    compression: Wrap<Option<IpcCompression>>,
+    compression_level: Option<i32>,
+) -> PyResult<Option<IpcCompression>> {
+    match (compression.0, compression_level) {
+        (Some(IpcCompression::ZSTD(_)), Some(lvl)) => Ok(Some(IpcCompression::ZSTD(
+            ZstdLevel::try_new(lvl).map_err(|e| PyValueError::new_err(format!("{e:?}")))?,
+        ))),
+        (Some(IpcCompression::LZ4), Some(_)) => Err(PyValueError::new_err(
+            "ipc `compression_level` is only supported with 'zstd' compression",
+        )),
+        (compression, _) => Ok(compression),
+    }
+}
+
 #[cfg(feature = "parquet")]
 pub(crate) fn parse_parquet_compression(
     compression: &str,
@@ -1769,6 +1862,25 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<MissingColumnsPolicy> {
     }
 }
 
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<SchemaUnificationPolicy> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "first_file" => SchemaUnificationPolicy::FirstFile,
+            "union" => SchemaUnificationPolicy::Union,
+            "strict" => SchemaUnificationPolicy::Strict,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "schema unification parameter must be one of \
+                    {{'first_file', 'union', 'strict'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<MissingColumnsPolicyOrExpr> {
     type Error = PyErr;
 
@@ -5,7 +5,7 @@ use either::Either;
 use parking_lot::RwLock;
 use polars::prelude::*;
 use polars_ffi::version_0::SeriesExport;
-use pyo3::exceptions::PyIndexError;
+use pyo3::exceptions::{PyIndexError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedStr;
 use pyo3::types::{PyList, PyType};
@@ -464,6 +464,88 @@ impl PyDataFrame {
         })
     }
 
+    pub fn diff_frames(
+        &self,
+        py: Python<'_>,
+        other: &PyDataFrame,
+        key_columns: Vec<PyBackedStr>,
+    ) -> PyResult<Self> {
+        let key_columns = strings_to_pl_smallstr(key_columns);
+        py.enter_polars_df(|| self.df.read().diff_frames(&other.df.read(), &key_columns))
+    }
+
+    pub fn drift_report(&self, py: Python<'_>, other: &PyDataFrame) -> PyResult<Self> {
+        py.enter_polars_df(|| self.df.read().drift_report(&other.df.read()))
+    }
+
+    pub fn match_funnel(
+        &self,
+        py: Python<'_>,
+        group_by: Vec<PyBackedStr>,
+        time_column: &str,
+        steps: Vec<PyBackedStr>,
+        within: Option<Wrap<AnyValue<'_>>>,
+    ) -> PyResult<Self> {
+        let group_by = strings_to_pl_smallstr(group_by);
+        let steps = strings_to_pl_smallstr(steps);
+        let options = FunnelOptions {
+            within: within.map(|w| {
+                let av = w.0.into_static();
+                Scalar::new(av.dtype(), av)
+            }),
+        };
+        py.enter_polars_df(|| {
+            self.df
+                .read()
+                .match_funnel(&group_by, time_column, &steps, &options)
+        })
+    }
+
+    pub fn connected_components(
+        &self,
+        py: Python<'_>,
+        source: &str,
+        target: &str,
+        degree: bool,
+    ) -> PyResult<Self> {
+        let options = ConnectedComponentsOptions { degree };
+        py.enter_polars_df(|| {
+            self.df
+                .read()
+                .connected_components(source, target, &options)
+        })
+    }
+
+    pub fn aggregate_neighbors(
+        &self,
+        py: Python<'_>,
+        key: &str,
+        neighbors: &str,
+        values: Vec<PyBackedStr>,
+        aggregation: &str,
+        k: Option<usize>,
+    ) -> PyResult<Self> {
+        let aggregation = match (aggregation, k) {
+            ("sum", _) => NeighborAggregation::Sum,
+            ("mean", _) => NeighborAggregation::Mean,
+            ("top_k", Some(k)) => NeighborAggregation::TopK(k),
+            ("top_k", None) => {
+                return Err(PyValueError::new_err("`k` must be set for the 'top_k' aggregation"));
+            },
+            (v, _) => {
+                return Err(PyValueError::new_err(format!(
+                    "`aggregation` must be one of {{'sum', 'mean', 'top_k'}}, got {v}",
+                )));
+            },
+        };
+        let values = strings_to_pl_smallstr(values);
+        py.enter_polars_df(|| {
+            self.df
+                .read()
+                .aggregate_neighbors(key, neighbors, &values, aggregation)
+        })
+    }
+
     pub fn null_count(&self, py: Python) -> PyResult<Self> {
         py.enter_polars_df(|| Ok(self.df.read().null_count()))
     }
@@ -20,7 +20,8 @@ impl PyDataFrame {
     #[staticmethod]
     #[cfg(feature = "csv")]
     #[pyo3(signature = (
-    py_f, infer_schema_length, chunk_size, has_header, ignore_errors, n_rows,
+    py_f, infer_schema_length, chunk_size, has_header, column_name_policy,
+    normalize_column_names, ignore_errors, n_rows,
     skip_rows, skip_lines, projection, separator, rechunk, columns, encoding, n_threads, path,
     overwrite_dtype, overwrite_dtype_slice, low_memory, comment_prefix, quote_char,
     null_values, missing_utf8_is_empty_string, try_parse_dates, skip_rows_after_header,
@@ -32,6 +33,8 @@ impl PyDataFrame {
         infer_schema_length: Option<usize>,
         chunk_size: usize,
         has_header: bool,
+        column_name_policy: Wrap<ColumnNamePolicy>,
+        normalize_column_names: bool,
         ignore_errors: bool,
         n_rows: Option<usize>,
         skip_rows: usize,
@@ -90,6 +93,8 @@ impl PyDataFrame {
                 .with_path(path)
                 .with_infer_schema_length(infer_schema_length)
                 .with_has_header(has_header)
+                .with_column_name_policy(column_name_policy.0)
+                .with_normalize_column_names(normalize_column_names)
                 .with_n_rows(n_rows)
                 .with_skip_rows(skip_rows)
                 .with_skip_lines(skip_lines)
@@ -3,13 +3,14 @@ use std::fmt::{Debug, Display, Formatter};
 use std::io::ErrorKind;
 
 use polars::prelude::PolarsError;
-use polars_error::PolarsWarning;
+use polars_error::{ErrorCode, ErrorDetail, PolarsWarning};
 use pyo3::PyTypeInfo;
 use pyo3::exceptions::{
     PyDeprecationWarning, PyFileExistsError, PyFileNotFoundError, PyIOError, PyPermissionError,
     PyRuntimeError, PyUserWarning,
 };
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 
 use crate::Wrap;
 use crate::exceptions::{
@@ -61,49 +62,14 @@ impl From<PyPolarsErr> for PyErr {
     fn from(err: PyPolarsErr) -> PyErr {
         use PyPolarsErr::*;
         match err {
-            Polars(err) => match err {
-                PolarsError::AssertionError(err) => {
-                    pyo3::exceptions::PyAssertionError::new_err(err.to_string())
-                },
-                PolarsError::ColumnNotFound(name) => ColumnNotFoundError::new_err(name.to_string()),
-                PolarsError::ComputeError(err) => ComputeError::new_err(err.to_string()),
-                PolarsError::Duplicate(err) => DuplicateError::new_err(err.to_string()),
-                PolarsError::InvalidOperation(err) => {
-                    InvalidOperationError::new_err(err.to_string())
-                },
-                PolarsError::IO { error, msg } => {
-                    let msg = if let Some(msg) = msg {
-                        msg.to_string()
-                    } else {
-                        error.to_string()
-                    };
-                    match error.kind() {
-                        ErrorKind::NotFound => PyFileNotFoundError::new_err(msg),
-                        ErrorKind::PermissionDenied => PyPermissionError::new_err(msg),
-                        ErrorKind::AlreadyExists => PyFileExistsError::new_err(msg),
-                        _ => PyIOError::new_err(msg),
-                    }
-                },
-                PolarsError::NoData(err) => NoDataError::new_err(err.to_string()),
-                PolarsError::OutOfBounds(err) => OutOfBoundsError::new_err(err.to_string()),
-                PolarsError::SQLInterface(name) => SQLInterfaceError::new_err(name.to_string()),
-                PolarsError::SQLSyntax(name) => SQLSyntaxError::new_err(name.to_string()),
-                PolarsError::SchemaFieldNotFound(name) => {
-                    SchemaFieldNotFoundError::new_err(name.to_string())
-                },
-                PolarsError::SchemaMismatch(err) => SchemaError::new_err(err.to_string()),
-                PolarsError::ShapeMismatch(err) => ShapeError::new_err(err.to_string()),
-                PolarsError::StringCacheMismatch(err) => {
-                    StringCacheMismatchError::new_err(err.to_string())
-                },
-                PolarsError::StructFieldNotFound(name) => {
-                    StructFieldNotFoundError::new_err(name.to_string())
-                },
-                PolarsError::Context { .. } => {
-                    let tmp = PyPolarsErr::Polars(err.context_trace());
-                    PyErr::from(tmp)
-                },
-                PolarsError::Python { error } => error.0,
+            Polars(err) => {
+                let code = err.code();
+                let detail = err.detail().cloned();
+                let py_err = polars_err_to_py(err);
+                if code != ErrorCode::Python {
+                    set_error_fields(&py_err, code, detail);
+                }
+                py_err
             },
             Python(err) => err,
             err => PyRuntimeError::new_err(format!("{:?}", &err)),
@@ -111,6 +77,102 @@ impl From<PyPolarsErr> for PyErr {
     }
 }
 
+fn polars_err_to_py(err: PolarsError) -> PyErr {
+    match err {
+        PolarsError::AssertionError(err) => {
+            pyo3::exceptions::PyAssertionError::new_err(err.to_string())
+        },
+        PolarsError::ColumnNotFound(name) => ColumnNotFoundError::new_err(name.to_string()),
+        PolarsError::ComputeError(err) => ComputeError::new_err(err.to_string()),
+        PolarsError::Duplicate(err) => DuplicateError::new_err(err.to_string()),
+        PolarsError::InvalidOperation(err) => InvalidOperationError::new_err(err.to_string()),
+        PolarsError::IO { error, msg } => {
+            let msg = if let Some(msg) = msg {
+                msg.to_string()
+            } else {
+                error.to_string()
+            };
+            match error.kind() {
+                ErrorKind::NotFound => PyFileNotFoundError::new_err(msg),
+                ErrorKind::PermissionDenied => PyPermissionError::new_err(msg),
+                ErrorKind::AlreadyExists => PyFileExistsError::new_err(msg),
+                _ => PyIOError::new_err(msg),
+            }
+        },
+        PolarsError::NoData(err) => NoDataError::new_err(err.to_string()),
+        PolarsError::OutOfBounds(err) => OutOfBoundsError::new_err(err.to_string()),
+        PolarsError::SQLInterface(name) => SQLInterfaceError::new_err(name.to_string()),
+        PolarsError::SQLSyntax(name) => SQLSyntaxError::new_err(name.to_string()),
+        PolarsError::SchemaFieldNotFound(name) => {
+            SchemaFieldNotFoundError::new_err(name.to_string())
+        },
+        PolarsError::SchemaMismatch(err) => SchemaError::new_err(err.to_string()),
+        PolarsError::ShapeMismatch(err) => ShapeError::new_err(err.to_string()),
+        PolarsError::StringCacheMismatch(err) => StringCacheMismatchError::new_err(err.to_string()),
+        PolarsError::StructFieldNotFound(name) => {
+            StructFieldNotFoundError::new_err(name.to_string())
+        },
+        PolarsError::Context { .. } => polars_err_to_py(err.context_trace()),
+        PolarsError::Python { error } => error.0,
+    }
+}
+
+/// Set the stable `code` of the error and its structured `detail`, if any, as attributes of the
+/// exception, such that they can be inspected without parsing the message.
+fn set_error_fields(err: &PyErr, code: ErrorCode, detail: Option<ErrorDetail>) {
+    let _ = Python::attach(|py| {
+        let value = err.value(py);
+        let detail = detail.map(|detail| error_detail_to_dict(py, detail)).transpose()?;
+        value.setattr("code", code.as_str())?;
+        value.setattr("detail", detail)
+    });
+}
+
+fn error_detail_to_dict(py: Python<'_>, detail: ErrorDetail) -> PyResult<Bound<'_, PyDict>> {
+    let dict = PyDict::new(py);
+    match detail {
+        ErrorDetail::SchemaMismatch {
+            column,
+            expected,
+            found,
+        } => {
+            dict.set_item("kind", "schema_mismatch")?;
+            dict.set_item("column", column)?;
+            dict.set_item("expected", expected)?;
+            dict.set_item("found", found)?;
+        },
+        ErrorDetail::ColumnNotFound { name, similar } => {
+            dict.set_item("kind", "column_not_found")?;
+            dict.set_item("name", name)?;
+            dict.set_item("similar", similar)?;
+        },
+        ErrorDetail::DuplicateColumn { name } => {
+            dict.set_item("kind", "duplicate_column")?;
+            dict.set_item("name", name)?;
+        },
+        ErrorDetail::OutOfBounds { index, len } => {
+            dict.set_item("kind", "out_of_bounds")?;
+            dict.set_item("index", index)?;
+            dict.set_item("length", len)?;
+        },
+        ErrorDetail::CastFailed {
+            column,
+            from,
+            to,
+            rows,
+            values,
+        } => {
+            dict.set_item("kind", "cast_failed")?;
+            dict.set_item("column", column)?;
+            dict.set_item("from", from)?;
+            dict.set_item("to", to)?;
+            dict.set_item("rows", rows)?;
+            dict.set_item("values", values)?;
+        },
+    }
+    Ok(dict)
+}
+
 impl Debug for PyPolarsErr {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         use PyPolarsErr::*;
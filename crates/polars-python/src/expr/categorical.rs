@@ -1,3 +1,4 @@
+use polars::prelude::PlSmallStr;
 use pyo3::prelude::*;
 
 use crate::PyExpr;
@@ -8,6 +9,20 @@ impl PyExpr {
         self.inner.clone().cat().get_categories().into()
     }
 
+    fn cat_extend_categories(&self, categories: Vec<String>) -> Self {
+        let categories = categories.into_iter().map(PlSmallStr::from_string).collect();
+        self.inner.clone().cat().extend_categories(categories).into()
+    }
+
+    fn cat_cast_with_added_categories(&self, categories: Vec<String>) -> Self {
+        let categories = categories.into_iter().map(PlSmallStr::from_string).collect();
+        self.inner
+            .clone()
+            .cat()
+            .cast_with_added_categories(categories)
+            .into()
+    }
+
     fn cat_len_bytes(&self) -> Self {
         self.inner.clone().cat().len_bytes().into()
     }
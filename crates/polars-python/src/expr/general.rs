@@ -283,6 +283,9 @@ impl PyExpr {
         let expr = self.inner.clone().cast_with_options(dtype.inner, options);
         expr.into()
     }
+    fn try_cast(&self, dtype: PyDataTypeExpr) -> Self {
+        self.inner.clone().try_cast(dtype.inner).into()
+    }
     fn sort_with(&self, descending: bool, nulls_last: bool) -> Self {
         self.inner
             .clone()
@@ -444,6 +447,14 @@ impl PyExpr {
             .into()
     }
 
+    fn is_outlier_mad(&self, threshold: f64) -> Self {
+        self.inner.clone().is_outlier_mad(threshold).into()
+    }
+
+    fn is_outlier_iqr(&self, k: f64) -> Self {
+        self.inner.clone().is_outlier_iqr(k).into()
+    }
+
     fn is_close(&self, other: Self, abs_tol: f64, rel_tol: f64, nans_equal: bool) -> Self {
         self.inner
             .clone()
@@ -616,8 +627,8 @@ impl PyExpr {
         &self,
         partition_by: Option<Vec<Self>>,
         order_by: Option<Vec<Self>>,
-        order_by_descending: bool,
-        order_by_nulls_last: bool,
+        order_by_descending: Vec<bool>,
+        order_by_nulls_last: Vec<bool>,
         mapping_strategy: Wrap<WindowMapping>,
     ) -> PyResult<Self> {
         let partition_by = partition_by.map(|partition_by| {
@@ -630,7 +641,7 @@ impl PyExpr {
         let order_by = order_by.map(|order_by| {
             (
                 order_by.into_iter().map(|e| e.inner).collect::<Vec<Expr>>(),
-                SortOptions {
+                SortMultipleOptions {
                     descending: order_by_descending,
                     nulls_last: order_by_nulls_last,
                     maintain_order: false,
@@ -642,7 +653,7 @@ impl PyExpr {
         Ok(self
             .inner
             .clone()
-            .over_with_options(partition_by, order_by, mapping_strategy.0)
+            .over_with_multi_options(partition_by, order_by, mapping_strategy.0)
             .map_err(PyPolarsErr::from)?
             .into())
     }
@@ -769,6 +780,12 @@ impl PyExpr {
         self.inner.clone().pct_change(n.inner).into()
     }
 
+    #[cfg(feature = "pct_change_by")]
+    fn pct_change_by(&self, by: Self, offset: &str) -> PyResult<Self> {
+        let offset = Duration::try_parse(offset).map_err(PyPolarsErr::from)?;
+        Ok(self.inner.clone().pct_change_by(by.inner, offset).into())
+    }
+
     fn skew(&self, bias: bool) -> Self {
         self.inner.clone().skew(bias).into()
     }
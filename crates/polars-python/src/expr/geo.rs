@@ -0,0 +1,34 @@
+use pyo3::prelude::*;
+
+use crate::PyExpr;
+
+#[pymethods]
+impl PyExpr {
+    fn st_x(&self) -> Self {
+        self.inner.clone().st().x().into()
+    }
+
+    fn st_y(&self) -> Self {
+        self.inner.clone().st().y().into()
+    }
+
+    fn st_distance(&self, other: Self) -> Self {
+        self.inner.clone().st().distance(other.inner).into()
+    }
+
+    fn st_contains(&self, other: Self) -> Self {
+        self.inner.clone().st().contains(other.inner).into()
+    }
+
+    fn st_within(&self, other: Self) -> Self {
+        self.inner.clone().st().within(other.inner).into()
+    }
+
+    fn st_intersects_bbox(&self, xmin: Self, ymin: Self, xmax: Self, ymax: Self) -> Self {
+        self.inner
+            .clone()
+            .st()
+            .intersects_bbox(xmin.inner, ymin.inner, xmax.inner, ymax.inner)
+            .into()
+    }
+}
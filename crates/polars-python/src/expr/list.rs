@@ -45,6 +45,18 @@ impl PyExpr {
         Ok(self.inner.clone().list().diff(n, null_behavior.0).into())
     }
 
+    fn list_rolling_sum(&self, window_size: usize) -> Self {
+        self.inner.clone().list().rolling_sum(window_size).into()
+    }
+
+    fn list_rolling_mean(&self, window_size: usize) -> Self {
+        self.inner.clone().list().rolling_mean(window_size).into()
+    }
+
+    fn list_zip_with(&self, other: PyExpr, op: Wrap<ListZipOp>) -> Self {
+        self.inner.clone().list().zip_with(other.inner, op.0).into()
+    }
+
     fn list_eval(&self, expr: PyExpr, _parallel: bool) -> Self {
         self.inner.clone().list().eval(expr.inner).into()
     }
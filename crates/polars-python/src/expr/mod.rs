@@ -13,12 +13,16 @@ mod datetime;
 mod extension;
 #[cfg(feature = "pymethods")]
 mod general;
+#[cfg(all(feature = "geo", feature = "pymethods"))]
+mod geo;
 #[cfg(feature = "pymethods")]
 mod list;
 #[cfg(all(feature = "meta", feature = "pymethods"))]
 mod meta;
 #[cfg(feature = "pymethods")]
 mod name;
+#[cfg(all(feature = "quant", feature = "pymethods"))]
+mod quant;
 #[cfg(feature = "pymethods")]
 mod rolling;
 pub mod selector;
@@ -0,0 +1,22 @@
+use pyo3::prelude::*;
+
+use crate::PyExpr;
+
+#[pymethods]
+impl PyExpr {
+    fn quant_drawdown(&self) -> Self {
+        self.inner.clone().quant().drawdown().into()
+    }
+
+    fn quant_max_drawdown(&self) -> Self {
+        self.inner.clone().quant().max_drawdown().into()
+    }
+
+    fn quant_hit_ratio(&self) -> Self {
+        self.inner.clone().quant().hit_ratio().into()
+    }
+
+    fn quant_sharpe(&self, annualization: f64) -> Self {
+        self.inner.clone().quant().sharpe(annualization).into()
+    }
+}
@@ -410,6 +410,45 @@ impl PyExpr {
         self.inner.clone().rolling_kurtosis(options).into()
     }
 
+    #[pyo3(signature = (threshold, window_size, min_periods, center))]
+    fn rolling_is_outlier_mad(
+        &self,
+        threshold: f64,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner
+            .clone()
+            .rolling_is_outlier_mad(threshold, options)
+            .into()
+    }
+
+    #[pyo3(signature = (k, window_size, min_periods, center))]
+    fn rolling_is_outlier_iqr(
+        &self,
+        k: f64,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_is_outlier_iqr(k, options).into()
+    }
+
     #[pyo3(signature = (lambda, window_size, weights, min_periods, center))]
     fn rolling_map(
         &self,
@@ -3,10 +3,11 @@ use std::borrow::Cow;
 use std::hash::{BuildHasher, Hash, Hasher};
 use std::sync::Arc;
 
-use polars::prelude::PlFixedStateQuality;
 use polars::prelude::extension::{register_extension_type, unregister_extension_type};
+use polars::prelude::{PlFixedStateQuality, PolarsResult, Series};
 use polars_core::datatypes::DataType;
 use polars_core::datatypes::extension::{ExtensionTypeFactory, ExtensionTypeImpl};
+use pyo3::intern;
 use pyo3::prelude::*;
 
 use crate::prelude::Wrap;
@@ -21,6 +22,20 @@ struct PyExtensionTypeImpl {
     name: String,
     display: String,
     metadata: Option<String>,
+    /// The instance of the Python extension class, used for validation and casting rules.
+    typ_obj: Arc<Py<PyAny>>,
+}
+
+impl PyExtensionTypeImpl {
+    fn can_cast(&self, method: &str, dtype: &DataType) -> bool {
+        Python::attach(|py| {
+            self.typ_obj
+                .bind(py)
+                .call_method1(method, (&Wrap(dtype.clone()),))
+                .and_then(|out| out.extract())
+                .unwrap_or(false)
+        })
+    }
 }
 
 impl ExtensionTypeFactory for PyExtensionTypeFactory {
@@ -52,6 +67,7 @@ impl ExtensionTypeFactory for PyExtensionTypeFactory {
                 name: name.to_string(),
                 display,
                 metadata,
+                typ_obj: Arc::new(typ_obj.unbind()),
             })
         })
     }
@@ -99,6 +115,24 @@ impl ExtensionTypeImpl for PyExtensionTypeImpl {
             Cow::Owned(format!("PyExtensionType(name='{}')", self.name))
         }
     }
+
+    fn validate(&self, storage: &Series) -> PolarsResult<()> {
+        Python::attach(|py| {
+            self.typ_obj.bind(py).call_method1(
+                intern!(py, "ext_validate"),
+                (Wrap(storage.clone()).into_pyobject(py)?,),
+            )?;
+            Ok(())
+        })
+    }
+
+    fn can_cast_from(&self, dtype: &DataType) -> bool {
+        self.can_cast("ext_can_cast_from", dtype)
+    }
+
+    fn can_cast_to(&self, dtype: &DataType) -> bool {
+        self.can_cast("ext_can_cast_to", dtype)
+    }
 }
 
 #[pyfunction]
@@ -1,7 +1,11 @@
 use polars::lazy::dsl;
+#[cfg(feature = "rule_failures")]
+use polars::prelude::RuleFailuresOutput;
 use pyo3::prelude::*;
 
 use crate::PyExpr;
+#[cfg(feature = "rule_failures")]
+use crate::conversion::Wrap;
 use crate::error::PyPolarsErr;
 use crate::expr::ToExprs;
 
@@ -46,3 +50,11 @@ pub fn mean_horizontal(exprs: Vec<PyExpr>, ignore_nulls: bool) -> PyResult<PyExp
     let e = dsl::mean_horizontal(exprs, ignore_nulls).map_err(PyPolarsErr::from)?;
     Ok(e.into())
 }
+
+#[cfg(feature = "rule_failures")]
+#[pyfunction]
+pub fn rule_failures(rules: Vec<PyExpr>, output: Wrap<RuleFailuresOutput>) -> PyResult<PyExpr> {
+    let rules = rules.to_exprs();
+    let e = dsl::rule_failures(rules, output.0).map_err(PyPolarsErr::from)?;
+    Ok(e.into())
+}
@@ -126,6 +126,22 @@ fn lfs_to_plans(lfs: Vec<PyLazyFrame>) -> Vec<DslPlan> {
         .collect()
 }
 
+/// The frames are collected together on the thread pool of the first frame that has one, see
+/// `LazyFrame::with_thread_pool`.
+fn thread_pool_of(lfs: &[PyLazyFrame]) -> Option<Arc<rayon::ThreadPool>> {
+    lfs.iter().find_map(|lf| lf.ldf.read().thread_pool().cloned())
+}
+
+fn install_thread_pool<R: Send>(
+    pool: Option<Arc<rayon::ThreadPool>>,
+    op: impl FnOnce() -> R + Send,
+) -> R {
+    match pool {
+        Some(pool) => polars_core::POOL.install_scoped(&pool, op),
+        None => op(),
+    }
+}
+
 #[pyfunction]
 pub fn collect_all(
     lfs: Vec<PyLazyFrame>,
@@ -133,9 +149,12 @@ pub fn collect_all(
     optflags: PyOptFlags,
     py: Python<'_>,
 ) -> PyResult<Vec<PyDataFrame>> {
+    let pool = thread_pool_of(&lfs);
     let plans = lfs_to_plans(lfs);
     let dfs = py.enter_polars(|| {
-        LazyFrame::collect_all_with_engine(plans, engine.0, optflags.inner.into_inner())
+        install_thread_pool(pool, || {
+            LazyFrame::collect_all_with_engine(plans, engine.0, optflags.inner.into_inner())
+        })
     })?;
     Ok(dfs.into_iter().map(Into::into).collect())
 }
@@ -156,13 +175,13 @@ pub fn collect_all_with_callback(
     lambda: Py<PyAny>,
     py: Python<'_>,
 ) {
-    let plans = lfs
-        .into_iter()
-        .map(|lf| lf.ldf.into_inner().logical_plan)
-        .collect();
+    let pool = thread_pool_of(&lfs);
+    let plans = lfs_to_plans(lfs);
     let result = py
         .enter_polars(|| {
-            LazyFrame::collect_all_with_engine(plans, engine.0, optflags.inner.into_inner())
+            install_thread_pool(pool, || {
+                LazyFrame::collect_all_with_engine(plans, engine.0, optflags.inner.into_inner())
+            })
         })
         .map(|dfs| {
             dfs.into_iter()
@@ -184,12 +203,14 @@ pub fn collect_all_with_callback(
 }
 
 #[pyfunction]
+#[pyo3(signature = (seq, rechunk, parallel, to_supertypes, maintain_order, by_name=false))]
 pub fn concat_lf(
     seq: &Bound<'_, PyAny>,
     rechunk: bool,
     parallel: bool,
     to_supertypes: bool,
     maintain_order: bool,
+    by_name: bool,
 ) -> PyResult<PyLazyFrame> {
     let len = seq.len()?;
     let mut lfs = Vec::with_capacity(len);
@@ -207,6 +228,7 @@ pub fn concat_lf(
             parallel,
             to_supertypes,
             maintain_order,
+            by_name,
             ..Default::default()
         },
     )
@@ -244,12 +266,36 @@ pub fn cov(a: PyExpr, b: PyExpr, ddof: u8) -> PyExpr {
     dsl::cov(a.inner, b.inner, ddof).into()
 }
 
+#[pyfunction]
+#[cfg(feature = "weighted")]
+pub fn weighted_mean(values: PyExpr, weights: PyExpr) -> PyExpr {
+    dsl::weighted_mean(values.inner, weights.inner).into()
+}
+
+#[pyfunction]
+#[cfg(feature = "weighted")]
+pub fn weighted_var(values: PyExpr, weights: PyExpr, ddof: u8) -> PyExpr {
+    dsl::weighted_var(values.inner, weights.inner, ddof).into()
+}
+
+#[pyfunction]
+#[cfg(feature = "weighted")]
+pub fn weighted_quantile(values: PyExpr, weights: PyExpr, quantile: f64) -> PyExpr {
+    dsl::weighted_quantile(values.inner, weights.inner, quantile).into()
+}
+
 #[pyfunction]
 #[cfg(feature = "trigonometry")]
 pub fn arctan2(y: PyExpr, x: PyExpr) -> PyExpr {
     y.inner.arctan2(x.inner).into()
 }
 
+#[pyfunction]
+#[cfg(feature = "geo")]
+pub fn st_point(x: PyExpr, y: PyExpr) -> PyExpr {
+    dsl::st_point(x.inner, y.inner).into()
+}
+
 #[pyfunction]
 pub fn cum_fold(
     acc: PyExpr,
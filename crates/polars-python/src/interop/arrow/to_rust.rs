@@ -30,6 +30,15 @@ pub fn pyarrow_schema_to_rust(obj: &Bound<'_, PyList>) -> PyResult<Schema> {
 }
 
 pub fn array_to_rust(obj: &Bound<PyAny>) -> PyResult<ArrayRef> {
+    array_to_rust_with_dtype(obj, None)
+}
+
+/// Import an array, taking its (nested) extension types from `dtype` if the
+/// exported array only carries their storage types.
+pub fn array_to_rust_with_dtype(
+    obj: &Bound<PyAny>,
+    dtype: Option<&ArrowDataType>,
+) -> PyResult<ArrayRef> {
     // prepare a pointer to receive the Array struct
     let mut array = Box::new(ffi::ArrowArray::empty());
     let mut schema = Box::new(ffi::ArrowSchema::empty());
@@ -46,11 +55,37 @@ pub fn array_to_rust(obj: &Bound<PyAny>) -> PyResult<ArrayRef> {
 
     unsafe {
         let field = ffi::import_field_from_c(schema.as_ref()).map_err(PyPolarsErr::from)?;
-        let array = ffi::import_array_from_c(*array, field.dtype).map_err(PyPolarsErr::from)?;
+        let dtype = match dtype {
+            Some(dtype) if has_storage(dtype, &field.dtype) => dtype.clone(),
+            _ => field.dtype,
+        };
+        let array = ffi::import_array_from_c(*array, dtype).map_err(PyPolarsErr::from)?;
         Ok(array)
     }
 }
 
+/// Whether `dtype` equals `storage` once all its extension types are replaced
+/// by their storage types.
+fn has_storage(dtype: &ArrowDataType, storage: &ArrowDataType) -> bool {
+    use ArrowDataType as D;
+    match (dtype, storage) {
+        (D::Extension(ext), _) => has_storage(&ext.inner, storage),
+        (D::List(l), D::List(r)) | (D::LargeList(l), D::LargeList(r)) => {
+            has_storage(&l.dtype, &r.dtype)
+        },
+        (D::FixedSizeList(l, l_width), D::FixedSizeList(r, r_width)) => {
+            l_width == r_width && has_storage(&l.dtype, &r.dtype)
+        },
+        (D::Struct(l), D::Struct(r)) => {
+            l.len() == r.len()
+                && l.iter().zip(r).all(|(l, r)| {
+                    l.name == r.name && has_storage(&l.dtype, &r.dtype)
+                })
+        },
+        _ => dtype == storage,
+    }
+}
+
 pub fn to_rust_df(
     py: Python<'_>,
     rb: &[Bound<PyAny>],
@@ -107,16 +142,10 @@ pub fn to_rust_df(
             let columns = (0..schema.len())
                 .map(|i| {
                     let array = rb.call_method1("column", (i,))?;
-                    let mut arr = array_to_rust(&array)?;
-
-                    // Only the schema contains extension type info, restore.
-                    // TODO: nested?
+                    // Only the schema contains extension type info, restore it
+                    // (including for nested fields).
                     let dtype = schema.get_at_index(i).unwrap().1.dtype();
-                    if let ArrowDataType::Extension(ext) = dtype {
-                        if *arr.dtype() == ext.inner {
-                            *arr.dtype_mut() = dtype.clone();
-                        }
-                    }
+                    let arr = array_to_rust_with_dtype(&array, Some(dtype))?;
 
                     run_parallel |= matches!(
                         arr.dtype(),
@@ -4,7 +4,7 @@ use polars::prelude::default_values::DefaultFieldValues;
 use polars::prelude::deletion::DeletionFilesList;
 use polars::prelude::{
     CastColumnsPolicy, CloudScheme, ColumnMapping, ExtraColumnsPolicy, MissingColumnsPolicy,
-    PlSmallStr, Schema, TableStatistics, UnifiedScanArgs,
+    PlSmallStr, Schema, SchemaUnificationPolicy, TableStatistics, UnifiedScanArgs,
 };
 use polars_io::{HiveOptions, RowIndex};
 use polars_utils::IdxSize;
@@ -52,6 +52,7 @@ impl PyScanOptions<'_> {
             cast_options: Wrap<CastColumnsPolicy>,
             extra_columns: Wrap<ExtraColumnsPolicy>,
             missing_columns: Wrap<MissingColumnsPolicy>,
+            schema_unification: Wrap<SchemaUnificationPolicy>,
             include_file_paths: Option<Wrap<PlSmallStr>>,
             glob: bool,
             hidden_file_prefix: Option<Vec<PyBackedStr>>,
@@ -76,6 +77,7 @@ impl PyScanOptions<'_> {
             cast_options,
             extra_columns,
             missing_columns,
+            schema_unification,
             include_file_paths,
             column_mapping,
             default_values,
@@ -132,6 +134,7 @@ impl PyScanOptions<'_> {
             cast_columns_policy: cast_options.0,
             missing_columns_policy: missing_columns.0,
             extra_columns_policy: extra_columns.0,
+            schema_unification_policy: schema_unification.0,
             include_file_paths: include_file_paths.map(|x| x.0),
             deletion_files: DeletionFilesList::filter_empty(deletion_files.map(|x| x.0)),
             table_statistics: table_statistics.map(|x| x.0),
@@ -4,6 +4,8 @@ use std::num::NonZeroUsize;
 use either::Either;
 use parking_lot::Mutex;
 use polars::io::RowIndex;
+#[cfg(feature = "http_json")]
+use polars::io::http_json::{HttpJsonScanOptions, HttpPagination};
 use polars::time::*;
 use polars_core::prelude::*;
 #[cfg(feature = "parquet")]
@@ -45,6 +47,31 @@ fn pyobject_to_first_path_and_scan_sources(
     })
 }
 
+#[cfg(any(feature = "csv", feature = "json"))]
+fn follow_options((poll_interval, detect_rotation): (f64, bool)) -> FollowOptions {
+    FollowOptions {
+        poll_interval: std::time::Duration::from_secs_f64(poll_interval),
+        detect_rotation,
+    }
+}
+
+#[cfg(feature = "http_json")]
+fn http_pagination(
+    (kind, param, limit_param, number): (String, Option<String>, Option<String>, Option<u64>),
+) -> PyResult<HttpPagination> {
+    Ok(match (kind.as_str(), param, limit_param, number) {
+        ("next_url", Some(pointer), _, _) => HttpPagination::NextUrl { pointer },
+        ("link_header", ..) => HttpPagination::LinkHeader,
+        ("page", Some(param), _, Some(start)) => HttpPagination::PageNumber { param, start },
+        ("offset", Some(offset_param), Some(limit_param), Some(limit)) => HttpPagination::Offset {
+            offset_param,
+            limit_param,
+            limit,
+        },
+        _ => return Err(PyValueError::new_err(format!("invalid pagination: {kind}"))),
+    })
+}
+
 fn post_opt_callback(
     lambda: &Py<PyAny>,
     root: Node,
@@ -82,7 +109,8 @@ impl PyLazyFrame {
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (
         source, sources, infer_schema_length, schema, schema_overrides, batch_size, n_rows, low_memory, rechunk,
-        row_index, ignore_errors, include_file_paths, cloud_options, credential_provider, retries, file_cache_ttl
+        row_index, ignore_errors, include_file_paths, cloud_options, credential_provider, retries, file_cache_ttl,
+        follow
     ))]
     fn new_from_ndjson(
         source: Option<Py<PyAny>>,
@@ -101,6 +129,7 @@ impl PyLazyFrame {
         credential_provider: Option<Py<PyAny>>,
         retries: usize,
         file_cache_ttl: Option<u64>,
+        follow: Option<(f64, bool)>,
     ) -> PyResult<Self> {
         use cloud::credential_provider::PlCredentialProvider;
         let row_index = row_index.map(|(name, offset)| RowIndex {
@@ -148,6 +177,7 @@ impl PyLazyFrame {
             .with_row_index(row_index)
             .with_ignore_errors(ignore_errors)
             .with_include_file_paths(include_file_paths.map(|x| x.into()))
+            .with_follow(follow.map(follow_options))
             .finish()
             .map_err(PyPolarsErr::from)?;
 
@@ -156,11 +186,12 @@ impl PyLazyFrame {
 
     #[staticmethod]
     #[cfg(feature = "csv")]
-    #[pyo3(signature = (source, sources, separator, has_header, ignore_errors, skip_rows, skip_lines, n_rows, cache, overwrite_dtype,
+    #[pyo3(signature = (source, sources, separator, has_header, column_name_policy,
+        normalize_column_names, ignore_errors, skip_rows, skip_lines, n_rows, cache, overwrite_dtype,
         low_memory, comment_prefix, quote_char, null_values, missing_utf8_is_empty_string,
         infer_schema_length, with_schema_modify, rechunk, skip_rows_after_header,
         encoding, row_index, try_parse_dates, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, glob, schema,
-        cloud_options, credential_provider, retries, file_cache_ttl, include_file_paths
+        cloud_options, credential_provider, retries, file_cache_ttl, include_file_paths, follow
     )
     )]
     fn new_from_csv(
@@ -168,6 +199,8 @@ impl PyLazyFrame {
         sources: Wrap<ScanSources>,
         separator: &str,
         has_header: bool,
+        column_name_policy: Wrap<ColumnNamePolicy>,
+        normalize_column_names: bool,
         ignore_errors: bool,
         skip_rows: usize,
         skip_lines: usize,
@@ -197,6 +230,7 @@ impl PyLazyFrame {
         retries: usize,
         file_cache_ttl: Option<u64>,
         include_file_paths: Option<String>,
+        follow: Option<(f64, bool)>,
     ) -> PyResult<Self> {
         #[cfg(feature = "cloud")]
         use cloud::credential_provider::PlCredentialProvider;
@@ -258,6 +292,8 @@ impl PyLazyFrame {
             .with_infer_schema_length(infer_schema_length)
             .with_separator(separator)
             .with_has_header(has_header)
+            .with_column_name_policy(column_name_policy.0)
+            .with_normalize_column_names(normalize_column_names)
             .with_ignore_errors(ignore_errors)
             .with_skip_rows(skip_rows)
             .with_skip_lines(skip_lines)
@@ -280,7 +316,8 @@ impl PyLazyFrame {
             .with_decimal_comma(decimal_comma)
             .with_glob(glob)
             .with_raise_if_empty(raise_if_empty)
-            .with_include_file_paths(include_file_paths.map(|x| x.into()));
+            .with_include_file_paths(include_file_paths.map(|x| x.into()))
+            .with_follow(follow.map(follow_options));
 
         if let Some(lambda) = with_schema_modify {
             let f = |schema: Schema| {
@@ -311,11 +348,12 @@ impl PyLazyFrame {
     #[cfg(feature = "parquet")]
     #[staticmethod]
     #[pyo3(signature = (
-        sources, schema, scan_options, parallel, low_memory, use_statistics
+        sources, schema, schema_overrides, scan_options, parallel, low_memory, use_statistics
     ))]
     fn new_from_parquet(
         sources: Wrap<ScanSources>,
         schema: Option<Wrap<Schema>>,
+        schema_overrides: Option<Wrap<Schema>>,
         scan_options: PyScanOptions,
         parallel: Wrap<ParallelStrategy>,
         low_memory: bool,
@@ -327,6 +365,7 @@ impl PyLazyFrame {
 
         let options = ParquetOptions {
             schema: schema.map(|x| Arc::new(x.0)),
+            schema_overwrite: schema_overrides.map(|x| Arc::new(x.0)),
             parallel,
             low_memory,
             use_statistics,
@@ -381,6 +420,34 @@ impl PyLazyFrame {
         Ok(lf.into())
     }
 
+    #[cfg(feature = "http_json")]
+    #[staticmethod]
+    #[pyo3(signature = (
+        url, pagination, records_pointer, headers, schema, infer_schema_length, max_pages
+    ))]
+    fn new_from_http_json(
+        py: Python<'_>,
+        url: String,
+        pagination: Option<(String, Option<String>, Option<String>, Option<u64>)>,
+        records_pointer: Option<String>,
+        headers: Option<Vec<(String, String)>>,
+        schema: Option<Wrap<Schema>>,
+        infer_schema_length: Option<usize>,
+        max_pages: Option<usize>,
+    ) -> PyResult<Self> {
+        let options = HttpJsonScanOptions {
+            pagination: pagination.map(http_pagination).transpose()?.unwrap_or_default(),
+            records_pointer,
+            headers: headers.unwrap_or_default(),
+            schema: schema.map(|schema| Arc::new(schema.0)),
+            infer_schema_length: infer_schema_length.and_then(NonZeroUsize::new),
+            max_pages,
+            ..HttpJsonScanOptions::new(&url, HttpPagination::None)
+        };
+        let lf = py.enter_polars(|| LazyFrame::scan_http_json(options))?;
+        Ok(lf.into())
+    }
+
     #[cfg(feature = "scan_lines")]
     #[staticmethod]
     #[pyo3(signature = (sources, scan_options, name, file_cache_ttl))]
@@ -503,6 +570,38 @@ impl PyLazyFrame {
         py.enter_polars(|| self.ldf.read().describe_optimized_plan_tree())
     }
 
+    fn describe_optimized_plan_with_pruning_report(
+        &self,
+        py: Python,
+        tree_format: bool,
+    ) -> PyResult<String> {
+        py.enter_polars(|| {
+            self.ldf
+                .read()
+                .describe_optimized_plan_with_pruning_report(tree_format)
+        })
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn pruning_report(
+        &self,
+        py: Python,
+    ) -> PyResult<Vec<(Option<String>, usize, Vec<String>, bool)>> {
+        let report = py.enter_polars(|| self.ldf.read().clone().pruning_report())?;
+        Ok(report
+            .sources
+            .into_iter()
+            .map(|source| {
+                (
+                    source.source.map(String::from),
+                    source.n_columns,
+                    source.columns.into_iter().map(String::from).collect(),
+                    source.unused,
+                )
+            })
+            .collect())
+    }
+
     fn to_dot(&self, py: Python<'_>, optimized: bool) -> PyResult<String> {
         py.enter_polars(|| self.ldf.read().to_dot(optimized))
     }
@@ -590,6 +689,12 @@ impl PyLazyFrame {
         ldf.with_optimizations(optflags.inner.into_inner()).into()
     }
 
+    fn with_max_threads(&self, py: Python<'_>, n: NonZeroUsize) -> PyResult<Self> {
+        let ldf = self.ldf.read().clone();
+        let ldf = py.enter_polars(|| ldf.with_max_threads(n))?;
+        Ok(ldf.into())
+    }
+
     #[pyo3(signature = (lambda_post_opt))]
     fn profile(
         &self,
@@ -628,6 +733,29 @@ impl PyLazyFrame {
         })
     }
 
+    #[cfg(feature = "checkpoint")]
+    #[pyo3(signature = (path, interval, resume))]
+    fn collect_with_checkpoint(
+        &self,
+        py: Python<'_>,
+        path: std::path::PathBuf,
+        interval: f64,
+        resume: bool,
+    ) -> PyResult<PyDataFrame> {
+        let options = CheckpointOptions {
+            dir: path,
+            interval: std::time::Duration::from_secs_f64(interval),
+        };
+        py.enter_polars_df(|| {
+            let ldf = self.ldf.read().clone();
+            if resume {
+                ldf.resume_from_checkpoint(options)
+            } else {
+                ldf.collect_with_checkpoint(options)
+            }
+        })
+    }
+
     #[cfg(feature = "async")]
     #[pyo3(signature = (engine, lambda))]
     fn collect_with_callback(
@@ -728,7 +856,8 @@ impl PyLazyFrame {
 
     #[cfg(feature = "ipc")]
     #[pyo3(signature = (
-        target, sink_options, compression, compat_level, record_batch_size
+        target, sink_options, compression, compression_level, zstd_dictionary_size, compat_level,
+        record_batch_size
     ))]
     fn sink_ipc(
         &self,
@@ -736,13 +865,16 @@ impl PyLazyFrame {
         target: PyFileSinkDestination,
         sink_options: PySinkOptions,
         compression: Wrap<Option<IpcCompression>>,
+        compression_level: Option<i32>,
+        zstd_dictionary_size: Option<usize>,
         compat_level: PyCompatLevel,
         record_batch_size: Option<usize>,
     ) -> PyResult<PyLazyFrame> {
         let options = IpcWriterOptions {
-            compression: compression.0,
+            compression: parse_ipc_compression(compression, compression_level)?,
             compat_level: compat_level.0,
             record_batch_size,
+            zstd_dictionary_size,
             ..Default::default()
         };
 
@@ -880,6 +1012,13 @@ impl PyLazyFrame {
         ldf.remove(predicate.inner).into()
     }
 
+    #[pyo3(signature = (predicate, reason))]
+    fn filter_partition(&self, predicate: PyExpr, reason: Option<String>) -> (Self, Self) {
+        let ldf = self.ldf.read().clone();
+        let (kept, dropped) = ldf.filter_partition(predicate.inner, reason.map(Into::into));
+        (kept.into(), dropped.into())
+    }
+
     fn select(&self, exprs: Vec<PyExpr>) -> Self {
         let ldf = self.ldf.read().clone();
         let exprs = exprs.to_exprs();
@@ -977,7 +1116,7 @@ impl PyLazyFrame {
     }
 
     #[cfg(feature = "asof_join")]
-    #[pyo3(signature = (other, left_on, right_on, left_by, right_by, allow_parallel, force_parallel, suffix, strategy, tolerance, tolerance_str, coalesce, allow_eq, check_sortedness))]
+    #[pyo3(signature = (other, left_on, right_on, left_by, right_by, allow_parallel, force_parallel, suffix, strategy, tolerance, tolerance_str, tolerance_expr, coalesce, allow_eq, check_sortedness))]
     fn join_asof(
         &self,
         other: Self,
@@ -991,6 +1130,7 @@ impl PyLazyFrame {
         strategy: Wrap<AsofStrategy>,
         tolerance: Option<Wrap<AnyValue<'_>>>,
         tolerance_str: Option<String>,
+        tolerance_expr: Option<PyExpr>,
         coalesce: bool,
         allow_eq: bool,
         check_sortedness: bool,
@@ -1004,8 +1144,11 @@ impl PyLazyFrame {
         let other = other.ldf.into_inner();
         let left_on = left_on.inner;
         let right_on = right_on.inner;
-        Ok(ldf
-            .join_builder()
+        let mut builder = ldf.join_builder();
+        if let Some(tolerance) = tolerance_expr {
+            builder = builder.asof_tolerance(tolerance.inner);
+        }
+        Ok(builder
             .with(other)
             .left_on([left_on])
             .right_on([right_on])
@@ -1022,6 +1165,8 @@ impl PyLazyFrame {
                     Scalar::new(dtype, av)
                 }),
                 tolerance_str: tolerance_str.map(|s| s.into()),
+                tolerance_column: None,
+                end_column: None,
                 allow_eq,
                 check_sortedness,
             })))
@@ -1235,6 +1380,42 @@ impl PyLazyFrame {
         ldf.rename(existing, new, strict).into()
     }
 
+    fn rename_with(&self, columns: PySelector, function: Py<PyAny>) -> Self {
+        let ldf = self.ldf.read().clone();
+        let function = RenameAliasFn::Map(PlanCallback::new_python(PythonObject(function)));
+        ldf.rename_with(columns.inner, function).into()
+    }
+
+    #[pyo3(signature = (columns, index=None, before=None, after=None))]
+    fn move_columns(
+        &self,
+        columns: PySelector,
+        index: Option<i64>,
+        before: Option<String>,
+        after: Option<String>,
+    ) -> PyResult<Self> {
+        let position = match (index, before, after) {
+            (Some(index), None, None) => ColumnPosition::Index(index),
+            (None, Some(before), None) => ColumnPosition::Before(before.into()),
+            (None, None, Some(after)) => ColumnPosition::After(after.into()),
+            _ => {
+                return Err(PyValueError::new_err(
+                    "exactly one of `index`, `before` and `after` must be given",
+                ));
+            },
+        };
+        let ldf = self.ldf.read().clone();
+        Ok(ldf.move_columns(columns.inner, position).into())
+    }
+
+    fn insert_column_at(&self, index: i64, column: PyExpr) -> PyResult<Self> {
+        let ldf = self.ldf.read().clone();
+        let ldf = ldf
+            .insert_column_at(index, column.inner)
+            .map_err(PyPolarsErr::from)?;
+        Ok(ldf.into())
+    }
+
     fn reverse(&self) -> Self {
         let ldf = self.ldf.read().clone();
         ldf.reverse().into()
@@ -1303,16 +1484,23 @@ impl PyLazyFrame {
         out.into()
     }
 
-    fn explode(&self, subset: PySelector, empty_as_null: bool, keep_nulls: bool) -> Self {
+    fn explode(
+        &self,
+        subset: PySelector,
+        empty_as_null: bool,
+        keep_nulls: bool,
+        length_mismatch: Wrap<ExplodeLengthMismatch>,
+    ) -> Self {
         self.ldf
             .read()
             .clone()
-            .explode(
+            .explode_with_mismatch(
                 subset.inner,
                 ExplodeOptions {
                     empty_as_null,
                     keep_nulls,
                 },
+                length_mismatch.0,
             )
             .into()
     }
@@ -1338,6 +1526,12 @@ impl PyLazyFrame {
         .into()
     }
 
+    fn unique_by(&self, subset: Vec<PyExpr>, by: PyExpr, descending: bool) -> Self {
+        let ldf = self.ldf.read().clone();
+        let subset = subset.into_iter().map(|e| e.inner).collect::<Vec<_>>();
+        ldf.unique_by(subset, by.inner, descending).into()
+    }
+
     fn drop_nans(&self, subset: Option<PySelector>) -> Self {
         self.ldf
             .read()
@@ -1445,6 +1639,38 @@ impl PyLazyFrame {
             .into()
     }
 
+    fn split_sources(
+        &self,
+        py: Python<'_>,
+    ) -> PyResult<Vec<(Self, usize, usize, Option<String>)>> {
+        let ldf = self.ldf.read().clone();
+        let partitions = py.enter_polars(|| ldf.split_sources())?;
+        Ok(partitions
+            .into_iter()
+            .map(|(lf, info)| {
+                let path = info.path.map(|p| p.to_str().to_owned());
+                (lf.into(), info.index, info.count, path)
+            })
+            .collect())
+    }
+
+    fn execute_per_partition(
+        &self,
+        py: Python<'_>,
+        partition_by: Vec<PyBackedStr>,
+        concurrency: NonZeroUsize,
+    ) -> PyResult<PyPartitionResults> {
+        let ldf = self.ldf.read().clone();
+        let partition_by = partition_by
+            .iter()
+            .map(|name| PlSmallStr::from_str(name))
+            .collect::<Vec<_>>();
+        let results = py.enter_polars(|| ldf.execute_per_partition(partition_by, concurrency))?;
+        Ok(PyPartitionResults {
+            inner: Mutex::new(results),
+        })
+    }
+
     fn drop(&self, columns: PySelector) -> Self {
         self.ldf.read().clone().drop(columns.inner).into()
     }
@@ -1504,6 +1730,7 @@ impl PyLazyFrame {
         columns: Vec<String>,
         descending: Vec<bool>,
         nulls_last: Vec<bool>,
+        verify: bool,
     ) -> PyResult<Self> {
         if columns.len() != descending.len() && descending.len() != 1 {
             return Err(PyValueError::new_err(
@@ -1549,7 +1776,29 @@ impl PyLazyFrame {
             .ldf
             .read()
             .clone()
-            .hint(HintIR::Sorted(sorted.into()))
+            .hint(HintIR::Sorted {
+                sorted: sorted.into(),
+                verify,
+            })
+            .map_err(PyPolarsErr::from)?;
+        Ok(out.into())
+    }
+
+    fn hint_unique(&self, columns: Vec<String>, verify: bool) -> PyResult<Self> {
+        if columns.is_empty() {
+            return Err(PyValueError::new_err(
+                "`set_unique_key` expects at least one column.",
+            ));
+        }
+        let keys = columns
+            .iter()
+            .map(|c| PlSmallStr::from_str(c.as_str()))
+            .collect();
+        let out = self
+            .ldf
+            .read()
+            .clone()
+            .hint(HintIR::Unique { keys, verify })
             .map_err(PyPolarsErr::from)?;
         Ok(out.into())
     }
@@ -1634,3 +1883,25 @@ impl PyCollectBatches {
         })
     }
 }
+
+#[pyclass(frozen)]
+struct PyPartitionResults {
+    inner: Mutex<PartitionResults>,
+}
+
+#[pymethods]
+impl PyPartitionResults {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&self, py: Python<'_>) -> PyResult<Option<(PyDataFrame, PyDataFrame)>> {
+        // Don't hold the GIL while waiting for the next partition to finish.
+        let next = py.enter_polars_ok(|| self.inner.lock().next())?;
+        next.map(|(partition, result)| {
+            let df = result.map_err(PyPolarsErr::from)?;
+            Ok((PyDataFrame::new(partition), PyDataFrame::new(df)))
+        })
+        .transpose()
+    }
+}
@@ -58,6 +58,10 @@ flag_getter_setters! {
     (COMM_SUBEXPR_ELIM, get_comm_subexpr_elim, set_comm_subexpr_elim, clear=true)
     (CHECK_ORDER_OBSERVE, get_check_order_observe, set_check_order_observe, clear=true)
     (FAST_PROJECTION, get_fast_projection, set_fast_projection, clear=true)
+    (SHARE_SCANS, get_share_scans, set_share_scans, clear=true)
+    (PARTITION_GROUP_BY, get_partition_group_by, set_partition_group_by, clear=true)
+    (SHRINK_DTYPES, get_shrink_dtypes, set_shrink_dtypes, clear=true)
+    (FLOAT_TOTAL_ORDER, get_float_total_order, set_float_total_order, clear=false)
 
     (EAGER, get_eager, set_eager, clear=true)
     (NEW_STREAMING, get_streaming, set_streaming, clear=true)
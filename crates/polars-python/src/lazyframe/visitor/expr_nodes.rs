@@ -176,6 +176,9 @@ pub enum PyStringFunction {
     ReplaceMany,
     EscapeRegex,
     Normalize,
+    Tokenize,
+    Encrypt,
+    MaskFormatPreserving,
 }
 
 #[pymethods]
@@ -796,9 +799,17 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 IRFunctionExpr::Extension(_) => {
                     return Err(PyNotImplementedError::new_err("extension expr"));
                 },
+                #[cfg(feature = "geo")]
+                IRFunctionExpr::Geo(_) => {
+                    return Err(PyNotImplementedError::new_err("geo expr"));
+                },
                 IRFunctionExpr::ListExpr(_) => {
                     return Err(PyNotImplementedError::new_err("list expr"));
                 },
+                #[cfg(feature = "quant")]
+                IRFunctionExpr::Quant(_) => {
+                    return Err(PyNotImplementedError::new_err("quant expr"));
+                },
                 IRFunctionExpr::Bitwise(_) => {
                     return Err(PyNotImplementedError::new_err("bitwise expr"));
                 },
@@ -960,6 +971,23 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                     IRStringFunction::EscapeRegex => {
                         (PyStringFunction::EscapeRegex,).into_py_any(py)
                     },
+                    #[cfg(feature = "crypto")]
+                    IRStringFunction::Tokenize { key } => {
+                        (PyStringFunction::Tokenize, key.key_id().as_str()).into_py_any(py)
+                    },
+                    #[cfg(feature = "crypto")]
+                    IRStringFunction::Encrypt { key } => {
+                        (PyStringFunction::Encrypt, key.key_id().as_str()).into_py_any(py)
+                    },
+                    #[cfg(feature = "crypto")]
+                    IRStringFunction::MaskFormatPreserving { key } => {
+                        (PyStringFunction::MaskFormatPreserving, key.key_id().as_str())
+                            .into_py_any(py)
+                    },
+                    #[cfg(feature = "string_mask")]
+                    IRStringFunction::MaskPattern { .. } | IRStringFunction::MaskPii { .. } => {
+                        return Err(PyNotImplementedError::new_err("string mask expr"));
+                    },
                 },
                 IRFunctionExpr::StructExpr(fun) => match fun {
                     IRStructFunction::FieldByName(name) => {
@@ -1341,6 +1369,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 IRFunctionExpr::RLE => ("rle",).into_py_any(py),
                 #[cfg(feature = "rle")]
                 IRFunctionExpr::RLEID => ("rle_id",).into_py_any(py),
+                #[cfg(feature = "to_dummies")]
+                IRFunctionExpr::ToDummies { .. } => {
+                    return Err(PyNotImplementedError::new_err("to_dummies"));
+                },
                 IRFunctionExpr::ToPhysical => ("to_physical",).into_py_any(py),
                 IRFunctionExpr::Random { .. } => {
                     return Err(PyNotImplementedError::new_err("random"));
@@ -1378,6 +1410,14 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                     ("mean_horizontal", ignore_nulls).into_py_any(py)
                 },
                 IRFunctionExpr::MinHorizontal => ("min_horizontal",).into_py_any(py),
+                #[cfg(feature = "rule_failures")]
+                IRFunctionExpr::RuleFailures { output: _ } => {
+                    return Err(PyNotImplementedError::new_err("rule failures"));
+                },
+                #[cfg(feature = "weighted")]
+                IRFunctionExpr::Weighted(_) => {
+                    return Err(PyNotImplementedError::new_err("weighted aggregation"));
+                },
                 IRFunctionExpr::EwmMean { options: _ } => {
                     return Err(PyNotImplementedError::new_err("ewm mean"));
                 },
@@ -1429,6 +1469,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 IRFunctionExpr::EwmMeanBy { half_life: _ } => {
                     return Err(PyNotImplementedError::new_err("ewm_mean_by"));
                 },
+                #[cfg(feature = "pct_change_by")]
+                IRFunctionExpr::PctChangeBy { offset: _ } => {
+                    return Err(PyNotImplementedError::new_err("pct_change_by"));
+                },
                 IRFunctionExpr::RowEncode(..) => {
                     return Err(PyNotImplementedError::new_err("row_encode"));
                 },
@@ -7,7 +7,7 @@ use polars_core::prelude::IdxSize;
 use polars_io::cloud::CloudOptions;
 use polars_ops::prelude::JoinType;
 use polars_plan::plans::IR;
-use polars_plan::prelude::{FileScanIR, FunctionIR, PythonPredicate, UnifiedScanArgs};
+use polars_plan::prelude::{FileScanIR, FunctionIR, PythonPredicate, UnifiedScanArgs, UnnestNaming};
 use pyo3::IntoPyObjectExt;
 use pyo3::exceptions::{PyNotImplementedError, PyValueError};
 use pyo3::prelude::*;
@@ -619,16 +619,31 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<Py<PyAny>> {
                     streamable: _,
                     fmt_str: _,
                 } => return Err(PyNotImplementedError::new_err("opaque rust mapfunction")),
-                FunctionIR::Unnest { columns, separator } => (
-                    "unnest",
-                    columns.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
-                    separator.as_ref().map(|s| s.to_string()),
-                )
-                    .into_py_any(py)?,
+                FunctionIR::Unnest { columns, naming } => {
+                    let separator = match naming {
+                        UnnestNaming::Keep => None,
+                        UnnestNaming::Prefix(separator) => Some(separator.to_string()),
+                        UnnestNaming::Custom(_) => {
+                            return Err(PyNotImplementedError::new_err("unnest with custom naming"));
+                        },
+                    };
+                    (
+                        "unnest",
+                        columns.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+                        separator,
+                    )
+                        .into_py_any(py)?
+                },
+                FunctionIR::UnnestAll {
+                    columns: _,
+                    separator,
+                    max_depth,
+                } => ("unnest_all", separator.to_string(), *max_depth).into_py_any(py)?,
                 FunctionIR::Rechunk => ("rechunk",).into_py_any(py)?,
                 FunctionIR::Explode {
                     columns,
                     options,
+                    mismatch: _,
                     schema: _,
                 } => (
                     "explode",
@@ -59,8 +59,10 @@ impl PySeries {
                 py.enter_polars(|| s.cast(&DataType::UInt8).unwrap().mean_reduce()),
                 py,
             ),
-            // For non-numeric output types we require mean_reduce.
-            dt if dt.is_temporal() => scalar_to_py(py.enter_polars(|| s.mean_reduce()), py),
+            // For non-float output types we require mean_reduce.
+            dt if dt.is_temporal() || dt.is_decimal() => {
+                scalar_to_py(py.enter_polars(|| s.mean_reduce()), py)
+            },
             _ => Ok(s.mean().into_pyobject(py)?),
         }
     }
@@ -72,8 +74,10 @@ impl PySeries {
                 py.enter_polars(|| s.cast(&DataType::UInt8).unwrap().median_reduce()),
                 py,
             ),
-            // For non-numeric output types we require median_reduce.
-            dt if dt.is_temporal() => scalar_to_py(py.enter_polars(|| s.median_reduce()), py),
+            // For non-float output types we require median_reduce.
+            dt if dt.is_temporal() || dt.is_decimal() => {
+                scalar_to_py(py.enter_polars(|| s.median_reduce()), py)
+            },
             _ => Ok(s.median().into_pyobject(py)?),
         }
     }
@@ -57,6 +57,15 @@ impl PySeries {
         Ok(self.clone())
     }
 
+    fn cat_remove_unused_categories(&self, py: Python) -> PyResult<Self> {
+        py.enter_polars_series(|| {
+            let s = self.series.read();
+            with_match_categorical_physical_type!(s.dtype().cat_physical()?, |$C| {
+                s.cat::<$C>()?.remove_unused_categories()
+            })
+        })
+    }
+
     fn estimated_size(&self) -> usize {
         self.series.read().estimated_size()
     }
@@ -575,6 +584,7 @@ impl PySeries {
             ))));
         }
 
+        typ.validate(&s).map_err(PyPolarsErr::from)?;
         Ok(s.clone().into_extension(typ.clone()).into())
     }
 
@@ -1,8 +1,12 @@
-use polars_testing::asserts::{DataFrameEqualOptions, assert_dataframe_equal};
+use polars_testing::asserts::{
+    DataFrameEqualOptions, assert_dataframe_equal, assert_dataframe_equal_by_key,
+};
 use pyo3::prelude::*;
+use pyo3::pybacked::PyBackedStr;
 
 use crate::PyDataFrame;
 use crate::error::PyPolarsErr;
+use crate::prelude::strings_to_pl_smallstr;
 
 #[pyfunction]
 #[pyo3(signature = (left, right, *, check_row_order, check_column_order, check_dtypes, check_exact, rel_tol, abs_tol, categorical_as_str))]
@@ -32,3 +36,19 @@ pub fn assert_dataframe_equal_py(
 
     assert_dataframe_equal(left_df, right_df, options).map_err(|e| PyPolarsErr::from(e).into())
 }
+
+#[pyfunction]
+#[pyo3(signature = (left, right, *, key_columns, max_differences))]
+pub fn assert_dataframe_equal_by_key_py(
+    left: &PyDataFrame,
+    right: &PyDataFrame,
+    key_columns: Vec<PyBackedStr>,
+    max_differences: usize,
+) -> PyResult<()> {
+    let left_df = &left.df.read();
+    let right_df = &right.df.read();
+    let key_columns = strings_to_pl_smallstr(key_columns);
+
+    assert_dataframe_equal_by_key(left_df, right_df, &key_columns, max_differences)
+        .map_err(|e| PyPolarsErr::from(e).into())
+}
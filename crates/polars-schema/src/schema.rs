@@ -2,7 +2,9 @@ use core::fmt::{Debug, Formatter};
 use core::hash::{Hash, Hasher};
 
 use indexmap::map::MutableKeys;
-use polars_error::{PolarsError, PolarsResult, polars_bail, polars_ensure, polars_err};
+use polars_error::{
+    ErrorDetail, PolarsError, PolarsResult, polars_bail, polars_ensure, polars_err,
+};
 use polars_utils::aliases::{InitHashMaps, PlIndexMap};
 use polars_utils::pl_str::PlSmallStr;
 
@@ -228,7 +230,10 @@ impl<D> Schema<D> {
     /// Raises DuplicateError if this column already exists in the schema.
     pub fn try_insert(&mut self, name: PlSmallStr, value: D) -> PolarsResult<()> {
         if self.fields.contains_key(&name) {
-            polars_bail!(Duplicate: "column '{}' is duplicate", name)
+            let err = polars_err!(Duplicate: "column '{}' is duplicate", name);
+            return Err(err.with_detail(ErrorDetail::DuplicateColumn {
+                name: name.to_string(),
+            }));
         }
 
         self.fields.insert(name, value);
@@ -313,11 +318,12 @@ impl<D> Schema<D> {
 
     pub fn try_index_of(&self, name: &str) -> PolarsResult<usize> {
         let Some(i) = self.fields.get_index_of(name) else {
-            polars_bail!(
+            let err = polars_err!(
                 ColumnNotFound:
                 "unable to find column {:?}; valid columns: {:?}",
                 name, self.iter_names().collect::<Vec<_>>(),
-            )
+            );
+            return Err(err.with_detail(ErrorDetail::column_not_found(name, self.iter_names())));
         };
 
         Ok(i)
@@ -409,10 +415,9 @@ where
             .into_iter()
             .map(|c| {
                 let name = c.as_ref();
-                let (_, name, dtype) = self
-                    .fields
-                    .get_full(name)
-                    .ok_or_else(|| polars_err!(col_not_found = name))?;
+                let (_, name, dtype) = self.fields.get_full(name).ok_or_else(|| {
+                    polars_err!(col_not_found = name, candidates = self.iter_names())
+                })?;
                 PolarsResult::Ok((name.clone(), dtype.clone()))
             })
             .collect::<PolarsResult<PlIndexMap<PlSmallStr, _>>>()?;
@@ -8,19 +8,25 @@ use polars_plan::dsl::function_expr::StructFunction;
 use polars_plan::prelude::*;
 use polars_utils::aliases::PlIndexSet;
 use polars_utils::format_pl_smallstr;
+use polars_utils::plpath::PlPath;
 use sqlparser::ast::{
-    BinaryOperator, CreateTable, CreateTableLikeKind, Delete, Distinct, ExcludeSelectItem,
-    Expr as SQLExpr, FromTable, FunctionArg, GroupByExpr, Ident, JoinConstraint, JoinOperator,
-    LimitClause, NamedWindowDefinition, NamedWindowExpr, ObjectName, ObjectNamePart, ObjectType,
-    OrderBy, OrderByKind, Query, RenameSelectItem, Select, SelectItem,
-    SelectItemQualifiedWildcardKind, SetExpr, SetOperator, SetQuantifier, Statement, TableAlias,
-    TableFactor, TableWithJoins, Truncate, UnaryOperator, Value as SQLValue, ValueWithSpan, Values,
-    Visit, WildcardAdditionalOptions, WindowSpec,
+    BinaryOperator, CopyOption, CopySource, CopyTarget, CreateTable, CreateTableLikeKind, Cte,
+    Delete, Distinct, ExcludeSelectItem, Expr as SQLExpr, FromTable, FunctionArg, GroupByExpr,
+    Ident, Insert, JoinConstraint, JoinOperator, LimitClause, NamedWindowDefinition,
+    NamedWindowExpr, ObjectName, ObjectNamePart, ObjectType, OrderBy, OrderByKind, Query,
+    RenameSelectItem, Select, SelectItem, SelectItemQualifiedWildcardKind, SetExpr, SetOperator,
+    SetQuantifier, Statement, TableAlias, TableFactor, TableObject, TableWithJoins, Truncate,
+    UnaryOperator, Value as SQLValue, ValueWithSpan, Values, Visit, WildcardAdditionalOptions,
+    WindowSpec,
 };
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
 
-use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry};
+use crate::function_registry::{
+    DefaultFunctionRegistry, FunctionRegistry, RegisteredFunction, SQLFunctionBuilder,
+    SQLFunctionSignature,
+};
+use crate::row_filter::{RowFilterProvider, SessionContext};
 use crate::sql_expr::{
     parse_sql_array, parse_sql_expr, resolve_compound_identifier, to_sql_interface_err,
 };
@@ -59,15 +65,22 @@ impl SelectModifiers {
     }
 }
 
+/// The default maximum number of times the recursive term of a `WITH RECURSIVE` CTE is evaluated.
+pub const DEFAULT_MAX_RECURSIVE_ITERATIONS: usize = 1000;
+
 /// The SQLContext is the main entry point for executing SQL queries.
 #[derive(Clone)]
 pub struct SQLContext {
     pub(crate) table_map: PlHashMap<String, LazyFrame>,
     pub(crate) function_registry: Arc<dyn FunctionRegistry>,
+    pub(crate) functions: PlHashMap<String, RegisteredFunction>,
+    pub(crate) row_filter: Option<Arc<dyn RowFilterProvider>>,
+    pub(crate) session: SessionContext,
     pub(crate) lp_arena: Arena<IR>,
     pub(crate) expr_arena: Arena<AExpr>,
 
     cte_map: PlHashMap<String, LazyFrame>,
+    max_recursive_iterations: usize,
     table_aliases: PlHashMap<String, String>,
     joined_aliases: PlHashMap<String, PlHashMap<String, String>>,
     pub(crate) named_windows: PlHashMap<String, WindowSpec>,
@@ -77,8 +90,12 @@ impl Default for SQLContext {
     fn default() -> Self {
         Self {
             function_registry: Arc::new(DefaultFunctionRegistry {}),
+            functions: Default::default(),
+            row_filter: None,
+            session: Default::default(),
             table_map: Default::default(),
             cte_map: Default::default(),
+            max_recursive_iterations: DEFAULT_MAX_RECURSIVE_ITERATIONS,
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
             named_windows: Default::default(),
@@ -150,20 +167,14 @@ impl SQLContext {
     /// # }
     ///```
     pub fn execute(&mut self, query: &str) -> PolarsResult<LazyFrame> {
-        let mut parser = Parser::new(&GenericDialect);
-        parser = parser.with_options(ParserOptions {
-            trailing_commas: true,
-            ..Default::default()
-        });
-
-        let ast = parser
-            .try_with_sql(query)
-            .map_err(to_sql_interface_err)?
-            .parse_statements()
-            .map_err(to_sql_interface_err)?;
-
+        let ast = parse_statements(query)?;
         polars_ensure!(ast.len() == 1, SQLInterface: "one (and only one) statement can be parsed at a time");
-        let res = self.execute_statement(ast.first().unwrap())?;
+        self.execute_parsed(ast.first().unwrap())
+    }
+
+    /// Execute a statement that has already been parsed.
+    pub(crate) fn execute_parsed(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let res = self.execute_statement(stmt)?;
 
         // Ensure the result uses the proper arenas.
         // This will instantiate new arenas with a new version.
@@ -187,6 +198,70 @@ impl SQLContext {
         self
     }
 
+    /// Register a scalar SQL function that is translated to the expression that `builder` builds
+    /// from the expressions of its arguments, e.g. to expose a plugin expression to SQL.
+    ///
+    /// Calls of the function are checked against `signature` when the query is translated.
+    /// Function names are case-insensitive, and the built-in SQL functions take precedence.
+    /// ```rust
+    /// # use polars_lazy::prelude::*;
+    /// # use polars_sql::SQLContext;
+    /// # use polars_sql::function_registry::{SQLArgType, SQLFunctionSignature};
+    /// # fn main() {
+    /// let mut ctx = SQLContext::new();
+    /// ctx.register_function(
+    ///     "add_one",
+    ///     SQLFunctionSignature::new(vec![SQLArgType::Numeric]),
+    ///     |mut args| Ok(args.pop().unwrap() + lit(1)),
+    /// );
+    /// # }
+    /// ```
+    pub fn register_function<F>(&mut self, name: &str, signature: SQLFunctionSignature, builder: F)
+    where
+        F: Fn(Vec<Expr>) -> PolarsResult<Expr> + Send + Sync + 'static,
+    {
+        self.register_sql_function(name, signature, Arc::new(builder), false)
+    }
+
+    /// Register an aggregate SQL function that is translated to the expression that `builder`
+    /// builds from the expressions of its arguments. The built expression must aggregate its
+    /// input to a single value.
+    ///
+    /// Unlike scalar functions, aggregate functions can be used as window functions, with OVER.
+    /// See [`SQLContext::register_function`] for how calls are checked.
+    pub fn register_aggregate<F>(
+        &mut self,
+        name: &str,
+        signature: SQLFunctionSignature,
+        builder: F,
+    ) where
+        F: Fn(Vec<Expr>) -> PolarsResult<Expr> + Send + Sync + 'static,
+    {
+        self.register_sql_function(name, signature, Arc::new(builder), true)
+    }
+
+    fn register_sql_function(
+        &mut self,
+        name: &str,
+        signature: SQLFunctionSignature,
+        builder: SQLFunctionBuilder,
+        is_aggregate: bool,
+    ) {
+        let function = RegisteredFunction {
+            signature,
+            builder,
+            is_aggregate,
+        };
+        self.functions.insert(name.to_lowercase(), function);
+    }
+
+    /// Set the maximum number of times the recursive term of a `WITH RECURSIVE` CTE is
+    /// evaluated before the query fails, to guard against recursions that don't terminate.
+    pub fn with_max_recursive_iterations(mut self, max_recursive_iterations: usize) -> Self {
+        self.max_recursive_iterations = max_recursive_iterations;
+        self
+    }
+
     /// Get the function registry of the SQLContext
     pub fn registry(&self) -> &Arc<dyn FunctionRegistry> {
         &self.function_registry
@@ -196,6 +271,24 @@ impl SQLContext {
     pub fn registry_mut(&mut self) -> &mut dyn FunctionRegistry {
         Arc::get_mut(&mut self.function_registry).unwrap()
     }
+
+    /// Add a row filter provider to the SQLContext.
+    /// The predicates it returns are applied to every scan of a registered table,
+    /// and `INSERT` rejects rows that do not match them.
+    pub fn with_row_filter(mut self, row_filter: Arc<dyn RowFilterProvider>) -> Self {
+        self.row_filter = Some(row_filter);
+        self
+    }
+
+    /// Set the session context that is passed to the row filter provider.
+    pub fn set_session(&mut self, session: SessionContext) {
+        self.session = session;
+    }
+
+    /// Get the session context of the SQLContext.
+    pub fn session(&self) -> &SessionContext {
+        &self.session
+    }
 }
 
 impl SQLContext {
@@ -204,6 +297,7 @@ impl SQLContext {
         Ok(match ast {
             Statement::Query(query) => self.execute_query(query)?,
             stmt @ Statement::ShowTables { .. } => self.execute_show_tables(stmt)?,
+            stmt @ Statement::ExplainTable { .. } => self.execute_describe_table(stmt)?,
             stmt @ Statement::CreateTable { .. } => self.execute_create_table(stmt)?,
             stmt @ Statement::Drop {
                 object_type: ObjectType::Table,
@@ -212,6 +306,8 @@ impl SQLContext {
             stmt @ Statement::Explain { .. } => self.execute_explain(stmt)?,
             stmt @ Statement::Truncate { .. } => self.execute_truncate_table(stmt)?,
             stmt @ Statement::Delete { .. } => self.execute_delete_from_table(stmt)?,
+            stmt @ Statement::Insert { .. } => self.execute_insert_into_table(stmt)?,
+            stmt @ Statement::Copy { .. } => self.execute_copy_to(stmt)?,
             _ => polars_bail!(
                 SQLInterface: "statement type is not supported:\n{:?}", ast,
             ),
@@ -247,21 +343,49 @@ impl SQLContext {
         frame.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)
     }
 
-    pub(super) fn get_table_from_current_scope(&self, name: &str) -> Option<LazyFrame> {
+    pub(super) fn get_table_from_current_scope(
+        &self,
+        name: &str,
+    ) -> PolarsResult<Option<LazyFrame>> {
         // Resolve the table name in the current scope; multi-stage fallback
         // * table name → cte name
         // * table alias → cte alias
-        self.table_map
-            .get(name)
-            .or_else(|| self.cte_map.get(name))
+        let resolve = |name: &str| {
+            self.get_registered_table(name)
+                .transpose()
+                .or_else(|| self.cte_map.get(name).cloned().map(Ok))
+        };
+        resolve(name)
             .or_else(|| {
-                self.table_aliases.get(name).and_then(|alias| {
-                    self.table_map
-                        .get(alias.as_str())
-                        .or_else(|| self.cte_map.get(alias.as_str()))
-                })
+                self.table_aliases
+                    .get(name)
+                    .and_then(|alias| resolve(alias.as_str()))
             })
-            .cloned()
+            .transpose()
+    }
+
+    /// Get the registered table `name` with its row filter applied.
+    fn get_registered_table(&self, name: &str) -> PolarsResult<Option<LazyFrame>> {
+        self.table_map
+            .get(name)
+            .map(|lf| self.apply_row_filter(name, lf.clone()))
+            .transpose()
+    }
+
+    /// Get the row filter of the registered table `name` in the current session, if any.
+    fn get_row_filter(&self, name: &str) -> PolarsResult<Option<Expr>> {
+        match &self.row_filter {
+            Some(provider) => provider.row_filter(name, &self.session),
+            None => Ok(None),
+        }
+    }
+
+    /// Apply the row filter of the registered table `name`, if any.
+    fn apply_row_filter(&self, name: &str, lf: LazyFrame) -> PolarsResult<LazyFrame> {
+        Ok(match self.get_row_filter(name)? {
+            Some(predicate) => lf.filter(predicate),
+            None => lf,
+        })
     }
 
     /// Execute a query in an isolated context. This prevents subqueries from mutating
@@ -430,7 +554,7 @@ impl SQLContext {
             SetExpr::Table(tbl) => {
                 if tbl.table_name.is_some() {
                     let table_name = tbl.table_name.as_ref().unwrap();
-                    self.get_table_from_current_scope(table_name)
+                    self.get_table_from_current_scope(table_name)?
                         .ok_or_else(|| {
                             polars_err!(
                                 SQLInterface: "no table or alias named '{}' found",
@@ -667,6 +791,58 @@ impl SQLContext {
         Ok(df.lazy())
     }
 
+    // DESCRIBE <tbl>
+    fn execute_describe_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::ExplainTable { table_name, .. } = stmt else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected DESCRIBE")
+        };
+        let Some((_, mut lf)) = self.get_named_table(table_name)? else {
+            polars_bail!(SQLInterface: "relation '{}' was not found", table_name);
+        };
+        let schema = lf.collect_schema()?;
+        let df = df! {
+            "column_name" => schema.iter_names().map(|name| name.as_str()).collect::<Vec<_>>(),
+            "data_type" => schema.iter_values().map(|dtype| dtype.to_string()).collect::<Vec<_>>(),
+        }?;
+        Ok(df.lazy())
+    }
+
+    // SELECT ... FROM information_schema.{tables|columns}
+    fn execute_information_schema(&self, view: &str) -> PolarsResult<LazyFrame> {
+        let tables = self.get_tables();
+        let df = match view.to_ascii_lowercase().as_str() {
+            "tables" => {
+                let table_type = vec!["BASE TABLE"; tables.len()];
+                df! { "table_name" => tables, "table_type" => table_type }?
+            },
+            "columns" => {
+                let mut table_name = vec![];
+                let mut column_name = vec![];
+                let mut ordinal_position = vec![];
+                let mut data_type = vec![];
+                for tbl_name in tables {
+                    let schema = self.table_map[&tbl_name].clone().collect_schema()?;
+                    for (idx, (name, dtype)) in schema.iter().enumerate() {
+                        table_name.push(tbl_name.clone());
+                        column_name.push(name.to_string());
+                        ordinal_position.push(idx as i64 + 1);
+                        data_type.push(dtype.to_string());
+                    }
+                }
+                df! {
+                    "table_name" => table_name,
+                    "column_name" => column_name,
+                    "ordinal_position" => ordinal_position,
+                    "data_type" => data_type,
+                }?
+            },
+            _ => polars_bail!(
+                SQLInterface: "relation 'information_schema.{}' was not found", view
+            ),
+        };
+        Ok(df.lazy())
+    }
+
     // DROP TABLE <tbl>
     fn execute_drop_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         match stmt {
@@ -752,14 +928,20 @@ impl SQLContext {
                         polars_bail!(SQLInterface: "TRUNCATE expects exactly one table name; found {}", table_names.len())
                     }
                     let tbl = table_names[0].name.to_string();
+                    let row_filter = self.get_row_filter(&tbl)?;
                     if let Some(lf) = self.table_map.get_mut(&tbl) {
-                        *lf = DataFrame::empty_with_schema(
-                            lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)
-                                .unwrap()
-                                .as_ref(),
-                        )
-                        .lazy();
-                        Ok(lf.clone())
+                        *lf = match row_filter {
+                            // Only the rows that are visible to the session are removed.
+                            Some(predicate) => lf.clone().filter(predicate.neq_missing(lit(true))),
+                            None => DataFrame::empty_with_schema(
+                                lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)
+                                    .unwrap()
+                                    .as_ref(),
+                            )
+                            .lazy(),
+                        };
+                        let lf = lf.clone();
+                        self.apply_row_filter(&tbl, lf)
                     } else {
                         polars_bail!(SQLInterface: "table '{}' does not exist", tbl);
                     }
@@ -773,17 +955,176 @@ impl SQLContext {
         }
     }
 
+    // INSERT INTO <tbl> [(<col>, ...)] {VALUES ... | SELECT ...}
+    fn execute_insert_into_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Insert(Insert {
+            table,
+            columns,
+            source,
+            overwrite,
+            on,
+            returning,
+            ..
+        }) = stmt
+        else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected INSERT")
+        };
+        if on.is_some() {
+            polars_bail!(SQLInterface: "INSERT does not support the ON CONFLICT clause")
+        }
+        if returning.is_some() {
+            polars_bail!(SQLInterface: "INSERT does not support the RETURNING clause")
+        }
+        let TableObject::TableName(name) = table else {
+            polars_bail!(SQLInterface: "INSERT expects a table name; found {}", table)
+        };
+        let Some(source) = source else {
+            polars_bail!(SQLInterface: "INSERT expects a VALUES clause or a query")
+        };
+        let tbl_name = name.0.first().unwrap().as_ident().unwrap().value.as_str();
+        let Some(mut target) = self.table_map.get(tbl_name).cloned() else {
+            polars_bail!(SQLInterface: "table '{}' does not exist", tbl_name)
+        };
+        let target_schema = self.get_frame_schema(&mut target)?;
+        let (lf, source_schema) = self.execute_isolated(|ctx| ctx.execute_query(source))?;
+
+        // The inserted columns default to all columns of the table, in order.
+        let insert_cols = if columns.is_empty() {
+            target_schema.iter_names_cloned().collect::<Vec<_>>()
+        } else {
+            columns
+                .iter()
+                .map(|c| PlSmallStr::from_str(c.value.as_str()))
+                .collect()
+        };
+        for c in &insert_cols {
+            polars_ensure!(
+                target_schema.contains(c),
+                ColumnNotFound: "column '{}' does not exist in table '{}'", c, tbl_name
+            );
+        }
+        polars_ensure!(
+            insert_cols.iter().collect::<PlHashSet<_>>().len() == insert_cols.len(),
+            SQLSyntax: "INSERT column list contains duplicate columns"
+        );
+        polars_ensure!(
+            insert_cols.len() == source_schema.len(),
+            SQLSyntax: "INSERT expects {} values per row, found {}",
+            insert_cols.len(), source_schema.len()
+        );
+
+        // Values are matched to the inserted columns by position; other columns are set to null.
+        let exprs = target_schema
+            .iter()
+            .map(|(name, dtype)| {
+                let value = match insert_cols.iter().position(|c| c == name) {
+                    Some(idx) => {
+                        let (source_name, _) = source_schema.get_at_index(idx).unwrap();
+                        col(source_name.clone()).strict_cast(dtype.clone())
+                    },
+                    None => lit(NULL).cast(dtype.clone()),
+                };
+                value.alias(name.clone())
+            })
+            .collect::<Vec<_>>();
+        let mut rows = lf.select(exprs);
+
+        // With a row filter, the session may only insert rows that it can see, and
+        // OVERWRITE only replaces the rows that are visible to the session.
+        let row_filter = self.get_row_filter(tbl_name)?;
+        if let Some(predicate) = &row_filter {
+            let df = rows.collect()?;
+            let n_hidden = df
+                .clone()
+                .lazy()
+                .filter(predicate.clone().neq_missing(lit(true)))
+                .collect()?
+                .height();
+            polars_ensure!(
+                n_hidden == 0,
+                SQLInterface:
+                "INSERT INTO '{}' would add {} row(s) that are not visible to the session",
+                tbl_name, n_hidden
+            );
+            rows = df.lazy();
+        }
+        let lf = match (*overwrite, row_filter) {
+            (true, Some(predicate)) => {
+                let hidden = target.filter(predicate.neq_missing(lit(true)));
+                concat(vec![hidden, rows], UnionArgs::default())?
+            },
+            (true, None) => rows,
+            (false, _) => concat(vec![target, rows], UnionArgs::default())?,
+        };
+        self.register(tbl_name, lf);
+
+        let df_inserted = df! { "Response" => [format!("INSERT INTO {tbl_name}")] };
+        Ok(df_inserted.unwrap().lazy())
+    }
+
+    // COPY {<tbl> [(<col>, ...)] | (<query>)} TO '<file>' [WITH (FORMAT <format>, ...)]
+    fn execute_copy_to(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Copy {
+            source,
+            to,
+            target,
+            options,
+            legacy_options,
+            values,
+        } = stmt
+        else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected COPY")
+        };
+        if !*to {
+            polars_bail!(
+                SQLInterface:
+                "COPY FROM is not supported; use a table function such as 'read_csv' instead"
+            )
+        }
+        if !legacy_options.is_empty() || !values.is_empty() {
+            polars_bail!(SQLInterface: "COPY TO only supports options given in a WITH clause")
+        }
+        let CopyTarget::File { filename } = target else {
+            polars_bail!(SQLInterface: "COPY TO only supports writing to a file; found {}", target)
+        };
+        let file_format = copy_to_file_format(filename, options)?;
+
+        let lf = match source {
+            CopySource::Query(query) => self.execute_query(query)?,
+            CopySource::Table {
+                table_name,
+                columns,
+            } => {
+                let tbl_name = table_name.0.first().unwrap().as_ident().unwrap().value.as_str();
+                let Some(lf) = self.get_table_from_current_scope(tbl_name)? else {
+                    polars_bail!(SQLInterface: "table '{}' does not exist", tbl_name)
+                };
+                if columns.is_empty() {
+                    lf
+                } else {
+                    lf.select(columns.iter().map(|c| col(c.value.as_str())).collect::<Vec<_>>())
+                }
+            },
+        };
+        let destination = SinkDestination::File {
+            target: SinkTarget::Path(PlPath::new(filename)),
+        };
+        lf.sink(destination, file_format, UnifiedSinkArgs::default())
+    }
+
     fn register_cte(&mut self, name: &str, lf: LazyFrame) {
         self.cte_map.insert(name.to_owned(), lf);
     }
 
     fn register_ctes(&mut self, query: &Query) -> PolarsResult<()> {
         if let Some(with) = &query.with {
-            if with.recursive {
-                polars_bail!(SQLInterface: "recursive CTEs are not supported")
-            }
             for cte in &with.cte_tables {
                 let cte_name = cte.alias.name.value.clone();
+                if with.recursive && cte_refers_to_itself(cte) {
+                    let df = self.execute_recursive_cte(cte)?;
+                    self.register_cte(&cte_name, df.lazy());
+                    continue;
+                }
                 let mut lf = self.execute_query(&cte.query)?;
                 lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
                 self.register_cte(&cte_name, lf);
@@ -792,6 +1133,99 @@ impl SQLContext {
         Ok(())
     }
 
+    /// Evaluate a `WITH RECURSIVE` CTE of the form `<anchor> UNION [ALL] <recursive term>`.
+    ///
+    /// The recursive term is evaluated against the rows produced by the previous iteration
+    /// until it produces no (new) rows, which requires executing it eagerly.
+    fn execute_recursive_cte(&mut self, cte: &Cte) -> PolarsResult<DataFrame> {
+        let cte_name = cte.alias.name.value.as_str();
+        let SetExpr::SetOperation {
+            op: SetOperator::Union,
+            set_quantifier,
+            left: anchor,
+            right: recursive_term,
+        } = cte.query.body.as_ref()
+        else {
+            polars_bail!(
+                SQLInterface:
+                "recursive CTE '{}' must be of the form '<anchor> UNION [ALL] <recursive term>'",
+                cte_name
+            )
+        };
+        let distinct = match set_quantifier {
+            SetQuantifier::All => false,
+            SetQuantifier::Distinct | SetQuantifier::None => true,
+            _ => polars_bail!(
+                SQLInterface:
+                "'UNION {}' is not supported in recursive CTE '{}'", set_quantifier, cte_name
+            ),
+        };
+        polars_ensure!(
+            cte.query.order_by.is_none() && cte.query.limit_clause.is_none(),
+            SQLInterface: "ORDER BY and LIMIT are not supported in recursive CTE '{}'", cte_name
+        );
+        self.register_ctes(&cte.query)?;
+
+        let (lf, _) = self.execute_isolated(|ctx| ctx.process_query(anchor, &cte.query))?;
+        let lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+        let lf = if distinct {
+            lf.unique_stable(None, UniqueKeepStrategy::First)
+        } else {
+            lf
+        };
+        let mut working = lf.collect()?;
+        let schema = working.schema().clone();
+        let mut out = working.clone();
+
+        let mut iterations = 0;
+        while working.height() > 0 {
+            polars_ensure!(
+                iterations < self.max_recursive_iterations,
+                SQLInterface:
+                "recursive CTE '{}' did not terminate within {} iterations",
+                cte_name, self.max_recursive_iterations
+            );
+            iterations += 1;
+
+            // The recursive term references the rows of the previous iteration.
+            self.register_cte(cte_name, working.lazy());
+            let (lf, recursive_schema) =
+                self.execute_isolated(|ctx| ctx.process_query(recursive_term, &cte.query))?;
+            polars_ensure!(
+                recursive_schema.len() == schema.len(),
+                SQLSyntax:
+                "recursive term of CTE '{}' returns {} columns, but the anchor returns {}",
+                cte_name, recursive_schema.len(), schema.len()
+            );
+            // As with any UNION, the columns are matched by position.
+            let exprs = recursive_schema
+                .iter_names()
+                .zip(schema.iter())
+                .map(|(recursive_name, (name, dtype))| {
+                    col(recursive_name.clone())
+                        .cast(dtype.clone())
+                        .alias(name.clone())
+                })
+                .collect::<Vec<_>>();
+            working = lf.select(exprs).collect()?;
+
+            if distinct {
+                // Only keep the rows that were not produced before.
+                let n_seen = out.height();
+                out.vstack_mut(&working)?;
+                out = out
+                    .lazy()
+                    .unique_stable(None, UniqueKeepStrategy::First)
+                    .collect()?;
+                working = out.slice(n_seen as i64, out.height() - n_seen);
+            } else {
+                out.vstack_mut(&working)?;
+            }
+        }
+        out.rechunk_mut_par();
+        Ok(out)
+    }
+
     fn register_named_windows(
         &mut self,
         named_windows: &[NamedWindowDefinition],
@@ -1278,6 +1712,7 @@ impl SQLContext {
                                 nulls_equal: false,
                                 coalesce: Default::default(),
                                 maintain_order: polars_ops::frame::MaintainOrderJoin::Left,
+                                promotion_rules: Default::default(),
                             },
                         );
                 }
@@ -1628,6 +2063,23 @@ impl SQLContext {
         }
     }
 
+    /// Resolve a table name, including the views of `information_schema`.
+    fn get_named_table(&self, name: &ObjectName) -> PolarsResult<Option<(String, LazyFrame)>> {
+        if let [schema, view] = name.0.as_slice() {
+            let is_information_schema = schema
+                .as_ident()
+                .is_some_and(|ident| ident.value.eq_ignore_ascii_case("information_schema"));
+            if let (true, Some(view)) = (is_information_schema, view.as_ident()) {
+                let lf = self.execute_information_schema(&view.value)?;
+                return Ok(Some((view.value.clone(), lf)));
+            }
+        }
+        let tbl_name = name.0.first().unwrap().as_ident().unwrap().value.as_str();
+        Ok(self
+            .get_table_from_current_scope(tbl_name)?
+            .map(|lf| (tbl_name.to_string(), lf)))
+    }
+
     fn get_table(&mut self, relation: &TableFactor) -> PolarsResult<(String, LazyFrame)> {
         match relation {
             TableFactor::Table {
@@ -1636,17 +2088,16 @@ impl SQLContext {
                 if let Some(args) = args {
                     return self.execute_table_function(name, alias, &args.args);
                 }
-                let tbl_name = name.0.first().unwrap().as_ident().unwrap().value.as_str();
-                if let Some(lf) = self.get_table_from_current_scope(tbl_name) {
+                if let Some((tbl_name, lf)) = self.get_named_table(name)? {
                     match alias {
                         Some(alias) => {
-                            self.table_aliases
-                                .insert(alias.name.value.clone(), tbl_name.to_string());
+                            self.table_aliases.insert(alias.name.value.clone(), tbl_name);
                             Ok((alias.name.value.clone(), lf))
                         },
-                        None => Ok((tbl_name.to_string(), lf)),
+                        None => Ok((tbl_name, lf)),
                     }
                 } else {
+                    let tbl_name = name.0.first().unwrap().as_ident().unwrap().value.as_str();
                     polars_bail!(SQLInterface: "relation '{}' was not found", tbl_name);
                 }
             },
@@ -2235,9 +2686,13 @@ impl SQLContext {
 }
 
 impl SQLContext {
-    /// Get internal table map. For internal use only.
-    pub fn get_table_map(&self) -> PlHashMap<String, LazyFrame> {
-        self.table_map.clone()
+    /// Get internal table map, with the row filters of the current session applied. For
+    /// internal use only.
+    pub fn get_table_map(&self) -> PolarsResult<PlHashMap<String, LazyFrame>> {
+        self.table_map
+            .keys()
+            .map(|name| Ok((name.clone(), self.get_registered_table(name)?.unwrap())))
+            .collect()
     }
 
     /// Create a new SQLContext from a table map. For internal use only
@@ -2316,6 +2771,67 @@ fn get_table_name(factor: &TableFactor) -> Option<String> {
     }
 }
 
+/// Determine the format that `COPY ... TO` writes, from its FORMAT option or else from the
+/// extension of the target file.
+#[allow(unused_variables, unreachable_code)]
+fn copy_to_file_format(filename: &str, options: &[CopyOption]) -> PolarsResult<FileType> {
+    let (mut format, mut delimiter, mut header) = (None, None, None);
+    for opt in options {
+        match opt {
+            CopyOption::Format(ident) => format = Some(ident.value.to_lowercase()),
+            CopyOption::Delimiter(c) => delimiter = Some(*c),
+            CopyOption::Header(h) => header = Some(*h),
+            _ => polars_bail!(SQLInterface: "COPY TO does not support the {} option", opt),
+        }
+    }
+    let format = match format {
+        Some(format) => format,
+        None => match std::path::Path::new(filename).extension() {
+            Some(ext) => ext.to_string_lossy().to_lowercase(),
+            None => polars_bail!(
+                SQLInterface:
+                "cannot determine the format of '{}'; specify it with the FORMAT option",
+                filename
+            ),
+        },
+    };
+    if format != "csv" && (delimiter.is_some() || header.is_some()) {
+        polars_bail!(SQLInterface: "the DELIMITER and HEADER options only apply to CSV files")
+    }
+
+    Ok(match format.as_str() {
+        #[cfg(feature = "parquet")]
+        "parquet" => FileType::Parquet(ParquetWriteOptions::default()),
+        #[cfg(feature = "ipc")]
+        "ipc" | "arrow" | "feather" => FileType::Ipc(IpcWriterOptions::default()),
+        #[cfg(feature = "csv")]
+        "csv" => {
+            let mut csv_options = CsvWriterOptions::default();
+            if let Some(header) = header {
+                csv_options.include_header = header;
+            }
+            if let Some(delimiter) = delimiter {
+                polars_ensure!(
+                    delimiter.is_ascii(),
+                    SQLInterface: "COPY TO requires a single-byte DELIMITER; found '{}'", delimiter
+                );
+                csv_options.serialize_options.separator = delimiter as u8;
+            }
+            FileType::Csv(csv_options)
+        },
+        #[cfg(feature = "json")]
+        "json" | "ndjson" | "jsonl" => FileType::Json(JsonWriterOptions::default()),
+        _ => polars_bail!(SQLInterface: "COPY TO does not support the '{}' format", format),
+    })
+}
+
+/// Check if the body of a CTE references the CTE itself (making it recursive).
+fn cte_refers_to_itself(cte: &Cte) -> bool {
+    let mut collector = TableIdentifierCollector::default();
+    let _ = cte.query.body.visit(&mut collector);
+    collector.tables.contains(&cte.alias.name.value)
+}
+
 /// Check if an expression is a simple column reference (with optional alias) to the given name.
 fn is_simple_col_ref(expr: &Expr, col_name: &PlSmallStr) -> bool {
     match expr {
@@ -2539,25 +3055,29 @@ fn process_join_constraint(
     }
 }
 
-/// Extract table identifiers referenced in a SQL query; uses a visitor to
-/// collect all table names that appear in FROM clauses, JOINs, TABLE refs
-/// in set operations, and subqueries.
-pub fn extract_table_identifiers(
-    query: &str,
-    include_schema: bool,
-    unique: bool,
-) -> PolarsResult<Vec<String>> {
+/// Parse the (possibly multiple) statements of a SQL string.
+pub(crate) fn parse_statements(query: &str) -> PolarsResult<Vec<Statement>> {
     let mut parser = Parser::new(&GenericDialect);
     parser = parser.with_options(ParserOptions {
         trailing_commas: true,
         ..Default::default()
     });
-    let ast = parser
+    parser
         .try_with_sql(query)
         .map_err(to_sql_interface_err)?
         .parse_statements()
-        .map_err(to_sql_interface_err)?;
+        .map_err(to_sql_interface_err)
+}
 
+/// Extract table identifiers referenced in a SQL query; uses a visitor to
+/// collect all table names that appear in FROM clauses, JOINs, TABLE refs
+/// in set operations, and subqueries.
+pub fn extract_table_identifiers(
+    query: &str,
+    include_schema: bool,
+    unique: bool,
+) -> PolarsResult<Vec<String>> {
+    let ast = parse_statements(query)?;
     let mut collector = TableIdentifierCollector {
         include_schema,
         ..Default::default()
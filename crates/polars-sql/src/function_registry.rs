@@ -1,8 +1,14 @@
 //! This module defines a FunctionRegistry for supported SQL functions and UDFs.
 
-use polars_error::{PolarsResult, polars_bail};
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+use polars_core::prelude::{DataType, Schema, UnknownKind};
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
+use polars_plan::dsl::Expr;
 pub use polars_plan::prelude::FunctionOptions;
 use polars_plan::prelude::udf::UserDefinedFunction;
+
 /// A registry that holds user defined functions.
 pub trait FunctionRegistry: Send + Sync {
     /// Register a function.
@@ -28,3 +34,148 @@ impl FunctionRegistry for DefaultFunctionRegistry {
         false
     }
 }
+
+/// Builds the expression of a registered SQL function from the expressions of its arguments.
+pub type SQLFunctionBuilder = Arc<dyn Fn(Vec<Expr>) -> PolarsResult<Expr> + Send + Sync>;
+
+/// The type of an argument of a registered SQL function.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SQLArgType {
+    /// Any type.
+    Any,
+    /// A boolean.
+    Boolean,
+    /// A (signed or unsigned) integer.
+    Integer,
+    /// An integer, float or decimal.
+    Numeric,
+    /// A string.
+    String,
+    /// A date, datetime, duration or time.
+    Temporal,
+    /// Exactly the given type.
+    Exact(DataType),
+}
+
+impl SQLArgType {
+    fn accepts(&self, dtype: &DataType) -> bool {
+        // Nulls (e.g. a NULL literal) are accepted by any type.
+        if matches!(dtype, DataType::Null | DataType::Unknown(UnknownKind::Any)) {
+            return true;
+        }
+        match self {
+            Self::Any => true,
+            Self::Boolean => dtype.is_bool(),
+            Self::Integer => dtype.is_integer(),
+            Self::Numeric => dtype.is_numeric(),
+            Self::String => dtype.is_string(),
+            Self::Temporal => dtype.is_temporal(),
+            Self::Exact(expected) => expected == dtype,
+        }
+    }
+}
+
+impl Display for SQLArgType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Any => write!(f, "any type"),
+            Self::Boolean => write!(f, "boolean"),
+            Self::Integer => write!(f, "integer"),
+            Self::Numeric => write!(f, "numeric"),
+            Self::String => write!(f, "string"),
+            Self::Temporal => write!(f, "temporal"),
+            Self::Exact(dtype) => write!(f, "{dtype}"),
+        }
+    }
+}
+
+/// The arguments that a registered SQL function accepts.
+///
+/// Calls are checked against the signature when the query is translated, so that invalid calls
+/// fail before the query is executed.
+#[derive(Clone, Debug, Default)]
+pub struct SQLFunctionSignature {
+    arg_types: Vec<SQLArgType>,
+    optional: usize,
+    variadic: bool,
+}
+
+impl SQLFunctionSignature {
+    /// A signature that accepts arguments of the given types.
+    pub fn new(arg_types: Vec<SQLArgType>) -> Self {
+        Self {
+            arg_types,
+            optional: 0,
+            variadic: false,
+        }
+    }
+
+    /// Allow the last `n` arguments to be omitted.
+    pub fn with_optional(mut self, n: usize) -> Self {
+        self.optional = n;
+        self
+    }
+
+    /// Allow the last argument to be repeated any number of times.
+    pub fn with_variadic(mut self) -> Self {
+        self.variadic = true;
+        self
+    }
+
+    /// Check the arguments of a call to the function `name`. The types are only checked if the
+    /// schema that the arguments are evaluated against is known.
+    pub(crate) fn check(
+        &self,
+        name: &str,
+        args: &[Expr],
+        schema: Option<&Schema>,
+    ) -> PolarsResult<()> {
+        let n_required = self.arg_types.len().saturating_sub(self.optional);
+        let n_args = args.len();
+        if self.variadic {
+            polars_ensure!(
+                n_args >= n_required,
+                SQLSyntax: "{} expects at least {} arguments (found {})", name, n_required, n_args
+            );
+        } else if n_required == self.arg_types.len() {
+            polars_ensure!(
+                n_args == n_required,
+                SQLSyntax: "{} expects {} arguments (found {})", name, n_required, n_args
+            );
+        } else {
+            polars_ensure!(
+                (n_required..=self.arg_types.len()).contains(&n_args),
+                SQLSyntax: "{} expects {} to {} arguments (found {})",
+                name, n_required, self.arg_types.len(), n_args
+            );
+        }
+
+        let Some(schema) = schema else {
+            return Ok(());
+        };
+        for (i, arg) in args.iter().enumerate() {
+            let Some(arg_type) = self.arg_types.get(i).or(self.arg_types.last()) else {
+                break;
+            };
+            let dtype = arg.to_field(schema)?.dtype;
+            polars_ensure!(
+                arg_type.accepts(&dtype),
+                SQLSyntax: "{} expects argument {} to be {} (found {})",
+                name, i + 1, arg_type, dtype
+            );
+        }
+        Ok(())
+    }
+}
+
+/// A function registered with [`SQLContext::register_function`] or
+/// [`SQLContext::register_aggregate`].
+///
+/// [`SQLContext::register_function`]: crate::SQLContext::register_function
+/// [`SQLContext::register_aggregate`]: crate::SQLContext::register_aggregate
+#[derive(Clone)]
+pub(crate) struct RegisteredFunction {
+    pub(crate) signature: SQLFunctionSignature,
+    pub(crate) builder: SQLFunctionBuilder,
+    pub(crate) is_aggregate: bool,
+}
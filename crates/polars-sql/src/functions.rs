@@ -2,8 +2,8 @@ use std::ops::{Add, Sub};
 
 use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
 use polars_core::prelude::{
-    DataType, ExplodeOptions, PolarsResult, QuantileMethod, Schema, TimeUnit, polars_bail,
-    polars_err,
+    DataType, ExplodeOptions, IDX_DTYPE, PolarsResult, QuantileMethod, RollingOptionsFixedWindow,
+    Schema, TimeUnit, polars_bail, polars_ensure, polars_err,
 };
 use polars_lazy::dsl::Expr;
 #[cfg(feature = "rank")]
@@ -11,9 +11,10 @@ use polars_lazy::prelude::{RankMethod, RankOptions};
 use polars_ops::chunked_array::UnicodeForm;
 use polars_ops::series::RoundMode;
 use polars_plan::dsl::{
-    as_struct, coalesce, concat_str, element, int_range, len, max_horizontal, min_horizontal, when,
+    FunctionExpr, coalesce, concat_str, element, int_range, len, max_horizontal, min_horizontal,
+    when,
 };
-use polars_plan::plans::{DynLiteralValue, LiteralValue, typed_lit};
+use polars_plan::plans::{DynLiteralValue, LiteralValue, RowEncodingVariant, typed_lit};
 use polars_plan::prelude::{StrptimeOptions, col, cols, lit};
 use polars_utils::pl_str::PlSmallStr;
 use sqlparser::ast::helpers::attached_token::AttachedToken;
@@ -763,6 +764,8 @@ pub(crate) enum PolarsSQLFunctions {
     // ----
     // User-defined
     // ----
+    /// A function registered with `SQLContext::register_function` or `register_aggregate`.
+    Registered(String),
     Udf(String),
 }
 
@@ -1043,7 +1046,9 @@ impl PolarsSQLFunctions {
             "columns" => Self::Columns,
 
             other => {
-                if ctx.function_registry.contains(other) {
+                if ctx.functions.contains_key(other) {
+                    Self::Registered(other.to_string())
+                } else if ctx.function_registry.contains(other) {
                     Self::Udf(other.to_string())
                 } else {
                     polars_bail!(SQLInterface: "unsupported function '{}'", other);
@@ -1522,14 +1527,16 @@ impl SQLFunctionVisitor<'_> {
             // ----
             // Aggregate functions
             // ----
-            Avg => self.visit_unary(Expr::mean),
+            Avg => self.visit_unary_with_opt_frame(Expr::mean, Expr::rolling_mean),
             Corr => self.visit_binary(polars_lazy::dsl::pearson_corr),
             Count => self.visit_count(),
             CovarPop => self.visit_binary(|a, b| polars_lazy::dsl::cov(a, b, 0)),
             CovarSamp => self.visit_binary(|a, b| polars_lazy::dsl::cov(a, b, 1)),
             First => self.visit_unary(Expr::first),
             Last => self.visit_unary(Expr::last),
-            Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max),
+            Max => {
+                self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max, Expr::rolling_max)
+            },
             Median => self.visit_unary(Expr::median),
             QuantileCont => {
                 let args = extract_args(function)?;
@@ -1583,9 +1590,13 @@ impl SQLFunctionVisitor<'_> {
                     _ => polars_bail!(SQLSyntax: "QUANTILE_DISC expects 2 arguments (found {})", args.len()),
                 }
             },
-            Min => self.visit_unary_with_opt_cumulative(Expr::min, Expr::cum_min),
+            Min => {
+                self.visit_unary_with_opt_cumulative(Expr::min, Expr::cum_min, Expr::rolling_min)
+            },
             StdDev => self.visit_unary(|e| e.std(1)),
-            Sum => self.visit_unary_with_opt_cumulative(Expr::sum, Expr::cum_sum),
+            Sum => {
+                self.visit_unary_with_opt_cumulative(Expr::sum, Expr::cum_sum, Expr::rolling_sum)
+            },
             Variance => self.visit_unary(|e| e.var(1)),
 
             // ----
@@ -1695,21 +1706,30 @@ impl SQLFunctionVisitor<'_> {
                         polars_bail!(SQLSyntax: "{} requires an OVER clause with ORDER BY", func_name)
                     },
                 };
-                let (order_exprs, all_desc) =
+                let (order_exprs, sort_opts) =
                     self.parse_order_by_in_window(&window_spec.order_by)?;
                 let rank_expr = if order_exprs.len() == 1 {
                     order_exprs[0].clone().rank(
                         RankOptions {
                             method: rank_method,
-                            descending: all_desc,
+                            descending: sort_opts.descending[0],
                         },
                         None,
                     )
                 } else {
-                    as_struct(order_exprs).rank(
+                    // Rank the row encoding of the keys, which sorts every key in its own
+                    // direction and null order.
+                    Expr::n_ary(
+                        FunctionExpr::RowEncode(RowEncodingVariant::Ordered {
+                            descending: Some(sort_opts.descending),
+                            nulls_last: Some(sort_opts.nulls_last),
+                        }),
+                        order_exprs,
+                    )
+                    .rank(
                         RankOptions {
                             method: rank_method,
-                            descending: all_desc,
+                            descending: false,
                         },
                         None,
                     )
@@ -1729,6 +1749,7 @@ impl SQLFunctionVisitor<'_> {
             // ----
             // User-defined
             // ----
+            Registered(func_name) => self.visit_registered_function(&func_name),
             Udf(func_name) => self.visit_udf(&func_name),
         }
     }
@@ -1769,6 +1790,35 @@ impl SQLFunctionVisitor<'_> {
         }.and_then(|e| self.apply_window_spec(e, &self.func.over))
     }
 
+    fn visit_registered_function(&mut self, func_name: &str) -> PolarsResult<Expr> {
+        let function = self.ctx.functions[func_name].clone();
+        let args = extract_args(self.func)?
+            .into_iter()
+            .map(|arg| {
+                if let FunctionArgExpr::Expr(e) = arg {
+                    parse_sql_expr(e, self.ctx, self.active_schema)
+                } else {
+                    polars_bail!(SQLSyntax: "{} expects expressions as arguments", self.func.name)
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        function
+            .signature
+            .check(&self.func.name.to_string(), &args, self.active_schema)?;
+
+        let expr = (function.builder)(args)?;
+        if function.is_aggregate {
+            self.apply_window_spec(expr, &self.func.over)
+        } else {
+            polars_ensure!(
+                self.func.over.is_none(),
+                SQLSyntax: "{} is not an aggregate function and cannot be used with OVER",
+                self.func.name
+            );
+            Ok(expr)
+        }
+    }
+
     fn visit_udf(&mut self, func_name: &str) -> PolarsResult<Expr> {
         let args = extract_args(self.func)?
             .into_iter()
@@ -1881,7 +1931,7 @@ impl SQLFunctionVisitor<'_> {
 
         if !order_by.is_empty() {
             // Extract ORDER BY exprs and sort direction
-            let (order_by_exprs, all_desc) = self.parse_order_by_in_window(order_by)?;
+            let (order_by_exprs, sort_opts) = self.parse_order_by_in_window(order_by)?;
 
             // Get the base expr/column
             let args = extract_args(self.func)?;
@@ -1904,8 +1954,7 @@ impl SQLFunctionVisitor<'_> {
 
             // Apply cumulative function and wrap with window spec
             let cumulative_expr = cumulative_fn(base_expr, false);
-            let sort_opts = SortOptions::default().with_order_descending(all_desc);
-            cumulative_expr.over_with_options(
+            cumulative_expr.over_with_multi_options(
                 partition_by_exprs,
                 Some((order_by_exprs, sort_opts)),
                 Default::default(),
@@ -1956,20 +2005,162 @@ impl SQLFunctionVisitor<'_> {
 
     /// Some functions have cumulative equivalents that can be applied to window specs
     /// e.g. SUM(a) OVER (ORDER BY b DESC) -> CUMSUM(a, false)
+    ///
+    /// Explicit `ROWS` frames are handled by [`Self::apply_framed_window`].
     fn visit_unary_with_opt_cumulative(
         &mut self,
         f: impl Fn(Expr) -> Expr,
         cumulative_fn: impl Fn(Expr, bool) -> Expr,
+        rolling_fn: impl Fn(Expr, RollingOptionsFixedWindow) -> Expr,
     ) -> PolarsResult<Expr> {
         match self.func.over.as_ref() {
             Some(window_type) => {
                 let spec = self.resolve_window_spec(window_type)?;
-                self.apply_cumulative_window(f, cumulative_fn, &spec)
+                match &spec.window_frame {
+                    Some(frame) => self.apply_framed_window(
+                        &f,
+                        Some(&cumulative_fn),
+                        &rolling_fn,
+                        &spec,
+                        frame,
+                    ),
+                    None => self.apply_cumulative_window(f, cumulative_fn, &spec),
+                }
             },
             None => self.visit_unary(f),
         }
     }
 
+    /// Functions without a cumulative equivalent only use the ORDER BY of a window spec
+    /// with an explicit `ROWS` frame, e.g.
+    /// AVG(a) OVER (ORDER BY b ROWS BETWEEN 2 PRECEDING AND CURRENT ROW) -> ROLLING_MEAN(a, 3)
+    fn visit_unary_with_opt_frame(
+        &mut self,
+        f: impl Fn(Expr) -> Expr,
+        rolling_fn: impl Fn(Expr, RollingOptionsFixedWindow) -> Expr,
+    ) -> PolarsResult<Expr> {
+        if let Some(window_type) = self.func.over.as_ref() {
+            let spec = self.resolve_window_spec(window_type)?;
+            if let Some(frame) = &spec.window_frame {
+                return self.apply_framed_window(&f, None, &rolling_fn, &spec, frame);
+            }
+        }
+        self.visit_unary(f)
+    }
+
+    /// Aggregate over an explicit `ROWS` window frame.
+    ///
+    /// - `<n> PRECEDING|FOLLOWING` and `CURRENT ROW` bounds map to a rolling aggregation.
+    /// - `UNBOUNDED PRECEDING AND CURRENT ROW` maps to the cumulative aggregation, and
+    ///   `CURRENT ROW AND UNBOUNDED FOLLOWING` to the reversed cumulative aggregation.
+    /// - `UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING` aggregates the whole partition.
+    ///
+    /// ```text
+    /// SUM(a) OVER (PARTITION BY p ORDER BY b ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING)
+    /// -> a.rolling_sum(3).over(p, order_by=b), with the window ending at the next row
+    /// ```
+    fn apply_framed_window(
+        &mut self,
+        f: &dyn Fn(Expr) -> Expr,
+        cumulative_fn: Option<&dyn Fn(Expr, bool) -> Expr>,
+        rolling_fn: &dyn Fn(Expr, RollingOptionsFixedWindow) -> Expr,
+        spec: &WindowSpec,
+        frame: &WindowFrame,
+    ) -> PolarsResult<Expr> {
+        let (start, end) = self.parse_rows_frame(frame)?;
+        let args = extract_args(self.func)?;
+        let expr = match args.as_slice() {
+            [FunctionArgExpr::Expr(sql_expr)] => {
+                parse_sql_expr(sql_expr, self.ctx, self.active_schema)?
+            },
+            _ => return self.not_supported_error(),
+        };
+
+        let expr = match (start, end, cumulative_fn) {
+            (None, None, _) => f(expr),
+            (None, Some(0), Some(cumulative_fn)) => cumulative_fn(expr, false),
+            (Some(0), None, Some(cumulative_fn)) => cumulative_fn(expr, true),
+            (Some(start), Some(end), _) => {
+                let options = RollingOptionsFixedWindow {
+                    window_size: (end - start + 1) as usize,
+                    min_periods: 1,
+                    ..Default::default()
+                };
+                // The rolling window ends at the current row; pad the input with nulls so
+                // that it can end `end` rows after the current row, also for the last rows.
+                if end > 0 {
+                    let padded = expr.extend_constant(lit(LiteralValue::untyped_null()), lit(end));
+                    rolling_fn(padded, options).slice(lit(end), len())
+                } else {
+                    rolling_fn(expr, options).shift(lit(-end))
+                }
+            },
+            _ => polars_bail!(
+                SQLInterface:
+                "'ROWS BETWEEN {} AND {}' is not supported for {}",
+                frame.start_bound,
+                frame.end_bound.as_ref().unwrap_or(&WindowFrameBound::CurrentRow),
+                self.func.name
+            ),
+        };
+        self.apply_window_spec_without_frame(expr, spec)
+    }
+
+    /// Parse the bounds of a `ROWS` window frame as offsets from the current row; `None` is an
+    /// unbounded side.
+    fn parse_rows_frame(
+        &mut self,
+        frame: &WindowFrame,
+    ) -> PolarsResult<(Option<i64>, Option<i64>)> {
+        match frame.units {
+            WindowFrameUnits::Rows => {},
+            WindowFrameUnits::Range => {
+                polars_bail!(SQLInterface: "RANGE-based window frames are not supported")
+            },
+            WindowFrameUnits::Groups => {
+                polars_bail!(SQLInterface: "GROUPS-based window frames are not supported")
+            },
+        }
+        let mut parse_bound = |bound: &WindowFrameBound| -> PolarsResult<Option<i64>> {
+            let (sql_expr, sign) = match bound {
+                WindowFrameBound::CurrentRow => return Ok(Some(0)),
+                WindowFrameBound::Preceding(None) | WindowFrameBound::Following(None) => {
+                    return Ok(None);
+                },
+                WindowFrameBound::Preceding(Some(sql_expr)) => (sql_expr, -1),
+                WindowFrameBound::Following(Some(sql_expr)) => (sql_expr, 1),
+            };
+            match parse_sql_expr(sql_expr, self.ctx, self.active_schema)? {
+                Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(n))) if n >= 0 => {
+                    Ok(Some(sign * n as i64))
+                },
+                _ => polars_bail!(
+                    SQLSyntax:
+                    "window frame offset must be a non-negative integer (found {})",
+                    sql_expr
+                ),
+            }
+        };
+        let start = parse_bound(&frame.start_bound)?;
+        let end = match &frame.end_bound {
+            Some(bound) => parse_bound(bound)?,
+            None => Some(0),
+        };
+        polars_ensure!(
+            !matches!(frame.start_bound, WindowFrameBound::Following(None))
+                && !matches!(frame.end_bound, Some(WindowFrameBound::Preceding(None))),
+            SQLSyntax:
+            "window frame cannot start at UNBOUNDED FOLLOWING or end at UNBOUNDED PRECEDING"
+        );
+        if let (Some(start), Some(end)) = (start, end) {
+            polars_ensure!(
+                start <= end,
+                SQLSyntax: "window frame cannot start after it ends"
+            );
+        }
+        Ok((start, end))
+    }
+
     fn visit_binary<Arg: FromSQLExpr>(
         &mut self,
         f: impl Fn(Expr, Arg) -> Expr,
@@ -2135,12 +2326,11 @@ impl SQLFunctionVisitor<'_> {
         };
         if has_order_by && !is_distinct {
             if let Some(WindowType::WindowSpec(spec)) = &self.func.over {
-                self.validate_window_frame(&spec.window_frame)?;
-
                 match args.as_slice() {
                     [FunctionArgExpr::Wildcard] | [] => {
                         // COUNT(*) with ORDER BY -> map to `int_range`
-                        let (order_by_exprs, all_desc) =
+                        self.validate_window_frame(&spec.window_frame)?;
+                        let (order_by_exprs, sort_opts) =
                             self.parse_order_by_in_window(&spec.order_by)?;
                         let partition_by_exprs = if spec.partition_by.is_empty() {
                             None
@@ -2152,10 +2342,9 @@ impl SQLFunctionVisitor<'_> {
                                     .collect::<PolarsResult<Vec<_>>>()?,
                             )
                         };
-                        let sort_opts = SortOptions::default().with_order_descending(all_desc);
                         let row_number = int_range(lit(0), len(), 1, DataType::Int64).add(lit(1)); // SQL is 1-indexed
 
-                        return row_number.over_with_options(
+                        return row_number.over_with_multi_options(
                             partition_by_exprs,
                             Some((order_by_exprs, sort_opts)),
                             Default::default(),
@@ -2166,6 +2355,12 @@ impl SQLFunctionVisitor<'_> {
                         return self.visit_unary_with_opt_cumulative(
                             |e| e.count(),
                             |e, reverse| e.cum_count(reverse),
+                            |e, options| {
+                                e.is_not_null()
+                                    .cast(IDX_DTYPE)
+                                    .rolling_sum(options)
+                                    .fill_null(lit(0).cast(IDX_DTYPE))
+                            },
                         );
                     },
                     _ => {},
@@ -2233,30 +2428,25 @@ impl SQLFunctionVisitor<'_> {
         self.apply_order_by(expr, order_by)
     }
 
-    /// Parse ORDER BY (in OVER clause), validating uniform direction.
+    /// Parse ORDER BY (in OVER clause), with the direction and null order of every expression.
     fn parse_order_by_in_window(
         &mut self,
         order_by: &[OrderByExpr],
-    ) -> PolarsResult<(Vec<Expr>, bool)> {
-        if order_by.is_empty() {
-            return Ok((Vec::new(), false));
-        }
-        // Parse expressions and validate uniform direction
-        let all_ascending = order_by[0].options.asc.unwrap_or(true);
+    ) -> PolarsResult<(Vec<Expr>, SortMultipleOptions)> {
         let mut exprs = Vec::with_capacity(order_by.len());
+        let mut descending = Vec::with_capacity(order_by.len());
+        let mut nulls_last = Vec::with_capacity(order_by.len());
         for o in order_by {
-            if all_ascending != o.options.asc.unwrap_or(true) {
-                // TODO: mixed sort directions are not currently supported; we
-                //  need to enhance `over_with_options` to take SortMultipleOptions
-                polars_bail!(
-                    SQLSyntax:
-                    "OVER does not (yet) support mixed asc/desc directions for ORDER BY"
-                )
-            }
-            let expr = parse_sql_expr(&o.expr, self.ctx, self.active_schema)?;
-            exprs.push(expr);
+            // Note: if not specified 'NULLS FIRST' is default for DESC, 'NULLS LAST' otherwise
+            let desc_order = !o.options.asc.unwrap_or(true);
+            exprs.push(parse_sql_expr(&o.expr, self.ctx, self.active_schema)?);
+            nulls_last.push(!o.options.nulls_first.unwrap_or(desc_order));
+            descending.push(desc_order);
         }
-        Ok((exprs, !all_ascending))
+        let sort_opts = SortMultipleOptions::default()
+            .with_order_descending_multi(descending)
+            .with_nulls_last_multi(nulls_last);
+        Ok((exprs, sort_opts))
     }
 
     fn apply_window_spec(
@@ -2269,7 +2459,16 @@ impl SQLFunctionVisitor<'_> {
         };
         let window_spec = self.resolve_window_spec(window_type)?;
         self.validate_window_frame(&window_spec.window_frame)?;
+        self.apply_window_spec_without_frame(expr, &window_spec)
+    }
 
+    /// Apply the partitioning and ordering of a window spec, of which the frame has already
+    /// been taken into account.
+    fn apply_window_spec_without_frame(
+        &mut self,
+        expr: Expr,
+        window_spec: &WindowSpec,
+    ) -> PolarsResult<Expr> {
         let partition_by = if window_spec.partition_by.is_empty() {
             None
         } else {
@@ -2284,16 +2483,16 @@ impl SQLFunctionVisitor<'_> {
         let order_by = if window_spec.order_by.is_empty() {
             None
         } else {
-            let (order_exprs, all_desc) = self.parse_order_by_in_window(&window_spec.order_by)?;
-            let sort_opts = SortOptions::default().with_order_descending(all_desc);
-            Some((order_exprs, sort_opts))
+            Some(self.parse_order_by_in_window(&window_spec.order_by)?)
         };
 
         // Apply window spec
         Ok(match (partition_by, order_by) {
             (None, None) => expr,
             (Some(part), None) => expr.over(part),
-            (part, Some(order)) => expr.over_with_options(part, Some(order), Default::default())?,
+            (part, Some(order)) => {
+                expr.over_with_multi_options(part, Some(order), Default::default())?
+            },
         })
     }
 
@@ -5,6 +5,9 @@ mod context;
 pub mod function_registry;
 mod functions;
 pub mod keywords;
+#[cfg(feature = "pgwire")]
+pub mod pgwire;
+pub mod row_filter;
 mod sql_expr;
 mod sql_visitors;
 mod table_functions;
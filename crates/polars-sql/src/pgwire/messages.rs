@@ -0,0 +1,160 @@
+//! Reading and writing of the messages of version 3.0 of the PostgreSQL protocol.
+use std::io::{Read, Write};
+
+use polars_core::prelude::*;
+
+const PROTOCOL_VERSION: i32 = 196608;
+const SSL_REQUEST: i32 = 80877103;
+const GSSENC_REQUEST: i32 = 80877104;
+const CANCEL_REQUEST: i32 = 80877102;
+/// The maximum length of a message, which is the limit of PostgreSQL itself.
+const MAX_MESSAGE_LENGTH: i32 = 1 << 30;
+
+/// The first message of a connection, which is not tagged.
+pub(super) enum StartupMessage {
+    /// A request for encryption, which is declined.
+    EncryptionRequest,
+    CancelRequest,
+    Startup,
+}
+
+pub(super) fn read_startup_message(r: &mut impl Read) -> PolarsResult<StartupMessage> {
+    let len = read_i32(r)?;
+    polars_ensure!(
+        (8..=10_000).contains(&len),
+        ComputeError: "invalid startup message length: {}", len
+    );
+    let mut body = vec![0; len as usize - 4];
+    r.read_exact(&mut body)?;
+    let code = i32::from_be_bytes(body[..4].try_into().unwrap());
+    Ok(match code {
+        SSL_REQUEST | GSSENC_REQUEST => StartupMessage::EncryptionRequest,
+        CANCEL_REQUEST => StartupMessage::CancelRequest,
+        // The parameters (user, database, ...) are ignored.
+        PROTOCOL_VERSION => StartupMessage::Startup,
+        _ => polars_bail!(ComputeError: "unsupported protocol version: {}", code),
+    })
+}
+
+/// Read a tagged message, returning `None` when the client closed the connection.
+pub(super) fn read_message(r: &mut impl Read) -> PolarsResult<Option<(u8, Vec<u8>)>> {
+    let mut tag = [0];
+    if r.read(&mut tag)? == 0 {
+        return Ok(None);
+    }
+    let len = read_i32(r)?;
+    polars_ensure!(
+        (4..=MAX_MESSAGE_LENGTH).contains(&len),
+        ComputeError: "invalid message length: {}", len
+    );
+    // The body grows as it is received instead of trusting the length for the allocation.
+    let body_len = len as u64 - 4;
+    let mut body = Vec::new();
+    r.take(body_len).read_to_end(&mut body)?;
+    polars_ensure!(
+        body.len() as u64 == body_len,
+        ComputeError: "unexpected end of message"
+    );
+    Ok(Some((tag[0], body)))
+}
+
+fn read_i32(r: &mut impl Read) -> PolarsResult<i32> {
+    let mut buf = [0; 4];
+    r.read_exact(&mut buf)?;
+    Ok(i32::from_be_bytes(buf))
+}
+
+/// A cursor over the body of a message.
+pub(super) struct MessageReader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> MessageReader<'a> {
+    pub(super) fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+
+    pub(super) fn bytes(&mut self, n: usize) -> PolarsResult<&'a [u8]> {
+        polars_ensure!(n <= self.buf.len(), ComputeError: "unexpected end of message");
+        let (out, rest) = self.buf.split_at(n);
+        self.buf = rest;
+        Ok(out)
+    }
+
+    pub(super) fn u8(&mut self) -> PolarsResult<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    pub(super) fn i16(&mut self) -> PolarsResult<i16> {
+        Ok(i16::from_be_bytes(self.bytes(2)?.try_into().unwrap()))
+    }
+
+    pub(super) fn i32(&mut self) -> PolarsResult<i32> {
+        Ok(i32::from_be_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    /// A null-terminated string.
+    pub(super) fn cstr(&mut self) -> PolarsResult<String> {
+        let Some(len) = self.buf.iter().position(|b| *b == 0) else {
+            polars_bail!(ComputeError: "unterminated string in message");
+        };
+        let s = std::str::from_utf8(&self.buf[..len])
+            .map_err(|_| polars_err!(ComputeError: "invalid UTF-8 in message"))?;
+        self.buf = &self.buf[len + 1..];
+        Ok(s.to_string())
+    }
+}
+
+/// Builder of an outgoing message.
+pub(super) struct Message {
+    buf: Vec<u8>,
+}
+
+impl Message {
+    pub(super) fn new(tag: u8) -> Self {
+        // The length is filled in when the message is written.
+        Self {
+            buf: vec![tag, 0, 0, 0, 0],
+        }
+    }
+
+    pub(super) fn u8(mut self, v: u8) -> Self {
+        self.buf.push(v);
+        self
+    }
+
+    pub(super) fn i16(mut self, v: i16) -> Self {
+        self.buf.extend_from_slice(&v.to_be_bytes());
+        self
+    }
+
+    pub(super) fn i32(mut self, v: i32) -> Self {
+        self.buf.extend_from_slice(&v.to_be_bytes());
+        self
+    }
+
+    pub(super) fn cstr(mut self, v: &str) -> Self {
+        self.buf.extend_from_slice(v.as_bytes());
+        self.buf.push(0);
+        self
+    }
+
+    /// A value of a data row, prefixed by its length, or -1 if it is null.
+    pub(super) fn value(mut self, v: Option<&str>) -> Self {
+        match v {
+            None => self.i32(-1),
+            Some(v) => {
+                self.buf.extend_from_slice(&(v.len() as i32).to_be_bytes());
+                self.buf.extend_from_slice(v.as_bytes());
+                self
+            },
+        }
+    }
+
+    pub(super) fn write(mut self, w: &mut impl Write) -> PolarsResult<()> {
+        let len = (self.buf.len() - 1) as i32;
+        self.buf[1..5].copy_from_slice(&len.to_be_bytes());
+        w.write_all(&self.buf)?;
+        Ok(())
+    }
+}
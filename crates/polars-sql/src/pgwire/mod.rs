@@ -0,0 +1,557 @@
+//! A server for the PostgreSQL wire protocol, so that PostgreSQL clients can query the tables of
+//! a [`SQLContext`].
+//!
+//! Both the simple and the extended query protocol are supported. Results are sent in the text
+//! format, batch by batch as they are computed. The server neither authenticates clients nor
+//! supports TLS, so it must only be exposed to trusted clients.
+mod messages;
+mod types;
+
+use std::io::{BufReader, BufWriter, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::ops::ControlFlow;
+use std::sync::{Arc, Mutex};
+
+use polars_core::prelude::*;
+use polars_lazy::frame::CollectBatches;
+use polars_lazy::prelude::*;
+use sqlparser::ast::{Expr as SQLExpr, Statement, Value as SQLValue, ValueWithSpan};
+use sqlparser::tokenizer::Span;
+
+use self::messages::{Message, MessageReader, StartupMessage};
+use crate::SQLContext;
+use crate::context::parse_statements;
+
+/// A server that executes the SQL it receives over the PostgreSQL wire protocol on a
+/// [`SQLContext`].
+///
+/// ```rust,no_run
+/// # use polars_core::prelude::*;
+/// # use polars_lazy::prelude::*;
+/// # use polars_sql::SQLContext;
+/// # use polars_sql::pgwire::PgWireServer;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut ctx = SQLContext::new();
+/// ctx.register("df", df! { "a" => [1, 2, 3] }?.lazy());
+///
+/// // Serve until the process is stopped, e.g. for `psql -h localhost -p 5433`.
+/// PgWireServer::bind(ctx, "127.0.0.1:5433")?.serve()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct PgWireServer {
+    listener: TcpListener,
+    ctx: Arc<Mutex<SQLContext>>,
+}
+
+impl PgWireServer {
+    /// Bind a server for `ctx` to `addr`.
+    ///
+    /// The tables of `ctx` are shared by all clients, including the tables that clients create.
+    pub fn bind(ctx: SQLContext, addr: impl ToSocketAddrs) -> std::io::Result<Self> {
+        Ok(Self {
+            listener: TcpListener::bind(addr)?,
+            ctx: Arc::new(Mutex::new(ctx)),
+        })
+    }
+
+    /// The address that the server is bound to.
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Accept clients, serving each client on its own thread.
+    ///
+    /// A client that fails to connect is skipped, and logged in verbose mode, so this only
+    /// returns when the process is stopped.
+    pub fn serve(&self) -> std::io::Result<()> {
+        for (process_id, stream) in self.listener.incoming().enumerate() {
+            let stream = match stream {
+                Ok(stream) => stream,
+                Err(err) => {
+                    if polars_core::config::verbose() {
+                        eprintln!("[PgWireServer]: failed to accept a client: {err}");
+                    }
+                    continue;
+                },
+            };
+            let session = Session::new(self.ctx.clone(), process_id as i32);
+            std::thread::spawn(move || {
+                // The session ends when the connection breaks.
+                let _ = session.run(stream);
+            });
+        }
+        Ok(())
+    }
+}
+
+struct PreparedStatement {
+    /// `None` for an empty query.
+    statement: Option<Statement>,
+    param_types: Vec<u32>,
+}
+
+struct Portal {
+    statement: Option<Statement>,
+    /// The results, once the portal is executed or described.
+    results: Option<Results>,
+}
+
+enum Results {
+    Rows(RowStream),
+    Command(&'static str),
+}
+
+/// The rows of a query, converted to text batch by batch.
+struct RowStream {
+    schema: SchemaRef,
+    batches: CollectBatches,
+    batch: Vec<StringChunked>,
+    offset: usize,
+    num_rows: usize,
+    done: bool,
+}
+
+impl RowStream {
+    fn new(mut lf: LazyFrame) -> PolarsResult<Self> {
+        let schema = lf.collect_schema()?;
+        let batches = lf.collect_batches(Engine::Auto, true, None, true)?;
+        Ok(Self {
+            schema,
+            batches,
+            batch: vec![],
+            offset: 0,
+            num_rows: 0,
+            done: false,
+        })
+    }
+
+    fn row_description(&self) -> Message {
+        let mut msg = Message::new(b'T').i16(self.schema.len() as i16);
+        for (name, dtype) in self.schema.iter() {
+            let (oid, size) = types::pg_type(dtype);
+            msg = msg
+                .cstr(name)
+                .i32(0)
+                .i16(0)
+                .i32(oid as i32)
+                .i16(size)
+                .i32(-1)
+                .i16(0);
+        }
+        msg
+    }
+
+    /// Write up to `max_rows` rows, or all rows if it is zero, returning whether all rows have
+    /// been written.
+    fn write_rows(&mut self, w: &mut impl Write, max_rows: usize) -> PolarsResult<bool> {
+        let mut written = 0;
+        while max_rows == 0 || written < max_rows {
+            let height = self.batch.first().map_or(0, |c| c.len());
+            if self.offset == height {
+                match self.batches.next() {
+                    None => {
+                        self.done = true;
+                        break;
+                    },
+                    Some(df) => {
+                        let df = df?;
+                        self.batch = df
+                            .columns()
+                            .iter()
+                            .map(|c| Ok(types::to_text(c)?.rechunk().into_owned()))
+                            .collect::<PolarsResult<_>>()?;
+                        self.offset = 0;
+                        continue;
+                    },
+                }
+            }
+
+            let mut msg = Message::new(b'D').i16(self.batch.len() as i16);
+            for c in &self.batch {
+                msg = msg.value(c.get(self.offset));
+            }
+            msg.write(w)?;
+            self.offset += 1;
+            self.num_rows += 1;
+            written += 1;
+        }
+        Ok(self.done)
+    }
+}
+
+struct Session {
+    ctx: Arc<Mutex<SQLContext>>,
+    process_id: i32,
+    statements: PlHashMap<String, PreparedStatement>,
+    portals: PlHashMap<String, Portal>,
+}
+
+impl Session {
+    fn new(ctx: Arc<Mutex<SQLContext>>, process_id: i32) -> Self {
+        Self {
+            ctx,
+            process_id,
+            statements: Default::default(),
+            portals: Default::default(),
+        }
+    }
+
+    fn run(mut self, stream: TcpStream) -> PolarsResult<()> {
+        let mut r = BufReader::new(stream.try_clone()?);
+        let mut w = BufWriter::new(stream);
+
+        loop {
+            match messages::read_startup_message(&mut r)? {
+                StartupMessage::EncryptionRequest => {
+                    w.write_all(b"N")?;
+                    w.flush()?;
+                },
+                // Queries run to completion, so there is nothing to cancel.
+                StartupMessage::CancelRequest => return Ok(()),
+                StartupMessage::Startup => break,
+            }
+        }
+        Message::new(b'R').i32(0).write(&mut w)?;
+        for (name, value) in [
+            ("server_version", "16.0"),
+            ("server_encoding", "UTF8"),
+            ("client_encoding", "UTF8"),
+            ("DateStyle", "ISO, MDY"),
+            ("integer_datetimes", "on"),
+            ("standard_conforming_strings", "on"),
+        ] {
+            Message::new(b'S').cstr(name).cstr(value).write(&mut w)?;
+        }
+        Message::new(b'K')
+            .i32(self.process_id)
+            .i32(0)
+            .write(&mut w)?;
+        ready_for_query(&mut w)?;
+        w.flush()?;
+
+        // After an error in the extended query protocol, messages are skipped up to the next
+        // Sync.
+        let mut skip_until_sync = false;
+        while let Some((tag, body)) = messages::read_message(&mut r)? {
+            let mut body = MessageReader::new(&body);
+            let result = match tag {
+                b'X' => return Ok(()),
+                b'S' => {
+                    skip_until_sync = false;
+                    self.portals.remove("");
+                    ready_for_query(&mut w)
+                },
+                _ if skip_until_sync => continue,
+                b'Q' => self.simple_query(&mut body, &mut w),
+                b'P' => self.parse(&mut body, &mut w),
+                b'B' => self.bind(&mut body, &mut w),
+                b'D' => self.describe(&mut body, &mut w),
+                b'E' => self.execute(&mut body, &mut w),
+                b'C' => self.close(&mut body, &mut w),
+                b'H' => Ok(()),
+                _ => Err(polars_err!(
+                    SQLInterface: "unsupported message type '{}'", tag as char
+                )),
+            };
+            if let Err(err) = result {
+                if let PolarsError::IO { .. } = err {
+                    return Err(err);
+                }
+                error_response(&err, &mut w)?;
+                if tag == b'Q' {
+                    ready_for_query(&mut w)?;
+                } else {
+                    skip_until_sync = true;
+                }
+            }
+            w.flush()?;
+        }
+        Ok(())
+    }
+
+    fn simple_query(&mut self, body: &mut MessageReader, w: &mut impl Write) -> PolarsResult<()> {
+        let statements = parse_statements(&body.cstr()?)?;
+        if statements.is_empty() {
+            Message::new(b'I').write(w)?;
+        }
+        for stmt in &statements {
+            match execute(&self.ctx, stmt)? {
+                Results::Rows(mut rows) => {
+                    rows.row_description().write(w)?;
+                    rows.write_rows(w, 0)?;
+                    command_complete(&format!("SELECT {}", rows.num_rows), w)?;
+                },
+                Results::Command(tag) => command_complete(tag, w)?,
+            }
+        }
+        ready_for_query(w)
+    }
+
+    fn parse(&mut self, body: &mut MessageReader, w: &mut impl Write) -> PolarsResult<()> {
+        let name = body.cstr()?;
+        let statements = parse_statements(&body.cstr()?)?;
+        polars_ensure!(
+            statements.len() <= 1,
+            SQLInterface: "cannot insert multiple commands into a prepared statement"
+        );
+        let num_params = body.i16()?;
+        let param_types = (0..num_params)
+            .map(|_| Ok(body.i32()? as u32))
+            .collect::<PolarsResult<_>>()?;
+        let statement = statements.into_iter().next();
+        self.statements.insert(
+            name,
+            PreparedStatement {
+                statement,
+                param_types,
+            },
+        );
+        Message::new(b'1').write(w)
+    }
+
+    fn bind(&mut self, body: &mut MessageReader, w: &mut impl Write) -> PolarsResult<()> {
+        let portal = body.cstr()?;
+        let name = body.cstr()?;
+        let Some(prepared) = self.statements.get(&name) else {
+            polars_bail!(SQLInterface: "prepared statement \"{}\" does not exist", name);
+        };
+
+        let num_formats = body.i16()? as usize;
+        let formats = (0..num_formats)
+            .map(|_| body.i16())
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let num_params = body.i16()? as usize;
+        let mut params = Vec::with_capacity(num_params);
+        for i in 0..num_params {
+            let len = body.i32()?;
+            let value = if len < 0 {
+                None
+            } else {
+                Some(body.bytes(len as usize)?)
+            };
+            let oid = prepared
+                .param_types
+                .get(i)
+                .copied()
+                .unwrap_or(types::UNSPECIFIED);
+            // A single format applies to all parameters.
+            let binary = formats.get(if num_formats == 1 { 0 } else { i }) == Some(&1);
+            params.push(types::parameter_value(value, oid, binary)?);
+        }
+        let num_result_formats = body.i16()?;
+        for _ in 0..num_result_formats {
+            polars_ensure!(
+                body.i16()? == 0,
+                SQLInterface: "only the text format is supported for results"
+            );
+        }
+
+        let mut statement = prepared.statement.clone();
+        if let Some(stmt) = &mut statement {
+            bind_parameters(stmt, |i| {
+                let value = i.checked_sub(1).and_then(|i| params.get(i));
+                value.cloned().ok_or_else(|| {
+                    polars_err!(SQLInterface: "no value was bound to parameter ${}", i)
+                })
+            })?;
+        }
+        self.portals.insert(
+            portal,
+            Portal {
+                statement,
+                results: None,
+            },
+        );
+        Message::new(b'2').write(w)
+    }
+
+    fn describe(&mut self, body: &mut MessageReader, w: &mut impl Write) -> PolarsResult<()> {
+        let kind = body.u8()?;
+        let name = body.cstr()?;
+        match kind {
+            b'S' => {
+                let Some(prepared) = self.statements.get(&name) else {
+                    polars_bail!(SQLInterface: "prepared statement \"{}\" does not exist", name);
+                };
+                let Some(stmt) = &prepared.statement else {
+                    Message::new(b't').i16(0).write(w)?;
+                    return Message::new(b'n').write(w);
+                };
+
+                // Describe the statement with all parameters being null.
+                let mut stmt = stmt.clone();
+                let mut num_params = prepared.param_types.len();
+                bind_parameters(&mut stmt, |i| {
+                    num_params = num_params.max(i);
+                    Ok(SQLValue::Null)
+                })?;
+                let mut msg = Message::new(b't').i16(num_params as i16);
+                for i in 0..num_params {
+                    let oid = prepared.param_types.get(i).copied();
+                    msg = msg.i32(oid.unwrap_or(types::UNSPECIFIED) as i32);
+                }
+                msg.write(w)?;
+
+                if returns_rows(&stmt) {
+                    let lf = self.ctx.lock().unwrap().execute_parsed(&stmt)?;
+                    RowStream::new(lf)?.row_description().write(w)
+                } else {
+                    Message::new(b'n').write(w)
+                }
+            },
+            b'P' => {
+                let Some(portal) = self.portals.get_mut(&name) else {
+                    polars_bail!(SQLInterface: "portal \"{}\" does not exist", name);
+                };
+                let Some(stmt) = portal.statement.as_ref().filter(|s| returns_rows(s)) else {
+                    return Message::new(b'n').write(w);
+                };
+                // The rows are only computed when the portal is executed.
+                if portal.results.is_none() {
+                    let lf = self.ctx.lock().unwrap().execute_parsed(stmt)?;
+                    portal.results = Some(Results::Rows(RowStream::new(lf)?));
+                }
+                match &portal.results {
+                    Some(Results::Rows(rows)) => rows.row_description().write(w),
+                    _ => unreachable!(),
+                }
+            },
+            _ => polars_bail!(SQLInterface: "invalid DESCRIBE message type '{}'", kind as char),
+        }
+    }
+
+    fn execute(&mut self, body: &mut MessageReader, w: &mut impl Write) -> PolarsResult<()> {
+        let name = body.cstr()?;
+        let max_rows = body.i32()?.max(0) as usize;
+        let Some(portal) = self.portals.get_mut(&name) else {
+            polars_bail!(SQLInterface: "portal \"{}\" does not exist", name);
+        };
+        let Some(stmt) = &portal.statement else {
+            return Message::new(b'I').write(w);
+        };
+
+        if portal.results.is_none() {
+            portal.results = Some(execute(&self.ctx, stmt)?);
+        }
+        match portal.results.as_mut().unwrap() {
+            Results::Rows(rows) => {
+                if rows.write_rows(w, max_rows)? {
+                    command_complete(&format!("SELECT {}", rows.num_rows), w)
+                } else {
+                    Message::new(b's').write(w)
+                }
+            },
+            Results::Command(tag) => command_complete(tag, w),
+        }
+    }
+
+    fn close(&mut self, body: &mut MessageReader, w: &mut impl Write) -> PolarsResult<()> {
+        let kind = body.u8()?;
+        let name = body.cstr()?;
+        match kind {
+            b'S' => self.statements.remove(&name).is_some(),
+            b'P' => self.portals.remove(&name).is_some(),
+            _ => polars_bail!(SQLInterface: "invalid CLOSE message type '{}'", kind as char),
+        };
+        Message::new(b'3').write(w)
+    }
+}
+
+/// Whether the statement returns rows, rather than only its command tag.
+fn returns_rows(stmt: &Statement) -> bool {
+    matches!(
+        stmt,
+        Statement::Query(_)
+            | Statement::ShowTables { .. }
+            | Statement::ExplainTable { .. }
+            | Statement::Explain { .. }
+    )
+}
+
+fn execute(ctx: &Mutex<SQLContext>, stmt: &Statement) -> PolarsResult<Results> {
+    let tag = match stmt {
+        // There are no transactions or settings, but clients issue these on their own.
+        Statement::StartTransaction { .. } => return Ok(Results::Command("BEGIN")),
+        Statement::Commit { .. } => return Ok(Results::Command("COMMIT")),
+        Statement::Rollback { .. } => return Ok(Results::Command("ROLLBACK")),
+        Statement::Set(_) => return Ok(Results::Command("SET")),
+        Statement::CreateTable(_) => "CREATE TABLE",
+        Statement::Drop { .. } => "DROP TABLE",
+        Statement::Truncate(_) => "TRUNCATE TABLE",
+        Statement::Delete(_) => "DELETE",
+        Statement::Insert(_) => "INSERT",
+        Statement::Copy { .. } => "COPY",
+        _ => "",
+    };
+    let lf = ctx.lock().unwrap().execute_parsed(stmt)?;
+    if returns_rows(stmt) {
+        Ok(Results::Rows(RowStream::new(lf)?))
+    } else {
+        lf.collect()?;
+        Ok(Results::Command(tag))
+    }
+}
+
+/// Replace the `$<n>` placeholders of `stmt` by the values of `param(n)`.
+fn bind_parameters(
+    stmt: &mut Statement,
+    mut param: impl FnMut(usize) -> PolarsResult<SQLValue>,
+) -> PolarsResult<()> {
+    let result = sqlparser::ast::visit_expressions_mut(stmt, |expr| {
+        if let SQLExpr::Value(ValueWithSpan {
+            value: SQLValue::Placeholder(p),
+            ..
+        }) = expr
+        {
+            let Some(i) = p.strip_prefix('$').and_then(|i| i.parse().ok()) else {
+                return ControlFlow::Break(polars_err!(
+                    SQLInterface: "unsupported placeholder '{}'; use $1, $2, ...", p
+                ));
+            };
+            match param(i) {
+                Ok(value) => {
+                    *expr = SQLExpr::Value(ValueWithSpan {
+                        value,
+                        span: Span::empty(),
+                    })
+                },
+                Err(err) => return ControlFlow::Break(err),
+            }
+        }
+        ControlFlow::Continue(())
+    });
+    match result {
+        ControlFlow::Break(err) => Err(err),
+        ControlFlow::Continue(()) => Ok(()),
+    }
+}
+
+fn ready_for_query(w: &mut impl Write) -> PolarsResult<()> {
+    // Always idle, as there are no transactions.
+    Message::new(b'Z').u8(b'I').write(w)
+}
+
+fn command_complete(tag: &str, w: &mut impl Write) -> PolarsResult<()> {
+    Message::new(b'C').cstr(tag).write(w)
+}
+
+fn error_response(err: &PolarsError, w: &mut impl Write) -> PolarsResult<()> {
+    let code = match err {
+        PolarsError::SQLSyntax(_) => "42601",
+        PolarsError::ColumnNotFound(_) => "42703",
+        PolarsError::SQLInterface(_) | PolarsError::InvalidOperation(_) => "42000",
+        _ => "XX000",
+    };
+    Message::new(b'E')
+        .u8(b'S')
+        .cstr("ERROR")
+        .u8(b'V')
+        .cstr("ERROR")
+        .u8(b'C')
+        .cstr(code)
+        .u8(b'M')
+        .cstr(&err.to_string())
+        .u8(0)
+        .write(w)
+}
@@ -0,0 +1,113 @@
+//! Conversion between polars values and the PostgreSQL text format.
+use polars_core::prelude::*;
+use sqlparser::ast::Value as SQLValue;
+
+pub(super) const UNSPECIFIED: u32 = 0;
+const BOOL: u32 = 16;
+const BYTEA: u32 = 17;
+const INT8: u32 = 20;
+const INT2: u32 = 21;
+const INT4: u32 = 23;
+const TEXT: u32 = 25;
+const FLOAT4: u32 = 700;
+const FLOAT8: u32 = 701;
+const VARCHAR: u32 = 1043;
+const DATE: u32 = 1082;
+const TIME: u32 = 1083;
+const TIMESTAMP: u32 = 1114;
+const TIMESTAMPTZ: u32 = 1184;
+const INTERVAL: u32 = 1186;
+const NUMERIC: u32 = 1700;
+
+/// The type OID and size of the PostgreSQL type that values of `dtype` are sent as.
+pub(super) fn pg_type(dtype: &DataType) -> (u32, i16) {
+    match dtype {
+        DataType::Boolean => (BOOL, 1),
+        DataType::Int8 | DataType::Int16 | DataType::UInt8 => (INT2, 2),
+        DataType::Int32 | DataType::UInt16 => (INT4, 4),
+        DataType::Int64 | DataType::UInt32 => (INT8, 8),
+        DataType::Float32 => (FLOAT4, 4),
+        DataType::Float64 => (FLOAT8, 8),
+        DataType::Date => (DATE, 4),
+        DataType::Time => (TIME, 8),
+        DataType::Datetime(_, None) => (TIMESTAMP, 8),
+        DataType::Datetime(_, Some(_)) => (TIMESTAMPTZ, 8),
+        DataType::Duration(_) => (INTERVAL, 16),
+        DataType::Binary => (BYTEA, -1),
+        dt if dt.is_integer() || dt.is_decimal() => (NUMERIC, -1),
+        _ => (TEXT, -1),
+    }
+}
+
+/// Format the values of a column in the PostgreSQL text format.
+pub(super) fn to_text(c: &Column) -> PolarsResult<StringChunked> {
+    let s = c.as_materialized_series();
+    let out: StringChunked = match s.dtype() {
+        DataType::Boolean => s
+            .bool()?
+            .into_iter()
+            .map(|v| v.map(|v| if v { "t" } else { "f" }))
+            .collect(),
+        DataType::Binary => s
+            .binary()?
+            .into_iter()
+            .map(|v| v.map(|v| format!("\\x{}", hex::encode(v))))
+            .collect(),
+        DataType::Float32 | DataType::Float64 => s
+            .cast(&DataType::String)?
+            .str()?
+            .into_iter()
+            .map(|v| {
+                v.map(|v| match v {
+                    "inf" => "Infinity",
+                    "-inf" => "-Infinity",
+                    v => v,
+                })
+            })
+            .collect(),
+        dt if dt.is_nested() || dt.is_object() => {
+            polars_bail!(SQLInterface: "cannot send values of type {} to a PostgreSQL client", dt)
+        },
+        _ => s.cast(&DataType::String)?.str()?.clone(),
+    };
+    Ok(out.with_name(s.name().clone()))
+}
+
+/// Convert a parameter of a prepared statement to a SQL literal.
+///
+/// Parameters of an unspecified type are passed as strings.
+pub(super) fn parameter_value(
+    value: Option<&[u8]>,
+    oid: u32,
+    binary: bool,
+) -> PolarsResult<SQLValue> {
+    let Some(value) = value else {
+        return Ok(SQLValue::Null);
+    };
+    let number = |v: String| SQLValue::Number(v, false);
+    if binary {
+        return Ok(match (oid, value.len()) {
+            (BOOL, 1) => SQLValue::Boolean(value[0] != 0),
+            (INT2, 2) => number(i16::from_be_bytes(value.try_into().unwrap()).to_string()),
+            (INT4, 4) => number(i32::from_be_bytes(value.try_into().unwrap()).to_string()),
+            (INT8, 8) => number(i64::from_be_bytes(value.try_into().unwrap()).to_string()),
+            (FLOAT4, 4) => number(f32::from_be_bytes(value.try_into().unwrap()).to_string()),
+            (FLOAT8, 8) => number(f64::from_be_bytes(value.try_into().unwrap()).to_string()),
+            (UNSPECIFIED | TEXT | VARCHAR, _) => SQLValue::SingleQuotedString(utf8(value)?),
+            _ => polars_bail!(
+                SQLInterface: "binary parameters of type OID {} are not supported", oid
+            ),
+        });
+    }
+    let value = utf8(value)?;
+    Ok(match oid {
+        BOOL => SQLValue::Boolean(matches!(value.as_str(), "t" | "true" | "1" | "on" | "yes")),
+        INT2 | INT4 | INT8 | FLOAT4 | FLOAT8 | NUMERIC => number(value),
+        _ => SQLValue::SingleQuotedString(value),
+    })
+}
+
+fn utf8(value: &[u8]) -> PolarsResult<String> {
+    String::from_utf8(value.to_vec())
+        .map_err(|_| polars_err!(SQLInterface: "parameter is not valid UTF-8"))
+}
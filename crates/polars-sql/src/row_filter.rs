@@ -0,0 +1,84 @@
+//! This module defines hooks to inject row-level security filters into SQL queries.
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+
+/// Attributes of the session executing queries, e.g. the tenant or user id.
+///
+/// The attributes are passed to the [`RowFilterProvider`] for every scan of a registered table.
+#[derive(Clone, Debug, Default)]
+pub struct SessionContext {
+    attributes: PlHashMap<PlSmallStr, Scalar>,
+}
+
+impl SessionContext {
+    /// Create an empty session context.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the attribute `name` to `value`.
+    pub fn with_attribute(mut self, name: impl Into<PlSmallStr>, value: impl Into<Scalar>) -> Self {
+        self.set_attribute(name, value);
+        self
+    }
+
+    /// Set the attribute `name` to `value`.
+    pub fn set_attribute(&mut self, name: impl Into<PlSmallStr>, value: impl Into<Scalar>) {
+        self.attributes.insert(name.into(), value.into());
+    }
+
+    /// Get the attribute `name`.
+    pub fn get_attribute(&self, name: &str) -> Option<&Scalar> {
+        self.attributes.get(name)
+    }
+}
+
+/// Supplies the predicates that are applied to every scan of a registered table.
+pub trait RowFilterProvider: Send + Sync {
+    /// Get the predicate for `table` in the given session, or `None` if the table is not
+    /// filtered. Returning an error fails the query.
+    fn row_filter(&self, table: &str, session: &SessionContext) -> PolarsResult<Option<Expr>>;
+}
+
+/// A [`RowFilterProvider`] that restricts tables to the rows where a column equals a session
+/// attribute, e.g. `tenant_id = <session tenant_id>`.
+///
+/// Queries on a scoped table fail if the session does not set the attribute.
+#[derive(Clone, Debug)]
+pub struct AttributeRowFilter {
+    attribute: PlSmallStr,
+    tables: PlHashMap<String, PlSmallStr>,
+}
+
+impl AttributeRowFilter {
+    /// Create a filter on the session attribute `attribute`.
+    pub fn new(attribute: impl Into<PlSmallStr>) -> Self {
+        Self {
+            attribute: attribute.into(),
+            tables: Default::default(),
+        }
+    }
+
+    /// Scope `table` by comparing its column `column` to the session attribute.
+    pub fn with_table(mut self, table: &str, column: impl Into<PlSmallStr>) -> Self {
+        self.tables.insert(table.to_owned(), column.into());
+        self
+    }
+}
+
+impl RowFilterProvider for AttributeRowFilter {
+    fn row_filter(&self, table: &str, session: &SessionContext) -> PolarsResult<Option<Expr>> {
+        let Some(column) = self.tables.get(table) else {
+            return Ok(None);
+        };
+        let Some(value) = session.get_attribute(&self.attribute) else {
+            polars_bail!(
+                SQLInterface: "table '{}' requires session attribute '{}', which is not set",
+                table, self.attribute
+            );
+        };
+        let value = Expr::Literal(LiteralValue::Scalar(value.clone()));
+        Ok(Some(col(column.clone()).eq(value)))
+    }
+}
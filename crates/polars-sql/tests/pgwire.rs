@@ -0,0 +1,145 @@
+#![cfg(feature = "pgwire")]
+use std::io::{Read, Write};
+use std::net::TcpStream;
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::IntoLazy;
+use polars_sql::SQLContext;
+use polars_sql::pgwire::PgWireServer;
+
+fn connect() -> TcpStream {
+    let mut ctx = SQLContext::new();
+    let df = df! {
+        "a" => [1, 2, 3],
+        "b" => [Some("x"), None, Some("z")],
+    }
+    .unwrap();
+    ctx.register("df", df.lazy());
+
+    let server = PgWireServer::bind(ctx, "127.0.0.1:0").unwrap();
+    let addr = server.local_addr().unwrap();
+    std::thread::spawn(move || server.serve());
+
+    let mut stream = TcpStream::connect(addr).unwrap();
+    let mut startup = 196608i32.to_be_bytes().to_vec();
+    startup.extend_from_slice(b"user\0test\0\0");
+    stream
+        .write_all(&(startup.len() as i32 + 4).to_be_bytes())
+        .unwrap();
+    stream.write_all(&startup).unwrap();
+    let messages = read_until_ready(&mut stream);
+    assert_eq!(messages.first().unwrap().0, b'R');
+    stream
+}
+
+fn send(stream: &mut TcpStream, tag: u8, body: &[u8]) {
+    stream.write_all(&[tag]).unwrap();
+    stream
+        .write_all(&(body.len() as i32 + 4).to_be_bytes())
+        .unwrap();
+    stream.write_all(body).unwrap();
+}
+
+fn read_until_ready(stream: &mut TcpStream) -> Vec<(u8, Vec<u8>)> {
+    let mut messages = vec![];
+    loop {
+        let mut header = [0; 5];
+        stream.read_exact(&mut header).unwrap();
+        let len = i32::from_be_bytes(header[1..].try_into().unwrap()) as usize;
+        let mut body = vec![0; len - 4];
+        stream.read_exact(&mut body).unwrap();
+        if header[0] == b'Z' {
+            return messages;
+        }
+        messages.push((header[0], body));
+    }
+}
+
+/// The values of a data row.
+fn data_row(body: &[u8]) -> Vec<Option<String>> {
+    let n = i16::from_be_bytes(body[..2].try_into().unwrap());
+    let mut offset = 2;
+    (0..n)
+        .map(|_| {
+            let len = i32::from_be_bytes(body[offset..offset + 4].try_into().unwrap());
+            offset += 4;
+            (len >= 0).then(|| {
+                let value = &body[offset..offset + len as usize];
+                offset += len as usize;
+                String::from_utf8(value.to_vec()).unwrap()
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn test_simple_query() {
+    let mut stream = connect();
+    send(&mut stream, b'Q', b"SELECT a, b FROM df WHERE a > 1 ORDER BY a\0");
+    let messages = read_until_ready(&mut stream);
+    let tags = messages.iter().map(|m| m.0).collect::<Vec<_>>();
+    assert_eq!(tags, b"TDDC");
+    assert_eq!(data_row(&messages[1].1), [Some("2".to_string()), None]);
+    assert_eq!(
+        data_row(&messages[2].1),
+        [Some("3".to_string()), Some("z".to_string())]
+    );
+    assert_eq!(messages[3].1, b"SELECT 2\0");
+
+    // Errors are reported, after which the session continues.
+    send(&mut stream, b'Q', b"SELECT c FROM df\0");
+    let messages = read_until_ready(&mut stream);
+    assert_eq!(messages.last().unwrap().0, b'E');
+
+    let query = b"CREATE TABLE t AS SELECT a FROM df; SELECT COUNT(*) FROM t\0";
+    send(&mut stream, b'Q', query);
+    let messages = read_until_ready(&mut stream);
+    let tags = messages.iter().map(|m| m.0).collect::<Vec<_>>();
+    assert_eq!(tags, b"CTDC");
+    assert_eq!(messages[0].1, b"CREATE TABLE\0");
+    assert_eq!(data_row(&messages[2].1), [Some("3".to_string())]);
+}
+
+#[test]
+fn test_extended_query() {
+    let mut stream = connect();
+
+    // Parse, with the parameter being an int8.
+    let mut body = b"stmt\0SELECT b FROM df WHERE a = $1\0".to_vec();
+    body.extend_from_slice(&1i16.to_be_bytes());
+    body.extend_from_slice(&20i32.to_be_bytes());
+    send(&mut stream, b'P', &body);
+
+    // Bind the value 3 in the text format.
+    let mut body = b"\0stmt\0".to_vec();
+    body.extend_from_slice(&0i16.to_be_bytes());
+    body.extend_from_slice(&1i16.to_be_bytes());
+    body.extend_from_slice(&1i32.to_be_bytes());
+    body.extend_from_slice(b"3");
+    body.extend_from_slice(&0i16.to_be_bytes());
+    send(&mut stream, b'B', &body);
+
+    send(&mut stream, b'D', b"P\0");
+    let mut body = b"\0".to_vec();
+    body.extend_from_slice(&0i32.to_be_bytes());
+    send(&mut stream, b'E', &body);
+    send(&mut stream, b'S', b"");
+
+    let messages = read_until_ready(&mut stream);
+    let tags = messages.iter().map(|m| m.0).collect::<Vec<_>>();
+    assert_eq!(tags, b"12TDC");
+    assert_eq!(data_row(&messages[3].1), [Some("z".to_string())]);
+    assert_eq!(messages[4].1, b"SELECT 1\0");
+}
+
+#[test]
+fn test_message_too_long() {
+    let mut stream = connect();
+
+    // The server closes the connection instead of allocating the claimed length.
+    stream.write_all(b"Q").unwrap();
+    stream.write_all(&i32::MAX.to_be_bytes()).unwrap();
+    let mut rest = vec![];
+    stream.read_to_end(&mut rest).unwrap();
+    assert!(rest.is_empty());
+}
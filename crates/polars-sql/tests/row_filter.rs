@@ -0,0 +1,154 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::IntoLazy;
+use polars_sql::SQLContext;
+use polars_sql::row_filter::{AttributeRowFilter, SessionContext};
+
+fn create_ctx() -> PolarsResult<SQLContext> {
+    let orders = df! {
+        "order_id" => [1, 2, 3, 4],
+        "tenant_id" => [1, 2, 1, 2],
+        "amount" => [10, 20, 30, 40],
+    }?;
+    let tenants = df! {
+        "tenant_id" => [1, 2],
+        "name" => ["foo", "bar"],
+    }?;
+
+    let mut ctx = SQLContext::new().with_row_filter(Arc::new(
+        AttributeRowFilter::new("tenant_id").with_table("orders", "tenant_id"),
+    ));
+    ctx.register("orders", orders.lazy());
+    ctx.register("tenants", tenants.lazy());
+    Ok(ctx)
+}
+
+#[test]
+fn test_row_filter() -> PolarsResult<()> {
+    let mut ctx = create_ctx()?;
+    ctx.set_session(SessionContext::new().with_attribute("tenant_id", 1i32));
+
+    let out = ctx
+        .execute("SELECT order_id FROM orders ORDER BY order_id")?
+        .collect()?;
+    assert_eq!(Vec::from(out.column("order_id")?.i32()?), &[Some(1), Some(3)]);
+
+    // The filter also applies to joins, subqueries and CTEs.
+    let out = ctx
+        .execute(
+            "
+        WITH o AS (SELECT * FROM orders)
+        SELECT SUM(o.amount) AS total FROM o
+        JOIN tenants t ON o.tenant_id = t.tenant_id
+        WHERE o.order_id IN (SELECT order_id FROM orders)",
+        )?
+        .collect()?;
+    assert_eq!(out.column("total")?.i32()?.get(0), Some(40));
+
+    // Tables without a filter are not scoped.
+    let out = ctx.execute("SELECT * FROM tenants")?.collect()?;
+    assert_eq!(out.height(), 2);
+
+    ctx.set_session(SessionContext::new().with_attribute("tenant_id", 2i32));
+    let out = ctx.execute("SELECT SUM(amount) AS total FROM orders")?.collect()?;
+    assert_eq!(out.column("total")?.i32()?.get(0), Some(60));
+
+    Ok(())
+}
+
+#[test]
+fn test_row_filter_missing_attribute() -> PolarsResult<()> {
+    let mut ctx = create_ctx()?;
+    assert!(matches!(
+        ctx.execute("SELECT * FROM orders"),
+        Err(PolarsError::SQLInterface(_))
+    ));
+    assert!(ctx.execute("SELECT * FROM tenants").is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_row_filter_create_table_as() -> PolarsResult<()> {
+    let mut ctx = create_ctx()?;
+    ctx.set_session(SessionContext::new().with_attribute("tenant_id", 1i32));
+
+    ctx.execute("CREATE TABLE orders_copy AS SELECT * FROM orders")?
+        .collect()?;
+    ctx.set_session(SessionContext::new().with_attribute("tenant_id", 2i32));
+    let out = ctx
+        .execute("SELECT order_id FROM orders_copy ORDER BY order_id")?
+        .collect()?;
+    assert_eq!(Vec::from(out.column("order_id")?.i32()?), &[Some(1), Some(3)]);
+    Ok(())
+}
+
+#[test]
+fn test_row_filter_insert_select() -> PolarsResult<()> {
+    let mut ctx = create_ctx()?;
+    ctx.set_session(SessionContext::new().with_attribute("tenant_id", 2i32));
+
+    ctx.execute("CREATE TABLE orders_copy (order_id INT, tenant_id INT, amount INT)")?
+        .collect()?;
+    ctx.execute("INSERT INTO orders_copy SELECT * FROM orders")?
+        .collect()?;
+    let out = ctx
+        .execute("SELECT order_id FROM orders_copy ORDER BY order_id")?
+        .collect()?;
+    assert_eq!(Vec::from(out.column("order_id")?.i32()?), &[Some(2), Some(4)]);
+    Ok(())
+}
+
+#[test]
+fn test_row_filter_insert_values() -> PolarsResult<()> {
+    let mut ctx = create_ctx()?;
+    ctx.set_session(SessionContext::new().with_attribute("tenant_id", 1i32));
+
+    // Rows that the session could not see are rejected.
+    assert!(matches!(
+        ctx.execute("INSERT INTO orders VALUES (5, 2, 50)"),
+        Err(PolarsError::SQLInterface(_))
+    ));
+    ctx.execute("INSERT INTO orders VALUES (5, 1, 50)")?
+        .collect()?;
+
+    // OVERWRITE only replaces the rows that are visible to the session.
+    ctx.execute("INSERT OVERWRITE TABLE orders VALUES (6, 1, 60)")?
+        .collect()?;
+    let out = ctx
+        .execute("SELECT order_id FROM orders ORDER BY order_id")?
+        .collect()?;
+    assert_eq!(Vec::from(out.column("order_id")?.i32()?), &[Some(6)]);
+    ctx.set_session(SessionContext::new().with_attribute("tenant_id", 2i32));
+    let out = ctx
+        .execute("SELECT order_id FROM orders ORDER BY order_id")?
+        .collect()?;
+    assert_eq!(Vec::from(out.column("order_id")?.i32()?), &[Some(2), Some(4)]);
+    Ok(())
+}
+
+#[test]
+fn test_row_filter_truncate() -> PolarsResult<()> {
+    let mut ctx = create_ctx()?;
+    ctx.set_session(SessionContext::new().with_attribute("tenant_id", 1i32));
+
+    // Only the rows that are visible to the session are removed.
+    let out = ctx.execute("TRUNCATE orders")?.collect()?;
+    assert_eq!(out.height(), 0);
+    ctx.set_session(SessionContext::new().with_attribute("tenant_id", 2i32));
+    let out = ctx
+        .execute("SELECT order_id FROM orders ORDER BY order_id")?
+        .collect()?;
+    assert_eq!(Vec::from(out.column("order_id")?.i32()?), &[Some(2), Some(4)]);
+    Ok(())
+}
+
+#[test]
+fn test_row_filter_table_map() -> PolarsResult<()> {
+    let mut ctx = create_ctx()?;
+    assert!(ctx.get_table_map().is_err());
+
+    ctx.set_session(SessionContext::new().with_attribute("tenant_id", 1i32));
+    let tables = ctx.get_table_map()?;
+    assert_eq!(tables["orders"].clone().collect()?.height(), 2);
+    assert_eq!(tables["tenants"].clone().collect()?.height(), 2);
+    Ok(())
+}
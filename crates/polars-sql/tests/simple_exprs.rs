@@ -856,6 +856,55 @@ fn test_cte_values() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_cte_recursive() -> PolarsResult<()> {
+    let edges = df! {
+        "src" => [1i64, 2, 3, 3],
+        "dst" => [2i64, 3, 1, 4],
+    }?;
+    let mut context = SQLContext::new();
+    context.register("edges", edges.lazy());
+
+    // UNION ALL: iterate until the recursive term returns no rows.
+    let sql = r#"
+        WITH RECURSIVE counter(n) AS (
+            SELECT CAST(1 AS BIGINT)
+            UNION ALL
+            SELECT n + 1 FROM counter WHERE n < 5
+        )
+        SELECT n FROM counter
+    "#;
+    let df = context.execute(sql)?.collect()?;
+    let expected = df! { "n" => [1i64, 2, 3, 4, 5] }?;
+    assert!(df.equals(&expected));
+
+    // UNION: iterate until the recursive term returns no new rows (the graph has a cycle).
+    let sql = r#"
+        WITH RECURSIVE reachable AS (
+            SELECT CAST(1 AS BIGINT) AS node
+            UNION
+            SELECT e.dst FROM reachable r JOIN edges e ON r.node = e.src
+        )
+        SELECT node FROM reachable ORDER BY node
+    "#;
+    let df = context.execute(sql)?.collect()?;
+    let expected = df! { "node" => [1i64, 2, 3, 4] }?;
+    assert!(df.equals(&expected));
+
+    // Guard against recursions that don't terminate.
+    let sql = r#"
+        WITH RECURSIVE counter(n) AS (
+            SELECT 1 UNION ALL SELECT n + 1 FROM counter
+        )
+        SELECT n FROM counter
+    "#;
+    let mut context = SQLContext::new().with_max_recursive_iterations(10);
+    let err = context.execute(sql).unwrap_err();
+    assert!(err.to_string().contains("did not terminate within 10 iterations"));
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "ipc")]
 fn test_group_by_2() -> PolarsResult<()> {
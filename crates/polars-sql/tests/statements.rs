@@ -173,6 +173,25 @@ fn test_drop_table() {
     assert!(res.is_err());
 }
 
+#[test]
+fn test_insert_into() {
+    let mut ctx = create_ctx();
+    ctx.execute("CREATE TABLE tbl AS SELECT b, a FROM df WHERE b < 3").unwrap();
+    ctx.execute("INSERT INTO tbl VALUES (10, 20), (11, 21)").unwrap();
+    ctx.execute("INSERT INTO tbl (b) SELECT b * 100 FROM df WHERE b = 1").unwrap();
+
+    let actual = ctx.execute("SELECT * FROM tbl").unwrap().collect().unwrap();
+    let expected = df! {
+        "b" => [1i64, 2, 10, 11, 100],
+        "a" => [Some(0i64), Some(0), Some(20), Some(21), None],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected));
+
+    // The number of values must match the number of columns.
+    assert!(ctx.execute("INSERT INTO tbl VALUES (1)").is_err());
+}
+
 #[test]
 fn iss_9560_join_as() {
     let df1 = df! {"id"=> [1, 2, 3, 4], "ano"=> [2, 3, 4, 5]}.unwrap();
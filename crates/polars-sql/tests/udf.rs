@@ -1,9 +1,9 @@
 use polars_core::prelude::*;
 use polars_lazy::prelude::IntoLazy;
-use polars_plan::dsl::BaseColumnUdf;
+use polars_plan::dsl::{BaseColumnUdf, Expr, min_horizontal};
 use polars_plan::prelude::UserDefinedFunction;
 use polars_sql::SQLContext;
-use polars_sql::function_registry::FunctionRegistry;
+use polars_sql::function_registry::{FunctionRegistry, SQLArgType, SQLFunctionSignature};
 
 struct MyFunctionRegistry {
     functions: PlHashMap<String, UserDefinedFunction>,
@@ -115,3 +115,62 @@ fn test_udfs() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_registered_functions() -> PolarsResult<()> {
+    let mut ctx = SQLContext::new();
+    ctx.register_function(
+        "clamp_to",
+        SQLFunctionSignature::new(vec![SQLArgType::Numeric, SQLArgType::Integer]),
+        |args| {
+            let [value, max] = <[Expr; 2]>::try_from(args).unwrap();
+            min_horizontal([value, max])
+        },
+    );
+    ctx.register_aggregate(
+        "sum_sq",
+        SQLFunctionSignature::new(vec![SQLArgType::Numeric]),
+        |mut args| Ok(args.pop().unwrap().pow(2).sum()),
+    );
+
+    let df = df! {
+        "k" => &["x", "y", "x"],
+        "v" => &[1i64, 2, 3],
+    }?;
+    ctx.register("df", df.lazy());
+
+    let res = ctx.execute("SELECT k, CLAMP_TO(v, 2) AS c FROM df")?.collect()?;
+    let expected = df! {
+        "k" => &["x", "y", "x"],
+        "c" => &[1i64, 2, 2],
+    }?;
+    assert!(expected.equals(&res));
+
+    let res = ctx
+        .execute("SELECT k, sum_sq(v) AS s FROM df GROUP BY k ORDER BY k")?
+        .collect()?;
+    let expected = df! {
+        "k" => &["x", "y"],
+        "s" => &[10i64, 4],
+    }?;
+    assert!(expected.equals(&res));
+
+    let res = ctx
+        .execute("SELECT v, sum_sq(v) OVER (PARTITION BY k) AS s FROM df ORDER BY v")?
+        .collect()?;
+    let expected = df! {
+        "v" => &[1i64, 2, 3],
+        "s" => &[10i64, 4, 10],
+    }?;
+    assert!(expected.equals(&res));
+
+    // Calls are checked against the signature when the query is translated.
+    for sql in [
+        "SELECT clamp_to(v) FROM df",
+        "SELECT clamp_to(k, 2) FROM df",
+        "SELECT clamp_to(v, 2) OVER (PARTITION BY k) FROM df",
+    ] {
+        assert!(matches!(ctx.execute(sql), Err(PolarsError::SQLSyntax(_))));
+    }
+    Ok(())
+}
@@ -37,9 +37,15 @@ pub fn track_task_metrics(should_track: bool) {
 
 static GLOBAL_SCHEDULER: OnceLock<Executor> = OnceLock::new();
 
+/// Executors with a fixed number of threads, at most one per number of threads, see
+/// [`with_num_threads`].
+static SIZED_EXECUTORS: Mutex<Vec<&'static Executor>> = Mutex::new(Vec::new());
+
 thread_local!(
-    /// Used to store which executor thread this is.
+    /// Used to store which executor thread this is, of the executor in `TLS_EXECUTOR`.
     static TLS_THREAD_ID: Cell<usize> = const { Cell::new(usize::MAX) };
+    /// The executor that runs the tasks spawned on this thread, the global executor if `None`.
+    static TLS_EXECUTOR: Cell<Option<&'static Executor>> = const { Cell::new(None) };
 );
 
 slotmap::new_key_type! {
@@ -181,7 +187,13 @@ impl Executor {
     fn schedule_task(&self, task: ReadyTask) {
         let thread = TLS_THREAD_ID.get();
         let meta = task.metadata();
-        let opt_ttl = self.thread_task_lists.get(thread);
+        // Only the threads of this executor have a task list.
+        let is_own_thread = TLS_EXECUTOR
+            .get()
+            .is_some_and(|executor| std::ptr::eq(executor, self));
+        let opt_ttl = is_own_thread
+            .then(|| self.thread_task_lists.get(thread))
+            .flatten();
 
         let mut use_global_queue = opt_ttl.is_none();
         if meta.freshly_spawned.load(Ordering::Relaxed) {
@@ -269,8 +281,9 @@ impl Executor {
         None
     }
 
-    fn runner(&self, thread: usize) {
+    fn runner(&'static self, thread: usize) {
         TLS_THREAD_ID.set(thread);
+        TLS_EXECUTOR.set(Some(self));
         ALLOW_RAYON_THREADS.set(false);
 
         let mut rng = SmallRng::from_rng(&mut rand::rng());
@@ -325,38 +338,92 @@ impl Executor {
         }
     }
 
+    fn new(n_threads: usize) -> Self {
+        let thread_task_lists = (0..n_threads)
+            .map(|_| {
+                let high_prio_tasks = WorkQueue::new_lifo();
+                CachePadded::new(ThreadLocalTaskList {
+                    high_prio_tasks_stealer: high_prio_tasks.stealer(),
+                    high_prio_tasks,
+                    local_slot: UnsafeCell::new(None),
+                })
+            })
+            .collect();
+        Self {
+            park_group: ParkGroup::new(),
+            thread_task_lists,
+            global_high_prio_task_queue: Injector::new(),
+            global_low_prio_task_queue: Injector::new(),
+        }
+    }
+
+    fn spawn_runners(&'static self, name: &str) {
+        for t in 0..self.thread_task_lists.len() {
+            std::thread::Builder::new()
+                .name(format!("{name}-{t}"))
+                .spawn(move || self.runner(t))
+                .unwrap();
+        }
+    }
+
     fn global() -> &'static Executor {
-        GLOBAL_SCHEDULER.get_or_init(|| {
+        let mut initialized = false;
+        let executor = GLOBAL_SCHEDULER.get_or_init(|| {
             let mut n_threads = NUM_EXECUTOR_THREADS.load();
             if n_threads == 0 {
                 n_threads = std::thread::available_parallelism()
                     .map(|n| n.get())
                     .unwrap_or(4);
             }
+            initialized = true;
+            Self::new(n_threads)
+        });
+        if initialized {
+            executor.spawn_runners("async-executor");
+        }
+        executor
+    }
 
-            let thread_task_lists = (0..n_threads)
-                .map(|t| {
-                    std::thread::Builder::new()
-                        .name(format!("async-executor-{t}"))
-                        .spawn(move || Self::global().runner(t))
-                        .unwrap();
-
-                    let high_prio_tasks = WorkQueue::new_lifo();
-                    CachePadded::new(ThreadLocalTaskList {
-                        high_prio_tasks_stealer: high_prio_tasks.stealer(),
-                        high_prio_tasks,
-                        local_slot: UnsafeCell::new(None),
-                    })
-                })
-                .collect();
-            Self {
-                park_group: ParkGroup::new(),
-                thread_task_lists,
-                global_high_prio_task_queue: Injector::new(),
-                global_low_prio_task_queue: Injector::new(),
-            }
-        })
+    fn sized(n_threads: usize) -> &'static Executor {
+        let mut executors = SIZED_EXECUTORS.lock();
+        if let Some(executor) = executors
+            .iter()
+            .find(|e| e.thread_task_lists.len() == n_threads)
+        {
+            return executor;
+        }
+        // Like the global executor, the executor lives as long as the process.
+        let executor: &'static Executor = Box::leak(Box::new(Self::new(n_threads)));
+        executor.spawn_runners(&format!("async-executor-{n_threads}"));
+        executors.push(executor);
+        executor
+    }
+
+    /// The executor that runs the tasks spawned on the current thread.
+    fn current() -> &'static Executor {
+        TLS_EXECUTOR.get().unwrap_or_else(Self::global)
+    }
+}
+
+/// Run `f`, with the tasks it spawns run by an executor of `n_threads` threads instead of the
+/// global executor. The executors are created once per number of threads.
+pub fn with_num_threads<R>(n_threads: usize, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<&'static Executor>, usize);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            TLS_EXECUTOR.set(self.0);
+            TLS_THREAD_ID.set(self.1);
+        }
     }
+
+    // This thread is not a thread of the sized executor, so it has no task list there.
+    let executor = Executor::sized(n_threads);
+    let _restore = Restore(
+        TLS_EXECUTOR.replace(Some(executor)),
+        TLS_THREAD_ID.replace(usize::MAX),
+    );
+    f()
 }
 
 pub struct TaskScope<'scope, 'env: 'scope> {
@@ -406,7 +473,7 @@ impl<'scope> TaskScope<'scope, '_> {
             let metrics = TRACK_METRICS.load().then(Arc::default);
             let dyn_task = unsafe {
                 // SAFETY: we make sure to cancel this task before 'scope ends.
-                let executor = Executor::global();
+                let executor = Executor::current();
                 let on_wake = move |task| executor.schedule_task(task);
                 task::spawn_with_lifetime(
                     fut,
@@ -465,7 +532,7 @@ where
     <F as Future>::Output: Send + 'static,
 {
     let spawn_location = Location::caller();
-    let executor = Executor::global();
+    let executor = Executor::current();
     let on_wake = move |task| executor.schedule_task(task);
     let metrics = TRACK_METRICS.load().then(Arc::default);
     let dyn_task = task::spawn(
@@ -0,0 +1,434 @@
+//! Checkpointing of long-running streaming queries, so they can be resumed after a crash.
+//!
+//! A checkpoint is taken between two execution phases, when no morsels are in flight. It records
+//! the number of rows every source has emitted, which nodes have finished, and the state of the
+//! nodes that build up state until their inputs are done (group-bys, aggregations, in-memory
+//! sinks, ...). When a query is resumed, the sources skip the rows they had emitted and the
+//! stateful nodes continue from their stored state.
+//!
+//! The state of a node is what it holds in memory anyway, e.g. the groups of a group-by, so a
+//! checkpoint is never larger than the memory used by the query. Only the latest checkpoint is
+//! kept on disk.
+use std::fs::File;
+use std::io::{BufReader, BufWriter, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use polars_io::ipc::{IpcReader, IpcWriter};
+use polars_io::{SerReader, SerWriter};
+use polars_utils::relaxed_cell::RelaxedCell;
+use serde::{Deserialize, Serialize};
+
+use crate::graph::{Graph, GraphNodeKey, LogicalPipeKey};
+use crate::nodes::compute_node_prelude::*;
+
+const MANIFEST_FILE: &str = "checkpoint.json";
+const STATES_DIR: &str = "states";
+
+#[derive(Clone, Debug)]
+pub struct CheckpointOptions {
+    /// The directory the checkpoints are written to.
+    pub dir: PathBuf,
+    /// The minimum time between two checkpoints.
+    pub interval: Duration,
+}
+
+impl CheckpointOptions {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            interval: Duration::from_secs(60),
+        }
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct Manifest {
+    /// The names of the nodes in the graph, to verify that a checkpoint is resumed by the query
+    /// that wrote it.
+    nodes: Vec<String>,
+    /// The nodes that had finished.
+    finished: Vec<usize>,
+    /// The positions of the source nodes and checkpoint triggers, see
+    /// [`ComputeNode::checkpoint_position`].
+    positions: Vec<(usize, u64)>,
+    /// The number of the checkpoint, which names the directory with the states of the nodes.
+    generation: u64,
+    /// The heights of the state frames of every stateful node.
+    states: Vec<(usize, Vec<usize>)>,
+}
+
+/// Asks the sources to end the execution phase once a checkpoint is due.
+#[derive(Default)]
+struct CheckpointTrigger {
+    /// `None` while no checkpoint can be taken.
+    deadline: Mutex<Option<Instant>>,
+}
+
+impl CheckpointTrigger {
+    fn is_due(&self) -> bool {
+        self.deadline
+            .lock()
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+}
+
+/// Passes the morsels to a node with [`CheckpointMode::Stateful`], and stops the sources once a
+/// checkpoint is due.
+///
+/// Its position is the sequence id following the morsels it has passed, so that after resuming
+/// the received morsels are ordered after the stored state of the node, which has sequence id 0.
+struct CheckpointTriggerNode {
+    trigger: Arc<CheckpointTrigger>,
+    /// Offset for the sequence ids of the received morsels.
+    seq_offset: MorselSeq,
+    next_seq: RelaxedCell<u64>,
+}
+
+impl ComputeNode for CheckpointTriggerNode {
+    fn name(&self) -> &str {
+        "checkpoint-trigger"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+        recv.swap_with_slice(send);
+        Ok(())
+    }
+
+    fn checkpoint_mode(&self) -> CheckpointMode {
+        CheckpointMode::Stateless
+    }
+
+    fn checkpoint_position(&self) -> Option<u64> {
+        Some(self.next_seq.load())
+    }
+
+    fn restore_position(&mut self, position: u64) -> PolarsResult<()> {
+        self.seq_offset = MorselSeq::from_u64(position);
+        self.next_seq.store(position);
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        let receivers = recv_ports[0].take().unwrap().parallel();
+        let senders = send_ports[0].take().unwrap().parallel();
+
+        for (mut recv, mut send) in receivers.into_iter().zip(senders) {
+            let slf = &*self;
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                while let Ok(mut morsel) = recv.recv().await {
+                    morsel.set_seq(morsel.seq().offset_by(slf.seq_offset));
+                    slf.next_seq.fetch_max(morsel.seq().successor().to_u64());
+                    if slf.trigger.is_due() {
+                        morsel.source_token().stop();
+                    }
+
+                    if send.send(morsel).await.is_err() {
+                        break;
+                    }
+                }
+
+                Ok(())
+            }));
+        }
+    }
+}
+
+/// Takes the place of a node that had finished at the checkpoint a query is resumed from.
+struct FinishedNode;
+
+impl ComputeNode for FinishedNode {
+    fn name(&self) -> &str {
+        "finished"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        recv.fill(PortState::Done);
+        send.fill(PortState::Done);
+        Ok(())
+    }
+
+    fn checkpoint_mode(&self) -> CheckpointMode {
+        CheckpointMode::Stateless
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        _scope: &'s TaskScope<'s, 'env>,
+        _recv_ports: &mut [Option<RecvPort<'_>>],
+        _send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        _join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        unreachable!()
+    }
+}
+
+/// Writes the checkpoints of a query graph, and restores the graph from a checkpoint.
+pub struct Checkpointer {
+    options: CheckpointOptions,
+    /// The names of the nodes of the graph before it was restored, see [`Manifest::nodes`].
+    node_names: Vec<String>,
+    trigger: Arc<CheckpointTrigger>,
+    last_checkpoint: Instant,
+    /// The number of the last checkpoint that was written.
+    generation: u64,
+    /// Set once a node can no longer be checkpointed, e.g. a sink that started writing.
+    disabled: bool,
+}
+
+impl Checkpointer {
+    /// Insert the checkpoint triggers into the graph, and restore it from the checkpoint in the
+    /// directory if `resume` is set.
+    pub fn try_new(
+        options: CheckpointOptions,
+        graph: &mut Graph,
+        resume: bool,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            !options.interval.is_zero(),
+            InvalidOperation: "the checkpoint interval must be positive"
+        );
+        for node in graph.nodes.values() {
+            polars_ensure!(
+                node.compute.checkpoint_mode() != CheckpointMode::Unsupported,
+                InvalidOperation: "checkpointing is not supported for queries with a '{}' node",
+                node.compute.name()
+            );
+        }
+
+        std::fs::create_dir_all(&options.dir)?;
+        let manifest_path = options.dir.join(MANIFEST_FILE);
+        let manifest = if resume {
+            let file = File::open(&manifest_path).map_err(|err| match err.kind() {
+                ErrorKind::NotFound => polars_err!(
+                    ComputeError: "no checkpoint found in '{}'", options.dir.display()
+                ),
+                _ => err.into(),
+            })?;
+            let manifest: Manifest =
+                serde_json::from_reader(BufReader::new(file)).map_err(to_compute_err)?;
+            Some(manifest)
+        } else {
+            remove_if_exists(&manifest_path)?;
+            remove_if_exists(&options.dir.join(STATES_DIR))?;
+            None
+        };
+
+        let trigger = Arc::<CheckpointTrigger>::default();
+        let stateful_inputs: Vec<LogicalPipeKey> = graph
+            .nodes
+            .values()
+            .filter(|node| node.compute.checkpoint_mode() == CheckpointMode::Stateful)
+            .flat_map(|node| node.inputs.iter().copied())
+            .collect();
+        for pipe_key in stateful_inputs {
+            let node = CheckpointTriggerNode {
+                trigger: trigger.clone(),
+                seq_offset: MorselSeq::default(),
+                next_seq: RelaxedCell::from(0),
+            };
+            graph.insert_node_on_pipe(pipe_key, node);
+        }
+
+        let node_names: Vec<String> = graph
+            .nodes
+            .values()
+            .map(|node| node.compute.name().to_string())
+            .collect();
+
+        let mut checkpointer = Self {
+            options,
+            node_names,
+            trigger,
+            last_checkpoint: Instant::now(),
+            generation: 0,
+            disabled: false,
+        };
+        if let Some(manifest) = manifest {
+            checkpointer.restore(graph, manifest)?;
+        }
+        let deadline = Instant::now() + checkpointer.options.interval;
+        *checkpointer.trigger.deadline.lock() = Some(deadline);
+        Ok(checkpointer)
+    }
+
+    fn states_dir(&self, generation: u64) -> PathBuf {
+        self.options
+            .dir
+            .join(STATES_DIR)
+            .join(generation.to_string())
+    }
+
+    fn restore(&mut self, graph: &mut Graph, manifest: Manifest) -> PolarsResult<()> {
+        polars_ensure!(
+            manifest.nodes == self.node_names,
+            ComputeError: "the checkpoint in '{}' was written by a different query",
+            self.options.dir.display()
+        );
+        let keys: Vec<GraphNodeKey> = graph.nodes.keys().collect();
+        for (idx, position) in manifest.positions {
+            graph.nodes[keys[idx]].compute.restore_position(position)?;
+        }
+        let dir = self.states_dir(manifest.generation);
+        for (idx, heights) in manifest.states {
+            let state = heights
+                .iter()
+                .enumerate()
+                .map(|(i, height)| {
+                    let path = dir.join(format!("{idx}-{i}.ipc"));
+                    if path.exists() {
+                        IpcReader::new(File::open(path)?).finish()
+                    } else {
+                        Ok(DataFrame::empty_with_height(*height))
+                    }
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            graph.nodes[keys[idx]].compute.restore_state(state)?;
+        }
+        for idx in manifest.finished {
+            graph.nodes[keys[idx]].compute = Box::new(FinishedNode);
+        }
+        self.generation = manifest.generation;
+        Ok(())
+    }
+
+    /// Write a checkpoint if one is due. Called before every execution phase, when no morsels are
+    /// in flight.
+    pub fn before_phase(&mut self, graph: &mut Graph) -> PolarsResult<()> {
+        if self.disabled {
+            return Ok(());
+        }
+
+        // A stateful node that is sending its output can't send it again after resuming, so no
+        // checkpoint can be taken while such a node is sending its output.
+        let is_consistent = graph.nodes.values().all(|node| {
+            node.compute.checkpoint_mode() != CheckpointMode::Stateful
+                || node.inputs.iter().any(|i| graph.pipes[*i].send_state != PortState::Done)
+                || node.outputs.iter().all(|o| graph.pipes[*o].send_state == PortState::Done)
+        });
+        if !is_consistent {
+            *self.trigger.deadline.lock() = None;
+            return Ok(());
+        }
+
+        if self.last_checkpoint.elapsed() >= self.options.interval {
+            if !self.write(graph)? {
+                if polars_core::config::verbose() {
+                    eprintln!("polars-stream: no further checkpoints can be written");
+                }
+                self.disabled = true;
+                *self.trigger.deadline.lock() = None;
+                return Ok(());
+            }
+            self.last_checkpoint = Instant::now();
+        }
+        *self.trigger.deadline.lock() = Some(self.last_checkpoint + self.options.interval);
+        Ok(())
+    }
+
+    /// Write a checkpoint, returns `false` if a node can't be checkpointed in its current state.
+    fn write(&mut self, graph: &mut Graph) -> PolarsResult<bool> {
+        let mut manifest = Manifest {
+            nodes: self.node_names.clone(),
+            generation: self.generation + 1,
+            ..Default::default()
+        };
+        let mut states = Vec::new();
+        let pipes = &graph.pipes;
+        for (idx, node) in graph.nodes.values_mut().enumerate() {
+            let is_finished = node.inputs.iter().chain(&node.outputs).all(|p| {
+                pipes[*p].send_state == PortState::Done && pipes[*p].recv_state == PortState::Done
+            });
+            // Sinks are kept, as they hold the output of the query.
+            if is_finished && !node.outputs.is_empty() {
+                manifest.finished.push(idx);
+            } else if node.compute.checkpoint_mode() == CheckpointMode::Stateful {
+                let Some(state) = node.compute.checkpoint_state()? else {
+                    return Ok(false);
+                };
+                states.push((idx, state));
+            } else if let Some(position) = node.compute.checkpoint_position() {
+                manifest.positions.push((idx, position));
+            }
+        }
+
+        // The states are written to a new directory, so a crash never leaves a partial
+        // checkpoint behind.
+        let dir = self.states_dir(manifest.generation);
+        remove_if_exists(&dir)?;
+        std::fs::create_dir_all(&dir)?;
+        for (idx, state) in states {
+            let mut heights = Vec::with_capacity(state.len());
+            for (i, mut df) in state.into_iter().enumerate() {
+                heights.push(df.height());
+                if df.width() > 0 {
+                    let file = File::create(dir.join(format!("{idx}-{i}.ipc")))?;
+                    let mut writer = BufWriter::new(file);
+                    IpcWriter::new(&mut writer).finish(&mut df)?;
+                    writer.into_inner().map_err(|err| err.into_error())?.sync_all()?;
+                }
+            }
+            manifest.states.push((idx, heights));
+        }
+
+        let manifest_path = self.options.dir.join(MANIFEST_FILE);
+        let tmp_path = manifest_path.with_extension("json.tmp");
+        let mut writer = BufWriter::new(File::create(&tmp_path)?);
+        serde_json::to_writer(&mut writer, &manifest).map_err(to_compute_err)?;
+        writer.into_inner().map_err(|err| err.into_error())?.sync_all()?;
+        std::fs::rename(&tmp_path, &manifest_path)?;
+
+        remove_if_exists(&self.states_dir(self.generation))?;
+        self.generation = manifest.generation;
+
+        if polars_core::config::verbose() {
+            eprintln!(
+                "polars-stream: wrote checkpoint to '{}'",
+                self.options.dir.display()
+            );
+        }
+        Ok(true)
+    }
+
+    /// Remove the checkpoint once the query has completed.
+    pub fn finish(self) -> PolarsResult<()> {
+        remove_if_exists(&self.options.dir.join(MANIFEST_FILE))?;
+        remove_if_exists(&self.options.dir.join(STATES_DIR))
+    }
+}
+
+fn remove_if_exists(path: &Path) -> PolarsResult<()> {
+    let result = if path.is_dir() {
+        std::fs::remove_dir_all(path)
+    } else {
+        std::fs::remove_file(path)
+    };
+    match result {
+        Err(err) if err.kind() != ErrorKind::NotFound => Err(err.into()),
+        _ => Ok(()),
+    }
+}
@@ -283,14 +283,32 @@ fn run_subgraph(
     Ok(())
 }
 
+/// Executes the graph phase by phase, calling `before_phase` with the updated graph before every
+/// execution phase.
 pub fn execute_graph(
     graph: &mut Graph,
     metrics: Option<Arc<Mutex<GraphMetrics>>>,
+    before_phase: &mut dyn FnMut(&mut Graph) -> PolarsResult<()>,
 ) -> PolarsResult<SparseSecondaryMap<GraphNodeKey, DataFrame>> {
     // Get the number of threads from the rayon thread-pool as that respects our config.
     let num_pipelines = POOL.current_num_threads();
+    if POOL.is_scoped() {
+        // A query on a scoped thread pool gets an executor of the same size, so that it doesn't
+        // share its threads with the queries on the global executor.
+        return async_executor::with_num_threads(num_pipelines, || {
+            execute_graph_with(graph, metrics, before_phase, num_pipelines)
+        });
+    }
     async_executor::set_num_threads(num_pipelines);
+    execute_graph_with(graph, metrics, before_phase, num_pipelines)
+}
 
+fn execute_graph_with(
+    graph: &mut Graph,
+    metrics: Option<Arc<Mutex<GraphMetrics>>>,
+    before_phase: &mut dyn FnMut(&mut Graph) -> PolarsResult<()>,
+    num_pipelines: usize,
+) -> PolarsResult<SparseSecondaryMap<GraphNodeKey, DataFrame>> {
     let (query_tasks_send, query_tasks_recv) = crossbeam_channel::unbounded();
     let (subphase_tasks_send, subphase_tasks_recv) = crossbeam_channel::unbounded();
 
@@ -342,6 +360,7 @@ pub fn execute_graph(
         if nodes.is_empty() {
             break;
         }
+        before_phase(graph)?;
 
         // Run the subgraph until phase completion.
         run_subgraph(
@@ -74,6 +74,44 @@ impl Graph {
         node_key
     }
 
+    /// Insert a new `GraphNode` with a single input and output on an existing
+    /// `LogicalPipe`, between the sender and the receiver of that pipe.
+    #[cfg_attr(not(feature = "checkpoint"), allow(unused))]
+    pub fn insert_node_on_pipe<N: ComputeNode + 'static>(
+        &mut self,
+        pipe_key: LogicalPipeKey,
+        node: N,
+    ) -> GraphNodeKey {
+        let LogicalPipe {
+            receiver,
+            recv_port,
+            ..
+        } = self.pipes[pipe_key];
+        let node_key = self.nodes.insert(GraphNode {
+            compute: Box::new(node),
+            inputs: vec![pipe_key],
+            outputs: Vec::new(),
+        });
+
+        // The existing pipe now ends at the new node, a new pipe connects the
+        // new node to the original receiver.
+        let pipe = &mut self.pipes[pipe_key];
+        pipe.receiver = node_key;
+        pipe.recv_port = 0;
+        let output_key = self.pipes.insert(LogicalPipe {
+            sender: node_key,
+            send_port: 0,
+            send_state: PortState::Blocked,
+            receiver,
+            recv_port,
+            recv_state: PortState::Blocked,
+        });
+        self.nodes[node_key].outputs.push(output_key);
+        self.nodes[receiver].inputs[recv_port] = output_key;
+
+        node_key
+    }
+
     /// Updates all the nodes' states until a fixed point is reached.
     pub fn update_all_states(
         &mut self,
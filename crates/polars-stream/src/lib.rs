@@ -1,10 +1,16 @@
 mod async_executor;
 mod async_primitives;
+#[cfg(feature = "checkpoint")]
+mod checkpoint;
 mod dispatch;
 mod skeleton;
 
 use std::sync::LazyLock;
 
+#[cfg(feature = "checkpoint")]
+pub use checkpoint::CheckpointOptions;
+#[cfg(feature = "checkpoint")]
+pub use skeleton::run_query_with_checkpoint;
 pub use skeleton::{run_query, visualize_physical_plan};
 
 mod execute;
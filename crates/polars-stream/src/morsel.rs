@@ -50,6 +50,11 @@ impl MorselSeq {
     pub fn to_u64(self) -> u64 {
         self.0
     }
+
+    /// Inverse of [`MorselSeq::to_u64`].
+    pub fn from_u64(seq: u64) -> Self {
+        Self(seq)
+    }
 }
 
 /// A token indicating which source this morsel originated from, and a way to
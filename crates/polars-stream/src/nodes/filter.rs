@@ -1,15 +1,17 @@
 use polars_error::polars_err;
+use polars_expr::state::NullFilterAudit;
 
 use super::compute_node_prelude::*;
 use crate::expression::StreamExpr;
 
 pub struct FilterNode {
     predicate: StreamExpr,
+    audit: Option<NullFilterAudit>,
 }
 
 impl FilterNode {
-    pub fn new(predicate: StreamExpr) -> Self {
-        Self { predicate }
+    pub fn new(predicate: StreamExpr, audit: Option<NullFilterAudit>) -> Self {
+        Self { predicate, audit }
     }
 }
 
@@ -18,6 +20,10 @@ impl ComputeNode for FilterNode {
         "filter"
     }
 
+    fn checkpoint_mode(&self) -> CheckpointMode {
+        CheckpointMode::Stateless
+    }
+
     fn update_state(
         &mut self,
         recv: &mut [PortState],
@@ -26,6 +32,11 @@ impl ComputeNode for FilterNode {
     ) -> PolarsResult<()> {
         assert!(recv.len() == 1 && send.len() == 1);
         recv.swap_with_slice(send);
+        if send[0] == PortState::Done
+            && let Some(audit) = &self.audit
+        {
+            audit.report();
+        }
         Ok(())
     }
 
@@ -53,6 +64,9 @@ impl ComputeNode for FilterNode {
                                 ComputeError: "filter predicate must be of type `Boolean`, got `{}`", mask.dtype()
                             )
                         })?;
+                        if let Some(audit) = &slf.audit {
+                            audit.record(&df, mask)?;
+                        }
 
                         // We already parallelize, call the sequential filter.
                         df.filter_seq(mask)
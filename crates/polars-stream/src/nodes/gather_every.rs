@@ -5,6 +5,7 @@ use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
 use crate::async_primitives::distributor_channel::distributor_channel;
 use crate::async_primitives::wait_group::WaitGroup;
 
+/// Gathers every `n`th row of the stream, starting at row `offset`.
 pub struct GatherEveryNode {
     n: usize,
     offset: usize,
@@ -80,17 +81,14 @@ impl ComputeNode for GatherEveryNode {
             join_handles.push(scope.spawn_task(TaskPriority::High, async move {
                 let wait_group = WaitGroup::default();
                 while let Ok((morsel, offset)) = recv.recv().await {
-                    let mut morsel = morsel.try_map(|mut df| {
-                        let column = &df.columns()[0];
-                        let out = column
-                            .gather_every(n, offset)?
-                            .with_name(column.name().clone());
-                        unsafe {
-                            let height = out.len();
-                            df.columns_mut_retain_schema()[0] = out;
-                            df.set_height(height);
-                        };
-                        PolarsResult::Ok(df)
+                    let mut morsel = morsel.try_map(|df| {
+                        let height = (df.height() - offset).div_ceil(n);
+                        let columns = df
+                            .columns()
+                            .iter()
+                            .map(|c| Ok(c.gather_every(n, offset)?.with_name(c.name().clone())))
+                            .collect::<PolarsResult<Vec<_>>>()?;
+                        PolarsResult::Ok(unsafe { DataFrame::new_unchecked(height, columns) })
                     })?;
                     morsel.set_consume_token(wait_group.token());
                     if send.send(morsel).await.is_err() {
@@ -22,6 +22,7 @@ use crate::async_executor;
 use crate::expression::StreamExpr;
 use crate::morsel::get_ideal_morsel_size;
 use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::nodes::reduce::{reduction_state, restore_reduction};
 
 #[cfg(debug_assertions)]
 const DEFAULT_HOT_TABLE_SIZE: usize = 4;
@@ -102,6 +103,7 @@ impl LocalGroupBySinkState {
 }
 
 struct GroupBySinkState {
+    key_schema: Arc<Schema>,
     key_selectors_per_input: Vec<Vec<StreamExpr>>,
     reductions_per_input: Vec<Vec<usize>>,
     grouper: Box<dyn Grouper>,
@@ -112,9 +114,70 @@ struct GroupBySinkState {
     random_state: PlRandomState,
     partitioner: HashPartitioner,
     has_order_sensitive_agg: bool,
+    hot_table_size: usize,
 }
 
 impl GroupBySinkState {
+    fn new_locals(&self, num_pipelines: usize) -> Vec<LocalGroupBySinkState> {
+        (0..num_pipelines)
+            .map(|_| {
+                let reductions = self
+                    .grouped_reductions
+                    .iter()
+                    .map(|gr| gr.new_empty())
+                    .collect();
+                LocalGroupBySinkState::new(
+                    self.key_schema.clone(),
+                    reductions,
+                    self.hot_table_size,
+                    self.partitioner.num_partitions(),
+                )
+            })
+            .collect()
+    }
+
+    /// Combines the groups of the locals, and returns the keys of the groups followed by the
+    /// state of every reduction. The combined groups are kept as a pre-aggregate.
+    fn checkpoint(&mut self, num_pipelines: usize) -> PolarsResult<Vec<DataFrame>> {
+        let partitions = self.combine_locals()?;
+        self.locals = self.new_locals(num_pipelines);
+
+        let mut keys = Vec::with_capacity(partitions.len());
+        let mut states = vec![Vec::with_capacity(partitions.len()); self.grouped_reductions.len()];
+        for p in partitions {
+            let p_keys = p.grouper.get_keys_in_group_order(&self.key_schema);
+            for (r, r_states) in p.grouped_reductions.iter().zip(&mut states) {
+                r_states.push(reduction_state(&**r).unwrap());
+            }
+            let hash_keys = HashKeys::from_df(&p_keys, self.random_state.clone(), true, false);
+            self.locals[0].add_pre_agg(hash_keys, p.grouped_reductions, &self.partitioner);
+            keys.push(p_keys);
+        }
+
+        let mut state = vec![accumulate_dataframes_vertical_unchecked(keys)];
+        state.extend(states.into_iter().map(accumulate_dataframes_vertical_unchecked));
+        Ok(state)
+    }
+
+    /// Restores the groups returned by [`GroupBySinkState::checkpoint`] as a pre-aggregate.
+    fn restore(&mut self, state: Vec<DataFrame>) -> PolarsResult<()> {
+        let mut state = state.into_iter();
+        let keys = state.next().unwrap();
+        let reductions = self
+            .grouped_reductions
+            .iter()
+            .zip(state)
+            .map(|(gr, r_state)| {
+                let mut r = gr.new_empty();
+                restore_reduction(&mut *r, &r_state)?;
+                PolarsResult::Ok(r)
+            })
+            .try_collect_vec()?;
+        let hash_keys = HashKeys::from_df(&keys, self.random_state.clone(), true, false);
+        self.locals[0].add_pre_agg(hash_keys, reductions, &self.partitioner);
+        Ok(())
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
@@ -481,6 +544,8 @@ pub struct GroupByNode {
     num_inputs: usize,
     num_pipelines: usize,
     output_schema: Arc<Schema>,
+    /// Whether all reductions support checkpointing.
+    checkpointable: bool,
 }
 
 impl GroupByNode {
@@ -516,35 +581,32 @@ impl GroupByNode {
                     .collect_vec()
             })
             .collect_vec();
-        let locals = (0..num_pipelines)
-            .map(|_| {
-                let reductions = grouped_reductions.iter().map(|gr| gr.new_empty()).collect();
-                LocalGroupBySinkState::new(
-                    key_schema.clone(),
-                    reductions,
-                    hot_table_size,
-                    num_partitions,
-                )
-            })
-            .collect();
+        let checkpointable = grouped_reductions
+            .iter()
+            .all(|gr| gr.checkpoint_state().is_some());
         let partitioner = HashPartitioner::new(num_partitions, 0);
+        let mut sink = GroupBySinkState {
+            key_schema: key_schema.clone(),
+            key_selectors_per_input,
+            reductions_per_input,
+            grouped_reductions,
+            grouper,
+            random_state,
+            uniq_grouped_reduction_cols_per_input,
+            grouped_reduction_cols,
+            locals: Vec::new(),
+            partitioner,
+            has_order_sensitive_agg,
+            hot_table_size,
+        };
+        sink.locals = sink.new_locals(num_pipelines);
         Self {
-            state: GroupByState::Sink(GroupBySinkState {
-                key_selectors_per_input,
-                reductions_per_input,
-                grouped_reductions,
-                grouper,
-                random_state,
-                uniq_grouped_reduction_cols_per_input,
-                grouped_reduction_cols,
-                locals,
-                partitioner,
-                has_order_sensitive_agg,
-            }),
+            state: GroupByState::Sink(sink),
             key_schema,
             num_inputs,
             num_pipelines,
             output_schema,
+            checkpointable,
         }
     }
 }
@@ -554,6 +616,28 @@ impl ComputeNode for GroupByNode {
         "group-by"
     }
 
+    fn checkpoint_mode(&self) -> CheckpointMode {
+        if self.checkpointable {
+            CheckpointMode::Stateful
+        } else {
+            CheckpointMode::Unsupported
+        }
+    }
+
+    fn checkpoint_state(&mut self) -> PolarsResult<Option<Vec<DataFrame>>> {
+        match &mut self.state {
+            GroupByState::Sink(sink) => sink.checkpoint(self.num_pipelines).map(Some),
+            GroupByState::Source(_) | GroupByState::Done => Ok(None),
+        }
+    }
+
+    fn restore_state(&mut self, state: Vec<DataFrame>) -> PolarsResult<()> {
+        let GroupByState::Sink(sink) = &mut self.state else {
+            unreachable!()
+        };
+        sink.restore(state)
+    }
+
     fn update_state(
         &mut self,
         recv: &mut [PortState],
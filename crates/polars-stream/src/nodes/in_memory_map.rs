@@ -30,6 +30,24 @@ impl ComputeNode for InMemoryMapNode {
         "in-memory-map"
     }
 
+    fn checkpoint_mode(&self) -> CheckpointMode {
+        CheckpointMode::Stateful
+    }
+
+    fn checkpoint_state(&mut self) -> PolarsResult<Option<Vec<DataFrame>>> {
+        match self {
+            Self::Sink { sink_node, .. } => sink_node.checkpoint_state(),
+            Self::Source(_) | Self::Done => Ok(None),
+        }
+    }
+
+    fn restore_state(&mut self, state: Vec<DataFrame>) -> PolarsResult<()> {
+        let Self::Sink { sink_node, .. } = self else {
+            unreachable!()
+        };
+        sink_node.restore_state(state)
+    }
+
     fn update_state(
         &mut self,
         recv: &mut [PortState],
@@ -26,6 +26,24 @@ impl ComputeNode for InMemorySinkNode {
         "in-memory-sink"
     }
 
+    fn checkpoint_mode(&self) -> CheckpointMode {
+        CheckpointMode::Stateful
+    }
+
+    fn checkpoint_state(&mut self) -> PolarsResult<Option<Vec<DataFrame>>> {
+        // Combine the morsels received so far, the morsels of later phases have a higher
+        // sequence id.
+        let df = self.get_output()?.unwrap();
+        self.restore_state(vec![df.clone()])?;
+        Ok(Some(vec![df]))
+    }
+
+    fn restore_state(&mut self, state: Vec<DataFrame>) -> PolarsResult<()> {
+        let df = state.into_iter().next().unwrap();
+        *self.morsels_per_pipe.get_mut() = vec![vec![(MorselSeq::default(), df)]];
+        Ok(())
+    }
+
     fn update_state(
         &mut self,
         recv: &mut [PortState],
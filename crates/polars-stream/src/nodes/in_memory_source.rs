@@ -10,6 +10,8 @@ pub struct InMemorySourceNode {
     morsel_size: usize,
     seq: AtomicU64,
     seq_offset: MorselSeq,
+    /// The number of rows skipped when resuming from a checkpoint.
+    rows_skipped: u64,
 }
 
 impl InMemorySourceNode {
@@ -19,6 +21,7 @@ impl InMemorySourceNode {
             morsel_size: 0,
             seq: AtomicU64::new(0),
             seq_offset,
+            rows_skipped: 0,
         }
     }
 }
@@ -28,6 +31,26 @@ impl ComputeNode for InMemorySourceNode {
         "in-memory-source"
     }
 
+    fn checkpoint_mode(&self) -> CheckpointMode {
+        CheckpointMode::Source
+    }
+
+    fn checkpoint_position(&self) -> Option<u64> {
+        let rows_sent = match &self.source {
+            Some(src) => (self.seq.load(Ordering::Relaxed) * self.morsel_size as u64)
+                .min(src.height() as u64),
+            None => 0,
+        };
+        Some(self.rows_skipped + rows_sent)
+    }
+
+    fn restore_position(&mut self, position: u64) -> PolarsResult<()> {
+        let src = self.source.as_ref().unwrap();
+        self.source = Some(Arc::new(src.slice(position as i64, usize::MAX)));
+        self.rows_skipped = position;
+        Ok(())
+    }
+
     fn update_state(
         &mut self,
         recv: &mut [PortState],
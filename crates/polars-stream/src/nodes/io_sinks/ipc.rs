@@ -213,9 +213,10 @@ impl SinkNode for IpcSinkNode {
             .expect("not initialized / spawn called more than once");
         let arrow_converters = self.arrow_converters.take().unwrap();
 
-        let options = WriteOptions {
+        let options = Arc::new(WriteOptions {
             compression: self.write_options.compression.map(Into::into),
-        };
+            zstd_dictionary: None,
+        });
 
         let chunk_size = self.write_options.chunk_size;
 
@@ -247,6 +248,7 @@ impl SinkNode for IpcSinkNode {
                         (((mut dist_rx, mut lin_tx), mut arrow_converter), dictionary_id_offset),
                     )| {
                         let io_tx = io_tx.clone();
+                        let options = options.clone();
 
                         spawn(TaskPriority::High, async move {
                             while let Some((seq, column)) = dist_rx.recv().await {
@@ -4,7 +4,7 @@ use polars_io::pl_async;
 use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
 
-use super::{ComputeNode, PortState};
+use super::{CheckpointMode, ComputeNode, PortState};
 use crate::async_executor;
 use crate::async_primitives::connector;
 use crate::execute::StreamingExecutionState;
@@ -56,6 +56,20 @@ impl ComputeNode for IOSinkNode {
         &self.name
     }
 
+    fn checkpoint_mode(&self) -> CheckpointMode {
+        CheckpointMode::Stateful
+    }
+
+    /// The written output can't be restored, so the sink can only be checkpointed before it
+    /// starts writing.
+    fn checkpoint_state(&mut self) -> PolarsResult<Option<Vec<DataFrame>>> {
+        Ok(matches!(self.state, IOSinkNodeState::Uninitialized { .. }).then(Vec::new))
+    }
+
+    fn restore_state(&mut self, _state: Vec<DataFrame>) -> PolarsResult<()> {
+        Ok(())
+    }
+
     fn update_state(
         &mut self,
         recv: &mut [crate::graph::PortState],
@@ -50,6 +50,9 @@ impl IOWriter {
                 IpcBatch::Dictionary(dictionary_data) => {
                     ipc_writer.write_encoded_dictionaries(&[dictionary_data])?
                 },
+                IpcBatch::ZstdDictionary(zstd_dictionary) => {
+                    ipc_writer.set_zstd_dictionary(Some(zstd_dictionary))
+                },
             }
         }
 
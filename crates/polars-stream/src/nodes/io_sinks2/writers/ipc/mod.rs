@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
 use polars_core::schema::SchemaRef;
+use polars_core::utils::arrow::io::ipc::ZstdDictionary;
 use polars_core::utils::arrow::io::ipc::write::{EncodedData, WriteOptions};
 use polars_error::PolarsResult;
-use polars_io::ipc::IpcWriterOptions;
+use polars_io::ipc::{IpcCompression, IpcWriterOptions};
 use polars_io::pl_async;
 use polars_io::utils::sync_on_close::SyncOnCloseType;
 use polars_utils::IdxSize;
@@ -37,6 +38,8 @@ enum IpcBatch {
         SinkMorselPermit,
     ),
     Dictionary(EncodedData),
+    /// The zstd dictionary the record batches that follow are compressed with.
+    ZstdDictionary(ZstdDictionary),
 }
 
 impl FileWriterStarter for IpcWriterStarter {
@@ -82,6 +85,10 @@ impl FileWriterStarter for IpcWriterStarter {
         let options = self.options;
         let sync_on_close = self.sync_on_close;
         let compression = self.options.compression.map(|x| x.into());
+        let zstd_dictionary_size = match self.options.compression {
+            Some(IpcCompression::ZSTD(_)) => self.options.zstd_dictionary_size,
+            _ => None,
+        };
 
         let handle = async_executor::spawn(TaskPriority::High, async move {
             let (ipc_batch_tx, ipc_batch_rx) =
@@ -112,7 +119,11 @@ impl FileWriterStarter for IpcWriterStarter {
                         ipc_batch_tx,
                         arrow_converters,
                         dictionary_id_offsets,
-                        write_options: WriteOptions { compression },
+                        write_options: Arc::new(WriteOptions {
+                            compression,
+                            zstd_dictionary: None,
+                        }),
+                        zstd_dictionary_size,
                     }
                     .run(),
                 ));
@@ -5,7 +5,7 @@ use arrow::io::ipc::write::encode_dictionary_values;
 use polars_core::series::ToArrowConverter;
 use polars_core::utils::arrow;
 use polars_core::utils::arrow::io::ipc::write::{
-    EncodedData, WriteOptions, commit_encoded_arrays, encode_array,
+    EncodedData, WriteOptions, commit_encoded_arrays, encode_array, train_zstd_dictionary,
 };
 use polars_error::PolarsResult;
 use polars_utils::concat_vec::ConcatVec as _;
@@ -21,7 +21,9 @@ pub struct RecordBatchEncoder {
     pub ipc_batch_tx: tokio::sync::mpsc::Sender<IpcBatch>,
     pub arrow_converters: Vec<ToArrowConverter>,
     pub dictionary_id_offsets: Arc<[usize]>,
-    pub write_options: WriteOptions,
+    pub write_options: Arc<WriteOptions>,
+    /// Train a zstd dictionary of at most this size on the first record batch.
+    pub zstd_dictionary_size: Option<usize>,
 }
 
 impl RecordBatchEncoder {
@@ -31,7 +33,8 @@ impl RecordBatchEncoder {
             ipc_batch_tx,
             mut arrow_converters,
             dictionary_id_offsets,
-            write_options,
+            mut write_options,
+            mut zstd_dictionary_size,
         } = self;
 
         let mut record_batch_arrow_arrays: Vec<Box<dyn Array>> =
@@ -65,34 +68,57 @@ impl RecordBatchEncoder {
                 record_batch_arrow_arrays.push(array);
             }
 
+            if let Some(max_size) = zstd_dictionary_size.take() {
+                // Fall back to plain zstd if there is too little data to train on.
+                if let Ok(zstd_dictionary) =
+                    train_zstd_dictionary(&record_batch_arrow_arrays, max_size)
+                {
+                    write_options = Arc::new(WriteOptions {
+                        compression: write_options.compression,
+                        zstd_dictionary: Some(zstd_dictionary.clone()),
+                    });
+                    if ipc_batch_tx
+                        .send(IpcBatch::ZstdDictionary(zstd_dictionary))
+                        .await
+                        .is_err()
+                    {
+                        return Ok(());
+                    }
+                }
+            }
+
             // Construct the iterator here so that the loop retains ownership of `record_batch_arrow_arrays`.
             let array_encode_fut_iter = parallelize_first_to_local(
                 TaskPriority::High,
-                record_batch_arrow_arrays.drain(..).map(|array| async move {
-                    let mut out = EncodedArrayData::default();
-
-                    let EncodedArrayData {
-                        variadic_buffer_counts,
-                        buffers,
-                        arrow_data,
-                        nodes,
-                        offset,
-                    } = &mut out;
-
-                    encode_array(
-                        &array,
-                        &write_options,
-                        variadic_buffer_counts,
-                        buffers,
-                        arrow_data,
-                        nodes,
-                        offset,
-                    );
-
-                    out
+                record_batch_arrow_arrays.drain(..).map(|array| {
+                    let write_options = write_options.clone();
+                    async move {
+                        let mut out = EncodedArrayData::default();
+
+                        let EncodedArrayData {
+                            variadic_buffer_counts,
+                            buffers,
+                            arrow_data,
+                            nodes,
+                            offset,
+                        } = &mut out;
+
+                        encode_array(
+                            &array,
+                            &write_options,
+                            variadic_buffer_counts,
+                            buffers,
+                            arrow_data,
+                            nodes,
+                            offset,
+                        );
+
+                        out
+                    }
                 }),
             );
 
+            let write_options = write_options.clone();
             let array_combine_handle = async_executor::AbortOnDropHandle::new(
                 async_executor::spawn(TaskPriority::High, async move {
                     let mut buffers: Vec<arrow::io::ipc::format::ipc::Buffer> = vec![];
@@ -160,6 +186,7 @@ impl RecordBatchEncoder {
                 })
                 .flat_map(|(arrow_converter, dictionary_id_offset)| {
                     let ipc_batch_tx = ipc_batch_tx.clone();
+                    let write_options = write_options.clone();
 
                     arrow_converter
                         .categorical_converter
@@ -168,6 +195,7 @@ impl RecordBatchEncoder {
                         .enumerate()
                         .map(move |(i, (_, categorical_converter))| {
                             let ipc_batch_tx = ipc_batch_tx.clone();
+                            let write_options = write_options.clone();
 
                             async move {
                                 let encoded_data = encode_dictionary_values(
@@ -174,12 +174,14 @@ impl FileReader for CsvFileReader {
             &mut reader,
         )?;
 
-        let used_schema = Arc::new(inferred_schema);
-
         if let Some(tx) = file_schema_tx {
-            _ = tx.send(used_schema.clone())
+            // Columns dropped by the column name policy still occupy their position in the file,
+            // but aren't part of its schema.
+            _ = tx.send(Arc::new(inferred_schema.read_schema()))
         }
 
+        let used_schema = Arc::new(inferred_schema.schema);
+
         let projection: Vec<usize> = projected_schema
             .iter_names()
             .filter_map(|name| used_schema.index_of(name))
@@ -549,13 +551,17 @@ impl ChunkReader {
             }
         }
 
+        let mut row_offset = chunk_row_offset;
         if slice != NO_SLICE {
             assert!(slice != SLICE_ENDED);
 
             df = df.slice(i64::try_from(slice.0).unwrap(), slice.1);
+            row_offset += slice.0;
         }
 
-        cast_columns(&mut df, &self.fields_to_cast, false, self.ignore_errors)?;
+        // Report the rows of values that fail to cast relative to the file.
+        cast_columns(&mut df, &self.fields_to_cast, false, self.ignore_errors)
+            .map_err(|err| err.with_row_offset(row_offset))?;
 
         Ok((df, height))
     }
@@ -32,7 +32,7 @@ impl ColumnSelectorBuilder {
     ) -> PolarsResult<ColumnSelector> {
         let out = if let Some((index, _, incoming_dtype)) = incoming_schema.get_full(target_name) {
             let input = ColumnSelector::Position(index);
-            self.attach_transforms(input, incoming_dtype, target_dtype, target_name)?
+            self.attach_column_transforms(input, incoming_dtype, target_dtype, target_name)?
         } else {
             match &self.missing_columns_policy {
                 MissingColumnsPolicy::Insert => ColumnSelector::Constant(Box::new((
@@ -46,6 +46,48 @@ impl ColumnSelectorBuilder {
         Ok(out)
     }
 
+    /// Adds transforms on top of the `input_selector` of a top-level column if necessary.
+    ///
+    /// Columns with a dtype overridden by the user are always cast, and extra struct fields are
+    /// ignored for struct columns that were pruned by the optimizer.
+    pub fn attach_column_transforms(
+        &self,
+        input_selector: ColumnSelector,
+        incoming_dtype: &DataType,
+        target_dtype: &DataType,
+        target_name: &str,
+    ) -> PolarsResult<ColumnSelector> {
+        if self.cast_columns_policy.is_overridden_column(target_name) {
+            return Ok(if incoming_dtype == target_dtype {
+                input_selector
+            } else {
+                ColumnTransform::Cast {
+                    dtype: target_dtype.clone(),
+                    options: CastOptions::Strict,
+                }
+                .into_selector(input_selector)
+            });
+        }
+
+        if self.cast_columns_policy.is_pruned_struct_column(target_name)
+            && matches!(
+                &self.cast_columns_policy.extra_struct_fields,
+                ExtraColumnsPolicy::Raise
+            )
+        {
+            let mut builder = self.clone();
+            builder.cast_columns_policy.extra_struct_fields = ExtraColumnsPolicy::Ignore;
+            return builder.attach_transforms(
+                input_selector,
+                incoming_dtype,
+                target_dtype,
+                target_name,
+            );
+        }
+
+        self.attach_transforms(input_selector, incoming_dtype, target_dtype, target_name)
+    }
+
     /// Adds transforms on top of the `input_selector` if necessary.
     pub fn attach_transforms(
         &self,
@@ -8,4 +8,5 @@ pub mod forbid_extra_columns;
 pub mod physical_slice;
 pub mod projection;
 pub mod reader_operation_pushdown;
+pub mod retry;
 pub mod row_deletions;
@@ -138,7 +138,7 @@ impl ProjectionBuilder {
                         continue;
                     };
 
-                    match selector_builder.attach_transforms(
+                    match selector_builder.attach_column_transforms(
                         ColumnSelector::Position(0),
                         incoming_dtype,
                         projected_dtype,
@@ -0,0 +1,151 @@
+use std::io::ErrorKind;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use polars_error::{PolarsError, PolarsResult};
+use polars_io::pl_async;
+
+/// Retries of the sources of a scan after transient errors, from a budget that is shared by all
+/// sources of the scan.
+pub struct SourceRetries {
+    budget: usize,
+    remaining: AtomicUsize,
+    verbose: bool,
+}
+
+impl SourceRetries {
+    pub fn new(budget: usize, verbose: bool) -> Self {
+        Self {
+            budget,
+            remaining: AtomicUsize::new(budget),
+            verbose,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.budget > 0
+    }
+
+    /// Run `f` for the source at `scan_source_idx`, retrying transient errors with exponential
+    /// backoff while the budget lasts.
+    pub async fn run<T, F, Fut>(
+        &self,
+        scan_source_idx: usize,
+        path: &str,
+        mut f: F,
+    ) -> PolarsResult<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = PolarsResult<T>>,
+    {
+        let mut n_retries: usize = 0;
+        loop {
+            let err = match f().await {
+                Ok(v) => return Ok(v),
+                Err(err) => err,
+            };
+            if !self.wait_for_retry(&err, scan_source_idx, n_retries).await {
+                return Err(self.source_failed(err, path, n_retries));
+            }
+            n_retries += 1;
+        }
+    }
+
+    /// Wait before the next attempt to read the source at `scan_source_idx` if `err` is transient
+    /// and the budget lasts. Returns whether the source should be retried.
+    pub async fn wait_for_retry(
+        &self,
+        err: &PolarsError,
+        scan_source_idx: usize,
+        n_retries: usize,
+    ) -> bool {
+        if !is_transient(err) || !self.take_retry() {
+            return false;
+        }
+
+        let delay = Duration::from_millis(100 << n_retries.min(7));
+        if self.verbose {
+            eprintln!(
+                "[MultiScan]: Retrying source {scan_source_idx} in {delay:?} after error: {err}"
+            );
+        }
+        pl_async::get_runtime()
+            .spawn(tokio::time::sleep(delay))
+            .await
+            .unwrap();
+        true
+    }
+
+    /// The error of the source at `path` that failed for good with `err`. Transient errors name
+    /// the source, as they only fail the scan once the budget is used up.
+    pub fn source_failed(&self, err: PolarsError, path: &str, n_retries: usize) -> PolarsError {
+        if !self.is_enabled() || !is_transient(&err) {
+            return err;
+        }
+        let budget = self.budget;
+        err.wrap_msg(|msg| {
+            format!(
+                "{msg}\n\nfailed to read source '{path}' after {n_retries} retries \
+                (retry budget of {budget} used up)"
+            )
+        })
+    }
+
+    fn take_retry(&self) -> bool {
+        self.remaining
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub(1))
+            .is_ok()
+    }
+}
+
+/// Whether `err` may succeed when retried: timeouts, interrupted or reset connections, and
+/// requests that an object store rejected as too many or while unavailable.
+fn is_transient(err: &PolarsError) -> bool {
+    match err {
+        PolarsError::IO { error, .. } => match error.kind() {
+            ErrorKind::TimedOut
+            | ErrorKind::Interrupted
+            | ErrorKind::ConnectionReset
+            | ErrorKind::WouldBlock => true,
+            // Errors of the object store are converted to I/O errors of kind `Other`, which only
+            // keep the HTTP status in their message.
+            ErrorKind::Other => {
+                let msg = error.to_string();
+                msg.contains("429 Too Many Requests") || msg.contains("503 Service Unavailable")
+            },
+            _ => false,
+        },
+        PolarsError::Context { error, .. } => is_transient(error),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use polars_error::PolarsError;
+
+    use super::is_transient;
+
+    #[test]
+    fn test_is_transient() {
+        let io_err = |kind: io::ErrorKind| PolarsError::from(io::Error::from(kind));
+
+        assert!(is_transient(&io_err(io::ErrorKind::TimedOut)));
+        assert!(is_transient(&io_err(io::ErrorKind::ConnectionReset)));
+        assert!(!is_transient(&io_err(io::ErrorKind::NotFound)));
+        assert!(!is_transient(&io_err(io::ErrorKind::PermissionDenied)));
+
+        let store_err = |msg: &str| PolarsError::from(io::Error::other(msg.to_string()));
+        assert!(is_transient(&store_err(
+            "object-store error: Server returned non-2xx status code: 503 Service Unavailable"
+        )));
+        assert!(!is_transient(&store_err(
+            "object-store error: Server returned non-2xx status code: 403 Forbidden"
+        )));
+
+        let err = io_err(io::ErrorKind::TimedOut).context("reading metadata".into());
+        assert!(is_transient(&err));
+    }
+}
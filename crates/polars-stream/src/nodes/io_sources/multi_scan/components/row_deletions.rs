@@ -53,6 +53,7 @@ impl DeletionFilesProvider {
                             (PlSmallStr::from_static("file_path"), DataType::String),
                             (PlSmallStr::from_static("pos"), DataType::Int64),
                         ]))),
+                        schema_overwrite: None,
 
                         parallel: polars_io::prelude::ParallelStrategy::Auto,
                         low_memory: false,
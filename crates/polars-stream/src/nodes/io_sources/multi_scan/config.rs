@@ -49,6 +49,9 @@ pub struct MultiScanConfig {
     /// step.
     pub n_readers_pre_init: RelaxedCell<usize>,
     pub max_concurrent_scans: RelaxedCell<usize>,
+    /// Number of rows sent to the output of the node. When resuming from a checkpoint this is set
+    /// before initialization, and that many rows are skipped.
+    pub rows_sent: Arc<RelaxedCell<u64>>,
 
     pub verbose: bool,
 }
@@ -39,6 +39,11 @@ pub fn resolve_projections(
             let in_hive = hive_schema.is_some_and(|x| x.contains(name));
             let is_row_index_col = row_index_name.is_some_and(|x| name == x);
             let is_file_path_col = include_file_paths.is_some_and(|x| name == x);
+            // Unused fields of struct columns can be pruned from the output schema.
+            let dtype = match final_output_schema.get(name) {
+                Some(output_dtype) if dtype.is_struct() => output_dtype,
+                _ => dtype,
+            };
             (in_final && !(in_hive || is_file_path_col || is_row_index_col))
                 .then(|| (name.clone(), dtype.clone()))
         })
@@ -11,13 +11,14 @@ use polars_error::PolarsResult;
 use polars_io::pl_async;
 use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
+use polars_utils::relaxed_cell::RelaxedCell;
 
 use crate::async_executor::{self, AbortOnDropHandle, TaskPriority};
 use crate::async_primitives::connector;
 use crate::async_primitives::wait_group::{WaitGroup, WaitToken};
 use crate::execute::StreamingExecutionState;
 use crate::graph::PortState;
-use crate::nodes::ComputeNode;
+use crate::nodes::{CheckpointMode, ComputeNode};
 use crate::nodes::io_sources::multi_scan::components::bridge::BridgeState;
 use crate::nodes::io_sources::multi_scan::config::MultiScanConfig;
 use crate::nodes::io_sources::multi_scan::functions::{
@@ -29,6 +30,7 @@ use crate::pipe::PortSender;
 pub struct MultiScan {
     name: PlSmallStr,
     state: MultiScanState,
+    rows_sent: Arc<RelaxedCell<u64>>,
     verbose: bool,
 }
 
@@ -36,10 +38,12 @@ impl MultiScan {
     pub fn new(config: Arc<MultiScanConfig>) -> Self {
         let name = format_pl_smallstr!("multi-scan[{}]", config.file_reader_builder.reader_name());
         let verbose = config.verbose;
+        let rows_sent = config.rows_sent.clone();
 
         MultiScan {
             name,
             state: MultiScanState::Uninitialized { config },
+            rows_sent,
             verbose,
         }
     }
@@ -50,6 +54,20 @@ impl ComputeNode for MultiScan {
         &self.name
     }
 
+    fn checkpoint_mode(&self) -> CheckpointMode {
+        CheckpointMode::Source
+    }
+
+    fn checkpoint_position(&self) -> Option<u64> {
+        Some(self.rows_sent.load())
+    }
+
+    fn restore_position(&mut self, position: u64) -> PolarsResult<()> {
+        assert!(matches!(self.state, MultiScanState::Uninitialized { .. }));
+        self.rows_sent.store(position);
+        Ok(())
+    }
+
     fn update_state(
         &mut self,
         recv: &mut [crate::graph::PortState],
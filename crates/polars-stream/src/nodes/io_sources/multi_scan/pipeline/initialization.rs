@@ -13,6 +13,7 @@ use crate::async_executor::{self, AbortOnDropHandle, TaskPriority};
 use crate::async_primitives::connector::{self};
 use crate::execute::StreamingExecutionState;
 use crate::nodes::io_sources::multi_scan::components::bridge::{BridgeRecvPort, BridgeState};
+use crate::nodes::io_sources::multi_scan::components::retry::SourceRetries;
 use crate::nodes::io_sources::multi_scan::components::row_deletions::{
     DeletionFilesProvider, ExternalFilterMask, RowDeletionsInit,
 };
@@ -53,7 +54,8 @@ pub fn initialize_multi_scan_pipeline(
 
     let bridge_state = Arc::new(Mutex::new(BridgeState::NotYetStarted));
 
-    let (bridge_handle, bridge_recv_port_tx, phase_channel_tx) = spawn_bridge(bridge_state.clone());
+    let (bridge_handle, bridge_recv_port_tx, phase_channel_tx) =
+        spawn_bridge(bridge_state.clone(), config.rows_sent.clone());
 
     let task_handle =
         AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
@@ -243,6 +245,10 @@ async fn finish_initialize_multi_scan_pipeline(
     let has_row_index_or_slice = extra_ops.has_row_index_or_slice();
 
     let config = config.clone();
+    let source_retries = Arc::new(SourceRetries::new(
+        polars_core::config::scan_retry_budget(),
+        verbose,
+    ));
 
     // Buffered initialization stream. This concurrently calls `FileReader::initialize()`,
     // allowing for e.g. concurrent Parquet metadata fetch.
@@ -298,6 +304,7 @@ async fn finish_initialize_multi_scan_pipeline(
         let file_reader_builder = config.file_reader_builder.clone();
         let deletion_files_provider =
             DeletionFilesProvider::new(config.deletion_files.clone(), &execution_state);
+        let source_retries = source_retries.clone();
 
         futures::stream::iter(range)
             .map(move |scan_source_idx| {
@@ -306,6 +313,7 @@ async fn finish_initialize_multi_scan_pipeline(
                 let file_reader_builder = file_reader_builder.clone();
                 let deletion_files_provider = deletion_files_provider.clone();
                 let initialized_row_deletions = initialized_row_deletions.clone();
+                let source_retries = source_retries.clone();
 
                 let maybe_initialized = initialized_readers.pop_front();
                 let scan_source = sources.get(scan_source_idx).unwrap().into_owned();
@@ -322,17 +330,31 @@ async fn finish_initialize_multi_scan_pipeline(
                             return PolarsResult::Ok((scan_source, reader, Some(n_rows_in_file)));
                         }
 
-                        let mut reader = file_reader_builder.build_file_reader(
-                            scan_source.clone(),
-                            cloud_options.clone(),
-                            scan_source_idx,
-                        );
-
-                        reader.initialize().await?;
-                        let opt_n_rows = reader
-                            .fast_n_rows_in_file()
-                            .await?
-                            .map(|num_phys_rows| RowCounter::new(num_phys_rows, 0));
+                        // Initialization is retried on transient errors, e.g. when the object
+                        // store throttles the metadata fetch.
+                        let path = scan_source
+                            .as_scan_source_ref()
+                            .to_include_path_name()
+                            .to_string();
+                        let (file_reader_builder, scan_source_ref, cloud_options) =
+                            (&file_reader_builder, &scan_source, &cloud_options);
+                        let (reader, opt_n_rows) = source_retries
+                            .run(scan_source_idx, &path, move || async move {
+                                let mut reader = file_reader_builder.build_file_reader(
+                                    scan_source_ref.clone(),
+                                    cloud_options.clone(),
+                                    scan_source_idx,
+                                );
+
+                                reader.initialize().await?;
+                                let opt_n_rows = reader
+                                    .fast_n_rows_in_file()
+                                    .await?
+                                    .map(|num_phys_rows| RowCounter::new(num_phys_rows, 0));
+
+                                PolarsResult::Ok((reader, opt_n_rows))
+                            })
+                            .await?;
 
                         PolarsResult::Ok((scan_source, reader, opt_n_rows))
                     }
@@ -391,6 +413,7 @@ async fn finish_initialize_multi_scan_pipeline(
                 cast_columns_policy,
                 missing_columns_policy,
                 forbid_extra_columns: config.forbid_extra_columns.clone(),
+                source_retries,
                 num_pipelines,
                 verbose,
             },
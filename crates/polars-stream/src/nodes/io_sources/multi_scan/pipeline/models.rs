@@ -22,6 +22,7 @@ use crate::nodes::io_sources::multi_scan::components::bridge::{BridgeRecvPort, B
 use crate::nodes::io_sources::multi_scan::components::forbid_extra_columns::ForbidExtraColumns;
 use crate::nodes::io_sources::multi_scan::components::physical_slice::PhysicalSlice;
 use crate::nodes::io_sources::multi_scan::components::projection::builder::ProjectionBuilder;
+use crate::nodes::io_sources::multi_scan::components::retry::SourceRetries;
 use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
 use crate::nodes::io_sources::multi_scan::reader_interface::{FileReader, FileReaderCallbacks};
 use crate::pipe::PortSender;
@@ -82,6 +83,7 @@ pub(super) struct StartReaderArgsConstant {
     pub(super) cast_columns_policy: CastColumnsPolicy,
     pub(super) missing_columns_policy: MissingColumnsPolicy,
     pub(super) forbid_extra_columns: Option<ForbidExtraColumns>,
+    pub(super) source_retries: Arc<SourceRetries>,
     pub(super) num_pipelines: usize,
     pub(super) verbose: bool,
 }
@@ -1,5 +1,7 @@
 use std::sync::{Arc, Mutex};
 
+use polars_utils::relaxed_cell::RelaxedCell;
+
 use crate::async_executor;
 use crate::async_executor::{JoinHandle, TaskPriority};
 use crate::async_primitives::connector;
@@ -12,6 +14,7 @@ use crate::pipe::PortSender;
 
 pub fn spawn_bridge(
     bridge_state: Arc<Mutex<BridgeState>>,
+    rows_sent: Arc<RelaxedCell<u64>>,
 ) -> (
     JoinHandle<()>,
     // For attaching file reader output port
@@ -29,6 +32,7 @@ pub fn spawn_bridge(
             outgoing,
             bridge_state,
             source_token: SourceToken::new(),
+            rows_sent,
         }
         .run(),
     );
@@ -43,6 +47,7 @@ struct Bridge {
     outgoing: connector::Receiver<(PortSender, WaitToken)>,
     bridge_state: Arc<Mutex<BridgeState>>,
     source_token: SourceToken,
+    rows_sent: Arc<RelaxedCell<u64>>,
 }
 
 impl Bridge {
@@ -76,6 +81,8 @@ impl Bridge {
         mut current_phase_wait_token: WaitToken,
     ) -> (StopReason, Option<WaitToken>) {
         let mut morsel_seq: u64 = 0;
+        // Rows that were sent before the checkpoint we are resuming from.
+        let mut rows_to_skip = self.rows_sent.load();
 
         loop {
             let mut morsel = match rx.recv().await {
@@ -97,6 +104,17 @@ impl Bridge {
                 },
             };
 
+            if rows_to_skip > 0 {
+                let height = morsel.df().height() as u64;
+                if height <= rows_to_skip {
+                    rows_to_skip -= height;
+                    continue;
+                }
+                *morsel.df_mut() = morsel.df().slice(rows_to_skip as i64, usize::MAX);
+                rows_to_skip = 0;
+            }
+            let height = morsel.df().height() as u64;
+
             morsel.replace_source_token(self.source_token.clone());
             // Important: We override the sequence ID here, as when we receive from a new reader the
             // incoming ID begins again from 0.
@@ -115,6 +133,7 @@ impl Bridge {
 
                 morsel = v;
             }
+            self.rows_sent.fetch_add(height);
 
             if self.source_token.stop_requested() {
                 drop(tx);
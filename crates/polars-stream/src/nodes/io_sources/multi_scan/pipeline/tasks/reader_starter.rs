@@ -408,6 +408,7 @@ async fn start_reader_impl(
         cast_columns_policy,
         missing_columns_policy,
         forbid_extra_columns,
+        source_retries,
         num_pipelines,
         verbose,
     } = constant_args;
@@ -494,7 +495,7 @@ async fn start_reader_impl(
         )
     });
 
-    let file_schema_rx = if forbid_extra_columns.is_some() {
+    let mut file_schema_rx = if forbid_extra_columns.is_some() {
         // Upstream should not have any reason to attach this.
         assert!(callbacks.file_schema_tx.is_none());
         let (tx, rx) = oneshot_channel::channel();
@@ -598,34 +599,67 @@ async fn start_reader_impl(
         predicate.set_external_constant_columns(external_predicate_cols);
     }
 
-    let begin_read_args = BeginReadArgs {
-        projection: projection_to_reader,
-        row_index,
-        pre_slice,
-        predicate,
-        cast_columns_policy: cast_columns_policy.clone(),
-        num_pipelines,
-        callbacks,
-    };
+    // Reading is retried on transient errors until the reader sends its first morsel, as sent
+    // morsels can't be taken back. Callbacks can only be sent once, so readers with callbacks are
+    // not retried.
+    let retry_read = source_retries.is_enabled()
+        && callbacks.file_schema_tx.is_none()
+        && callbacks.n_rows_in_file_tx.is_none()
+        && callbacks.row_position_on_end_tx.is_none();
+    let path = scan_source.as_scan_source_ref().to_include_path_name();
+    let mut n_retries: usize = 0;
+
+    let (reader_output_port, reader_handle, first_morsel) = loop {
+        let begin_read_args = BeginReadArgs {
+            projection: projection_to_reader.clone(),
+            row_index: row_index.clone(),
+            pre_slice: pre_slice.clone(),
+            predicate: predicate.clone(),
+            cast_columns_policy: cast_columns_policy.clone(),
+            num_pipelines,
+            callbacks: std::mem::take(&mut callbacks),
+        };
 
-    let (mut reader_output_port, reader_handle) = reader.begin_read(begin_read_args)?;
+        let (mut reader_output_port, reader_handle) = reader.begin_read(begin_read_args)?;
 
-    let reader_handle = AbortOnDropHandle::new(reader_handle);
+        let reader_handle = AbortOnDropHandle::new(reader_handle);
 
-    if let Some(forbid_extra_columns) = forbid_extra_columns {
-        if let Ok(this_file_schema) = file_schema_rx.unwrap().recv().await {
-            forbid_extra_columns.check_file_schema(
-                &this_file_schema,
-                file_iceberg_schema.as_ref(),
-                scan_source.as_scan_source_ref().to_include_path_name(),
-            )?;
-        } else {
-            drop(reader_output_port);
-            return Err(reader_handle.await.unwrap_err());
+        if let Some(forbid_extra_columns) = &forbid_extra_columns {
+            if let Ok(this_file_schema) = file_schema_rx.take().unwrap().recv().await {
+                forbid_extra_columns.check_file_schema(
+                    &this_file_schema,
+                    file_iceberg_schema.as_ref(),
+                    path,
+                )?;
+            } else {
+                drop(reader_output_port);
+                return Err(reader_handle.await.unwrap_err());
+            }
         }
-    }
 
-    let first_morsel = reader_output_port.recv().await.ok();
+        let first_morsel = reader_output_port.recv().await.ok();
+        if first_morsel.is_some() || !retry_read {
+            break (reader_output_port, reader_handle, first_morsel);
+        }
+
+        // Without morsels the file is either empty or the reader failed.
+        match reader_handle.await {
+            Ok(()) => {
+                let reader_handle =
+                    async_executor::spawn(TaskPriority::Low, async { PolarsResult::Ok(()) });
+                break (reader_output_port, AbortOnDropHandle::new(reader_handle), None);
+            },
+            Err(err) => {
+                if !source_retries
+                    .wait_for_retry(&err, scan_source_idx, n_retries)
+                    .await
+                {
+                    return Err(source_retries.source_failed(err, path, n_retries));
+                }
+                n_retries += 1;
+            },
+        }
+    };
 
     let ops_applier = if let Some(first_morsel) = &first_morsel {
         let final_output_schema = final_output_schema.clone();
@@ -243,7 +243,16 @@ impl FileReader for ParquetFileReader {
                 .map(|x| x.parse::<usize>().expect("integer").max(1))
                 .unwrap_or(16_777_216);
 
-        let is_full_projection = projected_arrow_fields.len() == file_schema.len();
+        let is_full_projection = projected_arrow_fields.len() == file_schema.len()
+            && !projected_arrow_fields.iter().any(|x| {
+                matches!(
+                    x,
+                    ArrowFieldProjection::Mapped {
+                        pruned_arrow_field: Some(_),
+                        ..
+                    }
+                )
+            });
 
         if verbose {
             eprintln!(
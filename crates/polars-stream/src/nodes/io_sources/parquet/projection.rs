@@ -1,10 +1,11 @@
 use std::borrow::Cow;
 use std::sync::Arc;
 
-use arrow::datatypes::ArrowSchema;
+use arrow::datatypes::{ArrowDataType, ArrowSchema};
 use polars_core::prelude::{ArrowField, Column, DataType};
 use polars_core::schema::Schema;
 use polars_error::PolarsResult;
+use polars_parquet::read::{ColumnChunkMetadata, RowGroupMetadata};
 use polars_plan::dsl::CastColumnsPolicy;
 use polars_utils::pl_str::PlSmallStr;
 
@@ -21,7 +22,7 @@ pub fn resolve_arrow_field_projections(
 ) -> PolarsResult<Arc<[ArrowFieldProjection]>> {
     let projection: Projection = match projection {
         Projection::Plain(projected_schema) => ProjectionBuilder::new(projected_schema, None, None)
-            .build_projection(Some(file_schema), None, cast_columns_policy, usize::MAX)?,
+            .build_projection(Some(file_schema), None, cast_columns_policy.clone(), usize::MAX)?,
         Projection::Mapped { .. } => projection,
     };
 
@@ -47,8 +48,27 @@ pub fn resolve_arrow_field_projections(
                     file_schema.get(source_name.as_str()).unwrap()
                 );
 
+                // Only decode the struct fields that are used by the query.
+                let pruned_arrow_field = if cast_columns_policy.is_pruned_struct_column(source_name)
+                    && let ArrowDataType::Struct(file_fields) = &arrow_field.dtype
+                    && let DataType::Struct(output_fields) = output_dtype
+                {
+                    let mut field = arrow_field.clone();
+                    field.dtype = ArrowDataType::Struct(
+                        file_fields
+                            .iter()
+                            .filter(|f| output_fields.iter().any(|o| o.name == f.name))
+                            .cloned()
+                            .collect(),
+                    );
+                    Some(field)
+                } else {
+                    None
+                };
+
                 ArrowFieldProjection::Mapped {
                     arrow_field,
+                    pruned_arrow_field,
                     output_name: output_name.clone(),
                     output_dtype: output_dtype.clone(),
                     transform: resolved_transform.attach_transforms(ColumnSelector::Position(0)),
@@ -64,6 +84,9 @@ pub enum ArrowFieldProjection {
     Plain(ArrowField),
     Mapped {
         arrow_field: ArrowField,
+        /// Struct field that only contains the fields used by the query. This is decoded instead
+        /// of `arrow_field`.
+        pruned_arrow_field: Option<ArrowField>,
         output_name: PlSmallStr,
         output_dtype: DataType,
         transform: ColumnSelector,
@@ -78,6 +101,44 @@ impl ArrowFieldProjection {
         }
     }
 
+    /// The field that is decoded from the file.
+    pub fn decoded_arrow_field(&self) -> &ArrowField {
+        match self {
+            Self::Mapped {
+                pruned_arrow_field: Some(field),
+                ..
+            } => field,
+            _ => self.arrow_field(),
+        }
+    }
+
+    /// The column chunks of the decoded field in a row group, or `None` if the column is missing.
+    pub fn column_chunks<'a>(
+        &self,
+        row_group_metadata: &'a RowGroupMetadata,
+    ) -> Option<Vec<&'a ColumnChunkMetadata>> {
+        let iter = row_group_metadata.columns_under_root_iter(&self.arrow_field().name)?;
+
+        let Self::Mapped {
+            pruned_arrow_field: Some(field),
+            ..
+        } = self
+        else {
+            return Some(iter.collect());
+        };
+        let ArrowDataType::Struct(fields) = &field.dtype else {
+            unreachable!()
+        };
+
+        Some(
+            iter.filter(|col_md| {
+                let path = &col_md.descriptor().path_in_schema;
+                fields.iter().any(|f| f.name == path[1])
+            })
+            .collect(),
+        )
+    }
+
     pub fn output_name(&self) -> &PlSmallStr {
         match self {
             Self::Plain(field) => &field.name,
@@ -97,10 +158,19 @@ impl ArrowFieldProjection {
         match self {
             Self::Plain(_) => Ok(column),
             Self::Mapped {
+                arrow_field,
+                pruned_arrow_field,
                 transform,
                 output_dtype,
                 ..
             } => {
+                // The transform selects from the struct as it is stored in the file, so fill the
+                // fields that were not decoded with nulls.
+                let column = if pruned_arrow_field.is_some() {
+                    column.cast(&DataType::from_arrow_field(arrow_field))?
+                } else {
+                    column
+                };
                 let output_height = column.len();
                 let out = transform.select_from_columns(&[column], output_height)?;
 
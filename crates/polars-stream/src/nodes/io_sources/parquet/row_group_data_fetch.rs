@@ -10,7 +10,6 @@ use polars_io::prelude::{FileMetadata, create_sorting_map};
 use polars_io::utils::byte_source::{ByteSource, DynByteSource};
 use polars_parquet::read::RowGroupMetadata;
 use polars_utils::mmap::MemSlice;
-use polars_utils::pl_str::PlSmallStr;
 
 use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
 use crate::utils::tokio_handle_ext;
@@ -97,7 +96,7 @@ impl RowGroupDataFetcher {
                             if !is_full_projection {
                                 for range in get_row_group_byte_ranges_for_projection(
                                     row_group_metadata,
-                                    &mut projection.iter().map(|x| &x.arrow_field().name),
+                                    projection.as_ref(),
                                 ) {
                                     memory_prefetch_func(unsafe { slice.get_unchecked(range) })
                                 }
@@ -120,7 +119,7 @@ impl RowGroupDataFetcher {
                     } else if !is_full_projection {
                         let mut ranges = get_row_group_byte_ranges_for_projection(
                             row_group_metadata,
-                            &mut projection.iter().map(|x| &x.arrow_field().name),
+                            projection.as_ref(),
                         )
                         .collect::<Vec<_>>();
 
@@ -193,11 +192,11 @@ impl FetchedBytes {
 
 fn get_row_group_byte_ranges_for_projection<'a>(
     row_group_metadata: &'a RowGroupMetadata,
-    columns: &'a mut dyn Iterator<Item = &PlSmallStr>,
+    projection: &'a [ArrowFieldProjection],
 ) -> impl Iterator<Item = std::ops::Range<usize>> + 'a {
-    columns.flat_map(|col_name| {
-        row_group_metadata
-            .columns_under_root_iter(col_name)
+    projection.iter().flat_map(|projection| {
+        projection
+            .column_chunks(row_group_metadata)
             // `Option::into_iter` so that we return an empty iterator for the
             // `allow_missing_columns` case
             .into_iter()
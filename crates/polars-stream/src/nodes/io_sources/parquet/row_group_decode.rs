@@ -2,7 +2,7 @@ use std::ops::Deref;
 use std::sync::Arc;
 
 use polars_core::frame::DataFrame;
-use polars_core::prelude::{ArrowField, BooleanChunked, ChunkFilter, Column, DataType, IntoColumn};
+use polars_core::prelude::{BooleanChunked, ChunkFilter, Column, DataType, IntoColumn};
 use polars_core::series::Series;
 use polars_core::utils::arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_error::PolarsResult;
@@ -208,7 +208,7 @@ impl RowGroupDecoder {
                                         [get_projected_field_at_output_index(i)];
 
                                     let (col, pred_true_mask) = decode_column(
-                                        projection.arrow_field(),
+                                        projection,
                                         &row_group_data,
                                         filter.clone(),
                                         expected_num_rows,
@@ -233,15 +233,14 @@ impl RowGroupDecoder {
 }
 
 fn decode_column(
-    arrow_field: &ArrowField,
+    projection: &ArrowFieldProjection,
     row_group_data: &RowGroupData,
     filter: Option<polars_parquet::read::Filter>,
     expected_num_rows: usize,
 ) -> PolarsResult<(Column, Bitmap)> {
-    let Some(iter) = row_group_data
-        .row_group_metadata
-        .columns_under_root_iter(&arrow_field.name)
-    else {
+    let arrow_field = projection.decoded_arrow_field();
+
+    let Some(columns) = projection.column_chunks(&row_group_data.row_group_metadata) else {
         return Ok((
             Column::full_null(
                 arrow_field.name.clone(),
@@ -252,7 +251,8 @@ fn decode_column(
         ));
     };
 
-    let columns_to_deserialize = iter
+    let columns_to_deserialize = columns
+        .into_iter()
         .map(|col_md| {
             let byte_range = col_md.byte_range();
 
@@ -353,12 +353,13 @@ fn calc_cols_per_thread(n_rows_per_col: usize, target_n_rows_per_thread: usize)
 // Pre-filtered
 
 fn decode_column_in_filter(
-    arrow_field: &ArrowField,
+    projection: &ArrowFieldProjection,
     use_column_predicates: bool,
     column_predicates: &ColumnPredicates,
     row_group_data: &RowGroupData,
     projection_height: usize,
 ) -> PolarsResult<(Column, Bitmap)> {
+    let arrow_field = projection.decoded_arrow_field();
     let mut filter = None;
     let mut constant = None;
     if use_column_predicates {
@@ -382,7 +383,7 @@ fn decode_column_in_filter(
             }));
         }
     }
-    let (mut c, m) = decode_column(arrow_field, row_group_data, filter, projection_height)?;
+    let (mut c, m) = decode_column(projection, row_group_data, filter, projection_height)?;
 
     if let Some(constant) = constant {
         c = Column::new_scalar(c.name().clone(), constant.clone(), m.set_bits());
@@ -469,7 +470,7 @@ impl RowGroupDecoder {
                                     }
 
                                     let (col, pred_true_mask) = decode_column_in_filter(
-                                        projection.arrow_field(),
+                                        projection,
                                         use_column_predicates,
                                         column_predicates.as_ref(),
                                         row_group_data.as_ref(),
@@ -603,7 +604,7 @@ impl RowGroupDecoder {
                                         &projected_arrow_fields[non_predicate_field_indices[i]];
 
                                     let col = decode_column_prefiltered(
-                                        projection.arrow_field(),
+                                        projection,
                                         row_group_data.as_ref(),
                                         &mask,
                                         &mask_bitmap,
@@ -635,16 +636,15 @@ impl RowGroupDecoder {
 }
 
 fn decode_column_prefiltered(
-    arrow_field: &ArrowField,
+    projection: &ArrowFieldProjection,
     row_group_data: &RowGroupData,
     mask: &BooleanChunked,
     mask_bitmap: &Bitmap,
     expected_num_rows: usize,
 ) -> PolarsResult<Column> {
-    let Some(iter) = row_group_data
-        .row_group_metadata
-        .columns_under_root_iter(&arrow_field.name)
-    else {
+    let arrow_field = projection.decoded_arrow_field();
+
+    let Some(columns) = projection.column_chunks(&row_group_data.row_group_metadata) else {
         return Ok(Column::full_null(
             arrow_field.name.clone(),
             expected_num_rows,
@@ -652,7 +652,8 @@ fn decode_column_prefiltered(
         ));
     };
 
-    let columns_to_deserialize = iter
+    let columns_to_deserialize = columns
+        .into_iter()
         .map(|col_md| {
             let byte_range = col_md.byte_range();
 
@@ -5,7 +5,7 @@ use arrow::bitmap::Bitmap;
 use arrow::pushable::Pushable;
 use polars_core::prelude::*;
 use polars_io::RowIndex;
-use polars_io::predicates::ScanIOPredicate;
+use polars_io::predicates::{NestedStatisticsPath, ScanIOPredicate};
 use polars_io::prelude::FileMetadata;
 use polars_parquet::read::RowGroupMetadata;
 use polars_parquet::read::statistics::{ArrowColumnStatisticsArrays, deserialize_all};
@@ -102,6 +102,7 @@ pub(super) async fn calculate_row_group_pred_pushdown_skip_mask(
     let num_row_groups = row_group_slice.len();
     let metadata = metadata.clone();
     let live_columns = predicate.live_columns.clone();
+    let nested_statistics = predicate.nested_statistics.clone();
 
     // Note: We are spawning here onto the computational async runtime because the caller is being run
     // on a tokio async thread.
@@ -144,6 +145,21 @@ pub(super) async fn calculate_row_group_pred_pushdown_skip_mask(
             columns.extend([statistics.min, statistics.max, statistics.null_count]);
         }
 
+        for (path, dtype) in nested_statistics.iter() {
+            let projection = projected_arrow_fields
+                .iter()
+                .find(|projection| projection.output_name() == path.column());
+            let statistics = match projection {
+                None => StatisticsColumns::new_null(dtype, num_row_groups),
+                Some(projection) => {
+                    load_parquet_nested_statistics(row_groups_slice, projection, path, dtype)?
+                },
+            };
+            let statistics = statistics.with_base_column_name(&path.name());
+
+            columns.extend([statistics.min, statistics.max, statistics.null_count]);
+        }
+
         if let Some(row_index) = row_index {
             let statistics = build_row_index_statistics(&row_index, row_groups_slice)
                 .with_base_column_name(&row_index.name);
@@ -204,6 +220,99 @@ fn load_parquet_column_statistics(
     StatisticsColumns::from_arrow_statistics(statistics, arrow_field)
 }
 
+/// Load the statistics of a struct field or of the list lengths of a column.
+fn load_parquet_nested_statistics(
+    row_groups: &[RowGroupMetadata],
+    projection: &ArrowFieldProjection,
+    path: &NestedStatisticsPath,
+    dtype: &DataType,
+) -> PolarsResult<StatisticsColumns> {
+    let arrow_field = projection.arrow_field();
+
+    let null_statistics = || Ok(StatisticsColumns::new_null(dtype, row_groups.len()));
+
+    let Some(idxs) = row_groups[0].columns_idxs_under_root_iter(&arrow_field.name) else {
+        return null_statistics();
+    };
+
+    let statistics = match path {
+        NestedStatisticsPath::StructField { fields, .. } => {
+            let mut field = arrow_field;
+            for name in fields {
+                let ArrowDataType::Struct(struct_fields) = field.dtype() else {
+                    return null_statistics();
+                };
+                let Some(struct_field) = struct_fields.iter().find(|f| &f.name == name) else {
+                    return null_statistics();
+                };
+                field = struct_field;
+            }
+
+            // Only fields that are stored as a single parquet column have statistics.
+            let Some(idx) = idxs.iter().copied().find(|idx| {
+                let path_in_schema =
+                    &row_groups[0].parquet_columns()[*idx].descriptor().path_in_schema;
+                path_in_schema[1..] == fields[..]
+            }) else {
+                return null_statistics();
+            };
+
+            let Some(statistics) = deserialize_all(field, row_groups, idx)? else {
+                return null_statistics();
+            };
+            StatisticsColumns::from_arrow_statistics(statistics, field)?
+        },
+        NestedStatisticsPath::ListLength { .. } => {
+            let (ArrowDataType::List(inner) | ArrowDataType::LargeList(inner)) =
+                arrow_field.dtype()
+            else {
+                return null_statistics();
+            };
+            if inner.dtype().is_nested() || idxs.len() != 1 {
+                return null_statistics();
+            }
+            build_list_length_statistics(row_groups, idxs[0])
+        },
+    };
+
+    // The file may store the values with a different type than the output.
+    match (statistics.min.strict_cast(dtype), statistics.max.strict_cast(dtype)) {
+        (Ok(min), Ok(max)) => Ok(StatisticsColumns {
+            min,
+            max,
+            null_count: statistics.null_count,
+        }),
+        _ => null_statistics(),
+    }
+}
+
+/// Bound the list lengths using the number of values in the leaf column.
+///
+/// Every list, including empty and null lists, takes up at least one value in the leaf column. So
+/// no list is longer than the number of values that are not taken up by the other lists.
+fn build_list_length_statistics(row_groups: &[RowGroupMetadata], idx: usize) -> StatisticsColumns {
+    let min_value = PrimitiveArray::<IdxSize>::full(row_groups.len(), 0, ArrowDataType::IDX_DTYPE);
+    let null_count =
+        PrimitiveArray::<IdxSize>::new_null(ArrowDataType::IDX_DTYPE, row_groups.len());
+
+    let mut max_value = MutablePrimitiveArray::<IdxSize>::with_capacity(row_groups.len());
+    for rg in row_groups.iter() {
+        let n_rows = rg.num_rows() as i64;
+        let n_values = rg.parquet_columns()[idx].num_values();
+
+        let max = (n_rows > 0 && n_values >= n_rows)
+            .then(|| IdxSize::try_from(n_values - n_rows + 1).ok())
+            .flatten();
+        max_value.push(max);
+    }
+
+    StatisticsColumns {
+        min: Series::from_array(PlSmallStr::EMPTY, min_value).into_column(),
+        max: Series::from_array(PlSmallStr::EMPTY, max_value.freeze()).into_column(),
+        null_count: Series::from_array(PlSmallStr::EMPTY, null_count).into_column(),
+    }
+}
+
 fn build_row_index_statistics(
     row_index: &RowIndex,
     row_groups: &[RowGroupMetadata],
@@ -0,0 +1,430 @@
+use std::path::PathBuf;
+
+use polars_core::config;
+use polars_core::prelude::{PlSmallStr, Series, SortMultipleOptions};
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_ops::frame::{DataFrameJoinOps, JoinArgs, JoinTypeOptions, MaintainOrderJoin};
+
+use crate::expression::StreamExpr;
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::nodes::compute_node_prelude::*;
+use crate::utils::spill::{SpillDir, load_spilled, memory_budget};
+
+/// Added to the probe morsels to restore their order when the build side consists of several
+/// chunks.
+const PROBE_ROW_INDEX: PlSmallStr = PlSmallStr::from_static("__POLARS_BLOCK_JOIN_PROBE_ROW");
+
+/// Joins every morsel of the probe side against the buffered build side.
+///
+/// This is valid for joins of which the output is the concatenation of joining the probe side
+/// block by block, such as inequality joins. The build side is chosen when lowering from a row
+/// estimate, so it is buffered in chunks under a memory budget (`POLARS_BLOCK_JOIN_MEMORY_BUDGET`
+/// bytes): half of the budget is used for chunks held in memory, the chunks after that are
+/// spilled to disk. Every probe morsel is joined against all chunks in turn, loading the spilled
+/// ones back one at a time, which leaves the other half of the budget for the loaded chunks of
+/// the pipelines. Cross joins use the `CrossJoinNode`.
+pub struct BlockJoinNode {
+    left_key_selectors: Vec<StreamExpr>,
+    right_key_selectors: Vec<StreamExpr>,
+    joiner: BlockJoiner,
+    state: BlockJoinState,
+}
+
+struct BlockJoiner {
+    left_is_build: bool,
+    args: JoinArgs,
+    options: Option<JoinTypeOptions>,
+}
+
+impl BlockJoinNode {
+    pub fn new(
+        left_key_selectors: Vec<StreamExpr>,
+        right_key_selectors: Vec<StreamExpr>,
+        args: JoinArgs,
+        options: Option<JoinTypeOptions>,
+        left_is_build: bool,
+    ) -> Self {
+        assert!(args.slice.is_none());
+        let budget = memory_budget("POLARS_BLOCK_JOIN_MEMORY_BUDGET");
+        if config::verbose() {
+            eprintln!(
+                "block join buffers the {} input with a memory budget of {budget} bytes",
+                if left_is_build { "left" } else { "right" }
+            );
+        }
+
+        Self {
+            left_key_selectors,
+            right_key_selectors,
+            joiner: BlockJoiner {
+                left_is_build,
+                args,
+                options,
+            },
+            state: BlockJoinState::Build(BuildBuffer::new(budget)),
+        }
+    }
+}
+
+impl BlockJoiner {
+    fn join(
+        &self,
+        probe_df: &DataFrame,
+        probe_keys: Vec<Series>,
+        build_df: &DataFrame,
+        build_keys: Vec<Series>,
+    ) -> PolarsResult<DataFrame> {
+        let (left_df, left_keys, right_df, right_keys);
+        if self.left_is_build {
+            (left_df, left_keys) = (build_df, build_keys);
+            (right_df, right_keys) = (probe_df, probe_keys);
+        } else {
+            (left_df, left_keys) = (probe_df, probe_keys);
+            (right_df, right_keys) = (build_df, build_keys);
+        }
+        left_df._join_impl(
+            right_df,
+            left_keys,
+            right_keys,
+            self.args.clone(),
+            self.options.clone(),
+            false,
+            false,
+        )
+    }
+
+    /// Whether the order of the build side must be maintained within the matches of a probe row.
+    fn maintains_build_order(&self) -> bool {
+        matches!(
+            self.args.maintain_order,
+            MaintainOrderJoin::LeftRight | MaintainOrderJoin::RightLeft
+        )
+    }
+}
+
+/// Buffers the build side in chunks, spilling the chunks that don't fit in half of the memory
+/// budget.
+struct BuildBuffer {
+    budget: usize,
+    /// The size of a chunk in bytes, set once the number of pipelines is known.
+    chunk_size: usize,
+    pending: Vec<DataFrame>,
+    pending_size: usize,
+    in_memory_size: usize,
+    chunks: Vec<BuildChunk>,
+    spill_dir: Option<SpillDir>,
+}
+
+enum BuildChunk {
+    InMemory(DataFrame),
+    Spilled(PathBuf),
+}
+
+impl BuildBuffer {
+    fn new(budget: usize) -> Self {
+        Self {
+            budget,
+            chunk_size: budget,
+            pending: Vec::new(),
+            pending_size: 0,
+            in_memory_size: 0,
+            chunks: Vec::new(),
+            spill_dir: None,
+        }
+    }
+
+    fn push(&mut self, df: DataFrame) -> PolarsResult<()> {
+        self.pending_size += df.estimated_size();
+        self.pending.push(df);
+        if self.pending_size >= self.chunk_size {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> PolarsResult<()> {
+        let size = std::mem::take(&mut self.pending_size);
+        let pending = std::mem::take(&mut self.pending);
+        if pending.iter().all(|df| df.height() == 0) {
+            return Ok(());
+        }
+        let df = accumulate_dataframes_vertical_unchecked(pending);
+
+        if self.in_memory_size + size <= self.budget / 2 {
+            self.in_memory_size += size;
+            self.chunks.push(BuildChunk::InMemory(df));
+        } else {
+            let spill_dir = match &mut self.spill_dir {
+                Some(spill_dir) => spill_dir,
+                None => self.spill_dir.insert(SpillDir::try_new("block-join")?),
+            };
+            self.chunks.push(BuildChunk::Spilled(spill_dir.spill(df)?));
+        }
+        Ok(())
+    }
+}
+
+/// A chunk of the build side as it is probed.
+enum ProbeChunk {
+    /// Rechunked, with its keys selected once as it is joined against every probe morsel.
+    InMemory {
+        df: DataFrame,
+        keys: Vec<Series>,
+    },
+    Spilled(PathBuf),
+}
+
+enum BlockJoinState {
+    Build(BuildBuffer),
+    Probe {
+        chunks: Vec<ProbeChunk>,
+        /// Keeps the spilled chunks on disk until the join is done.
+        _spill_dir: Option<SpillDir>,
+    },
+    Done,
+}
+
+async fn select_keys(
+    selectors: &[StreamExpr],
+    df: &DataFrame,
+    state: &ExecutionState,
+) -> PolarsResult<Vec<Series>> {
+    let mut keys = Vec::with_capacity(selectors.len());
+    for selector in selectors {
+        let key = selector.evaluate(df, state).await?;
+        keys.push(key.take_materialized_series());
+    }
+    Ok(keys)
+}
+
+/// Sends the output of joining a probe morsel, which can match many build rows, in morsels of
+/// the ideal size. Returns `false` if the receiver is gone.
+async fn send_output(
+    send: &mut PortSender,
+    out: DataFrame,
+    seq: MorselSeq,
+    source_token: &SourceToken,
+) -> bool {
+    let ideal_morsel_size = get_ideal_morsel_size();
+    let mut offset = 0;
+    while offset < out.height() {
+        let height = (out.height() - offset).min(ideal_morsel_size);
+        let df = out.slice(offset as i64, height);
+        let morsel = Morsel::new(df, seq, source_token.clone());
+        if send.send(morsel).await.is_err() {
+            return false;
+        }
+        offset += height;
+    }
+    true
+}
+
+impl ComputeNode for BlockJoinNode {
+    fn name(&self) -> &str {
+        "block-join"
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        true
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        let build_idx = if self.joiner.left_is_build { 0 } else { 1 };
+        let probe_idx = 1 - build_idx;
+
+        // Are we done?
+        if send[0] == PortState::Done || recv[probe_idx] == PortState::Done {
+            self.state = BlockJoinState::Done;
+        }
+
+        // Transition to probe?
+        if recv[build_idx] == PortState::Done {
+            if let BlockJoinState::Build(buffer) = &mut self.state {
+                buffer.flush()?;
+                let chunks = std::mem::take(&mut buffer.chunks);
+                let spill_dir = buffer.spill_dir.take();
+                if config::verbose() {
+                    let n_spilled = chunks
+                        .iter()
+                        .filter(|c| matches!(c, BuildChunk::Spilled(_)))
+                        .count();
+                    eprintln!(
+                        "block join buffered {} chunk(s), {n_spilled} spilled to disk",
+                        chunks.len()
+                    );
+                }
+
+                if chunks.is_empty() {
+                    self.state = BlockJoinState::Done;
+                } else {
+                    let selectors = if self.joiner.left_is_build {
+                        &self.left_key_selectors
+                    } else {
+                        &self.right_key_selectors
+                    };
+                    let chunks = chunks
+                        .into_iter()
+                        .map(|chunk| {
+                            Ok(match chunk {
+                                BuildChunk::InMemory(mut df) => {
+                                    df.rechunk_mut_par();
+                                    let keys = selectors
+                                        .iter()
+                                        .map(|s| {
+                                            let key = s.evaluate_blocking(
+                                                &df,
+                                                &state.in_memory_exec_state,
+                                            )?;
+                                            Ok(key.take_materialized_series())
+                                        })
+                                        .collect::<PolarsResult<Vec<_>>>()?;
+                                    ProbeChunk::InMemory { df, keys }
+                                },
+                                BuildChunk::Spilled(path) => ProbeChunk::Spilled(path),
+                            })
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                    self.state = BlockJoinState::Probe {
+                        chunks,
+                        _spill_dir: spill_dir,
+                    };
+                }
+            }
+        }
+
+        match &self.state {
+            BlockJoinState::Build(_) => {
+                recv[build_idx] = PortState::Ready;
+                recv[probe_idx] = PortState::Blocked;
+                send[0] = PortState::Blocked;
+            },
+            BlockJoinState::Probe { .. } => {
+                recv[build_idx] = PortState::Done;
+                core::mem::swap(&mut recv[probe_idx], &mut send[0]);
+            },
+            BlockJoinState::Done => {
+                recv[0] = PortState::Done;
+                recv[1] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 2 && send_ports.len() == 1);
+        let build_idx = if self.joiner.left_is_build { 0 } else { 1 };
+        let probe_idx = 1 - build_idx;
+        let build_key_selectors = if self.joiner.left_is_build {
+            &self.left_key_selectors
+        } else {
+            &self.right_key_selectors
+        };
+        let probe_key_selectors = if self.joiner.left_is_build {
+            &self.right_key_selectors
+        } else {
+            &self.left_key_selectors
+        };
+        match &mut self.state {
+            BlockJoinState::Build(buffer) => {
+                assert!(send_ports[0].is_none());
+                assert!(recv_ports[probe_idx].is_none());
+                // Every pipeline can hold a loaded spilled chunk while probing, they share the
+                // half of the budget that is not used for the chunks held in memory.
+                buffer.chunk_size = (buffer.budget / 2 / state.num_pipelines).max(1);
+                // Buffering is cheap, so the build side is received serially to keep its order
+                // when needed.
+                let mut recv = recv_ports[build_idx]
+                    .take()
+                    .unwrap()
+                    .serial_with_maintain_order(self.joiner.maintains_build_order());
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    while let Ok(mut morsel) = recv.recv().await {
+                        morsel.take_consume_token();
+                        buffer.push(morsel.into_df())?;
+                    }
+                    Ok(())
+                }));
+            },
+            BlockJoinState::Probe { chunks, .. } => {
+                assert!(recv_ports[build_idx].is_none());
+                let receivers = recv_ports[probe_idx].take().unwrap().parallel();
+                let senders = send_ports[0].take().unwrap().parallel();
+                // The output of every chunk is in the order of the probe morsel, but with several
+                // chunks the outputs have to be merged by probe row.
+                let restore_order =
+                    chunks.len() > 1 && self.joiner.args.maintain_order != MaintainOrderJoin::None;
+
+                for (mut recv, mut send) in receivers.into_iter().zip(senders) {
+                    let joiner = &self.joiner;
+                    let chunks = &*chunks;
+                    join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                        let exec_state = &state.in_memory_exec_state;
+                        while let Ok(morsel) = recv.recv().await {
+                            let (mut probe_df, seq, source_token, _consume_token) =
+                                morsel.into_inner();
+                            if restore_order {
+                                probe_df = probe_df.with_row_index(PROBE_ROW_INDEX, None)?;
+                            }
+                            let probe_keys =
+                                select_keys(probe_key_selectors, &probe_df, exec_state).await?;
+
+                            let mut outputs = Vec::new();
+                            for chunk in chunks {
+                                let out = match chunk {
+                                    ProbeChunk::InMemory { df, keys } => joiner.join(
+                                        &probe_df,
+                                        probe_keys.clone(),
+                                        df,
+                                        keys.clone(),
+                                    )?,
+                                    ProbeChunk::Spilled(path) => {
+                                        let df = load_spilled(path)?;
+                                        let keys =
+                                            select_keys(build_key_selectors, &df, exec_state)
+                                                .await?;
+                                        joiner.join(&probe_df, probe_keys.clone(), &df, keys)?
+                                    },
+                                };
+                                if restore_order {
+                                    outputs.push(out);
+                                } else if !send_output(&mut send, out, seq, &source_token).await {
+                                    return Ok(());
+                                }
+                            }
+
+                            if restore_order {
+                                // Stable, so the matches of a probe row stay in build order.
+                                let mut out = accumulate_dataframes_vertical_unchecked(outputs);
+                                out.sort_in_place(
+                                    [PROBE_ROW_INDEX],
+                                    SortMultipleOptions::default().with_maintain_order(true),
+                                )?;
+                                out.drop_in_place(&PROBE_ROW_INDEX)?;
+                                if !send_output(&mut send, out, seq, &source_token).await {
+                                    return Ok(());
+                                }
+                            }
+                        }
+                        Ok(())
+                    }));
+                }
+            },
+            BlockJoinState::Done => unreachable!(),
+        }
+    }
+}
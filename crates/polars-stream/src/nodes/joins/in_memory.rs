@@ -41,6 +41,28 @@ impl ComputeNode for InMemoryJoinNode {
         "in-memory-join"
     }
 
+    fn checkpoint_mode(&self) -> CheckpointMode {
+        CheckpointMode::Stateful
+    }
+
+    fn checkpoint_state(&mut self) -> PolarsResult<Option<Vec<DataFrame>>> {
+        let InMemoryJoinState::Sink { left, right } = &mut self.state else {
+            return Ok(None);
+        };
+        let mut state = left.checkpoint_state()?.unwrap();
+        state.extend(right.checkpoint_state()?.unwrap());
+        Ok(Some(state))
+    }
+
+    fn restore_state(&mut self, state: Vec<DataFrame>) -> PolarsResult<()> {
+        let InMemoryJoinState::Sink { left, right } = &mut self.state else {
+            unreachable!()
+        };
+        let mut state = state.into_iter();
+        left.restore_state(state.by_ref().take(1).collect())?;
+        right.restore_state(state.collect())
+    }
+
     fn update_state(
         &mut self,
         recv: &mut [PortState],
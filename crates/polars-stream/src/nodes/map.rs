@@ -20,6 +20,10 @@ impl ComputeNode for MapNode {
         "map"
     }
 
+    fn checkpoint_mode(&self) -> CheckpointMode {
+        CheckpointMode::Stateless
+    }
+
     fn update_state(
         &mut self,
         recv: &mut [PortState],
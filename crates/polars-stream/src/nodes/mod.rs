@@ -23,6 +23,7 @@ pub mod merge_sorted;
 pub mod multiplexer;
 pub mod negative_slice;
 pub mod ordered_union;
+pub mod partitioned_window;
 pub mod peak_minmax;
 pub mod reduce;
 pub mod repeat;
@@ -45,7 +46,7 @@ mod compute_node_prelude {
     pub use polars_error::PolarsResult;
     pub use polars_expr::state::ExecutionState;
 
-    pub use super::ComputeNode;
+    pub use super::{CheckpointMode, ComputeNode};
     pub use crate::async_executor::{JoinHandle, TaskPriority, TaskScope};
     pub use crate::execute::StreamingExecutionState;
     pub use crate::graph::PortState;
@@ -54,9 +55,24 @@ mod compute_node_prelude {
 }
 
 use compute_node_prelude::*;
+use polars_error::polars_bail;
 
 use crate::execute::StreamingExecutionState;
 
+/// How the state of a node is recovered when a query is resumed from a checkpoint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CheckpointMode {
+    /// The node can't be checkpointed.
+    Unsupported,
+    /// The node holds no state between morsels.
+    Stateless,
+    /// The node is a source, which can resume from the number of rows it has emitted.
+    Source,
+    /// The node only sends output once all its inputs are done, and the state it builds up
+    /// until then is stored in the checkpoint with [`ComputeNode::checkpoint_state`].
+    Stateful,
+}
+
 pub trait ComputeNode: Send {
     /// The name of this node.
     fn name(&self) -> &str;
@@ -101,4 +117,41 @@ pub trait ComputeNode: Send {
     fn get_output(&mut self) -> PolarsResult<Option<DataFrame>> {
         Ok(None)
     }
+
+    /// How the state of this node is recovered when a query is resumed from
+    /// a checkpoint.
+    fn checkpoint_mode(&self) -> CheckpointMode {
+        CheckpointMode::Unsupported
+    }
+
+    /// The number of rows this source has emitted, for nodes with
+    /// [`CheckpointMode::Source`].
+    fn checkpoint_position(&self) -> Option<u64> {
+        None
+    }
+
+    /// Resume this source from a position returned by `checkpoint_position`.
+    /// Called before the first execution phase.
+    fn restore_position(&mut self, _position: u64) -> PolarsResult<()> {
+        polars_bail!(
+            InvalidOperation: "'{}' can't be restored from a checkpoint", self.name()
+        )
+    }
+
+    /// The state this node has built up from its inputs, for nodes with
+    /// [`CheckpointMode::Stateful`]. Called in between two execution phases.
+    ///
+    /// Returns `None` if the node can't be checkpointed in its current state,
+    /// e.g. a sink that has started writing its output.
+    fn checkpoint_state(&mut self) -> PolarsResult<Option<Vec<DataFrame>>> {
+        polars_bail!(InvalidOperation: "'{}' can't be checkpointed", self.name())
+    }
+
+    /// Restore the state returned by `checkpoint_state`. Called before the
+    /// first execution phase.
+    fn restore_state(&mut self, _state: Vec<DataFrame>) -> PolarsResult<()> {
+        polars_bail!(
+            InvalidOperation: "'{}' can't be restored from a checkpoint", self.name()
+        )
+    }
 }
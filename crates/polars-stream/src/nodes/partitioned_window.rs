@@ -0,0 +1,287 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use polars_core::config;
+use polars_core::prelude::*;
+use polars_core::schema::Schema;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_expr::hash_keys::HashKeys;
+use polars_utils::hashing::HashPartitioner;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::compute_node_prelude::*;
+use crate::async_primitives::wait_group::WaitGroup;
+use crate::expression::StreamExpr;
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::utils::spill::{SpillBuckets, memory_budget};
+
+/// The number of partitions the rows are exchanged into. A single partition is evaluated at a
+/// time, so more partitions need less memory, unless a few window groups hold most rows.
+const NUM_PARTITIONS: usize = 64;
+
+/// Evaluates window expressions (`expr.over(..)`) on a stream.
+///
+/// The rows are exchanged into hash partitions on the `partition_by` keys, so every window group
+/// is contained in a single partition. Once the input is done, the rows of each partition are
+/// put back in the order of the input using the row index added when lowering, and the window
+/// expressions are evaluated on them by the in-memory engine, which also applies their
+/// `order_by`. The results are gathered into blocks of consecutive rows that are sent in the
+/// order of the input.
+///
+/// The partitions and the blocks each get half of the memory budget
+/// (`POLARS_WINDOW_MEMORY_BUDGET` bytes) and are spilled to disk beyond it, only the partition
+/// that is evaluated and the block that is sent have to fit in memory.
+pub struct PartitionedWindowNode {
+    row_index: PlSmallStr,
+    key_selectors: Vec<StreamExpr>,
+    exprs: Vec<StreamExpr>,
+    output_schema: Arc<Schema>,
+    random_state: PlRandomState,
+    budget: usize,
+    state: WindowState,
+}
+
+enum WindowState {
+    Sink {
+        partitions: Mutex<SpillBuckets>,
+    },
+    Source {
+        blocks: SpillBuckets,
+        next_block: usize,
+    },
+    Done,
+}
+
+impl PartitionedWindowNode {
+    pub fn new(
+        row_index: PlSmallStr,
+        key_selectors: Vec<StreamExpr>,
+        exprs: Vec<StreamExpr>,
+        output_schema: Arc<Schema>,
+    ) -> Self {
+        let budget = memory_budget("POLARS_WINDOW_MEMORY_BUDGET");
+        Self {
+            row_index,
+            key_selectors,
+            exprs,
+            output_schema,
+            random_state: PlRandomState::default(),
+            budget,
+            state: WindowState::Sink {
+                partitions: Mutex::new(SpillBuckets::new("window", budget / 2)),
+            },
+        }
+    }
+
+    /// Evaluates the window expressions on every partition, returning the results in blocks of
+    /// the ideal morsel size by row index.
+    fn evaluate_partitions(&self, partitions: &mut SpillBuckets) -> PolarsResult<SpillBuckets> {
+        let block_size = get_ideal_morsel_size();
+        let mut blocks = SpillBuckets::new("window", self.budget / 2);
+        for p in 0..partitions.num_buckets() {
+            let frames = partitions.take(p)?;
+            if frames.is_empty() {
+                continue;
+            }
+
+            // The partitions are filled by all pipelines, restore the order of the input.
+            let mut df = accumulate_dataframes_vertical_unchecked(frames);
+            df.sort_in_place([&self.row_index], SortMultipleOptions::default())?;
+
+            // Window results can't be cached between partitions.
+            let exec_state = ExecutionState::new();
+            let mut columns = Vec::with_capacity(self.exprs.len() + 1);
+            columns.push(df.column(&self.row_index)?.clone());
+            for expr in &self.exprs {
+                columns.push(expr.evaluate_blocking(&df, &exec_state)?);
+            }
+            let out = DataFrame::new_infer_broadcast(columns)?;
+
+            let idx = out.column(&self.row_index)?.idx()?.rechunk();
+            let idx = idx.cont_slice()?;
+            let mut offset = 0;
+            while offset < idx.len() {
+                let block = idx[offset] as usize / block_size;
+                let block_end = ((block + 1) * block_size) as IdxSize;
+                let len = idx[offset..].partition_point(|i| *i < block_end);
+                blocks.push(block, out.slice(offset as i64, len))?;
+                offset += len;
+            }
+        }
+
+        if config::verbose() {
+            eprintln!(
+                "window evaluated {} partition(s) into {} block(s), spilled {} partition and {} \
+                 block frame(s) to disk",
+                partitions.num_buckets(),
+                blocks.num_buckets(),
+                partitions.num_spilled(),
+                blocks.num_spilled(),
+            );
+        }
+        Ok(blocks)
+    }
+}
+
+impl ComputeNode for PartitionedWindowNode {
+    fn name(&self) -> &str {
+        "partitioned-window"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // If the output doesn't want any more data, transition to being done.
+        if send[0] == PortState::Done {
+            self.state = WindowState::Done;
+        }
+
+        // If the input is done, evaluate the partitions and transition to being a source.
+        if recv[0] == PortState::Done {
+            if let WindowState::Sink { partitions } = &mut self.state {
+                let mut partitions = std::mem::replace(
+                    partitions.get_mut(),
+                    SpillBuckets::new("window", 0),
+                );
+                let blocks = self.evaluate_partitions(&mut partitions)?;
+                self.state = WindowState::Source {
+                    blocks,
+                    next_block: 0,
+                };
+            }
+        }
+
+        // An empty input still sends a single empty morsel.
+        if let WindowState::Source { blocks, next_block } = &self.state {
+            if *next_block >= blocks.num_buckets().max(1) {
+                self.state = WindowState::Done;
+            }
+        }
+
+        match &self.state {
+            WindowState::Sink { .. } => {
+                recv[0] = PortState::Ready;
+                send[0] = PortState::Blocked;
+            },
+            WindowState::Source { .. } => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Ready;
+            },
+            WindowState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, WindowState::Sink { .. })
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        match &mut self.state {
+            WindowState::Sink { partitions } => {
+                assert!(send_ports[0].is_none());
+                let receivers = recv_ports[0].take().unwrap().parallel();
+                let partitioner = HashPartitioner::new(NUM_PARTITIONS, 0);
+
+                for mut recv in receivers {
+                    let partitions = &*partitions;
+                    let key_selectors = &self.key_selectors;
+                    let random_state = &self.random_state;
+                    let partitioner = partitioner.clone();
+                    join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                        let mut idxs_per_p = vec![Vec::new(); NUM_PARTITIONS];
+                        while let Ok(mut morsel) = recv.recv().await {
+                            morsel.take_consume_token();
+                            let df = morsel.into_df();
+
+                            let mut key_columns = Vec::with_capacity(key_selectors.len());
+                            for selector in key_selectors {
+                                let key = selector.evaluate(&df, &state.in_memory_exec_state);
+                                key_columns.push(key.await?.into_column());
+                            }
+                            let keys = unsafe {
+                                DataFrame::new_unchecked_with_broadcast(df.height(), key_columns)?
+                            };
+                            let hash_keys =
+                                HashKeys::from_df(&keys, random_state.clone(), true, false);
+
+                            idxs_per_p.iter_mut().for_each(|idxs| idxs.clear());
+                            hash_keys.gen_idxs_per_partition(
+                                &partitioner,
+                                &mut idxs_per_p,
+                                &mut [],
+                                true,
+                            );
+                            let parts = idxs_per_p
+                                .iter()
+                                .enumerate()
+                                .filter(|(_, idxs)| !idxs.is_empty())
+                                // SAFETY: the indices are in-bounds of the morsel.
+                                .map(|(p, idxs)| (p, unsafe { df.take_slice_unchecked(idxs) }))
+                                .collect::<Vec<_>>();
+
+                            let mut partitions = partitions.lock();
+                            for (p, part) in parts {
+                                partitions.push(p, part)?;
+                            }
+                        }
+                        Ok(())
+                    }));
+                }
+            },
+            WindowState::Source { blocks, next_block } => {
+                assert!(recv_ports[0].is_none());
+                let mut send = send_ports[0].take().unwrap().serial();
+                let row_index = &self.row_index;
+                let output_schema = &self.output_schema;
+
+                join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
+                    let source_token = SourceToken::new();
+                    let wait_group = WaitGroup::default();
+                    while *next_block < blocks.num_buckets().max(1) {
+                        let frames = blocks.take(*next_block)?;
+                        let df = if frames.is_empty() {
+                            DataFrame::empty_with_schema(output_schema)
+                        } else {
+                            let mut df = accumulate_dataframes_vertical_unchecked(frames);
+                            df.sort_in_place([row_index], SortMultipleOptions::default())?;
+                            df.drop_in_place(row_index)?;
+                            df
+                        };
+
+                        let seq = MorselSeq::new(*next_block as u64);
+                        *next_block += 1;
+                        let mut morsel = Morsel::new(df, seq, source_token.clone());
+                        morsel.set_consume_token(wait_group.token());
+                        if send.send(morsel).await.is_err() {
+                            break;
+                        }
+
+                        wait_group.wait().await;
+                        if source_token.stop_requested() {
+                            break;
+                        }
+                    }
+                    Ok(())
+                }));
+            },
+            WindowState::Done => unreachable!(),
+        }
+    }
+}
@@ -1,15 +1,41 @@
 use std::sync::Arc;
 
 use polars_core::frame::column::ScalarColumn;
-use polars_core::prelude::Column;
+use polars_core::prelude::{Column, IntoColumn};
 use polars_core::schema::{Schema, SchemaExt};
 use polars_expr::reduce::GroupedReduction;
+use polars_utils::format_pl_smallstr;
 use polars_utils::itertools::Itertools;
 
 use super::compute_node_prelude::*;
 use crate::expression::StreamExpr;
 use crate::morsel::SourceToken;
 
+/// The state of a reduction as a DataFrame with a row per group, see
+/// [`GroupedReduction::checkpoint_state`].
+pub(super) fn reduction_state(reduction: &dyn GroupedReduction) -> Option<DataFrame> {
+    let columns = reduction
+        .checkpoint_state()?
+        .into_iter()
+        .enumerate()
+        .map(|(i, s)| s.with_name(format_pl_smallstr!("{i}")).into_column())
+        .collect();
+    Some(DataFrame::new_infer_height(columns).unwrap())
+}
+
+/// Restore a reduction from the state returned by [`reduction_state`].
+pub(super) fn restore_reduction(
+    reduction: &mut dyn GroupedReduction,
+    state: &DataFrame,
+) -> PolarsResult<()> {
+    let state = state
+        .columns()
+        .iter()
+        .map(|c| c.as_materialized_series().clone())
+        .collect_vec();
+    reduction.restore_state(&state)
+}
+
 enum ReduceState {
     Sink {
         selectors: Vec<Vec<StreamExpr>>,
@@ -122,6 +148,34 @@ impl ComputeNode for ReduceNode {
         "reduce"
     }
 
+    fn checkpoint_mode(&self) -> CheckpointMode {
+        match &self.state {
+            ReduceState::Sink { reductions, .. }
+                if !reductions.iter().all(|r| r.checkpoint_state().is_some()) =>
+            {
+                CheckpointMode::Unsupported
+            },
+            _ => CheckpointMode::Stateful,
+        }
+    }
+
+    fn checkpoint_state(&mut self) -> PolarsResult<Option<Vec<DataFrame>>> {
+        let ReduceState::Sink { reductions, .. } = &self.state else {
+            return Ok(None);
+        };
+        Ok(reductions.iter().map(|r| reduction_state(&**r)).collect())
+    }
+
+    fn restore_state(&mut self, state: Vec<DataFrame>) -> PolarsResult<()> {
+        let ReduceState::Sink { reductions, .. } = &mut self.state else {
+            unreachable!()
+        };
+        for (r, state) in reductions.iter_mut().zip(&state) {
+            restore_reduction(&mut **r, state)?;
+        }
+        Ok(())
+    }
+
     fn update_state(
         &mut self,
         recv: &mut [PortState],
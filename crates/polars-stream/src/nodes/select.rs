@@ -31,6 +31,10 @@ impl ComputeNode for SelectNode {
         }
     }
 
+    fn checkpoint_mode(&self) -> CheckpointMode {
+        CheckpointMode::Stateless
+    }
+
     fn update_state(
         &mut self,
         recv: &mut [PortState],
@@ -24,6 +24,10 @@ impl ComputeNode for SimpleProjectionNode {
         "simple-projection"
     }
 
+    fn checkpoint_mode(&self) -> CheckpointMode {
+        CheckpointMode::Stateless
+    }
+
     fn update_state(
         &mut self,
         recv: &mut [PortState],
@@ -38,6 +38,8 @@ impl NodeStyle {
             | K::GroupBy { .. }
             | K::EquiJoin { .. }
             | K::SemiAntiJoin { .. }
+            | K::BlockJoin { .. }
+            | K::PartitionedWindow { .. }
             | K::Multiplexer { .. } => Self::MemoryIntensive,
             #[cfg(feature = "merge_sorted")]
             K::MergeSorted { .. } => Self::MemoryIntensive,
@@ -372,6 +374,19 @@ fn visualize_plan_rec(
 
             (s, from_ref(input))
         },
+        PhysNodeKind::PartitionedWindow {
+            input,
+            row_index: _,
+            partition_by,
+            exprs,
+        } => (
+            format!(
+                "partitioned-window\\npartition_by:\\n{}\\nexprs:\\n{}",
+                fmt_exprs_to_label(partition_by, expr_arena, FormatExprStyle::NoAliases),
+                fmt_exprs_to_label(exprs, expr_arena, FormatExprStyle::Select)
+            ),
+            from_ref(input),
+        ),
         PhysNodeKind::Sort {
             input,
             by_column,
@@ -640,6 +655,14 @@ fn visualize_plan_rec(
             args,
             ..
         }
+        | PhysNodeKind::BlockJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+            ..
+        }
         | PhysNodeKind::EquiJoin {
             input_left,
             input_right,
@@ -659,6 +682,7 @@ fn visualize_plan_rec(
                 PhysNodeKind::EquiJoin { .. } => "equi-join",
                 PhysNodeKind::InMemoryJoin { .. } => "in-memory-join",
                 PhysNodeKind::CrossJoin { .. } => "cross-join",
+                PhysNodeKind::BlockJoin { .. } => "block-join",
                 PhysNodeKind::SemiAntiJoin {
                     output_bool: false, ..
                 } if args.how.is_semi() => "semi-join",
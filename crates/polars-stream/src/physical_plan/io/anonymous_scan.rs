@@ -0,0 +1,70 @@
+use std::sync::{Arc, Mutex};
+
+use polars_core::config;
+use polars_core::schema::SchemaRef;
+use polars_plan::plans::{AnonymousBatchReader, AnonymousScan, AnonymousScanArgs};
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::execute::StreamingExecutionState;
+use crate::nodes::io_sources::batch::GetBatchFn;
+use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
+
+pub fn anonymous_scan_to_reader_builder(
+    name: PlSmallStr,
+    function: Arc<dyn AnonymousScan>,
+    schema: SchemaRef,
+) -> Arc<dyn FileReaderBuilder> {
+    let get_batch_fn = if function.allows_batches() {
+        // The reader of this execution, if the scan has one, is created with the first batch.
+        let reader: Mutex<Option<Option<AnonymousBatchReader>>> = Mutex::new(None);
+
+        Box::new(move |_state: &StreamingExecutionState| {
+            let mut reader = reader.lock().unwrap();
+            if reader.is_none() {
+                *reader = Some(function.batch_reader()?);
+            }
+            match reader.as_mut().unwrap() {
+                Some(reader) => reader(),
+                None => function.next_batch(),
+            }
+        }) as GetBatchFn
+    } else {
+        // The scan produces its full output with a single call. Mutex because the closure
+        // cannot be FnOnce.
+        let function = Mutex::new(Some(function));
+        let schema = schema.clone();
+
+        Box::new(move |_state: &StreamingExecutionState| {
+            let Some(function) = function.lock().unwrap().take() else {
+                return Ok(None);
+            };
+
+            let df = function.scan(AnonymousScanArgs {
+                n_rows: None,
+                with_columns: None,
+                schema: schema.clone(),
+                output_schema: None,
+                predicate: None,
+            })?;
+
+            Ok(Some(df))
+        }) as GetBatchFn
+    };
+
+    use crate::nodes::io_sources::batch::builder::BatchFnReaderBuilder;
+    use crate::nodes::io_sources::batch::{BatchFnReader, GetBatchState};
+
+    let reader = BatchFnReader {
+        name: name.clone(),
+        output_schema: Some(schema),
+        get_batch_state: Some(GetBatchState::from(get_batch_fn)),
+        execution_state: None,
+        verbose: config::verbose(),
+    };
+
+    Arc::new(BatchFnReaderBuilder {
+        name,
+        reader: std::sync::Mutex::new(Some(reader)),
+        execution_state: Default::default(),
+    }) as Arc<dyn FileReaderBuilder>
+}
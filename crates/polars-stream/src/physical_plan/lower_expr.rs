@@ -3,13 +3,13 @@ use std::sync::Arc;
 use polars_core::chunked_array::cast::CastOptions;
 use polars_core::frame::DataFrame;
 use polars_core::prelude::{
-    DataType, Field, IDX_DTYPE, InitHashMaps, PlHashMap, PlHashSet, PlIndexMap,
+    DataType, Field, IDX_DTYPE, InitHashMaps, PlHashMap, PlHashSet, PlIndexMap, PlIndexSet,
 };
 use polars_core::schema::{Schema, SchemaExt};
 use polars_error::PolarsResult;
 use polars_expr::state::ExecutionState;
 use polars_expr::{ExpressionConversionState, create_physical_expr};
-use polars_ops::frame::{JoinArgs, JoinType};
+use polars_ops::frame::{JoinArgs, JoinCoalesce, JoinType, MaintainOrderJoin};
 use polars_ops::series::{RLE_LENGTH_COLUMN_NAME, RLE_VALUE_COLUMN_NAME};
 use polars_plan::plans::AExpr;
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
@@ -603,6 +603,9 @@ fn lower_exprs_with_ctx(
     // together transformed nodes.
     let mut transformed_exprs = Vec::with_capacity(exprs.len());
 
+    // Output columns of `gather_every` expressions that are already gathered.
+    let mut gathered_every = PlHashMap::new();
+
     for expr in exprs.iter().copied() {
         if is_elementwise_rec_cached(expr, ctx.expr_arena, ctx.cache) {
             if !is_input_independent_ctx(expr, ctx) {
@@ -1110,6 +1113,7 @@ fn lower_exprs_with_ctx(
                         nulls_equal,
                         coalesce: Default::default(),
                         maintain_order: Default::default(),
+                        promotion_rules: Default::default(),
                     },
                     output_bool: true,
                 };
@@ -1305,19 +1309,35 @@ fn lower_exprs_with_ctx(
             } => {
                 assert_eq!(inner_exprs.len(), 1);
 
-                let value_key = unique_column_name();
+                if !gathered_every.contains_key(&expr) {
+                    // Gather all expressions with the same arguments in a single node, so that
+                    // decimating a frame gathers its rows once instead of once per column.
+                    let mut inner = Vec::new();
+                    for &other in exprs {
+                        if let AExpr::Function {
+                            input: other_inputs,
+                            function: IRFunctionExpr::GatherEvery { n: n2, offset: o2 },
+                            options: _,
+                        } = ctx.expr_arena.get(other)
+                            && (*n2, *o2) == (n, offset)
+                            && !gathered_every.contains_key(&other)
+                        {
+                            let value_key = unique_column_name();
+                            inner.push(other_inputs[0].with_alias(value_key.clone()));
+                            gathered_every.insert(other, value_key);
+                        }
+                    }
 
-                let input = build_select_stream_with_ctx(
-                    input,
-                    &[inner_exprs[0].with_alias(value_key.clone())],
-                    ctx,
-                )?;
-                let node_kind = PhysNodeKind::GatherEvery { input, n, offset };
+                    let input = build_select_stream_with_ctx(input, &inner, ctx)?;
+                    let node_kind = PhysNodeKind::GatherEvery { input, n, offset };
 
-                let output_schema = ctx.phys_sm[input.node].output_schema.clone();
-                let node_key = ctx.phys_sm.insert(PhysNode::new(output_schema, node_kind));
-                input_streams.insert(PhysStream::first(node_key));
-                transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(value_key.clone())));
+                    let output_schema = ctx.phys_sm[input.node].output_schema.clone();
+                    let node_key = ctx.phys_sm.insert(PhysNode::new(output_schema, node_kind));
+                    input_streams.insert(PhysStream::first(node_key));
+                }
+
+                let value_key = gathered_every[&expr].clone();
+                transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(value_key)));
             },
 
             AExpr::Function {
@@ -1897,7 +1917,9 @@ fn lower_exprs_with_ctx(
                 input: ref inner_exprs,
                 function: func @ (IRFunctionExpr::Shift | IRFunctionExpr::ShiftAndFill),
                 options: _,
-            } => {
+            } if func == IRFunctionExpr::Shift
+                || is_scalar_ae(inner_exprs[2].node(), ctx.expr_arena) =>
+            {
                 let out_name = unique_column_name();
                 let data_col_expr = inner_exprs[0].with_alias(out_name.clone());
                 let trans_data_column = build_select_stream_with_ctx(input, &[data_col_expr], ctx)?;
@@ -2022,6 +2044,164 @@ fn lower_exprs_with_ctx(
                 transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
             },
 
+            AExpr::Over {
+                function,
+                ref partition_by,
+                order_by: None,
+                mapping: WindowMapping::GroupsToRows,
+            } if is_scalar_ae(function, ctx.expr_arena)
+                && !partition_by.is_empty()
+                && partition_by
+                    .iter()
+                    .all(|k| is_length_preserving_ctx(*k, ctx)) =>
+            {
+                // Transform:
+                //    function.over(*partition_by).alias(name)
+                //      ->
+                //    .select(_k0 = partition_by[0], ...)
+                //    .join(
+                //        .group_by(_k0 = partition_by[0], ...).agg(_out = function),
+                //        on=[_k0, ...],
+                //        how="left",
+                //        nulls_equal=True,
+                //        maintain_order="left",
+                //    )
+                //    .select(_out)
+                //
+                // The function is evaluated once per group by the (partitioned) streaming group
+                // by, and the join maps the result of every group back to its rows.
+                //
+                // Only aggregations without an `order_by` are lowered this way, other windows
+                // are evaluated per partition below.
+
+                let out_name = unique_column_name();
+                let keys = partition_by
+                    .iter()
+                    .map(|k| AExprBuilder::new_from_node(*k).expr_ir(unique_column_name()))
+                    .collect_vec();
+                let agg = AExprBuilder::new_from_node(function).expr_ir(out_name.clone());
+
+                let input_schema = &ctx.phys_sm[input.node].output_schema;
+                let key_fields = keys
+                    .iter()
+                    .map(|k| k.field(input_schema, ctx.expr_arena))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let out_field = agg.field(input_schema, ctx.expr_arena)?;
+                let group_by_output_schema = Arc::new(Schema::from_iter(
+                    key_fields.iter().cloned().chain([out_field.clone()]),
+                ));
+
+                let group_by_stream = build_group_by_stream(
+                    input,
+                    &keys,
+                    std::slice::from_ref(&agg),
+                    group_by_output_schema,
+                    false,
+                    Default::default(),
+                    None,
+                    ctx.expr_arena,
+                    ctx.phys_sm,
+                    ctx.cache,
+                    StreamingLowerIRContext::from(&*ctx),
+                    false,
+                )?;
+                let keys_stream = build_select_stream_with_ctx(input, &keys, ctx)?;
+
+                let key_columns = keys
+                    .iter()
+                    .map(|k| {
+                        AExprBuilder::col(k.output_name().clone(), ctx.expr_arena)
+                            .expr_ir(k.output_name().clone())
+                    })
+                    .collect_vec();
+                let node_kind = PhysNodeKind::EquiJoin {
+                    input_left: keys_stream,
+                    input_right: group_by_stream,
+                    left_on: key_columns.clone(),
+                    right_on: key_columns,
+                    args: JoinArgs {
+                        how: JoinType::Left,
+                        validation: Default::default(),
+                        suffix: None,
+                        slice: None,
+                        nulls_equal: true,
+                        coalesce: JoinCoalesce::CoalesceColumns,
+                        maintain_order: MaintainOrderJoin::Left,
+                        promotion_rules: Default::default(),
+                    },
+                };
+                let output_schema = Schema::from_iter(key_fields.into_iter().chain([out_field]));
+                let node_key = ctx
+                    .phys_sm
+                    .insert(PhysNode::new(Arc::new(output_schema), node_kind));
+                input_streams.insert(PhysStream::first(node_key));
+                transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
+            },
+
+            AExpr::Over {
+                function: _,
+                ref partition_by,
+                ref order_by,
+                mapping,
+            } if !matches!(mapping, WindowMapping::Explode)
+                && !partition_by.is_empty()
+                && partition_by
+                    .iter()
+                    .chain(order_by.as_ref().map(|(e, _)| e))
+                    .all(|e| is_elementwise_rec_cached(*e, ctx.expr_arena, ctx.cache)) =>
+            {
+                // Windows that produce a value per row (e.g. `cum_sum().over(..)`) or that are
+                // ordered need all rows of a group together and in order. The rows are exchanged
+                // into hash partitions on the partition keys, which puts every group in a single
+                // partition, and every partition is evaluated on its own in the order of a row
+                // index. The keys and the `order_by` must be elementwise, so that they have the
+                // same values when they are evaluated per partition.
+
+                // Only the columns used by the window are buffered, but at least one is needed to
+                // give the row index the length of the input.
+                let input_schema = &ctx.phys_sm[input.node].output_schema;
+                let mut leaf_names: PlIndexSet<PlSmallStr> =
+                    polars_plan::utils::aexpr_to_leaf_names_iter(expr, ctx.expr_arena)
+                        .cloned()
+                        .collect();
+                if leaf_names.is_empty() {
+                    if let Some(name) = input_schema.iter_names().next() {
+                        leaf_names.insert(name.clone());
+                    }
+                }
+                let leaf_exprs = leaf_names
+                    .into_iter()
+                    .map(|name| {
+                        ExprIR::new(
+                            ctx.expr_arena.add(AExpr::Column(name.clone())),
+                            OutputName::ColumnLhs(name),
+                        )
+                    })
+                    .collect_vec();
+                let window_input = build_select_stream_with_ctx(input, &leaf_exprs, ctx)?;
+                let row_index = unique_column_name();
+                let window_input =
+                    build_row_idx_stream(window_input, row_index.clone(), None, ctx.phys_sm);
+
+                let out_name = unique_column_name();
+                let window_expr = ExprIR::new(expr, OutputName::Alias(out_name.clone()));
+                let keys = partition_by
+                    .iter()
+                    .map(|k| ExprIR::new(*k, OutputName::Alias(unique_column_name())))
+                    .collect_vec();
+                let output_schema =
+                    schema_for_select(window_input, std::slice::from_ref(&window_expr), ctx)?;
+                let node_kind = PhysNodeKind::PartitionedWindow {
+                    input: window_input,
+                    row_index,
+                    partition_by: keys,
+                    exprs: vec![window_expr],
+                };
+                let node_key = ctx.phys_sm.insert(PhysNode::new(output_schema, node_kind));
+                input_streams.insert(PhysStream::first(node_key));
+                transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
+            },
+
             AExpr::AnonymousFunction { .. }
             | AExpr::Function { .. }
             | AExpr::Over { .. }
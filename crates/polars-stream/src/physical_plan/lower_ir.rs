@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use arrow::buffer::Buffer;
 use parking_lot::Mutex;
 use polars_core::frame::{DataFrame, UniqueKeepStrategy};
 use polars_core::prelude::{DataType, PlHashMap, PlHashSet};
@@ -9,23 +10,25 @@ use polars_core::{SchemaExtPl, config};
 use polars_error::{PolarsResult, polars_bail};
 use polars_expr::state::ExecutionState;
 use polars_mem_engine::create_physical_plan;
+use polars_ops::frame::MaintainOrderJoin;
 use polars_plan::constants::get_literal_name;
 use polars_plan::dsl::default_values::DefaultFieldValues;
 use polars_plan::dsl::deletion::DeletionFilesList;
 use polars_plan::dsl::sink2::FileProviderType;
 use polars_plan::dsl::{
     CallbackSinkType, ExtraColumnsPolicy, FileScanIR, PartitionStrategyIR, PartitionVariantIR,
-    PartitionedSinkOptionsIR, SinkOptions, SinkTypeIR, UnifiedSinkArgs,
+    PartitionedSinkOptionsIR, ScanSources, SinkOptions, SinkTypeIR, UnifiedSinkArgs,
 };
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
 use polars_plan::plans::{
-    AExpr, FunctionIR, IR, IRAggExpr, LiteralValue, are_keys_sorted_any, is_sorted,
+    AExpr, FunctionIR, IR, IRAggExpr, LiteralValue, are_keys_sorted_any, estimate_plan, is_sorted,
     write_ir_non_recursive,
 };
 use polars_plan::prelude::GroupbyOptions;
 use polars_utils::arena::{Arena, Node};
 use polars_utils::itertools::Itertools;
 use polars_utils::pl_str::PlSmallStr;
+use polars_utils::plpath::PlPath;
 #[cfg(feature = "parquet")]
 use polars_utils::relaxed_cell::RelaxedCell;
 use polars_utils::row_counter::RowCounter;
@@ -41,6 +44,7 @@ use crate::nodes::io_sources::multi_scan::components::forbid_extra_columns::Forb
 use crate::nodes::io_sources::multi_scan::components::projection::builder::ProjectionBuilder;
 use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
 use crate::physical_plan::ZipBehavior;
+use crate::physical_plan::io::anonymous_scan::anonymous_scan_to_reader_builder;
 use crate::physical_plan::lower_expr::{ExprCache, build_select_stream, lower_exprs};
 use crate::physical_plan::lower_group_by::build_group_by_stream;
 use crate::utils::late_materialized_df::LateMaterializedDataFrame;
@@ -681,7 +685,7 @@ pub fn lower_ir(
 
         v @ IR::Scan { .. } => {
             let IR::Scan {
-                sources: scan_sources,
+                sources: mut scan_sources,
                 file_info,
                 mut hive_parts,
                 output_schema: _,
@@ -783,7 +787,18 @@ pub fn lower_ir(
                     #[cfg(feature = "scan_lines")]
                     FileScanIR::Lines { name: _ } => todo!(),
 
-                    FileScanIR::Anonymous { .. } => todo!("unimplemented: AnonymousScan"),
+                    FileScanIR::Anonymous { options, function } => {
+                        // Give multiscan a single scan source (it doesn't actually read from it).
+                        scan_sources = ScanSources::Paths(Buffer::from_iter([PlPath::from_str(
+                            "anonymous-scan-0",
+                        )]));
+
+                        anonymous_scan_to_reader_builder(
+                            PlSmallStr::from_static(options.fmt_str),
+                            function.clone(),
+                            file_info.schema.clone(),
+                        )
+                    },
                 };
 
                 {
@@ -1198,6 +1213,38 @@ pub fn lower_ir(
                     stream = build_slice_stream(stream, offset, len, phys_sm);
                 }
                 return Ok(stream);
+            } else if args.how.is_ie() {
+                // Inequality joins only buffer one side, the other side is joined in blocks. The
+                // side that is estimated to be smaller is buffered, unless the order of the other
+                // side must be maintained.
+                let left_is_build = match args.maintain_order {
+                    MaintainOrderJoin::None => {
+                        let left_rows = estimate_plan(input_left, ir_arena).rows;
+                        let right_rows = estimate_plan(input_right, ir_arena).rows;
+                        left_rows.unwrap_or(usize::MAX) <= right_rows.unwrap_or(usize::MAX)
+                    },
+                    MaintainOrderJoin::Left | MaintainOrderJoin::LeftRight => false,
+                    MaintainOrderJoin::Right | MaintainOrderJoin::RightLeft => true,
+                };
+                let mut block_args = args.clone();
+                block_args.slice = None;
+                let node = phys_sm.insert(PhysNode::new(
+                    output_schema,
+                    PhysNodeKind::BlockJoin {
+                        input_left: phys_left,
+                        input_right: phys_right,
+                        left_on,
+                        right_on,
+                        args: block_args,
+                        options,
+                        left_is_build,
+                    },
+                ));
+                let mut stream = PhysStream::first(node);
+                if let Some((offset, len)) = args.slice {
+                    stream = build_slice_stream(stream, offset, len, phys_sm);
+                }
+                return Ok(stream);
             } else {
                 PhysNodeKind::InMemoryJoin {
                     input_left: phys_left,
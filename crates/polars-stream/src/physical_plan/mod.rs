@@ -362,6 +362,16 @@ pub enum PhysNodeKind {
         aggs: Vec<ExprIR>,
     },
 
+    /// Evaluates window expressions by exchanging the rows into hash partitions on the
+    /// `partition_by` keys, evaluating every partition in the order of `row_index` and sending
+    /// the results in that order. Only the window columns are output.
+    PartitionedWindow {
+        input: PhysStream,
+        row_index: PlSmallStr,
+        partition_by: Vec<ExprIR>,
+        exprs: Vec<ExprIR>,
+    },
+
     EquiJoin {
         input_left: PhysStream,
         input_right: PhysStream,
@@ -385,6 +395,20 @@ pub enum PhysNodeKind {
         args: JoinArgs,
     },
 
+    /// Buffers one input and joins every morsel of the other input against it, for joins that
+    /// can be computed block by block such as inequality joins. The buffered input is spilled to
+    /// disk in chunks once it exceeds a memory budget.
+    BlockJoin {
+        input_left: PhysStream,
+        input_right: PhysStream,
+        left_on: Vec<ExprIR>,
+        right_on: Vec<ExprIR>,
+        args: JoinArgs,
+        options: Option<JoinTypeOptionsIR>,
+        /// Whether the left input is buffered, otherwise the right input is.
+        left_is_build: bool,
+    },
+
     /// Generic fallback for (as-of-yet) unsupported streaming joins.
     /// Fully sinks all data to in-memory data frames and uses the in-memory
     /// engine to perform the join.
@@ -459,6 +483,7 @@ fn visit_node_inputs_mut(
             | PhysNodeKind::PartitionedSink2 { input, .. }
             | PhysNodeKind::InMemoryMap { input, .. }
             | PhysNodeKind::SortedGroupBy { input, .. }
+            | PhysNodeKind::PartitionedWindow { input, .. }
             | PhysNodeKind::Map { input, .. }
             | PhysNodeKind::Sort { input, .. }
             | PhysNodeKind::Multiplexer { input }
@@ -506,6 +531,11 @@ fn visit_node_inputs_mut(
                 input_left,
                 input_right,
                 ..
+            }
+            | PhysNodeKind::BlockJoin {
+                input_left,
+                input_right,
+                ..
             } => {
                 rec!(input_left.node);
                 rec!(input_right.node);
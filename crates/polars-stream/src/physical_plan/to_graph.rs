@@ -9,7 +9,7 @@ use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
 use polars_expr::groups::new_hash_grouper;
 use polars_expr::planner::{ExpressionConversionState, create_physical_expr};
 use polars_expr::reduce::into_reduction;
-use polars_expr::state::ExecutionState;
+use polars_expr::state::{ExecutionState, NullFilterAudit};
 use polars_mem_engine::create_physical_plan;
 use polars_mem_engine::scan_predicate::create_scan_predicate;
 use polars_plan::dsl::{
@@ -198,8 +198,10 @@ fn to_graph_rec<'a>(
             let input_schema = &ctx.phys_sm[input.node].output_schema;
             let phys_predicate_expr = create_stream_expr(predicate, ctx, input_schema)?;
             let input_key = to_graph_rec(input.node, ctx)?;
+            let audit = config::audit_null_filters()
+                .then(|| NullFilterAudit::new(predicate.display(ctx.expr_arena).to_string()));
             ctx.graph.add_node(
-                nodes::filter::FilterNode::new(phys_predicate_expr),
+                nodes::filter::FilterNode::new(phys_predicate_expr, audit),
                 [(input_key, input.port)],
             )
         },
@@ -681,6 +683,33 @@ fn to_graph_rec<'a>(
             )
         },
 
+        PartitionedWindow {
+            input,
+            row_index,
+            partition_by,
+            exprs,
+        } => {
+            let input_schema = ctx.phys_sm[input.node].output_schema.clone();
+            let input_key = to_graph_rec(input.node, ctx)?;
+            let key_selectors = partition_by
+                .iter()
+                .map(|e| create_stream_expr(e, ctx, &input_schema))
+                .try_collect_vec()?;
+            let exprs = exprs
+                .iter()
+                .map(|e| create_stream_expr(e, ctx, &input_schema))
+                .try_collect_vec()?;
+            ctx.graph.add_node(
+                nodes::partitioned_window::PartitionedWindowNode::new(
+                    row_index.clone(),
+                    key_selectors,
+                    exprs,
+                    node.output_schema.clone(),
+                ),
+                [(input_key, input.port)],
+            )
+        },
+
         Sort {
             input,
             by_column,
@@ -926,6 +955,7 @@ fn to_graph_rec<'a>(
                     num_pipelines: RelaxedCell::new_usize(0),
                     n_readers_pre_init: RelaxedCell::new_usize(0),
                     max_concurrent_scans: RelaxedCell::new_usize(0),
+                    rows_sent: Arc::new(RelaxedCell::new_u64(0)),
                     verbose,
                 })),
                 [],
@@ -1079,6 +1109,7 @@ fn to_graph_rec<'a>(
             right_on,
             args,
             options,
+            left_is_build,
         } => {
             let left_input_key = to_graph_rec(input_left.node, ctx)?;
             let right_input_key = to_graph_rec(input_right.node, ctx)?;
@@ -1254,6 +1285,47 @@ fn to_graph_rec<'a>(
             )
         },
 
+        BlockJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+            options,
+        } => {
+            let left_input_key = to_graph_rec(input_left.node, ctx)?;
+            let right_input_key = to_graph_rec(input_right.node, ctx)?;
+            let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
+            let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
+
+            let left_key_selectors = left_on
+                .iter()
+                .map(|e| create_stream_expr(e, ctx, &left_input_schema))
+                .try_collect_vec()?;
+            let right_key_selectors = right_on
+                .iter()
+                .map(|e| create_stream_expr(e, ctx, &right_input_schema))
+                .try_collect_vec()?;
+            let options = options
+                .clone()
+                .map(|o| o.compile(|_| unreachable!("fused cross joins are not lowered")))
+                .transpose()?;
+
+            ctx.graph.add_node(
+                nodes::joins::block_join::BlockJoinNode::new(
+                    left_key_selectors,
+                    right_key_selectors,
+                    args.clone(),
+                    options,
+                    *left_is_build,
+                ),
+                [
+                    (left_input_key, input_left.port),
+                    (right_input_key, input_right.port),
+                ],
+            )
+        },
+
         #[cfg(feature = "merge_sorted")]
         MergeSorted {
             input_left,
@@ -1480,6 +1552,7 @@ fn to_graph_rec<'a>(
                     num_pipelines: RelaxedCell::new_usize(0),
                     n_readers_pre_init: RelaxedCell::new_usize(0),
                     max_concurrent_scans: RelaxedCell::new_usize(0),
+                    rows_sent: Arc::new(RelaxedCell::new_u64(0)),
                     verbose,
                 })),
                 [],
@@ -268,6 +268,26 @@ impl PhysicalPlanVisualizationDataGenerator<'_> {
                     ..Default::default()
                 }
             },
+            PhysNodeKind::PartitionedWindow {
+                input,
+                row_index,
+                partition_by,
+                exprs,
+            } => {
+                phys_node_inputs.push(input.node);
+
+                let properties = PhysNodeProperties::PartitionedWindow {
+                    row_index: row_index.clone(),
+                    partition_by: expr_list(partition_by, self.expr_arena),
+                    exprs: expr_list(exprs, self.expr_arena),
+                };
+
+                PhysNodeInfo {
+                    title: properties.variant_name(),
+                    properties,
+                    ..Default::default()
+                }
+            },
             PhysNodeKind::InMemoryMap {
                 input,
                 map: _, // dyn DataFrameUdf
@@ -341,6 +361,7 @@ impl PhysicalPlanVisualizationDataGenerator<'_> {
                     nulls_equal: _,
                     coalesce: _,
                     maintain_order,
+                    promotion_rules: _,
                 } = args;
 
                 let properties = PhysNodeProperties::CrossJoin {
@@ -354,6 +375,33 @@ impl PhysicalPlanVisualizationDataGenerator<'_> {
                     ..Default::default()
                 }
             },
+            PhysNodeKind::BlockJoin {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                args,
+                options: _,
+                left_is_build,
+            } => {
+                phys_node_inputs.push(input_left.node);
+                phys_node_inputs.push(input_right.node);
+
+                let properties = PhysNodeProperties::BlockJoin {
+                    how: format_pl_smallstr!("{}", args.how),
+                    left_on: expr_list(left_on, self.expr_arena),
+                    right_on: expr_list(right_on, self.expr_arena),
+                    maintain_order: args.maintain_order,
+                    suffix: args.suffix.clone(),
+                    left_is_build: *left_is_build,
+                };
+
+                PhysNodeInfo {
+                    title: properties.variant_name(),
+                    properties,
+                    ..Default::default()
+                }
+            },
             PhysNodeKind::EquiJoin {
                 input_left,
                 input_right,
@@ -373,6 +421,8 @@ impl PhysicalPlanVisualizationDataGenerator<'_> {
                     nulls_equal,
                     coalesce,
                     maintain_order,
+                    // Resolved into casts of the join keys
+                    promotion_rules: _,
                 } = args;
 
                 let properties = PhysNodeProperties::EquiJoin {
@@ -406,6 +456,7 @@ impl PhysicalPlanVisualizationDataGenerator<'_> {
                         nulls_equal,
                         coalesce,
                         maintain_order,
+                        promotion_rules: _,
                     },
                 options,
             } => {
@@ -421,6 +472,8 @@ impl PhysicalPlanVisualizationDataGenerator<'_> {
                             strategy,
                             tolerance,
                             tolerance_str,
+                            tolerance_column,
+                            end_column,
                             left_by,
                             right_by,
                             allow_eq,
@@ -126,6 +126,11 @@ pub enum PhysNodeProperties {
         aggs: Vec<PlSmallStr>,
         slice: Option<(IdxSize, IdxSize)>,
     },
+    PartitionedWindow {
+        row_index: PlSmallStr,
+        partition_by: Vec<PlSmallStr>,
+        exprs: Vec<PlSmallStr>,
+    },
     InMemoryMap {
         format_str: PlSmallStr,
     },
@@ -142,6 +147,14 @@ pub enum PhysNodeProperties {
         maintain_order: MaintainOrderJoin,
         suffix: Option<PlSmallStr>,
     },
+    BlockJoin {
+        how: PlSmallStr,
+        left_on: Vec<PlSmallStr>,
+        right_on: Vec<PlSmallStr>,
+        maintain_order: MaintainOrderJoin,
+        suffix: Option<PlSmallStr>,
+        left_is_build: bool,
+    },
     EquiJoin {
         how: PlSmallStr,
         left_on: Vec<PlSmallStr>,
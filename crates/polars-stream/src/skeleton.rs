@@ -13,6 +13,8 @@ use polars_utils::arena::{Arena, Node};
 use polars_utils::relaxed_cell::RelaxedCell;
 use slotmap::{SecondaryMap, SlotMap};
 
+#[cfg(feature = "checkpoint")]
+use crate::checkpoint::{CheckpointOptions, Checkpointer};
 use crate::graph::{Graph, GraphNodeKey};
 use crate::metrics::GraphMetrics;
 use crate::physical_plan::{PhysNode, PhysNodeKey, PhysNodeKind, StreamingLowerIRContext};
@@ -36,6 +38,26 @@ pub fn run_query(
     StreamingQuery::build(node, ir_arena, expr_arena)?.execute()
 }
 
+/// Executes the IR with the streaming engine, periodically writing a checkpoint to
+/// `options.dir`. If `resume` is set, execution continues from the checkpoint in that
+/// directory, which must have been written by the same query.
+///
+/// The checkpoint is removed once the query completes.
+#[cfg(feature = "checkpoint")]
+pub fn run_query_with_checkpoint(
+    node: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    options: CheckpointOptions,
+    resume: bool,
+) -> PolarsResult<QueryResult> {
+    let mut query = StreamingQuery::build(node, ir_arena, expr_arena)?;
+    let mut checkpointer = Checkpointer::try_new(options, &mut query.graph, resume)?;
+    let result = query.execute_impl(&mut |graph| checkpointer.before_phase(graph))?;
+    checkpointer.finish()?;
+    Ok(result)
+}
+
 /// Visualizes the physical plan as a dot graph.
 pub fn visualize_physical_plan(
     node: Node,
@@ -141,6 +163,13 @@ impl StreamingQuery {
     }
 
     pub fn execute(self) -> PolarsResult<QueryResult> {
+        self.execute_impl(&mut |_| Ok(()))
+    }
+
+    fn execute_impl(
+        self,
+        before_phase: &mut dyn FnMut(&mut Graph) -> PolarsResult<()>,
+    ) -> PolarsResult<QueryResult> {
         let StreamingQuery {
             top_ir,
             mut graph,
@@ -151,7 +180,8 @@ impl StreamingQuery {
         } = self;
 
         let query_start = Instant::now();
-        let mut results = crate::execute::execute_graph(&mut graph, metrics.clone())?;
+        let mut results =
+            crate::execute::execute_graph(&mut graph, metrics.clone(), before_phase)?;
         let query_elapsed = query_start.elapsed();
 
         // Print metrics.
@@ -0,0 +1,153 @@
+//! Spilling of buffered frames to disk for nodes that have to see their entire input.
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use polars_core::config;
+use polars_core::frame::DataFrame;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_error::PolarsResult;
+use polars_io::ipc::{IpcReader, IpcWriter};
+use polars_io::path_utils::POLARS_TEMP_DIR_BASE_PATH;
+use polars_io::{SerReader, SerWriter};
+use polars_utils::sys::MEMINFO;
+
+/// The number of bytes a node may buffer in memory, read from the environment variable `var`.
+/// Defaults to a quarter of the available memory.
+pub fn memory_budget(var: &str) -> usize {
+    std::env::var(var).map_or_else(
+        |_| (MEMINFO.free() / 4) as usize,
+        |x| x.parse().unwrap_or_else(|_| panic!("invalid value for {var}: {x}")),
+    )
+}
+
+/// A directory in the polars temp dir with spilled frames, removed when dropped.
+pub struct SpillDir {
+    path: PathBuf,
+    num_files: usize,
+}
+
+impl SpillDir {
+    /// Creates a directory for the spilled frames of a node, `name` identifies the node in its
+    /// path.
+    pub fn try_new(name: &str) -> PolarsResult<Self> {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        let path = POLARS_TEMP_DIR_BASE_PATH.join(format!(
+            "{name}-{}-{}",
+            std::process::id(),
+            NEXT_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::create_dir_all(&path)?;
+        if config::verbose() {
+            eprintln!("{name} spills to '{}'", path.display());
+        }
+        Ok(Self { path, num_files: 0 })
+    }
+
+    /// Writes a frame to a new IPC file in the directory, returning its path.
+    pub fn spill(&mut self, mut df: DataFrame) -> PolarsResult<PathBuf> {
+        let path = self.path.join(format!("{}.ipc", self.num_files));
+        self.num_files += 1;
+        let mut writer = BufWriter::new(File::create(&path)?);
+        IpcWriter::new(&mut writer).finish(&mut df)?;
+        writer.flush()?;
+        Ok(path)
+    }
+
+    /// The number of frames that were spilled.
+    pub fn num_files(&self) -> usize {
+        self.num_files
+    }
+}
+
+impl Drop for SpillDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Reads a frame written by [`SpillDir::spill`].
+pub fn load_spilled(path: &Path) -> PolarsResult<DataFrame> {
+    IpcReader::new(File::open(path)?).finish()
+}
+
+#[derive(Default)]
+struct Bucket {
+    spilled: Vec<PathBuf>,
+    frames: Vec<DataFrame>,
+    size: usize,
+}
+
+/// Frames buffered in numbered buckets. Once the buffered frames exceed the memory budget, the
+/// largest buckets are spilled to disk until they fit again.
+pub struct SpillBuckets {
+    name: &'static str,
+    budget: usize,
+    in_memory_size: usize,
+    buckets: Vec<Bucket>,
+    spill_dir: Option<SpillDir>,
+}
+
+impl SpillBuckets {
+    pub fn new(name: &'static str, budget: usize) -> Self {
+        Self {
+            name,
+            budget,
+            in_memory_size: 0,
+            buckets: Vec::new(),
+            spill_dir: None,
+        }
+    }
+
+    pub fn num_buckets(&self) -> usize {
+        self.buckets.len()
+    }
+
+    /// The number of frames that were spilled to disk.
+    pub fn num_spilled(&self) -> usize {
+        self.spill_dir.as_ref().map_or(0, |dir| dir.num_files())
+    }
+
+    pub fn push(&mut self, bucket: usize, df: DataFrame) -> PolarsResult<()> {
+        if bucket >= self.buckets.len() {
+            self.buckets.resize_with(bucket + 1, Bucket::default);
+        }
+        let size = df.estimated_size();
+        self.buckets[bucket].frames.push(df);
+        self.buckets[bucket].size += size;
+        self.in_memory_size += size;
+
+        while self.in_memory_size > self.budget {
+            let largest = self.buckets.iter_mut().max_by_key(|b| b.size).unwrap();
+            if largest.size == 0 {
+                break;
+            }
+            let spill_dir = match &mut self.spill_dir {
+                Some(spill_dir) => spill_dir,
+                None => self.spill_dir.insert(SpillDir::try_new(self.name)?),
+            };
+            let df = accumulate_dataframes_vertical_unchecked(std::mem::take(&mut largest.frames));
+            largest.spilled.push(spill_dir.spill(df)?);
+            self.in_memory_size -= std::mem::take(&mut largest.size);
+        }
+        Ok(())
+    }
+
+    /// Takes the frames of a bucket in the order they were pushed, loading the spilled ones back.
+    pub fn take(&mut self, bucket: usize) -> PolarsResult<Vec<DataFrame>> {
+        let Some(bucket) = self.buckets.get_mut(bucket) else {
+            return Ok(Vec::new());
+        };
+        let bucket = std::mem::take(bucket);
+        self.in_memory_size -= bucket.size;
+
+        let mut frames = Vec::with_capacity(bucket.spilled.len() + bucket.frames.len());
+        for path in &bucket.spilled {
+            frames.push(load_spilled(path)?);
+            let _ = std::fs::remove_file(path);
+        }
+        frames.extend(bucket.frames);
+        Ok(frames)
+    }
+}
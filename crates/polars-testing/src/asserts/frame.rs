@@ -628,4 +628,43 @@ mod tests {
 
         assert_dataframe_equal!(&df1, &df2);
     }
+
+    // Testing comparison by key
+    #[test]
+    fn test_dataframe_equal_by_key() {
+        let df1 = df! {
+            "id" => [1, 2, 3],
+            "value" => [Some("a"), None, Some("c")],
+        }
+        .unwrap();
+        let df2 = df! {
+            "value" => [Some("c"), Some("a"), None],
+            "id" => [3, 1, 2],
+        }
+        .unwrap();
+
+        crate::asserts::assert_dataframe_equal_by_key(&df1, &df2, &["id".into()], 10).unwrap();
+    }
+
+    #[test]
+    fn test_dataframe_equal_by_key_mismatch() {
+        let df1 = df! {
+            "id" => [1, 2, 3],
+            "value" => [1.0, 2.0, 3.0],
+        }
+        .unwrap();
+        let df2 = df! {
+            "id" => [4, 3, 2],
+            "value" => [4.0, 3.0, 2.5],
+        }
+        .unwrap();
+
+        let err = crate::asserts::assert_dataframe_equal_by_key(&df1, &df2, &["id".into()], 2)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("3 rows differ by key [\"id\"], showing the first 2"));
+        assert!(err.contains("removed"));
+        assert!(err.contains("modified"));
+        assert!(!err.contains("added"));
+    }
 }
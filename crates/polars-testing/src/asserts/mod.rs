@@ -3,5 +3,6 @@ pub mod series;
 mod utils;
 
 pub use utils::{
-    DataFrameEqualOptions, SeriesEqualOptions, assert_dataframe_equal, assert_series_equal,
+    DataFrameEqualOptions, SeriesEqualOptions, assert_dataframe_equal,
+    assert_dataframe_equal_by_key, assert_series_equal,
 };
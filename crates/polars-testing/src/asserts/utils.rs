@@ -2,6 +2,7 @@ use std::ops::Not;
 
 use polars_core::datatypes::unpack_dtypes;
 use polars_core::prelude::*;
+use polars_ops::frame::DataFrameOps;
 use polars_ops::series::is_close;
 
 /// Configuration options for comparing Series equality.
@@ -897,3 +898,47 @@ pub fn assert_dataframe_equal(
 
     Ok(())
 }
+
+/// Verifies that two DataFrames contain the same rows, matching the rows by their values in
+/// `key_columns`.
+///
+/// Unlike [`assert_dataframe_equal`], the order of the rows does not matter and the error
+/// reports which rows differ, instead of only the first column that does.
+///
+/// # Arguments
+///
+/// * `left` - The first DataFrame to compare
+/// * `right` - The second DataFrame to compare
+/// * `key_columns` - The columns identifying a row, which must be unique in both DataFrames
+/// * `max_differences` - The maximum number of differing rows to report
+///
+/// # Returns
+///
+/// * `Ok(())` if the rows of both DataFrames have the same values for every key
+/// * `Err` listing the first `max_differences` removed, added or modified rows otherwise
+///
+/// # Behavior
+///
+/// The schemas are compared first, without regard to the column order. The values are then
+/// compared exactly, with nulls being equal to each other.
+pub fn assert_dataframe_equal_by_key(
+    left: &DataFrame,
+    right: &DataFrame,
+    key_columns: &[PlSmallStr],
+    max_differences: usize,
+) -> PolarsResult<()> {
+    assert_dataframe_schema_equal(left, right, true, false)?;
+
+    let diff = left.diff_frames(right, key_columns)?;
+    if diff.height() == 0 {
+        return Ok(());
+    }
+    Err(polars_err!(
+        AssertionError: "DataFrames are different ({} rows differ by key {:?}, \
+        showing the first {})\n{}",
+        diff.height(),
+        key_columns,
+        max_differences.min(diff.height()),
+        diff.head(Some(max_differences))
+    ))
+}
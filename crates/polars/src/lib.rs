@@ -177,6 +177,8 @@
 //! * `lazy` - Lazy API
 //!     - `regex` - Use regexes in [column selection]
 //!     - `dot_diagram` - Create dot diagrams from lazy logical plans.
+//!     - `checkpoint` - Checkpoint long-running streaming queries and resume them after a crash.
+//!     - `apply_changes` - Apply change data capture (CDC) records to a `LazyFrame` snapshot.
 //! * `sql` - Pass SQL queries to Polars.
 //! * `random` - Generate arrays with randomly sampled values
 //! * `ndarray`- Convert from [`DataFrame`] to [ndarray](https://docs.rs/ndarray/)
@@ -184,12 +186,15 @@
 //! * `timezones` - Activate timezone support.
 //! * `strings` - Extra string utilities for [`StringChunked`]
 //!     - `string_pad` - `zfill`, `ljust`, `rjust`
+//!     - `string_mask` - Mask regex matches and built-in PII patterns (emails, phone numbers, cards)
 //!     - `string_to_integer` - `parse_int`
 //! * `object` - Support for generic ChunkedArrays called [`ObjectChunked<T>`] (generic over `T`).
 //!   These are downcastable from Series through the [Any](https://doc.rust-lang.org/std/any/index.html) trait.
 //! * Performance related:
 //!     - `nightly` - Several nightly only features such as SIMD and specialization.
 //!     - `performant` - more fast paths, slower compile times.
+//!     - `jit` - Compile fused chains of elementwise float operations to native code with
+//!       Cranelift, instead of interpreting them.
 //!     - `bigidx` - Activate this feature if you expect >> 2^32 rows. This is rarely needed.
 //!       This allows Polars to scale up beyond 2^32 rows by using an index with a `u64` data type.
 //!       Polars will be a bit slower with this feature activated as many data structures
@@ -203,6 +208,8 @@
 //!     - `parquet` - Read Apache Parquet format
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
+//!     - `http_json` - Read paginated JSON APIs over HTTP(S)
+//!     - `kafka` - Read Kafka topics with JSON or schema registry Avro messages
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
 //!       Supported compressions:
 //!          - gzip
@@ -220,6 +227,8 @@
 //!     - `sort_multiple` - Allow sorting a [`DataFrame`] on multiple columns
 //!     - `rows` - Create [`DataFrame`] from rows and extract rows from [`DataFrame`]s.
 //!       Also activates `pivot` and `transpose` operations
+//!     - `derive` - `#[derive(IntoDataFrame, FromDataFrame)]` to convert between `Vec<T>` of
+//!       structs and [`DataFrame`]s.
 //!     - `asof_join` - Join ASOF, to join on nearest keys instead of exact equality match.
 //!     - `cross_join` - Create the Cartesian product of two [`DataFrame`]s.
 //!     - `semi_anti_join` - SEMI and ANTI joins.
@@ -227,6 +236,14 @@
 //!     - `diagonal_concat` - Concat diagonally thereby combining different schemas.
 //!     - `dataframe_arithmetic` - Arithmetic on ([`Dataframe`] and [`DataFrame`]s) and ([`DataFrame`] on [`Series`])
 //!     - `partition_by` - Split into multiple [`DataFrame`]s partitioned by groups.
+//!     - `ann` - Approximate nearest neighbor search over embedding columns of type `Array`.
+//!     - `diff_frames` - Row-level comparison of two [`DataFrame`]s by key columns.
+//!     - `drift_report` - Per-column distribution changes between two [`DataFrame`]s.
+//!     - `funnel` - Match ordered sequences of events per group, like `MATCH_RECOGNIZE`.
+//!     - `connected_components` - Connected component labels of the nodes of an edge [`DataFrame`].
+//!     - `neighbor_agg` - Aggregate the values of the neighbors of the nodes of an adjacency list.
+//!     - `describe` - Summary statistics of the columns of a [`DataFrame`] or `LazyFrame`.
+//!     - `dataframe_index` - Hash and sorted indices on key columns of a [`DataFrame`] for lookups.
 //! * [`Series`]/[`Expr`] operations:
 //!     - `is_in` - Check for membership in [`Series`].
 //!     - `zip_with` - [Zip two Series/ ChunkedArrays](crate::chunked_array::ops::ChunkZip).
@@ -249,18 +266,26 @@
 //!         - `list_gather` take sublist by multiple indices
 //!     - `rank` - Ranking algorithms.
 //!     - `moment` - Kurtosis and skew statistics
+//!     - `outliers` - Flag outliers by median absolute deviation or interquartile range
 //!     - `ewma` - Exponential moving average windows
 //!     - `abs` - Get absolute values of [`Series`].
 //!     - `arange` - Range operation on [`Series`].
 //!     - `product` - Compute the product of a [`Series`].
 //!     - `diff` - [`diff`] operation.
 //!     - `pct_change` - Compute change percentages.
+//!     - `pct_change_by` - Compute change percentages versus the value a duration earlier.
+//!     - `quant` - Drawdowns, hit ratio and Sharpe ratio of prices and returns.
+//!     - `rule_failures` - Evaluate many validation rules into a per-row bitmask of failures.
+//!     - `weighted` - Weighted mean, variance and quantile aggregations.
+//!     - `crypto` - Keyed tokenization, deterministic encryption and keyed masking.
+//!     - `geo` - Geometry dtype, spatial expressions and GeoParquet metadata.
 //!     - `unique_counts` - Count unique values in expressions.
 //!     - `log` - Logarithms for [`Series`].
 //!     - `list_to_struct` - Convert [`List`] to [`Struct`] dtypes.
 //!     - `list_count` - Count elements in lists.
 //!     - `list_eval` - Apply expressions over list elements.
 //!     - `list_sets` - Compute UNION, INTERSECTION, and DIFFERENCE on list types.
+//!     - `array_linalg` - Dot products, norms, matrix-vector products and cosine similarity on `Array` types.
 //!     - `cumulative_eval` - Apply expressions over cumulatively increasing windows.
 //!     - `arg_where` - Get indices where condition holds.
 //!     - `search_sorted` - Find indices where elements should be inserted to maintain order.
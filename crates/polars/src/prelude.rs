@@ -1,5 +1,11 @@
 pub use polars_core::prelude::*;
-pub use polars_core::utils::NoNull;
+pub use polars_core::utils::{
+    DecimalPromotion, IntFloatPromotion, NoNull, NullPromotion, PromotionRules,
+};
+#[cfg(feature = "derive")]
+pub use polars_core::frame::row::typed::{FromDataFrame, IntoDataFrame};
+#[cfg(feature = "derive")]
+pub use polars_derive::{FromDataFrame, IntoDataFrame};
 #[cfg(feature = "polars-io")]
 pub use polars_io::prelude::*;
 #[cfg(feature = "lazy")]
@@ -17,7 +17,10 @@ pub(crate) fn write(
     compression: Option<Compression>,
 ) -> PolarsResult<Vec<u8>> {
     let result = vec![];
-    let options = WriteOptions { compression };
+    let options = WriteOptions {
+        compression,
+        zstd_dictionary: None,
+    };
     let mut writer = FileWriter::try_new(result, schema.clone(), ipc_fields.clone(), options)?;
     for batch in batches {
         writer.write(batch, ipc_fields.as_ref().map(|x| x.as_ref()))?;
@@ -0,0 +1,82 @@
+use chrono::{NaiveDate, NaiveDateTime};
+
+use super::*;
+
+#[derive(Debug, PartialEq, IntoDataFrame, FromDataFrame)]
+struct Address {
+    city: String,
+    zip: Option<u32>,
+}
+
+#[derive(Debug, PartialEq, IntoDataFrame, FromDataFrame)]
+struct Person {
+    name: String,
+    age: Option<i32>,
+    scores: Vec<f64>,
+    address: Address,
+    born: NaiveDate,
+    seen: Option<NaiveDateTime>,
+}
+
+fn people() -> Vec<Person> {
+    let born = NaiveDate::from_ymd_opt(1990, 5, 17).unwrap();
+    vec![
+        Person {
+            name: "a".into(),
+            age: Some(34),
+            scores: vec![1.0, 2.5],
+            address: Address {
+                city: "x".into(),
+                zip: Some(1000),
+            },
+            born,
+            seen: Some(born.and_hms_opt(12, 30, 0).unwrap()),
+        },
+        Person {
+            name: "b".into(),
+            age: None,
+            scores: vec![],
+            address: Address {
+                city: "y".into(),
+                zip: None,
+            },
+            born,
+            seen: None,
+        },
+    ]
+}
+
+#[test]
+fn test_derive_roundtrip() -> PolarsResult<()> {
+    let df = Person::into_df(people())?;
+    assert_eq!(df.height(), 2);
+    assert_eq!(df.column("age")?.dtype(), &DataType::Int32);
+    assert_eq!(df.column("age")?.null_count(), 1);
+    assert_eq!(
+        df.column("scores")?.dtype(),
+        &DataType::List(Box::new(DataType::Float64))
+    );
+    assert!(matches!(df.column("address")?.dtype(), DataType::Struct(_)));
+
+    assert_eq!(Person::from_df(&df)?, people());
+    Ok(())
+}
+
+#[test]
+fn test_derive_from_df_errors() -> PolarsResult<()> {
+    // Columns are cast to the type of the field.
+    let df = df!["city" => ["x"], "zip" => [1i64]]?;
+    assert_eq!(
+        Address::from_df(&df)?,
+        [Address {
+            city: "x".into(),
+            zip: Some(1)
+        }]
+    );
+
+    // Missing columns and nulls in non-optional fields are errors.
+    assert!(Address::from_df(&df!["city" => ["x"]]?).is_err());
+    let df = df!["city" => [None::<&str>], "zip" => [1u32]]?;
+    assert!(Address::from_df(&df).is_err());
+    Ok(())
+}
@@ -1,4 +1,6 @@
 mod date_like;
+#[cfg(all(feature = "derive", feature = "dtype-date", feature = "dtype-datetime"))]
+mod derive;
 mod group_by;
 mod joins;
 mod list;
@@ -1,6 +1,7 @@
 use std::io::{Cursor, Seek, SeekFrom};
 
 use polars::prelude::*;
+use polars_utils::compression::ZstdLevel;
 
 #[test]
 fn test_ipc_compression_variadic_buffers() {
@@ -141,6 +142,25 @@ fn test_write_with_compression() {
     }
 }
 
+#[test]
+fn test_write_with_zstd_dictionary() -> PolarsResult<()> {
+    let names = (0..20_000)
+        .map(|i| format!("user-{}@example.com", i % 997))
+        .collect::<Vec<_>>();
+    let mut df = df!("name" => names)?;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    IpcWriter::new(&mut buf)
+        .with_compression(Some(IpcCompression::ZSTD(ZstdLevel::try_new(19)?)))
+        .with_zstd_dictionary_size(Some(1024))
+        .finish(&mut df)?;
+    buf.set_position(0);
+
+    let df_read = IpcReader::new(buf).finish()?;
+    assert!(df.equals(&df_read));
+    Ok(())
+}
+
 #[test]
 fn write_and_read_ipc_empty_series() {
     let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
@@ -155,3 +175,27 @@ fn write_and_read_ipc_empty_series() {
     let df_read = IpcReader::new(buf).finish().unwrap();
     assert!(df.equals(&df_read));
 }
+
+#[test]
+fn test_ipc_shared_memory() -> PolarsResult<()> {
+    let mut df = create_df();
+    let name = format!("test-ipc-shared-memory-{}", std::process::id());
+
+    let segment = SharedMemoryFrame::publish(&name, &mut df)?;
+    assert!(matches!(
+        SharedMemoryFrame::publish(&name, &mut df),
+        Err(PolarsError::Duplicate(_))
+    ));
+
+    let out = SharedMemoryFrame::open(&name)?;
+    assert!(out.equals(&df));
+
+    drop(segment);
+    assert!(!shared_memory_path(&name)?.exists());
+    // The mapping stays valid after the segment is removed.
+    assert!(out.equals(&df));
+    assert!(SharedMemoryFrame::open(&name).is_err());
+
+    assert!(SharedMemoryFrame::publish("../escape", &mut df).is_err());
+    Ok(())
+}
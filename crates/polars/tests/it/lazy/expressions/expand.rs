@@ -46,3 +46,30 @@ fn test_expand_datetimes_3042() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_expand_nested_path() -> PolarsResult<()> {
+    let df = df![
+        "x" => [1, 2, 3],
+        "y" => ["a", "b", "c"],
+    ]?
+    .lazy()
+    .select([as_struct(vec![
+        col("x"),
+        as_struct(vec![col("x"), col("y")]).alias("inner"),
+    ])
+    .alias("a")]);
+
+    let out = df.clone().select([nested_path("a.inner.y")?]).collect()?;
+    assert_eq!(out.get_column_names(), &["y"]);
+    assert_eq!(out.column("y")?.str()?.get(1), Some("b"));
+
+    let out = df.select([nested(["a", "inner", "*"])?]).collect()?;
+    assert_eq!(out.get_column_names(), &["x", "y"]);
+
+    assert!(nested(Vec::<&str>::new()).is_err());
+    assert!(nested(["[]", "a"]).is_err());
+
+    Ok(())
+}
@@ -113,3 +113,63 @@ fn fuzz_exprs() {
         }
     }
 }
+
+#[test]
+#[cfg(feature = "to_dummies")]
+fn test_to_dummies_expr() -> PolarsResult<()> {
+    let lf = df![
+        "cat" => [Some("a"), Some("c"), None, Some("b"), Some("d")],
+    ]?
+    .lazy()
+    .select([col("cat").to_dummies(["a", "b", "c"], None, true)]);
+
+    let schema = lf.clone().collect_schema()?;
+    let DataType::Struct(fields) = schema.get("cat").unwrap() else {
+        panic!("expected a struct")
+    };
+    assert_eq!(
+        fields.iter().map(|f| f.name().as_str()).collect::<Vec<_>>(),
+        &["cat_b", "cat_c"]
+    );
+
+    let out = lf.unnest(by_name(["cat"], true), None).collect()?;
+    let expected = df![
+        "cat_b" => [0u8, 0, 0, 1, 0],
+        "cat_c" => [0u8, 1, 0, 0, 0],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "array_linalg")]
+fn test_array_linalg_expr() -> PolarsResult<()> {
+    let dtype = DataType::Array(Box::new(DataType::Int32), 2);
+    let lf = df![
+        "a" => [Series::new("".into(), [3, 4]), Series::new("".into(), [1, 0])],
+        "b" => [Series::new("".into(), [1, 2]), Series::new("".into(), [0, 1])],
+    ]?
+    .lazy()
+    .select([col("a").cast(dtype.clone()), col("b").cast(dtype)])
+    .select([
+        col("a").arr().linalg().dot(col("b")).alias("dot"),
+        col("a").arr().linalg().norm(VectorNorm::L2).alias("norm"),
+        col("a")
+            .arr()
+            .linalg()
+            .cosine_similarity(col("b"))
+            .alias("cosine"),
+    ]);
+
+    let schema = lf.clone().collect_schema()?;
+    assert!(schema.iter_values().all(|dt| dt == &DataType::Float64));
+
+    let out = lf.collect()?;
+    let expected = df![
+        "dot" => [11.0, 0.0],
+        "norm" => [5.0, 1.0],
+        "cosine" => [11.0 / (5.0 * 5.0f64.sqrt()), 0.0],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
@@ -169,6 +169,84 @@ fn test_unnest_pushdown() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_unnest_naming_pushdown() -> PolarsResult<()> {
+    let s = df![
+        "a" => [1, 2],
+        "b" => [3, 4],
+    ]?
+    .into_struct("s".into())
+    .into_series();
+    let df = DataFrame::new_infer_height(vec![s.into_column(), Column::new("a".into(), [5, 6])])?;
+
+    // The field `a` collides with the column `a`.
+    let out = df
+        .clone()
+        .lazy()
+        .unnest(by_name(["s"], true), None)
+        .collect();
+    assert!(matches!(out, Err(PolarsError::Duplicate(_))));
+
+    let out = df
+        .clone()
+        .lazy()
+        .unnest_with_naming(by_name(["s"], true), UnnestNaming::Prefix("_".into()))
+        .select([col("s_a"), col("a")])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["s_a", "a"]);
+
+    let naming = UnnestNaming::Custom(PlanCallback::new(|(column, field)| {
+        Ok(PlSmallStr::from_string(format!("{field}_from_{column}")))
+    }));
+    let out = df
+        .lazy()
+        .unnest_with_naming(by_name(["s"], true), naming)
+        .select([col("b_from_s"), col("a")])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["b_from_s", "a"]);
+    assert_eq!(out.column("b_from_s")?.i32()?.get(1), Some(4));
+    assert_eq!(out.column("a")?.i32()?.get(1), Some(6));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_unnest_all_pushdown() -> PolarsResult<()> {
+    let inner = df![
+        "c" => [1, 2],
+        "d" => ["x", "y"],
+    ]?
+    .into_struct("b".into())
+    .into_series();
+    let outer = DataFrame::new_infer_height(vec![
+        inner.into_column(),
+        Column::new("e".into(), [3.0, 4.0]),
+    ])?
+    .into_struct("a".into())
+    .into_series();
+    let df = DataFrame::new_infer_height(vec![
+        outer.into_column(),
+        Column::new("f".into(), [5, 6]),
+    ])?;
+
+    let out = df.clone().lazy().unnest_all("_".into(), None);
+    assert_eq!(
+        out.clone().collect_schema()?.iter_names().collect::<Vec<_>>(),
+        &["a_b_c", "a_b_d", "a_e", "f"]
+    );
+    let out = out.select([col("a_b_d"), col("f")]).collect()?;
+    assert_eq!(out.get_column_names(), &["a_b_d", "f"]);
+    assert_eq!(out.column("a_b_d")?.str()?.get(1), Some("y"));
+
+    let out = df.lazy().unnest_all("_".into(), Some(1)).collect()?;
+    assert_eq!(out.get_column_names(), &["a_b", "a_e", "f"]);
+    assert!(out.column("a_b")?.dtype().is_struct());
+
+    Ok(())
+}
+
 #[test]
 fn test_join_duplicate_7314() -> PolarsResult<()> {
     let df_a: DataFrame = df![
@@ -92,6 +92,7 @@ from polars.datatypes import (
     Float16,
     Float32,
     Float64,
+    Geometry,
     Int8,
     Int16,
     Int32,
@@ -191,10 +192,12 @@ from polars.functions import (
     rolling_corr,
     rolling_cov,
     row_index,
+    rule_failures,
     select,
     self_dtype,
     set_random_seed,
     sql_expr,
+    st_point,
     std,
     struct,
     struct_with_fields,
@@ -206,6 +209,9 @@ from polars.functions import (
     time_ranges,
     union,
     var,
+    weighted_mean,
+    weighted_quantile,
+    weighted_var,
     when,
     zeros,
 )
@@ -237,7 +243,9 @@ from polars.io import (
     read_parquet_metadata,
     read_parquet_schema,
     scan_csv,
+    scan_database,
     scan_delta,
+    scan_http_json,
     scan_iceberg,
     scan_ipc,
     scan_ndjson,
@@ -308,6 +316,7 @@ __all__ = [
     "Float16",
     "Float32",
     "Float64",
+    "Geometry",
     "Int8",
     "Int16",
     "Int32",
@@ -357,7 +366,9 @@ __all__ = [
     "read_parquet_metadata",
     "read_parquet_schema",
     "scan_csv",
+    "scan_database",
     "scan_delta",
+    "scan_http_json",
     "scan_iceberg",
     "scan_ipc",
     "scan_ndjson",
@@ -412,6 +423,7 @@ __all__ = [
     "mean_horizontal",
     "min",
     "min_horizontal",
+    "rule_failures",
     "sum",
     "sum_horizontal",
     # polars.functions.lazy
@@ -469,10 +481,15 @@ __all__ = [
     "tail",
     "time",
     "var",
+    "weighted_mean",
+    "weighted_quantile",
+    "weighted_var",
     # polars.functions.len
     "len",
     # polars.functions.random
     "set_random_seed",
+    # polars.functions.geo
+    "st_point",
     # polars.convert
     "from_arrow",
     "from_dataframe",
@@ -55,6 +55,7 @@ IpcCompression: TypeAlias = Literal["uncompressed", "lz4", "zstd"]
 JoinType: TypeAlias = Literal["inner", "left", "right", "full", "semi", "anti", "cross"]
 Label: TypeAlias = Literal["left", "right", "datapoint"]
 ListToStructWidthStrategy: TypeAlias = Literal["first_non_null", "max_width"]
+ListZipOperation: TypeAlias = Literal["add", "sub", "mul", "min", "max"]
 NonExistent: TypeAlias = Literal["null", "raise"]
 NullBehavior: TypeAlias = Literal["drop", "ignore"]
 NullStrategy: TypeAlias = Literal["ignore", "propagate"]
@@ -88,7 +89,10 @@ EngineType: TypeAlias = Literal["auto", "in-memory", "streaming", "gpu"]
 PyScanOptions: TypeAlias = Any
 
 # exceptions
-class PolarsError(Exception): ...
+class PolarsError(Exception):
+    code: str
+    detail: dict[str, Any] | None
+
 class ColumnNotFoundError(PolarsError): ...
 class ComputeError(PolarsError): ...
 class DuplicateError(PolarsError): ...
@@ -123,6 +127,7 @@ class PySeries:
     def cat_uses_lexical_ordering(self) -> bool: ...
     def cat_is_local(self) -> bool: ...
     def cat_to_local(self) -> PySeries: ...
+    def cat_remove_unused_categories(self) -> PySeries: ...
     def estimated_size(self) -> int: ...
     def get_object(self, index: int) -> Any: ...
     def reshape(self, dims: Sequence[int]) -> PySeries: ...
@@ -667,6 +672,8 @@ class PyDataFrame:
         drop_first: bool,
         drop_nulls: bool,
     ) -> PyDataFrame: ...
+    def diff_frames(self, other: PyDataFrame, key_columns: list[str]) -> PyDataFrame: ...
+    def drift_report(self, other: PyDataFrame) -> PyDataFrame: ...
     def null_count(self) -> PyDataFrame: ...
     def map_rows(
         self,
@@ -728,6 +735,8 @@ class PyDataFrame:
         infer_schema_length: int | None,
         chunk_size: int,
         has_header: bool,
+        column_name_policy: Any,
+        normalize_column_names: bool,
         ignore_errors: bool,
         n_rows: int | None,
         skip_rows: int,
@@ -828,6 +837,7 @@ class PyLazyFrame:
         credential_provider: Any | None,
         retries: int,
         file_cache_ttl: int | None,
+        follow: tuple[float, bool] | None,
     ) -> PyLazyFrame: ...
     @staticmethod
     def new_from_csv(
@@ -835,6 +845,8 @@ class PyLazyFrame:
         sources: Any,
         separator: str,
         has_header: bool,
+        column_name_policy: Any,
+        normalize_column_names: bool,
         ignore_errors: bool,
         skip_rows: int,
         skip_lines: int,
@@ -864,11 +876,13 @@ class PyLazyFrame:
         retries: int,
         file_cache_ttl: int | None,
         include_file_paths: str | None,
+        follow: tuple[float, bool] | None,
     ) -> PyLazyFrame: ...
     @staticmethod
     def new_from_parquet(
         sources: Any,
         schema: Any | None,
+        schema_overrides: Any | None,
         scan_options: ScanOptions,
         parallel: Any,
         low_memory: bool,
@@ -881,6 +895,16 @@ class PyLazyFrame:
         file_cache_ttl: int | None,
     ) -> PyLazyFrame: ...
     @staticmethod
+    def new_from_http_json(
+        url: str,
+        pagination: tuple[str, str | None, str | None, int | None] | None,
+        records_pointer: str | None,
+        headers: list[tuple[str, str]] | None,
+        schema: Any | None,
+        infer_schema_length: int | None,
+        max_pages: int | None,
+    ) -> PyLazyFrame: ...
+    @staticmethod
     def new_from_dataset_object(dataset_object: Any) -> PyLazyFrame: ...
     @staticmethod
     def scan_from_python_function_arrow_schema(
@@ -905,6 +929,8 @@ class PyLazyFrame:
     def describe_optimized_plan(self) -> str: ...
     def describe_plan_tree(self) -> str: ...
     def describe_optimized_plan_tree(self) -> str: ...
+    def describe_optimized_plan_with_pruning_report(self, tree_format: bool) -> str: ...
+    def pruning_report(self) -> list[tuple[str | None, int, list[str], bool]]: ...
     def to_dot(self, optimized: bool) -> str: ...
     def to_dot_streaming_phys(self, optimized: bool) -> str: ...
     def sort(
@@ -931,10 +957,14 @@ class PyLazyFrame:
     ) -> PyLazyFrame: ...
     def cache(self) -> PyLazyFrame: ...
     def with_optimizations(self, optflags: PyOptFlags) -> PyLazyFrame: ...
+    def with_max_threads(self, n: int) -> PyLazyFrame: ...
     def profile(
         self, lambda_post_opt: Any | None
     ) -> tuple[PyDataFrame, PyDataFrame]: ...
     def collect(self, engine: Any, lambda_post_opt: Any | None) -> PyDataFrame: ...
+    def collect_with_checkpoint(
+        self, path: str, interval: float, resume: bool
+    ) -> PyDataFrame: ...
     def collect_with_callback(self, engine: Any, lambda_func: Any) -> None: ...
     def collect_batches(
         self, engine: Any, maintain_order: bool, chunk_size: int | None, lazy: bool
@@ -991,6 +1021,9 @@ class PyLazyFrame:
     ) -> PyLazyFrame: ...
     def filter(self, predicate: PyExpr) -> PyLazyFrame: ...
     def remove(self, predicate: PyExpr) -> PyLazyFrame: ...
+    def filter_partition(
+        self, predicate: PyExpr, reason: str | None
+    ) -> tuple[PyLazyFrame, PyLazyFrame]: ...
     def select(self, exprs: Sequence[PyExpr]) -> PyLazyFrame: ...
     def select_seq(self, exprs: Sequence[PyExpr]) -> PyLazyFrame: ...
     def group_by(self, by: Sequence[PyExpr], maintain_order: bool) -> PyLazyGroupBy: ...
@@ -1064,6 +1097,17 @@ class PyLazyFrame:
     def rename(
         self, existing: Sequence[str], new: Sequence[str], strict: bool
     ) -> PyLazyFrame: ...
+    def rename_with(
+        self, columns: PySelector, function: Callable[[str], str]
+    ) -> PyLazyFrame: ...
+    def move_columns(
+        self,
+        columns: PySelector,
+        index: int | None = None,
+        before: str | None = None,
+        after: str | None = None,
+    ) -> PyLazyFrame: ...
+    def insert_column_at(self, index: int, column: PyExpr) -> PyLazyFrame: ...
     def reverse(self) -> PyLazyFrame: ...
     def shift(self, n: PyExpr, fill_value: PyExpr | None) -> PyLazyFrame: ...
     def fill_nan(self, fill_value: PyExpr) -> PyLazyFrame: ...
@@ -1087,6 +1131,9 @@ class PyLazyFrame:
         subset: list[PyExpr] | None,
         keep: UniqueKeepStrategy,
     ) -> PyLazyFrame: ...
+    def unique_by(
+        self, subset: list[PyExpr], by: PyExpr, descending: bool
+    ) -> PyLazyFrame: ...
     def drop_nans(self, subset: PySelector | None) -> PyLazyFrame: ...
     def drop_nulls(self, subset: PySelector | None) -> PyLazyFrame: ...
     def slice(self, offset: int, len: int | None) -> PyLazyFrame: ...
@@ -1119,6 +1166,10 @@ class PyLazyFrame:
         schema: Schema | None,
         validate_output: bool,
     ) -> PyLazyFrame: ...
+    def split_sources(self) -> list[tuple[PyLazyFrame, int, int, str | None]]: ...
+    def execute_per_partition(
+        self, partition_by: Sequence[str], concurrency: int
+    ) -> PyPartitionResults: ...
     def drop(self, columns: PySelector) -> PyLazyFrame: ...
     def cast(self, dtypes: dict[str, DataType], strict: bool) -> PyLazyFrame: ...
     def cast_all(self, dtype: PyDataTypeExpr, strict: bool) -> PyLazyFrame: ...
@@ -1128,8 +1179,13 @@ class PyLazyFrame:
     def count(self) -> PyLazyFrame: ...
     def merge_sorted(self, other: PyLazyFrame, key: str) -> PyLazyFrame: ...
     def hint_sorted(
-        self, columns: list[str], descending: list[bool], nulls_last: list[bool]
+        self,
+        columns: list[str],
+        descending: list[bool],
+        nulls_last: list[bool],
+        verify: bool,
     ) -> PyLazyFrame: ...
+    def hint_unique(self, columns: list[str], verify: bool) -> PyLazyFrame: ...
 
     # exitable
     def collect_concurrently(self) -> PyInProcessQuery: ...
@@ -1231,6 +1287,7 @@ class PyExpr:
     def cast(
         self, dtype: PyDataTypeExpr, strict: bool, wrap_numerical: bool
     ) -> PyExpr: ...
+    def try_cast(self, dtype: PyDataTypeExpr) -> PyExpr: ...
     def sort_with(self, descending: bool, nulls_last: bool) -> PyExpr: ...
     def arg_sort(self, descending: bool, nulls_last: bool) -> PyExpr: ...
     def top_k(self, k: PyExpr) -> PyExpr: ...
@@ -1274,6 +1331,8 @@ class PyExpr:
     def var(self, ddof: int) -> PyExpr: ...
     def is_unique(self) -> PyExpr: ...
     def is_between(self, lower: PyExpr, upper: PyExpr, closed: Any) -> PyExpr: ...
+    def is_outlier_mad(self, threshold: float) -> PyExpr: ...
+    def is_outlier_iqr(self, k: float) -> PyExpr: ...
     def is_close(
         self, other: PyExpr, abs_tol: float, rel_tol: float, nans_equal: bool
     ) -> PyExpr: ...
@@ -1313,8 +1372,8 @@ class PyExpr:
         self,
         partition_by: Sequence[PyExpr] | None,
         order_by: Sequence[PyExpr] | None,
-        order_by_descending: bool,
-        order_by_nulls_last: bool,
+        order_by_descending: Sequence[bool],
+        order_by_nulls_last: Sequence[bool],
         mapping_strategy: Any,
     ) -> PyExpr: ...
     def rolling(
@@ -1488,6 +1547,8 @@ class PyExpr:
     def cat_starts_with(self, prefix: str) -> PyExpr: ...
     def cat_ends_with(self, suffix: str) -> PyExpr: ...
     def cat_slice(self, offset: int, length: int | None = None) -> PyExpr: ...
+    def cat_extend_categories(self, categories: Sequence[str]) -> PyExpr: ...
+    def cat_cast_with_added_categories(self, categories: Sequence[str]) -> PyExpr: ...
 
     # datetime
     def dt_add_business_days(
@@ -1563,6 +1624,9 @@ class PyExpr:
     def list_contains(self, other: PyExpr, nulls_equal: bool) -> PyExpr: ...
     def list_count_matches(self, expr: PyExpr) -> PyExpr: ...
     def list_diff(self, n: int, null_behavior: NullBehavior) -> PyExpr: ...
+    def list_rolling_sum(self, window_size: int) -> PyExpr: ...
+    def list_rolling_mean(self, window_size: int) -> PyExpr: ...
+    def list_zip_with(self, other: PyExpr, op: ListZipOperation) -> PyExpr: ...
     def list_eval(self, expr: PyExpr, _parallel: bool) -> PyExpr: ...
     def list_agg(self, expr: PyExpr) -> PyExpr: ...
     def list_filter(self, predicate: PyExpr) -> PyExpr: ...
@@ -1784,6 +1848,20 @@ class PyExpr:
         min_periods: int | None = None,
         center: bool = False,
     ) -> PyExpr: ...
+    def rolling_is_outlier_mad(
+        self,
+        threshold: float,
+        window_size: int,
+        min_periods: int | None,
+        center: bool,
+    ) -> PyExpr: ...
+    def rolling_is_outlier_iqr(
+        self,
+        k: float,
+        window_size: int,
+        min_periods: int | None,
+        center: bool,
+    ) -> PyExpr: ...
     def rolling_map(
         self,
         lambda_function: Any,
@@ -1921,6 +1999,22 @@ class PyExpr:
     def ext_to(self, dtype: PyDataTypeExpr) -> PyExpr: ...
     def ext_storage(self) -> PyExpr: ...
 
+    # geo
+    def st_x(self) -> PyExpr: ...
+    def st_y(self) -> PyExpr: ...
+    def st_distance(self, other: PyExpr) -> PyExpr: ...
+    def st_contains(self, other: PyExpr) -> PyExpr: ...
+    def st_within(self, other: PyExpr) -> PyExpr: ...
+    def st_intersects_bbox(
+        self, xmin: PyExpr, ymin: PyExpr, xmax: PyExpr, ymax: PyExpr
+    ) -> PyExpr: ...
+
+    # quant
+    def quant_drawdown(self) -> PyExpr: ...
+    def quant_max_drawdown(self) -> PyExpr: ...
+    def quant_hit_ratio(self) -> PyExpr: ...
+    def quant_sharpe(self, annualization: float) -> PyExpr: ...
+
 class PyDataTypeExpr:
     def __init__(self, inner: Any) -> None: ...
     @staticmethod
@@ -2068,6 +2162,22 @@ class PyOptFlags:
     @fast_projection.setter
     def fast_projection(self, value: bool) -> None: ...
     @property
+    def share_scans(self) -> bool: ...
+    @share_scans.setter
+    def share_scans(self, value: bool) -> None: ...
+    @property
+    def partition_group_by(self) -> bool: ...
+    @partition_group_by.setter
+    def partition_group_by(self, value: bool) -> None: ...
+    @property
+    def shrink_dtypes(self) -> bool: ...
+    @shrink_dtypes.setter
+    def shrink_dtypes(self, value: bool) -> None: ...
+    @property
+    def float_total_order(self) -> bool: ...
+    @float_total_order.setter
+    def float_total_order(self, value: bool) -> None: ...
+    @property
     def eager(self) -> bool: ...
     @eager.setter
     def eager(self, value: bool) -> None: ...
@@ -2104,14 +2214,23 @@ def collect_all_with_callback(
     lfs: Sequence[PyLazyFrame], engine: Any, optflags: PyOptFlags, lambda_func: Any
 ) -> None: ...
 def concat_lf(
-    seq: Any, rechunk: bool, parallel: bool, to_supertypes: bool, maintain_order: bool
+    seq: Any,
+    rechunk: bool,
+    parallel: bool,
+    to_supertypes: bool,
+    maintain_order: bool,
+    by_name: bool = False,
 ) -> PyLazyFrame: ...
 def concat_list(s: Sequence[PyExpr]) -> PyExpr: ...
 def concat_arr(s: Sequence[PyExpr]) -> PyExpr: ...
 def concat_str(s: Sequence[PyExpr], separator: str, ignore_nulls: bool) -> PyExpr: ...
 def len() -> PyExpr: ...
 def cov(a: PyExpr, b: PyExpr, ddof: int) -> PyExpr: ...
+def weighted_mean(values: PyExpr, weights: PyExpr) -> PyExpr: ...
+def weighted_var(values: PyExpr, weights: PyExpr, ddof: int) -> PyExpr: ...
+def weighted_quantile(values: PyExpr, weights: PyExpr, quantile: float) -> PyExpr: ...
 def arctan2(y: PyExpr, x: PyExpr) -> PyExpr: ...
+def st_point(x: PyExpr, y: PyExpr) -> PyExpr: ...
 def cum_fold(
     acc: PyExpr,
     lambda_func: Any,
@@ -2191,6 +2310,7 @@ def max_horizontal(exprs: Sequence[PyExpr]) -> PyExpr: ...
 def min_horizontal(exprs: Sequence[PyExpr]) -> PyExpr: ...
 def sum_horizontal(exprs: Sequence[PyExpr], ignore_nulls: bool) -> PyExpr: ...
 def mean_horizontal(exprs: Sequence[PyExpr], ignore_nulls: bool) -> PyExpr: ...
+def rule_failures(rules: Sequence[PyExpr], output: str) -> PyExpr: ...
 
 # functions.business
 def business_day_count(
@@ -2460,6 +2580,13 @@ def assert_dataframe_equal_py(
     abs_tol: float,
     categorical_as_str: bool,
 ) -> None: ...
+def assert_dataframe_equal_by_key_py(
+    left: PyDataFrame,
+    right: PyDataFrame,
+    *,
+    key_columns: list[str],
+    max_differences: int,
+) -> None: ...
 
 # datatypes
 def _get_dtype_max(dt: DataType) -> PyExpr: ...
@@ -2502,3 +2629,7 @@ class NodeTraverser:
 
 class PyCollectBatches:
     def start(self) -> None: ...
+
+class PyPartitionResults:
+    def __iter__(self) -> PyPartitionResults: ...
+    def __next__(self) -> tuple[PyDataFrame, PyDataFrame]: ...
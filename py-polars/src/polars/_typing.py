@@ -103,6 +103,7 @@ AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate"]
 CsvQuoteStyle: TypeAlias = Literal["necessary", "always", "non_numeric", "never"]
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
 CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
+ColumnNamePolicy: TypeAlias = Literal["raise", "suffix", "take_last"]
 ColumnMapping: TypeAlias = tuple[
     Literal["iceberg-column-mapping"],
     # This is "pa.Schema". Not typed as that causes pyright strict type checking
@@ -115,6 +116,7 @@ DefaultFieldValues: TypeAlias = tuple[
 DeletionFiles: TypeAlias = tuple[
     Literal["iceberg-position-delete"], dict[int, list[str]]
 ]
+ExplodeLengthMismatch: TypeAlias = Literal["raise", "pad", "truncate"]
 FillNullStrategy: TypeAlias = Literal[
     "forward", "backward", "min", "max", "mean", "zero", "one"
 ]
@@ -183,6 +185,7 @@ JoinStrategy: TypeAlias = Literal[
     "inner", "left", "right", "full", "semi", "anti", "cross", "outer"
 ]  # JoinType
 ListToStructWidthStrategy: TypeAlias = Literal["first_non_null", "max_width"]
+ListZipOperation: TypeAlias = Literal["add", "sub", "mul", "min", "max"]
 
 # The following have no equivalent on the Rust side
 ConcatMethod = Literal[
@@ -190,6 +193,7 @@ ConcatMethod = Literal[
     "vertical_relaxed",
     "diagonal",
     "diagonal_relaxed",
+    "diagonal_relaxed_ordered",
     "horizontal",
     "align",
     "align_full",
@@ -358,6 +362,7 @@ __all__ = [
     "ClosedInterval",
     "ColumnFormatDict",
     "ColumnNameOrSelector",
+    "ColumnNamePolicy",
     "ColumnTotalsDefinition",
     "ColumnWidthsDefinition",
     "ComparisonOperator",
@@ -393,6 +398,7 @@ __all__ = [
     "JoinValidation",
     "Label",
     "ListToStructWidthStrategy",
+    "ListZipOperation",
     "MaintainOrderJoin",
     "MapElementsStrategy",
     "MultiColSelector",
@@ -78,6 +78,9 @@ _POLARS_CFG_ENV_VARS: Final[set[str]] = {
     "POLARS_VERBOSE",
     "POLARS_MAX_EXPR_DEPTH",
     "POLARS_ENGINE_AFFINITY",
+    "POLARS_AUDIT_NULL_FILTERS",
+    "POLARS_SCAN_RETRY_BUDGET",
+    "POLARS_SMALL_BATCH_THRESHOLD",
 }
 
 # vars that set the rust env directly should declare themselves here as the Config
@@ -99,6 +102,7 @@ class ConfigParameters(TypedDict, total=False):
     """Parameters supported by the polars Config."""
 
     ascii_tables: bool | None
+    audit_null_filters: bool | None
     auto_structify: bool | None
     decimal_separator: str | None
     thousands_separator: str | bool | None
@@ -106,6 +110,8 @@ class ConfigParameters(TypedDict, total=False):
     fmt_float: FloatFmt | None
     fmt_str_lengths: int | None
     fmt_table_cell_list_len: int | None
+    scan_retry_budget: int | None
+    small_batch_threshold: int | None
     streaming_chunk_size: int | None
     tbl_cell_alignment: Literal["LEFT", "CENTER", "RIGHT"] | None
     tbl_cell_numeric_alignment: Literal["LEFT", "CENTER", "RIGHT"] | None
@@ -124,6 +130,7 @@ class ConfigParameters(TypedDict, total=False):
     expr_depth_warning: int
 
     set_ascii_tables: bool | None
+    set_audit_null_filters: bool | None
     set_auto_structify: bool | None
     set_decimal_separator: str | None
     set_thousands_separator: str | bool | None
@@ -131,6 +138,8 @@ class ConfigParameters(TypedDict, total=False):
     set_fmt_float: FloatFmt | None
     set_fmt_str_lengths: int | None
     set_fmt_table_cell_list_len: int | None
+    set_scan_retry_budget: int | None
+    set_small_batch_threshold: int | None
     set_streaming_chunk_size: int | None
     set_tbl_cell_alignment: Literal["LEFT", "CENTER", "RIGHT"] | None
     set_tbl_cell_numeric_alignment: Literal["LEFT", "CENTER", "RIGHT"] | None
@@ -517,6 +526,47 @@ class Config(contextlib.ContextDecorator):
             os.environ["POLARS_FMT_TABLE_FORMATTING"] = fmt
         return cls
 
+    @classmethod
+    def set_audit_null_filters(cls, active: bool | None = True) -> type[Config]:
+        """
+        Report the rows that filters drop because their predicate evaluated to null.
+
+        Under Kleene logic a comparison with a null yields null, and `&` and `|`
+        only resolve a null operand if the other operand decides the result. Filters
+        drop the rows for which the predicate is null, just like the rows for which it
+        is `false`. With this setting enabled, every filter that drops rows on a null
+        predicate prints the predicate, the number of rows dropped this way and a
+        sample of those rows to stderr.
+
+        Notes
+        -----
+        The report is only printed in verbose mode, see :meth:`set_verbose`.
+
+        Predicates that are pushed down into a scan are not audited; disable
+        `predicate_pushdown` to audit those as well.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, None, 3], "b": [True, True, None]})
+        >>> with pl.Config(audit_null_filters=True, verbose=True):  # doctest: +SKIP
+        ...     df.filter((pl.col("a") > 1) & pl.col("b"))
+        [null filter audit] predicate `[([(col("a")) > (dyn int: 1)]) & (col("b"))]` evaluated to null for 2 of 3 rows, which were dropped; sample of the dropped rows:
+        shape: (2, 2)
+        ┌──────┬──────┐
+        │ a    ┆ b    │
+        │ ---  ┆ ---  │
+        │ i64  ┆ bool │
+        ╞══════╪══════╡
+        │ null ┆ true │
+        │ 3    ┆ null │
+        └──────┴──────┘
+        """  # noqa: W505
+        if active is None:
+            os.environ.pop("POLARS_AUDIT_NULL_FILTERS", None)
+        else:
+            os.environ["POLARS_AUDIT_NULL_FILTERS"] = str(int(active))
+        return cls
+
     @classmethod
     @deprecated("deprecated since version 1.32.0")
     def set_auto_structify(cls, active: bool | None = False) -> type[Config]:
@@ -873,6 +923,81 @@ class Config(contextlib.ContextDecorator):
             os.environ["POLARS_FMT_TABLE_CELL_LIST_LEN"] = str(n)
         return cls
 
+    @classmethod
+    def set_scan_retry_budget(cls, n: int | None) -> type[Config]:
+        """
+        Set the number of times the files of a scan may be retried after an I/O error.
+
+        When a file of a scan fails with a transient error, i.e. a timeout, an
+        interrupted or reset connection, or a request that an object store throttled
+        (HTTP 429) or rejected as unavailable (HTTP 503), it is retried with exponential
+        backoff instead of failing the query. Other errors, such as missing files or
+        denied permissions, fail the query right away. The budget is shared by all
+        files of a scan; once it is used up, the next transient error fails the query
+        and names the file that failed.
+
+        Retries cover opening a file, reading its metadata and reading the file until
+        it produces its first rows. Reading is not retried for scans with a row index,
+        a slice or `extra_columns="raise"`.
+
+        Parameters
+        ----------
+        n : int
+            Total number of retries of the files of a scan. The default of 0 disables
+            retries.
+
+        Examples
+        --------
+        >>> lf = pl.scan_parquet("s3://bucket/data/*.parquet")  # doctest: +SKIP
+        >>> with pl.Config(scan_retry_budget=10):
+        ...     df = lf.collect()  # doctest: +SKIP
+        """
+        if n is None:
+            os.environ.pop("POLARS_SCAN_RETRY_BUDGET", None)
+        else:
+            os.environ["POLARS_SCAN_RETRY_BUDGET"] = str(n)
+        return cls
+
+    @classmethod
+    def set_small_batch_threshold(cls, n: int | None) -> type[Config]:
+        """
+        Set the number of rows below which frames are processed on a single thread.
+
+        For small frames, the overhead of dispatching work to the thread pool, splitting
+        the frame into morsels and rechunking in parallel can exceed the work itself.
+        Frames with fewer than `n` rows skip these steps when they are filtered,
+        projected, grouped or sorted by the in-memory engine, which lowers the latency
+        of queries on small data. The results are the same.
+
+        Joins and the streaming engine are not affected by this setting.
+
+        Parameters
+        ----------
+        n : int
+            Number of rows below which a frame is processed on a single thread. The
+            default of 0 disables the small-batch mode.
+
+        Examples
+        --------
+        >>> with pl.Config(small_batch_threshold=10_000):
+        ...     pl.LazyFrame({"a": [1, 2, 3]}).select(pl.col("a") * 2).collect()
+        shape: (3, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 2   │
+        │ 4   │
+        │ 6   │
+        └─────┘
+        """
+        if n is None:
+            os.environ.pop("POLARS_SMALL_BATCH_THRESHOLD", None)
+        else:
+            os.environ["POLARS_SMALL_BATCH_THRESHOLD"] = str(n)
+        return cls
+
     @classmethod
     def set_streaming_chunk_size(cls, size: int | None) -> type[Config]:
         """
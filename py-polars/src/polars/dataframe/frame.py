@@ -155,6 +155,7 @@ if TYPE_CHECKING:
         CsvQuoteStyle,
         DbWriteEngine,
         EngineType,
+        ExplodeLengthMismatch,
         FillNullStrategy,
         FloatFmt,
         FrameInitTypes,
@@ -3774,6 +3775,8 @@ class DataFrame:
         file: None,
         *,
         compression: IpcCompression = "uncompressed",
+        compression_level: int | None = None,
+        zstd_dictionary_size: int | None = None,
         compat_level: CompatLevel | None = None,
         record_batch_size: int | None = None,
         storage_options: dict[str, Any] | None = None,
@@ -3789,6 +3792,8 @@ class DataFrame:
         file: str | Path | IO[bytes],
         *,
         compression: IpcCompression = "uncompressed",
+        compression_level: int | None = None,
+        zstd_dictionary_size: int | None = None,
         compat_level: CompatLevel | None = None,
         record_batch_size: int | None = None,
         storage_options: dict[str, Any] | None = None,
@@ -3804,6 +3809,8 @@ class DataFrame:
         file: str | Path | IO[bytes] | None,
         *,
         compression: IpcCompression = "uncompressed",
+        compression_level: int | None = None,
+        zstd_dictionary_size: int | None = None,
         compat_level: CompatLevel | None = None,
         record_batch_size: int | None = None,
         storage_options: dict[str, Any] | None = None,
@@ -3827,6 +3834,17 @@ class DataFrame:
             written. If set to `None`, the output is returned as a BytesIO object.
         compression : {'uncompressed', 'lz4', 'zstd'}
             Compression method. Defaults to "uncompressed".
+        compression_level
+            The level of "zstd" compression, between 1 and 22. Higher levels mean
+            smaller files on disk. Defaults to 3.
+        zstd_dictionary_size
+            Compress with a "zstd" dictionary of at most this many bytes, trained on
+            the first record batch. This can shrink files with many short strings
+            considerably, but such files can only be read by Polars.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
         compat_level
             Use a specific compatibility level
             when exporting Polars' internal data structures.
@@ -3888,6 +3906,8 @@ class DataFrame:
             self.lazy().sink_ipc(
                 target,
                 compression=compression,
+                compression_level=compression_level,
+                zstd_dictionary_size=zstd_dictionary_size,
                 compat_level=compat_level,
                 record_batch_size=record_batch_size,
                 storage_options=storage_options,
@@ -4303,16 +4323,7 @@ class DataFrame:
             elif connection_module_root.startswith("adbc"):
                 engine = "adbc"
 
-        def unpack_table_name(name: str) -> tuple[str | None, str | None, str]:
-            """Unpack optionally qualified table name to catalog/schema/table tuple."""
-            from csv import reader as delimited_read
-
-            components: list[str | None] = next(delimited_read([name], delimiter="."))  # type: ignore[arg-type]
-            if len(components) > 3:
-                msg = f"`table_name` appears to be invalid: '{name}'"
-                raise ValueError(msg)
-            catalog, schema, tbl = ([None] * (3 - len(components))) + components
-            return catalog, schema, tbl  # type: ignore[return-value]
+        from polars.io.database._utils import _unpack_table_name
 
         if engine == "adbc":
             from polars.io.database._utils import (
@@ -4365,7 +4376,7 @@ class DataFrame:
                 conn if can_close_conn else contextlib.nullcontext(),
                 conn.cursor() as cursor,
             ):
-                catalog, db_schema, unpacked_table_name = unpack_table_name(table_name)
+                catalog, db_schema, unpacked_table_name = _unpack_table_name(table_name)
                 n_rows: int
 
                 # We can reliably introspect the underlying driver from a URI
@@ -4499,7 +4510,7 @@ class DataFrame:
                 )
                 raise TypeError(msg)
 
-            catalog, db_schema, unpacked_table_name = unpack_table_name(table_name)
+            catalog, db_schema, unpacked_table_name = _unpack_table_name(table_name)
             if catalog:
                 msg = f"Unexpected three-part table name; provide the database/catalog ({catalog!r}) on the connection URI"
                 raise ValueError(msg)
@@ -5487,6 +5498,86 @@ class DataFrame:
             .collect(optimizations=QueryOptFlags._eager())
         )
 
+    @unstable()
+    def filter_partition(
+        self,
+        *predicates: IntoExprColumn | Iterable[IntoExprColumn],
+        reason: str | None = None,
+        **constraints: Any,
+    ) -> tuple[DataFrame, DataFrame]:
+        """
+        Split the rows into those that match the predicate(s) and those that don't.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        predicates
+            Expression(s) that evaluate to a boolean Series.
+        reason
+            Name of a column to add to the dropped rows with the string representation
+            of the first predicate that did not evaluate to True for that row. The
+            operands of `&` and `all_horizontal` count as separate predicates.
+        constraints
+            Column filters; use `name = value` to filter columns using the supplied
+            value. Each constraint behaves the same as `pl.col(name).eq(value)`,
+            and is implicitly joined with the other filter conditions using `&`.
+
+        Returns
+        -------
+        tuple of DataFrame
+            The kept rows and the dropped rows. Rows where the predicate evaluates as
+            `null` are dropped.
+
+        See Also
+        --------
+        filter
+        remove
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "foo": [1, 2, None, 4],
+        ...         "ham": ["a", None, "c", "d"],
+        ...     }
+        ... )
+        >>> kept, dropped = df.filter_partition(
+        ...     pl.col("foo") > 1,
+        ...     pl.col("ham").is_not_null(),
+        ...     reason="reason",
+        ... )
+        >>> kept
+        shape: (1, 2)
+        ┌─────┬─────┐
+        │ foo ┆ ham │
+        │ --- ┆ --- │
+        │ i64 ┆ str │
+        ╞═════╪═════╡
+        │ 4   ┆ d   │
+        └─────┴─────┘
+        >>> dropped
+        shape: (3, 3)
+        ┌──────┬──────┬───────────────────────────────┐
+        │ foo  ┆ ham  ┆ reason                        │
+        │ ---  ┆ ---  ┆ ---                           │
+        │ i64  ┆ str  ┆ str                           │
+        ╞══════╪══════╪═══════════════════════════════╡
+        │ 1    ┆ a    ┆ [(col("foo")) > (dyn int: 1)] │
+        │ 2    ┆ null ┆ col("ham").is_not_null()      │
+        │ null ┆ c    ┆ [(col("foo")) > (dyn int: 1)] │
+        └──────┴──────┴───────────────────────────────┘
+        """
+        from polars.lazyframe.opt_flags import QueryOptFlags
+
+        kept, dropped = F.collect_all(
+            self.lazy().filter_partition(*predicates, reason=reason, **constraints),
+            optimizations=QueryOptFlags._eager(),
+        )
+        return kept, dropped
+
     @overload
     def glimpse(
         self,
@@ -7680,7 +7771,7 @@ class DataFrame:
         by: str | Sequence[str] | None = None,
         strategy: AsofJoinStrategy = "backward",
         suffix: str = "_right",
-        tolerance: str | int | float | timedelta | None = None,
+        tolerance: str | int | float | timedelta | Expr | None = None,
         allow_parallel: bool = True,
         force_parallel: bool = False,
         coalesce: bool = True,
@@ -7757,6 +7848,10 @@ class DataFrame:
                 "calendar week", "calendar month", "calendar quarter", and
                 "calendar year".
 
+            If an expression is given, it is evaluated on the left DataFrame to get a
+            tolerance per row, e.g. a tolerance per group with `over`. Rows with a null
+            tolerance are joined without a tolerance.
+
         allow_parallel
             Allow the physical plan to optionally evaluate the computation of both
             DataFrames up to the join in parallel.
@@ -9212,6 +9307,7 @@ class DataFrame:
         *more_columns: ColumnNameOrSelector,
         empty_as_null: bool = True,
         keep_nulls: bool = True,
+        length_mismatch: ExplodeLengthMismatch = "raise",
     ) -> DataFrame:
         """
         Explode the dataframe to long format by exploding the given columns.
@@ -9227,6 +9323,13 @@ class DataFrame:
             Explode an empty list/array into a `null`.
         keep_nulls
             Explode a `null` list/array into a `null`.
+        length_mismatch : {'raise', 'pad', 'truncate'}
+            What to do when multiple columns are exploded and their lists differ in
+            length within a row.
+
+            - 'raise': Raise an error.
+            - 'pad': Pad the shorter lists with nulls to the longest list of the row.
+            - 'truncate': Truncate the longer lists to the shortest list of the row.
 
         Returns
         -------
@@ -9278,6 +9381,7 @@ class DataFrame:
                 *more_columns,
                 empty_as_null=empty_as_null,
                 keep_nulls=keep_nulls,
+                length_mismatch=length_mismatch,
             )
             .collect(optimizations=QueryOptFlags._eager())
         )
@@ -10919,6 +11023,328 @@ class DataFrame:
             self._df.to_dummies(columns, separator, drop_first, drop_nulls)
         )
 
+    @unstable()
+    def diff_frames(self, other: DataFrame, on: str | Sequence[str]) -> DataFrame:
+        """
+        Compare the rows of this DataFrame with those of another, matched by key.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        The result contains the key columns, a `change` column and the old and new
+        value of every other column, suffixed with `_old` and `_new`. The change is
+        `"removed"` for keys that only exist in this DataFrame, `"added"` for keys
+        that only exist in `other` and `"modified"` for rows with any differing
+        value. Unchanged rows are left out.
+
+        Parameters
+        ----------
+        other
+            DataFrame to compare with, having the same columns and data types.
+        on
+            Name(s) of the columns identifying a row. The keys must be unique in both
+            DataFrames.
+
+        See Also
+        --------
+        polars.testing.assert_frame_equal_by_key
+
+        Notes
+        -----
+        Values are compared exactly, with nulls being equal to each other. The rows
+        of this DataFrame come first, in their original order, followed by the added
+        rows in the order of `other`.
+
+        Examples
+        --------
+        >>> df1 = pl.DataFrame({"id": [1, 2, 3], "value": [10, 20, 30]})
+        >>> df2 = pl.DataFrame({"id": [2, 3, 4], "value": [20, 35, 40]})
+        >>> df1.diff_frames(df2, on="id")
+        shape: (3, 4)
+        ┌─────┬──────────┬───────────┬───────────┐
+        │ id  ┆ change   ┆ value_old ┆ value_new │
+        │ --- ┆ ---      ┆ ---       ┆ ---       │
+        │ i64 ┆ str      ┆ i64       ┆ i64       │
+        ╞═════╪══════════╪═══════════╪═══════════╡
+        │ 1   ┆ removed  ┆ 10        ┆ null      │
+        │ 3   ┆ modified ┆ 30        ┆ 35        │
+        │ 4   ┆ added    ┆ null      ┆ 40        │
+        └─────┴──────────┴───────────┴───────────┘
+        """
+        if isinstance(on, str):
+            on = [on]
+        return self._from_pydf(self._df.diff_frames(other._df, list(on)))
+
+    @unstable()
+    def drift_report(self, other: DataFrame) -> DataFrame:
+        """
+        Compare the distribution of every column of another DataFrame with this one.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        This DataFrame is the baseline, such as a reference period that a daily feed
+        is monitored against. The result has a row per column, with:
+
+        - `column`: the name of the column.
+        - `status`: `"removed"` or `"added"` for columns that only exist in this
+          DataFrame or in `other`, `"dtype_changed"` for columns of which the data type
+          differs and `"unchanged"` otherwise.
+        - `null_rate_delta`: the change of the fraction of null values.
+        - `mean_delta` and `std_delta`: the change of the mean and the standard
+          deviation of numeric columns.
+        - `psi`: the population stability index of the values, over the deciles of
+          the baseline for numeric columns and over the categories for string,
+          categorical and boolean columns.
+
+        Statistics that don't apply to the data types of a column are null.
+
+        Parameters
+        ----------
+        other
+            DataFrame to compare with this baseline.
+
+        Notes
+        -----
+        The columns are compared in parallel. As a rule of thumb, a population
+        stability index below 0.1 indicates no significant change, and above 0.25 a
+        significant change of the distribution.
+
+        Examples
+        --------
+        >>> baseline = pl.DataFrame(
+        ...     {"price": [10.0, 11.0, 12.0, 13.0], "city": ["a", "b", "a", "b"]}
+        ... )
+        >>> current = pl.DataFrame(
+        ...     {
+        ...         "price": [12.0, 13.0, 14.0, None],
+        ...         "city": ["a", "b", "b", "b"],
+        ...         "qty": [1, 2, 3, 4],
+        ...     }
+        ... )
+        >>> baseline.drift_report(current)
+        shape: (3, 6)
+        ┌────────┬───────────┬─────────────────┬────────────┬───────────┬──────────┐
+        │ column ┆ status    ┆ null_rate_delta ┆ mean_delta ┆ std_delta ┆ psi      │
+        │ ---    ┆ ---       ┆ ---             ┆ ---        ┆ ---       ┆ ---      │
+        │ str    ┆ str       ┆ f64             ┆ f64        ┆ f64       ┆ f64      │
+        ╞════════╪═══════════╪═════════════════╪════════════╪═══════════╪══════════╡
+        │ price  ┆ unchanged ┆ 0.25            ┆ 1.5        ┆ -0.290994 ┆ 4.343111 │
+        │ city   ┆ unchanged ┆ 0.0             ┆ null       ┆ null      ┆ 0.274653 │
+        │ qty    ┆ added     ┆ null            ┆ null       ┆ null      ┆ null     │
+        └────────┴───────────┴─────────────────┴────────────┴───────────┴──────────┘
+        """
+        return self._from_pydf(self._df.drift_report(other._df))
+
+    @unstable()
+    def match_funnel(
+        self,
+        time_column: str,
+        steps: Mapping[str, IntoExpr],
+        *,
+        group_by: str | Sequence[str] | None = None,
+        within: timedelta | int | None = None,
+    ) -> DataFrame:
+        """
+        Find the ordered sequences of events that complete a funnel, per group.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Every step is a boolean expression marking the events of that step. A match
+        is a sequence of events, one per step in the order of `steps`. The match that
+        completes first is taken, starting at the latest possible first step, and
+        the next match starts after it.
+
+        Parameters
+        ----------
+        time_column
+            Name of the column with the time of the events. The events must be
+            sorted by this column within every group.
+        steps
+            Names and boolean expressions of the steps, in order.
+        group_by
+            Name(s) of the columns identifying a session of events.
+        within
+            Maximum time between the first and the last step of a match, as a
+            timedelta for temporal time columns and as an integer otherwise.
+
+        Notes
+        -----
+        Events with a null time are skipped. The result has the group columns, a
+        `match_id` column numbering the matches and the time of every step, with the
+        groups in order of appearance.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "user": [1, 1, 1, 2, 1],
+        ...         "time": [1, 2, 5, 3, 9],
+        ...         "event": ["view", "cart", "view", "view", "buy"],
+        ...     }
+        ... )
+        >>> steps = {
+        ...     "viewed": pl.col("event") == "view",
+        ...     "bought": pl.col("event") == "buy",
+        ... }
+        >>> df.match_funnel("time", steps, group_by="user")
+        shape: (1, 4)
+        ┌──────┬──────────┬────────┬────────┐
+        │ user ┆ match_id ┆ viewed ┆ bought │
+        │ ---  ┆ ---      ┆ ---    ┆ ---    │
+        │ i64  ┆ u32      ┆ i64    ┆ i64    │
+        ╞══════╪══════════╪════════╪════════╡
+        │ 1    ┆ 0        ┆ 5      ┆ 9      │
+        └──────┴──────────┴────────┴────────┘
+        """
+        if group_by is None:
+            group_by = []
+        elif isinstance(group_by, str):
+            group_by = [group_by]
+        df = self.with_columns(**steps)
+        return self._from_pydf(
+            df._df.match_funnel(list(group_by), time_column, list(steps), within)
+        )
+
+    @unstable()
+    def connected_components(
+        self, source: str, target: str, *, degree: bool = False
+    ) -> DataFrame:
+        """
+        Label the nodes of a graph given by its edges with their connected component.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Every row of this DataFrame is an undirected edge between the values of
+        `source` and `target`. The result has a `node` column with every distinct
+        value of both columns, in order of first appearance, and a `component` column
+        numbering the connected components in the same order.
+
+        Parameters
+        ----------
+        source
+            Name of the column with the first node of every edge.
+        target
+            Name of the column with the second node of every edge.
+        degree
+            Add a `degree` column with the number of edges of every node. Self-loops
+            are counted twice.
+
+        Notes
+        -----
+        Null values are not nodes. An edge with a single null endpoint only adds its
+        other endpoint as a node.
+
+        Examples
+        --------
+        >>> edges = pl.DataFrame({"a": [1, 2, 4, 6], "b": [2, 3, 5, 6]})
+        >>> edges.connected_components("a", "b", degree=True)
+        shape: (6, 3)
+        ┌──────┬───────────┬────────┐
+        │ node ┆ component ┆ degree │
+        │ ---  ┆ ---       ┆ ---    │
+        │ i64  ┆ u32       ┆ u32    │
+        ╞══════╪═══════════╪════════╡
+        │ 1    ┆ 0         ┆ 1      │
+        │ 2    ┆ 0         ┆ 2      │
+        │ 4    ┆ 1         ┆ 1      │
+        │ 6    ┆ 2         ┆ 2      │
+        │ 3    ┆ 0         ┆ 1      │
+        │ 5    ┆ 1         ┆ 1      │
+        └──────┴───────────┴────────┘
+        """
+        return self._from_pydf(self._df.connected_components(source, target, degree))
+
+    @unstable()
+    def aggregate_neighbors(
+        self,
+        key: str,
+        neighbors: str,
+        values: str | Sequence[str],
+        *,
+        aggregation: Literal["sum", "mean", "top_k"] = "sum",
+        k: int | None = None,
+    ) -> DataFrame:
+        """
+        Aggregate the values of the neighbors of every node of a graph.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Every row of this DataFrame is a node, identified by `key`, and `neighbors` is
+        a list column with the keys of its neighbors. The values of the neighbors are
+        aggregated in a single pass over the adjacency lists, without exploding them
+        into an edge list. The result has the `key` column and a column with the
+        aggregate of every column of `values`.
+
+        Parameters
+        ----------
+        key
+            Name of the column with the unique key of every node.
+        neighbors
+            Name of the list column with the keys of the neighbors of every node.
+        values
+            Names of the numeric columns to aggregate.
+        aggregation : {'sum', 'mean', 'top_k'}
+            How to aggregate the values of the neighbors.
+
+            * 'sum': The sum of the values. 8 and 16-bit integers are summed as
+              `Int64`.
+            * 'mean': The mean of the values as `Float64`.
+            * 'top_k': A list with the `k` largest values, in descending order.
+        k
+            The number of values to keep for the 'top_k' aggregation.
+
+        Notes
+        -----
+        Null values and neighbors that are not the key of a node are skipped. A null
+        list of neighbors gives a null aggregate.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "id": [1, 2, 3],
+        ...         "friends": [[2, 3], [1], []],
+        ...         "score": [10, 20, 30],
+        ...     }
+        ... )
+        >>> df.aggregate_neighbors("id", "friends", "score")
+        shape: (3, 2)
+        ┌─────┬───────┐
+        │ id  ┆ score │
+        │ --- ┆ ---   │
+        │ i64 ┆ i64   │
+        ╞═════╪═══════╡
+        │ 1   ┆ 50    │
+        │ 2   ┆ 10    │
+        │ 3   ┆ 0     │
+        └─────┴───────┘
+        >>> df.aggregate_neighbors("id", "friends", "score", aggregation="top_k", k=1)
+        shape: (3, 2)
+        ┌─────┬───────────┐
+        │ id  ┆ score     │
+        │ --- ┆ ---       │
+        │ i64 ┆ list[i64] │
+        ╞═════╪═══════════╡
+        │ 1   ┆ [30]      │
+        │ 2   ┆ [10]      │
+        │ 3   ┆ []        │
+        └─────┴───────────┘
+        """
+        if isinstance(values, str):
+            values = [values]
+        return self._from_pydf(
+            self._df.aggregate_neighbors(key, neighbors, list(values), aggregation, k)
+        )
+
     def unique(
         self,
         subset: IntoExpr | Collection[IntoExpr] | None = None,
@@ -11069,6 +11495,78 @@ class DataFrame:
             .collect(optimizations=QueryOptFlags._eager())
         )
 
+    @unstable()
+    def unique_by(
+        self,
+        subset: IntoExpr | Collection[IntoExpr],
+        by: IntoExpr,
+        *,
+        descending: bool = False,
+    ) -> DataFrame:
+        """
+        Keep a single row per unique key: the row with the lowest (or highest) value.
+
+        This gives the same rows as sorting by `by` and keeping the first row of every
+        key with `unique(subset, keep="first")`, but doesn't need to sort the whole
+        DataFrame.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed at any
+            point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        subset
+            Column name(s), selector(s), or expressions that make up the key.
+        by
+            Column name or expression whose value determines which row of a key is
+            kept.
+        descending
+            Keep the row with the highest value of `by` instead of the lowest.
+
+        Returns
+        -------
+        DataFrame
+            DataFrame with a single row per key, in the original order of the rows.
+
+        Notes
+        -----
+        If several rows of a key have the lowest (or highest) value, the first of them
+        is kept. A row where `by` is null is only kept if `by` is null for all rows of
+        its key.
+
+        See Also
+        --------
+        unique
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "id": [1, 1, 2, 2, 2],
+        ...         "version": [3, 5, 1, 4, 2],
+        ...         "value": ["a", "b", "c", "d", "e"],
+        ...     }
+        ... )
+        >>> df.unique_by("id", by="version", descending=True)
+        shape: (2, 3)
+        ┌─────┬─────────┬───────┐
+        │ id  ┆ version ┆ value │
+        │ --- ┆ ---     ┆ ---   │
+        │ i64 ┆ i64     ┆ str   │
+        ╞═════╪═════════╪═══════╡
+        │ 1   ┆ 5       ┆ b     │
+        │ 2   ┆ 4       ┆ d     │
+        └─────┴─────────┴───────┘
+        """
+        from polars.lazyframe.opt_flags import QueryOptFlags
+
+        return (
+            self.lazy()
+            .unique_by(subset, by, descending=descending)
+            .collect(optimizations=QueryOptFlags._eager())
+        )
+
     def n_unique(self, subset: str | Expr | Sequence[str | Expr] | None = None) -> int:
         """
         Return the number of unique rows, or the number of unique row-subsets.
@@ -12331,6 +12829,7 @@ class DataFrame:
         column: str,
         *,
         descending: bool = False,
+        verify: bool = False,
     ) -> DataFrame:
         """
         Flag a column as sorted.
@@ -12343,6 +12842,9 @@ class DataFrame:
             Column that is sorted
         descending
             Whether the column is sorted in descending order.
+        verify
+            Check that the column is sorted and raise an error if it is not,
+            instead of trusting the declaration.
 
         Warnings
         --------
@@ -12356,7 +12858,7 @@ class DataFrame:
 
         return (
             self.lazy()
-            .set_sorted(column, descending=descending)
+            .set_sorted(column, descending=descending, verify=verify)
             .collect(optimizations=QueryOptFlags._eager())
         )
 
@@ -23,6 +23,7 @@ from polars.datatypes.classes import (
     Float32,
     Float64,
     FloatType,
+    Geometry,
     Int8,
     Int16,
     Int32,
@@ -86,6 +87,7 @@ __all__ = [
     "Float32",
     "Float64",
     "FloatType",
+    "Geometry",
     "Int16",
     "Int128",
     "Int32",
@@ -22,6 +22,8 @@ import polars.datatypes.classes as pldt
 if TYPE_CHECKING:
     from collections.abc import Callable, Iterable, Iterator, Sequence
 
+    from pathlib import Path
+
     from polars import Series
     from polars._typing import (
         CategoricalOrdering,
@@ -810,6 +812,64 @@ class Categories:
         """Returns whether this refers to the global categories."""
         return self._categories.is_global()
 
+    def write_shared_mapping(self, path: str | Path) -> None:
+        """
+        Write the current categories to a file that other processes can attach to.
+
+        See :meth:`attach_shared_mapping`.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        path
+            Path of the file to write.
+        """
+        from polars._utils.various import normalize_filepath
+
+        self._categories.write_shared_mapping(normalize_filepath(path))
+
+    def attach_shared_mapping(self, path: str | Path) -> None:
+        """
+        Back the mapping of this `Categories` by a file of shared categories.
+
+        The file, written by :meth:`write_shared_mapping`, is memory-mapped and never
+        copied, so that many processes can attach to the same file while its strings
+        are only held in memory once. The categories of the file keep their ids;
+        categories that are added later are local to this process.
+
+        This must be done before the `Categories` is used, and keeps the mapping
+        alive for as long as this `Categories` exists. The file must not be modified
+        while it is attached.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        path
+            Path of the file to attach to.
+
+        Examples
+        --------
+        >>> products = pl.Categories("products")
+        >>> s = pl.Series(["apple", "pear"], dtype=pl.Categorical(products))
+        >>> products.write_shared_mapping(dirpath / "products.bin")
+
+        In another process:
+
+        >>> shared = pl.Categories("shared_products")
+        >>> shared.attach_shared_mapping(dirpath / "products.bin")
+        >>> shared["pear"]
+        1
+        """
+        from polars._utils.various import normalize_filepath
+
+        self._categories.attach_shared_mapping(normalize_filepath(path))
+
     def __getitem__(self, key: str | int | None) -> str | int | None:
         if key is None:
             return key
@@ -1363,6 +1423,34 @@ class BaseExtension(DataType):
         else:
             return s[:10] + ".."
 
+    def ext_validate(self, storage: Series) -> None:
+        """
+        Validate the storage values of a Series converted to this extension type.
+
+        This is called by `Series.ext.to` and by casts to this extension type, and
+        should raise an exception if the values are invalid. Subclasses can override
+        this to restrict the values of the storage type, e.g. to non-negative numbers.
+        """
+        return
+
+    def ext_can_cast_from(self, dtype: PolarsDataType) -> bool:
+        """
+        Whether a Series of the given data type can be cast to this extension type.
+
+        The values are cast to the storage type and then validated. Casting from the
+        storage type is always allowed.
+        """
+        return False
+
+    def ext_can_cast_to(self, dtype: PolarsDataType) -> bool:
+        """
+        Whether this extension type can be cast to the given data type.
+
+        The storage values are cast to the given data type. Casting to the storage
+        type is always allowed.
+        """
+        return False
+
     def __repr__(self) -> str:
         md = self.ext_metadata()
         if md is not None:
@@ -1414,3 +1502,40 @@ class Extension(BaseExtension):
     BaseExtension
     polars.register_extension_type
     """
+
+
+class Geometry(BaseExtension):
+    """
+    Geometry data type.
+
+    Geometries are stored as WKB (well-known binary) in a `Binary` column. This type
+    is compatible with the GeoArrow `geoarrow.wkb` extension type, and is written to
+    and read from Parquet files with GeoParquet metadata.
+
+    Spatial operations are available in the `st` namespace.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed at any
+        point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    metadata
+        GeoArrow metadata serialized as JSON, e.g. the coordinate reference system.
+
+    See Also
+    --------
+    polars.st_point
+    """
+
+    def __init__(self, metadata: str | None = None) -> None:
+        super().__init__("geoarrow.wkb", Binary(), metadata)
+
+    def _string_repr(self) -> str:
+        return "geometry"
+
+    def __repr__(self) -> str:
+        md = self.ext_metadata()
+        if md is not None:
+            return f"{self.__class__.__name__}(metadata={md!r})"
+        return self.__class__.__name__
@@ -1,3 +1,5 @@
+from typing import Any
+
 try:
     from polars._plr import (
         CategoricalRemappingWarning,
@@ -24,7 +26,16 @@ except ImportError:
     # redefined for documentation purposes when there is no binary
 
     class PolarsError(Exception):  # type: ignore[no-redef]
-        """Base class for all Polars errors."""
+        """
+        Base class for all Polars errors.
+
+        Errors raised by Polars have a stable `code` attribute, such as
+        `"column_not_found"`, and a `detail` attribute with the structured fields of the
+        error as a dict, or `None` if the error has none.
+        """
+
+        code: str
+        detail: dict[str, Any] | None
 
     class ColumnNotFoundError(PolarsError):  # type: ignore[no-redef]
         """
@@ -4,8 +4,11 @@ from typing import TYPE_CHECKING
 
 from polars._utils.various import qualified_type_name
 from polars._utils.wrap import wrap_expr
+from polars.datatypes import Enum
 
 if TYPE_CHECKING:
+    from collections.abc import Iterable
+
     from polars import Expr
 
 
@@ -40,6 +43,54 @@ class ExprCatNameSpace:
         """
         return wrap_expr(self._pyexpr.cat_get_categories())
 
+    def extend_categories(self, categories: Iterable[str]) -> Expr:
+        """
+        Append categories to an Enum.
+
+        Categories which are already part of the Enum are ignored. The existing values
+        keep their physical representation, which is much cheaper than casting to an
+        extended Enum through strings.
+
+        Parameters
+        ----------
+        categories
+            The categories to append.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["x", "y"]}, schema={"a": pl.Enum(["x", "y"])})
+        >>> df.select(pl.col("a").cat.extend_categories(["z", "x"])).schema["a"]
+        Enum(categories=['x', 'y', 'z'])
+        """
+        return wrap_expr(self._pyexpr.cat_extend_categories(list(categories)))
+
+    def cast_with_added_categories(self, dtype: Enum) -> Expr:
+        """
+        Cast an Enum to another Enum, adding the categories missing from it.
+
+        Unlike a regular cast, values which are not a category of `dtype` do not become
+        null: their categories are appended to the categories of `dtype`. The values
+        are remapped without going through their string representation.
+
+        Parameters
+        ----------
+        dtype
+            The Enum to cast to.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["x", "y"]}, schema={"a": pl.Enum(["x", "y"])})
+        >>> df.select(
+        ...     pl.col("a").cat.cast_with_added_categories(pl.Enum(["y", "z"]))
+        ... ).schema["a"]
+        Enum(categories=['y', 'z', 'x'])
+        """
+        if not isinstance(dtype, Enum):
+            msg = f"'dtype' must be an Enum; found {qualified_type_name(dtype)!r}"
+            raise TypeError(msg)
+        categories = dtype.categories.to_list()
+        return wrap_expr(self._pyexpr.cat_cast_with_added_categories(categories))
+
     def len_bytes(self) -> Expr:
         """
         Return the byte-length of the string representation of each value.
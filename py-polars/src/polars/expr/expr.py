@@ -62,9 +62,11 @@ from polars.expr.binary import ExprBinaryNameSpace
 from polars.expr.categorical import ExprCatNameSpace
 from polars.expr.datetime import ExprDateTimeNameSpace
 from polars.expr.ext import ExprExtensionNameSpace
+from polars.expr.geo import ExprGeoNameSpace
 from polars.expr.list import ExprListNameSpace
 from polars.expr.meta import ExprMetaNameSpace
 from polars.expr.name import ExprNameNameSpace
+from polars.expr.quant import ExprQuantNameSpace
 from polars.expr.string import ExprStringNameSpace
 from polars.expr.struct import ExprStructNameSpace
 from polars.meta import thread_pool_size
@@ -1270,6 +1272,12 @@ class Expr:
             Length of the slice. If set to `None`, all rows starting at the offset
             will be selected.
 
+        Notes
+        -----
+        In a group-by context, `offset` and `length` can be expressions that give a
+        value per group. Expressions that are not aggregated, such as a column, use
+        their first value in each group.
+
         Examples
         --------
         >>> df = pl.DataFrame(
@@ -1881,6 +1889,47 @@ class Expr:
             self._pyexpr.cast(dtype._pydatatype_expr, strict, wrap_numerical)
         )
 
+    @unstable()
+    def try_cast(self, dtype: PolarsDataType | pl.DataTypeExpr | type[Any]) -> Expr:
+        """
+        Cast between data types, reporting which values could be converted.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Returns a struct with the converted `value`, which is null where the value
+        could not be converted, and whether the conversion succeeded in `ok`. Null
+        values convert successfully.
+
+        Parameters
+        ----------
+        dtype
+            DataType to cast to.
+
+        See Also
+        --------
+        cast
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["1", "x", None, "4"]})
+        >>> df.select(pl.col("a").try_cast(pl.Int64)).unnest("a")
+        shape: (4, 2)
+        ┌───────┬───────┐
+        │ value ┆ ok    │
+        │ ---   ┆ ---   │
+        │ i64   ┆ bool  │
+        ╞═══════╪═══════╡
+        │ 1     ┆ true  │
+        │ null  ┆ false │
+        │ null  ┆ true  │
+        │ 4     ┆ true  │
+        └───────┴───────┘
+        """
+        dtype = parse_into_datatype_expr(dtype)
+        return wrap_expr(self._pyexpr.try_cast(dtype._pydatatype_expr))
+
     def sort(self, *, descending: bool = False, nulls_last: bool = False) -> Expr:
         """
         Sort this column.
@@ -2768,7 +2817,10 @@ class Expr:
             Number of indices to shift forward. If a negative value is passed, values
             are shifted in the opposite direction instead.
         fill_value
-            Fill the resulting null values with this scalar value.
+            Fill the resulting null values with this value. This can be a scalar or
+            an expression of the same length, of which the values at the positions
+            of the resulting nulls are used. Within `over`, the expression is
+            evaluated per group.
 
         Notes
         -----
@@ -3644,8 +3696,8 @@ class Expr:
         partition_by: IntoExpr | Iterable[IntoExpr] | None = None,
         *more_exprs: IntoExpr,
         order_by: IntoExpr | Iterable[IntoExpr] | None = None,
-        descending: bool = False,
-        nulls_last: bool = False,
+        descending: bool | Sequence[bool] = False,
+        nulls_last: bool | Sequence[bool] = False,
         mapping_strategy: WindowMappingStrategy = "group_to_rows",
     ) -> Expr:
         """
@@ -3670,10 +3722,12 @@ class Expr:
             result of the expression passed to `order_by`.
         descending
             In case 'order_by' is given, indicate whether to order in
-            ascending or descending order.
+            ascending or descending order. Specify a list of booleans to set the
+            order of every `order_by` expression.
         nulls_last
             In case 'order_by' is given, indicate whether to order
-            the nulls in last position.
+            the nulls in last position. Specify a list of booleans to set the
+            null order of every `order_by` expression.
         mapping_strategy: {'group_to_rows', 'join', 'explode'}
             - group_to_rows
                 If the aggregation results in multiple values per group, map them back
@@ -3857,12 +3911,15 @@ class Expr:
         else:
             order_by_pyexprs = None
 
+        n_order_by = 0 if order_by_pyexprs is None else len(order_by_pyexprs)
+        descending = extend_bool(descending, n_order_by, "descending", "order_by")
+        nulls_last = extend_bool(nulls_last, n_order_by, "nulls_last", "order_by")
         return wrap_expr(
             self._pyexpr.over(
                 partition_by_pyexprs,
                 order_by=order_by_pyexprs,
                 order_by_descending=descending,
-                order_by_nulls_last=False,  # does not work yet
+                order_by_nulls_last=nulls_last,
                 mapping_strategy=mapping_strategy,
             )
         )
@@ -5171,7 +5228,8 @@ Consider using {self}.implode() instead"""
         Parameters
         ----------
         n
-            Number of rows to return.
+            Number of rows to return. In a group-by context this can be a column, in
+            which case its first value in each group is used.
 
         Examples
         --------
@@ -5197,7 +5255,8 @@ Consider using {self}.implode() instead"""
         Parameters
         ----------
         n
-            Number of rows to return.
+            Number of rows to return. In a group-by context this can be a column, in
+            which case its first value in each group is used.
 
         Examples
         --------
@@ -6325,6 +6384,104 @@ Consider using {self}.implode() instead"""
             self._pyexpr.is_close(other_pyexpr, abs_tol, rel_tol, nans_equal)
         )
 
+    @unstable()
+    def is_outlier_mad(self, threshold: float = 3.5) -> Expr:
+        """
+        Flag outliers by their modified z-score.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        A value is flagged if its absolute deviation from the median exceeds
+        `threshold` times the median absolute deviation (MAD), scaled by 1.4826 to be
+        consistent with the standard deviation of normally distributed data.
+
+        Use in a window context (:meth:`over`) to screen per group.
+
+        Parameters
+        ----------
+        threshold
+            Modified z-score above which a value is flagged.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        See Also
+        --------
+        is_outlier_iqr
+        rolling_is_outlier_mad
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1.0, 2.0, 3.0, 2.0, 100.0]})
+        >>> df.with_columns(pl.col("a").is_outlier_mad().alias("outlier"))
+        shape: (5, 2)
+        ┌───────┬─────────┐
+        │ a     ┆ outlier │
+        │ ---   ┆ ---     │
+        │ f64   ┆ bool    │
+        ╞═══════╪═════════╡
+        │ 1.0   ┆ false   │
+        │ 2.0   ┆ false   │
+        │ 3.0   ┆ false   │
+        │ 2.0   ┆ false   │
+        │ 100.0 ┆ true    │
+        └───────┴─────────┘
+        """
+        return wrap_expr(self._pyexpr.is_outlier_mad(threshold))
+
+    @unstable()
+    def is_outlier_iqr(self, k: float = 1.5) -> Expr:
+        """
+        Flag outliers by Tukey's fences.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        A value is flagged if it lies outside of `[q1 - k * iqr, q3 + k * iqr]`, with
+        `q1` and `q3` the first and third quartile and `iqr` the interquartile range
+        `q3 - q1`.
+
+        Use in a window context (:meth:`over`) to screen per group.
+
+        Parameters
+        ----------
+        k
+            Multiple of the interquartile range by which the quartiles are extended.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        See Also
+        --------
+        is_outlier_mad
+        rolling_is_outlier_iqr
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1.0, 2.0, 3.0, 2.0, 100.0]})
+        >>> df.with_columns(pl.col("a").is_outlier_iqr().alias("outlier"))
+        shape: (5, 2)
+        ┌───────┬─────────┐
+        │ a     ┆ outlier │
+        │ ---   ┆ ---     │
+        │ f64   ┆ bool    │
+        ╞═══════╪═════════╡
+        │ 1.0   ┆ false   │
+        │ 2.0   ┆ false   │
+        │ 3.0   ┆ false   │
+        │ 2.0   ┆ false   │
+        │ 100.0 ┆ true    │
+        └───────┴─────────┘
+        """
+        return wrap_expr(self._pyexpr.is_outlier_iqr(k))
+
     def hash(
         self,
         seed: int = 0,
@@ -8998,6 +9155,124 @@ Consider using {self}.implode() instead"""
             )
         )
 
+    @unstable()
+    def rolling_is_outlier_mad(
+        self,
+        window_size: int,
+        threshold: float = 3.5,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Flag outliers by their modified z-score within a rolling window.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        threshold
+            Modified z-score above which a value is flagged.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        See Also
+        --------
+        Expr.is_outlier_mad
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1.0, 2.0, 3.0, 2.0, 50.0, 3.0]})
+        >>> df.select(pl.col("a").rolling_is_outlier_mad(3))
+        shape: (6, 1)
+        ┌───────┐
+        │ a     │
+        │ ---   │
+        │ bool  │
+        ╞═══════╡
+        │ null  │
+        │ null  │
+        │ false │
+        │ false │
+        │ true  │
+        │ false │
+        └───────┘
+        """
+        return wrap_expr(
+            self._pyexpr.rolling_is_outlier_mad(
+                threshold, window_size, min_periods=min_samples, center=center
+            )
+        )
+
+    @unstable()
+    def rolling_is_outlier_iqr(
+        self,
+        window_size: int,
+        k: float = 1.5,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Flag outliers by Tukey's fences within a rolling window.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        k
+            Multiple of the interquartile range by which the quartiles are extended.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        See Also
+        --------
+        Expr.is_outlier_iqr
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1.0, 2.0, 3.0, 2.0, 50.0, 3.0]})
+        >>> df.select(pl.col("a").rolling_is_outlier_iqr(4))
+        shape: (6, 1)
+        ┌───────┐
+        │ a     │
+        │ ---   │
+        │ bool  │
+        ╞═══════╡
+        │ null  │
+        │ null  │
+        │ null  │
+        │ false │
+        │ true  │
+        │ false │
+        └───────┘
+        """
+        return wrap_expr(
+            self._pyexpr.rolling_is_outlier_iqr(
+                k, window_size, min_periods=min_samples, center=center
+            )
+        )
+
     @unstable()
     @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
     def rolling_map(
@@ -9275,7 +9550,12 @@ Consider using {self}.implode() instead"""
         n_pyexpr = parse_into_expression(n)
         return wrap_expr(self._pyexpr.diff(n_pyexpr, null_behavior))
 
-    def pct_change(self, n: int | IntoExprColumn = 1) -> Expr:
+    def pct_change(
+        self,
+        n: int | IntoExprColumn = 1,
+        *,
+        fill_policy: FillNullStrategy | None = None,
+    ) -> Expr:
         """
         Computes percentage change between values.
 
@@ -9288,11 +9568,20 @@ Consider using {self}.implode() instead"""
         ----------
         n
             periods to shift for forming percent change.
+        fill_policy : {None, 'forward', 'backward', 'min', 'max', 'mean', 'zero', 'one'}
+            Strategy used to fill null values before computing the change; see
+            :meth:`fill_null`.
 
         Notes
         -----
-        Null values are preserved. If you're coming from pandas, this matches
-        their ``fill_method=None`` behaviour.
+        Null values are preserved by default. If you're coming from pandas, this
+        matches their ``fill_method=None`` behaviour, while `fill_policy="forward"`
+        matches ``fill_method="pad"``.
+
+        See Also
+        --------
+        pct_change_by
+        log_return
 
         Examples
         --------
@@ -9314,9 +9603,210 @@ Consider using {self}.implode() instead"""
         │ null ┆ null       │
         │ 12   ┆ null       │
         └──────┴────────────┘
+
+        Forward fill the null values first:
+
+        >>> df.with_columns(
+        ...     pl.col("a").pct_change(fill_policy="forward").alias("pct_change")
+        ... )
+        shape: (5, 2)
+        ┌──────┬────────────┐
+        │ a    ┆ pct_change │
+        │ ---  ┆ ---        │
+        │ i64  ┆ f64        │
+        ╞══════╪════════════╡
+        │ 10   ┆ null       │
+        │ 11   ┆ 0.1        │
+        │ 12   ┆ 0.090909   │
+        │ null ┆ 0.0        │
+        │ 12   ┆ 0.0        │
+        └──────┴────────────┘
         """
+        expr = self if fill_policy is None else self.fill_null(strategy=fill_policy)
         n_pyexpr = parse_into_expression(n)
-        return wrap_expr(self._pyexpr.pct_change(n_pyexpr))
+        return wrap_expr(expr._pyexpr.pct_change(n_pyexpr))
+
+    def pct_change_by(
+        self,
+        by: str | IntoExpr,
+        offset: str | timedelta,
+        *,
+        fill_policy: FillNullStrategy | None = None,
+    ) -> Expr:
+        """
+        Computes percentage change between values and the values `offset` earlier.
+
+        For every row, the change is computed versus the last row of which `by` is at
+        most `by - offset`; it is null if there is no such row. `by` does not need to
+        be sorted, though this is faster if it is.
+
+        Parameters
+        ----------
+        by
+            Times to compute the change by. Should be ``DateTime``, ``Date``,
+            ``UInt64``, ``UInt32``, ``Int64``, or ``Int32`` data type.
+        offset
+            How far back to look for the value to compare against.
+
+            Can be created either from a timedelta, or
+            by using the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+            - 1i    (1 index count)
+
+            Or combine them:
+            "3d12h4m25s" # 3 days, 12 hours, 4 minutes, and 25 seconds
+        fill_policy : {None, 'forward', 'backward', 'min', 'max', 'mean', 'zero', 'one'}
+            Strategy used to fill null values before computing the change; see
+            :meth:`fill_null`. Values are filled in the order of the rows.
+
+        Returns
+        -------
+        Expr
+            :class:`.Float16` if input is `Float16`, class:`.Float32` if input is
+            `Float32`, otherwise class:`.Float64`.
+
+        See Also
+        --------
+        pct_change
+        log_return_by
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "values": [10, 11, 12, 15, 18],
+        ...         "times": [
+        ...             date(2020, 1, 1),
+        ...             date(2020, 1, 2),
+        ...             date(2020, 1, 5),
+        ...             date(2020, 1, 6),
+        ...             date(2020, 1, 8),
+        ...         ],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     result=pl.col("values").pct_change_by("times", "3d"),
+        ... )
+        shape: (5, 3)
+        ┌────────┬────────────┬──────────┐
+        │ values ┆ times      ┆ result   │
+        │ ---    ┆ ---        ┆ ---      │
+        │ i64    ┆ date       ┆ f64      │
+        ╞════════╪════════════╪══════════╡
+        │ 10     ┆ 2020-01-01 ┆ null     │
+        │ 11     ┆ 2020-01-02 ┆ null     │
+        │ 12     ┆ 2020-01-05 ┆ 0.090909 │
+        │ 15     ┆ 2020-01-06 ┆ 0.363636 │
+        │ 18     ┆ 2020-01-08 ┆ 0.5      │
+        └────────┴────────────┴──────────┘
+        """
+        expr = self if fill_policy is None else self.fill_null(strategy=fill_policy)
+        by_pyexpr = parse_into_expression(by)
+        offset = parse_as_duration_string(offset)
+        return wrap_expr(expr._pyexpr.pct_change_by(by_pyexpr, offset))
+
+    def log_return(self, n: int | IntoExprColumn = 1) -> Expr:
+        """
+        Computes the logarithmic return between values.
+
+        The logarithmic return is `ln(x_t / x_{t-n})`, the natural logarithm of the
+        ratio between the current element and the most-recent non-null element at
+        least `n` period(s) before the current element.
+
+        Parameters
+        ----------
+        n
+            periods to shift for forming the return.
+
+        See Also
+        --------
+        pct_change
+        log_return_by
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [10, 11, 12, None, 12]})
+        >>> df.with_columns(pl.col("a").log_return().alias("log_return"))
+        shape: (5, 2)
+        ┌──────┬────────────┐
+        │ a    ┆ log_return │
+        │ ---  ┆ ---        │
+        │ i64  ┆ f64        │
+        ╞══════╪════════════╡
+        │ 10   ┆ null       │
+        │ 11   ┆ 0.09531    │
+        │ 12   ┆ 0.087011   │
+        │ null ┆ null       │
+        │ 12   ┆ null       │
+        └──────┴────────────┘
+        """
+        return self.pct_change(n).log1p()
+
+    def log_return_by(self, by: str | IntoExpr, offset: str | timedelta) -> Expr:
+        """
+        Computes the logarithmic return between values and the values `offset` earlier.
+
+        For every row, the return is computed versus the last row of which `by` is at
+        most `by - offset`; it is null if there is no such row.
+
+        Parameters
+        ----------
+        by
+            Times to compute the return by. Should be ``DateTime``, ``Date``,
+            ``UInt64``, ``UInt32``, ``Int64``, or ``Int32`` data type.
+        offset
+            How far back to look for the value to compare against, in the same
+            string language as :meth:`pct_change_by`.
+
+        See Also
+        --------
+        log_return
+        pct_change_by
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "values": [10, 11, 12, 15, 18],
+        ...         "times": [
+        ...             date(2020, 1, 1),
+        ...             date(2020, 1, 2),
+        ...             date(2020, 1, 5),
+        ...             date(2020, 1, 6),
+        ...             date(2020, 1, 8),
+        ...         ],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     result=pl.col("values").log_return_by("times", "3d"),
+        ... )
+        shape: (5, 3)
+        ┌────────┬────────────┬──────────┐
+        │ values ┆ times      ┆ result   │
+        │ ---    ┆ ---        ┆ ---      │
+        │ i64    ┆ date       ┆ f64      │
+        ╞════════╪════════════╪══════════╡
+        │ 10     ┆ 2020-01-01 ┆ null     │
+        │ 11     ┆ 2020-01-02 ┆ null     │
+        │ 12     ┆ 2020-01-05 ┆ 0.087011 │
+        │ 15     ┆ 2020-01-06 ┆ 0.310155 │
+        │ 18     ┆ 2020-01-08 ┆ 0.405465 │
+        └────────┴────────────┴──────────┘
+        """
+        return self.pct_change_by(by, offset).log1p()
 
     def skew(self, *, bias: bool = True) -> Expr:
         r"""
@@ -11785,6 +12275,24 @@ Consider using {self}.implode() instead"""
         """
         return ExprExtensionNameSpace(self)
 
+    @property
+    def st(self) -> ExprGeoNameSpace:
+        """
+        Create an object namespace of all spatial expressions on geometries.
+
+        See the individual method pages for full details.
+        """
+        return ExprGeoNameSpace(self)
+
+    @property
+    def quant(self) -> ExprQuantNameSpace:
+        """
+        Create an object namespace of all performance statistics of prices and returns.
+
+        See the individual method pages for full details.
+        """
+        return ExprQuantNameSpace(self)
+
     def _skip_batch_predicate(self, schema: SchemaDict) -> Expr | None:
         result = self._pyexpr.skip_batch_predicate(schema)
         if result is None:
@@ -0,0 +1,132 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.parse import parse_into_expression
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+    from polars._typing import IntoExpr
+
+
+class ExprGeoNameSpace:
+    """
+    Namespace for spatial expressions on :class:`Geometry` columns.
+
+    All operations are planar; coordinates are treated as cartesian regardless of
+    the coordinate reference system of the geometries.
+    """
+
+    _accessor = "st"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    @unstable()
+    def x(self) -> Expr:
+        """
+        Get the x coordinate of point geometries.
+
+        Empty points give null; other geometry types raise an error.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+        """
+        return wrap_expr(self._pyexpr.st_x())
+
+    @unstable()
+    def y(self) -> Expr:
+        """
+        Get the y coordinate of point geometries.
+
+        Empty points give null; other geometry types raise an error.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+        """
+        return wrap_expr(self._pyexpr.st_y())
+
+    @unstable()
+    def distance(self, other: IntoExpr) -> Expr:
+        """
+        Compute the minimum planar distance to the geometries of `other`.
+
+        The distance to an empty geometry is NaN.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Geometries to compute the distance to.
+        """
+        other_pyexpr = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.st_distance(other_pyexpr))
+
+    @unstable()
+    def contains(self, other: IntoExpr) -> Expr:
+        """
+        Check whether the geometries contain the geometries of `other`.
+
+        Points on the boundary are considered to be contained.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Geometries to check for containment.
+        """
+        other_pyexpr = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.st_contains(other_pyexpr))
+
+    @unstable()
+    def within(self, other: IntoExpr) -> Expr:
+        """
+        Check whether the geometries lie within the geometries of `other`.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Geometries that should contain the geometries.
+        """
+        other_pyexpr = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.st_within(other_pyexpr))
+
+    @unstable()
+    def intersects_bbox(
+        self,
+        xmin: float | IntoExpr,
+        ymin: float | IntoExpr,
+        xmax: float | IntoExpr,
+        ymax: float | IntoExpr,
+    ) -> Expr:
+        """
+        Check whether the bounding boxes of the geometries intersect a box.
+
+        This is a cheap filter to select the geometries in an area. Empty
+        geometries never intersect the box.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        xmin, ymin, xmax, ymax
+            Bounds of the box; these must be scalars.
+        """
+        bounds = [parse_into_expression(b) for b in (xmin, ymin, xmax, ymax)]
+        return wrap_expr(self._pyexpr.st_intersects_bbox(*bounds))
@@ -20,6 +20,7 @@ if TYPE_CHECKING:
         IntoExpr,
         IntoExprColumn,
         ListToStructWidthStrategy,
+        ListZipOperation,
         NullBehavior,
     )
 
@@ -931,6 +932,99 @@ class ExprListNameSpace:
         """
         return wrap_expr(self._pyexpr.list_diff(n, null_behavior))
 
+    def rolling_sum(self, window_size: int) -> Expr:
+        """
+        Compute the sum of every window of consecutive values of every sublist.
+
+        The first `window_size - 1` values of every sublist, and the values whose
+        window contains a null, are null.
+
+        Parameters
+        ----------
+        window_size
+            The number of values in a window.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"n": [[1, 2, 3], [4, 5, 6], [7]]})
+        >>> df.with_columns(rolling_sum=pl.col("n").list.rolling_sum(2))
+        shape: (3, 2)
+        ┌───────────┬───────────────┐
+        │ n         ┆ rolling_sum   │
+        │ ---       ┆ ---           │
+        │ list[i64] ┆ list[i64]     │
+        ╞═══════════╪═══════════════╡
+        │ [1, 2, 3] ┆ [null, 3, 5]  │
+        │ [4, 5, 6] ┆ [null, 9, 11] │
+        │ [7]       ┆ [null]        │
+        └───────────┴───────────────┘
+        """
+        return wrap_expr(self._pyexpr.list_rolling_sum(window_size))
+
+    def rolling_mean(self, window_size: int) -> Expr:
+        """
+        Compute the mean of every window of consecutive values of every sublist.
+
+        The first `window_size - 1` values of every sublist, and the values whose
+        window contains a null, are null.
+
+        Parameters
+        ----------
+        window_size
+            The number of values in a window.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"n": [[1, 2, 3], [4, 5, 6], [7]]})
+        >>> df.with_columns(rolling_mean=pl.col("n").list.rolling_mean(2))
+        shape: (3, 2)
+        ┌───────────┬──────────────────┐
+        │ n         ┆ rolling_mean     │
+        │ ---       ┆ ---              │
+        │ list[i64] ┆ list[f64]        │
+        ╞═══════════╪══════════════════╡
+        │ [1, 2, 3] ┆ [null, 1.5, 2.5] │
+        │ [4, 5, 6] ┆ [null, 4.5, 5.5] │
+        │ [7]       ┆ [null]           │
+        └───────────┴──────────────────┘
+        """
+        return wrap_expr(self._pyexpr.list_rolling_mean(window_size))
+
+    def zip_with(self, other: IntoExpr, op: ListZipOperation) -> Expr:
+        """
+        Combine the elements at the same position of the sublists of two list columns.
+
+        The sublists of a row must have the same length. A row is null if either of
+        its sublists is null.
+
+        Parameters
+        ----------
+        other
+            The other list column.
+        op : {'add', 'sub', 'mul', 'min', 'max'}
+            The operation that combines two elements. `'min'` and `'max'` ignore
+            null elements.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2], [3, 4, 5]], "b": [[10, 20], [1, 5, 9]]})
+        >>> df.with_columns(
+        ...     max=pl.col("a").list.zip_with("b", "max"),
+        ...     mul=pl.col("a").list.zip_with("b", "mul"),
+        ... )
+        shape: (2, 4)
+        ┌───────────┬───────────┬───────────┬─────────────┐
+        │ a         ┆ b         ┆ max       ┆ mul         │
+        │ ---       ┆ ---       ┆ ---       ┆ ---         │
+        │ list[i64] ┆ list[i64] ┆ list[i64] ┆ list[i64]   │
+        ╞═══════════╪═══════════╪═══════════╪═════════════╡
+        │ [1, 2]    ┆ [10, 20]  ┆ [10, 20]  ┆ [10, 40]    │
+        │ [3, 4, 5] ┆ [1, 5, 9] ┆ [3, 5, 9] ┆ [3, 20, 45] │
+        └───────────┴───────────┴───────────┴─────────────┘
+        """
+        other_pyexpr = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.list_zip_with(other_pyexpr, op))
+
     def shift(self, n: int | IntoExprColumn = 1) -> Expr:
         """
         Shift list values by the given number of indices.
@@ -0,0 +1,152 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+
+
+class ExprQuantNameSpace:
+    """
+    Namespace for performance statistics of prices and returns.
+
+    Every statistic is computed in a single pass over its input, so it can be used
+    efficiently per group in a `group_by` or `over` context.
+    """
+
+    _accessor = "quant"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    @unstable()
+    def drawdown(self) -> Expr:
+        """
+        Compute the relative decline of every price from the running maximum.
+
+        The drawdown is `price / running_max - 1`; it is zero at a new maximum and
+        negative otherwise. Null values are ignored when computing the maximum.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"price": [100, 110, 99, 121, 110]})
+        >>> df.select(pl.col("price").quant.drawdown())
+        shape: (5, 1)
+        ┌───────────┐
+        │ price     │
+        │ ---       │
+        │ f64       │
+        ╞═══════════╡
+        │ 0.0       │
+        │ 0.0       │
+        │ -0.1      │
+        │ 0.0       │
+        │ -0.090909 │
+        └───────────┘
+        """
+        return wrap_expr(self._pyexpr.quant_drawdown())
+
+    @unstable()
+    def max_drawdown(self) -> Expr:
+        """
+        Compute the largest drawdown of the prices.
+
+        The result is the minimum of :meth:`drawdown`, so it is zero or negative.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "asset": ["a", "a", "a", "b", "b", "b"],
+        ...         "price": [100, 80, 120, 10, 11, 12],
+        ...     }
+        ... )
+        >>> df.group_by("asset", maintain_order=True).agg(
+        ...     pl.col("price").quant.max_drawdown()
+        ... )
+        shape: (2, 2)
+        ┌───────┬───────┐
+        │ asset ┆ price │
+        │ ---   ┆ ---   │
+        │ str   ┆ f64   │
+        ╞═══════╪═══════╡
+        │ a     ┆ -0.2  │
+        │ b     ┆ 0.0   │
+        └───────┴───────┘
+        """
+        return wrap_expr(self._pyexpr.quant_max_drawdown())
+
+    @unstable()
+    def hit_ratio(self) -> Expr:
+        """
+        Compute the fraction of the returns that is positive.
+
+        Null values are ignored; the hit ratio of only null values is null.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"returns": [0.01, -0.02, 0.03, 0.02, None]})
+        >>> df.select(pl.col("returns").quant.hit_ratio())
+        shape: (1, 1)
+        ┌─────────┐
+        │ returns │
+        │ ---     │
+        │ f64     │
+        ╞═════════╡
+        │ 0.75    │
+        └─────────┘
+        """
+        return wrap_expr(self._pyexpr.quant_hit_ratio())
+
+    @unstable()
+    def sharpe(self, annualization: float = 252) -> Expr:
+        """
+        Compute the Sharpe ratio of the returns.
+
+        The Sharpe ratio is the mean of the returns divided by their sample standard
+        deviation, multiplied by the square root of `annualization`. The returns are
+        expected to be in excess of the risk-free rate. Null values are ignored; with
+        fewer than two returns the result is null.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        annualization
+            The number of periods per year, e.g. 252 for daily returns or 12 for
+            monthly returns. Use 1 to get the Sharpe ratio per period.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"returns": [0.01, -0.02, 0.03, 0.02]})
+        >>> df.select(
+        ...     daily=pl.col("returns").quant.sharpe(),
+        ...     per_period=pl.col("returns").quant.sharpe(1),
+        ... )
+        shape: (1, 2)
+        ┌──────────┬────────────┐
+        │ daily    ┆ per_period │
+        │ ---      ┆ ---        │
+        │ f64      ┆ f64        │
+        ╞══════════╪════════════╡
+        │ 7.348469 ┆ 0.46291    │
+        └──────────┴────────────┘
+        """
+        return wrap_expr(self._pyexpr.quant_sharpe(annualization))
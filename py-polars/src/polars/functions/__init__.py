@@ -10,6 +10,7 @@ from polars.functions.aggregation import (
     mean_horizontal,
     min,
     min_horizontal,
+    rule_failures,
     sum,
     sum_horizontal,
 )
@@ -29,6 +30,7 @@ from polars.functions.col import col
 from polars.functions.datatype import dtype_of, self_dtype, struct_with_fields
 from polars.functions.eager import align_frames, concat, union
 from polars.functions.escape_regex import escape_regex
+from polars.functions.geo import st_point
 from polars.functions.lazy import (
     _row_encode,
     approx_n_unique,
@@ -72,6 +74,9 @@ from polars.functions.lazy import (
     std,
     tail,
     var,
+    weighted_mean,
+    weighted_quantile,
+    weighted_var,
 )
 from polars.functions.len import len
 from polars.functions.lit import lit
@@ -105,6 +110,7 @@ __all__ = [
     "cum_sum_horizontal",
     "max_horizontal",
     "min_horizontal",
+    "rule_failures",
     "sum_horizontal",
     # polars.functions.datatype
     "dtype_of",
@@ -184,6 +190,9 @@ __all__ = [
     "tail",
     "time",
     "var",
+    "weighted_mean",
+    "weighted_quantile",
+    "weighted_var",
     # polars.functions.len
     "len",
     # polars.functions.whenthen
@@ -191,4 +200,6 @@ __all__ = [
     "sql_expr",
     # polars.functions.escape_regex
     "escape_regex",
+    # polars.functions.geo
+    "st_point",
 ]
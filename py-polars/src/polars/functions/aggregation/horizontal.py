@@ -1,10 +1,11 @@
 from __future__ import annotations
 
 import contextlib
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Literal
 
 import polars.functions as F
 from polars._utils.parse import parse_into_list_of_expressions
+from polars._utils.unstable import unstable
 from polars._utils.wrap import wrap_expr
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -257,6 +258,79 @@ def mean_horizontal(
     return wrap_expr(plr.mean_horizontal(pyexprs, ignore_nulls))
 
 
+@unstable()
+def rule_failures(
+    *rules: IntoExpr | Iterable[IntoExpr],
+    output: Literal["bitmask", "names"] = "bitmask",
+    **named_rules: IntoExpr,
+) -> Expr:
+    """
+    Evaluate many validation rules at once and report the failures of every row.
+
+    A rule is a boolean expression; a row fails it if it evaluates to `False` or
+    null. All rules are evaluated in a single pass into one compact column, instead
+    of one boolean column per rule.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    *rules
+        Rules to evaluate, as boolean expressions. Strings are parsed as column
+        names. The output name of a rule is its name.
+    output : {'bitmask', 'names'}
+        How to report the failed rules.
+
+        - 'bitmask': bit `i` is set if the row fails the `i`-th rule. With up to
+          64 rules the result is a `UInt64`; with more rules it is an `Array` of
+          `UInt64` words, where word `i // 64` holds bit `i % 64`.
+        - 'names': a list with the names of the rules the row fails. The rule names
+          must be unique.
+    **named_rules
+        Additional rules, named by their keyword. They follow the positional rules.
+
+    Notes
+    -----
+    The resulting column is named `"rule_failures"`. To select the rows failing the
+    `i`-th of up to 64 rules, filter on `pl.col("rule_failures") & (1 << i) != 0`.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "age": [25, -1, 130, None],
+    ...         "email": ["a@x.com", "b@x.com", "nope", "c@x.com"],
+    ...     }
+    ... )
+    >>> rules = {
+    ...     "age_min": pl.col("age") >= 0,
+    ...     "age_max": pl.col("age") < 120,
+    ...     "email": pl.col("email").str.contains("@"),
+    ... }
+    >>> df.with_columns(pl.rule_failures(**rules))
+    shape: (4, 3)
+    ┌──────┬─────────┬───────────────┐
+    │ age  ┆ email   ┆ rule_failures │
+    │ ---  ┆ ---     ┆ ---           │
+    │ i64  ┆ str     ┆ u64           │
+    ╞══════╪═════════╪═══════════════╡
+    │ 25   ┆ a@x.com ┆ 0             │
+    │ -1   ┆ b@x.com ┆ 1             │
+    │ 130  ┆ nope    ┆ 6             │
+    │ null ┆ c@x.com ┆ 3             │
+    └──────┴─────────┴───────────────┘
+
+    Report the names of the failed rules instead.
+
+    >>> df.select(pl.rule_failures(**rules, output="names")).to_series().to_list()
+    [[], ['age_min'], ['age_max', 'email'], ['age_min', 'age_max']]
+    """
+    pyrules = parse_into_list_of_expressions(*rules, **named_rules)
+    return wrap_expr(plr.rule_failures(pyrules, output))
+
+
 def cum_sum_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     """
     Cumulatively sum all values horizontally across columns.
@@ -38,7 +38,7 @@ def concat(
     ----------
     items
         DataFrames, LazyFrames, or Series to concatenate.
-    how : {'vertical', 'vertical_relaxed', 'diagonal', 'diagonal_relaxed', 'horizontal', 'align', 'align_full', 'align_inner', 'align_left', 'align_right'}
+    how : {'vertical', 'vertical_relaxed', 'diagonal', 'diagonal_relaxed', 'diagonal_relaxed_ordered', 'horizontal', 'align', 'align_full', 'align_inner', 'align_left', 'align_right'}
         Note that `Series` only support the `vertical` strategy.
 
         * vertical: Applies multiple `vstack` operations.
@@ -48,6 +48,11 @@ def concat(
           values with `null`.
         * diagonal_relaxed: Same as `diagonal`, but additionally coerces columns to
           their common supertype *if* they are mismatched (eg: Int32 → Int64).
+        * diagonal_relaxed_ordered: Aligns the columns by name, in the order of the
+          first frame, and coerces them to their common supertype *if* they are
+          mismatched (eg: Int32 → Int64). Unlike `diagonal_relaxed`, all frames must
+          have the same columns; the error lists the missing and unexpected columns
+          of each frame.
         * horizontal: Stacks Series from DataFrames horizontally and fills with `null`
           if the lengths don't match.
         * align, align_full, align_left, align_right: Combines frames horizontally,
@@ -255,6 +260,17 @@ def concat(
                     maintain_order=True,
                 )
             ).collect(optimizations=QueryOptFlags._eager())
+        elif how == "diagonal_relaxed_ordered":
+            out = wrap_ldf(
+                plr.concat_lf(
+                    [df.lazy() for df in elems],
+                    rechunk=rechunk,
+                    parallel=parallel,
+                    to_supertypes=True,
+                    maintain_order=True,
+                    by_name=True,
+                )
+            ).collect(optimizations=QueryOptFlags._eager())
         elif how == "horizontal":
             out = wrap_df(plr.concat_df_horizontal(elems, strict=strict))
         else:
@@ -283,6 +299,17 @@ def concat(
                     maintain_order=True,
                 )
             )
+        elif how == "diagonal_relaxed_ordered":
+            return wrap_ldf(
+                plr.concat_lf(
+                    elems,
+                    rechunk=rechunk,
+                    parallel=parallel,
+                    to_supertypes=True,
+                    maintain_order=True,
+                    by_name=True,
+                )
+            )
         elif how == "horizontal":
             return wrap_ldf(
                 plr.concat_lf_horizontal(
@@ -331,7 +358,7 @@ def union(
     ----------
     items
         DataFrames, LazyFrames, or Series to concatenate.
-    how : {'vertical', 'vertical_relaxed', 'diagonal', 'diagonal_relaxed', 'horizontal', 'align', 'align_full', 'align_inner', 'align_left', 'align_right'}
+    how : {'vertical', 'vertical_relaxed', 'diagonal', 'diagonal_relaxed', 'diagonal_relaxed_ordered', 'horizontal', 'align', 'align_full', 'align_inner', 'align_left', 'align_right'}
         Note that `Series` only support the `vertical` strategy.
 
         * vertical: Applies multiple `vstack` operations.
@@ -341,6 +368,11 @@ def union(
           values with `null`.
         * diagonal_relaxed: Same as `diagonal`, but additionally coerces columns to
           their common supertype *if* they are mismatched (eg: Int32 → Int64).
+        * diagonal_relaxed_ordered: Aligns the columns by name, in the order of the
+          first frame, and coerces them to their common supertype *if* they are
+          mismatched (eg: Int32 → Int64). Unlike `diagonal_relaxed`, all frames must
+          have the same columns; the error lists the missing and unexpected columns
+          of each frame.
         * horizontal: Stacks Series from DataFrames horizontally and fills with `null`
           if the lengths don't match.
         * align, align_full, align_left, align_right: Combines frames horizontally,
@@ -538,6 +570,17 @@ def union(
                     maintain_order=False,
                 )
             ).collect(optimizations=QueryOptFlags._eager())
+        elif how == "diagonal_relaxed_ordered":
+            out = wrap_ldf(
+                plr.concat_lf(
+                    [df.lazy() for df in elems],
+                    rechunk=False,
+                    parallel=True,
+                    to_supertypes=True,
+                    maintain_order=False,
+                    by_name=True,
+                )
+            ).collect(optimizations=QueryOptFlags._eager())
         elif how == "horizontal":
             out = wrap_df(plr.concat_df_horizontal(elems, strict=strict))
         else:
@@ -566,6 +609,17 @@ def union(
                     maintain_order=False,
                 )
             )
+        elif how == "diagonal_relaxed_ordered":
+            return wrap_ldf(
+                plr.concat_lf(
+                    elems,
+                    rechunk=False,
+                    parallel=True,
+                    to_supertypes=True,
+                    maintain_order=False,
+                    by_name=True,
+                )
+            )
         elif how == "horizontal":
             return wrap_ldf(
                 plr.concat_lf_horizontal(
@@ -0,0 +1,46 @@
+from __future__ import annotations
+
+import contextlib
+from typing import TYPE_CHECKING
+
+from polars._utils.parse import parse_into_expression
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars._plr as plr
+
+if TYPE_CHECKING:
+    from polars import Expr
+    from polars._typing import IntoExpr
+
+
+@unstable()
+def st_point(x: float | IntoExpr, y: float | IntoExpr) -> Expr:
+    """
+    Create point geometries from `x` and `y` coordinates.
+
+    The coordinates are cast to `Float64`; if either of them is null, the point
+    is null.
+
+    .. warning::
+        This functionality is currently considered **unstable**. It may be
+        changed at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    x
+        The x coordinates. Accepts expression input; strings are parsed as
+        column names.
+    y
+        The y coordinates. Accepts expression input; strings are parsed as
+        column names.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`Geometry`.
+    """
+    x_pyexpr = parse_into_expression(x)
+    y_pyexpr = parse_into_expression(y)
+    return wrap_expr(plr.st_point(x_pyexpr, y_pyexpr))
@@ -1067,6 +1067,165 @@ def cov(
         return wrap_expr(plr.cov(a_pyexpr, b_pyexpr, ddof))
 
 
+@unstable()
+def weighted_mean(values: IntoExpr, weights: IntoExpr) -> Expr:
+    """
+    Compute the mean of the values weighted by the weights.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    values
+        Column name or Expression with the values to aggregate.
+    weights
+        Column name or Expression with the non-negative weight of every value.
+
+    Notes
+    -----
+    Values whose value or weight is null are ignored. The result is null if the
+    remaining values have a total weight of zero.
+
+    Like other aggregations, this can be used in a `group_by`, `over`, `rolling`
+    or `group_by_dynamic` context.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "g": ["a", "a", "a", "b", "b"],
+    ...         "x": [1.0, 2.0, 4.0, 3.0, 5.0],
+    ...         "w": [1, 1, 2, 3, 1],
+    ...     }
+    ... )
+    >>> df.group_by("g", maintain_order=True).agg(pl.weighted_mean("x", "w"))
+    shape: (2, 2)
+    ┌─────┬──────┐
+    │ g   ┆ x    │
+    │ --- ┆ ---  │
+    │ str ┆ f64  │
+    ╞═════╪══════╡
+    │ a   ┆ 2.75 │
+    │ b   ┆ 3.5  │
+    └─────┴──────┘
+    """
+    values_pyexpr = parse_into_expression(values)
+    weights_pyexpr = parse_into_expression(weights)
+    return wrap_expr(plr.weighted_mean(values_pyexpr, weights_pyexpr))
+
+
+@unstable()
+def weighted_var(values: IntoExpr, weights: IntoExpr, *, ddof: int = 1) -> Expr:
+    """
+    Compute the variance of the values weighted by the weights.
+
+    The weights are treated as frequencies: a value with weight 2 counts as the
+    value occurring twice.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    values
+        Column name or Expression with the values to aggregate.
+    weights
+        Column name or Expression with the non-negative weight of every value.
+    ddof
+        "Delta Degrees of Freedom": the divisor used in the calculation is W - ddof,
+        where W represents the sum of the weights.
+        By default ddof is 1.
+
+    Notes
+    -----
+    Values whose value or weight is null are ignored. The result is null if the
+    remaining values have a total weight of at most `ddof`.
+
+    Like other aggregations, this can be used in a `group_by`, `over`, `rolling`
+    or `group_by_dynamic` context.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "g": ["a", "a", "a", "b", "b"],
+    ...         "x": [1.0, 2.0, 4.0, 3.0, 5.0],
+    ...         "w": [1, 1, 2, 3, 1],
+    ...     }
+    ... )
+    >>> df.group_by("g", maintain_order=True).agg(pl.weighted_var("x", "w"))
+    shape: (2, 2)
+    ┌─────┬──────┐
+    │ g   ┆ x    │
+    │ --- ┆ ---  │
+    │ str ┆ f64  │
+    ╞═════╪══════╡
+    │ a   ┆ 2.25 │
+    │ b   ┆ 1.0  │
+    └─────┴──────┘
+    """
+    values_pyexpr = parse_into_expression(values)
+    weights_pyexpr = parse_into_expression(weights)
+    return wrap_expr(plr.weighted_var(values_pyexpr, weights_pyexpr, ddof))
+
+
+@unstable()
+def weighted_quantile(values: IntoExpr, weights: IntoExpr, quantile: float) -> Expr:
+    """
+    Compute the quantile of the values weighted by the weights.
+
+    The result is the smallest value for which the values up to and including it
+    have at least `quantile` times the total weight.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    values
+        Column name or Expression with the values to aggregate.
+    weights
+        Column name or Expression with the non-negative weight of every value.
+    quantile
+        Quantile between 0.0 and 1.0.
+
+    Notes
+    -----
+    Values whose value or weight is null are ignored. The result is null if the
+    remaining values have a total weight of zero.
+
+    Like other aggregations, this can be used in a `group_by`, `over`, `rolling`
+    or `group_by_dynamic` context.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "g": ["a", "a", "a", "b", "b"],
+    ...         "x": [1.0, 2.0, 4.0, 3.0, 5.0],
+    ...         "w": [1, 1, 2, 3, 1],
+    ...     }
+    ... )
+    >>> df.group_by("g", maintain_order=True).agg(pl.weighted_quantile("x", "w", 0.5))
+    shape: (2, 2)
+    ┌─────┬─────┐
+    │ g   ┆ x   │
+    │ --- ┆ --- │
+    │ str ┆ f64 │
+    ╞═════╪═════╡
+    │ a   ┆ 2.0 │
+    │ b   ┆ 3.0 │
+    └─────┴─────┘
+    """
+    values_pyexpr = parse_into_expression(values)
+    weights_pyexpr = parse_into_expression(weights)
+    return wrap_expr(plr.weighted_quantile(values_pyexpr, weights_pyexpr, quantile))
+
+
 class _map_batches_wrapper:
     def __init__(
         self,
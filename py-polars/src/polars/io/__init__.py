@@ -3,8 +3,9 @@
 from polars.io.avro import read_avro
 from polars.io.clipboard import read_clipboard
 from polars.io.csv import read_csv, read_csv_batched, scan_csv
-from polars.io.database import read_database, read_database_uri
+from polars.io.database import read_database, read_database_uri, scan_database
 from polars.io.delta import read_delta, scan_delta
+from polars.io.http_json import scan_http_json
 from polars.io.iceberg import scan_iceberg
 from polars.io.ipc import read_ipc, read_ipc_schema, read_ipc_stream, scan_ipc
 from polars.io.json import read_json
@@ -54,7 +55,9 @@ __all__ = [
     "read_parquet_metadata",
     "read_parquet_schema",
     "scan_csv",
+    "scan_database",
     "scan_delta",
+    "scan_http_json",
     "scan_iceberg",
     "scan_ipc",
     "scan_ndjson",
@@ -9,6 +9,7 @@ from pathlib import Path
 from typing import IO, TYPE_CHECKING, Any, cast, overload
 
 from polars._dependencies import _FSSPEC_AVAILABLE, fsspec
+from polars._utils.unstable import issue_unstable_warning
 from polars._utils.various import (
     is_int_sequence,
     is_path_or_str_sequence,
@@ -80,6 +81,25 @@ def parse_row_index_args(
         return (row_index_name, row_index_offset)
 
 
+def parse_follow_args(
+    follow: bool,  # noqa: FBT001
+    follow_poll_interval: float,
+    follow_detect_rotation: bool,  # noqa: FBT001
+) -> tuple[float, bool] | None:
+    """
+    Parse the follow mode arguments of a scan function.
+
+    The Rust functions take a single tuple rather than three separate arguments.
+    """
+    if not follow:
+        return None
+    issue_unstable_warning("follow mode is considered unstable.")
+    if follow_poll_interval <= 0:
+        msg = f"`follow_poll_interval` must be positive, got {follow_poll_interval}"
+        raise ValueError(msg)
+    return (follow_poll_interval, follow_detect_rotation)
+
+
 @overload
 def prepare_file_arg(
     file: str | Path | list[str] | IO[bytes] | bytes,
@@ -22,6 +22,7 @@ from polars.datatypes import N_INFER_DEFAULT, String, parse_into_dtype
 from polars.io._utils import (
     is_glob_pattern,
     parse_columns_arg,
+    parse_follow_args,
     parse_row_index_args,
     prepare_file_arg,
 )
@@ -38,7 +39,7 @@ if TYPE_CHECKING:
     from collections.abc import Callable, Mapping
 
     from polars import DataFrame, LazyFrame
-    from polars._typing import CsvEncoding, PolarsDataType, SchemaDict
+    from polars._typing import ColumnNamePolicy, CsvEncoding, PolarsDataType, SchemaDict
     from polars.io.cloud import CredentialProviderFunction
     from polars.io.cloud.credential_provider._builder import CredentialProviderBuilder
 
@@ -50,6 +51,8 @@ def read_csv(
     source: str | Path | IO[str] | IO[bytes] | bytes,
     *,
     has_header: bool = True,
+    column_name_policy: ColumnNamePolicy = "suffix",
+    normalize_column_names: bool = False,
     columns: Sequence[int] | Sequence[str] | None = None,
     new_columns: Sequence[str] | None = None,
     separator: str = ",",
@@ -109,6 +112,20 @@ def read_csv(
         Indicate if the first row of the dataset is a header or not. If set to False,
         column names will be autogenerated in the following format: `column_x`, with
         `x` being an enumeration over every column in the dataset, starting at 1.
+    column_name_policy : {'suffix', 'raise', 'take_last'}
+        How to handle duplicate and empty column names in the header.
+
+        - 'suffix': rename the later occurrences of a duplicate name to
+          `{name}_duplicated_{n}`.
+        - 'raise': raise an error on duplicate or empty names.
+        - 'take_last': only keep the last column with a duplicate name.
+
+        Except with 'raise', empty names are replaced by `column_x`, with `x` being
+        the position of the column, starting at 1.
+    normalize_column_names
+        Normalize the column names in the header to the Unicode NFC form before
+        resolving duplicates, such that names that look the same but are encoded
+        differently (e.g. with combining accents) are treated as the same name.
     columns
         Columns to select. Accepts a list of column indices (starting
         at zero) or a list of column names.
@@ -507,6 +524,8 @@ def read_csv(
         lf = _scan_csv_impl(
             source,
             has_header=has_header,
+            column_name_policy=column_name_policy,
+            normalize_column_names=normalize_column_names,
             separator=separator,
             comment_prefix=comment_prefix,
             quote_char=quote_char,
@@ -551,6 +570,8 @@ def read_csv(
             df = _read_csv_impl(
                 data,
                 has_header=has_header,
+                column_name_policy=column_name_policy,
+                normalize_column_names=normalize_column_names,
                 columns=columns if columns else projection,
                 separator=separator,
                 comment_prefix=comment_prefix,
@@ -589,6 +610,8 @@ def _read_csv_impl(
     source: str | Path | IO[bytes] | bytes,
     *,
     has_header: bool = True,
+    column_name_policy: ColumnNamePolicy = "suffix",
+    normalize_column_names: bool = False,
     columns: Sequence[int] | Sequence[str] | None = None,
     separator: str = ",",
     comment_prefix: str | None = None,
@@ -660,6 +683,8 @@ def _read_csv_impl(
         scan = scan_csv(
             source,
             has_header=has_header,
+            column_name_policy=column_name_policy,
+            normalize_column_names=normalize_column_names,
             separator=separator,
             comment_prefix=comment_prefix,
             quote_char=quote_char,
@@ -701,6 +726,8 @@ def _read_csv_impl(
         infer_schema_length,
         batch_size,
         has_header,
+        column_name_policy,
+        normalize_column_names,
         ignore_errors,
         n_rows,
         skip_rows,
@@ -1076,6 +1103,8 @@ def scan_csv(
     ),
     *,
     has_header: bool = True,
+    column_name_policy: ColumnNamePolicy = "suffix",
+    normalize_column_names: bool = False,
     separator: str = ",",
     comment_prefix: str | None = None,
     quote_char: str | None = '"',
@@ -1109,6 +1138,9 @@ def scan_csv(
     retries: int = 2,
     file_cache_ttl: int | None = None,
     include_file_paths: str | None = None,
+    follow: bool = False,
+    follow_poll_interval: float = 1.0,
+    follow_detect_rotation: bool = True,
 ) -> LazyFrame:
     r"""
     Lazily read from a CSV file or multiple files via glob patterns.
@@ -1133,6 +1165,20 @@ def scan_csv(
         Indicate if the first row of the dataset is a header or not. If set to False,
         column names will be autogenerated in the following format: `column_x`, with
         `x` being an enumeration over every column in the dataset, starting at 1.
+    column_name_policy : {'suffix', 'raise', 'take_last'}
+        How to handle duplicate and empty column names in the header.
+
+        - 'suffix': rename the later occurrences of a duplicate name to
+          `{name}_duplicated_{n}`.
+        - 'raise': raise an error on duplicate or empty names.
+        - 'take_last': only keep the last column with a duplicate name.
+
+        Except with 'raise', empty names are replaced by `column_x`, with `x` being
+        the position of the column, starting at 1.
+    normalize_column_names
+        Normalize the column names in the header to the Unicode NFC form before
+        resolving duplicates, such that names that look the same but are encoded
+        differently (e.g. with combining accents) are treated as the same name.
     separator
         Single byte character to use as separator in the file.
     comment_prefix
@@ -1256,6 +1302,22 @@ def scan_csv(
         (which defaults to 1 hour) if not given.
     include_file_paths
         Include the path of the source file(s) as a column with this name.
+    follow
+        Keep the query running and read the rows that are appended to the file, like
+        `tail -f`. The file is read from the start, after which the scan waits for
+        complete lines to be appended. This requires a single local file; the scan is
+        unbounded, so the query must be executed with the streaming engine, e.g. to
+        sink the results or with `n_rows` set.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    follow_poll_interval
+        Number of seconds to wait before checking the file for new data again in
+        follow mode.
+    follow_detect_rotation
+        Start reading from the beginning of the file again in follow mode when it is
+        replaced, e.g. by log rotation, or truncated.
 
     Returns
     -------
@@ -1367,6 +1429,8 @@ def scan_csv(
     return _scan_csv_impl(
         source,
         has_header=has_header,
+        column_name_policy=column_name_policy,
+        normalize_column_names=normalize_column_names,
         separator=separator,
         comment_prefix=comment_prefix,
         quote_char=quote_char,
@@ -1398,6 +1462,7 @@ def scan_csv(
         credential_provider=credential_provider_builder,
         file_cache_ttl=file_cache_ttl,
         include_file_paths=include_file_paths,
+        follow=parse_follow_args(follow, follow_poll_interval, follow_detect_rotation),
     )
 
 
@@ -1413,6 +1478,8 @@ def _scan_csv_impl(
     | list[bytes],
     *,
     has_header: bool = True,
+    column_name_policy: ColumnNamePolicy = "suffix",
+    normalize_column_names: bool = False,
     separator: str = ",",
     comment_prefix: str | None = None,
     quote_char: str | None = '"',
@@ -1444,6 +1511,7 @@ def _scan_csv_impl(
     retries: int = 2,
     file_cache_ttl: int | None = None,
     include_file_paths: str | None = None,
+    follow: tuple[float, bool] | None = None,
 ) -> LazyFrame:
     dtype_list: list[tuple[str, PolarsDataType]] | None = None
     if schema_overrides is not None:
@@ -1472,6 +1540,8 @@ def _scan_csv_impl(
         sources,
         separator=separator,
         has_header=has_header,
+        column_name_policy=column_name_policy,
+        normalize_column_names=normalize_column_names,
         ignore_errors=ignore_errors,
         skip_rows=skip_rows,
         skip_lines=skip_lines,
@@ -1501,5 +1571,6 @@ def _scan_csv_impl(
         retries=retries,
         file_cache_ttl=file_cache_ttl,
         include_file_paths=include_file_paths,
+        follow=follow,
     )
     return wrap_ldf(pylf)
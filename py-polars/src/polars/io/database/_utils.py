@@ -1,18 +1,21 @@
 from __future__ import annotations
 
 import re
+from concurrent.futures import ThreadPoolExecutor
+from datetime import date, datetime
+from decimal import Decimal
 from importlib import import_module
 from typing import TYPE_CHECKING, Any
 
 from polars._dependencies import _PYARROW_AVAILABLE, import_optional
-from polars._utils.various import parse_version
+from polars._utils.various import parse_version, qualified_type_name
 from polars.convert import from_arrow
 from polars.exceptions import ModuleUpgradeRequiredError
 
 if TYPE_CHECKING:
-    from collections.abc import Coroutine
+    from collections.abc import Coroutine, Iterable, Iterator
 
-    from polars import DataFrame
+    from polars import DataFrame, Expr, Schema
     from polars._typing import SchemaDict
 
 
@@ -203,3 +206,242 @@ def _is_adbc_snowflake_conn(conn: Any) -> bool:
         )
     except (ImportError, adbc_driver_manager.Error):
         return False
+
+
+def _quote_identifier(name: str, driver_name: str) -> str:
+    quote = "`" if driver_name in ("mysql", "mariadb") else '"'
+    return f"{quote}{name.replace(quote, quote * 2)}{quote}"
+
+
+def _sql_literal(value: Any) -> str:
+    if isinstance(value, bool):
+        return "TRUE" if value else "FALSE"
+    elif isinstance(value, (int, float, Decimal)):
+        return str(value)
+    elif isinstance(value, datetime):
+        return f"'{value.isoformat(sep=' ')}'"
+    elif isinstance(value, date):
+        return f"'{value.isoformat()}'"
+    elif isinstance(value, str):
+        return "'{}'".format(value.replace("'", "''"))
+    msg = f"cannot partition on values of type {qualified_type_name(value)!r}"
+    raise TypeError(msg)
+
+
+def _partition_predicates(
+    column: str, lower: Any, upper: Any, partition_num: int
+) -> list[str]:
+    """Split the range of a column into predicates that select every row once."""
+    if partition_num <= 1 or lower is None or upper is None:
+        return []
+    if isinstance(lower, int) and isinstance(upper, int):
+        step = -(-(upper - lower + 1) // partition_num)
+        bounds = [lower + step * i for i in range(1, partition_num)]
+    else:
+        step = (upper - lower) / partition_num
+        bounds = [lower + step * i for i in range(1, partition_num)]
+    bounds = [_sql_literal(b) for b in dict.fromkeys(bounds)]
+
+    # the first and last partitions are open-ended, so that values outside of the
+    # range (and nulls) are not lost
+    predicates = [f"{column} < {bounds[0]} OR {column} IS NULL"]
+    predicates.extend(
+        f"{column} >= {lo} AND {column} < {hi}"
+        for lo, hi in zip(bounds, bounds[1:])
+    )
+    predicates.append(f"{column} >= {bounds[-1]}")
+    return predicates
+
+
+def _read_adbc_batches(
+    query: str,
+    connection_uri: str,
+    schema_overrides: SchemaDict | None,
+) -> Iterator[DataFrame]:
+    with _open_adbc_connection(connection_uri) as conn, conn.cursor() as cursor:
+        cursor.execute(query)
+        if _PYARROW_AVAILABLE:
+            # stream the record batches instead of materialising the whole result
+            batches = cursor.fetch_record_batch()
+        else:
+            batches = [cursor.fetch_arrow()]
+        for batch in batches:
+            yield from_arrow(batch, schema_overrides=schema_overrides)  # type: ignore[misc]
+
+
+def _infer_adbc_schema(
+    query: str,
+    connection_uri: str,
+    schema_overrides: SchemaDict | None,
+) -> SchemaDict:
+    query = f"SELECT * FROM ({query}) AS _polars_query LIMIT 1"
+    with _open_adbc_connection(connection_uri) as conn, conn.cursor() as cursor:
+        cursor.execute(query)
+        if _PYARROW_AVAILABLE:
+            # the reader has the schema of the result even if it returns no rows
+            result = cursor.fetch_record_batch().schema.empty_table()
+        else:
+            result = cursor.fetch_arrow()
+        frame = from_arrow(result, schema_overrides=schema_overrides)
+    return dict(frame.schema)  # type: ignore[union-attr]
+
+
+def _read_adbc_partitions(
+    queries: list[str],
+    connection_uri: str,
+    schema_overrides: SchemaDict | None,
+) -> Iterator[DataFrame]:
+    """Read the partitions in parallel, each on its own connection."""
+
+    def read_partition(query: str) -> list[DataFrame]:
+        return list(_read_adbc_batches(query, connection_uri, schema_overrides))
+
+    with ThreadPoolExecutor(max_workers=len(queries)) as pool:
+        for frames in pool.map(read_partition, queries):
+            yield from frames
+
+
+def _scan_sql_adbc(
+    query: str,
+    connection_uri: str,
+    *,
+    with_columns: list[str] | None,
+    predicate: Expr | None,
+    n_rows: int | None,
+    partition_on: str | None,
+    partition_range: tuple[Any, Any] | None,
+    partition_num: int | None,
+    schema_overrides: SchemaDict | None,
+) -> Iterator[DataFrame]:
+    driver_name = _get_adbc_driver_name_from_uri(connection_uri)
+    subquery = f"({query}) AS _polars_query"
+    projection = (
+        "*"
+        if with_columns is None
+        else ", ".join(_quote_identifier(c, driver_name) for c in with_columns)
+    )
+
+    queries = [f"SELECT {projection} FROM {subquery}"]
+    if n_rows is not None and predicate is None:
+        queries = [f"{queries[0]} LIMIT {n_rows}"]
+    elif partition_on is not None and partition_num is not None and partition_num > 1:
+        column = _quote_identifier(partition_on, driver_name)
+        if partition_range is None:
+            (bounds,) = _read_adbc_batches(
+                f"SELECT MIN({column}), MAX({column}) FROM {subquery}",
+                connection_uri,
+                schema_overrides=None,
+            )
+            partition_range = bounds.row(0)
+        predicates = _partition_predicates(column, *partition_range, partition_num)
+        if predicates:
+            queries = [f"{queries[0]} WHERE ({p})" for p in predicates]
+
+    overrides = schema_overrides
+    if overrides is not None and with_columns is not None:
+        overrides = {c: tp for c, tp in overrides.items() if c in with_columns}
+
+    if len(queries) == 1:
+        frames = _read_adbc_batches(queries[0], connection_uri, overrides)
+    else:
+        frames = _read_adbc_partitions(queries, connection_uri, overrides)
+
+    for df in frames:
+        if predicate is not None:
+            df = df.filter(predicate)
+        if n_rows is not None:
+            df = df.head(n_rows)
+            n_rows -= df.height
+        yield df
+        if n_rows == 0:
+            break
+
+
+def _unpack_table_name(name: str) -> tuple[str | None, str | None, str]:
+    """Unpack optionally qualified table name to catalog/schema/table tuple."""
+    from csv import reader as delimited_read
+
+    components: list[str | None] = next(delimited_read([name], delimiter="."))  # type: ignore[arg-type]
+    if len(components) > 3:
+        msg = f"`table_name` appears to be invalid: '{name}'"
+        raise ValueError(msg)
+    catalog, schema, tbl = ([None] * (3 - len(components))) + components
+    return catalog, schema, tbl  # type: ignore[return-value]
+
+
+def _adbc_ingest_exprs(schema: Schema, driver_name: str) -> list[Expr]:
+    """Cast the columns that the driver cannot ingest as-is."""
+    import polars.functions as F
+    from polars.datatypes import (
+        Categorical,
+        Datetime,
+        Decimal,
+        Enum,
+        Float64,
+        String,
+        Struct,
+    )
+
+    exprs = []
+    for name, dtype in schema.items():
+        col = F.col(name)
+        if isinstance(dtype, Struct):
+            # nested data is written as JSON strings
+            exprs.append(col.struct.json_encode())
+        elif dtype.is_nested():
+            # wrap in a struct to encode the values, stripping the `{"_":` prefix and
+            # `}` suffix of the encoded struct again
+            json = F.struct(col.alias("_")).struct.json_encode()
+            json = json.str.slice(5).str.strip_suffix("}")
+            exprs.append(F.when(col.is_not_null()).then(json).alias(name))
+        elif isinstance(dtype, (Categorical, Enum)):
+            exprs.append(col.cast(String))
+        elif isinstance(dtype, Decimal) and driver_name == "sqlite":
+            exprs.append(col.cast(Float64))
+        elif (
+            isinstance(dtype, Datetime)
+            and dtype.time_unit == "ns"
+            and driver_name == "postgresql"
+        ):
+            # postgres timestamps have microsecond precision
+            exprs.append(col.dt.cast_time_unit("us"))
+    return exprs
+
+
+def _ingest_adbc_batches(
+    batches: Iterable[DataFrame],
+    table_name: str,
+    conn: Any,
+    *,
+    mode: str,
+    use_pyarrow: bool,
+    engine_options: dict[str, Any] | None,
+) -> int:
+    """
+    Ingest the batches into a table in a single transaction.
+
+    The first batch is ingested with the given `mode`, creating or replacing the
+    table if necessary, and the others are appended. The transaction is rolled back
+    if any batch fails.
+    """
+    catalog, db_schema, unpacked_table_name = _unpack_table_name(table_name)
+    n_rows = 0
+    try:
+        with conn.cursor() as cursor:
+            for df in batches:
+                n_ingested = cursor.adbc_ingest(
+                    unpacked_table_name,
+                    data=df.to_arrow() if use_pyarrow else df,
+                    mode=mode,
+                    catalog_name=catalog,
+                    db_schema_name=db_schema,
+                    **(engine_options or {}),
+                )
+                mode = "append"
+                if n_rows >= 0:
+                    n_rows = n_ingested + n_rows if n_ingested >= 0 else -1
+    except BaseException:
+        conn.rollback()
+        raise
+    conn.commit()
+    return n_rows
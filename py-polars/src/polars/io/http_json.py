@@ -0,0 +1,146 @@
+from __future__ import annotations
+
+import contextlib
+from typing import TYPE_CHECKING, Any
+
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_ldf
+from polars.datatypes import N_INFER_DEFAULT
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    from polars._plr import PyLazyFrame
+
+if TYPE_CHECKING:
+    from polars import LazyFrame
+    from polars._typing import SchemaDict
+
+_PAGINATION_DEFAULTS: dict[str, dict[str, Any]] = {
+    "next_url": {"pointer": "/next"},
+    "link_header": {},
+    "page": {"param": "page", "start": 1},
+    "offset": {"offset_param": "offset", "limit_param": "limit", "limit": 100},
+}
+
+
+def _parse_pagination(
+    pagination: dict[str, Any] | None,
+) -> tuple[str, str | None, str | None, int | None] | None:
+    """Normalize the `pagination` parameter into the tuple that the scan expects."""
+    if pagination is None:
+        return None
+
+    pagination = dict(pagination)
+    kind = pagination.pop("type", None)
+    if kind not in _PAGINATION_DEFAULTS:
+        msg = (
+            f"invalid pagination type: {kind!r}"
+            f"\n\nExpected one of {list(_PAGINATION_DEFAULTS)}."
+        )
+        raise ValueError(msg)
+    defaults = _PAGINATION_DEFAULTS[kind]
+    if unknown := set(pagination) - set(defaults):
+        msg = f"unexpected keys for {kind!r} pagination: {sorted(unknown)}"
+        raise ValueError(msg)
+    options = {**defaults, **pagination}
+
+    if kind == "next_url":
+        return (kind, options["pointer"], None, None)
+    elif kind == "page":
+        return (kind, options["param"], None, options["start"])
+    elif kind == "offset":
+        if options["limit"] < 1:
+            msg = f"the pagination limit must be positive, got {options['limit']}"
+            raise ValueError(msg)
+        return (kind, options["offset_param"], options["limit_param"], options["limit"])
+    return (kind, None, None, None)
+
+
+@unstable()
+def scan_http_json(
+    url: str,
+    pagination: dict[str, Any] | None = None,
+    *,
+    records_path: str | None = None,
+    headers: dict[str, str] | None = None,
+    schema: SchemaDict | None = None,
+    infer_schema_length: int | None = N_INFER_DEFAULT,
+    max_pages: int | None = None,
+) -> LazyFrame:
+    """
+    Lazily read the records of a (paginated) JSON API over HTTP(S).
+
+    The pages are requested one at a time while the query is executed, so a query
+    that only needs the first rows does not request the remaining pages.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    url
+        URL of the first page.
+    pagination
+        How the pages after the first page are requested, as a dictionary with a
+        `"type"` key and the options of that type. Omitted options take the values
+        shown below.
+
+        * `None`: the API responds with a single page.
+        * `{"type": "next_url", "pointer": "/next"}`: the URL of the next page is
+          in the response, at the given JSON pointer. The URL may be relative to
+          the URL of the current page.
+        * `{"type": "link_header"}`: the URL of the next page is in the `Link`
+          header of the response with `rel="next"`.
+        * `{"type": "page", "param": "page", "start": 1}`: the pages are numbered
+          by a query parameter. The read ends at the first empty page.
+        * `{"type": "offset", "offset_param": "offset", "limit_param": "limit",
+          "limit": 100}`: the records are selected by an offset and a limit query
+          parameter. The read ends at the first page with fewer than `limit`
+          records.
+    records_path
+        JSON pointer to the array of records in a response, e.g. `"/data"`. By
+        default, the response is expected to be the array of records.
+    headers
+        Headers to send with every request, e.g. for authentication.
+    schema
+        The schema of the records. If not given, the first page is requested when
+        this function is called, to infer the schema from it.
+    infer_schema_length
+        The maximum number of records of the first page to scan for schema
+        inference. If set to `None`, all records of the first page are scanned.
+    max_pages
+        The maximum number of pages to request.
+
+    See Also
+    --------
+    scan_ndjson
+
+    Notes
+    -----
+    To read Parquet, CSV or NDJSON files that are served over HTTP(S), pass their URL
+    to :func:`scan_parquet`, :func:`scan_csv` or :func:`scan_ndjson` instead.
+
+    Examples
+    --------
+    >>> lf = pl.scan_http_json(
+    ...     "https://api.example.com/v1/orders",
+    ...     {"type": "next_url", "pointer": "/links/next"},
+    ...     records_path="/data",
+    ...     headers={"Authorization": "Bearer <token>"},
+    ... )  # doctest: +SKIP
+    >>> lf.filter(pl.col("status") == "open").collect()  # doctest: +SKIP
+    """
+    if max_pages is not None and max_pages < 1:
+        msg = f"`max_pages` must be positive, got {max_pages}"
+        raise ValueError(msg)
+
+    pylf = PyLazyFrame.new_from_http_json(
+        url,
+        pagination=_parse_pagination(pagination),
+        records_pointer=records_path,
+        headers=list(headers.items()) if headers else None,
+        schema=schema,
+        infer_schema_length=infer_schema_length,
+        max_pages=max_pages,
+    )
+    return wrap_ldf(pylf)
@@ -9,6 +9,7 @@ import polars._reexport as pl
 import polars.functions as F
 from polars._dependencies import import_optional
 from polars._utils.deprecation import deprecate_renamed_parameter
+from polars._utils.unstable import issue_unstable_warning
 from polars._utils.various import (
     is_str_sequence,
     normalize_filepath,
@@ -391,6 +392,7 @@ def scan_ipc(
     hive_schema: SchemaDict | None = None,
     try_parse_hive_dates: bool = True,
     include_file_paths: str | None = None,
+    schema_unification: Literal["first_file", "union", "strict"] = "first_file",
 ) -> LazyFrame:
     """
     Lazily read from an Arrow IPC (Feather v2) file or multiple files via glob patterns.
@@ -470,10 +472,27 @@ def scan_ipc(
         Whether to try parsing hive values as date/datetime types.
     include_file_paths
         Include the path of the source file(s) as a column with this name.
+    schema_unification
+        How the schemas of multiple files are combined:
+
+        * `first_file`: Use the schema of the first file.
+        * `union`: Use the union of the columns of all files. Every column takes the
+          supertype of its datatypes across the files, and columns missing from a
+          file are filled with nulls.
+        * `strict`: Raise an error listing every file whose schema differs from the
+          schema of the first file.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
     """
     # Memory Mapping is now a no-op
     _ = memory_map
 
+    if schema_unification != "first_file":
+        msg = "The `schema_unification` parameter of `scan_ipc` is considered unstable."
+        issue_unstable_warning(msg)
+
     sources = get_sources(source)
 
     credential_provider_builder = _init_credential_provider_builder(
@@ -491,6 +510,7 @@ def scan_ipc(
             ),
             pre_slice=(0, n_rows) if n_rows is not None else None,
             include_file_paths=include_file_paths,
+            schema_unification=schema_unification,
             glob=glob,
             hive_partitioning=hive_partitioning,
             hive_schema=hive_schema,
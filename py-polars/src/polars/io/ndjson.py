@@ -8,7 +8,7 @@ from polars._utils.deprecation import deprecate_renamed_parameter
 from polars._utils.various import is_path_or_str_sequence, normalize_filepath
 from polars._utils.wrap import wrap_ldf
 from polars.datatypes import N_INFER_DEFAULT
-from polars.io._utils import parse_row_index_args
+from polars.io._utils import parse_follow_args, parse_row_index_args
 from polars.io.cloud.credential_provider._builder import (
     _init_credential_provider_builder,
 )
@@ -204,6 +204,9 @@ def scan_ndjson(
     retries: int = 2,
     file_cache_ttl: int | None = None,
     include_file_paths: str | None = None,
+    follow: bool = False,
+    follow_poll_interval: float = 1.0,
+    follow_detect_rotation: bool = True,
 ) -> LazyFrame:
     """
     Lazily read from a newline delimited JSON file or multiple files via glob patterns.
@@ -280,6 +283,22 @@ def scan_ndjson(
         (which defaults to 1 hour) if not given.
     include_file_paths
         Include the path of the source file(s) as a column with this name.
+    follow
+        Keep the query running and read the lines that are appended to the file, like
+        `tail -f`. The file is read from the start, after which the scan waits for
+        complete lines to be appended. This requires a single local file; the scan is
+        unbounded, so the query must be executed with the streaming engine, e.g. to
+        sink the results or with `n_rows` set.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    follow_poll_interval
+        Number of seconds to wait before checking the file for new data again in
+        follow mode.
+    follow_detect_rotation
+        Start reading from the beginning of the file again in follow mode when it is
+        replaced, e.g. by log rotation, or truncated.
     """
     sources: list[str] | list[Path] | list[IO[str]] | list[IO[bytes]] = []
     if isinstance(source, (str, Path)):
@@ -328,5 +347,6 @@ def scan_ndjson(
         cloud_options=storage_options,
         credential_provider=credential_provider_builder,
         file_cache_ttl=file_cache_ttl,
+        follow=parse_follow_args(follow, follow_poll_interval, follow_detect_rotation),
     )
     return wrap_ldf(pylf)
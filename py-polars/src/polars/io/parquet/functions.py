@@ -63,6 +63,7 @@ def read_parquet(
     hive_partitioning: bool | None = None,
     glob: bool = True,
     schema: SchemaDict | None = None,
+    schema_overrides: SchemaDict | None = None,
     hive_schema: SchemaDict | None = None,
     try_parse_hive_dates: bool = True,
     rechunk: bool = False,
@@ -76,6 +77,7 @@ def read_parquet(
     include_file_paths: str | None = None,
     missing_columns: Literal["insert", "raise"] = "raise",
     allow_missing_columns: bool | None = None,
+    schema_unification: Literal["first_file", "union", "strict"] = "first_file",
 ) -> DataFrame:
     """
     Read into a DataFrame from a parquet file.
@@ -125,6 +127,14 @@ def read_parquet(
         datatypes in the file(s). If there are extra columns that are not in the
         file(s), consider also passing `missing_columns='insert'`.
 
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    schema_overrides
+        Overwrite the datatypes of a subset of the columns, e.g. to read a string
+        column as Categorical or an integer column as Decimal. The columns are cast
+        while they are decoded, casts that fail raise an error.
+
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
@@ -195,6 +205,19 @@ def read_parquet(
         .. deprecated:: 1.30.0
             Use the parameter `missing_columns` instead and pass one of
             `('insert', 'raise')`.
+    schema_unification
+        How the schemas of multiple files are combined:
+
+        * `first_file`: Use the schema of the first file.
+        * `union`: Use the union of the columns of all files. Every column takes the
+          supertype of its datatypes across the files, and columns missing from a
+          file are filled with nulls.
+        * `strict`: Raise an error listing every file whose schema differs from the
+          schema of the first file.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
     Returns
     -------
@@ -217,6 +240,10 @@ def read_parquet(
         msg = "the `schema` parameter of `read_parquet` is considered unstable."
         issue_unstable_warning(msg)
 
+    if schema_overrides is not None:
+        msg = "the `schema_overrides` parameter of `read_parquet` is considered unstable."
+        issue_unstable_warning(msg)
+
     if hive_schema is not None:
         msg = "the `hive_schema` parameter of `read_parquet` is considered unstable."
         issue_unstable_warning(msg)
@@ -232,6 +259,9 @@ def read_parquet(
         if schema is not None:
             msg = "`schema` cannot be used with `use_pyarrow=True`"
             raise ValueError(msg)
+        if schema_overrides is not None:
+            msg = "`schema_overrides` cannot be used with `use_pyarrow=True`"
+            raise ValueError(msg)
         if hive_schema is not None:
             msg = (
                 "cannot use `hive_partitions` with `use_pyarrow=True`"
@@ -267,6 +297,7 @@ def read_parquet(
         use_statistics=use_statistics,
         hive_partitioning=hive_partitioning,
         schema=schema,
+        schema_overrides=schema_overrides,
         hive_schema=hive_schema,
         try_parse_hive_dates=try_parse_hive_dates,
         rechunk=rechunk,
@@ -278,6 +309,7 @@ def read_parquet(
         glob=glob,
         include_file_paths=include_file_paths,
         missing_columns=missing_columns,
+        schema_unification=schema_unification,
     )
 
     if columns is not None:
@@ -453,6 +485,7 @@ def scan_parquet(
     glob: bool = True,
     hidden_file_prefix: str | Sequence[str] | None = None,
     schema: SchemaDict | None = None,
+    schema_overrides: SchemaDict | None = None,
     hive_schema: SchemaDict | None = None,
     try_parse_hive_dates: bool = True,
     rechunk: bool = False,
@@ -465,6 +498,7 @@ def scan_parquet(
     missing_columns: Literal["insert", "raise"] = "raise",
     allow_missing_columns: bool | None = None,
     extra_columns: Literal["ignore", "raise"] = "raise",
+    schema_unification: Literal["first_file", "union", "strict"] = "first_file",
     cast_options: ScanCastOptions | None = None,
     _column_mapping: ColumnMapping | None = None,
     _default_values: DefaultFieldValues | None = None,
@@ -536,6 +570,14 @@ def scan_parquet(
         datatypes in the file(s). If there are extra columns that are not in the
         file(s), consider also passing `missing_columns='insert'`.
 
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    schema_overrides
+        Overwrite the datatypes of a subset of the columns, e.g. to read a string
+        column as Categorical or an integer column as Decimal. The columns are cast
+        while they are decoded, casts that fail raise an error.
+
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
@@ -605,6 +647,19 @@ def scan_parquet(
         * `ignore`: Silently ignores.
         * `raise`: Raises an error.
 
+    schema_unification
+        How the schemas of multiple files are combined:
+
+        * `first_file`: Use the schema of the first file.
+        * `union`: Use the union of the columns of all files. Every column takes the
+          supertype of its datatypes across the files, and columns missing from a
+          file are filled with nulls.
+        * `strict`: Raise an error listing every file whose schema differs from the
+          schema of the first file.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
     cast_options
         Configuration for column type-casting during scans. Useful for datasets
         containing files that have differing schemas.
@@ -639,6 +694,10 @@ def scan_parquet(
         msg = "the `schema` parameter of `scan_parquet` is considered unstable."
         issue_unstable_warning(msg)
 
+    if schema_overrides is not None:
+        msg = "the `schema_overrides` parameter of `scan_parquet` is considered unstable."
+        issue_unstable_warning(msg)
+
     if hive_schema is not None:
         msg = "the `hive_schema` parameter of `scan_parquet` is considered unstable."
         issue_unstable_warning(msg)
@@ -651,6 +710,10 @@ def scan_parquet(
         msg = "The `hidden_file_prefix` parameter of `scan_parquet` is considered unstable."
         issue_unstable_warning(msg)
 
+    if schema_unification != "first_file":
+        msg = "The `schema_unification` parameter of `scan_parquet` is considered unstable."
+        issue_unstable_warning(msg)
+
     if allow_missing_columns is not None:
         issue_deprecation_warning(
             "the parameter `allow_missing_columns` for `scan_parquet` is deprecated. "
@@ -672,6 +735,7 @@ def scan_parquet(
     pylf = PyLazyFrame.new_from_parquet(
         sources=sources,
         schema=schema,
+        schema_overrides=schema_overrides,
         parallel=parallel,
         low_memory=low_memory,
         use_statistics=use_statistics,
@@ -685,6 +749,7 @@ def scan_parquet(
             cast_options=cast_options,
             extra_columns=extra_columns,
             missing_columns=missing_columns,
+            schema_unification=schema_unification,
             include_file_paths=include_file_paths,
             glob=glob,
             hidden_file_prefix=(
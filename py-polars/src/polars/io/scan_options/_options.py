@@ -32,6 +32,7 @@ class ScanOptions:
     cast_options: ScanCastOptions | None = None
     extra_columns: Literal["ignore", "raise"] = "raise"
     missing_columns: Literal["insert", "raise"] = "raise"
+    schema_unification: Literal["first_file", "union", "strict"] = "first_file"
     include_file_paths: str | None = None
 
     # For path expansion
@@ -1,9 +1,11 @@
 from polars.lazyframe.engine_config import GPUEngine
 from polars.lazyframe.frame import LazyFrame
 from polars.lazyframe.opt_flags import QueryOptFlags
+from polars.lazyframe.partitions import PartitionInfo
 
 __all__ = [
     "GPUEngine",
     "LazyFrame",
+    "PartitionInfo",
     "QueryOptFlags",
 ]
@@ -41,6 +41,10 @@ class QueryOptFlags:
         collapse_joins: None | bool = None,
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
+        share_scans: None | bool = None,
+        partition_group_by: None | bool = None,
+        shrink_dtypes: None | bool = None,
+        float_total_order: None | bool = None,
     ) -> None:
         self._pyoptflags = PyOptFlags.default()
         self.update(
@@ -54,6 +58,10 @@ class QueryOptFlags:
             collapse_joins=collapse_joins,
             check_order_observe=check_order_observe,
             fast_projection=fast_projection,
+            share_scans=share_scans,
+            partition_group_by=partition_group_by,
+            shrink_dtypes=shrink_dtypes,
+            float_total_order=float_total_order,
         )
 
     @classmethod
@@ -75,6 +83,10 @@ class QueryOptFlags:
         collapse_joins: None | bool = None,
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
+        share_scans: None | bool = None,
+        partition_group_by: None | bool = None,
+        shrink_dtypes: None | bool = None,
+        float_total_order: None | bool = None,
     ) -> QueryOptFlags:
         """Create new empty set off optimizations."""
         optflags = QueryOptFlags()
@@ -90,6 +102,10 @@ class QueryOptFlags:
             collapse_joins=collapse_joins,
             check_order_observe=check_order_observe,
             fast_projection=fast_projection,
+            share_scans=share_scans,
+            partition_group_by=partition_group_by,
+            shrink_dtypes=shrink_dtypes,
+            float_total_order=float_total_order,
         )
 
     def update(
@@ -105,6 +121,10 @@ class QueryOptFlags:
         collapse_joins: None | bool = None,
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
+        share_scans: None | bool = None,
+        partition_group_by: None | bool = None,
+        shrink_dtypes: None | bool = None,
+        float_total_order: None | bool = None,
     ) -> QueryOptFlags:
         """Update the current optimization flags."""
         if predicate_pushdown is not None:
@@ -133,6 +153,14 @@ class QueryOptFlags:
             self.check_order_observe = check_order_observe
         if fast_projection is not None:
             self.fast_projection = fast_projection
+        if share_scans is not None:
+            self.share_scans = share_scans
+        if partition_group_by is not None:
+            self.partition_group_by = partition_group_by
+        if shrink_dtypes is not None:
+            self.shrink_dtypes = shrink_dtypes
+        if float_total_order is not None:
+            self.float_total_order = float_total_order
 
         return self
 
@@ -236,6 +264,83 @@ class QueryOptFlags:
     def fast_projection(self, value: bool) -> None:
         self._pyoptflags.fast_projection = value
 
+    @property
+    def share_scans(self) -> bool:
+        """
+        Keep sharing scans between queries that filter them differently.
+
+        The shared scan applies the union of the filters. This only has an effect
+        together with `comm_subplan_elim`, e.g. in :func:`polars.collect_all`.
+        """
+        return self._pyoptflags.share_scans
+
+    @share_scans.setter
+    def share_scans(self, value: bool) -> None:
+        self._pyoptflags.share_scans = value
+
+    @property
+    def partition_group_by(self) -> bool:
+        """
+        Group hive partitioned scans per partition if grouping by partition columns.
+
+        The groups of different partitions can't have the same keys, so every partition
+        is grouped independently, without merging the groups of all partitions.
+
+        This is disabled by default, as every partition adds a scan and a group-by to
+        the plan. Scans with more than 256 partitions are grouped at once.
+        """
+        return self._pyoptflags.partition_group_by
+
+    @partition_group_by.setter
+    def partition_group_by(self, value: bool) -> None:
+        self._pyoptflags.partition_group_by = value
+
+    @property
+    def shrink_dtypes(self) -> bool:
+        """
+        Cast integer columns to the smallest data types that hold their values.
+
+        Only the integer columns of scans of a single parquet file are shrunk, as the
+        bounds of the values are taken from the statistics of the file. Scans of
+        multiple files, in-memory frames, and floating point or other columns are not
+        shrunk. Only the columns that reach the output unchanged are shrunk, possibly
+        through filters, sorts, projections and the `min`, `max`, `first` and `last`
+        aggregations of group-bys. Columns that are computed, e.g. by other
+        aggregations, are never shrunk, as their bounds are not known. The output of
+        sinks is not shrunk.
+
+        This is disabled by default, as it changes the data types of the output of the
+        query, which differ from the schema that is resolved with `collect_schema`.
+        """
+        return self._pyoptflags.shrink_dtypes
+
+    @shrink_dtypes.setter
+    def shrink_dtypes(self, value: bool) -> None:
+        self._pyoptflags.shrink_dtypes = value
+
+    @property
+    def float_total_order(self) -> bool:
+        """
+        Order floats by the IEEE 754 totalOrder predicate.
+
+        By default `-0.0` and `0.0` are equal, and all NaN values are equal to each
+        other and larger than all other values. With the totalOrder predicate, `-0.0`
+        is smaller than `0.0`, NaN values with a negative sign are smaller than all
+        other values, and NaN values are ordered by their payload.
+
+        This applies to sorts, comparisons, the `min` and `max` aggregations, the keys
+        of group-bys and unique, and the column keys of equi-joins, in both engines.
+        The keys of asof joins and expressions on join keys are not affected.
+
+        This is disabled by default, as it changes the results of the query. It is not
+        cleared by `no_optimizations`.
+        """
+        return self._pyoptflags.float_total_order
+
+    @float_total_order.setter
+    def float_total_order(self, value: bool) -> None:
+        self._pyoptflags.float_total_order = value
+
     def __str__(self) -> str:
         return f"""
 QueryOptFlags {{
@@ -251,6 +356,10 @@ QueryOptFlags {{
     cluster_with_columns: {self.cluster_with_columns}
     check_order_observe: {self.check_order_observe}
     fast_projection: {self.fast_projection}
+    share_scans: {self.share_scans}
+    partition_group_by: {self.partition_group_by}
+    shrink_dtypes: {self.shrink_dtypes}
+    float_total_order: {self.float_total_order}
 
     eager: {self._pyoptflags.eager}
     streaming: {self._pyoptflags.streaming}
@@ -0,0 +1,17 @@
+from __future__ import annotations
+
+from typing import NamedTuple
+
+
+class PartitionInfo(NamedTuple):
+    """
+    Source of a partition passed to the function of `LazyFrame.map_partitions`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+    """
+
+    index: int  #: Position of the source in the expanded sources of the scan.
+    count: int  #: Number of sources of the scan.
+    path: str | None  #: Path of the source, `None` for opened files and buffers.
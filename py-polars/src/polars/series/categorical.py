@@ -7,7 +7,9 @@ from polars._utils.wrap import wrap_s
 from polars.series.utils import expr_dispatch
 
 if TYPE_CHECKING:
-    from polars import Series
+    from collections.abc import Iterable
+
+    from polars import Enum, Series
     from polars._plr import PySeries
 
 
@@ -37,6 +39,61 @@ class CatNameSpace:
         ]
         """
 
+    def extend_categories(self, categories: Iterable[str]) -> Series:
+        """
+        Append categories to an Enum.
+
+        Categories which are already part of the Enum are ignored. The existing values
+        keep their physical representation, which is much cheaper than casting to an
+        extended Enum through strings.
+
+        Parameters
+        ----------
+        categories
+            The categories to append.
+
+        Examples
+        --------
+        >>> s = pl.Series(["x", "y"], dtype=pl.Enum(["x", "y"]))
+        >>> s.cat.extend_categories(["z", "x"]).dtype
+        Enum(categories=['x', 'y', 'z'])
+        """
+
+    def cast_with_added_categories(self, dtype: Enum) -> Series:
+        """
+        Cast an Enum to another Enum, adding the categories missing from it.
+
+        Unlike a regular cast, values which are not a category of `dtype` do not become
+        null: their categories are appended to the categories of `dtype`. The values
+        are remapped without going through their string representation.
+
+        Parameters
+        ----------
+        dtype
+            The Enum to cast to.
+
+        Examples
+        --------
+        >>> s = pl.Series(["x", "y"], dtype=pl.Enum(["x", "y"]))
+        >>> s.cat.cast_with_added_categories(pl.Enum(["y", "z"])).dtype
+        Enum(categories=['y', 'z', 'x'])
+        """
+
+    def remove_unused(self) -> Series:
+        """
+        Remove the categories of an Enum which do not occur in the Series.
+
+        The remaining categories keep their relative order. This is useful to shrink
+        the Enum after filtering.
+
+        Examples
+        --------
+        >>> s = pl.Series(["x", "y", "z"], dtype=pl.Enum(["x", "y", "z", "w"]))
+        >>> s.filter(s != "y").cat.remove_unused().dtype
+        Enum(categories=['x', 'z'])
+        """
+        return wrap_s(self._s.cat_remove_unused_categories())
+
     @deprecated(
         "`cat.is_local()` is deprecated; Categoricals no longer have a local scope. "
         "This method will be removed in Polars 2.0."
@@ -0,0 +1,117 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    from polars import Series
+    from polars._plr import PySeries
+    from polars._typing import IntoExpr
+
+
+@expr_dispatch
+class GeoNameSpace:
+    """Series.st namespace."""
+
+    _accessor = "st"
+
+    def __init__(self, series: Series) -> None:
+        self._s: PySeries = series._s
+
+    @unstable()
+    def x(self) -> Series:
+        """
+        Get the x coordinate of point geometries.
+
+        Empty points give null; other geometry types raise an error.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+        """
+
+    @unstable()
+    def y(self) -> Series:
+        """
+        Get the y coordinate of point geometries.
+
+        Empty points give null; other geometry types raise an error.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+        """
+
+    @unstable()
+    def distance(self, other: IntoExpr) -> Series:
+        """
+        Compute the minimum planar distance to the geometries of `other`.
+
+        The distance to an empty geometry is NaN.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Geometries to compute the distance to.
+        """
+
+    @unstable()
+    def contains(self, other: IntoExpr) -> Series:
+        """
+        Check whether the geometries contain the geometries of `other`.
+
+        Points on the boundary are considered to be contained.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Geometries to check for containment.
+        """
+
+    @unstable()
+    def within(self, other: IntoExpr) -> Series:
+        """
+        Check whether the geometries lie within the geometries of `other`.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Geometries that should contain the geometries.
+        """
+
+    @unstable()
+    def intersects_bbox(
+        self,
+        xmin: float | IntoExpr,
+        ymin: float | IntoExpr,
+        xmax: float | IntoExpr,
+        ymax: float | IntoExpr,
+    ) -> Series:
+        """
+        Check whether the bounding boxes of the geometries intersect a box.
+
+        Empty geometries never intersect the box.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        xmin, ymin, xmax, ymax
+            Bounds of the box.
+        """
@@ -17,6 +17,7 @@ if TYPE_CHECKING:
         IntoExpr,
         IntoExprColumn,
         ListToStructWidthStrategy,
+        ListZipOperation,
         NullBehavior,
     )
 
@@ -712,6 +713,84 @@ class ListNameSpace:
         ]
         """
 
+    def rolling_sum(self, window_size: int) -> Series:
+        """
+        Compute the sum of every window of consecutive values of every sublist.
+
+        The first `window_size - 1` values of every sublist, and the values whose
+        window contains a null, are null.
+
+        Parameters
+        ----------
+        window_size
+            The number of values in a window.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3], [4, 5, 6], [7]])
+        >>> s.list.rolling_sum(2)
+        shape: (3,)
+        Series: 'a' [list[i64]]
+        [
+            [null, 3, 5]
+            [null, 9, 11]
+            [null]
+        ]
+        """
+
+    def rolling_mean(self, window_size: int) -> Series:
+        """
+        Compute the mean of every window of consecutive values of every sublist.
+
+        The first `window_size - 1` values of every sublist, and the values whose
+        window contains a null, are null.
+
+        Parameters
+        ----------
+        window_size
+            The number of values in a window.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3], [4, 5, 6], [7]])
+        >>> s.list.rolling_mean(2)
+        shape: (3,)
+        Series: 'a' [list[f64]]
+        [
+            [null, 1.5, 2.5]
+            [null, 4.5, 5.5]
+            [null]
+        ]
+        """
+
+    def zip_with(self, other: Series, op: ListZipOperation) -> Series:
+        """
+        Combine the elements at the same position of the sublists of two list Series.
+
+        The sublists of a row must have the same length. A row is null if either of
+        its sublists is null.
+
+        Parameters
+        ----------
+        other
+            The other list Series.
+        op : {'add', 'sub', 'mul', 'min', 'max'}
+            The operation that combines two elements. `'min'` and `'max'` ignore
+            null elements.
+
+        Examples
+        --------
+        >>> a = pl.Series("a", [[1, 2], [3, 4, 5]])
+        >>> b = pl.Series("b", [[10, 20], [1, 5, 9]])
+        >>> a.list.zip_with(b, "max")
+        shape: (2,)
+        Series: 'a' [list[i64]]
+        [
+            [10, 20]
+            [3, 5, 9]
+        ]
+        """
+
     def shift(self, n: int | IntoExprColumn = 1) -> Series:
         """
         Shift list values by the given number of indices.
@@ -0,0 +1,122 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    from polars import Series
+    from polars._plr import PySeries
+
+
+@expr_dispatch
+class QuantNameSpace:
+    """Series.quant namespace."""
+
+    _accessor = "quant"
+
+    def __init__(self, series: Series) -> None:
+        self._s: PySeries = series._s
+
+    @unstable()
+    def drawdown(self) -> Series:
+        """
+        Compute the relative decline of every price from the running maximum.
+
+        The drawdown is `price / running_max - 1`; it is zero at a new maximum and
+        negative otherwise. Null values are ignored when computing the maximum.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> s = pl.Series("price", [100, 110, 99, 121, 110])
+        >>> s.quant.drawdown()
+        shape: (5,)
+        Series: 'price' [f64]
+        [
+            0.0
+            0.0
+            -0.1
+            0.0
+            -0.090909
+        ]
+        """
+
+    @unstable()
+    def max_drawdown(self) -> Series:
+        """
+        Compute the largest drawdown of the prices.
+
+        The result is the minimum of :meth:`drawdown`, so it is zero or negative.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> s = pl.Series("price", [100, 110, 99, 121, 110])
+        >>> s.quant.max_drawdown()
+        shape: (1,)
+        Series: 'price' [f64]
+        [
+            -0.1
+        ]
+        """
+
+    @unstable()
+    def hit_ratio(self) -> Series:
+        """
+        Compute the fraction of the returns that is positive.
+
+        Null values are ignored; the hit ratio of only null values is null.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> s = pl.Series("returns", [0.01, -0.02, 0.03, 0.02, None])
+        >>> s.quant.hit_ratio()
+        shape: (1,)
+        Series: 'returns' [f64]
+        [
+            0.75
+        ]
+        """
+
+    @unstable()
+    def sharpe(self, annualization: float = 252) -> Series:
+        """
+        Compute the Sharpe ratio of the returns.
+
+        The Sharpe ratio is the mean of the returns divided by their sample standard
+        deviation, multiplied by the square root of `annualization`. The returns are
+        expected to be in excess of the risk-free rate. Null values are ignored; with
+        fewer than two returns the result is null.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        annualization
+            The number of periods per year, e.g. 252 for daily returns or 12 for
+            monthly returns. Use 1 to get the Sharpe ratio per period.
+
+        Examples
+        --------
+        >>> s = pl.Series("returns", [0.01, -0.02, 0.03, 0.02])
+        >>> s.quant.sharpe()
+        shape: (1,)
+        Series: 'returns' [f64]
+        [
+            7.348469
+        ]
+        """
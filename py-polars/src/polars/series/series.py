@@ -104,8 +104,10 @@ from polars.series.binary import BinaryNameSpace
 from polars.series.categorical import CatNameSpace
 from polars.series.datetime import DateTimeNameSpace
 from polars.series.ext import ExtensionNameSpace
+from polars.series.geo import GeoNameSpace
 from polars.series.list import ListNameSpace
 from polars.series.plotting import SeriesPlot
+from polars.series.quant import QuantNameSpace
 from polars.series.string import StringNameSpace
 from polars.series.struct import StructNameSpace
 from polars.series.utils import expr_dispatch, get_ffi_func
@@ -270,6 +272,8 @@ class Series:
         "ext",
         "list",
         "plot",
+        "quant",
+        "st",
         "str",
         "struct",
     }
@@ -4719,6 +4723,82 @@ class Series:
             )
         ).to_series()
 
+    @unstable()
+    def is_outlier_mad(self, threshold: float = 3.5) -> Series:
+        """
+        Flag outliers by their modified z-score.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        A value is flagged if its absolute deviation from the median exceeds
+        `threshold` times the median absolute deviation (MAD), scaled by 1.4826 to be
+        consistent with the standard deviation of normally distributed data.
+
+        Parameters
+        ----------
+        threshold
+            Modified z-score above which a value is flagged.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> s = pl.Series("s", [1.0, 2.0, 3.0, 2.0, 100.0])
+        >>> s.is_outlier_mad()
+        shape: (5,)
+        Series: 's' [bool]
+        [
+            false
+            false
+            false
+            false
+            true
+        ]
+        """
+
+    @unstable()
+    def is_outlier_iqr(self, k: float = 1.5) -> Series:
+        """
+        Flag outliers by Tukey's fences.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        A value is flagged if it lies outside of `[q1 - k * iqr, q3 + k * iqr]`, with
+        `q1` and `q3` the first and third quartile and `iqr` the interquartile range
+        `q3 - q1`.
+
+        Parameters
+        ----------
+        k
+            Multiple of the interquartile range by which the quartiles are extended.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> s = pl.Series("s", [1.0, 2.0, 3.0, 2.0, 100.0])
+        >>> s.is_outlier_iqr()
+        shape: (5,)
+        Series: 's' [bool]
+        [
+            false
+            false
+            false
+            false
+            true
+        ]
+        """
+
     def to_numpy(
         self,
         *,
@@ -7871,6 +7951,106 @@ class Series:
         ]
         """
 
+    @unstable()
+    def rolling_is_outlier_mad(
+        self,
+        window_size: int,
+        threshold: float = 3.5,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Flag outliers by their modified z-score within a rolling window.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        threshold
+            Modified z-score above which a value is flagged.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        See Also
+        --------
+        Series.is_outlier_mad
+
+        Examples
+        --------
+        >>> pl.Series([1.0, 2.0, 3.0, 2.0, 50.0, 3.0]).rolling_is_outlier_mad(3)
+        shape: (6,)
+        Series: '' [bool]
+        [
+            null
+            null
+            false
+            false
+            true
+            false
+        ]
+        """
+
+    @unstable()
+    def rolling_is_outlier_iqr(
+        self,
+        window_size: int,
+        k: float = 1.5,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Flag outliers by Tukey's fences within a rolling window.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        k
+            Multiple of the interquartile range by which the quartiles are extended.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        See Also
+        --------
+        Series.is_outlier_iqr
+
+        Examples
+        --------
+        >>> pl.Series([1.0, 2.0, 3.0, 2.0, 50.0, 3.0]).rolling_is_outlier_iqr(4)
+        shape: (6,)
+        Series: '' [bool]
+        [
+            null
+            null
+            null
+            false
+            true
+            false
+        ]
+        """
+
     def sample(
         self,
         n: int | None = None,
@@ -8240,7 +8420,12 @@ class Series:
         ]
         """
 
-    def pct_change(self, n: int | IntoExprColumn = 1) -> Series:
+    def pct_change(
+        self,
+        n: int | IntoExprColumn = 1,
+        *,
+        fill_policy: FillNullStrategy | None = None,
+    ) -> Series:
         """
         Computes percentage change between values.
 
@@ -8253,11 +8438,15 @@ class Series:
         ----------
         n
             periods to shift for forming percent change.
+        fill_policy : {None, 'forward', 'backward', 'min', 'max', 'mean', 'zero', 'one'}
+            Strategy used to fill null values before computing the change; see
+            :meth:`fill_null`.
 
         Notes
         -----
-        Null values are preserved. If you're coming from pandas, this matches
-        their ``fill_method=None`` behaviour.
+        Null values are preserved by default. If you're coming from pandas, this
+        matches their ``fill_method=None`` behaviour, while `fill_policy="forward"`
+        matches ``fill_method="pad"``.
 
         Examples
         --------
@@ -8294,6 +8483,102 @@ class Series:
         ]
         """
 
+    def pct_change_by(
+        self,
+        by: IntoExpr,
+        offset: str | timedelta,
+        *,
+        fill_policy: FillNullStrategy | None = None,
+    ) -> Series:
+        """
+        Computes percentage change between values and the values `offset` earlier.
+
+        For every element, the change is computed versus the last element of which
+        `by` is at most `by - offset`; it is null if there is no such element.
+
+        Parameters
+        ----------
+        by
+            Times to compute the change by. Should be ``DateTime``, ``Date``,
+            ``UInt64``, ``UInt32``, ``Int64``, or ``Int32`` data type.
+        offset
+            How far back to look for the value to compare against; see
+            :meth:`Expr.pct_change_by` for the accepted durations.
+        fill_policy : {None, 'forward', 'backward', 'min', 'max', 'mean', 'zero', 'one'}
+            Strategy used to fill null values before computing the change; see
+            :meth:`fill_null`.
+
+        Examples
+        --------
+        >>> s = pl.Series([10, 11, 12, 15, 18])
+        >>> s.pct_change_by(pl.Series([0, 1, 4, 5, 7]), "3i")
+        shape: (5,)
+        Series: '' [f64]
+        [
+            null
+            null
+            0.090909
+            0.363636
+            0.5
+        ]
+        """
+
+    def log_return(self, n: int | IntoExprColumn = 1) -> Series:
+        """
+        Computes the logarithmic return between values.
+
+        The logarithmic return is `ln(x_t / x_{t-n})`, the natural logarithm of the
+        ratio between the current element and the most-recent non-null element at
+        least `n` period(s) before the current element.
+
+        Parameters
+        ----------
+        n
+            periods to shift for forming the return.
+
+        Examples
+        --------
+        >>> pl.Series([1, 2, 4, 8]).log_return()
+        shape: (4,)
+        Series: '' [f64]
+        [
+            null
+            0.693147
+            0.693147
+            0.693147
+        ]
+        """
+
+    def log_return_by(self, by: IntoExpr, offset: str | timedelta) -> Series:
+        """
+        Computes the logarithmic return between values and the values `offset` earlier.
+
+        For every element, the return is computed versus the last element of which
+        `by` is at most `by - offset`; it is null if there is no such element.
+
+        Parameters
+        ----------
+        by
+            Times to compute the return by. Should be ``DateTime``, ``Date``,
+            ``UInt64``, ``UInt32``, ``Int64``, or ``Int32`` data type.
+        offset
+            How far back to look for the value to compare against; see
+            :meth:`Expr.pct_change_by` for the accepted durations.
+
+        Examples
+        --------
+        >>> s = pl.Series([1, 2, 4, 8])
+        >>> s.log_return_by(pl.Series([0, 1, 3, 4]), "2i")
+        shape: (4,)
+        Series: '' [f64]
+        [
+            null
+            null
+            0.693147
+            1.386294
+        ]
+        """
+
     def skew(self, *, bias: bool = True) -> float | None:
         r"""
         Compute the sample skewness of a data set.
@@ -9462,6 +9747,16 @@ class Series:
         """Create an object namespace of all extension type related methods."""
         return ExtensionNameSpace(self)
 
+    @property
+    def st(self) -> GeoNameSpace:
+        """Create an object namespace of all spatial methods on geometries."""
+        return GeoNameSpace(self)
+
+    @property
+    def quant(self) -> QuantNameSpace:
+        """Create an object namespace of all performance statistics methods."""
+        return QuantNameSpace(self)
+
     @property
     @unstable()
     def plot(self) -> SeriesPlot:
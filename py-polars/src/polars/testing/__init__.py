@@ -1,5 +1,6 @@
 from polars.testing.asserts import (
     assert_frame_equal,
+    assert_frame_equal_by_key,
     assert_frame_not_equal,
     assert_series_equal,
     assert_series_not_equal,
@@ -7,6 +8,7 @@ from polars.testing.asserts import (
 
 __all__ = [
     "assert_frame_equal",
+    "assert_frame_equal_by_key",
     "assert_frame_not_equal",
     "assert_series_equal",
     "assert_series_not_equal",
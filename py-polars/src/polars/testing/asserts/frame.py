@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 import contextlib
-from typing import cast
+from typing import TYPE_CHECKING, cast
 
 from polars._utils.deprecation import deprecate_renamed_parameter
 from polars.dataframe import DataFrame
@@ -9,7 +9,13 @@ from polars.lazyframe import LazyFrame
 from polars.testing.asserts.utils import raise_assertion_error
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
-    from polars._plr import assert_dataframe_equal_py
+    from polars._plr import (
+        assert_dataframe_equal_by_key_py,
+        assert_dataframe_equal_py,
+    )
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
 
 
 def _assert_correct_input_type(
@@ -142,6 +148,82 @@ def assert_frame_equal(
     )
 
 
+def assert_frame_equal_by_key(
+    left: DataFrame | LazyFrame,
+    right: DataFrame | LazyFrame,
+    on: str | Sequence[str],
+    *,
+    max_differences: int = 10,
+) -> None:
+    """
+    Assert that the left and right frame contain the same rows, matched by key.
+
+    Unlike :func:`assert_frame_equal`, the order of the rows does not matter, and
+    the raised `AssertionError` lists the rows that differ, with the old and new
+    value of every column. This function is intended for use in unit tests.
+
+    .. warning::
+        This functionality is currently considered **unstable**. It may be
+        changed at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    left
+        The first DataFrame or LazyFrame to compare.
+    right
+        The second DataFrame or LazyFrame to compare.
+    on
+        Name(s) of the columns identifying a row. The keys must be unique in both
+        frames.
+    max_differences
+        The maximum number of differing rows to report.
+
+    See Also
+    --------
+    assert_frame_equal
+    DataFrame.diff_frames
+
+    Notes
+    -----
+    The frames must have the same columns and data types, in any order. Values are
+    compared exactly, with nulls being equal to each other.
+
+    Examples
+    --------
+    >>> from polars.testing import assert_frame_equal_by_key
+    >>> df1 = pl.DataFrame({"id": [1, 2, 3], "a": [1, 2, 3]})
+    >>> df2 = pl.DataFrame({"id": [3, 2, 4], "a": [3, 5, 4]})
+    >>> assert_frame_equal_by_key(df1, df2, on="id", max_differences=2)
+    Traceback (most recent call last):
+    ...
+    AssertionError: DataFrames are different (3 rows differ by key ["id"], showing the first 2)
+    shape: (2, 4)
+    ┌─────┬──────────┬───────┬───────┐
+    │ id  ┆ change   ┆ a_old ┆ a_new │
+    │ --- ┆ ---      ┆ ---   ┆ ---   │
+    │ i64 ┆ str      ┆ i64   ┆ i64   │
+    ╞═════╪══════════╪═══════╪═══════╡
+    │ 1   ┆ removed  ┆ 1     ┆ null  │
+    │ 2   ┆ modified ┆ 2     ┆ 5     │
+    └─────┴──────────┴───────┴───────┘
+    """  # noqa: W505
+    __tracebackhide__ = True
+
+    lazy = _assert_correct_input_type(left, right)
+    if lazy:
+        left, right = left.collect(), right.collect()  # type: ignore[union-attr]
+    left, right = cast("DataFrame", left), cast("DataFrame", right)
+
+    if isinstance(on, str):
+        on = [on]
+    assert_dataframe_equal_by_key_py(
+        left._df,
+        right._df,
+        key_columns=list(on),
+        max_differences=max_differences,
+    )
+
+
 @deprecate_renamed_parameter("check_dtype", "check_dtypes", version="0.20.31")
 @deprecate_renamed_parameter("rtol", "rel_tol", version="1.32.3")
 @deprecate_renamed_parameter("atol", "abs_tol", version="1.32.3")
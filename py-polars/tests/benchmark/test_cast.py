@@ -0,0 +1,29 @@
+"""Benchmark tests for casting strings to numbers."""
+
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+
+pytestmark = pytest.mark.benchmark()
+
+
+@pytest.fixture(scope="module")
+def number_strings() -> pl.DataFrame:
+    return pl.DataFrame(
+        {"int": pl.int_range(0, 2_000_000, eager=True) * 7919}
+    ).with_columns(
+        pl.col("int").cast(pl.String),
+        pl.format("{}.{}", pl.col("int") // 1000, pl.col("int") % 1000).alias("float"),
+    )
+
+
+def test_cast_string_to_int(number_strings: pl.DataFrame) -> None:
+    out = number_strings.select(pl.col("int").cast(pl.Int64))
+    assert out.null_count().item() == 0
+
+
+def test_cast_string_to_float(number_strings: pl.DataFrame) -> None:
+    out = number_strings.select(pl.col("float").cast(pl.Float64))
+    assert out.null_count().item() == 0
@@ -0,0 +1,53 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+
+@pytest.fixture
+def nodes() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "id": ["a", "b", "c", "d"],
+            "neighbors": [["b", "c"], ["a", "x"], [], None],
+            "x": pl.Series([1, None, 3, 4], dtype=pl.Int16),
+            "y": [1.5, 2.5, 3.5, 4.5],
+        }
+    )
+
+
+def test_aggregate_neighbors_sum(nodes: pl.DataFrame) -> None:
+    out = nodes.aggregate_neighbors("id", "neighbors", ["x", "y"])
+    expected = pl.DataFrame(
+        {
+            "id": ["a", "b", "c", "d"],
+            "x": [3, 1, 0, None],
+            "y": [6.0, 1.5, 0.0, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_aggregate_neighbors_mean(nodes: pl.DataFrame) -> None:
+    out = nodes.aggregate_neighbors("id", "neighbors", "x", aggregation="mean")
+    assert out["x"].to_list() == [3.0, 1.0, None, None]
+
+
+def test_aggregate_neighbors_top_k(nodes: pl.DataFrame) -> None:
+    out = nodes.aggregate_neighbors(
+        "id", "neighbors", ["x", "y"], aggregation="top_k", k=1
+    )
+    assert out.schema["x"] == pl.List(pl.Int16)
+    assert out["x"].to_list() == [[3], [1], [], None]
+    assert out["y"].to_list() == [[3.5], [1.5], [], None]
+
+    with pytest.raises(ValueError, match="`k` must be set"):
+        nodes.aggregate_neighbors("id", "neighbors", "x", aggregation="top_k")
+
+
+def test_aggregate_neighbors_duplicate_key() -> None:
+    nodes = pl.DataFrame({"id": [1, 1], "neighbors": [[1], []], "x": [1, 2]})
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="unique"):
+        nodes.aggregate_neighbors("id", "neighbors", "x")
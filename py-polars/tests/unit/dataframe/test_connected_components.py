@@ -0,0 +1,37 @@
+from __future__ import annotations
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+
+def test_connected_components() -> None:
+    edges = pl.DataFrame(
+        {
+            "src": ["a", "b", "d", "e", None],
+            "dst": ["b", "c", "d", "f", "g"],
+        }
+    )
+    out = edges.connected_components("src", "dst", degree=True)
+    idx_type = pl.get_index_type()
+    expected = pl.DataFrame(
+        {
+            "node": ["a", "b", "d", "e", "c", "f", "g"],
+            "component": [0, 0, 1, 2, 0, 2, 3],
+            "degree": [1, 2, 2, 1, 1, 1, 1],
+        },
+        schema_overrides={"component": idx_type, "degree": idx_type},
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_connected_components_supertype() -> None:
+    edges = pl.DataFrame(
+        {
+            "src": pl.Series([1, 2], dtype=pl.Int32),
+            "dst": pl.Series([2, 3], dtype=pl.Int64),
+        }
+    )
+    out = edges.connected_components("src", "dst")
+    assert out.schema == pl.Schema({"node": pl.Int64, "component": pl.get_index_type()})
+    assert out["component"].to_list() == [0, 0, 0]
+    assert edges.head(0).connected_components("src", "dst").is_empty()
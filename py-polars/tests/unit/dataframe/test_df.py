@@ -582,6 +582,28 @@ def test_gather_every_agg() -> None:
     )
     assert_frame_equal(out, expected)
 
+    out = (
+        df.group_by(pl.col("g"))
+        .agg(
+            offset=pl.col("a").gather_every(2, offset=1),
+            reversed=pl.col("a").reverse().gather_every(2),
+            first=pl.col("a").first().gather_every(2),
+            skipped=pl.col("a").first().gather_every(2, offset=1),
+        )
+        .sort("g")
+    )
+    expected = pl.DataFrame(
+        {
+            "g": [1, 2],
+            "offset": [["b"], ["e"]],
+            "reversed": [["c", "a"], ["f", "d"]],
+            "first": [["a"], ["d"]],
+            "skipped": [[], []],
+        },
+        schema_overrides={"skipped": pl.List(pl.String)},
+    )
+    assert_frame_equal(out, expected)
+
 
 def test_take_misc(fruits_cars: pl.DataFrame) -> None:
     df = fruits_cars
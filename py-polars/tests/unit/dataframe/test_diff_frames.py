@@ -0,0 +1,66 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, SchemaError
+from polars.testing import assert_frame_equal
+
+
+def test_diff_frames() -> None:
+    df1 = pl.DataFrame(
+        {
+            "k1": [1, 1, 2, 2, None],
+            "k2": ["a", "b", "a", "b", "a"],
+            "x": [1.0, 2.0, None, 4.0, 5.0],
+            "y": ["p", "q", "r", "s", None],
+        }
+    )
+    df2 = pl.DataFrame(
+        {
+            "y": ["s", "t", None, "q", "r"],
+            "x": [4.0, 6.0, 5.0, 2.5, None],
+            "k2": ["b", "c", "a", "b", "a"],
+            "k1": [2, 3, None, 1, 2],
+        }
+    )
+    expected = pl.DataFrame(
+        {
+            "k1": [1, 1, 3],
+            "k2": ["a", "b", "c"],
+            "change": ["removed", "modified", "added"],
+            "x_old": [1.0, 2.0, None],
+            "x_new": [None, 2.5, 6.0],
+            "y_old": ["p", "q", None],
+            "y_new": [None, "q", "t"],
+        }
+    )
+    assert_frame_equal(df1.diff_frames(df2, on=["k1", "k2"]), expected)
+
+    assert df1.diff_frames(df1, on=["k1", "k2"]).is_empty()
+    assert df1.diff_frames(df1, on=["k1", "k2"]).columns == expected.columns
+
+
+def test_diff_frames_nested() -> None:
+    df1 = pl.DataFrame({"id": ["a", "b"], "values": [[1, 2], [3]]})
+    df2 = pl.DataFrame({"id": ["a", "b"], "values": [[1, 2], [3, None]]})
+    expected = pl.DataFrame(
+        {
+            "id": ["b"],
+            "change": ["modified"],
+            "values_old": [[3]],
+            "values_new": [[3, None]],
+        }
+    )
+    assert_frame_equal(df1.diff_frames(df2, on="id"), expected)
+
+
+def test_diff_frames_invalid() -> None:
+    df = pl.DataFrame({"id": [1, 2], "a": [1, 2]})
+
+    with pytest.raises(ComputeError, match="1:1 validation"):
+        df.diff_frames(pl.DataFrame({"id": [1, 1], "a": [1, 2]}), on="id")
+    with pytest.raises(SchemaError, match="same columns"):
+        df.diff_frames(pl.DataFrame({"id": [1, 2], "a": [1.0, 2.0]}), on="id")
+    with pytest.raises(SchemaError, match="same columns"):
+        df.diff_frames(df.select("id"), on="id")
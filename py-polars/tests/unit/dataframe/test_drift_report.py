@@ -0,0 +1,56 @@
+from __future__ import annotations
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+
+def test_drift_report() -> None:
+    baseline = pl.DataFrame(
+        {
+            "x": [1.0, 2.0, 3.0, 4.0],
+            "s": ["a", "a", "b", None],
+            "b": [True, False, True, True],
+            "d": [1, 2, 3, 4],
+            "r": ["p", "q", "r", "s"],
+        }
+    )
+    current = pl.DataFrame(
+        {
+            "x": [2.0, 3.0, 4.0, None],
+            "s": ["a", "b", "b", "b"],
+            "b": [False, False, True, True],
+            "d": [1.0, 2.0, 3.0, 4.0],
+            "e": [1, 2, 3, 4],
+        }
+    )
+    expected = pl.DataFrame(
+        {
+            "column": ["x", "s", "b", "d", "r", "e"],
+            "status": [
+                "unchanged",
+                "unchanged",
+                "unchanged",
+                "dtype_changed",
+                "removed",
+                "added",
+            ],
+            "null_rate_delta": [0.25, -0.25, 0.0, 0.0, None, None],
+            "mean_delta": [0.5, None, None, 0.0, None, None],
+            "std_delta": [-0.2909944487358056, None, None, 0.0, None, None],
+            "psi": [
+                2.0271496162259326,
+                0.7465664455116896,
+                0.27465307216702745,
+                0.0,
+                None,
+                None,
+            ],
+        }
+    )
+    assert_frame_equal(baseline.drift_report(current), expected)
+
+
+def test_drift_report_empty() -> None:
+    df = pl.DataFrame({"x": [None, None]}, schema={"x": pl.Float64})
+    out = df.drift_report(df.clear())
+    assert out.row(0) == ("x", "unchanged", None, None, None, None)
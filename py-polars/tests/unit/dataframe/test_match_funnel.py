@@ -0,0 +1,65 @@
+from __future__ import annotations
+
+from datetime import datetime, timedelta
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal
+
+
+def test_match_funnel() -> None:
+    minutes = [0, 1, 2, 3, 4, 10, 20, 21, 40]
+    df = pl.DataFrame(
+        {
+            "user": [1, 2, 1, 1, 2, 1, 1, 1, 1],
+            "time": [datetime(2024, 1, 1, 0, m) for m in minutes],
+            "event": ["view", "view", "view", "cart", "cart"]
+            + ["buy", "view", "cart", "buy"],
+        }
+    )
+    steps = {
+        "view": pl.col("event") == "view",
+        "cart": pl.col("event") == "cart",
+        "buy": pl.col("event") == "buy",
+    }
+
+    out = df.match_funnel("time", steps, group_by="user", within=timedelta(minutes=10))
+    expected = pl.DataFrame(
+        {
+            "user": [1],
+            "match_id": pl.Series([0], dtype=pl.get_index_type()),
+            "view": [datetime(2024, 1, 1, 0, 2)],
+            "cart": [datetime(2024, 1, 1, 0, 3)],
+            "buy": [datetime(2024, 1, 1, 0, 10)],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    out = df.match_funnel("time", steps, group_by="user")
+    assert out["view"].dt.minute().to_list() == [2, 20]
+    assert out["buy"].dt.minute().to_list() == [10, 40]
+
+
+def test_match_funnel_later_start() -> None:
+    df = pl.DataFrame(
+        {
+            "time": [0, 1, 5, 6, 12],
+            "event": ["view", "cart", "view", "cart", "buy"],
+        }
+    )
+    steps = {
+        "view": pl.col("event") == "view",
+        "cart": pl.col("event") == "cart",
+        "buy": pl.col("event") == "buy",
+    }
+    out = df.match_funnel("time", steps, within=10)
+    assert out.select("view", "cart", "buy").rows() == [(5, 6, 12)]
+
+
+def test_match_funnel_unsorted() -> None:
+    df = pl.DataFrame({"time": [2, 1], "event": ["a", "b"]})
+    steps = {"a": pl.col("event") == "a", "b": pl.col("event") == "b"}
+    with pytest.raises(InvalidOperationError, match="sorted"):
+        df.match_funnel("time", steps)
@@ -14,6 +14,7 @@ from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
     from collections.abc import Callable
+    from pathlib import Path
 
 
 def test_categorical_full_outer_join() -> None:
@@ -1020,3 +1021,27 @@ def test_categorical_cast_from_invalid_int() -> None:
     s = pl.Series("a", [0, 1000, 2000, 3000]).cast(dt, strict=False)
     assert s.null_count() == 3
     assert_series_equal(s, pl.Series("a", ["test", None, None, None], dtype=dt))
+
+
+def test_categories_shared_mapping(tmp_path: Path) -> None:
+    cats = pl.Categories.random()
+    s = pl.Series(["a", "b", "c", "a"], dtype=pl.Categorical(cats))
+    path = tmp_path / "categories.bin"
+    cats.write_shared_mapping(path)
+
+    shared = pl.Categories.random()
+    shared.attach_shared_mapping(path)
+    assert shared["b"] == 1
+    assert shared[2] == "c"
+
+    out = pl.Series(["c", "d", None, "a"], dtype=pl.Categorical(shared))
+    assert out.to_physical().to_list() == [2, 3, None, 0]
+    assert out.to_list() == ["c", "d", None, "a"]
+    assert_series_equal(s.cast(pl.String), pl.Series(["a", "b", "c", "a"]))
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="already in use"):
+        shared.attach_shared_mapping(path)
+
+    path.write_bytes(b"not a mapping")
+    with pytest.raises(pl.exceptions.ComputeError, match="not a shared"):
+        pl.Categories.random().attach_shared_mapping(path)
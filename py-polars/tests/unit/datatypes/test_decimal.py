@@ -10,6 +10,7 @@ from random import choice, randrange, seed
 from typing import TYPE_CHECKING, Any, NamedTuple
 
 import pyarrow as pa
+import pyarrow.parquet as pq
 import pytest
 
 import polars as pl
@@ -18,6 +19,9 @@ from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
     from collections.abc import Callable
+    from pathlib import Path
+
+    from polars._typing import EngineType
 
 
 @pytest.fixture(scope="module")
@@ -309,6 +313,83 @@ def test_decimal_series_value_arithmetic() -> None:
     assert out6.to_list() == [D("-4.9"), D("5.1"), D("95.01")]
 
 
+def test_decimal_modulo() -> None:
+    s = pl.Series([D("7.5"), D("-7.5"), D("0.30"), None])
+    out = s % D("2")
+    assert out.dtype == pl.Decimal(scale=2)
+    assert out.to_list() == [D("1.5"), D("0.5"), D("0.3"), None]
+
+    out = s % pl.Series([D("-2"), D("2.25"), D("0.07"), D("1")])
+    assert out.dtype == pl.Decimal(scale=2)
+    assert out.to_list() == [D("-0.5"), D("1.5"), D("0.02"), None]
+
+    with pytest.raises(pl.exceptions.ComputeError, match="modulo by zero"):
+        s % D("0")
+
+
+def test_decimal_mean_sum_precision() -> None:
+    df = pl.DataFrame(
+        {"g": [1, 1, 2], "a": [D("1.01"), D("2.02"), D("3.04")]},
+        schema={"g": pl.Int64, "a": pl.Decimal(4, 2)},
+    )
+    expected = pl.DataFrame(
+        {"sum": [D("6.07")], "mean": [D("2.023333")]},
+        schema={"sum": pl.Decimal(38, 2), "mean": pl.Decimal(38, 6)},
+    )
+    for engine in ("in-memory", "streaming"):
+        out = (
+            df.lazy()
+            .select(sum=pl.sum("a"), mean=pl.mean("a"))
+            .collect(engine=engine)
+        )
+        assert_frame_equal(out, expected)
+
+        out = (
+            df.lazy()
+            .group_by("g")
+            .agg(sum=pl.sum("a"), mean=pl.mean("a"))
+            .sort("g")
+            .collect(engine=engine)
+        )
+        assert out.schema == {
+            "g": pl.Int64,
+            "sum": pl.Decimal(38, 2),
+            "mean": pl.Decimal(38, 6),
+        }
+        assert out["mean"].to_list() == [D("1.515"), D("3.04")]
+
+    assert df["a"].mean() == D("2.023333")
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_decimal_sum_overflow(engine: EngineType) -> None:
+    large = D("9" * 38)
+    df = pl.DataFrame(
+        {"g": [1, 1, 1], "a": [large, large, -large]},
+        schema={"g": pl.Int64, "a": pl.Decimal(38, 0)},
+    )
+    for agg in (pl.sum("a"), pl.mean("a")):
+        with pytest.raises(pl.exceptions.ComputeError, match="overflow in decimal sum"):
+            df.lazy().select(agg).collect(engine=engine)
+        with pytest.raises(pl.exceptions.ComputeError, match="overflow in decimal sum"):
+            df.lazy().group_by("g").agg(agg).collect(engine=engine)
+
+    # Sums that fit are exact, even if they don't fit in a float.
+    df = pl.DataFrame({"a": [large, D(1), -large]}, schema={"a": pl.Decimal(38, 0)})
+    assert df.lazy().select(pl.sum("a")).collect(engine=engine).item() == D(1)
+
+
+def test_decimal_median_exact() -> None:
+    values = [D("12345678901234567890.12"), D("12345678901234567890.13")]
+    s = pl.Series(values, dtype=pl.Decimal(22, 2))
+    assert s.median() == D("12345678901234567890.125000")
+
+    df = pl.DataFrame({"g": [1, 1], "a": s})
+    out = df.group_by("g").agg(pl.median("a"))
+    assert out.schema["a"] == pl.Decimal(38, 6)
+    assert out["a"].item() == D("12345678901234567890.125000")
+
+
 def test_decimal_aggregations() -> None:
     df = pl.DataFrame(
         {
@@ -336,8 +417,8 @@ def test_decimal_aggregations() -> None:
             "sum": [D("10.20"), D("9100.13")],
             "min": [D("0.10"), D("100.01")],
             "max": [D("10.10"), D("9000.12")],
-            "mean": [5.1, 4550.065],
-            "median": [5.1, 4550.065],
+            "mean": [D("5.100000"), D("4550.065000")],
+            "median": [D("5.100000"), D("4550.065000")],
         }
     )
     assert_frame_equal(result, expected)
@@ -354,8 +435,8 @@ def test_decimal_aggregations() -> None:
             "sum": [D("9110.33")],
             "min": [D("0.10")],
             "max": [D("9000.12")],
-            "mean": [2277.5825],
-            "median": [55.055],
+            "mean": [D("2277.582500")],
+            "median": [D("55.055000")],
         }
     )
     assert_frame_equal(res, expected)
@@ -780,6 +861,29 @@ def test_decimal32_decimal64_22946() -> None:
     )
 
 
+@pytest.mark.write_disk
+def test_decimal256_load(tmp_path: Path) -> None:
+    values = [D("1.25"), None, D("-12345678901234567890.50")]
+    tbl = pa.table({"a": pa.array(values, pa.decimal256(38, 2))})
+    expected = pl.Series("a", values, pl.Decimal(38, 2)).to_frame()
+    assert_frame_equal(pl.DataFrame(tbl), expected)
+
+    path = tmp_path / "data.parquet"
+    pq.write_table(tbl, path)
+    assert_frame_equal(pl.read_parquet(path), expected)
+    assert_frame_equal(pl.scan_parquet(path).collect(), expected)
+
+    tbl = pa.table({"a": pa.array(values, pa.decimal256(50, 2))})
+    with pytest.raises(
+        pl.exceptions.InvalidOperationError, match="decimals have at most 38 digits"
+    ):
+        pl.DataFrame(tbl)
+    with pytest.raises(
+        pl.exceptions.InvalidOperationError, match="decimals have at most 38 digits"
+    ):
+        pl.Schema(tbl.schema)
+
+
 def test_decimal_cast_limit() -> None:
     fits = pl.Series([10**38 - 1, -(10**38 - 1)])
     assert_series_equal(fits.cast(pl.Decimal(38, 0)).cast(pl.Int128), fits)
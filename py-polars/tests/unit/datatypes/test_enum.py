@@ -690,3 +690,29 @@ def test_read_enum_from_csv() -> None:
     read = pl.read_csv(f, schema=schema)
     assert read.schema == schema
     assert_frame_equal(df.cast(schema), read)  # type: ignore[arg-type]
+
+
+def test_enum_category_evolution() -> None:
+    s = pl.Series("a", ["c", None, "a"], dtype=pl.Enum(["a", "b", "c"]))
+
+    extended = s.cat.extend_categories(["d", "a"])
+    assert extended.dtype == pl.Enum(["a", "b", "c", "d"])
+    assert_series_equal(extended.to_physical(), s.to_physical())
+
+    cast = s.cat.cast_with_added_categories(pl.Enum(["c", "x"]))
+    assert cast.dtype == pl.Enum(["c", "x", "a", "b"])
+    assert cast.to_list() == ["c", None, "a"]
+
+    out = pl.DataFrame({"a": s}).select(
+        pl.col("a").cat.extend_categories(["d"]),
+        b=pl.col("a").cat.cast_with_added_categories(pl.Enum(["c", "x"])),
+    )
+    assert out.schema == {"a": extended.dtype, "b": cast.dtype}
+    assert_series_equal(out["a"], extended)
+
+    unused = s.cat.remove_unused()
+    assert unused.dtype == pl.Enum(["a", "c"])
+    assert unused.to_list() == ["c", None, "a"]
+
+    with pytest.raises(InvalidOperationError, match="only supported for Enum"):
+        pl.Series(["a"], dtype=pl.Categorical).cat.remove_unused()
@@ -7,7 +7,7 @@ from typing import TYPE_CHECKING
 import pytest
 
 import polars as pl
-from polars.testing import assert_frame_equal
+from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
     from polars._typing import JoinStrategy, PolarsDataType
@@ -39,6 +39,30 @@ pl.register_extension_type("testing.python_test_extension", PythonTestExtension)
 pl.register_extension_type("testing.test_storage_extension", as_storage=True)
 
 
+class Probability(pl.datatypes.BaseExtension):
+    """A probability, stored as a float between 0 and 1."""
+
+    def __init__(self) -> None:
+        super().__init__(name="testing.probability", storage=pl.Float64)
+
+    def _string_repr(self) -> str:
+        return "prob"
+
+    def ext_validate(self, storage: pl.Series) -> None:
+        if not storage.is_between(0.0, 1.0).all():
+            msg = "probabilities must be between 0 and 1"
+            raise ValueError(msg)
+
+    def ext_can_cast_from(self, dtype: PolarsDataType) -> bool:
+        return dtype.is_numeric()
+
+    def ext_can_cast_to(self, dtype: PolarsDataType) -> bool:
+        return dtype == pl.Float32
+
+
+pl.register_extension_type("testing.probability", Probability)
+
+
 def test_extension_df_constructor() -> None:
     df = pl.DataFrame(
         {"a": [1, 2, 3], "b": ["a", "b", "c"]},
@@ -111,6 +135,19 @@ def test_extension_ipc_roundtrip() -> None:
     assert_frame_equal(df, df_read)
 
 
+def test_extension_arrow_roundtrip() -> None:
+    df = ROUNDTRIP_DF
+    tbl = df.to_arrow()
+    assert_frame_equal(df, pl.from_arrow(tbl))  # type: ignore[arg-type]
+
+    # Nested extension types must also survive the import from record batches.
+    ext_name = "testing.python_test_extension"
+    struct_type = tbl.schema.field("i").type.storage_type
+    assert struct_type.field("b").type.extension_name == ext_name
+    df_read = pl.from_arrow(tbl.to_batches())
+    assert_frame_equal(df, df_read)  # type: ignore[arg-type]
+
+
 def test_to_from_storage_roundtrip() -> None:
     df = ROUNDTRIP_DF
     df_storage = df.select(pl.all().ext.storage())
@@ -158,3 +195,34 @@ def test_extension_join_payload(how: JoinStrategy) -> None:
     )
     print(result, expected)
     assert_frame_equal(result, expected, check_row_order=False)
+
+
+def test_extension_validation_and_casting() -> None:
+    s = pl.Series("p", [0.25, None, 1.0]).ext.to(Probability())
+    assert s.dtype == Probability()
+    assert "prob" in str(s.to_frame())
+
+    with pytest.raises(ValueError, match="between 0 and 1"):
+        pl.Series([0.5, 1.5]).ext.to(Probability())
+
+    # Casts to the extension type are validated.
+    assert_series_equal(pl.Series("p", [0.25, None, 1.0]).cast(Probability()), s)
+    assert_series_equal(
+        pl.Series("p", [0, None, 1]).cast(Probability()),
+        pl.Series("p", [0.0, None, 1.0]).ext.to(Probability()),
+    )
+    with pytest.raises(ValueError, match="between 0 and 1"):
+        pl.Series([2]).cast(Probability())
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        pl.Series(["0.5"]).cast(Probability())
+
+    assert_series_equal(s.cast(pl.Float64), s.ext.storage())
+    assert_series_equal(s.cast(pl.Float32), s.ext.storage().cast(pl.Float32))
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        s.cast(pl.String)
+
+    df = s.to_frame()
+    buffer = io.BytesIO()
+    df.write_parquet(buffer)
+    buffer.seek(0)
+    assert_frame_equal(pl.read_parquet(buffer), df)
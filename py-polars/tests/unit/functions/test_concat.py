@@ -412,3 +412,39 @@ def test_concat_with_empty_dataframes_nonstrict_25727() -> None:
         schema={"c": pl.Int64, "a": pl.Int64, "b": pl.String},
     )
     assert_frame_equal(result, expected)
+
+
+@pytest.mark.parametrize("lazy", [False, True])
+def test_concat_diagonal_relaxed_ordered(lazy: bool) -> None:
+    df1 = pl.DataFrame(
+        {"a": [1], "b": ["x"], "c": [1.5]},
+        schema={"a": pl.Int32, "b": pl.String, "c": pl.Float64},
+    )
+    df2 = pl.DataFrame({"c": [2.5], "a": [2], "b": ["y"]})
+    df3 = pl.DataFrame({"b": ["z"], "c": [None], "a": [None]})
+
+    frames = [df.lazy() if lazy else df for df in (df1, df2, df3)]
+    result = pl.concat(frames, how="diagonal_relaxed_ordered")
+    if isinstance(result, pl.LazyFrame):
+        result = result.collect()
+
+    expected = pl.DataFrame(
+        {"a": [1, 2, None], "b": ["x", "y", "z"], "c": [1.5, 2.5, None]},
+        schema={"a": pl.Int64, "b": pl.String, "c": pl.Float64},
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_concat_diagonal_relaxed_ordered_mismatch() -> None:
+    df1 = pl.DataFrame({"a": [1], "b": ["x"]})
+    df2 = pl.DataFrame({"b": ["y"], "a": [2]})
+    df3 = pl.DataFrame({"b": ["z"], "c": [3]})
+    df4 = pl.DataFrame({"a": [4], "b": ["w"], "d": [True]})
+
+    with pytest.raises(pl.exceptions.SchemaError) as exc:
+        pl.concat([df1, df2, df3, df4], how="diagonal_relaxed_ordered")
+
+    msg = str(exc.value)
+    assert "input 1" not in msg
+    assert "input 2: missing columns [\"a\"]; unexpected columns [\"c\"]" in msg
+    assert "input 3: unexpected columns [\"d\"]" in msg
@@ -0,0 +1,71 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+@pytest.fixture
+def df() -> pl.DataFrame:
+    return pl.DataFrame({"a": [1, -1, 5, None], "b": ["x", "y", "", "z"]})
+
+
+def test_rule_failures_bitmask(df: pl.DataFrame) -> None:
+    out = df.lazy().select(
+        pl.rule_failures(
+            pl.col("a") > 0,
+            b_nonempty=pl.col("b").str.len_bytes() > 0,
+            small=pl.col("a") < 3,
+        )
+    )
+    assert out.collect_schema() == {"rule_failures": pl.UInt64}
+    expected = pl.DataFrame(
+        {"rule_failures": [0, 1, 6, 5]}, schema={"rule_failures": pl.UInt64}
+    )
+    assert_frame_equal(out.collect(), expected)
+
+    # Scalar rules are broadcast.
+    out = df.select(pl.rule_failures(pl.col("a") > 0, pl.lit(False)).alias("f"))
+    assert out["f"].to_list() == [2, 3, 2, 3]
+
+
+def test_rule_failures_names(df: pl.DataFrame) -> None:
+    out = df.select(
+        pl.rule_failures(
+            pl.col("a") > 0,
+            b_nonempty=pl.col("b").str.len_bytes() > 0,
+            output="names",
+        )
+    )
+    expected = pl.Series(
+        "rule_failures", [[], ["a"], ["b_nonempty"], ["a"]], dtype=pl.List(pl.String)
+    )
+    assert_series_equal(out.to_series(), expected)
+
+
+def test_rule_failures_many_rules() -> None:
+    df = pl.DataFrame({"x": range(100)})
+    rules = [(pl.col("x") != i).alias(f"rule_{i}") for i in range(70)]
+
+    out = df.lazy().select(pl.rule_failures(rules))
+    assert out.collect_schema() == {"rule_failures": pl.Array(pl.UInt64, 2)}
+    s = out.collect().to_series()
+    assert s[3].to_list() == [1 << 3, 0]
+    assert s[65].to_list() == [0, 1 << 1]
+    assert s[80].to_list() == [0, 0]
+
+    names = df.select(pl.rule_failures(rules, output="names")).to_series()
+    assert names[65].to_list() == ["rule_65"]
+    assert names[80].to_list() == []
+
+
+def test_rule_failures_errors(df: pl.DataFrame) -> None:
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="Boolean"):
+        df.select(pl.rule_failures(pl.col("a")))
+
+    with pytest.raises(pl.exceptions.DuplicateError, match="not unique"):
+        df.select(pl.rule_failures(pl.col("a") > 0, pl.col("a") < 3, output="names"))
+
+    with pytest.raises(ValueError, match="`output` must be one of"):
+        df.select(pl.rule_failures(pl.col("a") > 0, output="struct"))  # type: ignore[arg-type]
@@ -0,0 +1,133 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_frame_equal
+
+
+@pytest.fixture
+def df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "b", "b"],
+            "x": [1.0, 2.0, 4.0, 3.0, 5.0],
+            "w": [1, 1, 2, 3, 1],
+        }
+    )
+
+
+def test_weighted_group_by(df: pl.DataFrame) -> None:
+    out = df.lazy().group_by("g", maintain_order=True).agg(
+        mean=pl.weighted_mean("x", "w"),
+        var=pl.weighted_var("x", "w"),
+        var0=pl.weighted_var("x", "w", ddof=0),
+        median=pl.weighted_quantile("x", "w", 0.5),
+    )
+    assert out.collect_schema() == {
+        "g": pl.String,
+        "mean": pl.Float64,
+        "var": pl.Float64,
+        "var0": pl.Float64,
+        "median": pl.Float64,
+    }
+    expected = pl.DataFrame(
+        {
+            "g": ["a", "b"],
+            "mean": [2.75, 3.5],
+            "var": [2.25, 1.0],
+            "var0": [1.6875, 0.75],
+            "median": [2.0, 3.0],
+        }
+    )
+    assert_frame_equal(out.collect(), expected)
+
+
+def test_weighted_select(df: pl.DataFrame) -> None:
+    out = df.select(
+        pl.weighted_mean("x", "w"),
+        pl.weighted_mean("x", pl.lit(2)).alias("mean_lit"),
+        pl.weighted_quantile("x", "w", 0.0).alias("q0"),
+        pl.weighted_quantile("x", "w", 1.0).alias("q1"),
+    )
+    expected = pl.DataFrame({"x": [3.125], "mean_lit": [3.0], "q0": [1.0], "q1": [5.0]})
+    assert_frame_equal(out, expected)
+
+
+def test_weighted_nulls() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "a", "b", "b"],
+            "x": [1.0, None, 3.0, 100.0, 1.0, None],
+            "w": [1.0, 5.0, 1.0, None, None, 2.0],
+        }
+    )
+    out = df.group_by("g", maintain_order=True).agg(
+        mean=pl.weighted_mean("x", "w"),
+        var=pl.weighted_var("x", "w"),
+        median=pl.weighted_quantile("x", "w", 0.5),
+    )
+    expected = pl.DataFrame(
+        {
+            "g": ["a", "b"],
+            "mean": [2.0, None],
+            "var": [2.0, None],
+            "median": [1.0, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_weighted_zero_weights() -> None:
+    df = pl.DataFrame({"x": [1.0, 2.0, 3.0], "w": [0.0, 1.0, 0.0]})
+    out = df.select(
+        mean=pl.weighted_mean("x", "w"),
+        var=pl.weighted_var("x", "w", ddof=0),
+        q0=pl.weighted_quantile("x", "w", 0.0),
+        q1=pl.weighted_quantile("x", "w", 1.0),
+        empty=pl.weighted_mean("x", pl.lit(0.0)),
+    )
+    expected = pl.DataFrame(
+        {"mean": [2.0], "var": [0.0], "q0": [2.0], "q1": [2.0], "empty": [None]},
+        schema_overrides={"empty": pl.Float64},
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_weighted_over(df: pl.DataFrame) -> None:
+    out = df.select(pl.weighted_mean("x", "w").over("g"))
+    assert out["x"].to_list() == [2.75, 2.75, 2.75, 3.5, 3.5]
+
+
+def test_weighted_rolling(df: pl.DataFrame) -> None:
+    out = (
+        df.with_row_index("t")
+        .with_columns(pl.col("t").cast(pl.Int64))
+        .rolling("t", period="2i")
+        .agg(pl.weighted_mean("x", "w"))
+    )
+    expected = pl.DataFrame({"t": [0, 1, 2, 3, 4], "x": [1.0, 1.5, 10 / 3, 3.4, 3.5]})
+    assert_frame_equal(out, expected)
+
+
+def test_weighted_group_by_dynamic(df: pl.DataFrame) -> None:
+    out = (
+        df.with_row_index("t")
+        .with_columns(pl.col("t").cast(pl.Int64))
+        .group_by_dynamic("t", every="2i")
+        .agg(pl.weighted_mean("x", "w"))
+    )
+    expected = pl.DataFrame({"t": [0, 2, 4], "x": [1.5, 3.4, 5.0]})
+    assert_frame_equal(out, expected)
+
+
+def test_weighted_errors(df: pl.DataFrame) -> None:
+    with pytest.raises(InvalidOperationError, match="non-negative weights"):
+        df.select(pl.weighted_mean("x", -pl.col("w")))
+    with pytest.raises(InvalidOperationError, match="got NaN"):
+        df.select(pl.weighted_mean("x", pl.col("w").cast(pl.Float64) * float("nan")))
+    with pytest.raises(InvalidOperationError, match="numeric"):
+        df.select(pl.weighted_mean("g", "w"))
+    with pytest.raises(ComputeError, match="between 0.0 and 1.0"):
+        df.select(pl.weighted_quantile("x", "w", 1.5))
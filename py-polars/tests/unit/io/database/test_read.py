@@ -497,6 +497,47 @@ def test_read_database_iter_batches(
     assert df_empty["date"].to_list() == []
 
 
+@pytest.mark.write_disk
+@pytest.mark.skipif(
+    sys.platform == "win32",
+    reason="adbc_driver_sqlite not available on Windows",
+)
+def test_scan_database(tmp_sqlite_db: Path) -> None:
+    uri = f"sqlite:///{tmp_sqlite_db}"
+    query = "SELECT * FROM test_data"
+    expected = pl.read_database_uri(query, uri, engine="adbc")
+
+    lf = pl.scan_database(query, uri)
+    assert lf.collect_schema() == expected.schema
+    assert_frame_equal(lf.collect(), expected)
+
+    # projection is pushed down into the query
+    assert_frame_equal(
+        lf.select("name", "id").filter(pl.col("id") > 1).collect(),
+        expected.select("name", "id").filter(pl.col("id") > 1),
+    )
+    assert_frame_equal(lf.head(1).collect(), expected.head(1))
+
+    # the schema of a query without rows is known
+    lf_empty = pl.scan_database(f"{query} WHERE id < 0", uri)
+    assert lf_empty.collect_schema().names() == expected.columns
+    assert lf_empty.collect().height == 0
+
+    # partitioned reads return every row once
+    for partition_range in (None, (1, 2), (0, 10)):
+        df = pl.scan_database(
+            query,
+            uri,
+            partition_on="id",
+            partition_range=partition_range,
+            partition_num=2,
+        ).collect()
+        assert_frame_equal(df.sort("id"), expected)
+
+    with pytest.raises(ValueError, match="partition_on"):
+        pl.scan_database(query, uri, partition_num=2)
+
+
 def test_read_database_alchemy_selectable(tmp_sqlite_db: Path) -> None:
     # various flavours of alchemy connection
     alchemy_engine = create_engine(f"sqlite:///{tmp_sqlite_db}")
@@ -367,3 +367,47 @@ def test_write_database_adbc_temporary_table() -> None:
 
     if hasattr(conn, "close"):
         conn.close()
+
+
+@pytest.mark.write_disk
+@pytest.mark.skipif(sys.platform == "win32", reason="adbc not available on Windows")
+def test_sink_database_adbc(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    uri = f"sqlite:///{tmp_path}/test_sink.db"
+    lf = pl.LazyFrame(
+        {
+            "id": [1, 2, 3],
+            "tags": [["a", "b"], [], None],
+            "point": [{"x": 1, "y": 2}, {"x": 3, "y": None}, None],
+            "kind": pl.Series(["u", "v", "u"], dtype=pl.Categorical),
+            "price": pl.Series(["1.50", "2.25", None], dtype=pl.Decimal(10, 2)),
+        }
+    )
+    assert lf.sink_database("test_sink", uri, chunk_size=2) == 3
+
+    expected = pl.DataFrame(
+        {
+            "id": [1, 2, 3],
+            "tags": ['["a","b"]', "[]", None],
+            "point": ['{"x":1,"y":2}', '{"x":3,"y":null}', None],
+            "kind": ["u", "v", "u"],
+            "price": [1.5, 2.25, None],
+        }
+    )
+    query = "SELECT * FROM test_sink ORDER BY id"
+    assert_frame_equal(pl.read_database_uri(query, uri, engine="adbc"), expected)
+
+    # the table exists; nothing is written
+    with pytest.raises(Exception, match="test_sink"):
+        lf.sink_database("test_sink", uri)
+    assert_frame_equal(pl.read_database_uri(query, uri, engine="adbc"), expected)
+
+    assert lf.head(1).sink_database("test_sink", uri, if_table_exists="append") == 1
+    assert pl.read_database_uri(query, uri, engine="adbc").height == 4
+
+    # an empty result still creates the table
+    lf.clear().sink_database("test_sink", uri, if_table_exists="replace")
+    assert pl.read_database_uri(query, uri, engine="adbc").height == 0
+
+    with pytest.raises(ValueError, match="`if_table_exists` must be one of"):
+        lf.sink_database("test_sink", uri, if_table_exists="merge")  # type: ignore[arg-type]
@@ -9,7 +9,7 @@ import zlib
 from datetime import date, datetime, time, timedelta, timezone
 from decimal import Decimal as D
 from tempfile import NamedTemporaryFile
-from typing import TYPE_CHECKING, TypedDict
+from typing import TYPE_CHECKING, Any, TypedDict
 
 import numpy as np
 import pyarrow as pa
@@ -18,7 +18,12 @@ import zstandard
 
 import polars as pl
 from polars._utils.various import normalize_filepath
-from polars.exceptions import ComputeError, InvalidOperationError, NoDataError
+from polars.exceptions import (
+    ComputeError,
+    DuplicateError,
+    InvalidOperationError,
+    NoDataError,
+)
 from polars.io.csv import BatchedCsvReader
 from polars.testing import assert_frame_equal, assert_series_equal
 
@@ -1506,6 +1511,60 @@ def test_duplicated_columns() -> None:
     assert pl.read_csv(csv.encode(), new_columns=new).columns == new
 
 
+@pytest.mark.parametrize("read_fn", ["read_csv", "scan_csv"])
+def test_column_name_policy(read_fn: str) -> None:
+    csv = b"a,b,a\n1,2,3\n"
+
+    def read(source: bytes, **kwargs: Any) -> pl.DataFrame:
+        return getattr(pl, read_fn)(source, **kwargs).lazy().collect()
+
+    assert read(csv).columns == ["a", "b", "a_duplicated_0"]
+    assert_frame_equal(
+        read(csv, column_name_policy="take_last"),
+        pl.DataFrame({"b": [2], "a": [3]}),
+    )
+    assert_frame_equal(
+        read(csv, column_name_policy="take_last", columns=["a"]),
+        pl.DataFrame({"a": [3]}),
+    )
+    with pytest.raises(DuplicateError, match="'a' has more than one occurrence"):
+        read(csv, column_name_policy="raise")
+    with pytest.raises(ComputeError, match="column 2 of the CSV header has an empty"):
+        read(b"a,,b\n1,2,3\n", column_name_policy="raise")
+
+    # Empty names are named by their position.
+    assert read(b"a,,\n1,2,3\n").columns == ["a", "column_2", "column_3"]
+    assert_frame_equal(
+        read(b",a,,a\n1,2,3,4\n", column_name_policy="take_last"),
+        pl.DataFrame({"column_1": [1], "column_3": [3], "a": [4]}),
+    )
+
+    # Dropped columns don't clash with the names of the header.
+    assert_frame_equal(
+        read(b"a,a_0,a\n1,2,3\n", column_name_policy="take_last"),
+        pl.DataFrame({"a_0": [2], "a": [3]}),
+    )
+
+
+@pytest.mark.parametrize("read_fn", ["read_csv", "scan_csv"])
+def test_normalize_column_names(read_fn: str) -> None:
+    # The same name, with a combining accent and precomposed.
+    csv = "e\u0301,\u00e9\n1,2\n".encode()
+
+    def read(**kwargs: Any) -> pl.DataFrame:
+        return getattr(pl, read_fn)(csv, **kwargs).lazy().collect()
+
+    assert read().columns == ["e\u0301", "\u00e9"]
+    assert read(normalize_column_names=True).columns == [
+        "\u00e9",
+        "\u00e9_duplicated_0",
+    ]
+    assert_frame_equal(
+        read(normalize_column_names=True, column_name_policy="take_last"),
+        pl.DataFrame({"\u00e9": [2]}),
+    )
+
+
 def test_error_message() -> None:
     data = io.StringIO("target,wind,energy,miso\n1,2,3,4\n1,2,1e5,1\n")
     with pytest.raises(
@@ -13,7 +13,11 @@ import pyarrow.parquet as pq
 import pytest
 
 import polars as pl
-from polars.exceptions import ComputeError, SchemaFieldNotFoundError
+from polars.exceptions import (
+    ComputeError,
+    InvalidOperationError,
+    SchemaFieldNotFoundError,
+)
 from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
@@ -1192,3 +1196,87 @@ def test_hive_filter_in_ir(
         pl.scan_parquet(tmp_path).tail(1).filter(pl.col("a") == 1).collect(),
         pl.DataFrame(schema={"x": pl.Int64, "a": pl.Int64}),
     )
+
+
+@pytest.mark.write_disk
+def test_hive_partition_group_by(tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "day": [1, 1, 2, 2, 3],
+            "region": ["a", "b", "a", "a", "b"],
+            "x": [1, 2, 3, 4, 5],
+        }
+    )
+    df.write_parquet(tmp_path, partition_by=["day", "region"])
+    lf = pl.scan_parquet(tmp_path)
+    optimizations = pl.QueryOptFlags(partition_group_by=True)
+
+    q = lf.group_by("day").agg(pl.col("x").sum())
+    assert q.explain(optimizations=optimizations).count("PLAN") == 3
+    expected = pl.DataFrame({"day": [1, 2, 3], "x": [3, 7, 5]})
+    assert_frame_equal(q.collect(optimizations=optimizations).sort("day"), expected)
+    assert_frame_equal(
+        q.collect(engine="streaming", optimizations=optimizations).sort("day"),
+        expected,
+    )
+
+    q = lf.group_by("region", "day").agg(pl.col("x").sum())
+    assert q.explain(optimizations=optimizations).count("PLAN") == 4
+    assert_frame_equal(
+        q.collect(optimizations=optimizations).sort("region", "day"),
+        pl.DataFrame(
+            {"region": ["a", "a", "b", "b"], "day": [1, 2, 1, 3], "x": [1, 7, 2, 5]}
+        ),
+    )
+
+    # The groups of a partition can't be merged with those of other partitions.
+    for q in [
+        lf.group_by("x").len(),
+        lf.group_by("day", maintain_order=True).len(),
+        lf.with_row_index().group_by("day").agg(pl.col("index").min()),
+    ]:
+        assert "UNION" not in q.explain(optimizations=optimizations)
+
+    # Disabled by default.
+    q = lf.group_by("day").agg(pl.col("x").sum())
+    assert "UNION" not in q.explain()
+    assert_frame_equal(q.collect().sort("day"), expected)
+
+
+def test_hive_execute_per_partition(tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "day": [1, 1, 2, 2, 3],
+            "region": ["a", "b", "a", "a", "b"],
+            "x": [1, 2, 3, 4, 5],
+        }
+    )
+    df.write_parquet(tmp_path, partition_by=["day", "region"])
+    lf = pl.scan_parquet(tmp_path)
+
+    q = lf.select(pl.col("x").sum(), pl.len())
+    results = {
+        partition["day"]: out.rows()
+        for partition, out in q.execute_per_partition("day", concurrency=2)
+    }
+    assert results == {1: [(3, 2)], 2: [(7, 2)], 3: [(5, 1)]}
+
+    q = lf.select(pl.len())
+    results = [
+        (partition, out.item())
+        for partition, out in q.execute_per_partition(["day", "region"])
+    ]
+    results.sort(key=lambda r: (r[0]["day"], r[0]["region"]))
+    assert results == [
+        ({"day": 1, "region": "a"}, 1),
+        ({"day": 1, "region": "b"}, 1),
+        ({"day": 2, "region": "a"}, 2),
+        ({"day": 3, "region": "b"}, 1),
+    ]
+
+    with pytest.raises(InvalidOperationError, match="exactly one scan"):
+        lf.execute_per_partition("x")
+    with pytest.raises(InvalidOperationError, match="exactly one scan"):
+        lf.join(lf, on="x").execute_per_partition("day")
+    with pytest.raises(InvalidOperationError, match="row index"):
+        pl.scan_parquet(tmp_path, row_index_name="i").execute_per_partition("day")
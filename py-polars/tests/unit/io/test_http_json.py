@@ -0,0 +1,118 @@
+from __future__ import annotations
+
+import json
+import threading
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+from typing import TYPE_CHECKING, Any
+from urllib.parse import parse_qs, urlparse
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+RECORDS = [{"id": i, "name": f"item-{i}"} for i in range(5)]
+
+
+class _ApiHandler(BaseHTTPRequestHandler):
+    def do_GET(self) -> None:
+        url = urlparse(self.path)
+        query = {k: v[0] for k, v in parse_qs(url.query).items()}
+        headers: dict[str, str] = {}
+
+        if url.path == "/single":
+            body: Any = RECORDS
+        elif url.path == "/next_url":
+            cursor = int(query.get("cursor", 0))
+            page = RECORDS[cursor : cursor + 2]
+            next_url = f"/next_url?cursor={cursor + 2}" if cursor + 2 < 5 else None
+            body = {"data": page, "next": next_url}
+        elif url.path == "/link_header":
+            cursor = int(query.get("cursor", 0))
+            body = RECORDS[cursor : cursor + 2]
+            if cursor + 2 < 5:
+                headers["Link"] = f'</link_header?cursor={cursor + 2}>; rel="next"'
+        elif url.path == "/page":
+            page = int(query["p"])
+            body = RECORDS[(page - 1) * 2 : page * 2]
+        elif url.path == "/offset":
+            offset, limit = int(query["offset"]), int(query["limit"])
+            body = RECORDS[offset : offset + limit]
+        elif url.path == "/auth":
+            if self.headers.get("Authorization") != "Bearer token":
+                self.send_error(401)
+                return
+            body = RECORDS
+        else:
+            self.send_error(404)
+            return
+
+        payload = json.dumps(body).encode()
+        self.send_response(200)
+        self.send_header("Content-Type", "application/json")
+        self.send_header("Content-Length", str(len(payload)))
+        for name, value in headers.items():
+            self.send_header(name, value)
+        self.end_headers()
+        self.wfile.write(payload)
+
+    def log_message(self, format: str, *args: Any) -> None:
+        pass
+
+
+@pytest.fixture(scope="module")
+def api_url() -> Iterator[str]:
+    server = ThreadingHTTPServer(("127.0.0.1", 0), _ApiHandler)
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    yield f"http://127.0.0.1:{server.server_address[1]}"
+    server.shutdown()
+
+
+@pytest.mark.parametrize(
+    ("path", "pagination", "records_path"),
+    [
+        ("/single", None, None),
+        ("/next_url", {"type": "next_url", "pointer": "/next"}, "/data"),
+        ("/link_header", {"type": "link_header"}, None),
+        ("/page", {"type": "page", "param": "p"}, None),
+        ("/offset", {"type": "offset", "limit": 2}, None),
+    ],
+)
+def test_scan_http_json_pagination(
+    api_url: str,
+    path: str,
+    pagination: dict[str, Any] | None,
+    records_path: str | None,
+) -> None:
+    lf = pl.scan_http_json(
+        api_url + path, pagination, records_path=records_path, infer_schema_length=1
+    )
+    assert_frame_equal(lf.collect(), pl.DataFrame(RECORDS))
+
+
+def test_scan_http_json_options(api_url: str) -> None:
+    schema = {"id": pl.Int32, "name": pl.String}
+    lf = pl.scan_http_json(
+        f"{api_url}/page", {"type": "page", "param": "p"}, schema=schema, max_pages=2
+    )
+    assert lf.collect_schema() == pl.Schema(schema)
+    assert_frame_equal(lf.collect(), pl.DataFrame(RECORDS[:4], schema=schema))
+
+    lf = pl.scan_http_json(f"{api_url}/auth", headers={"Authorization": "Bearer token"})
+    assert lf.select(pl.len()).collect().item() == 5
+
+    with pytest.raises(pl.exceptions.ComputeError, match="401"):
+        pl.scan_http_json(f"{api_url}/auth")
+
+
+def test_scan_http_json_invalid_pagination() -> None:
+    with pytest.raises(ValueError, match="invalid pagination type"):
+        pl.scan_http_json("http://localhost", {"type": "cursor"})
+    with pytest.raises(ValueError, match="unexpected keys"):
+        pl.scan_http_json("http://localhost", {"type": "page", "limit": 10})
+    with pytest.raises(ValueError, match="must be positive"):
+        pl.scan_http_json("http://localhost", {"type": "offset", "limit": 0})
@@ -253,6 +253,38 @@ def test_compressed_simple(compression: IpcCompression, stream: bool) -> None:
     assert_frame_equal(df_read, df)
 
 
+@pytest.mark.parametrize("zstd_dictionary_size", [None, 1024])
+def test_ipc_zstd_compression_level_and_dictionary(
+    zstd_dictionary_size: int | None,
+) -> None:
+    df = pl.DataFrame(
+        {
+            "id": range(50_000),
+            "name": [f"user-{i % 997}@example.com" for i in range(50_000)],
+        }
+    )
+
+    f = io.BytesIO()
+    df.write_ipc(
+        f,
+        compression="zstd",
+        compression_level=19,
+        zstd_dictionary_size=zstd_dictionary_size,
+        record_batch_size=10_000,
+    )
+    f.seek(0)
+    assert_frame_equal(pl.read_ipc(f), df)
+
+    # Too little data to train a dictionary on falls back to plain zstd.
+    f = io.BytesIO()
+    df.head(1).write_ipc(f, compression="zstd", zstd_dictionary_size=1024)
+    f.seek(0)
+    assert_frame_equal(pl.read_ipc(f), df.head(1))
+
+    with pytest.raises(ValueError, match="compression_level"):
+        df.write_ipc(io.BytesIO(), compression="lz4", compression_level=3)
+
+
 @pytest.mark.parametrize("compression", COMPRESSIONS)
 def test_ipc_schema(compression: IpcCompression) -> None:
     schema = {
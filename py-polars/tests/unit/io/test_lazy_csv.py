@@ -2,6 +2,8 @@ from __future__ import annotations
 
 import io
 import tempfile
+import threading
+import time
 from collections import OrderedDict
 from pathlib import Path
 
@@ -9,7 +11,7 @@ import numpy as np
 import pytest
 
 import polars as pl
-from polars.exceptions import ComputeError, ShapeError
+from polars.exceptions import ComputeError, InvalidOperationError, ShapeError
 from polars.testing import assert_frame_equal
 
 
@@ -538,3 +540,38 @@ def test_csv_io_object_utf8_23629() -> None:
         f_str.seek(0)
         df_str = pl.read_csv(f_str)
         assert_frame_equal(df, df_str)
+
+
+@pytest.mark.write_disk
+def test_scan_csv_follow(tmp_path: Path) -> None:
+    path = tmp_path / "follow.csv"
+    # The last line is incomplete.
+    path.write_text("a,b\n1,x\n2,y\n3")
+
+    def append() -> None:
+        time.sleep(0.05)
+        with path.open("a") as f:
+            f.write(",z\n")
+        time.sleep(0.05)
+        # Rotate the file.
+        path.rename(tmp_path / "follow.csv.1")
+        path.write_text("a,b\n4,w\n")
+
+    writer = threading.Thread(target=append)
+    writer.start()
+    lf = pl.scan_csv(path, follow=True, follow_poll_interval=0.01, n_rows=4)
+    out = lf.collect(engine="streaming")
+    writer.join()
+
+    expected = pl.DataFrame({"a": [1, 2, 3, 4], "b": ["x", "y", "z", "w"]})
+    assert_frame_equal(out, expected)
+
+    # Every execution follows the file from its start.
+    lf = pl.scan_csv(path, follow=True, follow_poll_interval=0.01, n_rows=1)
+    assert lf.collect(engine="streaming")["a"].to_list() == [4]
+    assert lf.collect(engine="streaming")["a"].to_list() == [4]
+
+    with pytest.raises(InvalidOperationError, match="streaming engine"):
+        lf.collect(engine="in-memory")
+    with pytest.raises(ValueError, match="follow_poll_interval"):
+        pl.scan_csv(path, follow=True, follow_poll_interval=0)
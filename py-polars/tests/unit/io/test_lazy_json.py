@@ -1,6 +1,8 @@
 from __future__ import annotations
 
 import re
+import threading
+import time
 from typing import TYPE_CHECKING
 
 import pytest
@@ -331,3 +333,26 @@ def test_scan_ndjson_schema_overwrite_22514() -> None:
     q = pl.scan_ndjson(buf, schema_overrides={"a": pl.String})
     assert q.collect_schema() == {"a": pl.String}
     assert_frame_equal(q.collect(), pl.DataFrame({"a": "1"}))
+
+
+@pytest.mark.write_disk
+def test_scan_ndjson_follow(tmp_path: Path) -> None:
+    path = tmp_path / "follow.jsonl"
+    path.write_text('{"a": 1}\n{"a": 2}\n')
+
+    def append() -> None:
+        time.sleep(0.05)
+        with path.open("a") as f:
+            f.write('{"a": 3}\n')
+
+    writer = threading.Thread(target=append)
+    writer.start()
+    out = pl.scan_ndjson(
+        path, follow=True, follow_poll_interval=0.01, row_index_name="idx", n_rows=3
+    ).collect(engine="streaming")
+    writer.join()
+
+    expected = pl.DataFrame(
+        {"idx": [0, 1, 2], "a": [1, 2, 3]}, schema_overrides={"idx": pl.UInt32}
+    )
+    assert_frame_equal(out, expected)
@@ -1227,3 +1227,72 @@ def test_sink_large_rows_25834(tmp_path: Path, monkeypatch: pytest.MonkeyPatch)
         partition_by="idx",
     )
     assert_frame_equal(pl.scan_parquet(tmp_path / "partitioned").collect(), df)
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("parallel", ["auto", "prefiltered", "none"])
+def test_scan_parquet_struct_field_projection(
+    tmp_path: Path, parallel: ParallelStrategy
+) -> None:
+    path = tmp_path / "data.parquet"
+    df = pl.DataFrame(
+        {
+            "s": [
+                {"a": 1, "b": "x", "c": [1.0]},
+                None,
+                {"a": 3, "b": None, "c": [3.0, 4.0]},
+            ],
+            "x": [1, 2, 3],
+        }
+    )
+    df.write_parquet(path)
+
+    lf = pl.scan_parquet(path, parallel=parallel)
+    q = lf.select(pl.col("s").struct.field("c"), "x")
+    assert_frame_equal(q.collect(), df.select(pl.col("s").struct.field("c"), "x"))
+
+    q = lf.filter(pl.col("s").struct.field("a") > 1).select(
+        pl.col("s").struct.field("b")
+    )
+    assert_frame_equal(
+        q.collect(),
+        df.filter(pl.col("s").struct.field("a") > 1).select(
+            pl.col("s").struct.field("b")
+        ),
+    )
+
+    # The struct is used as a whole, so all fields are read.
+    q = lf.select(pl.col("s").struct.field("a"), pl.col("s").alias("t"))
+    assert_frame_equal(
+        q.collect(), df.select(pl.col("s").struct.field("a"), pl.col("s").alias("t"))
+    )
+
+    df.write_ipc(path.with_suffix(".ipc"))
+    q = pl.scan_ipc(path.with_suffix(".ipc")).select(pl.col("s").struct.field("b"))
+    assert_frame_equal(q.collect(), df.select(pl.col("s").struct.field("b")))
+
+
+@pytest.mark.write_disk
+def test_scan_parquet_schema_overrides(tmp_path: Path) -> None:
+    path = tmp_path / "data.parquet"
+    df = pl.DataFrame({"a": ["x", "y", "x"], "b": [100, 250, None], "c": [1, 2, 3]})
+    df.write_parquet(path)
+
+    schema_overrides = {"a": pl.Categorical(), "b": pl.Decimal(10, 2)}
+    expected = df.cast(schema_overrides)  # type: ignore[arg-type]
+
+    lf = pl.scan_parquet(path, schema_overrides=schema_overrides)
+    assert lf.collect_schema() == expected.schema
+    assert_frame_equal(lf.collect(), expected)
+    assert_frame_equal(
+        lf.filter(pl.col("b") > 1).collect(), expected.filter(pl.col("b") > 1)
+    )
+    assert_frame_equal(
+        pl.read_parquet(path, schema_overrides=schema_overrides), expected
+    )
+
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        pl.scan_parquet(path, schema_overrides={"a": pl.Int64}).collect()
+
+    with pytest.raises(pl.exceptions.ColumnNotFoundError):
+        pl.scan_parquet(path, schema_overrides={"d": pl.Int64}).collect()
@@ -940,3 +940,54 @@ def test_hive_predicate_filtering_edge_case_25630(
         schema={"index": pl.get_index_type()},
     )
     assert_frame_equal(res, expected)
+
+
+@pytest.mark.parametrize(
+    ("scan", "write", "ext"),
+    [
+        (pl.scan_ipc, pl.DataFrame.write_ipc, "ipc"),
+        (pl.scan_parquet, pl.DataFrame.write_parquet, "parquet"),
+    ],
+)
+@pytest.mark.write_disk
+def test_schema_unification(
+    tmp_path: Path,
+    scan: Callable[..., pl.LazyFrame],
+    write: Callable[[pl.DataFrame, Path], Any],
+    ext: str,
+) -> None:
+    paths = [tmp_path / f"{name}.{ext}" for name in "abcd"]
+    int32 = {"x": pl.Int32}
+    write(pl.DataFrame({"x": [1, 2], "y": ["a", "b"]}).cast(int32), paths[0])
+    write(pl.DataFrame({"x": [3.5], "z": [True]}), paths[1])
+    write(pl.DataFrame({"y": ["c"], "x": [4]}).cast(int32), paths[2])
+    write(pl.DataFrame({"x": ["d"]}), paths[3])
+
+    q = scan(paths[:3], schema_unification="union")
+    expected = pl.DataFrame(
+        {
+            "x": [1.0, 2.0, 3.5, 4.0],
+            "y": ["a", "b", None, "c"],
+            "z": [None, None, True, None],
+        }
+    )
+    assert q.collect_schema() == expected.schema
+    assert_frame_equal(q.collect(), expected)
+    assert_frame_equal(
+        q.filter(pl.col("x") > 2).select("z", "x").collect(),
+        expected.filter(pl.col("x") > 2).select("z", "x"),
+    )
+
+    # Columns in a different order are not a conflict.
+    assert_frame_equal(
+        scan([paths[0], paths[2]], schema_unification="strict").collect(),
+        pl.DataFrame({"x": [1, 2, 4], "y": ["a", "b", "c"]}, schema_overrides=int32),
+    )
+
+    q = scan(paths, schema_unification="strict")
+    with pytest.raises(pl.exceptions.SchemaError, match="schemas of 2 files") as exc:
+        q.collect()
+    msg = str(exc.value)
+    assert f"'{paths[1]}': column 'x' has dtype f64, expected i32" in msg
+    assert f"'{paths[3]}': column 'x' has dtype str, expected i32" in msg
+    assert f"'{paths[2]}'" not in msg
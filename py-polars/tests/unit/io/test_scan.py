@@ -1260,3 +1260,14 @@ def test_scan_csv_streaming_decompression(corrupt_compressed_csv: bytes) -> None
         ]
     )
     assert_frame_equal(df, expected)
+
+
+@pytest.mark.write_disk
+def test_scan_retry_budget_does_not_retry_missing_files(tmp_path: Path) -> None:
+    paths = [tmp_path / "a.parquet", tmp_path / "b.parquet"]
+    pl.DataFrame({"x": [1, 2]}).write_parquet(paths[0])
+    lf = pl.scan_parquet(paths)
+
+    with pl.Config(scan_retry_budget=2), pytest.raises(FileNotFoundError) as exc:
+        lf.collect()
+    assert "retries" not in str(exc.value)
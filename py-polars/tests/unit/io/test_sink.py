@@ -169,3 +169,34 @@ def test_sink_boolean_panic_25806(sink: Any, scan: Any) -> None:
     sink(df.lazy(), f)
 
     assert_frame_equal(scan(f).collect(), df)
+
+
+@pytest.mark.write_disk
+def test_sink_multiple(tmp_path: Path) -> None:
+    calls = 0
+
+    def udf(df: pl.DataFrame) -> pl.DataFrame:
+        nonlocal calls
+        calls += 1
+        return df
+
+    df = pl.DataFrame({"g": [1, 1, 2], "x": [1, 2, 3]})
+    df.lazy().map_batches(udf).sink_multiple(
+        lambda lf: lf.sink_parquet(tmp_path / "all.parquet", lazy=True),
+        lambda lf: (
+            lf.group_by("g")
+            .agg(pl.col("x").sum())
+            .sort("g")
+            .sink_csv(tmp_path / "summary.csv", lazy=True)
+        ),
+    )
+
+    # The shared part of the query runs once.
+    assert calls == 1
+    assert_frame_equal(pl.read_parquet(tmp_path / "all.parquet"), df)
+    assert_frame_equal(
+        pl.read_csv(tmp_path / "summary.csv"), pl.DataFrame({"g": [1, 2], "x": [3, 3]})
+    )
+
+    with pytest.raises(TypeError, match="must return a lazy sink"):
+        df.lazy().sink_multiple(lambda lf: lf.collect())  # type: ignore[return-value]
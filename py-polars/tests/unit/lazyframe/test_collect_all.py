@@ -1,8 +1,10 @@
+from pathlib import Path
 from typing import cast
 
 import pytest
 
 import polars as pl
+from polars.testing import assert_frame_equal
 
 
 def test_collect_all_type_coercion_21805() -> None:
@@ -18,3 +20,28 @@ def test_collect_all(df: pl.DataFrame, optimizations: pl.QueryOptFlags) -> None:
     out = pl.collect_all([lf1, lf2], optimizations=optimizations)
     assert cast("int", out[0].item()) == 6
     assert cast("float", out[1].item()) == 12.0
+
+
+@pytest.mark.write_disk
+def test_collect_all_share_scans(tmp_path: Path) -> None:
+    path = tmp_path / "data.parquet"
+    pl.DataFrame({"a": [1, 2, 3, 4, 5], "b": ["x", "y", "z", "x", "y"]}).write_parquet(
+        path
+    )
+
+    lf = pl.scan_parquet(path)
+    lfs = [
+        lf.filter(pl.col("a") > 3).select("a"),
+        lf.filter(pl.col("b") == "x").group_by("b").agg(pl.col("a").sum()),
+    ]
+
+    optimizations = pl.QueryOptFlags(share_scans=True)
+    plan = pl.explain_all(lfs, optimizations=optimizations)
+    caches = [x for x in map(str.strip, plan.splitlines()) if x.startswith("CACHE[")]
+    assert len(caches) == 2
+    assert len(set(caches)) == 1
+    assert len(pl.explain_all(lfs).split("CACHE[")) == 1
+
+    out = pl.collect_all(lfs, optimizations=optimizations)
+    assert_frame_equal(out[0], pl.DataFrame({"a": [4, 5]}))
+    assert_frame_equal(out[1], pl.DataFrame({"b": ["x"], "a": [5]}))
@@ -3,6 +3,7 @@ from __future__ import annotations
 import pytest
 
 import polars as pl
+from polars.testing import assert_frame_equal
 
 
 def test_lf_explain_format_tree() -> None:
@@ -37,3 +38,40 @@ def test_lf_explain_tree_format_deprecated() -> None:
 
     with pytest.deprecated_call():
         lf.explain(tree_format=True)
+
+
+def test_lf_explain_pruning_report() -> None:
+    lf = pl.LazyFrame({"a": [1, 2], "b": [3, 4], "c": [5, 6]})
+    other = pl.LazyFrame({"x": [1, 2], "y": ["p", "q"]})
+    q = lf.join(other, left_on="a", right_on="x").select("b")
+
+    assert "COLUMNS READ PER SOURCE" not in q.explain()
+    report = q.explain(pruning_report=True).rpartition("\n\n")[2].splitlines()
+    assert report[0] == "COLUMNS READ PER SOURCE"
+    assert sorted(report[1:]) == [
+        '  IN_MEMORY: 1/2 columns ["x"] UNUSED',
+        '  IN_MEMORY: 2/3 columns ["a", "b"]',
+    ]
+
+    with pytest.raises(ValueError, match="requires `optimized=True`"):
+        q.explain(optimized=False, pruning_report=True)
+
+    tree = q.explain(format="tree", pruning_report=True)
+    assert tree.endswith("\n".join(report))
+
+
+def test_lf_pruning_report() -> None:
+    lf = pl.LazyFrame({"a": [1, 2], "b": [3, 4], "c": [5, 6]})
+    other = pl.LazyFrame({"x": [1, 2], "y": ["p", "q"]})
+    q = lf.join(other, left_on="a", right_on="x").select("b")
+
+    expected = pl.DataFrame(
+        {
+            "source": [None, None],
+            "n_columns": pl.Series([3, 2], dtype=pl.UInt32),
+            "columns": [["a", "b"], ["x"]],
+            "unused": [False, True],
+        },
+        schema_overrides={"source": pl.String},
+    )
+    assert_frame_equal(q.pruning_report().sort("unused"), expected)
@@ -130,6 +130,13 @@ def test_gather_every() -> None:
     assert_frame_equal(expected_df, ldf.gather_every(2, offset=1).collect())
 
 
+def test_gather_every_streaming() -> None:
+    df = pl.DataFrame({"a": range(10_000), "b": [str(i) for i in range(10_000)]})
+    ldf = pl.concat([df.slice(i, 1_000).lazy() for i in range(0, 10_000, 1_000)])
+    q = ldf.gather_every(7, offset=3)
+    assert_frame_equal(q.collect(engine="streaming"), df.gather_every(7, offset=3))
+
+
 def test_agg() -> None:
     df = pl.DataFrame({"a": [1, 2, 3], "b": [1.0, 2.0, 3.0]})
     ldf = df.lazy().min()
@@ -1700,3 +1707,24 @@ def test_cache_hit_child_removal() -> None:
     assert_frame_equal(df1.tail(3), df, check_row_order=False)
     assert_frame_equal(df2.head(3), df, check_row_order=False)
     assert_frame_equal(df2.tail(3), df, check_row_order=False)
+
+
+def test_with_max_threads() -> None:
+    pool_sizes = []
+
+    def udf(s: pl.Series) -> pl.Series:
+        pool_sizes.append(pl.thread_pool_size())
+        return s
+
+    lf = pl.LazyFrame({"a": [1, 2, 3]}).with_max_threads(2)
+    # The limit is kept by the frames created from this one.
+    q = lf.select(pl.col("a").map_batches(udf, return_dtype=pl.Int64)).filter(
+        pl.col("a") > 1
+    )
+    assert_frame_equal(q.collect(), pl.DataFrame({"a": [2, 3]}))
+    assert_frame_equal(q.profile()[0], pl.DataFrame({"a": [2, 3]}))
+    assert_frame_equal(pl.collect_all([q])[0], pl.DataFrame({"a": [2, 3]}))
+    assert pool_sizes == [2, 2, 2]
+
+    with pytest.raises(ValueError, match="must be positive"):
+        lf.with_max_threads(0)
@@ -0,0 +1,73 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+import polars.selectors as cs
+from polars.exceptions import (
+    ColumnNotFoundError,
+    InvalidOperationError,
+    OutOfBoundsError,
+)
+from polars.testing import assert_frame_equal
+
+
+@pytest.fixture
+def lf() -> pl.LazyFrame:
+    return pl.LazyFrame({"a": [1], "b": ["x"], "c": [2], "d": ["y"]})
+
+
+@pytest.mark.parametrize(
+    ("columns", "position", "expected"),
+    [
+        (["d", "b"], {"index": 0}, ["d", "b", "a", "c"]),
+        ("a", {"index": -1}, ["b", "c", "a", "d"]),
+        ("a", {"index": 3}, ["b", "c", "d", "a"]),
+        (cs.integer(), {"after": "d"}, ["b", "d", "a", "c"]),
+        (cs.string(), {"before": "a"}, ["b", "d", "a", "c"]),
+        ("b", {"after": "a"}, ["a", "b", "c", "d"]),
+    ],
+)
+def test_move_columns(
+    lf: pl.LazyFrame,
+    columns: str | list[str] | cs.Selector,
+    position: dict[str, str | int],
+    expected: list[str],
+) -> None:
+    result = lf.move_columns(columns, **position)  # type: ignore[arg-type]
+    assert result.collect_schema().names() == expected
+    assert_frame_equal(result.collect(), lf.collect().select(expected))
+
+
+def test_move_columns_errors(lf: pl.LazyFrame) -> None:
+    with pytest.raises(ValueError, match="exactly one of"):
+        lf.move_columns("a")
+    with pytest.raises(ValueError, match="exactly one of"):
+        lf.move_columns("a", index=0, after="b")
+    with pytest.raises(ColumnNotFoundError):
+        lf.move_columns("e", index=0).collect()
+    with pytest.raises(ColumnNotFoundError):
+        lf.move_columns("a", before="e").collect()
+    with pytest.raises(InvalidOperationError, match="relative to itself"):
+        lf.move_columns(["a", "b"], after="b").collect()
+    with pytest.raises(OutOfBoundsError):
+        lf.move_columns("a", index=4).collect()
+
+
+def test_insert_column(lf: pl.LazyFrame) -> None:
+    result = lf.insert_column(1, (pl.col("a") * 2).alias("e")).collect()
+    expected = pl.DataFrame(
+        {"a": [1], "e": [2], "b": ["x"], "c": [2], "d": ["y"]},
+    )
+    assert_frame_equal(result, expected)
+
+    # An existing column is replaced and moved.
+    result = lf.insert_column(-1, pl.col("a") + 1).collect()
+    expected = pl.DataFrame({"b": ["x"], "c": [2], "a": [2], "d": ["y"]})
+    assert_frame_equal(result, expected)
+
+    result = lf.insert_column(0, pl.Series("s", [True])).collect()
+    assert result.columns == ["s", "a", "b", "c", "d"]
+
+    result = lf.insert_column(0, pl.col("a").alias("z")).select("c", "z").collect()
+    assert_frame_equal(result, pl.DataFrame({"c": [2], "z": [1]}))
@@ -1,11 +1,20 @@
+from __future__ import annotations
+
 import itertools
+import math
 from io import BytesIO
+from typing import TYPE_CHECKING
 
 import pytest
 
 import polars as pl
 from polars.testing import assert_frame_equal
 
+if TYPE_CHECKING:
+    from pathlib import Path
+
+    from polars._typing import EngineType
+
 
 def test_is_null_followed_by_all() -> None:
     lf = pl.LazyFrame({"group": [0, 0, 0, 1], "val": [6, 0, None, None]})
@@ -459,3 +468,139 @@ def test_slice_pushdown_expr_25473() -> None:
 
     with pytest.raises(pl.exceptions.ShapeError, match=r"lengths.*5 != 2"):
         q.collect()
+
+
+def test_set_unique_key_removes_unique() -> None:
+    lf = pl.LazyFrame({"id": [1, 2, 3], "k": [1, 1, 2], "name": ["a", "b", "a"]})
+
+    q = lf.set_unique_key("id").unique("id")
+    assert "UNIQUE[" not in q.explain()
+    assert_frame_equal(q.collect(), lf.collect())
+
+    # A superset of a unique key is unique, a subset is not.
+    assert "UNIQUE[" not in lf.set_unique_key("id").unique(["id", "k"]).explain()
+    assert "UNIQUE[" not in lf.set_unique_key("id").unique().explain()
+    assert "UNIQUE[" in lf.set_unique_key("id", "k").unique("k").explain()
+    assert "UNIQUE[" in lf.set_unique_key("k").with_columns(k=0).unique("k").explain()
+
+    # The key is tracked through renames.
+    q = lf.set_unique_key("id").select(pl.col("id").alias("x"), "name").unique("x")
+    assert "UNIQUE[" not in q.explain()
+    # The keys of a group by are unique.
+    q = lf.group_by("k").agg(pl.len()).set_unique_key("len").unique("k")
+    assert "UNIQUE[" not in q.explain()
+
+
+def test_set_unique_key_removes_lookup_join() -> None:
+    orders = pl.LazyFrame({"customer_id": [1, 1, 3, 4], "amount": [1, 2, 3, 4]})
+    customers = pl.LazyFrame({"id": [1, 2, 3], "name": ["a", "b", "c"]})
+
+    q = orders.join(
+        customers.set_unique_key("id"),
+        left_on="customer_id",
+        right_on="id",
+        how="left",
+    )
+    assert "JOIN" in q.explain()
+    q = q.select("customer_id", "amount")
+    assert "JOIN" not in q.explain()
+    assert_frame_equal(q.collect(), orders.collect())
+
+    q = orders.join(customers, left_on="customer_id", right_on="id", how="left")
+    assert "JOIN" in q.select("amount").explain()
+
+
+def test_set_unique_key_verify() -> None:
+    lf = pl.LazyFrame({"a": [1, 1, 2, None], "b": [1, 2, 1, None]})
+
+    assert_frame_equal(lf.set_unique_key("a", "b", verify=True).collect(), lf.collect())
+    with pytest.raises(pl.exceptions.ComputeError, match="is not unique"):
+        lf.set_unique_key("a", verify=True).collect()
+    with pytest.raises(pl.exceptions.ComputeError, match="is not unique"):
+        lf.set_unique_key("a", verify=True).unique("a").collect()
+
+
+@pytest.mark.write_disk
+def test_shrink_dtypes(tmp_path: Path) -> None:
+    f = BytesIO()
+    pl.DataFrame(
+        {
+            "a": [1, 2, 300],
+            "b": [1.5, 2.5, None],
+            "c": [-1, 0, 1],
+            "d": [-1, 2**40, 0],
+        }
+    ).write_parquet(f)
+    lf = pl.scan_parquet(f.getvalue())
+    optimizations = pl.QueryOptFlags(shrink_dtypes=True)
+
+    assert lf.collect().schema == dict(lf.collect_schema())
+    df = lf.collect(optimizations=optimizations)
+    assert df.schema == pl.Schema(
+        {"a": pl.Int16, "b": pl.Float64, "c": pl.Int8, "d": pl.Int64}
+    )
+    assert_frame_equal(df, lf.collect(), check_dtypes=False)
+
+    # Columns that expressions depend on keep their data types.
+    q = lf.select("a", "b", e=pl.col("c") + 1)
+    assert q.collect(optimizations=optimizations).schema == pl.Schema(
+        {"a": pl.Int16, "b": pl.Float64, "e": pl.Int64}
+    )
+
+    q = lf.group_by("c").agg(pl.col("a").max(), pl.col("b").sum())
+    assert q.collect(optimizations=optimizations).schema == pl.Schema(
+        {"c": pl.Int8, "a": pl.Int16, "b": pl.Float64}
+    )
+
+    # In-memory frames and the output of sinks are not shrunk.
+    in_memory = pl.LazyFrame({"a": [1, 2, 300]})
+    assert in_memory.collect(optimizations=optimizations).schema == pl.Schema(
+        {"a": pl.Int64}
+    )
+    lf.sink_parquet(tmp_path / "out.parquet", optimizations=optimizations)
+    assert pl.read_parquet_schema(tmp_path / "out.parquet") == lf.collect_schema()
+
+    # Neither are scans of multiple files and computed columns.
+    lf.sink_parquet(tmp_path / "out2.parquet")
+    q = pl.scan_parquet([tmp_path / "out.parquet", tmp_path / "out2.parquet"])
+    assert q.collect(optimizations=optimizations).schema == lf.collect_schema()
+    q = lf.group_by("c").agg(pl.len())
+    assert q.collect(optimizations=optimizations).schema == pl.Schema(
+        {"c": pl.Int8, "len": pl.get_index_type()}
+    )
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_float_total_order(engine: EngineType) -> None:
+    lf = pl.LazyFrame({"a": [0.0, float("nan"), -0.0, 1.0], "b": [1, 2, 3, 4]})
+    right = pl.LazyFrame({"a": [-0.0], "c": [1]})
+    optimizations = pl.QueryOptFlags(float_total_order=True)
+
+    def collect(q: pl.LazyFrame) -> pl.DataFrame:
+        return q.collect(optimizations=optimizations, engine=engine)
+
+    def signs(values: pl.Series) -> list[float]:
+        return [math.copysign(1.0, v) for v in values]
+
+    out = collect(lf.sort("a"))
+    assert out["b"].to_list() == [3, 1, 4, 2]
+    assert signs(out["a"].head(2)) == [-1.0, 1.0]
+
+    out = collect(lf.select(min=pl.col("a").min(), max=pl.col("a").max()))
+    assert signs(out["min"]) == [-1.0]
+    assert math.isnan(out.item(0, "max"))
+
+    out = collect(lf.group_by("a").agg(pl.col("b").sum()).sort("b"))
+    assert out["b"].to_list() == [1, 2, 3, 4]
+    assert signs(out["a"].gather([0, 2])) == [1.0, -1.0]
+
+    assert collect(lf.filter(pl.col("a") == 0.0))["b"].to_list() == [1]
+    assert collect(lf.unique("a")).height == 4
+
+    out = collect(lf.join(right, on="a"))
+    assert out["b"].to_list() == [3]
+    assert signs(out["a"]) == [-1.0]
+
+    # Disabled by default.
+    assert lf.join(right, on="a").collect()["b"].sort().to_list() == [1, 3]
+    assert lf.select(pl.col("a").max()).collect().item() == 1.0
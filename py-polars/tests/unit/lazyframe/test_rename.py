@@ -46,3 +46,16 @@ def test_rename_non_strict_20407() -> None:
         .collect(),
         pl.DataFrame({"TEST": [1]}),
     )
+
+
+def test_rename_function() -> None:
+    lf = pl.LazyFrame({"a": [1], "B": [2.0], "c": ["x"]})
+
+    result = lf.rename(str.upper)
+    assert result.collect_schema().names() == ["A", "B", "C"]
+    assert_frame_equal(
+        result.select("C", "A").collect(), pl.DataFrame({"C": ["x"], "A": [1]})
+    )
+
+    with pytest.raises(pl.exceptions.DuplicateError):
+        lf.rename(lambda _: "x").collect()
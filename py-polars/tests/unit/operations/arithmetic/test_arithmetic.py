@@ -240,6 +240,36 @@ def test_literal_no_upcast() -> None:
     )
 
 
+@pytest.mark.parametrize("dtype", [pl.Float32, pl.Float64])
+def test_fused_elementwise(dtype: pl.DataType) -> None:
+    # long enough to span several blocks, with nulls and NaNs
+    values = [None if i % 7 == 0 else float(i % 11) - 5.0 for i in range(3000)]
+    values[1] = float("nan")
+    s = pl.Series("a", values, dtype=dtype)
+    df = pl.concat([s[:1500].to_frame(), s[1500:].to_frame()], rechunk=False)
+
+    a = pl.col("a")
+    exprs = [
+        (a * 2 + 1) / 3,
+        (a - 0.5) * a - 1,
+        pl.when(a > 0).then(a * 2).otherwise(-1.0),
+        pl.when(a * 2 <= 4).then(a + 1).otherwise(None) * 10,
+        pl.when(a == a).then(a / 2).when(a > 2).then(a).otherwise(a - 1),
+    ]
+    for expr in exprs:
+        q = df.lazy().select(expr)
+        assert "fused_elementwise" in q.explain()
+        assert_frame_equal(
+            q.collect(),
+            q.collect(optimizations=pl.QueryOptFlags.none()),
+        )
+
+    # not fused: another column, an integer column, or a single operation
+    df = df.with_columns(b=pl.lit(1.0), c=pl.lit(1))
+    for expr in [a * pl.col("b") + 1, pl.col("c") * 2 + 1, a * 2]:
+        assert "fused_elementwise" not in df.lazy().select(expr).explain()
+
+
 def test_boolean_addition() -> None:
     s = pl.DataFrame(
         {"a": [True, False, False], "b": [True, False, True]}
@@ -0,0 +1,65 @@
+from __future__ import annotations
+
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from polars.lazyframe import PartitionInfo
+
+
+@pytest.fixture
+def sources(tmp_path: Path) -> Path:
+    pl.DataFrame({"a": [1, 2], "b": ["x", "y"]}).write_parquet(tmp_path / "0.parquet")
+    pl.DataFrame({"a": [3], "b": ["z"]}).write_parquet(tmp_path / "1.parquet")
+    pl.DataFrame({"a": [4, 5, 6], "b": ["u", "v", "w"]}).write_parquet(
+        tmp_path / "2.parquet"
+    )
+    return tmp_path
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_map_partitions(sources: Path, engine: pl.EngineType) -> None:
+    calls: list[tuple[PartitionInfo, list[str]]] = []
+
+    def shift(df: pl.DataFrame, info: PartitionInfo) -> pl.DataFrame:
+        calls.append((info, df.columns))
+        return df.with_columns(pl.col("a") + 100 * info.index)
+
+    lf = pl.scan_parquet(sources / "*.parquet").map_partitions(shift)
+    out = lf.select("a").collect(engine=engine)
+
+    assert_frame_equal(out, pl.DataFrame({"a": [1, 2, 103, 204, 205, 206]}))
+    calls.sort(key=lambda call: call[0].index)
+    assert [info.index for info, _ in calls] == [0, 1, 2]
+    assert all(info.count == 3 for info, _ in calls)
+    paths = [Path(info.path).name for info, _ in calls if info.path is not None]
+    assert paths == ["0.parquet", "1.parquet", "2.parquet"]
+    # The projection is pushed into every scan.
+    assert all(columns == ["a"] for _, columns in calls)
+
+
+def test_map_partitions_schema(sources: Path) -> None:
+    def summarize(df: pl.DataFrame, info: PartitionInfo) -> pl.DataFrame:
+        return pl.DataFrame({"index": [info.index], "rows": [df.height]})
+
+    lf = pl.scan_parquet(sources).map_partitions(
+        summarize,
+        schema={"index": pl.Int64, "rows": pl.Int64},
+        no_optimizations=True,
+    )
+    expected = pl.DataFrame({"index": [0, 1, 2], "rows": [2, 1, 3]})
+    assert_frame_equal(lf.collect(), expected)
+
+
+def test_map_partitions_requires_scan(sources: Path) -> None:
+    with pytest.raises(InvalidOperationError, match="can only split the sources"):
+        pl.LazyFrame({"a": [1]}).map_partitions(lambda df, _: df)
+
+    with pytest.raises(InvalidOperationError, match="can only split the sources"):
+        pl.scan_parquet(sources).select("a").map_partitions(lambda df, _: df)
@@ -451,6 +451,40 @@ def test_list_diff() -> None:
     assert s.list.diff().to_list() == expected.to_list()
 
 
+def test_list_rolling_sum_mean() -> None:
+    s = pl.Series("a", [[1, 2, None, 4, 5], [3], None, []], dtype=pl.List(pl.Int8))
+
+    out = s.list.rolling_sum(2)
+    assert out.dtype == pl.List(pl.Int64)
+    assert out.to_list() == [[None, 3, None, None, 9], [None], None, []]
+
+    out = s.list.rolling_mean(2)
+    assert out.dtype == pl.List(pl.Float64)
+    assert out.to_list() == [[None, 1.5, None, None, 4.5], [None], None, []]
+
+    assert s.slice(1).list.rolling_sum(1).to_list() == [[3], None, []]
+
+    with pytest.raises(InvalidOperationError, match="must be positive"):
+        s.list.rolling_sum(0)
+
+
+def test_list_zip_with() -> None:
+    a = pl.Series("a", [[1, 2], None, [3, None], [4, 5]])
+    b = pl.Series("b", [[10.0, 20.0], [1.0], [1.0, 1.0], None])
+
+    out = a.list.zip_with(b, "add")
+    assert out.dtype == pl.List(pl.Float64)
+    assert out.to_list() == [[11.0, 22.0], None, [4.0, None], None]
+    assert a.list.zip_with(b, "min").to_list() == [[1.0, 2.0], None, [1.0, 1.0], None]
+
+    df = pl.DataFrame({"a": [[1, 2], [3, 4]]})
+    out = df.select(pl.col("a").list.zip_with(pl.lit(pl.Series([[10, 10]])), "mul"))
+    assert out.to_series().to_list() == [[10, 20], [30, 40]]
+
+    with pytest.raises(pl.exceptions.ShapeError, match="equal length"):
+        pl.Series([[1, 2]]).list.zip_with(pl.Series([[1]]), "add")
+
+
 def test_slice() -> None:
     vals = [[1, 2, 3, 4], [10, 2, 1]]
     s = pl.Series("a", vals)
@@ -0,0 +1,100 @@
+from __future__ import annotations
+
+import json
+import struct
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+
+def wkb_polygon(coords: list[tuple[float, float]]) -> bytes:
+    out = struct.pack("<BII", 1, 3, 1) + struct.pack("<I", len(coords))
+    for x, y in coords:
+        out += struct.pack("<dd", x, y)
+    return out
+
+
+SQUARE = wkb_polygon([(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0), (0.0, 0.0)])
+
+
+def test_st_point_roundtrip() -> None:
+    df = pl.DataFrame({"x": [1.0, None, 3.5], "y": [2, 5, -1]})
+    out = df.select(pl.st_point("x", "y").alias("p"))
+    assert out.schema == {"p": pl.Geometry()}
+
+    result = out.select(pl.col("p").st.x(), pl.col("p").st.y().alias("y"))
+    expected = pl.DataFrame({"p": [1.0, None, 3.5], "y": [2.0, None, -1.0]})
+    assert_frame_equal(result, expected)
+
+    assert_series_equal(out["p"].st.x(), expected["p"])
+
+
+def test_st_predicates() -> None:
+    df = pl.DataFrame({"x": [1.0, 4.0, 7.0, None], "y": [1.0, 2.0, 8.0, 0.0]})
+    # Plain binary columns are interpreted as WKB as well.
+    square = pl.lit(SQUARE, dtype=pl.Binary)
+
+    out = df.select(
+        contains=square.st.contains(pl.st_point("x", "y")),
+        within=pl.st_point("x", "y").st.within(square),
+        distance=pl.st_point("x", "y").st.distance(square),
+    )
+    expected = pl.DataFrame(
+        {
+            "contains": [True, True, False, None],
+            "within": [True, True, False, None],
+            "distance": [0.0, 0.0, 5.0, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_st_intersects_bbox() -> None:
+    df = pl.DataFrame({"id": [0, 1, 2], "x": [1.0, 4.0, 7.0], "y": [1.0, 2.0, 8.0]})
+    out = df.lazy().filter(
+        pl.st_point("x", "y").st.intersects_bbox(0.0, 0.0, 5.0, 5.0)
+    )
+    assert out.collect()["id"].to_list() == [0, 1]
+
+    with pytest.raises(pl.exceptions.ComputeError, match="must be scalars"):
+        df.select(pl.st_point("x", "y").st.intersects_bbox("x", 0.0, 5.0, 5.0))
+
+
+def test_st_invalid_input() -> None:
+    s = pl.Series([SQUARE]).ext.to(pl.Geometry())
+    with pytest.raises(InvalidOperationError, match="point geometries"):
+        s.st.x()
+    with pytest.raises(InvalidOperationError, match="geometry or binary"):
+        pl.Series([1.0]).st.x()
+
+
+def test_geoparquet_roundtrip(tmp_path: Path) -> None:
+    pq = pytest.importorskip("pyarrow.parquet")
+
+    df = pl.DataFrame({"x": [1.0, 2.0], "y": [3.0, 4.0]}).select(
+        "x", geom=pl.st_point("x", "y")
+    )
+    path = tmp_path / "points.parquet"
+    df.write_parquet(path)
+
+    geo = json.loads(pq.read_metadata(path).metadata[b"geo"])
+    assert geo["primary_column"] == "geom"
+    assert geo["columns"]["geom"]["encoding"] == "WKB"
+
+    assert_frame_equal(pl.read_parquet(path), df)
+
+    # Files of other writers only mark the geometry columns in the GeoParquet metadata.
+    tbl = df.with_columns(pl.col("geom").ext.storage()).to_arrow(
+        compat_level=pl.CompatLevel.oldest()
+    )
+    tbl = tbl.replace_schema_metadata({"geo": json.dumps(geo)})
+    path = tmp_path / "other.parquet"
+    pq.write_table(tbl, path)
+    assert pl.read_parquet_schema(path) == {"x": pl.Float64, "geom": pl.Geometry()}
@@ -0,0 +1,65 @@
+from __future__ import annotations
+
+import math
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_quant_drawdown() -> None:
+    s = pl.Series("price", [100, 110, None, 99, 121, 110])
+    expected = pl.Series("price", [0.0, 0.0, None, 99 / 110 - 1, 0.0, 110 / 121 - 1])
+    assert_series_equal(s.quant.drawdown(), expected)
+    assert_series_equal(s.quant.max_drawdown(), pl.Series("price", [99 / 110 - 1]))
+
+
+def test_quant_hit_ratio_and_sharpe() -> None:
+    s = pl.Series("r", [0.01, -0.02, None, 0.03, 0.02])
+    assert s.quant.hit_ratio().item() == 0.75
+
+    mean, std = 0.01, math.sqrt(0.0014 / 3)
+    assert s.quant.sharpe(1).item() == pytest.approx(mean / std)
+    assert s.quant.sharpe(12).item() == pytest.approx(mean / std * math.sqrt(12))
+
+    # Too few values to compute a standard deviation.
+    assert pl.Series([0.01, None]).quant.sharpe().item() is None
+    assert pl.Series([None], dtype=pl.Float64).quant.hit_ratio().item() is None
+
+
+def test_quant_group_by() -> None:
+    df = pl.DataFrame(
+        {
+            "asset": ["a", "b", "a", "b", "a", "b"],
+            "price": [100, 10, 80, 11, 120, 9],
+        }
+    )
+    returns = pl.col("price").pct_change()
+    out = df.group_by("asset", maintain_order=True).agg(
+        max_drawdown=pl.col("price").quant.max_drawdown(),
+        hit_ratio=returns.quant.hit_ratio(),
+        sharpe=returns.quant.sharpe(1),
+    )
+    expected = pl.DataFrame(
+        {
+            "asset": ["a", "b"],
+            "max_drawdown": [-0.2, 9 / 11 - 1],
+            "hit_ratio": [0.5, 0.5],
+            "sharpe": [
+                pl.Series([-0.2, 0.5]).quant.sharpe(1).item(),
+                pl.Series([0.1, 9 / 11 - 1]).quant.sharpe(1).item(),
+            ],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    out = df.select(pl.col("price").quant.drawdown().over("asset"))
+    expected_drawdown = [0.0, 0.0, -0.2, 0.0, 0.0, 9 / 11 - 1]
+    assert out["price"].to_list() == pytest.approx(expected_drawdown)
+
+
+def test_quant_invalid_dtype() -> None:
+    with pytest.raises(InvalidOperationError, match="expected a numeric column"):
+        pl.Series(["a"]).quant.drawdown()
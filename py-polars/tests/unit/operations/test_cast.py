@@ -37,6 +37,23 @@ def test_invalid_string_date() -> None:
         df.with_columns(**{"x1-date": pl.col("x1").cast(pl.Date)})
 
 
+def test_string_to_numeric_large() -> None:
+    # Large enough to be parsed in parallel.
+    n = 100_000
+    ints = pl.int_range(-n, n, eager=True)
+    s = ints.cast(pl.String)
+    assert_series_equal(s.cast(pl.Int32), ints.cast(pl.Int32))
+    assert_series_equal(s.cast(pl.Float64), ints.cast(pl.Float64))
+    floats = ints / 1000
+    assert_series_equal(floats.cast(pl.String).cast(pl.Float64), floats)
+    assert_series_equal(
+        s.cast(pl.UInt8, strict=False),
+        ints.cast(pl.UInt8, strict=False),
+    )
+    with pytest.raises(InvalidOperationError):
+        pl.concat([s, pl.Series(["x"])]).cast(pl.Int64)
+
+
 def test_string_datetime() -> None:
     df = pl.DataFrame(
         {"x1": ["2021-12-19T00:39:57", "2022-12-19T16:39:57"]}
@@ -383,6 +400,39 @@ def test_strict_cast_temporal(
             _cast_lit_t(*args)  # type: ignore[arg-type]
 
 
+def test_strict_cast_failure_detail() -> None:
+    s = pl.Series("a", ["1", "x", None, "y", "5", "z"])
+
+    with pytest.raises(InvalidOperationError) as exc:
+        s.cast(pl.Int64)
+    assert 'values: ["x", "y", "z"]' in str(exc.value)
+    assert exc.value.detail == {
+        "kind": "cast_failed",
+        "column": "a",
+        "from": "str",
+        "to": "i64",
+        "rows": [1, 3, 5],
+        "values": ["x", "y", "z"],
+    }
+
+    # All failed rows are reported, but only the first values.
+    s = pl.Series("a", ["x"] * 12 + ["1"])
+    with pytest.raises(InvalidOperationError) as exc:
+        pl.DataFrame(s).select(pl.col("a").cast(pl.Int64))
+    detail = exc.value.detail
+    assert detail is not None
+    assert detail["rows"] == list(range(12))
+    assert detail["values"] == ["x"] * 10
+
+    # The rows of a scan are the rows of the file.
+    csv = b"t\n" + b"10:00:00\n" * 5 + b"bad\n"
+    with pytest.raises(InvalidOperationError) as exc:
+        pl.scan_csv(csv, schema_overrides={"t": pl.Time}).collect()
+    detail = exc.value.detail
+    assert detail is not None
+    assert detail["rows"] == [5]
+
+
 @pytest.mark.parametrize(
     (
         "value",
@@ -1060,3 +1110,25 @@ def test_lit_cast_arithmetic_matrix_schema(
         else df.lazy().select(op(pl.col("a"), pl.lit(1, lit_dtype)))
     )
     assert q.collect_schema() == q.collect().schema
+
+
+def test_try_cast() -> None:
+    df = pl.DataFrame({"a": ["1", "x", None, "300"]})
+
+    result = df.select(pl.col("a").try_cast(pl.UInt8))
+    expected = pl.DataFrame(
+        {
+            "a": [
+                {"value": 1, "ok": True},
+                {"value": None, "ok": False},
+                {"value": None, "ok": True},
+                {"value": None, "ok": False},
+            ]
+        },
+        schema={"a": pl.Struct({"value": pl.UInt8, "ok": pl.Boolean})},
+    )
+    assert_frame_equal(result, expected)
+
+    # Branch on whether the values could be converted.
+    result = df.lazy().filter(~pl.col("a").try_cast(pl.Int64).struct.field("ok"))
+    assert result.collect()["a"].to_list() == ["x"]
@@ -614,3 +614,27 @@ def test_explode_params() -> None:
         df.explode("a", empty_as_null=False, keep_nulls=False),
         pl.DataFrame({"a": [1, 2, 3, 4, 5, 6], "b": [1, 1, 1, 3, 3, 3]}),
     )
+
+
+def test_explode_length_mismatch() -> None:
+    df = pl.DataFrame(
+        {"a": [[1, 2], [3], None], "b": [[10], [20, 30], [40]], "c": [1, 2, 3]}
+    )
+
+    with pytest.raises(ShapeError):
+        df.explode("a", "b")
+
+    assert_frame_equal(
+        df.explode("a", "b", length_mismatch="pad"),
+        pl.DataFrame(
+            {
+                "a": [1, 2, 3, None, None],
+                "b": [10, None, 20, 30, 40],
+                "c": [1, 1, 2, 2, 3],
+            }
+        ),
+    )
+    assert_frame_equal(
+        df.lazy().explode("a", "b", length_mismatch="truncate").collect(),
+        pl.DataFrame({"a": [1, 3, None], "b": [10, 20, None], "c": [1, 2, 3]}),
+    )
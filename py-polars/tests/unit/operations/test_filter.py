@@ -11,7 +11,7 @@ import polars.selectors as cs
 from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
-    from polars._typing import PolarsDataType
+    from polars._typing import EngineType, PolarsDataType
 
 
 def test_simplify_expression_lit_true_4376() -> None:
@@ -360,3 +360,95 @@ def test_filter_group_by_23681(maintain_order: bool) -> None:
     )
 
     assert_frame_equal(df, expected, check_row_order=maintain_order)
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_filter_audit_null_predicate(
+    engine: EngineType, capfd: pytest.CaptureFixture[str]
+) -> None:
+    lf = pl.LazyFrame({"a": [1, None, 3, 4], "b": [True, True, None, True]})
+    predicate = (pl.col("a") > 1) & pl.col("b")
+
+    with pl.Config(audit_null_filters=True, verbose=True):
+        out = lf.filter(predicate).collect(engine=engine)
+    assert_frame_equal(out, pl.DataFrame({"a": [4], "b": [True]}))
+
+    err = capfd.readouterr().err
+    assert "[null filter audit]" in err
+    assert "evaluated to null for 2 of 4 rows" in err
+
+    # Predicates that resolve the nulls are not reported.
+    with pl.Config(audit_null_filters=True, verbose=True):
+        lf.filter(predicate.fill_null(False)).collect(engine=engine)
+    assert "[null filter audit]" not in capfd.readouterr().err
+
+    # Nothing is printed outside of verbose mode.
+    with pl.Config(audit_null_filters=True):
+        lf.filter(predicate).collect(engine=engine)
+    assert "[null filter audit]" not in capfd.readouterr().err
+
+
+def test_filter_partition() -> None:
+    lf = pl.LazyFrame({"a": [1, None, 3, 4, 5], "b": ["x", "y", None, "z", "x"]})
+
+    kept, dropped = lf.filter_partition(pl.col("a") > 2)
+    assert_frame_equal(kept, lf.filter(pl.col("a") > 2))
+    assert_frame_equal(dropped, lf.remove(pl.col("a") > 2))
+
+    kept, dropped = lf.filter_partition(
+        pl.col("a") > 2, pl.col("b").is_not_null(), b="x", reason="reason"
+    )
+    out_kept, out_dropped = pl.collect_all([kept, dropped])
+    assert_frame_equal(out_kept, pl.DataFrame({"a": [5], "b": ["x"]}))
+    assert_frame_equal(
+        out_dropped,
+        pl.DataFrame(
+            {
+                "a": [1, None, 3, 4],
+                "b": ["x", "y", None, "z"],
+                "reason": [
+                    str(pl.col("a") > 2),
+                    str(pl.col("a") > 2),
+                    str(pl.col("b").is_not_null()),
+                    str(pl.col("b") == "x"),
+                ],
+            }
+        ),
+    )
+
+    _, dropped = pl.DataFrame({"a": [1, 2]}).filter_partition(
+        (pl.col("a") > 0) & (pl.col("a") < 2), reason="reason"
+    )
+    assert dropped.to_dict(as_series=False) == {
+        "a": [2],
+        "reason": [str(pl.col("a") < 2)],
+    }
+
+    with pytest.raises(TypeError, match="at least one predicate"):
+        lf.filter_partition()
+
+
+def test_filter_zone_maps_skip_chunks(
+    capfd: pytest.CaptureFixture[str], monkeypatch: pytest.MonkeyPatch
+) -> None:
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    df = pl.concat(
+        [
+            pl.DataFrame({"x": [1, 2, 3], "y": [1.0, float("nan"), 2.0]}),
+            pl.DataFrame({"x": [4, 5, 6], "y": [3.0, 4.0, 5.0]}),
+            pl.DataFrame({"x": [7, None, 9], "y": [6.0, 7.0, None]}),
+        ],
+        rechunk=False,
+    )
+
+    out = df.lazy().filter(pl.col("x") >= 5).collect(engine="in-memory")
+    assert out["x"].to_list() == [5, 6, 7, 9]
+    assert "zone maps skipped 1 of 3 chunks" in capfd.readouterr().err
+
+    # Floats are not ordered well enough to skip chunks, NaN is larger than all values.
+    out = df.lazy().filter(pl.col("y") > 10.0).collect(engine="in-memory")
+    assert out["x"].to_list() == [2]
+    assert "zone maps skipped" not in capfd.readouterr().err
+
+    out = df.lazy().filter(pl.col("x") > 100).collect(engine="in-memory")
+    assert_frame_equal(out, df.clear())
@@ -1749,6 +1749,35 @@ def test_slice_group_by_offset_24259() -> None:
     }
 
 
+@pytest.mark.parametrize("maintain_order", [False, True])
+def test_group_by_slice_per_group_column(maintain_order: bool) -> None:
+    df = pl.DataFrame(
+        {
+            "user": ["a", "a", "a", "b", "b", "b", "c"],
+            "event": [1, 2, 3, 4, 5, 6, 7],
+            "n": [2, 2, 2, 1, 1, 1, 0],
+        }
+    )
+    result = (
+        df.group_by("user", maintain_order=maintain_order)
+        .agg(
+            head=pl.col("event").head(pl.col("n")),
+            tail=pl.col("event").tail(pl.col("n")),
+            slice=pl.col("event").slice(pl.col("n") - 1, 2),
+        )
+        .sort("user")
+    )
+    expected = pl.DataFrame(
+        {
+            "user": ["a", "b", "c"],
+            "head": [[1, 2], [4], []],
+            "tail": [[2, 3], [6], []],
+            "slice": [[2, 3], [4, 5], [7]],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
 def test_group_by_first_nondet_24278() -> None:
     values = [
         96, 86, 0, 86, 43, 50, 9, 14, 98, 39, 93, 7, 71, 1, 93, 41, 56,
@@ -427,3 +427,24 @@ def test_is_sorted_struct() -> None:
     s = s.sort(descending=True)
     assert s.flags["SORTED_DESC"]
     assert not s.flags["SORTED_ASC"]
+
+
+def test_sorted_flag_filter() -> None:
+    s = pl.Series([1, 2, 3, 4]).set_sorted()
+    assert s.filter(s != 2).flags["SORTED_ASC"]
+
+    df = pl.DataFrame({"a": [3.0, 2.0, 1.0], "b": [1, 2, 3]})
+    df = df.set_sorted("a", descending=True)
+    assert df.filter(pl.col("b") != 2)["a"].flags["SORTED_DESC"]
+
+
+def test_sorted_flag_float_shift() -> None:
+    s = pl.Series("a", [1.0, 2.5, 4.0]).set_sorted()
+    assert (s + 1.5).flags["SORTED_ASC"]
+    assert (s - 0.5).flags["SORTED_ASC"]
+    assert s.to_frame().select(pl.col("a") + 1.5)["a"].flags["SORTED_ASC"]
+
+    assert not (s + float("inf")).flags["SORTED_ASC"]
+    assert not (s * 2.0).flags["SORTED_ASC"]
+    # Integers may wrap around.
+    assert not (pl.Series([1, 2]).set_sorted() + 1).flags["SORTED_ASC"]
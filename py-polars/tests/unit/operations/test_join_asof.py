@@ -287,6 +287,33 @@ def test_join_asof_tolerance() -> None:
             )
 
 
+def test_join_asof_tolerance_expr() -> None:
+    left = pl.DataFrame(
+        {
+            "time": [1, 5, 10, 20],
+            "group": ["a", "a", "b", "b"],
+            "tol": [1, 0, 5, None],
+        }
+    )
+    right = pl.DataFrame(
+        {"time": [0, 4, 9, 20], "group": ["a", "a", "b", "b"], "val": [1, 2, 3, 4]}
+    )
+
+    out = left.join_asof(right, on="time", by="group", tolerance=pl.col("tol"))
+    expected = left.with_columns(val=pl.Series([1, None, 3, 4]))
+    assert_frame_equal(out, expected)
+
+    out = left.lazy().join_asof(
+        right.lazy(),
+        on="time",
+        tolerance=pl.col("tol").fill_null(0) * 2,
+        allow_exact_matches=False,
+    )
+    schema = out.collect_schema()
+    assert schema.names() == ["time", "group", "tol", "group_right", "val"]
+    assert out.collect()["val"].to_list() == [1, None, 3, None]
+
+
 def test_join_asof_tolerance_forward() -> None:
     df_quotes = pl.DataFrame(
         {
@@ -0,0 +1,70 @@
+from __future__ import annotations
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_is_outlier_mad() -> None:
+    s = pl.Series("a", [1.0, None, 2.0, 3.0, 2.0, 100.0])
+    expected = pl.Series("a", [False, None, False, False, False, True])
+    assert_series_equal(s.is_outlier_mad(), expected)
+    expected = pl.Series("a", [False, None, False, False, False, False])
+    assert_series_equal(s.is_outlier_mad(100.0), expected)
+
+    # a MAD of zero flags every deviation from the median
+    s = pl.Series("a", [1, 1, 1, 1, 2])
+    assert s.is_outlier_mad().to_list() == [False, False, False, False, True]
+
+
+def test_is_outlier_iqr() -> None:
+    s = pl.Series("a", [1.0, 2.0, 3.0, 2.0, 100.0, -50.0])
+    assert s.is_outlier_iqr().to_list() == [False, False, False, False, True, True]
+    assert s.is_outlier_iqr(100.0).to_list() == [False] * 6
+
+
+def test_is_outlier_over() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a"] * 5 + ["b"] * 5,
+            "x": [1.0, 2.0, 3.0, 2.0, 100.0, 10.0, 11.0, 12.0, 11.0, 100.0],
+        }
+    )
+    out = df.select(
+        mad=pl.col("x").is_outlier_mad().over("g"),
+        iqr=pl.col("x").is_outlier_iqr().over("g"),
+    )
+    expected = pl.DataFrame(
+        {
+            "mad": [False, False, False, False, True] * 2,
+            "iqr": [False, False, False, False, True] * 2,
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_rolling_is_outlier_mad() -> None:
+    s = pl.Series("a", [1.0, 2.0, 3.0, 2.0, 50.0, 3.0])
+    expected = pl.Series("a", [None, None, False, False, True, False])
+    assert_series_equal(s.rolling_is_outlier_mad(3), expected)
+
+    s = pl.Series("a", [1.0, None, 3.0, 2.0, 50.0])
+    expected = pl.Series("a", [None, None, False, False, True])
+    assert_series_equal(s.rolling_is_outlier_mad(3, min_samples=2), expected)
+
+
+def test_rolling_is_outlier_iqr() -> None:
+    s = pl.Series("a", [1.0, 2.0, 3.0, 2.0, 50.0, 3.0])
+    expected = pl.Series("a", [None, None, None, False, True, False])
+    assert_series_equal(s.rolling_is_outlier_iqr(4), expected)
+
+
+def test_rolling_is_outlier_over() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a"] * 4 + ["b"] * 4,
+            "x": [1.0, 2.0, 1.0, 40.0, 5.0, 6.0, 5.0, 5.0],
+        }
+    )
+    out = df.select(pl.col("x").rolling_is_outlier_mad(3).over("g"))
+    expected = pl.DataFrame({"x": [None, None, False, True, None, None, False, False]})
+    assert_frame_equal(out, expected)
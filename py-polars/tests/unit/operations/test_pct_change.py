@@ -1,6 +1,12 @@
 from __future__ import annotations
 
+import math
+from datetime import date
+
+import pytest
+
 import polars as pl
+from polars.exceptions import InvalidOperationError
 from polars.testing import assert_frame_equal, assert_series_equal
 
 
@@ -28,3 +34,71 @@ def test_pct_change_nulls() -> None:
     result = df.select(pl.col("a").pct_change().alias("pct_change"))
     expected = pl.DataFrame({"pct_change": [None, 0.1, 0.090909, None, None, 1.0]})
     assert_frame_equal(result, expected)
+
+
+def test_pct_change_fill_policy() -> None:
+    s = pl.Series("a", [10, 11, 12, None, 12, 24])
+    assert_series_equal(
+        s.pct_change(fill_policy="forward"),
+        pl.Series("a", [None, 0.1, 2 / 22, 0.0, 0.0, 1.0]),
+    )
+
+
+def test_pct_change_over_groups() -> None:
+    df = pl.DataFrame({"g": ["a", "a", "b", "b"], "v": [1, 2, 4, 8]})
+    result = df.select(
+        pct=pl.col("v").pct_change().over("g"),
+        log=pl.col("v").log_return().over("g"),
+    )
+    expected = pl.DataFrame(
+        {"pct": [None, 1.0, None, 1.0], "log": [None, 0.693147, None, 0.693147]}
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_log_return() -> None:
+    s = pl.Series("a", [1.0, 2.0, None, 8.0])
+    assert_series_equal(
+        s.log_return(), pl.Series("a", [None, 0.693147, None, None]), rtol=1e-5
+    )
+
+
+def test_pct_change_by() -> None:
+    df = pl.DataFrame(
+        {
+            "v": [18, 10, 12, 11, 15],
+            "t": [8, 1, 5, 2, 6],
+        }
+    )
+    result = df.select(
+        pct=pl.col("v").pct_change_by("t", "3i"),
+        log=pl.col("v").log_return_by("t", "3i"),
+    )
+    pct = [0.5, None, 12 / 11 - 1, None, 15 / 11 - 1]
+    expected = pl.DataFrame(
+        {
+            "pct": pct,
+            "log": [None if p is None else math.log1p(p) for p in pct],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    # the same result for sorted input
+    assert_frame_equal(
+        df.sort("t").select(pl.col("v").pct_change_by("t", "3i")),
+        df.sort("t").select(v=pl.Series([None, None, 12 / 11 - 1, 15 / 11 - 1, 0.5])),
+    )
+
+
+def test_pct_change_by_calendar_duration() -> None:
+    df = pl.DataFrame(
+        {
+            "v": [1, 2, 4],
+            "t": [date(2020, 1, 1), date(2020, 2, 1), date(2020, 3, 1)],
+        }
+    )
+    result = df.select(pl.col("v").pct_change_by("t", "1mo"))
+    assert_frame_equal(result, pl.DataFrame({"v": [None, 1.0, 1.0]}))
+
+    with pytest.raises(InvalidOperationError, match="parsed integer"):
+        df.select(pl.col("v").pct_change_by("t", "1i"))
@@ -14,8 +14,11 @@ def test_profile_columns() -> None:
     # ╞══════════════╪═══════╪═════╡
     # │ optimization ┆ 0     ┆ 69  │
     # │ group_by(a)  ┆ 69    ┆ 342 │
+    # │ expr(b)      ┆ 201   ┆ 330 │
     # └──────────────┴───────┴─────┘
     assert len(profiling_info) == 2
+    nodes = sorted(profiling_info[1]["node"])
+    assert nodes == ["expr(b)", "group_by(a)", "optimization"]
     assert profiling_info[1].columns == ["node", "start", "end"]
 
 
@@ -29,4 +32,22 @@ def test_profile_with_cse() -> None:
         pl.when(x.is_null())
         .then(None)
         .otherwise(pl.when(y == 0).then(None).otherwise(x + y))
-    ).profile(optimizations=pl.QueryOptFlags(comm_subexpr_elim=True))[1].shape == (2, 3)
+    ).profile(optimizations=pl.QueryOptFlags(comm_subexpr_elim=True))[1].filter(
+        ~pl.col("node").str.starts_with("expr(")
+    ).shape == (2, 3)
+
+
+def test_profile_expressions() -> None:
+    lf = pl.LazyFrame({"a": ["x", "y", "z"], "b": [1, 2, 3]})
+
+    q = lf.select(
+        pl.col("a").str.contains("x").alias("has_x"),
+        (pl.col("b") * 2).alias("b2"),
+    )
+    timings = q.profile()[1]
+    exprs = timings.filter(pl.col("node").str.starts_with("expr("))
+    assert sorted(exprs["node"]) == ["expr(b2)", "expr(has_x)"]
+
+    select = timings.filter(pl.col("node").str.starts_with("select")).row(0, named=True)
+    assert (exprs["start"] >= select["start"]).all()
+    assert (exprs["end"] <= select["end"]).all()
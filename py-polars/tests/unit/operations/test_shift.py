@@ -174,17 +174,28 @@ def test_shift_fill_value_nonscalar() -> None:
             "b": [4, 5, 6],
         }
     )
-    with pytest.raises(
-        ShapeError,
-        match="'fill_value' must be a scalar value",
-    ):
-        df.shift(1, fill_value=pl.col("b"))
+    assert_frame_equal(
+        df.shift(1, fill_value=pl.col("b")),
+        pl.DataFrame({"a": [4, 1, 2], "b": [4, 4, 5]}),
+    )
+    assert_series_equal(
+        df.select(pl.col("a").shift(-1, fill_value=pl.col("b"))).to_series(),
+        pl.Series("a", [2, 3, 6]),
+    )
 
-    with pytest.raises(
-        ShapeError,
-        match="'fill_value' must be a scalar value",
-    ):
-        df.select(pl.col("a").shift(1, fill_value=pl.col("b")))
+    with pytest.raises(ShapeError):
+        df.select(pl.col("a").shift(1, fill_value=pl.col("b").head(2)))
+
+
+def test_shift_fill_value_expr_over() -> None:
+    df = pl.DataFrame({"g": ["x", "x", "y", "y", "y"], "v": [1, 2, 3, 4, 5]})
+
+    out = df.select(
+        first=pl.col("v").shift(1, fill_value=pl.col("v").first()).over("g"),
+        lead=pl.col("v").shift(-1, fill_value=pl.col("v") * 10).over("g"),
+    )
+    expected = pl.DataFrame({"first": [1, 1, 3, 3, 4], "lead": [2, 20, 4, 5, 50]})
+    assert_frame_equal(out, expected)
 
 
 def test_shift_array_list_eval_24672() -> None:
@@ -1265,3 +1265,64 @@ def test_sort_by_empty_list_eval_25433() -> None:
     out = df.select(pl.col.a.list.eval(pl.element().sort_by(pl.element())))
     expected = pl.DataFrame({"a": [sorted(some_list), []]})
     assert_frame_equal(out, expected)
+
+
+def test_set_sorted_elides_sort() -> None:
+    lf = pl.LazyFrame({"ts": [1, 2, 5], "value": [3, 1, 2]})
+
+    q = lf.set_sorted("ts").sort("ts")
+    assert "SORT BY" not in q.explain()
+    assert_frame_equal(q.collect(), lf.collect())
+
+    # The sort is kept if the declared order differs.
+    assert "SORT BY" in lf.set_sorted("ts").sort("ts", descending=True).explain()
+    assert "SORT BY" in lf.set_sorted("ts").sort("value").explain()
+    assert "SORT BY" in lf.set_sorted("value", "ts").sort("ts").explain()
+    # Sorting by a prefix of the declared columns does not change the order.
+    assert "SORT BY" not in lf.set_sorted("ts", "value").sort("ts").explain()
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_set_sorted_verify(engine: pl.EngineType) -> None:
+    df = pl.DataFrame({"a": [1, 1, 2, None], "b": [2, 1, 3, 0]})
+
+    out = df.lazy().set_sorted("a", nulls_last=True, verify=True).collect(engine=engine)
+    assert_frame_equal(out, df)
+    out = (
+        df.lazy()
+        .set_sorted("a", "b", descending=[False, True], nulls_last=True, verify=True)
+        .collect(engine=engine)
+    )
+    assert_frame_equal(out, df)
+
+    with pytest.raises(pl.exceptions.ComputeError, match="not sorted by"):
+        df.lazy().set_sorted("a", verify=True).collect(engine=engine)
+    with pytest.raises(pl.exceptions.ComputeError, match="not sorted by"):
+        df.lazy().set_sorted("a", "b", nulls_last=True, verify=True).collect(
+            engine=engine
+        )
+    with pytest.raises(pl.exceptions.ComputeError, match="not sorted by"):
+        df.set_sorted("b", verify=True)
+
+
+def test_sortedness_propagation_elides_sort() -> None:
+    lf = pl.LazyFrame({"ts": [1, 2, 5], "value": [3, 1, 2]}).set_sorted("ts")
+    other = pl.LazyFrame({"value": [1, 2], "label": ["a", "b"]})
+
+    joined = lf.join(other, on="value", how="left", maintain_order="left")
+    q = joined.sort("ts")
+    assert "SORT BY" not in q.explain()
+    assert_frame_equal(q.collect(), joined.collect())
+    q = lf.join(other, on="value", how="inner", maintain_order="left").sort("ts")
+    assert "SORT BY" not in q.explain()
+    # Without a maintained left order the rows may be reordered.
+    q = lf.join(other, on="value", how="inner").sort("ts")
+    assert "SORT BY" in q.explain()
+
+    q = lf.with_columns(pl.col("ts").cast(pl.Float64)).sort("ts")
+    assert "SORT BY" not in q.explain()
+    q = lf.with_columns(pl.col("ts").cast(pl.Float64) + 0.5).sort("ts")
+    assert "SORT BY" not in q.explain()
+    assert q.collect()["ts"].to_list() == [1.5, 2.5, 5.5]
+    # Integer arithmetic may wrap around.
+    assert "SORT BY" in lf.with_columns(pl.col("ts") + 1).sort("ts").explain()
@@ -689,6 +689,35 @@ def test_window_order_by_8662() -> None:
     }
 
 
+def test_window_order_by_per_key_options() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 1, None, 2, 2],
+            "b": [None, 1, 2, 1, 3],
+            "t": [2, None, 1, 3, None],
+            "x": [1, 2, 3, 4, 5],
+        }
+    )
+    out = df.select(
+        pl.col("x")
+        .cum_sum()
+        .over(order_by=["a", "b"], descending=[True, False], nulls_last=[False, True])
+    )
+    assert out["x"].to_list() == [15, 14, 3, 7, 12]
+
+    out = df.head(4).select(
+        last=pl.col("x").cum_sum().over(order_by="t", descending=True, nulls_last=True),
+        first=pl.col("x").cum_sum().over(order_by="t", descending=True),
+    )
+    assert out.to_dict(as_series=False) == {
+        "last": [5, 10, 8, 4],
+        "first": [7, 2, 10, 6],
+    }
+
+    with pytest.raises(ValueError, match="length of `descending`"):
+        pl.col("x").over(order_by=["a", "b"], descending=[True, False, True])
+
+
 def test_window_chunked_std_17102() -> None:
     c1 = pl.DataFrame({"A": [1, 1], "B": [1.0, 2.0]})
     c2 = pl.DataFrame({"A": [2, 2], "B": [1.0, 2.0]})
@@ -398,3 +398,23 @@ def test_unique_on_literal_in_agg(maintain_order: bool, stable: bool) -> None:
         ),
         check_row_order=maintain_order,
     )
+
+
+def test_unique_by() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 2, 1, 2, 1, 3],
+            "b": [3, None, 5, 1, 5, None],
+            "c": ["x", "y", "z", "u", "v", "w"],
+        }
+    )
+
+    expected = pl.DataFrame({"a": [1, 2, 3], "b": [3, 1, None], "c": ["x", "u", "w"]})
+    assert_frame_equal(df.unique_by("a", by="b"), expected)
+    assert_frame_equal(df.lazy().unique_by("a", by="b").collect(), expected)
+
+    expected = pl.DataFrame({"a": [1, 2, 3], "b": [5, 1, None], "c": ["z", "u", "w"]})
+    assert_frame_equal(df.unique_by("a", by="b", descending=True), expected)
+
+    expected = pl.DataFrame({"a": [2, 1], "b": [1, 5], "c": ["u", "v"]})
+    assert_frame_equal(df.unique_by(pl.col("a") % 2, by=pl.col("c")), expected)
@@ -155,6 +155,44 @@ def test_cte_aliasing() -> None:
     assert expected == df3.rows()
 
 
+def test_cte_recursive() -> None:
+    employees = pl.DataFrame(
+        {
+            "id": [1, 2, 3, 4, 5],
+            "name": ["Ann", "Bob", "Cat", "Dan", "Eve"],
+            "manager_id": [None, 1, 1, 2, 4],
+        }
+    )
+    res = pl.sql(
+        query="""
+            WITH RECURSIVE chain(id, name, depth) AS (
+              SELECT id, name, 0 FROM employees WHERE manager_id IS NULL
+              UNION ALL
+              SELECT e.id, e.name, c.depth + 1
+              FROM employees e JOIN chain c ON e.manager_id = c.id
+            )
+            SELECT name, depth FROM chain ORDER BY depth, name
+        """,
+        eager=True,
+    )
+    assert res.rows() == [
+        ("Ann", 0),
+        ("Bob", 1),
+        ("Cat", 1),
+        ("Dan", 2),
+        ("Eve", 3),
+    ]
+
+    with pytest.raises(SQLInterfaceError, match="did not terminate"):
+        pl.sql(
+            """
+            WITH RECURSIVE t(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM t)
+            SELECT * FROM t
+            """,
+            eager=True,
+        )
+
+
 def test_distinct() -> None:
     df = pl.DataFrame(
         {
@@ -2,15 +2,17 @@ from __future__ import annotations
 
 import re
 from datetime import date
+from functools import partial
 from typing import TYPE_CHECKING
 
 import pytest
 
 import polars as pl
-from polars.exceptions import SQLInterfaceError
+from polars.exceptions import ColumnNotFoundError, SQLInterfaceError, SQLSyntaxError
 from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
+    from collections.abc import Callable
     from pathlib import Path
 
 
@@ -100,6 +102,85 @@ def test_delete_clause(delete_constraint: str, expected_ids: set[int]) -> None:
     assert set(res["id"]) == expected_ids
 
 
+def test_insert_into(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        ctx.execute("INSERT INTO frame VALUES (4, 'ddd', '2024-01-01')")
+        ctx.execute("INSERT INTO frame (y, x) SELECT y, x + 10 FROM frame WHERE x < 3")
+        res = ctx.execute("SELECT * FROM frame")
+
+    expected = pl.DataFrame(
+        {
+            "x": [1, 2, 3, 4, 11, 12],
+            "y": ["aaa", "bbb", "ccc", "ddd", "aaa", "bbb"],
+            "z": [
+                date(2000, 12, 31),
+                date(1978, 11, 15),
+                date(2077, 10, 20),
+                date(2024, 1, 1),
+                None,
+                None,
+            ],
+        },
+        schema_overrides={"x": pl.UInt8},
+    )
+    assert_frame_equal(res, expected)
+
+
+def test_insert_into_errors(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame) as ctx:
+        with pytest.raises(SQLInterfaceError, match="'tbl' does not exist"):
+            ctx.execute("INSERT INTO tbl VALUES (1)")
+        with pytest.raises(SQLSyntaxError, match="expects 3 values per row, found 2"):
+            ctx.execute("INSERT INTO frame VALUES (4, 'ddd')")
+        with pytest.raises(ColumnNotFoundError, match="'w' does not exist"):
+            ctx.execute("INSERT INTO frame (w) VALUES (4)")
+
+
+@pytest.mark.parametrize(
+    ("file_name", "options", "read_fn"),
+    [
+        ("out.parquet", "", pl.read_parquet),
+        ("out.arrow", "", pl.read_ipc),
+        ("out.csv", "", pl.read_csv),
+        (
+            "out.dat",
+            "WITH (FORMAT csv, DELIMITER '|')",
+            partial(pl.read_csv, separator="|"),
+        ),
+        ("out.jsonl", "", pl.read_ndjson),
+    ],
+)
+def test_copy_to(
+    file_name: str,
+    options: str,
+    read_fn: Callable[[Path], pl.DataFrame],
+    tmp_path: Path,
+) -> None:
+    path = tmp_path / file_name
+    df = pl.DataFrame({"x": [1, 2, 3], "y": ["aaa", "bbb", "ccc"]})
+    with pl.SQLContext(frame=df) as ctx:
+        ctx.execute(
+            f"COPY (SELECT * FROM frame WHERE x > 1) TO '{path}' {options}",
+            eager=True,
+        )
+    assert_frame_equal(read_fn(path), df.filter(pl.col("x") > 1))
+
+    path = tmp_path / "table.parquet"
+    with pl.SQLContext(frame=df) as ctx:
+        ctx.execute(f"COPY frame (y) TO '{path}'", eager=True)
+    assert_frame_equal(pl.read_parquet(path), df.select("y"))
+
+
+def test_copy_to_errors(tmp_path: Path) -> None:
+    with pl.SQLContext(frame=pl.DataFrame({"x": [1]})) as ctx:
+        with pytest.raises(SQLInterfaceError, match="cannot determine the format"):
+            ctx.execute(f"COPY frame TO '{tmp_path / 'out'}'")
+        with pytest.raises(SQLInterfaceError, match="only apply to CSV files"):
+            ctx.execute(f"COPY frame TO '{tmp_path / 'out.parquet'}' WITH (HEADER)")
+        with pytest.raises(SQLInterfaceError, match="COPY FROM is not supported"):
+            ctx.execute(f"COPY frame FROM '{tmp_path / 'out.csv'}'")
+
+
 def test_drop_table(test_frame: pl.LazyFrame) -> None:
     # 'drop' completely removes the table from sql context
     expected = pl.DataFrame()
@@ -142,6 +223,54 @@ def test_show_tables(test_frame: pl.LazyFrame) -> None:
         assert_frame_equal(res, pl.DataFrame({"name": ["tbl1", "tbl2", "tbl3"]}))
 
 
+def test_describe_table(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame) as ctx:
+        res = ctx.execute("DESCRIBE frame").collect()
+        expected = pl.DataFrame(
+            {"column_name": ["x", "y", "z"], "data_type": ["u8", "str", "date"]}
+        )
+        assert_frame_equal(res, expected)
+
+        with pytest.raises(SQLInterfaceError, match="relation 'other' was not found"):
+            ctx.execute("DESCRIBE other")
+
+
+def test_information_schema(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(
+        tbl2=test_frame.select("x"),
+        tbl1=test_frame,
+    ) as ctx:
+        res = ctx.execute("SELECT * FROM information_schema.tables").collect()
+        expected = pl.DataFrame(
+            {"table_name": ["tbl1", "tbl2"], "table_type": ["BASE TABLE"] * 2}
+        )
+        assert_frame_equal(res, expected)
+
+        res = ctx.execute(
+            """
+            SELECT table_name, column_name, ordinal_position, data_type
+            FROM INFORMATION_SCHEMA.COLUMNS
+            WHERE data_type <> 'date'
+            ORDER BY table_name, ordinal_position
+            """
+        ).collect()
+        expected = pl.DataFrame(
+            {
+                "table_name": ["tbl1", "tbl1", "tbl2"],
+                "column_name": ["x", "y", "x"],
+                "ordinal_position": [1, 2, 1],
+                "data_type": ["u8", "str", "u8"],
+            }
+        )
+        assert_frame_equal(res, expected)
+
+        with pytest.raises(
+            SQLInterfaceError,
+            match="relation 'information_schema.views' was not found",
+        ):
+            ctx.execute("SELECT * FROM information_schema.views")
+
+
 @pytest.mark.parametrize(
     "truncate_sql",
     [
@@ -3,7 +3,7 @@ from __future__ import annotations
 import pytest
 
 import polars as pl
-from polars.exceptions import SQLInterfaceError
+from polars.exceptions import SQLInterfaceError, SQLSyntaxError
 from polars.testing import assert_frame_equal
 from tests.unit.sql import assert_sql_matches
 
@@ -553,12 +553,12 @@ def test_window_frame_validation() -> None:
     ):
         df.sql(query)
 
-    # Rejected: ROWS with incompatible bounds
+    # Rejected: ROWS with incompatible bounds (for non-aggregate functions)
     query = """
-        SELECT lbl, SUM(value) OVER (
+        SELECT lbl, FIRST_VALUE(value) OVER (
             ORDER BY lbl
             ROWS BETWEEN 1 PRECEDING AND CURRENT ROW
-        ) AS sum_value
+        ) AS first_value
         FROM self
     """
     with pytest.raises(
@@ -569,3 +569,91 @@ def test_window_frame_validation() -> None:
         ),
     ):
         df.sql(query)
+
+
+def test_window_frame_aggregates(df_test: pl.DataFrame) -> None:
+    query = """
+        SELECT
+            id,
+            SUM(value) OVER (
+                PARTITION BY category ORDER BY id
+                ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING
+            ) AS sum_neighbours,
+            AVG(value) OVER (
+                ORDER BY id ROWS BETWEEN 1 PRECEDING AND CURRENT ROW
+            ) AS avg_pair,
+            MIN(value) OVER (
+                ORDER BY id ROWS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING
+            ) AS min_remaining,
+            MAX(value) OVER (
+                PARTITION BY category
+                ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING
+            ) AS max_category,
+            COUNT(value) OVER (
+                ORDER BY id ROWS BETWEEN 2 FOLLOWING AND 3 FOLLOWING
+            ) AS count_ahead
+        FROM self
+        ORDER BY id
+    """
+    assert_sql_matches(
+        df_test,
+        query=query,
+        compare_with="sqlite",
+        expected={
+            "id": [1, 2, 3, 4, 5, 6, 7],
+            "sum_neighbours": [30, 60, 40, 55, 80, 65, 35],
+            "avg_pair": [20.0, 15.0, 20.0, 22.5, 27.5, 32.5, 30.0],
+            "min_remaining": [10, 10, 15, 15, 25, 25, 35],
+            "max_category": [30, 30, 30, 40, 40, 40, 35],
+            "count_ahead": [2, 2, 2, 2, 1, 0, 0],
+        },
+    )
+
+
+def test_window_order_by_mixed_directions() -> None:
+    df = pl.DataFrame(
+        {
+            "id": [1, 2, 3, 4, 5],
+            "a": [1, 1, None, 2, 2],
+            "b": [None, 1, 2, 1, 3],
+        }
+    )
+    query = """
+        SELECT
+            id,
+            ROW_NUMBER() OVER (ORDER BY a DESC NULLS FIRST, b ASC NULLS LAST) AS rn,
+            RANK() OVER (ORDER BY a DESC, b NULLS LAST) AS rnk,
+            SUM(id) OVER (ORDER BY a DESC, b ASC NULLS LAST) AS cum_id
+        FROM self
+        ORDER BY id
+    """
+    assert_sql_matches(
+        df,
+        query=query,
+        compare_with="sqlite",
+        expected={
+            "id": [1, 2, 3, 4, 5],
+            "rn": [5, 4, 1, 2, 3],
+            "rnk": [5, 4, 1, 2, 3],
+            "cum_id": [15, 14, 3, 7, 12],
+        },
+    )
+
+
+def test_window_frame_aggregates_invalid(df_test: pl.DataFrame) -> None:
+    with pytest.raises(SQLInterfaceError, match="is not supported for AVG"):
+        df_test.sql(
+            """
+            SELECT AVG(value) OVER (
+                ORDER BY id ROWS BETWEEN UNBOUNDED PRECEDING AND 1 FOLLOWING
+            ) FROM self
+            """
+        )
+    with pytest.raises(SQLSyntaxError, match="cannot start after it ends"):
+        df_test.sql(
+            """
+            SELECT SUM(value) OVER (
+                ORDER BY id ROWS BETWEEN 1 FOLLOWING AND 1 PRECEDING
+            ) FROM self
+            """
+        )
@@ -385,3 +385,52 @@ def test_i128_sum_reduction() -> None:
         .item()
         == 6
     )
+
+
+@pytest.mark.parametrize(
+    ("expr", "node"),
+    [
+        (pl.col("x").sum().over("g"), "equi-join"),
+        (pl.col("x").mean().over("g", "h"), "equi-join"),
+        (pl.len().over(pl.col("h") % 2), "equi-join"),
+        ((pl.col("x").max() - pl.col("x").min()).over("g"), "equi-join"),
+        (pl.col("x").first().over("g", order_by="x"), "partitioned-window"),
+        (pl.col("x").cum_sum().over("g"), "partitioned-window"),
+        (pl.col("x").cum_sum().over("g", order_by="h"), "partitioned-window"),
+        (pl.col("x").rank().over(pl.col("h") % 2), "partitioned-window"),
+        (pl.col("x").over("h", mapping_strategy="join"), "partitioned-window"),
+        (pl.col("x").cum_sum().over(pl.col("h").rank()), "in-memory-map"),
+    ],
+)
+def test_streaming_over(expr: pl.Expr, node: str) -> None:
+    lf = pl.LazyFrame(
+        {
+            "g": ["a", "b", None, "a", "b", None, "c"],
+            "h": [1, 1, 2, 2, 1, 1, 2],
+            "x": [1, 2, 3, 4, 5, 6, None],
+        }
+    )
+    q = lf.with_columns(expr.alias("out"), y=pl.col("x") * 2)
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+    # Aggregating windows are lowered to a group by that is joined back to the rows,
+    # other windows with elementwise keys are evaluated per hash partition.
+    graph = q.show_graph(engine="streaming", plan_stage="physical", raw_output=True)
+    assert isinstance(graph, str)
+    assert node in graph
+    assert ("in-memory-map" in graph) == (node == "in-memory-map")
+
+
+def test_streaming_over_spill(monkeypatch: Any, capfd: Any) -> None:
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    monkeypatch.setenv("POLARS_WINDOW_MEMORY_BUDGET", "1")
+    lf = pl.LazyFrame({"g": [i % 13 for i in range(1_000)], "x": range(1_000)})
+    q = lf.select(
+        pl.col("x").cum_sum().over("g").alias("cum_sum"),
+        pl.col("x").shift().over("g", order_by=-pl.col("x")).alias("shift"),
+    )
+
+    expected = q.collect(engine="in-memory")
+    capfd.readouterr()
+    assert_frame_equal(q.collect(engine="streaming"), expected)
+    assert "window spills to" in capfd.readouterr().err
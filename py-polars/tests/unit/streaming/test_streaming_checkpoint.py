@@ -0,0 +1,78 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+pytestmark = pytest.mark.xdist_group("streaming")
+
+
+def _query(df: pl.DataFrame, udf: pl.Expr | None = None) -> pl.LazyFrame:
+    lf = df.lazy()
+    if udf is not None:
+        lf = lf.with_columns(udf)
+    return lf.filter(pl.col("x") % 3 != 0).group_by("g").agg(pl.col("x").sum())
+
+
+def test_collect_with_checkpoint(tmp_path: Path) -> None:
+    df = pl.DataFrame({"g": [i % 7 for i in range(500_000)], "x": range(500_000)})
+    expected = _query(df).collect().sort("g")
+
+    for interval in [60.0, 1e-6]:
+        out = _query(df).collect_with_checkpoint(tmp_path, interval=interval)
+        assert_frame_equal(out.sort("g"), expected)
+        # The checkpoint is removed once the query completes.
+        assert list(tmp_path.iterdir()) == []
+
+
+def test_resume_from_checkpoint(tmp_path: Path) -> None:
+    n = 1_000_000
+    df = pl.DataFrame({"g": [i % 7 for i in range(n)], "x": range(n)})
+    expected = _query(df).collect().sort("g")
+
+    rows_seen = 0
+    crash = True
+
+    def udf(s: pl.Series) -> pl.Series:
+        nonlocal rows_seen
+        rows_seen += s.len()
+        if crash and rows_seen > n // 2:
+            msg = "crash"
+            raise RuntimeError(msg)
+        return s
+
+    lf = _query(df, pl.col("x").map_batches(udf, is_elementwise=True))
+    with pytest.raises(Exception, match="crash"):
+        lf.collect_with_checkpoint(tmp_path, interval=1e-6)
+    assert (tmp_path / "checkpoint.json").exists()
+    # Only the state of the latest checkpoint is kept.
+    assert len(list((tmp_path / "states").iterdir())) == 1
+
+    rows_seen = 0
+    crash = False
+    out = lf.resume_from_checkpoint(tmp_path, interval=1e-6)
+    assert_frame_equal(out.sort("g"), expected)
+    # The rows before the checkpoint are not processed again.
+    assert 0 < rows_seen < n
+    assert list(tmp_path.iterdir()) == []
+
+
+def test_checkpoint_errors(tmp_path: Path) -> None:
+    df = pl.DataFrame({"g": [1, 2, 3], "x": [4, 5, 6]})
+
+    with pytest.raises(pl.exceptions.ComputeError, match="no checkpoint found"):
+        _query(df).resume_from_checkpoint(tmp_path)
+
+    with pytest.raises(
+        pl.exceptions.InvalidOperationError, match="checkpointing is not supported"
+    ):
+        df.lazy().join(df.lazy(), on="g").collect_with_checkpoint(tmp_path)
+
+    with pytest.raises(ValueError, match="must be positive"):
+        _query(df).collect_with_checkpoint(tmp_path, interval=0)
@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 from datetime import datetime
-from typing import TYPE_CHECKING, Literal
+from typing import TYPE_CHECKING, Any, Literal
 
 import numpy as np
 import pandas as pd
@@ -401,3 +401,53 @@ def test_cross_join_with_literal_column_25544() -> None:
 
     assert_frame_equal(streaming_result, in_memory_result)
     assert streaming_result.item() == 0
+
+
+def test_streaming_join_where_block_join() -> None:
+    left = pl.LazyFrame({"a": range(2_000), "b": [i % 7 for i in range(2_000)]})
+    right = pl.LazyFrame(
+        {"lo": [0, 500, 1_500], "hi": [100, 800, 1_600], "b": [3, 1, 5]}
+    )
+    q = left.join_where(right, pl.col("a") >= pl.col("lo"), pl.col("a") < pl.col("hi"))
+
+    expected = q.collect(engine="in-memory").sort("a", "lo")
+    assert expected.height == 500
+    assert expected.columns == ["a", "b", "lo", "hi", "b_right"]
+    assert_frame_equal(q.collect(engine="streaming").sort("a", "lo"), expected)
+
+    assert q.head(50).collect(engine="streaming").height == 50
+    assert q.slice(490).collect(engine="streaming").height == 10
+
+
+def test_streaming_join_where_block_join_buffers_smaller_side(
+    monkeypatch: Any, capfd: Any
+) -> None:
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    small = pl.LazyFrame({"lo": [0, 10], "hi": [5, 20]})
+    large = pl.LazyFrame({"a": range(1_000)})
+    predicates = [pl.col("a") >= pl.col("lo"), pl.col("a") < pl.col("hi")]
+
+    large.join_where(small, *predicates).collect(engine="streaming")
+    assert "block join buffers the right input" in capfd.readouterr().err
+
+    small.join_where(large, *predicates).collect(engine="streaming")
+    assert "block join buffers the left input" in capfd.readouterr().err
+
+
+def test_streaming_join_where_block_join_spill(monkeypatch: Any, capfd: Any) -> None:
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    monkeypatch.setenv("POLARS_BLOCK_JOIN_MEMORY_BUDGET", "1")
+    left = pl.LazyFrame({"a": range(2_000)})
+    right = pl.concat(
+        [
+            pl.LazyFrame({"lo": [0, 500], "hi": [100, 800]}),
+            pl.LazyFrame({"lo": [50], "hi": [150]}),
+            pl.LazyFrame({"lo": [1_500], "hi": [1_600]}),
+        ]
+    )
+    q = left.join_where(right, pl.col("a") >= pl.col("lo"), pl.col("a") < pl.col("hi"))
+
+    expected = q.collect(engine="in-memory").sort("a", "lo")
+    capfd.readouterr()
+    assert_frame_equal(q.collect(engine="streaming").sort("a", "lo"), expected)
+    assert "block join buffered 3 chunk(s), 3 spilled to disk" in capfd.readouterr().err
@@ -11,6 +11,7 @@ import polars as pl
 import polars._plr as plr
 from polars._utils.unstable import issue_unstable_warning
 from polars.config import _POLARS_CFG_ENV_VARS
+from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
     from collections.abc import Iterator
@@ -991,6 +992,8 @@ def test_warn_unstable(recwarn: pytest.WarningsRecorder) -> None:
             True,
             "1",
         ),
+        ("POLARS_SCAN_RETRY_BUDGET", "set_scan_retry_budget", 3, "3"),
+        ("POLARS_SMALL_BATCH_THRESHOLD", "set_small_batch_threshold", 1000, "1000"),
         ("POLARS_STREAMING_CHUNK_SIZE", "set_streaming_chunk_size", 100, "100"),
         ("POLARS_TABLE_WIDTH", "set_tbl_width_chars", 80, "80"),
         ("POLARS_VERBOSE", "set_verbose", True, "1"),
@@ -1007,3 +1010,21 @@ def test_unset_config_env_vars(
 
     with pl.Config(**{config_setting: None}):  # type: ignore[arg-type]
         assert environment_variable not in os.environ
+
+
+def test_small_batch_threshold() -> None:
+    df = pl.concat(
+        [pl.DataFrame({"a": [1, 2, 3, None], "b": ["x", "y", "x", "z"]})] * 3,
+        rechunk=False,
+    )
+    lf = (
+        df.lazy()
+        .filter(pl.col("a") > 1)
+        .with_columns(c=pl.col("a") * 2)
+        .group_by("b")
+        .agg(pl.col("c").sum())
+        .sort("b")
+    )
+    expected = lf.collect()
+    with pl.Config(small_batch_threshold=1000):
+        assert_frame_equal(lf.collect(), expected)
@@ -14,6 +14,7 @@ from polars.datatypes.convert import dtype_to_py_type
 from polars.exceptions import (
     ColumnNotFoundError,
     ComputeError,
+    DuplicateError,
     InvalidOperationError,
     OutOfBoundsError,
     SchemaError,
@@ -774,3 +775,48 @@ def test_raies_on_mismatch_column_length_binary_expr() -> None:
                 pl.col("c").head(pl.col("c").first()),
             )
         )
+
+
+def test_error_code_and_detail() -> None:
+    lf = pl.LazyFrame({"foo": [1], "bar": [2]})
+    with pytest.raises(ColumnNotFoundError) as exc:
+        lf.select("fooo").collect()
+    assert exc.value.code == "column_not_found"
+    assert exc.value.detail == {
+        "kind": "column_not_found",
+        "name": "fooo",
+        "similar": ["foo"],
+    }
+
+    with pytest.raises(DuplicateError) as exc_dup:
+        pl.Series([None, "null"]).to_dummies()
+    assert exc_dup.value.code == "duplicate"
+    assert exc_dup.value.detail == {
+        "kind": "duplicate_column",
+        "name": "_null",
+    }
+
+    with pytest.raises(OutOfBoundsError) as exc_oob:
+        pl.DataFrame({"a": [1, 2, 3]}).row(5)
+    assert exc_oob.value.code == "out_of_bounds"
+    assert exc_oob.value.detail == {
+        "kind": "out_of_bounds",
+        "index": 5,
+        "length": 3,
+    }
+
+    with pytest.raises(SchemaError) as exc_schema:
+        pl.Series([1, 2]).append(pl.Series(["a"]))
+    assert exc_schema.value.code == "schema_mismatch"
+    assert exc_schema.value.detail == {
+        "kind": "schema_mismatch",
+        "column": None,
+        "expected": "i64",
+        "found": "str",
+    }
+
+    # Errors without structured fields still have a code.
+    with pytest.raises(ComputeError) as exc_compute:
+        pl.DataFrame({"x": [0, 0, 1, 1]}).group_by([]).agg(pl.len())
+    assert exc_compute.value.code == "compute_error"
+    assert exc_compute.value.detail is None
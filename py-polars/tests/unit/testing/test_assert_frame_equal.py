@@ -8,7 +8,11 @@ from hypothesis import given
 
 import polars as pl
 from polars.exceptions import InvalidOperationError
-from polars.testing import assert_frame_equal, assert_frame_not_equal
+from polars.testing import (
+    assert_frame_equal,
+    assert_frame_equal_by_key,
+    assert_frame_not_equal,
+)
 from polars.testing.parametric import dataframes
 
 nan = float("nan")
@@ -423,6 +427,26 @@ def test_assert_dataframe_equal_all_nulls_fails_when_checking_dtypes() -> None:
         assert_frame_equal(x, y, check_dtypes=True)
 
 
+def test_assert_frame_equal_by_key() -> None:
+    df1 = pl.DataFrame({"id": [1, 2, 3], "a": ["x", None, "z"], "b": [1, 2, 3]})
+    df2 = pl.DataFrame({"b": [3, 1, 2], "a": ["z", "x", None], "id": [3, 1, 2]})
+    assert_frame_equal_by_key(df1, df2, on="id")
+    assert_frame_equal_by_key(df1.lazy(), df2.lazy(), on=["id", "a"])
+
+    df3 = pl.DataFrame({"id": [4, 3, 2], "a": ["w", "z", "y"], "b": [4, 3, 2]})
+    with pytest.raises(
+        AssertionError,
+        match=r'3 rows differ by key \["id"\], showing the first 2',
+    ) as exc:
+        assert_frame_equal_by_key(df1, df3, on="id", max_differences=2)
+    assert "removed" in str(exc.value)
+    assert "modified" in str(exc.value)
+    assert "added" not in str(exc.value)
+
+    with pytest.raises(AssertionError, match="dtypes do not match"):
+        assert_frame_equal_by_key(df1, df2.with_columns(pl.col("b") * 1.0), on="id")
+
+
 def test_tracebackhide(testdir: pytest.Testdir) -> None:
     testdir.makefile(
         ".py",